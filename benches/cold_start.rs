@@ -0,0 +1,101 @@
+//! Benchmarks for the cold start to the first recall.
+//!
+//! Each iteration opens the backends the way a fresh `subcog` process does
+//! and runs one vector search, the first recall's vector leg. Without
+//! `usearch-hnsw`, a cache miss parses the JSON vector index (and rebuilds the
+//! binary embedding cache), while a cache hit only reads the cache file and
+//! decodes the vectors on the search.
+//!
+//! Benchmark target:
+//! - Cache hit at least 10x faster than a cache miss at 10,000 memories
+
+// Criterion macros generate items without docs - this is expected for benchmarks
+// Benchmarks use expect/unwrap for simplicity - panics are acceptable in benchmarks
+#![allow(missing_docs)]
+#![allow(
+    clippy::expect_used,
+    clippy::unwrap_used,
+    clippy::cast_sign_loss,
+    clippy::cast_precision_loss
+)]
+
+use criterion::{BatchSize, BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::collections::HashMap;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use subcog::embedding::FastEmbedEmbedder;
+use subcog::services::BackendFactory;
+use subcog::storage::traits::VectorFilter;
+use subcog::storage::vector::EmbeddingCache;
+
+/// Embedding dimensions of the default model.
+const DIMENSIONS: usize = FastEmbedEmbedder::DEFAULT_DIMENSIONS;
+
+/// Returns a deterministic embedding for memory `i`.
+fn embedding(i: usize) -> Vec<f32> {
+    (0..DIMENSIONS)
+        .map(|d| ((i * DIMENSIONS + d) as f32).sin())
+        .collect()
+}
+
+/// Writes `count` embeddings as a JSON vector index in its own directory.
+///
+/// Returns the index database path and the vector index path.
+fn write_fixtures(dir: &Path, count: usize) -> (PathBuf, PathBuf) {
+    let dir = dir.join(count.to_string());
+    std::fs::create_dir_all(&dir).unwrap();
+    let vectors: HashMap<String, Vec<f32>> = (0..count)
+        .map(|i| (format!("mem-{i:08}"), embedding(i)))
+        .collect();
+
+    let vector_path = dir.join("vectors.usearch");
+    let json = serde_json::json!({ "dimensions": DIMENSIONS, "vectors": vectors });
+    std::fs::write(&vector_path, json.to_string()).unwrap();
+    (dir.join("index.db"), vector_path)
+}
+
+/// Opens the backends and runs the first vector search.
+fn first_recall(index_path: &Path, vector_path: &Path, embedder: &FastEmbedEmbedder) -> usize {
+    let backends = BackendFactory::create_all(index_path, vector_path, embedder);
+    let vector = backends.vector.unwrap();
+    let hits = vector
+        .search(&embedding(0), &VectorFilter::new(), 10)
+        .unwrap();
+    black_box(hits).len()
+}
+
+fn bench_cold_start(c: &mut Criterion) {
+    let dir = tempfile::TempDir::new().unwrap();
+    let embedder = FastEmbedEmbedder::new();
+    let mut group = c.benchmark_group("cold_start");
+    group.sample_size(10);
+    group.measurement_time(Duration::from_secs(10));
+
+    for count in [1_000, 10_000] {
+        let (index_path, vector_path) = write_fixtures(dir.path(), count);
+        let cache_path = EmbeddingCache::path_for(&vector_path);
+        group.throughput(Throughput::Elements(count as u64));
+
+        group.bench_function(BenchmarkId::new("cache_miss", count), |b| {
+            b.iter_batched(
+                || {
+                    let _ = std::fs::remove_file(&cache_path);
+                },
+                |()| first_recall(&index_path, &vector_path, &embedder),
+                BatchSize::PerIteration,
+            );
+        });
+
+        // The last cache miss left a current cache behind
+        group.bench_function(BenchmarkId::new("cache_hit", count), |b| {
+            b.iter(|| first_recall(&index_path, &vector_path, &embedder));
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_cold_start);
+criterion_main!(benches);
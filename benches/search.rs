@@ -26,7 +26,8 @@
 use subcog::storage::index::SqliteBackend;
 use subcog::storage::vector::UsearchBackend;
 use subcog::{
-    CaptureRequest, CaptureService, Domain, Namespace, RecallService, SearchFilter, SearchMode,
+    CaptureOrigin, CaptureRequest, CaptureService, Domain, Namespace, RecallService, SearchFilter,
+    SearchMode,
 };
 
 // ============================================================================
@@ -35,7 +36,8 @@
 
 /// Creates a capture service with all backends.
 fn create_capture_service(temp_dir: &TempDir) -> CaptureService {
-    let config = subcog::config::Config::default();
+    let mut config = subcog::config::Config::default();
+    config.security.allow_skip = true;
     let embedder: Arc<dyn subcog::Embedder> = Arc::new(FastEmbedEmbedder::new());
 
     let index_path = temp_dir.path().join("bench_index.db");
@@ -116,6 +118,12 @@ fn populate_index(capture_service: &CaptureService, count: usize) {
             scope: None,
             #[cfg(feature = "group-scope")]
             group_id: None,
+            sections: Vec::new(),
+            template: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
         };
 
         if let Err(e) = capture_service.capture(request) {
@@ -1,14 +1,21 @@
-//! Delete CLI command for removing memories.
+//! Delete and restore CLI commands for removing memories.
 //!
-//! Provides both soft delete (tombstone) and hard delete capabilities.
+//! Provides soft delete (tombstone), hard delete, and restore of tombstoned
+//! memories.
 //!
 //! # Usage
 //!
 //! ```bash
-//! # Soft delete (default) - can be restored via gc command
+//! # Soft delete (default) - can be restored with `subcog restore`
 //! subcog delete abc123
 //! subcog delete id1 id2 id3
 //!
+//! # Tombstone every memory in a namespace (requires --force)
+//! subcog delete --all --namespace progress --force
+//!
+//! # Restore tombstoned memories
+//! subcog restore abc123
+//!
 //! # Hard delete - permanent, irreversible
 //! subcog delete --hard abc123
 //!
@@ -25,13 +32,10 @@
 // Allow pass-by-value for command functions (consistent with main.rs)
 #![allow(clippy::needless_pass_by_value)]
 
-use crate::Result;
-use crate::models::{EventMeta, Memory, MemoryEvent, MemoryId, MemoryStatus};
-use crate::observability::current_request_id;
-use crate::security::record_event;
-use crate::services::ServiceContainer;
+use crate::models::{Memory, MemoryId, Namespace, SearchFilter};
+use crate::services::{CaptureService, ServiceContainer};
 use crate::storage::traits::IndexBackend;
-use chrono::TimeZone;
+use crate::{Error, Result};
 use std::io::{self, Write};
 
 /// Result of a delete operation.
@@ -47,20 +51,34 @@ pub struct DeleteResult {
     pub not_found_ids: Vec<String>,
 }
 
+/// Maximum number of memories removed by one `--all` delete.
+const MAX_BULK_DELETE: usize = 10_000;
+
 /// Executes the delete command.
 ///
 /// # Arguments
 ///
 /// * `ids` - Memory IDs to delete
+/// * `namespace` - Delete every memory in this namespace instead (`--all`)
 /// * `hard` - If true, permanently delete; otherwise tombstone (soft delete)
-/// * `force` - If true, skip confirmation prompt
+/// * `force` - If true, skip confirmation prompt (required for `--all`)
 /// * `dry_run` - If true, show what would be deleted without making changes
 ///
+/// Deleted memories are also removed from the vector index. Tombstones stay
+/// in the database, so `subcog reindex` keeps them deleted.
+///
 /// # Errors
 ///
-/// Returns an error if storage access fails.
-pub fn execute(ids: Vec<String>, hard: bool, force: bool, dry_run: bool) -> Result<()> {
-    if ids.is_empty() {
+/// Returns an error if storage access fails, the namespace is unknown, or
+/// `--all` is used without `--force` or `--dry-run`.
+pub fn execute(
+    ids: Vec<String>,
+    namespace: Option<String>,
+    hard: bool,
+    force: bool,
+    dry_run: bool,
+) -> Result<()> {
+    if ids.is_empty() && namespace.is_none() {
         println!("No memory IDs provided. Usage: subcog delete <ID>...");
         return Ok(());
     }
@@ -69,30 +87,10 @@ pub fn execute(ids: Vec<String>, hard: bool, force: bool, dry_run: bool) -> Resu
     let container = ServiceContainer::from_current_dir_or_user()?;
     let index = container.index()?;
 
-    // Validate IDs and collect existing memories
-    let mut valid_ids: Vec<(MemoryId, String, Memory)> = Vec::new();
-    let mut not_found_ids = Vec::new();
-
-    for id_str in &ids {
-        let id = MemoryId::new(id_str);
-        match index.get_memory(&id)? {
-            Some(memory) => {
-                valid_ids.push((id, memory.namespace.as_str().to_string(), memory));
-            },
-            None => {
-                not_found_ids.push(id_str.clone());
-            },
-        }
-    }
-
-    // Report not found IDs
-    if !not_found_ids.is_empty() {
-        println!("Not found ({}):", not_found_ids.len());
-        for id in &not_found_ids {
-            println!("  - {id}");
-        }
-        println!();
-    }
+    let valid_ids = match namespace {
+        Some(ref namespace) => find_in_namespace(&*index, namespace)?,
+        None => find_by_ids(&*index, &ids)?,
+    };
 
     if valid_ids.is_empty() {
         println!("No valid memories to delete.");
@@ -106,58 +104,31 @@ pub fn execute(ids: Vec<String>, hard: bool, force: bool, dry_run: bool) -> Resu
             "Dry-run mode: would {action} {} memories:\n",
             valid_ids.len()
         );
-        for (id, namespace, _) in &valid_ids {
-            println!("  - {} ({})", id.as_str(), namespace);
-        }
+        print_targets(&valid_ids);
         return Ok(());
     }
 
-    // Confirmation prompt (unless --force)
-    if !force {
-        let action = if hard {
-            "PERMANENTLY DELETE"
-        } else {
-            "tombstone (soft delete)"
-        };
-        println!("About to {action} {} memories:\n", valid_ids.len());
-        for (id, namespace, _) in &valid_ids {
-            println!("  - {} ({})", id.as_str(), namespace);
-        }
-        println!();
-
-        if hard {
-            println!("WARNING: Hard delete is IRREVERSIBLE!");
-        } else {
-            println!("Note: Tombstoned memories can be restored or purged later with `subcog gc`.");
-        }
+    if let Some(namespace) = namespace
+        && !force
+    {
+        return Err(Error::InvalidInput(format!(
+            "Deleting all {} memories in '{namespace}' requires --force \
+             (use --dry-run to preview)",
+            valid_ids.len()
+        )));
+    }
 
-        print!("\nProceed? [y/N] ");
-        io::stdout()
-            .flush()
-            .map_err(|e| crate::Error::OperationFailed {
-                operation: "flush_stdout".to_string(),
-                cause: e.to_string(),
-            })?;
-
-        let mut input = String::new();
-        io::stdin()
-            .read_line(&mut input)
-            .map_err(|e| crate::Error::OperationFailed {
-                operation: "read_stdin".to_string(),
-                cause: e.to_string(),
-            })?;
-
-        if !input.trim().eq_ignore_ascii_case("y") {
-            println!("Cancelled.");
-            return Ok(());
-        }
+    // Confirmation prompt (unless --force)
+    if !force && !confirm(&valid_ids, hard)? {
+        println!("Cancelled.");
+        return Ok(());
     }
 
     // Execute deletion
     let result = if hard {
-        hard_delete(&*index, &valid_ids)
+        hard_delete(container.capture(), &valid_ids)
     } else {
-        soft_delete(&*index, &valid_ids)
+        soft_delete(container.capture(), &valid_ids)
     };
 
     // Report results
@@ -172,44 +143,163 @@ pub fn execute(ids: Vec<String>, hard: bool, force: bool, dry_run: bool) -> Resu
 
     if !hard {
         println!("\nTo permanently delete, run: subcog gc --purge");
-        println!("To restore, tombstoned memories remain searchable with --include-tombstoned");
+        println!("To restore, run: subcog restore <ID>...");
+    }
+
+    Ok(())
+}
+
+/// Executes the restore command, reactivating tombstoned memories.
+///
+/// # Errors
+///
+/// Returns an error if storage access fails. Memories that are missing or
+/// not tombstoned are reported and skipped.
+pub fn restore(ids: Vec<String>) -> Result<()> {
+    let container = ServiceContainer::from_current_dir_or_user()?;
+    let capture = container.capture();
+
+    let mut restored = 0u64;
+    for id in &ids {
+        match capture.restore(&MemoryId::new(id)) {
+            Ok(memory) => {
+                restored += 1;
+                println!("Restored {} ({})", memory.id.as_str(), memory.namespace);
+            },
+            Err(e @ (Error::InvalidInput(_) | Error::OperationFailed { .. })) => {
+                eprintln!("Failed to restore {id}: {e}");
+            },
+            Err(e) => return Err(e),
+        }
     }
 
+    metrics::counter!("cli_restore_total").increment(restored);
     Ok(())
 }
 
+/// Looks up the given IDs, reporting the ones that do not exist.
+fn find_by_ids(
+    index: &dyn IndexBackend,
+    ids: &[String],
+) -> Result<Vec<(MemoryId, String, Memory)>> {
+    let mut valid_ids: Vec<(MemoryId, String, Memory)> = Vec::new();
+    let mut not_found_ids = Vec::new();
+
+    for id_str in ids {
+        let id = MemoryId::new(id_str);
+        match index.get_memory(&id)? {
+            Some(memory) => {
+                valid_ids.push((id, memory.namespace.as_str().to_string(), memory));
+            },
+            None => {
+                not_found_ids.push(id_str.clone());
+            },
+        }
+    }
+
+    // Report not found IDs
+    if !not_found_ids.is_empty() {
+        println!("Not found ({}):", not_found_ids.len());
+        for id in &not_found_ids {
+            println!("  - {id}");
+        }
+        println!();
+    }
+
+    Ok(valid_ids)
+}
+
+/// Collects every live (not yet tombstoned) memory in a namespace.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidInput`] if the namespace is unknown or holds more
+/// than [`MAX_BULK_DELETE`] live memories, rather than deleting only some.
+fn find_in_namespace(
+    index: &dyn IndexBackend,
+    namespace: &str,
+) -> Result<Vec<(MemoryId, String, Memory)>> {
+    let namespace = Namespace::parse(namespace)
+        .ok_or_else(|| Error::InvalidInput(format!("Unknown namespace: {namespace}")))?;
+    let filter = SearchFilter::new().with_namespace(namespace);
+    let ids: Vec<MemoryId> = index
+        .list_all(&filter, MAX_BULK_DELETE + 1)?
+        .into_iter()
+        .map(|(id, _score)| id)
+        .collect();
+    if ids.len() > MAX_BULK_DELETE {
+        let total = index.list_all(&filter, usize::MAX)?.len();
+        return Err(Error::InvalidInput(format!(
+            "Namespace '{namespace}' has {total} live memories; --all deletes at most \
+             {MAX_BULK_DELETE} at a time (delete by ID instead)"
+        )));
+    }
+
+    Ok(index
+        .get_memories_batch(&ids)?
+        .into_iter()
+        .flatten()
+        .map(|memory| (memory.id.clone(), namespace.as_str().to_string(), memory))
+        .collect())
+}
+
+/// Prints the memories a delete applies to.
+fn print_targets(ids: &[(MemoryId, String, Memory)]) {
+    for (id, namespace, _) in ids {
+        println!("  - {} ({})", id.as_str(), namespace);
+    }
+}
+
+/// Asks the user to confirm the delete; returns whether they did.
+fn confirm(ids: &[(MemoryId, String, Memory)], hard: bool) -> Result<bool> {
+    let action = if hard {
+        "PERMANENTLY DELETE"
+    } else {
+        "tombstone (soft delete)"
+    };
+    println!("About to {action} {} memories:\n", ids.len());
+    print_targets(ids);
+    println!();
+
+    if hard {
+        println!("WARNING: Hard delete is IRREVERSIBLE!");
+    } else {
+        println!("Note: Tombstoned memories can be restored with `subcog restore`.");
+    }
+
+    print!("\nProceed? [y/N] ");
+    io::stdout().flush().map_err(|e| Error::OperationFailed {
+        operation: "flush_stdout".to_string(),
+        cause: e.to_string(),
+    })?;
+
+    let mut input = String::new();
+    io::stdin()
+        .read_line(&mut input)
+        .map_err(|e| Error::OperationFailed {
+            operation: "read_stdin".to_string(),
+            cause: e.to_string(),
+        })?;
+
+    Ok(input.trim().eq_ignore_ascii_case("y"))
+}
+
 /// Performs soft delete (tombstone) on the given memories.
-fn soft_delete(index: &dyn IndexBackend, ids: &[(MemoryId, String, Memory)]) -> DeleteResult {
+///
+/// Goes through [`CaptureService::tombstone`], so deletes write the same
+/// stores `subcog restore` does.
+fn soft_delete(capture: &CaptureService, ids: &[(MemoryId, String, Memory)]) -> DeleteResult {
     let mut result = DeleteResult::default();
-    let now = crate::current_timestamp();
-    let now_i64 = i64::try_from(now).unwrap_or(i64::MAX);
-    let now_dt = chrono::Utc
-        .timestamp_opt(now_i64, 0)
-        .single()
-        .unwrap_or_else(chrono::Utc::now);
-
-    for (id, _namespace, memory) in ids {
-        // Update memory status to tombstoned
-        let mut updated_memory = memory.clone();
-        updated_memory.status = MemoryStatus::Tombstoned;
-        updated_memory.tombstoned_at = Some(now_dt);
-        updated_memory.updated_at = now;
-
-        // Re-index with updated status
-        match index.index(&updated_memory) {
-            Ok(()) => {
+
+    for (id, _namespace, _memory) in ids {
+        match capture.tombstone(id) {
+            Ok(memory) => {
                 result.deleted += 1;
                 result.deleted_ids.push(id.as_str().to_string());
 
-                record_event(MemoryEvent::Updated {
-                    meta: EventMeta::with_timestamp("cli.delete", current_request_id(), now),
-                    memory_id: id.clone(),
-                    modified_fields: vec!["status".to_string(), "tombstoned_at".to_string()],
-                });
-
                 tracing::info!(
                     memory_id = %id.as_str(),
-                    tombstoned_at = now,
+                    tombstoned_at = memory.updated_at,
                     "Tombstoned memory via CLI"
                 );
             },
@@ -226,21 +316,18 @@ fn soft_delete(index: &dyn IndexBackend, ids: &[(MemoryId, String, Memory)]) ->
 }
 
 /// Performs hard delete (permanent) on the given memories.
-fn hard_delete(index: &dyn IndexBackend, ids: &[(MemoryId, String, Memory)]) -> DeleteResult {
+///
+/// Goes through [`CaptureService::delete`], so the index, persistence and
+/// vector stores are cleared together, as for the MCP and REST deletes.
+fn hard_delete(capture: &CaptureService, ids: &[(MemoryId, String, Memory)]) -> DeleteResult {
     let mut result = DeleteResult::default();
 
     for (id, _namespace, _memory) in ids {
-        match index.remove(id) {
+        match capture.delete(id, true) {
             Ok(true) => {
                 result.deleted += 1;
                 result.deleted_ids.push(id.as_str().to_string());
 
-                record_event(MemoryEvent::Deleted {
-                    meta: EventMeta::new("cli.delete", current_request_id()),
-                    memory_id: id.clone(),
-                    reason: "cli.delete --hard".to_string(),
-                });
-
                 tracing::info!(
                     memory_id = %id.as_str(),
                     "Hard deleted memory via CLI"
@@ -265,8 +352,10 @@ fn hard_delete(index: &dyn IndexBackend, ids: &[(MemoryId, String, Memory)]) ->
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, Namespace};
+    use crate::config::Config;
+    use crate::models::{CaptureOrigin, Domain, MemoryStatus};
     use crate::storage::index::SqliteBackend;
+    use std::sync::Arc;
     use tempfile::TempDir;
 
     fn create_test_memory(id: &str) -> Memory {
@@ -291,13 +380,21 @@ fn create_test_memory(id: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
     #[test]
     fn test_soft_delete_single() {
         let dir = TempDir::new().unwrap();
-        let backend = SqliteBackend::new(dir.path().join("test.db")).unwrap();
+        let backend = Arc::new(SqliteBackend::new(dir.path().join("test.db")).unwrap());
+        let capture = CaptureService::new_minimal(Config::default()).with_index(backend.clone());
 
         // Store a memory
         let memory = create_test_memory("test-soft-1");
@@ -305,7 +402,7 @@ fn test_soft_delete_single() {
 
         // Soft delete
         let ids = vec![(memory.id.clone(), "decisions".to_string(), memory.clone())];
-        let result = soft_delete(&backend, &ids);
+        let result = soft_delete(&capture, &ids);
 
         assert_eq!(result.deleted, 1);
         assert_eq!(result.not_found, 0);
@@ -317,29 +414,39 @@ fn test_soft_delete_single() {
 
     #[test]
     fn test_hard_delete_single() {
+        use crate::storage::persistence::FilesystemBackend;
+        use crate::storage::traits::PersistenceBackend;
+
         let dir = TempDir::new().unwrap();
-        let backend = SqliteBackend::new(dir.path().join("test.db")).unwrap();
+        let backend = Arc::new(SqliteBackend::new(dir.path().join("test.db")).unwrap());
+        let persistence = Arc::new(FilesystemBackend::new(dir.path().join("memories")));
+        let capture = CaptureService::new_minimal(Config::default())
+            .with_index(backend.clone())
+            .with_persistence(persistence.clone());
 
         // Store a memory
         let memory = create_test_memory("test-hard-1");
         backend.index(&memory).unwrap();
+        persistence.store(&memory).unwrap();
 
         // Hard delete
         let ids = vec![(memory.id.clone(), "decisions".to_string(), memory.clone())];
-        let result = hard_delete(&backend, &ids);
+        let result = hard_delete(&capture, &ids);
 
         assert_eq!(result.deleted, 1);
         assert_eq!(result.not_found, 0);
 
-        // Verify it's gone
+        // Verify it's gone from the index and persistence
         let retrieved = backend.get_memory(&memory.id).unwrap();
         assert!(retrieved.is_none());
+        assert!(persistence.get(&memory.id).unwrap().is_none());
     }
 
     #[test]
     fn test_delete_not_found() {
         let dir = TempDir::new().unwrap();
-        let backend = SqliteBackend::new(dir.path().join("test.db")).unwrap();
+        let backend = Arc::new(SqliteBackend::new(dir.path().join("test.db")).unwrap());
+        let capture = CaptureService::new_minimal(Config::default()).with_index(backend);
 
         // Create a memory object but don't index it
         let memory = create_test_memory("nonexistent");
@@ -350,7 +457,7 @@ fn test_delete_not_found() {
             "decisions".to_string(),
             memory,
         )];
-        let result = hard_delete(&backend, &ids);
+        let result = hard_delete(&capture, &ids);
 
         assert_eq!(result.deleted, 0);
         assert_eq!(result.not_found, 1);
@@ -359,7 +466,8 @@ fn test_delete_not_found() {
     #[test]
     fn test_delete_multiple() {
         let dir = TempDir::new().unwrap();
-        let backend = SqliteBackend::new(dir.path().join("test.db")).unwrap();
+        let backend = Arc::new(SqliteBackend::new(dir.path().join("test.db")).unwrap());
+        let capture = CaptureService::new_minimal(Config::default()).with_index(backend.clone());
 
         // Store multiple memories
         let m1 = create_test_memory("test-multi-1");
@@ -375,9 +483,34 @@ fn test_delete_multiple() {
             (m2.id.clone(), "decisions".to_string(), m2),
             (m3.id.clone(), "decisions".to_string(), m3),
         ];
-        let result = hard_delete(&backend, &ids);
+        let result = hard_delete(&capture, &ids);
 
         assert_eq!(result.deleted, 3);
         assert_eq!(result.not_found, 0);
     }
+
+    #[test]
+    fn test_find_in_namespace_skips_tombstoned_and_other_namespaces() {
+        let dir = TempDir::new().unwrap();
+        let backend = SqliteBackend::new(dir.path().join("test.db")).unwrap();
+
+        let live = create_test_memory("test-ns-live");
+        let mut tombstoned = create_test_memory("test-ns-tombstoned");
+        tombstoned.status = MemoryStatus::Tombstoned;
+        let mut other = create_test_memory("test-ns-other");
+        other.namespace = Namespace::Progress;
+        for memory in [&live, &tombstoned, &other] {
+            backend.index(memory).unwrap();
+        }
+
+        let found = find_in_namespace(&backend, "decisions").unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0, live.id);
+        assert_eq!(found[0].2.content, live.content);
+
+        assert!(matches!(
+            find_in_namespace(&backend, "no-such-namespace"),
+            Err(Error::InvalidInput(_))
+        ));
+    }
 }
@@ -3,7 +3,11 @@
 #![allow(clippy::print_stdout)]
 
 use crate::Result;
-use crate::services::TombstoneService;
+use crate::config::SubcogConfig;
+use crate::gc::{
+    ExpirationConfig, GcOrchestrator, MaintenanceLock, RetentionConfig, UnifiedGcReport,
+};
+use crate::services::{ServiceContainer, TombstoneService};
 use crate::storage::get_user_data_dir;
 use crate::storage::persistence::FilesystemBackend;
 use std::sync::Arc;
@@ -60,3 +64,80 @@ pub fn execute(dry_run: bool, purge: bool, older_than_days: u64) -> Result<()> {
 
     Ok(())
 }
+
+/// Runs every garbage collector against all available scopes.
+///
+/// Holds the maintenance lock in the user data directory for the whole run.
+/// Used by `subcog gc --all` and the `serve` GC scheduler.
+///
+/// # Errors
+///
+/// Returns an error if another maintenance run holds the lock, or if an
+/// index cannot be opened or updated.
+pub fn run_all(dry_run: bool) -> Result<UnifiedGcReport> {
+    let _lock = MaintenanceLock::acquire(&get_user_data_dir()?)?;
+    let services = ServiceContainer::from_current_dir_or_user()?;
+    services
+        .gc_scopes()?
+        .into_iter()
+        .fold(
+            GcOrchestrator::new(
+                RetentionConfig::from_config(&SubcogConfig::load_default()),
+                ExpirationConfig::from_env(),
+            ),
+            GcOrchestrator::with_scope,
+        )
+        .run(dry_run)
+}
+
+/// Runs unified garbage collection and prints the report.
+///
+/// # Errors
+///
+/// Returns an error if the run fails (see [`run_all`]).
+pub fn execute_all(dry_run: bool) -> Result<()> {
+    let report = run_all(dry_run)?;
+    let scopes: Vec<&str> = report
+        .scopes_processed
+        .iter()
+        .map(crate::storage::index::DomainScope::as_str)
+        .collect();
+
+    println!(
+        "{}",
+        if dry_run {
+            "Garbage Collection Preview"
+        } else {
+            "Garbage Collection"
+        }
+    );
+    println!("Scopes: {}\n", scopes.join(", "));
+    println!("  TTL expired:   {}", report.expired_ttl);
+    if report.stale_branches.is_empty() {
+        println!("  Stale branch:  {}", report.stale_branch);
+    } else {
+        println!(
+            "  Stale branch:  {} ({})",
+            report.stale_branch,
+            report.stale_branches.join(", ")
+        );
+    }
+    println!("  Retention:     {}", report.retention);
+    println!("  Conflicts:     {}\n", report.conflicts);
+
+    let action = if dry_run {
+        "Would tombstone"
+    } else {
+        "Tombstoned"
+    };
+    println!(
+        "{action} {} memories in {}ms",
+        report.total_tombstoned(),
+        report.duration_ms
+    );
+    if dry_run {
+        println!("Run without --dry-run to apply changes.");
+    }
+
+    Ok(())
+}
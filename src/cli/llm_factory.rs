@@ -6,8 +6,8 @@
 
 use crate::config::LlmConfig;
 use crate::llm::{
-    AnthropicClient, LlmHttpConfig, LlmProvider, LlmResilienceConfig, LmStudioClient, OllamaClient,
-    OpenAiClient, ResilientLlmProvider,
+    AnthropicClient, GeminiClient, LlmHttpConfig, LlmProvider, LlmResilienceConfig, LmStudioClient,
+    OllamaClient, OpenAiClient, ResilientLlmProvider,
 };
 
 /// Builds HTTP configuration from LLM config with environment overrides.
@@ -57,6 +57,25 @@ pub fn build_anthropic_client(llm_config: &LlmConfig) -> AnthropicClient {
     client.with_http_config(build_http_config(llm_config))
 }
 
+/// Builds a Gemini client from configuration.
+#[must_use]
+pub fn build_gemini_client(llm_config: &LlmConfig) -> GeminiClient {
+    let mut client = GeminiClient::new();
+    if let Some(ref api_key) = llm_config.api_key {
+        client = client.with_api_key(api_key);
+    }
+    if let Some(ref model) = llm_config.model {
+        client = client.with_model(model);
+    }
+    if let Some(ref base_url) = llm_config.base_url {
+        client = client.with_endpoint(base_url);
+    }
+    if let Some(max_tokens) = llm_config.max_tokens {
+        client = client.with_max_tokens(max_tokens);
+    }
+    client.with_http_config(build_http_config(llm_config))
+}
+
 /// Builds an Ollama client from configuration.
 #[must_use]
 pub fn build_ollama_client(llm_config: &LlmConfig) -> OllamaClient {
@@ -85,14 +104,14 @@ pub fn build_lmstudio_client(llm_config: &LlmConfig) -> LmStudioClient {
 
 /// Builds an LLM provider for hooks from configuration.
 ///
-/// Returns `None` if LLM is disabled for search intent.
+/// Returns `None` if LLM is disabled for search intent or offline mode is enabled.
 #[must_use]
 pub fn build_hook_llm_provider(
     config: &crate::config::SubcogConfig,
 ) -> Option<Arc<dyn LlmProvider>> {
     use crate::config::LlmProvider as Provider;
 
-    if !config.search_intent.use_llm {
+    if !config.search_intent.use_llm || crate::config::is_offline() {
         return None;
     }
 
@@ -112,6 +131,13 @@ pub fn build_hook_llm_provider(
                 resilience_config,
             ))
         },
+        Provider::Gemini => {
+            let resilience_config = build_resilience_config(llm_config);
+            Arc::new(ResilientLlmProvider::new(
+                build_gemini_client(llm_config),
+                resilience_config,
+            ))
+        },
         Provider::Ollama => {
             let resilience_config = build_resilience_config(llm_config);
             Arc::new(ResilientLlmProvider::new(
@@ -138,7 +164,7 @@ pub fn build_hook_llm_provider(
 /// longer than the default LLM timeout. This function creates an LLM provider
 /// with the entity extraction timeout from config (default: 120s).
 ///
-/// Returns `None` if LLM features are disabled in config.
+/// Returns `None` if LLM features are disabled in config or offline mode is enabled.
 #[must_use]
 pub fn build_llm_provider_for_entity_extraction(
     config: &crate::config::SubcogConfig,
@@ -155,6 +181,10 @@ pub fn build_llm_provider_for_entity_extraction(
         tracing::debug!("LLM features disabled in config, returning None");
         return None;
     }
+    if crate::config::is_offline() {
+        tracing::debug!("Offline mode enabled, returning None");
+        return None;
+    }
 
     // Create a modified LLM config with the entity extraction timeout
     let entity_timeout_ms = u64::try_from(
@@ -187,6 +217,13 @@ pub fn build_llm_provider_for_entity_extraction(
                 resilience_config,
             ))
         },
+        Provider::Gemini => {
+            let resilience_config = build_resilience_config(&llm_config);
+            Arc::new(ResilientLlmProvider::new(
+                build_gemini_client(&llm_config),
+                resilience_config,
+            ))
+        },
         Provider::Ollama => {
             let resilience_config = build_resilience_config(&llm_config);
             Arc::new(ResilientLlmProvider::new(
@@ -217,7 +254,7 @@ pub fn build_llm_provider_for_entity_extraction(
 
 /// Builds an LLM provider from configuration.
 ///
-/// Returns `None` if LLM features are disabled in config.
+/// Returns `None` if LLM features are disabled in config or offline mode is enabled.
 /// This is a general-purpose LLM provider builder for entity extraction
 /// and other LLM-powered features (not tied to search intent).
 #[must_use]
@@ -234,6 +271,10 @@ pub fn build_llm_provider(config: &crate::config::SubcogConfig) -> Option<Arc<dy
         tracing::debug!("LLM features disabled in config, returning None");
         return None;
     }
+    if crate::config::is_offline() {
+        tracing::debug!("Offline mode enabled, returning None");
+        return None;
+    }
 
     let llm_config = &config.llm;
     let provider: Arc<dyn LlmProvider> = match llm_config.provider {
@@ -251,6 +292,13 @@ pub fn build_llm_provider(config: &crate::config::SubcogConfig) -> Option<Arc<dy
                 resilience_config,
             ))
         },
+        Provider::Gemini => {
+            let resilience_config = build_resilience_config(llm_config);
+            Arc::new(ResilientLlmProvider::new(
+                build_gemini_client(llm_config),
+                resilience_config,
+            ))
+        },
         Provider::Ollama => {
             let resilience_config = build_resilience_config(llm_config);
             Arc::new(ResilientLlmProvider::new(
@@ -324,6 +372,19 @@ fn test_build_anthropic_client_with_config() {
         assert_eq!(client.name(), "anthropic");
     }
 
+    #[test]
+    fn test_build_gemini_client_with_config() {
+        let llm_config = LlmConfig {
+            api_key: Some("AIza-test-key".to_string()),
+            model: Some("gemini-2.5-pro".to_string()),
+            max_tokens: Some(1024),
+            ..Default::default()
+        };
+
+        let client = build_gemini_client(&llm_config);
+        assert_eq!(client.name(), "gemini");
+    }
+
     #[test]
     fn test_build_ollama_client_with_config() {
         let llm_config = LlmConfig {
@@ -378,6 +439,16 @@ fn test_build_hook_llm_provider_anthropic() {
         assert!(provider.is_some());
     }
 
+    #[test]
+    fn test_build_hook_llm_provider_gemini() {
+        let mut config = SubcogConfig::default();
+        config.search_intent.use_llm = true;
+        config.llm.provider = Provider::Gemini;
+
+        let provider = build_hook_llm_provider(&config);
+        assert!(provider.is_some());
+    }
+
     #[test]
     fn test_build_hook_llm_provider_ollama() {
         let mut config = SubcogConfig::default();
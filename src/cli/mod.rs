@@ -17,6 +17,7 @@
 //! | `config` | Configuration management |
 //! | `prompt` | Prompt template management |
 //! | `namespaces` | List available namespaces |
+//! | `prune` | Tombstone low-confidence auto-captures |
 //!
 //! # Example Usage
 //!
@@ -50,6 +51,7 @@
 mod migrate;
 mod namespaces;
 mod prompt;
+pub mod prune;
 mod recall;
 mod serve;
 mod status;
@@ -61,9 +63,9 @@
 pub use consolidate::ConsolidateCommand;
 pub use hook::HookCommand;
 pub use llm_factory::{
-    build_anthropic_client, build_hook_llm_provider, build_http_config, build_llm_provider,
-    build_llm_provider_for_entity_extraction, build_lmstudio_client, build_ollama_client,
-    build_openai_client, build_resilience_config,
+    build_anthropic_client, build_gemini_client, build_hook_llm_provider, build_http_config,
+    build_llm_provider, build_llm_provider_for_entity_extraction, build_lmstudio_client,
+    build_ollama_client, build_openai_client, build_resilience_config,
 };
 pub use migrate::MigrateCommand;
 pub use namespaces::{NamespaceInfo, NamespacesOutputFormat, cmd_namespaces, get_all_namespaces};
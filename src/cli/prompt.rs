@@ -9,6 +9,7 @@
 // The if-let-else pattern is clearer for nested conditionals
 #![allow(clippy::option_if_let_else)]
 
+use crate::llm::LlmProvider;
 use crate::models::{PromptTemplate, PromptVariable, substitute_variables};
 use crate::services::{
     EnrichmentStatus, PartialMetadata, PromptFilter, PromptFormat, PromptParser, PromptService,
@@ -562,15 +563,19 @@ fn print_template_details(template: &PromptTemplate) {
 /// * `variables` - Variable values as KEY=VALUE pairs.
 /// * `domain` - Optional domain scope.
 /// * `interactive` - Whether to prompt for missing variables.
+/// * `llm` - Provider to send the rendered prompt to; its completion is
+///   printed as it streams in instead of the prompt.
 ///
 /// # Errors
 ///
-/// Returns an error if the prompt is not found or variables are missing.
+/// Returns an error if the prompt is not found, variables are missing, or
+/// the completion fails.
 pub fn cmd_prompt_run(
     name: String,
     variables: Vec<String>,
     domain: Option<String>,
     interactive: bool,
+    llm: Option<&dyn LlmProvider>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut service = create_prompt_service()?;
 
@@ -613,8 +618,19 @@ pub fn cmd_prompt_run(
     let actual_scope = scope.unwrap_or(DomainScope::Project);
     let _ = service.increment_usage(&name, actual_scope);
 
-    // Output the result
-    println!("{result}");
+    let Some(llm) = llm else {
+        println!("{result}");
+        return Ok(());
+    };
+
+    // Print tokens as they arrive; write errors (e.g. a closed pipe) are
+    // ignored rather than panicking like `print!`
+    let mut stdout = io::stdout();
+    llm.complete_stream(&result, &mut |chunk| {
+        let _ = write!(stdout, "{chunk}");
+        let _ = stdout.flush();
+    })?;
+    println!();
 
     Ok(())
 }
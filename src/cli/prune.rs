@@ -0,0 +1,180 @@
+//! Prune CLI command for removing low-confidence auto-captures.
+//!
+//! Tombstones hook-captured memories whose capture confidence is below a
+//! threshold. Manual captures are only considered with `--include-manual`.
+//!
+//! # Usage
+//!
+//! ```bash
+//! # Preview low-confidence auto-captures
+//! subcog prune --auto-captured --confidence-below 0.5 --dry-run
+//!
+//! # Only prune auto-captures older than 30 days, skipping confirmation
+//! subcog prune --auto-captured --confidence-below 0.5 --older-than 30d --force
+//! ```
+
+// Allow print_stdout/stderr in CLI module (consistent with main.rs)
+#![allow(clippy::print_stdout)]
+// Allow pass-by-value for command functions (consistent with main.rs)
+#![allow(clippy::needless_pass_by_value)]
+
+use crate::config::parse_duration_to_seconds;
+use crate::gc::{AutoCapturePruner, PruneCandidate, PruneConfig};
+use crate::services::ServiceContainer;
+use crate::{Error, Result};
+use std::io::{self, Write};
+use std::time::Duration;
+
+/// How the prune command applies its changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PruneMode {
+    /// Ask for confirmation before tombstoning.
+    #[default]
+    Confirm,
+    /// Tombstone without asking (`--force`).
+    Force,
+    /// Only show what would be pruned (`--dry-run`).
+    DryRun,
+}
+
+impl PruneMode {
+    /// Builds the mode from the `--force` and `--dry-run` flags; a dry run
+    /// wins over `--force`.
+    #[must_use]
+    pub const fn from_flags(force: bool, dry_run: bool) -> Self {
+        match (force, dry_run) {
+            (_, true) => Self::DryRun,
+            (true, false) => Self::Force,
+            (false, false) => Self::Confirm,
+        }
+    }
+}
+
+/// Arguments for the `prune` command.
+///
+/// Encapsulates all parameters to avoid function with too many arguments.
+#[derive(Debug, Clone, Default)]
+pub struct PruneArgs {
+    /// Prune hook (auto-captured) memories.
+    pub auto_captured: bool,
+    /// Confidence threshold (0.0-1.0).
+    pub confidence_below: f32,
+    /// Only prune memories older than this duration (e.g. `30d`).
+    pub older_than: Option<String>,
+    /// Also prune manual captures with a recorded confidence.
+    pub include_manual: bool,
+    /// Whether to confirm, force, or only preview the prune.
+    pub mode: PruneMode,
+}
+
+/// Executes the prune command.
+///
+/// # Errors
+///
+/// Returns an error if the arguments are invalid or storage access fails.
+pub fn execute(args: PruneArgs) -> Result<()> {
+    let PruneArgs {
+        auto_captured,
+        confidence_below,
+        older_than,
+        include_manual,
+        mode,
+    } = args;
+    if !auto_captured {
+        return Err(Error::InvalidInput(
+            "subcog prune only removes auto-captured memories; pass --auto-captured".to_string(),
+        ));
+    }
+    if !(0.0..=1.0).contains(&confidence_below) {
+        return Err(Error::InvalidInput(format!(
+            "--confidence-below must be between 0.0 and 1.0 (got {confidence_below})"
+        )));
+    }
+
+    let mut config = PruneConfig::new(confidence_below).with_include_manual(include_manual);
+    if let Some(age) = older_than {
+        let secs = parse_duration_to_seconds(&age)
+            .filter(|secs| *secs > 0)
+            .ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "Invalid --older-than duration: {age} (expected e.g. 30d, 12h)"
+                ))
+            })?;
+        config = config.with_older_than(Duration::from_secs(secs));
+    }
+
+    let container = ServiceContainer::from_current_dir_or_user()?;
+    let pruner = AutoCapturePruner::new(container.index()?, config);
+
+    let preview = pruner.prune(true)?;
+    if preview.candidates.is_empty() {
+        println!("{}", preview.summary());
+        report_skipped(preview.skipped_unknown_confidence);
+        return Ok(());
+    }
+
+    if mode == PruneMode::DryRun {
+        println!(
+            "Dry-run mode: would tombstone {} memories:\n",
+            preview.candidates.len()
+        );
+        print_candidates(&preview.candidates);
+        report_skipped(preview.skipped_unknown_confidence);
+        return Ok(());
+    }
+
+    if mode == PruneMode::Confirm {
+        println!(
+            "About to tombstone {} memories:\n",
+            preview.candidates.len()
+        );
+        print_candidates(&preview.candidates);
+        println!("\nNote: Tombstoned memories can be restored or purged later with `subcog gc`.");
+
+        print!("\nProceed? [y/N] ");
+        io::stdout().flush().map_err(|e| Error::OperationFailed {
+            operation: "flush_stdout".to_string(),
+            cause: e.to_string(),
+        })?;
+
+        let mut input = String::new();
+        io::stdin()
+            .read_line(&mut input)
+            .map_err(|e| Error::OperationFailed {
+                operation: "read_stdin".to_string(),
+                cause: e.to_string(),
+            })?;
+
+        if !input.trim().eq_ignore_ascii_case("y") {
+            println!("Cancelled.");
+            return Ok(());
+        }
+    }
+
+    let result = pruner.prune(false)?;
+    println!("\nTombstoned {} memories.", result.memories_tombstoned);
+    report_skipped(result.skipped_unknown_confidence);
+    println!("\nTo permanently delete, run: subcog gc --purge");
+
+    Ok(())
+}
+
+/// Prints one line per prune candidate.
+fn print_candidates(candidates: &[PruneCandidate]) {
+    for candidate in candidates {
+        println!(
+            "  - {} ({}, {}, confidence {:.2})",
+            candidate.id.as_str(),
+            candidate.namespace,
+            candidate.origin,
+            candidate.confidence
+        );
+    }
+}
+
+/// Reports memories skipped for lack of a recorded confidence.
+fn report_skipped(skipped: usize) {
+    if skipped > 0 {
+        println!("Skipped {skipped} memories with no recorded confidence.");
+    }
+}
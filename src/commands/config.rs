@@ -1,16 +1,34 @@
 //! Config command handler.
 //!
-//! Contains the implementation of the `config` CLI command and
-//! display helpers for configuration output.
+//! Contains the implementation of the `config` CLI command, its `validate`
+//! subcommand, and display helpers for configuration output.
 
-use subcog::config::{StorageBackendType, SubcogConfig};
+use std::path::Path;
+
+use subcog::config::{StorageBackendType, SubcogConfig, set_config_value, validate_config_file};
 
 /// Config command.
+///
+/// With `set` (`KEY=VALUE`), writes the value to the loaded config file (or
+/// the default location) and echoes it; otherwise shows the configuration.
 pub fn cmd_config(
     config: SubcogConfig,
     show: bool,
-    _set: Option<String>,
+    set: Option<String>,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    if let Some(assignment) = set {
+        let path = config
+            .config_sources
+            .last()
+            .cloned()
+            .or_else(SubcogConfig::default_config_path)
+            .ok_or("cannot locate the home directory; pass --config")?;
+        let change = set_config_value(&path, &assignment)?;
+        println!("{change}");
+        println!("Updated {}", path.display());
+        return Ok(());
+    }
+
     if show {
         println!("Current Configuration");
         println!("=====================");
@@ -117,6 +135,69 @@ pub fn cmd_config(
     Ok(())
 }
 
+/// Config validate command.
+///
+/// Validates `path`, else the file chosen by `--config` or
+/// `SUBCOG_CONFIG_PATH` (`config_path`), else `~/.config/subcog/config.toml`.
+/// First lists the config sources in precedence order, then prints `OK` if
+/// the file is clean. Otherwise prints each problem and returns an error, so
+/// the process exits non-zero. A missing default file is not an error, since
+/// the built-in defaults apply.
+pub fn cmd_config_validate(
+    path: Option<&Path>,
+    config_path: Option<&Path>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let path_is_default = path.is_none() && config_path.is_none();
+    let (path, origin) = match (path, config_path) {
+        (Some(path), _) => (path.to_path_buf(), "argument"),
+        (None, Some(path)) => (path.to_path_buf(), "--config or SUBCOG_CONFIG_PATH"),
+        (None, None) => (
+            SubcogConfig::default_config_path()
+                .ok_or("cannot locate the home directory; pass the config path explicitly")?,
+            "default location",
+        ),
+    };
+    let found = path.exists();
+
+    println!("Config sources (highest precedence first):");
+    let overrides = env_overrides();
+    if overrides.is_empty() {
+        println!("  1. environment: no SUBCOG_* variables set");
+    } else {
+        println!("  1. environment: {}", overrides.join(", "));
+    }
+    let status = if found { "" } else { ", not found" };
+    println!("  2. {} ({origin}{status})", path.display());
+    println!("  3. built-in defaults");
+    println!();
+
+    if !found && path_is_default {
+        println!("OK");
+        return Ok(());
+    }
+
+    let problems = validate_config_file(&path)?;
+    if problems.is_empty() {
+        println!("OK");
+        return Ok(());
+    }
+    for problem in &problems {
+        println!("{}: {problem}", path.display());
+    }
+    Err(format!("{} problem(s) found in {}", problems.len(), path.display()).into())
+}
+
+/// Returns the names of the set `SUBCOG_*` variables, which override the
+/// config file.
+fn env_overrides() -> Vec<String> {
+    let mut names: Vec<String> = std::env::vars_os()
+        .filter_map(|(name, _)| name.into_string().ok())
+        .filter(|name| name.starts_with("SUBCOG_") && name != "SUBCOG_CONFIG_PATH")
+        .collect();
+    names.sort();
+    names
+}
+
 /// Helper to display tracing configuration.
 fn display_tracing_config(config: &SubcogConfig) {
     let tracing_enabled = config
@@ -0,0 +1,87 @@
+//! Context command handler.
+//!
+//! Contains the implementation of the `context build` CLI command, which
+//! previews the memory context the hooks would inject without running
+//! Claude Code.
+
+use subcog::config::SubcogConfig;
+use subcog::hooks::{HookHandler, UserPromptHandler};
+use subcog::services::ContextBuilderService;
+
+use super::hook::{session_start_handler, try_init_recall_service, user_prompt_handler};
+
+pub use super::ContextAction;
+
+/// Handles context subcommands.
+///
+/// # Errors
+///
+/// Returns an error if neither `--prompt` nor `--session` is given or the
+/// session context cannot be built.
+pub fn cmd_context(
+    config: &SubcogConfig,
+    action: ContextAction,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match action {
+        ContextAction::Build { prompt, session } => build(config, prompt.as_deref(), session),
+    }
+}
+
+/// Prints the context the `SessionStart` and `UserPromptSubmit` hooks would inject.
+fn build(
+    config: &SubcogConfig,
+    prompt: Option<&str>,
+    session: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if prompt.is_none() && !session {
+        return Err("Specify --prompt <text>, --session, or both".into());
+    }
+
+    if session {
+        let cwd = std::env::current_dir()?;
+        let input = serde_json::json!({
+            "session_id": uuid::Uuid::new_v4().to_string(),
+            "cwd": cwd.to_string_lossy(),
+        });
+        let response = session_start_handler(try_init_recall_service(&config.data_dir))
+            .handle(&input.to_string())?;
+        let response: serde_json::Value = serde_json::from_str(&response)?;
+        let context = response
+            .pointer("/hookSpecificOutput/additionalContext")
+            .and_then(serde_json::Value::as_str)
+            .unwrap_or_default();
+        print_block("SessionStart", context, None);
+    }
+
+    if let Some(prompt) = prompt {
+        let handler = user_prompt_handler(config, try_init_recall_service(&config.data_dir));
+        let context = handler
+            .preview_memory_context(prompt)
+            .map(|ctx| UserPromptHandler::render_memory_context(&ctx))
+            .unwrap_or_default();
+        print_block(
+            "UserPromptSubmit",
+            &context,
+            Some(config.search_intent.max_tokens),
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints one injected block followed by its estimated token count.
+fn print_block(hook: &str, context: &str, budget: Option<usize>) {
+    println!("=== {hook} ===");
+    if context.is_empty() {
+        println!("(nothing would be injected)");
+    } else {
+        println!("{context}");
+    }
+    println!();
+    let tokens = ContextBuilderService::estimate_tokens(context);
+    match budget {
+        Some(budget) => println!("Tokens: ~{tokens} (memory budget {budget})"),
+        None => println!("Tokens: ~{tokens}"),
+    }
+    println!();
+}
@@ -6,8 +6,12 @@
 use std::path::PathBuf;
 
 use subcog::config::{SubcogConfig, parse_duration_to_seconds};
+use subcog::models::{
+    CaptureTemplate, ContentType, GroupBy, MemoryId, MemorySection, SearchHit, SearchLegs, SortBy,
+    group_hits, normalize_language, parse_sections, render_sections, sort_hits,
+};
 use subcog::storage::PersistenceBackend;
-use subcog::{CaptureRequest, Domain, Namespace, SearchFilter, SearchMode};
+use subcog::{CaptureOrigin, CaptureRequest, Domain, Namespace, SearchFilter, SearchMode};
 
 /// Parses namespace string.
 pub fn parse_namespace(s: &str) -> Namespace {
@@ -47,19 +51,44 @@ fn parse_domain(s: Option<&str>) -> Domain {
     }
 }
 
+/// Structure options for the capture command.
+#[derive(Debug, Default)]
+pub struct CaptureStructure {
+    /// Template name (e.g. "adr").
+    pub template: Option<String>,
+    /// Sections as `NAME=TEXT` pairs.
+    pub sections: Vec<String>,
+    /// Whether to compose the memory in `$EDITOR`.
+    pub edit: bool,
+    /// Content type (`markdown`, `text`, `code` or `code:<lang>`); detected if unset.
+    pub content_type: Option<String>,
+}
+
 /// Capture command.
+#[allow(clippy::too_many_arguments)]
 pub fn cmd_capture(
-    _config: &SubcogConfig,
-    content: String,
-    namespace: String,
+    config: &SubcogConfig,
+    content: Option<String>,
+    namespace: Option<String>,
     tags: Option<String>,
     source: Option<String>,
     ttl: Option<String>,
     domain: Option<String>,
+    structure: CaptureStructure,
+    attach: &[PathBuf],
+    split: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let services = subcog::services::ServiceContainer::from_current_dir_or_user()?;
     let service = services.capture();
 
+    let store = services
+        .attachments()
+        .with_max_bytes(config.attachments.max_bytes);
+    let attachments = attach
+        .iter()
+        .map(|path| store.attach_file(path))
+        .collect::<Result<Vec<_>, _>>()?;
+
     let tag_list = tags
         .map(|t| t.split(',').map(|s| s.trim().to_string()).collect())
         .unwrap_or_default();
@@ -70,9 +99,76 @@ pub fn cmd_capture(
     // Parse domain: user, org, project, or auto-detect from context
     let domain = parse_domain(domain.as_deref());
 
+    let template = structure
+        .template
+        .as_deref()
+        .map(|name| CaptureTemplate::parse(name).ok_or_else(|| format!("Unknown template: {name}")))
+        .transpose()?;
+
+    let content_type = structure
+        .content_type
+        .as_deref()
+        .map(|s| ContentType::parse(s).ok_or_else(|| format!("Unknown content type: {s}")))
+        .transpose()?;
+
+    let mut sections = structure
+        .sections
+        .iter()
+        .map(|pair| {
+            pair.split_once('=')
+                .map(|(name, text)| MemorySection::new(name, text))
+                .ok_or_else(|| format!("Invalid section '{pair}' (expected NAME=TEXT)"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut content = content.unwrap_or_default();
+
+    if structure.edit {
+        let initial = if sections.is_empty() {
+            template.map_or_else(|| content.clone(), |t| t.skeleton())
+        } else {
+            render_sections(template, &content, &sections)
+        };
+        let edited = edit_in_editor(&initial)?;
+        let edited_sections = parse_sections(&edited);
+        if edited_sections.is_empty() {
+            content = edited;
+            sections.clear();
+        } else {
+            content = String::new();
+            sections = edited_sections
+                .into_iter()
+                .filter(|s| !s.content.trim().is_empty())
+                .collect();
+        }
+    }
+
+    // Explicit namespaces are authoritative; "auto" (the default in a
+    // terminal) classifies the content
+    let namespace = namespace.unwrap_or_else(|| {
+        use std::io::IsTerminal as _;
+        if std::io::stdin().is_terminal() {
+            "auto"
+        } else {
+            "decisions"
+        }
+        .to_string()
+    });
+    let auto = if namespace.eq_ignore_ascii_case("auto") {
+        let mut text = content.clone();
+        for section in &sections {
+            text.push('\n');
+            text.push_str(&section.content);
+        }
+        Some(auto_namespace(config, &text))
+    } else {
+        None
+    };
+
     let request = CaptureRequest {
         content,
-        namespace: parse_namespace(&namespace),
+        namespace: auto
+            .as_ref()
+            .map_or_else(|| parse_namespace(&namespace), |(ns, _)| *ns),
         domain,
         tags: tag_list,
         source,
@@ -81,13 +177,65 @@ pub fn cmd_capture(
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections,
+        template,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments,
+        content_type,
     };
+    let attached = request.attachments.len();
+    let split = split || config.capture.auto_split;
+
+    // Content already stored in the namespace, by any process, is skipped;
+    // split parts are stored separately and not checked
+    if !split && let Ok(dedup) = services.deduplicator() {
+        let check = dedup.check(&request.rendered_content(), request.namespace)?;
+        if let (true, Some(urn), Some(reason)) =
+            (check.is_duplicate, check.matched_urn, check.reason)
+        {
+            println!("Not captured: duplicate of {urn} ({reason})");
+            println!("  Set SUBCOG_DEDUP_ENABLED=false to capture it anyway");
+            return Ok(());
+        }
+    }
 
-    let result = service.capture(request)?;
+    let results = if split {
+        // With "auto", every part is classified on its own
+        service.capture_split(request, config.capture.split_threshold_chars, |text| {
+            auto.is_some().then(|| auto_namespace(config, text).0)
+        })?
+    } else {
+        vec![(request.namespace, service.capture(request)?)]
+    };
+    let [(_, result)] = results.as_slice() else {
+        println!("Captured {} memories (split):", results.len());
+        for (namespace, result) in &results {
+            println!("  {} ({})", result.urn, namespace.as_str());
+        }
+        if results.iter().any(|(_, result)| result.content_modified) {
+            println!("  Note: Content was redacted for security");
+        }
+        return Ok(());
+    };
 
     println!("Memory captured:");
     println!("  ID: {}", result.memory_id.as_str());
     println!("  URN: {}", result.urn);
+    match auto {
+        Some((namespace, Some(confidence))) => println!(
+            "  Namespace: {} (auto, confidence {confidence:.2})",
+            namespace.as_str()
+        ),
+        Some((namespace, None)) => println!(
+            "  Namespace: {} (auto, no confident suggestion)",
+            namespace.as_str()
+        ),
+        None => {},
+    }
+    if attached > 0 {
+        println!("  Attachments: {attached}");
+    }
     if result.content_modified {
         println!("  Note: Content was redacted for security");
     }
@@ -95,29 +243,379 @@ pub fn cmd_capture(
     Ok(())
 }
 
+/// Classifies text for `capture --namespace auto`.
+///
+/// Uses the same analyzers as `suggest-namespace` and falls back to
+/// `decisions` without a confident suggestion. Returns the namespace and the
+/// confidence of the suggestion, if any.
+fn auto_namespace(config: &SubcogConfig, text: &str) -> (Namespace, Option<f32>) {
+    use subcog::cli::build_llm_provider;
+    use subcog::hooks::PreCompactHandler;
+
+    // LLM classification is used only when [auto_capture] use_llm is enabled
+    let mut handler = PreCompactHandler::new().with_config(config.auto_capture.clone());
+    if config.auto_capture.use_llm
+        && let Some(llm) = build_llm_provider(config)
+    {
+        handler = handler.with_llm(llm);
+    }
+    handler
+        .auto_namespace(text)
+        .map_or((Namespace::Decisions, None), |suggestion| {
+            (suggestion.namespace, Some(suggestion.confidence))
+        })
+}
+
+/// Opens `$EDITOR` (or `$VISUAL`, falling back to `vi`) on the given text.
+fn edit_in_editor(initial: &str) -> Result<String, Box<dyn std::error::Error>> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let path = std::env::temp_dir().join(format!("subcog-capture-{}.md", uuid::Uuid::new_v4()));
+    std::fs::write(&path, initial)?;
+
+    let status = std::process::Command::new(&editor).arg(&path).status();
+    let edited = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+
+    if !status?.success() {
+        return Err(format!("Editor '{editor}' exited with an error").into());
+    }
+    Ok(edited?)
+}
+
+/// Get command: shows a single memory, rendering structured sections.
+pub fn cmd_get(id: &str, format: &str) -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::IsTerminal as _;
+    use subcog::models::{MemoryId, parse_template};
+    use subcog::rendering::highlight_code;
+    use subcog::services::ServiceContainer;
+
+    let services = ServiceContainer::from_current_dir_or_user()?;
+    let index = services.index()?;
+    let memory = index
+        .get_memory(&MemoryId::new(id))?
+        .ok_or_else(|| format!("Memory not found: {id}"))?;
+    let sections = parse_sections(&memory.content);
+    let store = services.attachments();
+
+    if format.eq_ignore_ascii_case("json") {
+        let mut value = serde_json::json!({
+            "id": memory.id.as_str(),
+            "namespace": memory.namespace.as_str(),
+            "domain": memory.domain.to_string(),
+            "status": memory.status.as_str(),
+            "origin": memory.origin.as_str(),
+            "confidence": memory.confidence,
+            "tags": memory.tags,
+            "source": memory.source,
+            "created_at": memory.created_at,
+            "updated_at": memory.updated_at,
+            "content_type": memory.content_type.as_ref().map(ToString::to_string),
+            "pinned": memory.pinned,
+            "commit": memory.commit,
+            "language": memory.language,
+            "content": memory.content,
+        });
+        if !memory.attachments.is_empty() {
+            value["attachments"] = memory
+                .attachments
+                .iter()
+                .map(|a| {
+                    serde_json::json!({
+                        "name": a.name,
+                        "media_type": a.media_type,
+                        "size": a.size,
+                        "hash": a.hash,
+                        "path": store.blob_path(&a.hash).ok(),
+                    })
+                })
+                .collect();
+        }
+        if !sections.is_empty() {
+            value["sections"] = sections
+                .iter()
+                .map(|s| (s.name.clone(), serde_json::Value::String(s.content.clone())))
+                .collect::<serde_json::Map<_, _>>()
+                .into();
+        }
+        println!("{}", serde_json::to_string_pretty(&value)?);
+        return Ok(());
+    }
+
+    println!("ID: {}", memory.id.as_str());
+    println!("Namespace: {}", memory.namespace);
+    println!("Domain: {}", memory.domain);
+    println!("Status: {}", memory.status.as_str());
+    println!("Created: {}", format_timestamp(memory.created_at));
+    if memory.updated_at != memory.created_at {
+        println!("Updated: {}", format_timestamp(memory.updated_at));
+    }
+    if memory.pinned {
+        println!("Pinned: yes");
+    }
+    println!("Origin: {}", memory.origin);
+    if let Some(confidence) = memory.confidence {
+        println!("Confidence: {confidence:.2}");
+    }
+    if !memory.tags.is_empty() {
+        println!("Tags: {}", memory.tags.join(", "));
+    }
+    if let Some(ref source) = memory.source {
+        println!("Source: {source}");
+    }
+    if let Some(ref commit) = memory.commit {
+        println!("Commit: {commit}");
+    }
+    if let Some(ref language) = memory.language {
+        println!("Language: {language}");
+    }
+    if let Some(ref content_type) = memory.content_type {
+        println!("Content type: {content_type}");
+    }
+    if let Some(template) = parse_template(&memory.content) {
+        println!("Template: {template}");
+    }
+    if !memory.attachments.is_empty() {
+        println!("Attachments:");
+        for attachment in &memory.attachments {
+            let location = store.blob_path(&attachment.hash).map_or_else(
+                |_| "invalid hash".to_string(),
+                |path| {
+                    if path.is_file() {
+                        path.display().to_string()
+                    } else {
+                        format!("missing: {}", path.display())
+                    }
+                },
+            );
+            println!(
+                "  {} ({}, {} bytes) {location}",
+                attachment.name, attachment.media_type, attachment.size
+            );
+        }
+    }
+    println!();
+
+    if let Some(ref content_type) = memory.content_type
+        && content_type.is_code()
+        && std::io::stdout().is_terminal()
+    {
+        let code = ContentType::code_body(&memory.content);
+        println!("{}", highlight_code(code, content_type.lang()));
+    } else if sections.is_empty() {
+        println!("{}", memory.content);
+    } else {
+        for section in &sections {
+            println!("{}", section.title());
+            println!("{}", "-".repeat(section.title().len()));
+            println!("{}", section.content);
+            println!();
+        }
+    }
+
+    Ok(())
+}
+
+/// Formats a Unix timestamp as a UTC datetime string.
+fn format_timestamp(ts: u64) -> String {
+    chrono::DateTime::from_timestamp(i64::try_from(ts).unwrap_or(0), 0).map_or_else(
+        || "unknown".to_string(),
+        |dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+    )
+}
+
 /// Recall command.
 ///
 /// # Arguments
 ///
+/// * `config` - Loaded configuration (LLM and rerank settings)
 /// * `query` - The search query
 /// * `mode` - Search mode: text, vector, or hybrid
+/// * `alpha` - Optional hybrid ranking weight (0.0 keyword only, 1.0 semantic only)
+/// * `vector_only_fallback` - If true, hybrid search serves vector hits alone when text finds none
 /// * `namespace` - Optional namespace filter
 /// * `limit` - Maximum number of results
+/// * `offset` - Number of ranked results to skip, for paging
 /// * `raw` - If true, display raw (un-normalized) scores instead of normalized scores
+/// * `origin` - Optional capture origin filter (manual, hook, import, consolidation)
+/// * `min_confidence` - Optional minimum recorded capture confidence
+/// * `at_commit` - Optional commit SHA (or prefix) the memories were captured at
+/// * `lang` - Optional ISO 639-1 code of the content language
+/// * `explain` - If true, print score components, origin, and confidence per hit
+/// * `output_template` - Optional per-hit template (e.g. `{{id}}\t{{content_preview}}`)
+/// * `quiet` - If true, omit the result header and timing summary
+/// * `all_scopes` - If true, search project, user, and org scopes together
+/// * `rerank` - If true, reorder the top candidates by LLM-judged relevance
+/// * `feedback` - If true, ask which hits were useful and record the votes
+/// * `diverse` - If true, re-rank the hits for diversity (MMR)
+/// * `expand` - If true, expand the query with LLM-suggested related terms
+/// * `group_by` - Bucket the hits by namespace, tag, or source (`none` keeps a flat list)
+/// * `format` - Output format: text, or jsonl (one JSON object per hit on stdout)
+/// * `json_path` - Optional JSONPath selecting the values to print from the JSON results
+/// * `namespace_counts` - If true, print only the match counts per namespace, as JSON
+/// * `tag_counts` - With `namespace_counts`, also count the matches per tag
+/// * `min_results` - Optional hit count to reach by widening the query
+/// * `sort` - Result order: relevance, created, or updated (newest first)
+/// * `updated_since` - Optional duration (e.g. `7d`); only memories edited within it match
+/// * `since` - Optional lower bound on capture time (duration ago, date, or timestamp)
+/// * `until` - Optional upper bound on capture time (duration ago, date, or timestamp)
+/// * `within` - Optional memory ID; only its relationship neighborhood is searched
+/// * `within_depth` - Number of relationship hops the `within` neighborhood spans
+/// * `template_context` - If true, print the hits as one context block for a prompt
+/// * `template` - Optional context template for the block (default: numbered list)
+/// * `max_tokens` - Optional token budget the block is trimmed to fit
+#[allow(clippy::too_many_arguments)]
 pub fn cmd_recall(
+    config: &SubcogConfig,
     query: String,
     mode: String,
+    alpha: Option<f32>,
+    vector_only_fallback: bool,
     namespace: Option<String>,
     limit: usize,
+    offset: usize,
     raw: bool,
     include_tombstoned: bool,
     entity: Option<String>,
+    origin: Option<String>,
+    min_confidence: Option<f32>,
+    at_commit: Option<String>,
+    lang: Option<String>,
+    explain: bool,
+    output_template: Option<String>,
+    quiet: bool,
+    all_scopes: bool,
+    rerank: bool,
+    feedback: bool,
+    diverse: bool,
+    expand: bool,
+    group_by: &str,
+    format: &str,
+    json_path: Option<String>,
+    namespace_counts: bool,
+    tag_counts: bool,
+    min_results: Option<usize>,
+    sort: &str,
+    updated_since: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    within: Option<String>,
+    within_depth: usize,
+    template_context: bool,
+    template: Option<String>,
+    max_tokens: Option<usize>,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    use subcog::services::ServiceContainer;
+    use std::sync::Arc;
+    use subcog::cli::build_llm_provider;
+    use subcog::rendering::{JsonPath, OutputTemplate, hits_to_json, write_jsonl};
+    use subcog::services::{
+        ContextTemplateService, DIVERSITY_CANDIDATE_FACTOR, DiversityService,
+        QueryExpansionService, RerankService, ServiceContainer, WideningService,
+    };
+
+    // Validate the template before searching so typos fail fast
+    let output_template = output_template
+        .map(|t| OutputTemplate::parse(unescape_template(&t)))
+        .transpose()?;
+    let group_by = GroupBy::parse(group_by).ok_or_else(|| {
+        format!("Unknown grouping: {group_by} (expected namespace, tag, source, or none)")
+    })?;
+    let sort = SortBy::parse(sort)
+        .ok_or_else(|| format!("Unknown sort: {sort} (expected relevance, created, or updated)"))?;
+    let updated_after = updated_since
+        .map(|since| {
+            parse_duration_to_seconds(&since)
+                .filter(|&secs| secs > 0)
+                .map(|secs| subcog::current_timestamp().saturating_sub(secs))
+                .ok_or_else(|| format!("Invalid --updated-since: {since} (expected e.g. 12h, 7d)"))
+        })
+        .transpose()?;
+    let created_after = since
+        .map(|s| parse_created_bound("--since", &s))
+        .transpose()?;
+    let created_before = until
+        .map(|s| parse_created_bound("--until", &s))
+        .transpose()?;
+    let jsonl = match format.to_lowercase().as_str() {
+        "text" => false,
+        "jsonl" | "ndjson" => true,
+        _ => return Err(format!("Unknown format: {format} (expected text or jsonl)").into()),
+    };
+    if jsonl && (output_template.is_some() || group_by != GroupBy::None || feedback) {
+        return Err("--format jsonl cannot be combined with --output-template, \
+                    --group-by, or --feedback"
+            .into());
+    }
+    // Validate the path before searching so typos fail fast
+    let json_path = json_path.as_deref().map(JsonPath::parse).transpose()?;
+    if json_path.is_some()
+        && (jsonl || output_template.is_some() || group_by != GroupBy::None || feedback)
+    {
+        return Err("--json-path cannot be combined with --format jsonl, \
+                    --output-template, --group-by, or --feedback"
+            .into());
+    }
+    if namespace_counts
+        && (jsonl
+            || json_path.is_some()
+            || output_template.is_some()
+            || group_by != GroupBy::None
+            || feedback
+            || rerank
+            || diverse
+            || all_scopes
+            || min_results.is_some())
+    {
+        return Err(
+            "--namespace-counts cannot be combined with --format jsonl, --json-path, \
+                    --output-template, --group-by, --feedback, --rerank, --diverse, \
+                    --all-scopes, or --min-results"
+                .into(),
+        );
+    }
+    if template_context
+        && (jsonl
+            || json_path.is_some()
+            || output_template.is_some()
+            || group_by != GroupBy::None
+            || feedback
+            || namespace_counts)
+    {
+        return Err(
+            "--template-context cannot be combined with --format jsonl, --json-path, \
+                    --output-template, --group-by, --feedback, or --namespace-counts"
+                .into(),
+        );
+    }
+    if let Some(min_results) = min_results
+        && min_results > limit
+    {
+        return Err(
+            format!("--min-results ({min_results}) cannot exceed --limit ({limit})").into(),
+        );
+    }
+    let origin = origin
+        .map(|o| {
+            CaptureOrigin::parse(&o).ok_or_else(|| {
+                format!("Unknown origin: {o} (expected manual, hook, import, or consolidation)")
+            })
+        })
+        .transpose()?;
+    if let Some(ref sha) = at_commit
+        && (sha.len() < 4 || sha.len() > 40 || !sha.chars().all(|c| c.is_ascii_hexdigit()))
+    {
+        return Err(format!("Invalid commit: {sha} (expected 4-40 hex characters)").into());
+    }
+    let language = lang
+        .map(|l| {
+            normalize_language(&l)
+                .ok_or_else(|| format!("Invalid language: {l} (expected a code such as es)"))
+        })
+        .transpose()?;
 
     // Use domain-scoped index (user-level storage with project facets)
     let services = ServiceContainer::from_current_dir_or_user()?;
-    let service = services.recall()?;
 
     let mut filter = SearchFilter::new();
     if let Some(ns) = namespace {
@@ -126,6 +624,35 @@ pub fn cmd_recall(
     if include_tombstoned {
         filter = filter.with_include_tombstoned(true);
     }
+    if let Some(origin) = origin {
+        filter = filter.with_origin(origin);
+    }
+    if let Some(min_confidence) = min_confidence {
+        filter = filter.with_min_confidence(min_confidence);
+    }
+    if let Some(sha) = at_commit {
+        filter = filter.with_commit(sha.to_lowercase());
+    }
+    if let Some(language) = language {
+        filter = filter.with_language(language);
+    }
+    if let Some(after) = updated_after {
+        filter = filter.with_updated_after(after);
+    }
+    if let Some(after) = created_after {
+        filter = filter.with_created_after(after);
+    }
+    if let Some(before) = created_before {
+        filter = filter.with_created_before(before);
+    }
+    filter.validate_created_range()?;
+    if offset > 0 {
+        filter = filter.with_offset(offset);
+    }
+    if let Some(alpha) = alpha {
+        filter = filter.with_hybrid_alpha(alpha);
+    }
+    filter = filter.with_vector_only_fallback(vector_only_fallback);
     // Apply entity filter if provided (comma-separated for OR logic)
     if let Some(ref entity_arg) = entity {
         let entities: Vec<String> = entity_arg
@@ -136,38 +663,243 @@ pub fn cmd_recall(
             .collect();
         filter = filter.with_entities(entities);
     }
+    // Confine the search to the memories related to the given one
+    if let Some(id) = within {
+        let members = services
+            .recall()?
+            .neighborhood(&MemoryId::new(id), within_depth)?;
+        filter = filter.with_memory_ids(members);
+    }
 
-    let result = service.search(&query, parse_search_mode(&mode), &filter, limit);
+    // Reranking needs an LLM; without one the retrieval order is kept
+    let reranker = if rerank {
+        let llm = build_llm_provider(config);
+        if llm.is_none() {
+            eprintln!("Warning: no LLM provider available, results are not reranked");
+        }
+        llm.map(RerankService::new)
+    } else {
+        None
+    };
+    // Expansion needs an LLM; without one the raw query is searched
+    let expansion = if expand || config.search.expand {
+        let llm = build_llm_provider(config);
+        if llm.is_none() && expand {
+            eprintln!("Warning: no LLM provider available, the query is not expanded");
+        }
+        llm.map(|llm| {
+            Arc::new(
+                QueryExpansionService::new(llm).with_timeout_ms(config.search.expand_timeout_ms),
+            )
+        })
+    } else {
+        None
+    };
+    // Diversity picks the results from a larger pool of candidates
+    let diversifier = diverse.then(|| {
+        let diversifier = DiversityService::new(config.search.diversity_lambda);
+        match services.embedder() {
+            Some(embedder) => diversifier.with_embedder(embedder),
+            None => diversifier,
+        }
+    });
+    let pool_limit = if diversifier.is_some() {
+        limit.saturating_mul(DIVERSITY_CANDIDATE_FACTOR)
+    } else {
+        limit
+    };
+    let fetch_limit = if reranker.is_some() {
+        pool_limit.max(config.search.rerank_candidates)
+    } else {
+        pool_limit
+    };
 
-    match result {
-        Ok(search_result) => {
-            println!("Found {} memories:", search_result.total_count);
-            println!();
+    let mode = parse_search_mode(&mode);
+    if namespace_counts {
+        let mut recall = services.recall()?;
+        if let Some(ref expansion) = expansion {
+            recall = recall.with_query_expansion(Arc::clone(expansion));
+        }
+        let facets = recall.facets(&query, mode, &filter, limit, tag_counts)?;
+        println!("{}", serde_json::to_string_pretty(&facets)?);
+        if !quiet {
+            eprintln!("Counted {} memories", facets.total_count);
+        }
+        return Ok(());
+    }
+    let mut recall = if all_scopes {
+        services.recall_all_scopes()?
+    } else {
+        services.recall()?
+    };
+    if let Some(ref expansion) = expansion {
+        recall = recall.with_query_expansion(Arc::clone(expansion));
+    }
+    let search = |mode: SearchMode, filter: &SearchFilter| {
+        if all_scopes {
+            recall
+                .search_all_scopes(&query, mode, filter, fetch_limit)
+                .map(|r| {
+                    let hits = r.memories.into_iter().map(|scoped| scoped.hit);
+                    let hits = hits.collect::<Vec<_>>();
+                    let legs = SearchLegs::default();
+                    (hits, r.total_count, r.execution_time_ms, r.has_more, legs)
+                })
+        } else {
+            recall.search(&query, mode, filter, fetch_limit).map(|r| {
+                (
+                    r.memories,
+                    r.total_count,
+                    r.execution_time_ms,
+                    r.has_more,
+                    r.legs,
+                )
+            })
+        }
+    };
+    // Widening re-runs the search with relaxed constraints until enough hits
+    let result = match min_results {
+        Some(min_results) => WideningService::new(min_results)
+            .widen(mode, &filter, search, |(hits, ..)| hits.len())
+            .map(|widened| {
+                if !widened.relaxations.is_empty() {
+                    let applied: Vec<_> = widened
+                        .relaxations
+                        .iter()
+                        .map(ToString::to_string)
+                        .collect();
+                    eprintln!("Widened the query: {}", applied.join(", "));
+                }
+                widened.result
+            }),
+        None => search(mode, &filter),
+    };
+    let result = result.map(
+        |(mut hits, mut total_count, execution_time_ms, has_more, legs)| {
+            if reranker.is_some() || diversifier.is_some() {
+                if let Some(ref reranker) = reranker {
+                    hits = reranker.rerank(&query, hits, pool_limit);
+                }
+                if let Some(ref diversifier) = diversifier {
+                    hits = diversifier.diversify(hits, limit);
+                }
+                total_count = hits.len();
+            }
+            sort_hits(&mut hits, sort);
+            (hits, total_count, execution_time_ms, has_more, legs)
+        },
+    );
+    // Which search legs found the candidates, for --explain
+    let legs_summary = |legs: &SearchLegs| {
+        legs.summary()
+            .filter(|_| explain)
+            .map(|summary| format!("Search legs: {summary}"))
+    };
+    // JSON output reports the legs as one object on stderr
+    let legs_json = |legs: &SearchLegs| {
+        (explain && legs.summary().is_some())
+            .then(|| serde_json::json!({ "search_legs": legs }).to_string())
+    };
+    // Paging hint for the next window of ranked results
+    let more_hint = |hits: &[SearchHit]| {
+        format!(
+            "More results available: use --offset {}",
+            offset.saturating_add(hits.len())
+        )
+    };
 
-            for hit in &search_result.memories {
-                // Use raw_score if --raw flag is set, otherwise use normalized score
-                let display_score = if raw { hit.raw_score } else { hit.score };
-                println!(
-                    "  [{:.4}] {} ({})",
-                    display_score,
-                    hit.memory.id.as_str(),
-                    hit.memory.namespace
-                );
-                // Truncate content for display
-                let content = if hit.memory.content.len() > 100 {
-                    format!("{}...", &hit.memory.content[..100])
-                } else {
-                    hit.memory.content.clone()
-                };
-                println!("       {content}");
+    match result {
+        Ok((hits, total_count, execution_time_ms, has_more, legs)) if json_path.is_some() => {
+            // Keep stdout to the matched values; the summary goes to stderr
+            if !quiet {
+                eprintln!("Found {total_count} memories");
+            }
+            if let Some(legs) = legs_json(&legs) {
+                eprintln!("{legs}");
+            }
+            let results = hits_to_json(&hits, limit);
+            for value in json_path.iter().flat_map(|path| path.select(&results)) {
+                match value {
+                    serde_json::Value::String(text) => println!("{text}"),
+                    other => println!("{other}"),
+                }
+            }
+            if !quiet {
+                if has_more {
+                    eprintln!("{}", more_hint(&hits));
+                }
+                eprintln!("Search completed in {execution_time_ms}ms");
+            }
+        },
+        Ok((hits, total_count, execution_time_ms, has_more, legs)) if template_context => {
+            // Keep stdout to the block itself; the summary goes to stderr
+            if !quiet {
+                eprintln!("Found {total_count} memories");
+            }
+            if let Some(legs) = legs_json(&legs) {
+                eprintln!("{legs}");
+            }
+            let memories: Vec<_> = hits.iter().take(limit).map(|h| h.memory.clone()).collect();
+            let mut templates = ContextTemplateService::with_config(config.clone());
+            let block =
+                templates.render_recall_context(template.as_deref(), &memories, max_tokens)?;
+            println!("{}", block.trim_end());
+            if !quiet {
+                if has_more {
+                    eprintln!("{}", more_hint(&hits));
+                }
+                eprintln!("Search completed in {execution_time_ms}ms");
+            }
+        },
+        Ok((hits, total_count, execution_time_ms, has_more, legs)) if jsonl => {
+            // Keep stdout pure JSONL; the summary goes to stderr
+            if !quiet {
+                eprintln!("Found {total_count} memories");
+            }
+            if let Some(legs) = legs_json(&legs) {
+                eprintln!("{legs}");
+            }
+            write_jsonl(&mut std::io::stdout().lock(), &hits, limit)?;
+            if !quiet {
+                if has_more {
+                    eprintln!("{}", more_hint(&hits));
+                }
+                eprintln!("Search completed in {execution_time_ms}ms");
+            }
+        },
+        Ok((hits, total_count, execution_time_ms, has_more, legs)) => {
+            if !quiet {
+                println!("Found {total_count} memories:");
+                if let Some(summary) = legs_summary(&legs) {
+                    println!("{summary}");
+                }
                 println!();
             }
 
-            let score_type = if raw { " (raw)" } else { "" };
-            println!(
-                "Search completed in {}ms{}",
-                search_result.execution_time_ms, score_type
-            );
+            for group in group_hits(&hits, group_by) {
+                if group_by != GroupBy::None && !quiet {
+                    println!("{} ({})", group.key, group.hits.len());
+                }
+                for hit in group.hits {
+                    if let Some(ref template) = output_template {
+                        println!("{}", template.render(hit)?);
+                        continue;
+                    }
+                    print_recall_hit(hit, &query, raw, explain);
+                }
+            }
+
+            if !quiet {
+                if has_more {
+                    println!("{}", more_hint(&hits));
+                }
+                let score_type = if raw { " (raw)" } else { "" };
+                println!("Search completed in {execution_time_ms}ms{score_type}");
+            }
+
+            if feedback && !hits.is_empty() {
+                collect_feedback(&services, &query, &hits)?;
+            }
         },
         Err(e) => {
             eprintln!("Search failed: {e}");
@@ -178,8 +910,210 @@ pub fn cmd_recall(
     Ok(())
 }
 
+/// Asks which recall hits were useful and records the votes (`recall --feedback`).
+fn collect_feedback(
+    services: &subcog::services::ServiceContainer,
+    query: &str,
+    hits: &[SearchHit],
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::{BufRead as _, Write as _};
+
+    eprintln!("Which results were useful?");
+    for (n, hit) in hits.iter().enumerate() {
+        eprintln!("  {}. {}", n + 1, hit.memory.id.as_str());
+    }
+    eprint!("Numbers of useful results, -N for not useful (e.g. \"1 3 -2\"), empty to skip: ");
+    std::io::stderr().flush()?;
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+
+    let votes = parse_feedback_votes(&line, hits.len())?;
+    if votes.is_empty() {
+        return Ok(());
+    }
+    let recall = services.recall()?;
+    let mut recorded = 0usize;
+    for (position, useful) in votes {
+        let id = &hits[position].memory.id;
+        match recall.record_feedback(query, id, useful) {
+            Ok(()) => recorded += 1,
+            Err(e) => eprintln!("Feedback for {} not recorded: {e}", id.as_str()),
+        }
+    }
+    println!("Recorded {recorded} feedback vote(s)");
+    Ok(())
+}
+
+/// Parses feedback votes like `1 3 -2` into zero-based hit positions.
+///
+/// A plain number marks a hit useful, a `-` prefix marks it not useful.
+fn parse_feedback_votes(line: &str, count: usize) -> Result<Vec<(usize, bool)>, String> {
+    line.split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|token| !token.is_empty())
+        .map(|token| {
+            let (number, useful) = token
+                .strip_prefix('-')
+                .map_or((token.trim_start_matches('+'), true), |n| (n, false));
+            match number.parse::<usize>() {
+                Ok(n) if (1..=count).contains(&n) => Ok((n - 1, useful)),
+                _ => Err(format!(
+                    "Invalid result number: {token} (expected 1-{count})"
+                )),
+            }
+        })
+        .collect()
+}
+
+/// Prints one recall hit with the scope and domain it came from.
+fn print_recall_hit(hit: &SearchHit, query: &str, raw: bool, explain: bool) {
+    // Use raw_score if --raw flag is set, otherwise use normalized score
+    let display_score = if raw { hit.raw_score } else { hit.score };
+    println!(
+        "  [{:.4}] {} ({}) [scope: {}, domain: {}]{}",
+        display_score,
+        hit.memory.id.as_str(),
+        hit.memory.namespace,
+        hit.scope.as_str(),
+        hit.domain,
+        if hit.memory.pinned { " [pinned]" } else { "" }
+    );
+    // Code shows its first lines; prose shows the match snippet, or is
+    // truncated for display
+    if hit
+        .memory
+        .content_type
+        .as_ref()
+        .is_some_and(ContentType::is_code)
+    {
+        let code = ContentType::code_body(&hit.memory.content);
+        for line in code.lines().filter(|l| !l.trim().is_empty()).take(3) {
+            println!("       | {line}");
+        }
+    } else if let Some(ref snippet) = hit.snippet {
+        println!("       {snippet}");
+    } else {
+        let content = match hit.memory.content.char_indices().nth(100) {
+            Some((end, _)) => format!("{}...", &hit.memory.content[..end]),
+            None => hit.memory.content.clone(),
+        };
+        println!("       {content}");
+    }
+    if explain {
+        println!("       {}", explain_hit(hit, query));
+    }
+    println!();
+}
+
+/// Describes how a hit was scored and captured, for `recall --explain`.
+fn explain_hit(hit: &SearchHit, query: &str) -> String {
+    let optional =
+        |value: Option<f32>| value.map_or_else(|| "-".to_string(), |v| format!("{v:.4}"));
+    let matched = hit.matched_fields(query);
+    format!(
+        "score {:.4} (raw {:.4}, vector {}, bm25 {}), matched {}, origin {}, confidence {}",
+        hit.score,
+        hit.raw_score,
+        optional(hit.vector_score),
+        optional(hit.bm25_score),
+        if matched.is_empty() {
+            "-".to_string()
+        } else {
+            matched.join("+")
+        },
+        hit.memory.origin,
+        hit.memory
+            .confidence
+            .map_or_else(|| "-".to_string(), |c| format!("{c:.2}"))
+    )
+}
+
+/// Expands `\t` and `\n` escapes so shell-quoted templates can use them.
+fn unescape_template(template: &str) -> String {
+    template.replace("\\t", "\t").replace("\\n", "\n")
+}
+
+/// Parses a `--since`/`--until` value into a Unix timestamp.
+fn parse_created_bound(flag: &str, value: &str) -> Result<u64, String> {
+    subcog::services::parse_time_bound(value).ok_or_else(|| {
+        format!("Invalid {flag}: {value} (expected e.g. 7d, 2024-01-01, or 2024-01-01T12:00:00Z)")
+    })
+}
+
+/// Suggest-namespace command: recommends namespaces for text without capturing.
+///
+/// # Arguments
+///
+/// * `config` - Loaded configuration (used to build the optional LLM provider)
+/// * `text` - Text to classify, or `-` to read from stdin
+/// * `limit` - Maximum number of suggestions to print
+/// * `format` - Output format: text or json
+pub fn cmd_suggest_namespace(
+    config: &SubcogConfig,
+    text: &str,
+    limit: usize,
+    format: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use std::io::Read as _;
+    use subcog::cli::build_llm_provider;
+    use subcog::hooks::PreCompactHandler;
+
+    let text = if text == "-" {
+        let mut input = String::new();
+        std::io::stdin().read_to_string(&mut input)?;
+        input
+    } else {
+        text.to_string()
+    };
+    if text.trim().is_empty() {
+        return Err("Text cannot be empty".into());
+    }
+
+    // LLM classification is used only when [auto_capture] use_llm is enabled
+    let mut handler = PreCompactHandler::new().with_config(config.auto_capture.clone());
+    if let Some(llm) = build_llm_provider(config) {
+        handler = handler.with_llm(llm);
+    }
+    let mut suggestions = handler.suggest_namespaces(&text);
+    suggestions.truncate(limit);
+
+    if format.eq_ignore_ascii_case("json") {
+        let value: Vec<_> = suggestions
+            .iter()
+            .map(|s| {
+                serde_json::json!({
+                    "namespace": s.namespace.as_str(),
+                    "confidence": s.confidence,
+                })
+            })
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&value)?);
+        return Ok(());
+    }
+
+    if suggestions.is_empty() {
+        eprintln!("No namespace suggestion for this text");
+    }
+    for suggestion in &suggestions {
+        println!(
+            "{}\t{:.2}",
+            suggestion.namespace.as_str(),
+            suggestion.confidence
+        );
+    }
+
+    Ok(())
+}
+
 /// Status command.
-pub fn cmd_status(config: &SubcogConfig) -> Result<(), Box<dyn std::error::Error>> {
+///
+/// With `json`, prints the component health report for monitors instead.
+pub fn cmd_status(config: &SubcogConfig, json: bool) -> Result<(), Box<dyn std::error::Error>> {
+    if json {
+        let report = subcog::services::StatusService::from_config(config).report();
+        println!("{}", serde_json::to_string_pretty(&report)?);
+        return Ok(());
+    }
+
     println!("Subcog Status");
     println!("=============");
     println!();
@@ -223,6 +1157,17 @@ pub fn cmd_status(config: &SubcogConfig) -> Result<(), Box<dyn std::error::Error
     };
     println!("Vector Index: {usearch_status}");
 
+    // Check embedding cache
+    let cache_path =
+        subcog::services::PathManager::for_user(&config.data_dir).embedding_cache_path();
+    let cache_status = if cache_path.exists() {
+        subcog::embedding::ContentEmbeddingCache::summarize(&cache_path)
+            .map_or_else(|e| format!("Unreadable ({e})"), |stats| stats.to_string())
+    } else {
+        "Empty".to_string()
+    };
+    println!("Embedding Cache: {cache_status}");
+
     println!();
     println!("Use 'subcog config --show' to view full configuration");
 
@@ -230,6 +1175,9 @@ pub fn cmd_status(config: &SubcogConfig) -> Result<(), Box<dyn std::error::Error
 }
 
 /// Consolidate command.
+///
+/// With `dry_run`, prints the planned clusters and their proposed summaries
+/// (as JSON when `output` is `json`) without writing anything.
 #[allow(clippy::too_many_arguments, clippy::too_many_lines)]
 pub fn cmd_consolidate(
     config: &SubcogConfig,
@@ -238,12 +1186,13 @@ pub fn cmd_consolidate(
     dry_run: bool,
     min_memories: Option<usize>,
     similarity: Option<f32>,
+    output: &str,
 ) -> Result<(), Box<dyn std::error::Error>> {
     use std::str::FromStr;
     use std::sync::Arc;
     use subcog::cli::{
-        build_anthropic_client, build_lmstudio_client, build_ollama_client, build_openai_client,
-        build_resilience_config,
+        build_anthropic_client, build_gemini_client, build_lmstudio_client, build_ollama_client,
+        build_openai_client, build_resilience_config,
     };
     use subcog::config::{LlmProvider, StorageBackendType};
     use subcog::llm::ResilientLlmProvider;
@@ -252,8 +1201,19 @@ pub fn cmd_consolidate(
     use subcog::storage::index::{PostgresBackend, SqliteBackend};
     use subcog::storage::persistence::FilesystemBackend;
 
-    println!("Running memory consolidation...");
-    println!();
+    let json = match output.to_lowercase().as_str() {
+        "text" => false,
+        "json" => true,
+        _ => return Err(format!("Unknown output: {output} (expected text or json)").into()),
+    };
+    if json && !dry_run {
+        return Err("--output json requires --dry-run".into());
+    }
+
+    if !json {
+        println!("Running memory consolidation...");
+        println!();
+    }
 
     // Check if consolidation is enabled
     if !config.consolidation.enabled {
@@ -301,7 +1261,7 @@ pub fn cmd_consolidate(
     }
 
     // Handle dry-run mode
-    if dry_run {
+    if dry_run && !json {
         println!("DRY RUN MODE - No changes will be made");
         println!();
     }
@@ -341,6 +1301,13 @@ pub fn cmd_consolidate(
                     resilience_config,
                 )))
             },
+            LlmProvider::Gemini => {
+                let client = build_gemini_client(llm_config);
+                Some(Arc::new(ResilientLlmProvider::new(
+                    client,
+                    resilience_config,
+                )))
+            },
             LlmProvider::Ollama => {
                 let client = build_ollama_client(llm_config);
                 Some(Arc::new(ResilientLlmProvider::new(
@@ -359,37 +1326,39 @@ pub fn cmd_consolidate(
         }
     };
 
-    // Display configuration
-    println!("Configuration:");
-    println!("  Storage backend: {:?}", storage_config.backend);
-    if llm_provider.is_some() {
-        println!("  LLM provider: {:?}", config.llm.provider);
-    } else {
-        println!("  LLM provider: None (will skip summarization)");
-    }
-    if let Some(ref namespaces) = consolidation_config.namespace_filter {
-        println!("  Namespaces: {namespaces:?}");
-    } else {
-        println!("  Namespaces: all");
-    }
-    if let Some(d) = consolidation_config.time_window_days {
-        println!("  Time window: {d} days");
-    } else {
-        println!("  Time window: all time");
+    // Display configuration (JSON output carries only the plan)
+    if !json {
+        println!("Configuration:");
+        println!("  Storage backend: {:?}", storage_config.backend);
+        if llm_provider.is_some() {
+            println!("  LLM provider: {:?}", config.llm.provider);
+        } else {
+            println!("  LLM provider: None (will skip summarization)");
+        }
+        if let Some(ref namespaces) = consolidation_config.namespace_filter {
+            println!("  Namespaces: {namespaces:?}");
+        } else {
+            println!("  Namespaces: all");
+        }
+        if let Some(d) = consolidation_config.time_window_days {
+            println!("  Time window: {d} days");
+        } else {
+            println!("  Time window: all time");
+        }
+        println!(
+            "  Similarity threshold: {}",
+            consolidation_config.similarity_threshold
+        );
+        println!(
+            "  Minimum memories: {}",
+            consolidation_config.min_memories_to_consolidate
+        );
+        println!();
+
+        println!("Finding related memory groups...");
     }
-    println!(
-        "  Similarity threshold: {}",
-        consolidation_config.similarity_threshold
-    );
-    println!(
-        "  Minimum memories: {}",
-        consolidation_config.min_memories_to_consolidate
-    );
-    println!();
 
     // Create consolidation service based on configured backend
-    println!("Finding related memory groups...");
-
     match storage_config.backend {
         StorageBackendType::Sqlite => {
             let db_path = storage_config
@@ -409,6 +1378,7 @@ pub fn cmd_consolidate(
                 &recall_service,
                 &consolidation_config,
                 dry_run,
+                json,
             )?;
         },
         StorageBackendType::Filesystem => {
@@ -424,6 +1394,7 @@ pub fn cmd_consolidate(
                 &recall_service,
                 &consolidation_config,
                 dry_run,
+                json,
             )?;
         },
         StorageBackendType::PostgreSQL => {
@@ -444,6 +1415,7 @@ pub fn cmd_consolidate(
                 &recall_service,
                 &consolidation_config,
                 dry_run,
+                json,
             )?;
         },
         StorageBackendType::Redis => {
@@ -456,48 +1428,29 @@ pub fn cmd_consolidate(
 }
 
 /// Runs consolidation with the new API and prints results.
+///
+/// With `dry_run`, prints the consolidation plan instead (as JSON if `json`).
 #[allow(clippy::excessive_nesting, clippy::too_many_lines)]
 fn run_consolidation<P: PersistenceBackend>(
     service: &mut subcog::services::ConsolidationService<P>,
     recall_service: &subcog::services::RecallService,
     consolidation_config: &subcog::config::ConsolidationConfig,
     dry_run: bool,
+    json: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     if dry_run {
-        // Dry-run mode: show what would be consolidated without making changes
-        match service.find_related_memories(recall_service, consolidation_config) {
-            Ok(groups) => {
-                println!();
-                println!("Dry-run results (no changes made):");
-                println!();
-
-                let mut total_groups = 0;
-                let mut total_memories = 0;
-
-                for (namespace, namespace_groups) in &groups {
-                    if namespace_groups.is_empty() {
-                        continue;
-                    }
-
-                    println!("Namespace: {namespace:?}");
-                    for (idx, group) in namespace_groups.iter().enumerate() {
-                        println!("  Group {}: {} memories", idx + 1, group.len());
-                        total_groups += 1;
-                        total_memories += group.len();
-                    }
-                    println!();
-                }
-
-                println!("Summary:");
-                println!("  Would create {total_groups} summary node(s)");
-                println!("  Would consolidate {total_memories} memory/memories");
-                println!();
-                println!("Run without --dry-run to apply changes");
-
+        // Dry-run mode: plan clusters and summaries without making changes
+        match service.plan_consolidation(recall_service, consolidation_config) {
+            Ok(plan) if json => {
+                println!("{}", serde_json::to_string_pretty(&plan)?);
+                Ok(())
+            },
+            Ok(plan) => {
+                print_consolidation_plan(&plan);
                 Ok(())
             },
             Err(e) => {
-                eprintln!("Failed to find related memories: {e}");
+                eprintln!("Failed to plan consolidation: {e}");
                 Err(e.into())
             },
         }
@@ -565,7 +1518,10 @@ fn run_consolidation<P: PersistenceBackend>(
                         if stats.summaries_created > 0 {
                             let created = stats.summaries_created;
                             println!("  ✓ Created {created} summary node(s)");
-                            println!("  ✓ Linked {total_memories} source memories via edges");
+                            println!(
+                                "  ✓ Superseded {} source memories, linked via edges",
+                                stats.merged
+                            );
                         }
 
                         if stats.contradictions > 0 {
@@ -592,6 +1548,54 @@ fn run_consolidation<P: PersistenceBackend>(
     }
 }
 
+/// Prints a consolidation plan for `consolidate --dry-run`.
+fn print_consolidation_plan(plan: &subcog::services::ConsolidationPlan) {
+    use subcog::models::MemoryId;
+
+    println!();
+    println!("Dry-run results (no changes made):");
+    println!();
+
+    for (idx, cluster) in plan.clusters.iter().enumerate() {
+        println!(
+            "Cluster {} ({}, {} memories)",
+            idx + 1,
+            cluster.namespace,
+            cluster.members.len()
+        );
+        println!("  Members:");
+        for member in &cluster.members {
+            println!("    - {}: {}", member.id, member.snippet);
+        }
+        if let Some(ref summary) = cluster.proposed_summary {
+            println!("  Proposed summary:");
+            for line in summary.lines() {
+                println!("    {line}");
+            }
+            let superseded: Vec<&str> = cluster.superseded.iter().map(MemoryId::as_str).collect();
+            println!("  Would supersede: {}", superseded.join(", "));
+        } else {
+            println!(
+                "  No summary ({}); members would be linked as related",
+                cluster
+                    .summary_error
+                    .as_deref()
+                    .unwrap_or("summarization failed")
+            );
+        }
+        println!();
+    }
+
+    println!("Summary:");
+    println!("  Would create {} summary node(s)", plan.summary_count());
+    println!(
+        "  Would consolidate {} memory/memories",
+        plan.memory_count()
+    );
+    println!();
+    println!("Run without --dry-run to apply changes");
+}
+
 /// Reindex command.
 pub fn cmd_reindex(repo: Option<PathBuf>) -> Result<(), Box<dyn std::error::Error>> {
     use subcog::services::ServiceContainer;
@@ -0,0 +1,63 @@
+//! Digest command handler.
+//!
+//! Contains the implementation of the `digest` CLI command, which summarizes
+//! recently captured memories.
+
+use subcog::cli::build_llm_provider;
+use subcog::config::{SubcogConfig, parse_duration_to_seconds};
+use subcog::models::SearchFilter;
+use subcog::notifications::NotificationService;
+use subcog::services::{DigestService, ServiceContainer};
+
+use super::core::parse_namespace;
+
+/// Digest command.
+pub fn cmd_digest(
+    config: &SubcogConfig,
+    since: &str,
+    namespace: Option<String>,
+    format: &str,
+    summarize: bool,
+    notifications: Option<&NotificationService>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let window = parse_duration_to_seconds(since)
+        .filter(|&secs| secs > 0)
+        .ok_or_else(|| format!("Invalid --since: {since} (expected e.g. 12h, 1d, 7d)"))?;
+    if !matches!(format, "text" | "markdown" | "json") {
+        return Err(format!("Invalid format: {format} (expected text, markdown, or json)").into());
+    }
+
+    let services = ServiceContainer::from_current_dir_or_user()?;
+    let recall = services.recall()?;
+    let mut filter = SearchFilter::new();
+    if let Some(ns) = namespace {
+        filter = filter.with_namespace(parse_namespace(&ns));
+    }
+
+    let mut digest = DigestService::new(&recall);
+    if summarize {
+        match build_llm_provider(config) {
+            Some(llm) => digest = digest.with_llm(llm),
+            None => eprintln!("Warning: no LLM provider available, the digest is not summarized"),
+        }
+    }
+    let since = subcog::current_timestamp().saturating_sub(window);
+    let digest = digest.generate(since, &filter)?;
+
+    match format {
+        "json" => println!("{}", serde_json::to_string_pretty(&digest)?),
+        "markdown" => print!("{}", digest.to_markdown()),
+        _ => print!("{}", digest.to_text()),
+    }
+
+    if let Some(notifications) = notifications {
+        let sent = notifications.send(&digest.to_notification());
+        if sent == 0 {
+            eprintln!("Warning: no webhook or notifier accepts the digest event");
+        } else {
+            eprintln!("Digest sent to {sent} notifier(s)");
+        }
+    }
+
+    Ok(())
+}
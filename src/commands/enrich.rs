@@ -6,11 +6,11 @@
 use std::sync::Arc;
 
 use subcog::cli::{
-    build_anthropic_client, build_lmstudio_client, build_ollama_client, build_openai_client,
-    build_resilience_config,
+    build_anthropic_client, build_gemini_client, build_lmstudio_client, build_ollama_client,
+    build_openai_client, build_resilience_config,
 };
 use subcog::config::{LlmProvider, SubcogConfig};
-use subcog::llm::LlmProvider as LlmProviderTrait;
+use subcog::llm::{LlmProvider as LlmProviderTrait, TokenPricing};
 use subcog::services::ServiceContainer;
 use subcog::storage::traits::IndexBackend;
 
@@ -22,6 +22,9 @@ pub fn cmd_enrich(
     id: Option<String>,
     dry_run: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    // Enrichment always calls the LLM
+    subcog::config::ensure_online("enrich")?;
+
     // Create the appropriate LLM client based on config
     let llm_config = &config.llm;
     println!(
@@ -50,6 +53,14 @@ pub fn cmd_enrich(
             id,
             dry_run,
         ),
+        LlmProvider::Gemini => run_enrich_with_client(
+            build_gemini_client(llm_config),
+            llm_config,
+            all,
+            update_all,
+            id,
+            dry_run,
+        ),
         LlmProvider::Ollama => run_enrich_with_client(
             build_ollama_client(llm_config),
             llm_config,
@@ -90,13 +101,11 @@ fn run_enrich_with_client<P: LlmProviderTrait>(
 
     let resilience_config = build_resilience_config(llm_config);
     let client = subcog::llm::ResilientLlmProvider::new(client, resilience_config);
-    run_enrichment(
-        subcog::services::EnrichmentService::new(client, index),
-        all,
-        update_all,
-        id,
-        dry_run,
-    )
+    let mut service = subcog::services::EnrichmentService::new(client, index);
+    if let Some(pricing) = TokenPricing::from_config(llm_config) {
+        service = service.with_pricing(pricing);
+    }
+    run_enrichment(service, all, update_all, id, dry_run)
 }
 
 /// Runs the enrichment operation with the given service.
@@ -117,6 +126,10 @@ fn run_enrichment<P: LlmProviderTrait>(
                 } else {
                     println!("Would enrich with tags: {:?}", result.new_tags);
                 }
+                match result.cost_usd {
+                    Some(cost) => println!("LLM usage: {}, cost ${cost:.4}", result.tokens),
+                    None => println!("LLM usage: {}", result.tokens),
+                }
             },
             Err(e) => {
                 eprintln!("Enrichment failed: {e}");
@@ -4,28 +4,39 @@
 //! Claude Code hook event handling.
 
 use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 use subcog::config::SubcogConfig;
-use subcog::context::GitContext;
+use subcog::context::{GitContext, SubcogIgnore};
 use subcog::hooks::{
-    AdaptiveContextConfig, HookHandler, PostToolUseHandler, PreCompactHandler, SessionStartHandler,
-    StopHandler, UserPromptHandler,
+    AdaptiveContextConfig, HOOK_SCHEMA_VERSION, HookHandler, PostToolUseHandler, PreCompactHandler,
+    SessionStartHandler, StopHandler, UserPromptHandler,
 };
 use subcog::models::{EventMeta, MemoryEvent};
 use subcog::observability::{
     RequestContext, current_request_id, enter_request_context, flush_metrics,
 };
 use subcog::security::record_event;
-use subcog::services::ContextBuilderService;
+use subcog::services::{ContextBuilderService, DeduplicationConfig, IndexDeduplicator};
 use subcog::storage::index::SqliteBackend;
 use subcog::{CaptureService, RecallService, SyncService};
 use tracing::info_span;
 
-use super::HookEvent;
+use super::{HookAction, HookEvent};
 
 /// Hook command.
-pub fn cmd_hook(event: HookEvent, config: &SubcogConfig) -> Result<(), Box<dyn std::error::Error>> {
-    use subcog::cli::build_hook_llm_provider;
+pub fn cmd_hook(
+    action: HookAction,
+    config: &SubcogConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    match action {
+        HookAction::Run(event) => run_hook(event, config),
+        HookAction::Test { event, input, live } => test_hook(event, input.as_deref(), live, config),
+    }
+}
 
+/// Handles a hook event from Claude Code, reading the payload from stdin.
+fn run_hook(event: HookEvent, config: &SubcogConfig) -> Result<(), Box<dyn std::error::Error>> {
     // Set instance label for metrics including hook type to prevent metric collision
     // Each hook type gets its own instance (hooks-session-start, hooks-user-prompt-submit, etc.)
     let instance_label = format!("hooks-{}", event.as_str());
@@ -46,6 +57,101 @@ pub fn cmd_hook(event: HookEvent, config: &SubcogConfig) -> Result<(), Box<dyn s
     // Read input from stdin as a string
     let input = read_hook_input()?;
 
+    let hook_name = event.as_str();
+    record_event(MemoryEvent::HookInvoked {
+        meta: EventMeta::new("hooks", current_request_id()),
+        hook: hook_name.to_string(),
+    });
+
+    let response = handle_event(event, config, &input, true);
+    let response = match response {
+        Ok(response) => response,
+        Err(err) => {
+            record_event(MemoryEvent::HookFailed {
+                meta: EventMeta::new("hooks", current_request_id()),
+                hook: hook_name.to_string(),
+                error: err.to_string(),
+            });
+            eprintln!("{}", hook_error_json(hook_name, &err));
+            return Err(format!("{hook_name} hook failed").into());
+        },
+    };
+
+    // Output response (already JSON string)
+    println!("{response}");
+
+    // Delay to ensure spawned threads complete metric recording.
+    // Note: LLM threads use recv_timeout, so if HTTP timeout < search_intent timeout,
+    // the thread will complete before recv_timeout expires. This delay is just a buffer
+    // for any remaining metric recording after channel communication.
+    std::thread::sleep(std::time::Duration::from_millis(250));
+
+    // Flush metrics to push gateway before exit
+    flush_metrics();
+
+    Ok(())
+}
+
+/// Runs a hook on a sample or given payload and pretty-prints the response.
+fn test_hook(
+    event: HookEvent,
+    input: Option<&Path>,
+    live: bool,
+    config: &SubcogConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let payload = match input {
+        Some(path) => std::fs::read_to_string(path)
+            .map_err(|e| format!("Failed to read {}: {e}", path.display()))?,
+        None => sample_payload(event).to_string(),
+    };
+
+    let (response, elapsed) = run_hook_test(event, &payload, live, config)?;
+    println!("{response}");
+    eprintln!(
+        "{} hook responded in {:.1} ms",
+        event.as_str(),
+        elapsed.as_secs_f64() * 1000.0
+    );
+    Ok(())
+}
+
+/// Runs the handler on `payload`, returning the pretty-printed response and
+/// how long the handler took.
+fn run_hook_test(
+    event: HookEvent,
+    payload: &str,
+    live: bool,
+    config: &SubcogConfig,
+) -> Result<(String, Duration), Box<dyn std::error::Error>> {
+    let start = Instant::now();
+    let response = handle_event(event, config, payload, live)?;
+    let elapsed = start.elapsed();
+
+    let response: serde_json::Value = serde_json::from_str(&response)?;
+    Ok((serde_json::to_string_pretty(&response)?, elapsed))
+}
+
+/// Returns the built-in sample payload of `event`.
+const fn sample_payload(event: HookEvent) -> &'static str {
+    match event {
+        HookEvent::SessionStart => include_str!("../../hooks/samples/session-start.json"),
+        HookEvent::UserPromptSubmit => include_str!("../../hooks/samples/user-prompt-submit.json"),
+        HookEvent::PostToolUse => include_str!("../../hooks/samples/post-tool-use.json"),
+        HookEvent::PreCompact => include_str!("../../hooks/samples/pre-compact.json"),
+        HookEvent::Stop => include_str!("../../hooks/samples/stop.json"),
+    }
+}
+
+/// Runs the handler of `event` on `input`, wired with the services the real
+/// hook uses.
+///
+/// Without `live`, memories are not captured and nothing is synced.
+fn handle_event(
+    event: HookEvent,
+    config: &SubcogConfig,
+    input: &str,
+    live: bool,
+) -> subcog::Result<String> {
     // Try to initialize services for hooks (may fail if no data dir)
     // Use config.data_dir to respect user's config.toml setting
     let recall_service = try_init_recall_service(&config.data_dir);
@@ -56,94 +162,119 @@ pub fn cmd_hook(event: HookEvent, config: &SubcogConfig) -> Result<(), Box<dyn s
     if let Some(path) = cwd.as_ref() {
         capture_config = capture_config.with_repo_path(path);
     }
-    let capture_service = CaptureService::new(capture_config.clone());
-    let sync_service = SyncService::default();
 
-    let hook_name = event.as_str();
-    record_event(MemoryEvent::HookInvoked {
-        meta: EventMeta::new("hooks", current_request_id()),
-        hook: hook_name.to_string(),
-    });
-
-    let response = match event {
-        HookEvent::SessionStart => {
-            // SessionStart with context builder for memory injection
-            let handler = if let Some(recall) = recall_service {
-                SessionStartHandler::new()
-                    .with_context_builder(ContextBuilderService::with_recall(recall))
-            } else {
-                SessionStartHandler::new()
-            };
-            handler.handle(&input)
-        },
+    match event {
+        HookEvent::SessionStart => session_start_handler(recall_service).handle(input),
         HookEvent::UserPromptSubmit => {
-            let context_config =
-                AdaptiveContextConfig::from_search_intent_config(&config.search_intent);
-            // Create separate capture service for auto-capture in this handler
-            let handler_capture_service = CaptureService::new(capture_config);
-            let mut handler = UserPromptHandler::new()
-                .with_search_intent_config(config.search_intent.clone())
-                .with_context_config(context_config)
-                .with_capture_service(handler_capture_service)
-                .with_auto_capture(config.features.auto_capture);
-            if let Some(provider) = build_hook_llm_provider(config) {
-                handler = handler.with_llm_provider(provider);
+            let mut handler = user_prompt_handler(config, recall_service)
+                .with_auto_capture(live && config.features.auto_capture);
+            if live {
+                handler = handler.with_capture_service(CaptureService::new(capture_config));
             }
-            handler.handle(&input)
+            handler.handle(input)
         },
         HookEvent::PostToolUse => {
             // PostToolUse with recall service for memory surfacing
-            let handler = if let Some(recall) = recall_service {
+            let mut handler = if let Some(recall) = recall_service {
                 PostToolUseHandler::new().with_recall(recall)
             } else {
                 PostToolUseHandler::new()
             };
-            handler.handle(&input)
+            if let Some(path) = cwd.as_ref() {
+                handler = handler.with_ignore(SubcogIgnore::discover(path));
+            }
+            if live && config.auto_capture.from_tools {
+                handler = handler.with_tool_capture(
+                    CaptureService::new(capture_config),
+                    config.auto_capture.tool_patterns.clone(),
+                );
+            }
+            handler.handle(input)
         },
         HookEvent::PreCompact => {
             // PreCompact with capture service for auto-capture
-            let handler = PreCompactHandler::new().with_capture(capture_service);
-            handler.handle(&input)
+            let mut handler = PreCompactHandler::new().with_config(config.auto_capture.clone());
+            if live {
+                handler = handler.with_capture(CaptureService::new(capture_config));
+            }
+            if let Some(dedup) = try_init_deduplicator(&config.data_dir) {
+                handler = handler.with_deduplication(Arc::new(dedup));
+            }
+            if (config.auto_capture.use_llm || config.auto_capture.summarize_session)
+                && let Some(llm) = subcog::cli::build_llm_provider(config)
+            {
+                handler = handler.with_llm(llm);
+            }
+            handler.handle(input)
         },
         HookEvent::Stop => {
             // Stop with sync service for session-end sync
-            let handler = StopHandler::new().with_sync(sync_service);
-            handler.handle(&input)
+            let mut handler = StopHandler::new();
+            if live {
+                handler = handler.with_sync(SyncService::default());
+            }
+            handler.handle(input)
         },
-    };
+    }
+}
 
-    let response = match response {
-        Ok(response) => response,
-        Err(err) => {
-            record_event(MemoryEvent::HookFailed {
-                meta: EventMeta::new("hooks", current_request_id()),
-                hook: hook_name.to_string(),
-                error: err.to_string(),
-            });
-            return Err(Box::new(err));
-        },
+/// Describes a failed hook as a JSON object for stderr.
+///
+/// `kind` is `invalid_input` when the payload did not match the event's
+/// schema, and `failed` for any other error.
+fn hook_error_json(hook: &str, err: &subcog::Error) -> serde_json::Value {
+    let kind = match err {
+        subcog::Error::InvalidInput(_) => "invalid_input",
+        _ => "failed",
     };
+    serde_json::json!({
+        "error": {
+            "hook": hook,
+            "kind": kind,
+            "message": err.to_string(),
+            "schema_version": HOOK_SCHEMA_VERSION
+        }
+    })
+}
 
-    // Output response (already JSON string)
-    println!("{response}");
-
-    // Delay to ensure spawned threads complete metric recording.
-    // Note: LLM threads use recv_timeout, so if HTTP timeout < search_intent timeout,
-    // the thread will complete before recv_timeout expires. This delay is just a buffer
-    // for any remaining metric recording after channel communication.
-    std::thread::sleep(std::time::Duration::from_millis(250));
-
-    // Flush metrics to push gateway before exit
-    flush_metrics();
+/// Builds the `SessionStart` handler, injecting memories when recall is available.
+pub(super) fn session_start_handler(recall: Option<RecallService>) -> SessionStartHandler {
+    // SessionStart with context builder for memory injection
+    match recall {
+        Some(recall) => SessionStartHandler::new()
+            .with_context_builder(ContextBuilderService::with_recall(recall)),
+        None => SessionStartHandler::new(),
+    }
+}
 
-    Ok(())
+/// Builds the `UserPromptSubmit` handler with the adaptive context settings.
+///
+/// Memories are retrieved only with `[search_intent] inject_memories`.
+/// Auto-capture is left to the caller so previews never capture.
+pub(super) fn user_prompt_handler(
+    config: &SubcogConfig,
+    recall: Option<RecallService>,
+) -> UserPromptHandler {
+    let context_config = AdaptiveContextConfig::from_search_intent_config(&config.search_intent);
+    let mut handler = UserPromptHandler::new()
+        .with_search_intent_config(config.search_intent.clone())
+        .with_context_config(context_config);
+    if config.search_intent.inject_memories
+        && let Some(recall) = recall
+    {
+        handler = handler.with_recall_service(recall);
+    }
+    if let Some(provider) = subcog::cli::build_hook_llm_provider(config) {
+        handler = handler.with_llm_provider(provider);
+    }
+    handler
 }
 
 /// Tries to initialize a recall service with `SQLite` backend.
 ///
 /// Uses the provided `data_dir` from config to ensure hooks use the same
 /// data directory as the MCP server (respects config.toml `data_dir` setting).
-fn try_init_recall_service(data_dir: &Path) -> Option<RecallService> {
+pub(super) fn try_init_recall_service(data_dir: &Path) -> Option<RecallService> {
     if std::fs::create_dir_all(data_dir).is_err() {
         return None;
     }
@@ -163,6 +294,18 @@ fn try_init_recall_service(data_dir: &Path) -> Option<RecallService> {
     }
 }
 
+/// Tries to create the deduplicator for hook captures.
+///
+/// Checks the content hashes of the index captures are written to; the hook
+/// captures without embeddings, so there is no semantic check.
+fn try_init_deduplicator(data_dir: &Path) -> Option<IndexDeduplicator> {
+    let index = SqliteBackend::new(data_dir.join("index.db")).ok()?;
+    Some(IndexDeduplicator::new(
+        Arc::new(index),
+        DeduplicationConfig::from_env(),
+    ))
+}
+
 /// Reads hook input from stdin as a string.
 fn read_hook_input() -> Result<String, Box<dyn std::error::Error>> {
     use std::io::{self, Read};
@@ -176,3 +319,41 @@ fn read_hook_input() -> Result<String, Box<dyn std::error::Error>> {
         Ok(input)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hook_test_user_prompt_submit_sample() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let mut config = SubcogConfig {
+            data_dir: dir.path().to_path_buf(),
+            ..SubcogConfig::default()
+        };
+        config.search_intent.use_llm = false;
+
+        let event = HookEvent::UserPromptSubmit;
+        let (response, _) = run_hook_test(event, sample_payload(event), false, &config).unwrap();
+
+        let response: serde_json::Value = serde_json::from_str(&response).unwrap();
+        let output = &response["hookSpecificOutput"];
+        assert_eq!(output["hookEventName"], "UserPromptSubmit");
+        assert!(!output["additionalContext"].as_str().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sample_payloads_name_their_event() {
+        let samples = [
+            (HookEvent::SessionStart, "SessionStart"),
+            (HookEvent::UserPromptSubmit, "UserPromptSubmit"),
+            (HookEvent::PostToolUse, "PostToolUse"),
+            (HookEvent::PreCompact, "PreCompact"),
+            (HookEvent::Stop, "Stop"),
+        ];
+        for (event, name) in samples {
+            let sample: serde_json::Value = serde_json::from_str(sample_payload(event)).unwrap();
+            assert_eq!(sample["hook_event_name"], name);
+        }
+    }
+}
@@ -1,92 +1,124 @@
-//! Import and export command handlers.
+//! Import, export, and schema command handlers.
 
 use std::io::Write as _;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 use subcog::config::{Config, SubcogConfig};
 use subcog::io::formats::Format;
-use subcog::io::services::export::{ExportOptions, ExportService};
-use subcog::io::services::import::{ImportOptions, ImportService};
+use subcog::io::formats::markdown::MARKDOWN_DEFAULT_NAMESPACE;
+use subcog::io::services::export::{ExportCheckpoint, ExportOptions, ExportService};
+use subcog::io::services::import::{
+    ImportOptions, ImportProgress, ImportService, MergeStrategy, estimate_remaining,
+};
+use subcog::io::signing::{sign_file, signature_path, verify_file};
+use subcog::io::{ExportField, memory_schema};
 use subcog::models::{Domain, Namespace};
 use subcog::services::CaptureService;
 use subcog::storage::index::SqliteBackend;
 use subcog::{Error, Result};
 
+use super::SchemaKind;
+
 /// Executes the import command.
 #[allow(clippy::too_many_arguments)]
 pub fn cmd_import(
     config: &Config,
-    file: PathBuf,
+    file: Option<PathBuf>,
+    from_directory: Option<PathBuf>,
     format: Option<String>,
     namespace: Option<String>,
     domain: Option<String>,
     skip_duplicates: bool,
+    merge_strategy: Option<String>,
     dry_run: bool,
+    verify_key: Option<PathBuf>,
+    progress_interval: usize,
+    quiet: bool,
 ) -> Result<()> {
-    // Determine format from argument or file extension
-    let format = match format {
-        Some(f) => f.parse::<Format>()?,
-        None => Format::from_path(&file)?,
+    // Markdown directories have no format or signature to check
+    let is_directory = from_directory.is_some();
+    let (input, format) = match (file, from_directory) {
+        (Some(file), None) => {
+            let format = import_file_format(&file, format, verify_key.as_deref())?;
+            (file, format)
+        },
+        (None, Some(dir)) => (dir, Format::Json),
+        _ => {
+            return Err(Error::InvalidInput(
+                "Specify either an input file or --from-directory".to_string(),
+            ));
+        },
     };
 
-    if !format.supports_import() {
-        return Err(Error::InvalidInput(format!(
-            "Format '{format}' does not support import"
-        )));
-    }
-
-    // Parse namespace
+    // Parse namespace (Markdown notes default to context)
+    let fallback_namespace = if is_directory {
+        MARKDOWN_DEFAULT_NAMESPACE
+    } else {
+        Namespace::Decisions
+    };
     let default_namespace = namespace
         .as_deref()
         .and_then(Namespace::parse)
-        .unwrap_or(Namespace::Decisions);
+        .unwrap_or(fallback_namespace);
 
     // Parse domain
     let default_domain = domain.as_deref().map(parse_domain).unwrap_or_default();
 
+    // An explicit merge strategy takes precedence over --skip-duplicates
+    let merge_strategy = match merge_strategy {
+        Some(s) => s.parse::<MergeStrategy>()?,
+        None if skip_duplicates => MergeStrategy::Skip,
+        None => MergeStrategy::Append,
+    };
+
     let options = ImportOptions {
         format,
         default_namespace,
         default_domain,
-        skip_duplicates,
+        merge_strategy,
         skip_invalid: true,
         dry_run,
+        progress_interval: progress_interval.max(1),
     };
 
     // Create capture service
     let capture_service = Arc::new(CaptureService::new(config.clone()));
     let import_service = ImportService::new(capture_service);
 
-    // Progress callback for line-based updates
-    let progress_callback = Box::new(|progress: &subcog::io::services::import::ImportProgress| {
-        if let Some(total) = progress.total_estimate {
-            print!(
-                "\rProcessing: {}/{} ({:.1}%) - Imported: {}, Skipped: {}, Invalid: {}",
-                progress.processed,
-                total,
-                progress.percent_complete().unwrap_or(0.0),
+    // Progress bar (or spinner for streaming formats), suppressed with --quiet
+    let progress_callback = (!quiet).then(|| {
+        let bar = ProgressLine::new();
+        Box::new(move |progress: &ImportProgress| {
+            let detail = format!(
+                "Imported: {}, Updated: {}, Skipped: {}, Invalid: {}",
                 progress.imported,
+                progress.updated,
                 progress.skipped_duplicates,
                 progress.skipped_invalid,
             );
-        } else {
-            print!(
-                "\rProcessing: {} - Imported: {}, Skipped: {}, Invalid: {}",
+            bar.render(
                 progress.processed,
-                progress.imported,
-                progress.skipped_duplicates,
-                progress.skipped_invalid,
+                progress.total_estimate,
+                progress.eta(),
+                &detail,
             );
-        }
-        // Flush to ensure output appears immediately
-        let _ = std::io::stdout().flush();
+        }) as Box<dyn Fn(&ImportProgress) + Send>
     });
 
-    let result = import_service.import_from_file(&file, options, Some(progress_callback))?;
+    let show_progress = progress_callback.is_some();
+    let result = if is_directory {
+        import_service.import_from_directory(&input, options, progress_callback)?
+    } else {
+        import_service.import_from_file(&input, options, progress_callback)?
+    };
 
     // Clear progress line and print final summary
-    println!();
+    if show_progress {
+        println!();
+    }
     println!();
 
     if dry_run {
@@ -96,6 +128,7 @@ pub fn cmd_import(
     }
 
     println!("  Imported:         {}", result.imported);
+    println!("  Updated:          {}", result.updated);
     println!("  Skipped (dupe):   {}", result.skipped_duplicates);
     println!("  Skipped (invalid):{}", result.skipped_invalid);
     println!("  Total processed:  {}", result.total_processed);
@@ -125,7 +158,37 @@ pub fn cmd_import(
     Ok(())
 }
 
+/// Verifies an import file's signature, if requested, and resolves its format.
+fn import_file_format(
+    file: &Path,
+    format: Option<String>,
+    verify_key: Option<&Path>,
+) -> Result<Format> {
+    // Verify the detached signature before reading any records
+    if let Some(key) = verify_key {
+        let signature = verify_file(file, key)?;
+        println!("Signature verified (key {})", signature.key_fingerprint);
+    }
+
+    // Determine format from argument or file extension
+    let format = match format {
+        Some(f) => f.parse::<Format>()?,
+        None => Format::from_path(file)?,
+    };
+
+    if !format.supports_import() {
+        return Err(Error::InvalidInput(format!(
+            "Format '{format}' does not support import"
+        )));
+    }
+    Ok(format)
+}
+
 /// Executes the export command.
+///
+/// With `incremental`, a checkpoint is recorded next to the output; with
+/// `since_last` as well, only memories changed since that checkpoint are
+/// exported and the previous export is rotated aside rather than overwritten.
 #[allow(clippy::too_many_arguments)]
 pub fn cmd_export(
     config: &SubcogConfig,
@@ -134,6 +197,15 @@ pub fn cmd_export(
     filter: Option<String>,
     limit: Option<usize>,
     domain: Option<String>,
+    fields: Option<String>,
+    sign_key: Option<PathBuf>,
+    anonymize: bool,
+    redact_pii: bool,
+    incremental: bool,
+    since_last: bool,
+    reset_checkpoint: bool,
+    progress_interval: usize,
+    quiet: bool,
 ) -> Result<()> {
     // Determine format from argument or file extension
     let format = match format {
@@ -163,33 +235,68 @@ pub fn cmd_export(
         Some(filter_parts.join(" "))
     };
 
-    let mut options = ExportOptions::default().with_format(format);
+    let mut options = ExportOptions::default()
+        .with_format(format)
+        .with_anonymize(anonymize)
+        .with_redact_pii(redact_pii)
+        .with_progress_interval(progress_interval);
     if let Some(f) = filter_query {
         options = options.with_filter(f);
     }
     if let Some(l) = limit {
         options = options.with_limit(l);
     }
+    // Reject unknown field names before creating the output file
+    if let Some(spec) = fields {
+        options = options.with_fields(ExportField::parse_list(&spec)?);
+    }
+
+    let checkpoint_path = ExportCheckpoint::path_for(&output);
+    let since = if incremental && since_last && !reset_checkpoint {
+        ExportCheckpoint::load(&checkpoint_path)?
+    } else {
+        None
+    };
+    let mut rotated = None;
+    if let Some(checkpoint) = since {
+        if checkpoint.filter != options.filter {
+            return Err(Error::InvalidInput(format!(
+                "{} was recorded for filter {:?}; repeat it or use --reset-checkpoint",
+                checkpoint_path.display(),
+                checkpoint.filter.as_deref().unwrap_or("")
+            )));
+        }
+        rotated = rotate_export(&output, checkpoint.exported_at)?;
+        options = options.with_since(checkpoint);
+    } else if since_last && !reset_checkpoint {
+        eprintln!(
+            "No checkpoint at {}, exporting everything",
+            checkpoint_path.display()
+        );
+    }
 
     // Create index backend for querying
     let sqlite_path = config.data_dir.join("index.sqlite");
     let index = Arc::new(SqliteBackend::new(&sqlite_path)?);
     let export_service = ExportService::new(index);
 
-    // Progress callback
-    let progress_callback = Box::new(|exported: usize, total: Option<usize>| {
-        if let Some(t) = total {
-            print!("\rExporting: {exported}/{t}");
-        } else {
-            print!("\rExporting: {exported}");
-        }
-        let _ = std::io::stdout().flush();
+    // Progress bar, suppressed with --quiet
+    let progress_callback = (!quiet).then(|| {
+        let bar = ProgressLine::new();
+        let started = Instant::now();
+        Box::new(move |exported: usize, total: Option<usize>| {
+            let eta = total.and_then(|t| estimate_remaining(exported, t, started.elapsed()));
+            bar.render(exported, total, eta, "Exporting");
+        }) as Box<dyn Fn(usize, Option<usize>) + Send>
     });
 
-    let result = export_service.export_to_file(&output, options, Some(progress_callback))?;
+    let show_progress = progress_callback.is_some();
+    let result = export_service.export_to_file(&output, options, progress_callback)?;
 
     // Clear progress line and print final summary
-    println!();
+    if show_progress {
+        println!();
+    }
     println!();
 
     println!("Export completed:");
@@ -200,9 +307,126 @@ pub fn cmd_export(
         println!("  Output:       {path}");
     }
 
+    if let Some(path) = rotated {
+        println!("  Previous:     {}", path.display());
+    }
+
+    if let Some(key) = sign_key {
+        let signature = sign_file(&output, &key)?;
+        println!("  Signature:    {}", signature_path(&output).display());
+        println!("  Key:          {}", signature.key_fingerprint);
+    }
+
+    // Recorded last, so a failed export or signature is retried next run
+    if incremental {
+        result.checkpoint.save(&checkpoint_path)?;
+        println!("  Checkpoint:   {}", checkpoint_path.display());
+    }
+
     Ok(())
 }
 
+/// Moves a previous export and its signature aside as `<stem>.<stamp>.<ext>`.
+///
+/// Returns the new path of the export, or `None` if there was none.
+fn rotate_export(output: &Path, stamp: u64) -> Result<Option<PathBuf>> {
+    if !output.exists() {
+        return Ok(None);
+    }
+    let stem = output
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let name = match output.extension() {
+        Some(ext) => format!("{stem}.{stamp}.{}", ext.to_string_lossy()),
+        None => format!("{stem}.{stamp}"),
+    };
+    let rotated = output.with_file_name(name);
+
+    let rename = |from: &Path, to: &Path| {
+        std::fs::rename(from, to).map_err(|e| Error::OperationFailed {
+            operation: "rotate_export".to_string(),
+            cause: format!("{}: {e}", from.display()),
+        })
+    };
+    rename(output, &rotated)?;
+    let signature = signature_path(output);
+    if signature.exists() {
+        rename(&signature, &signature_path(&rotated))?;
+    }
+    Ok(Some(rotated))
+}
+
+/// Width of the progress bar in characters.
+const PROGRESS_BAR_WIDTH: usize = 30;
+
+/// Spinner frames for operations with an unknown total.
+const SPINNER_FRAMES: [char; 4] = ['|', '/', '-', '\\'];
+
+/// Single-line progress renderer for long imports and exports.
+///
+/// Draws a bar with percentage and ETA when the total is known, or a spinner
+/// with a running count for streaming formats.
+struct ProgressLine {
+    ticks: AtomicUsize,
+}
+
+impl ProgressLine {
+    const fn new() -> Self {
+        Self {
+            ticks: AtomicUsize::new(0),
+        }
+    }
+
+    fn render(&self, processed: usize, total: Option<usize>, eta: Option<Duration>, detail: &str) {
+        let line = match total {
+            Some(total) => {
+                let eta = eta.map_or_else(String::new, |eta| format!(" ETA {}", format_eta(eta)));
+                format!(
+                    "{} {processed}/{total}{eta} - {detail}",
+                    progress_bar(processed, total)
+                )
+            },
+            None => {
+                let tick = self.ticks.fetch_add(1, Ordering::Relaxed);
+                let frame = SPINNER_FRAMES[tick % SPINNER_FRAMES.len()];
+                format!("{frame} {processed} processed - {detail}")
+            },
+        };
+        // Pad to overwrite any longer previous line
+        print!("\r{line:<100}");
+        let _ = std::io::stdout().flush();
+    }
+}
+
+/// Renders a fixed-width bar such as `[#######-------] 50.0%`.
+#[allow(clippy::cast_precision_loss)]
+fn progress_bar(processed: usize, total: usize) -> String {
+    let ratio = if total == 0 {
+        1.0
+    } else {
+        (processed as f64 / total as f64).min(1.0)
+    };
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let filled = (ratio * PROGRESS_BAR_WIDTH as f64).round() as usize;
+    format!(
+        "[{}{}] {:>5.1}%",
+        "#".repeat(filled),
+        "-".repeat(PROGRESS_BAR_WIDTH - filled),
+        ratio * 100.0
+    )
+}
+
+/// Formats an ETA as `1h02m`, `3m05s`, or `12s`.
+fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    match secs {
+        0..60 => format!("{secs}s"),
+        60..3600 => format!("{}m{:02}s", secs / 60, secs % 60),
+        _ => format!("{}h{:02}m", secs / 3600, (secs % 3600) / 60),
+    }
+}
+
 /// Parses a domain string into a Domain.
 fn parse_domain(s: &str) -> Domain {
     match s.to_lowercase().as_str() {
@@ -231,4 +455,33 @@ fn test_parse_domain() {
         assert!(!org_domain.is_user());
         assert!(!org_domain.is_project_scoped());
     }
+
+    #[test]
+    fn test_progress_bar() {
+        assert_eq!(
+            progress_bar(5, 10),
+            format!("[{}{}]  50.0%", "#".repeat(15), "-".repeat(15))
+        );
+        assert!(progress_bar(0, 0).ends_with("100.0%"));
+    }
+
+    #[test]
+    fn test_format_eta() {
+        assert_eq!(format_eta(Duration::from_secs(12)), "12s");
+        assert_eq!(format_eta(Duration::from_secs(185)), "3m05s");
+        assert_eq!(format_eta(Duration::from_secs(3720)), "1h02m");
+    }
+}
+
+/// Executes the schema command, printing the requested JSON Schema.
+pub fn cmd_schema(kind: &SchemaKind) -> Result<()> {
+    let schema = match kind {
+        SchemaKind::Memory => memory_schema(),
+    };
+    let rendered = serde_json::to_string_pretty(&schema).map_err(|e| Error::OperationFailed {
+        operation: "render_schema".to_string(),
+        cause: e.to_string(),
+    })?;
+    println!("{rendered}");
+    Ok(())
 }
@@ -5,15 +5,21 @@
 
 use std::io::Write;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use subcog::Error;
 use subcog::services::ServiceContainer;
+use subcog::services::migration::{DimensionMigration, MigrationOptions};
 
 /// Migrate embeddings command.
 ///
 /// Generates embeddings for memories that don't have them and stores
 /// them in the vector index for semantic search.
 ///
+/// When the saved vector index was built for a different embedding
+/// dimension than the configured embedder produces, the index is instead
+/// rebuilt at the new dimension and swapped in once validated.
+///
 /// # Errors
 ///
 /// Returns an error if the migration fails.
@@ -22,10 +28,8 @@ pub fn cmd_migrate_embeddings(
     dry_run: bool,
     force: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let services = match repo {
-        Some(repo_path) => ServiceContainer::for_repo(&repo_path, None)?,
-        None => ServiceContainer::from_current_dir_or_user()?,
-    };
+    // Unchecked: this command is what fixes a dimension mismatch
+    let services = ServiceContainer::for_vector_repair(repo)?;
 
     println!("Migrating embeddings for memories...");
     match services.repo_path() {
@@ -47,6 +51,16 @@ pub fn cmd_migrate_embeddings(
         cause: "No embedder configured - cannot generate embeddings".to_string(),
     })?;
 
+    // A dimension change needs a full rebuild rather than incremental upserts
+    let migration = DimensionMigration::new(
+        Arc::clone(&index),
+        Arc::clone(&embedder),
+        services.vector_path(),
+    );
+    if migration.needs_rebuild()? {
+        return run_dimension_migration(&migration, dry_run);
+    }
+
     let vector = services.vector().ok_or_else(|| Error::OperationFailed {
         operation: "migrate".to_string(),
         cause: "No vector backend configured - cannot store embeddings".to_string(),
@@ -142,6 +156,34 @@ pub fn cmd_migrate_embeddings(
     Ok(())
 }
 
+/// Rebuilds the vector index at the embedder's dimension and reports counts.
+fn run_dimension_migration(
+    migration: &DimensionMigration,
+    dry_run: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    println!("Embedding dimension changed - rebuilding the vector index.");
+    println!("Text search stays available while the new index is built.");
+    println!();
+
+    let stats = migration.run(&MigrationOptions::new().with_dry_run(dry_run))?;
+
+    let from = stats
+        .from_dimensions
+        .map_or_else(|| "none".to_string(), |d| d.to_string());
+    println!("Dimension migration complete:");
+    println!("  Dimensions: {from} -> {}", stats.to_dimensions);
+    if stats.dry_run {
+        println!("  Would re-embed: {}", stats.embedded);
+        println!();
+        println!("This was a dry run. No changes were made.");
+        println!("Run without --dry-run to apply changes.");
+    } else {
+        println!("  Re-embedded: {}", stats.embedded);
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
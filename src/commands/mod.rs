@@ -1,39 +1,63 @@
 //! Command handlers module.
 //!
 //! This module organizes the CLI command implementations into separate files:
-//! - `core.rs`: Core commands (capture, recall, status, sync, consolidate, reindex)
-//! - `config.rs`: Configuration display command
+//! - `core.rs`: Core commands (capture, get, recall, status, sync, consolidate, reindex)
+//! - `config.rs`: Configuration display and validation commands
+//! - `context.rs`: Hook context preview command
+//! - `digest.rs`: Digest of recently captured memories
 //! - `enrich.rs`: LLM-powered tag enrichment command
 //! - `graph.rs`: Knowledge graph commands (entities, relationships, stats)
 //! - `hook.rs`: Claude Code hook event handlers
-//! - `io.rs`: Import/export commands
+//! - `io.rs`: Import/export and schema commands
 //! - `migrate.rs`: Migration commands (embeddings)
+//! - `pin.rs`: Memory pin and unpin commands
 //! - `prompt.rs`: Prompt template management
+//! - `rest.rs`: REST API commands (OpenAPI document)
+//! - `tag.rs`: Tag rename and merge commands
+//! - `update.rs`: Memory update command
+//! - `verify.rs`: Index integrity check command
 //! - `webhook.rs`: Webhook management commands
 
 mod config;
+mod context;
 mod core;
+mod digest;
 mod enrich;
 mod graph;
 mod hook;
 mod io;
 mod migrate;
+mod pin;
 mod prompt;
+mod rest;
+mod tag;
+mod update;
+mod verify;
 mod webhook;
 
 use std::path::PathBuf;
 
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 
 // Re-export command functions
-pub use config::cmd_config;
-pub use core::{cmd_capture, cmd_consolidate, cmd_recall, cmd_reindex, cmd_status};
+pub use config::{cmd_config, cmd_config_validate};
+pub use context::cmd_context;
+pub use core::{
+    CaptureStructure, cmd_capture, cmd_consolidate, cmd_get, cmd_recall, cmd_reindex, cmd_status,
+    cmd_suggest_namespace,
+};
+pub use digest::cmd_digest;
 pub use enrich::cmd_enrich;
 pub use graph::{GraphAction, cmd_graph};
 pub use hook::cmd_hook;
-pub use io::{cmd_export, cmd_import};
+pub use io::{cmd_export, cmd_import, cmd_schema};
 pub use migrate::cmd_migrate_embeddings;
+pub use pin::cmd_pin;
 pub use prompt::cmd_prompt;
+pub use rest::cmd_rest;
+pub use tag::cmd_tag;
+pub use update::cmd_update;
+pub use verify::cmd_verify;
 pub use webhook::cmd_webhook;
 
 /// Webhook subcommands.
@@ -95,6 +119,78 @@ pub enum WebhookAction {
     },
 }
 
+/// Context subcommands.
+#[derive(Subcommand)]
+pub enum ContextAction {
+    /// Print the memory context the hooks would inject.
+    Build {
+        /// Prompt to preview `UserPromptSubmit` injection for.
+        #[arg(long)]
+        prompt: Option<String>,
+
+        /// Preview `SessionStart` injection.
+        #[arg(long)]
+        session: bool,
+    },
+}
+
+/// Tag subcommands.
+#[derive(Subcommand)]
+pub enum TagAction {
+    /// Rename a tag on every memory (merging if the new tag already exists).
+    Rename {
+        /// Tag to rename.
+        old: String,
+
+        /// New tag name.
+        new: String,
+
+        /// Show what would change without writing.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Merge several tags into one on every memory.
+    Merge {
+        /// Comma-separated tags to merge (e.g. authn,auth).
+        #[arg(value_delimiter = ',', required = true)]
+        sources: Vec<String>,
+
+        /// Tag to merge into.
+        #[arg(long)]
+        into: String,
+
+        /// Show what would change without writing.
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+/// Config subcommands.
+#[derive(Subcommand)]
+pub enum ConfigAction {
+    /// Validate a config file, exiting non-zero if it has problems.
+    Validate {
+        /// Config file to validate (default: `--config`, `SUBCOG_CONFIG_PATH`,
+        /// or `~/.config/subcog/config.toml`).
+        path: Option<PathBuf>,
+    },
+}
+
+/// REST API subcommands.
+#[derive(Subcommand)]
+pub enum RestAction {
+    /// Print the OpenAPI 3 document of the REST API (`serve --rest`).
+    Openapi,
+}
+
+/// Schema subcommands.
+#[derive(Subcommand)]
+pub enum SchemaKind {
+    /// JSON Schema for memory records (import/export format).
+    Memory,
+}
+
 /// Migrate subcommands.
 #[derive(Subcommand)]
 pub enum MigrateAction {
@@ -114,8 +210,32 @@ pub enum MigrateAction {
     },
 }
 
-/// Hook events.
+/// Hook subcommands.
 #[derive(Subcommand)]
+pub enum HookAction {
+    /// Handle a hook event, reading its payload from stdin.
+    #[command(flatten)]
+    Run(HookEvent),
+
+    /// Run a hook on a sample payload and print the response with timing.
+    ///
+    /// Memories are not captured and nothing is synced unless `--live` is given.
+    Test {
+        /// Hook event to run.
+        event: HookEvent,
+
+        /// JSON payload to use instead of the built-in sample.
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Also capture memories and sync, as the real hook does.
+        #[arg(long)]
+        live: bool,
+    },
+}
+
+/// Hook events.
+#[derive(Clone, Copy, Subcommand, ValueEnum)]
 pub enum HookEvent {
     /// Session start hook.
     SessionStart,
@@ -236,6 +356,10 @@ pub enum PromptAction {
         /// Prompt for missing variables interactively.
         #[arg(short, long)]
         interactive: bool,
+
+        /// Send the rendered prompt to the configured LLM and stream its reply.
+        #[arg(long)]
+        complete: bool,
     },
 
     /// Delete a prompt.
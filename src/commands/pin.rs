@@ -0,0 +1,21 @@
+//! Pin command handlers.
+//!
+//! Contains the implementation of the `pin` and `unpin` CLI commands, which
+//! mark memories to always be injected at session start.
+
+use subcog::models::MemoryId;
+use subcog::services::ServiceContainer;
+
+/// Pins or unpins a memory.
+///
+/// # Errors
+///
+/// Returns an error if the memory does not exist or storage access fails.
+pub fn cmd_pin(id: &str, pinned: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let services = ServiceContainer::from_current_dir_or_user()?;
+    let memory = services.capture().set_pinned(&MemoryId::new(id), pinned)?;
+
+    let state = if memory.pinned { "Pinned" } else { "Unpinned" };
+    println!("{state} {} ({})", memory.id.as_str(), memory.namespace);
+    Ok(())
+}
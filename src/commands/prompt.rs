@@ -4,14 +4,18 @@
 //! managing prompt templates.
 
 use subcog::cli::{
-    cmd_prompt_delete, cmd_prompt_export, cmd_prompt_get, cmd_prompt_import, cmd_prompt_list,
-    cmd_prompt_run, cmd_prompt_save, cmd_prompt_share,
+    build_llm_provider, cmd_prompt_delete, cmd_prompt_export, cmd_prompt_get, cmd_prompt_import,
+    cmd_prompt_list, cmd_prompt_run, cmd_prompt_save, cmd_prompt_share,
 };
+use subcog::config::SubcogConfig;
 
 use super::PromptAction;
 
 /// Prompt command.
-pub fn cmd_prompt(action: PromptAction) -> Result<(), Box<dyn std::error::Error>> {
+pub fn cmd_prompt(
+    action: PromptAction,
+    config: &SubcogConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
     match action {
         PromptAction::Save {
             name,
@@ -54,7 +58,18 @@ pub fn cmd_prompt(action: PromptAction) -> Result<(), Box<dyn std::error::Error>
             variables,
             domain,
             interactive,
-        } => cmd_prompt_run(name, variables, domain, interactive),
+            complete,
+        } => {
+            let llm = if complete {
+                Some(build_llm_provider(config).ok_or(
+                    "--complete requires an LLM provider (set llm.provider and enable \
+                     features.llm_features)",
+                )?)
+            } else {
+                None
+            };
+            cmd_prompt_run(name, variables, domain, interactive, llm.as_deref())
+        },
 
         PromptAction::Delete {
             name,
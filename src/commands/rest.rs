@@ -0,0 +1,35 @@
+//! REST API command handlers.
+
+use subcog::{Error, Result};
+
+use super::RestAction;
+
+/// REST command.
+///
+/// # Errors
+///
+/// Returns an error if the `rest` feature is not enabled or the document
+/// cannot be rendered.
+pub fn cmd_rest(action: &RestAction) -> Result<()> {
+    match action {
+        RestAction::Openapi => print_openapi(),
+    }
+}
+
+/// Prints the OpenAPI document of the REST API.
+#[cfg(feature = "rest")]
+fn print_openapi() -> Result<()> {
+    let spec = subcog::mcp::openapi_spec();
+    let rendered = serde_json::to_string_pretty(&spec).map_err(|e| Error::OperationFailed {
+        operation: "render_openapi".to_string(),
+        cause: e.to_string(),
+    })?;
+    println!("{rendered}");
+    Ok(())
+}
+
+/// Prints the OpenAPI document of the REST API (feature not enabled).
+#[cfg(not(feature = "rest"))]
+fn print_openapi() -> Result<()> {
+    Err(Error::FeatureNotEnabled("rest".to_string()))
+}
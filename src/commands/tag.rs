@@ -0,0 +1,52 @@
+//! Tag command handler.
+//!
+//! Contains the implementation of the `tag` CLI command for renaming and
+//! merging tags across stored memories.
+
+use subcog::services::{ServiceContainer, TagRewriteResult, TagService};
+
+pub use super::TagAction;
+
+/// Handles tag subcommands.
+///
+/// # Errors
+///
+/// Returns an error if a tag is invalid or storage access fails.
+pub fn cmd_tag(action: TagAction) -> Result<(), Box<dyn std::error::Error>> {
+    let container = ServiceContainer::from_current_dir_or_user()?;
+    let mut service = TagService::new(container.index()?);
+    if let Some(persistence) = container.persistence() {
+        service = service.with_persistence(persistence);
+    }
+
+    let result = match action {
+        TagAction::Rename { old, new, dry_run } => service.rename(&old, &new, dry_run)?,
+        TagAction::Merge {
+            sources,
+            into,
+            dry_run,
+        } => service.merge(&sources, &into, dry_run)?,
+    };
+    print_result(&result);
+
+    Ok(())
+}
+
+/// Prints the per-memory tag changes and a summary line.
+fn print_result(result: &TagRewriteResult) {
+    if result.dry_run && !result.rewrites.is_empty() {
+        println!("Dry-run mode: no changes written.\n");
+    }
+    for rewrite in &result.rewrites {
+        println!(
+            "  {}: [{}] -> [{}]",
+            rewrite.id.as_str(),
+            rewrite.before.join(", "),
+            rewrite.after.join(", ")
+        );
+    }
+    if !result.rewrites.is_empty() {
+        println!();
+    }
+    println!("{}", result.summary());
+}
@@ -0,0 +1,61 @@
+//! Update command handler.
+//!
+//! Contains the implementation of the `update` CLI command, which edits the
+//! content, tags, namespace, or status of an existing memory in place.
+
+use subcog::models::{MemoryId, MemoryStatus, Namespace, UpdateRequest, Urn};
+use subcog::services::ServiceContainer;
+
+/// Updates an existing memory.
+///
+/// `tags` is a comma-separated list that replaces the memory's tags.
+///
+/// # Errors
+///
+/// Returns an error if the namespace or status is unknown, the memory does
+/// not exist, or storage access fails.
+pub fn cmd_update(
+    id: &str,
+    content: Option<String>,
+    tags: Option<&str>,
+    namespace: Option<&str>,
+    status: Option<&str>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut request = UpdateRequest::new();
+    if let Some(content) = content {
+        request = request.with_content(content);
+    }
+    if let Some(tags) = tags {
+        request = request.with_tags(
+            tags.split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(String::from)
+                .collect(),
+        );
+    }
+    if let Some(namespace) = namespace {
+        let parsed =
+            Namespace::parse(namespace).ok_or_else(|| format!("Unknown namespace: {namespace}"))?;
+        request = request.with_namespace(parsed);
+    }
+    if let Some(status) = status {
+        let parsed =
+            MemoryStatus::parse(status).ok_or_else(|| format!("Unknown status: {status}"))?;
+        request = request.with_status(parsed);
+    }
+
+    let services = ServiceContainer::from_current_dir_or_user()?;
+    let memory_id = MemoryId::new(Urn::extract_memory_id(id));
+    let result = services.capture().edit(&memory_id, request)?;
+
+    println!("Updated {}", result.memory_id.as_str());
+    println!("  URN: {}", result.urn);
+    if result.content_modified {
+        println!("  Note: Content was redacted for security");
+    }
+    for warning in &result.warnings {
+        eprintln!("Warning: {warning}");
+    }
+    Ok(())
+}
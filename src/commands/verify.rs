@@ -0,0 +1,51 @@
+//! Verify command handler.
+//!
+//! Contains the implementation of the `verify` CLI command, which checks the
+//! index against the persistence backend and optionally repairs it.
+
+use subcog::services::{ServiceContainer, VerifyReport, VerifyService};
+
+/// Checks index integrity, reindexing discrepancies when `fix` is set.
+///
+/// # Errors
+///
+/// Returns an error if no persistence backend is configured or storage
+/// access fails.
+pub fn cmd_verify(fix: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let container = ServiceContainer::from_current_dir_or_user()?;
+    let Some(persistence) = container.persistence() else {
+        return Err(
+            "verify requires a persistence backend (set a storage path or use PostgreSQL)".into(),
+        );
+    };
+    let service = VerifyService::new(persistence, container.index()?);
+
+    let report = service.verify(fix)?;
+    print_report(&report);
+
+    Ok(())
+}
+
+/// Prints each discrepancy and a summary line.
+fn print_report(report: &VerifyReport) {
+    let sections = [
+        ("Missing from index", &report.missing),
+        ("Stale in index", &report.stale),
+        ("Content hash mismatch", &report.mismatched),
+        ("Orphaned index rows", &report.orphaned),
+    ];
+    for (label, ids) in sections {
+        if ids.is_empty() {
+            continue;
+        }
+        println!("{label}:");
+        for id in ids {
+            println!("  {}", id.as_str());
+        }
+        println!();
+    }
+    if !report.fixed && !report.is_consistent() {
+        println!("Read-only check: run with --fix to reindex the discrepancies.\n");
+    }
+    println!("{}", report.summary());
+}
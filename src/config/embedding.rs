@@ -0,0 +1,182 @@
+//! Embedding model selection.
+//!
+//! `[embedding] model` selects the global model (`all-MiniLM-L6-v2` by
+//! default). Every namespace is embedded with the global model unless
+//! `[embedding.per_namespace]` assigns it another one. Namespaces with their
+//! own model get their own vector index, since vectors from different models
+//! cannot be compared.
+//!
+//! # Example TOML
+//!
+//! ```toml
+//! [embedding]
+//! model = "bge-small-en-v1.5"
+//!
+//! [embedding.per_namespace]
+//! apis = "jina-embeddings-v2-base-code"
+//! ```
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::embedding::DEFAULT_MODEL;
+use crate::models::Namespace;
+
+/// Embedding section in the config file.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileEmbedding {
+    /// Global model.
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Model per namespace name.
+    #[serde(default)]
+    pub per_namespace: HashMap<String, String>,
+}
+
+/// Runtime embedding model selection.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmbeddingConfig {
+    /// Global model.
+    model: String,
+    /// Namespaces embedded with a model other than the global one.
+    per_namespace: HashMap<Namespace, String>,
+}
+
+impl Default for EmbeddingConfig {
+    fn default() -> Self {
+        Self {
+            model: DEFAULT_MODEL.to_string(),
+            per_namespace: HashMap::new(),
+        }
+    }
+}
+
+impl EmbeddingConfig {
+    /// Creates a configuration that embeds every namespace with the default model.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates the configuration from the `[embedding]` config file section.
+    ///
+    /// Unknown namespace names are logged and ignored, and entries naming the
+    /// global model are dropped since they need no separate index.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileEmbedding) -> Self {
+        let mut config = Self::new();
+        if let Some(model) = &file.model {
+            config = config.with_model(model);
+        }
+        for (name, model) in &file.per_namespace {
+            let Some(namespace) = Namespace::parse(name) else {
+                tracing::warn!(namespace = %name, "Ignoring embedding model for unknown namespace");
+                continue;
+            };
+            config = config.with_namespace_model(namespace, model);
+        }
+        config
+    }
+
+    /// Sets the global model.
+    ///
+    /// An empty name keeps the default model. Namespaces assigned the new
+    /// global model no longer need a separate index and are dropped.
+    #[must_use]
+    pub fn with_model(mut self, model: impl Into<String>) -> Self {
+        let model = model.into().trim().to_string();
+        if !model.is_empty() {
+            self.per_namespace
+                .retain(|_, namespace_model| !namespace_model.eq_ignore_ascii_case(&model));
+            self.model = model;
+        }
+        self
+    }
+
+    /// Returns the global model.
+    #[must_use]
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Embeds `namespace` with `model`.
+    #[must_use]
+    pub fn with_namespace_model(mut self, namespace: Namespace, model: impl Into<String>) -> Self {
+        let model = model.into().trim().to_string();
+        if model.is_empty() || model.eq_ignore_ascii_case(&self.model) {
+            self.per_namespace.remove(&namespace);
+        } else {
+            self.per_namespace.insert(namespace, model);
+        }
+        self
+    }
+
+    /// Returns the model that embeds `namespace`.
+    #[must_use]
+    pub fn model_for(&self, namespace: Namespace) -> &str {
+        self.per_namespace
+            .get(&namespace)
+            .map_or(self.model.as_str(), String::as_str)
+    }
+
+    /// Returns the namespaces with their own model.
+    pub fn namespace_models(&self) -> impl Iterator<Item = (Namespace, &str)> {
+        self.per_namespace
+            .iter()
+            .map(|(namespace, model)| (*namespace, model.as_str()))
+    }
+
+    /// Returns true if every namespace uses the global model.
+    #[must_use]
+    pub fn is_global_only(&self) -> bool {
+        self.per_namespace.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unspecified_namespaces_fall_back_to_global_model() {
+        let file: ConfigFileEmbedding = toml::from_str(
+            r#"
+            [per_namespace]
+            apis = "jina-embeddings-v2-base-code"
+            decisions = "all-MiniLM-L6-v2"
+            bogus = "bge-small-en-v1.5"
+            "#,
+        )
+        .unwrap();
+        let config = EmbeddingConfig::from_config_file(&file);
+
+        assert_eq!(
+            config.model_for(Namespace::Apis),
+            "jina-embeddings-v2-base-code"
+        );
+        assert_eq!(config.model_for(Namespace::Decisions), DEFAULT_MODEL);
+        assert_eq!(config.model_for(Namespace::Learnings), DEFAULT_MODEL);
+        assert_eq!(config.namespace_models().count(), 1);
+    }
+
+    #[test]
+    fn test_global_model() {
+        let file: ConfigFileEmbedding = toml::from_str(
+            r#"
+            model = "bge-small-en-v1.5"
+
+            [per_namespace]
+            apis = "jina-embeddings-v2-base-code"
+            decisions = "bge-small-en-v1.5"
+            "#,
+        )
+        .unwrap();
+        let config = EmbeddingConfig::from_config_file(&file);
+
+        assert_eq!(config.model(), "bge-small-en-v1.5");
+        assert_eq!(config.model_for(Namespace::Decisions), "bge-small-en-v1.5");
+        assert_eq!(config.model_for(Namespace::Learnings), "bge-small-en-v1.5");
+        assert_eq!(config.namespace_models().count(), 1);
+        assert_eq!(EmbeddingConfig::new().model(), DEFAULT_MODEL);
+    }
+}
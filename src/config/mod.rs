@@ -1,13 +1,30 @@
 //! Configuration management.
 
+mod embedding;
 mod features;
+mod namespace_rules;
+mod offline;
 mod org;
+mod set;
+mod validate;
 
+pub use embedding::{ConfigFileEmbedding, EmbeddingConfig};
 pub use features::FeatureFlags;
+pub use namespace_rules::{ConfigFileNamespaceRule, NamespaceRule, NamespaceRulesConfig};
+#[cfg(test)]
+pub(crate) use offline::with_offline_mode;
+pub use offline::{
+    EMBED_OFFLINE_ENV, OFFLINE_ENV, enable_offline_mode, ensure_online, is_embed_offline,
+    is_offline,
+};
 pub use org::{ConfigFileOrg, OrgBackendConfig, OrgConfig};
+pub use set::{ConfigChange, set_config_value};
+pub use validate::{ConfigProblem, validate_config_file, validate_config_str};
 
+use crate::storage::index::DomainScope;
 use serde::Deserialize;
 use std::borrow::Cow;
+use std::fmt;
 use std::path::{Path, PathBuf};
 
 /// Warns if a config file has world-readable permissions (SEC-M4).
@@ -63,7 +80,7 @@ fn warn_if_world_readable(_path: &Path) {
 /// Prevents `DoS` attacks from strings with many `${VAR}` patterns.
 const MAX_ENV_VAR_EXPANSIONS: usize = 100;
 
-fn expand_env_vars(input: &str) -> Cow<'_, str> {
+pub(crate) fn expand_env_vars(input: &str) -> Cow<'_, str> {
     // Fast path: no ${} pattern at all
     if !input.contains("${") {
         return Cow::Borrowed(input);
@@ -187,6 +204,28 @@ pub struct SubcogConfig {
     pub org: OrgConfig,
     /// Webhook configuration.
     pub webhooks: WebhooksConfig,
+    /// Desktop and email notifier configuration.
+    pub notifiers: NotifiersConfig,
+    /// Per-namespace capture validation rules.
+    pub namespace_rules: NamespaceRulesConfig,
+    /// Capture configuration (content normalization).
+    pub capture: CaptureConfig,
+    /// Auto-capture (pre-compact analyzer) configuration.
+    pub auto_capture: AutoCaptureConfig,
+    /// Search configuration (recall query cache).
+    pub search: SearchConfig,
+    /// Security configuration (redaction).
+    pub security: SecurityConfig,
+    /// Per-namespace embedding model selection.
+    pub embedding: EmbeddingConfig,
+    /// Attachment configuration (size limit).
+    pub attachments: AttachmentsConfig,
+    /// Garbage collection configuration (serve schedule).
+    pub gc: GcConfig,
+    /// Per-namespace retention periods in days (`0` = never expire).
+    pub retention: std::collections::HashMap<crate::models::Namespace, u32>,
+    /// MCP server configuration (HTTP bearer token).
+    pub mcp: McpConfig,
     /// Config files that were loaded (for debugging).
     pub config_sources: Vec<PathBuf>,
 }
@@ -194,7 +233,7 @@ pub struct SubcogConfig {
 /// LLM provider configuration.
 #[derive(Debug, Clone, Default)]
 pub struct LlmConfig {
-    /// Provider name: "anthropic", "openai", "ollama", "lmstudio".
+    /// Provider name: "anthropic", "openai", "gemini", "ollama", "lmstudio".
     pub provider: LlmProvider,
     /// Model name.
     pub model: Option<String>,
@@ -224,6 +263,10 @@ pub struct LlmConfig {
     pub error_budget_ratio: Option<f64>,
     /// Error budget window in seconds.
     pub error_budget_window_secs: Option<u64>,
+    /// Price per million prompt tokens, in US dollars.
+    pub prompt_price_per_mtok: Option<f64>,
+    /// Price per million completion tokens, in US dollars.
+    pub completion_price_per_mtok: Option<f64>,
 }
 
 impl LlmConfig {
@@ -264,6 +307,8 @@ pub fn from_config_file(file: &ConfigFileLlm) -> Self {
         config.latency_slo_ms = file.latency_slo_ms;
         config.error_budget_ratio = file.error_budget_ratio;
         config.error_budget_window_secs = file.error_budget_window_secs;
+        config.prompt_price_per_mtok = file.prompt_price_per_mtok;
+        config.completion_price_per_mtok = file.completion_price_per_mtok;
 
         config
     }
@@ -308,6 +353,10 @@ pub fn merge_from(&mut self, file: &ConfigFileLlm) {
         self.error_budget_window_secs = file
             .error_budget_window_secs
             .or(self.error_budget_window_secs);
+        self.prompt_price_per_mtok = file.prompt_price_per_mtok.or(self.prompt_price_per_mtok);
+        self.completion_price_per_mtok = file
+            .completion_price_per_mtok
+            .or(self.completion_price_per_mtok);
     }
 }
 
@@ -400,6 +449,8 @@ pub struct SearchIntentConfig {
     pub max_count: usize,
     /// Maximum tokens for injected memories.
     pub max_tokens: usize,
+    /// Whether the `UserPromptSubmit` hook retrieves memories to inject.
+    pub inject_memories: bool,
     /// Namespace weights configuration.
     pub weights: NamespaceWeightsConfig,
 }
@@ -576,6 +627,7 @@ fn default() -> Self {
             base_count: 5,
             max_count: 15,
             max_tokens: 4000,
+            inject_memories: false,
             weights: NamespaceWeightsConfig::with_defaults(),
         }
     }
@@ -615,6 +667,9 @@ pub fn with_env_overrides(mut self) -> Self {
         {
             self.min_confidence = conf.clamp(0.0, 1.0);
         }
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_INTENT_INJECT_MEMORIES") {
+            self.inject_memories = v.to_lowercase() == "true" || v == "1";
+        }
 
         self
     }
@@ -645,6 +700,9 @@ pub fn from_config_file(config: &ConfigFileSearchIntent) -> Self {
         if let Some(max_tokens) = config.max_tokens {
             settings.max_tokens = max_tokens;
         }
+        if let Some(inject_memories) = config.inject_memories {
+            settings.inject_memories = inject_memories;
+        }
         if let Some(ref weights) = config.weights {
             settings.weights.merge_from_file(weights);
         }
@@ -776,6 +834,8 @@ pub enum LlmProvider {
     Anthropic,
     /// `OpenAI` GPT.
     OpenAi,
+    /// Google Gemini.
+    Gemini,
     /// Ollama (local).
     Ollama,
     /// LM Studio (local).
@@ -790,6 +850,7 @@ impl LlmProvider {
     pub fn parse(s: &str) -> Self {
         match s.to_lowercase().as_str() {
             "openai" => Self::OpenAi,
+            "gemini" | "google" => Self::Gemini,
             "ollama" => Self::Ollama,
             "lmstudio" | "lm_studio" | "lm-studio" => Self::LmStudio,
             "none" | "disabled" | "" => Self::None,
@@ -797,6 +858,23 @@ pub fn parse(s: &str) -> Self {
         }
     }
 
+    /// Parses a provider string, returning `None` for unrecognized names.
+    ///
+    /// Unlike [`parse`](Self::parse), which falls back to Anthropic, this is
+    /// meant for validating untrusted input such as per-request overrides.
+    #[must_use]
+    pub fn try_parse(s: &str) -> Option<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "anthropic" => Some(Self::Anthropic),
+            "openai" => Some(Self::OpenAi),
+            "gemini" | "google" => Some(Self::Gemini),
+            "ollama" => Some(Self::Ollama),
+            "lmstudio" | "lm_studio" | "lm-studio" => Some(Self::LmStudio),
+            "none" | "disabled" => Some(Self::None),
+            _ => None,
+        }
+    }
+
     /// Returns `true` if this provider is configured (not `None`).
     #[must_use]
     pub const fn is_configured(&self) -> bool {
@@ -838,6 +916,35 @@ pub struct ConfigFile {
     /// Webhook configurations.
     #[serde(default)]
     pub webhooks: Vec<ConfigFileWebhook>,
+    /// Desktop and email notifier configurations.
+    #[serde(default)]
+    pub notifiers: Vec<ConfigFileNotifier>,
+    /// Per-namespace capture validation rules, keyed by namespace name.
+    pub namespace_rules: Option<std::collections::HashMap<String, ConfigFileNamespaceRule>>,
+    /// Capture configuration.
+    pub capture: Option<ConfigFileCapture>,
+    /// Auto-capture (pre-compact analyzer) configuration.
+    pub auto_capture: Option<ConfigFileAutoCapture>,
+    /// Search configuration.
+    pub search: Option<ConfigFileSearch>,
+    /// Security configuration.
+    pub security: Option<ConfigFileSecurity>,
+    /// Embedding model configuration.
+    pub embedding: Option<ConfigFileEmbedding>,
+    /// Attachment configuration.
+    pub attachments: Option<ConfigFileAttachments>,
+    /// Garbage collection configuration.
+    pub gc: Option<ConfigFileGc>,
+    /// Per-namespace retention periods in days, keyed by namespace name.
+    ///
+    /// ```toml
+    /// [retention]
+    /// progress = 30
+    /// decisions = 0   # never expire
+    /// ```
+    pub retention: Option<std::collections::HashMap<String, u32>>,
+    /// MCP server configuration.
+    pub mcp: Option<ConfigFileMcp>,
 }
 
 /// Features section in config file.
@@ -896,6 +1003,10 @@ pub struct ConfigFileLlm {
     pub error_budget_ratio: Option<f64>,
     /// Error budget window in seconds.
     pub error_budget_window_secs: Option<u64>,
+    /// Price per million prompt tokens, in US dollars.
+    pub prompt_price_per_mtok: Option<f64>,
+    /// Price per million completion tokens, in US dollars.
+    pub completion_price_per_mtok: Option<f64>,
 }
 
 /// Search intent section in config file.
@@ -915,6 +1026,8 @@ pub struct ConfigFileSearchIntent {
     pub max_count: Option<usize>,
     /// Maximum tokens for injected memories.
     pub max_tokens: Option<usize>,
+    /// Whether the `UserPromptSubmit` hook retrieves memories to inject.
+    pub inject_memories: Option<bool>,
     /// Namespace weights configuration.
     pub weights: Option<ConfigFileNamespaceWeights>,
 }
@@ -1146,59 +1259,853 @@ pub struct HookTemplatesConfig {
     pub pre_compact: Option<HookTemplateConfig>,
 }
 
-impl HookTemplatesConfig {
-    /// Creates config from a config file section.
-    pub fn from_config_file(file: &ConfigFileHookTemplates) -> Self {
+impl HookTemplatesConfig {
+    /// Creates config from a config file section.
+    pub fn from_config_file(file: &ConfigFileHookTemplates) -> Self {
+        Self {
+            session_start: file
+                .session_start
+                .as_ref()
+                .map(HookTemplateConfig::from_config_file),
+            user_prompt_submit: file
+                .user_prompt_submit
+                .as_ref()
+                .map(HookTemplateConfig::from_config_file),
+            post_tool_use: file
+                .post_tool_use
+                .as_ref()
+                .map(HookTemplateConfig::from_config_file),
+            pre_compact: file
+                .pre_compact
+                .as_ref()
+                .map(HookTemplateConfig::from_config_file),
+        }
+    }
+}
+
+/// Runtime configuration for a specific hook's template.
+#[derive(Debug, Clone)]
+pub struct HookTemplateConfig {
+    /// Name of the template to use.
+    pub template: String,
+    /// Specific version to use (None = latest).
+    pub version: Option<u32>,
+    /// Output format override.
+    pub format: Option<crate::models::OutputFormat>,
+}
+
+impl HookTemplateConfig {
+    /// Creates config from a config file section.
+    pub fn from_config_file(file: &ConfigFileHookTemplate) -> Self {
+        Self {
+            template: file.template.clone().unwrap_or_default(),
+            version: file.version,
+            format: file.format.as_deref().and_then(parse_output_format),
+        }
+    }
+}
+
+/// Parses output format from string.
+fn parse_output_format(s: &str) -> Option<crate::models::OutputFormat> {
+    match s.to_lowercase().as_str() {
+        "markdown" | "md" => Some(crate::models::OutputFormat::Markdown),
+        "json" => Some(crate::models::OutputFormat::Json),
+        "xml" => Some(crate::models::OutputFormat::Xml),
+        _ => None,
+    }
+}
+
+/// Parses a retention namespace key, warning when it is unknown.
+fn parse_retention_namespace(name: &str) -> Option<crate::models::Namespace> {
+    let namespace = crate::models::Namespace::parse(name);
+    if namespace.is_none() {
+        tracing::warn!(namespace = %name, "Ignoring unknown retention namespace");
+    }
+    namespace
+}
+
+/// Runtime auto-capture configuration for the pre-compact analyzer.
+///
+/// # Defaults
+///
+/// - `min_section_length`: 20 (shorter sections are ignored)
+/// - `fingerprint_length`: 50 (leading characters compared for near-duplicates)
+/// - `duplicate_prefix_chars`: 30 (candidates sharing more leading characters are duplicates)
+/// - `llm_confidence_threshold`: 0.6 (LLM classifications must exceed this)
+/// - `min_confidence`: 0.6 (candidates below this are never captured)
+/// - `use_llm`: false
+/// - `summarize_session`: false
+/// - `from_tools`: false (`PostToolUse` results are not captured)
+/// - `tool_patterns`: common test runners and benchmark tools
+///
+/// # Environment Variables
+///
+/// | Variable | Description | Default |
+/// |----------|-------------|---------|
+/// | `SUBCOG_AUTO_CAPTURE_MIN_SECTION_LENGTH` | Minimum section length | 20 |
+/// | `SUBCOG_AUTO_CAPTURE_FINGERPRINT_LENGTH` | Fingerprint length | 50 |
+/// | `SUBCOG_AUTO_CAPTURE_DUPLICATE_PREFIX_CHARS` | Duplicate prefix length | 30 |
+/// | `SUBCOG_AUTO_CAPTURE_LLM_CONFIDENCE` | LLM confidence threshold (0.0-1.0) | 0.6 |
+/// | `SUBCOG_AUTO_CAPTURE_MIN_CONFIDENCE` | Global confidence floor (0.0-1.0) | 0.6 |
+/// | `SUBCOG_AUTO_CAPTURE_USE_LLM` | Enable LLM classification | false |
+/// | `SUBCOG_AUTO_CAPTURE_SUMMARIZE_SESSION` | Capture an LLM session summary | false |
+/// | `SUBCOG_AUTO_CAPTURE_FROM_TOOLS` | Capture notable `PostToolUse` results | false |
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutoCaptureConfig {
+    /// Minimum section length in bytes to consider for capture.
+    pub min_section_length: usize,
+    /// Number of leading characters used as a candidate fingerprint.
+    pub fingerprint_length: usize,
+    /// Shared fingerprint prefix length above which candidates are duplicates.
+    pub duplicate_prefix_chars: usize,
+    /// Minimum LLM confidence for LLM-classified candidates (0.0-1.0).
+    pub llm_confidence_threshold: f32,
+    /// Global confidence floor below which candidates are never captured (0.0-1.0).
+    pub min_confidence: f32,
+    /// Whether to classify sections with no keyword match using the LLM.
+    pub use_llm: bool,
+    /// Whether to also capture one LLM-written session summary per compaction.
+    ///
+    /// Requires an LLM provider; failures never block per-section captures.
+    pub summarize_session: bool,
+    /// Whether the `PostToolUse` hook captures notable tool results
+    /// (failing test runs, benchmark numbers).
+    pub from_tools: bool,
+    /// Tool runs captured when `from_tools` is enabled; the first match wins.
+    pub tool_patterns: Vec<ToolCapturePattern>,
+}
+
+impl Default for AutoCaptureConfig {
+    fn default() -> Self {
+        Self {
+            min_section_length: 20,
+            fingerprint_length: 50,
+            duplicate_prefix_chars: 30,
+            llm_confidence_threshold: 0.6,
+            min_confidence: 0.6,
+            use_llm: false,
+            summarize_session: false,
+            from_tools: false,
+            tool_patterns: ToolCapturePattern::defaults(),
+        }
+    }
+}
+
+/// A tool run worth capturing from a `PostToolUse` payload.
+///
+/// Matches when the tool name equals `tool` (case-insensitive) and the tool's
+/// `command` input contains `command`.
+///
+/// # Example TOML
+///
+/// ```toml
+/// [[auto_capture.tool_patterns]]
+/// tool = "Bash"
+/// command = "make check"
+/// namespace = "testing"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ToolCapturePattern {
+    /// Tool name (default: `Bash`).
+    #[serde(default = "default_capture_tool")]
+    pub tool: String,
+    /// Substring of the command that identifies the run (e.g. `cargo test`).
+    pub command: String,
+    /// Namespace of the captured memory: `testing` captures failing runs,
+    /// `performance` captures benchmark results.
+    pub namespace: String,
+}
+
+fn default_capture_tool() -> String {
+    "Bash".to_string()
+}
+
+impl ToolCapturePattern {
+    /// Creates a pattern for a command run by the `Bash` tool.
+    #[must_use]
+    pub fn bash(command: impl Into<String>, namespace: impl Into<String>) -> Self {
+        Self {
+            tool: default_capture_tool(),
+            command: command.into(),
+            namespace: namespace.into(),
+        }
+    }
+
+    /// Returns the built-in patterns: benchmark tools, then test runners.
+    #[must_use]
+    pub fn defaults() -> Vec<Self> {
+        // Benchmarks first: `go test -bench` must not be taken for a test run
+        let benchmarks = [
+            "cargo bench",
+            "go test -bench",
+            "pytest --benchmark",
+            "hyperfine",
+            "npm run bench",
+        ];
+        let test_runners = [
+            "cargo test",
+            "cargo nextest",
+            "pytest",
+            "go test",
+            "npm test",
+            "npm run test",
+            "yarn test",
+            "pnpm test",
+            "jest",
+            "vitest",
+            "mvn test",
+            "gradle test",
+        ];
+        benchmarks
+            .into_iter()
+            .map(|command| Self::bash(command, "performance"))
+            .chain(
+                test_runners
+                    .into_iter()
+                    .map(|command| Self::bash(command, "testing")),
+            )
+            .collect()
+    }
+}
+
+impl AutoCaptureConfig {
+    /// Creates a new auto-capture configuration with defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates configuration from config file settings.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileAutoCapture) -> Self {
+        let mut config = Self::default();
+
+        if let Some(len) = file.min_section_length {
+            config.min_section_length = len;
+        }
+        if let Some(len) = file.fingerprint_length {
+            config.fingerprint_length = len.max(1);
+        }
+        if let Some(chars) = file.duplicate_prefix_chars {
+            config.duplicate_prefix_chars = chars;
+        }
+        if let Some(threshold) = file.llm_confidence_threshold {
+            config.llm_confidence_threshold = threshold.clamp(0.0, 1.0);
+        }
+        if let Some(floor) = file.min_confidence {
+            config.min_confidence = floor.clamp(0.0, 1.0);
+        }
+        if let Some(use_llm) = file.use_llm {
+            config.use_llm = use_llm;
+        }
+        if let Some(summarize) = file.summarize_session {
+            config.summarize_session = summarize;
+        }
+        if let Some(from_tools) = file.from_tools {
+            config.from_tools = from_tools;
+        }
+        if let Some(ref patterns) = file.tool_patterns {
+            config.tool_patterns.clone_from(patterns);
+        }
+
+        config
+    }
+
+    /// Loads configuration from environment variables.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::default().with_env_overrides()
+    }
+
+    /// Applies environment variable overrides.
+    #[must_use]
+    pub fn with_env_overrides(mut self) -> Self {
+        if let Ok(v) = std::env::var("SUBCOG_AUTO_CAPTURE_MIN_SECTION_LENGTH")
+            && let Ok(len) = v.parse::<usize>()
+        {
+            self.min_section_length = len;
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_AUTO_CAPTURE_FINGERPRINT_LENGTH")
+            && let Ok(len) = v.parse::<usize>()
+        {
+            self.fingerprint_length = len.max(1);
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_AUTO_CAPTURE_DUPLICATE_PREFIX_CHARS")
+            && let Ok(chars) = v.parse::<usize>()
+        {
+            self.duplicate_prefix_chars = chars;
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_AUTO_CAPTURE_LLM_CONFIDENCE")
+            && let Ok(threshold) = v.parse::<f32>()
+        {
+            self.llm_confidence_threshold = threshold.clamp(0.0, 1.0);
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_AUTO_CAPTURE_MIN_CONFIDENCE")
+            && let Ok(floor) = v.parse::<f32>()
+        {
+            self.min_confidence = floor.clamp(0.0, 1.0);
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_AUTO_CAPTURE_USE_LLM") {
+            self.use_llm = v.to_lowercase() == "true" || v == "1";
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_AUTO_CAPTURE_SUMMARIZE_SESSION") {
+            self.summarize_session = v.to_lowercase() == "true" || v == "1";
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_AUTO_CAPTURE_FROM_TOOLS") {
+            self.from_tools = v.to_lowercase() == "true" || v == "1";
+        }
+
+        self
+    }
+
+    /// Sets the global confidence floor.
+    #[must_use]
+    pub const fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Sets the minimum section length.
+    #[must_use]
+    pub const fn with_min_section_length(mut self, min_section_length: usize) -> Self {
+        self.min_section_length = min_section_length;
+        self
+    }
+
+    /// Sets whether LLM classification is used.
+    #[must_use]
+    pub const fn with_use_llm(mut self, use_llm: bool) -> Self {
+        self.use_llm = use_llm;
+        self
+    }
+
+    /// Sets whether a session summary is captured.
+    #[must_use]
+    pub const fn with_summarize_session(mut self, summarize_session: bool) -> Self {
+        self.summarize_session = summarize_session;
+        self
+    }
+
+    /// Sets whether notable `PostToolUse` results are captured.
+    #[must_use]
+    pub const fn with_from_tools(mut self, from_tools: bool) -> Self {
+        self.from_tools = from_tools;
+        self
+    }
+}
+
+/// Runtime search configuration.
+///
+/// # Defaults
+///
+/// - `cache`: false (every recall hits the index)
+/// - `cache_ttl_secs`: 60
+/// - `cache_max_entries`: 256
+/// - `field_weights`: content 1.0, tags 4.0, source 2.0
+/// - `rerank_candidates`: 30
+/// - `feedback_boost`: true
+/// - `diversity_lambda`: 0.7
+/// - `expand`: false
+/// - `expand_timeout_ms`: 2000
+/// - `snippet_marker`: `**`
+/// - `hybrid_alpha`: 0.5
+///
+/// # Environment Variables
+///
+/// | Variable | Description | Default |
+/// |----------|-------------|---------|
+/// | `SUBCOG_SEARCH_CACHE` | Enable the recall query cache | false |
+/// | `SUBCOG_SEARCH_CACHE_TTL_SECS` | Cached result lifetime in seconds | 60 |
+/// | `SUBCOG_SEARCH_CACHE_MAX_ENTRIES` | Maximum cached results | 256 |
+/// | `SUBCOG_SEARCH_RERANK_CANDIDATES` | Candidates retrieved for `recall --rerank` | 30 |
+/// | `SUBCOG_SEARCH_FEEDBACK_BOOST` | Rank with `recall --feedback` votes | true |
+/// | `SUBCOG_SEARCH_DIVERSITY_LAMBDA` | Relevance weight for `recall --diverse` | 0.7 |
+/// | `SUBCOG_SEARCH_EXPAND` | Expand recall queries with the LLM | false |
+/// | `SUBCOG_SEARCH_EXPAND_TIMEOUT_MS` | Time allowed for query expansion | 2000 |
+/// | `SUBCOG_SEARCH_SNIPPET_MARKER` | Marker around matched terms in snippets | `**` |
+/// | `SUBCOG_SEARCH_HYBRID_ALPHA` | Vector weight in hybrid ranking | 0.5 |
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchConfig {
+    /// Whether repeated recall queries are served from an in-memory cache.
+    ///
+    /// The cache is cleared on any capture, update or delete.
+    pub cache: bool,
+    /// Lifetime of a cached result in seconds.
+    pub cache_ttl_secs: u64,
+    /// Maximum number of cached results (oldest evicted first).
+    pub cache_max_entries: usize,
+    /// Per-field BM25 weights for text search.
+    pub field_weights: crate::models::FieldWeights,
+    /// Number of candidates retrieved for LLM reranking (`recall --rerank`).
+    ///
+    /// Never fewer than the number of results requested.
+    pub rerank_candidates: usize,
+    /// Whether memories voted up (or down) with `recall --feedback` rank
+    /// higher (or lower) for similar queries.
+    pub feedback_boost: bool,
+    /// Weight of relevance against dissimilarity for `recall --diverse`
+    /// (0.0 to 1.0; 1.0 keeps the relevance order).
+    pub diversity_lambda: f32,
+    /// Whether recall queries are expanded with LLM-suggested related terms
+    /// (always on with `recall --expand`).
+    pub expand: bool,
+    /// Time allowed for the query expansion LLM call, in milliseconds.
+    ///
+    /// On timeout the raw query is searched.
+    pub expand_timeout_ms: u64,
+    /// Marker wrapped around matched terms in recall hit snippets.
+    pub snippet_marker: String,
+    /// Weight of vector ranks against BM25 ranks in hybrid search
+    /// (0.0 is pure BM25, 1.0 pure vector).
+    pub hybrid_alpha: f32,
+}
+
+impl Default for SearchConfig {
+    fn default() -> Self {
+        Self {
+            cache: false,
+            cache_ttl_secs: 60,
+            cache_max_entries: 256,
+            field_weights: crate::models::FieldWeights::default(),
+            rerank_candidates: 30,
+            feedback_boost: true,
+            diversity_lambda: crate::services::DEFAULT_DIVERSITY_LAMBDA,
+            expand: false,
+            expand_timeout_ms: crate::services::DEFAULT_EXPANSION_TIMEOUT_MS,
+            snippet_marker: crate::services::DEFAULT_SNIPPET_MARKER.to_string(),
+            hybrid_alpha: crate::services::DEFAULT_HYBRID_ALPHA,
+        }
+    }
+}
+
+impl SearchConfig {
+    /// Creates configuration from config file settings.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileSearch) -> Self {
+        let mut config = Self::default();
+
+        if let Some(cache) = file.cache {
+            config.cache = cache;
+        }
+        if let Some(ttl) = file.cache_ttl_secs {
+            config.cache_ttl_secs = ttl;
+        }
+        if let Some(max) = file.cache_max_entries {
+            config.cache_max_entries = max.max(1);
+        }
+        if let Some(ref weights) = file.field_weights {
+            let defaults = config.field_weights;
+            config.field_weights = crate::models::FieldWeights::new(
+                weights.content.unwrap_or(defaults.content),
+                weights.tags.unwrap_or(defaults.tags),
+                weights.source.unwrap_or(defaults.source),
+            )
+            .sanitized();
+        }
+        if let Some(candidates) = file.rerank_candidates {
+            config.rerank_candidates = candidates.max(1);
+        }
+        if let Some(feedback_boost) = file.feedback_boost {
+            config.feedback_boost = feedback_boost;
+        }
+        if let Some(lambda) = file.diversity_lambda {
+            config.diversity_lambda = lambda.clamp(0.0, 1.0);
+        }
+        if let Some(expand) = file.expand {
+            config.expand = expand;
+        }
+        if let Some(timeout_ms) = file.expand_timeout_ms {
+            config.expand_timeout_ms = timeout_ms;
+        }
+        if let Some(ref marker) = file.snippet_marker {
+            config.snippet_marker.clone_from(marker);
+        }
+        if let Some(alpha) = file.hybrid_alpha {
+            config.hybrid_alpha = alpha.clamp(0.0, 1.0);
+        }
+
+        config
+    }
+
+    /// Loads configuration from environment variables.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::default().with_env_overrides()
+    }
+
+    /// Applies environment variable overrides.
+    #[must_use]
+    pub fn with_env_overrides(mut self) -> Self {
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_CACHE")
+            && let Some(cache) = parse_bool_env(&v)
+        {
+            self.cache = cache;
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_CACHE_TTL_SECS")
+            && let Ok(ttl) = v.parse::<u64>()
+        {
+            self.cache_ttl_secs = ttl;
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_CACHE_MAX_ENTRIES")
+            && let Ok(max) = v.parse::<usize>()
+        {
+            self.cache_max_entries = max.max(1);
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_RERANK_CANDIDATES")
+            && let Ok(candidates) = v.parse::<usize>()
+        {
+            self.rerank_candidates = candidates.max(1);
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_FEEDBACK_BOOST")
+            && let Some(feedback_boost) = parse_bool_env(&v)
+        {
+            self.feedback_boost = feedback_boost;
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_DIVERSITY_LAMBDA")
+            && let Ok(lambda) = v.parse::<f32>()
+        {
+            self.diversity_lambda = lambda.clamp(0.0, 1.0);
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_EXPAND")
+            && let Some(expand) = parse_bool_env(&v)
+        {
+            self.expand = expand;
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_EXPAND_TIMEOUT_MS")
+            && let Ok(timeout_ms) = v.parse::<u64>()
+        {
+            self.expand_timeout_ms = timeout_ms;
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_SNIPPET_MARKER") {
+            self.snippet_marker = v;
+        }
+
+        if let Ok(v) = std::env::var("SUBCOG_SEARCH_HYBRID_ALPHA")
+            && let Ok(alpha) = v.parse::<f32>()
+        {
+            self.hybrid_alpha = alpha.clamp(0.0, 1.0);
+        }
+
+        self
+    }
+
+    /// Sets whether the recall query cache is enabled.
+    #[must_use]
+    pub const fn with_cache(mut self, cache: bool) -> Self {
+        self.cache = cache;
+        self
+    }
+}
+
+/// How captured content is normalized before storing and hashing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ContentNormalization {
+    /// Store content as given.
+    Off,
+    /// Strip a leading BOM, convert CRLF/CR to LF, and trim trailing
+    /// whitespace on each line.
+    #[default]
+    Standard,
+    /// `Standard`, and also collapse runs of 3+ blank lines to 2.
+    CollapseBlankLines,
+}
+
+impl ContentNormalization {
+    /// Builds the mode from the `normalize` and `collapse_blank_lines`
+    /// settings; collapsing requires normalization.
+    #[must_use]
+    pub const fn from_flags(normalize: bool, collapse_blank_lines: bool) -> Self {
+        match (normalize, collapse_blank_lines) {
+            (false, _) => Self::Off,
+            (true, false) => Self::Standard,
+            (true, true) => Self::CollapseBlankLines,
+        }
+    }
+}
+
+/// Runtime capture configuration.
+///
+/// # Defaults
+///
+/// - `normalization`: `Standard` (blank lines are not collapsed)
+/// - `detect_language`: true
+/// - `default_language`: none
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaptureConfig {
+    /// Normalization applied to content before storing and hashing.
+    pub normalization: ContentNormalization,
+    /// WASM content transformers run before each capture.
+    pub plugins: CapturePluginsConfig,
+    /// Split large captures into several memories by default (`capture --split`).
+    pub auto_split: bool,
+    /// Content length, in characters, above which captures are split.
+    pub split_threshold_chars: usize,
+    /// Detect the language of captured content.
+    pub detect_language: bool,
+    /// ISO 639-1 language recorded when detection is off or not confident.
+    pub default_language: Option<String>,
+}
+
+impl Default for CaptureConfig {
+    fn default() -> Self {
+        Self {
+            normalization: ContentNormalization::default(),
+            plugins: CapturePluginsConfig::default(),
+            auto_split: false,
+            split_threshold_chars: crate::services::DEFAULT_SPLIT_THRESHOLD_CHARS,
+            detect_language: true,
+            default_language: None,
+        }
+    }
+}
+
+impl CaptureConfig {
+    /// Creates configuration from config file settings.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileCapture) -> Self {
+        let defaults = Self::default();
+        Self {
+            normalization: ContentNormalization::from_flags(
+                file.normalize
+                    .unwrap_or(defaults.normalization != ContentNormalization::Off),
+                file.collapse_blank_lines
+                    .unwrap_or(defaults.normalization == ContentNormalization::CollapseBlankLines),
+            ),
+            plugins: file
+                .plugins
+                .as_ref()
+                .map_or(defaults.plugins, CapturePluginsConfig::from_config_file),
+            auto_split: file.auto_split.unwrap_or(defaults.auto_split),
+            split_threshold_chars: file
+                .split_threshold_chars
+                .filter(|&chars| chars > 0)
+                .unwrap_or(defaults.split_threshold_chars),
+            detect_language: file.detect_language.unwrap_or(defaults.detect_language),
+            default_language: file
+                .default_language
+                .as_deref()
+                .and_then(crate::models::normalize_language),
+        }
+    }
+}
+
+/// Runtime configuration of WASM capture transformers.
+///
+/// # Defaults
+///
+/// - `modules`: none
+/// - `fuel`: 100,000,000
+/// - `max_memory_bytes`: 16 MB
+/// - `timeout_ms`: 1000
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CapturePluginsConfig {
+    /// WASM modules run in order on each capture's content.
+    pub modules: Vec<PathBuf>,
+    /// Fuel (roughly instructions) available to each call.
+    pub fuel: u64,
+    /// Largest linear memory a module may grow to, in bytes.
+    pub max_memory_bytes: usize,
+    /// Wall-clock time allowed for each call, in milliseconds.
+    pub timeout_ms: u64,
+}
+
+impl Default for CapturePluginsConfig {
+    fn default() -> Self {
+        Self {
+            modules: Vec::new(),
+            fuel: 100_000_000,
+            max_memory_bytes: 16 * 1024 * 1024,
+            timeout_ms: 1_000,
+        }
+    }
+}
+
+impl CapturePluginsConfig {
+    /// Creates configuration from config file settings.
+    ///
+    /// Module paths support `~` and environment variable expansion.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileCapturePlugins) -> Self {
+        let defaults = Self::default();
+        Self {
+            modules: file
+                .modules
+                .iter()
+                .flatten()
+                .map(|path| PathBuf::from(expand_config_path(path)))
+                .collect(),
+            fuel: file.fuel.unwrap_or(defaults.fuel),
+            max_memory_bytes: file.max_memory_bytes.unwrap_or(defaults.max_memory_bytes),
+            timeout_ms: file.timeout_ms.unwrap_or(defaults.timeout_ms),
+        }
+    }
+}
+
+/// Runtime security configuration.
+///
+/// # Defaults
+///
+/// - `redaction_placeholder`: `[REDACTED]`
+/// - `allow_skip`: false
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecurityConfig {
+    /// Text that replaces each redacted secret in stored content.
+    pub redaction_placeholder: String,
+    /// Whether captures may set `skip_security_check` to bypass the secrets
+    /// filter. When disabled, such captures are rejected.
+    pub allow_skip: bool,
+}
+
+impl Default for SecurityConfig {
+    fn default() -> Self {
+        Self {
+            redaction_placeholder: "[REDACTED]".to_string(),
+            allow_skip: false,
+        }
+    }
+}
+
+impl SecurityConfig {
+    /// Creates configuration from config file settings.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileSecurity) -> Self {
+        let defaults = Self::default();
+        Self {
+            redaction_placeholder: file
+                .redaction_placeholder
+                .clone()
+                .unwrap_or(defaults.redaction_placeholder),
+            allow_skip: file.allow_skip.unwrap_or(defaults.allow_skip),
+        }
+    }
+}
+
+/// Runtime attachment configuration.
+///
+/// # Defaults
+///
+/// - `max_bytes`: 10 MB
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AttachmentsConfig {
+    /// Largest file that may be attached to a memory, in bytes.
+    pub max_bytes: u64,
+}
+
+impl Default for AttachmentsConfig {
+    fn default() -> Self {
+        Self {
+            max_bytes: crate::storage::DEFAULT_MAX_ATTACHMENT_BYTES,
+        }
+    }
+}
+
+impl AttachmentsConfig {
+    /// Creates configuration from config file settings.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileAttachments) -> Self {
+        let defaults = Self::default();
+        Self {
+            max_bytes: file.max_bytes.unwrap_or(defaults.max_bytes),
+        }
+    }
+}
+
+/// Runtime garbage collection configuration.
+///
+/// # Defaults
+///
+/// - `interval_secs`: None (`subcog serve` does not run GC)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct GcConfig {
+    /// Seconds between unified GC runs in `subcog serve`.
+    pub interval_secs: Option<u64>,
+}
+
+impl GcConfig {
+    /// Creates configuration from config file settings.
+    ///
+    /// An interval of `0` or an invalid duration disables scheduled GC.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileGc) -> Self {
         Self {
-            session_start: file
-                .session_start
-                .as_ref()
-                .map(HookTemplateConfig::from_config_file),
-            user_prompt_submit: file
-                .user_prompt_submit
-                .as_ref()
-                .map(HookTemplateConfig::from_config_file),
-            post_tool_use: file
-                .post_tool_use
-                .as_ref()
-                .map(HookTemplateConfig::from_config_file),
-            pre_compact: file
-                .pre_compact
-                .as_ref()
-                .map(HookTemplateConfig::from_config_file),
+            interval_secs: file
+                .interval
+                .as_deref()
+                .and_then(parse_duration_to_seconds)
+                .filter(|&secs| secs > 0),
         }
     }
 }
 
-/// Runtime configuration for a specific hook's template.
-#[derive(Debug, Clone)]
-pub struct HookTemplateConfig {
-    /// Name of the template to use.
-    pub template: String,
-    /// Specific version to use (None = latest).
-    pub version: Option<u32>,
-    /// Output format override.
-    pub format: Option<crate::models::OutputFormat>,
+/// Runtime MCP server configuration.
+///
+/// # Defaults
+///
+/// - `auth_token`: None (the HTTP transport requires JWT authentication)
+///
+/// # Environment Variables
+///
+/// | Variable | Description |
+/// |----------|-------------|
+/// | `SUBCOG_MCP_TOKEN` | Bearer token for the HTTP and SSE transports |
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct McpConfig {
+    /// Static bearer token accepted by the HTTP and SSE transports.
+    pub auth_token: Option<String>,
 }
 
-impl HookTemplateConfig {
-    /// Creates config from a config file section.
-    pub fn from_config_file(file: &ConfigFileHookTemplate) -> Self {
+impl McpConfig {
+    /// Creates configuration from config file settings.
+    ///
+    /// Expands `${VAR}` references; an empty token is treated as unset.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileMcp) -> Self {
         Self {
-            template: file.template.clone().unwrap_or_default(),
-            version: file.version,
-            format: file.format.as_deref().and_then(parse_output_format),
+            auth_token: file
+                .auth_token
+                .as_deref()
+                .map(|token| expand_env_vars(token).trim().to_string())
+                .filter(|token| !token.is_empty()),
+        }
+    }
+
+    /// Applies environment variable overrides.
+    #[must_use]
+    pub fn with_env_overrides(mut self) -> Self {
+        if let Ok(token) = std::env::var("SUBCOG_MCP_TOKEN") {
+            let token = token.trim();
+            if !token.is_empty() {
+                self.auth_token = Some(token.to_string());
+            }
         }
+        self
     }
 }
 
-/// Parses output format from string.
-fn parse_output_format(s: &str) -> Option<crate::models::OutputFormat> {
-    match s.to_lowercase().as_str() {
-        "markdown" | "md" => Some(crate::models::OutputFormat::Markdown),
-        "json" => Some(crate::models::OutputFormat::Json),
-        "xml" => Some(crate::models::OutputFormat::Xml),
-        _ => None,
+/// Redacts the token so it does not end up in logs.
+impl fmt::Debug for McpConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("McpConfig")
+            .field(
+                "auth_token",
+                &self.auth_token.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
     }
 }
 
@@ -1502,6 +2409,219 @@ pub struct ConfigFileConsolidation {
     pub similarity_threshold: Option<f32>,
 }
 
+/// Auto-capture (pre-compact analyzer) section in config file.
+///
+/// # Example TOML
+///
+/// ```toml
+/// [auto_capture]
+/// min_section_length = 20
+/// fingerprint_length = 50
+/// duplicate_prefix_chars = 30
+/// llm_confidence_threshold = 0.6
+/// min_confidence = 0.6
+/// use_llm = false
+/// summarize_session = false
+/// from_tools = false
+///
+/// [[auto_capture.tool_patterns]]   # replaces the built-in patterns
+/// tool = "Bash"
+/// command = "cargo test"
+/// namespace = "testing"
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileAutoCapture {
+    /// Minimum section length in bytes to consider for capture.
+    pub min_section_length: Option<usize>,
+    /// Number of leading characters used as a candidate fingerprint.
+    pub fingerprint_length: Option<usize>,
+    /// Shared fingerprint prefix length above which candidates are duplicates.
+    pub duplicate_prefix_chars: Option<usize>,
+    /// Minimum LLM confidence for LLM-classified candidates (0.0-1.0).
+    pub llm_confidence_threshold: Option<f32>,
+    /// Global confidence floor below which candidates are never captured (0.0-1.0).
+    pub min_confidence: Option<f32>,
+    /// Whether to classify sections with no keyword match using the LLM.
+    pub use_llm: Option<bool>,
+    /// Whether to also capture one LLM-written session summary per compaction.
+    pub summarize_session: Option<bool>,
+    /// Whether the `PostToolUse` hook captures notable tool results.
+    pub from_tools: Option<bool>,
+    /// Tool runs to capture (replaces the built-in patterns).
+    pub tool_patterns: Option<Vec<ToolCapturePattern>>,
+}
+
+/// Search section in config file.
+///
+/// # Example TOML
+///
+/// ```toml
+/// [search]
+/// cache = true              # Cache repeated recall queries (off by default)
+/// cache_ttl_secs = 60
+/// cache_max_entries = 256
+/// feedback_boost = true     # Rank with `recall --feedback` votes
+/// diversity_lambda = 0.7    # Relevance vs. dissimilarity for `recall --diverse`
+/// expand = false            # Expand queries with LLM-suggested terms (`recall --expand`)
+/// expand_timeout_ms = 2000  # Time allowed for the expansion LLM call
+/// snippet_marker = "**"     # Wraps matched terms in recall snippets
+/// hybrid_alpha = 0.5        # Vector vs. BM25 weight in hybrid ranking (0.0-1.0)
+///
+/// [search.field_weights]    # BM25 weight per field (a tag match counts more)
+/// content = 1.0
+/// tags = 4.0
+/// source = 2.0
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileSearch {
+    /// Whether repeated recall queries are served from an in-memory cache.
+    pub cache: Option<bool>,
+    /// Lifetime of a cached result in seconds.
+    pub cache_ttl_secs: Option<u64>,
+    /// Maximum number of cached results.
+    pub cache_max_entries: Option<usize>,
+    /// Per-field BM25 weights.
+    pub field_weights: Option<ConfigFileFieldWeights>,
+    /// Number of candidates retrieved for LLM reranking.
+    pub rerank_candidates: Option<usize>,
+    /// Whether ranking uses `recall --feedback` votes.
+    pub feedback_boost: Option<bool>,
+    /// Relevance weight for diversity re-ranking (0.0 to 1.0).
+    pub diversity_lambda: Option<f32>,
+    /// Whether recall queries are expanded by the LLM.
+    pub expand: Option<bool>,
+    /// Time allowed for query expansion, in milliseconds.
+    pub expand_timeout_ms: Option<u64>,
+    /// Marker wrapped around matched terms in recall snippets.
+    pub snippet_marker: Option<String>,
+    /// Vector weight in hybrid ranking (0.0 to 1.0).
+    pub hybrid_alpha: Option<f32>,
+}
+
+/// Per-field BM25 weights in config file (`[search.field_weights]`).
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileFieldWeights {
+    /// Weight of the memory content.
+    pub content: Option<f32>,
+    /// Weight of the memory tags.
+    pub tags: Option<f32>,
+    /// Weight of the source reference.
+    pub source: Option<f32>,
+}
+
+/// Capture configuration section in config file.
+///
+/// # Example TOML
+///
+/// ```toml
+/// [capture]
+/// normalize = true              # Strip BOM, CRLF -> LF, trim trailing whitespace
+/// collapse_blank_lines = true   # Collapse 3+ blank lines to 2
+/// auto_split = true             # Split large captures (`capture --split`)
+/// split_threshold_chars = 2000  # Split content longer than this
+/// detect_language = true        # Record the content language
+/// default_language = "en"       # Language when detection is not confident
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileCapture {
+    /// Whether to normalize content before storing and hashing.
+    pub normalize: Option<bool>,
+    /// Whether normalization collapses runs of 3+ blank lines to 2.
+    pub collapse_blank_lines: Option<bool>,
+    /// WASM content transformers run before each capture.
+    pub plugins: Option<ConfigFileCapturePlugins>,
+    /// Whether large captures are split into several memories by default.
+    pub auto_split: Option<bool>,
+    /// Content length, in characters, above which captures are split.
+    pub split_threshold_chars: Option<usize>,
+    /// Whether the language of captured content is detected.
+    pub detect_language: Option<bool>,
+    /// Language recorded when detection is off or not confident.
+    pub default_language: Option<String>,
+}
+
+/// Capture plugins section in config file (requires the `wasm` feature).
+///
+/// # Example TOML
+///
+/// ```toml
+/// [capture.plugins]
+/// modules = ["~/.config/subcog/plugins/rewrite.wasm"]
+/// fuel = 100000000          # Instructions budget per call
+/// max_memory_bytes = 16777216
+/// timeout_ms = 1000
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileCapturePlugins {
+    /// Paths of WASM modules, run in order.
+    pub modules: Option<Vec<String>>,
+    /// Fuel (roughly instructions) available to each call.
+    pub fuel: Option<u64>,
+    /// Largest linear memory a module may grow to, in bytes.
+    pub max_memory_bytes: Option<usize>,
+    /// Wall-clock time allowed for each call, in milliseconds.
+    pub timeout_ms: Option<u64>,
+}
+
+/// Security configuration section in config file.
+///
+/// # Example TOML
+///
+/// ```toml
+/// [security]
+/// redaction_placeholder = "<secret>"   # Replaces each redacted secret
+/// allow_skip = false                    # Honor skip_security_check on capture
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileSecurity {
+    /// Text that replaces each redacted secret in stored content.
+    pub redaction_placeholder: Option<String>,
+    /// Whether captures may bypass the secrets filter.
+    pub allow_skip: Option<bool>,
+}
+
+/// Attachments section in config file.
+///
+/// # Example TOML
+///
+/// ```toml
+/// [attachments]
+/// max_bytes = 10485760   # Largest file accepted by `capture --attach`
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileAttachments {
+    /// Largest attachment in bytes.
+    pub max_bytes: Option<u64>,
+}
+
+/// Garbage collection section in config file.
+///
+/// # Example TOML
+///
+/// ```toml
+/// [gc]
+/// interval = "1d"   # Run `gc --all` from `subcog serve` once a day
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileGc {
+    /// Interval between scheduled GC runs (e.g. "6h", "1d").
+    pub interval: Option<String>,
+}
+
+/// MCP server section in config file.
+///
+/// # Example TOML
+///
+/// ```toml
+/// [mcp]
+/// auth_token = "${SUBCOG_MCP_TOKEN}"   # Require `Authorization: Bearer <token>` over HTTP
+/// ```
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileMcp {
+    /// Bearer token required by the HTTP and SSE transports.
+    pub auth_token: Option<String>,
+}
+
 /// Prompt customization section in config file.
 ///
 /// Allows users to add custom guidance to the LLM system prompts.
@@ -1560,6 +2680,13 @@ pub struct ConfigFileStorageBackend {
     /// Enable encryption at rest (COMP-CRIT-002).
     /// Defaults to true when not specified.
     pub encryption_enabled: Option<bool>,
+    /// Maximum search results recalled from this scope.
+    /// Only the requested limit applies if not specified.
+    pub max_results: Option<usize>,
+    /// Vector backend for PostgreSQL storage: `pgvector` keeps embeddings in
+    /// a standalone pgvector table (`vectors`). The index backend's
+    /// `memory_vectors` table is used if not specified.
+    pub vector_backend: Option<String>,
 }
 
 /// Runtime storage configuration.
@@ -1588,6 +2715,10 @@ pub struct StorageBackendConfig {
     /// Enable encryption at rest (COMP-CRIT-002).
     /// Defaults to true for security-by-default.
     pub encryption_enabled: bool,
+    /// Maximum search results recalled from this scope (no cap if `None`).
+    pub max_results: Option<usize>,
+    /// Store embeddings in a standalone pgvector table (PostgreSQL only).
+    pub pgvector: bool,
 }
 
 impl Default for StorageBackendConfig {
@@ -1599,6 +2730,8 @@ fn default() -> Self {
             pool_max_size: None,
             // COMP-CRIT-002: Enable encryption by default for security
             encryption_enabled: true,
+            max_results: None,
+            pgvector: false,
         }
     }
 }
@@ -1633,6 +2766,11 @@ pub fn parse(s: &str) -> Self {
     }
 }
 
+/// Returns true if a `vector_backend` setting selects pgvector.
+fn is_pgvector(vector_backend: Option<&str>) -> bool {
+    vector_backend.is_some_and(|backend| backend.eq_ignore_ascii_case("pgvector"))
+}
+
 /// Operation-level timeout configuration (CHAOS-HIGH-005).
 ///
 /// Provides configurable timeouts for different operation types to prevent
@@ -1650,6 +2788,7 @@ pub fn parse(s: &str) -> Self {
 /// | `SUBCOG_TIMEOUT_REDIS_MS` | Redis operation timeout | 5000 |
 /// | `SUBCOG_TIMEOUT_SQLITE_MS` | `SQLite` operation timeout | 5000 |
 /// | `SUBCOG_TIMEOUT_POSTGRES_MS` | PostgreSQL operation timeout | 10000 |
+/// | `SUBCOG_TIMEOUT_MCP_TOOL_MS` | MCP tool call timeout (cancels the call) | 300000 |
 #[derive(Debug, Clone)]
 pub struct OperationTimeoutConfig {
     /// Default timeout in milliseconds for all operations.
@@ -1671,6 +2810,9 @@ pub struct OperationTimeoutConfig {
     /// Timeout for entity extraction LLM operations in milliseconds.
     /// Default is 120 seconds (longer than general LLM timeout for complex content).
     pub entity_extraction_ms: u64,
+    /// Timeout for MCP tool calls in milliseconds, after which the call is cancelled.
+    /// Default is 5 minutes to leave room for multi-step LLM tools (consolidation).
+    pub mcp_tool_ms: u64,
 }
 
 impl Default for OperationTimeoutConfig {
@@ -1685,6 +2827,7 @@ fn default() -> Self {
             sqlite_ms: 5_000,
             postgres_ms: 10_000,
             entity_extraction_ms: 120_000, // 120s for complex LLM extraction
+            mcp_tool_ms: 300_000,          // 5 min for multi-step LLM tools
         }
     }
 }
@@ -1703,6 +2846,7 @@ pub const fn new() -> Self {
             sqlite_ms: 5_000,
             postgres_ms: 10_000,
             entity_extraction_ms: 120_000,
+            mcp_tool_ms: 300_000,
         }
     }
 
@@ -1760,6 +2904,11 @@ pub fn with_env_overrides(mut self) -> Self {
         {
             self.entity_extraction_ms = parsed.max(1000); // Minimum 1s for LLM calls
         }
+        if let Ok(v) = std::env::var("SUBCOG_TIMEOUT_MCP_TOOL_MS")
+            && let Ok(parsed) = v.parse::<u64>()
+        {
+            self.mcp_tool_ms = parsed.max(1000);
+        }
         self
     }
 
@@ -1775,6 +2924,7 @@ pub const fn get(&self, operation: OperationType) -> std::time::Duration {
             OperationType::Sqlite => self.sqlite_ms,
             OperationType::Postgres => self.postgres_ms,
             OperationType::EntityExtraction => self.entity_extraction_ms,
+            OperationType::McpTool => self.mcp_tool_ms,
             OperationType::Default => self.default_ms,
         };
         std::time::Duration::from_millis(ms)
@@ -1842,6 +2992,13 @@ pub const fn with_entity_extraction_ms(mut self, ms: u64) -> Self {
         self.entity_extraction_ms = ms;
         self
     }
+
+    /// Builder method to set MCP tool call timeout.
+    #[must_use]
+    pub const fn with_mcp_tool_ms(mut self, ms: u64) -> Self {
+        self.mcp_tool_ms = ms;
+        self
+    }
 }
 
 /// Operation types for timeout configuration.
@@ -1863,6 +3020,8 @@ pub enum OperationType {
     Postgres,
     /// Entity extraction LLM operations.
     EntityExtraction,
+    /// MCP tool calls.
+    McpTool,
     /// Default/fallback timeout.
     Default,
 }
@@ -1883,6 +3042,8 @@ pub fn from_config_file(file: &ConfigFileStorage) -> Self {
                 .connection_string
                 .clone_from(&project.connection_string);
             config.project.pool_max_size = project.pool_max_size;
+            config.project.max_results = project.max_results;
+            config.project.pgvector = is_pgvector(project.vector_backend.as_deref());
             // COMP-CRIT-002: Allow explicit override, default is true
             if let Some(encryption) = project.encryption_enabled {
                 config.project.encryption_enabled = encryption;
@@ -1899,6 +3060,8 @@ pub fn from_config_file(file: &ConfigFileStorage) -> Self {
                 .connection_string
                 .clone_from(&user.connection_string);
             config.user.pool_max_size = user.pool_max_size;
+            config.user.max_results = user.max_results;
+            config.user.pgvector = is_pgvector(user.vector_backend.as_deref());
             // COMP-CRIT-002: Allow explicit override, default is true
             if let Some(encryption) = user.encryption_enabled {
                 config.user.encryption_enabled = encryption;
@@ -1915,6 +3078,8 @@ pub fn from_config_file(file: &ConfigFileStorage) -> Self {
                 .connection_string
                 .clone_from(&org.connection_string);
             config.org.pool_max_size = org.pool_max_size;
+            config.org.max_results = org.max_results;
+            config.org.pgvector = is_pgvector(org.vector_backend.as_deref());
             // COMP-CRIT-002: Allow explicit override, default is true
             if let Some(encryption) = org.encryption_enabled {
                 config.org.encryption_enabled = encryption;
@@ -1923,6 +3088,16 @@ pub fn from_config_file(file: &ConfigFileStorage) -> Self {
 
         config
     }
+
+    /// Returns the `max_results` override for a domain scope, if any.
+    #[must_use]
+    pub const fn max_results_for(&self, scope: DomainScope) -> Option<usize> {
+        match scope {
+            DomainScope::Project => self.project.max_results,
+            DomainScope::User => self.user.max_results,
+            DomainScope::Org => self.org.max_results,
+        }
+    }
 }
 
 /// Runtime prompt configuration.
@@ -2220,6 +3395,17 @@ fn default() -> Self {
             context_templates: ContextTemplatesConfig::default(),
             org: OrgConfig::default(),
             webhooks: WebhooksConfig::default(),
+            notifiers: NotifiersConfig::default(),
+            namespace_rules: NamespaceRulesConfig::default(),
+            capture: CaptureConfig::default(),
+            auto_capture: AutoCaptureConfig::default(),
+            search: SearchConfig::default(),
+            security: SecurityConfig::default(),
+            embedding: EmbeddingConfig::default(),
+            attachments: AttachmentsConfig::default(),
+            gc: GcConfig::default(),
+            retention: std::collections::HashMap::new(),
+            mcp: McpConfig::default(),
             config_sources: Vec::new(),
         }
     }
@@ -2260,6 +3446,14 @@ pub fn load_from_file(path: &std::path::Path) -> crate::Result<Self> {
         Ok(config)
     }
 
+    /// Returns the default config file location, `~/.config/subcog/config.toml`.
+    ///
+    /// Returns `None` if the home directory cannot be determined.
+    #[must_use]
+    pub fn default_config_path() -> Option<PathBuf> {
+        directories::BaseDirs::new().map(|base_dirs| default_config_file(&base_dirs))
+    }
+
     /// Loads configuration from the default location.
     ///
     /// Config location: `~/.config/subcog/config.toml`
@@ -2277,9 +3471,6 @@ pub fn load_default() -> Self {
             return config;
         };
 
-        // Single config location: ~/.config/subcog/
-        let config_dir = base_dirs.home_dir().join(".config").join("subcog");
-
         // Use platform-appropriate data directory (not config directory)
         // - macOS: ~/Library/Application Support/subcog/
         // - Linux: ~/.local/share/subcog/
@@ -2291,7 +3482,7 @@ pub fn load_default() -> Self {
             ..Self::default()
         };
 
-        let config_path = config_dir.join("config.toml");
+        let config_path = default_config_file(&base_dirs);
         if apply_config_path(&mut config, &config_path) {
             config.config_sources.push(config_path);
         }
@@ -2389,8 +3580,11 @@ fn apply_env_overrides(&mut self) {
 
         self.search_intent = self.search_intent.clone().with_env_overrides();
         self.prompt = self.prompt.clone().with_env_overrides();
+        self.mcp = self.mcp.clone().with_env_overrides();
         self.consolidation = self.consolidation.clone().with_env_overrides();
         self.ttl = self.ttl.clone().with_env_overrides();
+        self.auto_capture = self.auto_capture.clone().with_env_overrides();
+        self.search = self.search.clone().with_env_overrides();
     }
 
     /// Applies a `ConfigFile` to the current configuration.
@@ -2451,6 +3645,45 @@ fn apply_config_file(&mut self, file: ConfigFile) {
         if !file.webhooks.is_empty() {
             self.webhooks = WebhooksConfig::from_config_file(file.webhooks);
         }
+
+        // Notifiers from [[notifiers]] array
+        if !file.notifiers.is_empty() {
+            self.notifiers = NotifiersConfig::from_config_file(file.notifiers);
+        }
+
+        if let Some(ref namespace_rules) = file.namespace_rules {
+            self.namespace_rules = NamespaceRulesConfig::from_config_file(namespace_rules);
+        }
+        if let Some(ref capture) = file.capture {
+            self.capture = CaptureConfig::from_config_file(capture);
+        }
+        if let Some(ref auto_capture) = file.auto_capture {
+            self.auto_capture = AutoCaptureConfig::from_config_file(auto_capture);
+        }
+        if let Some(ref search) = file.search {
+            self.search = SearchConfig::from_config_file(search);
+        }
+        if let Some(ref security) = file.security {
+            self.security = SecurityConfig::from_config_file(security);
+        }
+        if let Some(ref embedding) = file.embedding {
+            self.embedding = EmbeddingConfig::from_config_file(embedding);
+        }
+        if let Some(ref attachments) = file.attachments {
+            self.attachments = AttachmentsConfig::from_config_file(attachments);
+        }
+        if let Some(ref gc) = file.gc {
+            self.gc = GcConfig::from_config_file(gc);
+        }
+        if let Some(ref retention) = file.retention {
+            self.retention = retention
+                .iter()
+                .filter_map(|(name, &days)| parse_retention_namespace(name).map(|ns| (ns, days)))
+                .collect();
+        }
+        if let Some(ref mcp) = file.mcp {
+            self.mcp = McpConfig::from_config_file(mcp);
+        }
     }
 
     /// Sets the repository path.
@@ -2483,6 +3716,15 @@ fn apply_config_path(config: &mut SubcogConfig, path: &std::path::Path) -> bool
     }
 }
 
+/// Single config location: `~/.config/subcog/config.toml`.
+fn default_config_file(base_dirs: &directories::BaseDirs) -> PathBuf {
+    base_dirs
+        .home_dir()
+        .join(".config")
+        .join("subcog")
+        .join("config.toml")
+}
+
 fn load_config_file(path: &std::path::Path) -> crate::Result<ConfigFile> {
     // SEC-M4: Warn if config file is world-readable (may contain API keys)
     warn_if_world_readable(path);
@@ -2509,6 +3751,12 @@ pub struct Config {
     pub data_dir: Option<PathBuf>,
     /// Feature configuration.
     pub features: ServiceFeatures,
+    /// Per-namespace capture validation rules.
+    pub namespace_rules: NamespaceRulesConfig,
+    /// Capture configuration (content normalization).
+    pub capture: CaptureConfig,
+    /// Security configuration (redaction).
+    pub security: SecurityConfig,
 }
 
 /// Feature configuration for services.
@@ -2569,6 +3817,9 @@ fn from(subcog: SubcogConfig) -> Self {
                 auto_sync: false,
                 auto_extract_entities: subcog.features.auto_extract_entities,
             },
+            namespace_rules: subcog.namespace_rules,
+            capture: subcog.capture,
+            security: subcog.security,
         }
     }
 }
@@ -2692,6 +3943,105 @@ pub const fn is_empty(&self) -> bool {
     }
 }
 
+// =============================================================================
+// NOTIFIER CONFIGURATION
+// =============================================================================
+
+/// Notifier configuration from config.toml.
+///
+/// ```toml
+/// [[notifiers]]
+/// name = "important-captures"
+/// type = "desktop"
+/// events = ["captured"]
+/// namespaces = ["decisions", "blockers"]
+///
+/// [[notifiers]]
+/// name = "daily-digest"
+/// type = "email"
+/// events = ["captured"]
+///
+/// [notifiers.email]
+/// smtp_host = "smtp.example.com"
+/// username = "${SMTP_USER}"
+/// password = "${SMTP_PASSWORD}"
+/// from = "subcog@example.com"
+/// to = ["me@example.com"]
+/// digest_interval_secs = 86400
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfigFileNotifier {
+    /// Unique name for this notifier.
+    pub name: String,
+    /// Notifier type (`desktop` or `email`).
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Event types to notify about (empty = all events).
+    #[serde(default)]
+    pub events: Vec<String>,
+    /// Domain scopes to filter (empty = all scopes).
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    /// Namespaces of captured memories to filter (empty = all namespaces).
+    #[serde(default)]
+    pub namespaces: Vec<String>,
+    /// Whether this notifier is enabled.
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// SMTP settings (required for `email` notifiers).
+    pub email: Option<ConfigFileNotifierEmail>,
+}
+
+/// Email notifier SMTP settings from config.toml.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConfigFileNotifierEmail {
+    /// SMTP relay host.
+    pub smtp_host: String,
+    /// SMTP port (default: 587, STARTTLS).
+    #[serde(default = "default_smtp_port")]
+    pub smtp_port: u16,
+    /// SMTP username (supports `${ENV_VAR}` expansion).
+    pub username: Option<String>,
+    /// SMTP password (supports `${ENV_VAR}` expansion).
+    pub password: Option<String>,
+    /// Sender address.
+    pub from: String,
+    /// Recipient addresses.
+    pub to: Vec<String>,
+    /// Seconds between digests (default: 86400, daily).
+    #[serde(default = "default_digest_interval_secs")]
+    pub digest_interval_secs: u64,
+}
+
+const fn default_smtp_port() -> u16 {
+    587
+}
+
+const fn default_digest_interval_secs() -> u64 {
+    86_400
+}
+
+/// Runtime notifier configuration.
+#[derive(Debug, Clone, Default)]
+pub struct NotifiersConfig {
+    /// List of configured notifiers.
+    pub notifiers: Vec<ConfigFileNotifier>,
+}
+
+impl NotifiersConfig {
+    /// Creates notifiers config from parsed config file entries.
+    #[must_use]
+    pub const fn from_config_file(notifiers: Vec<ConfigFileNotifier>) -> Self {
+        Self { notifiers }
+    }
+
+    /// Returns true if no notifiers are configured.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.notifiers.is_empty()
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::float_cmp)]
 mod tests {
@@ -2952,6 +4302,133 @@ fn test_consolidation_config_from_config_file() {
         assert_eq!(namespaces.len(), 2);
     }
 
+    #[test]
+    fn test_auto_capture_config_from_config_file() {
+        let file: ConfigFileAutoCapture = toml::from_str(
+            r"
+            min_section_length = 40
+            fingerprint_length = 0
+            min_confidence = 1.5
+            ",
+        )
+        .unwrap();
+        let config = AutoCaptureConfig::from_config_file(&file);
+
+        assert_eq!(config.min_section_length, 40);
+        assert_eq!(config.fingerprint_length, 1);
+        assert!((config.min_confidence - 1.0).abs() < f32::EPSILON);
+        // Unset knobs keep the previous hardcoded values
+        assert_eq!(config.duplicate_prefix_chars, 30);
+        assert!((config.llm_confidence_threshold - 0.6).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_auto_capture_tool_patterns_from_config_file() {
+        let config = AutoCaptureConfig::default();
+        assert!(!config.from_tools);
+        assert!(
+            config
+                .tool_patterns
+                .contains(&ToolCapturePattern::bash("cargo test", "testing"))
+        );
+
+        let file: ConfigFileAutoCapture = toml::from_str(
+            r#"
+            from_tools = true
+
+            [[tool_patterns]]
+            command = "make check"
+            namespace = "testing"
+            "#,
+        )
+        .unwrap();
+        let config = AutoCaptureConfig::from_config_file(&file);
+
+        assert!(config.from_tools);
+        assert_eq!(
+            config.tool_patterns,
+            vec![ToolCapturePattern::bash("make check", "testing")]
+        );
+    }
+
+    #[test]
+    fn test_search_config_cache_disabled_by_default() {
+        assert!(!SearchConfig::default().cache);
+
+        let file: ConfigFileSearch = toml::from_str(
+            r"
+            cache = true
+            cache_max_entries = 0
+            ",
+        )
+        .unwrap();
+        let config = SearchConfig::from_config_file(&file);
+
+        assert!(config.cache);
+        assert_eq!(config.cache_max_entries, 1);
+        assert_eq!(config.cache_ttl_secs, 60);
+    }
+
+    #[test]
+    fn test_search_config_clamps_hybrid_alpha() {
+        assert!((SearchConfig::default().hybrid_alpha - 0.5).abs() < f32::EPSILON);
+
+        let file: ConfigFileSearch = toml::from_str("hybrid_alpha = 1.7").unwrap();
+        let config = SearchConfig::from_config_file(&file);
+        assert!((config.hybrid_alpha - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_storage_config_per_scope_max_results() {
+        let file: ConfigFileStorage = toml::from_str(
+            r#"
+            [user]
+            max_results = 50
+
+            [org]
+            max_results = 10
+            vector_backend = "pgvector"
+            "#,
+        )
+        .unwrap();
+        let config = StorageConfig::from_config_file(&file);
+
+        assert!(config.org.pgvector && !config.user.pgvector);
+        assert_eq!(config.max_results_for(DomainScope::User), Some(50));
+        assert_eq!(config.max_results_for(DomainScope::Org), Some(10));
+        assert_eq!(config.max_results_for(DomainScope::Project), None);
+    }
+
+    #[test]
+    fn test_notifiers_from_config_file() {
+        let file: ConfigFile = toml::from_str(
+            r#"
+            [[notifiers]]
+            name = "important"
+            type = "desktop"
+            namespaces = ["decisions"]
+
+            [[notifiers]]
+            name = "digest"
+            type = "email"
+
+            [notifiers.email]
+            smtp_host = "smtp.example.com"
+            from = "subcog@example.com"
+            to = ["me@example.com"]
+            "#,
+        )
+        .unwrap();
+        let config = NotifiersConfig::from_config_file(file.notifiers);
+
+        assert_eq!(config.notifiers.len(), 2);
+        assert_eq!(config.notifiers[0].kind, "desktop");
+        assert!(config.notifiers[0].enabled);
+        let email = config.notifiers[1].email.as_ref().unwrap();
+        assert_eq!(email.smtp_port, 587);
+        assert_eq!(email.digest_interval_secs, 86_400);
+    }
+
     #[test]
     fn test_consolidation_config_min_memories_enforcement() {
         let file = ConfigFileConsolidation {
@@ -3126,4 +4603,31 @@ fn test_ttl_config_explicit_no_expiration() {
         // Some(0) means explicitly set to never expire
         assert_eq!(config.get_ttl_seconds("tech-debt", "project"), Some(0));
     }
+
+    #[test]
+    fn test_mcp_config_from_config_file() {
+        let file: ConfigFile = toml::from_str("[mcp]\nauth_token = \" s3cret-token \"\n").unwrap();
+        let config = McpConfig::from_config_file(file.mcp.as_ref().unwrap());
+        assert_eq!(config.auth_token.as_deref(), Some("s3cret-token"));
+        assert!(!format!("{config:?}").contains("s3cret"));
+
+        let blank = ConfigFileMcp {
+            auth_token: Some("  ".to_string()),
+        };
+        assert_eq!(McpConfig::from_config_file(&blank), McpConfig::default());
+    }
+
+    #[test]
+    fn test_retention_from_config_file() {
+        use crate::models::Namespace;
+
+        let file: ConfigFile =
+            toml::from_str("[retention]\nprogress = 30\ndecisions = 0\nrecipes = 7\n").unwrap();
+        let mut config = SubcogConfig::default();
+        config.apply_config_file(file);
+
+        assert_eq!(config.retention.len(), 2);
+        assert_eq!(config.retention.get(&Namespace::Progress), Some(&30));
+        assert_eq!(config.retention.get(&Namespace::Decisions), Some(&0));
+    }
 }
@@ -0,0 +1,252 @@
+//! Per-namespace capture validation rules.
+//!
+//! Rules are opt-in: without a `[namespace_rules]` section every namespace
+//! accepts any non-empty content, exactly as before.
+//!
+//! # Example TOML
+//!
+//! ```toml
+//! [namespace_rules.decisions]
+//! min_length = 80
+//!
+//! [namespace_rules.progress]
+//! max_length = 500
+//!
+//! [namespace_rules.apis]
+//! required_tags = ["api"]
+//! ```
+
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::models::Namespace;
+use crate::{Error, Result};
+
+/// Rule section for a single namespace in the config file.
+#[derive(Debug, Clone, Deserialize, Default)]
+pub struct ConfigFileNamespaceRule {
+    /// Minimum content length in characters.
+    pub min_length: Option<usize>,
+    /// Maximum content length in characters.
+    pub max_length: Option<usize>,
+    /// Tags that every memory in the namespace must carry.
+    #[serde(default)]
+    pub required_tags: Vec<String>,
+}
+
+/// Runtime validation rule for a single namespace.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamespaceRule {
+    /// Minimum content length in characters (trimmed).
+    pub min_length: Option<usize>,
+    /// Maximum content length in characters (trimmed).
+    pub max_length: Option<usize>,
+    /// Tags that must be present (case-insensitive).
+    pub required_tags: Vec<String>,
+}
+
+impl NamespaceRule {
+    /// Creates a rule from a config file section.
+    #[must_use]
+    pub fn from_config_file(file: &ConfigFileNamespaceRule) -> Self {
+        Self {
+            min_length: file.min_length,
+            max_length: file.max_length,
+            required_tags: file
+                .required_tags
+                .iter()
+                .map(|t| t.trim().to_lowercase())
+                .filter(|t| !t.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Sets the minimum content length.
+    #[must_use]
+    pub const fn with_min_length(mut self, min_length: usize) -> Self {
+        self.min_length = Some(min_length);
+        self
+    }
+
+    /// Sets the maximum content length.
+    #[must_use]
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Adds a required tag.
+    #[must_use]
+    pub fn with_required_tag(mut self, tag: impl Into<String>) -> Self {
+        self.required_tags.push(tag.into().trim().to_lowercase());
+        self
+    }
+
+    /// Checks content and tags against this rule.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] naming the violated rule
+    /// (e.g. `decisions.min_length`).
+    pub fn check(&self, namespace: Namespace, content: &str, tags: &[String]) -> Result<()> {
+        let length = content.trim().chars().count();
+
+        if let Some(min) = self.min_length
+            && length < min
+        {
+            return Err(Error::InvalidInput(format!(
+                "Namespace rule {namespace}.min_length violated: \
+                 content must be at least {min} characters (got {length})"
+            )));
+        }
+        if let Some(max) = self.max_length
+            && length > max
+        {
+            return Err(Error::InvalidInput(format!(
+                "Namespace rule {namespace}.max_length violated: \
+                 content must be at most {max} characters (got {length})"
+            )));
+        }
+
+        let missing: Vec<&str> = self
+            .required_tags
+            .iter()
+            .filter(|required| !tags.iter().any(|t| t.eq_ignore_ascii_case(required)))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "Namespace rule {namespace}.required_tags violated: missing tag(s): {}",
+                missing.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// Per-namespace capture validation rules (runtime).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NamespaceRulesConfig {
+    rules: HashMap<Namespace, NamespaceRule>,
+}
+
+impl NamespaceRulesConfig {
+    /// Creates an empty rule set (no constraints).
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates rules from the `[namespace_rules]` config file section.
+    ///
+    /// Unknown namespace names are logged and ignored.
+    #[must_use]
+    pub fn from_config_file(file: &HashMap<String, ConfigFileNamespaceRule>) -> Self {
+        let mut rules = HashMap::new();
+        for (name, rule) in file {
+            let Some(namespace) = Namespace::parse(name) else {
+                tracing::warn!(namespace = %name, "Ignoring rules for unknown namespace");
+                continue;
+            };
+            rules.insert(namespace, NamespaceRule::from_config_file(rule));
+        }
+        Self { rules }
+    }
+
+    /// Sets the rule for a namespace.
+    #[must_use]
+    pub fn with_rule(mut self, namespace: Namespace, rule: NamespaceRule) -> Self {
+        self.rules.insert(namespace, rule);
+        self
+    }
+
+    /// Returns the rule for a namespace, if any.
+    #[must_use]
+    pub fn get(&self, namespace: Namespace) -> Option<&NamespaceRule> {
+        self.rules.get(&namespace)
+    }
+
+    /// Returns true if no rules are configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Checks content and tags against the rule for `namespace`.
+    ///
+    /// Namespaces without a rule always pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] naming the violated rule.
+    pub fn check(&self, namespace: Namespace, content: &str, tags: &[String]) -> Result<()> {
+        self.get(namespace)
+            .map_or(Ok(()), |rule| rule.check(namespace, content, tags))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_no_rules_accepts_everything() {
+        let rules = NamespaceRulesConfig::new();
+        assert!(rules.is_empty());
+        assert!(rules.check(Namespace::Decisions, "x", &[]).is_ok());
+    }
+
+    #[test]
+    fn test_min_and_max_length() {
+        let rule = NamespaceRule::default()
+            .with_min_length(5)
+            .with_max_length(10);
+        assert!(rule.check(Namespace::Progress, "  abc  ", &[]).is_err());
+        assert!(rule.check(Namespace::Progress, "abcdef", &[]).is_ok());
+        let err = rule
+            .check(Namespace::Progress, "abcdefghijkl", &[])
+            .unwrap_err();
+        assert!(err.to_string().contains("progress.max_length"));
+    }
+
+    #[test]
+    fn test_required_tags_case_insensitive() {
+        let rule = NamespaceRule::default().with_required_tag("API");
+        assert!(rule.check(Namespace::Apis, "content", &[]).is_err());
+        assert!(
+            rule.check(Namespace::Apis, "content", &["api".to_string()])
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_from_config_file() {
+        let file: HashMap<String, ConfigFileNamespaceRule> = toml::from_str(
+            r#"
+            [decisions]
+            min_length = 50
+
+            [tech_debt]
+            required_tags = ["debt"]
+
+            [bogus]
+            min_length = 1
+            "#,
+        )
+        .unwrap();
+        let rules = NamespaceRulesConfig::from_config_file(&file);
+
+        assert_eq!(
+            rules.get(Namespace::Decisions).and_then(|r| r.min_length),
+            Some(50)
+        );
+        assert_eq!(
+            rules
+                .get(Namespace::TechDebt)
+                .map(|r| r.required_tags.clone()),
+            Some(vec!["debt".to_string()])
+        );
+        assert!(rules.get(Namespace::Patterns).is_none());
+    }
+}
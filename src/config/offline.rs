@@ -0,0 +1,111 @@
+//! Process-wide offline mode.
+//!
+//! Offline mode guarantees subcog never reaches the network: LLM requests,
+//! embedding model loading (which may download from Hugging Face), sync and
+//! webhook delivery all fail with a clear error instead. Features that use
+//! these opportunistically (hook LLM analysis, vector search) degrade to
+//! their local fallbacks.
+//!
+//! It is enabled for the whole process by the `--offline` flag
+//! ([`enable_offline_mode`]) or the `SUBCOG_OFFLINE` environment variable,
+//! and takes precedence over individual feature flags.
+//!
+//! `SUBCOG_EMBED_OFFLINE` is the narrower variant for machines that have the
+//! embedding model cached but should never download one: the model is loaded
+//! from the local cache only, and a missing model fails at once instead of
+//! waiting on the network. Everything else keeps its network access.
+
+use super::parse_bool_env;
+use crate::{Error, Result};
+use std::sync::LazyLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Environment variable enabling offline mode.
+pub const OFFLINE_ENV: &str = "SUBCOG_OFFLINE";
+
+/// Environment variable restricting embedding models to the local cache.
+pub const EMBED_OFFLINE_ENV: &str = "SUBCOG_EMBED_OFFLINE";
+
+static OFFLINE: AtomicBool = AtomicBool::new(false);
+
+static ENV_OFFLINE: LazyLock<bool> = LazyLock::new(|| {
+    std::env::var(OFFLINE_ENV)
+        .ok()
+        .and_then(|v| parse_bool_env(&v))
+        .unwrap_or(false)
+});
+
+static ENV_EMBED_OFFLINE: LazyLock<bool> = LazyLock::new(|| {
+    std::env::var(EMBED_OFFLINE_ENV)
+        .ok()
+        .and_then(|v| parse_bool_env(&v))
+        .unwrap_or(false)
+});
+
+#[cfg(test)]
+thread_local! {
+    /// Per-thread override so tests can exercise offline mode without
+    /// affecting tests running concurrently.
+    static TEST_OFFLINE: std::cell::Cell<bool> = const { std::cell::Cell::new(false) };
+}
+
+/// Enables offline mode for the rest of the process.
+pub fn enable_offline_mode() {
+    OFFLINE.store(true, Ordering::SeqCst);
+}
+
+/// Returns whether offline mode is enabled.
+#[must_use]
+pub fn is_offline() -> bool {
+    #[cfg(test)]
+    if TEST_OFFLINE.with(std::cell::Cell::get) {
+        return true;
+    }
+    OFFLINE.load(Ordering::SeqCst) || *ENV_OFFLINE
+}
+
+/// Fails if offline mode is enabled.
+///
+/// Call before any operation that would reach the network.
+///
+/// # Errors
+///
+/// Returns [`Error::OperationFailed`] naming `operation` when offline.
+pub fn ensure_online(operation: &str) -> Result<()> {
+    if is_offline() {
+        return Err(Error::OperationFailed {
+            operation: operation.to_string(),
+            cause: format!(
+                "network access is disabled in offline mode (--offline / {OFFLINE_ENV})"
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Returns whether embedding models may only be loaded from the local cache.
+#[must_use]
+pub fn is_embed_offline() -> bool {
+    *ENV_EMBED_OFFLINE
+}
+
+/// Runs `f` with offline mode enabled on the current thread.
+#[cfg(test)]
+pub(crate) fn with_offline_mode<T>(f: impl FnOnce() -> T) -> T {
+    TEST_OFFLINE.with(|offline| offline.set(true));
+    let result = f();
+    TEST_OFFLINE.with(|offline| offline.set(false));
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ensure_online_errors_when_offline() {
+        let err = with_offline_mode(|| ensure_online("llm_request")).unwrap_err();
+        assert!(err.to_string().contains("offline mode"));
+        assert!(err.to_string().contains("llm_request"));
+    }
+}
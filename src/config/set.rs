@@ -0,0 +1,234 @@
+//! Config file editing (`subcog config --set`).
+//!
+//! Sets a single dotted key (`llm.provider`, `features.audit_log`,
+//! `max_results`) in a config file, keeping its comments and layout. A change
+//! is only written if it passes the same strict checks as
+//! `subcog config validate`, so unknown keys and values of the wrong type are
+//! rejected instead of being silently ignored on the next load.
+
+use std::fmt;
+use std::path::Path;
+
+use toml_edit::{DocumentMut, Item, Table, TableLike, Value};
+
+use super::validate::{ConfigProblem, validate_config_str};
+use crate::{Error, Result};
+
+/// A value written by [`set_config_value`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigChange {
+    /// Dotted key that was set.
+    pub key: String,
+    /// New value, rendered as TOML.
+    pub value: String,
+}
+
+impl fmt::Display for ConfigChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.key, self.value)
+    }
+}
+
+/// Applies a `KEY=VALUE` assignment to the config file at `path`.
+///
+/// The file (and its parent directories) is created if absent. `VALUE` is
+/// read as a TOML value (`true`, `10`, `0.5`, `"text"`); bare words are
+/// taken as strings, as are values that only validate as strings.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidInput`] if the assignment is malformed, the key is
+/// unknown, the value does not parse for the key's type, or the existing
+/// file is not valid TOML. Returns an error if the file cannot be read or
+/// written.
+pub fn set_config_value(path: &Path, assignment: &str) -> Result<ConfigChange> {
+    let (key, raw) = assignment
+        .split_once('=')
+        .map(|(key, raw)| (key.trim(), raw.trim()))
+        .ok_or_else(|| Error::InvalidInput(format!("Expected KEY=VALUE, got '{assignment}'")))?;
+    let segments: Vec<&str> = key.split('.').map(str::trim).collect();
+    if segments.iter().any(|segment| segment.is_empty()) {
+        return Err(Error::InvalidInput(format!("Invalid config key: '{key}'")));
+    }
+
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => {
+            return Err(Error::OperationFailed {
+                operation: "read_config_file".to_string(),
+                cause: format!("{}: {e}", path.display()),
+            });
+        },
+    };
+    let document: DocumentMut = contents
+        .parse()
+        .map_err(|e| Error::InvalidInput(format!("{}: {e}", path.display())))?;
+
+    let existing = validate_config_str(&contents);
+    if existing.iter().any(|problem| problem.key.is_empty()) {
+        return Err(Error::InvalidInput(format!(
+            "{} has errors; run `subcog config validate` and fix them first",
+            path.display()
+        )));
+    }
+
+    let mut first_problems = None;
+    for value in candidate_values(raw) {
+        let mut updated = document.clone();
+        set_value(updated.as_table_mut(), &segments, value.clone())?;
+        let updated = updated.to_string();
+
+        let problems: Vec<ConfigProblem> = validate_config_str(&updated)
+            .into_iter()
+            .filter(|problem| !existing.contains(problem))
+            .collect();
+        if problems.is_empty() {
+            write_config(path, &updated)?;
+            return Ok(ConfigChange {
+                key: segments.join("."),
+                value: value.to_string().trim().to_string(),
+            });
+        }
+        if first_problems.is_none() {
+            first_problems = Some(problems);
+        }
+    }
+
+    let problems = first_problems.unwrap_or_default();
+    Err(Error::InvalidInput(format!(
+        "Cannot set {key}: {}",
+        problems
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("; ")
+    )))
+}
+
+/// Returns the values to try for `raw`, most specific first.
+///
+/// A non-string TOML literal is also tried as a string, so `llm.model=4`
+/// works for a string key.
+fn candidate_values(raw: &str) -> Vec<Value> {
+    match raw.parse::<Value>() {
+        Ok(value) if value.is_str() => vec![value],
+        Ok(value) => vec![value, Value::from(raw)],
+        Err(_) => vec![Value::from(raw)],
+    }
+}
+
+/// Sets `segments` (a dotted key) to `value`, creating parent tables.
+///
+/// An existing value keeps its surrounding whitespace and trailing comment.
+fn set_value(mut table: &mut dyn TableLike, segments: &[&str], mut value: Value) -> Result<()> {
+    let Some((last, parents)) = segments.split_last() else {
+        return Ok(());
+    };
+
+    for (depth, segment) in parents.iter().enumerate() {
+        let item = table.entry(segment).or_insert_with(|| {
+            let mut child = Table::new();
+            child.set_implicit(true);
+            Item::Table(child)
+        });
+        table = item.as_table_like_mut().ok_or_else(|| {
+            Error::InvalidInput(format!("'{}' is not a table", segments[..=depth].join(".")))
+        })?;
+    }
+    let item = table.entry(last).or_insert(Item::None);
+    match item.as_value() {
+        Some(existing) => *value.decor_mut() = existing.decor().clone(),
+        None => value.decor_mut().clear(),
+    }
+    *item = Item::Value(value);
+    Ok(())
+}
+
+/// Writes `contents` to `path`, creating parent directories.
+fn write_config(path: &Path, contents: &str) -> Result<()> {
+    let write_error = |e: std::io::Error| Error::OperationFailed {
+        operation: "write_config_file".to_string(),
+        cause: format!("{}: {e}", path.display()),
+    };
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        std::fs::create_dir_all(parent).map_err(write_error)?;
+    }
+    std::fs::write(path, contents).map_err(write_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_set_creates_missing_file() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("subcog").join("config.toml");
+
+        let change = set_config_value(&path, "features.audit_log=true").unwrap();
+        assert_eq!(change.to_string(), "features.audit_log = true");
+
+        let change = set_config_value(&path, "llm.provider = anthropic").unwrap();
+        assert_eq!(change.value, "\"anthropic\"");
+        set_config_value(&path, "max_results=25").unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(validate_config_str(&contents).is_empty());
+        let file: toml::Table = toml::from_str(&contents).unwrap();
+        assert_eq!(file["features"]["audit_log"].as_bool(), Some(true));
+        assert_eq!(file["llm"]["provider"].as_str(), Some("anthropic"));
+        assert_eq!(file["max_results"].as_integer(), Some(25));
+    }
+
+    #[test]
+    fn test_set_preserves_comments_and_toggles_bool() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "# Personal settings\n[features]\n# Keep an audit trail\naudit_log = true\n",
+        )
+        .unwrap();
+
+        set_config_value(&path, "features.audit_log=false").unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(contents.starts_with("# Personal settings\n[features]\n# Keep an audit trail\n"));
+        assert!(contents.contains("audit_log = false"));
+    }
+
+    #[test]
+    fn test_set_rejects_unknown_keys_and_bad_values() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("config.toml");
+
+        for assignment in [
+            "features.no_such_flag=true",
+            "no_such_section.key=1",
+            "features.audit_log=sometimes",
+            "max_results=many",
+            "features",
+            "llm..provider=ollama",
+        ] {
+            assert!(
+                matches!(
+                    set_config_value(&path, assignment),
+                    Err(Error::InvalidInput(_))
+                ),
+                "{assignment} should be rejected"
+            );
+        }
+        assert!(!path.exists());
+
+        set_config_value(&path, "max_results=10").unwrap();
+        assert!(matches!(
+            set_config_value(&path, "max_results.limit=1"),
+            Err(Error::InvalidInput(message)) if message == "'max_results' is not a table"
+        ));
+    }
+}
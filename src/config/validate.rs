@@ -0,0 +1,659 @@
+//! Config file validation (`subcog config validate`).
+//!
+//! Loading a config is lenient: unknown keys are ignored and unrecognized
+//! values fall back to defaults with a warning. Validation is strict instead
+//! and reports every problem it finds, so a CI job can reject a bad config
+//! before it ships:
+//!
+//! - TOML syntax and value types
+//! - unknown keys
+//! - enum values (search mode, providers, backends, formats, namespaces)
+//! - numeric ranges (confidences and ratios within 0.0-1.0, counts)
+//! - TTL durations
+//! - regex patterns in the log filter
+//! - `${VAR}` environment references
+//!
+//! Validation only reads the file and the environment; it never opens a
+//! repository, an index, or a network connection.
+
+use std::fmt;
+use std::path::Path;
+
+use toml::{Table, Value};
+use tracing_subscriber::EnvFilter;
+
+use super::{
+    ConfigFile, ConfigFileStorageBackend, LlmProvider, parse_duration_to_seconds,
+    parse_output_format,
+};
+use crate::models::Namespace;
+use crate::observability::OtlpProtocol;
+use crate::{Error, Result};
+
+/// A problem found in a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// Dotted path of the offending key (empty for file-level problems).
+    pub key: String,
+    /// What is wrong with it.
+    pub message: String,
+}
+
+impl ConfigProblem {
+    fn new(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.key.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.key, self.message)
+        }
+    }
+}
+
+/// Validates the config file at `path`.
+///
+/// Returns the problems found; an empty list means the file is valid.
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read.
+pub fn validate_config_file(path: &Path) -> Result<Vec<ConfigProblem>> {
+    let contents = std::fs::read_to_string(path).map_err(|e| Error::OperationFailed {
+        operation: "read_config_file".to_string(),
+        cause: format!("{}: {e}", path.display()),
+    })?;
+    Ok(validate_config_str(&contents))
+}
+
+/// Validates config file contents.
+///
+/// Syntax and type errors stop validation, since the remaining checks need a
+/// parsed file; all other problems are collected.
+#[must_use]
+pub fn validate_config_str(contents: &str) -> Vec<ConfigProblem> {
+    let raw: Table = match toml::from_str(contents) {
+        Ok(raw) => raw,
+        Err(e) => return vec![parse_problem(contents, &e)],
+    };
+    let mut problems = Vec::new();
+    // Keys serde ignores while deserializing are the unknown ones
+    let file: ConfigFile = match toml::Deserializer::parse(contents).and_then(|de| {
+        serde_ignored::deserialize(de, |path| push_unknown_key(&path, &mut problems))
+    }) {
+        Ok(file) => file,
+        Err(e) => return vec![parse_problem(contents, &e)],
+    };
+
+    check_core_values(&file, &mut problems);
+    check_ranges(&file, &mut problems);
+    check_observability(&file, &mut problems);
+    check_storage(&file, &mut problems);
+    check_namespaces(&file, &mut problems);
+    check_durations(&file, &mut problems);
+    check_env_references(&Value::Table(raw), "", &mut problems);
+    problems
+}
+
+/// Converts a TOML parse error into a problem located by line.
+fn parse_problem(contents: &str, error: &toml::de::Error) -> ConfigProblem {
+    let message = error.message().trim().to_string();
+    let Some(span) = error.span() else {
+        return ConfigProblem::new("", message);
+    };
+    let preceding = contents.get(..span.start).unwrap_or(contents);
+    let line = preceding.matches('\n').count() + 1;
+    ConfigProblem::new("", format!("line {line}: {message}"))
+}
+
+/// Reports the key at `path`, which the config file does not define.
+fn push_unknown_key(path: &serde_ignored::Path<'_>, problems: &mut Vec<ConfigProblem>) {
+    problems.push(ConfigProblem::new(key_path(path), "unknown key"));
+}
+
+/// Returns the dotted key of a deserializer path (`webhooks[0].url`).
+fn key_path(path: &serde_ignored::Path<'_>) -> String {
+    match path {
+        serde_ignored::Path::Root => String::new(),
+        serde_ignored::Path::Seq { parent, index } => format!("{}[{index}]", key_path(parent)),
+        serde_ignored::Path::Map { parent, key } => join_key(&key_path(parent), key),
+        serde_ignored::Path::Some { parent }
+        | serde_ignored::Path::NewtypeStruct { parent }
+        | serde_ignored::Path::NewtypeVariant { parent } => key_path(parent),
+    }
+}
+
+/// Checks top-level, LLM, context template, org, and webhook values.
+fn check_core_values(file: &ConfigFile, problems: &mut Vec<ConfigProblem>) {
+    if let Some(mode) = &file.default_search_mode {
+        check_enum(
+            "default_search_mode",
+            mode,
+            matches!(mode.to_lowercase().as_str(), "text" | "vector" | "hybrid"),
+            "text, vector, hybrid",
+            problems,
+        );
+    }
+    if let Some(provider) = file.llm.as_ref().and_then(|llm| llm.provider.as_deref()) {
+        check_enum(
+            "llm.provider",
+            provider,
+            LlmProvider::try_parse(provider).is_some(),
+            "anthropic, openai, gemini, ollama, lmstudio, none",
+            problems,
+        );
+    }
+
+    if let Some(templates) = &file.context_templates {
+        let hooks = templates.hooks.as_ref();
+        let formats = [
+            ("default_format", templates.default_format.as_deref()),
+            (
+                "hooks.session_start.format",
+                hooks.and_then(|h| h.session_start.as_ref()?.format.as_deref()),
+            ),
+            (
+                "hooks.user_prompt_submit.format",
+                hooks.and_then(|h| h.user_prompt_submit.as_ref()?.format.as_deref()),
+            ),
+            (
+                "hooks.post_tool_use.format",
+                hooks.and_then(|h| h.post_tool_use.as_ref()?.format.as_deref()),
+            ),
+            (
+                "hooks.pre_compact.format",
+                hooks.and_then(|h| h.pre_compact.as_ref()?.format.as_deref()),
+            ),
+        ];
+        for (key, format) in formats.into_iter().filter_map(|(key, f)| Some((key, f?))) {
+            check_enum(
+                &format!("context_templates.{key}"),
+                format,
+                parse_output_format(format).is_some(),
+                "markdown, json, xml",
+                problems,
+            );
+        }
+    }
+
+    if let Some(backend) = file.org.as_ref().and_then(|org| org.backend.as_deref()) {
+        check_enum(
+            "org.backend",
+            backend,
+            matches!(
+                backend,
+                "sqlite" | "sqlite3" | "postgresql" | "postgres" | "pg" | "none"
+            ),
+            "sqlite, postgresql, none",
+            problems,
+        );
+    }
+
+    for (i, webhook) in file.webhooks.iter().enumerate() {
+        if let Some(format) = &webhook.format {
+            check_enum(
+                &format!("webhooks[{i}].format"),
+                format,
+                matches!(
+                    format.to_lowercase().as_str(),
+                    "default" | "slack" | "discord"
+                ),
+                "default, slack, discord",
+                problems,
+            );
+        }
+    }
+}
+
+/// Checks ratios that must lie within 0.0-1.0 and count bounds.
+fn check_ranges(file: &ConfigFile, problems: &mut Vec<ConfigProblem>) {
+    let search_intent = file.search_intent.as_ref();
+    let auto_capture = file.auto_capture.as_ref();
+    let search = file.search.as_ref();
+    check_ratio(
+        "search_intent.min_confidence",
+        search_intent.and_then(|s| s.min_confidence),
+        problems,
+    );
+    check_ratio(
+        "llm.error_budget_ratio",
+        file.llm.as_ref().and_then(|llm| llm.error_budget_ratio),
+        problems,
+    );
+    check_ratio(
+        "observability.tracing.sample_ratio",
+        file.observability
+            .as_ref()
+            .and_then(|o| o.tracing.as_ref()?.sample_ratio),
+        problems,
+    );
+    check_ratio(
+        "consolidation.similarity_threshold",
+        file.consolidation
+            .as_ref()
+            .and_then(|c| c.similarity_threshold),
+        problems,
+    );
+    check_ratio(
+        "auto_capture.llm_confidence_threshold",
+        auto_capture.and_then(|a| a.llm_confidence_threshold),
+        problems,
+    );
+    check_ratio(
+        "auto_capture.min_confidence",
+        auto_capture.and_then(|a| a.min_confidence),
+        problems,
+    );
+    check_ratio(
+        "search.diversity_lambda",
+        search.and_then(|s| s.diversity_lambda),
+        problems,
+    );
+    check_ratio(
+        "search.hybrid_alpha",
+        search.and_then(|s| s.hybrid_alpha),
+        problems,
+    );
+
+    if file.max_results == Some(0) {
+        problems.push(ConfigProblem::new("max_results", "must be at least 1"));
+    }
+    if let Some(intent) = search_intent
+        && let (Some(base), Some(max)) = (intent.base_count, intent.max_count)
+        && base > max
+    {
+        problems.push(ConfigProblem::new(
+            "search_intent.base_count",
+            format!("{base} exceeds search_intent.max_count ({max})"),
+        ));
+    }
+}
+
+/// Checks logging and tracing values, including the log filter regexes.
+fn check_observability(file: &ConfigFile, problems: &mut Vec<ConfigProblem>) {
+    let Some(observability) = &file.observability else {
+        return;
+    };
+
+    if let Some(logging) = &observability.logging {
+        if let Some(format) = &logging.format {
+            check_enum(
+                "observability.logging.format",
+                format,
+                matches!(format.to_lowercase().as_str(), "json" | "pretty"),
+                "json, pretty",
+                problems,
+            );
+        }
+        // Field values in filter directives are regexes, e.g. `subcog[{query=auth.*}]=debug`
+        if let Some(filter) = &logging.filter
+            && let Err(e) = EnvFilter::try_new(filter)
+        {
+            problems.push(ConfigProblem::new(
+                "observability.logging.filter",
+                format!("invalid filter {filter:?}: {e}"),
+            ));
+        }
+        if let Some(level) = &logging.level
+            && !level.contains(['=', ','])
+        {
+            check_enum(
+                "observability.logging.level",
+                level,
+                matches!(
+                    level.trim().to_lowercase().as_str(),
+                    "trace" | "debug" | "info" | "warn" | "error" | "off"
+                ),
+                "trace, debug, info, warn, error, off",
+                problems,
+            );
+        }
+    }
+
+    let protocol = observability
+        .tracing
+        .as_ref()
+        .and_then(|tracing| tracing.otlp.as_ref()?.protocol.as_deref());
+    if let Some(protocol) = protocol {
+        check_enum(
+            "observability.tracing.otlp.protocol",
+            protocol,
+            OtlpProtocol::parse(protocol).is_some(),
+            "grpc, http",
+            problems,
+        );
+    }
+}
+
+/// Checks the storage backend of each scope.
+fn check_storage(file: &ConfigFile, problems: &mut Vec<ConfigProblem>) {
+    let Some(storage) = &file.storage else {
+        return;
+    };
+    let scopes: [(&str, Option<&ConfigFileStorageBackend>); 3] = [
+        ("project", storage.project.as_ref()),
+        ("user", storage.user.as_ref()),
+        ("org", storage.org.as_ref()),
+    ];
+    for (scope, backend) in scopes {
+        if let Some(backend) = backend.and_then(|b| b.backend.as_deref()) {
+            check_enum(
+                &format!("storage.{scope}.backend"),
+                backend,
+                matches!(
+                    backend.to_lowercase().as_str(),
+                    "sqlite"
+                        | "filesystem"
+                        | "fs"
+                        | "file"
+                        | "postgresql"
+                        | "postgres"
+                        | "pg"
+                        | "redis"
+                ),
+                "sqlite, filesystem, postgresql, redis",
+                problems,
+            );
+        }
+    }
+}
+
+/// Checks namespace names used as keys or values.
+fn check_namespaces(file: &ConfigFile, problems: &mut Vec<ConfigProblem>) {
+    let mut check = |key: String, namespace: &str| {
+        if Namespace::parse(namespace).is_none() {
+            problems.push(ConfigProblem::new(
+                key,
+                format!("unknown namespace {namespace:?}"),
+            ));
+        }
+    };
+
+    if let Some(rules) = &file.namespace_rules {
+        for namespace in rules.keys() {
+            check(format!("namespace_rules.{namespace}"), namespace);
+        }
+    }
+    if let Some(embedding) = &file.embedding {
+        for namespace in embedding.per_namespace.keys() {
+            check(format!("embedding.per_namespace.{namespace}"), namespace);
+        }
+    }
+    if let Some(retention) = &file.retention {
+        for namespace in retention.keys() {
+            check(format!("retention.{namespace}"), namespace);
+        }
+    }
+    let patterns = file
+        .auto_capture
+        .as_ref()
+        .and_then(|auto_capture| auto_capture.tool_patterns.as_ref());
+    for (i, pattern) in patterns.into_iter().flatten().enumerate() {
+        check(
+            format!("auto_capture.tool_patterns[{i}].namespace"),
+            &pattern.namespace,
+        );
+    }
+}
+
+/// Checks TTL durations such as `30d`.
+fn check_durations(file: &ConfigFile, problems: &mut Vec<ConfigProblem>) {
+    let Some(ttl) = &file.ttl else {
+        return;
+    };
+    let mut durations = vec![("ttl.default".to_string(), ttl.default.as_deref())];
+    if let Some(namespace) = &ttl.namespace {
+        durations.extend(
+            [
+                ("decisions", namespace.decisions.as_deref()),
+                ("patterns", namespace.patterns.as_deref()),
+                ("learnings", namespace.learnings.as_deref()),
+                ("context", namespace.context.as_deref()),
+                ("tech_debt", namespace.tech_debt.as_deref()),
+                ("apis", namespace.apis.as_deref()),
+                ("config", namespace.config.as_deref()),
+                ("security", namespace.security.as_deref()),
+                ("performance", namespace.performance.as_deref()),
+                ("testing", namespace.testing.as_deref()),
+            ]
+            .map(|(key, value)| (format!("ttl.namespace.{key}"), value)),
+        );
+    }
+    if let Some(scope) = &ttl.scope {
+        durations.extend(
+            [
+                ("project", scope.project.as_deref()),
+                ("user", scope.user.as_deref()),
+                ("org", scope.org.as_deref()),
+            ]
+            .map(|(key, value)| (format!("ttl.scope.{key}"), value)),
+        );
+    }
+
+    for (key, value) in durations {
+        if let Some(value) = value
+            && parse_duration_to_seconds(value).is_none()
+        {
+            problems.push(ConfigProblem::new(
+                key,
+                format!("invalid duration {value:?} (expected e.g. \"30d\", \"12h\", or \"0\")"),
+            ));
+        }
+    }
+}
+
+/// Checks that every `${VAR}` reference is well-formed and set.
+fn check_env_references(value: &Value, key: &str, problems: &mut Vec<ConfigProblem>) {
+    match value {
+        Value::String(s) => {
+            let mut rest = s.as_str();
+            while let Some(start) = rest.find("${") {
+                let Some(end) = rest[start..].find('}') else {
+                    problems.push(ConfigProblem::new(key, "unterminated ${...} reference"));
+                    return;
+                };
+                let name = &rest[start + 2..start + end];
+                if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+                    problems.push(ConfigProblem::new(
+                        key,
+                        format!("invalid environment variable name {name:?}"),
+                    ));
+                } else if std::env::var_os(name).is_none() {
+                    problems.push(ConfigProblem::new(
+                        key,
+                        format!("environment variable {name} is not set"),
+                    ));
+                }
+                rest = &rest[start + end + 1..];
+            }
+        },
+        Value::Table(table) => {
+            for (name, value) in table {
+                check_env_references(value, &join_key(key, name), problems);
+            }
+        },
+        Value::Array(values) => {
+            for (i, value) in values.iter().enumerate() {
+                check_env_references(value, &format!("{key}[{i}]"), problems);
+            }
+        },
+        _ => {},
+    }
+}
+
+/// Records a problem if a ratio lies outside 0.0-1.0 (or is not a number).
+fn check_ratio<T: Into<f64> + fmt::Display + Copy>(
+    key: &str,
+    value: Option<T>,
+    problems: &mut Vec<ConfigProblem>,
+) {
+    if let Some(value) = value
+        && !(0.0..=1.0).contains(&value.into())
+    {
+        problems.push(ConfigProblem::new(
+            key,
+            format!("{value} is out of range (expected 0.0 to 1.0)"),
+        ));
+    }
+}
+
+/// Records a problem if `valid` is false.
+fn check_enum(
+    key: &str,
+    value: &str,
+    valid: bool,
+    expected: &str,
+    problems: &mut Vec<ConfigProblem>,
+) {
+    if !valid {
+        problems.push(ConfigProblem::new(
+            key,
+            format!("invalid value {value:?} (expected one of: {expected})"),
+        ));
+    }
+}
+
+/// Appends `key` to a dotted key path.
+fn join_key(prefix: &str, key: &str) -> String {
+    if prefix.is_empty() {
+        key.to_string()
+    } else {
+        format!("{prefix}.{key}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_config_has_no_problems() {
+        let problems = validate_config_str(
+            r#"
+            default_search_mode = "hybrid"
+
+            [llm]
+            provider = "ollama"
+
+            [observability.logging]
+            format = "pretty"
+            filter = "subcog=debug,subcog[{query=auth.*}]=trace"
+
+            [ttl]
+            default = "30d"
+
+            [ttl.namespace]
+            tech-debt = "90d"
+
+            [namespace_rules.decisions]
+            min_length = 20
+
+            [retention]
+            progress = 30
+            decisions = 0
+
+            [[webhooks]]
+            name = "ci"
+            url = "https://example.com/hook"
+            format = "slack"
+            auth = { type = "bearer", token = "${PATH}" }
+            "#,
+        );
+        assert!(problems.is_empty(), "unexpected problems: {problems:?}");
+    }
+
+    #[test]
+    fn test_bad_regex_and_unknown_key_are_both_reported() {
+        let problems = validate_config_str(
+            r#"
+            [search]
+            cahce = true
+
+            [observability.logging]
+            filter = "subcog[{query=(}]=debug"
+            "#,
+        );
+        let keys: Vec<&str> = problems.iter().map(|p| p.key.as_str()).collect();
+        assert_eq!(keys, ["search.cahce", "observability.logging.filter"]);
+        assert!(problems[0].to_string().contains("unknown key"));
+    }
+
+    #[test]
+    fn test_values_and_env_references_are_checked() {
+        let problems = validate_config_str(
+            r#"
+            default_search_mode = "fuzzy"
+
+            [ttl]
+            default = "forever"
+
+            [storage.project]
+            backend = "mongo"
+            connection_string = "${SUBCOG_VALIDATE_TEST_UNSET_VAR}"
+
+            [embedding.per_namespace]
+            recipes = "all-MiniLM-L6-v2"
+
+            [retention]
+            recipes = 30
+            "#,
+        );
+        let keys: Vec<&str> = problems.iter().map(|p| p.key.as_str()).collect();
+        assert_eq!(
+            keys,
+            [
+                "default_search_mode",
+                "storage.project.backend",
+                "embedding.per_namespace.recipes",
+                "retention.recipes",
+                "ttl.default",
+                "storage.project.connection_string",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_out_of_range_values_are_reported() {
+        let problems = validate_config_str(
+            r"
+            max_results = 0
+
+            [search_intent]
+            min_confidence = 1.5
+            base_count = 20
+            max_count = 10
+
+            [search]
+            hybrid_alpha = 0.5
+            diversity_lambda = -0.1
+            ",
+        );
+        let messages: Vec<String> = problems.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            messages,
+            [
+                "search_intent.min_confidence: 1.5 is out of range (expected 0.0 to 1.0)",
+                "search.diversity_lambda: -0.1 is out of range (expected 0.0 to 1.0)",
+                "max_results: must be at least 1",
+                "search_intent.base_count: 20 exceeds search_intent.max_count (10)",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_syntax_and_type_errors_stop_validation() {
+        let problems = validate_config_str("max_results = \"ten\"\n");
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].message.starts_with("line 1:"));
+
+        let problems = validate_config_str("[search\n");
+        assert_eq!(problems.len(), 1);
+    }
+}
@@ -44,6 +44,11 @@ pub struct GitContext {
     ///
     /// Can be set to provide file-specific context for operations.
     pub file_path: Option<String>,
+
+    /// Full SHA of the commit checked out on the current branch.
+    ///
+    /// `None` if in detached HEAD state, if HEAD is unborn, or outside a repository.
+    pub commit: Option<String>,
 }
 
 impl GitContext {
@@ -105,6 +110,7 @@ pub fn from_path(path: &Path) -> Self {
             project_id: detect_project_id(&repo),
             branch: detect_branch(&repo),
             file_path: None,
+            commit: detect_commit(&repo),
         }
     }
 
@@ -201,6 +207,19 @@ fn detect_branch(repo: &Repository) -> Option<String> {
     head.shorthand().map(String::from)
 }
 
+/// Detects the commit SHA at the tip of the current branch.
+///
+/// Returns `None` under the same conditions as [`detect_branch`].
+fn detect_commit(repo: &Repository) -> Option<String> {
+    let head = repo.head().ok()?;
+    if !head.is_branch() {
+        return None;
+    }
+    head.peel_to_commit()
+        .ok()
+        .map(|commit| commit.id().to_string())
+}
+
 /// Sanitizes a git remote URL by removing credentials and normalizing format.
 ///
 /// # Security
@@ -441,6 +460,7 @@ fn test_git_context_is_git_repo() {
             project_id: Some("org/repo".to_string()),
             branch: Some("main".to_string()),
             file_path: None,
+            commit: None,
         };
         assert!(ctx.is_git_repo());
 
@@ -454,6 +474,7 @@ fn test_git_context_is_detached() {
             project_id: Some("org/repo".to_string()),
             branch: None,
             file_path: None,
+            commit: None,
         };
         assert!(detached.is_detached());
 
@@ -461,6 +482,7 @@ fn test_git_context_is_detached() {
             project_id: Some("org/repo".to_string()),
             branch: Some("main".to_string()),
             file_path: None,
+            commit: None,
         };
         assert!(!attached.is_detached());
 
@@ -496,6 +518,7 @@ fn test_from_path_non_git_directory() {
         assert!(ctx.project_id.is_none());
         assert!(ctx.branch.is_none());
         assert!(ctx.file_path.is_none());
+        assert!(ctx.commit.is_none());
     }
 
     #[test]
@@ -562,6 +585,7 @@ fn test_from_path_detached_head() {
 
         assert!(ctx.project_id.is_some()); // Should still have project ID
         assert!(ctx.branch.is_none()); // Branch should be None
+        assert!(ctx.commit.is_none()); // Commit is only recorded on a branch
         assert!(ctx.is_detached());
     }
 
@@ -0,0 +1,148 @@
+//! `.subcogignore` support.
+//!
+//! A `.subcogignore` file at the repository root lists paths, in gitignore
+//! syntax, that subcog leaves alone: typically vendored or generated
+//! directories. Memories captured with an ignored source are not tagged with
+//! its file path, and the `PostToolUse` hook surfaces no memories for tool
+//! runs on ignored files.
+//!
+//! ```text
+//! # .subcogignore
+//! vendor/
+//! target/
+//! *.generated.rs
+//! !src/keep.generated.rs
+//! ```
+
+use crate::storage::index::find_repo_root;
+use ignore::gitignore::Gitignore;
+use std::path::{Path, PathBuf};
+
+/// Name of the ignore file at the repository root.
+pub const SUBCOG_IGNORE_FILE: &str = ".subcogignore";
+
+/// Paths excluded by a `.subcogignore` file.
+#[derive(Debug, Clone)]
+pub struct SubcogIgnore {
+    /// Directory the patterns are relative to.
+    root: PathBuf,
+    /// Gitignore-compatible matcher for the patterns.
+    matcher: Gitignore,
+}
+
+impl SubcogIgnore {
+    /// Returns an ignore list that excludes nothing.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self {
+            root: PathBuf::new(),
+            matcher: Gitignore::empty(),
+        }
+    }
+
+    /// Loads `root/.subcogignore`, excluding nothing if the file is missing.
+    ///
+    /// Invalid patterns are skipped with a warning.
+    #[must_use]
+    pub fn load(root: &Path) -> Self {
+        let path = root.join(SUBCOG_IGNORE_FILE);
+        if !path.is_file() {
+            return Self::empty();
+        }
+
+        let (matcher, error) = Gitignore::new(&path);
+        if let Some(e) = error {
+            tracing::warn!(path = %path.display(), error = %e, "Invalid .subcogignore patterns");
+        }
+        Self {
+            root: root.to_path_buf(),
+            matcher,
+        }
+    }
+
+    /// Loads the `.subcogignore` of the repository containing `start`, or of
+    /// `start` itself outside a repository.
+    #[must_use]
+    pub fn discover(start: &Path) -> Self {
+        find_repo_root(start).map_or_else(|_| Self::load(start), |root| Self::load(&root))
+    }
+
+    /// Returns `true` if no path is excluded.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.matcher.is_empty()
+    }
+
+    /// Returns `true` if `path` or one of its parent directories is excluded.
+    ///
+    /// Relative paths are resolved against the repository root; absolute
+    /// paths outside it are never excluded.
+    #[must_use]
+    pub fn is_ignored(&self, path: &Path) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        let relative = if path.is_absolute() {
+            let path = path.canonicalize().unwrap_or_else(|_| path.to_path_buf());
+            match path.strip_prefix(&self.root) {
+                Ok(relative) => relative.to_path_buf(),
+                Err(_) => return false,
+            }
+        } else {
+            path.to_path_buf()
+        };
+        let is_dir = self.root.join(&relative).is_dir();
+        self.matcher
+            .matched_path_or_any_parents(&relative, is_dir)
+            .is_ignore()
+    }
+}
+
+impl Default for SubcogIgnore {
+    fn default() -> Self {
+        Self::empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn ignore_with(patterns: &str) -> (TempDir, SubcogIgnore) {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join(SUBCOG_IGNORE_FILE), patterns).unwrap();
+        let ignore = SubcogIgnore::load(&dir.path().canonicalize().unwrap());
+        (dir, ignore)
+    }
+
+    #[test]
+    fn test_missing_file_ignores_nothing() {
+        let dir = TempDir::new().unwrap();
+        let ignore = SubcogIgnore::load(dir.path());
+
+        assert!(ignore.is_empty());
+        assert!(!ignore.is_ignored(Path::new("vendor/lib.rs")));
+    }
+
+    #[test]
+    fn test_gitignore_syntax() {
+        let (_dir, ignore) = ignore_with("vendor/\n*.generated.rs\n!keep.generated.rs\n");
+
+        assert!(ignore.is_ignored(Path::new("vendor/crate/src/lib.rs")));
+        assert!(ignore.is_ignored(Path::new("src/api.generated.rs")));
+        assert!(!ignore.is_ignored(Path::new("src/keep.generated.rs")));
+        assert!(!ignore.is_ignored(Path::new("src/lib.rs")));
+    }
+
+    #[test]
+    fn test_absolute_paths() {
+        let (dir, ignore) = ignore_with("target/\n");
+        let root = dir.path().canonicalize().unwrap();
+
+        assert!(ignore.is_ignored(&root.join("target/debug/build.rs")));
+        assert!(!ignore.is_ignored(&root.join("src/main.rs")));
+        assert!(!ignore.is_ignored(Path::new("/elsewhere/target/x.rs")));
+    }
+}
@@ -40,5 +40,7 @@
 //! | `https://github.com/org/repo.git` | `github.com/org/repo` |
 
 mod detector;
+mod ignore_file;
 
 pub use detector::GitContext;
+pub use ignore_file::{SUBCOG_IGNORE_FILE, SubcogIgnore};
@@ -0,0 +1,615 @@
+//! On-disk cache of embeddings keyed by content hash.
+//!
+//! Embedding is the slow part of `migrate embeddings` and bulk imports, and
+//! most of the content they embed was embedded before. [`CachedEmbedder`]
+//! looks each text up in a [`ContentEmbeddingCache`] before calling the
+//! model and stores what the model returns, so unchanged content is only
+//! embedded once per model.
+//!
+//! Entries are keyed by the model name and the SHA-256 of the normalized
+//! text (trimmed, whitespace runs collapsed to one space), so texts that
+//! differ only in whitespace share an entry. Switching the embedding model
+//! never returns vectors of the previous model, and an entry whose stored
+//! dimensions differ from the embedder's counts as a miss.
+//!
+//! The cache is a `SQLite` database in WAL mode. Entries are written with
+//! `INSERT OR REPLACE` inside a transaction, so concurrent processes that
+//! embed the same content both succeed and leave one complete entry.
+//!
+//! # Schema
+//!
+//! ```sql
+//! CREATE TABLE embeddings (
+//!     model TEXT NOT NULL,
+//!     content_hash TEXT NOT NULL,
+//!     dimensions INTEGER NOT NULL,
+//!     vector BLOB NOT NULL,
+//!     created_at INTEGER NOT NULL,
+//!     PRIMARY KEY (model, content_hash)
+//! );
+//! CREATE TABLE embedding_cache_stats (
+//!     id INTEGER PRIMARY KEY CHECK (id = 1),
+//!     hits INTEGER NOT NULL,
+//!     misses INTEGER NOT NULL
+//! );
+//! ```
+
+use super::Embedder;
+use crate::current_timestamp;
+use crate::{Error, Result};
+use rusqlite::{Connection, OpenFlags, OptionalExtension, TransactionBehavior, params};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Entry count and cumulative lookups of an embedding cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EmbeddingCacheStats {
+    /// Number of cached embeddings.
+    pub entries: u64,
+    /// Lookups answered from the cache.
+    pub hits: u64,
+    /// Lookups that had to call the model.
+    pub misses: u64,
+}
+
+impl EmbeddingCacheStats {
+    /// Returns the share of lookups answered from the cache, if any were made.
+    #[must_use]
+    pub fn hit_rate(&self) -> Option<f64> {
+        let lookups = self.hits.saturating_add(self.misses);
+        (lookups > 0).then(|| self.hits as f64 / lookups as f64)
+    }
+}
+
+/// Formats as `120 entries, 300 hits, 120 misses (71% hit rate)`.
+impl std::fmt::Display for EmbeddingCacheStats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} entries, {} hits, {} misses",
+            self.entries, self.hits, self.misses
+        )?;
+        if let Some(rate) = self.hit_rate() {
+            write!(f, " ({:.0}% hit rate)", rate * 100.0)?;
+        }
+        Ok(())
+    }
+}
+
+/// `SQLite` store of embeddings keyed by model and content hash.
+pub struct ContentEmbeddingCache {
+    /// `SQLite` connection.
+    conn: Mutex<Connection>,
+}
+
+// Mutex guards are held for the duration of database operations, which is correct behavior
+#[allow(clippy::significant_drop_tightening)]
+impl ContentEmbeddingCache {
+    /// Opens or creates the cache at `db_path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened or initialized.
+    pub fn open(db_path: &Path) -> Result<Self> {
+        if let Some(parent) = db_path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| Error::OperationFailed {
+                operation: "create_embedding_cache_dir".to_string(),
+                cause: e.to_string(),
+            })?;
+        }
+
+        let conn = Connection::open(db_path).map_err(|e| Error::OperationFailed {
+            operation: "open_embedding_cache".to_string(),
+            cause: e.to_string(),
+        })?;
+
+        // WAL lets readers continue while another process writes
+        conn.execute_batch("PRAGMA busy_timeout=5000; PRAGMA journal_mode=WAL;")
+            .map_err(|e| Error::OperationFailed {
+                operation: "configure_embedding_cache".to_string(),
+                cause: e.to_string(),
+            })?;
+
+        let cache = Self {
+            conn: Mutex::new(conn),
+        };
+        cache.create_schema()?;
+        Ok(cache)
+    }
+
+    /// Creates an in-memory cache for testing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be initialized.
+    #[cfg(test)]
+    pub fn in_memory() -> Result<Self> {
+        let conn = Connection::open_in_memory().map_err(|e| Error::OperationFailed {
+            operation: "open_memory_db".to_string(),
+            cause: e.to_string(),
+        })?;
+        let cache = Self {
+            conn: Mutex::new(conn),
+        };
+        cache.create_schema()?;
+        Ok(cache)
+    }
+
+    /// Reads the statistics of the cache at `db_path` without modifying it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened or read.
+    pub fn summarize(db_path: &Path) -> Result<EmbeddingCacheStats> {
+        let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY).map_err(
+            |e| Error::OperationFailed {
+                operation: "open_embedding_cache_read_only".to_string(),
+                cause: e.to_string(),
+            },
+        )?;
+        read_stats(&conn)
+    }
+
+    /// Creates the database schema.
+    fn create_schema(&self) -> Result<()> {
+        let conn = self.lock()?;
+        conn.execute_batch(
+            r"
+            CREATE TABLE IF NOT EXISTS embeddings (
+                model TEXT NOT NULL,
+                content_hash TEXT NOT NULL,
+                dimensions INTEGER NOT NULL,
+                vector BLOB NOT NULL,
+                created_at INTEGER NOT NULL,
+                PRIMARY KEY (model, content_hash)
+            );
+            CREATE TABLE IF NOT EXISTS embedding_cache_stats (
+                id INTEGER PRIMARY KEY CHECK (id = 1),
+                hits INTEGER NOT NULL,
+                misses INTEGER NOT NULL
+            );
+            INSERT OR IGNORE INTO embedding_cache_stats (id, hits, misses) VALUES (1, 0, 0);
+            ",
+        )
+        .map_err(|e| Error::OperationFailed {
+            operation: "create_embedding_cache_schema".to_string(),
+            cause: e.to_string(),
+        })
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Connection>> {
+        self.conn.lock().map_err(|e| Error::OperationFailed {
+            operation: "lock_embedding_cache".to_string(),
+            cause: e.to_string(),
+        })
+    }
+
+    /// Returns the cached embedding of `text` for `model`.
+    ///
+    /// An entry with other than `dimensions` dimensions is treated as absent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn get(&self, model: &str, text: &str, dimensions: usize) -> Result<Option<Vec<f32>>> {
+        let conn = self.lock()?;
+        let row: Option<(i64, Vec<u8>)> = conn
+            .query_row(
+                "SELECT dimensions, vector FROM embeddings WHERE model = ?1 AND content_hash = ?2",
+                params![model, content_hash(text)],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()
+            .map_err(|e| Error::OperationFailed {
+                operation: "read_embedding_cache".to_string(),
+                cause: e.to_string(),
+            })?;
+        Ok(row
+            .filter(|(stored, _)| usize::try_from(*stored).ok() == Some(dimensions))
+            .map(|(_, bytes)| decode_vector(&bytes))
+            .filter(|vector| vector.len() == dimensions))
+    }
+
+    /// Stores the embeddings of `entries` (text and vector) for `model`.
+    ///
+    /// Existing entries for the same content are replaced.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the transaction fails.
+    pub fn put_many(&self, model: &str, entries: &[(&str, &[f32])]) -> Result<()> {
+        let mut conn = self.lock()?;
+        let map_err = |e: rusqlite::Error| Error::OperationFailed {
+            operation: "write_embedding_cache".to_string(),
+            cause: e.to_string(),
+        };
+        // Take the write lock up front so concurrent writers wait for each other
+        let tx = conn
+            .transaction_with_behavior(TransactionBehavior::Immediate)
+            .map_err(map_err)?;
+        {
+            let mut stmt = tx
+                .prepare(
+                    "INSERT OR REPLACE INTO embeddings \
+                     (model, content_hash, dimensions, vector, created_at) \
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                )
+                .map_err(map_err)?;
+            let now = i64::try_from(current_timestamp()).unwrap_or(i64::MAX);
+            for (text, vector) in entries {
+                let dimensions = i64::try_from(vector.len()).unwrap_or(i64::MAX);
+                stmt.execute(params![
+                    model,
+                    content_hash(text),
+                    dimensions,
+                    encode_vector(vector),
+                    now
+                ])
+                .map_err(map_err)?;
+            }
+        }
+        tx.commit().map_err(map_err)
+    }
+
+    /// Adds `hits` and `misses` to the cumulative lookup counts.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the update fails.
+    pub fn record_lookups(&self, hits: u64, misses: u64) -> Result<()> {
+        if hits == 0 && misses == 0 {
+            return Ok(());
+        }
+        let conn = self.lock()?;
+        conn.execute(
+            "UPDATE embedding_cache_stats SET hits = hits + ?1, misses = misses + ?2 WHERE id = 1",
+            params![
+                i64::try_from(hits).unwrap_or(i64::MAX),
+                i64::try_from(misses).unwrap_or(i64::MAX)
+            ],
+        )
+        .map_err(|e| Error::OperationFailed {
+            operation: "record_embedding_cache_lookups".to_string(),
+            cause: e.to_string(),
+        })?;
+        Ok(())
+    }
+
+    /// Deletes the entries of every model other than `model`.
+    ///
+    /// Returns the number of entries deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the delete fails.
+    pub fn retain_model(&self, model: &str) -> Result<usize> {
+        let map_err = |e: rusqlite::Error| Error::OperationFailed {
+            operation: "prune_embedding_cache".to_string(),
+            cause: e.to_string(),
+        };
+        let conn = self.lock()?;
+        // Ranges instead of `!=` let both statements use the primary key, and
+        // checking first avoids taking the write lock on every open
+        let stale: bool = conn
+            .query_row(
+                "SELECT EXISTS (SELECT 1 FROM embeddings WHERE model < ?1 OR model > ?1)",
+                params![model],
+                |row| row.get(0),
+            )
+            .map_err(map_err)?;
+        if !stale {
+            return Ok(0);
+        }
+        conn.execute(
+            "DELETE FROM embeddings WHERE model < ?1 OR model > ?1",
+            params![model],
+        )
+        .map_err(map_err)
+    }
+
+    /// Returns the entry count and cumulative lookups.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn stats(&self) -> Result<EmbeddingCacheStats> {
+        let conn = self.lock()?;
+        read_stats(&conn)
+    }
+}
+
+/// Reads the entry count and lookup counts.
+fn read_stats(conn: &Connection) -> Result<EmbeddingCacheStats> {
+    let (entries, hits, misses): (i64, i64, i64) = conn
+        .query_row(
+            "SELECT (SELECT COUNT(*) FROM embeddings), hits, misses \
+             FROM embedding_cache_stats WHERE id = 1",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .map_err(|e| Error::OperationFailed {
+            operation: "read_embedding_cache_stats".to_string(),
+            cause: e.to_string(),
+        })?;
+    Ok(EmbeddingCacheStats {
+        entries: u64::try_from(entries).unwrap_or(0),
+        hits: u64::try_from(hits).unwrap_or(0),
+        misses: u64::try_from(misses).unwrap_or(0),
+    })
+}
+
+/// Returns the hex SHA-256 of `text` with whitespace normalized.
+fn content_hash(text: &str) -> String {
+    let normalized = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    hex::encode(Sha256::digest(normalized.as_bytes()))
+}
+
+fn encode_vector(vector: &[f32]) -> Vec<u8> {
+    vector
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect()
+}
+
+fn decode_vector(bytes: &[u8]) -> Vec<f32> {
+    bytes
+        .chunks_exact(4)
+        .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+        .collect()
+}
+
+/// Embedder that consults a [`ContentEmbeddingCache`] before its model.
+///
+/// Cache failures are logged and fall through to the model, so a locked or
+/// corrupt cache file slows embedding down but never fails it. Hits and
+/// misses are counted in the `embedding_cache_hits_total` and
+/// `embedding_cache_misses_total` metrics (labelled with the model) and
+/// added to the cache's cumulative counts.
+pub struct CachedEmbedder {
+    /// Embedder called on cache misses.
+    inner: Arc<dyn Embedder>,
+    /// Name of the inner embedder's model.
+    model: String,
+    /// Embedding store.
+    cache: Arc<ContentEmbeddingCache>,
+    /// Hits not yet added to the cache's counts.
+    pending_hits: AtomicU64,
+    /// Misses not yet added to the cache's counts.
+    pending_misses: AtomicU64,
+}
+
+impl CachedEmbedder {
+    /// Wraps `inner`, which embeds with `model`, with `cache`.
+    #[must_use]
+    pub fn new(
+        inner: Arc<dyn Embedder>,
+        model: impl Into<String>,
+        cache: Arc<ContentEmbeddingCache>,
+    ) -> Self {
+        Self {
+            inner,
+            model: model.into(),
+            cache,
+            pending_hits: AtomicU64::new(0),
+            pending_misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the cache.
+    #[must_use]
+    pub fn cache(&self) -> &ContentEmbeddingCache {
+        &self.cache
+    }
+
+    fn lookup(&self, text: &str) -> Option<Vec<f32>> {
+        self.cache
+            .get(&self.model, text, self.inner.dimensions())
+            .unwrap_or_else(|e| {
+                tracing::warn!(error = %e, "Embedding cache lookup failed");
+                None
+            })
+    }
+
+    /// Counts lookups, stores new embeddings, and flushes the counts.
+    fn record(&self, hits: u64, stored: &[(&str, &[f32])]) {
+        let misses = u64::try_from(stored.len()).unwrap_or(u64::MAX);
+        metrics::counter!("embedding_cache_hits_total", "model" => self.model.clone())
+            .increment(hits);
+        metrics::counter!("embedding_cache_misses_total", "model" => self.model.clone())
+            .increment(misses);
+        self.pending_hits.fetch_add(hits, Ordering::Relaxed);
+        self.pending_misses.fetch_add(misses, Ordering::Relaxed);
+
+        // Counts are persisted with writes (and on drop) rather than on every hit
+        if stored.is_empty() {
+            return;
+        }
+        if let Err(e) = self.cache.put_many(&self.model, stored) {
+            tracing::warn!(error = %e, "Failed to store embeddings in cache");
+        }
+        self.flush_counts();
+    }
+
+    fn flush_counts(&self) {
+        let hits = self.pending_hits.swap(0, Ordering::Relaxed);
+        let misses = self.pending_misses.swap(0, Ordering::Relaxed);
+        if let Err(e) = self.cache.record_lookups(hits, misses) {
+            tracing::debug!(error = %e, "Failed to record embedding cache lookups");
+        }
+    }
+}
+
+impl Embedder for CachedEmbedder {
+    fn dimensions(&self) -> usize {
+        self.inner.dimensions()
+    }
+
+    fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        if let Some(vector) = self.lookup(text) {
+            self.record(1, &[]);
+            return Ok(vector);
+        }
+        let vector = self.inner.embed(text)?;
+        self.record(0, &[(text, vector.as_slice())]);
+        Ok(vector)
+    }
+
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let mut vectors: Vec<Option<Vec<f32>>> = texts.iter().map(|t| self.lookup(t)).collect();
+        let missing: Vec<&str> = texts
+            .iter()
+            .zip(&vectors)
+            .filter(|(_, vector)| vector.is_none())
+            .map(|(text, _)| *text)
+            .collect();
+        let hits = u64::try_from(texts.len() - missing.len()).unwrap_or(u64::MAX);
+
+        let embedded = if missing.is_empty() {
+            Vec::new()
+        } else {
+            self.inner.embed_batch(&missing)?
+        };
+        let stored: Vec<(&str, &[f32])> = missing
+            .iter()
+            .zip(&embedded)
+            .map(|(text, vector)| (*text, vector.as_slice()))
+            .collect();
+        self.record(hits, &stored);
+
+        let mut embedded = embedded.into_iter();
+        for slot in vectors.iter_mut().filter(|slot| slot.is_none()) {
+            *slot = embedded.next();
+        }
+        vectors
+            .into_iter()
+            .map(|vector| {
+                vector.ok_or_else(|| Error::OperationFailed {
+                    operation: "embed_batch".to_string(),
+                    cause: "embedder returned fewer embeddings than texts".to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Drop for CachedEmbedder {
+    fn drop(&mut self) {
+        self.flush_counts();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Embedder that counts how many texts it embedded.
+    struct CountingEmbedder {
+        calls: AtomicUsize,
+    }
+
+    impl Embedder for CountingEmbedder {
+        fn dimensions(&self) -> usize {
+            3
+        }
+
+        fn embed(&self, text: &str) -> Result<Vec<f32>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let len = text.len() as f32;
+            Ok(vec![len, 1.0, 0.5])
+        }
+    }
+
+    fn cached(cache: &Arc<ContentEmbeddingCache>) -> (Arc<CountingEmbedder>, CachedEmbedder) {
+        let inner = Arc::new(CountingEmbedder {
+            calls: AtomicUsize::new(0),
+        });
+        let embedder = CachedEmbedder::new(
+            Arc::clone(&inner) as Arc<dyn Embedder>,
+            "test-model",
+            Arc::clone(cache),
+        );
+        (inner, embedder)
+    }
+
+    #[test]
+    fn test_unchanged_content_is_embedded_once() {
+        let cache = Arc::new(ContentEmbeddingCache::in_memory().unwrap());
+        let (inner, embedder) = cached(&cache);
+
+        let first = embedder.embed("Use SQLite for the index").unwrap();
+        let second = embedder.embed("  Use SQLite\nfor the   index ").unwrap();
+        assert_eq!(first, second);
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 1);
+
+        let batch = embedder
+            .embed_batch(&["Use SQLite for the index", "Use usearch for vectors"])
+            .unwrap();
+        assert_eq!(batch[0], first);
+        assert_eq!(batch[1], vec![23.0, 1.0, 0.5]);
+        assert_eq!(inner.calls.load(Ordering::SeqCst), 2);
+
+        drop(embedder);
+        let stats = cache.stats().unwrap();
+        assert_eq!(stats.entries, 2);
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.misses, 2);
+        assert_eq!(stats.hit_rate(), Some(0.5));
+        assert_eq!(
+            stats.to_string(),
+            "2 entries, 2 hits, 2 misses (50% hit rate)"
+        );
+    }
+
+    #[test]
+    fn test_other_model_or_dimensions_miss() {
+        let cache = ContentEmbeddingCache::in_memory().unwrap();
+        let (a, b): (&[f32], &[f32]) = (&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]);
+        cache.put_many("model-a", &[("content", a)]).unwrap();
+
+        assert_eq!(
+            cache.get("model-a", "content", 3).unwrap(),
+            Some(vec![1.0, 2.0, 3.0])
+        );
+        assert_eq!(cache.get("model-b", "content", 3).unwrap(), None);
+        assert_eq!(cache.get("model-a", "content", 4).unwrap(), None);
+
+        cache.put_many("model-b", &[("content", b)]).unwrap();
+        assert_eq!(cache.retain_model("model-b").unwrap(), 1);
+        assert_eq!(cache.get("model-a", "content", 3).unwrap(), None);
+        assert_eq!(cache.stats().unwrap().entries, 1);
+    }
+
+    #[test]
+    fn test_concurrent_writers_share_the_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("embedding_cache.db");
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let path = path.clone();
+                std::thread::spawn(move || {
+                    let cache = ContentEmbeddingCache::open(&path).unwrap();
+                    for i in 0..20 {
+                        let text = format!("memory {i}");
+                        let vector: &[f32] = &[1.0, 2.0];
+                        cache.put_many("model", &[(text.as_str(), vector)]).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        let stats = ContentEmbeddingCache::summarize(&path).unwrap();
+        assert_eq!(stats.entries, 20);
+        let cache = ContentEmbeddingCache::open(&path).unwrap();
+        assert_eq!(
+            cache.get("model", "memory 7", 2).unwrap(),
+            Some(vec![1.0, 2.0])
+        );
+    }
+}
@@ -3,32 +3,89 @@
 //! Provides semantic embeddings using the all-MiniLM-L6-v2 model via fastembed-rs.
 //! When the `fastembed-embeddings` feature is enabled, this uses real ONNX-based
 //! semantic embeddings. Otherwise, falls back to deterministic hash-based pseudo-embeddings.
+//!
+//! # Model Files
+//!
+//! No model is bundled with the binary. Each model is downloaded from Hugging
+//! Face on first use and cached in `FASTEMBED_CACHE_DIR` (`.fastembed_cache`
+//! by default). With `SUBCOG_EMBED_OFFLINE` set, models are loaded from that
+//! cache only, and a model missing from it fails at once.
 
 use super::{DEFAULT_DIMENSIONS, Embedder};
 use crate::{Error, Result};
 
+/// An embedding model [`FastEmbedEmbedder`] can load.
+struct ModelSpec {
+    /// Model name used in configuration.
+    name: &'static str,
+    /// Embedding dimensions produced by the model.
+    dimensions: usize,
+}
+
+/// Supported models; the first entry is the default.
+const MODELS: [ModelSpec; 3] = [
+    ModelSpec {
+        name: "all-MiniLM-L6-v2",
+        dimensions: DEFAULT_DIMENSIONS,
+    },
+    ModelSpec {
+        name: "bge-small-en-v1.5",
+        dimensions: 384,
+    },
+    ModelSpec {
+        name: "jina-embeddings-v2-base-code",
+        dimensions: 768,
+    },
+];
+
+/// Name of the default embedding model.
+pub const DEFAULT_MODEL: &str = MODELS[0].name;
+
+/// Returns the names of the models [`FastEmbedEmbedder::with_model`] accepts.
+#[must_use]
+pub fn supported_models() -> Vec<&'static str> {
+    MODELS.iter().map(|spec| spec.name).collect()
+}
+
+/// Returns the position of `name` in [`MODELS`].
+fn model_index(name: &str) -> Result<usize> {
+    MODELS
+        .iter()
+        .position(|spec| spec.name.eq_ignore_ascii_case(name.trim()))
+        .ok_or_else(|| {
+            Error::InvalidInput(format!(
+                "Unknown embedding model '{name}' (supported: {})",
+                supported_models().join(", ")
+            ))
+        })
+}
+
 // ============================================================================
 // Native FastEmbed Implementation (with feature)
 // ============================================================================
 
 #[cfg(feature = "fastembed-embeddings")]
 mod native {
-    use super::{DEFAULT_DIMENSIONS, Embedder, Error, Result};
+    use super::{DEFAULT_DIMENSIONS, Embedder, Error, MODELS, Result, model_index};
+    use crate::config::EMBED_OFFLINE_ENV;
     use std::panic::{AssertUnwindSafe, catch_unwind};
+    use std::path::{Path, PathBuf};
     use std::sync::OnceLock;
     use std::time::Instant;
 
-    /// Thread-safe singleton for the embedding model.
+    /// Thread-safe singleton per supported embedding model.
     /// Uses `OnceLock` for lazy initialization on first use.
-    static EMBEDDING_MODEL: OnceLock<std::sync::Mutex<fastembed::TextEmbedding>> = OnceLock::new();
+    static EMBEDDING_MODELS: [OnceLock<std::sync::Mutex<fastembed::TextEmbedding>>; MODELS.len()] =
+        [const { OnceLock::new() }; MODELS.len()];
 
-    /// `FastEmbed` embedder using all-MiniLM-L6-v2.
+    /// `FastEmbed` embedder, using all-MiniLM-L6-v2 unless another model is selected.
     ///
     /// Uses the fastembed-rs library for real semantic embeddings.
     /// The model is lazily loaded on first embed call to preserve cold start time.
+    #[derive(Debug, Clone)]
     pub struct FastEmbedEmbedder {
-        /// Model name for logging/debugging.
-        model_name: &'static str,
+        /// Position of the model in the supported model table.
+        model: usize,
     }
 
     impl FastEmbedEmbedder {
@@ -40,9 +97,18 @@ impl FastEmbedEmbedder {
         /// Note: Model is lazily loaded on first `embed()` call.
         #[must_use]
         pub const fn new() -> Self {
-            Self {
-                model_name: "all-MiniLM-L6-v2",
-            }
+            Self { model: 0 }
+        }
+
+        /// Creates an embedder for a named model (see [`supported_models`](super::supported_models)).
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::InvalidInput`] if the model is not supported.
+        pub fn with_model(name: &str) -> Result<Self> {
+            Ok(Self {
+                model: model_index(name)?,
+            })
         }
 
         /// Creates a new embedder with custom dimensions.
@@ -71,17 +137,50 @@ pub const fn with_dimensions(_dimensions: usize) -> Self {
         ///
         /// For applications sensitive to first-call latency, consider warming up the
         /// embedder during startup: `FastEmbedEmbedder::new().embed("warmup").ok();`
-        fn get_model() -> Result<&'static std::sync::Mutex<fastembed::TextEmbedding>> {
+        fn get_model(&self) -> Result<&'static std::sync::Mutex<fastembed::TextEmbedding>> {
+            let slot = &EMBEDDING_MODELS[self.model];
             // Check if already initialized
-            if let Some(model) = EMBEDDING_MODEL.get() {
+            if let Some(model) = slot.get() {
                 return Ok(model);
             }
 
+            // Loading may download the model from Hugging Face
+            crate::config::ensure_online("load_embedding_model")?;
+
+            let (model_kind, repository) = match self.model {
+                0 => (
+                    fastembed::EmbeddingModel::AllMiniLML6V2,
+                    "Qdrant/all-MiniLM-L6-v2-onnx",
+                ),
+                1 => (
+                    fastembed::EmbeddingModel::BGESmallENV15,
+                    "Xenova/bge-small-en-v1.5",
+                ),
+                _ => (
+                    fastembed::EmbeddingModel::JinaEmbeddingsV2BaseCode,
+                    "jinaai/jina-embeddings-v2-base-code",
+                ),
+            };
+            let cache_dir = cache_dir();
+            if crate::config::is_embed_offline() && !is_cached(&cache_dir, repository) {
+                return Err(Error::OperationFailed {
+                    operation: "load_embedding_model".to_string(),
+                    cause: format!(
+                        "embedding model {} is not in the model cache ({}) and downloads are \
+                         disabled by {EMBED_OFFLINE_ENV}; download it once without \
+                         {EMBED_OFFLINE_ENV} or point FASTEMBED_CACHE_DIR at a cache that has it",
+                        self.model_name(),
+                        cache_dir.display()
+                    ),
+                });
+            }
+
             // Initialize the model
             tracing::info!("Loading embedding model (first use)...");
             let start = Instant::now();
 
-            let options = fastembed::InitOptions::new(fastembed::EmbeddingModel::AllMiniLML6V2)
+            let options = fastembed::InitOptions::new(model_kind)
+                .with_cache_dir(cache_dir)
                 .with_show_download_progress(false);
 
             let model =
@@ -92,15 +191,15 @@ fn get_model() -> Result<&'static std::sync::Mutex<fastembed::TextEmbedding>> {
 
             tracing::info!(
                 elapsed_ms = start.elapsed().as_millis() as u64,
-                model = "all-MiniLM-L6-v2",
+                model = self.model_name(),
                 "Embedding model loaded successfully"
             );
 
             // Store the model, ignoring if another thread beat us to it
-            let _ = EMBEDDING_MODEL.set(std::sync::Mutex::new(model));
+            let _ = slot.set(std::sync::Mutex::new(model));
             // Return the (possibly other thread's) model
             // SAFETY: We just set the model, so it must be present
-            EMBEDDING_MODEL.get().ok_or_else(|| Error::OperationFailed {
+            slot.get().ok_or_else(|| Error::OperationFailed {
                 operation: "get_embedding_model".to_string(),
                 cause: "Model initialization race condition".to_string(),
             })
@@ -109,7 +208,7 @@ fn get_model() -> Result<&'static std::sync::Mutex<fastembed::TextEmbedding>> {
         /// Returns the model name.
         #[must_use]
         pub const fn model_name(&self) -> &'static str {
-            self.model_name
+            MODELS[self.model].name
         }
     }
 
@@ -119,9 +218,25 @@ fn default() -> Self {
         }
     }
 
+    /// Returns the model cache directory, fastembed's default unless
+    /// `FASTEMBED_CACHE_DIR` is set.
+    fn cache_dir() -> PathBuf {
+        std::env::var_os("FASTEMBED_CACHE_DIR")
+            .map_or_else(|| PathBuf::from(".fastembed_cache"), PathBuf::from)
+    }
+
+    /// Returns true if a snapshot of the Hugging Face `repository` is in the
+    /// cache at `cache_dir`.
+    fn is_cached(cache_dir: &Path, repository: &str) -> bool {
+        let snapshots = cache_dir
+            .join(format!("models--{}", repository.replace('/', "--")))
+            .join("snapshots");
+        std::fs::read_dir(snapshots).is_ok_and(|mut entries| entries.next().is_some())
+    }
+
     impl Embedder for FastEmbedEmbedder {
         fn dimensions(&self) -> usize {
-            Self::DEFAULT_DIMENSIONS
+            MODELS[self.model].dimensions
         }
 
         fn embed(&self, text: &str) -> Result<Vec<f32>> {
@@ -129,7 +244,7 @@ fn embed(&self, text: &str) -> Result<Vec<f32>> {
                 return Err(Error::InvalidInput("Cannot embed empty text".to_string()));
             }
 
-            let model = Self::get_model()?;
+            let model = self.get_model()?;
             let mut model = model.lock().map_err(|e| Error::OperationFailed {
                 operation: "lock_embedding_model".to_string(),
                 cause: e.to_string(),
@@ -184,7 +299,7 @@ fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
                 return Err(Error::InvalidInput("Cannot embed empty text".to_string()));
             }
 
-            let model = Self::get_model()?;
+            let model = self.get_model()?;
             let mut model = model.lock().map_err(|e| Error::OperationFailed {
                 operation: "lock_embedding_model".to_string(),
                 cause: e.to_string(),
@@ -227,7 +342,7 @@ fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
 
 #[cfg(not(feature = "fastembed-embeddings"))]
 mod fallback {
-    use super::{DEFAULT_DIMENSIONS, Embedder, Error, Result};
+    use super::{DEFAULT_DIMENSIONS, Embedder, Error, MODELS, Result, model_index};
     use std::collections::hash_map::DefaultHasher;
     use std::hash::{Hash, Hasher};
 
@@ -239,7 +354,10 @@ mod fallback {
     ///
     /// Note: Hash-based embeddings do NOT capture semantic similarity.
     /// "database storage" and "PostgreSQL database" will NOT be similar.
+    #[derive(Debug, Clone)]
     pub struct FastEmbedEmbedder {
+        /// Position of the model in the supported model table.
+        model: usize,
         /// Embedding dimensions.
         dimensions: usize,
         /// Whether the embedder is initialized.
@@ -254,6 +372,7 @@ impl FastEmbedEmbedder {
         #[must_use]
         pub const fn new() -> Self {
             Self {
+                model: 0,
                 dimensions: Self::DEFAULT_DIMENSIONS,
                 initialized: true,
             }
@@ -263,11 +382,34 @@ pub const fn new() -> Self {
         #[must_use]
         pub const fn with_dimensions(dimensions: usize) -> Self {
             Self {
+                model: 0,
                 dimensions,
                 initialized: true,
             }
         }
 
+        /// Creates an embedder for a named model (see [`supported_models`](super::supported_models)).
+        ///
+        /// Pseudo-embeddings use the model's dimensions.
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Error::InvalidInput`] if the model is not supported.
+        pub fn with_model(name: &str) -> Result<Self> {
+            let model = model_index(name)?;
+            Ok(Self {
+                model,
+                dimensions: MODELS[model].dimensions,
+                initialized: true,
+            })
+        }
+
+        /// Returns the model name.
+        #[must_use]
+        pub const fn model_name(&self) -> &'static str {
+            MODELS[self.model].name
+        }
+
         /// Generates a deterministic pseudo-embedding from text.
         ///
         /// This creates a normalized vector based on content hashing.
@@ -416,6 +558,18 @@ fn test_embedder_creation() {
         assert_eq!(embedder.dimensions(), FastEmbedEmbedder::DEFAULT_DIMENSIONS);
     }
 
+    #[test]
+    fn test_with_model() {
+        let code = FastEmbedEmbedder::with_model("jina-embeddings-v2-base-code").unwrap();
+        assert_eq!(code.model_name(), "jina-embeddings-v2-base-code");
+        assert_eq!(code.dimensions(), 768);
+        assert_eq!(FastEmbedEmbedder::new().model_name(), DEFAULT_MODEL);
+        assert!(matches!(
+            FastEmbedEmbedder::with_model("word2vec"),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
     #[test]
     fn test_embed_empty_text() {
         let embedder = FastEmbedEmbedder::new();
@@ -8,12 +8,14 @@
 #![allow(clippy::cast_possible_truncation)]
 
 mod bulkhead;
+mod cache;
 mod fallback;
 mod fastembed;
 
 pub use bulkhead::{BulkheadEmbedder, EmbeddingBulkheadConfig};
+pub use cache::{CachedEmbedder, ContentEmbeddingCache, EmbeddingCacheStats};
 pub use fallback::FallbackEmbedder;
-pub use fastembed::{FastEmbedEmbedder, cosine_similarity};
+pub use fastembed::{DEFAULT_MODEL, FastEmbedEmbedder, cosine_similarity, supported_models};
 
 /// Default embedding dimensions for the all-MiniLM-L6-v2 model.
 ///
@@ -326,6 +326,53 @@ pub fn gc_stale_branches(&self, project_id: &str, dry_run: bool) -> Result<GcRes
         })
     }
 
+    /// Returns the stale branches of a project and their active memories.
+    ///
+    /// Memories that already carry a tombstone timestamp are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the git repository cannot be discovered or the
+    /// index backend operations fail.
+    pub fn find_stale_branch_memories(
+        &self,
+        project_id: &str,
+    ) -> Result<(Vec<String>, Vec<crate::models::Memory>)> {
+        use crate::models::SearchFilter;
+
+        let repo = self.discover_repository()?;
+        let current_branches = Self::get_current_branches(&repo)?;
+        let mut stale_branches: Vec<String> = self
+            .get_indexed_branches(project_id)?
+            .into_iter()
+            .filter(|branch| !current_branches.contains(branch))
+            .collect();
+        stale_branches.sort();
+
+        let mut memories = Vec::new();
+        for branch in &stale_branches {
+            let filter = SearchFilter::new()
+                .with_project_id(project_id)
+                .with_branch(branch)
+                .with_include_tombstoned(false);
+            let ids: Vec<_> = self
+                .index
+                .list_all(&filter, 10000)?
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+            memories.extend(
+                self.index
+                    .get_memories_batch(&ids)?
+                    .into_iter()
+                    .flatten()
+                    .filter(|m| m.tombstoned_at.is_none()),
+            );
+        }
+
+        Ok((stale_branches, memories))
+    }
+
     /// Discovers the git repository.
     fn discover_repository(&self) -> Result<Repository> {
         let path = self.repo_path.as_deref().map_or_else(
@@ -582,7 +629,7 @@ pub fn branch_exists(branch: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
     use crate::storage::index::SqliteBackend;
     use git2::Signature;
     use tempfile::TempDir;
@@ -590,7 +637,7 @@ mod tests {
     fn create_test_memory(id: &str, project_id: &str, branch: &str) -> Memory {
         Memory {
             id: MemoryId::new(id),
-            content: format!("Test memory for {branch}"),
+            content: format!("Test memory {id} for {branch}"),
             namespace: Namespace::Decisions,
             domain: Domain::new(),
             project_id: Some(project_id.to_string()),
@@ -609,6 +656,13 @@ fn create_test_memory(id: &str, project_id: &str, branch: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -48,7 +48,7 @@
 //! ```
 
 use crate::Result;
-use crate::models::SearchFilter;
+use crate::models::{Memory, SearchFilter};
 use crate::storage::traits::IndexBackend;
 use chrono::{TimeZone, Utc};
 use std::sync::Arc;
@@ -389,6 +389,32 @@ pub fn gc_expired_memories(&self, dry_run: bool) -> Result<ExpirationGcResult> {
         Ok(result)
     }
 
+    /// Returns the active memories whose TTL has passed.
+    ///
+    /// Memories that already carry a tombstone timestamp are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if index backend operations fail.
+    pub fn find_expired(&self) -> Result<Vec<Memory>> {
+        let now = crate::current_timestamp();
+        let filter = SearchFilter::new().with_include_tombstoned(false);
+        let ids: Vec<_> = self
+            .index
+            .list_all(&filter, self.config.batch_limit)?
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+
+        Ok(self
+            .index
+            .get_memories_batch(&ids)?
+            .into_iter()
+            .flatten()
+            .filter(|m| m.tombstoned_at.is_none() && m.expires_at.is_some_and(|at| at < now))
+            .collect())
+    }
+
     /// Returns the current expiration configuration.
     #[must_use]
     pub const fn config(&self) -> &ExpirationConfig {
@@ -428,7 +454,7 @@ fn rand_float() -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::models::{CaptureOrigin, Domain, MemoryId, MemoryStatus, Namespace};
     use crate::storage::index::SqliteBackend;
 
     fn create_test_memory(id: &str, namespace: Namespace, expires_at: Option<u64>) -> Memory {
@@ -454,6 +480,13 @@ fn create_test_memory(id: &str, namespace: Namespace, expires_at: Option<u64>) -
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -0,0 +1,453 @@
+//! Unified garbage collection.
+//!
+//! Runs the TTL, stale-branch and retention collectors against every index
+//! in one pass under a maintenance lock. `subcog gc --all` and the `serve`
+//! scheduler both use [`GcOrchestrator`].
+//!
+//! Collectors run in order of specificity: explicit TTLs first, then stale
+//! branches, then the age-based retention policy. A memory is tombstoned
+//! once, by the first collector that claims it; later claims are counted
+//! as conflicts. Memories with an explicit TTL are left to the TTL
+//! collector, since their `expires_at` overrides the retention policy.
+
+use super::{
+    BranchGarbageCollector, ExpirationConfig, ExpirationService, RetentionConfig,
+    RetentionGarbageCollector,
+};
+use crate::models::{Memory, MemoryId, MemoryStatus};
+use crate::storage::index::DomainScope;
+use crate::storage::traits::IndexBackend;
+use crate::{Error, Result};
+use chrono::{TimeZone, Utc};
+use fs2::FileExt;
+use std::collections::HashSet;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{info, instrument, warn};
+
+/// Name of the maintenance lock file in the data directory.
+pub const MAINTENANCE_LOCK_FILE: &str = "maintenance.lock";
+
+/// Exclusive lock held for the duration of a maintenance run.
+///
+/// The lock is an advisory file lock (`flock` on Unix, `LockFileEx` on
+/// Windows) on a file in the data directory. The operating system releases
+/// it when the holder exits, so a crashed run never leaves a stale lock and
+/// two runs can never both acquire it. The file itself is left in place.
+#[derive(Debug)]
+pub struct MaintenanceLock {
+    /// Path of the lock file.
+    path: PathBuf,
+    /// Open lock file; closing it releases the lock.
+    file: File,
+}
+
+impl MaintenanceLock {
+    /// Acquires the maintenance lock in `data_dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OperationFailed`] if another run holds the lock or the
+    /// lock file cannot be opened.
+    pub fn acquire(data_dir: &Path) -> Result<Self> {
+        let failed = |cause: String| Error::OperationFailed {
+            operation: "acquire_maintenance_lock".to_string(),
+            cause,
+        };
+        fs::create_dir_all(data_dir).map_err(|e| failed(e.to_string()))?;
+
+        let path = data_dir.join(MAINTENANCE_LOCK_FILE);
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .map_err(|e| failed(e.to_string()))?;
+
+        match FileExt::try_lock_exclusive(&file) {
+            Ok(()) => {},
+            Err(e) if e.kind() == fs2::lock_contended_error().kind() => {
+                return Err(failed(format!(
+                    "another maintenance run holds {}",
+                    path.display()
+                )));
+            },
+            Err(e) => return Err(failed(e.to_string())),
+        }
+
+        // The PID is informational only; the lock is what excludes other runs
+        let _ = file.set_len(0);
+        let _ = writeln!(file, "{}", std::process::id());
+        Ok(Self { path, file })
+    }
+
+    /// Returns the path of the lock file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for MaintenanceLock {
+    fn drop(&mut self) {
+        let _ = FileExt::unlock(&self.file);
+    }
+}
+
+/// Memories claimed by the collectors of one index.
+#[derive(Default)]
+struct Claims {
+    /// IDs claimed so far.
+    claimed: HashSet<MemoryId>,
+    /// Memories to tombstone, with the collector that claimed them first.
+    doomed: Vec<(Collector, Memory)>,
+    /// Memories claimed by more than one collector.
+    conflicts: usize,
+}
+
+impl Claims {
+    /// Claims `memories` for `collector`, counting those already claimed.
+    fn claim(&mut self, collector: Collector, memories: Vec<Memory>) {
+        for memory in memories {
+            if !self.claimed.insert(memory.id.clone()) {
+                self.conflicts += 1;
+                continue;
+            }
+            self.doomed.push((collector, memory));
+        }
+    }
+}
+
+/// An index to collect, with the context the branch collector needs.
+pub struct GcScope {
+    /// Domain scope of the index.
+    pub scope: DomainScope,
+    /// Index backend.
+    pub index: Arc<dyn IndexBackend + Send + Sync>,
+    /// Project whose stale branches are collected. Without one, branch GC is
+    /// skipped for this index.
+    pub project_id: Option<String>,
+    /// Git repository of the project. Defaults to the current directory.
+    pub repo_path: Option<PathBuf>,
+}
+
+/// Result of a unified garbage collection run.
+#[derive(Debug, Clone, Default)]
+pub struct UnifiedGcReport {
+    /// Whether this was a dry run.
+    pub dry_run: bool,
+    /// Scopes whose indexes were processed.
+    pub scopes_processed: Vec<DomainScope>,
+    /// Memories tombstoned because their TTL passed.
+    pub expired_ttl: usize,
+    /// Memories tombstoned because their branch was deleted.
+    pub stale_branch: usize,
+    /// Memories tombstoned by the retention policy.
+    pub retention: usize,
+    /// Memories claimed by more than one collector (counted once above).
+    pub conflicts: usize,
+    /// Stale branches found across all scopes.
+    pub stale_branches: Vec<String>,
+    /// Duration of the run in milliseconds.
+    pub duration_ms: u64,
+}
+
+impl UnifiedGcReport {
+    /// Returns the total number of memories tombstoned.
+    #[must_use]
+    pub const fn total_tombstoned(&self) -> usize {
+        self.expired_ttl + self.stale_branch + self.retention
+    }
+
+    /// Returns a human-readable summary of the run.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let action = if self.dry_run {
+            "Would tombstone"
+        } else {
+            "Tombstoned"
+        };
+        let scopes: Vec<&str> = self
+            .scopes_processed
+            .iter()
+            .map(DomainScope::as_str)
+            .collect();
+        format!(
+            "{action} {} memories ({} TTL-expired, {} stale-branch, {} retention, {} conflicts) \
+             across {} in {}ms",
+            self.total_tombstoned(),
+            self.expired_ttl,
+            self.stale_branch,
+            self.retention,
+            self.conflicts,
+            scopes.join(", "),
+            self.duration_ms
+        )
+    }
+}
+
+/// Collector that claimed a memory.
+#[derive(Debug, Clone, Copy)]
+enum Collector {
+    Expiration,
+    StaleBranch,
+    Retention,
+}
+
+/// Runs all garbage collectors against a set of indexes.
+pub struct GcOrchestrator {
+    /// Indexes to collect.
+    scopes: Vec<GcScope>,
+    /// Retention policy.
+    retention: RetentionConfig,
+    /// TTL expiration settings.
+    expiration: ExpirationConfig,
+}
+
+impl GcOrchestrator {
+    /// Creates an orchestrator with no scopes.
+    #[must_use]
+    pub const fn new(retention: RetentionConfig, expiration: ExpirationConfig) -> Self {
+        Self {
+            scopes: Vec::new(),
+            retention,
+            expiration,
+        }
+    }
+
+    /// Adds an index to collect.
+    #[must_use]
+    pub fn with_scope(mut self, scope: GcScope) -> Self {
+        self.scopes.push(scope);
+        self
+    }
+
+    /// Runs the collectors against every scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a collector or a tombstone update fails.
+    #[instrument(
+        name = "subcog.gc.all",
+        skip(self),
+        fields(component = "gc", operation = "all", dry_run = dry_run)
+    )]
+    pub fn run(&self, dry_run: bool) -> Result<UnifiedGcReport> {
+        let start = Instant::now();
+        let mut report = UnifiedGcReport {
+            dry_run,
+            ..UnifiedGcReport::default()
+        };
+
+        for scope in &self.scopes {
+            self.run_scope(scope, dry_run, &mut report)?;
+            report.scopes_processed.push(scope.scope);
+        }
+        report.stale_branches.sort();
+        report.stale_branches.dedup();
+        report.duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        metrics::counter!("gc_all_runs_total", "dry_run" => dry_run.to_string()).increment(1);
+        info!(
+            expired_ttl = report.expired_ttl,
+            stale_branch = report.stale_branch,
+            retention = report.retention,
+            conflicts = report.conflicts,
+            duration_ms = report.duration_ms,
+            dry_run,
+            "Unified GC completed"
+        );
+
+        Ok(report)
+    }
+
+    /// Collects one index.
+    fn run_scope(
+        &self,
+        scope: &GcScope,
+        dry_run: bool,
+        report: &mut UnifiedGcReport,
+    ) -> Result<()> {
+        let mut claims = Claims::default();
+
+        let expiration = ExpirationService::new(Arc::clone(&scope.index), self.expiration.clone());
+        claims.claim(Collector::Expiration, expiration.find_expired()?);
+
+        if let Some(ref project_id) = scope.project_id {
+            let branches = scope.repo_path.as_ref().map_or_else(
+                || BranchGarbageCollector::new(Arc::clone(&scope.index)),
+                |path| BranchGarbageCollector::with_repo_path(Arc::clone(&scope.index), path),
+            );
+            let (stale, memories) = branches.find_stale_branch_memories(project_id)?;
+            report.stale_branches.extend(stale);
+            claims.claim(Collector::StaleBranch, memories);
+        }
+
+        let retention =
+            RetentionGarbageCollector::new(Arc::clone(&scope.index), self.retention.clone());
+        let aged_out = retention
+            .find_expired()?
+            .into_iter()
+            .filter(|memory| memory.expires_at.is_none())
+            .collect();
+        claims.claim(Collector::Retention, aged_out);
+        report.conflicts += claims.conflicts;
+
+        let now = crate::current_timestamp();
+        let now_dt = Utc
+            .timestamp_opt(i64::try_from(now).unwrap_or(i64::MAX), 0)
+            .single()
+            .unwrap_or_else(Utc::now);
+        for (collector, mut memory) in claims.doomed {
+            if !dry_run {
+                memory.status = MemoryStatus::Tombstoned;
+                memory.tombstoned_at = Some(now_dt);
+                memory.updated_at = now;
+                scope.index.index(&memory)?;
+            }
+            match collector {
+                Collector::Expiration => report.expired_ttl += 1,
+                Collector::StaleBranch => report.stale_branch += 1,
+                Collector::Retention => report.retention += 1,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Domain, Namespace};
+    use crate::storage::index::SqliteBackend;
+    use git2::{Repository, Signature};
+    use tempfile::TempDir;
+
+    const PROJECT: &str = "github.com/org/repo";
+
+    fn memory(id: &str, branch: &str, created_at: u64, expires_at: Option<u64>) -> Memory {
+        Memory {
+            id: MemoryId::new(id),
+            content: format!("Test memory {id}"),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: Some(PROJECT.to_string()),
+            branch: Some(branch.to_string()),
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at,
+            updated_at: created_at,
+            tombstoned_at: None,
+            expires_at,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    /// Creates a repository with one commit and returns its current branch.
+    fn create_test_repo() -> (TempDir, String) {
+        let dir = TempDir::new().expect("temp dir");
+        let repo = Repository::init(dir.path()).expect("init repo");
+        let sig = Signature::now("test", "test@test.com").expect("signature");
+        let tree_id = repo.index().expect("index").write_tree().expect("tree");
+        let tree = repo.find_tree(tree_id).expect("find tree");
+        repo.commit(Some("HEAD"), &sig, &sig, "Initial commit", &tree, &[])
+            .expect("commit");
+        let branch = repo
+            .head()
+            .expect("head")
+            .shorthand()
+            .expect("branch name")
+            .to_string();
+        (dir, branch)
+    }
+
+    #[test]
+    fn test_run_reports_every_collector_in_one_pass() {
+        let (dir, live) = create_test_repo();
+        let index = Arc::new(SqliteBackend::in_memory().expect("index"));
+        let now = crate::current_timestamp();
+        let old = 1_000_000;
+        for m in [
+            memory("ttl", &live, now, Some(now - 60)),
+            memory("branch", "feature/gone", now, None),
+            memory("old", &live, old, None),
+            memory("ttl-and-old", &live, old, Some(now - 60)),
+            memory("branch-and-old", "feature/gone", old, None),
+            memory("fresh", &live, now, Some(now + 3600)),
+        ] {
+            index.index(&m).expect("seed");
+        }
+
+        let orchestrator =
+            GcOrchestrator::new(RetentionConfig::default(), ExpirationConfig::default())
+                .with_scope(GcScope {
+                    scope: DomainScope::Project,
+                    index: Arc::clone(&index) as Arc<dyn IndexBackend + Send + Sync>,
+                    project_id: Some(PROJECT.to_string()),
+                    repo_path: Some(dir.path().to_path_buf()),
+                });
+
+        let preview = orchestrator.run(true).expect("dry run");
+        assert_eq!(preview.total_tombstoned(), 5);
+        let active = index.get_memory(&MemoryId::new("ttl")).expect("get");
+        assert_eq!(active.expect("memory").status, MemoryStatus::Active);
+
+        let report = orchestrator.run(false).expect("run");
+        assert!(!report.dry_run);
+        // "ttl-and-old" is left to its TTL; "branch-and-old" is a conflict
+        assert_eq!(report.expired_ttl, 2);
+        assert_eq!(report.stale_branch, 2);
+        assert_eq!(report.retention, 1);
+        assert_eq!(report.conflicts, 1);
+        assert_eq!(report.stale_branches, vec!["feature/gone".to_string()]);
+        assert_eq!(report.scopes_processed, vec![DomainScope::Project]);
+
+        for (id, status) in [
+            ("ttl", MemoryStatus::Tombstoned),
+            ("branch", MemoryStatus::Tombstoned),
+            ("old", MemoryStatus::Tombstoned),
+            ("ttl-and-old", MemoryStatus::Tombstoned),
+            ("branch-and-old", MemoryStatus::Tombstoned),
+            ("fresh", MemoryStatus::Active),
+        ] {
+            let stored = index.get_memory(&MemoryId::new(id)).expect("get");
+            assert_eq!(stored.expect("memory").status, status, "{id}");
+        }
+
+        // Everything eligible is already tombstoned
+        assert_eq!(
+            orchestrator.run(false).expect("rerun").total_tombstoned(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_maintenance_lock_is_exclusive() {
+        let dir = TempDir::new().expect("temp dir");
+        let lock = MaintenanceLock::acquire(dir.path()).expect("acquire");
+        assert!(lock.path().exists());
+        assert!(MaintenanceLock::acquire(dir.path()).is_err());
+
+        drop(lock);
+        assert!(MaintenanceLock::acquire(dir.path()).is_ok());
+    }
+}
@@ -50,6 +50,17 @@
 //! println!("Tombstoned {} expired memories", result.memories_tombstoned);
 //! ```
 //!
+//! # Pruning Auto-Captures
+//!
+//! Low-confidence hook captures can be tombstoned with [`AutoCapturePruner`]
+//! (`subcog prune --auto-captured --confidence-below 0.5`).
+//!
+//! # Unified GC
+//!
+//! [`GcOrchestrator`] runs the TTL, stale-branch and retention collectors
+//! against every index in one pass, holding a [`MaintenanceLock`]
+//! (`subcog gc --all`, and the `serve` scheduler when `[gc] interval` is set).
+//!
 //! # Lazy GC
 //!
 //! The garbage collector can be integrated into the recall path for lazy,
@@ -58,6 +69,8 @@
 
 mod branch;
 mod expiration;
+mod maintenance;
+mod prune;
 mod retention;
 
 pub use branch::{BranchGarbageCollector, GcResult, branch_exists};
@@ -65,6 +78,10 @@
     DEFAULT_CLEANUP_PROBABILITY, EXPIRATION_CLEANUP_PROBABILITY_ENV, ExpirationConfig,
     ExpirationGcResult, ExpirationService,
 };
+pub use maintenance::{
+    GcOrchestrator, GcScope, MAINTENANCE_LOCK_FILE, MaintenanceLock, UnifiedGcReport,
+};
+pub use prune::{AutoCapturePruner, PruneCandidate, PruneConfig, PruneResult};
 pub use retention::{
     DEFAULT_RETENTION_DAYS, RETENTION_DAYS_ENV, RetentionConfig, RetentionGarbageCollector,
     RetentionGcResult, retention_days,
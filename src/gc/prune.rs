@@ -0,0 +1,426 @@
+//! Low-confidence auto-capture pruning.
+//!
+//! Identifies and tombstones hook-captured memories whose recorded capture
+//! confidence is below a threshold, optionally restricted to memories older
+//! than a given age.
+//!
+//! Manual captures are never touched unless explicitly included. Memories
+//! without a recorded confidence (e.g. captured before confidence was stored)
+//! are skipped, since there is no evidence they are low quality.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::gc::{AutoCapturePruner, PruneConfig};
+//! use std::time::Duration;
+//!
+//! let config = PruneConfig::new(0.6).with_older_than(Duration::from_secs(30 * 86400));
+//! let pruner = AutoCapturePruner::new(backend, config);
+//!
+//! // Preview what would be pruned
+//! let preview = pruner.prune(true)?;
+//! for candidate in &preview.candidates {
+//!     println!("{} ({:.2})", candidate.id, candidate.confidence);
+//! }
+//!
+//! // Tombstone the candidates
+//! let result = pruner.prune(false)?;
+//! println!("{}", result.summary());
+//! ```
+
+use crate::Result;
+use crate::models::{
+    CaptureOrigin, EventMeta, MemoryEvent, MemoryId, MemoryStatus, Namespace, SearchFilter,
+};
+use crate::observability::current_request_id;
+use crate::security::record_event;
+use crate::storage::traits::IndexBackend;
+use chrono::{TimeZone, Utc};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, instrument, warn};
+
+/// Configuration for pruning low-confidence auto-captures.
+#[derive(Debug, Clone)]
+pub struct PruneConfig {
+    /// Memories with a confidence strictly below this value are pruned.
+    pub confidence_below: f32,
+
+    /// Only prune memories created at least this many seconds ago.
+    pub older_than_secs: Option<u64>,
+
+    /// Whether manual captures are also eligible.
+    pub include_manual: bool,
+
+    /// Maximum memories to examine in a single run.
+    pub batch_limit: usize,
+}
+
+impl PruneConfig {
+    /// Creates a config pruning hook captures below `confidence_below`.
+    #[must_use]
+    pub const fn new(confidence_below: f32) -> Self {
+        Self {
+            confidence_below,
+            older_than_secs: None,
+            include_manual: false,
+            batch_limit: 10000,
+        }
+    }
+
+    /// Restricts pruning to memories older than `age`.
+    #[must_use]
+    pub const fn with_older_than(mut self, age: Duration) -> Self {
+        self.older_than_secs = Some(age.as_secs());
+        self
+    }
+
+    /// Sets whether manual captures are also eligible.
+    #[must_use]
+    pub const fn with_include_manual(mut self, include_manual: bool) -> Self {
+        self.include_manual = include_manual;
+        self
+    }
+
+    /// Sets the batch limit.
+    #[must_use]
+    pub const fn with_batch_limit(mut self, limit: usize) -> Self {
+        self.batch_limit = limit;
+        self
+    }
+
+    /// Returns the capture origins eligible for pruning.
+    #[must_use]
+    pub fn origins(&self) -> Vec<CaptureOrigin> {
+        if self.include_manual {
+            vec![CaptureOrigin::Hook, CaptureOrigin::Manual]
+        } else {
+            vec![CaptureOrigin::Hook]
+        }
+    }
+}
+
+/// A memory selected for pruning.
+#[derive(Debug, Clone)]
+pub struct PruneCandidate {
+    /// Memory ID.
+    pub id: MemoryId,
+    /// Memory namespace.
+    pub namespace: Namespace,
+    /// How the memory was captured.
+    pub origin: CaptureOrigin,
+    /// Recorded capture confidence.
+    pub confidence: f32,
+    /// Creation timestamp (Unix epoch seconds).
+    pub created_at: u64,
+}
+
+/// Result of a prune operation.
+#[derive(Debug, Clone, Default)]
+pub struct PruneResult {
+    /// Total number of memories checked.
+    pub memories_checked: usize,
+
+    /// Memories selected for pruning.
+    pub candidates: Vec<PruneCandidate>,
+
+    /// Number of memories that were tombstoned (0 for dry runs).
+    pub memories_tombstoned: usize,
+
+    /// Number of memories skipped because no confidence was recorded.
+    pub skipped_unknown_confidence: usize,
+
+    /// Whether this was a dry run (no actual changes made).
+    pub dry_run: bool,
+
+    /// Duration of the operation in milliseconds.
+    pub duration_ms: u64,
+}
+
+impl PruneResult {
+    /// Returns a human-readable summary of the prune result.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        if self.candidates.is_empty() {
+            return format!(
+                "No low-confidence memories found ({} memories checked in {}ms)",
+                self.memories_checked, self.duration_ms
+            );
+        }
+        if self.dry_run {
+            format!(
+                "would tombstone {} low-confidence memories - checked {} in {}ms",
+                self.candidates.len(),
+                self.memories_checked,
+                self.duration_ms
+            )
+        } else {
+            format!(
+                "tombstoned {} low-confidence memories - checked {} in {}ms",
+                self.memories_tombstoned, self.memories_checked, self.duration_ms
+            )
+        }
+    }
+}
+
+/// Tombstones low-confidence automatic captures.
+pub struct AutoCapturePruner {
+    /// Reference to the index backend for querying and updating memories.
+    index: Arc<dyn IndexBackend + Send + Sync>,
+
+    /// Prune configuration.
+    config: PruneConfig,
+}
+
+impl AutoCapturePruner {
+    /// Creates a new pruner.
+    #[must_use]
+    pub fn new(index: Arc<dyn IndexBackend + Send + Sync>, config: PruneConfig) -> Self {
+        // Arc::strong_count prevents clippy::missing_const_for_fn false positive
+        let _ = Arc::strong_count(&index);
+        Self { index, config }
+    }
+
+    /// Finds low-confidence memories and tombstones them (unless `dry_run`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if index backend operations fail.
+    #[instrument(
+        name = "subcog.gc.prune",
+        skip(self),
+        fields(
+            component = "gc",
+            operation = "prune",
+            dry_run = dry_run,
+            confidence_below = self.config.confidence_below
+        )
+    )]
+    pub fn prune(&self, dry_run: bool) -> Result<PruneResult> {
+        let start = Instant::now();
+        let mut result = PruneResult {
+            dry_run,
+            ..Default::default()
+        };
+
+        let now = crate::current_timestamp();
+        let cutoff = self
+            .config
+            .older_than_secs
+            .map(|secs| now.saturating_sub(secs));
+
+        let origins = self.config.origins();
+        let mut filter = SearchFilter::new().with_include_tombstoned(false);
+        for origin in &origins {
+            filter = filter.with_origin(*origin);
+        }
+        let memories = self.index.list_all(&filter, self.config.batch_limit)?;
+
+        let now_dt = Utc
+            .timestamp_opt(i64::try_from(now).unwrap_or(i64::MAX), 0)
+            .single()
+            .unwrap_or_else(Utc::now);
+
+        for (id, _score) in memories {
+            result.memories_checked += 1;
+
+            let Some(memory) = self.index.get_memory(&id)? else {
+                continue;
+            };
+            // Defends against backends that ignore the origin filter
+            if !origins.contains(&memory.origin) {
+                continue;
+            }
+            let Some(confidence) = memory.confidence else {
+                result.skipped_unknown_confidence += 1;
+                continue;
+            };
+            if confidence >= self.config.confidence_below {
+                continue;
+            }
+            if cutoff.is_some_and(|cutoff| memory.created_at > cutoff) {
+                continue;
+            }
+
+            debug!(memory_id = %id.as_str(), confidence, "Memory selected for pruning");
+            result.candidates.push(PruneCandidate {
+                id: id.clone(),
+                namespace: memory.namespace,
+                origin: memory.origin,
+                confidence,
+                created_at: memory.created_at,
+            });
+
+            if dry_run {
+                continue;
+            }
+
+            let mut updated = memory;
+            updated.status = MemoryStatus::Tombstoned;
+            updated.tombstoned_at = Some(now_dt);
+            updated.updated_at = now;
+
+            let Err(e) = self.index.index(&updated) else {
+                result.memories_tombstoned += 1;
+                record_event(MemoryEvent::Updated {
+                    meta: EventMeta::with_timestamp("gc.prune", current_request_id(), now),
+                    memory_id: id,
+                    modified_fields: vec!["status".to_string(), "tombstoned_at".to_string()],
+                });
+                continue;
+            };
+
+            warn!(
+                memory_id = %id.as_str(),
+                error = %e,
+                "Failed to tombstone low-confidence memory"
+            );
+        }
+
+        result.duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+
+        metrics::counter!(
+            "gc_prune_runs_total",
+            "dry_run" => dry_run.to_string()
+        )
+        .increment(1);
+
+        info!(
+            memories_checked = result.memories_checked,
+            candidates = result.candidates.len(),
+            memories_tombstoned = result.memories_tombstoned,
+            duration_ms = result.duration_ms,
+            dry_run,
+            "Prune completed"
+        );
+
+        Ok(result)
+    }
+
+    /// Returns the current prune configuration.
+    #[must_use]
+    pub const fn config(&self) -> &PruneConfig {
+        &self.config
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Domain, Memory};
+    use crate::storage::index::SqliteBackend;
+
+    const DAY: u64 = 86400;
+
+    fn seed(
+        index: &Arc<dyn IndexBackend + Send + Sync>,
+        id: &str,
+        origin: CaptureOrigin,
+        confidence: Option<f32>,
+        age_days: u64,
+    ) {
+        let created_at = crate::current_timestamp() - age_days * DAY;
+        let memory = Memory {
+            id: MemoryId::new(id),
+            content: format!("Test memory {id}"),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at,
+            updated_at: created_at,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin,
+            confidence,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        };
+        index.index(&memory).unwrap();
+    }
+
+    fn is_tombstoned(index: &Arc<dyn IndexBackend + Send + Sync>, id: &str) -> bool {
+        index
+            .get_memory(&MemoryId::new(id))
+            .unwrap()
+            .is_some_and(|m| m.status == MemoryStatus::Tombstoned)
+    }
+
+    fn seeded_index() -> Arc<dyn IndexBackend + Send + Sync> {
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        seed(&index, "hook-low-old", CaptureOrigin::Hook, Some(0.3), 60);
+        seed(&index, "hook-low-new", CaptureOrigin::Hook, Some(0.4), 1);
+        seed(&index, "hook-high", CaptureOrigin::Hook, Some(0.9), 60);
+        seed(&index, "hook-unknown", CaptureOrigin::Hook, None, 60);
+        seed(&index, "manual-low", CaptureOrigin::Manual, Some(0.2), 60);
+        seed(&index, "manual-none", CaptureOrigin::Manual, None, 60);
+        seed(&index, "import-low", CaptureOrigin::Import, Some(0.1), 60);
+        index
+    }
+
+    #[test]
+    fn test_prunes_only_low_confidence_auto_captures() {
+        let index = seeded_index();
+        let pruner = AutoCapturePruner::new(Arc::clone(&index), PruneConfig::new(0.5));
+
+        let result = pruner.prune(false).unwrap();
+        assert_eq!(result.memories_tombstoned, 2);
+        assert_eq!(result.skipped_unknown_confidence, 1);
+
+        assert!(is_tombstoned(&index, "hook-low-old"));
+        assert!(is_tombstoned(&index, "hook-low-new"));
+        for id in [
+            "hook-high",
+            "hook-unknown",
+            "manual-low",
+            "manual-none",
+            "import-low",
+        ] {
+            assert!(!is_tombstoned(&index, id), "{id} should not be pruned");
+        }
+    }
+
+    #[test]
+    fn test_older_than_and_dry_run() {
+        let index = seeded_index();
+        let config = PruneConfig::new(0.5).with_older_than(Duration::from_secs(30 * DAY));
+        let pruner = AutoCapturePruner::new(Arc::clone(&index), config);
+
+        let preview = pruner.prune(true).unwrap();
+        let ids: Vec<&str> = preview.candidates.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["hook-low-old"]);
+        assert_eq!(preview.memories_tombstoned, 0);
+        assert!(!is_tombstoned(&index, "hook-low-old"));
+
+        pruner.prune(false).unwrap();
+        assert!(is_tombstoned(&index, "hook-low-old"));
+        assert!(!is_tombstoned(&index, "hook-low-new"));
+    }
+
+    #[test]
+    fn test_include_manual() {
+        let index = seeded_index();
+        let config = PruneConfig::new(0.5).with_include_manual(true);
+        let pruner = AutoCapturePruner::new(Arc::clone(&index), config);
+
+        let result = pruner.prune(false).unwrap();
+        assert_eq!(result.memories_tombstoned, 3);
+        assert!(is_tombstoned(&index, "manual-low"));
+        assert!(!is_tombstoned(&index, "manual-none"));
+        assert!(!is_tombstoned(&index, "import-low"));
+    }
+}
@@ -6,8 +6,12 @@
 //!
 //! Retention can be configured via:
 //! - Environment variable: `SUBCOG_RETENTION_DAYS` (default: 365)
-//! - Config file: `[gc] retention_days = 365`
-//! - Per-namespace overrides: `[gc.retention] decisions = 730`
+//! - Per-namespace environment overrides: `SUBCOG_RETENTION_<NAMESPACE>_DAYS`
+//! - Per-namespace config file overrides: `[retention] progress = 30`
+//!
+//! A retention period of `0` days means memories never expire. A memory with
+//! an explicit `expires_at` is governed by that timestamp instead of its
+//! namespace's retention period.
 //!
 //! # Example
 //!
@@ -34,7 +38,8 @@
 //! ```
 
 use crate::Result;
-use crate::models::{Namespace, SearchFilter};
+use crate::config::SubcogConfig;
+use crate::models::{Memory, Namespace, SearchFilter};
 use crate::storage::traits::IndexBackend;
 use chrono::{TimeZone, Utc};
 use std::collections::HashMap;
@@ -89,7 +94,7 @@ pub struct RetentionConfig {
 
     /// Per-namespace retention overrides.
     ///
-    /// Namespaces not in this map use `default_days`.
+    /// Namespaces not in this map use `default_days`. `0` means never expire.
     pub namespace_days: HashMap<Namespace, u32>,
 
     /// Minimum retention period in days (cannot go below this).
@@ -173,6 +178,20 @@ pub fn from_env() -> Self {
         config
     }
 
+    /// Creates a retention config from the environment and the `[retention]`
+    /// section of the config file.
+    ///
+    /// `SUBCOG_RETENTION_<NAMESPACE>_DAYS` variables take precedence over
+    /// the config file.
+    #[must_use]
+    pub fn from_config(config: &SubcogConfig) -> Self {
+        let mut retention = Self::from_env();
+        for (namespace, days) in &config.retention {
+            retention.namespace_days.entry(*namespace).or_insert(*days);
+        }
+        retention
+    }
+
     /// Sets the default retention period.
     #[must_use]
     pub const fn with_default_days(mut self, days: u32) -> Self {
@@ -204,7 +223,8 @@ pub fn with_namespace_days(mut self, namespace: Namespace, days: u32) -> Self {
     /// Gets the effective retention period for a namespace.
     ///
     /// Returns the namespace-specific override if set, otherwise the default.
-    /// The result is clamped to be at least `minimum_days`.
+    /// The result is clamped to be at least `minimum_days`, except for `0`,
+    /// which means memories in the namespace never expire.
     #[must_use]
     pub fn effective_days(&self, namespace: Namespace) -> u32 {
         let days = self
@@ -212,6 +232,9 @@ pub fn effective_days(&self, namespace: Namespace) -> u32 {
             .get(&namespace)
             .copied()
             .unwrap_or(self.default_days);
+        if days == 0 {
+            return 0;
+        }
 
         // Enforce minimum retention
         days.max(self.minimum_days)
@@ -220,12 +243,35 @@ pub fn effective_days(&self, namespace: Namespace) -> u32 {
     /// Returns the cutoff timestamp for expired memories in a namespace.
     ///
     /// Memories with `created_at` before this timestamp are considered expired.
+    /// Returns `None` if memories in the namespace never expire.
     #[must_use]
-    pub fn cutoff_timestamp(&self, namespace: Namespace) -> u64 {
+    pub fn cutoff_timestamp(&self, namespace: Namespace) -> Option<u64> {
+        self.cutoff_at(namespace, crate::current_timestamp())
+    }
+
+    /// Returns whether a memory has outlived its retention policy at `now`.
+    ///
+    /// An explicit `expires_at` always wins over the namespace's retention
+    /// period, in both directions.
+    #[must_use]
+    pub fn is_expired(&self, memory: &Memory, now: u64) -> bool {
+        memory.expires_at.map_or_else(
+            || {
+                self.cutoff_at(memory.namespace, now)
+                    .is_some_and(|cutoff| memory.created_at < cutoff)
+            },
+            |expires_at| expires_at < now,
+        )
+    }
+
+    /// Returns the retention cutoff for a namespace relative to `now`.
+    fn cutoff_at(&self, namespace: Namespace, now: u64) -> Option<u64> {
         let days = self.effective_days(namespace);
-        let now = crate::current_timestamp();
+        if days == 0 {
+            return None;
+        }
         let seconds_per_day: u64 = 86400;
-        now.saturating_sub(u64::from(days) * seconds_per_day)
+        Some(now.saturating_sub(u64::from(days) * seconds_per_day))
     }
 }
 
@@ -297,7 +343,7 @@ pub fn summary(&self) -> String {
 ///
 /// The garbage collector holds an `Arc` reference to the index backend,
 /// making it safe to share across threads.
-pub struct RetentionGarbageCollector<I: IndexBackend> {
+pub struct RetentionGarbageCollector<I: IndexBackend + ?Sized> {
     /// Reference to the index backend for querying and updating memories.
     index: Arc<I>,
 
@@ -305,7 +351,7 @@ pub struct RetentionGarbageCollector<I: IndexBackend> {
     config: RetentionConfig,
 }
 
-impl<I: IndexBackend> RetentionGarbageCollector<I> {
+impl<I: IndexBackend + ?Sized> RetentionGarbageCollector<I> {
     /// Creates a new retention garbage collector.
     ///
     /// # Arguments
@@ -323,9 +369,9 @@ pub fn new(index: Arc<I>, config: RetentionConfig) -> Self {
     ///
     /// This method:
     /// 1. Iterates through all namespaces
-    /// 2. For each namespace, calculates the retention cutoff
-    /// 3. Queries for memories older than the cutoff
-    /// 4. Tombstones expired memories (unless `dry_run`)
+    /// 2. Evaluates each memory against its namespace's retention period,
+    ///    or its explicit `expires_at` when set
+    /// 3. Tombstones expired memories (unless `dry_run`)
     ///
     /// # Arguments
     ///
@@ -363,7 +409,6 @@ pub fn gc_expired_memories(&self, dry_run: bool) -> Result<RetentionGcResult> {
 
         // Process each namespace with its specific retention policy
         for namespace in Namespace::user_namespaces().iter().copied() {
-            let cutoff = self.config.cutoff_timestamp(namespace);
             let retention_days = self.config.effective_days(namespace);
             let _span = info_span!(
                 "subcog.gc.retention.namespace",
@@ -374,10 +419,10 @@ pub fn gc_expired_memories(&self, dry_run: bool) -> Result<RetentionGcResult> {
 
             debug!(
                 namespace = namespace.as_str(),
-                retention_days, cutoff, "Processing namespace for retention GC"
+                retention_days, "Processing namespace for retention GC"
             );
 
-            let count = self.process_namespace(namespace, cutoff, now, dry_run, &mut result)?;
+            let count = self.process_namespace(namespace, now, dry_run, &mut result)?;
 
             if count > 0 {
                 result
@@ -418,7 +463,6 @@ pub fn gc_expired_memories(&self, dry_run: bool) -> Result<RetentionGcResult> {
     fn process_namespace(
         &self,
         namespace: Namespace,
-        cutoff: u64,
         now: u64,
         dry_run: bool,
         result: &mut RetentionGcResult,
@@ -433,13 +477,12 @@ fn process_namespace(
         for (id, _score) in memories {
             result.memories_checked += 1;
 
-            // Get the full memory to check created_at
+            // Get the full memory to check created_at and expires_at
             let Some(memory) = self.index.get_memory(&id)? else {
                 continue;
             };
 
-            // Check if memory has expired
-            if memory.created_at >= cutoff {
+            if !self.config.is_expired(&memory, now) {
                 continue;
             }
 
@@ -474,6 +517,39 @@ fn process_namespace(
         Ok(tombstoned)
     }
 
+    /// Returns the active memories older than their namespace's retention period.
+    ///
+    /// Memories with an explicit `expires_at` are judged by it instead.
+    /// Memories that already carry a tombstone timestamp are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if index backend operations fail.
+    pub fn find_expired(&self) -> Result<Vec<Memory>> {
+        let now = crate::current_timestamp();
+        let mut expired = Vec::new();
+        for namespace in Namespace::user_namespaces().iter().copied() {
+            let filter = SearchFilter::new()
+                .with_namespace(namespace)
+                .with_include_tombstoned(false);
+            let ids: Vec<_> = self
+                .index
+                .list_all(&filter, self.config.batch_limit)?
+                .into_iter()
+                .map(|(id, _)| id)
+                .collect();
+
+            expired.extend(
+                self.index
+                    .get_memories_batch(&ids)?
+                    .into_iter()
+                    .flatten()
+                    .filter(|m| m.tombstoned_at.is_none() && self.config.is_expired(m, now)),
+            );
+        }
+        Ok(expired)
+    }
+
     /// Returns the current retention configuration.
     #[must_use]
     pub const fn config(&self) -> &RetentionConfig {
@@ -484,7 +560,7 @@ pub const fn config(&self) -> &RetentionConfig {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, Memory, MemoryId, MemoryStatus};
+    use crate::models::{CaptureOrigin, Domain, MemoryId, MemoryStatus};
     use crate::storage::index::SqliteBackend;
 
     fn create_test_memory(id: &str, namespace: Namespace, created_at: u64) -> Memory {
@@ -509,6 +585,13 @@ fn create_test_memory(id: &str, namespace: Namespace, created_at: u64) -> Memory
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -561,7 +644,7 @@ fn test_effective_days_minimum_enforced() {
     fn test_cutoff_timestamp() {
         let config = RetentionConfig::new().with_default_days(30);
 
-        let cutoff = config.cutoff_timestamp(Namespace::Decisions);
+        let cutoff = config.cutoff_timestamp(Namespace::Decisions).unwrap();
         let now = crate::current_timestamp();
         let expected = now - (30 * 86400);
 
@@ -569,6 +652,33 @@ fn test_cutoff_timestamp() {
         assert!(cutoff.abs_diff(expected) <= 1);
     }
 
+    #[test]
+    fn test_zero_days_never_expires() {
+        let config = RetentionConfig::new()
+            .with_minimum_days(30)
+            .with_namespace_days(Namespace::Decisions, 0);
+
+        // Zero is not clamped to the minimum
+        assert_eq!(config.effective_days(Namespace::Decisions), 0);
+        assert!(config.cutoff_timestamp(Namespace::Decisions).is_none());
+        assert!(config.cutoff_timestamp(Namespace::Progress).is_some());
+    }
+
+    #[test]
+    fn test_from_config_reads_retention_section() {
+        let mut subcog = SubcogConfig::default();
+        subcog.retention.insert(Namespace::Progress, 30);
+        subcog.retention.insert(Namespace::Decisions, 0);
+
+        let config = RetentionConfig::from_config(&subcog);
+        assert_eq!(config.effective_days(Namespace::Progress), 30);
+        assert_eq!(config.effective_days(Namespace::Decisions), 0);
+        assert_eq!(
+            config.effective_days(Namespace::Learnings),
+            config.default_days.max(config.minimum_days)
+        );
+    }
+
     #[test]
     fn test_retention_gc_result_summary_no_expired() {
         let result = RetentionGcResult {
@@ -747,4 +857,77 @@ fn test_retention_days_from_env() {
         let days = retention_days();
         assert_eq!(days, DEFAULT_RETENTION_DAYS);
     }
+
+    #[test]
+    fn test_gc_short_window_and_never_expire_namespaces() {
+        let backend = Arc::new(SqliteBackend::in_memory().expect("Failed to create backend"));
+        let now = crate::current_timestamp();
+
+        // Both are 45 days old: past the progress window, within the default
+        for (id, namespace) in [
+            ("progress1", Namespace::Progress),
+            ("learnings1", Namespace::Learnings),
+        ] {
+            let memory = create_test_memory(id, namespace, now - (45 * 86400));
+            backend.index(&memory).expect("Failed to index memory");
+        }
+        // Far older than any window, but decisions never expire
+        let decisions =
+            create_test_memory("decisions1", Namespace::Decisions, now - (3000 * 86400));
+        backend.index(&decisions).expect("Failed to index memory");
+
+        let config = RetentionConfig::new()
+            .with_namespace_days(Namespace::Progress, 30)
+            .with_namespace_days(Namespace::Decisions, 0);
+        let gc = RetentionGarbageCollector::new(Arc::clone(&backend), config);
+
+        let result = gc.gc_expired_memories(false).expect("GC should succeed");
+        assert_eq!(result.memories_tombstoned, 1);
+        assert_eq!(result.by_namespace.get("progress"), Some(&1));
+
+        let tombstoned = |id: &str| {
+            backend
+                .get_memory(&MemoryId::new(id))
+                .expect("Failed to get memory")
+                .expect("Memory should exist")
+                .tombstoned_at
+                .is_some()
+        };
+        assert!(tombstoned("progress1"));
+        assert!(!tombstoned("learnings1"));
+        assert!(!tombstoned("decisions1"));
+    }
+
+    #[test]
+    fn test_gc_explicit_expires_at_wins() {
+        let backend = Arc::new(SqliteBackend::in_memory().expect("Failed to create backend"));
+        let now = crate::current_timestamp();
+
+        // Past the progress window, but explicitly kept for another day
+        let mut kept = create_test_memory("kept", Namespace::Progress, now - (100 * 86400));
+        kept.expires_at = Some(now + 86400);
+        backend.index(&kept).expect("Failed to index memory");
+
+        // In a never-expire namespace, but explicitly expired
+        let mut expired = create_test_memory("expired", Namespace::Decisions, now - 86400);
+        expired.expires_at = Some(now - 60);
+        backend.index(&expired).expect("Failed to index memory");
+
+        let config = RetentionConfig::new()
+            .with_namespace_days(Namespace::Progress, 30)
+            .with_namespace_days(Namespace::Decisions, 0);
+        let gc = RetentionGarbageCollector::new(Arc::clone(&backend), config);
+
+        let found: Vec<_> = gc
+            .find_expired()
+            .expect("find_expired should succeed")
+            .into_iter()
+            .map(|memory| memory.id)
+            .collect();
+        assert_eq!(found, vec![MemoryId::new("expired")]);
+
+        let result = gc.gc_expired_memories(false).expect("GC should succeed");
+        assert_eq!(result.memories_tombstoned, 1);
+        assert_eq!(result.by_namespace.get("decisions"), Some(&1));
+    }
 }
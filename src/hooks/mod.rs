@@ -45,6 +45,13 @@
 //!
 //! When no context is available, return an empty object `{}`.
 //!
+//! # Hook Input
+//!
+//! Each handler parses its stdin into the typed input of its event (see
+//! [`HookInput`]), version [`HOOK_SCHEMA_VERSION`]. Payloads that are not a
+//! JSON object, have mistyped fields, or name another event or schema version
+//! are rejected with [`Error::InvalidInput`](crate::Error::InvalidInput).
+//!
 //! # Handler Configuration
 //!
 //! All handlers use a builder pattern for dependency injection. While handlers
@@ -56,6 +63,7 @@
 //! | [`PreCompactHandler`] | [`CaptureService`](crate::services::CaptureService) | `with_capture()` |
 //! | [`UserPromptHandler`] | [`RecallService`](crate::services::RecallService) | `with_recall()` |
 //! | [`PostToolUseHandler`] | [`RecallService`](crate::services::RecallService) | `with_recall()` |
+//! | [`PostToolUseHandler`] (tool results) | [`CaptureService`](crate::services::CaptureService) | `with_tool_capture()` |
 //! | [`StopHandler`] | [`SyncService`](crate::services::SyncService) | `with_sync()` |
 //! | [`SessionStartHandler`] | [`ContextBuilderService`](crate::services::ContextBuilderService) | `with_context_builder()` |
 //!
@@ -68,15 +76,22 @@
 
 mod post_tool_use;
 mod pre_compact;
+mod schema;
 mod search_context;
 mod search_intent;
 mod search_patterns;
 mod session_start;
 mod stop;
+mod tool_capture;
 mod user_prompt;
 
 pub use post_tool_use::PostToolUseHandler;
-pub use pre_compact::PreCompactHandler;
+pub use pre_compact::{NamespaceSuggestion, PreCompactHandler};
+pub use schema::{
+    ConversationSection, HOOK_SCHEMA_VERSION, HookCommonInput, HookInput, HookOutput,
+    HookSpecificOutput, NamespaceStatsInput, PostToolUseInput, PreCompactInput, SessionStartInput,
+    StopInput, TagUsageInput, UserPromptSpecificData, UserPromptSubmitInput,
+};
 pub use search_context::{
     AdaptiveContextConfig, InjectedMemory, MemoryContext, NamespaceWeights, SearchContextBuilder,
 };
@@ -99,6 +114,7 @@ pub trait HookHandler: Send + Sync {
     ///
     /// # Errors
     ///
-    /// Returns an error if handling fails.
+    /// Returns [`Error::InvalidInput`](crate::Error::InvalidInput) if the
+    /// input does not match the event's schema, or an error if handling fails.
     fn handle(&self, input: &str) -> Result<String>;
 }
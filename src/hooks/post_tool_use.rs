@@ -1,24 +1,33 @@
 //! Post tool use hook handler.
 
-use super::HookHandler;
+use super::tool_capture::ToolResultCapture;
+use super::{HookHandler, HookInput, HookOutput, PostToolUseInput};
 use crate::Result;
+use crate::config::ToolCapturePattern;
+use crate::context::SubcogIgnore;
 use crate::models::{IssueSeverity, SearchFilter, SearchMode, validate_prompt_content};
 use crate::observability::current_request_id;
-use crate::services::RecallService;
+use crate::services::{CaptureService, RecallService};
 use std::fmt::Write;
+use std::path::Path;
 use std::time::Instant;
 use tracing::instrument;
 
 /// Handles `PostToolUse` hook events.
 ///
-/// Surfaces related memories after tool usage.
+/// Surfaces related memories after tool usage and, when configured, captures
+/// notable tool results such as failing test runs.
 pub struct PostToolUseHandler {
     /// Recall service for searching memories.
     recall: Option<RecallService>,
+    /// Captures notable tool results (`[auto_capture] from_tools`).
+    tool_capture: Option<ToolResultCapture>,
     /// Maximum number of memories to surface.
     max_memories: usize,
     /// Minimum relevance score to surface.
     min_relevance: f32,
+    /// Paths no memories are surfaced for (`.subcogignore`).
+    ignore: Option<SubcogIgnore>,
 }
 
 /// Tools that may benefit from memory context.
@@ -32,8 +41,10 @@ impl PostToolUseHandler {
     pub const fn new() -> Self {
         Self {
             recall: None,
+            tool_capture: None,
             max_memories: 3,
             min_relevance: 0.5,
+            ignore: None,
         }
     }
 
@@ -44,6 +55,17 @@ pub fn with_recall(mut self, recall: RecallService) -> Self {
         self
     }
 
+    /// Enables capture of tool results matching `patterns`.
+    #[must_use]
+    pub fn with_tool_capture(
+        mut self,
+        capture: CaptureService,
+        patterns: Vec<ToolCapturePattern>,
+    ) -> Self {
+        self.tool_capture = Some(ToolResultCapture::new(capture, patterns));
+        self
+    }
+
     /// Sets the maximum number of memories to surface.
     #[must_use]
     pub const fn with_max_memories(mut self, max: usize) -> Self {
@@ -58,6 +80,13 @@ pub const fn with_min_relevance(mut self, min: f32) -> Self {
         self
     }
 
+    /// Skips memory lookup for tool runs on paths excluded by `ignore`.
+    #[must_use]
+    pub fn with_ignore(mut self, ignore: SubcogIgnore) -> Self {
+        self.ignore = Some(ignore);
+        self
+    }
+
     /// Determines if a tool use warrants memory lookup.
     /// Kept as method for API consistency.
     #[allow(clippy::unused_self)]
@@ -120,6 +149,18 @@ fn validate_prompt(&self, tool_input: &serde_json::Value) -> Option<String> {
         Some(guidance.join("\n"))
     }
 
+    /// Checks if a tool run targets a file excluded by `.subcogignore`.
+    fn targets_ignored_path(&self, tool_input: &serde_json::Value) -> bool {
+        let Some(ignore) = &self.ignore else {
+            return false;
+        };
+        tool_input
+            .get("file_path")
+            .or_else(|| tool_input.get("path"))
+            .and_then(|v| v.as_str())
+            .is_some_and(|path| ignore.is_ignored(Path::new(path)))
+    }
+
     /// Extracts a search query from tool input.
     /// Kept as method for API consistency.
     #[allow(clippy::unused_self)]
@@ -214,23 +255,16 @@ fn find_related_memories(&self, query: &str) -> Result<Vec<RelatedMemory>> {
     }
 
     fn empty_response() -> Result<String> {
-        Self::serialize_response(&serde_json::json!({}))
-    }
-
-    fn serialize_response(response: &serde_json::Value) -> Result<String> {
-        serde_json::to_string(response).map_err(|e| crate::Error::OperationFailed {
-            operation: "serialize_response".to_string(),
-            cause: e.to_string(),
-        })
+        HookOutput::empty().to_json()
     }
 
     fn build_memories_response(
         tool_name: &str,
         query: &str,
         memories: &[RelatedMemory],
-    ) -> serde_json::Value {
+    ) -> HookOutput {
         if memories.is_empty() {
-            return serde_json::json!({});
+            return HookOutput::empty();
         }
 
         let memories_json: Vec<serde_json::Value> = memories
@@ -278,12 +312,7 @@ fn build_memories_response(
         let context_with_metadata =
             format!("{context}\n\n<!-- subcog-metadata: {metadata_str} -->");
 
-        serde_json::json!({
-            "hookSpecificOutput": {
-                "hookEventName": "PostToolUse",
-                "additionalContext": context_with_metadata
-            }
-        })
+        HookOutput::with_context("PostToolUse", context_with_metadata)
     }
 
     fn handle_inner(
@@ -292,34 +321,28 @@ fn handle_inner(
         lookup_performed: &mut bool,
         memories_found: &mut usize,
     ) -> Result<String> {
-        let input_json: serde_json::Value =
-            serde_json::from_str(input).unwrap_or_else(|_| serde_json::json!({}));
-
-        let tool_name = input_json
-            .get("tool_name")
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
+        let input = PostToolUseInput::parse(input)?;
+        let tool_name = input.tool_name.as_str();
         let span = tracing::Span::current();
         span.record("tool_name", tool_name);
 
-        let tool_input = input_json
-            .get("tool_input")
-            .unwrap_or(&serde_json::Value::Null);
+        let tool_input = &input.tool_input;
+
+        if let Some(tool_capture) = &self.tool_capture
+            && let Some(memory_id) =
+                tool_capture.capture(tool_name, tool_input, &input.tool_response)
+        {
+            tracing::debug!(memory_id = %memory_id.as_str(), "Captured tool result");
+        }
 
         if Self::is_prompt_save_tool(tool_name) {
             if let Some(guidance) = self.validate_prompt(tool_input) {
-                let response = serde_json::json!({
-                    "hookSpecificOutput": {
-                        "hookEventName": "PostToolUse",
-                        "additionalContext": guidance
-                    }
-                });
-                return Self::serialize_response(&response);
+                return HookOutput::with_context("PostToolUse", guidance).to_json();
             }
             return Self::empty_response();
         }
 
-        if !self.should_lookup(tool_name) {
+        if !self.should_lookup(tool_name) || self.targets_ignored_path(tool_input) {
             return Self::empty_response();
         }
 
@@ -336,8 +359,7 @@ fn handle_inner(
         span.record("lookup_performed", *lookup_performed);
         span.record("memories_found", *memories_found);
 
-        let response = Self::build_memories_response(tool_name, &query, &memories);
-        Self::serialize_response(&response)
+        Self::build_memories_response(tool_name, &query, &memories).to_json()
     }
 }
 
@@ -510,6 +532,23 @@ fn test_handle_contextual_tool() {
         assert!(response.as_object().unwrap().is_empty());
     }
 
+    #[test]
+    fn test_handle_rejects_malformed_input() {
+        let handler = PostToolUseHandler::new();
+
+        for input in [
+            "{{{{",
+            r#"{"tool_name": 42}"#,
+            r#"{"hook_event_name": "PreCompact"}"#,
+        ] {
+            let result = handler.handle(input);
+            assert!(
+                matches!(result, Err(crate::Error::InvalidInput(_))),
+                "{input}"
+            );
+        }
+    }
+
     #[test]
     fn test_truncate_content() {
         let short = "Short text";
@@ -617,4 +656,113 @@ fn test_validate_prompt_missing_content() {
         let guidance = handler.validate_prompt(&input);
         assert!(guidance.is_none());
     }
+
+    #[test]
+    fn test_no_memories_surfaced_for_ignored_paths() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join(".subcogignore"), "vendor/\n").unwrap();
+        let index: std::sync::Arc<dyn crate::storage::traits::IndexBackend + Send + Sync> =
+            std::sync::Arc::new(crate::storage::index::SqliteBackend::in_memory().unwrap());
+        CaptureService::new_minimal(crate::config::Config::default())
+            .with_index(std::sync::Arc::clone(&index))
+            .capture(crate::models::CaptureRequest::new(
+                "Parser error recovery notes",
+            ))
+            .unwrap();
+        let handler = PostToolUseHandler::new()
+            .with_recall(RecallService::with_dyn_index(index))
+            .with_ignore(SubcogIgnore::load(dir.path()));
+        let read = |path: &str| {
+            let input = serde_json::json!({
+                "tool_name": "Read",
+                "tool_input": { "file_path": path }
+            });
+            let response = handler.handle(&input.to_string()).unwrap();
+            serde_json::from_str::<serde_json::Value>(&response).unwrap()
+        };
+
+        assert!(read("src/parser").get("hookSpecificOutput").is_some());
+        assert!(read("vendor/parser").as_object().unwrap().is_empty());
+    }
+
+    fn tool_capture_handler() -> (
+        PostToolUseHandler,
+        std::sync::Arc<dyn crate::storage::traits::IndexBackend + Send + Sync>,
+    ) {
+        let index: std::sync::Arc<dyn crate::storage::traits::IndexBackend + Send + Sync> =
+            std::sync::Arc::new(crate::storage::index::SqliteBackend::in_memory().unwrap());
+        let capture = CaptureService::new_minimal(crate::config::Config::default())
+            .with_index(std::sync::Arc::clone(&index));
+        let handler =
+            PostToolUseHandler::new().with_tool_capture(capture, ToolCapturePattern::defaults());
+        (handler, index)
+    }
+
+    #[test]
+    fn test_failed_test_run_is_captured() {
+        let (handler, index) = tool_capture_handler();
+        let input = serde_json::json!({
+            "tool_name": "Bash",
+            "tool_input": { "command": "cargo test -p parser" },
+            "tool_response": {
+                "stdout": "running 3 tests\ntest lexer::tests::test_empty ... ok\ntest parser::tests::test_nested ... FAILED\n\nthread 'parser::tests::test_nested' panicked at src/parser.rs:42:9:\nassertion `left == right` failed\n\ntest result: FAILED. 2 passed; 1 failed; 0 ignored",
+                "stderr": "error: test failed, to rerun pass `--lib`"
+            }
+        })
+        .to_string();
+
+        let result = handler.handle(&input).unwrap();
+        assert_eq!(result, "{}");
+
+        let captured = index.list_all(&SearchFilter::new(), 10).unwrap();
+        assert_eq!(captured.len(), 1);
+        let memory = index.get_memory(&captured[0].0).unwrap().unwrap();
+        assert_eq!(memory.namespace, crate::models::Namespace::Testing);
+        for tag in [
+            "auto-captured",
+            "post-tool-use",
+            "bash",
+            "cargo-test",
+            "failed",
+        ] {
+            assert!(memory.tags.iter().any(|t| t == tag), "missing tag {tag}");
+        }
+        assert!(
+            memory
+                .content
+                .starts_with("Test run failed: `cargo test -p parser`")
+        );
+        assert!(memory.content.contains("panicked at src/parser.rs:42:9"));
+
+        // The same failure is captured once
+        handler.handle(&input).unwrap();
+        assert_eq!(index.list_all(&SearchFilter::new(), 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_unmatched_or_passing_tool_runs_are_not_captured() {
+        let (handler, index) = tool_capture_handler();
+        let inputs = [
+            serde_json::json!({
+                "tool_name": "Bash",
+                "tool_input": { "command": "ls -la" },
+                "tool_response": { "stdout": "FAILED to list", "exit_code": 1 }
+            }),
+            serde_json::json!({
+                "tool_name": "Read",
+                "tool_input": { "file_path": "/src/lib.rs" },
+                "tool_response": "cargo test FAILED"
+            }),
+            serde_json::json!({
+                "tool_name": "Bash",
+                "tool_input": { "command": "cargo test" },
+                "tool_response": { "stdout": "test result: ok. 5 passed; 0 failed" }
+            }),
+        ];
+
+        for input in inputs {
+            assert!(handler.handle(&input.to_string()).is_ok());
+        }
+        assert!(index.list_all(&SearchFilter::new(), 10).unwrap().is_empty());
+    }
 }
@@ -3,7 +3,6 @@
 //! This module handles extracting capture candidates from conversation content
 //! using keyword-based language detection.
 
-use super::{FINGERPRINT_LENGTH, MIN_COMMON_CHARS_FOR_DUPLICATE};
 use crate::models::Namespace;
 
 /// Candidate for capture.
@@ -17,6 +16,54 @@ pub struct CaptureCandidate {
     pub confidence: f32,
 }
 
+/// A namespace recommendation for a piece of text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NamespaceSuggestion {
+    /// Suggested namespace.
+    pub namespace: Namespace,
+    /// Confidence score (0.0-1.0).
+    pub confidence: f32,
+}
+
+/// A namespace and the keyword check that classifies text into it.
+type KeywordAnalyzer = (Namespace, fn(&str) -> bool);
+
+/// Keyword analyzers in the precedence order used to classify sections.
+const KEYWORD_ANALYZERS: [KeywordAnalyzer; 5] = [
+    (Namespace::Decisions, contains_decision_language),
+    (Namespace::Learnings, contains_learning_language),
+    (Namespace::Blockers, contains_blocker_language),
+    (Namespace::Patterns, contains_pattern_language),
+    (Namespace::Context, contains_context_language),
+];
+
+/// Confidence lost per rank for lower-precedence keyword matches.
+const SUGGESTION_RANK_PENALTY: f32 = 0.1;
+
+/// Suggests namespaces for text using the keyword analyzers.
+///
+/// Every matching analyzer contributes a suggestion. The highest-precedence
+/// match (the namespace pre-compact would capture into) gets the section
+/// confidence; each later match loses [`SUGGESTION_RANK_PENALTY`].
+/// Returns an empty list if no analyzer matches.
+#[must_use]
+pub fn suggest_namespaces(text: &str) -> Vec<NamespaceSuggestion> {
+    let base = calculate_section_confidence(text);
+    KEYWORD_ANALYZERS
+        .iter()
+        .filter(|(_, matches)| matches(text))
+        .enumerate()
+        .map(|(rank, (namespace, _))| {
+            #[allow(clippy::cast_precision_loss)]
+            let penalty = SUGGESTION_RANK_PENALTY * rank as f32;
+            NamespaceSuggestion {
+                namespace: *namespace,
+                confidence: (base - penalty).max(SUGGESTION_RANK_PENALTY),
+            }
+        })
+        .collect()
+}
+
 /// Checks if text contains decision-related language.
 #[must_use]
 pub fn contains_decision_language(text: &str) -> bool {
@@ -122,9 +169,16 @@ pub fn calculate_section_confidence(section: &str) -> f32 {
 
 /// Removes duplicate/similar candidates based on content fingerprints.
 ///
-/// Keeps highest-confidence candidates when similar content is detected.
+/// The fingerprint is the first `fingerprint_length` characters; candidates
+/// whose fingerprints share more than `duplicate_prefix_chars` leading
+/// characters are duplicates. Keeps highest-confidence candidates when similar
+/// content is detected.
 #[must_use]
-pub fn deduplicate_candidates(mut candidates: Vec<CaptureCandidate>) -> Vec<CaptureCandidate> {
+pub fn deduplicate_candidates(
+    mut candidates: Vec<CaptureCandidate>,
+    fingerprint_length: usize,
+    duplicate_prefix_chars: usize,
+) -> Vec<CaptureCandidate> {
     // Sort by confidence descending
     candidates.sort_by(|a, b| {
         b.confidence
@@ -137,7 +191,7 @@ pub fn deduplicate_candidates(mut candidates: Vec<CaptureCandidate>) -> Vec<Capt
 
     for candidate in candidates {
         // Take first N chars as a "fingerprint"
-        let prefix: String = candidate.content.chars().take(FINGERPRINT_LENGTH).collect();
+        let prefix: String = candidate.content.chars().take(fingerprint_length).collect();
 
         // Check if we've seen a similar prefix
         let is_duplicate = seen_prefixes.iter().any(|p| {
@@ -146,7 +200,7 @@ pub fn deduplicate_candidates(mut candidates: Vec<CaptureCandidate>) -> Vec<Capt
                 .zip(prefix.chars())
                 .take_while(|(a, b)| a == b)
                 .count();
-            common > MIN_COMMON_CHARS_FOR_DUPLICATE
+            common > duplicate_prefix_chars
         });
 
         if !is_duplicate {
@@ -218,6 +272,25 @@ fn test_contains_context_language() {
         ));
     }
 
+    #[test]
+    fn test_suggest_namespaces_decision_text() {
+        let suggestions = suggest_namespaces(
+            "We decided to use PostgreSQL for storage because of its JSON support.",
+        );
+        assert_eq!(suggestions[0].namespace, Namespace::Decisions);
+        // "because" also matches context, ranked lower
+        assert_eq!(suggestions[1].namespace, Namespace::Context);
+        assert!(suggestions[0].confidence > suggestions[1].confidence);
+    }
+
+    #[test]
+    fn test_suggest_namespaces_blocker_text() {
+        let suggestions =
+            suggest_namespaces("Fixed the bug where the connection pool leaked handles.");
+        assert_eq!(suggestions[0].namespace, Namespace::Blockers);
+        assert!(suggest_namespaces("Hello world").is_empty());
+    }
+
     #[test]
     fn test_calculate_confidence() {
         let short_text = "Short";
@@ -254,7 +327,7 @@ fn test_deduplicate_candidates() {
             },
         ];
 
-        let result = deduplicate_candidates(candidates);
+        let result = deduplicate_candidates(candidates, 50, 30);
         // Should keep highest confidence of similar ones + the unique one
         assert_eq!(result.len(), 2);
     }
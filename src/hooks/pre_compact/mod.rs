@@ -13,18 +13,15 @@
 mod formatter;
 mod orchestrator;
 
-pub use analyzer::{
-    CaptureCandidate, calculate_section_confidence, contains_blocker_language,
-    contains_context_language, contains_decision_language, contains_learning_language,
-    contains_pattern_language,
-};
+pub use analyzer::{CaptureCandidate, NamespaceSuggestion, suggest_namespaces};
 pub use formatter::ResponseFormatter;
 // CapturedMemory and SkippedDuplicate are internal types used by orchestrator and formatter
 pub use orchestrator::CaptureOrchestrator;
 
 use crate::Result;
-use crate::hooks::HookHandler;
-use crate::llm::LlmProvider;
+use crate::config::AutoCaptureConfig;
+use crate::hooks::{HookHandler, HookInput, PreCompactInput};
+use crate::llm::{LlmProvider, extract_json_from_response};
 use crate::models::Namespace;
 use crate::observability::current_request_id;
 use crate::services::CaptureService;
@@ -34,21 +31,30 @@
 use std::time::Instant;
 use tracing::instrument;
 
-// Content analysis thresholds
-//
-// These constants are intentionally kept in the pre_compact module rather than
-// centralized in config for the following reasons:
-// 1. They are implementation details specific to the pre-compact hook algorithm
-// 2. They are not user-configurable and don't need environment variable support
-// 3. Moving them to config would increase coupling between modules
-// 4. They are compile-time constants that benefit from inlining
-
-/// Minimum section length to consider for capture.
-pub const MIN_SECTION_LENGTH: usize = 20;
-/// Length of content fingerprint for deduplication.
-pub const FINGERPRINT_LENGTH: usize = 50;
-/// Minimum common characters to consider a duplicate.
-pub const MIN_COMMON_CHARS_FOR_DUPLICATE: usize = 30;
+/// Maximum conversation characters sent to the LLM for a session summary.
+const SESSION_SUMMARY_MAX_INPUT_CHARS: usize = 24_000;
+
+/// Minimum confidence for [`PreCompactHandler::auto_namespace`] to trust a
+/// suggestion (any top-ranked keyword match qualifies).
+const AUTO_NAMESPACE_MIN_CONFIDENCE: f32 = 0.5;
+
+/// Confidence assigned to LLM-written session summaries.
+const SESSION_SUMMARY_CONFIDENCE: f32 = 0.8;
+
+/// System prompt for the session summarizer.
+const SESSION_SUMMARY_SYSTEM_PROMPT: &str = "You summarize coding sessions for a memory system. \
+Given a conversation that is about to be compacted, write one concise summary (at most 5 \
+sentences) of what was worked on, what was decided, and what remains open. Respond with JSON \
+only: {\"namespace\": \"progress\" or \"context\", \"summary\": \"...\"}. Use \"progress\" \
+when the session advanced a task and \"context\" when it mostly explained background.";
+
+/// LLM response for a session summary.
+#[derive(Debug, Deserialize)]
+struct SessionSummaryResponse {
+    #[serde(default)]
+    namespace: Option<String>,
+    summary: String,
+}
 
 /// Handler for the `PreCompact` hook event.
 ///
@@ -66,64 +72,59 @@
 ///
 /// # LLM Analysis Mode
 ///
-/// When an LLM provider is configured and `use_llm_analysis` is enabled, content
+/// When an LLM provider is configured and LLM analysis is enabled, content
 /// that doesn't match keyword-based detection patterns will be analyzed by the
 /// LLM for classification. This provides more accurate namespace assignment at
 /// the cost of increased latency.
 ///
-/// Configure via environment variable: `SUBCOG_AUTO_CAPTURE_USE_LLM=true`
+/// Configure via `[auto_capture] use_llm = true` or the environment variable
+/// `SUBCOG_AUTO_CAPTURE_USE_LLM=true`.
+///
+/// # Session Summary
+///
+/// With `[auto_capture] summarize_session = true` and an LLM provider, the
+/// handler also asks the LLM for one summary of the whole conversation and
+/// captures it in `progress` or `context`, tagged `session-summary`.
+/// Near-identical summaries are not captured twice, and summarizer failures never
+/// affect the per-section captures.
+///
+/// # Thresholds
+///
+/// Section length, near-duplicate fingerprinting, the LLM confidence threshold,
+/// and a global confidence floor come from [`AutoCaptureConfig`].
 pub struct PreCompactHandler {
     /// Capture orchestrator for coordinating captures with deduplication.
     orchestrator: CaptureOrchestrator,
     /// Optional LLM provider for content classification.
     llm: Option<Arc<dyn LlmProvider>>,
-    /// Whether to use LLM for classifying ambiguous content.
-    use_llm_analysis: bool,
-}
-
-/// Input for the `PreCompact` hook.
-#[derive(Debug, Clone, Deserialize, Default)]
-pub struct PreCompactInput {
-    /// Conversation context being compacted.
-    #[serde(default)]
-    pub context: String,
-    /// Sections of the conversation.
-    #[serde(default)]
-    pub sections: Vec<ConversationSection>,
-}
-
-/// A section of conversation.
-#[derive(Debug, Clone, Deserialize)]
-pub struct ConversationSection {
-    /// Section content.
-    pub content: String,
-    /// Type of content (user, assistant, `tool_result`).
-    #[serde(default = "default_role")]
-    pub role: String,
-}
-
-fn default_role() -> String {
-    "assistant".to_string()
+    /// Analyzer thresholds and LLM toggle.
+    config: AutoCaptureConfig,
 }
 
 impl PreCompactHandler {
     /// Creates a new `PreCompact` handler.
     ///
-    /// The `use_llm_analysis` setting is loaded from the environment variable
-    /// `SUBCOG_AUTO_CAPTURE_USE_LLM` (default: false).
+    /// Thresholds use their defaults with `SUBCOG_AUTO_CAPTURE_*` environment
+    /// overrides applied; use [`with_config`](Self::with_config) to pass the
+    /// loaded `[auto_capture]` section instead.
     #[must_use]
     pub fn new() -> Self {
-        let use_llm_analysis = std::env::var("SUBCOG_AUTO_CAPTURE_USE_LLM")
-            .map(|v| v.to_lowercase() == "true" || v == "1")
-            .unwrap_or(false);
-
+        let config = AutoCaptureConfig::from_env();
         Self {
-            orchestrator: CaptureOrchestrator::new(),
+            orchestrator: CaptureOrchestrator::new().with_min_confidence(config.min_confidence),
             llm: None,
-            use_llm_analysis,
+            config,
         }
     }
 
+    /// Sets the analyzer configuration.
+    #[must_use]
+    pub fn with_config(mut self, config: AutoCaptureConfig) -> Self {
+        self.orchestrator = self.orchestrator.with_min_confidence(config.min_confidence);
+        self.config = config;
+        self
+    }
+
     /// Sets the capture service.
     #[must_use]
     pub fn with_capture(mut self, capture: CaptureService) -> Self {
@@ -153,10 +154,10 @@ pub fn with_llm(mut self, llm: Arc<dyn LlmProvider>) -> Self {
 
     /// Enables or disables LLM analysis mode.
     ///
-    /// This overrides the `SUBCOG_AUTO_CAPTURE_USE_LLM` environment variable.
+    /// This overrides `[auto_capture] use_llm` and `SUBCOG_AUTO_CAPTURE_USE_LLM`.
     #[must_use]
     pub const fn with_llm_analysis(mut self, enabled: bool) -> Self {
-        self.use_llm_analysis = enabled;
+        self.config.use_llm = enabled;
         self
     }
 
@@ -176,8 +177,15 @@ fn analyze_content(&self, input: &PreCompactInput) -> Vec<CaptureCandidate> {
             }
         }
 
+        // Drop candidates below the global confidence floor
+        candidates.retain(|c| c.confidence >= self.config.min_confidence);
+
         // Deduplicate similar candidates
-        analyzer::deduplicate_candidates(candidates)
+        analyzer::deduplicate_candidates(
+            candidates,
+            self.config.fingerprint_length,
+            self.config.duplicate_prefix_chars,
+        )
     }
 
     /// Uses LLM to classify content that didn't match keyword detection.
@@ -188,7 +196,10 @@ fn classify_with_llm(&self, section: &str) -> Option<CaptureCandidate> {
         let llm = self.llm.as_ref()?;
 
         match llm.analyze_for_capture(section) {
-            Ok(analysis) if analysis.should_capture && analysis.confidence > 0.6 => {
+            Ok(analysis)
+                if analysis.should_capture
+                    && analysis.confidence > self.config.llm_confidence_threshold =>
+            {
                 let namespace = analysis
                     .suggested_namespace
                     .as_ref()
@@ -239,10 +250,142 @@ fn classify_with_llm(&self, section: &str) -> Option<CaptureCandidate> {
         }
     }
 
+    /// Asks the LLM for one summary of the whole conversation.
+    ///
+    /// Returns `None` if no LLM is configured, the conversation is empty, or
+    /// the LLM call fails.
+    fn summarize_session(&self, input: &PreCompactInput) -> Option<CaptureCandidate> {
+        let llm = self.llm.as_ref()?;
+
+        let context = input.context.trim();
+        let mut parts: Vec<String> = Vec::new();
+        if !context.is_empty() {
+            parts.push(context.to_string());
+        }
+        parts.extend(
+            input
+                .sections
+                .iter()
+                .filter(|section| !section.content.trim().is_empty())
+                .map(|section| format!("[{}] {}", section.role, section.content.trim())),
+        );
+        let mut transcript = parts.join("\n\n");
+        if transcript.is_empty() {
+            return None;
+        }
+        if transcript.chars().count() > SESSION_SUMMARY_MAX_INPUT_CHARS {
+            // Keep the most recent part of the conversation
+            let skip = transcript.chars().count() - SESSION_SUMMARY_MAX_INPUT_CHARS;
+            transcript = transcript.chars().skip(skip).collect();
+        }
+
+        let response = match llm.complete_with_system(SESSION_SUMMARY_SYSTEM_PROMPT, &transcript) {
+            Ok(response) => response,
+            Err(e) => {
+                tracing::warn!(error = %e, "LLM session summary failed, skipping summary");
+                return None;
+            },
+        };
+
+        let (namespace, summary) = match serde_json::from_str::<SessionSummaryResponse>(
+            extract_json_from_response(&response),
+        ) {
+            Ok(parsed) => (
+                parsed
+                    .namespace
+                    .as_deref()
+                    .and_then(Namespace::parse)
+                    .filter(|ns| *ns == Namespace::Context)
+                    .unwrap_or(Namespace::Progress),
+                parsed.summary,
+            ),
+            // Not JSON: treat the whole response as the summary
+            Err(_) => (Namespace::Progress, response),
+        };
+
+        let summary = summary.trim();
+        if summary.is_empty() {
+            return None;
+        }
+
+        Some(CaptureCandidate {
+            content: summary.to_string(),
+            namespace,
+            confidence: SESSION_SUMMARY_CONFIDENCE,
+        })
+    }
+
+    /// Suggests namespaces for text without capturing it.
+    ///
+    /// Runs the keyword analyzers and, when LLM analysis is enabled, merges in
+    /// the LLM's suggested namespace. Suggestions are sorted by confidence,
+    /// highest first.
+    #[must_use]
+    pub fn suggest_namespaces(&self, text: &str) -> Vec<NamespaceSuggestion> {
+        let text = text.trim();
+        let mut suggestions = analyzer::suggest_namespaces(text);
+
+        if let Some(suggested) = self.llm_namespace_suggestion(text) {
+            match suggestions
+                .iter_mut()
+                .find(|s| s.namespace == suggested.namespace)
+            {
+                Some(existing) => {
+                    existing.confidence = existing.confidence.max(suggested.confidence);
+                },
+                None => suggestions.push(suggested),
+            }
+        }
+
+        suggestions.sort_by(|a, b| {
+            b.confidence
+                .partial_cmp(&a.confidence)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+        suggestions
+    }
+
+    /// Asks the LLM for a namespace, when LLM analysis is enabled.
+    ///
+    /// Failures are logged and yield `None`, leaving the keyword suggestions.
+    fn llm_namespace_suggestion(&self, text: &str) -> Option<NamespaceSuggestion> {
+        if !self.config.use_llm {
+            return None;
+        }
+        let analysis = match self.llm.as_ref()?.analyze_for_capture(text) {
+            Ok(analysis) => analysis,
+            Err(e) => {
+                tracing::warn!(error = %e, "LLM namespace suggestion failed, using keywords only");
+                return None;
+            },
+        };
+        let namespace = analysis
+            .suggested_namespace
+            .as_deref()
+            .and_then(Namespace::parse)?;
+        Some(NamespaceSuggestion {
+            namespace,
+            confidence: analysis.confidence,
+        })
+    }
+
+    /// Picks a namespace for text being captured (`--namespace auto`).
+    ///
+    /// Returns the top suggestion from [`Self::suggest_namespaces`], or `None`
+    /// when nothing matches with enough confidence; callers then fall back to
+    /// `decisions`.
+    #[must_use]
+    pub fn auto_namespace(&self, text: &str) -> Option<NamespaceSuggestion> {
+        self.suggest_namespaces(text)
+            .into_iter()
+            .next()
+            .filter(|top| top.confidence >= AUTO_NAMESPACE_MIN_CONFIDENCE)
+    }
+
     /// Extracts potential memories from text.
     ///
     /// Uses keyword-based detection first, then optionally falls back to LLM
-    /// classification if `use_llm_analysis` is enabled.
+    /// classification if LLM analysis is enabled.
     fn extract_from_text(&self, text: &str) -> Vec<CaptureCandidate> {
         let mut candidates = Vec::new();
 
@@ -254,52 +397,20 @@ fn extract_from_text(&self, text: &str) -> Vec<CaptureCandidate> {
 
         for section in sections {
             let section = section.trim();
-            if section.len() < MIN_SECTION_LENGTH {
+            if section.len() < self.config.min_section_length {
                 continue;
             }
 
-            // Check for decision-related language
-            if contains_decision_language(section) {
-                candidates.push(CaptureCandidate {
-                    content: section.to_string(),
-                    namespace: Namespace::Decisions,
-                    confidence: calculate_section_confidence(section),
-                });
-            }
-            // Check for learning-related language
-            else if contains_learning_language(section) {
+            // Keyword analyzers in precedence order; the top match wins
+            if let Some(top) = suggest_namespaces(section).into_iter().next() {
                 candidates.push(CaptureCandidate {
                     content: section.to_string(),
-                    namespace: Namespace::Learnings,
-                    confidence: calculate_section_confidence(section),
-                });
-            }
-            // Check for blocker/issue resolution language
-            else if contains_blocker_language(section) {
-                candidates.push(CaptureCandidate {
-                    content: section.to_string(),
-                    namespace: Namespace::Blockers,
-                    confidence: calculate_section_confidence(section),
-                });
-            }
-            // Check for pattern-related language
-            else if contains_pattern_language(section) {
-                candidates.push(CaptureCandidate {
-                    content: section.to_string(),
-                    namespace: Namespace::Patterns,
-                    confidence: calculate_section_confidence(section),
-                });
-            }
-            // Check for context-related language (explains "why" behind decisions)
-            else if contains_context_language(section) {
-                candidates.push(CaptureCandidate {
-                    content: section.to_string(),
-                    namespace: Namespace::Context,
-                    confidence: calculate_section_confidence(section),
+                    namespace: top.namespace,
+                    confidence: top.confidence,
                 });
             }
             // No keyword match - try LLM classification if enabled
-            else if self.use_llm_analysis {
+            else if self.config.use_llm {
                 candidates.extend(self.classify_with_llm(section));
             }
         }
@@ -363,18 +474,29 @@ fn handle(&self, input: &str) -> Result<String> {
             tracing::Span::current().record("request_id", request_id.as_str());
         }
 
-        // Parse input
-        let parsed: PreCompactInput =
-            serde_json::from_str(input).unwrap_or_else(|_| PreCompactInput {
-                context: input.to_string(),
-                ..Default::default()
-            });
+        let parsed = match PreCompactInput::parse(input) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                Self::record_metrics("error", start.elapsed().as_secs_f64() * 1000.0, 0, 0);
+                return Err(e);
+            },
+        };
 
         // Analyze content for capture candidates
         let candidates = self.analyze_content(&parsed);
 
         // Capture the candidates (with deduplication if configured)
-        let (captured, skipped) = self.orchestrator.capture_candidates(candidates);
+        let (mut captured, mut skipped) = self.orchestrator.capture_candidates(candidates);
+
+        // Optionally capture one summary of the whole session
+        if self.config.summarize_session
+            && let Some(summary) = self.summarize_session(&parsed)
+        {
+            let (summary_captured, summary_skipped) =
+                self.orchestrator.capture_session_summary(&summary);
+            captured.extend(summary_captured);
+            skipped.extend(summary_skipped);
+        }
         let capture_count = captured.len();
         let skip_count = skipped.len();
 
@@ -403,6 +525,7 @@ fn handle(&self, input: &str) -> Result<String> {
 
 #[cfg(test)]
 mod tests {
+    use super::orchestrator::SESSION_SUMMARY_TAG;
     use super::*;
     use crate::services::deduplication::{Deduplicator, DuplicateCheckResult, DuplicateReason};
 
@@ -423,18 +546,211 @@ fn test_handle_empty_input() {
         assert!(response.as_object().unwrap().is_empty());
     }
 
+    #[test]
+    fn test_handle_rejects_raw_text() {
+        let handler = PreCompactHandler::default();
+        let result = handler.handle("We decided to use PostgreSQL for the database.");
+
+        assert!(matches!(result, Err(crate::Error::InvalidInput(_))));
+    }
+
     #[test]
     fn test_analyze_content() {
         let handler = PreCompactHandler::default();
         let input = PreCompactInput {
             context: "We decided to use PostgreSQL for the database. This was a key architectural decision.\n\nTIL that connection pooling is important for performance.".to_string(),
-            sections: vec![],
+            ..Default::default()
         };
 
         let candidates = handler.analyze_content(&input);
         assert!(!candidates.is_empty());
     }
 
+    #[test]
+    fn test_confidence_floor_suppresses_low_confidence_captures() {
+        // Single sentence over 100 bytes: keyword match at confidence 0.6
+        let input = PreCompactInput {
+            context: "We decided to use PostgreSQL for the database since it offers JSON columns \
+                      and mature replication tooling"
+                .to_string(),
+            ..Default::default()
+        };
+
+        let handler = PreCompactHandler::new().with_config(AutoCaptureConfig::default());
+        assert_eq!(handler.analyze_content(&input).len(), 1);
+
+        let handler = PreCompactHandler::new()
+            .with_config(AutoCaptureConfig::default().with_min_confidence(0.7));
+        assert!(handler.analyze_content(&input).is_empty());
+    }
+
+    #[test]
+    fn test_min_section_length_is_configurable() {
+        let input = PreCompactInput {
+            context: "We decided to use PostgreSQL for the database".to_string(),
+            ..Default::default()
+        };
+        let handler = PreCompactHandler::new()
+            .with_config(AutoCaptureConfig::default().with_min_section_length(100));
+        assert!(handler.analyze_content(&input).is_empty());
+    }
+
+    /// LLM that returns a fixed session summary and never classifies sections.
+    struct MockSummaryLlm {
+        should_fail: bool,
+    }
+
+    impl LlmProvider for MockSummaryLlm {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        fn complete(&self, _prompt: &str) -> Result<String> {
+            if self.should_fail {
+                return Err(crate::Error::OperationFailed {
+                    operation: "mock_complete".to_string(),
+                    cause: "Mock LLM failure".to_string(),
+                });
+            }
+            Ok(r#"{"namespace": "progress", "summary": "Chose PostgreSQL for storage and wired up connection pooling; migrations remain open."}"#.to_string())
+        }
+
+        fn analyze_for_capture(&self, _content: &str) -> Result<crate::llm::CaptureAnalysis> {
+            Ok(crate::llm::CaptureAnalysis {
+                should_capture: false,
+                confidence: 0.0,
+                suggested_namespace: None,
+                suggested_tags: vec![],
+                reasoning: "Mock analysis".to_string(),
+            })
+        }
+    }
+
+    fn summary_handler(
+        should_fail: bool,
+    ) -> (
+        PreCompactHandler,
+        Arc<dyn crate::storage::traits::IndexBackend + Send + Sync>,
+    ) {
+        let index: Arc<dyn crate::storage::traits::IndexBackend + Send + Sync> =
+            Arc::new(crate::storage::index::SqliteBackend::in_memory().unwrap());
+        let capture = CaptureService::new_minimal(crate::config::Config::default())
+            .with_index(Arc::clone(&index));
+        let handler = PreCompactHandler::new()
+            .with_config(AutoCaptureConfig::default().with_summarize_session(true))
+            .with_capture(capture)
+            .with_llm(Arc::new(MockSummaryLlm { should_fail }));
+        (handler, index)
+    }
+
+    const SUMMARY_SESSION_INPUT: &str = r#"{"context": "We decided to use PostgreSQL for the database since it offers JSON columns and mature replication tooling"}"#;
+
+    #[test]
+    fn test_session_summary_captured_alongside_sections() {
+        use crate::models::SearchFilter;
+
+        let (handler, index) = summary_handler(false);
+        handler.handle(SUMMARY_SESSION_INPUT).unwrap();
+
+        let summaries = index
+            .list_all(&SearchFilter::new().with_tag(SESSION_SUMMARY_TAG), 10)
+            .unwrap();
+        assert_eq!(summaries.len(), 1);
+        let summary = index.get_memory(&summaries[0].0).unwrap().unwrap();
+        assert_eq!(summary.namespace, Namespace::Progress);
+        assert!(summary.content.starts_with("Chose PostgreSQL"));
+
+        let all = index.list_all(&SearchFilter::new(), 10).unwrap();
+        assert_eq!(all.len(), 2, "one section capture plus one summary");
+
+        // A second compaction of the same session does not duplicate the summary
+        handler.handle(SUMMARY_SESSION_INPUT).unwrap();
+        let summaries = index
+            .list_all(&SearchFilter::new().with_tag(SESSION_SUMMARY_TAG), 10)
+            .unwrap();
+        assert_eq!(summaries.len(), 1);
+    }
+
+    #[test]
+    fn test_session_summary_failure_keeps_section_captures() {
+        use crate::models::SearchFilter;
+
+        let (handler, index) = summary_handler(true);
+        assert!(handler.handle(SUMMARY_SESSION_INPUT).is_ok());
+
+        let all = index.list_all(&SearchFilter::new(), 10).unwrap();
+        assert_eq!(all.len(), 1);
+        let summaries = index
+            .list_all(&SearchFilter::new().with_tag(SESSION_SUMMARY_TAG), 10)
+            .unwrap();
+        assert!(summaries.is_empty());
+    }
+
+    #[test]
+    fn test_pre_compact_captures_have_hook_origin() {
+        use crate::models::{CaptureOrigin, SearchFilter};
+
+        let (handler, index) = summary_handler(false);
+        handler.handle(SUMMARY_SESSION_INPUT).unwrap();
+
+        let all = index.list_all(&SearchFilter::new(), 10).unwrap();
+        assert_eq!(all.len(), 2);
+        for (id, _) in &all {
+            let memory = index.get_memory(id).unwrap().unwrap();
+            assert_eq!(memory.origin, CaptureOrigin::Hook);
+            assert!(memory.confidence.is_some());
+        }
+    }
+
+    #[test]
+    fn test_pre_compact_capture_stores_confidence_manual_does_not() {
+        use crate::models::{CaptureRequest, SearchFilter};
+
+        let (handler, index) = summary_handler(false);
+        handler.handle(SUMMARY_SESSION_INPUT).unwrap();
+        let manual = CaptureService::new_minimal(crate::config::Config::default())
+            .with_index(Arc::clone(&index))
+            .capture(CaptureRequest::new("Use PostgreSQL for primary storage"))
+            .unwrap();
+
+        let all = index.list_all(&SearchFilter::new(), 10).unwrap();
+        assert_eq!(all.len(), 3);
+        for (id, _) in &all {
+            let memory = index.get_memory(id).unwrap().unwrap();
+            if *id == manual.memory_id {
+                assert_eq!(memory.confidence, None);
+            } else {
+                assert!(memory.confidence.is_some_and(|c| (0.0..=1.0).contains(&c)));
+            }
+        }
+
+        // Stored confidence is queryable from the index
+        let confident = index
+            .list_all(&SearchFilter::new().with_min_confidence(0.0), 10)
+            .unwrap();
+        assert_eq!(confident.len(), 2);
+        assert!(confident.iter().all(|(id, _)| *id != manual.memory_id));
+    }
+
+    #[test]
+    fn test_auto_namespace() {
+        let handler = PreCompactHandler::new();
+
+        let decision = handler
+            .auto_namespace("We decided to use PostgreSQL for storage.")
+            .unwrap();
+        assert_eq!(decision.namespace, Namespace::Decisions);
+
+        let blocker = handler
+            .auto_namespace("Fixed the bug where the connection pool leaked handles.")
+            .unwrap();
+        assert_eq!(blocker.namespace, Namespace::Blockers);
+        assert!(blocker.confidence >= AUTO_NAMESPACE_MIN_CONFIDENCE);
+
+        // No confident suggestion: the caller falls back to decisions
+        assert!(handler.auto_namespace("Hello world").is_none());
+    }
+
     #[test]
     fn test_with_deduplication_builder() {
         // Mock deduplicator that always returns not duplicate
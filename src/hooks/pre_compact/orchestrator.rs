@@ -5,17 +5,33 @@
 
 use super::analyzer::CaptureCandidate;
 use super::formatter::{CapturedMemory, SkippedDuplicate};
-use crate::models::{CaptureRequest, Domain, MemoryId};
+use crate::models::{CaptureOrigin, CaptureRequest, ContentHasher, Domain, MemoryId, SearchFilter};
 use crate::services::CaptureService;
-use crate::services::deduplication::{ContentHasher, Deduplicator, DuplicateReason};
+use crate::services::deduplication::{Deduplicator, DuplicateReason};
+use std::collections::HashSet;
 use std::sync::Arc;
 
+/// Tag added to session summary memories.
+pub const SESSION_SUMMARY_TAG: &str = "session-summary";
+
+/// Default capture confidence floor (matches `[auto_capture] min_confidence`).
+const DEFAULT_MIN_CONFIDENCE: f32 = 0.6;
+
+/// Word-overlap (Jaccard) similarity at which a session summary counts as a
+/// near-duplicate of an earlier one.
+const SUMMARY_NEAR_DUPLICATE_THRESHOLD: f32 = 0.8;
+
+/// Number of existing session summaries compared against a new one.
+const SUMMARY_COMPARE_LIMIT: usize = 50;
+
 /// Orchestrates capture operations with deduplication support.
 pub struct CaptureOrchestrator {
     /// Capture service instance.
     capture: Option<CaptureService>,
     /// Deduplication service instance (trait object for flexibility).
     dedup: Option<Arc<dyn Deduplicator>>,
+    /// Candidates below this confidence are never captured.
+    min_confidence: f32,
 }
 
 impl CaptureOrchestrator {
@@ -25,6 +41,7 @@ pub fn new() -> Self {
         Self {
             capture: None,
             dedup: None,
+            min_confidence: DEFAULT_MIN_CONFIDENCE,
         }
     }
 
@@ -42,6 +59,13 @@ pub fn with_deduplication(mut self, dedup: Arc<dyn Deduplicator>) -> Self {
         self
     }
 
+    /// Sets the confidence floor below which candidates are never captured.
+    #[must_use]
+    pub const fn with_min_confidence(mut self, min_confidence: f32) -> Self {
+        self.min_confidence = min_confidence;
+        self
+    }
+
     /// Returns whether deduplication is configured.
     ///
     /// This method is primarily used in tests to verify builder configuration.
@@ -77,7 +101,7 @@ pub fn capture_candidates(
         let mut skipped = Vec::new();
 
         for candidate in candidates {
-            if candidate.confidence < 0.6 {
+            if candidate.confidence < self.min_confidence {
                 continue;
             }
 
@@ -87,33 +111,141 @@ pub fn capture_candidates(
                 continue;
             }
 
-            // Capture the candidate
-            let request = CaptureRequest {
-                content: candidate.content.clone(),
-                namespace: candidate.namespace,
-                domain: Domain::default(),
-                tags: vec!["auto-captured".to_string(), "pre-compact".to_string()],
-                source: Some("PreCompactHandler".to_string()),
-                skip_security_check: false,
-                ttl_seconds: None,
-                scope: None, // Use default scope
-                #[cfg(feature = "group-scope")]
-                group_id: None,
-            };
-
-            if let Ok(result) = capture.capture(request.clone()) {
-                self.record_capture_for_dedup(&request.content, &result.memory_id);
-
-                captured.push(CapturedMemory {
-                    memory_id: result.memory_id.to_string(),
+            captured.extend(
+                Self::capture_one(capture, &candidate, &[]).map(|memory_id| {
+                    self.record_capture_for_dedup(&candidate.content, &memory_id);
+                    CapturedMemory {
+                        memory_id: memory_id.to_string(),
+                        namespace: candidate.namespace.as_str().to_string(),
+                        confidence: candidate.confidence,
+                    }
+                }),
+            );
+        }
+
+        (captured, skipped)
+    }
+
+    /// Captures a session summary, tagged with [`SESSION_SUMMARY_TAG`].
+    ///
+    /// The summary is skipped if the deduplication service reports a duplicate
+    /// or an identical or near-identical summary already exists in the index.
+    pub fn capture_session_summary(
+        &self,
+        candidate: &CaptureCandidate,
+    ) -> (Option<CapturedMemory>, Option<SkippedDuplicate>) {
+        let Some(capture) = &self.capture else {
+            return (None, None);
+        };
+
+        if let Some(skip_info) = self
+            .check_for_duplicate(candidate)
+            .or_else(|| Self::find_existing_summary(capture, candidate))
+        {
+            return (None, Some(skip_info));
+        }
+
+        let captured =
+            Self::capture_one(capture, candidate, &[SESSION_SUMMARY_TAG]).map(|memory_id| {
+                self.record_capture_for_dedup(&candidate.content, &memory_id);
+                CapturedMemory {
+                    memory_id: memory_id.to_string(),
                     namespace: candidate.namespace.as_str().to_string(),
                     confidence: candidate.confidence,
-                });
-            }
-            // Errors are silently ignored, continue with other candidates
+                }
+            });
+        (captured, None)
+    }
+
+    /// Captures a single candidate with the standard auto-capture tags.
+    ///
+    /// Errors are logged and reported as `None` so other captures proceed.
+    fn capture_one(
+        capture: &CaptureService,
+        candidate: &CaptureCandidate,
+        extra_tags: &[&str],
+    ) -> Option<MemoryId> {
+        let mut tags = vec!["auto-captured".to_string(), "pre-compact".to_string()];
+        tags.extend(extra_tags.iter().map(ToString::to_string));
+
+        let request = CaptureRequest {
+            content: candidate.content.clone(),
+            namespace: candidate.namespace,
+            domain: Domain::default(),
+            tags,
+            source: Some("PreCompactHandler".to_string()),
+            skip_security_check: false,
+            ttl_seconds: None,
+            scope: None, // Use default scope
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            sections: Vec::new(),
+            template: None,
+            origin: CaptureOrigin::Hook,
+            confidence: Some(candidate.confidence),
+            attachments: Vec::new(),
+            content_type: None,
+        };
+
+        match capture.capture(request) {
+            Ok(result) => Some(result.memory_id),
+            Err(e) => {
+                tracing::debug!(
+                    error = %e,
+                    namespace = %candidate.namespace.as_str(),
+                    "Auto-capture failed, continuing with other candidates"
+                );
+                None
+            },
         }
+    }
 
-        (captured, skipped)
+    /// Looks for an identical or near-identical session summary in the index.
+    ///
+    /// LLM summaries of the same session rarely match word for word, so the
+    /// most recent summaries are compared by word overlap; the closest one at
+    /// or above [`SUMMARY_NEAR_DUPLICATE_THRESHOLD`] is reported.
+    fn find_existing_summary(
+        capture: &CaptureService,
+        candidate: &CaptureCandidate,
+    ) -> Option<SkippedDuplicate> {
+        let index = capture.index_backend()?;
+        let filter = SearchFilter::new().with_tag(SESSION_SUMMARY_TAG);
+        let ids: Vec<MemoryId> = index
+            .list_all(&filter, SUMMARY_COMPARE_LIMIT)
+            .ok()?
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect();
+        let words = word_set(&candidate.content);
+        let (memory, similarity) = index
+            .get_memories_batch(&ids)
+            .ok()?
+            .into_iter()
+            .flatten()
+            .map(|memory| {
+                let similarity = jaccard(&words, &word_set(&memory.content));
+                (memory, similarity)
+            })
+            .filter(|(_, similarity)| *similarity >= SUMMARY_NEAR_DUPLICATE_THRESHOLD)
+            .max_by(|a, b| a.1.total_cmp(&b.1))?;
+
+        let exact = ContentHasher::hash(&memory.content) == ContentHasher::hash(&candidate.content);
+        let (reason, similarity_score) = if exact {
+            (DuplicateReason::ExactMatch, None)
+        } else {
+            (DuplicateReason::SemanticSimilar, Some(similarity))
+        };
+        Some(SkippedDuplicate {
+            reason: reason_to_str(Some(reason)).to_string(),
+            matched_urn: format!(
+                "subcog://project/{}/{}",
+                memory.namespace.as_str(),
+                memory.id
+            ),
+            similarity_score,
+            namespace: candidate.namespace.as_str().to_string(),
+        })
     }
 
     /// Checks if a candidate is a duplicate and returns skip info if so.
@@ -188,6 +320,24 @@ pub fn reason_to_str(reason: Option<DuplicateReason>) -> &'static str {
     })
 }
 
+/// Lowercased alphanumeric words of `text`.
+fn word_set(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Jaccard similarity of two word sets (1.0 when both are empty).
+#[allow(clippy::cast_precision_loss)] // word counts are far below f32 precision
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    let union = a.union(b).count();
+    if union == 0 {
+        return 1.0;
+    }
+    a.intersection(b).count() as f32 / union as f32
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -308,6 +458,51 @@ fn record_capture(&self, _hash: &str, _memory_id: &MemoryId) {}
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_near_duplicate_session_summary_is_skipped() {
+        use crate::storage::index::SqliteBackend;
+        use crate::storage::traits::IndexBackend;
+
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        let capture = CaptureService::new_minimal(crate::config::Config::default())
+            .with_index(Arc::clone(&index));
+        let orchestrator = CaptureOrchestrator::new().with_capture(capture);
+        let summary = |content: &str| CaptureCandidate {
+            content: content.to_string(),
+            namespace: Namespace::Progress,
+            confidence: 0.8,
+        };
+
+        let (captured, _) = orchestrator.capture_session_summary(&summary(
+            "Chose PostgreSQL for storage and wired up connection pooling; migrations remain open.",
+        ));
+        assert!(captured.is_some());
+
+        // Reworded summary of the same session
+        let (captured, skipped) = orchestrator.capture_session_summary(&summary(
+            "Chose PostgreSQL for storage and wired up the connection pooling; migrations remain open.",
+        ));
+        assert!(captured.is_none());
+        let skipped = skipped.unwrap();
+        assert_eq!(skipped.reason, "semantic_similar");
+        assert!(skipped.similarity_score.unwrap() >= SUMMARY_NEAR_DUPLICATE_THRESHOLD);
+
+        // A different session is captured
+        let (captured, skipped) = orchestrator.capture_session_summary(&summary(
+            "Fixed the flaky retry test by capping exponential backoff at five seconds.",
+        ));
+        assert!(captured.is_some());
+        assert!(skipped.is_none());
+    }
+
+    #[test]
+    fn test_jaccard() {
+        let a = word_set("Use SQLite, for the index");
+        assert!((jaccard(&a, &word_set("use sqlite for the INDEX")) - 1.0).abs() < f32::EPSILON);
+        assert!((jaccard(&a, &word_set("prefer postgres")) - 0.0).abs() < f32::EPSILON);
+    }
+
     #[test]
     fn test_reason_to_str() {
         assert_eq!(
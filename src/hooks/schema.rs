@@ -0,0 +1,477 @@
+//! Typed hook input and output schemas.
+//!
+//! Every hook event has an input struct that its handler parses stdin into,
+//! and the context-injecting hooks share [`HookOutput`] for their response.
+//!
+//! # Versioning
+//!
+//! The schemas are versioned by [`HOOK_SCHEMA_VERSION`]. A payload may carry a
+//! `schema_version`; without one the current version is assumed, and any other
+//! version is rejected. If the payload names its event (`hook_event_name`, as
+//! Claude Code sends it, or `hook_event`), it must be the event the handler
+//! processes.
+//!
+//! Unknown fields are ignored so that fields added by newer Claude Code
+//! releases do not break hooks, but known fields must have the documented
+//! type. A payload that is not a JSON object, or whose known fields have the
+//! wrong type, fails with [`Error::InvalidInput`] instead of being read
+//! loosely. Empty input is treated as `{}`.
+
+use crate::{Error, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+/// Version of the hook input schemas this build accepts.
+pub const HOOK_SCHEMA_VERSION: u32 = 1;
+
+/// A typed hook input.
+pub trait HookInput: DeserializeOwned {
+    /// The `hook_event_name` of the event this input belongs to.
+    const EVENT: &'static str;
+
+    /// Returns the fields shared by all events.
+    fn common(&self) -> &HookCommonInput;
+
+    /// Parses and validates a hook payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the payload is not a JSON object,
+    /// a known field has the wrong type, or it names another event or an
+    /// unsupported schema version.
+    fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        let value = if input.is_empty() {
+            serde_json::Value::Object(serde_json::Map::new())
+        } else {
+            serde_json::from_str(input)
+                .map_err(|e| reject(Self::EVENT, &format!("not valid JSON: {e}")))?
+        };
+        if !value.is_object() {
+            return Err(reject(Self::EVENT, "expected a JSON object"));
+        }
+
+        let parsed: Self =
+            serde_json::from_value(value).map_err(|e| reject(Self::EVENT, &e.to_string()))?;
+        let common = parsed.common();
+        if let Some(event) = common.hook_event_name.as_deref()
+            && event != Self::EVENT
+        {
+            return Err(reject(
+                Self::EVENT,
+                &format!("payload is for the {event} event"),
+            ));
+        }
+        if let Some(version) = common.schema_version
+            && version != HOOK_SCHEMA_VERSION
+        {
+            return Err(reject(
+                Self::EVENT,
+                &format!("unsupported schema_version {version} (supported: {HOOK_SCHEMA_VERSION})"),
+            ));
+        }
+        Ok(parsed)
+    }
+}
+
+/// Builds the error for a rejected payload.
+fn reject(event: &str, reason: &str) -> Error {
+    Error::InvalidInput(format!("{event} hook input: {reason}"))
+}
+
+/// Deserializes `null` as the type's default.
+fn null_as_default<'de, D, T>(deserializer: D) -> std::result::Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + Default,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Fields sent with every hook event.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct HookCommonInput {
+    /// Claude Code session ID.
+    #[serde(default)]
+    pub session_id: Option<String>,
+    /// Path to the session transcript.
+    #[serde(default)]
+    pub transcript_path: Option<String>,
+    /// Working directory of the session.
+    #[serde(default)]
+    pub cwd: Option<String>,
+    /// Event the payload belongs to.
+    #[serde(default, alias = "hook_event")]
+    pub hook_event_name: Option<String>,
+    /// Schema version of the payload (absent means [`HOOK_SCHEMA_VERSION`]).
+    #[serde(default)]
+    pub schema_version: Option<u32>,
+}
+
+/// Input of the `SessionStart` hook.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct SessionStartInput {
+    /// Fields shared by all events.
+    #[serde(flatten)]
+    pub common: HookCommonInput,
+    /// What started the session (`startup`, `resume`, `clear`, `compact`).
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+impl HookInput for SessionStartInput {
+    const EVENT: &'static str = "SessionStart";
+
+    fn common(&self) -> &HookCommonInput {
+        &self.common
+    }
+}
+
+/// Input of the `UserPromptSubmit` hook.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct UserPromptSubmitInput {
+    /// Fields shared by all events.
+    #[serde(flatten)]
+    pub common: HookCommonInput,
+    /// The submitted prompt.
+    #[serde(default)]
+    pub prompt: Option<String>,
+    /// Legacy location of the prompt.
+    #[serde(default, rename = "hookSpecificData")]
+    pub hook_specific_data: Option<UserPromptSpecificData>,
+}
+
+impl UserPromptSubmitInput {
+    /// Returns the submitted prompt, or an empty string if there is none.
+    #[must_use]
+    pub fn prompt_text(&self) -> &str {
+        self.hook_specific_data
+            .as_ref()
+            .and_then(|data| data.user_prompt_content.as_deref())
+            .or(self.prompt.as_deref())
+            .unwrap_or("")
+    }
+}
+
+impl HookInput for UserPromptSubmitInput {
+    const EVENT: &'static str = "UserPromptSubmit";
+
+    fn common(&self) -> &HookCommonInput {
+        &self.common
+    }
+}
+
+/// Legacy `hookSpecificData` of the `UserPromptSubmit` hook.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserPromptSpecificData {
+    /// The submitted prompt.
+    #[serde(default)]
+    pub user_prompt_content: Option<String>,
+}
+
+/// Input of the `PostToolUse` hook.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PostToolUseInput {
+    /// Fields shared by all events.
+    #[serde(flatten)]
+    pub common: HookCommonInput,
+    /// Name of the tool that ran.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub tool_name: String,
+    /// Arguments the tool was called with.
+    #[serde(default)]
+    pub tool_input: serde_json::Value,
+    /// What the tool returned.
+    #[serde(default)]
+    pub tool_response: serde_json::Value,
+}
+
+impl HookInput for PostToolUseInput {
+    const EVENT: &'static str = "PostToolUse";
+
+    fn common(&self) -> &HookCommonInput {
+        &self.common
+    }
+}
+
+/// Input of the `PreCompact` hook.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct PreCompactInput {
+    /// Fields shared by all events.
+    #[serde(flatten)]
+    pub common: HookCommonInput,
+    /// What triggered the compaction (`manual` or `auto`).
+    #[serde(default)]
+    pub trigger: Option<String>,
+    /// Conversation context being compacted.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub context: String,
+    /// Sections of the conversation.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub sections: Vec<ConversationSection>,
+}
+
+impl HookInput for PreCompactInput {
+    const EVENT: &'static str = "PreCompact";
+
+    fn common(&self) -> &HookCommonInput {
+        &self.common
+    }
+}
+
+/// A section of conversation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ConversationSection {
+    /// Section content.
+    pub content: String,
+    /// Type of content (user, assistant, `tool_result`).
+    #[serde(default = "default_role")]
+    pub role: String,
+}
+
+fn default_role() -> String {
+    "assistant".to_string()
+}
+
+/// Input of the `Stop` hook.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct StopInput {
+    /// Fields shared by all events.
+    #[serde(flatten)]
+    pub common: HookCommonInput,
+    /// Whether the session is already continuing because of a stop hook.
+    #[serde(default)]
+    pub stop_hook_active: Option<bool>,
+    /// Unix timestamp the session started at.
+    #[serde(default)]
+    pub start_time: Option<u64>,
+    /// Number of interactions in the session.
+    #[serde(default)]
+    pub interaction_count: Option<u64>,
+    /// Number of memories captured during the session.
+    #[serde(default)]
+    pub memories_captured: Option<u64>,
+    /// Tools used during the session.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub tools_used: Vec<String>,
+    /// Captures and recalls per namespace.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub namespace_stats: HashMap<String, NamespaceStatsInput>,
+    /// Tags used with their frequencies.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub tags_used: Vec<TagUsageInput>,
+    /// Search queries issued during the session.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub query_patterns: Vec<String>,
+    /// MCP resources read during the session.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub resources_read: Vec<String>,
+}
+
+impl HookInput for StopInput {
+    const EVENT: &'static str = "Stop";
+
+    fn common(&self) -> &HookCommonInput {
+        &self.common
+    }
+}
+
+/// Captures and recalls of one namespace in the `Stop` hook input.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct NamespaceStatsInput {
+    /// Memories captured.
+    #[serde(default)]
+    pub captures: u64,
+    /// Memories recalled.
+    #[serde(default)]
+    pub recalls: u64,
+}
+
+/// A tag and how often it was used, in the `Stop` hook input.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TagUsageInput {
+    /// The tag.
+    pub tag: String,
+    /// Times it was used.
+    pub count: u64,
+}
+
+/// Response printed by a hook.
+///
+/// Serializes to `{}` when there is nothing to inject.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HookOutput {
+    /// Context to inject, for the events that support it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hook_specific_output: Option<HookSpecificOutput>,
+}
+
+/// The `hookSpecificOutput` of a hook response.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HookSpecificOutput {
+    /// Event the response belongs to.
+    pub hook_event_name: String,
+    /// Context added to the conversation.
+    pub additional_context: String,
+}
+
+impl HookOutput {
+    /// Creates an empty response.
+    #[must_use]
+    pub const fn empty() -> Self {
+        Self {
+            hook_specific_output: None,
+        }
+    }
+
+    /// Creates a response injecting `context` for `event`.
+    #[must_use]
+    pub fn with_context(event: &str, context: impl Into<String>) -> Self {
+        Self {
+            hook_specific_output: Some(HookSpecificOutput {
+                hook_event_name: event.to_string(),
+                additional_context: context.into(),
+            }),
+        }
+    }
+
+    /// Serializes the response to JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> Result<String> {
+        serde_json::to_string(self).map_err(|e| Error::OperationFailed {
+            operation: "serialize_response".to_string(),
+            cause: e.to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rejected<T: HookInput + std::fmt::Debug>(input: &str) {
+        let result = T::parse(input);
+        assert!(
+            matches!(result, Err(Error::InvalidInput(ref msg)) if msg.starts_with(T::EVENT)),
+            "expected {input} to be rejected, got {result:?}"
+        );
+    }
+
+    #[test]
+    fn test_session_start_input() {
+        let input = SessionStartInput::parse(
+            r#"{"session_id": "abc", "cwd": "/repo", "hook_event_name": "SessionStart",
+                "source": "startup", "permission_mode": "default"}"#,
+        )
+        .unwrap();
+        assert_eq!(input.common.session_id.as_deref(), Some("abc"));
+        assert_eq!(input.common.cwd.as_deref(), Some("/repo"));
+        assert_eq!(input.source.as_deref(), Some("startup"));
+
+        assert_rejected::<SessionStartInput>(r#"{"session_id": 12345}"#);
+    }
+
+    #[test]
+    fn test_user_prompt_submit_input() {
+        let input = UserPromptSubmitInput::parse(
+            r#"{"hook_event_name": "UserPromptSubmit", "prompt": "how does auth work?"}"#,
+        )
+        .unwrap();
+        assert_eq!(input.prompt_text(), "how does auth work?");
+
+        let input = UserPromptSubmitInput::parse(
+            r#"{"hookSpecificData": {"userPromptContent": "legacy"}, "prompt": "new"}"#,
+        )
+        .unwrap();
+        assert_eq!(input.prompt_text(), "legacy");
+
+        assert_rejected::<UserPromptSubmitInput>(r#"{"prompt": ["not", "text"]}"#);
+    }
+
+    #[test]
+    fn test_post_tool_use_input() {
+        let input = PostToolUseInput::parse(
+            r#"{"hook_event_name": "PostToolUse", "tool_name": "Read",
+                "tool_input": {"file_path": "src/lib.rs"}, "tool_response": "ok"}"#,
+        )
+        .unwrap();
+        assert_eq!(input.tool_name, "Read");
+        assert_eq!(input.tool_input["file_path"], "src/lib.rs");
+        assert_eq!(input.tool_response, "ok");
+
+        assert_rejected::<PostToolUseInput>(r#"{"tool_name": {"name": "Read"}}"#);
+    }
+
+    #[test]
+    fn test_pre_compact_input() {
+        let input = PreCompactInput::parse(
+            r#"{"hook_event_name": "PreCompact", "trigger": "auto", "context": "notes",
+                "sections": [{"content": "We decided to use Rust"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(input.trigger.as_deref(), Some("auto"));
+        assert_eq!(input.context, "notes");
+        assert_eq!(input.sections[0].role, "assistant");
+
+        // Raw text is no longer taken as the context
+        assert_rejected::<PreCompactInput>("We decided to use Rust");
+        assert_rejected::<PreCompactInput>(r#"{"sections": [{"role": "user"}]}"#);
+    }
+
+    #[test]
+    fn test_stop_input() {
+        let input = StopInput::parse(
+            r#"{"hook_event_name": "Stop", "stop_hook_active": false, "interaction_count": 3,
+                "tags_used": [{"tag": "rust", "count": 2}]}"#,
+        )
+        .unwrap();
+        assert_eq!(input.stop_hook_active, Some(false));
+        assert_eq!(input.interaction_count, Some(3));
+        assert_eq!(input.tags_used[0].tag, "rust");
+
+        assert_rejected::<StopInput>(r#"{"interaction_count": "three"}"#);
+    }
+
+    #[test]
+    fn test_rejects_non_object_payloads() {
+        assert_rejected::<StopInput>("not json");
+        assert_rejected::<StopInput>("[1, 2, 3]");
+        assert_rejected::<StopInput>(r#""text""#);
+    }
+
+    #[test]
+    fn test_empty_input_is_empty_object() {
+        let input = StopInput::parse("  \n").unwrap();
+        assert!(input.common.session_id.is_none());
+    }
+
+    #[test]
+    fn test_validates_event_and_version() {
+        assert_rejected::<SessionStartInput>(r#"{"hook_event_name": "Stop"}"#);
+        assert_rejected::<SessionStartInput>(r#"{"hook_event": "Stop"}"#);
+        assert_rejected::<SessionStartInput>(r#"{"schema_version": 2}"#);
+        assert!(SessionStartInput::parse(r#"{"schema_version": 1}"#).is_ok());
+    }
+
+    #[test]
+    fn test_hook_output() {
+        assert_eq!(HookOutput::empty().to_json().unwrap(), "{}");
+
+        let json = HookOutput::with_context("SessionStart", "# Memory Context")
+            .to_json()
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(value["hookSpecificOutput"]["hookEventName"], "SessionStart");
+        assert_eq!(
+            value["hookSpecificOutput"]["additionalContext"],
+            "# Memory Context"
+        );
+    }
+}
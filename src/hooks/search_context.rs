@@ -538,7 +538,7 @@ fn build_preview(
 mod tests {
     use super::*;
     use crate::hooks::search_intent::DetectionSource;
-    use crate::models::{Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
     use crate::services::RecallService;
     use crate::storage::index::SqliteBackend;
     use crate::storage::traits::IndexBackend;
@@ -777,6 +777,13 @@ fn create_test_memory(id: &str, content: &str, now: u64) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -787,7 +794,7 @@ fn test_injected_memories_respect_token_budget() {
         let content = "authentication ".repeat(40);
 
         let memory1 = create_test_memory("mem1", &content, now);
-        let memory2 = create_test_memory("mem2", &content, now);
+        let memory2 = create_test_memory("mem2", &format!("{content}tokens"), now);
         index.index(&memory1).unwrap();
         index.index(&memory2).unwrap();
 
@@ -7,7 +7,7 @@
 //! - Session enumeration attacks
 //! - Weak identifier exploitation
 
-use super::HookHandler;
+use super::{HookHandler, HookInput, HookOutput, SessionStartInput};
 use crate::Result;
 use crate::observability::current_request_id;
 use crate::services::{ContextBuilderService, MemoryStatistics};
@@ -510,20 +510,9 @@ fn handle(&self, input: &str) -> Result<String> {
         tracing::info!(hook = "SessionStart", "Processing session start hook");
 
         let result = (|| {
-            // Parse input as JSON
-            let input_json: serde_json::Value =
-                serde_json::from_str(input).unwrap_or_else(|_| serde_json::json!({}));
-
-            // Extract session info from input
-            let session_id = input_json
-                .get("session_id")
-                .and_then(|v| v.as_str())
-                .unwrap_or("unknown");
-
-            let cwd = input_json
-                .get("cwd")
-                .and_then(|v| v.as_str())
-                .unwrap_or(".");
+            let input = SessionStartInput::parse(input)?;
+            let session_id = input.common.session_id.as_deref().unwrap_or("unknown");
+            let cwd = input.common.cwd.as_deref().unwrap_or(".");
             let span = tracing::Span::current();
             span.record("session_id", session_id);
             span.record("cwd", cwd);
@@ -580,7 +569,7 @@ fn handle(&self, input: &str) -> Result<String> {
             // See: https://docs.anthropic.com/en/docs/claude-code/hooks
             let response = if session_context.content.is_empty() {
                 // Empty response when no context to inject
-                serde_json::json!({})
+                HookOutput::empty()
             } else {
                 // Embed metadata as XML comment for debugging
                 let metadata_str = serde_json::to_string(&metadata).unwrap_or_default();
@@ -588,18 +577,10 @@ fn handle(&self, input: &str) -> Result<String> {
                     "{}\n\n<!-- subcog-metadata: {} -->",
                     session_context.content, metadata_str
                 );
-                serde_json::json!({
-                    "hookSpecificOutput": {
-                        "hookEventName": "SessionStart",
-                        "additionalContext": context_with_metadata
-                    }
-                })
+                HookOutput::with_context("SessionStart", context_with_metadata)
             };
 
-            serde_json::to_string(&response).map_err(|e| crate::Error::OperationFailed {
-                operation: "serialize_response".to_string(),
-                cause: e.to_string(),
-            })
+            response.to_json()
         })();
 
         let status = if result.is_ok() { "success" } else { "error" };
@@ -674,6 +655,60 @@ fn test_handle_basic() {
         assert!(context.contains("subcog-metadata"));
     }
 
+    #[test]
+    fn test_pinned_memory_is_injected() {
+        use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+        use crate::services::RecallService;
+        use crate::storage::index::SqliteBackend;
+        use crate::storage::traits::IndexBackend;
+
+        let memory = |id: &str, pinned: bool| Memory {
+            id: MemoryId::new(id),
+            content: format!("Keep the public API stable ({id})"),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1,
+            updated_at: 1,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned,
+            commit: None,
+            language: None,
+        };
+        let index = SqliteBackend::in_memory().unwrap();
+        index.index(&memory("pinned-decision", true)).unwrap();
+        index.index(&memory("other-decision", false)).unwrap();
+        let handler = SessionStartHandler::new().with_context_builder(
+            ContextBuilderService::with_recall(RecallService::with_index(index)),
+        );
+
+        let input = r#"{"session_id": "test-session-abc123def456", "cwd": "/path/to/project"}"#;
+        let response: serde_json::Value =
+            serde_json::from_str(&handler.handle(input).unwrap()).unwrap();
+        let context = response["hookSpecificOutput"]["additionalContext"]
+            .as_str()
+            .unwrap();
+        assert!(context.contains("## Pinned"));
+        assert!(context.contains("pinned-decision"));
+        assert!(!context.contains("other-decision"));
+    }
+
     #[test]
     fn test_handle_missing_fields() {
         let handler = SessionStartHandler::default();
@@ -684,6 +719,23 @@ fn test_handle_missing_fields() {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_handle_rejects_malformed_input() {
+        let handler = SessionStartHandler::default();
+
+        for input in [
+            "not json",
+            r#"{"session_id": 12345}"#,
+            r#"{"hook_event_name": "Stop"}"#,
+        ] {
+            let result = handler.handle(input);
+            assert!(
+                matches!(result, Err(crate::Error::InvalidInput(_))),
+                "{input}"
+            );
+        }
+    }
+
     #[test]
     fn test_first_session_detection() {
         let handler = SessionStartHandler::default();
@@ -1,6 +1,6 @@
 //! Stop hook handler.
 
-use super::HookHandler;
+use super::{HookHandler, HookInput, StopInput};
 use crate::Result;
 use crate::current_timestamp;
 use crate::observability::current_request_id;
@@ -60,41 +60,29 @@ pub const fn with_auto_sync(mut self, enabled: bool) -> Self {
 
     /// Generates a session summary.
     #[allow(clippy::cast_possible_truncation)]
-    fn generate_summary(&self, input: &serde_json::Value) -> SessionSummary {
+    fn generate_summary(&self, input: &StopInput) -> SessionSummary {
         // Extract session info
         let session_id = input
-            .get("session_id")
-            .and_then(|v| v.as_str())
-            .unwrap_or("unknown")
-            .to_string();
+            .common
+            .session_id
+            .clone()
+            .unwrap_or_else(|| "unknown".to_string());
 
-        let start_time = input
-            .get("start_time")
-            .and_then(serde_json::Value::as_u64)
-            .unwrap_or(0);
+        let start_time = input.start_time.unwrap_or(0);
 
         let end_time = current_timestamp();
         let duration_seconds = end_time.saturating_sub(start_time);
 
         // Count interactions (from transcript if available)
         // Safe cast: interaction counts are always small
-        let interaction_count = input
-            .get("interaction_count")
-            .and_then(serde_json::Value::as_u64)
-            .unwrap_or(0) as usize;
+        let interaction_count = input.interaction_count.unwrap_or(0) as usize;
 
         // Count memories captured during session
         // Safe cast: memory counts are always small
-        let memories_captured = input
-            .get("memories_captured")
-            .and_then(serde_json::Value::as_u64)
-            .unwrap_or(0) as usize;
+        let memories_captured = input.memories_captured.unwrap_or(0) as usize;
 
         // Count tools used
-        let tools_used = input
-            .get("tools_used")
-            .and_then(|v| v.as_array())
-            .map_or(0, std::vec::Vec::len);
+        let tools_used = input.tools_used.len();
 
         // Extract namespace counts
         let namespace_counts = Self::extract_namespace_counts(input);
@@ -102,12 +90,6 @@ fn generate_summary(&self, input: &serde_json::Value) -> SessionSummary {
         // Extract tags used with frequencies
         let tags_used = Self::extract_tags_used(input);
 
-        // Extract query patterns
-        let query_patterns = Self::extract_query_patterns(input);
-
-        // Extract resources read
-        let resources_read = Self::extract_resources_read(input);
-
         SessionSummary {
             session_id,
             duration_seconds,
@@ -116,56 +98,37 @@ fn generate_summary(&self, input: &serde_json::Value) -> SessionSummary {
             tools_used,
             namespace_counts,
             tags_used,
-            query_patterns,
-            resources_read,
+            query_patterns: input.query_patterns.clone(),
+            resources_read: input.resources_read.clone(),
         }
     }
 
     /// Extracts namespace statistics from input.
     #[allow(clippy::cast_possible_truncation)]
     fn extract_namespace_counts(
-        input: &serde_json::Value,
+        input: &StopInput,
     ) -> std::collections::HashMap<String, NamespaceStats> {
-        let Some(ns_stats) = input.get("namespace_stats").and_then(|v| v.as_object()) else {
-            return std::collections::HashMap::new();
-        };
-
-        ns_stats
+        input
+            .namespace_stats
             .iter()
-            .filter_map(|(ns, stats)| {
-                let captures = stats
-                    .get("captures")
-                    .and_then(serde_json::Value::as_u64)
-                    .unwrap_or(0) as usize;
-                let recalls = stats
-                    .get("recalls")
-                    .and_then(serde_json::Value::as_u64)
-                    .unwrap_or(0) as usize;
-
-                if captures > 0 || recalls > 0 {
-                    Some((ns.clone(), NamespaceStats { captures, recalls }))
-                } else {
-                    None
-                }
+            .filter(|(_, stats)| stats.captures > 0 || stats.recalls > 0)
+            .map(|(ns, stats)| {
+                let stats = NamespaceStats {
+                    captures: stats.captures as usize,
+                    recalls: stats.recalls as usize,
+                };
+                (ns.clone(), stats)
             })
             .collect()
     }
 
     /// Extracts tags used with frequencies, sorted by count descending.
     #[allow(clippy::cast_possible_truncation)]
-    fn extract_tags_used(input: &serde_json::Value) -> Vec<(String, usize)> {
-        let Some(tag_array) = input.get("tags_used").and_then(|v| v.as_array()) else {
-            return Vec::new();
-        };
-
-        let mut tags: Vec<(String, usize)> = tag_array
+    fn extract_tags_used(input: &StopInput) -> Vec<(String, usize)> {
+        let mut tags: Vec<(String, usize)> = input
+            .tags_used
             .iter()
-            .filter_map(|entry| {
-                let obj = entry.as_object()?;
-                let tag = obj.get("tag").and_then(|v| v.as_str())?;
-                let count = obj.get("count").and_then(serde_json::Value::as_u64)? as usize;
-                Some((tag.to_string(), count))
-            })
+            .map(|entry| (entry.tag.clone(), entry.count as usize))
             .collect();
 
         // Sort by count descending, limit to top 10
@@ -174,35 +137,9 @@ fn extract_tags_used(input: &serde_json::Value) -> Vec<(String, usize)> {
         tags
     }
 
-    /// Extracts query patterns from the session.
-    fn extract_query_patterns(input: &serde_json::Value) -> Vec<String> {
-        input
-            .get("query_patterns")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|v| v.as_str().map(String::from))
-                    .collect()
-            })
-            .unwrap_or_default()
-    }
-
-    /// Extracts MCP resources read during the session.
-    fn extract_resources_read(input: &serde_json::Value) -> Vec<String> {
-        input
-            .get("resources_read")
-            .and_then(|v| v.as_array())
-            .map(|arr| {
-                arr.iter()
-                    .filter_map(|v| v.as_str().map(String::from))
-                    .collect()
-            })
-            .unwrap_or_default()
-    }
-
-    /// Performs sync if enabled and available.
+    /// Performs sync if enabled and available (never in offline mode).
     fn perform_sync(&self) -> Option<SyncResult> {
-        if !self.auto_sync {
+        if !self.auto_sync || crate::config::is_offline() {
             return None;
         }
 
@@ -413,9 +350,19 @@ fn handle(&self, input: &str) -> Result<String> {
         );
 
         // Parse input and generate summary
-        let input_json: serde_json::Value =
-            serde_json::from_str(input).unwrap_or_else(|_| serde_json::json!({}));
-        let summary = self.generate_summary(&input_json);
+        let input = match StopInput::parse(input) {
+            Ok(input) => input,
+            Err(e) => {
+                metrics::counter!(
+                    "hook_executions_total",
+                    "hook_type" => "Stop",
+                    "status" => "error"
+                )
+                .increment(1);
+                return Err(e);
+            },
+        };
+        let summary = self.generate_summary(&input);
 
         // Record session ID in span
         let span = tracing::Span::current();
@@ -559,6 +506,10 @@ fn test_handler_creation() {
         assert_eq!(handler.event_type(), "Stop");
     }
 
+    fn stop_input(value: serde_json::Value) -> StopInput {
+        serde_json::from_value(value).unwrap()
+    }
+
     #[test]
     fn test_generate_summary() {
         let handler = StopHandler::default();
@@ -572,7 +523,7 @@ fn test_generate_summary() {
             "tools_used": ["Read", "Write", "Bash"]
         });
 
-        let summary = handler.generate_summary(&input);
+        let summary = handler.generate_summary(&stop_input(input));
 
         assert_eq!(summary.session_id, "test-session");
         assert_eq!(summary.interaction_count, 10);
@@ -727,7 +678,7 @@ fn test_extract_namespace_counts() {
             }
         });
 
-        let counts = StopHandler::extract_namespace_counts(&input);
+        let counts = StopHandler::extract_namespace_counts(&stop_input(input));
 
         assert_eq!(counts.len(), 2);
         assert_eq!(counts.get("decisions").map(|s| s.captures), Some(3));
@@ -745,13 +696,31 @@ fn test_extract_tags_used() {
             ]
         });
 
-        let tags = StopHandler::extract_tags_used(&input);
+        let tags = StopHandler::extract_tags_used(&stop_input(input));
 
         assert_eq!(tags.len(), 3);
         assert_eq!(tags[0], ("rust".to_string(), 10)); // Highest count first
         assert_eq!(tags[1], ("testing".to_string(), 5));
     }
 
+    #[test]
+    fn test_handle_rejects_malformed_input() {
+        let handler = StopHandler::new();
+
+        let inputs = [
+            "not json",
+            r#"{"tags_used": [{"tag": "rust"}]}"#,
+            r#"{"hook_event_name": "SessionStart"}"#,
+        ];
+        for input in inputs {
+            let result = handler.handle(input);
+            assert!(
+                matches!(result, Err(crate::Error::InvalidInput(_))),
+                "{input}"
+            );
+        }
+    }
+
     #[test]
     fn test_default_timeout() {
         let handler = StopHandler::new();
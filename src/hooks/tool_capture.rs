@@ -0,0 +1,306 @@
+//! Structured capture of notable tool results.
+//!
+//! When `[auto_capture] from_tools` is enabled, the `PostToolUse` hook checks
+//! each tool run against the configured [`ToolCapturePattern`]s and captures a
+//! short summary of the interesting ones:
+//!
+//! | Namespace | Captured when | Summary |
+//! |-----------|---------------|---------|
+//! | `testing` | The run failed | Failure lines (failed tests, panics, assertions) |
+//! | `performance` | Benchmark numbers are found | Result lines (`ns/iter`, `time:`, ...) |
+//!
+//! Memories are tagged with `auto-captured`, `post-tool-use`, the tool name,
+//! the runner (e.g. `cargo-test`) and the outcome (`passed`/`failed`).
+//! Identical summaries are captured once. Capture failures are logged and
+//! never fail the hook.
+
+use crate::config::ToolCapturePattern;
+use crate::models::{
+    CaptureOrigin, CaptureRequest, ContentHasher, Domain, MemoryId, Namespace, SearchFilter,
+};
+use crate::services::{CaptureService, normalize_content};
+use serde_json::Value;
+
+/// Tag added to memories captured from tool results.
+pub const TOOL_RESULT_TAG: &str = "post-tool-use";
+
+/// Maximum number of output lines kept in a summary.
+const MAX_SUMMARY_LINES: usize = 15;
+
+/// Maximum length of a summary line or command, in characters.
+const MAX_LINE_CHARS: usize = 200;
+
+/// Markers of benchmark result lines.
+const BENCHMARK_MARKERS: &[&str] = &[
+    "ns/iter",
+    "ns/op",
+    "time:",
+    "Time (mean",
+    "ops/sec",
+    "op/s",
+    "Mean",
+    "± ",
+];
+
+/// Markers of test failure detail lines.
+const FAILURE_DETAIL_MARKERS: &[&str] = &["panicked at", "assert", "Error", "error:", "Expected"];
+
+/// Captures summaries of notable tool runs.
+pub struct ToolResultCapture {
+    /// Capture service for storing summaries.
+    capture: CaptureService,
+    /// Tool runs worth capturing; the first match wins.
+    patterns: Vec<ToolCapturePattern>,
+}
+
+impl ToolResultCapture {
+    /// Creates a capture for the given patterns.
+    #[must_use]
+    pub const fn new(capture: CaptureService, patterns: Vec<ToolCapturePattern>) -> Self {
+        Self { capture, patterns }
+    }
+
+    /// Captures a summary of a tool run if it matches a pattern.
+    ///
+    /// Returns the captured memory ID, or `None` if the run did not match,
+    /// was not notable, was already captured, or the capture failed.
+    #[must_use]
+    pub fn capture(
+        &self,
+        tool_name: &str,
+        tool_input: &Value,
+        tool_response: &Value,
+    ) -> Option<MemoryId> {
+        let command = tool_input.get("command").and_then(Value::as_str)?;
+        let pattern = self.patterns.iter().find(|pattern| {
+            pattern.tool.eq_ignore_ascii_case(tool_name) && command.contains(&pattern.command)
+        })?;
+        let Some(namespace) = Namespace::parse(&pattern.namespace) else {
+            tracing::debug!(
+                namespace = %pattern.namespace,
+                "Unknown namespace in tool capture pattern, skipping"
+            );
+            return None;
+        };
+
+        let output = ToolOutput::from_response(tool_response);
+        let outcome = if output.failed() { "failed" } else { "passed" };
+        let (heading, lines) = match namespace {
+            Namespace::Performance => ("Benchmark results", output.benchmark_lines()),
+            _ if output.failed() => ("Test run failed", output.failure_lines()),
+            _ => return None,
+        };
+        if lines.is_empty() {
+            return None;
+        }
+
+        let mut content = format!("{heading}: `{}`\n\n", truncate(command.trim()));
+        for line in lines {
+            content.push_str("- ");
+            content.push_str(&truncate(line));
+            content.push('\n');
+        }
+        let content = normalize_content(&content, false);
+        if self.already_captured(&content) {
+            tracing::debug!(tool = tool_name, "Tool result already captured, skipping");
+            return None;
+        }
+
+        let request = CaptureRequest {
+            content,
+            namespace,
+            domain: Domain::default(),
+            tags: vec![
+                "auto-captured".to_string(),
+                TOOL_RESULT_TAG.to_string(),
+                tool_name.to_lowercase(),
+                slug(&pattern.command),
+                outcome.to_string(),
+            ],
+            source: Some("PostToolUseHandler".to_string()),
+            skip_security_check: false,
+            ttl_seconds: None,
+            scope: None,
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            sections: Vec::new(),
+            template: None,
+            origin: CaptureOrigin::Hook,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+        };
+
+        match self.capture.capture(request) {
+            Ok(result) => {
+                metrics::counter!(
+                    "hook_auto_capture_total",
+                    "hook_type" => "PostToolUse",
+                    "namespace" => namespace.as_str()
+                )
+                .increment(1);
+                Some(result.memory_id)
+            },
+            Err(e) => {
+                tracing::debug!(error = %e, tool = tool_name, "Tool result capture failed");
+                None
+            },
+        }
+    }
+
+    /// Returns whether an identical summary is already in the index.
+    fn already_captured(&self, content: &str) -> bool {
+        let Some(index) = self.capture.index_backend() else {
+            return false;
+        };
+        let filter = SearchFilter::new()
+            .with_tag(TOOL_RESULT_TAG)
+            .with_tag(ContentHasher::content_to_tag(content));
+        index
+            .list_all(&filter, 1)
+            .is_ok_and(|existing| !existing.is_empty())
+    }
+}
+
+/// Output of a tool run, as reported in the `tool_response` payload.
+struct ToolOutput {
+    /// Combined stdout and stderr.
+    text: String,
+    /// Exit code, when reported.
+    exit_code: Option<i64>,
+}
+
+impl ToolOutput {
+    /// Reads the output from a `tool_response` object or string.
+    fn from_response(response: &Value) -> Self {
+        if let Some(text) = response.as_str() {
+            return Self {
+                text: text.to_string(),
+                exit_code: None,
+            };
+        }
+        let text = ["stdout", "stderr", "output"]
+            .iter()
+            .filter_map(|key| response.get(*key).and_then(Value::as_str))
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let exit_code = ["exit_code", "exitCode", "returncode"]
+            .iter()
+            .find_map(|key| response.get(*key).and_then(Value::as_i64));
+        Self { text, exit_code }
+    }
+
+    /// Returns whether the run failed.
+    ///
+    /// Uses the exit code when reported, otherwise looks for the failure
+    /// markers of common test runners.
+    fn failed(&self) -> bool {
+        self.exit_code
+            .map_or_else(|| self.lines().any(is_failure_line), |code| code != 0)
+    }
+
+    /// Returns the lines describing failures, falling back to the output tail.
+    fn failure_lines(&self) -> Vec<&str> {
+        let lines: Vec<&str> = self
+            .lines()
+            .filter(|line| {
+                is_failure_line(line)
+                    || FAILURE_DETAIL_MARKERS
+                        .iter()
+                        .any(|marker| line.contains(marker))
+            })
+            .take(MAX_SUMMARY_LINES)
+            .collect();
+        if !lines.is_empty() {
+            return lines;
+        }
+        let all: Vec<&str> = self.lines().collect();
+        all[all.len().saturating_sub(MAX_SUMMARY_LINES)..].to_vec()
+    }
+
+    /// Returns the lines reporting benchmark results.
+    fn benchmark_lines(&self) -> Vec<&str> {
+        self.lines()
+            .filter(|line| BENCHMARK_MARKERS.iter().any(|marker| line.contains(marker)))
+            .take(MAX_SUMMARY_LINES)
+            .collect()
+    }
+
+    /// Returns the non-blank lines, trimmed.
+    fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+    }
+}
+
+/// Returns whether a line reports a failed test or test run.
+///
+/// Matches `test result: FAILED` and `FAILED tests/...` (cargo, pytest) and
+/// `FAIL src/...`/`--- FAIL:` (jest, vitest, go), but not `0 failed`.
+fn is_failure_line(line: &str) -> bool {
+    line.contains("FAILED") || line.starts_with("FAIL") || line.starts_with("--- FAIL")
+}
+
+/// Truncates a line to [`MAX_LINE_CHARS`] characters.
+fn truncate(line: &str) -> String {
+    match line.char_indices().nth(MAX_LINE_CHARS) {
+        Some((end, _)) => format!("{}...", &line[..end]),
+        None => line.to_string(),
+    }
+}
+
+/// Converts a command pattern into a tag (`go test -bench` to `go-test-bench`).
+fn slug(command: &str) -> String {
+    command
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_failure_detection() {
+        let passed = ToolOutput::from_response(&json!({
+            "stdout": "test result: ok. 5 passed; 0 failed; 0 ignored"
+        }));
+        assert!(!passed.failed());
+
+        let failed = ToolOutput::from_response(&json!({
+            "stdout": "--- FAIL: TestParse (0.00s)\nFAIL\tgithub.com/acme/parser"
+        }));
+        assert!(failed.failed());
+
+        let exit_code = ToolOutput::from_response(&json!({ "stdout": "", "exit_code": 1 }));
+        assert!(exit_code.failed());
+    }
+
+    #[test]
+    fn test_benchmark_lines() {
+        let output = ToolOutput::from_response(&json!(
+            "running 2 tests\ntest bench_parse ... bench:       1,204 ns/iter (+/- 31)\n\ntest result: ok"
+        ));
+        assert_eq!(
+            output.benchmark_lines(),
+            vec!["test bench_parse ... bench:       1,204 ns/iter (+/- 31)"]
+        );
+    }
+
+    #[test]
+    fn test_slug_and_truncate() {
+        assert_eq!(slug("go test -bench"), "go-test-bench");
+        assert_eq!(slug("pytest --benchmark"), "pytest-benchmark");
+        assert_eq!(
+            truncate(&"é".repeat(250)).chars().count(),
+            MAX_LINE_CHARS + 3
+        );
+    }
+}
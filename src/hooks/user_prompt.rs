@@ -2,16 +2,18 @@
 // Allow expect() on static regex patterns - these are guaranteed to compile
 #![allow(clippy::expect_used)]
 
-use super::HookHandler;
 use super::search_context::{AdaptiveContextConfig, MemoryContext, SearchContextBuilder};
 use super::search_intent::{
     SearchIntent, detect_search_intent, detect_search_intent_hybrid,
     detect_search_intent_with_timeout,
 };
+use super::{HookHandler, HookInput, HookOutput, UserPromptSubmitInput};
 use crate::Result;
 use crate::config::SearchIntentConfig;
 use crate::llm::LlmProvider;
-use crate::models::{CaptureRequest, CaptureResult, EventMeta, MemoryEvent, Namespace};
+use crate::models::{
+    CaptureOrigin, CaptureRequest, CaptureResult, EventMeta, MemoryEvent, Namespace,
+};
 use crate::observability::current_request_id;
 use crate::security::record_event;
 use crate::services::{CaptureService, RecallService};
@@ -232,6 +234,28 @@ pub const fn with_auto_capture(mut self, enabled: bool) -> Self {
         self
     }
 
+    /// Builds the memory context this handler would inject for `prompt`.
+    ///
+    /// Runs the same intent detection and adaptive retrieval as
+    /// [`handle`](HookHandler::handle), but never captures and records no
+    /// hook events, so it is safe for previews. Returns `None` when search
+    /// intent is disabled or below the threshold.
+    #[must_use]
+    pub fn preview_memory_context(&self, prompt: &str) -> Option<MemoryContext> {
+        if !self.search_intent_config.enabled || prompt.trim().is_empty() {
+            return None;
+        }
+        let intent = self.classify_intent(prompt);
+        (intent.confidence >= self.search_intent_threshold)
+            .then(|| self.build_memory_context(&intent))
+    }
+
+    /// Renders a memory context as the text block injected into the prompt.
+    #[must_use]
+    pub fn render_memory_context(ctx: &MemoryContext) -> String {
+        build_memory_context_text(ctx)
+    }
+
     /// Builds memory context from a search intent using the `SearchContextBuilder`.
     fn build_memory_context(&self, intent: &SearchIntent) -> MemoryContext {
         let mut builder = SearchContextBuilder::new().with_config(self.context_config.clone());
@@ -384,13 +408,6 @@ fn extract_content(&self, prompt: &str) -> String {
         content.to_string()
     }
 
-    fn serialize_response(response: &serde_json::Value) -> Result<String> {
-        serde_json::to_string(response).map_err(|e| crate::Error::OperationFailed {
-            operation: "serialize_response".to_string(),
-            cause: e.to_string(),
-        })
-    }
-
     /// Attempts to auto-capture a memory if enabled and conditions are met.
     ///
     /// Returns the capture result if successful, and updates metadata with outcome.
@@ -407,6 +424,9 @@ fn try_auto_capture(
             content: content.to_string(),
             tags: Vec::new(),
             source: Some("auto-capture".to_string()),
+            origin: CaptureOrigin::Hook,
+            confidence: Some(signal.confidence),
+            attachments: Vec::new(),
             ..Default::default()
         };
 
@@ -444,23 +464,14 @@ fn handle_inner(
         prompt_len: &mut usize,
         intent_detected: &mut bool,
     ) -> Result<String> {
-        // Parse input as JSON
-        let input_json: serde_json::Value =
-            serde_json::from_str(input).unwrap_or_else(|_| serde_json::json!({}));
-
-        // Extract prompt from input - Claude Code format: hookSpecificData.userPromptContent
-        let prompt = input_json
-            .get("hookSpecificData")
-            .and_then(|v| v.get("userPromptContent"))
-            .and_then(|v| v.as_str())
-            .or_else(|| input_json.get("prompt").and_then(|v| v.as_str()))
-            .unwrap_or("");
+        let input = UserPromptSubmitInput::parse(input)?;
+        let prompt = input.prompt_text();
         *prompt_len = prompt.len();
         let span = tracing::Span::current();
         span.record("prompt_length", *prompt_len);
 
         if prompt.is_empty() {
-            return Self::serialize_response(&serde_json::json!({}));
+            return HookOutput::empty().to_json();
         }
 
         // Detect capture signals
@@ -572,23 +583,15 @@ fn handle_inner(
 
         // Build Claude Code hook response format per specification
         // See: https://docs.anthropic.com/en/docs/claude-code/hooks
-        let response = combined_context.map_or_else(
-            || serde_json::json!({}),
-            |ctx| {
-                // Embed metadata as XML comment for debugging
-                let metadata_str = serde_json::to_string(&metadata).unwrap_or_default();
-                let context_with_metadata =
-                    format!("{ctx}\n\n<!-- subcog-metadata: {metadata_str} -->");
-                serde_json::json!({
-                    "hookSpecificOutput": {
-                        "hookEventName": "UserPromptSubmit",
-                        "additionalContext": context_with_metadata
-                    }
-                })
-            },
-        );
+        let response = combined_context.map_or_else(HookOutput::empty, |ctx| {
+            // Embed metadata as XML comment for debugging
+            let metadata_str = serde_json::to_string(&metadata).unwrap_or_default();
+            let context_with_metadata =
+                format!("{ctx}\n\n<!-- subcog-metadata: {metadata_str} -->");
+            HookOutput::with_context("UserPromptSubmit", context_with_metadata)
+        });
 
-        Self::serialize_response(&response)
+        response.to_json()
     }
 }
 
@@ -912,6 +915,9 @@ fn build_memory_context_text(ctx: &MemoryContext) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{Domain, Memory, MemoryId, MemoryStatus};
+    use crate::storage::index::SqliteBackend;
+    use crate::storage::traits::IndexBackend;
 
     #[test]
     fn test_handler_creation() {
@@ -919,6 +925,23 @@ fn test_handler_creation() {
         assert_eq!(handler.event_type(), "UserPromptSubmit");
     }
 
+    #[test]
+    fn test_handle_rejects_malformed_input() {
+        let handler = UserPromptHandler::default();
+
+        for input in [
+            "not json",
+            r#"{"prompt": 12345}"#,
+            r#"{"schema_version": 99}"#,
+        ] {
+            let result = handler.handle(input);
+            assert!(
+                matches!(result, Err(crate::Error::InvalidInput(_))),
+                "{input}"
+            );
+        }
+    }
+
     #[test]
     fn test_explicit_capture_command() {
         let handler = UserPromptHandler::default();
@@ -1291,4 +1314,76 @@ fn test_sanitize_partial_patterns() {
         let sanitized = sanitize_for_context(safe);
         assert_eq!(sanitized, safe); // "system" as part of word is fine
     }
+
+    fn index_memory(index: &SqliteBackend, id: &str, content: &str) {
+        let memory = Memory {
+            id: MemoryId::new(id),
+            content: content.to_string(),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1_700_000_000,
+            updated_at: 1_700_000_000,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        };
+        index.index(&memory).unwrap();
+    }
+
+    #[test]
+    fn test_preview_memory_context_injects_relevant_memories_within_budget() {
+        let index = SqliteBackend::in_memory().unwrap();
+        index_memory(&index, "auth", &"Use OAuth for authentication. ".repeat(20));
+        index_memory(&index, "python", "Python scripting conventions");
+
+        let handler = UserPromptHandler::new()
+            .with_recall_service(RecallService::with_index(index))
+            .with_context_config(
+                AdaptiveContextConfig::new()
+                    .with_max_tokens(20)
+                    .with_min_confidence(0.0),
+            );
+
+        let ctx = handler
+            .preview_memory_context("How do I implement authentication in this project?")
+            .unwrap();
+        let ids: Vec<&str> = ctx
+            .injected_memories
+            .iter()
+            .map(|m| m.id.as_str())
+            .collect();
+        assert_eq!(ids, vec!["subcog://memories/auth"]);
+        let tokens: usize = ctx
+            .injected_memories
+            .iter()
+            .map(|m| crate::services::ContextBuilderService::estimate_tokens(&m.content_preview))
+            .sum();
+        assert!(tokens <= 20);
+
+        let text = UserPromptHandler::render_memory_context(&ctx);
+        assert!(text.contains("subcog://memories/auth"));
+        assert!(
+            handler
+                .preview_memory_context("I finished the task.")
+                .is_none()
+        );
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! Supports configurable column mapping with sensible defaults.
 
-use crate::io::traits::{ExportSink, ExportableMemory, ImportSource, ImportedMemory};
+use crate::io::traits::{ExportField, ExportSink, ExportableMemory, ImportSource, ImportedMemory};
 use crate::{Error, Result};
 use std::io::{BufRead, Write};
 
@@ -20,6 +20,7 @@ pub struct CsvImportSource<R: BufRead> {
 /// Maps CSV column indices to memory fields.
 #[derive(Debug, Default)]
 struct ColumnMap {
+    id: Option<usize>,
     content: Option<usize>,
     namespace: Option<usize>,
     domain: Option<usize>,
@@ -36,6 +37,7 @@ fn from_headers(headers: &csv::StringRecord) -> Result<Self> {
 
         for (i, header) in headers.iter().enumerate() {
             match header.to_lowercase().as_str() {
+                "id" | "memory_id" => map.id = Some(i),
                 "content" | "text" | "memory" | "body" => map.content = Some(i),
                 "namespace" | "ns" | "category" | "type" => map.namespace = Some(i),
                 "domain" | "scope" => map.domain = Some(i),
@@ -114,6 +116,7 @@ fn parse_record(&self, record: &csv::StringRecord) -> Result<ImportedMemory> {
             get_field(self.column_map.ttl_seconds).and_then(|s| s.parse::<u64>().ok());
 
         Ok(ImportedMemory {
+            id: get_field(self.column_map.id),
             content,
             namespace: get_field(self.column_map.namespace),
             domain: get_field(self.column_map.domain),
@@ -150,12 +153,14 @@ fn next(&mut self) -> Result<Option<ImportedMemory>> {
 /// Writes memories as CSV with headers.
 pub struct CsvExportSink<W: Write> {
     writer: csv::Writer<W>,
+    /// Columns to write, in order.
+    fields: Vec<ExportField>,
     /// Whether headers have been written.
     headers_written: bool,
 }
 
 impl<W: Write> CsvExportSink<W> {
-    /// Creates a new CSV export sink.
+    /// Creates a new CSV export sink writing all columns.
     ///
     /// # Errors
     ///
@@ -167,28 +172,23 @@ pub fn new(writer: W) -> Result<Self> {
 
         Ok(Self {
             writer: csv_writer,
+            fields: ExportField::all().to_vec(),
             headers_written: false,
         })
     }
 
+    /// Restricts output to the given columns, in the given order.
+    #[must_use]
+    pub fn with_fields(mut self, fields: Vec<ExportField>) -> Self {
+        self.fields = fields;
+        self
+    }
+
     /// Writes headers if not already written.
     fn ensure_headers(&mut self) -> Result<()> {
         if !self.headers_written {
             self.writer
-                .write_record([
-                    "id",
-                    "content",
-                    "namespace",
-                    "domain",
-                    "project_id",
-                    "branch",
-                    "file_path",
-                    "status",
-                    "created_at",
-                    "updated_at",
-                    "tags",
-                    "source",
-                ])
+                .write_record(self.fields.iter().map(ExportField::as_str))
                 .map_err(|e| Error::OperationFailed {
                     operation: "write_csv_headers".to_string(),
                     cause: e.to_string(),
@@ -204,20 +204,7 @@ fn write(&mut self, memory: &ExportableMemory) -> Result<()> {
         self.ensure_headers()?;
 
         self.writer
-            .write_record([
-                &memory.id,
-                &memory.content,
-                &memory.namespace,
-                &memory.domain,
-                memory.project_id.as_deref().unwrap_or(""),
-                memory.branch.as_deref().unwrap_or(""),
-                memory.file_path.as_deref().unwrap_or(""),
-                &memory.status,
-                &memory.created_at.to_string(),
-                &memory.updated_at.to_string(),
-                &memory.tags.join(","),
-                memory.source.as_deref().unwrap_or(""),
-            ])
+            .write_record(self.fields.iter().map(|field| field.csv_value(memory)))
             .map_err(|e| Error::OperationFailed {
                 operation: "write_csv".to_string(),
                 cause: e.to_string(),
@@ -299,6 +286,7 @@ fn test_export_csv() {
                 updated_at: 1_234_567_890,
                 tags: vec!["rust".to_string(), "test".to_string()],
                 source: None,
+                attachments: Vec::new(),
             })
             .unwrap();
             Box::new(sink).finalize().unwrap();
@@ -2,7 +2,10 @@
 //!
 //! Supports both newline-delimited JSON (NDJSON/JSONL) and JSON arrays.
 
-use crate::io::traits::{ExportSink, ExportableMemory, ImportSource, ImportedMemory};
+use crate::io::traits::{
+    ExportField, ExportSink, ExportableMemory, ImportRecord, ImportSource, ImportedMemory,
+    ProjectedMemory,
+};
 use crate::{Error, Result};
 use std::io::{BufRead, Write};
 
@@ -13,8 +16,8 @@
 /// - **Array**: A JSON array of objects `[{...}, {...}]`
 pub struct JsonImportSource<R: BufRead> {
     reader: R,
-    /// Buffered memories when parsing array format.
-    buffer: Vec<ImportedMemory>,
+    /// Buffered records when parsing array format.
+    buffer: Vec<ImportRecord>,
     /// Current index into buffer (for array format).
     buffer_index: usize,
     /// Whether we've detected and started parsing.
@@ -73,16 +76,22 @@ fn detect_format(&mut self) -> Result<bool> {
                 })?;
             let full_content = format!("{first_line}{remaining}");
 
-            let memories: Vec<ImportedMemory> = serde_json::from_str(&full_content)
+            let values: Vec<serde_json::Value> = serde_json::from_str(&full_content)
                 .map_err(|e| Error::InvalidInput(format!("Failed to parse JSON array: {e}")))?;
 
-            self.buffer = memories;
+            self.buffer = values
+                .into_iter()
+                .map(|value| ImportRecord { value, line: None })
+                .collect();
         } else {
             // NDJSON mode - parse first line as object
-            let memory: ImportedMemory = serde_json::from_str(trimmed).map_err(|e| {
+            let value: serde_json::Value = serde_json::from_str(trimmed).map_err(|e| {
                 Error::InvalidInput(format!("Line 1: Failed to parse JSON object: {e}"))
             })?;
-            self.buffer.push(memory);
+            self.buffer.push(ImportRecord {
+                value,
+                line: Some(1),
+            });
         }
 
         self.buffer_index = 0;
@@ -93,29 +102,36 @@ fn detect_format(&mut self) -> Result<bool> {
 
 impl<R: BufRead> ImportSource for JsonImportSource<R> {
     fn next(&mut self) -> Result<Option<ImportedMemory>> {
-        // First call: detect format
-        if !self.started && !self.detect_format()? {
+        let Some(record) = self.next_record()? else {
             return Ok(None);
-        }
+        };
+        let line = record.line;
+        let memory = serde_json::from_value(record.value).map_err(|e| {
+            Error::InvalidInput(line.map_or_else(
+                || format!("Failed to parse JSON record: {e}"),
+                |line| format!("Line {line}: Failed to parse JSON: {e}"),
+            ))
+        })?;
+        Ok(Some(memory))
+    }
 
-        // Array mode: return from buffer
-        if self.array_mode {
-            if self.buffer_index < self.buffer.len() {
-                let memory = self.buffer[self.buffer_index].clone();
-                self.buffer_index += 1;
-                return Ok(Some(memory));
-            }
+    fn next_record(&mut self) -> Result<Option<ImportRecord>> {
+        // First call: detect format
+        if !self.started && !self.detect_format()? {
             return Ok(None);
         }
 
-        // NDJSON mode: return buffered first, then read lines
+        // Return buffered records first (all of them in array mode)
         if self.buffer_index < self.buffer.len() {
-            let memory = self.buffer[self.buffer_index].clone();
+            let record = self.buffer[self.buffer_index].clone();
             self.buffer_index += 1;
-            return Ok(Some(memory));
+            return Ok(Some(record));
+        }
+        if self.array_mode {
+            return Ok(None);
         }
 
-        // Read next line
+        // NDJSON mode: read the next non-empty line
         let mut line = String::new();
         loop {
             line.clear();
@@ -137,14 +153,17 @@ fn next(&mut self) -> Result<Option<ImportedMemory>> {
             }
         }
 
-        let memory: ImportedMemory = serde_json::from_str(line.trim()).map_err(|e| {
+        let value = serde_json::from_str(line.trim()).map_err(|e| {
             Error::InvalidInput(format!(
                 "Line {}: Failed to parse JSON: {e}",
                 self.line_number
             ))
         })?;
 
-        Ok(Some(memory))
+        Ok(Some(ImportRecord {
+            value,
+            line: Some(self.line_number),
+        }))
     }
 
     fn size_hint(&self) -> Option<usize> {
@@ -163,19 +182,38 @@ pub struct JsonExportSink<W: Write> {
     writer: W,
     /// Number of records written.
     count: usize,
+    /// Fields to write, in order (`None` writes all fields).
+    fields: Option<Vec<ExportField>>,
 }
 
 impl<W: Write> JsonExportSink<W> {
     /// Creates a new JSON export sink.
     #[must_use]
     pub const fn new(writer: W) -> Self {
-        Self { writer, count: 0 }
+        Self {
+            writer,
+            count: 0,
+            fields: None,
+        }
+    }
+
+    /// Restricts output to the given fields, in the given order.
+    #[must_use]
+    pub fn with_fields(mut self, fields: Vec<ExportField>) -> Self {
+        self.fields = Some(fields);
+        self
     }
 }
 
 impl<W: Write + Send> ExportSink for JsonExportSink<W> {
     fn write(&mut self, memory: &ExportableMemory) -> Result<()> {
-        serde_json::to_writer(&mut self.writer, memory).map_err(|e| Error::OperationFailed {
+        let result = match &self.fields {
+            Some(fields) => {
+                serde_json::to_writer(&mut self.writer, &ProjectedMemory::new(memory, fields))
+            },
+            None => serde_json::to_writer(&mut self.writer, memory),
+        };
+        result.map_err(|e| Error::OperationFailed {
             operation: "write_json".to_string(),
             cause: e.to_string(),
         })?;
@@ -219,6 +257,25 @@ fn test_import_ndjson() {
         assert!(source.next().unwrap().is_none());
     }
 
+    #[test]
+    fn test_next_record_reports_line_numbers() {
+        let input = r#"{"content": "First memory"}
+
+{"content": 42}
+"#;
+        let mut source = JsonImportSource::new(Cursor::new(input));
+
+        let first = source.next_record().unwrap().unwrap();
+        assert_eq!(first.line, Some(1));
+
+        // Malformed records are still returned raw, for schema validation
+        let second = source.next_record().unwrap().unwrap();
+        assert_eq!(second.line, Some(3));
+        assert_eq!(second.value["content"], 42);
+
+        assert!(source.next_record().unwrap().is_none());
+    }
+
     #[test]
     fn test_import_array() {
         let input = r#"[
@@ -254,6 +311,7 @@ fn test_export_ndjson() {
                 updated_at: 0,
                 tags: vec![],
                 source: None,
+                attachments: Vec::new(),
             })
             .unwrap();
             Box::new(sink).finalize().unwrap();
@@ -0,0 +1,553 @@
+//! Markdown adapters: directory import and document export.
+//!
+//! Import reads a directory tree of Markdown notes, one memory per `.md` file. An
+//! optional YAML front-matter block sets the namespace, domain, tags and
+//! creation time; the rest of the file is the memory content, and the path
+//! relative to the directory is its source.
+//!
+//! ```text
+//! ---
+//! namespace: decisions
+//! tags: [storage, sqlite]
+//! created_at: 2024-03-01T12:00:00Z
+//! ---
+//! Use SQLite for the local index.
+//! ```
+//!
+//! Export renders a single human-readable document for wikis and PR
+//! descriptions (see [`MarkdownExportSink`]). It is not read back by import.
+
+use crate::context::SubcogIgnore;
+use crate::io::traits::{ExportSink, ExportableMemory, ImportSource, ImportedMemory};
+use crate::models::Namespace;
+use crate::{Error, Result};
+use chrono::{DateTime, NaiveDate};
+use serde_yaml_ng::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Namespace for notes whose front-matter does not name one.
+pub const MARKDOWN_DEFAULT_NAMESPACE: Namespace = Namespace::Context;
+
+/// Extension of the files read as notes.
+const MARKDOWN_EXTENSION: &str = "md";
+
+/// Number of exported memories above which a table of contents is emitted.
+const TOC_THRESHOLD: usize = 20;
+
+/// Number of ID characters shown in section titles.
+const SHORT_ID_LEN: usize = 8;
+
+/// Line prefixes that mark a line of content as code.
+const CODE_PREFIXES: &[&str] = &[
+    "fn ",
+    "pub ",
+    "let ",
+    "use ",
+    "impl ",
+    "struct ",
+    "def ",
+    "class ",
+    "import ",
+    "from ",
+    "return ",
+    "const ",
+    "function ",
+    "#include",
+    "//",
+    "SELECT ",
+    "$ ",
+];
+
+/// Markdown directory import source.
+///
+/// Collects the `.md` files under a directory up front, skipping hidden
+/// entries and paths excluded by `.subcogignore` (in the directory itself or
+/// at the root of its repository), and yields them in path order.
+pub struct MarkdownDirectorySource {
+    /// Canonical directory the notes were collected from.
+    root: PathBuf,
+    /// Note files, sorted by path.
+    files: Vec<PathBuf>,
+    /// Current index.
+    index: usize,
+}
+
+impl MarkdownDirectorySource {
+    /// Creates a source for the notes under `dir`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` is not a directory or cannot be read.
+    pub fn new(dir: &Path) -> Result<Self> {
+        let root = dir.canonicalize().map_err(|e| Error::OperationFailed {
+            operation: "open_import_directory".to_string(),
+            cause: format!("{}: {e}", dir.display()),
+        })?;
+        if !root.is_dir() {
+            return Err(Error::InvalidInput(format!(
+                "Not a directory: {}",
+                dir.display()
+            )));
+        }
+
+        let ignores = [SubcogIgnore::load(&root), SubcogIgnore::discover(&root)];
+        let mut files = Vec::new();
+        collect_notes(&root, &ignores, &mut files)?;
+        files.sort();
+
+        Ok(Self {
+            root,
+            files,
+            index: 0,
+        })
+    }
+
+    /// Returns the source reference for a note: its path relative to the
+    /// imported directory, with `/` separators.
+    fn source_for(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root)
+            .unwrap_or(path)
+            .iter()
+            .map(|component| component.to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+}
+
+impl ImportSource for MarkdownDirectorySource {
+    fn next(&mut self) -> Result<Option<ImportedMemory>> {
+        let Some(path) = self.files.get(self.index) else {
+            return Ok(None);
+        };
+        self.index += 1;
+
+        let text = std::fs::read_to_string(path).map_err(|e| Error::OperationFailed {
+            operation: "read_markdown_note".to_string(),
+            cause: format!("{}: {e}", path.display()),
+        })?;
+        parse_note(&text, &self.source_for(path)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.files.len())
+    }
+
+    /// A note that cannot be read or parsed doesn't affect the others.
+    fn recovers_from_errors(&self) -> bool {
+        true
+    }
+}
+
+/// Parses a Markdown note into an imported memory.
+///
+/// Front-matter keys other than `namespace`, `domain`, `tags` and
+/// `created_at` are ignored. `tags` may be a list or a comma-separated
+/// string; `created_at` may be Unix epoch seconds, an RFC 3339 timestamp or
+/// a `YYYY-MM-DD` date.
+///
+/// # Errors
+///
+/// Returns an error if the front-matter is not valid YAML or `created_at`
+/// cannot be parsed.
+pub fn parse_note(text: &str, source: &str) -> Result<ImportedMemory> {
+    let (front_matter, body) = split_front_matter(text);
+    let mut memory = ImportedMemory::new(body.trim()).with_source(source);
+    let Some(front_matter) = front_matter else {
+        return Ok(memory);
+    };
+
+    let value: Value = serde_yaml_ng::from_str(front_matter)
+        .map_err(|e| Error::InvalidInput(format!("{source}: invalid front-matter: {e}")))?;
+    memory.namespace = value.get("namespace").and_then(scalar_string);
+    memory.domain = value.get("domain").and_then(scalar_string);
+    memory.tags = value.get("tags").map(parse_tags).unwrap_or_default();
+    memory.created_at = value
+        .get("created_at")
+        .map(|created_at| parse_created_at(created_at, source))
+        .transpose()?;
+    Ok(memory)
+}
+
+/// Splits a leading `---` front-matter block from the note body.
+///
+/// The block ends at the next `---` (or `...`) line; an unterminated block
+/// is treated as part of the body.
+fn split_front_matter(text: &str) -> (Option<&str>, &str) {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let Some(rest) = text
+        .strip_prefix("---\n")
+        .or_else(|| text.strip_prefix("---\r\n"))
+    else {
+        return (None, text);
+    };
+
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if matches!(line.trim_end(), "---" | "...") {
+            return (Some(&rest[..offset]), &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    (None, text)
+}
+
+/// Recursively collects the note files under `dir`.
+fn collect_notes(dir: &Path, ignores: &[SubcogIgnore], files: &mut Vec<PathBuf>) -> Result<()> {
+    let read_error = |e: std::io::Error| Error::OperationFailed {
+        operation: "read_import_directory".to_string(),
+        cause: format!("{}: {e}", dir.display()),
+    };
+
+    for entry in std::fs::read_dir(dir).map_err(read_error)? {
+        let entry = entry.map_err(read_error)?;
+        let path = entry.path();
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if hidden || ignores.iter().any(|ignore| ignore.is_ignored(&path)) {
+            continue;
+        }
+
+        // Symlinked directories are not followed, so cycles cannot occur
+        if entry.file_type().map_err(read_error)?.is_dir() {
+            collect_notes(&path, ignores, files)?;
+        } else if path.is_file() && is_markdown(&path) {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Returns `true` if `path` has the Markdown extension.
+fn is_markdown(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case(MARKDOWN_EXTENSION))
+}
+
+/// Renders a scalar front-matter value as a string.
+fn scalar_string(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.trim().to_string()).filter(|s| !s.is_empty()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Parses `tags` from a list or a comma-separated string.
+fn parse_tags(value: &Value) -> Vec<String> {
+    match value {
+        Value::Sequence(items) => items.iter().filter_map(scalar_string).collect(),
+        Value::String(s) => s
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(String::from)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Parses `created_at` into Unix epoch seconds.
+fn parse_created_at(value: &Value, source: &str) -> Result<u64> {
+    let invalid = || Error::InvalidInput(format!("{source}: invalid created_at: {value:?}"));
+
+    if let Some(seconds) = value.as_u64() {
+        return Ok(seconds);
+    }
+    let text = value.as_str().map(str::trim).ok_or_else(invalid)?;
+    let seconds = DateTime::parse_from_rfc3339(text)
+        .map(|timestamp| timestamp.timestamp())
+        .or_else(|_| {
+            NaiveDate::parse_from_str(text, "%Y-%m-%d")
+                .map(|date| date.and_time(chrono::NaiveTime::MIN).and_utc().timestamp())
+        })
+        .map_err(|_| invalid())?;
+    u64::try_from(seconds).map_err(|_| invalid())
+}
+
+/// Markdown export sink.
+///
+/// Buffers memories and renders them on finalize: an H1 per namespace (in
+/// name order), then an H2 per memory titled with its namespace and short ID,
+/// a metadata table, and the content. Content that looks like code is
+/// fenced. Exports of more than [`TOC_THRESHOLD`] memories start with a
+/// table of contents.
+pub struct MarkdownExportSink<W: Write> {
+    writer: W,
+    /// Buffered memories, grouped by namespace.
+    namespaces: BTreeMap<String, Vec<ExportableMemory>>,
+    /// Number of memories buffered.
+    count: usize,
+}
+
+impl<W: Write> MarkdownExportSink<W> {
+    /// Creates a new Markdown export sink.
+    #[must_use]
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            namespaces: BTreeMap::new(),
+            count: 0,
+        }
+    }
+
+    /// Renders the buffered memories as a Markdown document.
+    fn render(&self) -> String {
+        let mut out = String::new();
+        if self.count > TOC_THRESHOLD {
+            self.render_contents(&mut out);
+        }
+
+        for (namespace, memories) in &self.namespaces {
+            let _ = write!(out, "# {namespace}\n\n");
+            for memory in memories {
+                render_memory(&mut out, memory);
+            }
+        }
+        out.truncate(out.trim_end().len());
+        out.push('\n');
+        out
+    }
+
+    /// Appends a table of contents linking every namespace and memory.
+    fn render_contents(&self, out: &mut String) {
+        out.push_str("# Contents\n\n");
+        for (namespace, memories) in &self.namespaces {
+            let _ = writeln!(
+                out,
+                "- [{namespace}](#{}) ({})",
+                anchor(namespace),
+                memories.len()
+            );
+            for memory in memories {
+                let title = section_title(memory);
+                let _ = writeln!(out, "  - [{title}](#{})", anchor(&title));
+            }
+        }
+        out.push('\n');
+    }
+}
+
+impl<W: Write + Send> ExportSink for MarkdownExportSink<W> {
+    fn write(&mut self, memory: &ExportableMemory) -> Result<()> {
+        self.namespaces
+            .entry(memory.namespace.clone())
+            .or_default()
+            .push(memory.clone());
+        self.count += 1;
+        Ok(())
+    }
+
+    fn finalize(mut self: Box<Self>) -> Result<()> {
+        if self.count > 0 {
+            let document = self.render();
+            self.writer
+                .write_all(document.as_bytes())
+                .map_err(|e| Error::OperationFailed {
+                    operation: "write_markdown".to_string(),
+                    cause: e.to_string(),
+                })?;
+        }
+        self.writer.flush().map_err(|e| Error::OperationFailed {
+            operation: "flush_markdown".to_string(),
+            cause: e.to_string(),
+        })?;
+        Ok(())
+    }
+}
+
+/// Appends one memory's section to `out`.
+fn render_memory(out: &mut String, memory: &ExportableMemory) {
+    let _ = write!(out, "## {}\n\n", section_title(memory));
+    out.push_str("| Field | Value |\n|-------|-------|\n");
+    let mut row = |field: &str, value: &str| {
+        let _ = writeln!(out, "| {field} | {} |", table_cell(value));
+    };
+    if !memory.tags.is_empty() {
+        row("Tags", &memory.tags.join(", "));
+    }
+    if let Some(source) = &memory.source {
+        row("Source", source);
+    }
+    row("Created", &format_timestamp(memory.created_at));
+    row("Status", &memory.status);
+    out.push('\n');
+
+    let content = memory.content.trim();
+    if looks_like_code(content) {
+        let _ = write!(out, "```\n{content}\n```\n\n");
+    } else {
+        let _ = write!(out, "{content}\n\n");
+    }
+}
+
+/// Returns the H2 title for a memory: its namespace and short ID.
+fn section_title(memory: &ExportableMemory) -> String {
+    let short_id: String = memory.id.chars().take(SHORT_ID_LEN).collect();
+    format!("{}: {short_id}", memory.namespace)
+}
+
+/// Returns the GitHub-style anchor for a heading.
+fn anchor(heading: &str) -> String {
+    heading
+        .to_lowercase()
+        .chars()
+        .filter_map(|c| match c {
+            ' ' => Some('-'),
+            c if c.is_alphanumeric() || c == '-' || c == '_' => Some(c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Escapes a value for a single table cell.
+fn table_cell(value: &str) -> String {
+    value.replace('|', "\\|").replace(['\r', '\n'], " ")
+}
+
+/// Formats a Unix timestamp as a UTC datetime string.
+fn format_timestamp(ts: u64) -> String {
+    DateTime::from_timestamp(i64::try_from(ts).unwrap_or(0), 0).map_or_else(
+        || ts.to_string(),
+        |dt| dt.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+    )
+}
+
+/// Returns `true` if most non-blank lines of a multi-line text look like code.
+///
+/// Text that already contains a fence is left as Markdown.
+fn looks_like_code(content: &str) -> bool {
+    if content.contains("```") {
+        return false;
+    }
+    let lines: Vec<&str> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+    if lines.len() < 2 {
+        return false;
+    }
+    let code_lines = lines.iter().filter(|line| is_code_line(line)).count();
+    code_lines * 2 > lines.len()
+}
+
+/// Returns `true` if a line is indented, ends like a statement or block, or
+/// starts with a common code keyword.
+fn is_code_line(line: &str) -> bool {
+    let trimmed = line.trim();
+    line.starts_with("    ")
+        || line.starts_with('\t')
+        || trimmed.ends_with([';', '{', '}'])
+        || CODE_PREFIXES
+            .iter()
+            .any(|prefix| trimmed.starts_with(prefix))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_note_with_front_matter() {
+        let text = "---\nnamespace: decisions\ntags: [storage, sqlite]\n\
+                    created_at: 2024-03-01T00:00:00Z\ntitle: Index\n---\n\n# Index\n\
+                    Use SQLite.\n";
+        let memory = parse_note(text, "adr/0001.md").unwrap();
+
+        assert_eq!(memory.content, "# Index\nUse SQLite.");
+        assert_eq!(memory.namespace.as_deref(), Some("decisions"));
+        assert_eq!(memory.tags, vec!["storage", "sqlite"]);
+        assert_eq!(memory.created_at, Some(1_709_251_200));
+        assert_eq!(memory.source.as_deref(), Some("adr/0001.md"));
+    }
+
+    #[test]
+    fn test_parse_note_without_front_matter() {
+        let memory = parse_note("---\nnot closed\n", "scratch.md").unwrap();
+        assert_eq!(memory.content, "---\nnot closed");
+        assert!(memory.namespace.is_none());
+        assert!(memory.tags.is_empty());
+
+        let memory = parse_note("---\ntags: a, b\ncreated_at: 2024-03-01\n---\nx", "n.md").unwrap();
+        assert_eq!(memory.tags, vec!["a", "b"]);
+        assert_eq!(memory.created_at, Some(1_709_251_200));
+
+        assert!(parse_note("---\ncreated_at: soon\n---\nx", "n.md").is_err());
+    }
+
+    fn exportable(id: &str, namespace: &str, content: &str) -> ExportableMemory {
+        ExportableMemory {
+            id: id.to_string(),
+            content: content.to_string(),
+            namespace: namespace.to_string(),
+            domain: "project".to_string(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: "active".to_string(),
+            created_at: 1_709_251_200,
+            updated_at: 1_709_251_200,
+            tags: Vec::new(),
+            source: None,
+            attachments: Vec::new(),
+        }
+    }
+
+    fn export(memories: &[ExportableMemory]) -> String {
+        let mut buffer = Vec::new();
+        let mut sink = MarkdownExportSink::new(&mut buffer);
+        for memory in memories {
+            sink.write(memory).unwrap();
+        }
+        Box::new(sink).finalize().unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn test_markdown_export_sections() {
+        let mut decision = exportable("abcdef0123456789", "decisions", "Use SQLite | locally.");
+        decision.tags = vec!["storage".to_string(), "sqlite".to_string()];
+        decision.source = Some("src/lib.rs".to_string());
+        let pattern = exportable("fedcba98", "patterns", "fn main() {\n    run();\n}");
+
+        let document = export(&[pattern, decision]);
+
+        assert!(!document.contains("# Contents"));
+        let decisions = document.find("# decisions\n").unwrap();
+        let patterns = document.find("# patterns\n").unwrap();
+        assert!(decisions < patterns);
+        assert!(document.contains("## decisions: abcdef01\n"));
+        assert!(document.contains("| Tags | storage, sqlite |\n"));
+        assert!(document.contains("| Source | src/lib.rs |\n"));
+        assert!(document.contains("| Created | 2024-03-01 00:00:00 UTC |\n"));
+        assert!(document.contains("| Status | active |\n"));
+        assert!(document.contains("\nUse SQLite | locally.\n"));
+        assert!(document.contains("```\nfn main() {\n    run();\n}\n```\n"));
+        assert!(document.ends_with("```\n"));
+
+        assert!(export(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_markdown_export_table_of_contents() {
+        let memories: Vec<_> = (0..=TOC_THRESHOLD)
+            .map(|i| exportable(&format!("id{i:06}"), "learnings", "Note."))
+            .collect();
+
+        assert!(!export(&memories[..TOC_THRESHOLD]).contains("# Contents"));
+        let document = export(&memories);
+        assert!(document.starts_with("# Contents\n\n- [learnings](#learnings) (21)\n"));
+        assert!(document.contains("  - [learnings: id000020](#learnings-id000020)\n"));
+    }
+
+    #[test]
+    fn test_looks_like_code() {
+        assert!(looks_like_code("let x = 1;\nlet y = 2;"));
+        assert!(looks_like_code("SELECT id\n    FROM memories;"));
+        assert!(!looks_like_code("Use SQLite for the local index."));
+        assert!(!looks_like_code("First line.\nSecond line."));
+        assert!(!looks_like_code("```rust\nlet x = 1;\n```"));
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod csv;
 pub mod json;
+pub mod markdown;
 #[cfg(feature = "parquet-export")]
 pub mod parquet;
 pub mod yaml;
@@ -13,7 +14,7 @@
 use std::path::Path;
 use std::str::FromStr;
 
-use super::traits::{ExportSink, ImportSource};
+use super::traits::{ExportField, ExportSink, ImportSource};
 
 /// Supported file formats for import/export.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -24,7 +25,9 @@ pub enum Format {
     Yaml,
     /// CSV format with configurable column mapping.
     Csv,
-    /// Apache Parquet columnar format (export only).
+    /// Human-readable Markdown document (export only).
+    Markdown,
+    /// Apache Parquet columnar format.
     #[cfg(feature = "parquet-export")]
     Parquet,
 }
@@ -33,7 +36,14 @@ impl Format {
     /// Returns all available formats for import.
     #[must_use]
     pub fn import_formats() -> Vec<Self> {
-        vec![Self::Json, Self::Yaml, Self::Csv]
+        #[cfg(feature = "parquet-export")]
+        {
+            vec![Self::Json, Self::Yaml, Self::Csv, Self::Parquet]
+        }
+        #[cfg(not(feature = "parquet-export"))]
+        {
+            vec![Self::Json, Self::Yaml, Self::Csv]
+        }
     }
 
     /// Returns all available formats for export.
@@ -41,11 +51,17 @@ pub fn import_formats() -> Vec<Self> {
     pub fn export_formats() -> Vec<Self> {
         #[cfg(feature = "parquet-export")]
         {
-            vec![Self::Json, Self::Yaml, Self::Csv, Self::Parquet]
+            vec![
+                Self::Json,
+                Self::Yaml,
+                Self::Csv,
+                Self::Markdown,
+                Self::Parquet,
+            ]
         }
         #[cfg(not(feature = "parquet-export"))]
         {
-            vec![Self::Json, Self::Yaml, Self::Csv]
+            vec![Self::Json, Self::Yaml, Self::Csv, Self::Markdown]
         }
     }
 
@@ -56,6 +72,7 @@ pub const fn extension(&self) -> &'static str {
             Self::Json => "json",
             Self::Yaml => "yaml",
             Self::Csv => "csv",
+            Self::Markdown => "md",
             #[cfg(feature = "parquet-export")]
             Self::Parquet => "parquet",
         }
@@ -68,6 +85,7 @@ pub const fn mime_type(&self) -> &'static str {
             Self::Json => "application/json",
             Self::Yaml => "application/x-yaml",
             Self::Csv => "text/csv",
+            Self::Markdown => "text/markdown",
             #[cfg(feature = "parquet-export")]
             Self::Parquet => "application/vnd.apache.parquet",
         }
@@ -88,6 +106,7 @@ pub fn from_path(path: &Path) -> Result<Self> {
             Some("json" | "ndjson" | "jsonl") => Ok(Self::Json),
             Some("yaml" | "yml") => Ok(Self::Yaml),
             Some("csv" | "tsv") => Ok(Self::Csv),
+            Some("md" | "markdown") => Ok(Self::Markdown),
             #[cfg(feature = "parquet-export")]
             Some("parquet" | "pq") => Ok(Self::Parquet),
             Some(ext) => Err(Error::InvalidInput(format!(
@@ -102,11 +121,26 @@ pub fn from_path(path: &Path) -> Result<Self> {
     /// Returns whether this format supports import.
     #[must_use]
     pub const fn supports_import(&self) -> bool {
-        match self {
-            Self::Json | Self::Yaml | Self::Csv => true,
-            #[cfg(feature = "parquet-export")]
-            Self::Parquet => false,
+        !matches!(self, Self::Markdown)
+    }
+
+    /// Parses a format name for import.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::FeatureNotEnabled`] for Parquet when the
+    /// `parquet-export` feature is disabled, or an error if the name is
+    /// unknown or the format is export-only.
+    pub fn parse_import(name: &str) -> Result<Self> {
+        #[cfg(not(feature = "parquet-export"))]
+        if matches!(name.to_lowercase().as_str(), "parquet" | "pq") {
+            return Err(Error::FeatureNotEnabled("parquet-export".to_string()));
+        }
+        let format: Self = name.parse()?;
+        if !format.supports_import() {
+            return Err(import_unsupported(format));
         }
+        Ok(format)
     }
 
     /// Returns whether this format supports export.
@@ -124,6 +158,7 @@ fn from_str(s: &str) -> Result<Self> {
             "json" | "ndjson" | "jsonl" => Ok(Self::Json),
             "yaml" | "yml" => Ok(Self::Yaml),
             "csv" | "tsv" => Ok(Self::Csv),
+            "markdown" | "md" => Ok(Self::Markdown),
             #[cfg(feature = "parquet-export")]
             "parquet" | "pq" => Ok(Self::Parquet),
             _ => Err(Error::InvalidInput(format!("Unknown format: {s}"))),
@@ -137,6 +172,7 @@ fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
             Self::Json => write!(f, "json"),
             Self::Yaml => write!(f, "yaml"),
             Self::Csv => write!(f, "csv"),
+            Self::Markdown => write!(f, "markdown"),
             #[cfg(feature = "parquet-export")]
             Self::Parquet => write!(f, "parquet"),
         }
@@ -156,13 +192,17 @@ pub fn create_import_source<R: BufRead + 'static>(
         Format::Json => Ok(Box::new(json::JsonImportSource::new(reader))),
         Format::Yaml => Ok(Box::new(yaml::YamlImportSource::new(reader)?)),
         Format::Csv => Ok(Box::new(csv::CsvImportSource::new(reader)?)),
+        Format::Markdown => Err(import_unsupported(format)),
         #[cfg(feature = "parquet-export")]
-        Format::Parquet => Err(Error::NotImplemented(
-            "Parquet import is not supported".to_string(),
-        )),
+        Format::Parquet => Ok(Box::new(parquet::ParquetImportSource::new(reader)?)),
     }
 }
 
+/// Error for an attempt to import an export-only format.
+fn import_unsupported(format: Format) -> Error {
+    Error::InvalidInput(format!("Format '{format}' does not support import"))
+}
+
 /// Creates an export sink for the given format and writer.
 ///
 /// # Errors
@@ -172,12 +212,62 @@ pub fn create_export_sink<W: Write + Send + 'static>(
     writer: W,
     format: Format,
 ) -> Result<Box<dyn ExportSink>> {
+    create_projected_export_sink(writer, format, None)
+}
+
+/// Creates an export sink that writes only the given fields, in order.
+///
+/// With `fields` of `None` every field is written.
+///
+/// # Errors
+///
+/// Returns an error if sink creation fails, or if a field selection is
+/// requested for a format with a fixed layout (Markdown, Parquet).
+pub fn create_projected_export_sink<W: Write + Send + 'static>(
+    writer: W,
+    format: Format,
+    fields: Option<&[ExportField]>,
+) -> Result<Box<dyn ExportSink>> {
+    let fields = fields.map(<[ExportField]>::to_vec);
     match format {
-        Format::Json => Ok(Box::new(json::JsonExportSink::new(writer))),
-        Format::Yaml => Ok(Box::new(yaml::YamlExportSink::new(writer))),
-        Format::Csv => Ok(Box::new(csv::CsvExportSink::new(writer)?)),
+        Format::Json => {
+            let sink = json::JsonExportSink::new(writer);
+            Ok(match fields {
+                Some(fields) => Box::new(sink.with_fields(fields)),
+                None => Box::new(sink),
+            })
+        },
+        Format::Yaml => {
+            let sink = yaml::YamlExportSink::new(writer);
+            Ok(match fields {
+                Some(fields) => Box::new(sink.with_fields(fields)),
+                None => Box::new(sink),
+            })
+        },
+        Format::Csv => {
+            let sink = csv::CsvExportSink::new(writer)?;
+            Ok(match fields {
+                Some(fields) => Box::new(sink.with_fields(fields)),
+                None => Box::new(sink),
+            })
+        },
+        Format::Markdown => {
+            if fields.is_some() {
+                return Err(Error::InvalidInput(
+                    "Field selection is not supported for Markdown export".to_string(),
+                ));
+            }
+            Ok(Box::new(markdown::MarkdownExportSink::new(writer)))
+        },
         #[cfg(feature = "parquet-export")]
-        Format::Parquet => Ok(Box::new(parquet::ParquetExportSink::new(writer)?)),
+        Format::Parquet => {
+            if fields.is_some() {
+                return Err(Error::InvalidInput(
+                    "Field selection is not supported for Parquet export".to_string(),
+                ));
+            }
+            Ok(Box::new(parquet::ParquetExportSink::new(writer)?))
+        },
     }
 }
 
@@ -207,6 +297,10 @@ fn test_format_from_path() {
             Format::from_path(Path::new("test.csv")).unwrap(),
             Format::Csv
         );
+        assert_eq!(
+            Format::from_path(Path::new("decisions.markdown")).unwrap(),
+            Format::Markdown
+        );
         assert!(Format::from_path(Path::new("test.txt")).is_err());
     }
 
@@ -223,5 +317,29 @@ fn test_format_supports() {
         assert!(Format::Json.supports_export());
         assert!(Format::Yaml.supports_import());
         assert!(Format::Csv.supports_import());
+        assert!(!Format::Markdown.supports_import());
+        assert!(Format::Markdown.supports_export());
+        assert!(!Format::import_formats().contains(&Format::Markdown));
+    }
+
+    #[test]
+    fn test_format_parse_import() {
+        assert_eq!(Format::parse_import("yml").unwrap(), Format::Yaml);
+        #[cfg(feature = "parquet-export")]
+        assert_eq!(Format::parse_import("Parquet").unwrap(), Format::Parquet);
+        #[cfg(not(feature = "parquet-export"))]
+        assert!(matches!(
+            Format::parse_import("Parquet"),
+            Err(Error::FeatureNotEnabled(feature)) if feature == "parquet-export"
+        ));
+        assert!(matches!(
+            Format::parse_import("xml"),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            Format::parse_import("md"),
+            Err(Error::InvalidInput(message))
+                if message == "Format 'markdown' does not support import"
+        ));
     }
 }
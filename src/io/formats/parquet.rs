@@ -1,22 +1,199 @@
-//! Apache Parquet format adapter for export.
+//! Apache Parquet format adapter for import and export.
 //!
 //! Provides columnar storage format for efficient analytics queries.
 //! Requires the `parquet-export` feature.
 //!
-//! Note: Parquet import is not supported as it's primarily an analytics format.
+//! Import reads the schema written by [`ParquetExportSink`], so a Parquet
+//! export can be restored with `subcog import`.
 
-use crate::io::traits::{ExportSink, ExportableMemory};
+use crate::io::traits::{ExportSink, ExportableMemory, ImportSource, ImportedMemory};
 use crate::{Error, Result};
-use arrow::array::{ArrayRef, StringArray, UInt64Array};
+use arrow::array::{Array, ArrayRef, StringArray, UInt64Array};
 use arrow::datatypes::{DataType, Field, Schema};
 use arrow::record_batch::RecordBatch;
+use bytes::Bytes;
 use parquet::arrow::ArrowWriter;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 // Note: SNAPPY/ZSTD compression requires additional features on parquet crate.
 // Using uncompressed for simplicity since memory export files are typically small.
 use parquet::file::properties::WriterProperties;
-use std::io::Write;
+use std::io::{BufRead, Write};
 use std::sync::Arc;
 
+/// Parquet import source.
+///
+/// Reads the whole file up front, since Parquet metadata lives in the footer.
+/// Expects the columns written by [`ParquetExportSink`]; `id`, `namespace`,
+/// `content` and `created_at` are required.
+pub struct ParquetImportSource {
+    /// Decoded memories, in file order.
+    memories: std::vec::IntoIter<ImportedMemory>,
+    /// Total number of rows in the file.
+    total: usize,
+}
+
+impl ParquetImportSource {
+    /// Creates a new Parquet import source.
+    ///
+    /// An empty input (as written by an export with no matches) yields no
+    /// memories.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the input is not a Parquet file or
+    /// its schema lacks a required column, or an error if reading fails.
+    pub fn new<R: BufRead>(mut reader: R) -> Result<Self> {
+        let mut data = Vec::new();
+        reader
+            .read_to_end(&mut data)
+            .map_err(|e| Error::OperationFailed {
+                operation: "read_parquet".to_string(),
+                cause: e.to_string(),
+            })?;
+        if data.is_empty() {
+            return Ok(Self {
+                memories: Vec::new().into_iter(),
+                total: 0,
+            });
+        }
+
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(data))
+            .map_err(|e| Error::InvalidInput(format!("Invalid Parquet file: {e}")))?;
+        validate_schema(builder.schema())?;
+
+        let batches = builder.build().map_err(|e| Error::OperationFailed {
+            operation: "parquet_reader_create".to_string(),
+            cause: format!("Failed to create Parquet reader: {e}"),
+        })?;
+
+        let mut memories = Vec::new();
+        for batch in batches {
+            let batch = batch.map_err(|e| Error::OperationFailed {
+                operation: "parquet_read".to_string(),
+                cause: format!("Failed to read Parquet batch: {e}"),
+            })?;
+            memories.extend(memories_from_batch(&batch)?);
+        }
+
+        Ok(Self {
+            total: memories.len(),
+            memories: memories.into_iter(),
+        })
+    }
+}
+
+impl ImportSource for ParquetImportSource {
+    fn next(&mut self) -> Result<Option<ImportedMemory>> {
+        Ok(self.memories.next())
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.total)
+    }
+}
+
+/// Checks that a schema has the columns needed to rebuild memories.
+fn validate_schema(schema: &Schema) -> Result<()> {
+    let required = [
+        ("id", DataType::Utf8),
+        ("namespace", DataType::Utf8),
+        ("content", DataType::Utf8),
+        ("created_at", DataType::UInt64),
+    ];
+    let optional = [
+        ("domain", DataType::Utf8),
+        ("tags", DataType::Utf8),
+        ("source", DataType::Utf8),
+    ];
+
+    let mut problems = Vec::new();
+    for (name, expected) in &required {
+        match schema.field_with_name(name) {
+            Ok(field) if field.data_type() == expected => {},
+            Ok(field) => problems.push(format!(
+                "column '{name}' is {}, expected {expected}",
+                field.data_type()
+            )),
+            Err(_) => problems.push(format!("missing required column '{name}'")),
+        }
+    }
+    for (name, expected) in &optional {
+        if let Ok(field) = schema.field_with_name(name)
+            && field.data_type() != expected
+        {
+            problems.push(format!(
+                "column '{name}' is {}, expected {expected}",
+                field.data_type()
+            ));
+        }
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(Error::InvalidInput(format!(
+            "Parquet schema does not match the subcog export schema: {}",
+            problems.join("; ")
+        )))
+    }
+}
+
+/// Converts a record batch into imported memories.
+fn memories_from_batch(batch: &RecordBatch) -> Result<Vec<ImportedMemory>> {
+    let ids = required_column::<StringArray>(batch, "id")?;
+    let namespaces = required_column::<StringArray>(batch, "namespace")?;
+    let contents = required_column::<StringArray>(batch, "content")?;
+    let created_ats = required_column::<UInt64Array>(batch, "created_at")?;
+    let domains = optional_column::<StringArray>(batch, "domain");
+    let tags = optional_column::<StringArray>(batch, "tags");
+    let sources = optional_column::<StringArray>(batch, "source");
+
+    let memories = (0..batch.num_rows())
+        .map(|row| {
+            let string_at = |column: Option<&StringArray>| {
+                column
+                    .filter(|c| c.is_valid(row))
+                    .map(|c| c.value(row))
+                    .filter(|value| !value.is_empty())
+                    .map(str::to_string)
+            };
+            ImportedMemory {
+                id: string_at(Some(ids)),
+                content: string_at(Some(contents)).unwrap_or_default(),
+                namespace: string_at(Some(namespaces)),
+                domain: string_at(domains),
+                tags: string_at(tags)
+                    .map(|tags| {
+                        tags.split(',')
+                            .map(str::trim)
+                            .filter(|tag| !tag.is_empty())
+                            .map(str::to_string)
+                            .collect()
+                    })
+                    .unwrap_or_default(),
+                source: string_at(sources),
+                created_at: created_ats.is_valid(row).then(|| created_ats.value(row)),
+                ttl_seconds: None,
+            }
+        })
+        .collect();
+    Ok(memories)
+}
+
+/// Returns a column that [`validate_schema`] guarantees is present.
+fn required_column<'a, T: Array + 'static>(batch: &'a RecordBatch, name: &str) -> Result<&'a T> {
+    optional_column(batch, name).ok_or_else(|| {
+        Error::InvalidInput(format!("Parquet file is missing required column '{name}'"))
+    })
+}
+
+/// Returns a column of the expected array type, if present.
+fn optional_column<'a, T: Array + 'static>(batch: &'a RecordBatch, name: &str) -> Option<&'a T> {
+    batch
+        .column_by_name(name)
+        .and_then(|column| column.as_any().downcast_ref::<T>())
+}
+
 /// Parquet export sink.
 ///
 /// Buffers memories and writes them as a Parquet file on finalize.
@@ -55,6 +232,7 @@ fn schema() -> Schema {
             Field::new("updated_at", DataType::UInt64, false),
             Field::new("tags", DataType::Utf8, false), // Stored as comma-separated
             Field::new("source", DataType::Utf8, true),
+            Field::new("attachments", DataType::Utf8, true), // Stored as a JSON array
         ])
     }
 
@@ -102,6 +280,17 @@ fn to_record_batch(&self) -> Result<RecordBatch> {
             .map(|m| Some(m.tags.join(",")))
             .collect();
         let sources: StringArray = self.memories.iter().map(|m| m.source.as_deref()).collect();
+        let attachments: StringArray = self
+            .memories
+            .iter()
+            .map(|m| {
+                if m.attachments.is_empty() {
+                    None
+                } else {
+                    serde_json::to_string(&m.attachments).ok()
+                }
+            })
+            .collect();
 
         let columns: Vec<ArrayRef> = vec![
             Arc::new(ids),
@@ -116,6 +305,7 @@ fn to_record_batch(&self) -> Result<RecordBatch> {
             Arc::new(updated_ats),
             Arc::new(tags),
             Arc::new(sources),
+            Arc::new(attachments),
         ];
 
         RecordBatch::try_new(schema, columns).map_err(|e| Error::OperationFailed {
@@ -222,6 +412,7 @@ fn test_parquet_export() {
             updated_at: 1_234_567_890,
             tags: vec!["rust".to_string(), "test".to_string()],
             source: Some("test.rs".to_string()),
+            attachments: Vec::new(),
         })
         .unwrap();
         Box::new(sink).finalize().unwrap();
@@ -248,7 +439,7 @@ fn test_parquet_empty_export() {
     #[test]
     fn test_schema_fields() {
         let schema = ParquetExportSink::<Vec<u8>>::schema();
-        assert_eq!(schema.fields().len(), 12);
+        assert_eq!(schema.fields().len(), 13);
 
         // Verify required fields are non-nullable
         let id_field = schema.field_with_name("id").unwrap();
@@ -258,4 +449,95 @@ fn test_schema_fields() {
         let project_id_field = schema.field_with_name("project_id").unwrap();
         assert!(project_id_field.is_nullable());
     }
+
+    #[test]
+    fn test_parquet_import_roundtrip() {
+        let buffer = SharedBuffer::new();
+        let buffer_clone = buffer.clone();
+
+        let mut sink = ParquetExportSink::new(buffer).unwrap();
+        for (id, domain, tags) in [
+            ("test-1", "project", vec!["rust", "test"]),
+            ("test-2", "", vec![]),
+        ] {
+            sink.write(&ExportableMemory {
+                id: id.to_string(),
+                content: format!("Content of {id}"),
+                namespace: "decisions".to_string(),
+                domain: domain.to_string(),
+                project_id: None,
+                branch: None,
+                file_path: None,
+                status: "active".to_string(),
+                created_at: 1_234_567_890,
+                updated_at: 1_234_567_899,
+                tags: tags.into_iter().map(str::to_string).collect(),
+                source: (id == "test-1").then(|| "test.rs".to_string()),
+                attachments: Vec::new(),
+            })
+            .unwrap();
+        }
+        Box::new(sink).finalize().unwrap();
+
+        let data = buffer_clone.into_inner();
+        let mut source = ParquetImportSource::new(std::io::Cursor::new(data)).unwrap();
+        assert_eq!(source.size_hint(), Some(2));
+
+        let first = source.next().unwrap().unwrap();
+        assert_eq!(first.id.as_deref(), Some("test-1"));
+        assert_eq!(first.content, "Content of test-1");
+        assert_eq!(first.namespace.as_deref(), Some("decisions"));
+        assert_eq!(first.domain.as_deref(), Some("project"));
+        assert_eq!(first.tags, vec!["rust", "test"]);
+        assert_eq!(first.source.as_deref(), Some("test.rs"));
+        assert_eq!(first.created_at, Some(1_234_567_890));
+
+        let second = source.next().unwrap().unwrap();
+        assert_eq!(second.id.as_deref(), Some("test-2"));
+        assert!(second.domain.is_none());
+        assert!(second.tags.is_empty());
+        assert!(second.source.is_none());
+
+        assert!(source.next().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parquet_import_empty_input() {
+        let mut source = ParquetImportSource::new(std::io::Cursor::new(Vec::new())).unwrap();
+        assert_eq!(source.size_hint(), Some(0));
+        assert!(source.next().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_parquet_import_rejects_mismatched_schema() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Utf8, false),
+            Field::new("content", DataType::Utf8, false),
+            Field::new("created_at", DataType::Utf8, false),
+        ]));
+        let columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec!["test-1"])),
+            Arc::new(StringArray::from(vec!["Test memory content"])),
+            Arc::new(StringArray::from(vec!["yesterday"])),
+        ];
+        let batch = RecordBatch::try_new(Arc::clone(&schema), columns).unwrap();
+
+        let mut data = Vec::new();
+        let mut writer = ArrowWriter::try_new(&mut data, schema, None).unwrap();
+        writer.write(&batch).unwrap();
+        writer.close().unwrap();
+
+        let Err(Error::InvalidInput(message)) =
+            ParquetImportSource::new(std::io::Cursor::new(data))
+        else {
+            panic!("expected a schema mismatch");
+        };
+        assert!(message.contains("missing required column 'namespace'"));
+        assert!(message.contains("column 'created_at' is Utf8, expected UInt64"));
+
+        assert!(matches!(
+            ParquetImportSource::new(std::io::Cursor::new(b"not parquet".to_vec())),
+            Err(Error::InvalidInput(_))
+        ));
+    }
 }
@@ -2,7 +2,10 @@
 //!
 //! Supports YAML document streams (multiple documents separated by `---`).
 
-use crate::io::traits::{ExportSink, ExportableMemory, ImportSource, ImportedMemory};
+use crate::io::traits::{
+    ExportField, ExportSink, ExportableMemory, ImportRecord, ImportSource, ImportedMemory,
+    ProjectedMemory,
+};
 use crate::{Error, Result};
 use std::io::{BufRead, Write};
 
@@ -11,8 +14,8 @@
 /// Reads YAML document streams where each document is a memory object.
 /// Documents are separated by `---` markers.
 pub struct YamlImportSource {
-    /// Pre-parsed memories from the YAML stream.
-    memories: Vec<ImportedMemory>,
+    /// Pre-parsed documents from the YAML stream.
+    documents: Vec<serde_json::Value>,
     /// Current index.
     index: usize,
 }
@@ -36,46 +39,63 @@ pub fn new<R: BufRead>(mut reader: R) -> Result<Self> {
 
         if content.trim().is_empty() {
             return Ok(Self {
-                memories: Vec::new(),
+                documents: Vec::new(),
                 index: 0,
             });
         }
 
         // Try parsing as a sequence first (array of memories)
-        if let Ok(memories) = serde_yaml_ng::from_str::<Vec<ImportedMemory>>(&content) {
-            return Ok(Self { memories, index: 0 });
+        if let Ok(documents) = serde_yaml_ng::from_str::<Vec<serde_json::Value>>(&content) {
+            return Ok(Self {
+                documents,
+                index: 0,
+            });
         }
 
         // Try parsing as multi-document stream
-        let mut memories = Vec::new();
+        let mut documents = Vec::new();
         for (doc_index, document) in serde_yaml_ng::Deserializer::from_str(&content).enumerate() {
-            let memory: ImportedMemory =
+            let value: serde_json::Value =
                 serde::Deserialize::deserialize(document).map_err(|e| {
                     Error::InvalidInput(format!(
                         "Document {}: Failed to parse YAML: {e}",
                         doc_index + 1
                     ))
                 })?;
-            memories.push(memory);
+            documents.push(value);
         }
 
-        Ok(Self { memories, index: 0 })
+        Ok(Self {
+            documents,
+            index: 0,
+        })
     }
 }
 
 impl ImportSource for YamlImportSource {
     fn next(&mut self) -> Result<Option<ImportedMemory>> {
-        if self.index < self.memories.len() {
-            let memory = self.memories[self.index].clone();
-            self.index += 1;
-            Ok(Some(memory))
-        } else {
-            Ok(None)
-        }
+        let Some(record) = self.next_record()? else {
+            return Ok(None);
+        };
+        let memory = serde_json::from_value(record.value).map_err(|e| {
+            Error::InvalidInput(format!(
+                "Document {}: Failed to parse YAML: {e}",
+                self.index
+            ))
+        })?;
+        Ok(Some(memory))
+    }
+
+    fn next_record(&mut self) -> Result<Option<ImportRecord>> {
+        let Some(value) = self.documents.get(self.index).cloned() else {
+            return Ok(None);
+        };
+        self.index += 1;
+        Ok(Some(ImportRecord { value, line: None }))
     }
 
     fn size_hint(&self) -> Option<usize> {
-        Some(self.memories.len())
+        Some(self.documents.len())
     }
 }
 
@@ -86,13 +106,26 @@ pub struct YamlExportSink<W: Write> {
     writer: W,
     /// Number of records written.
     count: usize,
+    /// Fields to write, in order (`None` writes all fields).
+    fields: Option<Vec<ExportField>>,
 }
 
 impl<W: Write> YamlExportSink<W> {
     /// Creates a new YAML export sink.
     #[must_use]
     pub const fn new(writer: W) -> Self {
-        Self { writer, count: 0 }
+        Self {
+            writer,
+            count: 0,
+            fields: None,
+        }
+    }
+
+    /// Restricts output to the given fields, in the given order.
+    #[must_use]
+    pub fn with_fields(mut self, fields: Vec<ExportField>) -> Self {
+        self.fields = Some(fields);
+        self
     }
 }
 
@@ -106,7 +139,13 @@ fn write(&mut self, memory: &ExportableMemory) -> Result<()> {
             })?;
         }
 
-        serde_yaml_ng::to_writer(&mut self.writer, memory).map_err(|e| Error::OperationFailed {
+        let result = match &self.fields {
+            Some(fields) => {
+                serde_yaml_ng::to_writer(&mut self.writer, &ProjectedMemory::new(memory, fields))
+            },
+            None => serde_yaml_ng::to_writer(&mut self.writer, memory),
+        };
+        result.map_err(|e| Error::OperationFailed {
             operation: "write_yaml".to_string(),
             cause: e.to_string(),
         })?;
@@ -204,6 +243,7 @@ fn test_export_yaml() {
                 updated_at: 0,
                 tags: vec![],
                 source: None,
+                attachments: Vec::new(),
             })
             .unwrap();
             sink.write(&ExportableMemory {
@@ -219,6 +259,7 @@ fn test_export_yaml() {
                 updated_at: 0,
                 tags: vec![],
                 source: None,
+                attachments: Vec::new(),
             })
             .unwrap();
             Box::new(sink).finalize().unwrap();
@@ -1,15 +1,17 @@
 //! Import/Export I/O subsystem.
 //!
 //! Provides bulk memory import and structured export capabilities with support
-//! for multiple file formats (JSON, YAML, CSV, Parquet).
+//! for multiple file formats (JSON, YAML, CSV, Markdown, Parquet).
 //!
 //! # Architecture
 //!
 //! The I/O subsystem uses a clean trait-based architecture:
 //!
 //! - **Format adapters** implement [`ImportSource`] and [`ExportSink`] traits
-//! - **Validation layer** normalizes and validates imported data
+//! - **Validation layer** checks records against the memory JSON Schema, then
+//!   normalizes and validates imported data
 //! - **Services** orchestrate format parsing, validation, and storage
+//! - **Signing** adds detached Ed25519 signatures to export files
 //!
 //! # Supported Formats
 //!
@@ -18,20 +20,21 @@
 //! | JSON | ✓ | ✓ | Newline-delimited (NDJSON) or array |
 //! | YAML | ✓ | ✓ | Document stream |
 //! | CSV | ✓ | ✓ | Configurable column mapping |
-//! | Parquet | - | ✓ | Requires `parquet-export` feature |
+//! | Markdown | - | ✓ | Grouped by namespace, for wikis and PRs |
+//! | Parquet | ✓ | ✓ | Requires `parquet-export` feature |
 //!
 //! # Examples
 //!
 //! ## Import memories from JSON
 //!
 //! ```rust,ignore
-//! use subcog::io::{ImportService, ImportOptions, Format};
+//! use subcog::io::{ImportService, ImportOptions, Format, MergeStrategy};
 //! use std::fs::File;
 //!
 //! let file = File::open("memories.json")?;
 //! let result = service.import_from_reader(file, ImportOptions {
 //!     format: Format::Json,
-//!     skip_duplicates: true,
+//!     merge_strategy: MergeStrategy::Skip,
 //!     ..Default::default()
 //! })?;
 //! println!("Imported {} memories", result.imported);
@@ -53,13 +56,19 @@
 //! ```
 
 pub mod formats;
+pub mod schema;
 pub mod services;
+pub mod signing;
 pub mod traits;
 pub mod validation;
 
 // Re-exports for convenience
 pub use formats::Format;
-pub use services::export::{ExportOptions, ExportResult, ExportService};
-pub use services::import::{ImportOptions, ImportProgress, ImportResult, ImportService};
-pub use traits::{ExportSink, ImportSource, ImportedMemory};
+pub use schema::{MEMORY_SCHEMA_ID, memory_schema};
+pub use services::export::{ExportCheckpoint, ExportOptions, ExportResult, ExportService};
+pub use services::import::{
+    ImportOptions, ImportProgress, ImportResult, ImportService, MergeStrategy, UpsertOutcome,
+};
+pub use signing::{BundleSignature, sign_file, verify_file};
+pub use traits::{ExportField, ExportSink, ImportRecord, ImportSource, ImportedMemory};
 pub use validation::{ImportValidator, ValidationIssue, ValidationResult};
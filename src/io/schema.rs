@@ -0,0 +1,267 @@
+//! Canonical JSON Schema for memory records.
+//!
+//! Defines the record shape shared by `subcog export` output and `subcog
+//! import` input. The schema is generated from a single field table so the
+//! published document (`subcog schema memory`) and the checks applied by
+//! [`ImportValidator`](super::ImportValidator) cannot drift apart.
+
+use serde_json::{Map, Value, json};
+
+/// Identifier of the memory record schema.
+pub const MEMORY_SCHEMA_ID: &str = "urn:subcog:schema:memory:v1";
+
+/// JSON type accepted by a memory record field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    /// A string.
+    String,
+    /// A string or `null`.
+    OptionalString,
+    /// A non-negative integer or `null`.
+    OptionalUnsignedInteger,
+    /// An array of strings.
+    StringArray,
+    /// An array of objects.
+    ObjectArray,
+}
+
+impl FieldType {
+    /// Returns a human-readable description of the accepted type.
+    #[must_use]
+    pub const fn expected(&self) -> &'static str {
+        match self {
+            Self::String => "string",
+            Self::OptionalString => "string or null",
+            Self::OptionalUnsignedInteger => "non-negative integer or null",
+            Self::StringArray => "array of strings",
+            Self::ObjectArray => "array of objects",
+        }
+    }
+
+    /// Returns whether `value` has the accepted type.
+    ///
+    /// For [`FieldType::StringArray`] only the outer array is checked;
+    /// element types are reported individually by the validator.
+    #[must_use]
+    pub fn accepts(&self, value: &Value) -> bool {
+        match self {
+            Self::String => value.is_string(),
+            Self::OptionalString => value.is_string() || value.is_null(),
+            Self::OptionalUnsignedInteger => value.is_u64() || value.is_null(),
+            Self::StringArray => value.is_array(),
+            Self::ObjectArray => value
+                .as_array()
+                .is_some_and(|items| items.iter().all(Value::is_object)),
+        }
+    }
+
+    /// Returns the JSON Schema fragment for this type.
+    fn schema(self) -> Value {
+        match self {
+            Self::String => json!({ "type": "string" }),
+            Self::OptionalString => json!({ "type": ["string", "null"] }),
+            Self::OptionalUnsignedInteger => json!({ "type": ["integer", "null"], "minimum": 0 }),
+            Self::StringArray => json!({ "type": "array", "items": { "type": "string" } }),
+            Self::ObjectArray => json!({ "type": "array", "items": { "type": "object" } }),
+        }
+    }
+}
+
+/// A field of the memory record schema.
+#[derive(Debug, Clone, Copy)]
+pub struct SchemaField {
+    /// Field name.
+    pub name: &'static str,
+    /// Accepted JSON type.
+    pub field_type: FieldType,
+    /// Whether the field must be present.
+    pub required: bool,
+    /// Description published in the schema.
+    pub description: &'static str,
+}
+
+/// Fields of the memory record, in export order.
+pub const MEMORY_RECORD_FIELDS: &[SchemaField] = &[
+    SchemaField {
+        name: "id",
+        field_type: FieldType::OptionalString,
+        required: false,
+        description: "Memory identifier; used as the match key when re-importing",
+    },
+    SchemaField {
+        name: "content",
+        field_type: FieldType::String,
+        required: true,
+        description: "Memory content",
+    },
+    SchemaField {
+        name: "namespace",
+        field_type: FieldType::OptionalString,
+        required: false,
+        description: "Namespace (e.g. decisions, learnings); defaults on import",
+    },
+    SchemaField {
+        name: "domain",
+        field_type: FieldType::OptionalString,
+        required: false,
+        description: "Domain scope: project, user, or org",
+    },
+    SchemaField {
+        name: "project_id",
+        field_type: FieldType::OptionalString,
+        required: false,
+        description: "Project identifier (git remote URL)",
+    },
+    SchemaField {
+        name: "branch",
+        field_type: FieldType::OptionalString,
+        required: false,
+        description: "Git branch name",
+    },
+    SchemaField {
+        name: "file_path",
+        field_type: FieldType::OptionalString,
+        required: false,
+        description: "File path relative to the repository root",
+    },
+    SchemaField {
+        name: "status",
+        field_type: FieldType::OptionalString,
+        required: false,
+        description: "Memory status (e.g. active, archived)",
+    },
+    SchemaField {
+        name: "created_at",
+        field_type: FieldType::OptionalUnsignedInteger,
+        required: false,
+        description: "Creation time (Unix epoch seconds)",
+    },
+    SchemaField {
+        name: "updated_at",
+        field_type: FieldType::OptionalUnsignedInteger,
+        required: false,
+        description: "Last update time (Unix epoch seconds)",
+    },
+    SchemaField {
+        name: "tags",
+        field_type: FieldType::StringArray,
+        required: false,
+        description: "Tags for categorization",
+    },
+    SchemaField {
+        name: "source",
+        field_type: FieldType::OptionalString,
+        required: false,
+        description: "Source reference (file path, URL)",
+    },
+    SchemaField {
+        name: "attachments",
+        field_type: FieldType::ObjectArray,
+        required: false,
+        description: "Attached files (name, media_type, size, hash); blobs stay in the data \
+                      directory (export only)",
+    },
+    SchemaField {
+        name: "ttl_seconds",
+        field_type: FieldType::OptionalUnsignedInteger,
+        required: false,
+        description: "Time-to-live in seconds (import only)",
+    },
+];
+
+/// Looks up a schema field by name.
+#[must_use]
+pub fn memory_field(name: &str) -> Option<&'static SchemaField> {
+    MEMORY_RECORD_FIELDS.iter().find(|field| field.name == name)
+}
+
+/// Returns the JSON Schema (draft 2020-12) for a memory record.
+#[must_use]
+pub fn memory_schema() -> Value {
+    let mut properties = Map::new();
+    for field in MEMORY_RECORD_FIELDS {
+        let mut property = field.field_type.schema();
+        if let Some(object) = property.as_object_mut() {
+            object.insert("description".to_string(), json!(field.description));
+        }
+        properties.insert(field.name.to_string(), property);
+    }
+
+    let required: Vec<&str> = MEMORY_RECORD_FIELDS
+        .iter()
+        .filter(|field| field.required)
+        .map(|field| field.name)
+        .collect();
+
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$id": MEMORY_SCHEMA_ID,
+        "title": "Subcog memory record",
+        "description": "A memory as written by `subcog export` and accepted by `subcog import`.",
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    })
+}
+
+/// Returns the JSON type name of a value, for error messages.
+#[must_use]
+pub fn json_type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "boolean",
+        Value::Number(n) if n.is_u64() || n.is_i64() => "integer",
+        Value::Number(_) => "number",
+        Value::String(_) => "string",
+        Value::Array(_) => "array",
+        Value::Object(_) => "object",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::traits::ExportableMemory;
+
+    #[test]
+    fn test_memory_schema_shape() {
+        let schema = memory_schema();
+
+        assert_eq!(schema["$id"], MEMORY_SCHEMA_ID);
+        assert_eq!(schema["required"], json!(["content"]));
+        assert_eq!(schema["additionalProperties"], json!(false));
+        assert_eq!(schema["properties"]["tags"]["items"]["type"], "string");
+        assert_eq!(
+            schema["properties"].as_object().unwrap().len(),
+            MEMORY_RECORD_FIELDS.len()
+        );
+    }
+
+    #[test]
+    fn test_exported_record_fields_are_in_schema() {
+        let exported = serde_json::to_value(ExportableMemory {
+            id: "1".to_string(),
+            content: "Test".to_string(),
+            namespace: "decisions".to_string(),
+            domain: "project".to_string(),
+            project_id: Some("github.com/org/repo".to_string()),
+            branch: Some("main".to_string()),
+            file_path: Some("src/lib.rs".to_string()),
+            status: "active".to_string(),
+            created_at: 1,
+            updated_at: 2,
+            tags: vec!["rust".to_string()],
+            source: Some("test.rs".to_string()),
+            attachments: Vec::new(),
+        })
+        .unwrap();
+
+        for (name, value) in exported.as_object().unwrap() {
+            assert!(
+                memory_field(name).is_some_and(|field| field.field_type.accepts(value)),
+                "{name} rejected by the schema"
+            );
+        }
+    }
+}
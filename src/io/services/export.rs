@@ -1,19 +1,22 @@
 //! Memory export service.
 //!
-//! Orchestrates bulk memory export to various formats.
+//! Orchestrates bulk memory export to various formats, optionally limited to
+//! the memories changed since a previous export's [`ExportCheckpoint`].
 
 #![allow(clippy::needless_pass_by_value)]
 
-use crate::io::formats::{Format, create_export_sink};
+use crate::io::formats::{Format, create_projected_export_sink};
+use crate::io::services::import::DEFAULT_PROGRESS_INTERVAL;
 use crate::io::traits::{ExportField, ExportSink, ExportableMemory};
 use crate::models::{Memory, SearchFilter};
+use crate::security::{ContentRedactor, RedactionConfig};
 use crate::services::parse_filter_query;
 use crate::storage::IndexBackend;
-use crate::storage::index::SqliteBackend;
 use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
 use std::io::Write;
-use std::path::Path;
-use std::sync::Arc;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
 
 /// Options for memory export.
 #[derive(Debug, Clone)]
@@ -24,8 +27,16 @@ pub struct ExportOptions {
     pub filter: Option<String>,
     /// Maximum number of memories to export.
     pub limit: Option<usize>,
-    /// Fields to include in export.
+    /// Fields to include in export, in output order (`None` for all fields).
     pub fields: Option<Vec<ExportField>>,
+    /// Number of memories between progress callbacks (the final count is always reported).
+    pub progress_interval: usize,
+    /// Strip identifying metadata (see [`ExportableMemory::anonymized`]).
+    pub anonymize: bool,
+    /// Redact PII and secrets in the content.
+    pub redact_pii: bool,
+    /// Export only memories changed since this checkpoint (`None` for all).
+    pub since: Option<ExportCheckpoint>,
 }
 
 impl Default for ExportOptions {
@@ -35,6 +46,10 @@ fn default() -> Self {
             filter: None,
             limit: None,
             fields: None, // All fields
+            progress_interval: DEFAULT_PROGRESS_INTERVAL,
+            anonymize: false,
+            redact_pii: false,
+            since: None,
         }
     }
 }
@@ -61,20 +76,57 @@ pub const fn with_limit(mut self, limit: usize) -> Self {
         self
     }
 
-    /// Sets the fields to include in export.
+    /// Sets the fields to include in export, in output order.
     #[must_use]
     pub fn with_fields(mut self, fields: Vec<ExportField>) -> Self {
         self.fields = Some(fields);
         self
     }
 
+    /// Sets the number of memories between progress callbacks (minimum 1).
+    #[must_use]
+    pub const fn with_progress_interval(mut self, interval: usize) -> Self {
+        self.progress_interval = if interval == 0 { 1 } else { interval };
+        self
+    }
+
+    /// Sets whether identifying metadata is stripped.
+    #[must_use]
+    pub const fn with_anonymize(mut self, anonymize: bool) -> Self {
+        self.anonymize = anonymize;
+        self
+    }
+
+    /// Sets whether PII and secrets in the content are redacted.
+    #[must_use]
+    pub const fn with_redact_pii(mut self, redact_pii: bool) -> Self {
+        self.redact_pii = redact_pii;
+        self
+    }
+
+    /// Limits the export to memories changed since `checkpoint`.
+    #[must_use]
+    pub fn with_since(mut self, checkpoint: ExportCheckpoint) -> Self {
+        self.since = Some(checkpoint);
+        self
+    }
+
     /// Parses the filter query into a `SearchFilter`.
+    ///
+    /// Exports since a checkpoint only match memories updated at or after
+    /// it, including tombstoned ones so deletions reach the backup.
     #[must_use]
     pub fn parse_filter(&self) -> SearchFilter {
-        self.filter
+        let mut filter: SearchFilter = self
+            .filter
             .as_ref()
             .map(|f| parse_filter_query(f))
-            .unwrap_or_default()
+            .unwrap_or_default();
+        if let Some(since) = &self.since {
+            filter.updated_after = Some(since.updated_at);
+            filter.include_tombstoned = true;
+        }
+        filter
     }
 }
 
@@ -89,6 +141,8 @@ pub struct ExportResult {
     pub format: Format,
     /// Output path (if file export).
     pub output_path: Option<String>,
+    /// Checkpoint reached, for the next incremental export.
+    pub checkpoint: ExportCheckpoint,
 }
 
 impl ExportResult {
@@ -100,6 +154,12 @@ pub const fn new(format: Format) -> Self {
             total_matched: 0,
             format,
             output_path: None,
+            checkpoint: ExportCheckpoint {
+                updated_at: 0,
+                boundary_ids: Vec::new(),
+                filter: None,
+                exported_at: 0,
+            },
         }
     }
 
@@ -110,19 +170,115 @@ pub const fn has_exports(&self) -> bool {
     }
 }
 
+/// Extension of the checkpoint file written next to an export.
+const CHECKPOINT_EXTENSION: &str = "checkpoint";
+
+/// Position reached by an export, for incremental backups.
+///
+/// Stored as JSON next to the export file. An export since the checkpoint
+/// writes the memories with a later `updated_at`, and those with the same
+/// `updated_at` that are not in `boundary_ids`, so changes made within the
+/// checkpoint's second are neither lost nor written twice.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportCheckpoint {
+    /// Latest `updated_at` among the exported memories (Unix epoch seconds).
+    pub updated_at: u64,
+    /// IDs of the exported memories updated exactly at `updated_at`.
+    #[serde(default)]
+    pub boundary_ids: Vec<String>,
+    /// Filter query of the export, which later exports must repeat.
+    #[serde(default)]
+    pub filter: Option<String>,
+    /// When the export ran (Unix epoch seconds).
+    pub exported_at: u64,
+}
+
+impl ExportCheckpoint {
+    /// Returns the checkpoint path for an export file (`<output>.checkpoint`).
+    #[must_use]
+    pub fn path_for(output: &Path) -> PathBuf {
+        let mut name = output.as_os_str().to_os_string();
+        name.push(".");
+        name.push(CHECKPOINT_EXTENSION);
+        PathBuf::from(name)
+    }
+
+    /// Loads a checkpoint, returning `None` if none has been recorded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read or is not a checkpoint.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(Error::OperationFailed {
+                    operation: "read_export_checkpoint".to_string(),
+                    cause: format!("{}: {e}", path.display()),
+                });
+            },
+        };
+        serde_json::from_str(&text).map(Some).map_err(|e| {
+            Error::InvalidInput(format!("{}: invalid checkpoint: {e}", path.display()))
+        })
+    }
+
+    /// Writes the checkpoint.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let write_error = |cause: String| Error::OperationFailed {
+            operation: "write_export_checkpoint".to_string(),
+            cause,
+        };
+        let json = serde_json::to_string_pretty(self).map_err(|e| write_error(e.to_string()))?;
+        std::fs::write(path, json + "\n")
+            .map_err(|e| write_error(format!("{}: {e}", path.display())))
+    }
+
+    /// Returns `true` if `memory` was already exported at this checkpoint.
+    fn covers(&self, memory: &Memory) -> bool {
+        let updated_at = last_change(memory);
+        updated_at < self.updated_at
+            || (updated_at == self.updated_at
+                && self.boundary_ids.iter().any(|id| id == memory.id.as_str()))
+    }
+
+    /// Moves the checkpoint past an exported memory.
+    fn advance(&mut self, memory: &Memory) {
+        let updated_at = last_change(memory);
+        if updated_at > self.updated_at {
+            self.updated_at = updated_at;
+            self.boundary_ids.clear();
+        }
+        if updated_at == self.updated_at {
+            self.boundary_ids.push(memory.id.as_str().to_string());
+        }
+    }
+}
+
+/// Returns when a memory last changed; rows without an update time fall back
+/// to their creation time, as the index filter does.
+fn last_change(memory: &Memory) -> u64 {
+    memory.updated_at.max(memory.created_at)
+}
+
 /// Progress callback for export operations.
 pub type ExportProgressCallback = Box<dyn Fn(usize, Option<usize>) + Send>;
 
 /// Service for exporting memories to external formats.
 pub struct ExportService {
     /// Index backend for querying memories.
-    index: Arc<SqliteBackend>,
+    index: Arc<dyn IndexBackend + Send + Sync>,
 }
 
 impl ExportService {
     /// Creates a new export service.
     #[must_use]
-    pub const fn new(index: Arc<SqliteBackend>) -> Self {
+    pub fn new(index: Arc<dyn IndexBackend + Send + Sync>) -> Self {
         Self { index }
     }
 
@@ -172,12 +328,37 @@ pub fn export_to_writer<W: Write + Send + 'static>(
         options: ExportOptions,
         progress: Option<ExportProgressCallback>,
     ) -> Result<ExportResult> {
-        let mut sink = create_export_sink(writer, options.format)?;
+        let mut sink =
+            create_projected_export_sink(writer, options.format, options.fields.as_deref())?;
         let result = self.export_to_sink(sink.as_mut(), &options, progress)?;
         sink.finalize()?;
         Ok(result)
     }
 
+    /// Exports memories to a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the format is binary (Parquet) or export fails.
+    pub fn export_to_string(&self, options: ExportOptions) -> Result<(String, ExportResult)> {
+        #[cfg(feature = "parquet-export")]
+        if options.format == Format::Parquet {
+            return Err(Error::InvalidInput(
+                "Parquet is a binary format; export it to a file".to_string(),
+            ));
+        }
+
+        let buffer = SharedBuffer::default();
+        let result = self.export_to_writer(buffer.clone(), options, None)?;
+        let failed = |cause: String| Error::OperationFailed {
+            operation: "export_to_string".to_string(),
+            cause,
+        };
+        let bytes = std::mem::take(&mut *buffer.lock().map_err(|e| failed(e.to_string()))?);
+        let content = String::from_utf8(bytes).map_err(|e| failed(e.to_string()))?;
+        Ok((content, result))
+    }
+
     /// Exports memories to a sink.
     ///
     /// # Errors
@@ -194,25 +375,55 @@ pub fn export_to_sink(
 
         // Query memories from index
         let memory_ids = self.index.list_all(&filter, limit)?;
-        let total_matched = memory_ids.len();
+
+        // Batch fetch memories, dropping those the checkpoint already covers
+        let ids: Vec<_> = memory_ids.iter().map(|(id, _)| id.clone()).collect();
+        let memories: Vec<Memory> = self
+            .index
+            .get_memories_batch(&ids)?
+            .into_iter()
+            .flatten()
+            .filter(|memory| !options.since.as_ref().is_some_and(|cp| cp.covers(memory)))
+            .collect();
+        let total_matched = memories.len();
 
         let mut result = ExportResult::new(options.format);
         result.total_matched = total_matched;
+        result.checkpoint = options.since.clone().unwrap_or_default();
+        result.checkpoint.filter.clone_from(&options.filter);
 
-        // Batch fetch memories
-        let ids: Vec<_> = memory_ids.iter().map(|(id, _)| id.clone()).collect();
-        let memories = self.index.get_memories_batch(&ids)?;
+        let redactor = options
+            .redact_pii
+            .then(|| ContentRedactor::with_config(RedactionConfig::new().with_pii()));
 
-        for memory in memories.into_iter().flatten() {
-            let exportable = ExportableMemory::from(&memory);
+        let interval = options.progress_interval.max(1);
+        for memory in memories {
+            result.checkpoint.advance(&memory);
+            let mut exportable = ExportableMemory::from(memory);
+            if options.anonymize {
+                exportable = exportable.anonymized();
+            }
+            if let Some(ref redactor) = redactor {
+                exportable.content = redactor.redact(&exportable.content);
+            }
             sink.write(&exportable)?;
             result.exported += 1;
 
-            if let Some(ref cb) = progress {
+            if let Some(ref cb) = progress
+                && result.exported.is_multiple_of(interval)
+            {
                 cb(result.exported, Some(total_matched));
             }
         }
 
+        // Always report the final count
+        if let Some(ref cb) = progress
+            && !result.exported.is_multiple_of(interval)
+        {
+            cb(result.exported, Some(total_matched));
+        }
+
+        result.checkpoint.exported_at = crate::current_timestamp();
         Ok(result)
     }
 
@@ -250,11 +461,38 @@ pub fn export_memories<'a, I>(
     }
 }
 
+/// In-memory writer whose output stays readable after the sink that owns a
+/// clone of it is finalized.
+#[derive(Clone, Default)]
+struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    /// Locks the buffer.
+    fn lock(&self) -> std::io::Result<std::sync::MutexGuard<'_, Vec<u8>>> {
+        self.0
+            .lock()
+            .map_err(|_| std::io::Error::other("export buffer lock poisoned"))
+    }
+}
+
+impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.lock()?.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::io::formats::csv::CsvExportSink;
     use crate::io::formats::json::JsonExportSink;
-    use crate::models::{Domain, MemoryId, MemoryStatus, Namespace};
+    use crate::models::{CaptureOrigin, Domain, MemoryId, MemoryStatus, Namespace};
+    use crate::storage::index::{InMemoryIndexBackend, SqliteBackend};
 
     fn test_memory(id: &str, content: &str) -> Memory {
         Memory {
@@ -278,6 +516,13 @@ fn test_memory(id: &str, content: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -331,4 +576,199 @@ fn test_export_memories_to_json() {
         assert!(output_str.contains("First memory"));
         assert!(output_str.contains("Second memory"));
     }
+
+    #[test]
+    fn test_export_projects_requested_fields() {
+        let index = Arc::new(SqliteBackend::in_memory().unwrap());
+        let service = ExportService::new(index);
+        let memories = [test_memory("1", "First memory")];
+        let fields = ExportField::parse_list("content,id").unwrap();
+
+        let mut json = Vec::new();
+        {
+            let mut sink = JsonExportSink::new(&mut json).with_fields(fields.clone());
+            service
+                .export_memories(memories.iter(), &mut sink, None)
+                .unwrap();
+            Box::new(sink).finalize().unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(json).unwrap(),
+            "{\"content\":\"First memory\",\"id\":\"1\"}\n"
+        );
+
+        let mut csv = Vec::new();
+        {
+            let mut sink = CsvExportSink::new(&mut csv).unwrap().with_fields(fields);
+            service
+                .export_memories(memories.iter(), &mut sink, None)
+                .unwrap();
+            Box::new(sink).finalize().unwrap();
+        }
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "content,id\nFirst memory,1\n"
+        );
+    }
+
+    #[test]
+    fn test_export_references_attachments() {
+        let index = Arc::new(SqliteBackend::in_memory().unwrap());
+        let service = ExportService::new(index);
+        let mut memory = test_memory("1", "Login page layout");
+        memory.attachments.push(crate::models::Attachment {
+            hash: "cd".repeat(32),
+            name: "login.png".to_string(),
+            media_type: "image/png".to_string(),
+            size: 2048,
+        });
+
+        let mut output = Vec::new();
+        {
+            let mut sink = JsonExportSink::new(&mut output);
+            service
+                .export_memories(std::iter::once(&memory), &mut sink, None)
+                .unwrap();
+            Box::new(sink).finalize().unwrap();
+        }
+
+        let exported: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(exported["attachments"][0]["name"], "login.png");
+        assert_eq!(exported["attachments"][0]["hash"], "cd".repeat(32));
+    }
+
+    #[test]
+    fn test_anonymized_export_strips_metadata_and_pii() {
+        let index = Arc::new(SqliteBackend::in_memory().unwrap());
+        let mut memory = test_memory("1", "Ask jane.doe@example.com about refund rounding");
+        memory.domain = Domain::for_repository("acme", "billing");
+        memory.project_id = Some("https://github.com/acme/billing.git".to_string());
+        memory.source = Some("src/refunds.rs".to_string());
+        index.index(&memory).unwrap();
+        let service = ExportService::new(index);
+
+        let options = ExportOptions::default()
+            .with_anonymize(true)
+            .with_redact_pii(true);
+        let mut output = Vec::new();
+        {
+            let mut sink = JsonExportSink::new(&mut output);
+            let result = service.export_to_sink(&mut sink, &options, None).unwrap();
+            assert_eq!(result.exported, 1);
+            Box::new(sink).finalize().unwrap();
+        }
+
+        let exported: serde_json::Value = serde_json::from_slice(&output).unwrap();
+        assert_eq!(exported["namespace"], "decisions");
+        assert!(exported.get("source").is_none());
+        assert!(exported.get("domain").is_none());
+        assert!(exported.get("project_id").is_none());
+        let content = exported["content"].as_str().unwrap();
+        assert!(!content.contains("jane.doe@example.com"));
+        assert!(content.contains("[REDACTED]"));
+        assert!(!String::from_utf8(output).unwrap().contains("acme"));
+    }
+
+    /// Exports with `options`, returning the exported IDs and the result.
+    fn export_ids(service: &ExportService, options: &ExportOptions) -> (Vec<String>, ExportResult) {
+        let mut output = Vec::new();
+        let mut sink = JsonExportSink::new(&mut output);
+        let result = service.export_to_sink(&mut sink, options, None).unwrap();
+        Box::new(sink).finalize().unwrap();
+
+        let mut ids: Vec<String> = String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| {
+                let value: serde_json::Value = serde_json::from_str(line).unwrap();
+                value["id"].as_str().unwrap().to_string()
+            })
+            .collect();
+        ids.sort();
+        (ids, result)
+    }
+
+    #[test]
+    fn test_incremental_export_writes_only_changes() {
+        assert_incremental_export(Arc::new(SqliteBackend::in_memory().unwrap()));
+    }
+
+    #[test]
+    fn test_incremental_export_from_in_memory_index() {
+        assert_incremental_export(Arc::new(InMemoryIndexBackend::new()));
+    }
+
+    /// Exports everything from `index`, changes it, then checks that an
+    /// export since the first checkpoint holds only the changes.
+    fn assert_incremental_export(index: Arc<dyn IndexBackend + Send + Sync>) {
+        for id in ["a", "b", "c"] {
+            let mut memory = test_memory(id, &format!("Memory {id}"));
+            memory.created_at = 1_000;
+            memory.updated_at = 1_000;
+            index.index(&memory).unwrap();
+        }
+        let service = ExportService::new(Arc::clone(&index));
+
+        let (ids, full) = export_ids(&service, &ExportOptions::default());
+        assert_eq!(ids, vec!["a", "b", "c"]);
+        assert_eq!(full.checkpoint.updated_at, 1_000);
+
+        // Modify one, delete one, add two (one within the checkpoint's second)
+        let mut modified = test_memory("b", "Memory b, revised");
+        modified.created_at = 1_000;
+        modified.updated_at = 2_000;
+        index.index(&modified).unwrap();
+        let mut deleted = test_memory("c", "Memory c");
+        deleted.created_at = 1_000;
+        deleted.updated_at = 2_000;
+        deleted.status = MemoryStatus::Tombstoned;
+        deleted.tombstoned_at = chrono::DateTime::from_timestamp(2_000, 0);
+        index.index(&deleted).unwrap();
+        for (id, at) in [("d", 1_500), ("e", 1_000)] {
+            let mut added = test_memory(id, &format!("Memory {id}"));
+            added.created_at = at;
+            added.updated_at = at;
+            index.index(&added).unwrap();
+        }
+
+        let options = ExportOptions::default().with_since(full.checkpoint);
+        let (ids, incremental) = export_ids(&service, &options);
+        assert_eq!(ids, vec!["b", "c", "d", "e"]);
+        assert_eq!(incremental.total_matched, 4);
+        assert_eq!(incremental.checkpoint.updated_at, 2_000);
+
+        let options = ExportOptions::default().with_since(incremental.checkpoint);
+        let (ids, _) = export_ids(&service, &options);
+        assert!(ids.is_empty());
+    }
+
+    #[test]
+    fn test_export_checkpoint_roundtrip() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let output = dir.path().join("backup.json");
+        let path = ExportCheckpoint::path_for(&output);
+        assert_eq!(path, dir.path().join("backup.json.checkpoint"));
+        assert_eq!(ExportCheckpoint::load(&path).unwrap(), None);
+
+        let checkpoint = ExportCheckpoint {
+            updated_at: 1_000,
+            boundary_ids: vec!["a".to_string()],
+            filter: Some("ns:decisions".to_string()),
+            exported_at: 1_010,
+        };
+        checkpoint.save(&path).unwrap();
+        assert_eq!(ExportCheckpoint::load(&path).unwrap(), Some(checkpoint));
+
+        std::fs::write(&path, "not json").unwrap();
+        assert!(matches!(
+            ExportCheckpoint::load(&path),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_export_rejects_unknown_field() {
+        let err = ExportField::parse_list("id,bogus").unwrap_err();
+        assert!(matches!(err, Error::InvalidInput(_)));
+    }
 }
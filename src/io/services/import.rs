@@ -9,16 +9,97 @@
     clippy::unnecessary_wraps
 )]
 
+use crate::io::formats::markdown::MarkdownDirectorySource;
 use crate::io::formats::{Format, create_import_source};
-use crate::io::traits::ImportSource;
-use crate::io::validation::{ImportValidator, ValidationSeverity};
-use crate::models::{Domain, Namespace};
+use crate::io::traits::{ImportSource, ImportedMemory};
+use crate::io::validation::{ImportValidator, ValidationIssue, ValidationSeverity, parse_domain};
+use crate::models::{ContentHasher, Domain, Memory, MemoryId, Namespace};
 use crate::services::CaptureService;
-use crate::services::deduplication::ContentHasher;
 use crate::{Error, Result};
+use std::fmt;
 use std::io::BufRead;
 use std::path::Path;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Default number of records between progress callbacks.
+pub const DEFAULT_PROGRESS_INTERVAL: usize = 100;
+
+/// How imported records that match an existing memory are handled.
+///
+/// The match key depends on the record: records carrying an `id` (as written
+/// by `subcog export`) are matched by memory ID; records without one, or whose
+/// ID is unknown, are matched by the hash of their normalized content within
+/// the namespace they are imported into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MergeStrategy {
+    /// Skip records that match an existing memory (default).
+    #[default]
+    Skip,
+    /// Update the matching memory in place.
+    Overwrite,
+    /// Add every record as a new memory, even if it matches by ID.
+    ///
+    /// Content that already exists in the target namespace is still not
+    /// stored twice.
+    Append,
+}
+
+impl MergeStrategy {
+    /// Returns the strategy name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Skip => "skip",
+            Self::Overwrite => "overwrite",
+            Self::Append => "append",
+        }
+    }
+}
+
+impl fmt::Display for MergeStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for MergeStrategy {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "skip" => Ok(Self::Skip),
+            "overwrite" | "update" | "upsert" => Ok(Self::Overwrite),
+            "append" | "duplicate" => Ok(Self::Append),
+            _ => Err(Error::InvalidInput(format!(
+                "Unknown merge strategy: {s} (expected skip, overwrite, or append)"
+            ))),
+        }
+    }
+}
+
+/// Outcome of an [`ImportService::upsert`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpsertOutcome {
+    /// No matching memory existed; a new one was captured.
+    Inserted,
+    /// A matching memory was updated in place.
+    Updated,
+    /// The content already exists in its namespace; nothing was stored.
+    Duplicate,
+}
+
+impl UpsertOutcome {
+    /// Outcome of capturing a record that matched no memory.
+    const fn captured(duplicate: bool) -> Self {
+        if duplicate {
+            Self::Duplicate
+        } else {
+            Self::Inserted
+        }
+    }
+}
 
 /// Options for memory import.
 #[derive(Debug, Clone)]
@@ -29,12 +110,14 @@ pub struct ImportOptions {
     pub default_namespace: Namespace,
     /// Default domain for memories without one.
     pub default_domain: Domain,
-    /// Skip memories that would be duplicates.
-    pub skip_duplicates: bool,
+    /// How records matching an existing memory are handled.
+    pub merge_strategy: MergeStrategy,
     /// Continue on validation errors (skip invalid records).
     pub skip_invalid: bool,
     /// Dry run mode (validate without storing).
     pub dry_run: bool,
+    /// Number of records between progress callbacks (the final state is always reported).
+    pub progress_interval: usize,
 }
 
 impl Default for ImportOptions {
@@ -43,9 +126,10 @@ fn default() -> Self {
             format: Format::Json,
             default_namespace: Namespace::Decisions,
             default_domain: Domain::new(),
-            skip_duplicates: true,
+            merge_strategy: MergeStrategy::Skip,
             skip_invalid: true,
             dry_run: false,
+            progress_interval: DEFAULT_PROGRESS_INTERVAL,
         }
     }
 }
@@ -73,9 +157,22 @@ pub fn with_default_domain(mut self, domain: Domain) -> Self {
     }
 
     /// Enables or disables duplicate skipping.
+    ///
+    /// Shorthand for [`MergeStrategy::Skip`] (`true`) or [`MergeStrategy::Append`] (`false`).
     #[must_use]
     pub const fn with_skip_duplicates(mut self, skip: bool) -> Self {
-        self.skip_duplicates = skip;
+        self.merge_strategy = if skip {
+            MergeStrategy::Skip
+        } else {
+            MergeStrategy::Append
+        };
+        self
+    }
+
+    /// Sets the merge strategy for records matching existing memories.
+    #[must_use]
+    pub const fn with_merge_strategy(mut self, strategy: MergeStrategy) -> Self {
+        self.merge_strategy = strategy;
         self
     }
 
@@ -85,6 +182,13 @@ pub const fn with_dry_run(mut self, dry_run: bool) -> Self {
         self.dry_run = dry_run;
         self
     }
+
+    /// Sets the number of records between progress callbacks (minimum 1).
+    #[must_use]
+    pub const fn with_progress_interval(mut self, interval: usize) -> Self {
+        self.progress_interval = if interval == 0 { 1 } else { interval };
+        self
+    }
 }
 
 /// Progress callback for import operations.
@@ -97,6 +201,8 @@ pub struct ImportProgress {
     pub processed: usize,
     /// Records successfully imported.
     pub imported: usize,
+    /// Existing memories updated in place.
+    pub updated: usize,
     /// Records skipped (duplicates).
     pub skipped_duplicates: usize,
     /// Records skipped (invalid).
@@ -105,6 +211,8 @@ pub struct ImportProgress {
     pub total_estimate: Option<usize>,
     /// Current record being processed (1-indexed).
     pub current: usize,
+    /// Time elapsed since the import started.
+    pub elapsed: Duration,
 }
 
 impl ImportProgress {
@@ -119,6 +227,24 @@ pub fn percent_complete(&self) -> Option<f32> {
             }
         })
     }
+
+    /// Returns the estimated time remaining if total is known.
+    #[must_use]
+    pub fn eta(&self) -> Option<Duration> {
+        estimate_remaining(self.processed, self.total_estimate?, self.elapsed)
+    }
+}
+
+/// Estimates the time remaining from the average rate so far.
+///
+/// Returns `None` until at least one record has been processed.
+#[must_use]
+pub fn estimate_remaining(processed: usize, total: usize, elapsed: Duration) -> Option<Duration> {
+    if processed == 0 {
+        return None;
+    }
+    let remaining = total.saturating_sub(processed);
+    Some(elapsed.mul_f64(remaining as f64 / processed as f64))
 }
 
 /// Result of an import operation.
@@ -126,6 +252,8 @@ pub fn percent_complete(&self) -> Option<f32> {
 pub struct ImportResult {
     /// Number of records successfully imported.
     pub imported: usize,
+    /// Number of existing memories updated in place.
+    pub updated: usize,
     /// Number of records skipped as duplicates.
     pub skipped_duplicates: usize,
     /// Number of records skipped due to validation errors.
@@ -144,6 +272,7 @@ impl ImportResult {
     pub const fn new() -> Self {
         Self {
             imported: 0,
+            updated: 0,
             skipped_duplicates: 0,
             skipped_invalid: 0,
             total_processed: 0,
@@ -152,10 +281,10 @@ pub const fn new() -> Self {
         }
     }
 
-    /// Returns whether any records were imported.
+    /// Returns whether any records were imported or updated.
     #[must_use]
     pub const fn has_imports(&self) -> bool {
-        self.imported > 0
+        self.imported > 0 || self.updated > 0
     }
 
     /// Returns whether any errors occurred.
@@ -226,6 +355,26 @@ pub fn import_from_reader<R: BufRead + 'static>(
         self.import_from_source(source.as_mut(), &options, progress)
     }
 
+    /// Imports Markdown notes from a directory tree.
+    ///
+    /// Each `.md` file becomes one memory (see [`MarkdownDirectorySource`]);
+    /// `options.format` is ignored. Notes whose front-matter names no
+    /// namespace take `options.default_namespace`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory or a note cannot be read, or storage
+    /// errors occur.
+    pub fn import_from_directory(
+        &self,
+        dir: &Path,
+        options: ImportOptions,
+        progress: Option<ProgressCallback>,
+    ) -> Result<ImportResult> {
+        let mut source = MarkdownDirectorySource::new(dir)?;
+        self.import_from_source(&mut source, &options, progress)
+    }
+
     /// Imports memories from a source.
     ///
     /// # Errors
@@ -238,24 +387,56 @@ pub fn import_from_source(
         options: &ImportOptions,
         progress: Option<ProgressCallback>,
     ) -> Result<ImportResult> {
-        let validator = ImportValidator::new()
-            .with_default_namespace(options.default_namespace)
-            .with_default_domain(options.default_domain.clone());
+        let validator = Self::validator(options);
 
         let mut result = ImportResult::new();
+        let started = Instant::now();
+        let interval = options.progress_interval.max(1);
+        let report = |prog: &mut ImportProgress, force: bool| {
+            if let Some(ref cb) = progress
+                && (force || prog.processed.is_multiple_of(interval))
+            {
+                prog.elapsed = started.elapsed();
+                cb(prog);
+            }
+        };
         let mut prog = ImportProgress {
             total_estimate: source.size_hint(),
             ..Default::default()
         };
 
-        // Track existing content hashes for deduplication
+        // Track content hashes seen in this batch for deduplication
         let mut seen_hashes = std::collections::HashSet::new();
 
-        while let Some(imported) = source.next()? {
+        loop {
+            let record = match source.next_record() {
+                Ok(Some(record)) => record,
+                Ok(None) => break,
+                // A bad note is skipped like an invalid record
+                Err(e) if source.recovers_from_errors() => {
+                    prog.current += 1;
+                    prog.processed += 1;
+                    result.total_processed += 1;
+                    Self::reject_unreadable(&mut result, &mut prog, options, e)?;
+                    report(&mut prog, false);
+                    continue;
+                },
+                Err(e) => return Err(e),
+            };
             prog.current += 1;
             prog.processed += 1;
             result.total_processed += 1;
 
+            // Check the raw record against the memory schema
+            let imported = match validator.parse_record(record) {
+                Ok(imported) => imported,
+                Err(issues) => {
+                    Self::reject_invalid(&mut result, &mut prog, options, &issues)?;
+                    report(&mut prog, false);
+                    continue;
+                },
+            };
+
             // Validate the imported memory
             let validation = validator.validate(&imported);
 
@@ -271,96 +452,244 @@ pub fn import_from_source(
 
             // Handle validation errors
             if !validation.is_valid {
-                if options.skip_invalid {
-                    prog.skipped_invalid += 1;
-                    result.skipped_invalid += 1;
-                    for issue in &validation.issues {
-                        if issue.severity == ValidationSeverity::Error {
-                            result.errors.push(format!(
-                                "Record {}: {}: {}",
-                                prog.current, issue.field, issue.message
-                            ));
-                        }
-                    }
-                    if let Some(ref cb) = progress {
-                        cb(&prog);
-                    }
-                    continue;
-                }
-                return Err(Error::InvalidInput(format!(
-                    "Record {}: validation failed",
-                    prog.current
-                )));
+                Self::reject_invalid(&mut result, &mut prog, options, &validation.issues)?;
+                report(&mut prog, false);
+                continue;
             }
 
-            // Check for duplicates
-            let content_hash = ContentHasher::hash(&imported.content);
-            if options.skip_duplicates {
-                // Check in-batch duplicates
-                if seen_hashes.contains(&content_hash) {
-                    prog.skipped_duplicates += 1;
-                    result.skipped_duplicates += 1;
-                    if let Some(ref cb) = progress {
-                        cb(&prog);
-                    }
-                    continue;
-                }
-
-                // Check existing memories via content hash tag
-                let hash_tag = ContentHasher::content_to_tag(&imported.content);
-                if self.memory_exists_with_tag(&hash_tag)? {
-                    prog.skipped_duplicates += 1;
-                    result.skipped_duplicates += 1;
-                    seen_hashes.insert(content_hash);
-                    if let Some(ref cb) = progress {
-                        cb(&prog);
-                    }
-                    continue;
-                }
-
-                seen_hashes.insert(content_hash);
+            // Skip in-batch duplicates and records matching existing memories
+            if options.merge_strategy == MergeStrategy::Skip
+                && (!seen_hashes.insert(self.content_key(&imported, options))
+                    || self.find_existing(&imported, options)?.is_some())
+            {
+                prog.skipped_duplicates += 1;
+                result.skipped_duplicates += 1;
+                report(&mut prog, false);
+                continue;
             }
 
             // Store the memory (unless dry run)
-            if options.dry_run {
-                // Dry run counts as imported
+            let outcome = self.store_record(&validator, imported, options);
+            Self::count_outcome(&mut result, &mut prog, options, outcome)?;
+
+            report(&mut prog, false);
+        }
+
+        // Always report the final state
+        if !prog.processed.is_multiple_of(interval) {
+            report(&mut prog, true);
+        }
+
+        Ok(result)
+    }
+
+    /// Stores a validated record according to the merge strategy; a dry run
+    /// only reports what would happen.
+    fn store_record(
+        &self,
+        validator: &ImportValidator,
+        imported: ImportedMemory,
+        options: &ImportOptions,
+    ) -> Result<UpsertOutcome> {
+        if options.dry_run {
+            // Dry run counts as imported (or updated, if it would overwrite)
+            let would_update = options.merge_strategy == MergeStrategy::Overwrite
+                && self.find_existing(&imported, options)?.is_some();
+            return Ok(if would_update {
+                UpsertOutcome::Updated
+            } else {
+                UpsertOutcome::Inserted
+            });
+        }
+        if options.merge_strategy == MergeStrategy::Overwrite {
+            return self.upsert(imported, options);
+        }
+        let request = validator.to_capture_request(imported);
+        self.capture_service
+            .capture(request)
+            .map(|captured| UpsertOutcome::captured(captured.duplicate))
+    }
+
+    /// Counts the outcome of storing a record; a failed record is skipped
+    /// with an error, or fails the import when `skip_invalid` is disabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns the storage error when `skip_invalid` is disabled.
+    fn count_outcome(
+        result: &mut ImportResult,
+        prog: &mut ImportProgress,
+        options: &ImportOptions,
+        outcome: Result<UpsertOutcome>,
+    ) -> Result<()> {
+        match outcome {
+            Ok(UpsertOutcome::Inserted) => {
                 prog.imported += 1;
                 result.imported += 1;
-            } else {
-                let request = validator.to_capture_request(imported);
-                match self.capture_service.capture(request) {
-                    Ok(_) => {
-                        prog.imported += 1;
-                        result.imported += 1;
-                    },
-                    Err(e) => {
-                        if options.skip_invalid {
-                            result
-                                .errors
-                                .push(format!("Record {}: capture failed: {}", prog.current, e));
-                            prog.skipped_invalid += 1;
-                            result.skipped_invalid += 1;
-                        } else {
-                            return Err(e);
-                        }
-                    },
-                }
-            }
+            },
+            Ok(UpsertOutcome::Updated) => {
+                prog.updated += 1;
+                result.updated += 1;
+            },
+            Ok(UpsertOutcome::Duplicate) => {
+                prog.skipped_duplicates += 1;
+                result.skipped_duplicates += 1;
+            },
+            Err(e) if options.skip_invalid => {
+                result
+                    .errors
+                    .push(format!("Record {}: capture failed: {}", prog.current, e));
+                prog.skipped_invalid += 1;
+                result.skipped_invalid += 1;
+            },
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
 
-            if let Some(ref cb) = progress {
-                cb(&prog);
-            }
+    /// Records an invalid record as skipped, or fails the import.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error issue when `skip_invalid` is disabled.
+    fn reject_invalid(
+        result: &mut ImportResult,
+        prog: &mut ImportProgress,
+        options: &ImportOptions,
+        issues: &[ValidationIssue],
+    ) -> Result<()> {
+        let current = prog.current;
+        let mut errors = issues
+            .iter()
+            .filter(|issue| issue.severity == ValidationSeverity::Error)
+            .map(|issue| format!("Record {current}: {issue}"));
+
+        if !options.skip_invalid {
+            let detail = errors
+                .next()
+                .unwrap_or_else(|| format!("Record {current}: validation failed"));
+            return Err(Error::InvalidInput(detail));
         }
 
-        Ok(result)
+        prog.skipped_invalid += 1;
+        result.skipped_invalid += 1;
+        result.errors.extend(errors);
+        Ok(())
     }
 
-    /// Checks if a memory with the given hash tag already exists.
-    const fn memory_exists_with_tag(&self, _hash_tag: &str) -> Result<bool> {
-        // For now, we'll rely on the capture service's deduplication
-        // The hash tag is added during capture and can be checked there
-        // This is a simplified check - in production, we'd query the index
-        Ok(false)
+    /// Records a record the source could not read as skipped, or fails the
+    /// import.
+    ///
+    /// # Errors
+    ///
+    /// Returns `error` when `skip_invalid` is disabled.
+    fn reject_unreadable(
+        result: &mut ImportResult,
+        prog: &mut ImportProgress,
+        options: &ImportOptions,
+        error: Error,
+    ) -> Result<()> {
+        if !options.skip_invalid {
+            return Err(error);
+        }
+
+        prog.skipped_invalid += 1;
+        result.skipped_invalid += 1;
+        result
+            .errors
+            .push(format!("Record {}: {error}", prog.current));
+        Ok(())
+    }
+
+    /// Inserts a record, or updates the existing memory it matches in place.
+    ///
+    /// Matching follows [`MergeStrategy`]: by memory ID when the record has
+    /// one, otherwise by content hash within the record's namespace. On
+    /// update, the record's content, tags, and source replace the stored
+    /// values, as do its namespace and domain when present; the memory ID and
+    /// creation time are preserved.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lookup, capture, or update fails.
+    pub fn upsert(
+        &self,
+        imported: ImportedMemory,
+        options: &ImportOptions,
+    ) -> Result<UpsertOutcome> {
+        let Some(mut memory) = self.find_existing(&imported, options)? else {
+            let request = Self::validator(options).to_capture_request(imported);
+            let captured = self.capture_service.capture(request)?;
+            return Ok(UpsertOutcome::captured(captured.duplicate));
+        };
+
+        if let Some(namespace) = imported.namespace.as_deref().and_then(Namespace::parse) {
+            memory.namespace = namespace;
+        }
+        if let Some(ref domain) = imported.domain {
+            memory.domain = parse_domain(domain);
+        }
+        memory.content = imported.content;
+        memory.tags = imported.tags;
+        if imported.source.is_some() {
+            memory.source = imported.source;
+        }
+        if let Some(ttl) = imported.ttl_seconds {
+            memory.expires_at = (ttl > 0).then(|| memory.created_at.saturating_add(ttl));
+        }
+
+        self.capture_service.update(memory)?;
+        Ok(UpsertOutcome::Updated)
+    }
+
+    /// Finds the existing memory an imported record matches, if any.
+    ///
+    /// Returns `None` when the capture service has no index backend.
+    fn find_existing(
+        &self,
+        imported: &ImportedMemory,
+        options: &ImportOptions,
+    ) -> Result<Option<Memory>> {
+        let Some(index) = self.capture_service.index_backend() else {
+            return Ok(None);
+        };
+
+        // Records from a subcog export carry their original ID
+        if let Some(ref id) = imported.id
+            && let Some(memory) = index.get_memory(&MemoryId::new(id.as_str()))?
+        {
+            return Ok(Some(memory));
+        }
+
+        // Fall back to a live memory with the same content in the record's
+        // namespace
+        let (namespace, content_hash) = self.content_key(imported, options);
+        index
+            .find_by_content_hash(namespace, &content_hash)?
+            .map_or(Ok(None), |id| index.get_memory(&id))
+    }
+
+    /// Returns the namespace a record is imported into and the hash of its
+    /// content as capture would store it.
+    fn content_key(
+        &self,
+        imported: &ImportedMemory,
+        options: &ImportOptions,
+    ) -> (Namespace, String) {
+        let namespace = imported
+            .namespace
+            .as_deref()
+            .and_then(Namespace::parse)
+            .unwrap_or(options.default_namespace);
+        let content = self.capture_service.normalize(&imported.content);
+        (namespace, ContentHasher::hash(&content))
+    }
+
+    /// Builds the validator for the given options.
+    fn validator(options: &ImportOptions) -> ImportValidator {
+        ImportValidator::new()
+            .with_default_namespace(options.default_namespace)
+            .with_default_domain(options.default_domain.clone())
     }
 }
 
@@ -368,17 +697,30 @@ const fn memory_exists_with_tag(&self, _hash_tag: &str) -> Result<bool> {
 mod tests {
     use super::*;
     use crate::config::Config;
+    use crate::models::SearchFilter;
+    use crate::storage::index::SqliteBackend;
+    use crate::storage::traits::IndexBackend;
     use std::io::Cursor;
 
+    fn test_index() -> Arc<dyn IndexBackend + Send + Sync> {
+        Arc::new(SqliteBackend::in_memory().unwrap())
+    }
+
     fn test_capture_service() -> Arc<CaptureService> {
-        Arc::new(CaptureService::new(Config::default()))
+        Arc::new(CaptureService::new_minimal(Config::default()).with_index(test_index()))
+    }
+
+    fn import_json(service: &ImportService, input: &str, options: ImportOptions) -> ImportResult {
+        service
+            .import_from_reader(Cursor::new(input.to_string()), options, None)
+            .unwrap()
     }
 
     #[test]
     fn test_import_options_defaults() {
         let options = ImportOptions::default();
         assert_eq!(options.format, Format::Json);
-        assert!(options.skip_duplicates);
+        assert_eq!(options.merge_strategy, MergeStrategy::Skip);
         assert!(options.skip_invalid);
         assert!(!options.dry_run);
     }
@@ -440,4 +782,275 @@ fn test_import_with_invalid_record() {
         assert_eq!(result.skipped_invalid, 1);
         assert_eq!(result.imported, 1);
     }
+
+    #[test]
+    fn test_import_skips_schema_violations_with_line() {
+        let service = ImportService::new(test_capture_service());
+        let input = r#"{"content": "Valid memory"}
+{"content": "Bad tags", "tags": "rust"}
+{"content": "Typo", "namepsace": "decisions"}"#;
+
+        let result = import_json(&service, input, ImportOptions::default().with_dry_run(true));
+
+        assert_eq!(result.imported, 1);
+        assert_eq!(result.skipped_invalid, 2);
+        assert_eq!(
+            result.errors[0],
+            "Record 2: line 2: tags: expected array of strings, got string"
+        );
+        assert!(result.errors[1].starts_with("Record 3: line 3: namepsace: unknown field"));
+    }
+
+    #[test]
+    fn test_import_markdown_directory() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join("adr")).unwrap();
+        std::fs::create_dir_all(root.join("drafts")).unwrap();
+        std::fs::write(
+            root.join("adr/0001-index.md"),
+            "---\nnamespace: decisions\ntags: [storage, sqlite]\n---\nUse SQLite for the index.\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("notes.md"),
+            "Builds run on the self-hosted runner.\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("drafts/wip.md"), "Half-formed idea.\n").unwrap();
+        std::fs::write(root.join("zz-broken.md"), "---\ncreated_at: soon\n---\nx\n").unwrap();
+        std::fs::write(root.join("readme.txt"), "Not a note.\n").unwrap();
+        std::fs::write(root.join(".subcogignore"), "drafts/\n").unwrap();
+
+        let capture = test_capture_service();
+        let service = ImportService::new(Arc::clone(&capture));
+        let options = ImportOptions::default()
+            .with_default_namespace(crate::io::formats::markdown::MARKDOWN_DEFAULT_NAMESPACE);
+        let result = service
+            .import_from_directory(root, options.clone(), None)
+            .unwrap();
+        assert_eq!(result.total_processed, 3);
+        assert_eq!(result.imported, 2);
+        assert_eq!(result.skipped_invalid, 1);
+        assert!(result.errors[0].starts_with("Record 3: "));
+        assert!(result.errors[0].contains("zz-broken.md"));
+
+        let index = capture.index_backend().unwrap();
+        let mut memories: Vec<Memory> = index
+            .list_all(&SearchFilter::new(), 10)
+            .unwrap()
+            .into_iter()
+            .map(|(id, _)| index.get_memory(&id).unwrap().unwrap())
+            .collect();
+        memories.sort_by(|a, b| a.source.cmp(&b.source));
+
+        assert_eq!(memories[0].source.as_deref(), Some("adr/0001-index.md"));
+        assert_eq!(memories[0].content, "Use SQLite for the index.");
+        assert_eq!(memories[0].namespace, Namespace::Decisions);
+        assert!(memories[0].tags.contains(&"sqlite".to_string()));
+        assert_eq!(memories[1].source.as_deref(), Some("notes.md"));
+        assert_eq!(memories[1].namespace, Namespace::Context);
+
+        // Re-importing the same tree skips every note as a duplicate
+        let again = service.import_from_directory(root, options, None).unwrap();
+        assert_eq!(again.skipped_duplicates, 2);
+    }
+
+    #[test]
+    fn test_merge_strategy_parse() {
+        assert_eq!(
+            "skip".parse::<MergeStrategy>().unwrap(),
+            MergeStrategy::Skip
+        );
+        assert_eq!(
+            "Overwrite".parse::<MergeStrategy>().unwrap(),
+            MergeStrategy::Overwrite
+        );
+        assert_eq!(
+            "append".parse::<MergeStrategy>().unwrap(),
+            MergeStrategy::Append
+        );
+        assert!("merge".parse::<MergeStrategy>().is_err());
+        assert_eq!(
+            ImportOptions::default()
+                .with_skip_duplicates(false)
+                .merge_strategy,
+            MergeStrategy::Append
+        );
+    }
+
+    #[test]
+    fn test_reimport_skip_and_append() {
+        let capture = test_capture_service();
+        let service = ImportService::new(Arc::clone(&capture));
+        let input = r#"{"content": "Use SQLite for the index", "namespace": "decisions"}
+{"content": "Prefer thiserror for library errors", "namespace": "patterns"}"#;
+
+        let first = import_json(&service, input, ImportOptions::default());
+        assert_eq!(first.imported, 2);
+
+        let skipped = import_json(&service, input, ImportOptions::default());
+        assert_eq!(skipped.imported, 0);
+        assert_eq!(skipped.skipped_duplicates, 2);
+
+        let appended = import_json(
+            &service,
+            input,
+            ImportOptions::default().with_merge_strategy(MergeStrategy::Append),
+        );
+        assert_eq!(appended.imported, 0);
+        assert_eq!(appended.skipped_duplicates, 2);
+
+        let index = capture.index_backend().unwrap();
+        assert_eq!(index.list_all(&SearchFilter::new(), 10).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_reimport_overwrite_updates_by_content_hash() {
+        let capture = test_capture_service();
+        let service = ImportService::new(Arc::clone(&capture));
+        let index = Arc::clone(capture.index_backend().unwrap());
+
+        let original = r#"{"content": "Use SQLite\nfor the index", "tags": ["storage"]}"#;
+        assert_eq!(
+            import_json(&service, original, ImportOptions::default()).imported,
+            1
+        );
+        let ids = index.list_all(&SearchFilter::new(), 10).unwrap();
+        let id = ids[0].0.clone();
+
+        // Same content, up to normalization, with modified metadata
+        let modified = r#"{"content": "Use SQLite  \r\nfor the index\r\n", "namespace": "decisions", "tags": ["sqlite", "index"]}"#;
+        let result = import_json(
+            &service,
+            modified,
+            ImportOptions::default().with_merge_strategy(MergeStrategy::Overwrite),
+        );
+        assert_eq!(result.updated, 1);
+        assert_eq!(result.imported, 0);
+
+        let memory = index.get_memory(&id).unwrap().unwrap();
+        assert_eq!(memory.namespace, Namespace::Decisions);
+        assert!(memory.tags.contains(&"sqlite".to_string()));
+        assert!(!memory.tags.contains(&"storage".to_string()));
+        assert_eq!(index.list_all(&SearchFilter::new(), 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_reimport_matches_normalized_content_within_namespace() {
+        let capture = test_capture_service();
+        let service = ImportService::new(Arc::clone(&capture));
+        let index = Arc::clone(capture.index_backend().unwrap());
+
+        let original = r#"{"content": "Prefer thiserror\nfor library errors"}"#;
+        import_json(&service, original, ImportOptions::default());
+        let id = index.list_all(&SearchFilter::new(), 10).unwrap()[0]
+            .0
+            .clone();
+
+        // CRLF line endings and trailing whitespace still match
+        let crlf = r#"{"content": "\ufeffPrefer thiserror \r\nfor library errors\r\n"}"#;
+        let skipped = import_json(&service, crlf, ImportOptions::default());
+        assert_eq!(skipped.skipped_duplicates, 1);
+
+        // The same content in another namespace is a new memory
+        let other =
+            r#"{"content": "Prefer thiserror\nfor library errors", "namespace": "patterns"}"#;
+        let result = import_json(
+            &service,
+            other,
+            ImportOptions::default().with_merge_strategy(MergeStrategy::Overwrite),
+        );
+        assert_eq!(result.imported, 1);
+        assert_eq!(result.updated, 0);
+
+        let memory = index.get_memory(&id).unwrap().unwrap();
+        assert_eq!(memory.namespace, Namespace::Decisions);
+        assert_eq!(index.list_all(&SearchFilter::new(), 10).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_reimport_overwrite_updates_by_id() {
+        let capture = test_capture_service();
+        let service = ImportService::new(Arc::clone(&capture));
+        let index = Arc::clone(capture.index_backend().unwrap());
+
+        let original = r#"{"content": "Cache embeddings in memory"}"#;
+        import_json(&service, original, ImportOptions::default());
+        let id = index.list_all(&SearchFilter::new(), 10).unwrap()[0]
+            .0
+            .clone();
+
+        // An exported record with edited content keeps its id
+        let modified = format!(
+            r#"{{"id": "{}", "content": "Cache embeddings on disk, keyed by content hash"}}"#,
+            id.as_str()
+        );
+        let result = import_json(
+            &service,
+            &modified,
+            ImportOptions::default().with_merge_strategy(MergeStrategy::Overwrite),
+        );
+        assert_eq!(result.updated, 1);
+
+        let memory = index.get_memory(&id).unwrap().unwrap();
+        assert_eq!(
+            memory.content,
+            "Cache embeddings on disk, keyed by content hash"
+        );
+        assert!(
+            memory
+                .tags
+                .contains(&ContentHasher::content_to_tag(&memory.content))
+        );
+        assert_eq!(index.list_all(&SearchFilter::new(), 10).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_progress_callback_invoked_at_interval() {
+        use std::sync::Mutex;
+
+        let service = ImportService::new(test_capture_service());
+        let input: String = (1..=5)
+            .map(|i| format!("{{\"content\": \"Progress record number {i}\"}}\n"))
+            .collect();
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let seen_cb = Arc::clone(&seen);
+        let callback: ProgressCallback = Box::new(move |p: &ImportProgress| {
+            seen_cb.lock().unwrap().push(p.processed);
+        });
+
+        let result = service
+            .import_from_reader(
+                Cursor::new(input),
+                ImportOptions::default()
+                    .with_dry_run(true)
+                    .with_progress_interval(2),
+                Some(callback),
+            )
+            .unwrap();
+
+        assert_eq!(result.imported, 5);
+        // Every 2 records, plus the final state
+        assert_eq!(*seen.lock().unwrap(), vec![2, 4, 5]);
+    }
+
+    #[test]
+    fn test_import_progress_eta() {
+        let progress = ImportProgress {
+            processed: 25,
+            total_estimate: Some(100),
+            elapsed: Duration::from_secs(10),
+            ..Default::default()
+        };
+        assert_eq!(progress.eta(), Some(Duration::from_secs(30)));
+
+        let streaming = ImportProgress {
+            processed: 25,
+            ..Default::default()
+        };
+        assert!(streaming.eta().is_none());
+        assert!(estimate_remaining(0, 10, Duration::from_secs(1)).is_none());
+    }
 }
@@ -5,5 +5,7 @@
 pub mod export;
 pub mod import;
 
-pub use export::{ExportOptions, ExportResult, ExportService};
-pub use import::{ImportOptions, ImportProgress, ImportResult, ImportService};
+pub use export::{ExportCheckpoint, ExportOptions, ExportResult, ExportService};
+pub use import::{
+    ImportOptions, ImportProgress, ImportResult, ImportService, MergeStrategy, UpsertOutcome,
+};
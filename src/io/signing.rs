@@ -0,0 +1,271 @@
+//! Ed25519 signing and verification of export files.
+//!
+//! Signing writes a detached signature next to the export (`<file>.sig`)
+//! recording the signature and the signer's public key fingerprint. Import
+//! with verification refuses files that are unsigned, tampered with, or
+//! signed by a different key.
+//!
+//! Keys are read from PEM (PKCS#8 / SPKI, e.g. from
+//! `openssl genpkey -algorithm ed25519`) or from 64 hex characters holding
+//! the raw 32-byte key.
+
+use std::path::{Path, PathBuf};
+
+use ed25519_dalek::pkcs8::{DecodePrivateKey, DecodePublicKey};
+use ed25519_dalek::{Signature, Signer, SigningKey, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{Error, Result};
+
+/// Signature algorithm recorded in signature files.
+pub const SIGNATURE_ALGORITHM: &str = "ed25519";
+
+/// Extension appended to the export path for the detached signature.
+pub const SIGNATURE_EXTENSION: &str = "sig";
+
+/// Detached signature for an export file.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BundleSignature {
+    /// Signature algorithm (always `ed25519`).
+    pub algorithm: String,
+    /// Hex-encoded signature over the exact file bytes.
+    pub signature: String,
+    /// Fingerprint of the signer's public key (`sha256:<hex>`).
+    pub key_fingerprint: String,
+}
+
+impl BundleSignature {
+    /// Signs `data` with `key`.
+    #[must_use]
+    pub fn sign(data: &[u8], key: &SigningKey) -> Self {
+        Self {
+            algorithm: SIGNATURE_ALGORITHM.to_string(),
+            signature: hex::encode(key.sign(data).to_bytes()),
+            key_fingerprint: key_fingerprint(&key.verifying_key()),
+        }
+    }
+
+    /// Verifies that `data` was signed by `key`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the algorithm is unsupported, the
+    /// signature was made with a different key, or the data was tampered with.
+    pub fn verify(&self, data: &[u8], key: &VerifyingKey) -> Result<()> {
+        if self.algorithm != SIGNATURE_ALGORITHM {
+            return Err(Error::InvalidInput(format!(
+                "Unsupported signature algorithm '{}'",
+                self.algorithm
+            )));
+        }
+
+        let expected = key_fingerprint(key);
+        if self.key_fingerprint != expected {
+            return Err(Error::InvalidInput(format!(
+                "Signature was made with key {}, not {expected}",
+                self.key_fingerprint
+            )));
+        }
+
+        let bytes = hex::decode(&self.signature)
+            .map_err(|e| Error::InvalidInput(format!("Malformed signature: {e}")))?;
+        let signature = Signature::from_slice(&bytes)
+            .map_err(|e| Error::InvalidInput(format!("Malformed signature: {e}")))?;
+        key.verify_strict(data, &signature).map_err(|_| {
+            Error::InvalidInput("Signature verification failed: file was modified".to_string())
+        })
+    }
+}
+
+/// Returns the fingerprint of a public key (`sha256:<hex>`).
+#[must_use]
+pub fn key_fingerprint(key: &VerifyingKey) -> String {
+    format!("sha256:{}", hex::encode(Sha256::digest(key.as_bytes())))
+}
+
+/// Returns the detached signature path for an export file.
+#[must_use]
+pub fn signature_path(path: &Path) -> PathBuf {
+    let mut name = path.as_os_str().to_os_string();
+    name.push(".");
+    name.push(SIGNATURE_EXTENSION);
+    PathBuf::from(name)
+}
+
+/// Signs an export file, writing the detached signature next to it.
+///
+/// # Errors
+///
+/// Returns an error if the file or key cannot be read, or the signature
+/// file cannot be written.
+pub fn sign_file(path: &Path, key_path: &Path) -> Result<BundleSignature> {
+    let key = load_signing_key(key_path)?;
+    let data = read(path, "read_export")?;
+    let signature = BundleSignature::sign(&data, &key);
+
+    let json = serde_json::to_string_pretty(&signature).map_err(|e| Error::OperationFailed {
+        operation: "serialize_signature".to_string(),
+        cause: e.to_string(),
+    })?;
+    let sig_path = signature_path(path);
+    std::fs::write(&sig_path, json).map_err(|e| Error::OperationFailed {
+        operation: "write_signature".to_string(),
+        cause: format!("{}: {e}", sig_path.display()),
+    })?;
+
+    Ok(signature)
+}
+
+/// Verifies an export file against its detached signature.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidInput`] if the file is unsigned, was tampered
+/// with, or was signed by a different key.
+pub fn verify_file(path: &Path, key_path: &Path) -> Result<BundleSignature> {
+    let sig_path = signature_path(path);
+    if !sig_path.exists() {
+        return Err(Error::InvalidInput(format!(
+            "Refusing to import unsigned file: {} not found",
+            sig_path.display()
+        )));
+    }
+
+    let signature: BundleSignature = serde_json::from_slice(&read(&sig_path, "read_signature")?)
+        .map_err(|e| Error::InvalidInput(format!("Malformed signature file: {e}")))?;
+    let derived = hex_private_key(key_path)?
+        .filter(|derived| key_fingerprint(derived) == signature.key_fingerprint);
+    let key = derived.map_or_else(|| load_verifying_key(key_path), Ok)?;
+    signature.verify(&read(path, "read_import")?, &key)?;
+    Ok(signature)
+}
+
+/// Returns the public half of a hex key file read as a private key.
+///
+/// Raw 32-byte keys can't be told apart, so a hex private key is first read
+/// as a public key by [`load_verifying_key`]; verification falls back to its
+/// derived public key when that is the one that signed.
+fn hex_private_key(path: &Path) -> Result<Option<VerifyingKey>> {
+    let text = read_key(path)?;
+    if text.starts_with("-----BEGIN") {
+        return Ok(None);
+    }
+    Ok(Some(
+        SigningKey::from_bytes(&decode_hex_key(&text)?).verifying_key(),
+    ))
+}
+
+/// Loads a signing (private) key from a PEM or hex key file.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidInput`] if the key cannot be parsed.
+pub fn load_signing_key(path: &Path) -> Result<SigningKey> {
+    let text = read_key(path)?;
+    if text.starts_with("-----BEGIN") {
+        return SigningKey::from_pkcs8_pem(&text)
+            .map_err(|e| Error::InvalidInput(format!("Invalid Ed25519 private key: {e}")));
+    }
+    Ok(SigningKey::from_bytes(&decode_hex_key(&text)?))
+}
+
+/// Loads a verifying key from a PEM or hex key file.
+///
+/// A private key is also accepted; its public half is used.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidInput`] if the key cannot be parsed.
+pub fn load_verifying_key(path: &Path) -> Result<VerifyingKey> {
+    let text = read_key(path)?;
+    if text.contains("PRIVATE KEY-----") {
+        return load_signing_key(path).map(|key| key.verifying_key());
+    }
+    if text.starts_with("-----BEGIN") {
+        return VerifyingKey::from_public_key_pem(&text)
+            .map_err(|e| Error::InvalidInput(format!("Invalid Ed25519 public key: {e}")));
+    }
+    VerifyingKey::from_bytes(&decode_hex_key(&text)?)
+        .map_err(|e| Error::InvalidInput(format!("Invalid Ed25519 public key: {e}")))
+}
+
+fn read(path: &Path, operation: &str) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| Error::OperationFailed {
+        operation: operation.to_string(),
+        cause: format!("{}: {e}", path.display()),
+    })
+}
+
+fn read_key(path: &Path) -> Result<String> {
+    let bytes = read(path, "read_key")?;
+    Ok(String::from_utf8_lossy(&bytes).trim().to_string())
+}
+
+fn decode_hex_key(text: &str) -> Result<[u8; 32]> {
+    let bytes = hex::decode(text)
+        .map_err(|e| Error::InvalidInput(format!("Key must be PEM or 64 hex characters: {e}")))?;
+    bytes.try_into().map_err(|_| {
+        Error::InvalidInput("Key must be PEM or 64 hex characters (32 bytes)".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    /// Writes an export, a private key, and the matching public key.
+    fn setup(seed: u8) -> (TempDir, PathBuf, PathBuf, PathBuf) {
+        let dir = TempDir::new().unwrap();
+        let export = dir.path().join("memories.json");
+        std::fs::write(&export, "{\"content\": \"Use SQLite\"}\n").unwrap();
+
+        let key = SigningKey::from_bytes(&[seed; 32]);
+        let private = dir.path().join("signing.key");
+        std::fs::write(&private, hex::encode(key.to_bytes())).unwrap();
+        let public = dir.path().join("signing.pub");
+        std::fs::write(&public, hex::encode(key.verifying_key().as_bytes())).unwrap();
+
+        (dir, export, private, public)
+    }
+
+    #[test]
+    fn test_valid_signature_verifies() {
+        let (_dir, export, private, public) = setup(7);
+        let signed = sign_file(&export, &private).unwrap();
+        assert!(signature_path(&export).exists());
+
+        let verified = verify_file(&export, &public).unwrap();
+        assert_eq!(verified, signed);
+        // The private key also works for verification
+        assert!(verify_file(&export, &private).is_ok());
+    }
+
+    #[test]
+    fn test_tampered_file_rejected() {
+        let (_dir, export, private, public) = setup(7);
+        sign_file(&export, &private).unwrap();
+        std::fs::write(&export, "{\"content\": \"Use Postgres\"}\n").unwrap();
+
+        let err = verify_file(&export, &public).unwrap_err();
+        assert!(err.to_string().contains("modified"));
+    }
+
+    #[test]
+    fn test_wrong_key_rejected() {
+        let (_dir, export, private, _public) = setup(7);
+        let (_other_dir, _, _, other_public) = setup(9);
+        sign_file(&export, &private).unwrap();
+
+        let err = verify_file(&export, &other_public).unwrap_err();
+        assert!(err.to_string().contains("not sha256:"));
+    }
+
+    #[test]
+    fn test_unsigned_file_rejected() {
+        let (_dir, export, _private, public) = setup(7);
+        let err = verify_file(&export, &public).unwrap_err();
+        assert!(err.to_string().contains("unsigned"));
+    }
+}
@@ -3,9 +3,10 @@
 //! Defines the [`ImportSource`] and [`ExportSink`] traits that format adapters
 //! implement to support different file formats.
 
-use crate::Result;
-use crate::models::Memory;
-use serde::{Deserialize, Serialize};
+use crate::models::{Attachment, Memory};
+use crate::{Error, Result};
+use serde::ser::SerializeMap;
+use serde::{Deserialize, Serialize, Serializer};
 
 /// Intermediate representation for imported memory data.
 ///
@@ -17,6 +18,7 @@
 ///
 /// | Field | Required | Default |
 /// |-------|----------|---------|
+/// | `id` | No | `None` (match by content hash) |
 /// | `content` | Yes | - |
 /// | `namespace` | No | `decisions` |
 /// | `domain` | No | Context-dependent |
@@ -25,6 +27,12 @@
 /// | `created_at` | No | Current time |
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ImportedMemory {
+    /// Original memory identifier (present in `subcog export` output).
+    ///
+    /// Used as the match key when merging into existing memories.
+    #[serde(default)]
+    pub id: Option<String>,
+
     /// The memory content (required).
     pub content: String,
 
@@ -60,6 +68,7 @@ impl ImportedMemory {
     #[must_use]
     pub fn new(content: impl Into<String>) -> Self {
         Self {
+            id: None,
             content: content.into(),
             namespace: None,
             domain: None,
@@ -70,6 +79,13 @@ pub fn new(content: impl Into<String>) -> Self {
         }
     }
 
+    /// Sets the original memory identifier.
+    #[must_use]
+    pub fn with_id(mut self, id: impl Into<String>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
     /// Sets the namespace.
     #[must_use]
     pub fn with_namespace(mut self, namespace: impl Into<String>) -> Self {
@@ -99,6 +115,18 @@ pub fn with_source(mut self, source: impl Into<String>) -> Self {
     }
 }
 
+/// An untyped record read from an import source.
+///
+/// Sources yield records as JSON values so they can be checked against the
+/// memory schema (see [`crate::io::schema`]) before deserialization.
+#[derive(Debug, Clone)]
+pub struct ImportRecord {
+    /// The record as parsed from the source.
+    pub value: serde_json::Value,
+    /// 1-indexed source line the record started on, when known.
+    pub line: Option<usize>,
+}
+
 /// Source of imported memories.
 ///
 /// Implementations read memories from a specific format (JSON, YAML, CSV, etc.)
@@ -132,6 +160,36 @@ pub trait ImportSource {
     /// Returns an error if parsing fails or I/O errors occur.
     fn next(&mut self) -> Result<Option<ImportedMemory>>;
 
+    /// Reads the next record as an untyped value for schema validation.
+    ///
+    /// The default implementation serializes the result of
+    /// [`next`](Self::next), so it always conforms to the schema. Sources that
+    /// parse self-describing formats (JSON, YAML) override this so malformed
+    /// records surface as validation issues instead of parse errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if parsing fails or I/O errors occur.
+    fn next_record(&mut self) -> Result<Option<ImportRecord>> {
+        let Some(memory) = self.next()? else {
+            return Ok(None);
+        };
+        let value = serde_json::to_value(memory).map_err(|e| Error::OperationFailed {
+            operation: "serialize_import_record".to_string(),
+            cause: e.to_string(),
+        })?;
+        Ok(Some(ImportRecord { value, line: None }))
+    }
+
+    /// Returns true if an error from [`next_record`](Self::next_record)
+    /// affects only that record, so the import can skip it and read on.
+    ///
+    /// Sources of independent records (one file per memory) override this;
+    /// streams, whose position is unknown after an error, keep the default.
+    fn recovers_from_errors(&self) -> bool {
+        false
+    }
+
     /// Returns an estimate of the total number of records.
     ///
     /// Used for progress reporting. Returns `None` if unknown.
@@ -152,7 +210,8 @@ pub struct ExportableMemory {
     pub content: String,
     /// Namespace (e.g., "decisions", "learnings").
     pub namespace: String,
-    /// Domain (e.g., "project", "user").
+    /// Domain (e.g., "project", "user"); empty in anonymized exports.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub domain: String,
     /// Project identifier (git remote URL).
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -174,6 +233,29 @@ pub struct ExportableMemory {
     /// Source reference.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<String>,
+    /// Attached files (references only; blobs stay in the data directory).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+}
+
+impl ExportableMemory {
+    /// Strips the metadata that identifies where a memory came from.
+    ///
+    /// Clears `domain` (org/repository), `project_id` (git remote URL),
+    /// `branch`, `file_path`, `source` and `attachments` (file names).
+    /// The ID, content, namespace, status, timestamps and tags are kept.
+    #[must_use]
+    pub fn anonymized(self) -> Self {
+        Self {
+            domain: String::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            source: None,
+            attachments: Vec::new(),
+            ..self
+        }
+    }
 }
 
 impl From<Memory> for ExportableMemory {
@@ -191,6 +273,7 @@ fn from(m: Memory) -> Self {
             updated_at: m.updated_at,
             tags: m.tags,
             source: m.source,
+            attachments: m.attachments,
         }
     }
 }
@@ -210,10 +293,75 @@ fn from(m: &Memory) -> Self {
             updated_at: m.updated_at,
             tags: m.tags.clone(),
             source: m.source.clone(),
+            attachments: m.attachments.clone(),
         }
     }
 }
 
+/// An [`ExportableMemory`] restricted to selected fields.
+///
+/// Serializes as a map containing only the requested fields, in the
+/// requested order. Missing optional values are omitted, matching the
+/// unprojected serialization.
+pub struct ProjectedMemory<'a> {
+    memory: &'a ExportableMemory,
+    fields: &'a [ExportField],
+}
+
+impl<'a> ProjectedMemory<'a> {
+    /// Projects `memory` onto `fields`.
+    #[must_use]
+    pub const fn new(memory: &'a ExportableMemory, fields: &'a [ExportField]) -> Self {
+        Self { memory, fields }
+    }
+}
+
+impl Serialize for ProjectedMemory<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for field in self.fields {
+            serialize_field(&mut map, self.memory, *field)?;
+        }
+        map.end()
+    }
+}
+
+/// Serializes one field of `m`; empty optional fields are omitted.
+fn serialize_field<M: SerializeMap>(
+    map: &mut M,
+    m: &ExportableMemory,
+    field: ExportField,
+) -> std::result::Result<(), M::Error> {
+    let key = field.as_str();
+    match field {
+        ExportField::Id => map.serialize_entry(key, &m.id),
+        ExportField::Content => map.serialize_entry(key, &m.content),
+        ExportField::Namespace => map.serialize_entry(key, &m.namespace),
+        ExportField::Domain => {
+            serialize_present(map, key, Some(m.domain.as_str()).filter(|d| !d.is_empty()))
+        },
+        ExportField::Status => map.serialize_entry(key, &m.status),
+        ExportField::CreatedAt => map.serialize_entry(key, &m.created_at),
+        ExportField::UpdatedAt => map.serialize_entry(key, &m.updated_at),
+        ExportField::Tags => map.serialize_entry(key, &m.tags),
+        ExportField::ProjectId => serialize_present(map, key, m.project_id.as_deref()),
+        ExportField::Branch => serialize_present(map, key, m.branch.as_deref()),
+        ExportField::FilePath => serialize_present(map, key, m.file_path.as_deref()),
+        ExportField::Source => serialize_present(map, key, m.source.as_deref()),
+        ExportField::Attachments if m.attachments.is_empty() => Ok(()),
+        ExportField::Attachments => map.serialize_entry(key, &m.attachments),
+    }
+}
+
+/// Serializes an optional entry only when it has a value.
+fn serialize_present<M: SerializeMap>(
+    map: &mut M,
+    key: &str,
+    value: Option<&str>,
+) -> std::result::Result<(), M::Error> {
+    value.map_or(Ok(()), |value| map.serialize_entry(key, value))
+}
+
 /// Sink for exported memories.
 ///
 /// Implementations write memories to a specific format (JSON, YAML, CSV, etc.).
@@ -288,6 +436,8 @@ pub enum ExportField {
     Tags,
     /// Source reference.
     Source,
+    /// Attachment references.
+    Attachments,
 }
 
 impl ExportField {
@@ -307,6 +457,7 @@ pub const fn all() -> &'static [Self] {
             Self::UpdatedAt,
             Self::Tags,
             Self::Source,
+            Self::Attachments,
         ]
     }
 
@@ -326,6 +477,7 @@ pub const fn as_str(&self) -> &'static str {
             Self::UpdatedAt => "updated_at",
             Self::Tags => "tags",
             Self::Source => "source",
+            Self::Attachments => "attachments",
         }
     }
 
@@ -347,9 +499,69 @@ pub fn parse(s: &str) -> Option<Self> {
             "updated_at" | "updated" => Some(Self::UpdatedAt),
             "tags" => Some(Self::Tags),
             "source" => Some(Self::Source),
+            "attachments" | "attachment" => Some(Self::Attachments),
             _ => None,
         }
     }
+
+    /// Parses a comma-separated field list (e.g. `id,namespace,content`).
+    ///
+    /// Order is preserved and repeated fields are kept once.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if a field name is not recognized or
+    /// the list is empty.
+    pub fn parse_list(spec: &str) -> Result<Vec<Self>> {
+        let mut fields = Vec::new();
+        for name in spec.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+            let field = Self::parse(name).ok_or_else(|| {
+                let valid: Vec<&str> = Self::all().iter().map(Self::as_str).collect();
+                Error::InvalidInput(format!(
+                    "Unknown export field: {name} (expected one of: {})",
+                    valid.join(", ")
+                ))
+            })?;
+            if !fields.contains(&field) {
+                fields.push(field);
+            }
+        }
+
+        if fields.is_empty() {
+            return Err(Error::InvalidInput(
+                "Export field list cannot be empty".to_string(),
+            ));
+        }
+        Ok(fields)
+    }
+
+    /// Returns this field of `memory` as a CSV cell.
+    ///
+    /// Missing optional values are empty, tags are comma-joined and
+    /// attachments are listed as comma-joined `name:hash` pairs.
+    #[must_use]
+    pub fn csv_value(&self, memory: &ExportableMemory) -> String {
+        match self {
+            Self::Id => memory.id.clone(),
+            Self::Content => memory.content.clone(),
+            Self::Namespace => memory.namespace.clone(),
+            Self::Domain => memory.domain.clone(),
+            Self::ProjectId => memory.project_id.clone().unwrap_or_default(),
+            Self::Branch => memory.branch.clone().unwrap_or_default(),
+            Self::FilePath => memory.file_path.clone().unwrap_or_default(),
+            Self::Status => memory.status.clone(),
+            Self::CreatedAt => memory.created_at.to_string(),
+            Self::UpdatedAt => memory.updated_at.to_string(),
+            Self::Tags => memory.tags.join(","),
+            Self::Source => memory.source.clone().unwrap_or_default(),
+            Self::Attachments => memory
+                .attachments
+                .iter()
+                .map(|a| format!("{}:{}", a.name, a.hash))
+                .collect::<Vec<_>>()
+                .join(","),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -384,11 +596,32 @@ fn test_export_field_parsing() {
         assert_eq!(ExportField::parse("unknown"), None);
     }
 
+    #[test]
+    fn test_export_field_parse_list() {
+        let fields = ExportField::parse_list("id, ns,content,id").unwrap();
+        assert_eq!(
+            fields,
+            vec![
+                ExportField::Id,
+                ExportField::Namespace,
+                ExportField::Content
+            ]
+        );
+
+        let err = ExportField::parse_list("id,bogus").unwrap_err();
+        assert!(err.to_string().contains("bogus"));
+        assert!(ExportField::parse_list(" , ").is_err());
+    }
+
     #[test]
     fn test_export_field_all() {
         let all = ExportField::all();
-        assert_eq!(all.len(), 12);
+        assert_eq!(all.len(), 13);
         assert!(all.contains(&ExportField::Id));
         assert!(all.contains(&ExportField::Content));
+        assert!(all.contains(&ExportField::Attachments));
+        // Every field is listed once
+        let unique: std::collections::HashSet<_> = all.iter().collect();
+        assert_eq!(unique.len(), all.len());
     }
 }
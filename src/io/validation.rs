@@ -1,11 +1,17 @@
 //! Import validation and normalization.
 //!
 //! Validates imported memory data and applies defaults before storage.
+//!
+//! Raw records are first checked against the memory JSON Schema (see
+//! [`super::schema`]) so malformed input is reported per field and line
+//! instead of failing deep in storage.
 
-use crate::models::{CaptureRequest, Domain, Namespace};
-use crate::services::deduplication::ContentHasher;
+use crate::models::{CaptureOrigin, CaptureRequest, ContentHasher, Domain, Namespace};
+use serde_json::Value;
+use std::fmt;
 
-use super::traits::ImportedMemory;
+use super::schema::{FieldType, MEMORY_RECORD_FIELDS, json_type_name, memory_field};
+use super::traits::{ImportRecord, ImportedMemory};
 
 /// Severity of a validation issue.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -25,6 +31,10 @@ pub struct ValidationIssue {
     pub message: String,
     /// Severity of the issue.
     pub severity: ValidationSeverity,
+    /// Type the schema expects for the field, for schema violations.
+    pub expected: Option<String>,
+    /// 1-indexed source line of the record, when known.
+    pub line: Option<usize>,
 }
 
 impl ValidationIssue {
@@ -35,6 +45,8 @@ pub fn warning(field: impl Into<String>, message: impl Into<String>) -> Self {
             field: field.into(),
             message: message.into(),
             severity: ValidationSeverity::Warning,
+            expected: None,
+            line: None,
         }
     }
 
@@ -45,8 +57,33 @@ pub fn error(field: impl Into<String>, message: impl Into<String>) -> Self {
             field: field.into(),
             message: message.into(),
             severity: ValidationSeverity::Error,
+            expected: None,
+            line: None,
         }
     }
+
+    /// Records the type the schema expects.
+    #[must_use]
+    pub fn with_expected(mut self, expected: impl Into<String>) -> Self {
+        self.expected = Some(expected.into());
+        self
+    }
+
+    /// Records the source line of the offending record.
+    #[must_use]
+    pub const fn at_line(mut self, line: Option<usize>) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+impl fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {line}: ")?;
+        }
+        write!(f, "{}: {}", self.field, self.message)
+    }
 }
 
 /// Result of validating an imported memory.
@@ -139,6 +176,60 @@ pub fn with_default_domain(mut self, domain: Domain) -> Self {
         self
     }
 
+    /// Checks a raw record against the memory JSON Schema.
+    ///
+    /// Reports every violation found: a non-object record, missing required
+    /// fields, fields of the wrong type, non-string tags, and fields the
+    /// schema does not define. All issues are errors.
+    #[must_use]
+    pub fn validate_record(&self, record: &ImportRecord) -> Vec<ValidationIssue> {
+        let Value::Object(fields) = &record.value else {
+            return vec![
+                ValidationIssue::error(
+                    "record",
+                    format!("expected object, got {}", json_type_name(&record.value)),
+                )
+                .with_expected("object")
+                .at_line(record.line),
+            ];
+        };
+
+        let mut issues: Vec<ValidationIssue> = MEMORY_RECORD_FIELDS
+            .iter()
+            .filter(|field| field.required && !fields.contains_key(field.name))
+            .map(|field| {
+                ValidationIssue::error(field.name, "required field is missing")
+                    .with_expected(field.field_type.expected())
+                    .at_line(record.line)
+            })
+            .collect();
+
+        for (name, value) in fields {
+            issues.extend(field_issues(name, value, record.line));
+        }
+
+        issues
+    }
+
+    /// Validates a raw record against the schema and deserializes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the schema violations if the record does not conform.
+    pub fn parse_record(
+        &self,
+        record: ImportRecord,
+    ) -> std::result::Result<ImportedMemory, Vec<ValidationIssue>> {
+        let issues = self.validate_record(&record);
+        if !issues.is_empty() {
+            return Err(issues);
+        }
+
+        let line = record.line;
+        serde_json::from_value(record.value)
+            .map_err(|e| vec![ValidationIssue::error("record", e.to_string()).at_line(line)])
+    }
+
     /// Validates an imported memory.
     ///
     /// # Returns
@@ -219,6 +310,12 @@ pub fn to_capture_request(&self, imported: ImportedMemory) -> CaptureRequest {
             scope: None,
             #[cfg(feature = "group-scope")]
             group_id: None,
+            sections: Vec::new(),
+            template: None,
+            origin: CaptureOrigin::Import,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
         }
     }
 
@@ -232,7 +329,7 @@ pub fn content_hash_tag(&self, imported: &ImportedMemory) -> String {
 }
 
 /// Parses a domain string into a Domain.
-fn parse_domain(s: &str) -> Domain {
+pub(crate) fn parse_domain(s: &str) -> Domain {
     match s.to_lowercase().as_str() {
         "user" => Domain::for_user(),
         "org" => Domain::for_org(),
@@ -240,6 +337,51 @@ fn parse_domain(s: &str) -> Domain {
     }
 }
 
+/// Checks one record field against its memory schema entry: unknown
+/// fields, values of the wrong type, and non-string tags.
+fn field_issues(name: &str, value: &Value, line: Option<usize>) -> Vec<ValidationIssue> {
+    let Some(field) = memory_field(name) else {
+        return vec![
+            ValidationIssue::error(name, "unknown field (not in the memory schema)").at_line(line),
+        ];
+    };
+
+    if !field.field_type.accepts(value) {
+        return vec![
+            ValidationIssue::error(
+                name,
+                format!(
+                    "expected {}, got {}",
+                    field.field_type.expected(),
+                    json_type_name(value)
+                ),
+            )
+            .with_expected(field.field_type.expected())
+            .at_line(line),
+        ];
+    }
+
+    let Value::Array(items) = value else {
+        return Vec::new();
+    };
+    if field.field_type != FieldType::StringArray {
+        return Vec::new();
+    }
+    items
+        .iter()
+        .enumerate()
+        .filter(|(_, item)| !item.is_string())
+        .map(|(index, item)| {
+            ValidationIssue::error(
+                format!("{name}[{index}]"),
+                format!("expected string, got {}", json_type_name(item)),
+            )
+            .with_expected("string")
+            .at_line(line)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -296,6 +438,97 @@ fn test_validate_unknown_namespace() {
         );
     }
 
+    fn record(value: Value) -> ImportRecord {
+        ImportRecord {
+            value,
+            line: Some(7),
+        }
+    }
+
+    #[test]
+    fn test_validate_record_accepts_good_record() {
+        let validator = ImportValidator::new();
+        let good = record(serde_json::json!({
+            "id": "abc123",
+            "content": "Use PostgreSQL",
+            "namespace": "decisions",
+            "domain": null,
+            "tags": ["db"],
+            "created_at": 1_700_000_000,
+            "status": "active"
+        }));
+
+        assert!(validator.validate_record(&good).is_empty());
+        let imported = validator.parse_record(good).unwrap();
+        assert_eq!(imported.content, "Use PostgreSQL");
+        assert_eq!(imported.created_at, Some(1_700_000_000));
+    }
+
+    #[test]
+    fn test_validate_record_wrong_type() {
+        let validator = ImportValidator::new();
+        let issues = validator.validate_record(&record(serde_json::json!({
+            "content": "Valid",
+            "tags": "rust",
+            "created_at": -5
+        })));
+
+        assert_eq!(issues.len(), 2);
+        let tags = issues.iter().find(|i| i.field == "tags").unwrap();
+        assert_eq!(tags.expected.as_deref(), Some("array of strings"));
+        assert_eq!(tags.message, "expected array of strings, got string");
+        assert_eq!(tags.line, Some(7));
+        assert_eq!(
+            tags.to_string(),
+            "line 7: tags: expected array of strings, got string"
+        );
+        assert!(issues.iter().any(|i| i.field == "created_at"));
+    }
+
+    #[test]
+    fn test_validate_record_non_string_tag() {
+        let validator = ImportValidator::new();
+        let issues = validator.validate_record(&record(serde_json::json!({
+            "content": "Valid",
+            "tags": ["ok", 3]
+        })));
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "tags[1]");
+        assert_eq!(issues[0].expected.as_deref(), Some("string"));
+    }
+
+    #[test]
+    fn test_validate_record_extra_field() {
+        let validator = ImportValidator::new();
+        let issues = validator.validate_record(&record(serde_json::json!({
+            "content": "Valid",
+            "namepsace": "decisions"
+        })));
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "namepsace");
+        assert_eq!(issues[0].severity, ValidationSeverity::Error);
+        assert!(issues[0].message.contains("unknown field"));
+    }
+
+    #[test]
+    fn test_validate_record_missing_required() {
+        let validator = ImportValidator::new();
+        let issues = validator.validate_record(&record(serde_json::json!({
+            "namespace": "decisions"
+        })));
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].field, "content");
+        assert_eq!(issues[0].expected.as_deref(), Some("string"));
+        assert!(
+            validator
+                .parse_record(record(serde_json::json!([])))
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_to_capture_request() {
         let validator = ImportValidator::new().with_default_namespace(Namespace::Learnings);
@@ -51,6 +51,7 @@
 pub mod llm;
 pub mod mcp;
 pub mod models;
+pub mod notifications;
 pub mod observability;
 pub mod rendering;
 pub mod security;
@@ -63,8 +64,8 @@
 pub use embedding::Embedder;
 pub use llm::LlmProvider;
 pub use models::{
-    CaptureRequest, CaptureResult, DetailLevel, Domain, Memory, MemoryId, MemoryStatus, Namespace,
-    SearchFilter, SearchMode, SearchResult,
+    CaptureOrigin, CaptureRequest, CaptureResult, DetailLevel, Domain, Memory, MemoryId,
+    MemoryStatus, Namespace, SearchFilter, SearchMode, SearchResult,
 };
 pub use services::{
     CaptureService, ConsolidationService, ContextBuilderService, RecallService, SyncService,
@@ -85,6 +86,7 @@
 /// | `NotImplemented` | Calling unfinished features (e.g., PostgreSQL consolidation) |
 /// | `FeatureNotEnabled` | Using features requiring compile-time flags |
 /// | `Unauthorized` | Invalid/missing JWT token in MCP HTTP transport |
+/// | `Cancelled` | Long-running operation cancelled by the client or a timeout |
 #[derive(Debug, ThisError)]
 pub enum Error {
     /// Invalid input was provided.
@@ -153,6 +155,19 @@ pub enum Error {
     /// - Insufficient entropy in JWT secret
     #[error("unauthorized: {0}")]
     Unauthorized(String),
+
+    /// An operation was cancelled before it completed.
+    ///
+    /// Raised when:
+    /// - An MCP client cancels a tool call or disconnects mid-call
+    /// - An MCP tool call exceeds its timeout
+    ///
+    /// See `services::Cancellation`.
+    #[error("operation '{operation}' cancelled")]
+    Cancelled {
+        /// The operation that was cancelled.
+        operation: String,
+    },
 }
 
 /// Result type alias for subcog operations.
@@ -203,5 +218,10 @@ fn test_error_display() {
             reason: "secrets detected".to_string(),
         };
         assert_eq!(err.to_string(), "content blocked: secrets detected");
+
+        let err = Error::Cancelled {
+            operation: "enrich".to_string(),
+        };
+        assert_eq!(err.to_string(), "operation 'enrich' cancelled");
     }
 }
@@ -1,6 +1,9 @@
 //! Anthropic Claude client.
 
-use super::{CaptureAnalysis, LlmHttpConfig, LlmProvider, build_http_client};
+use super::{
+    CaptureAnalysis, LlmHttpConfig, LlmProvider, TokenUsage, build_http_client,
+    combine_system_prompt,
+};
 use crate::{Error, Result};
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
@@ -136,7 +139,12 @@ fn is_valid_api_key_format(key: &str) -> bool {
     }
 
     /// Makes a request to the Anthropic API.
-    fn request(&self, messages: Vec<Message>) -> Result<String> {
+    ///
+    /// Returns the completion with the usage reported by the API, or an
+    /// estimate if the response has none.
+    fn request(&self, messages: Vec<Message>) -> Result<(String, TokenUsage)> {
+        crate::config::ensure_online("anthropic_request")?;
+
         self.validate()?;
 
         tracing::info!(provider = "anthropic", model = %self.model, "Making LLM request");
@@ -218,7 +226,7 @@ fn request(&self, messages: Vec<Message>) -> Result<String> {
         })?;
 
         // Extract text from first content block
-        response
+        let content = response
             .content
             .first()
             .and_then(|block| {
@@ -231,7 +239,10 @@ fn request(&self, messages: Vec<Message>) -> Result<String> {
             .ok_or_else(|| Error::OperationFailed {
                 operation: "anthropic_response".to_string(),
                 cause: "No text content in response".to_string(),
-            })
+            })?;
+
+        let usage = token_usage(response.usage, &request.messages, &content);
+        Ok((content, usage))
     }
 }
 
@@ -247,6 +258,11 @@ fn name(&self) -> &'static str {
     }
 
     fn complete(&self, prompt: &str) -> Result<String> {
+        self.complete_with_usage(prompt)
+            .map(|(response, _)| response)
+    }
+
+    fn complete_with_usage(&self, prompt: &str) -> Result<(String, TokenUsage)> {
         let messages = vec![Message {
             role: "user".to_string(),
             content: prompt.to_string(),
@@ -255,6 +271,10 @@ fn complete(&self, prompt: &str) -> Result<String> {
         self.request(messages)
     }
 
+    fn complete_with_system_usage(&self, system: &str, user: &str) -> Result<(String, TokenUsage)> {
+        self.complete_with_usage(&combine_system_prompt(system, user))
+    }
+
     fn analyze_for_capture(&self, content: &str) -> Result<CaptureAnalysis> {
         // Use XML tags to isolate user content and mitigate prompt injection (SEC-M3).
         // The content is wrapped in <user_content> tags to clearly delimit it from
@@ -299,6 +319,18 @@ fn analyze_for_capture(&self, content: &str) -> Result<CaptureAnalysis> {
     }
 }
 
+/// Converts the usage reported in a response, estimating it from the text if
+/// the response has none.
+fn token_usage(reported: Option<MessagesUsage>, messages: &[Message], content: &str) -> TokenUsage {
+    reported.map_or_else(
+        || {
+            let prompt: Vec<&str> = messages.iter().map(|m| m.content.as_str()).collect();
+            TokenUsage::estimate(&prompt.join("\n"), content)
+        },
+        |usage| TokenUsage::new(usage.input_tokens, usage.output_tokens),
+    )
+}
+
 /// Request to the Messages API.
 #[derive(Debug, Serialize)]
 struct MessagesRequest {
@@ -318,6 +350,15 @@ struct Message {
 #[derive(Debug, Deserialize)]
 struct MessagesResponse {
     content: Vec<ContentBlock>,
+    #[serde(default)]
+    usage: Option<MessagesUsage>,
+}
+
+/// Token usage reported in a response.
+#[derive(Debug, Deserialize)]
+struct MessagesUsage {
+    input_tokens: u64,
+    output_tokens: u64,
 }
 
 /// A content block in the response.
@@ -488,4 +529,20 @@ fn test_escape_xml_prompt_injection_attempt() {
         assert!(escaped.contains("&lt;/user_content&gt;"));
         assert!(!escaped.contains("</user_content>"));
     }
+
+    #[test]
+    fn test_response_usage_parsing() {
+        let response: MessagesResponse = serde_json::from_str(
+            r#"{"content": [{"type": "text", "text": "ok"}],
+            "usage": {"input_tokens": 42, "output_tokens": 7}}"#,
+        )
+        .unwrap();
+        let usage = response.usage.unwrap();
+        assert_eq!(usage.input_tokens, 42);
+        assert_eq!(usage.output_tokens, 7);
+
+        let response: MessagesResponse =
+            serde_json::from_str(r#"{"content": [{"type": "text", "text": "ok"}]}"#).unwrap();
+        assert!(response.usage.is_none());
+    }
 }
@@ -57,9 +57,12 @@ pub fn with_http_config(mut self, config: LlmHttpConfig) -> Self {
         self
     }
 
-    /// Checks if LM Studio is available.
+    /// Checks if LM Studio is available (always `false` in offline mode).
     #[must_use]
     pub fn is_available(&self) -> bool {
+        if crate::config::is_offline() {
+            return false;
+        }
         self.client
             .get(format!("{}/models", self.endpoint))
             .send()
@@ -69,6 +72,8 @@ pub fn is_available(&self) -> bool {
 
     /// Makes a request to the LM Studio API.
     fn request(&self, messages: Vec<ChatMessage>) -> Result<String> {
+        crate::config::ensure_online("lmstudio_request")?;
+
         let model = self
             .model
             .clone()
@@ -1,7 +1,7 @@
 //! LLM client abstraction (DOC-H3).
 //!
 //! Provides a unified interface for different LLM providers including
-//! Anthropic Claude, `OpenAI` GPT, Ollama (local), and LM Studio (local).
+//! Anthropic Claude, `OpenAI` GPT, Google Gemini, Ollama (local), and LM Studio (local).
 //!
 //! # Supported Providers
 //!
@@ -9,6 +9,7 @@
 //! |----------|--------|----------------------|
 //! | Anthropic | [`AnthropicClient`] | `ANTHROPIC_API_KEY` |
 //! | `OpenAI` | [`OpenAiClient`] | `OPENAI_API_KEY` |
+//! | Gemini | [`GeminiClient`] | `GEMINI_API_KEY` |
 //! | Ollama | [`OllamaClient`] | `OLLAMA_HOST`, `OLLAMA_MODEL` |
 //! | LM Studio | [`LmStudioClient`] | `LMSTUDIO_ENDPOINT`, `LMSTUDIO_MODEL` |
 //!
@@ -36,6 +37,22 @@
 //! )?;
 //! ```
 //!
+//! ## Streaming
+//!
+//! `OpenAI` and Ollama stream completions; other providers deliver the whole
+//! response as a single chunk.
+//!
+//! ```rust,ignore
+//! use std::io::Write;
+//! use subcog::llm::{LlmProvider, OllamaClient};
+//!
+//! let client = OllamaClient::new();
+//! let response = client.complete_stream("Explain lifetimes", &mut |chunk| {
+//!     print!("{chunk}");
+//!     let _ = std::io::stdout().flush();
+//! })?;
+//! ```
+//!
 //! ## Capture Analysis
 //!
 //! ```rust,ignore
@@ -73,7 +90,7 @@
 //!
 //! ```toml
 //! [llm]
-//! provider = "anthropic"  # or "openai", "ollama", "lmstudio"
+//! provider = "anthropic"  # or "openai", "gemini", "ollama", "lmstudio"
 //! model = "claude-sonnet-4-20250514"
 //! timeout_ms = 30000
 //! max_retries = 3
@@ -150,13 +167,17 @@
 //! ```
 
 mod anthropic;
+mod gemini;
 mod lmstudio;
 mod ollama;
 mod openai;
 mod resilience;
+mod stream;
 pub mod system_prompt;
+mod usage;
 
 pub use anthropic::AnthropicClient;
+pub use gemini::GeminiClient;
 pub use lmstudio::LmStudioClient;
 pub use ollama::OllamaClient;
 pub use openai::OpenAiClient;
@@ -168,6 +189,7 @@
     OperationMode, SEARCH_INTENT_PROMPT, SecurityAssessment, build_system_prompt,
     build_system_prompt_with_config,
 };
+pub use usage::{TokenPricing, TokenUsage};
 
 use crate::Result;
 use crate::security::{ContentRedactor, RedactionConfig};
@@ -195,8 +217,56 @@ pub trait LlmProvider: Send + Sync {
     /// Default implementation concatenates system and user prompts.
     /// Providers should override this to use native system prompt support.
     fn complete_with_system(&self, system: &str, user: &str) -> Result<String> {
-        let combined = format!("{system}\n\n---\n\nUser message:\n{user}");
-        self.complete(&combined)
+        self.complete(&combine_system_prompt(system, user))
+    }
+
+    /// Generates a completion and reports the tokens it consumed.
+    ///
+    /// The default implementation estimates usage from the text (see
+    /// [`TokenUsage::estimate`]). Providers whose API reports usage should
+    /// override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the completion fails.
+    fn complete_with_usage(&self, prompt: &str) -> Result<(String, TokenUsage)> {
+        let response = self.complete(prompt)?;
+        let usage = TokenUsage::estimate(prompt, &response);
+        Ok((response, usage))
+    }
+
+    /// Generates a completion with a system prompt and reports the tokens it
+    /// consumed.
+    ///
+    /// The default implementation estimates usage from the text, like
+    /// [`complete_with_usage`](Self::complete_with_usage).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the completion fails.
+    fn complete_with_system_usage(&self, system: &str, user: &str) -> Result<(String, TokenUsage)> {
+        let response = self.complete_with_system(system, user)?;
+        let usage = TokenUsage::estimate(&combine_system_prompt(system, user), &response);
+        Ok((response, usage))
+    }
+
+    /// Generates a completion, passing each chunk of text to `on_chunk` as it
+    /// arrives, and returns the full completion.
+    ///
+    /// The callback is a trait object so the trait stays usable as
+    /// `dyn LlmProvider`. The default implementation does not stream: it
+    /// calls `on_chunk` once with the buffered [`complete`](Self::complete)
+    /// response. Providers with a streaming API should override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the completion fails. A stream cut off part-way
+    /// fails with a retryable connection error; chunks already passed to
+    /// `on_chunk` are not withdrawn.
+    fn complete_stream(&self, prompt: &str, on_chunk: &mut dyn FnMut(&str)) -> Result<String> {
+        let response = self.complete(prompt)?;
+        on_chunk(&response);
+        Ok(response)
     }
 
     /// Analyzes content for memory capture.
@@ -265,6 +335,60 @@ fn analyze_for_consolidation(&self, memories: &str) -> Result<ConsolidationAnaly
     }
 }
 
+/// Folds a system prompt into the user prompt, for providers without
+/// native system prompt support.
+fn combine_system_prompt(system: &str, user: &str) -> String {
+    format!("{system}\n\n---\n\nUser message:\n{user}")
+}
+
+/// Shared providers (e.g. `Arc<dyn LlmProvider + Send + Sync>`) can be used
+/// wherever a concrete provider is expected.
+impl<P: LlmProvider + ?Sized> LlmProvider for std::sync::Arc<P> {
+    fn name(&self) -> &'static str {
+        (**self).name()
+    }
+
+    fn complete(&self, prompt: &str) -> Result<String> {
+        (**self).complete(prompt)
+    }
+
+    fn complete_with_system(&self, system: &str, user: &str) -> Result<String> {
+        (**self).complete_with_system(system, user)
+    }
+
+    fn complete_with_usage(&self, prompt: &str) -> Result<(String, TokenUsage)> {
+        (**self).complete_with_usage(prompt)
+    }
+
+    fn complete_with_system_usage(&self, system: &str, user: &str) -> Result<(String, TokenUsage)> {
+        (**self).complete_with_system_usage(system, user)
+    }
+
+    fn complete_stream(&self, prompt: &str, on_chunk: &mut dyn FnMut(&str)) -> Result<String> {
+        (**self).complete_stream(prompt, on_chunk)
+    }
+
+    fn analyze_for_capture(&self, content: &str) -> Result<CaptureAnalysis> {
+        (**self).analyze_for_capture(content)
+    }
+
+    fn analyze_for_capture_extended(
+        &self,
+        content: &str,
+        existing_memories: Option<&str>,
+    ) -> Result<ExtendedCaptureAnalysis> {
+        (**self).analyze_for_capture_extended(content, existing_memories)
+    }
+
+    fn classify_search_intent(&self, prompt: &str) -> Result<ExtendedSearchIntent> {
+        (**self).classify_search_intent(prompt)
+    }
+
+    fn analyze_for_consolidation(&self, memories: &str) -> Result<ConsolidationAnalysis> {
+        (**self).analyze_for_consolidation(memories)
+    }
+}
+
 /// Analysis result for content capture.
 #[derive(Debug, Clone)]
 pub struct CaptureAnalysis {
@@ -368,6 +492,30 @@ pub fn build_http_client(config: LlmHttpConfig) -> reqwest::blocking::Client {
     })
 }
 
+/// Builds a blocking HTTP client for streamed LLM responses.
+///
+/// A stream may run longer than any whole-request timeout, so this client has
+/// none: `timeout_ms` bounds each read instead, which catches a stalled
+/// stream, alongside the connect timeout.
+#[must_use]
+pub fn build_stream_http_client(config: LlmHttpConfig) -> reqwest::blocking::Client {
+    let mut builder = reqwest::Client::builder();
+    if config.timeout_ms > 0 {
+        builder = builder.read_timeout(Duration::from_millis(config.timeout_ms));
+    }
+    if config.connect_timeout_ms > 0 {
+        builder = builder.connect_timeout(Duration::from_millis(config.connect_timeout_ms));
+    }
+
+    reqwest::blocking::ClientBuilder::from(builder)
+        .timeout(None)
+        .build()
+        .unwrap_or_else(|err| {
+            tracing::warn!("Failed to build LLM streaming HTTP client: {err}");
+            reqwest::blocking::Client::new()
+        })
+}
+
 pub(crate) fn sanitize_llm_response_for_error(response: &str) -> String {
     let redacted = LLM_ERROR_REDACTOR.redact(response);
     if redacted.chars().count() > MAX_LLM_ERROR_RESPONSE_CHARS {
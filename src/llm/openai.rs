@@ -1,9 +1,14 @@
 //! `OpenAI` client.
 
-use super::{CaptureAnalysis, LlmHttpConfig, LlmProvider, build_http_client};
+use super::stream::{StreamLine, read_stream_lines};
+use super::{
+    CaptureAnalysis, LlmHttpConfig, LlmProvider, TokenUsage, build_http_client,
+    build_stream_http_client,
+};
 use crate::{Error, Result};
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
+use std::io::BufReader;
 
 /// Escapes XML special characters to prevent prompt injection (SEC-M3).
 ///
@@ -39,6 +44,8 @@ pub struct OpenAiClient {
     max_tokens: Option<u32>,
     /// HTTP client.
     client: reqwest::blocking::Client,
+    /// HTTP client for streamed responses (per-read timeout only).
+    stream_client: reqwest::blocking::Client,
 }
 
 impl OpenAiClient {
@@ -55,12 +62,14 @@ impl OpenAiClient {
     #[must_use]
     pub fn new() -> Self {
         let api_key = std::env::var("OPENAI_API_KEY").ok().map(SecretString::from);
+        let http_config = LlmHttpConfig::from_env();
         Self {
             api_key,
             endpoint: Self::DEFAULT_ENDPOINT.to_string(),
             model: Self::DEFAULT_MODEL.to_string(),
             max_tokens: None,
-            client: build_http_client(LlmHttpConfig::from_env()),
+            client: build_http_client(http_config),
+            stream_client: build_stream_http_client(http_config),
         }
     }
 
@@ -96,6 +105,7 @@ pub fn without_api_key(mut self) -> Self {
     #[must_use]
     pub fn with_http_config(mut self, config: LlmHttpConfig) -> Self {
         self.client = build_http_client(config);
+        self.stream_client = build_stream_http_client(config);
         self
     }
 
@@ -159,8 +169,110 @@ fn is_valid_api_key_format(key: &str) -> bool {
     }
 
     /// Makes a request to the `OpenAI` API.
-    #[allow(clippy::too_many_lines)]
     fn request(&self, messages: Vec<ChatMessage>) -> Result<String> {
+        self.request_with_usage(messages)
+            .map(|(content, _)| content)
+    }
+
+    /// Makes a request to the `OpenAI` API, returning the completion with the
+    /// usage reported by the API, or an estimate if the response has none.
+    fn request_with_usage(&self, messages: Vec<ChatMessage>) -> Result<(String, TokenUsage)> {
+        let prompt: Vec<&str> = messages.iter().map(|m| m.content.as_str()).collect();
+        let prompt = prompt.join("\n");
+        let response = self.send(messages, false)?;
+
+        // Get raw response text for debugging
+        let response_text = response.text().map_err(|e| {
+            tracing::error!(
+                provider = "openai",
+                model = %self.model,
+                error = %e,
+                "Failed to read LLM response body"
+            );
+            Error::OperationFailed {
+                operation: "openai_response".to_string(),
+                cause: format!("Failed to read response: {e}"),
+            }
+        })?;
+
+        tracing::debug!(
+            provider = "openai",
+            response_len = response_text.len(),
+            response_preview = %response_text.chars().take(500).collect::<String>(),
+            "Raw API response"
+        );
+
+        let response: ChatCompletionResponse =
+            serde_json::from_str(&response_text).map_err(|e| {
+                tracing::error!(
+                    provider = "openai",
+                    model = %self.model,
+                    error = %e,
+                    response_text = %response_text,
+                    "Failed to parse LLM response"
+                );
+                Error::OperationFailed {
+                    operation: "openai_response".to_string(),
+                    cause: e.to_string(),
+                }
+            })?;
+
+        // Extract content from first choice
+        let content = response
+            .choices
+            .first()
+            .map(|choice| choice.message.content.clone())
+            .ok_or_else(|| Error::OperationFailed {
+                operation: "openai_response".to_string(),
+                cause: "No choices in response".to_string(),
+            })?;
+
+        tracing::debug!(
+            provider = "openai",
+            content_len = content.len(),
+            content_preview = %content.chars().take(200).collect::<String>(),
+            "LLM response received"
+        );
+
+        let usage = response.usage.map_or_else(
+            || TokenUsage::estimate(&prompt, &content),
+            |usage| TokenUsage::new(usage.prompt_tokens, usage.completion_tokens),
+        );
+
+        Ok((content, usage))
+    }
+
+    /// Makes a streaming request, passing each content delta to `on_chunk`.
+    fn request_stream(
+        &self,
+        messages: Vec<ChatMessage>,
+        on_chunk: &mut dyn FnMut(&str),
+    ) -> Result<String> {
+        let response = self.send(messages, true)?;
+
+        let mut content = String::new();
+        read_stream_lines(BufReader::new(response), "openai_stream", |line| {
+            parse_stream_line(line, &mut content, on_chunk)
+        })?;
+
+        tracing::debug!(
+            provider = "openai",
+            content_len = content.len(),
+            "LLM stream completed"
+        );
+
+        Ok(content)
+    }
+
+    /// Sends a chat completion request, returning the successful response.
+    #[allow(clippy::too_many_lines)]
+    fn send(
+        &self,
+        messages: Vec<ChatMessage>,
+        stream: bool,
+    ) -> Result<reqwest::blocking::Response> {
+        crate::config::ensure_online("openai_request")?;
+
         self.validate()?;
 
         tracing::info!(provider = "openai", model = %self.model, "Making LLM request");
@@ -183,6 +295,7 @@ fn request(&self, messages: Vec<ChatMessage>) -> Result<String> {
                 max_tokens: None,
                 max_completion_tokens: Some(max_tokens),
                 temperature: None, // GPT-5 only supports default (1)
+                stream,
             }
         } else {
             ChatCompletionRequest {
@@ -191,11 +304,16 @@ fn request(&self, messages: Vec<ChatMessage>) -> Result<String> {
                 max_tokens: Some(max_tokens),
                 max_completion_tokens: None,
                 temperature: Some(0.7),
+                stream,
             }
         };
 
-        let response = self
-            .client
+        let client = if stream {
+            &self.stream_client
+        } else {
+            &self.client
+        };
+        let response = client
             .post(format!("{}/chat/completions", self.endpoint))
             .header(
                 "Authorization",
@@ -245,60 +363,7 @@ fn request(&self, messages: Vec<ChatMessage>) -> Result<String> {
             });
         }
 
-        // Get raw response text for debugging
-        let response_text = response.text().map_err(|e| {
-            tracing::error!(
-                provider = "openai",
-                model = %self.model,
-                error = %e,
-                "Failed to read LLM response body"
-            );
-            Error::OperationFailed {
-                operation: "openai_response".to_string(),
-                cause: format!("Failed to read response: {e}"),
-            }
-        })?;
-
-        tracing::debug!(
-            provider = "openai",
-            response_len = response_text.len(),
-            response_preview = %response_text.chars().take(500).collect::<String>(),
-            "Raw API response"
-        );
-
-        let response: ChatCompletionResponse =
-            serde_json::from_str(&response_text).map_err(|e| {
-                tracing::error!(
-                    provider = "openai",
-                    model = %self.model,
-                    error = %e,
-                    response_text = %response_text,
-                    "Failed to parse LLM response"
-                );
-                Error::OperationFailed {
-                    operation: "openai_response".to_string(),
-                    cause: e.to_string(),
-                }
-            })?;
-
-        // Extract content from first choice
-        let content = response
-            .choices
-            .first()
-            .map(|choice| choice.message.content.clone())
-            .ok_or_else(|| Error::OperationFailed {
-                operation: "openai_response".to_string(),
-                cause: "No choices in response".to_string(),
-            })?;
-
-        tracing::debug!(
-            provider = "openai",
-            content_len = content.len(),
-            content_preview = %content.chars().take(200).collect::<String>(),
-            "LLM response received"
-        );
-
-        Ok(content)
+        Ok(response)
     }
 }
 
@@ -314,15 +379,25 @@ fn name(&self) -> &'static str {
     }
 
     fn complete(&self, prompt: &str) -> Result<String> {
+        self.complete_with_usage(prompt)
+            .map(|(response, _)| response)
+    }
+
+    fn complete_with_system(&self, system: &str, user: &str) -> Result<String> {
+        self.complete_with_system_usage(system, user)
+            .map(|(response, _)| response)
+    }
+
+    fn complete_with_usage(&self, prompt: &str) -> Result<(String, TokenUsage)> {
         let messages = vec![ChatMessage {
             role: "user".to_string(),
             content: prompt.to_string(),
         }];
 
-        self.request(messages)
+        self.request_with_usage(messages)
     }
 
-    fn complete_with_system(&self, system: &str, user: &str) -> Result<String> {
+    fn complete_with_system_usage(&self, system: &str, user: &str) -> Result<(String, TokenUsage)> {
         let messages = vec![
             ChatMessage {
                 role: "system".to_string(),
@@ -334,7 +409,16 @@ fn complete_with_system(&self, system: &str, user: &str) -> Result<String> {
             },
         ];
 
-        self.request(messages)
+        self.request_with_usage(messages)
+    }
+
+    fn complete_stream(&self, prompt: &str, on_chunk: &mut dyn FnMut(&str)) -> Result<String> {
+        let messages = vec![ChatMessage {
+            role: "user".to_string(),
+            content: prompt.to_string(),
+        }];
+
+        self.request_stream(messages, on_chunk)
     }
 
     fn analyze_for_capture(&self, content: &str) -> Result<CaptureAnalysis> {
@@ -403,6 +487,9 @@ struct ChatCompletionRequest {
     max_completion_tokens: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     temperature: Option<f32>,
+    /// Whether to stream the response as server-sent events.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
 }
 
 /// A message in the chat.
@@ -416,6 +503,15 @@ struct ChatMessage {
 #[derive(Debug, Deserialize)]
 struct ChatCompletionResponse {
     choices: Vec<ChatChoice>,
+    #[serde(default)]
+    usage: Option<ChatUsage>,
+}
+
+/// Token usage reported in a response.
+#[derive(Debug, Deserialize)]
+struct ChatUsage {
+    prompt_tokens: u64,
+    completion_tokens: u64,
 }
 
 /// A choice in the response.
@@ -424,6 +520,75 @@ struct ChatChoice {
     message: ChatMessage,
 }
 
+/// A server-sent event of a streamed response.
+#[derive(Debug, Deserialize)]
+struct ChatCompletionChunk {
+    #[serde(default)]
+    choices: Vec<ChunkChoice>,
+    /// Error reported in place of a chunk.
+    #[serde(default)]
+    error: Option<StreamError>,
+}
+
+/// A choice in a streamed chunk.
+#[derive(Debug, Deserialize)]
+struct ChunkChoice {
+    delta: ChunkDelta,
+}
+
+/// Content added by a streamed chunk.
+#[derive(Debug, Deserialize)]
+struct ChunkDelta {
+    #[serde(default)]
+    content: Option<String>,
+}
+
+/// An error event in a streamed response.
+#[derive(Debug, Deserialize)]
+struct StreamError {
+    message: String,
+}
+
+/// Parses one line of a server-sent event stream.
+///
+/// Content deltas are appended to `content` and passed to `on_chunk`; lines
+/// other than `data:` (comments, event names) are skipped.
+fn parse_stream_line(
+    line: &str,
+    content: &mut String,
+    on_chunk: &mut dyn FnMut(&str),
+) -> Result<StreamLine> {
+    let Some(data) = line.strip_prefix("data:").map(str::trim) else {
+        return Ok(StreamLine::Continue);
+    };
+    if data == "[DONE]" {
+        return Ok(StreamLine::Done);
+    }
+
+    let chunk: ChatCompletionChunk =
+        serde_json::from_str(data).map_err(|e| Error::OperationFailed {
+            operation: "openai_stream".to_string(),
+            cause: format!("Failed to parse stream event: {e}"),
+        })?;
+    if let Some(error) = chunk.error {
+        return Err(Error::OperationFailed {
+            operation: "openai_stream".to_string(),
+            cause: error.message,
+        });
+    }
+    for text in chunk
+        .choices
+        .into_iter()
+        .filter_map(|choice| choice.delta.content)
+    {
+        if !text.is_empty() {
+            on_chunk(&text);
+            content.push_str(&text);
+        }
+    }
+    Ok(StreamLine::Continue)
+}
+
 /// Parsed analysis response.
 #[derive(Debug, Deserialize)]
 struct AnalysisResponse {
@@ -470,6 +635,7 @@ fn test_validate_no_key() {
             model: OpenAiClient::DEFAULT_MODEL.to_string(),
             max_tokens: None,
             client: reqwest::blocking::Client::new(),
+            stream_client: reqwest::blocking::Client::new(),
         };
 
         let result = client.validate();
@@ -649,6 +815,7 @@ fn test_request_without_api_key_fails() {
             model: OpenAiClient::DEFAULT_MODEL.to_string(),
             max_tokens: None,
             client: reqwest::blocking::Client::new(),
+            stream_client: reqwest::blocking::Client::new(),
         };
 
         let result = client.complete("test prompt");
@@ -681,4 +848,80 @@ fn test_default_http_config() {
         assert!(config.timeout_ms > 0);
         assert!(config.connect_timeout_ms > 0);
     }
+
+    #[test]
+    fn test_stream_request_serialization() {
+        let request = ChatCompletionRequest {
+            model: "gpt-4".to_string(),
+            messages: Vec::new(),
+            max_tokens: Some(16),
+            max_completion_tokens: None,
+            temperature: None,
+            stream: true,
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        assert_eq!(json["stream"], true);
+
+        let buffered = ChatCompletionRequest {
+            stream: false,
+            ..request
+        };
+        let json = serde_json::to_value(&buffered).unwrap();
+        assert!(json.get("stream").is_none());
+    }
+
+    #[test]
+    fn test_response_usage_parsing() {
+        let response: ChatCompletionResponse = serde_json::from_str(
+            r#"{"choices": [{"message": {"role": "assistant", "content": "ok"}}],
+            "usage": {"prompt_tokens": 42, "completion_tokens": 7, "total_tokens": 49}}"#,
+        )
+        .unwrap();
+        let usage = response.usage.unwrap();
+        assert_eq!(usage.prompt_tokens, 42);
+        assert_eq!(usage.completion_tokens, 7);
+
+        let response: ChatCompletionResponse = serde_json::from_str(
+            r#"{"choices": [{"message": {"role": "assistant", "content": "ok"}}]}"#,
+        )
+        .unwrap();
+        assert!(response.usage.is_none());
+    }
+
+    #[test]
+    fn test_parse_stream_lines() {
+        let events = [
+            ": keep-alive",
+            r#"data: {"choices":[{"delta":{"role":"assistant"}}]}"#,
+            r#"data: {"choices":[{"delta":{"content":"Hello"}}]}"#,
+            r#"data: {"choices":[{"delta":{"content":", world"}}]}"#,
+            "data: [DONE]",
+        ];
+        let mut content = String::new();
+        let mut chunks = Vec::new();
+        let mut results = Vec::new();
+        for event in events {
+            let result = parse_stream_line(event, &mut content, &mut |chunk| {
+                chunks.push(chunk.to_string());
+            });
+            results.push(result.unwrap());
+        }
+
+        assert_eq!(chunks, vec!["Hello", ", world"]);
+        assert_eq!(content, "Hello, world");
+        assert_eq!(results.last(), Some(&StreamLine::Done));
+        assert!(results[..4].iter().all(|r| *r == StreamLine::Continue));
+    }
+
+    #[test]
+    fn test_parse_stream_line_error_event() {
+        let mut content = String::new();
+        let result = parse_stream_line(
+            r#"data: {"error":{"message":"The server is overloaded"}}"#,
+            &mut content,
+            &mut |_| {},
+        );
+        assert!(result.unwrap_err().to_string().contains("overloaded"));
+        assert!(parse_stream_line("data: {not json", &mut content, &mut |_| {}).is_err());
+    }
 }
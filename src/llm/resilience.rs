@@ -1,7 +1,8 @@
 //! LLM resilience wrapper with circuit breaking and budget instrumentation.
 
-use super::{CaptureAnalysis, LlmProvider};
+use super::{CaptureAnalysis, LlmProvider, TokenUsage};
 use crate::{Error, Result};
+use std::cell::Cell;
 use std::collections::VecDeque;
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
@@ -296,9 +297,24 @@ pub fn new(inner: P, config: LlmResilienceConfig) -> Self {
         }
     }
 
-    fn execute<T, F>(&self, operation: &'static str, mut call: F) -> Result<T>
+    fn execute<T, F>(&self, operation: &'static str, call: F) -> Result<T>
     where
         F: FnMut() -> Result<T>,
+    {
+        self.execute_guarded(operation, || true, call)
+    }
+
+    /// Like [`execute`](Self::execute), but retries only while `may_retry`
+    /// returns `true`; failures are still recorded against the breaker.
+    fn execute_guarded<T, F, G>(
+        &self,
+        operation: &'static str,
+        may_retry: G,
+        mut call: F,
+    ) -> Result<T>
+    where
+        F: FnMut() -> Result<T>,
+        G: Fn() -> bool,
     {
         let provider: &'static str = self.inner.name();
         let span = tracing::info_span!(
@@ -360,7 +376,14 @@ fn execute<T, F>(&self, operation: &'static str, mut call: F) -> Result<T>
             let Err(err) = result else {
                 unreachable!("checked Ok above")
             };
-            match self.handle_failure(provider, operation, err, elapsed, attempts, max_attempts) {
+            // A refusal (e.g. a safety filter block) is a healthy response:
+            // retrying cannot change it and it must not trip the breaker
+            if matches!(err, Error::ContentBlocked { .. }) {
+                span.record("status", "blocked");
+                return Err(err);
+            }
+            let retry_limit = if may_retry() { max_attempts } else { attempts };
+            match self.handle_failure(provider, operation, err, elapsed, attempts, retry_limit) {
                 FailureAction::Retry(err) => {
                     last_error = Some(err);
                 },
@@ -542,6 +565,24 @@ fn record_budget_metrics(&self, provider: &'static str, operation: &'static str,
         }
     }
 
+    /// Counts the tokens a call consumed, split by prompt and completion.
+    ///
+    /// Estimated counts are labeled `approximate` so they can be told apart
+    /// from the billed figures reported by the API.
+    fn record_token_usage(provider: &'static str, operation: &'static str, usage: TokenUsage) {
+        let approximate = if usage.approximate { "true" } else { "false" };
+        for (kind, tokens) in [("prompt", usage.prompt), ("completion", usage.completion)] {
+            metrics::counter!(
+                "llm_tokens_total",
+                "provider" => provider,
+                "operation" => operation,
+                "kind" => kind,
+                "approximate" => approximate
+            )
+            .increment(tokens);
+        }
+    }
+
     fn record_breaker_state(provider: &'static str, breaker_state: u8) {
         metrics::gauge!("llm_circuit_breaker_state", "provider" => provider)
             .set(f64::from(breaker_state));
@@ -609,6 +650,37 @@ fn complete_with_system(&self, system: &str, user: &str) -> Result<String> {
         })
     }
 
+    fn complete_with_usage(&self, prompt: &str) -> Result<(String, TokenUsage)> {
+        let (response, usage) =
+            self.execute("complete", || self.inner.complete_with_usage(prompt))?;
+        Self::record_token_usage(self.inner.name(), "complete", usage);
+        Ok((response, usage))
+    }
+
+    fn complete_with_system_usage(&self, system: &str, user: &str) -> Result<(String, TokenUsage)> {
+        let (response, usage) = self.execute("complete_with_system", || {
+            self.inner.complete_with_system_usage(system, user)
+        })?;
+        Self::record_token_usage(self.inner.name(), "complete_with_system", usage);
+        Ok((response, usage))
+    }
+
+    fn complete_stream(&self, prompt: &str, on_chunk: &mut dyn FnMut(&str)) -> Result<String> {
+        // Once a chunk has reached the caller a retry would repeat it, so a
+        // stream interrupted part-way fails without retrying
+        let streamed = Cell::new(false);
+        self.execute_guarded(
+            "complete_stream",
+            || !streamed.get(),
+            || {
+                self.inner.complete_stream(prompt, &mut |chunk| {
+                    streamed.set(true);
+                    on_chunk(chunk);
+                })
+            },
+        )
+    }
+
     fn analyze_for_capture(&self, content: &str) -> Result<CaptureAnalysis> {
         self.execute("analyze_for_capture", || {
             self.inner.analyze_for_capture(content)
@@ -1106,6 +1178,161 @@ fn test_is_retryable_error_returns_false_for_client_errors() {
         }
     }
 
+    #[test]
+    fn test_content_blocked_does_not_trip_breaker() {
+        struct RefusingProvider;
+
+        impl LlmProvider for RefusingProvider {
+            fn name(&self) -> &'static str {
+                "refusing"
+            }
+
+            fn complete(&self, _prompt: &str) -> Result<String> {
+                Err(Error::ContentBlocked {
+                    reason: "blocked by safety filter".to_string(),
+                })
+            }
+
+            fn analyze_for_capture(&self, _content: &str) -> Result<CaptureAnalysis> {
+                Err(Error::OperationFailed {
+                    operation: "analyze_for_capture".to_string(),
+                    cause: "not used".to_string(),
+                })
+            }
+        }
+
+        let config = LlmResilienceConfig {
+            breaker_failure_threshold: 1,
+            max_retries: 2,
+            retry_backoff_ms: 0,
+            ..Default::default()
+        };
+        let provider = ResilientLlmProvider::new(RefusingProvider, config);
+
+        for _ in 0..3 {
+            assert!(matches!(
+                provider.complete("prompt"),
+                Err(Error::ContentBlocked { .. })
+            ));
+        }
+        let breaker = provider
+            .breaker
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        assert_eq!(breaker.state_value(), 0); // Still closed
+    }
+
+    /// Provider whose streams fail with a dropped connection on the first
+    /// `failures` calls, after emitting `chunks_before_failure` chunks.
+    struct FlakyStreamProvider {
+        calls: std::sync::atomic::AtomicU32,
+        failures: u32,
+        chunks_before_failure: usize,
+    }
+
+    impl LlmProvider for FlakyStreamProvider {
+        fn name(&self) -> &'static str {
+            "flaky"
+        }
+
+        fn complete(&self, _prompt: &str) -> Result<String> {
+            Ok("Hello, world".to_string())
+        }
+
+        fn complete_stream(&self, _prompt: &str, on_chunk: &mut dyn FnMut(&str)) -> Result<String> {
+            let call = self.calls.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            let chunks = ["Hello", ", world"];
+            if call < self.failures {
+                for &chunk in &chunks[..self.chunks_before_failure] {
+                    on_chunk(chunk);
+                }
+                return Err(Error::OperationFailed {
+                    operation: "flaky_stream".to_string(),
+                    cause: "connection interrupted mid-stream: reset".to_string(),
+                });
+            }
+            for chunk in chunks {
+                on_chunk(chunk);
+            }
+            Ok(chunks.concat())
+        }
+
+        fn analyze_for_capture(&self, _content: &str) -> Result<CaptureAnalysis> {
+            Err(Error::OperationFailed {
+                operation: "analyze_for_capture".to_string(),
+                cause: "not used".to_string(),
+            })
+        }
+    }
+
+    fn stream_config() -> LlmResilienceConfig {
+        LlmResilienceConfig {
+            breaker_failure_threshold: 1,
+            max_retries: 2,
+            retry_backoff_ms: 0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_complete_stream_retries_before_first_chunk() {
+        let inner = FlakyStreamProvider {
+            calls: std::sync::atomic::AtomicU32::new(0),
+            failures: 1,
+            chunks_before_failure: 0,
+        };
+        let config = LlmResilienceConfig {
+            breaker_failure_threshold: 5,
+            ..stream_config()
+        };
+        let provider = ResilientLlmProvider::new(inner, config);
+
+        let mut chunks = Vec::new();
+        let result =
+            provider.complete_stream("prompt", &mut |chunk| chunks.push(chunk.to_string()));
+
+        assert_eq!(result.unwrap(), "Hello, world");
+        assert_eq!(chunks, vec!["Hello", ", world"]);
+        assert_eq!(
+            provider
+                .inner
+                .calls
+                .load(std::sync::atomic::Ordering::SeqCst),
+            2
+        );
+    }
+
+    #[test]
+    fn test_complete_stream_mid_stream_disconnect_trips_breaker() {
+        let inner = FlakyStreamProvider {
+            calls: std::sync::atomic::AtomicU32::new(0),
+            failures: u32::MAX,
+            chunks_before_failure: 1,
+        };
+        let provider = ResilientLlmProvider::new(inner, stream_config());
+
+        let mut chunks = Vec::new();
+        let result =
+            provider.complete_stream("prompt", &mut |chunk| chunks.push(chunk.to_string()));
+
+        // Not retried, so the caller never sees a chunk twice
+        let err = result.unwrap_err();
+        assert!(is_retryable_error(&err));
+        assert_eq!(chunks, vec!["Hello"]);
+        assert_eq!(
+            provider
+                .inner
+                .calls
+                .load(std::sync::atomic::Ordering::SeqCst),
+            1
+        );
+        let breaker = provider
+            .breaker
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        assert_eq!(breaker.state_value(), 1); // Open
+    }
+
     #[test]
     fn test_is_retryable_error_is_case_insensitive() {
         let err = Error::OperationFailed {
@@ -1114,4 +1341,20 @@ fn test_is_retryable_error_is_case_insensitive() {
         };
         assert!(is_retryable_error(&err));
     }
+
+    #[test]
+    fn test_complete_with_usage_passes_usage_through() {
+        let inner = FlakyStreamProvider {
+            calls: std::sync::atomic::AtomicU32::new(0),
+            failures: 0,
+            chunks_before_failure: 0,
+        };
+        let provider = ResilientLlmProvider::new(inner, stream_config());
+
+        let (response, usage) = provider.complete_with_usage("say hello").unwrap();
+        assert_eq!(response, "Hello, world");
+        // The inner provider reports no usage, so it is estimated
+        assert_eq!(usage, TokenUsage::estimate("say hello", "Hello, world"));
+        assert!(usage.approximate);
+    }
 }
@@ -0,0 +1,122 @@
+//! Reading of streamed LLM responses.
+//!
+//! Streaming APIs deliver a completion line by line: server-sent events
+//! (`data: {...}`) for `OpenAI`, one JSON object per line for Ollama. The
+//! client parses each line; this module reads them and turns a connection
+//! that ends before the client's end-of-stream marker into an error.
+//!
+//! Interruption errors mention the connection, so
+//! [`ResilientLlmProvider`](super::ResilientLlmProvider) classifies them as
+//! retryable and counts them against the circuit breaker.
+
+use crate::{Error, Result};
+use std::io::BufRead;
+
+/// Whether a streamed line ended the completion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamLine {
+    /// More lines follow.
+    Continue,
+    /// The completion is finished.
+    Done,
+}
+
+/// Reads `reader` line by line, passing each non-blank line to `on_line`
+/// until it returns [`StreamLine::Done`].
+///
+/// # Errors
+///
+/// Returns the errors of `on_line`, or an error for `operation` if reading
+/// fails or the stream ends before `on_line` reports [`StreamLine::Done`].
+pub fn read_stream_lines<R: BufRead>(
+    reader: R,
+    operation: &str,
+    mut on_line: impl FnMut(&str) -> Result<StreamLine>,
+) -> Result<()> {
+    for line in reader.lines() {
+        let line = line.map_err(|e| interrupted(operation, &e.to_string()))?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if on_line(line)? == StreamLine::Done {
+            return Ok(());
+        }
+    }
+    Err(interrupted(
+        operation,
+        "stream ended before the completion finished",
+    ))
+}
+
+/// Builds the error for a stream cut off part-way.
+fn interrupted(operation: &str, cause: &str) -> Error {
+    Error::OperationFailed {
+        operation: operation.to_string(),
+        cause: format!("connection interrupted mid-stream: {cause}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufReader, Cursor, Read};
+
+    /// Reader that yields `data` and then fails like a dropped connection.
+    struct DroppedConnection {
+        data: Cursor<Vec<u8>>,
+    }
+
+    impl Read for DroppedConnection {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            match self.data.read(buf)? {
+                0 => Err(std::io::ErrorKind::ConnectionReset.into()),
+                n => Ok(n),
+            }
+        }
+    }
+
+    fn collect(reader: impl BufRead) -> (Vec<String>, Result<()>) {
+        let mut lines = Vec::new();
+        let result = read_stream_lines(reader, "test_stream", |line| {
+            lines.push(line.to_string());
+            Ok(if line == "end" {
+                StreamLine::Done
+            } else {
+                StreamLine::Continue
+            })
+        });
+        (lines, result)
+    }
+
+    #[test]
+    fn test_read_stream_lines_stops_at_done() {
+        let (lines, result) = collect(Cursor::new("one\n\ntwo\r\nend\nignored\n"));
+        assert!(result.is_ok());
+        assert_eq!(lines, vec!["one", "two", "end"]);
+    }
+
+    #[test]
+    fn test_read_stream_lines_reports_interruptions() {
+        let (lines, result) = collect(Cursor::new("one\ntwo\n"));
+        assert_eq!(lines, vec!["one", "two"]);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("connection interrupted")
+        );
+
+        let reader = BufReader::new(DroppedConnection {
+            data: Cursor::new(b"one\n".to_vec()),
+        });
+        let (lines, result) = collect(reader);
+        assert_eq!(lines, vec!["one"]);
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("connection interrupted")
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Token usage of LLM calls.
+//!
+//! Hosted APIs report how many tokens a call consumed (`usage` in `OpenAI`
+//! and Anthropic responses), which is what they bill for. Providers that do
+//! not report usage get an estimate from the prompt and completion text,
+//! flagged as approximate so it is not mistaken for a billed figure.
+//!
+//! [`TokenPricing`] converts usage to a cost from the per-million-token
+//! prices set in the `[llm]` config section. Prices vary by model and
+//! change over time, so none are built in.
+
+use crate::config::LlmConfig;
+use std::fmt;
+use std::ops::AddAssign;
+
+/// Tokens consumed by one or more LLM calls.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TokenUsage {
+    /// Tokens in the prompt (input).
+    pub prompt: u64,
+    /// Tokens in the completion (output).
+    pub completion: u64,
+    /// Prompt and completion tokens together.
+    pub total: u64,
+    /// Whether any of the counts were estimated rather than reported.
+    pub approximate: bool,
+}
+
+impl TokenUsage {
+    /// Creates usage as reported by a provider.
+    #[must_use]
+    pub const fn new(prompt: u64, completion: u64) -> Self {
+        Self {
+            prompt,
+            completion,
+            total: prompt.saturating_add(completion),
+            approximate: false,
+        }
+    }
+
+    /// Estimates usage from the prompt and completion text.
+    ///
+    /// Counts whitespace-separated words, which undercounts the subword
+    /// tokens of code and non-English text, so the result is flagged
+    /// approximate.
+    #[must_use]
+    pub fn estimate(prompt: &str, completion: &str) -> Self {
+        Self {
+            approximate: true,
+            ..Self::new(count_words(prompt), count_words(completion))
+        }
+    }
+
+    /// Returns `true` if no tokens were recorded.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.total == 0
+    }
+}
+
+impl AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: Self) {
+        self.prompt = self.prompt.saturating_add(other.prompt);
+        self.completion = self.completion.saturating_add(other.completion);
+        self.total = self.total.saturating_add(other.total);
+        self.approximate = self.approximate || other.approximate;
+    }
+}
+
+/// Formats as `1234 tokens (1000 prompt, 234 completion)`, prefixed with `~`
+/// when approximate.
+impl fmt::Display for TokenUsage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let marker = if self.approximate { "~" } else { "" };
+        write!(
+            f,
+            "{marker}{} tokens ({} prompt, {} completion)",
+            self.total, self.prompt, self.completion
+        )
+    }
+}
+
+/// Price of LLM tokens, in US dollars per million tokens.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct TokenPricing {
+    /// Price per million prompt (input) tokens.
+    pub prompt_per_mtok: f64,
+    /// Price per million completion (output) tokens.
+    pub completion_per_mtok: f64,
+}
+
+impl TokenPricing {
+    /// Creates pricing from per-million-token prices.
+    #[must_use]
+    pub const fn new(prompt_per_mtok: f64, completion_per_mtok: f64) -> Self {
+        Self {
+            prompt_per_mtok,
+            completion_per_mtok,
+        }
+    }
+
+    /// Returns the pricing configured for the LLM provider, if any.
+    ///
+    /// `SUBCOG_LLM_PROMPT_PRICE_PER_MTOK` and
+    /// `SUBCOG_LLM_COMPLETION_PRICE_PER_MTOK` override the config file. An
+    /// unset price counts as free, so `None` is returned only when neither
+    /// price is set.
+    #[must_use]
+    pub fn from_config(config: &LlmConfig) -> Option<Self> {
+        let env_price = |name: &str| std::env::var(name).ok().and_then(|v| v.parse::<f64>().ok());
+        let prompt = env_price("SUBCOG_LLM_PROMPT_PRICE_PER_MTOK").or(config.prompt_price_per_mtok);
+        let completion =
+            env_price("SUBCOG_LLM_COMPLETION_PRICE_PER_MTOK").or(config.completion_price_per_mtok);
+        if prompt.is_none() && completion.is_none() {
+            return None;
+        }
+        Some(Self::new(
+            prompt.unwrap_or_default().max(0.0),
+            completion.unwrap_or_default().max(0.0),
+        ))
+    }
+
+    /// Returns the cost of `usage` in US dollars.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)] // token counts are far below f64 precision
+    pub fn cost(&self, usage: &TokenUsage) -> f64 {
+        (usage.prompt as f64).mul_add(
+            self.prompt_per_mtok,
+            usage.completion as f64 * self.completion_per_mtok,
+        ) / 1_000_000.0
+    }
+}
+
+/// Counts whitespace-separated words.
+fn count_words(text: &str) -> u64 {
+    u64::try_from(text.split_whitespace().count()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_is_approximate() {
+        let usage = TokenUsage::estimate("tag this  memory\nplease", "rust, sqlite");
+        assert_eq!(usage.prompt, 4);
+        assert_eq!(usage.completion, 2);
+        assert_eq!(usage.total, 6);
+        assert!(usage.approximate);
+        assert_eq!(usage.to_string(), "~6 tokens (4 prompt, 2 completion)");
+    }
+
+    #[test]
+    fn test_add_assign_keeps_approximate_flag() {
+        let mut usage = TokenUsage::default();
+        assert!(usage.is_empty());
+
+        usage += TokenUsage::new(100, 20);
+        assert_eq!(usage, TokenUsage::new(100, 20));
+        assert_eq!(usage.to_string(), "120 tokens (100 prompt, 20 completion)");
+
+        usage += TokenUsage::estimate("one two", "three");
+        assert_eq!(usage.total, 123);
+        assert!(usage.approximate);
+    }
+
+    #[test]
+    fn test_pricing_cost() {
+        let pricing = TokenPricing::new(3.0, 15.0);
+        let cost = pricing.cost(&TokenUsage::new(1_000_000, 200_000));
+        assert!((cost - 6.0).abs() < 1e-9);
+        assert!(pricing.cost(&TokenUsage::default()).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_pricing_from_config() {
+        assert_eq!(TokenPricing::from_config(&LlmConfig::default()), None);
+
+        let config = LlmConfig {
+            prompt_price_per_mtok: Some(0.15),
+            ..Default::default()
+        };
+        assert_eq!(
+            TokenPricing::from_config(&config),
+            Some(TokenPricing::new(0.15, 0.0))
+        );
+    }
+}
@@ -24,8 +24,11 @@
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
 use std::process::ExitCode;
+use std::sync::Arc;
 use subcog::config::SubcogConfig;
+use subcog::io::services::import::DEFAULT_PROGRESS_INTERVAL;
 use subcog::mcp::{McpServer, Transport};
+use subcog::notifications::NotificationService;
 use subcog::observability::{
     self, InitOptions, RequestContext, enter_request_context, scope_request_context,
 };
@@ -34,7 +37,10 @@
 use subcog::webhooks::WebhookService;
 use tracing::info_span;
 
-use commands::{GraphAction, HookEvent, MigrateAction, PromptAction, WebhookAction};
+use commands::{
+    ConfigAction, ContextAction, GraphAction, HookAction, MigrateAction, PromptAction, RestAction,
+    SchemaKind, TagAction, WebhookAction,
+};
 
 /// Subcog - A persistent memory system for AI coding assistants.
 #[derive(Parser)]
@@ -49,6 +55,10 @@ struct Cli {
     #[arg(short, long, global = true)]
     config: Option<String>,
 
+    /// Disable all network access (LLM calls, model downloads, sync, webhooks).
+    #[arg(long, global = true)]
+    offline: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -58,12 +68,14 @@ struct Cli {
 enum Commands {
     /// Capture a memory.
     Capture {
-        /// The content to capture.
-        content: String,
+        /// The content to capture (optional with --edit or --section).
+        #[arg(required_unless_present_any = ["edit", "section"])]
+        content: Option<String>,
 
-        /// Namespace for the memory.
-        #[arg(short, long, default_value = "decisions")]
-        namespace: String,
+        /// Namespace for the memory, or "auto" to classify the content.
+        /// Defaults to "auto" in a terminal and "decisions" otherwise.
+        #[arg(short, long)]
+        namespace: Option<String>,
 
         /// Tags for the memory (comma-separated).
         #[arg(short, long)]
@@ -81,6 +93,41 @@ enum Commands {
         /// Storage domain: "project" (default if in git repo), "user" (global), or "org".
         #[arg(short, long)]
         domain: Option<String>,
+
+        /// Structured template for the memory: "adr" (context, decision, consequences, alternatives).
+        #[arg(long)]
+        template: Option<String>,
+
+        /// Named section as "name=text" (repeatable), e.g. "decision=Adopt Rust".
+        #[arg(long)]
+        section: Vec<String>,
+
+        /// Compose the memory in $EDITOR (pre-filled with the template skeleton, if any).
+        #[arg(long)]
+        edit: bool,
+
+        /// Attach a file (repeatable), e.g. a screenshot or log.
+        #[arg(long, value_name = "FILE")]
+        attach: Vec<PathBuf>,
+
+        /// Content type: "markdown", "text", "code" or "code:<lang>" (detected if omitted).
+        #[arg(long, value_name = "TYPE")]
+        content_type: Option<String>,
+
+        /// Split content longer than `[capture] split_threshold_chars` into
+        /// several linked memories at headings and blank lines.
+        #[arg(long)]
+        split: bool,
+    },
+
+    /// Show a single memory by ID.
+    Get {
+        /// Memory ID.
+        id: String,
+
+        /// Output format: text or json.
+        #[arg(short, long, default_value = "text")]
+        format: String,
     },
 
     /// Search for memories.
@@ -92,6 +139,17 @@ enum Commands {
         #[arg(short, long, default_value = "hybrid")]
         mode: String,
 
+        /// Hybrid ranking weight: 0.0 ranks by keyword (BM25) matches only,
+        /// 1.0 by semantic (vector) matches only; out-of-range values are
+        /// clamped. Defaults to `[search] hybrid_alpha` (0.5).
+        #[arg(long, value_name = "WEIGHT", allow_negative_numbers = true)]
+        alpha: Option<f32>,
+
+        /// In hybrid mode, serve the semantic (vector) matches alone when no
+        /// memory matches the query keywords, whatever the --alpha weight.
+        #[arg(long)]
+        vector_only_fallback: bool,
+
         /// Filter by namespace.
         #[arg(short, long)]
         namespace: Option<String>,
@@ -100,6 +158,15 @@ enum Commands {
         #[arg(short, long, default_value = "10")]
         limit: usize,
 
+        /// Skip this many ranked results, to page through them with --limit.
+        #[arg(
+            short,
+            long,
+            default_value = "0",
+            conflicts_with_all = ["rerank", "diverse", "min_results", "namespace_counts"]
+        )]
+        offset: usize,
+
         /// Display raw (un-normalized) scores instead of normalized scores.
         #[arg(long)]
         raw: bool,
@@ -112,10 +179,165 @@ enum Commands {
         /// Comma-separated for OR logic (e.g., "PostgreSQL,Redis").
         #[arg(short, long)]
         entity: Option<String>,
+
+        /// Filter by capture origin: manual, hook, import, or consolidation.
+        #[arg(long)]
+        origin: Option<String>,
+
+        /// Only return memories with a recorded capture confidence of at least this value.
+        #[arg(long)]
+        min_confidence: Option<f32>,
+
+        /// Only return memories captured at this commit (full SHA or a prefix of
+        /// at least 4 hex characters).
+        #[arg(long, value_name = "SHA")]
+        at_commit: Option<String>,
+
+        /// Only return memories in this language (ISO 639-1 code, e.g. "es").
+        #[arg(long, value_name = "CODE")]
+        lang: Option<String>,
+
+        /// Show score components, origin, and capture confidence for each hit,
+        /// and the candidates found by the text and vector searches.
+        #[arg(long)]
+        explain: bool,
+
+        /// Render each hit with a template, one line per hit
+        /// (e.g., '{{id}}\t{{namespace}}\t{{score}}\t{{content_preview}}').
+        #[arg(long)]
+        output_template: Option<String>,
+
+        /// Suppress the result header and timing summary.
+        #[arg(short, long)]
+        quiet: bool,
+
+        /// Search project, user, and org scopes together, labeling each hit.
+        #[arg(long)]
+        all_scopes: bool,
+
+        /// Rerank the top candidates with the LLM before returning the results
+        /// (candidate count from `[search] rerank_candidates`).
+        #[arg(long)]
+        rerank: bool,
+
+        /// After the results, ask which were useful and record the votes.
+        /// Later searches for similar queries rank voted memories accordingly.
+        #[arg(long)]
+        feedback: bool,
+
+        /// Prefer dissimilar results over near-duplicates (Maximal Marginal
+        /// Relevance, weighted by `[search] diversity_lambda`).
+        #[arg(long)]
+        diverse: bool,
+
+        /// Expand the query with LLM-suggested synonyms and related terms
+        /// (always on with `[search] expand = true`).
+        #[arg(long)]
+        expand: bool,
+
+        /// Bucket the results under headers: namespace, tag, source, or none.
+        /// Each group keeps the relevance order.
+        #[arg(long, default_value = "none")]
+        group_by: String,
+
+        /// Output format: text, or jsonl (one JSON object per hit, flushed
+        /// per line; the header and timing go to stderr).
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// Print only the values a JSONPath expression selects from the JSON
+        /// results (e.g., '$[*].memory.id'), one per line.
+        #[arg(long, value_name = "EXPR")]
+        json_path: Option<String>,
+
+        /// Print only how many results match per namespace, as JSON, without
+        /// loading the memories' content.
+        #[arg(long)]
+        namespace_counts: bool,
+
+        /// With --namespace-counts, also count the matches per tag.
+        #[arg(long, requires = "namespace_counts")]
+        tag_counts: bool,
+
+        /// Widen the query until at least N results are found: drop filters
+        /// weakest first, switch text to hybrid, then drop the score threshold.
+        #[arg(long, value_name = "N")]
+        min_results: Option<usize>,
+
+        /// Order the results: relevance, created, or updated (newest first).
+        #[arg(long, default_value = "relevance")]
+        sort: String,
+
+        /// Only return memories edited within this window, e.g. 12h or 7d.
+        #[arg(long, value_name = "DURATION")]
+        updated_since: Option<String>,
+
+        /// Only return memories captured at or after this time: a duration
+        /// ago (12h, 7d, 2w), a date (2024-01-01), or an RFC 3339 timestamp.
+        #[arg(long, value_name = "WHEN")]
+        since: Option<String>,
+
+        /// Only return memories captured at or before this time (same forms
+        /// as --since; a date means midnight UTC).
+        #[arg(long, value_name = "WHEN")]
+        until: Option<String>,
+
+        /// Only search the memories related to this one: the memory itself and
+        /// those reachable over links, supersession, and consolidation edges.
+        #[arg(long, value_name = "ID", conflicts_with = "all_scopes")]
+        within: Option<String>,
+
+        /// Number of relationship hops --within follows.
+        #[arg(long, value_name = "N", default_value = "2", requires = "within")]
+        within_depth: usize,
+
+        /// Print the results as one context block ready to paste into a
+        /// prompt: a numbered list citing each memory's source, or the
+        /// context template named by --template.
+        #[arg(long)]
+        template_context: bool,
+
+        /// Context template to render the block with (latest version).
+        #[arg(long, value_name = "NAME", requires = "template_context")]
+        template: Option<String>,
+
+        /// Drop the lowest-ranked memories until the block fits this many
+        /// (estimated) tokens.
+        #[arg(long, value_name = "N", requires = "template_context")]
+        max_tokens: Option<usize>,
+    },
+
+    /// Summarize recently captured memories: counts per namespace, top tags,
+    /// and notable captures.
+    Digest {
+        /// Time window, e.g. 12h, 1d, or 7d.
+        #[arg(long, default_value = "1d")]
+        since: String,
+
+        /// Filter by namespace.
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// Output format: text, markdown, or json.
+        #[arg(short, long, default_value = "text")]
+        format: String,
+
+        /// Add an LLM-written summary of the memories.
+        #[arg(long)]
+        summarize: bool,
+
+        /// Also send the digest through the webhooks and notifiers subscribed
+        /// to the `digest` event.
+        #[arg(long)]
+        notify: bool,
     },
 
     /// Show status.
-    Status,
+    Status {
+        /// Print component health as JSON (for monitoring).
+        #[arg(short, long)]
+        json: bool,
+    },
 
     /// Run consolidation.
     Consolidate {
@@ -127,7 +349,7 @@ enum Commands {
         #[arg(short, long)]
         days: Option<u32>,
 
-        /// Show what would be consolidated without making changes.
+        /// Show the proposed clusters and summaries without making changes.
         #[arg(long)]
         dry_run: bool,
 
@@ -138,6 +360,10 @@ enum Commands {
         /// Similarity threshold (0.0-1.0) for grouping related memories.
         #[arg(long)]
         similarity: Option<f32>,
+
+        /// Dry-run report format: text or json.
+        #[arg(long, default_value = "text")]
+        output: String,
     },
 
     /// Rebuild search index from stored memories.
@@ -168,35 +394,54 @@ enum Commands {
 
     /// Manage configuration.
     Config {
+        /// Config subcommand.
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
+
         /// Show current configuration.
         #[arg(long)]
         show: bool,
 
-        /// Set a configuration value.
-        #[arg(long)]
+        /// Set a value in the config file, e.g. `features.audit_log=true`.
+        #[arg(long, value_name = "KEY=VALUE")]
         set: Option<String>,
     },
 
     /// Start MCP server.
     Serve {
-        /// Transport type: stdio or http.
+        /// Transport type: stdio, http, sse, or unix.
         #[arg(short, long, default_value = "stdio")]
         transport: String,
 
-        /// Port for HTTP transport.
+        /// Socket path for the unix transport.
+        #[arg(long, required_if_eq("transport", "unix"))]
+        socket: Option<PathBuf>,
+
+        /// Port for the HTTP and SSE transports.
         #[arg(short, long, default_value = "3000")]
         port: u16,
 
         /// Allow unauthenticated /healthz requests (for K8s sidecar probes).
         #[arg(long)]
         health_no_auth: bool,
+
+        /// Also serve the REST API (/memories) on the HTTP or SSE transport.
+        #[arg(long)]
+        rest: bool,
     },
 
     /// Handle Claude Code hooks.
     Hook {
-        /// Hook event type.
+        /// Hook event type, or `test`.
         #[command(subcommand)]
-        event: HookEvent,
+        action: HookAction,
+    },
+
+    /// Preview the memory context hooks would inject.
+    Context {
+        /// Context subcommand.
+        #[command(subcommand)]
+        action: ContextAction,
     },
 
     /// Manage prompt templates.
@@ -217,6 +462,20 @@ enum Commands {
         verbose: bool,
     },
 
+    /// Suggest namespaces for text without capturing it.
+    SuggestNamespace {
+        /// Text to classify (use - to read from stdin).
+        text: String,
+
+        /// Maximum number of suggestions.
+        #[arg(short, long, default_value = "3")]
+        limit: usize,
+
+        /// Output format: text or json.
+        #[arg(short, long, default_value = "text")]
+        format: String,
+    },
+
     /// Migrate memories to new features.
     Migrate {
         /// Migration subcommand.
@@ -237,6 +496,10 @@ enum Commands {
         #[arg(long)]
         dry_run: bool,
 
+        /// Run TTL, stale-branch and retention GC against every scope.
+        #[arg(long, conflicts_with = "purge")]
+        all: bool,
+
         /// Purge tombstoned memories older than threshold.
         #[arg(long)]
         purge: bool,
@@ -246,17 +509,52 @@ enum Commands {
         older_than: u64,
     },
 
+    /// Tombstone low-confidence auto-captured memories.
+    Prune {
+        /// Prune memories captured automatically by hooks.
+        #[arg(long)]
+        auto_captured: bool,
+
+        /// Prune memories whose capture confidence is below this value (0.0-1.0).
+        #[arg(long)]
+        confidence_below: f32,
+
+        /// Only prune memories older than this duration (e.g. 30d, 12h).
+        #[arg(long)]
+        older_than: Option<String>,
+
+        /// Also prune manual captures that have a recorded confidence.
+        #[arg(long)]
+        include_manual: bool,
+
+        /// Skip confirmation prompt.
+        #[arg(short, long)]
+        force: bool,
+
+        /// Show what would be pruned without making changes.
+        #[arg(long)]
+        dry_run: bool,
+    },
+
     /// Delete one or more memories.
     Delete {
         /// Memory IDs to delete.
-        #[arg(required = true)]
+        #[arg(required_unless_present = "all", conflicts_with = "all")]
         ids: Vec<String>,
 
+        /// Delete every memory in the namespace given by `--namespace`.
+        #[arg(long, requires = "namespace")]
+        all: bool,
+
+        /// Namespace to delete with `--all`.
+        #[arg(short, long, requires = "all")]
+        namespace: Option<String>,
+
         /// Permanently delete (hard delete). Default is soft delete (tombstone).
         #[arg(long)]
         hard: bool,
 
-        /// Skip confirmation prompt.
+        /// Skip confirmation prompt (required with `--all`).
         #[arg(short, long)]
         force: bool,
 
@@ -265,6 +563,13 @@ enum Commands {
         dry_run: bool,
     },
 
+    /// Restore one or more tombstoned (soft-deleted) memories.
+    Restore {
+        /// Memory IDs to restore.
+        #[arg(required = true)]
+        ids: Vec<String>,
+    },
+
     /// Manage the knowledge graph.
     Graph {
         /// Graph subcommand.
@@ -272,6 +577,56 @@ enum Commands {
         action: GraphAction,
     },
 
+    /// Pin a memory so it is always injected at session start and ranked
+    /// higher in recall.
+    Pin {
+        /// Memory ID.
+        id: String,
+    },
+
+    /// Unpin a memory.
+    Unpin {
+        /// Memory ID.
+        id: String,
+    },
+
+    /// Edit an existing memory in place.
+    #[command(arg_required_else_help = true)]
+    Update {
+        /// Memory ID or URN.
+        id: String,
+
+        /// New content (re-embedded and re-scanned for secrets).
+        #[arg(long)]
+        content: Option<String>,
+
+        /// Tags replacing the current ones (comma-separated).
+        #[arg(short, long)]
+        tags: Option<String>,
+
+        /// Namespace to move the memory to.
+        #[arg(short, long)]
+        namespace: Option<String>,
+
+        /// New status: active, archived, superseded, pending, deleted, or consolidated.
+        #[arg(long)]
+        status: Option<String>,
+    },
+
+    /// Rename or merge tags across all memories.
+    Tag {
+        /// Tag subcommand.
+        #[command(subcommand)]
+        action: TagAction,
+    },
+
+    /// Check the index against the persistence backend.
+    Verify {
+        /// Reindex missing, stale and mismatched memories and remove orphaned rows.
+        #[arg(long)]
+        fix: bool,
+    },
+
     /// Manage webhook notifications.
     Webhook {
         /// Webhook subcommand.
@@ -279,12 +634,20 @@ enum Commands {
         action: WebhookAction,
     },
 
-    /// Import memories from a file.
+    /// Import memories from a file or a directory of Markdown notes.
     Import {
         /// Input file path.
-        file: PathBuf,
+        #[arg(required_unless_present = "from_directory")]
+        file: Option<PathBuf>,
+
+        /// Import every `.md` file under this directory, one memory per note.
+        ///
+        /// YAML front-matter may set namespace, domain, tags and created_at; notes
+        /// without a namespace default to `context`. Honors `.subcogignore`.
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["file", "format", "verify"])]
+        from_directory: Option<PathBuf>,
 
-        /// File format: json, yaml, csv (auto-detected from extension if not specified).
+        /// File format: json, yaml, csv, parquet (auto-detected from extension if not specified).
         #[arg(short, long)]
         format: Option<String>,
 
@@ -300,9 +663,31 @@ enum Commands {
         #[arg(long, default_value = "true")]
         skip_duplicates: bool,
 
+        /// How to handle records matching existing memories: skip, overwrite, or append.
+        ///
+        /// Records with an `id` match by ID, others by content hash. Overrides --skip-duplicates.
+        #[arg(long)]
+        merge_strategy: Option<String>,
+
         /// Dry run (validate without storing).
         #[arg(long)]
         dry_run: bool,
+
+        /// Refuse to import unless the file's detached signature (<file>.sig) verifies.
+        #[arg(long, requires = "key")]
+        verify: bool,
+
+        /// Ed25519 public key (PEM or hex) used with --verify.
+        #[arg(long)]
+        key: Option<PathBuf>,
+
+        /// Update the progress bar every N records.
+        #[arg(long, value_name = "N", default_value_t = DEFAULT_PROGRESS_INTERVAL)]
+        progress_interval: usize,
+
+        /// Suppress the progress bar.
+        #[arg(short, long)]
+        quiet: bool,
     },
 
     /// Export memories to a file.
@@ -310,7 +695,8 @@ enum Commands {
         /// Output file path.
         output: PathBuf,
 
-        /// File format: json, yaml, csv, parquet (auto-detected from extension if not specified).
+        /// File format: json, yaml, csv, markdown, parquet (auto-detected from extension if not
+        /// specified).
         #[arg(short, long)]
         format: Option<String>,
 
@@ -325,6 +711,62 @@ enum Commands {
         /// Filter by domain: project, user, or org.
         #[arg(short, long)]
         domain: Option<String>,
+
+        /// Comma-separated fields to export, in output order (e.g. id,namespace,content).
+        #[arg(long)]
+        fields: Option<String>,
+
+        /// Sign the export with Ed25519, writing a detached signature to <output>.sig.
+        #[arg(long, requires = "key")]
+        sign: bool,
+
+        /// Ed25519 private key (PEM or hex) used with --sign.
+        #[arg(long)]
+        key: Option<PathBuf>,
+
+        /// Strip identifying metadata: domain, project_id, branch, file_path,
+        /// source and attachments.
+        #[arg(long)]
+        anonymize: bool,
+
+        /// Redact PII and secrets in the content (with --anonymize).
+        #[arg(long, requires = "anonymize")]
+        redact_pii: bool,
+
+        /// Record a checkpoint in <output>.checkpoint for incremental backups.
+        #[arg(long, conflicts_with = "limit")]
+        incremental: bool,
+
+        /// Export only memories created or updated since the checkpoint,
+        /// rotating the previous export aside.
+        #[arg(long, requires = "incremental")]
+        since_last: bool,
+
+        /// Ignore the recorded checkpoint and export everything.
+        #[arg(long, requires = "incremental")]
+        reset_checkpoint: bool,
+
+        /// Update the progress bar every N records.
+        #[arg(long, value_name = "N", default_value_t = DEFAULT_PROGRESS_INTERVAL)]
+        progress_interval: usize,
+
+        /// Suppress the progress bar.
+        #[arg(short, long)]
+        quiet: bool,
+    },
+
+    /// Print a JSON Schema (e.g. `subcog schema memory` for import/export records).
+    Schema {
+        /// Schema to print.
+        #[command(subcommand)]
+        kind: SchemaKind,
+    },
+
+    /// REST API tools (e.g. `subcog rest openapi` prints the OpenAPI document).
+    Rest {
+        /// REST subcommand.
+        #[command(subcommand)]
+        action: RestAction,
     },
 }
 
@@ -332,6 +774,26 @@ enum Commands {
 #[tokio::main]
 async fn main() -> ExitCode {
     let cli = Cli::parse();
+    if cli.offline {
+        subcog::config::enable_offline_mode();
+    }
+
+    // Validation runs before loading, which would reject a broken file, and
+    // before observability, which may open network exporters
+    if let Commands::Config {
+        action: Some(ConfigAction::Validate { path }),
+        ..
+    } = &cli.command
+    {
+        let config_path = explicit_config_path(cli.config.as_deref());
+        return match commands::cmd_config_validate(path.as_deref(), config_path.as_deref()) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(e) => {
+                eprintln!("Error: {e}");
+                ExitCode::FAILURE
+            },
+        };
+    }
 
     let config = match load_config(cli.config.as_deref()) {
         Ok(config) => config,
@@ -380,24 +842,37 @@ async fn run_command(cli: Cli, config: SubcogConfig) -> Result<(), Box<dyn std::
 
     let command_name = match &cli.command {
         Commands::Capture { .. } => "capture",
+        Commands::Get { .. } => "get",
         Commands::Recall { .. } => "recall",
-        Commands::Status => "status",
+        Commands::Digest { .. } => "digest",
+        Commands::Status { .. } => "status",
         Commands::Consolidate { .. } => "consolidate",
         Commands::Reindex { .. } => "reindex",
         Commands::Enrich { .. } => "enrich",
         Commands::Config { .. } => "config",
         Commands::Serve { .. } => "serve",
         Commands::Hook { .. } => "hook",
+        Commands::Context { .. } => "context",
         Commands::Prompt { .. } => "prompt",
         Commands::Namespaces { .. } => "namespaces",
+        Commands::SuggestNamespace { .. } => "suggest-namespace",
         Commands::Migrate { .. } => "migrate",
         Commands::Completions { .. } => "completions",
         Commands::Gc { .. } => "gc",
+        Commands::Prune { .. } => "prune",
         Commands::Delete { .. } => "delete",
+        Commands::Restore { .. } => "restore",
         Commands::Graph { .. } => "graph",
+        Commands::Pin { .. } => "pin",
+        Commands::Unpin { .. } => "unpin",
+        Commands::Update { .. } => "update",
+        Commands::Tag { .. } => "tag",
+        Commands::Verify { .. } => "verify",
         Commands::Webhook { .. } => "webhook",
         Commands::Import { .. } => "import",
         Commands::Export { .. } => "export",
+        Commands::Schema { .. } => "schema",
+        Commands::Rest { .. } => "rest",
     };
 
     let request_context = RequestContext::new();
@@ -439,36 +914,141 @@ macro_rules! run_blocking_cmd {
             source,
             ttl,
             domain,
+            template,
+            section,
+            edit,
+            attach,
+            content_type,
+            split,
         } => {
             let config = config.clone();
+            let structure = commands::CaptureStructure {
+                template,
+                sections: section,
+                edit,
+                content_type,
+            };
             run_blocking_cmd!(move || {
-                commands::cmd_capture(&config, content, namespace, tags, source, ttl, domain)
-                    .map_err(|e| e.to_string())
+                commands::cmd_capture(
+                    &config, content, namespace, tags, source, ttl, domain, structure, &attach,
+                    split,
+                )
+                .map_err(|e| e.to_string())
             })
         },
+        Commands::Get { id, format } => {
+            run_blocking_cmd!(move || commands::cmd_get(&id, &format).map_err(|e| e.to_string()))
+        },
         Commands::Recall {
             query,
             mode,
+            alpha,
+            vector_only_fallback,
             namespace,
             limit,
+            offset,
             raw,
             include_tombstoned,
             entity,
-        } => run_blocking_cmd!(move || {
-            commands::cmd_recall(
-                query,
-                mode,
-                namespace,
-                limit,
-                raw,
-                include_tombstoned,
-                entity,
-            )
-            .map_err(|e| e.to_string())
-        }),
-        Commands::Status => {
+            origin,
+            min_confidence,
+            at_commit,
+            lang,
+            explain,
+            output_template,
+            quiet,
+            all_scopes,
+            rerank,
+            feedback,
+            diverse,
+            expand,
+            group_by,
+            format,
+            json_path,
+            namespace_counts,
+            tag_counts,
+            min_results,
+            sort,
+            updated_since,
+            since,
+            until,
+            within,
+            within_depth,
+            template_context,
+            template,
+            max_tokens,
+        } => {
             let config = config.clone();
-            run_blocking_cmd!(move || commands::cmd_status(&config).map_err(|e| e.to_string()))
+            run_blocking_cmd!(move || {
+                commands::cmd_recall(
+                    &config,
+                    query,
+                    mode,
+                    alpha,
+                    vector_only_fallback,
+                    namespace,
+                    limit,
+                    offset,
+                    raw,
+                    include_tombstoned,
+                    entity,
+                    origin,
+                    min_confidence,
+                    at_commit,
+                    lang,
+                    explain,
+                    output_template,
+                    quiet,
+                    all_scopes,
+                    rerank,
+                    feedback,
+                    diverse,
+                    expand,
+                    &group_by,
+                    &format,
+                    json_path,
+                    namespace_counts,
+                    tag_counts,
+                    min_results,
+                    &sort,
+                    updated_since,
+                    since,
+                    until,
+                    within,
+                    within_depth,
+                    template_context,
+                    template,
+                    max_tokens,
+                )
+                .map_err(|e| e.to_string())
+            })
+        },
+        Commands::Digest {
+            since,
+            namespace,
+            format,
+            summarize,
+            notify,
+        } => {
+            let config = config.clone();
+            run_blocking_cmd!(move || {
+                let notifications = notify.then(|| notification_service(&config));
+                commands::cmd_digest(
+                    &config,
+                    &since,
+                    namespace,
+                    &format,
+                    summarize,
+                    notifications.as_ref(),
+                )
+                .map_err(|e| e.to_string())
+            })
+        },
+        Commands::Status { json } => {
+            let config = config.clone();
+            run_blocking_cmd!(move || {
+                commands::cmd_status(&config, json).map_err(|e| e.to_string())
+            })
         },
         Commands::Consolidate {
             namespace,
@@ -476,6 +1056,7 @@ macro_rules! run_blocking_cmd {
             dry_run,
             min_memories,
             similarity,
+            output,
         } => {
             let config = config.clone();
             let namespace = namespace.clone();
@@ -487,6 +1068,7 @@ macro_rules! run_blocking_cmd {
                     dry_run,
                     min_memories,
                     similarity,
+                    &output,
                 )
                 .map_err(|e| e.to_string())
             })
@@ -506,20 +1088,34 @@ macro_rules! run_blocking_cmd {
                     .map_err(|e| e.to_string())
             })
         },
-        Commands::Config { show, set } => run_blocking_cmd!(move || {
+        // `config validate` is handled in `main` before the config is loaded
+        Commands::Config { show, set, .. } => run_blocking_cmd!(move || {
             commands::cmd_config(config, show, set).map_err(|e| e.to_string())
         }),
         Commands::Serve {
             transport,
+            socket,
             port,
             health_no_auth,
-        } => cmd_serve(transport, port, health_no_auth).await,
-        Commands::Hook { event } => {
+            rest,
+        } => cmd_serve(transport, socket, port, health_no_auth, rest).await,
+        Commands::Hook { action } => {
             let config = config.clone();
-            run_blocking_cmd!(move || commands::cmd_hook(event, &config).map_err(|e| e.to_string()))
+            run_blocking_cmd!(move || {
+                commands::cmd_hook(action, &config).map_err(|e| e.to_string())
+            })
+        },
+        Commands::Context { action } => {
+            let config = config.clone();
+            run_blocking_cmd!(move || {
+                commands::cmd_context(&config, action).map_err(|e| e.to_string())
+            })
         },
         Commands::Prompt { action } => {
-            run_blocking_cmd!(move || { commands::cmd_prompt(action).map_err(|e| e.to_string()) })
+            let config = config.clone();
+            run_blocking_cmd!(move || {
+                commands::cmd_prompt(action, &config).map_err(|e| e.to_string())
+            })
         },
         Commands::Namespaces { format, verbose } => run_blocking_cmd!(move || {
             use std::str::FromStr;
@@ -527,6 +1123,17 @@ macro_rules! run_blocking_cmd {
             let format = NamespacesOutputFormat::from_str(&format).unwrap_or_default();
             cmd_namespaces(format, verbose).map_err(|e| e.to_string())
         }),
+        Commands::SuggestNamespace {
+            text,
+            limit,
+            format,
+        } => {
+            let config = config.clone();
+            run_blocking_cmd!(move || {
+                commands::cmd_suggest_namespace(&config, &text, limit, &format)
+                    .map_err(|e| e.to_string())
+            })
+        },
         Commands::Migrate { action } => run_blocking_cmd!(move || {
             match action {
                 MigrateAction::Embeddings {
@@ -547,18 +1154,47 @@ macro_rules! run_blocking_cmd {
         }),
         Commands::Gc {
             dry_run,
+            all,
             purge,
             older_than,
         } => run_blocking_cmd!(move || {
-            subcog::cli::gc::execute(dry_run, purge, older_than).map_err(|e| e.to_string())
+            if all {
+                subcog::cli::gc::execute_all(dry_run).map_err(|e| e.to_string())
+            } else {
+                subcog::cli::gc::execute(dry_run, purge, older_than).map_err(|e| e.to_string())
+            }
+        }),
+        Commands::Prune {
+            auto_captured,
+            confidence_below,
+            older_than,
+            include_manual,
+            force,
+            dry_run,
+        } => run_blocking_cmd!(move || {
+            subcog::cli::prune::execute(subcog::cli::prune::PruneArgs {
+                auto_captured,
+                confidence_below,
+                older_than,
+                include_manual,
+                mode: subcog::cli::prune::PruneMode::from_flags(force, dry_run),
+            })
+            .map_err(|e| e.to_string())
         }),
         Commands::Delete {
             ids,
+            all,
+            namespace,
             hard,
             force,
             dry_run,
         } => run_blocking_cmd!(move || {
-            subcog::cli::delete::execute(ids, hard, force, dry_run).map_err(|e| e.to_string())
+            let namespace = namespace.filter(|_| all);
+            subcog::cli::delete::execute(ids, namespace, hard, force, dry_run)
+                .map_err(|e| e.to_string())
+        }),
+        Commands::Restore { ids } => run_blocking_cmd!(move || {
+            subcog::cli::delete::restore(ids).map_err(|e| e.to_string())
         }),
         Commands::Graph { action } => {
             let config = config.clone();
@@ -566,27 +1202,67 @@ macro_rules! run_blocking_cmd {
                 commands::cmd_graph(&config, action).map_err(|e| e.to_string())
             })
         },
+        Commands::Pin { id } => {
+            run_blocking_cmd!(move || { commands::cmd_pin(&id, true).map_err(|e| e.to_string()) })
+        },
+        Commands::Unpin { id } => {
+            run_blocking_cmd!(move || { commands::cmd_pin(&id, false).map_err(|e| e.to_string()) })
+        },
+        Commands::Update {
+            id,
+            content,
+            tags,
+            namespace,
+            status,
+        } => run_blocking_cmd!(move || {
+            commands::cmd_update(
+                &id,
+                content,
+                tags.as_deref(),
+                namespace.as_deref(),
+                status.as_deref(),
+            )
+            .map_err(|e| e.to_string())
+        }),
+        Commands::Tag { action } => {
+            run_blocking_cmd!(move || { commands::cmd_tag(action).map_err(|e| e.to_string()) })
+        },
+        Commands::Verify { fix } => {
+            run_blocking_cmd!(move || { commands::cmd_verify(fix).map_err(|e| e.to_string()) })
+        },
         Commands::Webhook { action } => {
             run_blocking_cmd!(move || { commands::cmd_webhook(action).map_err(|e| e.to_string()) })
         },
         Commands::Import {
             file,
+            from_directory,
             format,
             namespace,
             domain,
             skip_duplicates,
+            merge_strategy,
             dry_run,
+            verify,
+            key,
+            progress_interval,
+            quiet,
         } => {
             let service_config = subcog::config::Config::from(config.clone());
+            let verify_key = if verify { key } else { None };
             run_blocking_cmd!(move || {
                 commands::cmd_import(
                     &service_config,
                     file,
+                    from_directory,
                     format,
                     namespace,
                     domain,
                     skip_duplicates,
+                    merge_strategy,
                     dry_run,
+                    verify_key,
+                    progress_interval,
+                    quiet,
                 )
                 .map_err(|e| e.to_string())
             })
@@ -597,13 +1273,46 @@ macro_rules! run_blocking_cmd {
             filter,
             limit,
             domain,
+            fields,
+            sign,
+            key,
+            anonymize,
+            redact_pii,
+            incremental,
+            since_last,
+            reset_checkpoint,
+            progress_interval,
+            quiet,
         } => {
             let config = config.clone();
+            let sign_key = if sign { key } else { None };
             run_blocking_cmd!(move || {
-                commands::cmd_export(&config, output, format, filter, limit, domain)
-                    .map_err(|e| e.to_string())
+                commands::cmd_export(
+                    &config,
+                    output,
+                    format,
+                    filter,
+                    limit,
+                    domain,
+                    fields,
+                    sign_key,
+                    anonymize,
+                    redact_pii,
+                    incremental,
+                    since_last,
+                    reset_checkpoint,
+                    progress_interval,
+                    quiet,
+                )
+                .map_err(|e| e.to_string())
             })
         },
+        Commands::Schema { kind } => {
+            run_blocking_cmd!(move || { commands::cmd_schema(&kind).map_err(|e| e.to_string()) })
+        },
+        Commands::Rest { action } => {
+            run_blocking_cmd!(move || { commands::cmd_rest(&action).map_err(|e| e.to_string()) })
+        },
     }
 }
 
@@ -669,51 +1378,76 @@ async fn run_blocking<F>(
 /// subcog status
 /// ```
 fn load_config(path: Option<&str>) -> Result<SubcogConfig, Box<dyn std::error::Error>> {
-    // Priority 1: CLI argument
-    if let Some(config_path) = path {
-        return SubcogConfig::load_from_file(std::path::Path::new(config_path))
-            .map_err(std::convert::Into::into);
-    }
-
-    // Priority 2: Environment variable
-    if let Ok(config_path) = std::env::var("SUBCOG_CONFIG_PATH")
-        && !config_path.trim().is_empty()
-    {
-        return SubcogConfig::load_from_file(std::path::Path::new(&config_path))
-            .map_err(std::convert::Into::into);
+    // Priorities 1 and 2: CLI argument, then environment variable
+    if let Some(config_path) = explicit_config_path(path) {
+        return SubcogConfig::load_from_file(&config_path).map_err(std::convert::Into::into);
     }
 
     // Priority 3: Default locations
     Ok(SubcogConfig::load_default())
 }
 
+/// Returns the config file chosen by `--config` or `SUBCOG_CONFIG_PATH`, if any.
+fn explicit_config_path(path: Option<&str>) -> Option<std::path::PathBuf> {
+    if let Some(config_path) = path {
+        return Some(std::path::PathBuf::from(config_path));
+    }
+    std::env::var("SUBCOG_CONFIG_PATH")
+        .ok()
+        .filter(|config_path| !config_path.trim().is_empty())
+        .map(std::path::PathBuf::from)
+}
+
 /// Serve command.
 async fn cmd_serve(
     transport: String,
+    socket: Option<PathBuf>,
     port: u16,
     health_no_auth: bool,
+    rest: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     // Set instance label for metrics to prevent MCP from overwriting hook metrics
     observability::set_instance_label("mcp");
 
     let transport_type = match transport.as_str() {
         "http" => Transport::Http,
+        "unix" => Transport::Unix,
+        "sse" => Transport::Sse,
         _ => Transport::Stdio,
     };
+    if rest && !matches!(transport_type, Transport::Http | Transport::Sse) {
+        return Err("--rest requires --transport http or sse".into());
+    }
+    if rest && !cfg!(feature = "rest") {
+        return Err(subcog::Error::FeatureNotEnabled("rest".to_string()).into());
+    }
 
-    // Start webhook service if configured
-    let _webhook_handle = start_webhook_service();
+    // Start webhooks and notifiers if configured
+    let _notification_handle = start_notification_service();
+    let _gc_handle = start_gc_scheduler();
 
     let mut server = McpServer::new()
         .with_transport(transport_type)
         .with_port(port);
+    if let Some(socket) = socket {
+        server = server.with_socket_path(socket);
+    }
 
     #[cfg(feature = "http")]
-    if matches!(transport_type, Transport::Http) {
-        server = server
-            .with_jwt_from_env()
-            .map_err(|e| e.to_string())?
-            .with_health_no_auth(health_no_auth);
+    if matches!(transport_type, Transport::Http | Transport::Sse) {
+        // A static token replaces JWT unless a JWT secret is also set
+        let auth_token = SubcogConfig::load_default().mcp.auth_token;
+        if let Some(token) = &auth_token {
+            server = server.with_auth_token(token).map_err(|e| e.to_string())?;
+        }
+        if auth_token.is_none() || std::env::var_os("SUBCOG_MCP_JWT_SECRET").is_some() {
+            server = server.with_jwt_from_env().map_err(|e| e.to_string())?;
+        }
+        server = server.with_health_no_auth(health_no_auth);
+    }
+    #[cfg(feature = "rest")]
+    {
+        server = server.with_rest(rest);
     }
 
     server.start().await.map_err(|e| e.to_string())?;
@@ -721,10 +1455,56 @@ async fn cmd_serve(
     Ok(())
 }
 
-/// Starts the webhook service if webhooks are configured.
+/// Starts the unified GC scheduler if `[gc] interval` is configured.
+///
+/// Returns a join handle for the background task, or None if no interval is set.
+fn start_gc_scheduler() -> Option<tokio::task::JoinHandle<()>> {
+    let interval_secs = SubcogConfig::load_default().gc.interval_secs?;
+    tracing::info!(interval_secs, "Starting GC scheduler");
+
+    Some(tokio::spawn(async move {
+        let mut interval = tokio::time::interval(std::time::Duration::from_secs(interval_secs));
+        // The first tick completes immediately; skip it so GC runs after one interval
+        interval.tick().await;
+        loop {
+            interval.tick().await;
+            match tokio::task::spawn_blocking(|| subcog::cli::gc::run_all(false)).await {
+                Ok(Ok(report)) => tracing::info!(summary = %report.summary(), "Scheduled GC run"),
+                Ok(Err(e)) => tracing::warn!(error = %e, "Scheduled GC run failed"),
+                Err(e) => tracing::warn!(error = %e, "Scheduled GC task failed"),
+            }
+        }
+    }))
+}
+
+/// Builds the notification service from the configured notifiers and webhooks.
+fn notification_service(config: &SubcogConfig) -> NotificationService {
+    let notifications = NotificationService::from_config(&config.notifiers, DomainScope::Project);
+    match load_webhook_service() {
+        Some(webhooks) => notifications.with_notifier(Arc::new(webhooks.create_dispatcher())),
+        None => notifications,
+    }
+}
+
+/// Starts the notification service if webhooks or notifiers are configured.
 ///
-/// Returns a join handle for the background task, or None if no webhooks are configured.
-fn start_webhook_service() -> Option<tokio::task::JoinHandle<()>> {
+/// Returns a join handle for the background task, or None if nothing is configured.
+fn start_notification_service() -> Option<tokio::task::JoinHandle<()>> {
+    let notifications = notification_service(&SubcogConfig::load_default());
+    if notifications.is_empty() {
+        tracing::debug!("No webhooks or notifiers configured");
+        return None;
+    }
+
+    tracing::info!(
+        notifiers = notifications.len(),
+        "Starting notification service"
+    );
+    Some(notifications.start())
+}
+
+/// Loads the webhook service if webhooks are configured.
+fn load_webhook_service() -> Option<WebhookService> {
     // Get user data directory for audit database
     let data_dir = match subcog::storage::get_user_data_dir() {
         Ok(dir) => dir,
@@ -747,15 +1527,10 @@ fn start_webhook_service() -> Option<tokio::task::JoinHandle<()>> {
         },
     };
 
-    let webhook_count = service.webhook_count();
-    let enabled_count = service.enabled_webhook_count();
-
     tracing::info!(
-        total = webhook_count,
-        enabled = enabled_count,
-        "Starting webhook service"
+        total = service.webhook_count(),
+        enabled = service.enabled_webhook_count(),
+        "Loaded webhooks"
     );
-
-    // Start the webhook dispatcher as a background task
-    Some(service.start())
+    Some(service)
 }
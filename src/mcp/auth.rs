@@ -3,6 +3,9 @@
 //! Provides bearer token validation for the MCP HTTP server.
 //! The stdio transport does NOT require authentication.
 //!
+//! A single-user deployment can instead set a static token with
+//! `[mcp] auth_token` or `SUBCOG_MCP_TOKEN`; see [`TokenAuthenticator`].
+//!
 //! # Configuration
 //!
 //! Set these environment variables for JWT validation:
@@ -163,8 +166,10 @@ impl ToolAuthorization {
         ("prompt_delete", "write"),
         // Read operations
         ("subcog_recall", "read"),
+        ("subcog_facets", "read"),
         ("subcog_status", "read"),
         ("subcog_namespaces", "read"),
+        ("subcog_suggest_namespace", "read"),
         ("prompt_understanding", "read"),
         ("prompt_list", "read"),
         ("prompt_get", "read"),
@@ -172,6 +177,8 @@ impl ToolAuthorization {
         // Admin operations
         ("subcog_sync", "admin"),
         ("subcog_reindex", "admin"),
+        ("subcog_export", "admin"),
+        ("subcog_import", "admin"),
     ];
 
     /// Returns the required scope for a tool, or `None` if the tool is unknown.
@@ -183,8 +190,9 @@ impl ToolAuthorization {
     ///
     /// Tool scope mapping:
     /// - `subcog_capture`, `subcog_enrich`, `subcog_consolidate`: "write"
-    /// - `subcog_recall`, `subcog_status`, `subcog_namespaces`, `prompt_understanding`: "read"
-    /// - `subcog_sync`, `subcog_reindex`: "admin"
+    /// - `subcog_recall`, `subcog_facets`, `subcog_status`, `subcog_namespaces`,
+    ///   `prompt_understanding`: "read"
+    /// - `subcog_sync`, `subcog_reindex`, `subcog_export`, `subcog_import`: "admin"
     /// - `prompt_save`, `prompt_delete`: "write"
     /// - `prompt_list`, `prompt_get`, `prompt_run`: "read"
     /// - Unknown tools: `None` (explicit deny) or "admin" if `allow_unknown_with_admin`
@@ -393,6 +401,89 @@ pub fn validate_header(&self, auth_header: &str) -> Result<Claims> {
     }
 }
 
+/// Subject of the claims granted to the static bearer token.
+pub const STATIC_TOKEN_SUBJECT: &str = "token";
+
+/// Static bearer token authenticator.
+///
+/// Accepts `Authorization: Bearer <token>` when the token equals the
+/// configured one and grants all scopes. Tokens are compared through their
+/// SHA-256 digests in constant time, so neither the content nor the length of
+/// the configured token leaks through response timing.
+#[derive(Clone)]
+pub struct TokenAuthenticator {
+    /// SHA-256 digest of the configured token.
+    digest: Vec<u8>,
+}
+
+impl fmt::Debug for TokenAuthenticator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenAuthenticator").finish_non_exhaustive()
+    }
+}
+
+impl TokenAuthenticator {
+    /// Creates an authenticator for `token`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is empty.
+    pub fn new(token: &str) -> Result<Self> {
+        if token.trim().is_empty() {
+            return Err(Error::InvalidInput(
+                "MCP auth token must not be empty".to_string(),
+            ));
+        }
+        Ok(Self {
+            digest: token_digest(token),
+        })
+    }
+
+    /// Validates a bearer token and returns claims with the wildcard scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token does not match.
+    pub fn validate(&self, token: &str) -> Result<Claims> {
+        if !constant_time_eq(&self.digest, &token_digest(token)) {
+            return Err(Error::Unauthorized("Invalid token".to_string()));
+        }
+        Ok(Claims {
+            sub: STATIC_TOKEN_SUBJECT.to_string(),
+            exp: 0,
+            iat: 0,
+            iss: None,
+            aud: None,
+            scopes: vec!["*".to_string()],
+        })
+    }
+
+    /// Extracts and validates a bearer token from an Authorization header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the header format is invalid or the token does not
+    /// match.
+    pub fn validate_header(&self, auth_header: &str) -> Result<Claims> {
+        let token = auth_header.strip_prefix("Bearer ").ok_or_else(|| {
+            Error::Unauthorized("Invalid Authorization header format".to_string())
+        })?;
+
+        self.validate(token)
+    }
+}
+
+/// Hashes a token so comparisons run over fixed-length digests.
+fn token_digest(token: &str) -> Vec<u8> {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(token.as_bytes()).to_vec()
+}
+
+/// Constant-time comparison of two equal-length digests.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -606,6 +697,8 @@ fn test_tool_authorization_required_scopes() {
         // Admin operations
         assert_eq!(auth.required_scope("subcog_sync"), Some("admin"));
         assert_eq!(auth.required_scope("subcog_reindex"), Some("admin"));
+        assert_eq!(auth.required_scope("subcog_export"), Some("admin"));
+        assert_eq!(auth.required_scope("subcog_import"), Some("admin"));
 
         // Unknown tools return None (explicitly denied by default)
         assert_eq!(auth.required_scope("unknown_tool"), None);
@@ -761,6 +854,22 @@ fn test_entropy_validation_weak_pattern_still_fails() {
         assert!(result.unwrap_err().contains("weak pattern"));
     }
 
+    #[test]
+    fn test_token_authenticator() {
+        let authenticator = TokenAuthenticator::new("static-test-token").unwrap();
+
+        let claims = authenticator
+            .validate_header("Bearer static-test-token")
+            .unwrap();
+        assert_eq!(claims.sub, STATIC_TOKEN_SUBJECT);
+        assert!(claims.has_scope("admin"));
+
+        assert!(authenticator.validate("static-test-toke").is_err());
+        assert!(authenticator.validate("").is_err());
+        assert!(authenticator.validate_header("static-test-token").is_err());
+        assert!(TokenAuthenticator::new("  ").is_err());
+    }
+
     #[test]
     fn test_entropy_validation_special_chars_count() {
         // Verify special chars include base64 characters (+, /, =)
@@ -0,0 +1,483 @@
+//! JSON-RPC 2.0 batch support for line-delimited transports.
+//!
+//! rmcp reads and writes one JSON-RPC message per line and rejects batch
+//! arrays. [`batching`] sits between the raw transport and rmcp:
+//!
+//! ```text
+//! stdin ──▶ split batch into single messages ──▶ rmcp
+//! stdout ◀── reassemble batch responses     ◀── rmcp
+//! ```
+//!
+//! Responses to batched requests are held back until every request of the
+//! batch has been answered, then written as one array in request order.
+//! rmcp may answer the requests of a batch in any order; each response is
+//! placed in the slot of its request. Batched requests are forwarded under
+//! tracker-assigned IDs (`subcog-batch-<n>`), so they cannot collide with
+//! each other or with single requests in flight, and responses get the
+//! client's original ID back. A batch still incomplete after
+//! [`BATCH_RESPONSE_TIMEOUT`], or when the server stops writing, is flushed
+//! with an `Internal error` in each missing slot.
+//!
+//! Notifications in a batch get no response, and a batch of notifications
+//! only produces no output at all. Per the JSON-RPC 2.0 specification an
+//! empty batch is answered with a single `Invalid Request` error, and batch
+//! members that are not objects get an `Invalid Request` error in their slot.
+
+use serde_json::{Value, json};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader, DuplexStream};
+use tokio::sync::Mutex;
+
+/// Buffer size of the pipes between the transport and rmcp.
+const PIPE_CAPACITY: usize = 64 * 1024;
+
+/// JSON-RPC error code for an invalid request object.
+const INVALID_REQUEST: i64 = -32600;
+
+/// JSON-RPC error code for an internal error.
+const INTERNAL_ERROR: i64 = -32603;
+
+/// Prefix of the IDs batched requests are forwarded under.
+const BATCH_ID_PREFIX: &str = "subcog-batch-";
+
+/// How long a batch waits for its responses before the missing ones are
+/// answered with an error.
+pub const BATCH_RESPONSE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// How often incomplete batches are checked against the timeout.
+const EXPIRY_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Returns the `Invalid Request` error response with a null ID.
+fn invalid_request() -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "error": { "code": INVALID_REQUEST, "message": "Invalid Request" },
+        "id": null,
+    })
+}
+
+/// Returns the error response for a batched request that was never answered.
+fn no_response(id: &Value) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "error": { "code": INTERNAL_ERROR, "message": "Internal error: no response from server" },
+        "id": id,
+    })
+}
+
+/// Result of splitting an incoming line.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Split {
+    /// Single messages to forward to the server, in order.
+    pub forward: Vec<String>,
+    /// Response to write back immediately without involving the server.
+    pub respond: Option<String>,
+}
+
+/// A batched request awaiting its response.
+#[derive(Debug)]
+struct Waiting {
+    /// Serialized ID the request was forwarded under.
+    forwarded: String,
+    /// ID the client sent.
+    original: Value,
+    /// Slot of the response in the batch.
+    slot: usize,
+}
+
+/// A batch whose responses are still being collected.
+#[derive(Debug)]
+struct PendingBatch {
+    /// Response per request, in request order.
+    responses: Vec<Option<Value>>,
+    /// Requests still awaiting a response.
+    waiting: Vec<Waiting>,
+    /// When the batch was received.
+    received: Instant,
+}
+
+impl PendingBatch {
+    /// Returns the response array once every request has been answered.
+    fn take_if_complete(&mut self) -> Option<Value> {
+        if !self.waiting.is_empty() {
+            return None;
+        }
+        Some(Value::Array(
+            self.responses.iter_mut().filter_map(Option::take).collect(),
+        ))
+    }
+
+    /// Answers every request still waiting with an error and returns the
+    /// response array.
+    fn flush(&mut self) -> Value {
+        for waiting in self.waiting.drain(..) {
+            self.responses[waiting.slot] = Some(no_response(&waiting.original));
+        }
+        Value::Array(self.responses.iter_mut().filter_map(Option::take).collect())
+    }
+}
+
+/// Splits incoming batches and reassembles their responses.
+#[derive(Debug, Default)]
+pub struct BatchTracker {
+    /// Batches awaiting responses, oldest first.
+    pending: VecDeque<PendingBatch>,
+    /// Sequence number of the next forwarded batch request.
+    next_id: u64,
+}
+
+impl BatchTracker {
+    /// Creates a tracker with no pending batches.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Splits an incoming line into the messages to forward to the server.
+    ///
+    /// Lines that are not a JSON array are forwarded unchanged.
+    pub fn split(&mut self, line: &str) -> Split {
+        let Ok(Value::Array(items)) = serde_json::from_str::<Value>(line) else {
+            return Split {
+                forward: vec![line.to_string()],
+                respond: None,
+            };
+        };
+        if items.is_empty() {
+            return Split {
+                forward: Vec::new(),
+                respond: Some(invalid_request().to_string()),
+            };
+        }
+
+        let mut split = Split::default();
+        let mut batch = PendingBatch {
+            responses: Vec::new(),
+            waiting: Vec::new(),
+            received: Instant::now(),
+        };
+        for mut item in items {
+            if !item.is_object() {
+                batch.responses.push(Some(invalid_request()));
+                continue;
+            }
+            self.track(&mut batch, &mut item);
+            split.forward.push(item.to_string());
+        }
+
+        // A batch of only notifications returns nothing
+        if !batch.responses.is_empty() {
+            match batch.take_if_complete() {
+                Some(responses) => split.respond = Some(responses.to_string()),
+                None => self.pending.push_back(batch),
+            }
+        }
+        split
+    }
+
+    /// Gives a batched request a slot in `batch` and its forwarded ID.
+    ///
+    /// Requests carry both a method and an ID; notifications (and client
+    /// responses) get no slot in the response array and keep their ID.
+    fn track(&mut self, batch: &mut PendingBatch, item: &mut Value) {
+        let Some(object) = item.as_object_mut() else {
+            return;
+        };
+        if !object.contains_key("method") {
+            return;
+        }
+        let Some(original) = object.get("id").cloned() else {
+            return;
+        };
+        let forwarded = Value::String(format!("{BATCH_ID_PREFIX}{}", self.next_id));
+        self.next_id += 1;
+        batch.waiting.push(Waiting {
+            forwarded: forwarded.to_string(),
+            original,
+            slot: batch.responses.len(),
+        });
+        batch.responses.push(None);
+        object.insert("id".to_string(), forwarded);
+    }
+
+    /// Routes an outgoing line from the server.
+    ///
+    /// Returns the line to write, or `None` while the line is held back as
+    /// part of an incomplete batch. When the last response of a batch
+    /// arrives, the whole batch is returned as one array.
+    pub fn collect(&mut self, line: &str) -> Option<String> {
+        let Some(id) = response_id(line) else {
+            return Some(line.to_string());
+        };
+        let Some(index) = self
+            .pending
+            .iter()
+            .position(|batch| batch.waiting.iter().any(|w| w.forwarded == id))
+        else {
+            return Some(line.to_string());
+        };
+
+        let batch = &mut self.pending[index];
+        let position = batch.waiting.iter().position(|w| w.forwarded == id)?;
+        let waiting = batch.waiting.remove(position);
+        let mut response: Value =
+            serde_json::from_str(line).unwrap_or_else(|_| no_response(&waiting.original));
+        if let Some(object) = response.as_object_mut() {
+            object.insert("id".to_string(), waiting.original);
+        }
+        batch.responses[waiting.slot] = Some(response);
+        let responses = batch.take_if_complete()?;
+        self.pending.remove(index);
+        Some(responses.to_string())
+    }
+
+    /// Flushes batches that have waited at least `timeout`, answering their
+    /// missing requests with an error.
+    ///
+    /// Returns the response arrays to write, oldest batch first.
+    pub fn expire(&mut self, timeout: Duration) -> Vec<String> {
+        let mut flushed = Vec::new();
+        while self
+            .pending
+            .front()
+            .is_some_and(|batch| batch.received.elapsed() >= timeout)
+        {
+            if let Some(mut batch) = self.pending.pop_front() {
+                flushed.push(batch.flush().to_string());
+            }
+        }
+        flushed
+    }
+}
+
+/// Returns the serialized ID of a response line (not of a request).
+fn response_id(line: &str) -> Option<String> {
+    let value: Value = serde_json::from_str(line).ok()?;
+    let object = value.as_object()?;
+    if object.contains_key("method") {
+        return None;
+    }
+    object.get("id").map(Value::to_string)
+}
+
+/// Wraps a line-delimited JSON-RPC transport with batch support.
+///
+/// Returns the reader and writer to hand to rmcp. Two background tasks pump
+/// lines between `input`/`output` and rmcp, splitting batches on the way in
+/// and reassembling their responses on the way out. The reader reaches end
+/// of file when `input` does.
+pub fn batching<R, W>(input: R, output: W) -> (DuplexStream, DuplexStream)
+where
+    R: AsyncRead + Unpin + Send + 'static,
+    W: AsyncWrite + Unpin + Send + 'static,
+{
+    let (server_reader, feed) = tokio::io::duplex(PIPE_CAPACITY);
+    let (server_writer, drain) = tokio::io::duplex(PIPE_CAPACITY);
+    let tracker = Arc::new(Mutex::new(BatchTracker::new()));
+    let output = Arc::new(Mutex::new(output));
+
+    tokio::spawn(pump_requests(
+        input,
+        feed,
+        Arc::clone(&tracker),
+        Arc::clone(&output),
+    ));
+    tokio::spawn(pump_responses(drain, tracker, output));
+
+    (server_reader, server_writer)
+}
+
+/// Forwards client lines to rmcp, splitting batches.
+async fn pump_requests<R, W>(
+    input: R,
+    mut feed: DuplexStream,
+    tracker: Arc<Mutex<BatchTracker>>,
+    output: Arc<Mutex<W>>,
+) where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let mut lines = BufReader::new(input).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let split = tracker.lock().await.split(&line);
+        for message in split.forward {
+            if write_line(&mut feed, &message).await.is_err() {
+                return;
+            }
+        }
+        let Some(response) = split.respond else {
+            continue;
+        };
+        if write_line(&mut *output.lock().await, &response)
+            .await
+            .is_err()
+        {
+            return;
+        }
+    }
+}
+
+/// Writes rmcp's lines to the client, reassembling batch responses and
+/// flushing batches that time out or outlive the server.
+async fn pump_responses<W>(
+    drain: DuplexStream,
+    tracker: Arc<Mutex<BatchTracker>>,
+    output: Arc<Mutex<W>>,
+) where
+    W: AsyncWrite + Unpin,
+{
+    let mut lines = BufReader::new(drain).lines();
+    let mut expiry = tokio::time::interval(EXPIRY_CHECK_INTERVAL);
+    loop {
+        let messages: Vec<String> = tokio::select! {
+            line = lines.next_line() => {
+                let Ok(Some(line)) = line else {
+                    break;
+                };
+                tracker.lock().await.collect(&line).into_iter().collect()
+            },
+            _ = expiry.tick() => tracker.lock().await.expire(BATCH_RESPONSE_TIMEOUT),
+        };
+        if write_lines(&output, &messages).await.is_err() {
+            return;
+        }
+    }
+
+    // The server stopped writing: answer whatever is still pending
+    let remaining = tracker.lock().await.expire(Duration::ZERO);
+    let _ = write_lines(&output, &remaining).await;
+}
+
+/// Writes several messages, one per line.
+async fn write_lines<W: AsyncWrite + Unpin>(
+    output: &Mutex<W>,
+    messages: &[String],
+) -> std::io::Result<()> {
+    if messages.is_empty() {
+        return Ok(());
+    }
+    let mut output = output.lock().await;
+    for message in messages {
+        write_line(&mut *output, message).await?;
+    }
+    drop(output);
+    Ok(())
+}
+
+/// Writes one newline-terminated message and flushes it.
+async fn write_line<W: AsyncWrite + Unpin>(writer: &mut W, message: &str) -> std::io::Result<()> {
+    writer.write_all(message.as_bytes()).await?;
+    writer.write_all(b"\n").await?;
+    writer.flush().await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(id: u64) -> String {
+        json!({ "jsonrpc": "2.0", "id": id, "result": { "n": id } }).to_string()
+    }
+
+    #[test]
+    fn test_single_messages_pass_through() {
+        let mut tracker = BatchTracker::new();
+        let line = r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#;
+
+        assert_eq!(tracker.split(line).forward, vec![line.to_string()]);
+        assert_eq!(tracker.collect(&response(1)), Some(response(1)));
+        assert!(tracker.pending.is_empty());
+    }
+
+    /// Returns a response to the forwarded message `forwarded`.
+    fn answer(forwarded: &str) -> String {
+        let request: Value = serde_json::from_str(forwarded).unwrap();
+        json!({ "jsonrpc": "2.0", "id": request["id"], "result": {} }).to_string()
+    }
+
+    #[test]
+    fn test_batch_responses_are_reassembled_in_request_order() {
+        let mut tracker = BatchTracker::new();
+        let split = tracker.split(
+            r#"[{"jsonrpc":"2.0","id":1,"method":"tools/list"},
+                {"jsonrpc":"2.0","method":"notifications/progress"},
+                {"jsonrpc":"2.0","id":2,"method":"ping"}]"#,
+        );
+        assert_eq!(split.forward.len(), 3);
+        assert_eq!(split.respond, None);
+
+        // Responses arrive out of order
+        assert_eq!(tracker.collect(&answer(&split.forward[2])), None);
+        let batch: Value =
+            serde_json::from_str(&tracker.collect(&answer(&split.forward[0])).unwrap()).unwrap();
+        let ids: Vec<&Value> = batch.as_array().unwrap().iter().map(|r| &r["id"]).collect();
+        assert_eq!(ids, vec![&json!(1), &json!(2)]);
+        assert!(tracker.pending.is_empty());
+    }
+
+    #[test]
+    fn test_batch_ids_do_not_clash_with_single_requests() {
+        let mut tracker = BatchTracker::new();
+        let batch = tracker.split(r#"[{"jsonrpc":"2.0","id":1,"method":"tools/list"}]"#);
+        let single = tracker.split(r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#);
+
+        let forwarded: Value = serde_json::from_str(&batch.forward[0]).unwrap();
+        assert_eq!(forwarded["id"], json!("subcog-batch-0"));
+
+        // The single request's response passes through untouched
+        assert_eq!(
+            tracker.collect(&answer(&single.forward[0])),
+            Some(answer(&single.forward[0]))
+        );
+        assert_eq!(tracker.pending.len(), 1);
+
+        let responses: Value =
+            serde_json::from_str(&tracker.collect(&answer(&batch.forward[0])).unwrap()).unwrap();
+        assert_eq!(responses[0]["id"], json!(1));
+    }
+
+    #[test]
+    fn test_expire_answers_missing_responses() {
+        let mut tracker = BatchTracker::new();
+        let split = tracker.split(
+            r#"[{"jsonrpc":"2.0","id":"a","method":"ping"},
+                {"jsonrpc":"2.0","id":"b","method":"ping"}]"#,
+        );
+        assert_eq!(tracker.collect(&answer(&split.forward[0])), None);
+        assert!(tracker.expire(BATCH_RESPONSE_TIMEOUT).is_empty());
+
+        let flushed = tracker.expire(Duration::ZERO);
+        assert_eq!(flushed.len(), 1);
+        let responses: Value = serde_json::from_str(&flushed[0]).unwrap();
+        assert_eq!(responses[0]["id"], json!("a"));
+        assert_eq!(responses[1]["id"], json!("b"));
+        assert_eq!(responses[1]["error"]["code"], INTERNAL_ERROR);
+        assert!(tracker.pending.is_empty());
+    }
+
+    #[test]
+    fn test_empty_and_invalid_batches() {
+        let mut tracker = BatchTracker::new();
+
+        let empty = tracker.split("[]");
+        assert!(empty.forward.is_empty());
+        let error: Value = serde_json::from_str(&empty.respond.unwrap()).unwrap();
+        assert_eq!(error["error"]["code"], INVALID_REQUEST);
+        assert_eq!(error["id"], Value::Null);
+
+        let invalid = tracker.split("[1, 2]");
+        let errors: Value = serde_json::from_str(&invalid.respond.unwrap()).unwrap();
+        assert_eq!(errors.as_array().unwrap().len(), 2);
+
+        let notifications =
+            tracker.split(r#"[{"jsonrpc":"2.0","method":"notifications/initialized"}]"#);
+        assert_eq!(notifications.forward.len(), 1);
+        assert_eq!(notifications.respond, None);
+        assert!(tracker.pending.is_empty());
+    }
+}
@@ -61,11 +61,14 @@
 |------|-------------|
 | `subcog_capture` | Capture a memory |
 | `subcog_recall` | Search memories |
+| `subcog_facets` | Count matches per namespace and tag |
 | `subcog_status` | Check system status |
 | `subcog_namespaces` | List memory namespaces |
 | `subcog_consolidate` | Consolidate memories (LLM) |
 | `subcog_enrich` | Enrich a memory (LLM) |
 | `subcog_reindex` | Rebuild search index |
+| `subcog_export` | Export memories as JSON, YAML, CSV, Markdown or Parquet |
+| `subcog_import` | Import memories from JSON, YAML, CSV or Parquet |
 | `prompt_understanding` | Guidance for using Subcog MCP tools |
 
 ## Available MCP Resources
@@ -505,13 +508,27 @@
 }
 ```
 
+Pass `provider` and/or `model` to generate tags server-side with a
+specific LLM for this request instead of the configured `[llm]` provider:
+
+```json
+{
+  "tool": "subcog_enrich",
+  "arguments": {
+    "memory_id": "decisions_abc123",
+    "provider": "ollama",
+    "model": "llama3.2"
+  }
+}
+```
+
 ## LLM Provider Configuration
 
 Configure in `~/.config/subcog/config.toml`:
 
 ```toml
 [llm]
-provider = "anthropic" # or "openai", "ollama", "lmstudio"
+provider = "anthropic" # or "openai", "gemini", "ollama", "lmstudio"
 api_key = "${ANTHROPIC_API_KEY}"
 model = "claude-3-haiku-20240307"
 ```
@@ -43,18 +43,31 @@
 #![allow(clippy::match_same_arms)]
 
 mod auth;
+mod batch;
 mod dispatch;
 mod help_content;
+#[cfg(feature = "rest")]
+mod openapi;
 mod prompt_understanding;
 mod prompts;
 mod resources;
+#[cfg(feature = "rest")]
+mod rest;
 mod server;
 mod session;
+#[cfg(feature = "http")]
+mod sse;
 mod tool_types;
 mod tools;
+#[cfg(unix)]
+mod unix_socket;
 
-pub use auth::{Claims, JwtAuthenticator, JwtConfig};
+pub use auth::{Claims, JwtAuthenticator, JwtConfig, TokenAuthenticator};
+#[cfg(feature = "rest")]
+pub use openapi::openapi_spec;
 pub use prompts::{PromptArgument, PromptContent, PromptDefinition, PromptMessage, PromptRegistry};
 pub use resources::{HelpCategory, ResourceContent, ResourceDefinition, ResourceHandler};
+#[cfg(feature = "rest")]
+pub use rest::{REST_ROUTES, rest_router};
 pub use server::{McpServer, Transport};
-pub use tools::{ToolContent, ToolDefinition, ToolRegistry, ToolResult};
+pub use tools::{CustomToolHandler, ToolContent, ToolDefinition, ToolRegistry, ToolResult};
@@ -0,0 +1,399 @@
+//! OpenAPI 3 document for the REST API.
+//!
+//! The document is maintained by hand next to the routes in
+//! [`rest`](super::rest): every entry of
+//! [`REST_ROUTES`](super::rest::REST_ROUTES) has an operation here, which the
+//! tests check against the served router. It is served at
+//! `GET /openapi.json` and printed by `subcog rest openapi` for client
+//! generators.
+
+use super::rest::{MEMORIES_PATH, MEMORY_PATH, OPENAPI_PATH};
+use serde_json::{Value, json};
+
+/// OpenAPI version of the document.
+const OPENAPI_VERSION: &str = "3.0.3";
+
+/// Returns the OpenAPI 3 document for the REST API.
+#[must_use]
+pub fn openapi_spec() -> Value {
+    json!({
+        "openapi": OPENAPI_VERSION,
+        "info": {
+            "title": "Subcog REST API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Capture, recall, get and delete memories over JSON. \
+                Served by `subcog serve --transport http --rest`."
+        },
+        "security": [{ "bearerAuth": [] }],
+        "paths": {
+            MEMORIES_PATH: {
+                "post": {
+                    "operationId": "captureMemory",
+                    "summary": "Capture a memory",
+                    "description": "Requires the `write` scope.",
+                    "requestBody": {
+                        "required": true,
+                        "content": json_content("#/components/schemas/CaptureRequest")
+                    },
+                    "responses": {
+                        "201": response("Memory captured", "#/components/schemas/CaptureResponse"),
+                        "400": error_response("Invalid request or content blocked"),
+                        "401": error_response("Missing or invalid token"),
+                        "403": error_response("Missing `write` scope"),
+                        "429": error_response("Rate limit exceeded")
+                    }
+                },
+                "get": {
+                    "operationId": "recallMemories",
+                    "summary": "Recall memories",
+                    "description": "Searches memories matching `q`, or lists them when `q` \
+                        is omitted. Tombstoned memories are excluded. Requires the `read` scope.",
+                    "parameters": [
+                        query_parameter("q", "Search query", json!({ "type": "string" })),
+                        query_parameter(
+                            "namespace",
+                            "Namespace filter (e.g. `decisions`)",
+                            json!({ "type": "string" })
+                        ),
+                        query_parameter(
+                            "mode",
+                            "Search mode",
+                            json!({
+                                "type": "string",
+                                "enum": ["text", "vector", "hybrid"],
+                                "default": "hybrid"
+                            })
+                        ),
+                        query_parameter(
+                            "limit",
+                            "Maximum number of results",
+                            json!({
+                                "type": "integer",
+                                "minimum": 1,
+                                "maximum": 100,
+                                "default": 10
+                            })
+                        )
+                    ],
+                    "responses": {
+                        "200": response("Matching memories", "#/components/schemas/RecallResponse"),
+                        "400": error_response("Invalid parameters"),
+                        "401": error_response("Missing or invalid token"),
+                        "403": error_response("Missing `read` scope"),
+                        "429": error_response("Rate limit exceeded")
+                    }
+                }
+            },
+            MEMORY_PATH: {
+                "parameters": [{
+                    "name": "id",
+                    "in": "path",
+                    "required": true,
+                    "description": "Memory ID or URN",
+                    "schema": { "type": "string" }
+                }],
+                "get": {
+                    "operationId": "getMemory",
+                    "summary": "Get a memory",
+                    "description": "Requires the `read` scope.",
+                    "responses": {
+                        "200": response("The memory", "#/components/schemas/Memory"),
+                        "401": error_response("Missing or invalid token"),
+                        "403": error_response("Missing `read` scope"),
+                        "404": error_response("Memory not found"),
+                        "429": error_response("Rate limit exceeded")
+                    }
+                },
+                "delete": {
+                    "operationId": "deleteMemory",
+                    "summary": "Delete a memory",
+                    "description": "Tombstones the memory, or removes it permanently with \
+                        `hard=true`. Requires the `write` scope.",
+                    "parameters": [query_parameter(
+                        "hard",
+                        "Remove the memory permanently",
+                        json!({ "type": "boolean", "default": false })
+                    )],
+                    "responses": {
+                        "204": { "description": "Memory deleted" },
+                        "401": error_response("Missing or invalid token"),
+                        "403": error_response("Missing `write` scope"),
+                        "404": error_response("Memory not found"),
+                        "429": error_response("Rate limit exceeded")
+                    }
+                }
+            },
+            OPENAPI_PATH: {
+                "get": {
+                    "operationId": "getOpenApi",
+                    "summary": "OpenAPI document for this API",
+                    "responses": {
+                        "200": {
+                            "description": "The OpenAPI document",
+                            "content": { "application/json": { "schema": { "type": "object" } } }
+                        },
+                        "401": error_response("Missing or invalid token")
+                    }
+                }
+            }
+        },
+        "components": {
+            "securitySchemes": {
+                "bearerAuth": { "type": "http", "scheme": "bearer", "bearerFormat": "JWT" }
+            },
+            "schemas": schemas()
+        }
+    })
+}
+
+/// Returns the component schemas.
+fn schemas() -> Value {
+    json!({
+        "CaptureRequest": {
+            "type": "object",
+            "required": ["content"],
+            "additionalProperties": false,
+            "properties": {
+                "content": { "type": "string", "description": "Memory content" },
+                "namespace": {
+                    "type": "string",
+                    "description": "Namespace (default `decisions`)"
+                },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "source": { "type": "string", "description": "Source reference" },
+                "content_type": {
+                    "type": "string",
+                    "description": "`markdown`, `text`, `code` or `code:<lang>`; \
+                        detected if omitted"
+                }
+            }
+        },
+        "CaptureResponse": {
+            "type": "object",
+            "required": ["id", "urn", "content_modified"],
+            "properties": {
+                "id": { "type": "string" },
+                "urn": { "type": "string" },
+                "content_modified": {
+                    "type": "boolean",
+                    "description": "Whether secrets or PII were redacted"
+                }
+            }
+        },
+        "Memory": {
+            "type": "object",
+            "required": [
+                "id", "namespace", "domain", "status", "tags", "created_at", "updated_at", "content"
+            ],
+            "properties": {
+                "id": { "type": "string" },
+                "namespace": { "type": "string" },
+                "domain": { "type": "string" },
+                "status": { "type": "string" },
+                "tags": { "type": "array", "items": { "type": "string" } },
+                "source": { "type": "string", "nullable": true },
+                "content_type": { "type": "string", "nullable": true },
+                "pinned": { "type": "boolean" },
+                "commit": { "type": "string", "nullable": true },
+                "language": { "type": "string", "nullable": true },
+                "created_at": { "type": "integer", "format": "int64", "minimum": 0 },
+                "updated_at": { "type": "integer", "format": "int64", "minimum": 0 },
+                "content": { "type": "string" }
+            }
+        },
+        "RecallHit": {
+            "allOf": [
+                { "$ref": "#/components/schemas/Memory" },
+                {
+                    "type": "object",
+                    "required": ["score"],
+                    "properties": { "score": { "type": "number", "format": "float" } }
+                }
+            ]
+        },
+        "RecallResponse": {
+            "type": "object",
+            "required": ["count", "memories"],
+            "properties": {
+                "count": { "type": "integer", "minimum": 0 },
+                "memories": {
+                    "type": "array",
+                    "items": { "$ref": "#/components/schemas/RecallHit" }
+                }
+            }
+        },
+        "Error": {
+            "type": "object",
+            "required": ["error"],
+            "properties": {
+                "error": {
+                    "type": "object",
+                    "required": ["code", "message"],
+                    "properties": {
+                        "code": { "type": "integer" },
+                        "message": { "type": "string" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+/// Returns a JSON media type object for a schema reference.
+fn json_content(schema_ref: &str) -> Value {
+    json!({ "application/json": { "schema": { "$ref": schema_ref } } })
+}
+
+/// Returns a response object with a JSON body.
+fn response(description: &str, schema_ref: &str) -> Value {
+    json!({ "description": description, "content": json_content(schema_ref) })
+}
+
+/// Returns an error response object.
+fn error_response(description: &str) -> Value {
+    response(description, "#/components/schemas/Error")
+}
+
+/// Returns an optional query parameter object.
+fn query_parameter(name: &str, description: &str, schema: Value) -> Value {
+    json!({ "name": name, "in": "query", "description": description, "schema": schema })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mcp::rest::{REST_ROUTES, rest_router};
+    use crate::services::ServiceContainer;
+    use axum::Router;
+    use axum::body::Body;
+    use axum::http::{Method, Request, StatusCode};
+    use std::collections::BTreeSet;
+    use std::sync::Arc;
+    use tower::util::ServiceExt;
+
+    /// Methods that may appear in an OpenAPI path item.
+    const METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch"];
+
+    fn test_router(dir: &tempfile::TempDir) -> Router {
+        let services = ServiceContainer::builder()
+            .with_config(crate::config::Config::new().with_data_dir(dir.path()))
+            .with_index(Arc::new(
+                crate::storage::index::SqliteBackend::in_memory().unwrap(),
+            ))
+            .build()
+            .unwrap();
+        rest_router(Arc::new(services))
+    }
+
+    /// Returns the `(method, path)` of every documented operation.
+    fn documented_operations(spec: &Value) -> BTreeSet<(String, String)> {
+        let mut operations = BTreeSet::new();
+        for (path, item) in spec["paths"].as_object().unwrap() {
+            for method in METHODS {
+                if item.get(*method).is_some() {
+                    operations.insert(((*method).to_string(), path.clone()));
+                }
+            }
+        }
+        operations
+    }
+
+    /// Sends a request and returns the status and body.
+    async fn send(router: &Router, method: &str, path: &str) -> (StatusCode, Vec<u8>) {
+        let request = Request::builder()
+            .method(Method::from_bytes(method.to_uppercase().as_bytes()).unwrap())
+            .uri(path.replace("{id}", "missing"))
+            .body(Body::empty())
+            .unwrap();
+        let response = router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        (status, body.to_vec())
+    }
+
+    #[tokio::test]
+    async fn test_every_route_has_documented_operation() {
+        let routes: BTreeSet<(String, String)> = REST_ROUTES
+            .iter()
+            .map(|(method, path)| ((*method).to_string(), (*path).to_string()))
+            .collect();
+        assert_eq!(documented_operations(&openapi_spec()), routes);
+
+        // The router serves exactly the listed methods on each path: listed
+        // routes reach a handler, other methods are rejected by the router
+        let dir = tempfile::TempDir::new().unwrap();
+        let router = test_router(&dir);
+        let paths: BTreeSet<&str> = REST_ROUTES.iter().map(|(_, path)| *path).collect();
+        for path in paths {
+            for method in ["get", "post", "put", "patch", "delete"] {
+                let (status, body) = send(&router, method, path).await;
+                if routes.contains(&(method.to_string(), path.to_string())) {
+                    assert_ne!(status, StatusCode::METHOD_NOT_ALLOWED, "{method} {path}");
+                    assert!(
+                        status != StatusCode::NOT_FOUND || !body.is_empty(),
+                        "{method} {path} is not routed"
+                    );
+                } else {
+                    assert_eq!(
+                        status,
+                        StatusCode::METHOD_NOT_ALLOWED,
+                        "{method} {path} is served but not documented"
+                    );
+                }
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_served_document_is_valid_openapi() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let (status, body) = send(&test_router(&dir), "get", OPENAPI_PATH).await;
+        assert_eq!(status, StatusCode::OK);
+        let spec: Value = serde_json::from_slice(&body).unwrap();
+
+        assert!(spec["openapi"].as_str().unwrap().starts_with("3.0."));
+        assert!(spec["info"]["title"].is_string());
+        assert!(spec["info"]["version"].is_string());
+
+        let mut operation_ids = BTreeSet::new();
+        for (path, item) in spec["paths"].as_object().unwrap() {
+            assert!(path.starts_with('/'), "{path}");
+            for method in METHODS {
+                let Some(operation) = item.get(*method) else {
+                    continue;
+                };
+                let id = operation["operationId"].as_str().unwrap();
+                assert!(operation_ids.insert(id.to_string()), "duplicate {id}");
+                let responses = operation["responses"].as_object().unwrap();
+                assert!(!responses.is_empty(), "{id} has no responses");
+                for (code, response) in responses {
+                    assert!(code.parse::<u16>().is_ok(), "{id}: {code}");
+                    assert!(response["description"].is_string(), "{id}: {code}");
+                }
+            }
+            // Templated segments must be declared as path parameters
+            if path.contains("{id}") {
+                assert_eq!(item["parameters"][0]["in"], "path");
+                assert_eq!(item["parameters"][0]["required"], true);
+            }
+        }
+
+        // Every reference resolves to a component
+        let mut pending = vec![&spec];
+        while let Some(value) = pending.pop() {
+            match value {
+                Value::Object(map) => {
+                    if let Some(reference) = map.get("$ref").and_then(Value::as_str) {
+                        let pointer = reference.strip_prefix('#').unwrap();
+                        assert!(spec.pointer(pointer).is_some(), "unresolved {reference}");
+                    }
+                    pending.extend(map.values());
+                },
+                Value::Array(items) => pending.extend(items),
+                _ => {},
+            }
+        }
+    }
+}
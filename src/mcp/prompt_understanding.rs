@@ -82,7 +82,7 @@
 |------|-------------|
 | `subcog_capture` | Create a memory (required: content, namespace) |
 | `subcog_get` | Retrieve a memory by ID |
-| `subcog_update` | Update memory content and/or tags |
+| `subcog_update` | Update memory content, tags, namespace, or status in place |
 | `subcog_delete` | Delete a memory (soft by default, hard optional) |
 | `subcog_delete_all` | Bulk delete with filter (dry-run by default) |
 | `subcog_restore` | Restore a soft-deleted memory |
@@ -155,8 +155,8 @@
 
 | Command | Description |
 |---------|-------------|
-| `subcog import <file>` | Import memories from JSON, YAML, or CSV |
-| `subcog export <file>` | Export memories to JSON, YAML, CSV, or Parquet |
+| `subcog import <file>` | Import memories from JSON, YAML, CSV, or Parquet |
+| `subcog export <file>` | Export memories to JSON, YAML, CSV, Markdown, or Parquet |
 
 **Import options**:
 - `--format`: Force format (auto-detected from extension)
@@ -171,13 +171,21 @@
 - `--limit`: Maximum memories to export
 - `--domain`: Filter by domain
 
+The same operations are available as MCP tools:
+
+| Tool | Description |
+|------|-------------|
+| `subcog_export` | Export memories (`format`, `filter`, `limit`); returns the content, or writes `path` |
+| `subcog_import` | Import memories (`format`, `content` or `path`, `skip_duplicates`); returns `imported`, `skipped` and `failed` counts |
+
 **Supported formats**:
 | Format | Import | Export | Extension |
 |--------|--------|--------|-----------|
 | JSON | Yes | Yes | `.json`, `.ndjson`, `.jsonl` |
 | YAML | Yes | Yes | `.yaml`, `.yml` |
 | CSV | Yes | Yes | `.csv`, `.tsv` |
-| Parquet | No | Yes (feature-gated) | `.parquet`, `.pq` |
+| Markdown | No | Yes | `.md`, `.markdown` |
+| Parquet | Yes (feature-gated) | Yes (feature-gated) | `.parquet`, `.pq` |
 
 **Import file structure** (JSON example):
 ```json
@@ -567,7 +575,7 @@
 - `SUBCOG_DEDUP_ENABLED` - Enable deduplication
 - `SUBCOG_AUTO_EXTRACT_ENTITIES` - Enable entity extraction
 - `SUBCOG_CONSOLIDATION_ENABLED` - Enable consolidation
-- `SUBCOG_LLM_PROVIDER` - LLM provider (anthropic, openai, ollama, lmstudio)
+- `SUBCOG_LLM_PROVIDER` - LLM provider (anthropic, openai, gemini, ollama, lmstudio)
 
 See `~/.config/subcog/config.toml` for full configuration options.
 "#;
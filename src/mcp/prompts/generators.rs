@@ -272,6 +272,10 @@ pub fn generate_browse_prompt(arguments: &Value) -> Vec<PromptMessage> {
         ));
     }
 
+    prompt.push_str(
+        "For the namespace and tag counts, use the `mcp__plugin_subcog_subcog__subcog_facets` tool with the same query, filter, and limit plus `\"tags\": true` instead of counting the fetched memories.\n\n",
+    );
+
     prompt.push_str(&format!(
         "View mode: {view}\nShow top {top} items per facet.\n\n"
     ));
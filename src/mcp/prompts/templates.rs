@@ -308,9 +308,9 @@
 pub const BROWSE_SYSTEM_RESPONSE: &str = r"
 I'll create a memory browser dashboard for you. Let me fetch the memories using `mcp__plugin_subcog_subcog__subcog_recall`.
 
-I'll call the tool with the specified filter to get server-side filtered results, then compute:
-1. Namespace distribution with counts
-2. Tag frequency (top N most common)
+I'll call the tool with the specified filter to get server-side filtered results, and `mcp__plugin_subcog_subcog__subcog_facets` for the counts, then compute:
+1. Namespace distribution with counts (from the facets)
+2. Tag frequency (top N most common, from the facets)
 3. Time-based grouping (today, this week, this month, older)
 4. Status breakdown (active, archived)
 
@@ -43,6 +43,8 @@
 //! - `subcog://topics` - List all indexed topics with memory counts
 //! - `subcog://topics/{topic}` - Get memories for a specific topic
 //! - `subcog://namespaces` - List all namespaces with descriptions and signal words
+//! - `subcog://namespace/{ns}` - A namespace's memories, most recent first (paginated)
+//! - `subcog://namespace/{ns}/tags` - Tag counts for a namespace
 //!
 //! ## Summary Resources
 //! - `subcog://summaries` - List all consolidated memory summaries
@@ -62,6 +64,7 @@
 //! subcog://memory/abc123         # Specific memory by ID
 //! subcog://search/postgres       # Search for "postgres"
 //! subcog://topics/authentication # Memories about authentication
+//! subcog://namespace/decisions?offset=50 # Second page of decisions
 //! subcog://summaries             # List all consolidated summaries
 //! subcog://summaries/summary_123 # Get specific summary with sources
 //! ```
@@ -78,6 +81,15 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// Default page size for `subcog://namespace/{ns}`.
+const NAMESPACE_PAGE_SIZE: usize = 50;
+
+/// Largest page size accepted for `subcog://namespace/{ns}`.
+const MAX_NAMESPACE_PAGE_SIZE: usize = 500;
+
+/// Most memories read to build `subcog://namespace/{ns}/tags`.
+const TAG_FACET_SCAN_LIMIT: usize = 10_000;
+
 /// Handler for MCP resources (URN scheme).
 pub struct ResourceHandler {
     /// Help content by category.
@@ -401,6 +413,18 @@ fn search_topic_resource_definitions() -> Vec<ResourceDefinition> {
             "List all memory namespaces with descriptions and signal words",
             "application/json",
         ));
+        resources.push(Self::build_resource(
+            "subcog://namespace/{ns}",
+            "Namespace Memories",
+            "Memories in a namespace, most recent first (replace {ns}; ?offset=&limit= to page)",
+            "application/json",
+        ));
+        resources.push(Self::build_resource(
+            "subcog://namespace/{ns}/tags",
+            "Namespace Tags",
+            "Tag counts for a namespace (replace {ns})",
+            "application/json",
+        ));
 
         resources
     }
@@ -511,10 +535,11 @@ pub fn get_resource(&mut self, uri: &str) -> Result<ResourceContent> {
             "search" => self.get_search_resource(uri, &parts),
             "topics" => self.get_topics_resource(uri, &parts),
             "namespaces" => self.get_namespaces_resource(uri),
+            "namespace" => self.get_namespace_resource(uri, path),
             "summaries" => self.get_summaries_resource(uri, &parts),
             "_prompts" => self.get_aggregate_prompts_resource(uri),
             _ => Err(Error::InvalidInput(format!(
-                "Unknown resource type: {}. Valid: _, help, memory, project, user, org, search, topics, namespaces, summaries, _prompts",
+                "Unknown resource type: {}. Valid: _, help, memory, project, user, org, search, topics, namespaces, namespace, summaries, _prompts",
                 parts[0]
             ))),
         }
@@ -654,6 +679,7 @@ fn format_memory_response(
             "tags": memory.tags,
             "source": memory.source,
             "status": memory.status.as_str(),
+            "origin": memory.origin.as_str(),
             "created_at": memory.created_at,
             "updated_at": memory.updated_at,
         });
@@ -870,6 +896,125 @@ fn get_namespaces_resource(&self, uri: &str) -> Result<ResourceContent> {
         })
     }
 
+    /// Gets a namespace browsing resource.
+    ///
+    /// URIs:
+    /// - `subcog://namespace/{ns}[?offset=N&limit=M]` - Memories, most recent first
+    /// - `subcog://namespace/{ns}/tags` - Tag counts for the namespace
+    fn get_namespace_resource(&self, uri: &str, path: &str) -> Result<ResourceContent> {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let parts: Vec<&str> = path.split('/').collect();
+        let ns_str = parts
+            .get(1)
+            .copied()
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| {
+                Error::InvalidInput("Namespace required: subcog://namespace/{ns}".to_string())
+            })?;
+        let namespace = Namespace::parse(ns_str)
+            .ok_or_else(|| Error::InvalidInput(format!("Unknown namespace: {ns_str}")))?;
+
+        match &parts[2..] {
+            [] => self.get_namespace_page(uri, namespace, query),
+            ["tags"] => self.get_namespace_tags(uri, namespace),
+            _ => Err(Error::InvalidInput(format!(
+                "Unknown namespace resource: {uri}. Valid: subcog://namespace/{{ns}}, \
+                 subcog://namespace/{{ns}}/tags"
+            ))),
+        }
+    }
+
+    /// Lists one page of a namespace's memories, most recent first.
+    fn get_namespace_page(
+        &self,
+        uri: &str,
+        namespace: Namespace,
+        query: &str,
+    ) -> Result<ResourceContent> {
+        let recall = self.recall_service.as_ref().ok_or_else(|| {
+            Error::InvalidInput("Memory browsing requires RecallService".to_string())
+        })?;
+        let (offset, limit) = parse_page_params(query)?;
+
+        let filter = SearchFilter::new()
+            .with_namespace(namespace)
+            .with_offset(offset);
+        let result = recall.list_all(&filter, limit)?;
+
+        let memories: Vec<serde_json::Value> = result
+            .memories
+            .iter()
+            .map(|hit| {
+                serde_json::json!({
+                    "id": hit.memory.id.as_str(),
+                    "tags": hit.memory.tags,
+                    "created_at": hit.memory.created_at,
+                    "uri": format!("subcog://memory/{}", hit.memory.id.as_str()),
+                })
+            })
+            .collect();
+
+        let ns = namespace.as_str();
+        let mut response = serde_json::json!({
+            "namespace": ns,
+            "offset": offset,
+            "limit": limit,
+            "count": memories.len(),
+            "has_more": result.has_more,
+            "memories": memories,
+        });
+        if result.has_more {
+            let next = offset.saturating_add(result.memories.len());
+            response["next"] = serde_json::json!(format!(
+                "subcog://namespace/{ns}?offset={next}&limit={limit}"
+            ));
+        }
+
+        Ok(ResourceContent {
+            uri: uri.to_string(),
+            mime_type: Some("application/json".to_string()),
+            text: Some(serde_json::to_string_pretty(&response).unwrap_or_default()),
+            blob: None,
+        })
+    }
+
+    /// Counts tags across a namespace's memories, most used first.
+    fn get_namespace_tags(&self, uri: &str, namespace: Namespace) -> Result<ResourceContent> {
+        let recall = self.recall_service.as_ref().ok_or_else(|| {
+            Error::InvalidInput("Memory browsing requires RecallService".to_string())
+        })?;
+
+        let filter = SearchFilter::new().with_namespace(namespace);
+        let result = recall.list_all(&filter, TAG_FACET_SCAN_LIMIT)?;
+
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for tag in result.memories.iter().flat_map(|hit| &hit.memory.tags) {
+            *counts.entry(tag.as_str()).or_default() += 1;
+        }
+        let mut counts: Vec<(&str, usize)> = counts.into_iter().collect();
+        counts.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+        let tags: Vec<serde_json::Value> = counts
+            .iter()
+            .map(|(tag, count)| serde_json::json!({ "tag": tag, "count": count }))
+            .collect();
+
+        let response = serde_json::json!({
+            "namespace": namespace.as_str(),
+            "memory_count": result.memories.len(),
+            "truncated": result.has_more,
+            "count": tags.len(),
+            "tags": tags,
+        });
+
+        Ok(ResourceContent {
+            uri: uri.to_string(),
+            mime_type: Some("application/json".to_string()),
+            text: Some(serde_json::to_string_pretty(&response).unwrap_or_default()),
+            blob: None,
+        })
+    }
+
     /// Gets summaries resource (list or specific summary).
     ///
     /// URIs:
@@ -1313,6 +1458,28 @@ fn decode_uri_component(s: &str) -> String {
     result
 }
 
+/// Parses `offset` and `limit` from a resource query string.
+fn parse_page_params(query: &str) -> Result<(usize, usize)> {
+    let mut offset = 0;
+    let mut limit = NAMESPACE_PAGE_SIZE;
+    for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        let parsed = value
+            .parse::<usize>()
+            .map_err(|_| Error::InvalidInput(format!("Invalid {key}: {value}")));
+        match key {
+            "offset" => offset = parsed?,
+            "limit" => limit = parsed?.clamp(1, MAX_NAMESPACE_PAGE_SIZE),
+            _ => {
+                return Err(Error::InvalidInput(format!(
+                    "Unknown query parameter: {key}. Valid: offset, limit"
+                )));
+            },
+        }
+    }
+    Ok((offset, limit))
+}
+
 /// Truncates content to a maximum length, breaking at word boundaries.
 fn truncate_content(content: &str, max_len: usize) -> String {
     if content.len() <= max_len {
@@ -1329,7 +1496,7 @@ fn truncate_content(content: &str, max_len: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, Memory, MemoryId, MemoryStatus};
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus};
     use crate::services::RecallService;
     use crate::storage::index::SqliteBackend;
     use crate::storage::traits::IndexBackend;
@@ -1358,6 +1525,13 @@ fn build_handler_with_memories() -> ResourceHandler {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         };
         let other = Memory {
             id: MemoryId::new("patterns-1"),
@@ -1380,6 +1554,13 @@ fn build_handler_with_memories() -> ResourceHandler {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         };
 
         index.index(&memory).expect("index memory");
@@ -1569,6 +1750,46 @@ fn test_project_namespace_memory_fetch_rejects_mismatch() {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_namespace_resource_pages_memories_and_tags() {
+        let mut handler = build_handler_with_memories();
+        let result = handler
+            .get_resource("subcog://namespace/decisions")
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&result.text.unwrap()).unwrap();
+        assert_eq!(value["namespace"], "decisions");
+        assert_eq!(value["count"].as_u64(), Some(1));
+        assert_eq!(value["memories"][0]["id"], "decisions-1");
+        assert_eq!(value["has_more"], false);
+
+        let page = handler
+            .get_resource("subcog://namespace/decisions?offset=1&limit=10")
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&page.text.unwrap()).unwrap();
+        assert_eq!(value["count"].as_u64(), Some(0));
+
+        let tags = handler
+            .get_resource("subcog://namespace/patterns/tags")
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&tags.text.unwrap()).unwrap();
+        assert_eq!(value["tags"][0]["tag"], "beta");
+        assert_eq!(value["tags"][0]["count"].as_u64(), Some(1));
+    }
+
+    #[test]
+    fn test_namespace_resource_rejects_unknown_namespace() {
+        let mut handler = build_handler_with_memories();
+        let err = handler
+            .get_resource("subcog://namespace/nonsense")
+            .unwrap_err();
+        assert!(err.to_string().contains("Unknown namespace"));
+        assert!(
+            handler
+                .get_resource("subcog://namespace/decisions?page=2")
+                .is_err()
+        );
+    }
+
     #[test]
     fn test_namespaces_resource() {
         let mut handler = ResourceHandler::new();
@@ -1663,6 +1884,13 @@ fn test_summaries_list_filters_only_summary_nodes() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         };
 
         // Create a summary memory
@@ -1687,6 +1915,13 @@ fn test_summaries_list_filters_only_summary_nodes() {
             is_summary: true,
             source_memory_ids: Some(vec![MemoryId::new("regular-1")]),
             consolidation_timestamp: Some(now),
+            origin: CaptureOrigin::Consolidation,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         };
 
         index.index(&regular).expect("index regular memory");
@@ -1733,6 +1968,13 @@ fn test_get_specific_summary_with_sources() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         };
 
         let source2 = Memory {
@@ -1756,6 +1998,13 @@ fn test_get_specific_summary_with_sources() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         };
 
         // Create summary memory
@@ -1780,6 +2029,13 @@ fn test_get_specific_summary_with_sources() {
             is_summary: true,
             source_memory_ids: Some(vec![MemoryId::new("source-1"), MemoryId::new("source-2")]),
             consolidation_timestamp: Some(now),
+            origin: CaptureOrigin::Consolidation,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         };
 
         index.index(&source1).expect("index source1");
@@ -1837,6 +2093,13 @@ fn test_get_summary_rejects_non_summary() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         };
 
         index.index(&regular).expect("index memory");
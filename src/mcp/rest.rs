@@ -0,0 +1,327 @@
+//! REST API for integrations that do not speak MCP (`rest` feature).
+//!
+//! `subcog serve --transport http --rest` serves these routes next to `/mcp`,
+//! behind the same JWT authentication and per-client rate limit:
+//!
+//! | Method | Path | Scope | Description |
+//! |--------|------|-------|-------------|
+//! | `POST` | `/memories` | `write` | Capture a memory |
+//! | `GET` | `/memories?q=...` | `read` | Recall memories (lists them without `q`) |
+//! | `GET` | `/memories/{id}` | `read` | Get a memory by ID or URN |
+//! | `DELETE` | `/memories/{id}` | `write` | Tombstone a memory (`?hard=true` removes it) |
+//! | `GET` | `/openapi.json` | - | OpenAPI 3 document for these routes |
+//!
+//! Requests and responses are JSON. Errors use the envelope of the MCP HTTP
+//! transport: `{"error": {"code": -32000, "message": "..."}}`.
+//!
+//! # Example
+//!
+//! ```bash
+//! curl -X POST http://localhost:3000/memories \
+//!   -H "Authorization: Bearer $TOKEN" -H "Content-Type: application/json" \
+//!   -d '{"content": "Use PostgreSQL for storage", "namespace": "decisions"}'
+//! curl "http://localhost:3000/memories?q=storage&limit=5" -H "Authorization: Bearer $TOKEN"
+//! ```
+
+use crate::mcp::auth::Claims;
+use crate::mcp::openapi::openapi_spec;
+use crate::mcp::server::error_response;
+use crate::mcp::tool_types::parse_search_mode;
+use crate::models::{
+    CaptureRequest, ContentType, Domain, Memory, MemoryId, Namespace, SearchFilter, SearchHit, Urn,
+};
+use crate::services::ServiceContainer;
+use crate::{Error, Result};
+use axum::extract::rejection::{JsonRejection, QueryRejection};
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::Response;
+use axum::routing::{get, post};
+use axum::{Extension, Json, Router};
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::sync::Arc;
+
+/// Path of the memory collection.
+pub(super) const MEMORIES_PATH: &str = "/memories";
+
+/// Path of a single memory.
+pub(super) const MEMORY_PATH: &str = "/memories/{id}";
+
+/// Path of the OpenAPI document.
+pub(super) const OPENAPI_PATH: &str = "/openapi.json";
+
+/// Routes served by [`rest_router`] as `(method, path)` pairs.
+///
+/// Each route must have an operation in [`openapi_spec`].
+pub const REST_ROUTES: &[(&str, &str)] = &[
+    ("post", MEMORIES_PATH),
+    ("get", MEMORIES_PATH),
+    ("get", MEMORY_PATH),
+    ("delete", MEMORY_PATH),
+    ("get", OPENAPI_PATH),
+];
+
+/// Results returned by recall when `limit` is not given.
+const DEFAULT_LIMIT: usize = 10;
+
+/// Maximum results returned by recall.
+const MAX_LIMIT: usize = 100;
+
+/// Result of a REST handler; errors are already rendered responses.
+type ApiResult<T> = std::result::Result<T, Response>;
+
+/// Body of `POST /memories`.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CaptureBody {
+    /// Memory content.
+    content: String,
+    /// Namespace (defaults to `decisions`).
+    #[serde(default)]
+    namespace: Option<String>,
+    /// Tags.
+    #[serde(default)]
+    tags: Vec<String>,
+    /// Source reference (e.g. a file path).
+    #[serde(default)]
+    source: Option<String>,
+    /// Content type (`markdown`, `text`, `code` or `code:<lang>`); detected if unset.
+    #[serde(default)]
+    content_type: Option<String>,
+}
+
+/// Query parameters of `GET /memories`.
+#[derive(Debug, Deserialize)]
+struct RecallParams {
+    /// Search query; all memories are listed when omitted.
+    #[serde(default)]
+    q: Option<String>,
+    /// Namespace filter.
+    #[serde(default)]
+    namespace: Option<String>,
+    /// Search mode: text, vector or hybrid.
+    #[serde(default)]
+    mode: Option<String>,
+    /// Maximum number of results.
+    #[serde(default)]
+    limit: Option<usize>,
+}
+
+/// Query parameters of `DELETE /memories/{id}`.
+#[derive(Debug, Deserialize)]
+struct DeleteParams {
+    /// Remove the memory permanently instead of tombstoning it.
+    #[serde(default)]
+    hard: bool,
+}
+
+/// Builds the REST routes over the given services.
+///
+/// The router has no authentication of its own; the HTTP server wraps it in
+/// the same middleware as `/mcp`. When a request carries JWT [`Claims`], the
+/// handlers check the `read` or `write` scope.
+pub fn rest_router(services: Arc<ServiceContainer>) -> Router {
+    Router::new()
+        .route(MEMORIES_PATH, post(capture).get(recall))
+        .route(MEMORY_PATH, get(get_memory).delete(delete_memory))
+        .route(OPENAPI_PATH, get(openapi))
+        .with_state(services)
+}
+
+/// `GET /openapi.json`: returns the OpenAPI document of the REST API.
+async fn openapi() -> Json<Value> {
+    Json(openapi_spec())
+}
+
+/// `POST /memories`: captures a memory.
+async fn capture(
+    State(services): State<Arc<ServiceContainer>>,
+    claims: Option<Extension<Claims>>,
+    body: std::result::Result<Json<CaptureBody>, JsonRejection>,
+) -> ApiResult<(StatusCode, Json<Value>)> {
+    require_scope(claims.as_deref(), "write")?;
+    let Json(body) = body.map_err(|e| error_response(StatusCode::BAD_REQUEST, &e.body_text()))?;
+
+    let result = run_blocking(&services, move |services| {
+        let namespace = body
+            .namespace
+            .as_deref()
+            .map_or(Ok(Namespace::Decisions), parse_namespace)?;
+        let content_type = body
+            .content_type
+            .as_deref()
+            .map(|s| {
+                ContentType::parse(s)
+                    .ok_or_else(|| Error::InvalidInput(format!("Unknown content type: {s}")))
+            })
+            .transpose()?;
+
+        let mut request = CaptureRequest::new(body.content)
+            .with_namespace(namespace)
+            .with_domain(Domain::default_for_context());
+        request.tags = body.tags;
+        request.source = body.source;
+        request.content_type = content_type;
+        services.capture().capture(request)
+    })
+    .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        Json(json!({
+            "id": result.memory_id.as_str(),
+            "urn": result.urn,
+            "content_modified": result.content_modified,
+        })),
+    ))
+}
+
+/// `GET /memories?q=...`: recalls memories matching a query.
+async fn recall(
+    State(services): State<Arc<ServiceContainer>>,
+    claims: Option<Extension<Claims>>,
+    params: std::result::Result<Query<RecallParams>, QueryRejection>,
+) -> ApiResult<Json<Value>> {
+    require_scope(claims.as_deref(), "read")?;
+    let Query(params) =
+        params.map_err(|e| error_response(StatusCode::BAD_REQUEST, &e.body_text()))?;
+
+    let hits = run_blocking(&services, move |services| {
+        let mut filter = SearchFilter::new();
+        if let Some(namespace) = params.namespace.as_deref() {
+            filter = filter.with_namespace(parse_namespace(namespace)?);
+        }
+        let limit = params.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+        let recall = services.recall()?;
+        let result = match params.q.as_deref().map(str::trim) {
+            Some(query) if !query.is_empty() => {
+                let mode = parse_search_mode(params.mode.as_deref().unwrap_or("hybrid"));
+                recall.search(query, mode, &filter, limit)?
+            },
+            _ => recall.list_all_with_content(&filter, limit)?,
+        };
+        Ok(result.memories)
+    })
+    .await?;
+
+    let memories: Vec<Value> = hits.iter().map(hit_json).collect();
+    Ok(Json(json!({
+        "count": memories.len(),
+        "memories": memories,
+    })))
+}
+
+/// `GET /memories/{id}`: returns a memory.
+async fn get_memory(
+    State(services): State<Arc<ServiceContainer>>,
+    claims: Option<Extension<Claims>>,
+    Path(id): Path<String>,
+) -> ApiResult<Json<Value>> {
+    require_scope(claims.as_deref(), "read")?;
+
+    let memory_id = MemoryId::new(Urn::extract_memory_id(&id));
+    let memory = run_blocking(&services, move |services| {
+        services.index()?.get_memory(&memory_id)
+    })
+    .await?;
+
+    memory
+        .map(|memory| Json(memory_json(&memory)))
+        .ok_or_else(|| not_found(&id))
+}
+
+/// `DELETE /memories/{id}`: tombstones a memory, or removes it with `?hard=true`.
+async fn delete_memory(
+    State(services): State<Arc<ServiceContainer>>,
+    claims: Option<Extension<Claims>>,
+    Path(id): Path<String>,
+    params: std::result::Result<Query<DeleteParams>, QueryRejection>,
+) -> ApiResult<StatusCode> {
+    require_scope(claims.as_deref(), "write")?;
+    let Query(params) =
+        params.map_err(|e| error_response(StatusCode::BAD_REQUEST, &e.body_text()))?;
+
+    let memory_id = MemoryId::new(Urn::extract_memory_id(&id));
+    let deleted = run_blocking(&services, move |services| {
+        services.capture().delete(&memory_id, params.hard)
+    })
+    .await?;
+
+    if deleted {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(not_found(&id))
+    }
+}
+
+/// Runs a service call on the blocking pool, rendering errors as responses.
+async fn run_blocking<T, F>(services: &Arc<ServiceContainer>, f: F) -> ApiResult<T>
+where
+    T: Send + 'static,
+    F: FnOnce(&ServiceContainer) -> Result<T> + Send + 'static,
+{
+    let services = Arc::clone(services);
+    tokio::task::spawn_blocking(move || f(&services))
+        .await
+        .map_err(|e| error_response(StatusCode::INTERNAL_SERVER_ERROR, &e.to_string()))?
+        .map_err(|e| api_error(&e))
+}
+
+/// Rejects requests whose claims lack `scope`; requests without claims pass.
+fn require_scope(claims: Option<&Claims>, scope: &str) -> ApiResult<()> {
+    match claims {
+        Some(claims) if !claims.has_scope(scope) => Err(error_response(
+            StatusCode::FORBIDDEN,
+            &format!("Forbidden: requires '{scope}' scope"),
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Parses a namespace, rejecting unknown names.
+fn parse_namespace(name: &str) -> Result<Namespace> {
+    Namespace::parse(name).ok_or_else(|| Error::InvalidInput(format!("Unknown namespace: {name}")))
+}
+
+/// Renders a service error with the matching HTTP status.
+fn api_error(error: &Error) -> Response {
+    let status = match error {
+        Error::InvalidInput(_) | Error::ContentBlocked { .. } => StatusCode::BAD_REQUEST,
+        Error::Unauthorized(_) => StatusCode::FORBIDDEN,
+        Error::FeatureNotEnabled(_) | Error::NotImplemented(_) => StatusCode::NOT_IMPLEMENTED,
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    };
+    error_response(status, &error.to_string())
+}
+
+/// Renders a 404 for a memory ID.
+fn not_found(id: &str) -> Response {
+    error_response(StatusCode::NOT_FOUND, &format!("Memory not found: {id}"))
+}
+
+/// Serializes a memory for REST responses.
+fn memory_json(memory: &Memory) -> Value {
+    json!({
+        "id": memory.id.as_str(),
+        "namespace": memory.namespace.as_str(),
+        "domain": memory.domain.to_string(),
+        "status": memory.status.as_str(),
+        "tags": memory.tags,
+        "source": memory.source,
+        "content_type": memory.content_type.as_ref().map(ToString::to_string),
+        "pinned": memory.pinned,
+        "commit": memory.commit,
+        "language": memory.language,
+        "created_at": memory.created_at,
+        "updated_at": memory.updated_at,
+        "content": memory.content,
+    })
+}
+
+/// Serializes a recall hit: the memory plus its score.
+fn hit_json(hit: &SearchHit) -> Value {
+    let mut value = memory_json(&hit.memory);
+    value["score"] = json!(hit.score);
+    value
+}
@@ -1,6 +1,6 @@
 //! MCP server setup and lifecycle.
 //!
-//! Implements an rmcp-based MCP server over stdio or HTTP transport.
+//! Implements an rmcp-based MCP server over stdio, HTTP, SSE or Unix socket transport.
 //!
 //! ## Transport Security Model (COMP-CRIT-003)
 //!
@@ -30,16 +30,29 @@
 //! - **Resource exhaustion**: Rate limits and memory caps protect against `DoS`.
 //! - **Privilege escalation**: Subcog runs with user privileges, never elevated.
 //!
+//! ### Unix Socket Transport (Optional)
+//!
+//! Serves local clients over a Unix domain socket, one MCP session per connection.
+//! There is no token authentication; access is limited by the socket file, which
+//! is created owner-only (`0600`) and removed on shutdown. See
+//! `mcp::unix_socket` for how stale and live sockets are handled.
+//!
 //! ### HTTP Transport (Optional)
 //!
 //! The HTTP transport exposes subcog over a network socket and requires explicit security:
 //!
 //! - **JWT bearer token authentication** (SEC-H1): All requests must include a valid JWT.
 //!   Requires `SUBCOG_MCP_JWT_SECRET` environment variable (min 32 characters).
+//!   Alternatively, a static token set with `[mcp] auth_token` or `SUBCOG_MCP_TOKEN`
+//!   is accepted as `Authorization: Bearer <token>` and grants all scopes.
 //! - **Per-client rate limiting** (ARCH-H1): Prevents abuse via configurable request limits.
 //! - **CORS protection** (HIGH-SEC-006): Restrictive by default; origins must be explicitly allowed.
 //! - **Security headers**: X-Content-Type-Options, X-Frame-Options, CSP, no-cache directives.
 //!
+//! The SSE transport (`mcp::sse`) is served the same way, with the same
+//! authentication, rate limiting and headers, on `/sse` and `/message`
+//! instead of `/mcp`.
+//!
 //! **When to use HTTP transport:**
 //! - Remote access to subcog server (e.g., from containerized environments)
 //! - Shared team server with multi-user access
@@ -47,8 +60,9 @@
 //!
 //! **Configuration:**
 //! ```bash
-//! # Required for HTTP transport
+//! # Required for HTTP transport (one of)
 //! export SUBCOG_MCP_JWT_SECRET="your-32-char-minimum-secret-key"
+//! export SUBCOG_MCP_TOKEN="$(openssl rand -hex 32)"
 //!
 //! # Optional: customize rate limits
 //! export SUBCOG_MCP_RATE_LIMIT_MAX_REQUESTS=1000
@@ -58,17 +72,25 @@
 //! export SUBCOG_MCP_CORS_ALLOWED_ORIGINS="https://your-app.com"
 //! ```
 
+use crate::config::{OperationTimeoutConfig, OperationType};
+use crate::mcp::batch::batching;
+#[cfg(feature = "http")]
+use crate::mcp::sse::SseSessions;
+#[cfg(unix)]
+use crate::mcp::unix_socket::SocketListener;
 use crate::mcp::{
-    ResourceContent, ResourceDefinition, ResourceHandler, ToolContent, ToolDefinition,
-    ToolRegistry, ToolResult,
+    CustomToolHandler, ResourceContent, ResourceDefinition, ResourceHandler, ToolContent,
+    ToolDefinition, ToolRegistry, ToolResult,
     prompts::{PromptContent, PromptDefinition, PromptRegistry},
 };
 use crate::models::{EventMeta, MemoryEvent};
+#[cfg(feature = "http")]
+use crate::observability::global_event_bus;
 use crate::observability::{
     RequestContext as ObsRequestContext, current_request_id, flush_metrics, scope_request_context,
 };
 use crate::security::record_event;
-use crate::services::ServiceContainer;
+use crate::services::{Cancellation, ServiceContainer};
 use crate::{Error, Result as SubcogResult};
 #[cfg(feature = "http")]
 use axum::extract::{Request, State};
@@ -90,7 +112,6 @@
     Resource, ResourceContents, ServerCapabilities, ServerInfo, Tool,
 };
 use rmcp::service::RequestContext;
-use rmcp::transport::stdio;
 #[cfg(feature = "http")]
 use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
 #[cfg(feature = "http")]
@@ -102,6 +123,7 @@
 use serde_json::{Map, Value};
 #[cfg(feature = "http")]
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{Duration, Instant};
@@ -168,26 +190,74 @@ fn init_request_context() -> (Option<ObsRequestContext>, String) {
 }
 
 async fn await_shutdown(cancel_token: rmcp::service::RunningServiceCancellationToken) {
+    wait_for_shutdown().await;
+    cancel_token.cancel();
+}
+
+/// Returns once a graceful shutdown has been requested.
+async fn wait_for_shutdown() {
     while !is_shutdown_requested() {
         tokio::time::sleep(Duration::from_millis(200)).await;
     }
-    cancel_token.cancel();
+}
+
+/// Runs a tool call on the blocking pool, cancelling it when `cancelled`
+/// resolves or the tool timeout elapses.
+async fn run_call_tool(
+    state: Arc<McpState>,
+    request: CallToolRequestParams,
+    start: Instant,
+    cancelled: impl std::future::Future<Output = ()>,
+) -> McpResult<CallToolResult> {
+    let tool_name = request.name.clone();
+    let cancellation = Cancellation::new();
+    let tool_cancellation = cancellation.clone();
+    let timeout = state.tool_timeout;
+    // Use spawn_blocking to run the potentially blocking tool execution
+    // (e.g., LLM calls use reqwest::blocking::Client)
+    let execution = tokio::task::spawn_blocking(move || {
+        execute_call_tool(&state, request, start, &tool_cancellation)
+    });
+
+    // The blocking task can't be aborted; cancelling makes it stop at
+    // its next phase boundary instead of spending more LLM calls
+    let reason = tokio::select! {
+        result = execution => {
+            return result.map_err(|e| join_error_to_mcp(&e))?;
+        },
+        () = cancelled => "cancelled by client",
+        () = tokio::time::sleep(timeout) => "timed out",
+    };
+    cancellation.cancel();
+    metrics::counter!("mcp_tool_cancellations_total", "reason" => reason).increment(1);
+    let err = Error::Cancelled {
+        operation: format!("{tool_name} ({reason})"),
+    };
+    record_event(MemoryEvent::McpRequestError {
+        meta: EventMeta::new("mcp", current_request_id()),
+        operation: "call_tool".to_string(),
+        error: err.to_string(),
+    });
+    Err(McpError::internal_error(err.to_string(), None))
 }
 
 fn execute_call_tool(
     state: &McpState,
     request: CallToolRequestParams,
     start: Instant,
+    cancellation: &Cancellation,
 ) -> McpResult<CallToolResult> {
     let arguments = match request.arguments {
         Some(args) => Value::Object(args),
         None => Value::Object(Map::new()),
     };
 
-    let result = match state
-        .tools
-        .execute(&request.name, arguments, &state.services)
-    {
+    let result = match state.tools.execute_cancellable(
+        &request.name,
+        arguments,
+        &state.services,
+        cancellation,
+    ) {
         Ok(result) => result,
         Err(err) => {
             record_event(MemoryEvent::McpRequestError {
@@ -195,6 +265,9 @@ fn execute_call_tool(
                 operation: "call_tool".to_string(),
                 error: err.to_string(),
             });
+            if matches!(err, Error::Cancelled { .. }) {
+                return Err(McpError::internal_error(err.to_string(), None));
+            }
             return Err(McpError::invalid_params(err.to_string(), None));
         },
     };
@@ -258,7 +331,9 @@ pub fn setup_signal_handler() -> SubcogResult<()> {
 }
 
 #[cfg(feature = "http")]
-use crate::mcp::auth::{Claims, JwtAuthenticator, JwtConfig, ToolAuthorization};
+use crate::mcp::auth::{
+    Claims, JwtAuthenticator, JwtConfig, TokenAuthenticator, ToolAuthorization,
+};
 
 /// Default maximum requests per rate limit window.
 const DEFAULT_RATE_LIMIT_MAX_REQUESTS: usize = 1000;
@@ -303,11 +378,31 @@ struct RateLimitEntry {
 #[cfg(feature = "http")]
 #[derive(Clone)]
 struct HttpAuthState {
-    authenticator: JwtAuthenticator,
+    authenticator: Option<JwtAuthenticator>,
+    token_authenticator: Option<TokenAuthenticator>,
     rate_limit: RateLimitConfig,
     rate_limits: Arc<Mutex<HashMap<String, RateLimitEntry>>>,
 }
 
+#[cfg(feature = "http")]
+impl HttpAuthState {
+    /// Validates an Authorization header against the static token, then JWT.
+    fn authenticate(&self, auth_header: &str) -> SubcogResult<Claims> {
+        let static_token = self
+            .token_authenticator
+            .as_ref()
+            .map(|authenticator| authenticator.validate_header(auth_header));
+        match (static_token, &self.authenticator) {
+            (Some(Ok(claims)), _) => Ok(claims),
+            (_, Some(authenticator)) => authenticator.validate_header(auth_header),
+            (Some(Err(e)), None) => Err(e),
+            (None, None) => Err(Error::Unauthorized(
+                "No authentication method configured".to_string(),
+            )),
+        }
+    }
+}
+
 #[cfg(feature = "http")]
 async fn auth_middleware(
     State(state): State<HttpAuthState>,
@@ -331,25 +426,19 @@ async fn auth_middleware(
             .and_then(|h| h.to_str().ok());
 
         let claims = if let Some(header_value) = auth_header {
-            match state.authenticator.validate_header(header_value) {
+            match state.authenticate(header_value) {
                 Ok(claims) => claims,
                 Err(e) => {
-                    tracing::warn!(error = %e, "JWT authentication failed");
+                    tracing::warn!(error = %e, "Bearer token authentication failed");
                     record_event(MemoryEvent::McpAuthFailed {
                         meta: EventMeta::new("mcp", current_request_id()),
                         client_id: None,
                         reason: e.to_string(),
                     });
-                    return (
+                    return error_response(
                         StatusCode::UNAUTHORIZED,
-                        Json(serde_json::json!({
-                            "error": {
-                                "code": -32000,
-                                "message": format!("Authentication failed: {e}")
-                            }
-                        })),
-                    )
-                        .into_response();
+                        &format!("Authentication failed: {e}"),
+                    );
                 },
             }
         } else {
@@ -358,16 +447,7 @@ async fn auth_middleware(
                 client_id: None,
                 reason: "missing authorization header".to_string(),
             });
-            return (
-                StatusCode::UNAUTHORIZED,
-                Json(serde_json::json!({
-                    "error": {
-                        "code": -32000,
-                        "message": "Authentication required"
-                    }
-                })),
-            )
-                .into_response();
+            return error_response(StatusCode::UNAUTHORIZED, "Authentication required");
         };
 
         let client_id = claims.sub.clone();
@@ -390,20 +470,13 @@ async fn auth_middleware(
                 requests = entry.count,
                 "Per-client rate limit exceeded"
             );
-            return (
+            return error_response(
                 StatusCode::TOO_MANY_REQUESTS,
-                Json(serde_json::json!({
-                    "error": {
-                        "code": -32000,
-                        "message": format!(
-                            "Rate limit exceeded: max {} requests per {:?}",
-                            state.rate_limit.max_requests,
-                            state.rate_limit.window
-                        )
-                    }
-                })),
-            )
-                .into_response();
+                &format!(
+                    "Rate limit exceeded: max {} requests per {:?}",
+                    state.rate_limit.max_requests, state.rate_limit.window
+                ),
+            );
         }
 
         entry.count += 1;
@@ -416,6 +489,21 @@ async fn auth_middleware(
     .await
 }
 
+/// Builds an error response with the JSON error envelope of the HTTP transport.
+#[cfg(feature = "http")]
+pub(super) fn error_response(status: StatusCode, message: &str) -> Response {
+    (
+        status,
+        Json(serde_json::json!({
+            "error": {
+                "code": -32000,
+                "message": message
+            }
+        })),
+    )
+        .into_response()
+}
+
 #[cfg(feature = "http")]
 async fn map_notification_status(req: Request, next: Next) -> Response {
     let mut response = next.run(req).await;
@@ -640,6 +728,10 @@ pub enum Transport {
     Stdio,
     /// HTTP transport.
     Http,
+    /// Unix domain socket (local clients only).
+    Unix,
+    /// Server-sent events over HTTP, with server-pushed notifications.
+    Sse,
 }
 
 struct McpState {
@@ -647,6 +739,8 @@ struct McpState {
     resources: Mutex<ResourceHandler>,
     prompts: PromptRegistry,
     services: Arc<ServiceContainer>,
+    /// Time after which a tool call is cancelled.
+    tool_timeout: Duration,
     #[cfg(feature = "http")]
     tool_auth: ToolAuthorization,
 }
@@ -669,6 +763,7 @@ fn new(
                 resources: Mutex::new(resources),
                 prompts,
                 services,
+                tool_timeout: OperationTimeoutConfig::from_env().get(OperationType::McpTool),
                 #[cfg(feature = "http")]
                 tool_auth: ToolAuthorization::default(),
             }),
@@ -750,21 +845,10 @@ fn call_tool(
                 });
                 return Err(err);
             }
-            #[cfg(not(feature = "http"))]
-            let _ = &context;
 
-            run_mcp_with_context(
-                request_context,
-                span,
-                "call_tool",
-                move |start| async move {
-                    // Use spawn_blocking to run the potentially blocking tool execution
-                    // (e.g., LLM calls use reqwest::blocking::Client)
-                    tokio::task::spawn_blocking(move || execute_call_tool(&state, request, start))
-                        .await
-                        .map_err(|e| join_error_to_mcp(&e))?
-                },
-            )
+            run_mcp_with_context(request_context, span, "call_tool", move |start| {
+                run_call_tool(state, request, start, context.ct.cancelled_owned())
+            })
             .await
         }
     }
@@ -1078,17 +1162,25 @@ pub struct McpServer {
     transport: Transport,
     /// HTTP port (if using HTTP transport).
     port: u16,
+    /// Socket path (if using Unix socket transport).
+    socket_path: Option<PathBuf>,
     /// Rate limit configuration (ARCH-H1).
     rate_limit: RateLimitConfig,
     /// JWT authenticator for HTTP transport (SEC-H1).
     #[cfg(feature = "http")]
     jwt_authenticator: Option<JwtAuthenticator>,
+    /// Static bearer token authenticator for HTTP transport.
+    #[cfg(feature = "http")]
+    token_authenticator: Option<TokenAuthenticator>,
     /// CORS configuration for HTTP transport (HIGH-SEC-006).
     #[cfg(feature = "http")]
     cors_config: CorsConfig,
     /// Allow unauthenticated `/healthz` requests (for K8s sidecar probes).
     #[cfg(feature = "http")]
     health_no_auth: bool,
+    /// Serve the REST API (`/memories`) next to `/mcp`.
+    #[cfg(feature = "rest")]
+    rest: bool,
 }
 
 impl McpServer {
@@ -1099,13 +1191,18 @@ pub fn new() -> Self {
             tools: ToolRegistry::new(),
             transport: Transport::Stdio,
             port: 3000,
+            socket_path: None,
             rate_limit: RateLimitConfig::from_env(),
             #[cfg(feature = "http")]
             jwt_authenticator: None,
             #[cfg(feature = "http")]
+            token_authenticator: None,
+            #[cfg(feature = "http")]
             cors_config: CorsConfig::from_env(),
             #[cfg(feature = "http")]
             health_no_auth: false,
+            #[cfg(feature = "rest")]
+            rest: false,
         }
     }
 
@@ -1120,6 +1217,16 @@ pub const fn with_health_no_auth(mut self, enabled: bool) -> Self {
         self
     }
 
+    /// Serves the REST API (`/memories`) next to `/mcp` on the HTTP transport.
+    ///
+    /// The routes share the JWT authentication and rate limit of `/mcp`.
+    #[cfg(feature = "rest")]
+    #[must_use]
+    pub const fn with_rest(mut self, enabled: bool) -> Self {
+        self.rest = enabled;
+        self
+    }
+
     /// Sets the CORS configuration for HTTP transport (HIGH-SEC-006).
     ///
     /// By default, no origins are allowed (deny all CORS requests).
@@ -1158,6 +1265,20 @@ pub fn with_jwt_from_env(self) -> SubcogResult<Self> {
         Ok(self.with_jwt_authenticator(authenticator))
     }
 
+    /// Requires a static bearer token on HTTP requests.
+    ///
+    /// Requests authenticated with the token get the wildcard scope. A JWT
+    /// authenticator, if also set, still accepts JWTs.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the token is empty.
+    #[cfg(feature = "http")]
+    pub fn with_auth_token(mut self, token: &str) -> SubcogResult<Self> {
+        self.token_authenticator = Some(TokenAuthenticator::new(token)?);
+        Ok(self)
+    }
+
     /// Sets the rate limit configuration (ARCH-H1).
     ///
     /// # Arguments
@@ -1169,6 +1290,24 @@ pub const fn with_rate_limit(mut self, config: RateLimitConfig) -> Self {
         self
     }
 
+    /// Adds a custom tool served next to the built-in tools.
+    ///
+    /// Must be called before [`start`](Self::start). See
+    /// [`ToolRegistry::register`] for the naming rules.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the name is invalid or already registered.
+    pub fn with_custom_tool(
+        mut self,
+        name: impl Into<String>,
+        definition: ToolDefinition,
+        handler: CustomToolHandler,
+    ) -> SubcogResult<Self> {
+        self.tools.register(name, definition, handler)?;
+        Ok(self)
+    }
+
     /// Tries to initialize `ResourceHandler` with services.
     ///
     /// Uses domain-scoped index (user-level index with project facets).
@@ -1213,6 +1352,13 @@ pub const fn with_port(mut self, port: u16) -> Self {
         self
     }
 
+    /// Sets the socket path for the Unix socket transport.
+    #[must_use]
+    pub fn with_socket_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.socket_path = Some(path.into());
+        self
+    }
+
     /// Starts the MCP server with graceful shutdown handling (RES-M4).
     ///
     /// Sets up signal handlers for SIGINT/SIGTERM before starting the server.
@@ -1228,6 +1374,8 @@ pub async fn start(&mut self) -> SubcogResult<()> {
         let (transport, port) = match self.transport {
             Transport::Stdio => ("stdio", None),
             Transport::Http => ("http", Some(self.port)),
+            Transport::Unix => ("unix", None),
+            Transport::Sse => ("sse", Some(self.port)),
         };
         record_event(MemoryEvent::McpStarted {
             meta: EventMeta::new("mcp", current_request_id()),
@@ -1237,23 +1385,27 @@ pub async fn start(&mut self) -> SubcogResult<()> {
 
         match self.transport {
             Transport::Stdio => self.run_stdio().await,
-            Transport::Http => self.run_http().await,
+            Transport::Http | Transport::Sse => self.run_http().await,
+            Transport::Unix => self.run_unix().await,
         }
     }
 
     fn build_handler(&mut self) -> SubcogResult<McpHandler> {
-        let tools = std::mem::take(&mut self.tools);
         let prompts = PromptRegistry::new();
         let services = Arc::new(ServiceContainer::from_current_dir_or_user()?);
+        let tools = std::mem::take(&mut self.tools)
+            .with_skip_security_check(services.capture().allows_skip_security_check());
         let resources = Self::try_init_resources(&services);
         Ok(McpHandler::new(tools, resources, prompts, services))
     }
 
     /// Runs the server over stdio with graceful shutdown (RES-M4).
+    ///
+    /// JSON-RPC batch arrays are split and reassembled by [`batching`].
     async fn run_stdio(&mut self) -> SubcogResult<()> {
         let handler = self.build_handler()?;
         let service = handler
-            .serve(stdio())
+            .serve(batching(tokio::io::stdin(), tokio::io::stdout()))
             .await
             .map_err(|e| Error::OperationFailed {
                 operation: "serve_stdio".to_string(),
@@ -1286,6 +1438,37 @@ async fn run_stdio(&mut self) -> SubcogResult<()> {
         Ok(())
     }
 
+    /// Runs the server over a Unix domain socket until shutdown.
+    ///
+    /// Each connection gets its own MCP session. The socket file is removed
+    /// when the server stops.
+    #[cfg(unix)]
+    async fn run_unix(&mut self) -> SubcogResult<()> {
+        let path = self.socket_path.clone().ok_or_else(|| {
+            Error::InvalidInput("Unix socket transport requires a socket path".to_string())
+        })?;
+        let handler = self.build_handler()?;
+        let socket = SocketListener::bind(&path)?;
+        tracing::info!(path = %path.display(), "Starting MCP server on Unix socket");
+
+        tokio::select! {
+            () = serve_unix_socket(&socket, &handler) => {},
+            () = wait_for_shutdown() => {
+                tracing::info!(path = %socket.path().display(), "Closing Unix socket");
+            },
+        }
+        Ok(())
+    }
+
+    /// Runs the server over a Unix domain socket (unsupported platform).
+    #[cfg(not(unix))]
+    #[allow(clippy::unused_async)] // Matches async signature of the unix version
+    async fn run_unix(&self) -> SubcogResult<()> {
+        Err(Error::InvalidInput(
+            "Unix socket transport is not supported on this platform".to_string(),
+        ))
+    }
+
     /// Performs graceful shutdown cleanup (RES-M4).
     #[allow(dead_code)]
     fn graceful_shutdown(&self) {
@@ -1306,36 +1489,37 @@ fn graceful_shutdown(&self) {
         );
     }
 
-    /// Runs the server over HTTP with JWT authentication (SEC-H1).
+    /// Runs the server over HTTP with bearer token authentication (SEC-H1).
     ///
-    /// Requires the `http` feature and `SUBCOG_MCP_JWT_SECRET` environment variable.
+    /// Requires the `http` feature and either `SUBCOG_MCP_JWT_SECRET` or a
+    /// static token (`[mcp] auth_token` / `SUBCOG_MCP_TOKEN`).
     #[cfg(feature = "http")]
     async fn run_http(&mut self) -> SubcogResult<()> {
-        // Ensure JWT authenticator is configured
-        let authenticator = self.jwt_authenticator.clone().ok_or_else(|| {
-            Error::OperationFailed {
+        // Refuse to serve without authentication
+        if self.jwt_authenticator.is_none() && self.token_authenticator.is_none() {
+            return Err(Error::OperationFailed {
                 operation: "run_http".to_string(),
-                cause: "JWT authenticator not configured. Set SUBCOG_MCP_JWT_SECRET or call with_jwt_authenticator()".to_string(),
-            }
-        })?;
+                cause:
+                    "No authentication configured. Set SUBCOG_MCP_JWT_SECRET or SUBCOG_MCP_TOKEN"
+                        .to_string(),
+            });
+        }
 
         // Capture tool count before build_handler takes ownership
         let tool_count = self.tools.tool_count();
 
         let handler = self.build_handler()?;
+        #[cfg(feature = "rest")]
+        let rest_services = Arc::clone(&handler.state.services);
         let health_state = HealthState {
             tool_count,
             services: Arc::clone(&handler.state.services),
         };
-        let session_manager = Arc::new(LocalSessionManager::default());
-        let streamable = StreamableHttpService::new(
-            move || Ok(handler.clone()),
-            session_manager,
-            StreamableHttpServerConfig::default(),
-        );
+        let (mcp_routes, sse_sessions) = self.mcp_routes(handler);
 
         let auth_state = HttpAuthState {
-            authenticator,
+            authenticator: self.jwt_authenticator.clone(),
+            token_authenticator: self.token_authenticator.clone(),
             rate_limit: self.rate_limit.clone(),
             rate_limits: Arc::new(Mutex::new(HashMap::new())),
         };
@@ -1343,14 +1527,22 @@ async fn run_http(&mut self) -> SubcogResult<()> {
         // Build CORS layer
         let cors_layer = build_cors_layer(&self.cors_config)?;
 
-        // Auth-protected routes: /mcp always requires JWT
-        let protected = Router::new()
-            .route_service("/mcp", any_service(streamable))
-            .layer(axum::middleware::from_fn_with_state(
-                auth_state.clone(),
-                auth_middleware,
+        // Auth-protected routes: /mcp (or /sse and /message) always require a bearer token
+        let protected = mcp_routes.layer(axum::middleware::from_fn_with_state(
+            auth_state.clone(),
+            auth_middleware,
+        ));
+
+        // /memories: REST API behind the same authentication as /mcp
+        #[cfg(feature = "rest")]
+        let protected = if self.rest {
+            tracing::info!("Serving REST API at /memories");
+            protected.merge(super::rest::rest_router(rest_services).layer(
+                axum::middleware::from_fn_with_state(auth_state.clone(), auth_middleware),
             ))
-            .layer(axum::middleware::from_fn(map_notification_status));
+        } else {
+            protected
+        };
 
         // /healthz: auth-protected by default, optionally unauthenticated
         let health_route = Router::new()
@@ -1396,8 +1588,9 @@ async fn run_http(&mut self) -> SubcogResult<()> {
         let addr = std::net::SocketAddr::from(([0, 0, 0, 0], self.port));
         tracing::info!(
             port = self.port,
+            transport = ?self.transport,
             health_no_auth = self.health_no_auth,
-            "Starting MCP HTTP server with JWT auth"
+            "Starting MCP HTTP server with bearer token auth"
         );
 
         let listener =
@@ -1408,12 +1601,67 @@ async fn run_http(&mut self) -> SubcogResult<()> {
                     cause: e.to_string(),
                 })?;
 
-        axum::serve(listener, app)
-            .await
-            .map_err(|e| Error::OperationFailed {
-                operation: "serve".to_string(),
-                cause: e.to_string(),
-            })
+        let serve = axum::serve(listener, app);
+        let served = match sse_sessions {
+            // SSE streams never end on their own; close them so shutdown can finish
+            Some(sessions) => {
+                serve
+                    .with_graceful_shutdown(async move {
+                        wait_for_shutdown().await;
+                        sessions.close();
+                    })
+                    .await
+            },
+            None => serve.await,
+        };
+        served.map_err(|e| Error::OperationFailed {
+            operation: "serve".to_string(),
+            cause: e.to_string(),
+        })
+    }
+
+    /// Builds the MCP routes of the HTTP-based transports.
+    ///
+    /// Streamable HTTP is served on `/mcp`. The SSE transport is served on
+    /// `/sse` and `/message`, and its sessions are returned so shutdown can
+    /// close their streams.
+    #[cfg(feature = "http")]
+    fn mcp_routes(&self, handler: McpHandler) -> (Router, Option<SseSessions>) {
+        if self.transport == Transport::Sse {
+            let tool_auth = handler.state.tool_auth.clone();
+            let sessions = SseSessions::new(
+                move |input, output| {
+                    let handler = handler.clone();
+                    let span = tracing::Span::current();
+                    tokio::spawn(
+                        async move {
+                            match handler.serve(batching(input, output)).await {
+                                Ok(service) => {
+                                    let _ = service.waiting().await;
+                                },
+                                Err(e) => tracing::debug!(error = %e, "SSE session failed"),
+                            }
+                        }
+                        .instrument(span),
+                    );
+                },
+                tool_auth,
+            );
+            sessions.forward_memory_events(global_event_bus());
+            tracing::info!("Serving MCP over SSE at /sse and /message");
+            return (sessions.router(), Some(sessions));
+        }
+
+        let session_manager = Arc::new(LocalSessionManager::default());
+        let streamable = StreamableHttpService::new(
+            move || Ok(handler.clone()),
+            session_manager,
+            StreamableHttpServerConfig::default(),
+        );
+        let routes = Router::new()
+            .route_service("/mcp", any_service(streamable))
+            .layer(axum::middleware::from_fn(map_notification_status));
+        (routes, None)
     }
 
     /// Runs the server over HTTP (feature not enabled).
@@ -1424,6 +1672,35 @@ async fn run_http(&self) -> SubcogResult<()> {
     }
 }
 
+/// Accepts connections on `socket`, serving one MCP session per connection.
+#[cfg(unix)]
+async fn serve_unix_socket(socket: &SocketListener, handler: &McpHandler) {
+    loop {
+        let stream = match socket.accept().await {
+            Ok(stream) => stream,
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to accept Unix socket connection");
+                tokio::time::sleep(Duration::from_millis(100)).await;
+                continue;
+            },
+        };
+        let handler = handler.clone();
+        let span = tracing::Span::current();
+        tokio::spawn(
+            async move {
+                let (reader, writer) = stream.into_split();
+                match handler.serve(batching(reader, writer)).await {
+                    Ok(service) => {
+                        let _ = service.waiting().await;
+                    },
+                    Err(e) => tracing::debug!(error = %e, "Unix socket session failed"),
+                }
+            }
+            .instrument(span),
+        );
+    }
+}
+
 impl Default for McpServer {
     fn default() -> Self {
         Self::new()
@@ -1456,6 +1733,166 @@ fn test_tool_definition_mapping() {
         let rmcp_tool = tool_definition_to_rmcp(tool);
         assert_eq!(rmcp_tool.name, "subcog_status");
     }
+
+    /// Line-delimited JSON-RPC client over a pipe or socket.
+    struct TestClient<R, W> {
+        writer: W,
+        responses: tokio::io::Lines<tokio::io::BufReader<R>>,
+    }
+
+    impl<R, W> TestClient<R, W>
+    where
+        R: tokio::io::AsyncRead + Unpin,
+        W: tokio::io::AsyncWrite + Unpin,
+    {
+        /// Sends a message and returns the response, if one is expected.
+        async fn exchange(&mut self, message: Value) -> Option<Value> {
+            use tokio::io::AsyncWriteExt;
+
+            let line = format!("{message}\n");
+            self.writer.write_all(line.as_bytes()).await.unwrap();
+            if message.get("id").is_none() && !message.is_array() {
+                return None;
+            }
+            let response =
+                tokio::time::timeout(Duration::from_secs(30), self.responses.next_line())
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .unwrap();
+            Some(serde_json::from_str(&response).unwrap())
+        }
+    }
+
+    fn test_handler(dir: &tempfile::TempDir) -> McpHandler {
+        let services = ServiceContainer::builder()
+            .with_config(crate::config::Config::new().with_data_dir(dir.path()))
+            .with_index(Arc::new(
+                crate::storage::index::SqliteBackend::in_memory().unwrap(),
+            ))
+            .build()
+            .unwrap();
+        McpHandler::new(
+            ToolRegistry::new(),
+            ResourceHandler::new(),
+            PromptRegistry::new(),
+            Arc::new(services),
+        )
+    }
+
+    /// The `initialize` request sent by test clients.
+    fn initialize_request() -> Value {
+        serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "initialize",
+            "params": {
+                "protocolVersion": "2024-11-05",
+                "capabilities": {},
+                "clientInfo": { "name": "test-client", "version": "1.0" }
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_batch_of_list_and_call_returns_ordered_responses() {
+        use tokio::io::{AsyncBufReadExt, BufReader};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let handler = test_handler(&dir);
+
+        let (client_writer, server_input) = tokio::io::duplex(64 * 1024);
+        let (server_output, client_reader) = tokio::io::duplex(64 * 1024);
+        let transport = batching(server_input, server_output);
+        tokio::spawn(async move {
+            if let Ok(service) = handler.serve(transport).await {
+                let _ = service.waiting().await;
+            }
+        });
+
+        let mut client = TestClient {
+            writer: client_writer,
+            responses: BufReader::new(client_reader).lines(),
+        };
+
+        let init = client
+            .exchange(serde_json::json!([initialize_request()]))
+            .await
+            .unwrap();
+        assert_eq!(init[0]["id"], 0);
+        client
+            .exchange(serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "notifications/initialized"
+            }))
+            .await;
+
+        let batch = client
+            .exchange(serde_json::json!([
+                { "jsonrpc": "2.0", "id": 1, "method": "tools/list" },
+                { "jsonrpc": "2.0", "method": "notifications/roots/list_changed" },
+                {
+                    "jsonrpc": "2.0",
+                    "id": 2,
+                    "method": "tools/call",
+                    "params": { "name": "subcog_namespaces", "arguments": {} }
+                }
+            ]))
+            .await
+            .unwrap();
+
+        let batch = batch.as_array().unwrap();
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch[0]["id"], 1);
+        assert!(batch[0]["result"]["tools"].is_array());
+        assert_eq!(batch[1]["id"], 2);
+        assert!(batch[1]["result"]["content"].is_array());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_unix_socket_lists_tools() {
+        use tokio::io::{AsyncBufReadExt, BufReader};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let handler = test_handler(&dir);
+        let path = dir.path().join("subcog.sock");
+        let socket = SocketListener::bind(&path).unwrap();
+        let server = tokio::spawn(async move { serve_unix_socket(&socket, &handler).await });
+
+        let stream = tokio::net::UnixStream::connect(&path).await.unwrap();
+        let (reader, writer) = stream.into_split();
+        let mut client = TestClient {
+            writer,
+            responses: BufReader::new(reader).lines(),
+        };
+
+        let init = client.exchange(initialize_request()).await.unwrap();
+        assert_eq!(init["id"], 0);
+        client
+            .exchange(serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": "notifications/initialized"
+            }))
+            .await;
+
+        let tools = client
+            .exchange(serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "tools/list" }))
+            .await
+            .unwrap();
+        let names: Vec<&str> = tools["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|tool| tool["name"].as_str())
+            .collect();
+        assert!(names.contains(&"subcog_status"));
+
+        // Stopping the server removes the socket file
+        server.abort();
+        let _ = server.await;
+        assert!(!path.exists());
+    }
 }
 
 #[cfg(all(test, feature = "http"))]
@@ -1544,7 +1981,8 @@ fn build_app(state: HttpAuthState) -> Router {
     fn build_state(max_requests: usize) -> HttpAuthState {
         let config = JwtConfig::new(TEST_JWT_SECRET);
         HttpAuthState {
-            authenticator: JwtAuthenticator::new(&config),
+            authenticator: Some(JwtAuthenticator::new(&config)),
+            token_authenticator: None,
             rate_limit: RateLimitConfig {
                 max_requests,
                 window: Duration::from_secs(60),
@@ -1608,4 +2046,61 @@ async fn test_auth_middleware_rate_limit_exceeded() {
         let second = app.oneshot(request).await.expect("response");
         assert_eq!(second.status(), StatusCode::TOO_MANY_REQUESTS);
     }
+
+    async fn status_with_header(app: &Router, auth_header: Option<&str>) -> StatusCode {
+        let request = Request::builder().uri("/");
+        let request = match auth_header {
+            Some(value) => request.header(header::AUTHORIZATION, value),
+            None => request,
+        };
+        let request = request.body(Body::empty()).expect("request");
+        app.clone()
+            .oneshot(request)
+            .await
+            .expect("response")
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_auth_middleware_static_token() {
+        let state = HttpAuthState {
+            authenticator: None,
+            token_authenticator: Some(TokenAuthenticator::new("static-test-token").expect("token")),
+            ..build_state(5)
+        };
+        let app = build_app(state);
+
+        assert_eq!(
+            status_with_header(&app, None).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status_with_header(&app, Some("Bearer wrong-token")).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status_with_header(&app, Some("Bearer static-test-token")).await,
+            StatusCode::OK
+        );
+    }
+
+    #[tokio::test]
+    async fn test_auth_middleware_static_token_with_jwt() {
+        let state = HttpAuthState {
+            token_authenticator: Some(TokenAuthenticator::new("static-test-token").expect("token")),
+            ..build_state(5)
+        };
+        let app = build_app(state);
+        let jwt = format!("Bearer {}", create_test_token("client-a"));
+
+        assert_eq!(
+            status_with_header(&app, Some("Bearer static-test-token")).await,
+            StatusCode::OK
+        );
+        assert_eq!(status_with_header(&app, Some(&jwt)).await, StatusCode::OK);
+        assert_eq!(
+            status_with_header(&app, Some("Bearer wrong-token")).await,
+            StatusCode::UNAUTHORIZED
+        );
+    }
 }
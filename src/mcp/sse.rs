@@ -0,0 +1,721 @@
+//! Server-sent events (SSE) transport.
+//!
+//! Serves MCP to clients that keep one long-lived HTTP connection open for
+//! server-to-client messages:
+//!
+//! - `GET /sse` opens an event stream. Its first event, `endpoint`, carries
+//!   the URL to post messages to (`/message?sessionId=<id>`).
+//! - `POST /message?sessionId=<id>` takes one JSON-RPC message or batch and
+//!   answers `202 Accepted`; the responses arrive on the stream as `message`
+//!   events.
+//! - Memory changes are pushed to every stream unprompted, as
+//!   `notifications/resources/updated` for `subcog://memory/{id}` or, for
+//!   sync and consolidation, `notifications/resources/list_changed`.
+//!
+//! Each session runs the same handler as the stdio and Unix socket
+//! transports over an in-process pipe, so tool, prompt and resource dispatch
+//! is shared and only the framing differs. The routes sit behind the JWT
+//! authentication of the HTTP transport; a session can only be posted to and
+//! resumed by the client (`sub` claim) that opened it, and `tools/call`
+//! messages are checked against the token's scopes before they are
+//! forwarded.
+//!
+//! # Reconnecting
+//!
+//! Every message event has the ID `<session>/<sequence>`. A client that
+//! reconnects with that ID in `Last-Event-ID` resumes its session and gets
+//! the events it missed replayed. A session keeps its last
+//! [`REPLAY_CAPACITY`] events and is dropped [`SESSION_IDLE_TIMEOUT`] after
+//! its last stream closed; reconnecting after that opens a new session, which
+//! the client has to initialize again.
+//!
+//! # Shutdown
+//!
+//! [`SseSessions::close`] ends every open stream and session, so the HTTP
+//! server's graceful shutdown does not wait on connections that never end.
+
+use super::auth::{Claims, ToolAuthorization};
+use super::server::error_response;
+use crate::models::MemoryEvent;
+use crate::observability::EventBus;
+use axum::Router;
+use axum::extract::{Query, Request, State};
+use axum::http::StatusCode;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Response};
+use axum::routing::{get, post};
+use futures_util::Stream;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use std::collections::{HashMap, VecDeque};
+use std::convert::Infallible;
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, DuplexStream};
+use tokio::sync::{Mutex, broadcast, watch};
+
+/// Number of events a session keeps for replay after a reconnect.
+pub const REPLAY_CAPACITY: usize = 256;
+
+/// Time a session without an open stream is kept for a reconnect.
+pub const SESSION_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Interval at which idle sessions are dropped.
+const REAP_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Buffer size of the pipes between a session and its handler.
+const PIPE_CAPACITY: usize = 64 * 1024;
+
+/// Largest accepted message body.
+const MAX_MESSAGE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Starts a handler reading JSON-RPC lines from its first argument and
+/// writing responses to its second.
+type SessionStarter = dyn Fn(DuplexStream, DuplexStream) + Send + Sync;
+
+/// Events of a session, oldest first.
+#[derive(Debug, Default)]
+struct EventLog {
+    /// Retained events with their sequence numbers.
+    events: VecDeque<(u64, String)>,
+    /// Sequence number of the newest event.
+    last: u64,
+}
+
+impl EventLog {
+    /// Appends `data` and returns its sequence number.
+    fn push(&mut self, data: String) -> u64 {
+        self.last += 1;
+        self.events.push_back((self.last, data));
+        if self.events.len() > REPLAY_CAPACITY {
+            self.events.pop_front();
+        }
+        self.last
+    }
+
+    /// Returns the retained events after sequence number `cursor`.
+    fn after(&self, cursor: u64) -> Vec<(u64, String)> {
+        self.events
+            .iter()
+            .filter(|(seq, _)| *seq > cursor)
+            .cloned()
+            .collect()
+    }
+}
+
+/// Open streams of a session.
+#[derive(Debug)]
+struct Streams {
+    /// Number of open streams.
+    open: usize,
+    /// When the last stream closed.
+    idle_since: Instant,
+}
+
+/// One MCP session and its event log.
+struct Session {
+    /// Session ID.
+    id: String,
+    /// `sub` claim of the client that opened the session.
+    owner: Option<String>,
+    /// Pipe into the session's handler.
+    input: Mutex<DuplexStream>,
+    /// Events sent to the client.
+    log: std::sync::Mutex<EventLog>,
+    /// Sequence number of the newest event, to wake streams.
+    latest: watch::Sender<u64>,
+    /// Open streams.
+    streams: std::sync::Mutex<Streams>,
+}
+
+impl Session {
+    /// Appends a JSON-RPC message to the log and wakes the streams.
+    fn push(&self, data: String) {
+        let seq = self
+            .log
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(data);
+        self.latest.send_replace(seq);
+    }
+
+    fn events_after(&self, cursor: u64) -> Vec<(u64, String)> {
+        self.log
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .after(cursor)
+    }
+
+    /// Passes one JSON-RPC line to the handler.
+    async fn send(&self, line: &str) -> std::io::Result<()> {
+        let mut input = self.input.lock().await;
+        input.write_all(line.as_bytes()).await?;
+        input.write_all(b"\n").await?;
+        input.flush().await
+    }
+
+    /// Returns `true` if no stream is open and none was for `timeout`.
+    fn is_idle(&self, now: Instant, timeout: Duration) -> bool {
+        let streams = self.streams.lock().unwrap_or_else(PoisonError::into_inner);
+        streams.open == 0 && now.duration_since(streams.idle_since) >= timeout
+    }
+}
+
+/// Marks a session as having an open stream until dropped.
+struct Attachment {
+    session: Arc<Session>,
+}
+
+impl Attachment {
+    fn new(session: Arc<Session>) -> Self {
+        session
+            .streams
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .open += 1;
+        Self { session }
+    }
+}
+
+impl Drop for Attachment {
+    fn drop(&mut self) {
+        let mut streams = self
+            .session
+            .streams
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        streams.open = streams.open.saturating_sub(1);
+        streams.idle_since = Instant::now();
+    }
+}
+
+/// State of one open event stream.
+struct StreamState {
+    /// Session the stream belongs to.
+    attachment: Attachment,
+    /// Sequence number of the last event sent.
+    cursor: u64,
+    /// Wakes the stream when the session has new events.
+    latest: watch::Receiver<u64>,
+    /// Ends the stream on shutdown.
+    closed: watch::Receiver<bool>,
+    /// Events ready to send.
+    queue: VecDeque<Event>,
+}
+
+struct SessionsInner {
+    /// Open sessions by ID.
+    sessions: std::sync::Mutex<HashMap<String, Arc<Session>>>,
+    /// Starts the handler of a new session.
+    start_session: Box<SessionStarter>,
+    /// Scopes required by each tool.
+    tool_auth: ToolAuthorization,
+    /// Set once the transport shuts down.
+    closed: watch::Sender<bool>,
+}
+
+/// Sessions of the SSE transport.
+#[derive(Clone)]
+pub struct SseSessions {
+    inner: Arc<SessionsInner>,
+}
+
+/// Query string of `POST /message`.
+#[derive(Debug, Deserialize)]
+struct MessageQuery {
+    #[serde(rename = "sessionId")]
+    session_id: String,
+}
+
+impl SseSessions {
+    /// Creates the session table.
+    ///
+    /// `start_session` is called with the handler's input and output pipes
+    /// for every new session and must serve the session in the background.
+    /// Must be called inside a tokio runtime, which runs the task dropping
+    /// idle sessions.
+    pub fn new(
+        start_session: impl Fn(DuplexStream, DuplexStream) + Send + Sync + 'static,
+        tool_auth: ToolAuthorization,
+    ) -> Self {
+        let sessions = Self {
+            inner: Arc::new(SessionsInner {
+                sessions: std::sync::Mutex::new(HashMap::new()),
+                start_session: Box::new(start_session),
+                tool_auth,
+                closed: watch::Sender::new(false),
+            }),
+        };
+        sessions.spawn_reaper();
+        sessions
+    }
+
+    /// Returns the `/sse` and `/message` routes.
+    pub fn router(&self) -> Router {
+        Router::new()
+            .route("/sse", get(open_stream))
+            .route("/message", post(post_message))
+            .with_state(self.clone())
+    }
+
+    /// Pushes resource notifications for the memory events on `event_bus`
+    /// to every session until the transport closes.
+    pub fn forward_memory_events(&self, event_bus: &EventBus) {
+        let mut events = event_bus.subscribe();
+        let mut closed = self.inner.closed.subscribe();
+        let sessions = Arc::downgrade(&self.inner);
+        tokio::spawn(async move {
+            loop {
+                let notification = tokio::select! {
+                    received = events.recv() => match received {
+                        Ok(event) => resource_notification(&event),
+                        // Missed events may have changed anything
+                        Err(broadcast::error::RecvError::Lagged(_)) => Some(list_changed()),
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    },
+                    _ = closed.wait_for(|closed| *closed) => break,
+                };
+                let Some(inner) = sessions.upgrade() else {
+                    break;
+                };
+                if let Some(notification) = notification {
+                    Self { inner }.broadcast(&notification.to_string());
+                }
+            }
+        });
+    }
+
+    /// Ends every stream and drops every session.
+    pub fn close(&self) {
+        self.inner.closed.send_replace(true);
+        let count = {
+            let mut sessions = self.lock_sessions();
+            let count = sessions.len();
+            sessions.clear();
+            count
+        };
+        tracing::info!(sessions = count, "Closed SSE sessions");
+    }
+
+    /// Returns the number of open sessions.
+    pub fn session_count(&self) -> usize {
+        self.lock_sessions().len()
+    }
+
+    fn lock_sessions(&self) -> std::sync::MutexGuard<'_, HashMap<String, Arc<Session>>> {
+        self.inner
+            .sessions
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get(&self, id: &str) -> Option<Arc<Session>> {
+        self.lock_sessions().get(id).cloned()
+    }
+
+    /// Resumes the session named in `last_event_id`, or creates one.
+    ///
+    /// Returns the session and the sequence number to replay from.
+    fn open(
+        &self,
+        last_event_id: Option<&str>,
+        owner: Option<String>,
+    ) -> Result<(Arc<Session>, u64), StatusCode> {
+        if let Some((id, cursor)) = last_event_id.and_then(parse_event_id)
+            && let Some(session) = self.get(id)
+        {
+            if session.owner != owner {
+                return Err(StatusCode::FORBIDDEN);
+            }
+            metrics::counter!("mcp_sse_reconnects_total").increment(1);
+            return Ok((session, cursor));
+        }
+        Ok((self.create(owner), 0))
+    }
+
+    fn create(&self, owner: Option<String>) -> Arc<Session> {
+        let (input, handler_input) = tokio::io::duplex(PIPE_CAPACITY);
+        let (handler_output, output) = tokio::io::duplex(PIPE_CAPACITY);
+        let session = Arc::new(Session {
+            id: uuid::Uuid::new_v4().simple().to_string(),
+            owner,
+            input: Mutex::new(input),
+            log: std::sync::Mutex::new(EventLog::default()),
+            latest: watch::Sender::new(0),
+            streams: std::sync::Mutex::new(Streams {
+                open: 0,
+                idle_since: Instant::now(),
+            }),
+        });
+
+        (self.inner.start_session)(handler_input, handler_output);
+        // Weak, so dropping the session closes the handler's input and ends it
+        let weak = Arc::downgrade(&session);
+        tokio::spawn(async move {
+            let mut lines = BufReader::new(output).lines();
+            while let Ok(Some(line)) = lines.next_line().await {
+                let Some(session) = weak.upgrade() else {
+                    return;
+                };
+                session.push(line);
+            }
+        });
+
+        self.lock_sessions()
+            .insert(session.id.clone(), Arc::clone(&session));
+        metrics::counter!("mcp_sse_sessions_total").increment(1);
+        tracing::debug!(session = %session.id, "Opened SSE session");
+        session
+    }
+
+    /// Appends `message` to every session.
+    fn broadcast(&self, message: &str) {
+        let sessions: Vec<Arc<Session>> = self.lock_sessions().values().cloned().collect();
+        for session in sessions {
+            session.push(message.to_string());
+        }
+    }
+
+    /// Drops sessions that have had no open stream for `timeout`.
+    fn reap(&self, now: Instant, timeout: Duration) {
+        self.lock_sessions()
+            .retain(|_, session| !session.is_idle(now, timeout));
+    }
+
+    fn spawn_reaper(&self) {
+        let sessions = Arc::downgrade(&self.inner);
+        let mut closed = self.inner.closed.subscribe();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(REAP_INTERVAL);
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {},
+                    _ = closed.wait_for(|closed| *closed) => break,
+                }
+                let Some(inner) = sessions.upgrade() else {
+                    break;
+                };
+                Self { inner }.reap(Instant::now(), SESSION_IDLE_TIMEOUT);
+            }
+        });
+    }
+
+    /// Returns the events of `session` from `cursor` on, starting with the
+    /// `endpoint` event.
+    fn event_stream(
+        &self,
+        session: Arc<Session>,
+        cursor: u64,
+    ) -> impl Stream<Item = Result<Event, Infallible>> + Send + 'static {
+        let endpoint = Event::default()
+            .event("endpoint")
+            .data(format!("/message?sessionId={}", session.id));
+        let state = StreamState {
+            latest: session.latest.subscribe(),
+            closed: self.inner.closed.subscribe(),
+            attachment: Attachment::new(session),
+            cursor,
+            queue: VecDeque::from([endpoint]),
+        };
+        futures_util::stream::unfold(state, next_event)
+    }
+}
+
+/// Waits for the next event of a stream; `None` ends the stream.
+async fn next_event(mut state: StreamState) -> Option<(Result<Event, Infallible>, StreamState)> {
+    loop {
+        if *state.closed.borrow() {
+            return None;
+        }
+        if let Some(event) = state.queue.pop_front() {
+            return Some((Ok(event), state));
+        }
+
+        state.latest.mark_unchanged();
+        let session = &state.attachment.session;
+        for (seq, data) in session.events_after(state.cursor) {
+            state.queue.push_back(
+                Event::default()
+                    .event("message")
+                    .id(format!("{}/{seq}", session.id))
+                    .data(data),
+            );
+            state.cursor = seq;
+        }
+        if !state.queue.is_empty() {
+            continue;
+        }
+
+        tokio::select! {
+            changed = state.latest.changed() => if changed.is_err() {
+                return None;
+            },
+            changed = state.closed.changed() => if changed.is_err() {
+                return None;
+            },
+        }
+    }
+}
+
+/// Handler for `GET /sse`.
+async fn open_stream(State(sessions): State<SseSessions>, request: Request) -> Response {
+    let owner = request
+        .extensions()
+        .get::<Claims>()
+        .map(|claims| claims.sub.clone());
+    let last_event_id = request
+        .headers()
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok());
+
+    match sessions.open(last_event_id, owner) {
+        Ok((session, cursor)) => Sse::new(sessions.event_stream(session, cursor))
+            .keep_alive(KeepAlive::default())
+            .into_response(),
+        Err(status) => error_response(status, "Session belongs to another client"),
+    }
+}
+
+/// Handler for `POST /message`.
+async fn post_message(State(sessions): State<SseSessions>, request: Request) -> Response {
+    let Ok(Query(query)) = Query::<MessageQuery>::try_from_uri(request.uri()) else {
+        return error_response(StatusCode::BAD_REQUEST, "Missing sessionId");
+    };
+    let Some(session) = sessions.get(&query.session_id) else {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            "Unknown or expired session; reconnect to /sse",
+        );
+    };
+    let claims = request.extensions().get::<Claims>().cloned();
+    if session.owner != claims.as_ref().map(|claims| claims.sub.clone()) {
+        return error_response(StatusCode::FORBIDDEN, "Session belongs to another client");
+    }
+
+    let body = match axum::body::to_bytes(request.into_body(), MAX_MESSAGE_BYTES).await {
+        Ok(body) => body,
+        Err(e) => return error_response(StatusCode::PAYLOAD_TOO_LARGE, &e.to_string()),
+    };
+    // The handler reads one message per line
+    let line = match serde_json::from_slice::<Value>(&body) {
+        Ok(message) => {
+            if let Some(claims) = &claims
+                && let Some(reason) = forbidden_tool(&sessions.inner.tool_auth, claims, &message)
+            {
+                return error_response(StatusCode::FORBIDDEN, &reason);
+            }
+            message.to_string()
+        },
+        // Forwarded so the handler answers with a parse error
+        Err(_) => String::from_utf8_lossy(&body).replace(['\r', '\n'], " "),
+    };
+
+    if session.send(&line).await.is_err() {
+        return error_response(StatusCode::GONE, "Session closed");
+    }
+    StatusCode::ACCEPTED.into_response()
+}
+
+/// Returns why `message` calls a tool `claims` lacks the scope for, if it
+/// does.
+fn forbidden_tool(
+    tool_auth: &ToolAuthorization,
+    claims: &Claims,
+    message: &Value,
+) -> Option<String> {
+    let messages = match message {
+        Value::Array(messages) => messages.as_slice(),
+        message => std::slice::from_ref(message),
+    };
+    messages
+        .iter()
+        .filter(|message| message["method"] == "tools/call")
+        .map(|message| message["params"]["name"].as_str().unwrap_or_default())
+        .find(|tool| !tool_auth.is_authorized(claims, tool))
+        .map(|tool| {
+            let scope = tool_auth.required_scope(tool).unwrap_or("unknown");
+            format!("Forbidden: tool '{tool}' requires '{scope}' scope")
+        })
+}
+
+/// Splits an event ID into session ID and sequence number.
+fn parse_event_id(id: &str) -> Option<(&str, u64)> {
+    let (session, seq) = id.rsplit_once('/')?;
+    Some((session, seq.parse().ok()?))
+}
+
+/// Returns the notification for a memory event, if it changes resources.
+fn resource_notification(event: &MemoryEvent) -> Option<Value> {
+    match event {
+        MemoryEvent::Captured { memory_id, .. }
+        | MemoryEvent::Updated { memory_id, .. }
+        | MemoryEvent::Archived { memory_id, .. }
+        | MemoryEvent::Deleted { memory_id, .. }
+        | MemoryEvent::Redacted { memory_id, .. } => Some(json!({
+            "jsonrpc": "2.0",
+            "method": "notifications/resources/updated",
+            "params": { "uri": format!("subcog://memory/{memory_id}") },
+        })),
+        MemoryEvent::Synced { .. } | MemoryEvent::Consolidated { .. } => Some(list_changed()),
+        _ => None,
+    }
+}
+
+fn list_changed() -> Value {
+    json!({ "jsonrpc": "2.0", "method": "notifications/resources/list_changed" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Domain, EventMeta, MemoryId, Namespace};
+    use futures_util::StreamExt;
+
+    /// Session table whose handlers echo every line back.
+    fn echo_sessions() -> SseSessions {
+        SseSessions::new(
+            |input, mut output| {
+                tokio::spawn(async move {
+                    let mut lines = BufReader::new(input).lines();
+                    while let Ok(Some(line)) = lines.next_line().await {
+                        let reply = format!("{line}\n");
+                        if output.write_all(reply.as_bytes()).await.is_err() {
+                            return;
+                        }
+                    }
+                });
+            },
+            ToolAuthorization::default(),
+        )
+    }
+
+    /// Reads the next event of `stream` as its SSE wire format.
+    async fn next_frame(
+        stream: &mut (impl Stream<Item = Result<Event, Infallible>> + Unpin),
+    ) -> Option<String> {
+        let event = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()?
+            .unwrap();
+        let body = Sse::new(futures_util::stream::iter([Ok::<_, Infallible>(event)]))
+            .into_response()
+            .into_body();
+        let bytes = axum::body::to_bytes(body, usize::MAX).await.unwrap();
+        Some(String::from_utf8(bytes.to_vec()).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_messages_are_streamed_and_replayed_after_reconnect() {
+        let sessions = echo_sessions();
+        let (session, cursor) = sessions.open(None, Some("alice".to_string())).unwrap();
+        let mut stream = Box::pin(sessions.event_stream(Arc::clone(&session), cursor));
+
+        let endpoint = next_frame(&mut stream).await.unwrap();
+        assert!(endpoint.contains("event: endpoint"));
+        assert!(endpoint.contains(&format!("/message?sessionId={}", session.id)));
+
+        session.send(r#"{"id":1}"#).await.unwrap();
+        let first = next_frame(&mut stream).await.unwrap();
+        assert!(first.contains(&format!("id: {}/1", session.id)), "{first}");
+        assert!(first.contains(r#"data: {"id":1}"#));
+
+        // The client drops off, misses a message, and reconnects
+        drop(stream);
+        session.send(r#"{"id":2}"#).await.unwrap();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while session.events_after(1).is_empty() {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .unwrap();
+
+        let last_event_id = format!("{}/1", session.id);
+        assert_eq!(
+            sessions
+                .open(Some(&last_event_id), Some("mallory".to_string()))
+                .err(),
+            Some(StatusCode::FORBIDDEN)
+        );
+        let (resumed, cursor) = sessions
+            .open(Some(&last_event_id), Some("alice".to_string()))
+            .unwrap();
+        assert_eq!(resumed.id, session.id);
+        let mut stream = Box::pin(sessions.event_stream(resumed, cursor));
+        next_frame(&mut stream).await.unwrap();
+        let replayed = next_frame(&mut stream).await.unwrap();
+        assert!(replayed.contains(r#"data: {"id":2}"#), "{replayed}");
+        assert_eq!(sessions.session_count(), 1);
+
+        // An unknown session starts over
+        let (fresh, cursor) = sessions.open(Some("gone/7"), None).unwrap();
+        assert_ne!(fresh.id, session.id);
+        assert_eq!(cursor, 0);
+    }
+
+    #[tokio::test]
+    async fn test_memory_events_are_pushed_and_close_ends_streams() {
+        let sessions = echo_sessions();
+        let bus = EventBus::new(16);
+        sessions.forward_memory_events(&bus);
+        let (session, cursor) = sessions.open(None, None).unwrap();
+        let mut stream = Box::pin(sessions.event_stream(session, cursor));
+        next_frame(&mut stream).await.unwrap();
+
+        bus.publish(MemoryEvent::Captured {
+            meta: EventMeta::new("test", None),
+            memory_id: MemoryId::new("abc123"),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            content_length: 10,
+        });
+        let notification = next_frame(&mut stream).await.unwrap();
+        assert!(notification.contains("notifications/resources/updated"));
+        assert!(notification.contains("subcog://memory/abc123"));
+
+        sessions.close();
+        assert_eq!(next_frame(&mut stream).await, None);
+        assert_eq!(sessions.session_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_idle_sessions_are_reaped() {
+        let sessions = echo_sessions();
+        let (attached, cursor) = sessions.open(None, None).unwrap();
+        let _stream = sessions.event_stream(attached, cursor);
+        sessions.open(None, None).unwrap();
+        assert_eq!(sessions.session_count(), 2);
+
+        let later = Instant::now() + SESSION_IDLE_TIMEOUT;
+        sessions.reap(later, SESSION_IDLE_TIMEOUT);
+        assert_eq!(sessions.session_count(), 1);
+    }
+
+    #[test]
+    fn test_forbidden_tool() {
+        let claims: Claims = serde_json::from_value(json!({
+            "sub": "alice",
+            "exp": 0,
+            "scopes": ["read"],
+        }))
+        .unwrap();
+        let auth = ToolAuthorization::default();
+        let call = |name: &str| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "tools/call",
+                "params": { "name": name },
+            })
+        };
+
+        assert_eq!(forbidden_tool(&auth, &claims, &call("subcog_recall")), None);
+        let batch = json!([call("subcog_recall"), call("subcog_capture")]);
+        assert_eq!(
+            forbidden_tool(&auth, &claims, &batch).as_deref(),
+            Some("Forbidden: tool 'subcog_capture' requires 'write' scope")
+        );
+        assert_eq!(parse_event_id("abc/12"), Some(("abc", 12)));
+        assert_eq!(parse_event_id("abc"), None);
+    }
+}
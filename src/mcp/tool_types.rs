@@ -34,6 +34,9 @@ pub struct CaptureArgs {
     /// - "user": Stored globally for user across all projects
     /// - "org": Stored in organization-shared index
     pub domain: Option<String>,
+    /// Bypass the secrets filter. Rejected unless `security.allow_skip` is
+    /// enabled, and only advertised in the tool schema when it is.
+    pub skip_security_check: Option<bool>,
 }
 
 /// Arguments for the recall tool.
@@ -51,20 +54,52 @@ pub struct RecallArgs {
     pub namespace: Option<String>,
     /// Search mode: "hybrid" (default), "vector", or "text".
     pub mode: Option<String>,
+    /// Hybrid ranking weight: 0.0 keyword (BM25) only, 1.0 semantic (vector) only.
+    pub alpha: Option<f32>,
     /// Detail level: "light", "medium" (default), or "everything".
     pub detail: Option<String>,
     /// Maximum number of results to return (default: 10 for search, 50 for list).
     pub limit: Option<usize>,
     /// Entity filter: filter to memories mentioning these entities (comma-separated for OR logic).
     pub entity: Option<String>,
-    /// Offset for pagination (default: 0). Used when listing without query.
-    /// Note: Reserved for future pagination support in `list_all()`.
-    #[allow(dead_code)]
+    /// Number of ranked results to skip, for paging (default: 0).
     pub offset: Option<usize>,
     /// Filter by user ID (for multi-tenant scoping).
     pub user_id: Option<String>,
     /// Filter by agent ID (for multi-agent scoping).
     pub agent_id: Option<String>,
+    /// Search scope: `"all"` searches project, user, and org scopes together.
+    pub scope: Option<String>,
+    /// Reorder the top candidates by LLM-judged relevance.
+    pub rerank: Option<bool>,
+    /// Expand the query with LLM-suggested related terms (default: `[search] expand`).
+    pub expand: Option<bool>,
+    /// Group the results by `namespace`, `tag`, or `source` (default: `none`).
+    pub group_by: Option<String>,
+}
+
+/// Arguments for the facets tool.
+///
+/// Takes the same query, filter, and limit as `subcog_recall` and returns
+/// only the match counts.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FacetsArgs {
+    /// Search query text. If omitted, empty, or `*`, counts all memories
+    /// matching the filter.
+    pub query: Option<String>,
+    /// GitHub-style filter query (e.g., "ns:decisions tag:rust -tag:test since:7d").
+    pub filter: Option<String>,
+    /// Search mode: "hybrid" (default), "vector", or "text".
+    pub mode: Option<String>,
+    /// Number of results counted (default: 10 for search, 50 for list).
+    pub limit: Option<usize>,
+    /// Entity filter: filter to memories mentioning these entities (comma-separated for OR logic).
+    pub entity: Option<String>,
+    /// Expand the query with LLM-suggested related terms (default: `[search] expand`).
+    pub expand: Option<bool>,
+    /// Also count the matches per tag.
+    pub tags: Option<bool>,
 }
 
 /// Arguments for the consolidate tool.
@@ -103,6 +138,10 @@ pub struct EnrichArgs {
     pub enrich_structure: Option<bool>,
     /// Add inferred context and rationale (default: false).
     pub add_context: Option<bool>,
+    /// LLM provider for this request, overriding `[llm] provider`.
+    pub provider: Option<String>,
+    /// LLM model for this request, overriding the provider default.
+    pub model: Option<String>,
 }
 
 /// Arguments for the reindex tool.
@@ -113,6 +152,35 @@ pub struct ReindexArgs {
     pub repo_path: Option<String>,
 }
 
+/// Arguments for the export tool.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExportArgs {
+    /// Output format: json, yaml, csv, markdown or parquet (default: from `path`, else json).
+    pub format: Option<String>,
+    /// Filter query (same syntax as `subcog_recall`).
+    pub filter: Option<String>,
+    /// Maximum number of memories to export.
+    pub limit: Option<usize>,
+    /// File to write; without it the serialized memories are returned.
+    pub path: Option<String>,
+}
+
+/// Arguments for the import tool.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImportArgs {
+    /// Input format: json, yaml, csv or parquet (default: from `path`, else json).
+    pub format: Option<String>,
+    /// Serialized memories to import (exclusive with `path`).
+    pub content: Option<String>,
+    /// File to import (exclusive with `content`).
+    pub path: Option<String>,
+    /// Skip records matching an existing memory (default: true).
+    #[serde(default = "default_true")]
+    pub skip_duplicates: bool,
+}
+
 // ============================================================================
 // Core CRUD Tool Arguments (Industry Parity: Mem0, Zep, LangMem)
 // ============================================================================
@@ -145,7 +213,8 @@ pub struct DeleteArgs {
 
 /// Arguments for the update tool.
 ///
-/// Allows updating content and/or tags of an existing memory.
+/// Allows updating the content, tags, namespace, and/or status of an
+/// existing memory.
 /// Follows the industry pattern of partial updates (Mem0 `update`,
 /// `LangMem` `update`).
 #[derive(Debug, Deserialize)]
@@ -158,6 +227,10 @@ pub struct UpdateArgs {
     /// New tags (optional - if not provided, tags unchanged).
     /// Replaces existing tags entirely when provided.
     pub tags: Option<Vec<String>>,
+    /// New namespace (optional).
+    pub namespace: Option<String>,
+    /// New status (optional): active, archived, superseded, pending, etc.
+    pub status: Option<String>,
 }
 
 /// Arguments for the list tool.
@@ -719,6 +792,20 @@ pub struct ContextTemplateDeleteArgs {
     pub domain: String,
 }
 
+/// Arguments for the `subcog_suggest_namespace` tool.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SuggestNamespaceArgs {
+    /// Text to classify.
+    pub text: String,
+    /// Maximum number of suggestions (default: 3).
+    pub limit: Option<usize>,
+    /// LLM provider for this request, overriding `[llm] provider`.
+    pub provider: Option<String>,
+    /// LLM model for this request, overriding the provider default.
+    pub model: Option<String>,
+}
+
 /// Arguments for the `subcog_init` tool.
 #[derive(Debug, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -965,6 +1052,10 @@ pub fn build_filter_description(filter: &SearchFilter) -> String {
         parts.push(format!("status:{}", status_list.join(",")));
     }
 
+    for origin in &filter.origins {
+        parts.push(format!("origin:{origin}"));
+    }
+
     if filter.created_after.is_some() {
         parts.push("since:active".to_string());
     }
@@ -1125,12 +1216,20 @@ fn test_delete_args_defaults_hard_to_false() {
 
     #[test]
     fn test_update_args_rejects_unknown_fields() {
-        let json = r#"{"memory_id": "123", "namespace": "decisions"}"#;
+        let json = r#"{"memory_id": "123", "domain": "user"}"#;
         let result: Result<UpdateArgs, _> = serde_json::from_str(json);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("unknown field"));
     }
 
+    #[test]
+    fn test_update_args_accepts_namespace_and_status() {
+        let json = r#"{"memory_id": "123", "namespace": "decisions", "status": "archived"}"#;
+        let args: UpdateArgs = serde_json::from_str(json).unwrap();
+        assert_eq!(args.namespace.as_deref(), Some("decisions"));
+        assert_eq!(args.status.as_deref(), Some("archived"));
+    }
+
     #[test]
     fn test_update_args_accepts_valid_fields() {
         let json = r#"{"memory_id": "abc123", "content": "new content", "tags": ["a", "b"]}"#;
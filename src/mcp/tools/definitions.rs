@@ -47,6 +47,24 @@ pub fn capture_tool() -> ToolDefinition {
     }
 }
 
+/// Defines the capture tool with the `skip_security_check` parameter.
+///
+/// Only advertised when `security.allow_skip` is enabled.
+pub fn capture_tool_with_skip_security_check() -> ToolDefinition {
+    let mut tool = capture_tool();
+    if let Some(properties) = tool.input_schema["properties"].as_object_mut() {
+        properties.insert(
+            "skip_security_check".to_string(),
+            serde_json::json!({
+                "type": "boolean",
+                "description": "Bypass secret detection and redaction for this capture",
+                "default": false
+            }),
+        );
+    }
+    tool
+}
+
 /// Defines the recall tool.
 ///
 /// When `query` is omitted, behaves like `subcog_list` and returns all memories
@@ -65,7 +83,7 @@ pub fn recall_tool() -> ToolDefinition {
                 },
                 "filter": {
                     "type": "string",
-                    "description": "Filter query using GitHub-style syntax: ns:decisions tag:rust -tag:test since:7d source:src/*"
+                    "description": "Filter query using GitHub-style syntax: ns:decisions tag:rust -tag:test since:7d source:src/* origin:manual"
                 },
                 "namespace": {
                     "type": "string",
@@ -77,6 +95,12 @@ pub fn recall_tool() -> ToolDefinition {
                     "description": "Search mode: hybrid (default), vector, text",
                     "enum": ["hybrid", "vector", "text"]
                 },
+                "alpha": {
+                    "type": "number",
+                    "description": "Hybrid ranking weight: 0.0 ranks by keyword (BM25) matches only, 1.0 by semantic (vector) similarity only. Raise it for conceptual questions, lower it for exact names or identifiers. Default: [search] hybrid_alpha (0.5); out-of-range values are clamped.",
+                    "minimum": 0.0,
+                    "maximum": 1.0
+                },
                 "detail": {
                     "type": "string",
                     "description": "Detail level: light (frontmatter only), medium (+ summary), everything (full content). Default: medium",
@@ -94,7 +118,7 @@ pub fn recall_tool() -> ToolDefinition {
                 },
                 "offset": {
                     "type": "integer",
-                    "description": "Offset for pagination (default: 0). Used when listing without query.",
+                    "description": "Number of ranked results to skip, for paging with limit (default: 0). The output notes when more results are available.",
                     "minimum": 0,
                     "default": 0
                 },
@@ -105,6 +129,73 @@ pub fn recall_tool() -> ToolDefinition {
                 "agent_id": {
                     "type": "string",
                     "description": "Filter by agent ID (for multi-agent scoping)"
+                },
+                "scope": {
+                    "type": "string",
+                    "description": "Set to 'all' to search project, user, and org scopes together; each hit is labeled with its scope. Requires a query.",
+                    "enum": ["all"]
+                },
+                "rerank": {
+                    "type": "boolean",
+                    "description": "Reorder the top candidates by LLM-judged relevance (requires a query and an LLM provider)",
+                    "default": false
+                },
+                "expand": {
+                    "type": "boolean",
+                    "description": "Also search for LLM-suggested synonyms and related terms of the query (default: [search] expand; requires an LLM provider)"
+                },
+                "group_by": {
+                    "type": "string",
+                    "description": "Return the results as JSON grouped by namespace, tag, or source; each group keeps the relevance order",
+                    "enum": ["namespace", "tag", "source", "none"],
+                    "default": "none"
+                }
+            },
+            "required": []
+        }),
+    }
+}
+
+/// Defines the facets tool.
+pub fn facets_tool() -> ToolDefinition {
+    ToolDefinition {
+        name: "subcog_facets".to_string(),
+        description: "Count how many memories a recall matches per namespace (and optionally per tag) without returning the memories. Much cheaper than subcog_recall for dashboards and overviews; takes the same query, filter, and limit, so the counts match what subcog_recall returns.".to_string(),
+        input_schema: serde_json::json!({
+            "type": "object",
+            "additionalProperties": false,
+            "properties": {
+                "query": {
+                    "type": "string",
+                    "description": "The search query. If omitted or '*', counts all memories matching the filter."
+                },
+                "filter": {
+                    "type": "string",
+                    "description": "Filter query using GitHub-style syntax: ns:decisions tag:rust -tag:test since:7d source:src/* origin:manual"
+                },
+                "mode": {
+                    "type": "string",
+                    "description": "Search mode: hybrid (default), vector, text",
+                    "enum": ["hybrid", "vector", "text"]
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Number of results counted, as in subcog_recall (default: 10 for search, 50 for list)",
+                    "minimum": 1,
+                    "maximum": 1000
+                },
+                "entity": {
+                    "type": "string",
+                    "description": "Filter by entity names (memories mentioning these entities). Comma-separated for OR logic (e.g., 'PostgreSQL,Redis')"
+                },
+                "expand": {
+                    "type": "boolean",
+                    "description": "Also search for LLM-suggested synonyms and related terms of the query (default: [search] expand; requires an LLM provider)"
+                },
+                "tags": {
+                    "type": "boolean",
+                    "description": "Also count the matches per tag",
+                    "default": false
                 }
             },
             "required": []
@@ -151,6 +242,38 @@ pub fn namespaces_tool() -> ToolDefinition {
     }
 }
 
+/// Defines the suggest namespace tool.
+pub fn suggest_namespace_tool() -> ToolDefinition {
+    ToolDefinition {
+        name: "subcog_suggest_namespace".to_string(),
+        description: "Suggest the best namespaces for a piece of text, with confidence scores, without capturing it".to_string(),
+        input_schema: serde_json::json!({
+            "type": "object",
+            "properties": {
+                "text": {
+                    "type": "string",
+                    "description": "The text to classify"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of suggestions (default: 3)",
+                    "minimum": 1
+                },
+                "provider": {
+                    "type": "string",
+                    "enum": ["anthropic", "openai", "gemini", "ollama", "lmstudio"],
+                    "description": "LLM provider for capture analysis in this request (defaults to [llm] provider)"
+                },
+                "model": {
+                    "type": "string",
+                    "description": "LLM model for capture analysis in this request"
+                }
+            },
+            "required": ["text"]
+        }),
+    }
+}
+
 /// Defines the consolidate tool.
 pub fn consolidate_tool() -> ToolDefinition {
     ToolDefinition {
@@ -216,7 +339,7 @@ pub fn get_summary_tool() -> ToolDefinition {
 pub fn enrich_tool() -> ToolDefinition {
     ToolDefinition {
         name: "subcog_enrich".to_string(),
-        description: "Enrich a memory with better structure, tags, and context using LLM. Uses MCP sampling to request LLM completion.".to_string(),
+        description: "Enrich a memory with better structure, tags, and context using LLM. Uses MCP sampling to request LLM completion, or generates tags server-side when provider or model is given.".to_string(),
         input_schema: serde_json::json!({
             "type": "object",
             "properties": {
@@ -238,6 +361,15 @@ pub fn enrich_tool() -> ToolDefinition {
                     "type": "boolean",
                     "description": "Add inferred context and rationale",
                     "default": false
+                },
+                "provider": {
+                    "type": "string",
+                    "enum": ["anthropic", "openai", "gemini", "ollama", "lmstudio"],
+                    "description": "LLM provider for this request. When provider or model is set, tags are generated server-side instead of via sampling"
+                },
+                "model": {
+                    "type": "string",
+                    "description": "LLM model for this request (defaults to the provider's default model)"
                 }
             },
             "required": ["memory_id"]
@@ -301,6 +433,70 @@ pub fn gdpr_export_tool() -> ToolDefinition {
     }
 }
 
+/// Defines the bulk export tool.
+pub fn export_tool() -> ToolDefinition {
+    ToolDefinition {
+        name: "subcog_export".to_string(),
+        description: "Export memories in bulk as JSON, YAML, CSV or Markdown. Returns the serialized memories, or writes them to `path` and returns the path. Use `subcog_import` to load them elsewhere.".to_string(),
+        input_schema: serde_json::json!({
+            "type": "object",
+            "properties": {
+                "format": {
+                    "type": "string",
+                    "description": "Output format (default: from the path extension, else json). Parquet requires `path`.",
+                    "enum": ["json", "yaml", "csv", "markdown", "parquet"]
+                },
+                "filter": {
+                    "type": "string",
+                    "description": "GitHub-style filter query: ns:decisions tag:rust -tag:test since:7d"
+                },
+                "limit": {
+                    "type": "integer",
+                    "description": "Maximum number of memories to export",
+                    "minimum": 1
+                },
+                "path": {
+                    "type": "string",
+                    "description": "File to write instead of returning the content; must be a new file in the subcog data directory (relative paths resolve there)"
+                }
+            },
+            "required": []
+        }),
+    }
+}
+
+/// Defines the bulk import tool.
+pub fn import_tool() -> ToolDefinition {
+    ToolDefinition {
+        name: "subcog_import".to_string(),
+        description: "Import memories in bulk from JSON, YAML, CSV or Parquet, given inline as `content` or as a file `path`. Returns counts of imported, skipped and failed records.".to_string(),
+        input_schema: serde_json::json!({
+            "type": "object",
+            "properties": {
+                "format": {
+                    "type": "string",
+                    "description": "Input format (default: from the path extension, else json). Parquet requires `path`.",
+                    "enum": ["json", "yaml", "csv", "parquet"]
+                },
+                "content": {
+                    "type": "string",
+                    "description": "Serialized memories, e.g. the output of subcog_export"
+                },
+                "path": {
+                    "type": "string",
+                    "description": "File to import (instead of content); must be in the subcog data directory (relative paths resolve there)"
+                },
+                "skip_duplicates": {
+                    "type": "boolean",
+                    "description": "Skip records matching an existing memory",
+                    "default": true
+                }
+            },
+            "required": []
+        }),
+    }
+}
+
 // ============================================================================
 // Prompt Management Tools
 // ============================================================================
@@ -614,12 +810,12 @@ pub fn delete_tool() -> ToolDefinition {
 
 /// Defines the update tool for modifying existing memories.
 ///
-/// Allows partial updates to content and/or tags. Follows the industry
+/// Allows partial updates to content, tags, namespace, and status. Follows the industry
 /// pattern of partial updates (Mem0 `update`, `LangMem` `update`).
 pub fn update_tool() -> ToolDefinition {
     ToolDefinition {
         name: "subcog_update".to_string(),
-        description: "Update an existing memory's content and/or tags. Provide only the fields you want to change.".to_string(),
+        description: "Update an existing memory in place (same ID and URN): content, tags, namespace, and/or status. Provide only the fields you want to change.".to_string(),
         input_schema: serde_json::json!({
             "type": "object",
             "properties": {
@@ -635,6 +831,15 @@ pub fn update_tool() -> ToolDefinition {
                     "type": "array",
                     "items": { "type": "string" },
                     "description": "New tags for the memory (optional - omit to keep existing). Replaces all existing tags when provided."
+                },
+                "namespace": {
+                    "type": "string",
+                    "description": "Move the memory to this namespace (optional)"
+                },
+                "status": {
+                    "type": "string",
+                    "enum": ["active", "archived", "superseded", "pending", "deleted", "consolidated"],
+                    "description": "New status for the memory (optional); use subcog_delete to tombstone"
                 }
             },
             "required": ["memory_id"]
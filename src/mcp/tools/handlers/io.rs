@@ -0,0 +1,333 @@
+//! Bulk import and export tool execution handlers.
+//!
+//! Delegate to the [`ImportService`](crate::io::ImportService) and
+//! [`ExportService`](crate::io::ExportService) behind `subcog import` and
+//! `subcog export`, and report counts as JSON.
+
+use super::super::{ToolContent, ToolResult};
+use crate::io::{ExportOptions, Format, ImportOptions};
+use crate::mcp::tool_types::{ExportArgs, ImportArgs};
+use crate::services::ServiceContainer;
+use crate::{Error, Result};
+use serde_json::Value;
+use std::io::Cursor;
+use std::path::PathBuf;
+
+/// Maximum number of record errors listed in an import result.
+const MAX_REPORTED_ERRORS: usize = 10;
+
+/// Executes the export tool.
+///
+/// Returns the serialized memories, or the written path when `path` is set.
+/// The path must lie in the subcog data directory and not exist yet.
+pub fn execute_export(services: &ServiceContainer, arguments: Value) -> Result<ToolResult> {
+    let args: ExportArgs =
+        serde_json::from_value(arguments).map_err(|e| Error::InvalidInput(e.to_string()))?;
+
+    let path = args
+        .path
+        .as_deref()
+        .map(|path| confined_path(services, path))
+        .transpose()?;
+    if let Some(ref path) = path
+        && path.exists()
+    {
+        return Err(Error::InvalidInput(format!(
+            "Refusing to overwrite existing file: {}",
+            path.display()
+        )));
+    }
+    let path = path.as_deref();
+    let format = match (args.format.as_deref(), path) {
+        (Some(format), _) => format.parse::<Format>()?,
+        (None, Some(path)) => Format::from_path(path).unwrap_or(Format::Json),
+        (None, None) => Format::Json,
+    };
+
+    let mut options = ExportOptions::default().with_format(format);
+    if let Some(filter) = args.filter {
+        options = options.with_filter(filter);
+    }
+    if let Some(limit) = args.limit {
+        options = options.with_limit(limit);
+    }
+
+    let export = services.export()?;
+    let summary = if let Some(path) = path {
+        let result = export.export_to_file(path, options, None)?;
+        serde_json::json!({
+            "format": result.format.to_string(),
+            "exported": result.exported,
+            "total_matched": result.total_matched,
+            "path": result.output_path,
+        })
+    } else {
+        let (content, result) = export.export_to_string(options)?;
+        serde_json::json!({
+            "format": result.format.to_string(),
+            "exported": result.exported,
+            "total_matched": result.total_matched,
+            "content": content,
+        })
+    };
+    json_result(&summary)
+}
+
+/// Executes the import tool.
+///
+/// Reports `imported`, `skipped` (duplicates) and `failed` (invalid) counts.
+/// A `path` must lie in the subcog data directory.
+pub fn execute_import(services: &ServiceContainer, arguments: Value) -> Result<ToolResult> {
+    let args: ImportArgs =
+        serde_json::from_value(arguments).map_err(|e| Error::InvalidInput(e.to_string()))?;
+
+    let format = args
+        .format
+        .as_deref()
+        .map(Format::parse_import)
+        .transpose()?;
+    let options = ImportOptions::default().with_skip_duplicates(args.skip_duplicates);
+    let import = services.import();
+
+    let (format, result) = match (args.content, args.path) {
+        (Some(content), None) => {
+            let format = format.unwrap_or(Format::Json);
+            #[cfg(feature = "parquet-export")]
+            if format == Format::Parquet {
+                return Err(Error::InvalidInput(
+                    "Parquet is a binary format; import it from a file".to_string(),
+                ));
+            }
+            let reader = Cursor::new(content.into_bytes());
+            let result = import.import_from_reader(reader, options.with_format(format), None)?;
+            (format, result)
+        },
+        (None, Some(path)) => {
+            let path = confined_path(services, &path)?;
+            let path = path.as_path();
+            let format = match format {
+                Some(format) => format,
+                None => path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .map_or(Ok(Format::Json), Format::parse_import)?,
+            };
+            let result = import.import_from_file(path, options.with_format(format), None)?;
+            (format, result)
+        },
+        _ => {
+            return Err(Error::InvalidInput(
+                "Provide either content or path".to_string(),
+            ));
+        },
+    };
+
+    let errors: Vec<&str> = result
+        .errors
+        .iter()
+        .take(MAX_REPORTED_ERRORS)
+        .map(String::as_str)
+        .collect();
+    json_result(&serde_json::json!({
+        "format": format.to_string(),
+        "imported": result.imported,
+        "skipped": result.skipped_duplicates,
+        "failed": result.skipped_invalid,
+        "total_processed": result.total_processed,
+        "errors": errors,
+    }))
+}
+
+/// Resolves a tool `path` inside the subcog data directory.
+///
+/// Relative paths are taken relative to the data directory. Paths that
+/// resolve outside it, through `..` or symlinks, are rejected: tool calls
+/// can come from any holder of an HTTP token, and must not read or write
+/// arbitrary files as the server user.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidInput`] if the path lies outside the data
+/// directory or its parent directory does not exist.
+fn confined_path(services: &ServiceContainer, path: &str) -> Result<PathBuf> {
+    let data_dir = services
+        .user_data_dir()
+        .canonicalize()
+        .map_err(|e| Error::OperationFailed {
+            operation: "resolve_data_dir".to_string(),
+            cause: e.to_string(),
+        })?;
+    let requested = data_dir.join(path);
+    let outside = || {
+        Error::InvalidInput(format!(
+            "Path must be inside the subcog data directory ({}): {path}",
+            data_dir.display()
+        ))
+    };
+
+    let resolved = if requested.exists() {
+        requested.canonicalize().map_err(|_| outside())?
+    } else {
+        let (Some(parent), Some(file_name)) = (requested.parent(), requested.file_name()) else {
+            return Err(outside());
+        };
+        parent
+            .canonicalize()
+            .map_err(|_| outside())?
+            .join(file_name)
+    };
+    if !resolved.starts_with(&data_dir) {
+        return Err(outside());
+    }
+    Ok(resolved)
+}
+
+/// Creates a result with pretty-printed JSON text content.
+fn json_result(value: &Value) -> Result<ToolResult> {
+    let text = serde_json::to_string_pretty(value).map_err(|e| Error::OperationFailed {
+        operation: "serialize_result".to_string(),
+        cause: e.to_string(),
+    })?;
+    Ok(ToolResult {
+        content: vec![ToolContent::Text { text }],
+        is_error: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::storage::index::SqliteBackend;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+
+    fn test_services(temp_dir: &TempDir) -> ServiceContainer {
+        ServiceContainer::builder()
+            .with_config(Config::new().with_data_dir(temp_dir.path()))
+            .with_index(Arc::new(SqliteBackend::in_memory().unwrap()))
+            .build()
+            .unwrap()
+    }
+
+    fn result_json(result: &ToolResult) -> Value {
+        let ToolContent::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_import_then_export_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let services = test_services(&temp_dir);
+        let content = r#"{"content": "Use PostgreSQL for storage", "namespace": "decisions"}
+{"content": "Prefer thiserror for library errors", "namespace": "patterns"}
+{"content": ""}"#;
+
+        let args = serde_json::json!({ "content": content });
+        let imported = result_json(&execute_import(&services, args.clone()).unwrap());
+        assert_eq!(imported["imported"], 2);
+        assert_eq!(imported["skipped"], 0);
+        assert_eq!(imported["failed"], 1);
+        assert!(!imported["errors"].as_array().unwrap().is_empty());
+
+        let again = result_json(&execute_import(&services, args).unwrap());
+        assert_eq!(again["imported"], 0);
+        assert_eq!(again["skipped"], 2);
+
+        let args = serde_json::json!({ "format": "csv", "filter": "ns:decisions" });
+        let exported = result_json(&execute_export(&services, args).unwrap());
+        assert_eq!(exported["format"], "csv");
+        assert_eq!(exported["exported"], 1);
+        let csv = exported["content"].as_str().unwrap();
+        assert!(csv.contains("Use PostgreSQL for storage"));
+        assert!(!csv.contains("thiserror"));
+
+        let path = temp_dir.path().join("backup.yaml");
+        let args = serde_json::json!({ "path": path.to_str().unwrap() });
+        let written = result_json(&execute_export(&services, args).unwrap());
+        assert_eq!(written["format"], "yaml");
+        assert_eq!(written["exported"], 2);
+        assert!(
+            std::fs::read_to_string(&path)
+                .unwrap()
+                .contains("thiserror")
+        );
+    }
+
+    #[test]
+    fn test_paths_are_confined_to_the_data_directory() {
+        let temp_dir = TempDir::new().unwrap();
+        let services = test_services(&temp_dir);
+        let outside_dir = TempDir::new().unwrap();
+        let outside = outside_dir.path().join("backup.json");
+        std::fs::write(&outside, r#"{"content": "Use PostgreSQL for storage"}"#).unwrap();
+
+        for path in [outside.to_str().unwrap(), "../backup.json"] {
+            let args = serde_json::json!({ "path": path });
+            assert!(matches!(
+                execute_export(&services, args.clone()),
+                Err(Error::InvalidInput(_))
+            ));
+            assert!(matches!(
+                execute_import(&services, args),
+                Err(Error::InvalidInput(_))
+            ));
+        }
+
+        // Relative paths resolve in the data directory; existing files are kept
+        let args = serde_json::json!({ "path": "backup.json" });
+        execute_export(&services, args.clone()).unwrap();
+        assert!(temp_dir.path().join("backup.json").exists());
+        assert!(matches!(
+            execute_export(&services, args.clone()),
+            Err(Error::InvalidInput(message)) if message.contains("overwrite")
+        ));
+        execute_import(&services, args).unwrap();
+    }
+
+    #[test]
+    fn test_import_rejects_inline_parquet_and_missing_input() {
+        let temp_dir = TempDir::new().unwrap();
+        let services = test_services(&temp_dir);
+
+        let args = serde_json::json!({ "format": "parquet", "content": "" });
+        #[cfg(feature = "parquet-export")]
+        assert!(matches!(
+            execute_import(&services, args),
+            Err(Error::InvalidInput(message)) if message.contains("binary format")
+        ));
+        #[cfg(not(feature = "parquet-export"))]
+        assert!(matches!(
+            execute_import(&services, args),
+            Err(Error::FeatureNotEnabled(_))
+        ));
+
+        let args = serde_json::json!({ "skip_duplicates": false });
+        assert!(matches!(
+            execute_import(&services, args),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[cfg(feature = "parquet-export")]
+    #[test]
+    fn test_parquet_file_roundtrip() {
+        let temp_dir = TempDir::new().unwrap();
+        let services = test_services(&temp_dir);
+        let content = r#"{"content": "Use PostgreSQL for storage", "namespace": "decisions"}"#;
+        execute_import(&services, serde_json::json!({ "content": content })).unwrap();
+
+        let path = temp_dir.path().join("backup.parquet");
+        let args = serde_json::json!({ "path": path.to_str().unwrap() });
+        let written = result_json(&execute_export(&services, args.clone()).unwrap());
+        assert_eq!(written["format"], "parquet");
+
+        let other_dir = TempDir::new().unwrap();
+        let other = test_services(&other_dir);
+        let imported = result_json(&execute_import(&other, args).unwrap());
+        assert_eq!(imported["format"], "parquet");
+        assert_eq!(imported["imported"], 1);
+    }
+}
@@ -8,6 +8,7 @@
 mod graph;
 #[cfg(feature = "group-scope")]
 mod groups;
+mod io;
 mod prompts;
 
 pub use context_templates::{
@@ -16,9 +17,9 @@
 };
 pub use core::{
     execute_capture, execute_consolidate, execute_delete, execute_delete_all, execute_enrich,
-    execute_gdpr_export, execute_get, execute_get_summary, execute_history, execute_init,
-    execute_list, execute_namespaces, execute_prompt_understanding, execute_recall,
-    execute_reindex, execute_restore, execute_status, execute_update,
+    execute_facets, execute_gdpr_export, execute_get, execute_get_summary, execute_history,
+    execute_init, execute_list, execute_namespaces, execute_prompt_understanding, execute_recall,
+    execute_reindex, execute_restore, execute_status, execute_suggest_namespace, execute_update,
 };
 pub use graph::{
     execute_entities, execute_entity_merge, execute_extract_entities, execute_graph,
@@ -30,6 +31,7 @@
     execute_group_add_member, execute_group_create, execute_group_delete, execute_group_get,
     execute_group_list, execute_group_remove_member, execute_group_update_role, execute_groups,
 };
+pub use io::{execute_export, execute_import};
 pub use prompts::{
     execute_prompt_delete, execute_prompt_get, execute_prompt_list, execute_prompt_run,
     execute_prompt_save, execute_prompts,
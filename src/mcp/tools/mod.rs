@@ -8,20 +8,34 @@
 //! - [`handlers`]: Tool execution logic
 //!   - [`handlers::core`]: Core memory operations (capture, recall, sync, etc.)
 //!   - [`handlers::prompts`]: Prompt management operations (save, list, run, etc.)
+//!   - [`handlers::io`]: Bulk import and export
+//!
+//! # Custom Tools
+//!
+//! Binaries embedding subcog can expose their own tools through the same
+//! server with [`ToolRegistry::register`] (or
+//! [`McpServer::with_custom_tool`](crate::mcp::McpServer::with_custom_tool)).
+//! Custom tools are listed next to the built-ins and cannot replace them.
 
 mod definitions;
 mod handlers;
 
-use crate::services::ServiceContainer;
+use crate::services::{Cancellation, ServiceContainer};
 use crate::{Error, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Handler executing a custom tool with its call arguments.
+pub type CustomToolHandler = Arc<dyn Fn(Value) -> Result<ToolResult> + Send + Sync>;
 
 /// Registry of MCP tools.
 pub struct ToolRegistry {
-    /// Available tools.
+    /// Available tools, built-in and custom.
     tools: HashMap<String, ToolDefinition>,
+    /// Handlers of tools added with [`register`](Self::register).
+    custom: HashMap<String, CustomToolHandler>,
 }
 
 impl ToolRegistry {
@@ -33,6 +47,7 @@ pub fn new() -> Self {
 
         tools.insert("subcog_capture".to_string(), definitions::capture_tool());
         tools.insert("subcog_recall".to_string(), definitions::recall_tool());
+        tools.insert("subcog_facets".to_string(), definitions::facets_tool());
         tools.insert("subcog_status".to_string(), definitions::status_tool());
         tools.insert(
             "prompt_understanding".to_string(),
@@ -42,6 +57,10 @@ pub fn new() -> Self {
             "subcog_namespaces".to_string(),
             definitions::namespaces_tool(),
         );
+        tools.insert(
+            "subcog_suggest_namespace".to_string(),
+            definitions::suggest_namespace_tool(),
+        );
         tools.insert(
             "subcog_consolidate".to_string(),
             definitions::consolidate_tool(),
@@ -57,6 +76,8 @@ pub fn new() -> Self {
             "subcog_gdpr_export".to_string(),
             definitions::gdpr_export_tool(),
         );
+        tools.insert("subcog_export".to_string(), definitions::export_tool());
+        tools.insert("subcog_import".to_string(), definitions::import_tool());
         tools.insert(
             "prompt_understanding".to_string(),
             definitions::prompt_understanding_tool(),
@@ -183,7 +204,63 @@ pub fn new() -> Self {
             );
         }
 
-        Self { tools }
+        Self {
+            tools,
+            custom: HashMap::new(),
+        }
+    }
+
+    /// Registers a custom tool executed by `handler`.
+    ///
+    /// Custom tools are additive: they are listed with the built-in tools and
+    /// called with the same `tools/call` request, but cannot shadow a
+    /// built-in or an already registered tool.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if `name` is empty, differs from
+    /// `definition.name`, or is already registered.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        definition: ToolDefinition,
+        handler: CustomToolHandler,
+    ) -> Result<()> {
+        let name = name.into();
+        if name.trim().is_empty() {
+            return Err(Error::InvalidInput(
+                "Custom tool name must not be empty".to_string(),
+            ));
+        }
+        if definition.name != name {
+            return Err(Error::InvalidInput(format!(
+                "Custom tool '{name}' has a definition named '{}'",
+                definition.name
+            )));
+        }
+        if self.tools.contains_key(&name) {
+            return Err(Error::InvalidInput(format!(
+                "Tool '{name}' is already registered"
+            )));
+        }
+        self.tools.insert(name.clone(), definition);
+        self.custom.insert(name, handler);
+        Ok(())
+    }
+
+    /// Advertises the `skip_security_check` capture parameter when `allowed`.
+    ///
+    /// Mirrors `security.allow_skip` so agents are not offered a flag the
+    /// capture service would reject.
+    #[must_use]
+    pub fn with_skip_security_check(mut self, allowed: bool) -> Self {
+        if allowed {
+            self.tools.insert(
+                "subcog_capture".to_string(),
+                definitions::capture_tool_with_skip_security_check(),
+            );
+        }
+        self
     }
 
     /// Returns the number of registered tools.
@@ -214,56 +291,49 @@ pub fn execute(
         name: &str,
         arguments: Value,
         services: &ServiceContainer,
+    ) -> Result<ToolResult> {
+        self.execute_cancellable(name, arguments, services, &Cancellation::new())
+    }
+
+    /// Executes a tool that stops early once `cancellation` is cancelled.
+    ///
+    /// Long-running tools (recall with rerank, enrich, consolidate) check
+    /// `cancellation` between phases; other tools run to completion.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the tool execution fails, or
+    /// [`Error::Cancelled`] if it was cancelled.
+    pub fn execute_cancellable(
+        &self,
+        name: &str,
+        arguments: Value,
+        services: &ServiceContainer,
+        cancellation: &Cancellation,
     ) -> Result<ToolResult> {
         // Handle group management tools (feature-gated)
         #[cfg(feature = "group-scope")]
-        {
-            let group_result = match name {
-                // Consolidated group management tool
-                "subcog_groups" => Some(handlers::execute_groups(services, arguments.clone())),
-                // Legacy group management tools
-                "subcog_group_create" => {
-                    Some(handlers::execute_group_create(services, arguments.clone()))
-                },
-                "subcog_group_list" => {
-                    Some(handlers::execute_group_list(services, arguments.clone()))
-                },
-                "subcog_group_get" => {
-                    Some(handlers::execute_group_get(services, arguments.clone()))
-                },
-                "subcog_group_add_member" => Some(handlers::execute_group_add_member(
-                    services,
-                    arguments.clone(),
-                )),
-                "subcog_group_remove_member" => Some(handlers::execute_group_remove_member(
-                    services,
-                    arguments.clone(),
-                )),
-                "subcog_group_update_role" => Some(handlers::execute_group_update_role(
-                    services,
-                    arguments.clone(),
-                )),
-                "subcog_group_delete" => {
-                    Some(handlers::execute_group_delete(services, arguments.clone()))
-                },
-                _ => None,
-            };
-            if let Some(result) = group_result {
-                return result;
-            }
+        if let Some(result) = execute_group_tool(name, &arguments, services) {
+            return result;
         }
 
         let result = match name {
             "subcog_capture" => handlers::execute_capture(services, arguments),
-            "subcog_recall" => handlers::execute_recall(services, arguments),
+            "subcog_recall" => handlers::execute_recall(services, arguments, cancellation),
+            "subcog_facets" => handlers::execute_facets(services, arguments),
             "subcog_status" => handlers::execute_status(services, arguments),
             "prompt_understanding" => handlers::execute_prompt_understanding(arguments),
             "subcog_namespaces" => handlers::execute_namespaces(arguments),
-            "subcog_consolidate" => handlers::execute_consolidate(services, arguments),
+            "subcog_suggest_namespace" => handlers::execute_suggest_namespace(arguments),
+            "subcog_consolidate" => {
+                handlers::execute_consolidate(services, arguments, cancellation)
+            },
             "subcog_get_summary" => handlers::execute_get_summary(services, arguments),
-            "subcog_enrich" => handlers::execute_enrich(services, arguments),
+            "subcog_enrich" => handlers::execute_enrich(services, arguments, cancellation),
             "subcog_reindex" => handlers::execute_reindex(services, arguments),
             "subcog_gdpr_export" => handlers::execute_gdpr_export(services, arguments),
+            "subcog_export" => handlers::execute_export(services, arguments),
+            "subcog_import" => handlers::execute_import(services, arguments),
             // Consolidated prompt management tool
             "subcog_prompts" => handlers::execute_prompts(services, arguments),
             // Legacy prompt management tools
@@ -307,7 +377,10 @@ pub fn execute(
             "context_template_delete" => {
                 handlers::execute_context_template_delete(services, arguments)
             },
-            _ => Err(Error::InvalidInput(format!("Unknown tool: {name}"))),
+            _ => match self.custom.get(name) {
+                Some(handler) => handler(arguments),
+                None => Err(Error::InvalidInput(format!("Unknown tool: {name}"))),
+            },
         }?;
 
         // Append hint for uninitialized sessions (except for init/prompt_understanding tools)
@@ -338,6 +411,37 @@ fn default() -> Self {
     }
 }
 
+/// Executes a group management tool, or returns `None` if `name` is not one.
+#[cfg(feature = "group-scope")]
+fn execute_group_tool(
+    name: &str,
+    arguments: &Value,
+    services: &ServiceContainer,
+) -> Option<Result<ToolResult>> {
+    match name {
+        // Consolidated group management tool
+        "subcog_groups" => Some(handlers::execute_groups(services, arguments.clone())),
+        // Legacy group management tools
+        "subcog_group_create" => Some(handlers::execute_group_create(services, arguments.clone())),
+        "subcog_group_list" => Some(handlers::execute_group_list(services, arguments.clone())),
+        "subcog_group_get" => Some(handlers::execute_group_get(services, arguments.clone())),
+        "subcog_group_add_member" => Some(handlers::execute_group_add_member(
+            services,
+            arguments.clone(),
+        )),
+        "subcog_group_remove_member" => Some(handlers::execute_group_remove_member(
+            services,
+            arguments.clone(),
+        )),
+        "subcog_group_update_role" => Some(handlers::execute_group_update_role(
+            services,
+            arguments.clone(),
+        )),
+        "subcog_group_delete" => Some(handlers::execute_group_delete(services, arguments.clone())),
+        _ => None,
+    }
+}
+
 /// Definition of an MCP tool.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolDefinition {
@@ -420,6 +524,23 @@ fn test_tool_definitions() {
         );
     }
 
+    #[test]
+    fn test_skip_security_check_advertised_only_when_allowed() {
+        let has_skip = |registry: &ToolRegistry| {
+            registry.get_tool("subcog_capture").unwrap().input_schema["properties"]
+                .get("skip_security_check")
+                .is_some()
+        };
+
+        assert!(!has_skip(&ToolRegistry::new()));
+        assert!(!has_skip(
+            &ToolRegistry::new().with_skip_security_check(false)
+        ));
+        assert!(has_skip(
+            &ToolRegistry::new().with_skip_security_check(true)
+        ));
+    }
+
     #[test]
     fn test_execute_namespaces() {
         let registry = ToolRegistry::new();
@@ -437,6 +558,25 @@ fn test_execute_namespaces() {
         }
     }
 
+    #[test]
+    fn test_execute_suggest_namespace() {
+        let registry = ToolRegistry::new();
+        let services = test_services();
+        let result = registry
+            .execute(
+                "subcog_suggest_namespace",
+                serde_json::json!({"text": "Resolved the error in the retry loop by capping backoff"}),
+                &services,
+            )
+            .unwrap();
+
+        assert!(!result.is_error);
+        let ToolContent::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.starts_with("1. blockers"));
+    }
+
     #[test]
     fn test_execute_status() {
         let registry = ToolRegistry::new();
@@ -460,6 +600,73 @@ fn test_execute_unknown_tool() {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_register_custom_tool() {
+        let mut registry = ToolRegistry::new();
+        let builtin_count = registry.tool_count();
+        let definition = ToolDefinition {
+            name: "acme_create_ticket".to_string(),
+            description: "Create a ticket from a memory".to_string(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": { "memory_id": { "type": "string" } },
+                "required": ["memory_id"]
+            }),
+        };
+        let handler: CustomToolHandler = Arc::new(|arguments| {
+            let id = arguments["memory_id"].as_str().unwrap_or_default();
+            Ok(ToolResult {
+                content: vec![ToolContent::Text {
+                    text: format!("Created ACME-1 for {id}"),
+                }],
+                is_error: false,
+            })
+        });
+
+        registry
+            .register(
+                "acme_create_ticket",
+                definition.clone(),
+                Arc::clone(&handler),
+            )
+            .unwrap();
+        assert_eq!(registry.tool_count(), builtin_count + 1);
+        assert!(
+            registry
+                .list_tools()
+                .iter()
+                .any(|t| t.name == "acme_create_ticket")
+        );
+
+        let services = test_services();
+        let result = registry
+            .execute(
+                "acme_create_ticket",
+                serde_json::json!({ "memory_id": "mem-1" }),
+                &services,
+            )
+            .unwrap();
+        let ToolContent::Text { text } = &result.content[0] else {
+            panic!("expected text content");
+        };
+        assert!(text.starts_with("Created ACME-1 for mem-1"));
+
+        // Neither built-ins nor registered tools can be replaced
+        let shadow = ToolDefinition {
+            name: "subcog_capture".to_string(),
+            ..definition.clone()
+        };
+        assert!(matches!(
+            registry.register("subcog_capture", shadow, Arc::clone(&handler)),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(matches!(
+            registry.register("acme_create_ticket", definition, handler),
+            Err(Error::InvalidInput(_))
+        ));
+        assert_eq!(registry.tool_count(), builtin_count + 1);
+    }
+
     #[test]
     fn test_parse_namespace() {
         assert_eq!(parse_namespace("decisions"), Namespace::Decisions);
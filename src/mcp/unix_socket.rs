@@ -0,0 +1,191 @@
+//! Unix domain socket listener for the MCP server.
+//!
+//! Local agents on the same host can connect over a socket file instead of
+//! spawning subcog over stdio or going through the authenticated HTTP
+//! transport. Access is controlled by file permissions: the socket is
+//! created owner-only (`0600`), so only the user running subcog can connect.
+//! It is bound and restricted inside a private (`0700`) directory and only
+//! then moved into place, so it is never reachable with wider permissions.
+//!
+//! Each connection speaks line-delimited JSON-RPC, like stdio. Binding
+//! refuses a path where another server is still accepting connections,
+//! replaces a stale socket file left by a crashed server, and never removes
+//! anything that is not a socket. The socket file is removed when the
+//! [`SocketListener`] is dropped.
+
+use crate::{Error, Result};
+use std::os::unix::fs::{DirBuilderExt, FileTypeExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use tokio::net::{UnixListener, UnixStream};
+
+/// Permissions of the socket file (owner read/write only).
+const SOCKET_MODE: u32 = 0o600;
+
+/// Permissions of the private directory the socket is bound in.
+const STAGING_DIR_MODE: u32 = 0o700;
+
+/// A bound socket that removes its file when dropped.
+pub struct SocketListener {
+    /// The listening socket.
+    listener: UnixListener,
+    /// Path of the socket file.
+    path: PathBuf,
+}
+
+impl SocketListener {
+    /// Binds a socket at `path` with owner-only permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the path is served by a live server, exists and is
+    /// not a socket, or the socket cannot be created.
+    pub fn bind(path: &Path) -> Result<Self> {
+        remove_stale_socket(path)?;
+
+        let staging = create_staging_dir(path)?;
+        let result = bind_in(&staging, path);
+        if let Err(e) = std::fs::remove_dir_all(&staging) {
+            tracing::debug!(path = %staging.display(), error = %e, "Failed to remove socket staging directory");
+        }
+        let listener = result?;
+        Ok(Self {
+            listener,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Returns the path of the socket file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Waits for the next connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if accepting fails.
+    pub async fn accept(&self) -> Result<UnixStream> {
+        self.listener
+            .accept()
+            .await
+            .map(|(stream, _)| stream)
+            .map_err(|e| Error::OperationFailed {
+                operation: "accept_unix_socket".to_string(),
+                cause: e.to_string(),
+            })
+    }
+}
+
+impl Drop for SocketListener {
+    fn drop(&mut self) {
+        if let Err(e) = std::fs::remove_file(&self.path) {
+            tracing::debug!(path = %self.path.display(), error = %e, "Failed to remove socket file");
+        }
+    }
+}
+
+/// Creates an owner-only directory next to `path` to bind the socket in.
+fn create_staging_dir(path: &Path) -> Result<PathBuf> {
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+    let staging = parent.join(format!(".subcog-socket-{}", std::process::id()));
+    std::fs::DirBuilder::new()
+        .mode(STAGING_DIR_MODE)
+        .create(&staging)
+        .map_err(|e| Error::OperationFailed {
+            operation: "create_socket_staging_dir".to_string(),
+            cause: format!("{}: {e}", staging.display()),
+        })?;
+    Ok(staging)
+}
+
+/// Binds a socket inside `staging`, restricts it, and moves it to `path`.
+fn bind_in(staging: &Path, path: &Path) -> Result<UnixListener> {
+    let staged = staging.join("subcog.sock");
+    let listener = UnixListener::bind(&staged).map_err(|e| Error::OperationFailed {
+        operation: "bind_unix_socket".to_string(),
+        cause: format!("{}: {e}", path.display()),
+    })?;
+    std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(SOCKET_MODE)).map_err(
+        |e| Error::OperationFailed {
+            operation: "chmod_unix_socket".to_string(),
+            cause: format!("{}: {e}", path.display()),
+        },
+    )?;
+    std::fs::rename(&staged, path).map_err(|e| Error::OperationFailed {
+        operation: "bind_unix_socket".to_string(),
+        cause: format!("{}: {e}", path.display()),
+    })?;
+    Ok(listener)
+}
+
+/// Removes a socket file left behind by a server that is no longer running.
+fn remove_stale_socket(path: &Path) -> Result<()> {
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return Ok(());
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(Error::InvalidInput(format!(
+            "{} exists and is not a socket",
+            path.display()
+        )));
+    }
+    if std::os::unix::net::UnixStream::connect(path).is_ok() {
+        return Err(Error::InvalidInput(format!(
+            "{} is in use by a running server",
+            path.display()
+        )));
+    }
+
+    tracing::info!(path = %path.display(), "Removing stale socket file");
+    std::fs::remove_file(path).map_err(|e| Error::OperationFailed {
+        operation: "remove_stale_socket".to_string(),
+        cause: format!("{}: {e}", path.display()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_bind_refuses_live_socket_and_cleans_up() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("subcog.sock");
+
+        let socket = SocketListener::bind(&path).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, SOCKET_MODE);
+        // The staging directory is gone; only the socket remains
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+        assert!(matches!(
+            SocketListener::bind(&path),
+            Err(Error::InvalidInput(_))
+        ));
+
+        drop(socket);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_bind_replaces_stale_socket_but_not_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("subcog.sock");
+
+        // A socket file nobody listens on any more
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+        assert!(path.exists());
+        assert!(SocketListener::bind(&path).is_ok());
+
+        let file = dir.path().join("notes.txt");
+        std::fs::write(&file, "keep me").unwrap();
+        assert!(matches!(
+            SocketListener::bind(&file),
+            Err(Error::InvalidInput(_))
+        ));
+        assert_eq!(std::fs::read_to_string(&file).unwrap(), "keep me");
+    }
+}
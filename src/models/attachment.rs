@@ -0,0 +1,74 @@
+//! Memory attachments.
+//!
+//! Screenshots, logs and other files attached to a memory are stored outside
+//! the memory, content-addressed by SHA-256 (see
+//! [`AttachmentStore`](crate::storage::AttachmentStore)). The memory keeps an
+//! [`Attachment`] reference per file, so identical files attached to several
+//! memories are stored once.
+
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Reference to a file attached to a memory.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Attachment {
+    /// Hex-encoded SHA-256 of the file content, which locates the blob.
+    pub hash: String,
+    /// Original file name.
+    pub name: String,
+    /// Media type guessed from the file name (e.g. `image/png`).
+    pub media_type: String,
+    /// Size in bytes.
+    pub size: u64,
+}
+
+impl Attachment {
+    /// Returns whether the attachment is an image.
+    #[must_use]
+    pub fn is_image(&self) -> bool {
+        self.media_type.starts_with("image/")
+    }
+
+    /// Returns whether the attachment is text (logs, JSON, Markdown).
+    #[must_use]
+    pub fn is_text(&self) -> bool {
+        self.media_type.starts_with("text/") || self.media_type == "application/json"
+    }
+}
+
+/// Guesses the media type of a file from its extension.
+///
+/// Unknown extensions are `application/octet-stream`.
+#[must_use]
+pub fn media_type_for(name: &str) -> &'static str {
+    let extension = Path::new(name)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase)
+        .unwrap_or_default();
+    match extension.as_str() {
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "webp" => "image/webp",
+        "svg" => "image/svg+xml",
+        "txt" | "log" => "text/plain",
+        "md" => "text/markdown",
+        "csv" => "text/csv",
+        "json" => "application/json",
+        "pdf" => "application/pdf",
+        _ => "application/octet-stream",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_media_type_for() {
+        assert_eq!(media_type_for("screenshot.PNG"), "image/png");
+        assert_eq!(media_type_for("build.log"), "text/plain");
+        assert_eq!(media_type_for("trace"), "application/octet-stream");
+    }
+}
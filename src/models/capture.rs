@@ -1,6 +1,11 @@
 //! Capture request and result types.
 
-use super::{Domain, MemoryId, Namespace};
+use super::{
+    Attachment, CaptureOrigin, CaptureTemplate, ContentType, Domain, MemoryId, MemorySection,
+    MemoryStatus, Namespace,
+};
+use crate::Result;
+use crate::config::NamespaceRulesConfig;
 use crate::storage::index::DomainScope;
 
 /// Request to capture a new memory.
@@ -37,6 +42,22 @@ pub struct CaptureRequest {
     /// all group members during recall.
     #[cfg(feature = "group-scope")]
     pub group_id: Option<String>,
+    /// Named sections for structured captures.
+    ///
+    /// When non-empty, the stored content is rendered as YAML front matter
+    /// listing the sections followed by one `## Heading` per section, with
+    /// `content` used as an optional preamble.
+    pub sections: Vec<MemorySection>,
+    /// Template the sections follow (e.g. ADR), recorded in the front matter.
+    pub template: Option<CaptureTemplate>,
+    /// How the capture was initiated (defaults to manual).
+    pub origin: CaptureOrigin,
+    /// Confidence of an automatic capture (0.0-1.0), if known.
+    pub confidence: Option<f32>,
+    /// Files attached to the memory, already stored in the attachment store.
+    pub attachments: Vec<Attachment>,
+    /// Format of the content; detected from the content when `None`.
+    pub content_type: Option<ContentType>,
 }
 
 impl CaptureRequest {
@@ -107,6 +128,161 @@ pub fn with_group_id(mut self, group_id: impl Into<String>) -> Self {
         self.group_id = Some(group_id.into());
         self
     }
+
+    /// Adds a named section, making this a structured capture.
+    #[must_use]
+    pub fn with_section(mut self, name: impl AsRef<str>, content: impl Into<String>) -> Self {
+        self.sections.push(MemorySection::new(name, content));
+        self
+    }
+
+    /// Sets the template the sections follow.
+    #[must_use]
+    pub const fn with_template(mut self, template: CaptureTemplate) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Sets how the capture was initiated.
+    #[must_use]
+    pub const fn with_origin(mut self, origin: CaptureOrigin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Sets the confidence of an automatic capture.
+    #[must_use]
+    pub const fn with_confidence(mut self, confidence: f32) -> Self {
+        self.confidence = Some(confidence);
+        self
+    }
+
+    /// Attaches a stored file to the memory.
+    #[must_use]
+    pub fn with_attachment(mut self, attachment: Attachment) -> Self {
+        self.attachments.push(attachment);
+        self
+    }
+
+    /// Sets the content type instead of detecting it.
+    #[must_use]
+    pub fn with_content_type(mut self, content_type: ContentType) -> Self {
+        self.content_type = Some(content_type);
+        self
+    }
+
+    /// Returns whether this is a structured (sectioned) capture.
+    #[must_use]
+    pub const fn is_structured(&self) -> bool {
+        !self.sections.is_empty()
+    }
+
+    /// Returns the content that will be stored for this request.
+    ///
+    /// Structured captures are rendered as front matter plus sectioned body;
+    /// plain captures return `content` unchanged.
+    #[must_use]
+    pub fn rendered_content(&self) -> String {
+        if self.sections.is_empty() {
+            self.content.clone()
+        } else {
+            super::render_sections(self.template, &self.content, &self.sections)
+        }
+    }
+
+    /// Validates this request against per-namespace rules.
+    ///
+    /// Length limits apply to the stored (rendered) content. Namespaces
+    /// without a configured rule always pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::Error::InvalidInput`] naming the violated rule
+    /// (e.g. `decisions.min_length` or `apis.required_tags`).
+    pub fn validate(&self, rules: &NamespaceRulesConfig) -> Result<()> {
+        if rules.is_empty() {
+            return Ok(());
+        }
+        rules.check(self.namespace, &self.rendered_content(), &self.tags)
+    }
+}
+
+/// Request to edit an existing memory in place.
+///
+/// Fields left as `None` keep their current value.
+#[derive(Debug, Clone, Default)]
+pub struct UpdateRequest {
+    /// Replacement content.
+    pub content: Option<String>,
+    /// Replacement tags (replaces all existing tags).
+    pub tags: Option<Vec<String>>,
+    /// New namespace.
+    pub namespace: Option<Namespace>,
+    /// New status.
+    pub status: Option<MemoryStatus>,
+}
+
+impl UpdateRequest {
+    /// Creates an update request that changes nothing.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            content: None,
+            tags: None,
+            namespace: None,
+            status: None,
+        }
+    }
+
+    /// Replaces the content.
+    #[must_use]
+    pub fn with_content(mut self, content: impl Into<String>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Replaces the tags.
+    #[must_use]
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = Some(tags);
+        self
+    }
+
+    /// Moves the memory to another namespace.
+    #[must_use]
+    pub const fn with_namespace(mut self, namespace: Namespace) -> Self {
+        self.namespace = Some(namespace);
+        self
+    }
+
+    /// Sets the status.
+    #[must_use]
+    pub const fn with_status(mut self, status: MemoryStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// Returns whether the request changes nothing.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.content.is_none()
+            && self.tags.is_none()
+            && self.namespace.is_none()
+            && self.status.is_none()
+    }
+}
+
+/// Sensitive content removed from captured text.
+///
+/// The span is a byte range in the captured content before redaction (after
+/// normalization), not in the stored content, so what was scrubbed can be
+/// audited without retaining the secret.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redaction {
+    /// Kind of sensitive content (e.g. `AWS Access Key ID`).
+    pub kind: String,
+    /// Byte range of the redacted text in the original content.
+    pub span: std::ops::Range<usize>,
 }
 
 /// Result of a capture operation.
@@ -120,6 +296,11 @@ pub struct CaptureResult {
     pub content_modified: bool,
     /// Any warnings generated during capture.
     pub warnings: Vec<String>,
+    /// Content redacted before storing, with spans in the original content.
+    pub redactions: Vec<Redaction>,
+    /// Whether the content matched an existing memory in the namespace,
+    /// which is returned instead of storing a copy.
+    pub duplicate: bool,
 }
 
 impl CaptureResult {
@@ -131,6 +312,8 @@ pub const fn new(memory_id: MemoryId, urn: String) -> Self {
             urn,
             content_modified: false,
             warnings: Vec::new(),
+            redactions: Vec::new(),
+            duplicate: false,
         }
     }
 }
@@ -103,6 +103,18 @@ pub enum EdgeType {
 }
 
 impl EdgeType {
+    /// All edge types.
+    pub const ALL: [Self; 8] = [
+        Self::Contradicts,
+        Self::Supersedes,
+        Self::RelatedTo,
+        Self::Refines,
+        Self::ParentOf,
+        Self::ChildOf,
+        Self::SummarizedBy,
+        Self::SourceOf,
+    ];
+
     /// Returns the edge type as a string slice.
     #[must_use]
     pub const fn as_str(&self) -> &'static str {
@@ -1,4 +1,4 @@
-//! Content hashing utility for deduplication.
+//! Content hashing for exact-match deduplication.
 //!
 //! This module provides SHA256-based content hashing for exact match detection.
 //! Content is normalized before hashing to ensure consistent matches despite
@@ -20,7 +20,7 @@
 /// # Example
 ///
 /// ```rust
-/// use subcog::services::deduplication::ContentHasher;
+/// use subcog::models::ContentHasher;
 ///
 /// let hash = ContentHasher::hash("Use PostgreSQL for primary storage");
 /// assert_eq!(hash.len(), 64); // SHA256 produces 64 hex chars
@@ -45,7 +45,7 @@ impl ContentHasher {
     /// # Example
     ///
     /// ```rust
-    /// use subcog::services::deduplication::ContentHasher;
+    /// use subcog::models::ContentHasher;
     ///
     /// let hash = ContentHasher::hash("Hello, world!");
     /// assert_eq!(hash.len(), 64);
@@ -77,7 +77,7 @@ pub fn hash(content: &str) -> String {
     /// # Example
     ///
     /// ```rust
-    /// use subcog::services::deduplication::ContentHasher;
+    /// use subcog::models::ContentHasher;
     ///
     /// let hash = ContentHasher::hash("test content");
     /// let tag = ContentHasher::hash_to_tag(&hash);
@@ -105,7 +105,7 @@ pub fn hash_to_tag(hash: &str) -> String {
     /// # Example
     ///
     /// ```rust
-    /// use subcog::services::deduplication::ContentHasher;
+    /// use subcog::models::ContentHasher;
     ///
     /// let tag = ContentHasher::content_to_tag("Use PostgreSQL");
     /// assert!(tag.starts_with("hash:sha256:"));
@@ -134,7 +134,7 @@ pub fn content_to_tag(content: &str) -> String {
     /// # Example
     ///
     /// ```rust
-    /// use subcog::services::deduplication::ContentHasher;
+    /// use subcog::models::ContentHasher;
     ///
     /// let normalized = ContentHasher::normalize("  Hello   WORLD  ");
     /// assert_eq!(normalized, "hello world");
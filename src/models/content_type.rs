@@ -0,0 +1,305 @@
+//! Memory content types.
+//!
+//! Code snippets and prose are searched and displayed differently: code is
+//! indexed with its identifiers split into words (`parseConfig` also matches
+//! `parse config`) and syntax-rendered by `subcog get`. The type is detected
+//! at capture time unless given explicitly.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Fence markers that open and close a Markdown code block.
+const FENCES: [&str; 2] = ["```", "~~~"];
+
+/// Line prefixes that mark a line as code.
+const CODE_LINE_PREFIXES: &[&str] = &[
+    "fn ", "pub ", "impl ", "use ", "let ", "mod ", "def ", "class ", "import ", "from ", "const ",
+    "var ", "func ", "package ", "return ", "if (", "for (", "while (", "#include", "#!", "//",
+    "SELECT ", "INSERT ", "CREATE ",
+];
+
+/// Line endings that mark a line as code.
+const CODE_LINE_SUFFIXES: &[&str] = &[";", "{", "}", "):", "=>", "->"];
+
+/// Minimum share of code-like lines for unfenced content to count as code.
+const CODE_LINE_RATIO: f32 = 0.6;
+
+/// Format of a memory's content.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ContentType {
+    /// Markdown prose (headings, lists, fenced snippets among text).
+    Markdown,
+    /// Source code, with the language when known (e.g. `rust`).
+    Code {
+        /// Lowercase language name.
+        lang: Option<String>,
+    },
+    /// Plain prose.
+    PlainText,
+}
+
+impl ContentType {
+    /// Detects the content type from fenced code blocks and line heuristics.
+    ///
+    /// Content that is a single fenced block is code in the fence's language.
+    /// Content with Markdown syntax is Markdown. Unfenced content where most
+    /// lines look like code (`fn `, `import `, trailing `;` or `{`) is code.
+    /// Anything else is plain text.
+    #[must_use]
+    pub fn detect(content: &str) -> Self {
+        let content = content.trim();
+        if let Some((lang, _)) = split_fence(content) {
+            return Self::Code { lang };
+        }
+        if has_markdown_syntax(content) {
+            return Self::Markdown;
+        }
+        if looks_like_code(content) {
+            return Self::Code {
+                lang: guess_language(content).map(str::to_string),
+            };
+        }
+        Self::PlainText
+    }
+
+    /// Parses a content type (`markdown`, `text`, `code` or `code:<lang>`).
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim().to_lowercase();
+        match s.as_str() {
+            "markdown" | "md" => Some(Self::Markdown),
+            "text" | "plain" | "plaintext" => Some(Self::PlainText),
+            "code" => Some(Self::Code { lang: None }),
+            _ => s
+                .strip_prefix("code:")
+                .filter(|lang| !lang.is_empty())
+                .map(|lang| Self::Code {
+                    lang: Some(lang.to_string()),
+                }),
+        }
+    }
+
+    /// Returns whether the content is code.
+    #[must_use]
+    pub const fn is_code(&self) -> bool {
+        matches!(self, Self::Code { .. })
+    }
+
+    /// Returns the code language, if known.
+    #[must_use]
+    pub fn lang(&self) -> Option<&str> {
+        match self {
+            Self::Code { lang } => lang.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the extra full-text search terms for content of this type.
+    ///
+    /// For code, each compound identifier is split into its words
+    /// (`parseConfigFile` into `parse config file`, `MAX_RETRIES` into
+    /// `max retries`). Prose needs no extra terms.
+    #[must_use]
+    pub fn search_terms(&self, content: &str) -> String {
+        if !self.is_code() {
+            return String::new();
+        }
+        let mut terms = BTreeSet::new();
+        for identifier in content.split(|c: char| !(c.is_alphanumeric() || c == '_')) {
+            let words = split_identifier(identifier);
+            if words.len() > 1 {
+                terms.extend(words);
+            }
+        }
+        terms.into_iter().collect::<Vec<_>>().join(" ")
+    }
+
+    /// Returns the code inside a single fenced block, or the whole content.
+    #[must_use]
+    pub fn code_body(content: &str) -> &str {
+        split_fence(content.trim()).map_or(content, |(_, body)| body)
+    }
+}
+
+impl fmt::Display for ContentType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Markdown => write!(f, "markdown"),
+            Self::Code { lang: Some(lang) } => write!(f, "code:{lang}"),
+            Self::Code { lang: None } => write!(f, "code"),
+            Self::PlainText => write!(f, "text"),
+        }
+    }
+}
+
+impl Serialize for ContentType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ContentType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(&s)
+            .ok_or_else(|| serde::de::Error::custom(format!("unknown content type: {s}")))
+    }
+}
+
+/// Splits content that is exactly one fenced block into its language and body.
+fn split_fence(content: &str) -> Option<(Option<String>, &str)> {
+    let fence = FENCES.iter().find(|fence| content.starts_with(**fence))?;
+    let (opening, rest) = content.split_once('\n')?;
+    let body = rest.strip_suffix(*fence)?;
+    // A fence inside the body means several blocks (or prose between them)
+    if body
+        .lines()
+        .any(|line| line.trim_start().starts_with(*fence))
+    {
+        return None;
+    }
+    let lang = opening[fence.len()..]
+        .split_whitespace()
+        .next()
+        .map(str::to_lowercase);
+    Some((lang, body.strip_suffix('\n').unwrap_or(body)))
+}
+
+/// Returns whether the content uses Markdown block or inline syntax.
+fn has_markdown_syntax(content: &str) -> bool {
+    content.contains("](")
+        || content.contains("**")
+        || content.lines().map(str::trim_start).any(|line| {
+            FENCES.iter().any(|fence| line.starts_with(*fence))
+                || line.starts_with("# ")
+                || line.starts_with("## ")
+                || line.starts_with("### ")
+                || line.starts_with("- ")
+                || line.starts_with("* ")
+                || line.starts_with("> ")
+        })
+}
+
+/// Returns whether most lines of multi-line content look like code.
+fn looks_like_code(content: &str) -> bool {
+    let lines: Vec<&str> = content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .collect();
+    if lines.len() < 2 {
+        return false;
+    }
+    let code_lines = lines
+        .iter()
+        .filter(|line| {
+            CODE_LINE_PREFIXES.iter().any(|p| line.starts_with(*p))
+                || CODE_LINE_SUFFIXES.iter().any(|s| line.ends_with(*s))
+        })
+        .count();
+    #[allow(clippy::cast_precision_loss)]
+    let ratio = code_lines as f32 / lines.len() as f32;
+    ratio >= CODE_LINE_RATIO
+}
+
+/// Guesses the language of unfenced code from characteristic syntax.
+fn guess_language(code: &str) -> Option<&'static str> {
+    if code.contains("fn ") && (code.contains("let ") || code.contains("->") || code.contains("::"))
+    {
+        Some("rust")
+    } else if code.contains("def ") || (code.contains("import ") && code.contains("):")) {
+        Some("python")
+    } else if code.contains("func ") || code.contains(":=") {
+        Some("go")
+    } else if code.contains("function ") || code.contains("=>") {
+        Some("javascript")
+    } else if code.contains("#include") {
+        Some("c")
+    } else {
+        None
+    }
+}
+
+/// Splits a `snake_case` or `camelCase` identifier into lowercase words.
+fn split_identifier(identifier: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    for part in identifier.split('_').filter(|part| !part.is_empty()) {
+        let mut word = String::new();
+        let mut previous_lower = false;
+        for c in part.chars() {
+            if c.is_uppercase() && previous_lower && !word.is_empty() {
+                words.push(std::mem::take(&mut word));
+            }
+            previous_lower = c.is_lowercase() || c.is_ascii_digit();
+            word.extend(c.to_lowercase());
+        }
+        if !word.is_empty() {
+            words.push(word);
+        }
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_fenced_code() {
+        let content = "```rust\nfn main() {\n    println!(\"hi\");\n}\n```";
+        let detected = ContentType::detect(content);
+        assert_eq!(
+            detected,
+            ContentType::Code {
+                lang: Some("rust".to_string())
+            }
+        );
+        assert!(detected.is_code());
+        assert_eq!(
+            ContentType::code_body(content),
+            "fn main() {\n    println!(\"hi\");\n}"
+        );
+    }
+
+    #[test]
+    fn test_detect_prose_and_markdown() {
+        assert_eq!(
+            ContentType::detect("Use PostgreSQL for storage because of JSONB support."),
+            ContentType::PlainText
+        );
+        assert_eq!(
+            ContentType::detect("## Context\nWe need storage.\n\n```sql\nSELECT 1;\n```"),
+            ContentType::Markdown
+        );
+        assert_eq!(
+            ContentType::detect("import os\n\ndef main():\n    return os.getcwd()"),
+            ContentType::Code {
+                lang: Some("python".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        for s in ["markdown", "text", "code", "code:rust"] {
+            assert_eq!(ContentType::parse(s).unwrap().to_string(), s);
+        }
+        assert_eq!(ContentType::parse("MD"), Some(ContentType::Markdown));
+        assert_eq!(ContentType::parse("html"), None);
+    }
+
+    #[test]
+    fn test_code_search_terms() {
+        let code = ContentType::Code { lang: None };
+        assert_eq!(
+            code.search_terms("let cfg = parseConfigFile(MAX_RETRIES);"),
+            "config file max parse retries"
+        );
+        assert!(
+            ContentType::PlainText
+                .search_terms("parseConfig")
+                .is_empty()
+        );
+    }
+}
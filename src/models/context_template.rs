@@ -40,6 +40,7 @@
     "memories",
     // Individual memory fields (used inside {{#each memories}})
     "memory.id",
+    "memory.number",
     "memory.content",
     "memory.namespace",
     "memory.tags",
@@ -47,6 +48,7 @@
     "memory.created_at",
     "memory.updated_at",
     "memory.domain",
+    "memory.source",
     // Statistics
     "statistics",
     "total_count",
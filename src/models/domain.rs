@@ -308,6 +308,21 @@ pub const fn as_str(&self) -> &'static str {
             Self::Consolidated => "consolidated",
         }
     }
+
+    /// Parses a status from a string.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "active" => Some(Self::Active),
+            "archived" => Some(Self::Archived),
+            "superseded" => Some(Self::Superseded),
+            "pending" => Some(Self::Pending),
+            "deleted" => Some(Self::Deleted),
+            "tombstoned" => Some(Self::Tombstoned),
+            "consolidated" => Some(Self::Consolidated),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for MemoryStatus {
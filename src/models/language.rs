@@ -0,0 +1,135 @@
+//! Memory content languages.
+//!
+//! The language of a memory is detected at capture time and stored as an
+//! ISO 639-1 code (`en`, `es`, `de`), so recall can be limited to one
+//! language (`recall --lang es`). Detection only records confident results;
+//! short or mixed-language content is left without a language.
+
+/// ISO 639-3 codes reported by the detector and their ISO 639-1 equivalents.
+const ISO_639_3_TO_1: &[(&str, &str)] = &[
+    ("afr", "af"),
+    ("aka", "ak"),
+    ("amh", "am"),
+    ("ara", "ar"),
+    ("aze", "az"),
+    ("bel", "be"),
+    ("ben", "bn"),
+    ("bul", "bg"),
+    ("cat", "ca"),
+    ("ces", "cs"),
+    ("cmn", "zh"),
+    ("dan", "da"),
+    ("deu", "de"),
+    ("ell", "el"),
+    ("eng", "en"),
+    ("epo", "eo"),
+    ("est", "et"),
+    ("fin", "fi"),
+    ("fra", "fr"),
+    ("guj", "gu"),
+    ("heb", "he"),
+    ("hin", "hi"),
+    ("hrv", "hr"),
+    ("hun", "hu"),
+    ("hye", "hy"),
+    ("ind", "id"),
+    ("ita", "it"),
+    ("jav", "jv"),
+    ("jpn", "ja"),
+    ("kan", "kn"),
+    ("kat", "ka"),
+    ("khm", "km"),
+    ("kor", "ko"),
+    ("lat", "la"),
+    ("lav", "lv"),
+    ("lit", "lt"),
+    ("mal", "ml"),
+    ("mar", "mr"),
+    ("mkd", "mk"),
+    ("mya", "my"),
+    ("nep", "ne"),
+    ("nld", "nl"),
+    ("nob", "nb"),
+    ("ori", "or"),
+    ("pan", "pa"),
+    ("pes", "fa"),
+    ("pol", "pl"),
+    ("por", "pt"),
+    ("ron", "ro"),
+    ("rus", "ru"),
+    ("sin", "si"),
+    ("slk", "sk"),
+    ("slv", "sl"),
+    ("sna", "sn"),
+    ("spa", "es"),
+    ("srp", "sr"),
+    ("swe", "sv"),
+    ("tam", "ta"),
+    ("tel", "te"),
+    ("tgl", "tl"),
+    ("tha", "th"),
+    ("tuk", "tk"),
+    ("tur", "tr"),
+    ("ukr", "uk"),
+    ("urd", "ur"),
+    ("uzb", "uz"),
+    ("vie", "vi"),
+    ("yid", "yi"),
+    ("zul", "zu"),
+];
+
+/// Detects the language of `content` as an ISO 639-1 code.
+///
+/// Returns `None` when the detector is not confident, which is typical for
+/// content of only a few words.
+#[must_use]
+pub fn detect_language(content: &str) -> Option<String> {
+    let info = whatlang::detect(content).filter(whatlang::Info::is_reliable)?;
+    let code = info.lang().code();
+    ISO_639_3_TO_1
+        .iter()
+        .find(|(iso3, _)| *iso3 == code)
+        .map(|(_, iso1)| (*iso1).to_string())
+}
+
+/// Normalizes a user-supplied language code (`ES`, `spa`) to ISO 639-1.
+///
+/// Accepts two-letter ISO 639-1 codes and the three-letter codes reported by
+/// the detector. Returns `None` for anything else.
+#[must_use]
+pub fn normalize_language(code: &str) -> Option<String> {
+    let code = code.trim().to_ascii_lowercase();
+    if code.len() == 2 && code.chars().all(|c| c.is_ascii_lowercase()) {
+        return Some(code);
+    }
+    ISO_639_3_TO_1
+        .iter()
+        .find(|(iso3, _)| *iso3 == code)
+        .map(|(_, iso1)| (*iso1).to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_language() {
+        let spanish = "Decidimos usar PostgreSQL para el almacenamiento principal porque \
+                       necesitamos transacciones y consultas complejas sobre los datos.";
+        assert_eq!(detect_language(spanish).as_deref(), Some("es"));
+
+        let english = "We decided to use PostgreSQL for primary storage because we need \
+                       transactions and complex queries over the data.";
+        assert_eq!(detect_language(english).as_deref(), Some("en"));
+
+        assert_eq!(detect_language(""), None);
+    }
+
+    #[test]
+    fn test_normalize_language() {
+        assert_eq!(normalize_language("ES").as_deref(), Some("es"));
+        assert_eq!(normalize_language(" spa ").as_deref(), Some("es"));
+        assert_eq!(normalize_language("spanish"), None);
+        assert_eq!(normalize_language("e1"), None);
+    }
+}
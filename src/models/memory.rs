@@ -103,6 +103,82 @@ pub struct Memory {
     /// Only populated for consolidated memories (both summaries and source memories
     /// that have been included in a consolidation).
     pub consolidation_timestamp: Option<u64>,
+    /// How the memory was captured (manual, hook, import, consolidation).
+    pub origin: CaptureOrigin,
+    /// Confidence of the automatic capture that produced this memory (0.0-1.0).
+    ///
+    /// `None` when no confidence was recorded, e.g. for manual captures.
+    pub confidence: Option<f32>,
+    /// Files attached to the memory, stored content-addressed outside it.
+    pub attachments: Vec<super::Attachment>,
+    /// Format of the content (code, Markdown or plain text).
+    ///
+    /// Detected at capture time; `None` for memories stored before content
+    /// types were recorded.
+    pub content_type: Option<super::ContentType>,
+    /// Whether the memory is pinned (`subcog pin`).
+    ///
+    /// Pinned memories are always injected at session start (within the
+    /// token budget) and get a ranking boost in recall.
+    pub pinned: bool,
+    /// SHA of the commit checked out on the current branch at capture time.
+    ///
+    /// `None` for captures outside a git repository or on a detached HEAD,
+    /// and for memories stored before commits were recorded.
+    pub commit: Option<String>,
+    /// ISO 639-1 code of the content's language (e.g. `es`).
+    ///
+    /// Detected at capture time; `None` when detection is disabled or not
+    /// confident, and for memories stored before languages were recorded.
+    pub language: Option<String>,
+}
+
+/// How a memory entered the system.
+///
+/// Lets deliberate captures be told apart from automatic ones when
+/// auditing or cleaning up (e.g. `recall --origin hook`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptureOrigin {
+    /// Captured deliberately via the CLI or an MCP tool.
+    #[default]
+    Manual,
+    /// Captured automatically by a hook (e.g. pre-compact).
+    Hook,
+    /// Created by importing memories from a file.
+    Import,
+    /// Created by consolidating related memories into a summary.
+    Consolidation,
+}
+
+impl CaptureOrigin {
+    /// All origins, in display order.
+    pub const ALL: [Self; 4] = [Self::Manual, Self::Hook, Self::Import, Self::Consolidation];
+
+    /// Returns the origin as a string slice.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Manual => "manual",
+            Self::Hook => "hook",
+            Self::Import => "import",
+            Self::Consolidation => "consolidation",
+        }
+    }
+
+    /// Parses an origin from a string (case-insensitive).
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|origin| origin.as_str().eq_ignore_ascii_case(s.trim()))
+    }
+}
+
+impl fmt::Display for CaptureOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
 }
 
 /// Result of a memory operation with optional metadata.
@@ -2,34 +2,51 @@
 //!
 //! This module contains all the core data structures used throughout the system.
 
+mod attachment;
 mod capture;
 mod consolidation;
+mod content_hash;
+mod content_type;
 mod context_template;
 mod domain;
 mod events;
 pub mod graph;
 pub mod group;
+mod language;
 mod memory;
 mod prompt;
 mod search;
+mod sections;
 pub mod temporal;
 mod urn;
 
-pub use capture::{CaptureRequest, CaptureResult};
+pub use attachment::{Attachment, media_type_for};
+pub use capture::{CaptureRequest, CaptureResult, Redaction, UpdateRequest};
 pub use consolidation::{EdgeType, MemoryTier, RetentionScore};
+pub use content_hash::ContentHasher;
+pub use content_type::ContentType;
 pub use context_template::{
     AUTO_VARIABLE_PREFIXES, AUTO_VARIABLES, ContextTemplate, OutputFormat, TemplateVariable,
     TemplateVersion, VariableType, is_auto_variable,
 };
 pub use domain::{Domain, MemoryStatus, Namespace};
 pub use events::{EventMeta, MemoryEvent};
-pub use memory::{Memory, MemoryId, MemoryResult};
+pub use language::{detect_language, normalize_language};
+pub use memory::{CaptureOrigin, Memory, MemoryId, MemoryResult};
 pub use prompt::{
     ExtractedVariable, IssueSeverity, MAX_VARIABLE_VALUE_LENGTH, PromptTemplate, PromptVariable,
     ValidationIssue, ValidationResult, extract_variables, is_reserved_variable_name,
     sanitize_variable_value, substitute_variables, validate_prompt_content,
 };
-pub use search::{DetailLevel, SearchFilter, SearchHit, SearchMode, SearchResult};
+pub use search::{
+    DetailLevel, FieldWeights, GroupBy, HitGroup, NO_SOURCE_GROUP, RelevanceFeedback,
+    ScopedSearchHit, ScopedSearchResult, SearchFacets, SearchFilter, SearchHit, SearchLegs,
+    SearchMode, SearchResult, SortBy, UNTAGGED_GROUP, VectorEmptyReason, group_hits, sort_hits,
+};
+pub use sections::{
+    CaptureTemplate, MemorySection, normalize_section_name, parse_sections, parse_template,
+    render_sections,
+};
 pub use urn::{Urn, UrnComponent};
 
 // Group types (feature-gated)
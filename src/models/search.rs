@@ -1,6 +1,10 @@
 //! Search types and filters.
 
-use super::{Domain, Memory, MemoryStatus, Namespace};
+use super::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+use crate::storage::index::DomainScope;
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
 
 /// Search mode for memory recall.
@@ -87,6 +91,49 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
     }
 }
 
+/// Per-field BM25 weights for text search.
+///
+/// A term matching a heavier field contributes more to a memory's score, so
+/// under the defaults a tag match outranks the same term in the content.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FieldWeights {
+    /// Weight of the memory content.
+    pub content: f32,
+    /// Weight of the memory tags.
+    pub tags: f32,
+    /// Weight of the source reference.
+    pub source: f32,
+}
+
+impl Default for FieldWeights {
+    fn default() -> Self {
+        Self {
+            content: 1.0,
+            tags: 4.0,
+            source: 2.0,
+        }
+    }
+}
+
+impl FieldWeights {
+    /// Creates weights for content, tags and source.
+    #[must_use]
+    pub const fn new(content: f32, tags: f32, source: f32) -> Self {
+        Self {
+            content,
+            tags,
+            source,
+        }
+    }
+
+    /// Returns the weights with negative or non-finite values replaced by 0.
+    #[must_use]
+    pub fn sanitized(self) -> Self {
+        let clean = |w: f32| if w.is_finite() && w > 0.0 { w } else { 0.0 };
+        Self::new(clean(self.content), clean(self.tags), clean(self.source))
+    }
+}
+
 /// Filter criteria for memory search.
 #[derive(Debug, Clone, Default)]
 pub struct SearchFilter {
@@ -96,6 +143,8 @@ pub struct SearchFilter {
     pub domains: Vec<Domain>,
     /// Filter by statuses.
     pub statuses: Vec<MemoryStatus>,
+    /// Filter by capture origins (OR logic - matches ANY).
+    pub origins: Vec<CaptureOrigin>,
     /// Filter by tags (AND logic - must have ALL).
     pub tags: Vec<String>,
     /// Filter by tags (OR logic - must have ANY).
@@ -110,17 +159,50 @@ pub struct SearchFilter {
     pub branch: Option<String>,
     /// Filter by file path (relative to repo root).
     pub file_path: Option<String>,
+    /// Filter by the commit checked out at capture (full SHA or a prefix).
+    pub commit: Option<String>,
+    /// Filter by content language (ISO 639-1 code).
+    pub language: Option<String>,
+    /// Restrict matches to these memories (OR logic; empty matches all).
+    pub memory_ids: Vec<MemoryId>,
     /// Minimum creation timestamp.
     pub created_after: Option<u64>,
     /// Maximum creation timestamp.
     pub created_before: Option<u64>,
+    /// Minimum last update timestamp.
+    pub updated_after: Option<u64>,
     /// Minimum similarity score (0.0 to 1.0).
     pub min_score: Option<f32>,
+    /// Minimum recorded capture confidence (0.0 to 1.0).
+    ///
+    /// Memories without a recorded confidence never match.
+    pub min_confidence: Option<f32>,
+    /// Only match pinned memories (default: false).
+    pub pinned_only: bool,
     /// Include tombstoned memories (default: false).
     pub include_tombstoned: bool,
     /// Filter by entity names (memories mentioning these entities).
     /// Uses OR logic - matches memories mentioning ANY of the listed entities.
     pub entity_names: Vec<String>,
+    /// Restrict text matching to a named section of structured memories.
+    ///
+    /// Only structured memories with this section whose body matches the
+    /// query are returned, ranked by that body (e.g. `section:consequences`).
+    pub section: Option<String>,
+    /// Number of ranked results to skip before the returned page.
+    ///
+    /// Applied by [`RecallService::search`](crate::services::RecallService::search)
+    /// after ranking; not a match criterion.
+    pub offset: usize,
+    /// Weight of vector ranks against BM25 ranks in hybrid search
+    /// (0.0 is pure BM25, 1.0 pure vector).
+    ///
+    /// Overrides the configured `[search] hybrid_alpha` for one query; not a
+    /// match criterion.
+    pub hybrid_alpha: Option<f32>,
+    /// Serve the vector hits alone in hybrid search when text search finds
+    /// no candidates, whatever the hybrid weight; not a match criterion.
+    pub vector_only_fallback: bool,
     /// Filter by group identifiers (group-scoped memories).
     /// Uses OR logic - matches memories in ANY of the listed groups.
     #[cfg(feature = "group-scope")]
@@ -135,6 +217,7 @@ pub const fn new() -> Self {
             namespaces: Vec::new(),
             domains: Vec::new(),
             statuses: Vec::new(),
+            origins: Vec::new(),
             tags: Vec::new(),
             tags_any: Vec::new(),
             excluded_tags: Vec::new(),
@@ -142,11 +225,21 @@ pub const fn new() -> Self {
             project_id: None,
             branch: None,
             file_path: None,
+            commit: None,
+            language: None,
+            memory_ids: Vec::new(),
             created_after: None,
             created_before: None,
+            updated_after: None,
             min_score: None,
+            min_confidence: None,
+            pinned_only: false,
             include_tombstoned: false,
             entity_names: Vec::new(),
+            section: None,
+            offset: 0,
+            hybrid_alpha: None,
+            vector_only_fallback: false,
             #[cfg(feature = "group-scope")]
             group_ids: Vec::new(),
         }
@@ -173,6 +266,13 @@ pub fn with_status(mut self, status: MemoryStatus) -> Self {
         self
     }
 
+    /// Adds a capture origin filter.
+    #[must_use]
+    pub fn with_origin(mut self, origin: CaptureOrigin) -> Self {
+        self.origins.push(origin);
+        self
+    }
+
     /// Adds a tag filter (AND logic - must have ALL).
     #[must_use]
     pub fn with_tag(mut self, tag: impl Into<String>) -> Self {
@@ -222,6 +322,20 @@ pub fn with_file_path(mut self, file_path: impl Into<String>) -> Self {
         self
     }
 
+    /// Sets the commit filter (full SHA or a prefix).
+    #[must_use]
+    pub fn with_commit(mut self, commit: impl Into<String>) -> Self {
+        self.commit = Some(commit.into());
+        self
+    }
+
+    /// Sets the language filter (ISO 639-1 code).
+    #[must_use]
+    pub fn with_language(mut self, language: impl Into<String>) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
     /// Sets the minimum score threshold.
     #[must_use]
     pub const fn with_min_score(mut self, score: f32) -> Self {
@@ -229,6 +343,20 @@ pub const fn with_min_score(mut self, score: f32) -> Self {
         self
     }
 
+    /// Sets the minimum capture confidence.
+    #[must_use]
+    pub const fn with_min_confidence(mut self, confidence: f32) -> Self {
+        self.min_confidence = Some(confidence);
+        self
+    }
+
+    /// Restricts the filter to pinned memories.
+    #[must_use]
+    pub const fn with_pinned_only(mut self, pinned_only: bool) -> Self {
+        self.pinned_only = pinned_only;
+        self
+    }
+
     /// Sets the `created_after` filter.
     #[must_use]
     pub const fn with_created_after(mut self, timestamp: u64) -> Self {
@@ -243,6 +371,39 @@ pub const fn with_created_before(mut self, timestamp: u64) -> Self {
         self
     }
 
+    /// Sets the `updated_after` filter.
+    #[must_use]
+    pub const fn with_updated_after(mut self, timestamp: u64) -> Self {
+        self.updated_after = Some(timestamp);
+        self
+    }
+
+    /// Sets the number of ranked results to skip.
+    #[must_use]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the hybrid ranking weight, clamped to 0.0-1.0 (not-a-number is ignored).
+    #[must_use]
+    pub const fn with_hybrid_alpha(mut self, alpha: f32) -> Self {
+        self.hybrid_alpha = if alpha.is_nan() {
+            None
+        } else {
+            Some(alpha.clamp(0.0, 1.0))
+        };
+        self
+    }
+
+    /// Sets whether hybrid search falls back to the vector hits alone when
+    /// text search finds no candidates.
+    #[must_use]
+    pub const fn with_vector_only_fallback(mut self, fallback: bool) -> Self {
+        self.vector_only_fallback = fallback;
+        self
+    }
+
     /// Includes tombstoned memories in results.
     #[must_use]
     pub const fn with_include_tombstoned(mut self, include: bool) -> Self {
@@ -250,6 +411,21 @@ pub const fn with_include_tombstoned(mut self, include: bool) -> Self {
         self
     }
 
+    /// Checks that the creation window is not inverted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`](crate::Error::InvalidInput) if
+    /// `created_after` is later than `created_before`.
+    pub fn validate_created_range(&self) -> crate::Result<()> {
+        match (self.created_after, self.created_before) {
+            (Some(after), Some(before)) if after > before => Err(crate::Error::InvalidInput(
+                format!("created_after ({after}) is later than created_before ({before})"),
+            )),
+            _ => Ok(()),
+        }
+    }
+
     /// Returns true if the filter is empty (matches all).
     #[must_use]
     #[allow(clippy::missing_const_for_fn)] // Can't be const due to cfg attributes
@@ -257,6 +433,7 @@ pub fn is_empty(&self) -> bool {
         let base_empty = self.namespaces.is_empty()
             && self.domains.is_empty()
             && self.statuses.is_empty()
+            && self.origins.is_empty()
             && self.tags.is_empty()
             && self.tags_any.is_empty()
             && self.excluded_tags.is_empty()
@@ -264,10 +441,17 @@ pub fn is_empty(&self) -> bool {
             && self.project_id.is_none()
             && self.branch.is_none()
             && self.file_path.is_none()
+            && self.commit.is_none()
+            && self.language.is_none()
+            && self.memory_ids.is_empty()
             && self.created_after.is_none()
             && self.created_before.is_none()
+            && self.updated_after.is_none()
             && self.min_score.is_none()
-            && self.entity_names.is_empty();
+            && self.min_confidence.is_none()
+            && !self.pinned_only
+            && self.entity_names.is_empty()
+            && self.section.is_none();
 
         #[cfg(feature = "group-scope")]
         {
@@ -295,6 +479,20 @@ pub fn with_entities(mut self, entities: impl IntoIterator<Item = impl Into<Stri
         self
     }
 
+    /// Restricts matching to the given memories.
+    #[must_use]
+    pub fn with_memory_ids(mut self, ids: impl IntoIterator<Item = MemoryId>) -> Self {
+        self.memory_ids.extend(ids);
+        self
+    }
+
+    /// Restricts matching to a named section of structured memories.
+    #[must_use]
+    pub fn with_section(mut self, section: impl AsRef<str>) -> Self {
+        self.section = Some(super::normalize_section_name(section.as_ref()));
+        self
+    }
+
     /// Adds a group identifier filter.
     ///
     /// Filters to memories belonging to this group (OR logic with other groups).
@@ -324,10 +522,153 @@ pub struct SearchResult {
     pub memories: Vec<SearchHit>,
     /// Total count of matches (may be more than returned).
     pub total_count: usize,
+    /// Whether more results exist after this page.
+    pub has_more: bool,
     /// The search mode used.
     pub mode: SearchMode,
     /// Search execution time in milliseconds.
     pub execution_time_ms: u64,
+    /// Candidates found by each search leg.
+    pub legs: SearchLegs,
+}
+
+/// Candidates found by the text and vector legs of a search, to tell
+/// whether hybrid search degraded to one of them (`recall --explain`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SearchLegs {
+    /// Candidates from text (BM25) search; `None` if the mode skips it.
+    pub text_candidates: Option<usize>,
+    /// Candidates from vector search; `None` if the mode skips it.
+    pub vector_candidates: Option<usize>,
+    /// Whether a returned hit was matched by text search.
+    pub text_contributed: bool,
+    /// Whether a returned hit was matched by vector search.
+    pub vector_contributed: bool,
+    /// Why vector search found no candidates, when it ran and found none.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vector_empty: Option<VectorEmptyReason>,
+    /// Whether hybrid search served the vector hits alone because text
+    /// search found no candidates ([`SearchFilter::vector_only_fallback`]).
+    pub vector_only: bool,
+}
+
+impl SearchLegs {
+    /// Records which legs matched the returned `hits`.
+    pub fn mark_contributions(&mut self, hits: &[SearchHit]) {
+        self.text_contributed = hits.iter().any(|hit| hit.bm25_score.is_some());
+        self.vector_contributed = hits.iter().any(|hit| hit.vector_score.is_some());
+    }
+
+    /// Describes the legs that ran, e.g. `text: 12 candidates (contributed),
+    /// vector: 0 candidates (no memories are embedded)`.
+    ///
+    /// Returns `None` if no leg ran (listings, merged scope results).
+    #[must_use]
+    pub fn summary(&self) -> Option<String> {
+        let describe = |name: &str,
+                        candidates: Option<usize>,
+                        contributed: bool,
+                        empty: Option<VectorEmptyReason>| {
+            candidates.map(|count| {
+                let note = match (contributed, empty) {
+                    (true, _) => "contributed",
+                    (false, Some(reason)) => reason.describe(),
+                    (false, None) => "not in results",
+                };
+                format!("{name}: {count} candidates ({note})")
+            })
+        };
+        let legs: Vec<String> = [
+            describe("text", self.text_candidates, self.text_contributed, None),
+            describe(
+                "vector",
+                self.vector_candidates,
+                self.vector_contributed,
+                self.vector_empty,
+            ),
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        let fallback = if self.vector_only {
+            "; served by vector search only"
+        } else {
+            ""
+        };
+        (!legs.is_empty()).then(|| format!("{}{fallback}", legs.join(", ")))
+    }
+}
+
+/// Why the vector leg of a search found no candidates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VectorEmptyReason {
+    /// No embedder or vector backend is configured.
+    Unavailable,
+    /// No memory has been embedded yet.
+    NothingEmbedded,
+    /// Memories are embedded, but none is similar to the query.
+    NoMatches,
+}
+
+impl VectorEmptyReason {
+    /// Returns a short explanation for display.
+    #[must_use]
+    pub const fn describe(self) -> &'static str {
+        match self {
+            Self::Unavailable => "vector search unavailable",
+            Self::NothingEmbedded => "no memories are embedded",
+            Self::NoMatches => "no similar memories",
+        }
+    }
+}
+
+/// Match counts for a query, without the matching memories
+/// (`recall --namespace-counts`, `subcog_facets`).
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SearchFacets {
+    /// Number of matching memories per namespace.
+    pub namespaces: BTreeMap<String, usize>,
+    /// Number of matching memories per tag, when tag facets were requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<BTreeMap<String, usize>>,
+    /// Total number of matching memories.
+    pub total_count: usize,
+    /// Search execution time in milliseconds.
+    pub execution_time_ms: u64,
+}
+
+impl SearchFacets {
+    /// Counts the namespaces, and with `include_tags` the tags, of `hits`.
+    ///
+    /// A memory counts once per distinct tag.
+    #[must_use]
+    pub fn from_hits(hits: &[SearchHit], include_tags: bool) -> Self {
+        let mut namespaces = BTreeMap::new();
+        for hit in hits {
+            *namespaces
+                .entry(hit.memory.namespace.as_str().to_string())
+                .or_insert(0) += 1;
+        }
+        Self {
+            namespaces,
+            tags: include_tags.then(|| count_tags(hits)),
+            total_count: hits.len(),
+            execution_time_ms: 0,
+        }
+    }
+}
+
+/// Counts the memories of `hits` per tag, once per distinct tag.
+fn count_tags(hits: &[SearchHit]) -> BTreeMap<String, usize> {
+    let mut tags = BTreeMap::new();
+    for hit in hits {
+        let distinct: BTreeSet<&String> = hit.memory.tags.iter().collect();
+        for tag in distinct {
+            *tags.entry(tag.clone()).or_insert(0) += 1;
+        }
+    }
+    tags
 }
 
 /// A single search hit with scoring.
@@ -347,4 +688,427 @@ pub struct SearchHit {
     pub vector_score: Option<f32>,
     /// BM25 text score if applicable.
     pub bm25_score: Option<f32>,
+    /// Domain scope whose index produced the hit.
+    pub scope: DomainScope,
+    /// Domain of the matched memory.
+    pub domain: Domain,
+    /// Excerpt of the content around the query match, with matched terms
+    /// wrapped in the snippet marker; `None` for listings and empty queries.
+    pub snippet: Option<String>,
+}
+
+impl SearchHit {
+    /// Creates a hit with `score` as both normalized and raw score.
+    ///
+    /// The hit is attributed to the scope implied by the memory's domain
+    /// (see [`DomainScope::for_domain`]).
+    #[must_use]
+    pub fn new(memory: Memory, score: f32) -> Self {
+        Self {
+            scope: DomainScope::for_domain(&memory.domain),
+            domain: memory.domain.clone(),
+            memory,
+            score,
+            raw_score: score,
+            vector_score: None,
+            bm25_score: None,
+            snippet: None,
+        }
+    }
+
+    /// Returns the fields (`content`, `tags`, `source`) containing a term of
+    /// `query`.
+    ///
+    /// Terms are compared case-insensitively on alphanumeric tokens, like the
+    /// `SQLite` FTS tokenizer.
+    #[must_use]
+    pub fn matched_fields(&self, query: &str) -> Vec<&'static str> {
+        let terms: Vec<String> = tokens(query).collect();
+        let matches = |text: &str| tokens(text).any(|token| terms.contains(&token));
+
+        let mut fields = Vec::new();
+        if matches(&self.memory.content) {
+            fields.push("content");
+        }
+        if self.memory.tags.iter().any(|tag| matches(tag)) {
+            fields.push("tags");
+        }
+        if self.memory.source.as_deref().is_some_and(matches) {
+            fields.push("source");
+        }
+        fields
+    }
+}
+
+/// Splits text into lowercase alphanumeric tokens.
+fn tokens(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// How recall results are bucketed for display (`recall --group-by`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GroupBy {
+    /// One group per namespace.
+    Namespace,
+    /// One group per tag; a hit with several tags appears in each of them.
+    Tag,
+    /// One group per source reference.
+    Source,
+    /// A flat ranked list (default).
+    #[default]
+    None,
+}
+
+impl GroupBy {
+    /// Returns the grouping as a string slice.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Namespace => "namespace",
+            Self::Tag => "tag",
+            Self::Source => "source",
+            Self::None => "none",
+        }
+    }
+
+    /// Parses a grouping from a string.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "namespace" | "ns" => Some(Self::Namespace),
+            "tag" | "tags" => Some(Self::Tag),
+            "source" => Some(Self::Source),
+            "none" | "" => Some(Self::None),
+            _ => None,
+        }
+    }
+
+    /// Returns the group keys of a hit.
+    fn keys(self, hit: &SearchHit) -> Vec<String> {
+        match self {
+            Self::Namespace => vec![hit.memory.namespace.as_str().to_string()],
+            Self::Tag if hit.memory.tags.is_empty() => vec![UNTAGGED_GROUP.to_string()],
+            Self::Tag => hit.memory.tags.iter().fold(Vec::new(), |mut tags, tag| {
+                if !tags.contains(tag) {
+                    tags.push(tag.clone());
+                }
+                tags
+            }),
+            Self::Source => vec![
+                hit.memory
+                    .source
+                    .clone()
+                    .unwrap_or_else(|| NO_SOURCE_GROUP.to_string()),
+            ],
+            Self::None => vec![String::new()],
+        }
+    }
+}
+
+impl fmt::Display for GroupBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// How recall results are ordered (`recall --sort`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortBy {
+    /// Best match first (default).
+    #[default]
+    Relevance,
+    /// Newest capture first.
+    Created,
+    /// Most recently edited first.
+    Updated,
+}
+
+impl SortBy {
+    /// Returns the order as a string slice.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Relevance => "relevance",
+            Self::Created => "created",
+            Self::Updated => "updated",
+        }
+    }
+
+    /// Parses an order from a string.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "relevance" | "score" | "" => Some(Self::Relevance),
+            "created" | "created_at" => Some(Self::Created),
+            "updated" | "updated_at" => Some(Self::Updated),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SortBy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Reorders ranked hits by `sort_by`.
+///
+/// The sort is stable, so hits with the same timestamp keep their rank
+/// order. [`SortBy::Relevance`] leaves the hits unchanged.
+pub fn sort_hits(hits: &mut [SearchHit], sort_by: SortBy) {
+    match sort_by {
+        SortBy::Relevance => {},
+        SortBy::Created => hits.sort_by_key(|hit| Reverse(hit.memory.created_at)),
+        SortBy::Updated => hits.sort_by_key(|hit| Reverse(hit.memory.updated_at)),
+    }
+}
+
+/// Group key of hits without tags when grouping by tag.
+pub const UNTAGGED_GROUP: &str = "(untagged)";
+
+/// Group key of hits without a source when grouping by source.
+pub const NO_SOURCE_GROUP: &str = "(no source)";
+
+/// Ranked hits sharing a group key.
+#[derive(Debug, Clone)]
+pub struct HitGroup<'a> {
+    /// The namespace, tag, or source the hits share (empty for
+    /// [`GroupBy::None`]).
+    pub key: String,
+    /// The hits of the group, in their original rank order.
+    pub hits: Vec<&'a SearchHit>,
+}
+
+/// Buckets ranked hits by `group_by`.
+///
+/// Hits keep their relative order within each group, and groups are ordered
+/// by their best-ranked hit. [`GroupBy::None`] returns a single group holding
+/// every hit.
+#[must_use]
+pub fn group_hits(hits: &[SearchHit], group_by: GroupBy) -> Vec<HitGroup<'_>> {
+    let mut groups: Vec<HitGroup<'_>> = Vec::new();
+    for hit in hits {
+        for key in group_by.keys(hit) {
+            match groups.iter_mut().find(|group| group.key == key) {
+                Some(group) => group.hits.push(hit),
+                None => groups.push(HitGroup {
+                    key,
+                    hits: vec![hit],
+                }),
+            }
+        }
+    }
+    groups
+}
+
+/// A search hit annotated with the domain scope it was found in.
+#[derive(Debug, Clone)]
+pub struct ScopedSearchHit {
+    /// Scope whose index produced the hit.
+    pub scope: DomainScope,
+    /// The hit itself.
+    pub hit: SearchHit,
+}
+
+/// Result of a search across several domain scopes.
+#[derive(Debug, Clone)]
+pub struct ScopedSearchResult {
+    /// The matching memories, ranked together across scopes.
+    pub memories: Vec<ScopedSearchHit>,
+    /// Total count of merged matches.
+    pub total_count: usize,
+    /// Whether more results exist after this page.
+    pub has_more: bool,
+    /// The search mode used.
+    pub mode: SearchMode,
+    /// Scopes that were searched successfully.
+    pub scopes: Vec<DomainScope>,
+    /// Search execution time in milliseconds.
+    pub execution_time_ms: u64,
+}
+
+/// Relevance votes recorded for a memory on one query (`recall --feedback`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RelevanceFeedback {
+    /// The rated memory.
+    pub memory_id: MemoryId,
+    /// The query the memory was rated for, normalized with
+    /// [`normalize_query`](Self::normalize_query).
+    pub query: String,
+    /// Number of times the memory was marked useful.
+    pub up: u32,
+    /// Number of times the memory was marked not useful.
+    pub down: u32,
+}
+
+impl RelevanceFeedback {
+    /// Normalizes a query for storing feedback: lowercase alphanumeric terms
+    /// separated by single spaces.
+    #[must_use]
+    pub fn normalize_query(query: &str) -> String {
+        tokens(query).collect::<Vec<_>>().join(" ")
+    }
+
+    /// Returns the net vote count (useful minus not useful).
+    #[must_use]
+    pub fn net(&self) -> i64 {
+        i64::from(self.up) - i64::from(self.down)
+    }
+
+    /// Returns the share of the terms of `query` that also appear in the
+    /// rated query (0.0 to 1.0).
+    #[must_use]
+    pub fn overlap(&self, query: &str) -> f32 {
+        let rated: Vec<&str> = self.query.split(' ').collect();
+        let terms: Vec<String> = tokens(query).collect();
+        if terms.is_empty() {
+            return 0.0;
+        }
+        let shared = terms
+            .iter()
+            .filter(|term| rated.contains(&term.as_str()))
+            .count();
+        #[allow(clippy::cast_precision_loss)]
+        let overlap = shared as f32 / terms.len() as f32;
+        overlap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(id: &str, namespace: Namespace, tags: &[&str], score: f32) -> SearchHit {
+        let memory = Memory {
+            id: MemoryId::new(id),
+            content: format!("memory {id}"),
+            namespace,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1,
+            updated_at: 1,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: tags.iter().map(ToString::to_string).collect(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        };
+        SearchHit::new(memory, score)
+    }
+
+    #[test]
+    fn test_validate_created_range() {
+        let filter = SearchFilter::new()
+            .with_created_after(200)
+            .with_created_before(100);
+        assert!(matches!(
+            filter.validate_created_range(),
+            Err(crate::Error::InvalidInput(_))
+        ));
+        assert!(
+            SearchFilter::new()
+                .with_created_after(100)
+                .with_created_before(100)
+                .validate_created_range()
+                .is_ok()
+        );
+        assert!(
+            SearchFilter::new()
+                .with_created_after(200)
+                .validate_created_range()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_group_by_namespace_preserves_rank() {
+        let hits = vec![
+            hit("a", Namespace::Decisions, &[], 1.0),
+            hit("b", Namespace::Patterns, &[], 0.9),
+            hit("c", Namespace::Decisions, &[], 0.8),
+            hit("d", Namespace::Learnings, &[], 0.7),
+            hit("e", Namespace::Patterns, &[], 0.6),
+        ];
+
+        let groups = group_hits(&hits, GroupBy::Namespace);
+        let buckets: Vec<(&str, Vec<&str>)> = groups
+            .iter()
+            .map(|group| {
+                let ids = group.hits.iter().map(|h| h.memory.id.as_str()).collect();
+                (group.key.as_str(), ids)
+            })
+            .collect();
+        assert_eq!(
+            buckets,
+            vec![
+                ("decisions", vec!["a", "c"]),
+                ("patterns", vec!["b", "e"]),
+                ("learnings", vec!["d"]),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_group_by_tag_and_none() {
+        let hits = vec![
+            hit("a", Namespace::Decisions, &["db", "rust"], 1.0),
+            hit("b", Namespace::Decisions, &[], 0.5),
+            hit("c", Namespace::Decisions, &["rust"], 0.2),
+        ];
+
+        let groups = group_hits(&hits, GroupBy::Tag);
+        let keys: Vec<&str> = groups.iter().map(|g| g.key.as_str()).collect();
+        assert_eq!(keys, vec!["db", "rust", UNTAGGED_GROUP]);
+        assert_eq!(groups[1].hits.len(), 2);
+
+        let flat = group_hits(&hits, GroupBy::None);
+        assert_eq!(flat.len(), 1);
+        assert_eq!(flat[0].hits.len(), 3);
+        assert_eq!(GroupBy::parse("NS"), Some(GroupBy::Namespace));
+        assert_eq!(GroupBy::parse("owner"), None);
+    }
+
+    #[test]
+    fn test_sort_hits_by_updated_and_created() {
+        let mut hits = vec![
+            hit("new", Namespace::Decisions, &[], 1.0),
+            hit("old", Namespace::Decisions, &[], 0.9),
+            hit("old-edited", Namespace::Decisions, &[], 0.8),
+        ];
+        hits[0].memory.created_at = 200;
+        hits[0].memory.updated_at = 200;
+        hits[2].memory.updated_at = 300;
+
+        sort_hits(&mut hits, SortBy::Relevance);
+        let ids: Vec<&str> = hits.iter().map(|h| h.memory.id.as_str()).collect();
+        assert_eq!(ids, vec!["new", "old", "old-edited"]);
+
+        sort_hits(&mut hits, SortBy::Updated);
+        let ids: Vec<&str> = hits.iter().map(|h| h.memory.id.as_str()).collect();
+        assert_eq!(ids, vec!["old-edited", "new", "old"]);
+
+        sort_hits(&mut hits, SortBy::Created);
+        let ids: Vec<&str> = hits.iter().map(|h| h.memory.id.as_str()).collect();
+        assert_eq!(ids, vec!["new", "old-edited", "old"]);
+        assert_eq!(SortBy::parse("Updated"), Some(SortBy::Updated));
+        assert_eq!(SortBy::parse("oldest"), None);
+    }
 }
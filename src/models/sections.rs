@@ -0,0 +1,291 @@
+//! Structured memory sections and capture templates.
+//!
+//! Structured memories carry named sections (e.g. an ADR's context, decision,
+//! consequences and alternatives). They are stored as YAML front matter listing
+//! the section names, followed by a Markdown body with one `## Heading` per
+//! section:
+//!
+//! ```text
+//! ---
+//! template: adr
+//! sections:
+//! - context
+//! - decision
+//! ---
+//! ## Context
+//!
+//! We need a local store.
+//!
+//! ## Decision
+//!
+//! Use SQLite.
+//! ```
+//!
+//! Because the sections live in the memory content, they are indexed for
+//! full-text search like any other content. The index also keeps each section
+//! body on its own, so a section-scoped search (`section:decision`) matches
+//! and ranks just that section.
+
+use crate::git::YamlFrontMatterParser;
+use std::fmt::{self, Write as _};
+
+/// A named section of a structured memory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MemorySection {
+    /// Normalized section name (lowercase, hyphen-separated).
+    pub name: String,
+    /// Section body.
+    pub content: String,
+}
+
+impl MemorySection {
+    /// Creates a new section, normalizing its name.
+    #[must_use]
+    pub fn new(name: impl AsRef<str>, content: impl Into<String>) -> Self {
+        Self {
+            name: normalize_section_name(name.as_ref()),
+            content: content.into(),
+        }
+    }
+
+    /// Returns the section name formatted as a Markdown heading title.
+    #[must_use]
+    pub fn title(&self) -> String {
+        section_title(&self.name)
+    }
+}
+
+/// Template for structured captures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureTemplate {
+    /// Architecture Decision Record (context, decision, consequences, alternatives).
+    ///
+    /// Mirrors the structure requested by the `subcog_document_decision` prompt.
+    Adr,
+}
+
+impl CaptureTemplate {
+    /// Section names of an ADR, in rendering order.
+    pub const ADR_SECTIONS: &'static [&'static str] =
+        &["context", "decision", "consequences", "alternatives"];
+
+    /// Returns the template name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Adr => "adr",
+        }
+    }
+
+    /// Parses a template name.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "adr" | "decision" => Some(Self::Adr),
+            _ => None,
+        }
+    }
+
+    /// Returns the section names of this template, in rendering order.
+    #[must_use]
+    pub const fn section_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::Adr => Self::ADR_SECTIONS,
+        }
+    }
+
+    /// Renders an empty skeleton of this template for interactive editing.
+    #[must_use]
+    pub fn skeleton(&self) -> String {
+        let sections: Vec<MemorySection> = self
+            .section_names()
+            .iter()
+            .map(|name| MemorySection::new(name, ""))
+            .collect();
+        render_sections(Some(*self), "", &sections)
+    }
+}
+
+impl fmt::Display for CaptureTemplate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// Normalizes a section name (`"Key Consequences"` -> `"key-consequences"`).
+#[must_use]
+pub fn normalize_section_name(name: &str) -> String {
+    name.trim()
+        .to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn section_title(name: &str) -> String {
+    name.split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_uppercase().collect::<String>() + chars.as_str()
+            })
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Renders structured content as front matter plus a sectioned Markdown body.
+///
+/// `preamble` is placed before the first section heading (it may be empty).
+#[must_use]
+pub fn render_sections(
+    template: Option<CaptureTemplate>,
+    preamble: &str,
+    sections: &[MemorySection],
+) -> String {
+    let mut out = String::from("---\n");
+    if let Some(template) = template {
+        let _ = writeln!(out, "template: {template}");
+    }
+    out.push_str("sections:\n");
+    for section in sections {
+        let _ = writeln!(out, "- {}", section.name);
+    }
+    out.push_str("---\n");
+
+    let preamble = preamble.trim();
+    if !preamble.is_empty() {
+        out.push_str(preamble);
+        out.push_str("\n\n");
+    }
+
+    for section in sections {
+        let _ = write!(out, "## {}\n\n", section.title());
+        let body = section.content.trim();
+        if !body.is_empty() {
+            out.push_str(body);
+            out.push('\n');
+        }
+        out.push('\n');
+    }
+
+    out.trim_end().to_string()
+}
+
+/// Parses the sections of structured content.
+///
+/// Returns an empty vector for unstructured content (no `sections` key in the
+/// front matter) or when the front matter is malformed.
+#[must_use]
+pub fn parse_sections(content: &str) -> Vec<MemorySection> {
+    let Ok((metadata, body)) = YamlFrontMatterParser::parse(content) else {
+        return Vec::new();
+    };
+    let Some(declared) = metadata.get("sections").and_then(|v| v.as_array()) else {
+        return Vec::new();
+    };
+    let declared: Vec<String> = declared
+        .iter()
+        .filter_map(|v| v.as_str())
+        .map(normalize_section_name)
+        .collect();
+
+    let mut sections: Vec<MemorySection> = Vec::new();
+    let mut current: Option<MemorySection> = None;
+    for line in body.lines() {
+        if let Some(heading) = line.strip_prefix("## ") {
+            let name = normalize_section_name(heading);
+            if declared.contains(&name) {
+                sections.extend(current.take());
+                current = Some(MemorySection::new(&name, String::new()));
+                continue;
+            }
+        }
+        if let Some(section) = current.as_mut() {
+            section.content.push_str(line);
+            section.content.push('\n');
+        }
+    }
+    sections.extend(current);
+
+    for section in &mut sections {
+        section.content = section.content.trim().to_string();
+    }
+    sections
+}
+
+/// Returns the template recorded in structured content, if any.
+#[must_use]
+pub fn parse_template(content: &str) -> Option<CaptureTemplate> {
+    let (metadata, _) = YamlFrontMatterParser::parse(content).ok()?;
+    metadata
+        .get("template")
+        .and_then(|v| v.as_str())
+        .and_then(CaptureTemplate::parse)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_section_name() {
+        assert_eq!(
+            normalize_section_name("  Key Consequences "),
+            "key-consequences"
+        );
+        assert_eq!(normalize_section_name("open_questions"), "open-questions");
+        assert_eq!(normalize_section_name("Decision"), "decision");
+    }
+
+    #[test]
+    fn test_render_and_parse_roundtrip() {
+        let sections = vec![
+            MemorySection::new("Context", "We need a local store."),
+            MemorySection::new("decision", "Use SQLite.\n\nIt is embedded."),
+        ];
+        let content = render_sections(Some(CaptureTemplate::Adr), "Storage choice", &sections);
+
+        assert!(content.starts_with("---\ntemplate: adr\nsections:\n- context\n- decision\n---"));
+        assert!(content.contains("## Decision"));
+        assert_eq!(parse_sections(&content), sections);
+        assert_eq!(parse_template(&content), Some(CaptureTemplate::Adr));
+    }
+
+    #[test]
+    fn test_parse_sections_unstructured() {
+        assert!(parse_sections("Plain memory content").is_empty());
+        assert!(parse_sections("---\nnamespace: decisions\n---\n## Heading\nbody").is_empty());
+    }
+
+    #[test]
+    fn test_parse_sections_ignores_undeclared_headings() {
+        let content = "---\nsections:\n- decision\n---\n## Decision\nUse Rust.\n## Notes\nmore";
+        let sections = parse_sections(content);
+        assert_eq!(sections.len(), 1);
+        assert_eq!(sections[0].content, "Use Rust.\n## Notes\nmore");
+    }
+
+    #[test]
+    fn test_adr_skeleton() {
+        let skeleton = CaptureTemplate::Adr.skeleton();
+        for title in [
+            "## Context",
+            "## Decision",
+            "## Consequences",
+            "## Alternatives",
+        ] {
+            assert!(skeleton.contains(title));
+        }
+        let sections = parse_sections(&skeleton);
+        assert_eq!(sections.len(), 4);
+        assert!(sections.iter().all(|s| s.content.is_empty()));
+    }
+
+    #[test]
+    fn test_template_parse() {
+        assert_eq!(CaptureTemplate::parse("ADR"), Some(CaptureTemplate::Adr));
+        assert_eq!(CaptureTemplate::parse("unknown"), None);
+    }
+}
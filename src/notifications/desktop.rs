@@ -0,0 +1,169 @@
+//! Desktop notifications.
+
+use super::{Notification, NotificationFilter, Notifier};
+use crate::Result;
+use crate::models::MemoryEvent;
+use crate::storage::index::DomainScope;
+use std::sync::Arc;
+
+/// Trait for showing desktop notifications.
+///
+/// This trait allows for different implementations (`notify-rust`, mock for
+/// testing).
+pub trait DesktopTransport: Send + Sync {
+    /// Shows a notification.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notification cannot be shown.
+    fn show(&self, notification: &Notification) -> Result<()>;
+}
+
+/// Desktop transport using the platform notification service.
+#[cfg(feature = "desktop-notify")]
+pub struct NotifyRustTransport;
+
+#[cfg(feature = "desktop-notify")]
+impl DesktopTransport for NotifyRustTransport {
+    fn show(&self, notification: &Notification) -> Result<()> {
+        notify_rust::Notification::new()
+            .appname("subcog")
+            .summary(&notification.title)
+            .body(&notification.body)
+            .show()
+            .map(|_| ())
+            .map_err(|e| crate::Error::OperationFailed {
+                operation: "desktop_notify".to_string(),
+                cause: e.to_string(),
+            })
+    }
+}
+
+/// Notifier that shows a desktop notification for each matching event.
+pub struct DesktopNotifier {
+    /// Notifier name.
+    name: String,
+    /// Events to notify about.
+    filter: NotificationFilter,
+    /// Domain scope of the events.
+    scope: DomainScope,
+    /// Notification transport.
+    transport: Arc<dyn DesktopTransport>,
+}
+
+impl DesktopNotifier {
+    /// Creates a desktop notifier with the given transport.
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        filter: NotificationFilter,
+        scope: DomainScope,
+        transport: Arc<dyn DesktopTransport>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            filter,
+            scope,
+            transport,
+        }
+    }
+
+    /// Creates a desktop notifier using the platform notification service.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::Error::FeatureNotEnabled`] without the
+    /// `desktop-notify` feature.
+    #[cfg(feature = "desktop-notify")]
+    pub fn from_config(name: &str, filter: NotificationFilter, scope: DomainScope) -> Result<Self> {
+        Ok(Self::new(
+            name,
+            filter,
+            scope,
+            Arc::new(NotifyRustTransport),
+        ))
+    }
+
+    /// Creates a desktop notifier using the platform notification service.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`crate::Error::FeatureNotEnabled`] without the
+    /// `desktop-notify` feature.
+    #[cfg(not(feature = "desktop-notify"))]
+    pub fn from_config(
+        _name: &str,
+        _filter: NotificationFilter,
+        _scope: DomainScope,
+    ) -> Result<Self> {
+        Err(crate::Error::FeatureNotEnabled(
+            "desktop-notify".to_string(),
+        ))
+    }
+}
+
+impl Notifier for DesktopNotifier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn notify(&self, event: &MemoryEvent) -> Result<()> {
+        if !self.filter.matches(event, self.scope) {
+            return Ok(());
+        }
+        self.transport
+            .show(&Notification::from_event(event, self.scope))
+    }
+
+    fn send(&self, notification: &Notification) -> Result<bool> {
+        if !self.filter.matches_notification(notification, self.scope) {
+            return Ok(false);
+        }
+        self.transport.show(notification)?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Namespace;
+    use crate::notifications::tests::captured;
+    use std::sync::Mutex;
+
+    /// Transport recording shown notifications.
+    #[derive(Default)]
+    struct MockDesktopTransport {
+        shown: Mutex<Vec<Notification>>,
+    }
+
+    impl DesktopTransport for MockDesktopTransport {
+        fn show(&self, notification: &Notification) -> Result<()> {
+            self.shown.lock().expect("lock").push(notification.clone());
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_desktop_notifier_fires_on_matching_events() {
+        let transport = Arc::new(MockDesktopTransport::default());
+        let filter = NotificationFilter {
+            events: vec!["captured".to_string()],
+            scopes: Vec::new(),
+            namespaces: vec!["decisions".to_string()],
+        };
+        let notifier = DesktopNotifier::new(
+            "important",
+            filter,
+            DomainScope::Project,
+            Arc::clone(&transport) as Arc<dyn DesktopTransport>,
+        );
+
+        notifier.notify(&captured(Namespace::Decisions)).unwrap();
+        notifier.notify(&captured(Namespace::Learnings)).unwrap();
+
+        let shown = transport.shown.lock().expect("lock");
+        assert_eq!(shown.len(), 1);
+        assert!(shown[0].body.starts_with("decisions memory abc123"));
+    }
+}
@@ -0,0 +1,397 @@
+//! Email digest notifications.
+//!
+//! Matching events are queued in memory and sent as one digest email once
+//! the digest interval has passed since the last digest. A failed send keeps
+//! the queue for the next attempt. Queued events are lost when the server
+//! stops.
+
+use super::{Notification, NotificationFilter, Notifier};
+use crate::config::{ConfigFileNotifierEmail, expand_env_vars};
+use crate::models::MemoryEvent;
+use crate::storage::index::DomainScope;
+use crate::{Error, Result};
+use secrecy::SecretString;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
+
+/// Maximum number of events listed in one digest.
+const MAX_DIGEST_ENTRIES: usize = 500;
+
+/// An email to send.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EmailMessage {
+    /// Recipient addresses.
+    pub to: Vec<String>,
+    /// Subject line.
+    pub subject: String,
+    /// Plain-text body.
+    pub body: String,
+}
+
+/// Trait for sending email.
+///
+/// This trait allows for different implementations (SMTP, mock for testing).
+pub trait EmailTransport: Send + Sync {
+    /// Sends an email.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the email cannot be sent.
+    fn send(&self, message: &EmailMessage) -> Result<()>;
+}
+
+/// SMTP connection settings.
+#[derive(Debug, Clone)]
+pub struct EmailSettings {
+    /// SMTP relay host.
+    pub smtp_host: String,
+    /// SMTP port.
+    pub smtp_port: u16,
+    /// SMTP username.
+    pub username: Option<String>,
+    /// SMTP password.
+    pub password: Option<SecretString>,
+    /// Sender address.
+    pub from: String,
+}
+
+/// Email transport over SMTP with STARTTLS.
+#[cfg(feature = "email-notify")]
+pub struct SmtpEmailTransport {
+    /// Connection settings.
+    settings: EmailSettings,
+}
+
+#[cfg(feature = "email-notify")]
+impl SmtpEmailTransport {
+    /// Creates an SMTP transport.
+    #[must_use]
+    pub const fn new(settings: EmailSettings) -> Self {
+        Self { settings }
+    }
+}
+
+#[cfg(feature = "email-notify")]
+impl EmailTransport for SmtpEmailTransport {
+    fn send(&self, message: &EmailMessage) -> Result<()> {
+        use lettre::Transport;
+        use lettre::transport::smtp::authentication::Credentials;
+        use secrecy::ExposeSecret;
+
+        crate::config::ensure_online("send_email")?;
+        let failed = |cause: String| Error::OperationFailed {
+            operation: "send_email".to_string(),
+            cause,
+        };
+        let parse = |address: &str| {
+            address
+                .parse::<lettre::message::Mailbox>()
+                .map_err(|e| Error::InvalidInput(format!("Invalid email address '{address}': {e}")))
+        };
+
+        let mut builder = lettre::Message::builder()
+            .from(parse(&self.settings.from)?)
+            .subject(message.subject.as_str());
+        for to in &message.to {
+            builder = builder.to(parse(to)?);
+        }
+        let email = builder
+            .body(message.body.clone())
+            .map_err(|e| failed(e.to_string()))?;
+
+        let mut mailer = lettre::SmtpTransport::starttls_relay(&self.settings.smtp_host)
+            .map_err(|e| failed(e.to_string()))?
+            .port(self.settings.smtp_port);
+        if let (Some(username), Some(password)) = (&self.settings.username, &self.settings.password)
+        {
+            mailer = mailer.credentials(Credentials::new(
+                username.clone(),
+                password.expose_secret().to_string(),
+            ));
+        }
+        mailer
+            .build()
+            .send(&email)
+            .map(|_| ())
+            .map_err(|e| failed(e.to_string()))
+    }
+}
+
+/// Events queued for the next digest.
+#[derive(Debug, Default)]
+struct Digest {
+    /// Queued notifications, oldest first.
+    entries: Vec<Notification>,
+    /// Matching events beyond [`MAX_DIGEST_ENTRIES`].
+    omitted: usize,
+    /// When the last digest was sent (Unix seconds).
+    last_sent: u64,
+}
+
+/// Notifier that batches matching events into periodic digest emails.
+pub struct EmailDigestNotifier {
+    /// Notifier name.
+    name: String,
+    /// Events to include.
+    filter: NotificationFilter,
+    /// Domain scope of the events.
+    scope: DomainScope,
+    /// Recipient addresses.
+    to: Vec<String>,
+    /// Seconds between digests.
+    interval_secs: u64,
+    /// Pending digest.
+    digest: Mutex<Digest>,
+    /// Email transport.
+    transport: Arc<dyn EmailTransport>,
+}
+
+impl EmailDigestNotifier {
+    /// Creates a digest notifier with the given transport.
+    ///
+    /// The first digest is due `interval_secs` after creation.
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        filter: NotificationFilter,
+        scope: DomainScope,
+        to: Vec<String>,
+        interval_secs: u64,
+        transport: Arc<dyn EmailTransport>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            filter,
+            scope,
+            to,
+            interval_secs,
+            digest: Mutex::new(Digest {
+                last_sent: crate::current_timestamp(),
+                ..Digest::default()
+            }),
+            transport,
+        }
+    }
+
+    /// Creates a digest notifier sending over SMTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no recipient is configured, or
+    /// [`Error::FeatureNotEnabled`] without the `email-notify` feature.
+    pub fn from_config(
+        name: &str,
+        filter: NotificationFilter,
+        scope: DomainScope,
+        config: &ConfigFileNotifierEmail,
+    ) -> Result<Self> {
+        if config.to.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "Notifier '{name}': email notifiers need at least one recipient"
+            )));
+        }
+        let settings = EmailSettings {
+            smtp_host: config.smtp_host.clone(),
+            smtp_port: config.smtp_port,
+            username: config
+                .username
+                .as_deref()
+                .map(|username| expand_env_vars(username).into_owned()),
+            password: config
+                .password
+                .as_deref()
+                .map(|password| SecretString::from(expand_env_vars(password).into_owned())),
+            from: config.from.clone(),
+        };
+        let transport = smtp_transport(settings)?;
+        Ok(Self::new(
+            name,
+            filter,
+            scope,
+            config.to.clone(),
+            config.digest_interval_secs,
+            transport,
+        ))
+    }
+
+    /// Returns the number of events queued for the next digest.
+    #[must_use]
+    pub fn pending(&self) -> usize {
+        self.digest
+            .lock()
+            .map_or(0, |digest| digest.entries.len() + digest.omitted)
+    }
+
+    /// Formats the digest email for the queued events.
+    fn format_digest(&self, digest: &Digest) -> EmailMessage {
+        let count = digest.entries.len() + digest.omitted;
+        let mut body = format!("{count} subcog events since the last digest:\n\n");
+        for entry in &digest.entries {
+            let time = chrono::DateTime::from_timestamp(
+                i64::try_from(entry.timestamp).unwrap_or(i64::MAX),
+                0,
+            )
+            .map_or_else(|| entry.timestamp.to_string(), |t| t.to_rfc3339());
+            let _ = writeln!(body, "- {time} [{}] {}", entry.event_type, entry.body);
+        }
+        if digest.omitted > 0 {
+            let _ = writeln!(body, "- ... and {} more", digest.omitted);
+        }
+
+        EmailMessage {
+            to: self.to.clone(),
+            subject: format!("Subcog digest ({}): {count} events", self.name),
+            body,
+        }
+    }
+}
+
+impl Notifier for EmailDigestNotifier {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn notify(&self, event: &MemoryEvent) -> Result<()> {
+        if !self.filter.matches(event, self.scope) {
+            return Ok(());
+        }
+        let mut digest = lock(&self.digest)?;
+        if digest.entries.len() < MAX_DIGEST_ENTRIES {
+            digest
+                .entries
+                .push(Notification::from_event(event, self.scope));
+        } else {
+            digest.omitted += 1;
+        }
+        drop(digest);
+        Ok(())
+    }
+
+    /// Sends the notification as its own email, outside the digest.
+    fn send(&self, notification: &Notification) -> Result<bool> {
+        if !self.filter.matches_notification(notification, self.scope) {
+            return Ok(false);
+        }
+        self.transport.send(&EmailMessage {
+            to: self.to.clone(),
+            subject: notification.title.clone(),
+            body: notification.body.clone(),
+        })?;
+        Ok(true)
+    }
+
+    fn flush(&self, now: u64) -> Result<()> {
+        let mut digest = lock(&self.digest)?;
+        if digest.entries.is_empty() || now < digest.last_sent.saturating_add(self.interval_secs) {
+            return Ok(());
+        }
+
+        self.transport.send(&self.format_digest(&digest))?;
+        metrics::counter!("notification_digests_sent_total", "notifier" => self.name.clone())
+            .increment(1);
+        *digest = Digest {
+            last_sent: now,
+            ..Digest::default()
+        };
+        drop(digest);
+        Ok(())
+    }
+}
+
+/// Locks the pending digest.
+fn lock(digest: &Mutex<Digest>) -> Result<std::sync::MutexGuard<'_, Digest>> {
+    digest.lock().map_err(|e| Error::OperationFailed {
+        operation: "lock_digest".to_string(),
+        cause: e.to_string(),
+    })
+}
+
+/// Creates the SMTP transport.
+#[cfg(feature = "email-notify")]
+fn smtp_transport(settings: EmailSettings) -> Result<Arc<dyn EmailTransport>> {
+    Ok(Arc::new(SmtpEmailTransport::new(settings)))
+}
+
+/// Creates the SMTP transport.
+#[cfg(not(feature = "email-notify"))]
+fn smtp_transport(_settings: EmailSettings) -> Result<Arc<dyn EmailTransport>> {
+    Err(Error::FeatureNotEnabled("email-notify".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Namespace;
+    use crate::notifications::tests::captured;
+
+    /// Transport recording sent emails.
+    #[derive(Default)]
+    struct MockEmailTransport {
+        sent: Mutex<Vec<EmailMessage>>,
+    }
+
+    impl EmailTransport for MockEmailTransport {
+        fn send(&self, message: &EmailMessage) -> Result<()> {
+            self.sent.lock().expect("lock").push(message.clone());
+            Ok(())
+        }
+    }
+
+    fn notifier(transport: &Arc<MockEmailTransport>) -> EmailDigestNotifier {
+        let filter = NotificationFilter {
+            events: vec!["captured".to_string()],
+            scopes: vec!["project".to_string()],
+            namespaces: Vec::new(),
+        };
+        EmailDigestNotifier::new(
+            "daily",
+            filter,
+            DomainScope::Project,
+            vec!["me@example.com".to_string()],
+            3600,
+            Arc::clone(transport) as Arc<dyn EmailTransport>,
+        )
+    }
+
+    #[test]
+    fn test_email_digest_batches_matching_events_on_schedule() {
+        let transport = Arc::new(MockEmailTransport::default());
+        let notifier = notifier(&transport);
+        let start = crate::current_timestamp();
+
+        notifier.notify(&captured(Namespace::Decisions)).unwrap();
+        notifier.notify(&captured(Namespace::Patterns)).unwrap();
+        assert_eq!(notifier.pending(), 2);
+
+        // Not due yet
+        notifier.flush(start + 60).unwrap();
+        assert!(transport.sent.lock().expect("lock").is_empty());
+
+        notifier.flush(start + 3600).unwrap();
+        let sent = transport.sent.lock().expect("lock");
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].to, vec!["me@example.com".to_string()]);
+        assert_eq!(sent[0].subject, "Subcog digest (daily): 2 events");
+        assert!(sent[0].body.contains("[captured] decisions memory abc123"));
+        assert!(sent[0].body.contains("[captured] patterns memory abc123"));
+        drop(sent);
+        assert_eq!(notifier.pending(), 0);
+    }
+
+    #[test]
+    fn test_email_digest_skips_non_matching_and_empty_digests() {
+        let transport = Arc::new(MockEmailTransport::default());
+        let notifier = notifier(&transport);
+        let deleted = MemoryEvent::Deleted {
+            meta: crate::models::EventMeta::new("test", None),
+            memory_id: crate::models::MemoryId::new("abc123"),
+            reason: "cleanup".to_string(),
+        };
+
+        notifier.notify(&deleted).unwrap();
+        notifier.flush(u64::MAX).unwrap();
+
+        assert_eq!(notifier.pending(), 0);
+        assert!(transport.sent.lock().expect("lock").is_empty());
+    }
+}
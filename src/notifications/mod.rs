@@ -0,0 +1,478 @@
+//! Event notifications (webhooks, desktop, email).
+//!
+//! A [`NotificationService`] subscribes to the global event bus and hands
+//! each memory event to its [`Notifier`]s. Webhooks are one notifier (see
+//! [`crate::webhooks::WebhookDispatcher`]); this module adds desktop
+//! notifications and batched email digests.
+//!
+//! # Configuration
+//!
+//! Notifiers are configured in `~/.config/subcog/config.toml` under
+//! `[[notifiers]]`, with the same event and scope filters as webhooks plus a
+//! namespace filter for captures:
+//!
+//! ```toml
+//! [[notifiers]]
+//! name = "important-captures"
+//! type = "desktop"
+//! events = ["captured"]
+//! namespaces = ["decisions", "blockers"]
+//!
+//! [[notifiers]]
+//! name = "daily-digest"
+//! type = "email"
+//! events = ["captured"]
+//!
+//! [notifiers.email]
+//! smtp_host = "smtp.example.com"
+//! username = "${SMTP_USER}"
+//! password = "${SMTP_PASSWORD}"
+//! from = "subcog@example.com"
+//! to = ["me@example.com"]
+//! digest_interval_secs = 86400
+//! ```
+//!
+//! Desktop notifications require the `desktop-notify` feature and email
+//! digests the `email-notify` feature.
+
+mod desktop;
+mod email;
+
+#[cfg(feature = "desktop-notify")]
+pub use desktop::NotifyRustTransport;
+pub use desktop::{DesktopNotifier, DesktopTransport};
+#[cfg(feature = "email-notify")]
+pub use email::SmtpEmailTransport;
+pub use email::{EmailDigestNotifier, EmailMessage, EmailSettings, EmailTransport};
+
+use crate::config::{ConfigFileNotifier, NotifiersConfig};
+use crate::models::MemoryEvent;
+use crate::observability::{EventBus, global_event_bus};
+use crate::storage::index::DomainScope;
+use crate::{Error, Result};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// How often batched notifiers are asked to flush.
+const FLUSH_INTERVAL: Duration = Duration::from_secs(60);
+
+/// A consumer of memory events.
+pub trait Notifier: Send + Sync {
+    /// Returns the notifier name, used in logs and metrics.
+    fn name(&self) -> &str;
+
+    /// Handles an event. Implementations apply their own filters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notification cannot be delivered.
+    fn notify(&self, event: &MemoryEvent) -> Result<()>;
+
+    /// Sends batched notifications that are due at `now` (Unix seconds).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the batch cannot be delivered.
+    fn flush(&self, _now: u64) -> Result<()> {
+        Ok(())
+    }
+
+    /// Sends a standalone notification, such as a memory digest, if it
+    /// passes the notifier's filters. Returns whether it was sent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the notification cannot be delivered.
+    fn send(&self, _notification: &Notification) -> Result<bool> {
+        Ok(false)
+    }
+}
+
+/// Event, scope and namespace filter of a notifier.
+///
+/// Empty lists match everything and `*` matches any value. Only `captured`
+/// events carry a namespace, so a namespace filter excludes all other events
+/// and standalone notifications.
+#[derive(Debug, Clone, Default)]
+pub struct NotificationFilter {
+    /// Event types to notify about.
+    pub events: Vec<String>,
+    /// Domain scopes to notify about.
+    pub scopes: Vec<String>,
+    /// Namespaces of captured memories to notify about.
+    pub namespaces: Vec<String>,
+}
+
+impl NotificationFilter {
+    /// Creates a filter from a config file entry.
+    #[must_use]
+    pub fn from_config_file(config: &ConfigFileNotifier) -> Self {
+        Self {
+            events: config.events.clone(),
+            scopes: config.scopes.clone(),
+            namespaces: config.namespaces.clone(),
+        }
+    }
+
+    /// Checks if a standalone notification sent from `scope` passes the filter.
+    #[must_use]
+    pub fn matches_notification(&self, notification: &Notification, scope: DomainScope) -> bool {
+        self.namespaces.is_empty()
+            && matches_any(&self.events, &notification.event_type)
+            && matches_any(&self.scopes, scope.as_str())
+    }
+
+    /// Checks if `event`, raised in `scope`, passes the filter.
+    #[must_use]
+    pub fn matches(&self, event: &MemoryEvent, scope: DomainScope) -> bool {
+        if !matches_any(&self.events, event.event_type())
+            || !matches_any(&self.scopes, scope.as_str())
+        {
+            return false;
+        }
+        if self.namespaces.is_empty() {
+            return true;
+        }
+        match event {
+            MemoryEvent::Captured { namespace, .. } => {
+                matches_any(&self.namespaces, namespace.as_str())
+            },
+            _ => false,
+        }
+    }
+}
+
+/// Checks if `value` is in `list` (empty = all, `*` = any).
+fn matches_any(list: &[String], value: &str) -> bool {
+    list.is_empty() || list.iter().any(|v| v == "*" || v == value)
+}
+
+/// A human-readable description of a memory event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Notification {
+    /// Event type (e.g. `captured`).
+    pub event_type: String,
+    /// Short title.
+    pub title: String,
+    /// One-line description.
+    pub body: String,
+    /// Event timestamp (Unix seconds).
+    pub timestamp: u64,
+}
+
+impl Notification {
+    /// Describes `event`, raised in `scope`.
+    #[must_use]
+    pub fn from_event(event: &MemoryEvent, scope: DomainScope) -> Self {
+        let event_type = event.event_type();
+        let body = match event {
+            MemoryEvent::Captured {
+                memory_id,
+                namespace,
+                content_length,
+                ..
+            } => format!(
+                "{} memory {} captured in {} ({content_length} bytes)",
+                namespace.as_str(),
+                memory_id.as_str(),
+                scope.as_str()
+            ),
+            MemoryEvent::Updated {
+                memory_id,
+                modified_fields,
+                ..
+            } => format!(
+                "Memory {} updated: {}",
+                memory_id.as_str(),
+                modified_fields.join(", ")
+            ),
+            MemoryEvent::Deleted {
+                memory_id, reason, ..
+            }
+            | MemoryEvent::Archived {
+                memory_id, reason, ..
+            } => format!("Memory {} {event_type}: {reason}", memory_id.as_str()),
+            MemoryEvent::Consolidated {
+                processed,
+                archived,
+                merged,
+                ..
+            } => {
+                format!("{processed} memories consolidated ({merged} merged, {archived} archived)")
+            },
+            MemoryEvent::Synced { pushed, pulled, .. } => {
+                format!("Memories synced: {pushed} pushed, {pulled} pulled")
+            },
+            _ => format!("{event_type} event in {}", scope.as_str()),
+        };
+
+        Self {
+            event_type: event_type.to_string(),
+            title: format!("Subcog: {event_type}"),
+            body,
+            timestamp: event.meta().timestamp,
+        }
+    }
+}
+
+/// Routes memory events from the event bus to notifiers.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use subcog::notifications::NotificationService;
+///
+/// let service = NotificationService::from_config(&config.notifiers, DomainScope::Project);
+/// if !service.is_empty() {
+///     let _handle = service.start();
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct NotificationService {
+    /// Registered notifiers.
+    notifiers: Vec<Arc<dyn Notifier>>,
+}
+
+impl NotificationService {
+    /// Creates a service without notifiers.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a service with the enabled desktop and email notifiers of
+    /// `config`.
+    ///
+    /// Invalid notifiers are logged and skipped.
+    #[must_use]
+    pub fn from_config(config: &NotifiersConfig, scope: DomainScope) -> Self {
+        let mut service = Self::new();
+        for entry in config.notifiers.iter().filter(|n| n.enabled) {
+            match build_notifier(entry, scope) {
+                Ok(notifier) => service = service.with_notifier(notifier),
+                Err(e) => tracing::warn!(
+                    notifier = %entry.name,
+                    error = %e,
+                    "Skipping notifier"
+                ),
+            }
+        }
+        service
+    }
+
+    /// Adds a notifier.
+    #[must_use]
+    pub fn with_notifier(mut self, notifier: Arc<dyn Notifier>) -> Self {
+        self.notifiers.push(notifier);
+        self
+    }
+
+    /// Returns the number of notifiers.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.notifiers.len()
+    }
+
+    /// Returns true if no notifiers are registered.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.notifiers.is_empty()
+    }
+
+    /// Hands an event to every notifier, logging failures.
+    pub fn dispatch(&self, event: &MemoryEvent) {
+        for notifier in &self.notifiers {
+            if let Err(e) = notifier.notify(event) {
+                let name = notifier.name().to_string();
+                metrics::counter!("notifications_failed_total", "notifier" => name).increment(1);
+                tracing::warn!(
+                    notifier = %notifier.name(),
+                    event_type = %event.event_type(),
+                    error = %e,
+                    "Notification failed"
+                );
+            }
+        }
+    }
+
+    /// Flushes every notifier's due batch, logging failures.
+    pub fn flush(&self, now: u64) {
+        for notifier in &self.notifiers {
+            if let Err(e) = notifier.flush(now) {
+                tracing::warn!(
+                    notifier = %notifier.name(),
+                    error = %e,
+                    "Notification flush failed"
+                );
+            }
+        }
+    }
+
+    /// Sends a standalone notification through every notifier whose filters
+    /// accept it, logging failures. Returns the number of notifiers that sent
+    /// it.
+    pub fn send(&self, notification: &Notification) -> usize {
+        let mut sent = 0;
+        for notifier in &self.notifiers {
+            match notifier.send(notification) {
+                Ok(true) => sent += 1,
+                Ok(false) => {},
+                Err(e) => tracing::warn!(
+                    notifier = %notifier.name(),
+                    event_type = %notification.event_type,
+                    error = %e,
+                    "Notification failed"
+                ),
+            }
+        }
+        sent
+    }
+
+    /// Runs the service until the event bus is closed.
+    ///
+    /// Events are dispatched as they arrive and batched notifiers are flushed
+    /// every minute. Notifiers block (SMTP sends, desktop popups), so both run
+    /// on the blocking thread pool.
+    pub async fn run(&self, event_bus: &EventBus) {
+        let mut receiver = event_bus.subscribe();
+        let mut ticker = tokio::time::interval(FLUSH_INTERVAL);
+
+        loop {
+            tokio::select! {
+                message = receiver.recv() => match message {
+                    Ok(event) => self.dispatch_blocking(event).await,
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        metrics::counter!("notification_events_lagged_total").increment(skipped);
+                        tracing::warn!(skipped = skipped, "Notifications lagged behind event bus");
+                    },
+                    Err(broadcast::error::RecvError::Closed) => {
+                        tracing::info!("Event bus closed, notification service shutting down");
+                        break;
+                    },
+                },
+                _ = ticker.tick() => self.flush_blocking(crate::current_timestamp()).await,
+            }
+        }
+    }
+
+    /// Runs [`dispatch`](Self::dispatch) on the blocking thread pool.
+    async fn dispatch_blocking(&self, event: MemoryEvent) {
+        let service = self.clone();
+        if let Err(e) = tokio::task::spawn_blocking(move || service.dispatch(&event)).await {
+            tracing::warn!(error = %e, "Notification dispatch task failed");
+        }
+    }
+
+    /// Runs [`flush`](Self::flush) on the blocking thread pool.
+    async fn flush_blocking(&self, now: u64) {
+        let service = self.clone();
+        if let Err(e) = tokio::task::spawn_blocking(move || service.flush(now)).await {
+            tracing::warn!(error = %e, "Notification flush task failed");
+        }
+    }
+
+    /// Starts the service on the global event bus as a background task.
+    #[must_use]
+    pub fn start(self) -> tokio::task::JoinHandle<()> {
+        let event_bus = global_event_bus();
+        tokio::spawn(async move {
+            self.run(event_bus).await;
+        })
+    }
+}
+
+/// Builds a desktop or email notifier from a config file entry.
+fn build_notifier(config: &ConfigFileNotifier, scope: DomainScope) -> Result<Arc<dyn Notifier>> {
+    let filter = NotificationFilter::from_config_file(config);
+    match config.kind.as_str() {
+        "desktop" => Ok(Arc::new(DesktopNotifier::from_config(
+            &config.name,
+            filter,
+            scope,
+        )?)),
+        "email" => {
+            let email = config.email.as_ref().ok_or_else(|| {
+                Error::InvalidInput(format!(
+                    "Notifier '{}': email notifiers need an [notifiers.email] section",
+                    config.name
+                ))
+            })?;
+            Ok(Arc::new(EmailDigestNotifier::from_config(
+                &config.name,
+                filter,
+                scope,
+                email,
+            )?))
+        },
+        other => Err(Error::InvalidInput(format!(
+            "Notifier '{}': unknown type '{other}' (expected desktop or email)",
+            config.name
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Domain, EventMeta, MemoryId, Namespace};
+
+    pub(super) fn captured(namespace: Namespace) -> MemoryEvent {
+        MemoryEvent::Captured {
+            meta: EventMeta::with_timestamp("test", None, 1_700_000_000),
+            memory_id: MemoryId::new("abc123"),
+            namespace,
+            domain: Domain::new(),
+            content_length: 42,
+        }
+    }
+
+    fn filter(events: &[&str], scopes: &[&str], namespaces: &[&str]) -> NotificationFilter {
+        let strings =
+            |values: &[&str]| -> Vec<String> { values.iter().map(ToString::to_string).collect() };
+        NotificationFilter {
+            events: strings(events),
+            scopes: strings(scopes),
+            namespaces: strings(namespaces),
+        }
+    }
+
+    #[test]
+    fn test_filter_matches_events_scopes_and_namespaces() {
+        let event = captured(Namespace::Decisions);
+
+        assert!(filter(&[], &[], &[]).matches(&event, DomainScope::Project));
+        assert!(filter(&["captured"], &["*"], &["decisions"]).matches(&event, DomainScope::User));
+        assert!(!filter(&["deleted"], &[], &[]).matches(&event, DomainScope::Project));
+        assert!(!filter(&[], &["user"], &[]).matches(&event, DomainScope::Project));
+        assert!(!filter(&[], &[], &["blockers"]).matches(&event, DomainScope::Project));
+    }
+
+    #[test]
+    fn test_notification_describes_capture() {
+        let notification =
+            Notification::from_event(&captured(Namespace::Decisions), DomainScope::Project);
+
+        assert_eq!(notification.title, "Subcog: captured");
+        assert_eq!(
+            notification.body,
+            "decisions memory abc123 captured in project (42 bytes)"
+        );
+        assert_eq!(notification.timestamp, 1_700_000_000);
+    }
+
+    #[test]
+    fn test_unknown_notifier_type_is_skipped() {
+        let config = NotifiersConfig::from_config_file(vec![ConfigFileNotifier {
+            name: "pager".to_string(),
+            kind: "pager".to_string(),
+            events: Vec::new(),
+            scopes: Vec::new(),
+            namespaces: Vec::new(),
+            enabled: true,
+            email: None,
+        }]);
+
+        assert!(NotificationService::from_config(&config, DomainScope::Project).is_empty());
+    }
+}
@@ -0,0 +1,171 @@
+//! Terminal syntax highlighting for code memories.
+//!
+//! A lightweight, dependency-free highlighter used by `subcog get` to render
+//! memories whose content type is code. It colors keywords, string literals,
+//! numbers, and line comments with ANSI escapes; everything else is printed
+//! unchanged. It is a display aid, not a parser: unknown languages fall back
+//! to a keyword set shared by most C-like languages.
+
+/// ANSI escape for keywords (bold magenta).
+const KEYWORD_STYLE: &str = "\x1b[1;35m";
+/// ANSI escape for string literals (green).
+const STRING_STYLE: &str = "\x1b[32m";
+/// ANSI escape for numbers (cyan).
+const NUMBER_STYLE: &str = "\x1b[36m";
+/// ANSI escape for comments (dim).
+const COMMENT_STYLE: &str = "\x1b[2m";
+/// ANSI escape that resets all styles.
+const RESET: &str = "\x1b[0m";
+
+/// Keywords highlighted in every language.
+const COMMON_KEYWORDS: &[&str] = &[
+    "if", "else", "for", "while", "return", "break", "continue", "true", "false", "null", "const",
+    "static", "class", "import", "switch", "case", "default", "new", "try", "catch", "throw",
+];
+
+/// Returns the keywords of a language in addition to [`COMMON_KEYWORDS`].
+fn language_keywords(lang: Option<&str>) -> &'static [&'static str] {
+    match lang {
+        Some("rust" | "rs") => &[
+            "fn", "let", "mut", "pub", "impl", "struct", "enum", "trait", "use", "mod", "match",
+            "loop", "where", "self", "Self", "crate", "super", "async", "await", "move", "ref",
+            "type", "unsafe", "dyn", "in", "as", "Some", "None", "Ok", "Err",
+        ],
+        Some("python" | "py") => &[
+            "def", "from", "as", "in", "is", "not", "and", "or", "with", "yield", "lambda", "pass",
+            "raise", "except", "finally", "elif", "None", "True", "False", "self", "async",
+            "await",
+        ],
+        Some("go") => &[
+            "func",
+            "package",
+            "var",
+            "type",
+            "struct",
+            "interface",
+            "map",
+            "chan",
+            "go",
+            "defer",
+            "range",
+            "select",
+            "nil",
+        ],
+        Some("javascript" | "js" | "typescript" | "ts") => &[
+            "function",
+            "let",
+            "var",
+            "async",
+            "await",
+            "export",
+            "from",
+            "of",
+            "in",
+            "this",
+            "typeof",
+            "instanceof",
+            "undefined",
+            "interface",
+            "type",
+        ],
+        Some("sql") => &[
+            "SELECT", "FROM", "WHERE", "INSERT", "INTO", "VALUES", "UPDATE", "SET", "DELETE",
+            "CREATE", "TABLE", "JOIN", "ON", "AND", "OR", "NOT", "ORDER", "BY", "GROUP", "LIMIT",
+        ],
+        Some("sh" | "bash" | "shell" | "zsh") => &[
+            "then", "fi", "do", "done", "esac", "function", "local", "export", "in",
+        ],
+        _ => &[
+            "fn", "let", "var", "def", "func", "function", "struct", "in",
+        ],
+    }
+}
+
+/// Returns the line comment marker of a language.
+fn comment_marker(lang: Option<&str>) -> &'static str {
+    match lang {
+        Some("python" | "py" | "sh" | "bash" | "shell" | "zsh" | "toml" | "yaml" | "yml") => "#",
+        Some("sql") => "--",
+        _ => "//",
+    }
+}
+
+/// Highlights code for display in an ANSI terminal.
+///
+/// `lang` is the lowercase language name (e.g. `rust`), if known.
+#[must_use]
+pub fn highlight_code(code: &str, lang: Option<&str>) -> String {
+    let keywords = language_keywords(lang);
+    let comment = comment_marker(lang);
+    let mut out = String::with_capacity(code.len() * 2);
+    for (i, line) in code.lines().enumerate() {
+        if i > 0 {
+            out.push('\n');
+        }
+        highlight_line(line, keywords, comment, &mut out);
+    }
+    out
+}
+
+/// Highlights a single line into `out`.
+fn highlight_line(line: &str, keywords: &[&str], comment: &str, out: &mut String) {
+    let mut rest = line;
+    while let Some(c) = rest.chars().next() {
+        if rest.starts_with(comment) {
+            push_styled(out, COMMENT_STYLE, rest);
+            return;
+        }
+        let len = if c == '"' || c == '\'' || c == '`' {
+            let end = rest[1..].find(c).map_or(rest.len(), |end| end + 2);
+            push_styled(out, STRING_STYLE, &rest[..end]);
+            end
+        } else if c.is_alphanumeric() || c == '_' {
+            let end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..end];
+            if c.is_ascii_digit() {
+                push_styled(out, NUMBER_STYLE, word);
+            } else if COMMON_KEYWORDS.contains(&word) || keywords.contains(&word) {
+                push_styled(out, KEYWORD_STYLE, word);
+            } else {
+                out.push_str(word);
+            }
+            end
+        } else {
+            out.push(c);
+            c.len_utf8()
+        };
+        rest = &rest[len..];
+    }
+}
+
+/// Appends `text` wrapped in an ANSI style.
+fn push_styled(out: &mut String, style: &str, text: &str) {
+    out.push_str(style);
+    out.push_str(text);
+    out.push_str(RESET);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_highlight_code() {
+        let highlighted = highlight_code("let x = \"hi\"; // note", Some("rust"));
+        assert_eq!(
+            highlighted,
+            format!(
+                "{KEYWORD_STYLE}let{RESET} x = {STRING_STYLE}\"hi\"{RESET}; {COMMENT_STYLE}// note{RESET}"
+            )
+        );
+        // Identifiers that merely contain a keyword are left alone
+        assert_eq!(highlight_code("letter", Some("rust")), "letter");
+        // Unterminated strings run to the end of the line
+        assert_eq!(
+            highlight_code("'é", None),
+            format!("{STRING_STYLE}'é{RESET}")
+        );
+    }
+}
@@ -0,0 +1,313 @@
+//! `JSONPath` extraction for JSON output (`subcog recall --json-path`).
+//!
+//! Supports the common subset of `JSONPath` used when scripting:
+//!
+//! | Syntax | Selects |
+//! |--------|---------|
+//! | `$` | The root |
+//! | `.name`, `['name']` | An object member |
+//! | `[2]`, `[-1]` | An array element (negative counts from the end) |
+//! | `.*`, `[*]` | Every member or element |
+//! | `..name`, `..*` | Matches at any depth below the current node |
+//!
+//! Filters, slices and unions are rejected with an error rather than silently
+//! matching nothing.
+
+use serde_json::Value;
+
+use crate::{Error, Result};
+
+/// A parsed `JSONPath` expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath {
+    /// Steps applied in order from the root.
+    segments: Vec<Segment>,
+}
+
+/// One step of a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Segment {
+    /// What the step selects from each node.
+    selector: Selector,
+    /// Whether the step also applies to every descendant (`..`).
+    recursive: bool,
+}
+
+/// What a step selects.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector {
+    /// An object member.
+    Name(String),
+    /// An array element; negative indices count from the end.
+    Index(i64),
+    /// Every member or element.
+    Wildcard,
+}
+
+impl JsonPath {
+    /// Parses a `JSONPath` expression.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] naming the position of the first
+    /// syntax error or unsupported construct.
+    pub fn parse(expression: &str) -> Result<Self> {
+        let bytes = expression.as_bytes();
+        if bytes.first() != Some(&b'$') {
+            return Err(invalid(expression, 0, "must start with `$`"));
+        }
+
+        let mut segments = Vec::new();
+        let mut pos = 1;
+        while pos < bytes.len() {
+            match bytes[pos] {
+                b'[' => {
+                    let (selector, end) = parse_bracket(expression, pos)?;
+                    segments.push(Segment {
+                        selector,
+                        recursive: false,
+                    });
+                    pos = end;
+                },
+                b'.' => {
+                    let (segment, end) = parse_dot(expression, pos)?;
+                    segments.push(segment);
+                    pos = end;
+                },
+                _ => return Err(invalid(expression, pos, "expected `.` or `[`")),
+            }
+        }
+        Ok(Self { segments })
+    }
+
+    /// Returns the values matched in `root`, in document order.
+    #[must_use]
+    pub fn select<'a>(&self, root: &'a Value) -> Vec<&'a Value> {
+        let mut nodes = vec![root];
+        for segment in &self.segments {
+            let mut matched = Vec::new();
+            for node in nodes {
+                segment.apply(node, &mut matched);
+            }
+            nodes = matched;
+        }
+        nodes
+    }
+}
+
+impl Segment {
+    /// Appends the nodes this step selects from `node` to `out`.
+    fn apply<'a>(&self, node: &'a Value, out: &mut Vec<&'a Value>) {
+        if !self.recursive {
+            self.selector.apply(node, out);
+            return;
+        }
+        let mut subtree = Vec::new();
+        collect_subtree(node, &mut subtree);
+        for descendant in subtree {
+            self.selector.apply(descendant, out);
+        }
+    }
+}
+
+impl Selector {
+    /// Appends the children of `node` selected by `self` to `out`.
+    fn apply<'a>(&self, node: &'a Value, out: &mut Vec<&'a Value>) {
+        match (self, node) {
+            (Self::Name(name), Value::Object(members)) => out.extend(members.get(name)),
+            (Self::Index(index), Value::Array(items)) => {
+                let position = if *index < 0 {
+                    usize::try_from(index.unsigned_abs())
+                        .ok()
+                        .and_then(|back| items.len().checked_sub(back))
+                } else {
+                    usize::try_from(*index).ok()
+                };
+                out.extend(position.and_then(|i| items.get(i)));
+            },
+            (Self::Wildcard, Value::Array(items)) => out.extend(items),
+            (Self::Wildcard, Value::Object(members)) => out.extend(members.values()),
+            _ => {},
+        }
+    }
+}
+
+/// Parses the dot step starting at `expression[start] == '.'`.
+///
+/// Returns the segment and the position after it.
+fn parse_dot(expression: &str, start: usize) -> Result<(Segment, usize)> {
+    let bytes = expression.as_bytes();
+    let recursive = bytes.get(start + 1) == Some(&b'.');
+    let pos = if recursive { start + 2 } else { start + 1 };
+    let (selector, end) = match bytes.get(pos) {
+        Some(b'[') if recursive => parse_bracket(expression, pos)?,
+        Some(b'*') => (Selector::Wildcard, pos + 1),
+        _ => parse_member_name(expression, pos)?,
+    };
+    Ok((
+        Segment {
+            selector,
+            recursive,
+        },
+        end,
+    ))
+}
+
+/// Parses the dot-notation member name starting at `start`.
+///
+/// Returns the selector and the position after the name.
+fn parse_member_name(expression: &str, start: usize) -> Result<(Selector, usize)> {
+    let bytes = expression.as_bytes();
+    let end = bytes[start..]
+        .iter()
+        .position(|&b| !is_name_byte(b))
+        .map_or(bytes.len(), |n| start + n);
+    if end == start {
+        return Err(invalid(expression, start, "expected a member name"));
+    }
+    Ok((Selector::Name(expression[start..end].to_string()), end))
+}
+
+/// Parses the bracket selector starting at `expression[start] == '['`.
+///
+/// Returns the selector and the position after the closing `]`.
+fn parse_bracket(expression: &str, start: usize) -> Result<(Selector, usize)> {
+    let bytes = expression.as_bytes();
+    let inner = start + 1;
+    if let Some(&quote) = bytes.get(inner).filter(|&&b| b == b'\'' || b == b'"') {
+        let Some(len) = bytes[inner + 1..].iter().position(|&b| b == quote) else {
+            return Err(invalid(expression, inner, "unterminated quoted name"));
+        };
+        let close = inner + 1 + len;
+        if bytes.get(close + 1) != Some(&b']') {
+            return Err(invalid(expression, close + 1, "expected `]`"));
+        }
+        let name = expression[inner + 1..close].to_string();
+        return Ok((Selector::Name(name), close + 2));
+    }
+
+    let Some(len) = bytes[inner..].iter().position(|&b| b == b']') else {
+        return Err(invalid(expression, start, "unterminated `[`"));
+    };
+    let end = inner + len;
+    let content = expression[inner..end].trim();
+    let selector = if content == "*" {
+        Selector::Wildcard
+    } else if let Ok(index) = content.parse() {
+        Selector::Index(index)
+    } else {
+        return Err(invalid(
+            expression,
+            inner,
+            "expected an index, `*`, or a quoted name (filters, slices and unions are not \
+             supported)",
+        ));
+    };
+    Ok((selector, end + 1))
+}
+
+/// Appends `node` and all of its descendants to `out`, parents first.
+fn collect_subtree<'a>(node: &'a Value, out: &mut Vec<&'a Value>) {
+    out.push(node);
+    match node {
+        Value::Array(items) => items.iter().for_each(|item| collect_subtree(item, out)),
+        Value::Object(members) => members.values().for_each(|v| collect_subtree(v, out)),
+        _ => {},
+    }
+}
+
+/// Returns whether `byte` may appear in a dot-notation member name.
+const fn is_name_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || byte == b'_' || byte == b'-' || !byte.is_ascii()
+}
+
+/// Builds the error for a malformed expression.
+fn invalid(expression: &str, position: usize, reason: &str) -> Error {
+    Error::InvalidInput(format!(
+        "Invalid JSONPath `{expression}` at position {position}: {reason}"
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{
+        CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace, SearchHit,
+    };
+    use crate::rendering::hits_to_json;
+    use serde_json::json;
+
+    fn hit(id: &str, score: f32) -> SearchHit {
+        let memory = Memory {
+            id: MemoryId::new(id),
+            content: "Use PostgreSQL for storage".to_string(),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 0,
+            updated_at: 0,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: vec![format!("tag-{id}")],
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        };
+        SearchHit::new(memory, score)
+    }
+
+    #[test]
+    fn test_extracts_ids_and_scores_from_recall_results() {
+        let hits = [hit("mem-1", 1.0), hit("mem-2", 0.5), hit("mem-3", 0.25)];
+        let results = hits_to_json(&hits, 10);
+
+        let ids = JsonPath::parse("$[*].memory.id").unwrap();
+        assert_eq!(
+            ids.select(&results),
+            [&json!("mem-1"), &json!("mem-2"), &json!("mem-3")]
+        );
+
+        let scores = JsonPath::parse("$[*]['score']").unwrap();
+        assert_eq!(
+            scores.select(&results),
+            [&json!(1.0), &json!(0.5), &json!(0.25)]
+        );
+
+        let last = JsonPath::parse("$[-1].memory.tags").unwrap();
+        assert_eq!(last.select(&results), [&json!(["tag-mem-3"])]);
+
+        let nested = JsonPath::parse("$..id").unwrap();
+        assert_eq!(nested.select(&results).len(), 3);
+    }
+
+    #[test]
+    fn test_invalid_paths_are_rejected() {
+        for expression in [
+            "memory.id",
+            "$.",
+            "$[?(@.score > 0.5)]",
+            "$['id'",
+            "$[0:2]",
+            "$x",
+        ] {
+            let Err(Error::InvalidInput(message)) = JsonPath::parse(expression) else {
+                panic!("expected `{expression}` to be rejected");
+            };
+            assert!(message.contains("Invalid JSONPath"), "{message}");
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Newline-delimited JSON output for search results.
+//!
+//! Writes one self-contained JSON object per [`SearchHit`] (full content,
+//! scores, and metadata) and flushes after every line, so streaming consumers
+//! such as `jq --stream` or a log shipper see each hit as soon as it is
+//! written instead of after the whole result set.
+//!
+//! [`hits_to_json`] builds the same data as one JSON array with the memory
+//! fields nested under `memory`, for `JSONPath` extraction
+//! (`subcog recall --json-path`).
+
+use std::io::{ErrorKind, Write};
+
+use serde_json::{Map, Value, json};
+
+use super::output_template::{format_timestamp, memory_urn};
+use crate::models::{Memory, SearchHit};
+use crate::{Error, Result};
+
+/// Converts a search hit into its JSONL object.
+#[must_use]
+pub fn hit_to_json(hit: &SearchHit) -> Value {
+    let mut object = memory_fields(&hit.memory);
+    object.extend(hit_fields(hit));
+    Value::Object(object)
+}
+
+/// Converts up to `limit` hits into a JSON array, one object per hit with
+/// the memory under `memory` and the scores and scope beside it.
+#[must_use]
+pub fn hits_to_json<'a>(hits: impl IntoIterator<Item = &'a SearchHit>, limit: usize) -> Value {
+    hits.into_iter()
+        .take(limit)
+        .map(|hit| {
+            let mut object = hit_fields(hit);
+            object.insert(
+                "memory".to_string(),
+                Value::Object(memory_fields(&hit.memory)),
+            );
+            Value::Object(object)
+        })
+        .collect()
+}
+
+/// Returns the JSON fields describing `memory`.
+fn memory_fields(memory: &Memory) -> Map<String, Value> {
+    let Value::Object(fields) = json!({
+        "id": memory.id.as_str(),
+        "urn": memory_urn(memory),
+        "namespace": memory.namespace.as_str(),
+        "domain": memory.domain.to_string(),
+        "status": memory.status.as_str(),
+        "tags": memory.tags,
+        "source": memory.source,
+        "origin": memory.origin.as_str(),
+        "confidence": memory.confidence,
+        "content_type": memory.content_type.as_ref().map(ToString::to_string),
+        "pinned": memory.pinned,
+        "commit": memory.commit,
+        "language": memory.language,
+        "created_at": format_timestamp(memory.created_at),
+        "updated_at": format_timestamp(memory.updated_at),
+        "content": memory.content,
+    }) else {
+        unreachable!("json! object literal")
+    };
+    fields
+}
+
+/// Returns the JSON fields describing how `hit` matched.
+fn hit_fields(hit: &SearchHit) -> Map<String, Value> {
+    let Value::Object(fields) = json!({
+        "scope": hit.scope.as_str(),
+        "score": hit.score,
+        "raw_score": hit.raw_score,
+        "vector_score": hit.vector_score,
+        "bm25_score": hit.bm25_score,
+        "snippet": hit.snippet,
+    }) else {
+        unreachable!("json! object literal")
+    };
+    fields
+}
+
+/// Writes up to `limit` hits as JSON lines, flushing after each line.
+///
+/// Stops quietly if the reader goes away (e.g. `subcog recall ... | head -1`).
+/// Returns the number of lines written.
+///
+/// # Errors
+///
+/// Returns [`Error::OperationFailed`] if a line cannot be written.
+pub fn write_jsonl<'a, W: Write>(
+    out: &mut W,
+    hits: impl IntoIterator<Item = &'a SearchHit>,
+    limit: usize,
+) -> Result<usize> {
+    let mut written = 0;
+    for hit in hits.into_iter().take(limit) {
+        match write_line(out, hit) {
+            Ok(()) => written += 1,
+            Err(e) if e.kind() == ErrorKind::BrokenPipe => break,
+            Err(e) => {
+                return Err(Error::OperationFailed {
+                    operation: "write_jsonl_hit".to_string(),
+                    cause: e.to_string(),
+                });
+            },
+        }
+    }
+    Ok(written)
+}
+
+/// Serializes one hit straight into `out`, ends the line, and flushes it.
+fn write_line<W: Write>(out: &mut W, hit: &SearchHit) -> std::io::Result<()> {
+    serde_json::to_writer(&mut *out, &hit_to_json(hit))?;
+    out.write_all(b"\n")?;
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::storage::index::DomainScope;
+
+    fn hit(id: &str, score: f32) -> SearchHit {
+        SearchHit {
+            memory: Memory {
+                id: MemoryId::new(id),
+                content: "Line one\n\"quoted\" line two".to_string(),
+                namespace: Namespace::Decisions,
+                domain: Domain::new(),
+                project_id: None,
+                branch: None,
+                file_path: None,
+                status: MemoryStatus::Active,
+                created_at: 0,
+                updated_at: 0,
+                tombstoned_at: None,
+                expires_at: None,
+                embedding: None,
+                tags: vec!["storage".to_string()],
+                #[cfg(feature = "group-scope")]
+                group_id: None,
+                source: Some("src/main.rs".to_string()),
+                is_summary: false,
+                source_memory_ids: None,
+                consolidation_timestamp: None,
+                origin: CaptureOrigin::Manual,
+                confidence: None,
+                attachments: Vec::new(),
+                content_type: None,
+                pinned: false,
+                commit: None,
+                language: None,
+            },
+            score,
+            raw_score: score / 2.0,
+            vector_score: None,
+            bm25_score: Some(score),
+            scope: DomainScope::Project,
+            domain: Domain::new(),
+            snippet: None,
+        }
+    }
+
+    #[test]
+    fn test_each_line_is_valid_json_and_limited() {
+        let hits: Vec<SearchHit> = (0..5u8)
+            .map(|i| hit(&format!("mem-{i}"), 1.0 - f32::from(i) / 10.0))
+            .collect();
+        let mut out = Vec::new();
+        let written = write_jsonl(&mut out, &hits, 3).unwrap();
+        assert_eq!(written, 3);
+
+        let output = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 3);
+        for (line, hit) in lines.iter().zip(&hits) {
+            let value: Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value["id"], hit.memory.id.as_str());
+            assert_eq!(value["content"], hit.memory.content.as_str());
+            assert_eq!(value["namespace"], "decisions");
+            assert!(value["score"].is_number());
+        }
+    }
+}
@@ -1,8 +1,19 @@
 //! Template rendering engine.
 //!
 //! Provides rendering capabilities for context templates with variable substitution,
-//! iteration support, and output format conversion.
+//! iteration support, and output format conversion, plus per-hit output
+//! templates, JSONL output and `JSONPath` extraction for search results, and
+//! terminal highlighting of code memories.
 
+mod code;
+mod json_path;
+mod jsonl;
+mod output_template;
 mod template_renderer;
 
+pub use code::highlight_code;
+pub use json_path::JsonPath;
+pub use jsonl::{hit_to_json, hits_to_json, write_jsonl};
+pub(crate) use output_template::memory_urn;
+pub use output_template::{OUTPUT_TEMPLATE_FIELDS, OutputTemplate};
 pub use template_renderer::{RenderContext, RenderValue, TemplateRenderer};
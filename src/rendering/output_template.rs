@@ -0,0 +1,219 @@
+//! Per-hit output templates for search results.
+//!
+//! Renders one line per [`SearchHit`] using the same `{{field}}` engine as
+//! prompts and context templates, e.g.
+//! `{{id}}\t{{namespace}}\t{{score}}\t{{content_preview}}`.
+
+use std::collections::HashMap;
+
+use crate::models::{Memory, SearchHit, extract_variables, substitute_variables};
+use crate::{Error, Result};
+
+/// Maximum characters in `{{content_preview}}`.
+const CONTENT_PREVIEW_LENGTH: usize = 100;
+
+/// Fields available to output templates.
+pub const OUTPUT_TEMPLATE_FIELDS: &[&str] = &[
+    "id",
+    "urn",
+    "namespace",
+    "domain",
+    "score",
+    "raw_score",
+    "vector_score",
+    "bm25_score",
+    "content",
+    "content_preview",
+    "snippet",
+    "tags",
+    "status",
+    "source",
+    "project_id",
+    "branch",
+    "file_path",
+    "created_at",
+    "updated_at",
+];
+
+/// A validated per-hit output template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputTemplate {
+    template: String,
+}
+
+impl OutputTemplate {
+    /// Parses a template, checking that every `{{field}}` is known.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] listing the available fields if the
+    /// template references an unknown field.
+    pub fn parse(template: impl Into<String>) -> Result<Self> {
+        let template = template.into();
+        let unknown: Vec<String> = extract_variables(&template)
+            .into_iter()
+            .map(|v| v.name)
+            .filter(|name| !OUTPUT_TEMPLATE_FIELDS.contains(&name.as_str()))
+            .collect();
+        if !unknown.is_empty() {
+            return Err(Error::InvalidInput(format!(
+                "Unknown output template field(s): {}. Available fields: {}",
+                unknown.join(", "),
+                OUTPUT_TEMPLATE_FIELDS.join(", ")
+            )));
+        }
+        Ok(Self { template })
+    }
+
+    /// Returns the template source.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.template
+    }
+
+    /// Renders the template for a single hit.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if variable substitution fails.
+    pub fn render(&self, hit: &SearchHit) -> Result<String> {
+        substitute_variables(&self.template, &hit_fields(hit), &[])
+    }
+}
+
+/// Builds the field map for a search hit.
+fn hit_fields(hit: &SearchHit) -> HashMap<String, String> {
+    let memory = &hit.memory;
+    let optional_score = |score: Option<f32>| score.map(|s| format!("{s:.4}")).unwrap_or_default();
+    let fields = [
+        ("id", memory.id.to_string()),
+        ("urn", memory_urn(memory)),
+        ("namespace", memory.namespace.to_string()),
+        ("domain", memory.domain.to_string()),
+        ("score", format!("{:.4}", hit.score)),
+        ("raw_score", format!("{:.4}", hit.raw_score)),
+        ("vector_score", optional_score(hit.vector_score)),
+        ("bm25_score", optional_score(hit.bm25_score)),
+        ("content", memory.content.clone()),
+        ("content_preview", content_preview(&memory.content)),
+        ("snippet", hit.snippet.clone().unwrap_or_default()),
+        ("tags", memory.tags.join(",")),
+        ("status", memory.status.to_string()),
+        ("source", memory.source.clone().unwrap_or_default()),
+        ("project_id", memory.project_id.clone().unwrap_or_default()),
+        ("branch", memory.branch.clone().unwrap_or_default()),
+        ("file_path", memory.file_path.clone().unwrap_or_default()),
+        ("created_at", format_timestamp(memory.created_at)),
+        ("updated_at", format_timestamp(memory.updated_at)),
+    ];
+    fields
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value))
+        .collect()
+}
+
+/// Returns the `subcog://` URN of a memory.
+pub fn memory_urn(memory: &Memory) -> String {
+    let domain_part = if memory.domain.is_project_scoped() {
+        "project".to_string()
+    } else {
+        memory.domain.to_string()
+    };
+    format!("subcog://{domain_part}/{}/{}", memory.namespace, memory.id)
+}
+
+/// Collapses whitespace and truncates content to a single-line preview.
+fn content_preview(content: &str) -> String {
+    let single_line = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if single_line.chars().count() > CONTENT_PREVIEW_LENGTH {
+        let truncated: String = single_line.chars().take(CONTENT_PREVIEW_LENGTH).collect();
+        format!("{truncated}...")
+    } else {
+        single_line
+    }
+}
+
+/// Formats a Unix timestamp as RFC 3339.
+pub(super) fn format_timestamp(ts: u64) -> String {
+    chrono::DateTime::from_timestamp(i64::try_from(ts).unwrap_or(0), 0)
+        .map_or_else(|| ts.to_string(), |dt| dt.to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::storage::index::DomainScope;
+
+    fn test_hit() -> SearchHit {
+        SearchHit {
+            memory: Memory {
+                id: MemoryId::new("mem-1"),
+                content: "Use SQLite\nfor the local index".to_string(),
+                namespace: Namespace::Decisions,
+                domain: Domain::new(),
+                project_id: None,
+                branch: None,
+                file_path: None,
+                status: MemoryStatus::Active,
+                created_at: 0,
+                updated_at: 0,
+                tombstoned_at: None,
+                expires_at: None,
+                embedding: None,
+                tags: vec!["storage".to_string(), "sqlite".to_string()],
+                #[cfg(feature = "group-scope")]
+                group_id: None,
+                source: None,
+                is_summary: false,
+                source_memory_ids: None,
+                consolidation_timestamp: None,
+                origin: CaptureOrigin::Manual,
+                confidence: None,
+                attachments: Vec::new(),
+                content_type: None,
+                pinned: false,
+                commit: None,
+                language: None,
+            },
+            score: 0.5,
+            raw_score: 0.25,
+            vector_score: None,
+            bm25_score: Some(1.0),
+            scope: DomainScope::Project,
+            domain: Domain::new(),
+            snippet: None,
+        }
+    }
+
+    #[test]
+    fn test_render_fields() {
+        let template =
+            OutputTemplate::parse("{{id}}\t{{namespace}}\t{{score}}\t{{content_preview}}").unwrap();
+        assert_eq!(
+            template.render(&test_hit()).unwrap(),
+            "mem-1\tdecisions\t0.5000\tUse SQLite for the local index"
+        );
+
+        let template = OutputTemplate::parse("{{tags}} {{created_at}} [{{vector_score}}]").unwrap();
+        assert_eq!(
+            template.render(&test_hit()).unwrap(),
+            "storage,sqlite 1970-01-01T00:00:00+00:00 []"
+        );
+    }
+
+    #[test]
+    fn test_unknown_field_lists_available_fields() {
+        let err = OutputTemplate::parse("{{id}} {{bogus}}").unwrap_err();
+        let msg = err.to_string();
+        assert!(msg.contains("bogus"));
+        assert!(msg.contains("content_preview"));
+    }
+
+    #[test]
+    fn test_content_preview_truncates() {
+        let preview = content_preview(&"x".repeat(150));
+        assert_eq!(preview.chars().count(), CONTENT_PREVIEW_LENGTH + 3);
+        assert!(preview.ends_with("..."));
+    }
+}
@@ -0,0 +1,221 @@
+//! Async variants of capture and recall.
+//!
+//! The services are synchronous: `SQLite`, the vector index and the embedder
+//! all block the calling thread. The `*_async` methods run the synchronous
+//! operation on tokio's blocking thread pool, so an async application can
+//! await them without wrapping every call in `spawn_blocking` and without
+//! stalling the runtime's worker threads. They must be awaited inside a
+//! tokio runtime.
+//!
+//! Each call occupies one blocking-pool thread for its duration, so
+//! concurrency is bounded by the pool size (`max_blocking_threads`) and, for
+//! captures, by the backend's own write locking. The current tracing span is
+//! carried into the blocking task.
+//!
+//! The services are shared through an [`Arc`], which the task clones, so the
+//! returned futures are `Send + 'static` and can be used from axum handlers:
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use subcog::services::ServiceContainer;
+//! use subcog::{CaptureRequest, SearchFilter, SearchMode};
+//!
+//! let services = Arc::new(ServiceContainer::from_current_dir_or_user()?);
+//! let captured = services
+//!     .capture_async(CaptureRequest::new("Use axum for the HTTP layer"))
+//!     .await?;
+//! let hits = services
+//!     .search_async("web framework", SearchMode::Hybrid, SearchFilter::new(), 10)
+//!     .await?;
+//! ```
+//!
+//! The synchronous API is unchanged.
+
+use super::{CaptureService, RecallService, ServiceContainer};
+use crate::models::{CaptureRequest, CaptureResult, SearchFilter, SearchMode, SearchResult};
+use crate::{Error, Result};
+use std::sync::Arc;
+
+impl CaptureService {
+    /// Captures a memory on the blocking pool.
+    ///
+    /// Async equivalent of [`capture`](Self::capture).
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`capture`](Self::capture), or an error if the
+    /// blocking task panics or is cancelled.
+    pub async fn capture_async(self: &Arc<Self>, request: CaptureRequest) -> Result<CaptureResult> {
+        let service = Arc::clone(self);
+        run_blocking("capture_async", move || service.capture(request)).await
+    }
+}
+
+impl RecallService {
+    /// Searches for memories on the blocking pool.
+    ///
+    /// Async equivalent of [`search`](Self::search).
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`search`](Self::search), or an error if the
+    /// blocking task panics or is cancelled.
+    pub async fn search_async(
+        self: &Arc<Self>,
+        query: impl Into<String>,
+        mode: SearchMode,
+        filter: SearchFilter,
+        limit: usize,
+    ) -> Result<SearchResult> {
+        let service = Arc::clone(self);
+        let query = query.into();
+        run_blocking("search_async", move || {
+            service.search(&query, mode, &filter, limit)
+        })
+        .await
+    }
+}
+
+impl ServiceContainer {
+    /// Captures a memory with the container's capture service on the
+    /// blocking pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`CaptureService::capture`], or an error if the
+    /// blocking task panics or is cancelled.
+    pub async fn capture_async(self: &Arc<Self>, request: CaptureRequest) -> Result<CaptureResult> {
+        let services = Arc::clone(self);
+        run_blocking("capture_async", move || services.capture().capture(request)).await
+    }
+
+    /// Searches for memories with the container's recall service on the
+    /// blocking pool.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`recall`](Self::recall) and
+    /// [`RecallService::search`], or an error if the blocking task panics or
+    /// is cancelled.
+    pub async fn search_async(
+        self: &Arc<Self>,
+        query: impl Into<String>,
+        mode: SearchMode,
+        filter: SearchFilter,
+        limit: usize,
+    ) -> Result<SearchResult> {
+        let services = Arc::clone(self);
+        let query = query.into();
+        run_blocking("search_async", move || {
+            services.recall()?.search(&query, mode, &filter, limit)
+        })
+        .await
+    }
+}
+
+/// Runs `f` on tokio's blocking pool inside the caller's tracing span.
+async fn run_blocking<T, F>(operation: &str, f: F) -> Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> Result<T> + Send + 'static,
+{
+    let span = tracing::Span::current();
+    tokio::task::spawn_blocking(move || span.in_scope(f))
+        .await
+        .map_err(|e| Error::OperationFailed {
+            operation: operation.to_string(),
+            cause: e.to_string(),
+        })?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::models::Namespace;
+    use crate::storage::index::SqliteBackend;
+    use crate::storage::traits::IndexBackend;
+
+    /// Creates capture and recall services sharing one in-memory index.
+    fn shared_services() -> (Arc<CaptureService>, Arc<RecallService>) {
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        let capture = CaptureService::new_minimal(Config::default()).with_index(Arc::clone(&index));
+        let recall = RecallService::with_dyn_index(index);
+        (Arc::new(capture), Arc::new(recall))
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_captures_and_searches() {
+        let (capture, recall) = shared_services();
+
+        let captures = (0..16).map(|i| {
+            let capture = Arc::clone(&capture);
+            tokio::spawn(async move {
+                let request = CaptureRequest::new(format!("Async capture {i} uses tokio"))
+                    .with_namespace(Namespace::Decisions);
+                capture.capture_async(request).await
+            })
+        });
+        let mut ids = Vec::new();
+        for handle in captures.collect::<Vec<_>>() {
+            ids.push(handle.await.unwrap().unwrap().memory_id);
+        }
+        ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        ids.dedup();
+        assert_eq!(ids.len(), 16);
+
+        let searches = (0..8).map(|_| {
+            let recall = Arc::clone(&recall);
+            tokio::spawn(async move {
+                recall
+                    .search_async("tokio", SearchMode::Text, SearchFilter::new(), 20)
+                    .await
+            })
+        });
+        for handle in searches.collect::<Vec<_>>() {
+            let result = handle.await.unwrap().unwrap();
+            assert_eq!(result.memories.len(), 16);
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_capture_async_interleaved_with_search() {
+        let (capture, recall) = shared_services();
+
+        let writer = {
+            let capture = Arc::clone(&capture);
+            tokio::spawn(async move {
+                for i in 0..8 {
+                    let request = CaptureRequest::new(format!("Interleaved memory {i}"));
+                    capture.capture_async(request).await?;
+                }
+                Ok::<_, Error>(())
+            })
+        };
+        // Searches racing the writer see a consistent, growing prefix
+        let mut previous = 0;
+        while !writer.is_finished() {
+            let result = recall
+                .search_async("interleaved", SearchMode::Text, SearchFilter::new(), 20)
+                .await
+                .unwrap();
+            assert!(result.memories.len() >= previous);
+            previous = result.memories.len();
+        }
+        writer.await.unwrap().unwrap();
+
+        let result = recall
+            .search_async("interleaved", SearchMode::Text, SearchFilter::new(), 20)
+            .await
+            .unwrap();
+        assert_eq!(result.memories.len(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_capture_async_returns_service_errors() {
+        let (capture, _) = shared_services();
+        let result = capture.capture_async(CaptureRequest::new("   ")).await;
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+}
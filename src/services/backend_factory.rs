@@ -10,7 +10,7 @@
 //!
 //! ```text
 //! BackendFactory
-//!   ├── create_embedder() → Arc<dyn Embedder>
+//!   ├── create_embedder(model) → Arc<dyn Embedder>
 //!   ├── create_index_backend() → Option<Arc<dyn IndexBackend>>
 //!   └── create_vector_backend() → Option<Arc<dyn VectorBackend>>
 //! ```
@@ -25,12 +25,16 @@
 use crate::storage::index::SqliteBackend;
 use crate::storage::persistence::FilesystemBackend;
 use crate::storage::traits::{IndexBackend, PersistenceBackend, VectorBackend};
+#[cfg(not(feature = "usearch-hnsw"))]
+use crate::storage::vector::EmbeddingCache;
 use crate::storage::vector::UsearchBackend;
 use std::path::Path;
 use std::sync::Arc;
 
 #[cfg(feature = "postgres")]
 use crate::storage::index::PostgresBackend;
+#[cfg(feature = "postgres")]
+use crate::storage::vector::PgVectorBackend;
 
 /// Result of backend initialization with optional components.
 ///
@@ -89,7 +93,8 @@ pub fn has_persistence(&self) -> bool {
 /// use subcog::services::{BackendFactory, PathManager};
 ///
 /// let paths = PathManager::for_repo("/path/to/repo");
-/// let backends = BackendFactory::create_all(&paths.index_path(), &paths.vector_path());
+/// let model = FastEmbedEmbedder::new();
+/// let backends = BackendFactory::create_all(&paths.index_path(), &paths.vector_path(), &model);
 ///
 /// if backends.is_complete() {
 ///     println!("All backends initialized successfully");
@@ -107,19 +112,28 @@ impl BackendFactory {
     ///
     /// * `index_path` - Path for `SQLite` index database
     /// * `vector_path` - Path for vector index files
+    /// * `model` - Embedding model; a new vector index is sized for it
     ///
     /// # Returns
     ///
     /// A `BackendSet` containing available backends.
     #[must_use]
-    pub fn create_all(index_path: &Path, vector_path: &Path) -> BackendSet {
-        let embedder = Self::create_embedder();
-        let index = Self::create_index_backend(index_path);
-        let vector = Self::create_vector_backend(vector_path);
+    pub fn create_all(
+        index_path: &Path,
+        vector_path: &Path,
+        model: &FastEmbedEmbedder,
+    ) -> BackendSet {
+        let embedder = Self::create_embedder(model);
+        let sqlite = Self::create_sqlite_backend(index_path);
+
+        #[cfg(feature = "usearch-hnsw")]
+        let vector = Self::create_vector_backend_with_dimensions(vector_path, model.dimensions());
+        #[cfg(not(feature = "usearch-hnsw"))]
+        let vector = Self::create_cached_vector_backend(vector_path, sqlite.as_deref(), model);
 
         BackendSet {
             embedder,
-            index,
+            index: sqlite.map(|backend| backend as Arc<dyn IndexBackend + Send + Sync>),
             vector,
             persistence: None,
         }
@@ -134,20 +148,22 @@ pub fn create_all(index_path: &Path, vector_path: &Path) -> BackendSet {
     /// # Arguments
     ///
     /// * `config` - Storage backend configuration (backend type, connection string, etc.)
+    /// * `model` - Embedding model (`[embedding] model`)
     /// * `index_path` - Fallback path for `SQLite` index database
     /// * `vector_path` - Fallback path for vector index files
     #[must_use]
     pub fn create_from_config(
         config: &StorageBackendConfig,
+        model: &FastEmbedEmbedder,
         index_path: &Path,
         vector_path: &Path,
     ) -> BackendSet {
         match config.backend {
             StorageBackendType::PostgreSQL => {
-                Self::create_postgres_backends(config, index_path, vector_path)
+                Self::create_postgres_backends(config, model, index_path, vector_path)
             },
             StorageBackendType::Filesystem => {
-                let mut set = Self::create_all(index_path, vector_path);
+                let mut set = Self::create_all(index_path, vector_path, model);
                 // Create filesystem persistence if a path is configured
                 if let Some(ref path) = config.path {
                     let fs_path = std::path::Path::new(path);
@@ -161,7 +177,7 @@ pub fn create_from_config(
             },
             // SQLite and Redis both fall back to default SQLite/usearch
             StorageBackendType::Sqlite | StorageBackendType::Redis => {
-                Self::create_all(index_path, vector_path)
+                Self::create_all(index_path, vector_path, model)
             },
         }
     }
@@ -176,6 +192,7 @@ pub fn create_from_config(
     /// falls back to `SQLite`.
     fn create_postgres_backends(
         config: &StorageBackendConfig,
+        model: &FastEmbedEmbedder,
         index_path: &Path,
         vector_path: &Path,
     ) -> BackendSet {
@@ -184,12 +201,14 @@ fn create_postgres_backends(
                 "PostgreSQL backend configured but no connection_string provided, \
                  falling back to SQLite"
             );
-            return Self::create_all(index_path, vector_path);
+            return Self::create_all(index_path, vector_path, model);
         };
 
         Self::create_postgres_backends_inner(
             connection_string,
             config.pool_max_size,
+            config.pgvector,
+            model,
             index_path,
             vector_path,
         )
@@ -200,10 +219,12 @@ fn create_postgres_backends(
     fn create_postgres_backends_inner(
         connection_url: &str,
         pool_max_size: Option<usize>,
+        pgvector: bool,
+        model: &FastEmbedEmbedder,
         fallback_index_path: &Path,
         fallback_vector_path: &Path,
     ) -> BackendSet {
-        let embedder = Self::create_embedder();
+        let embedder = Self::create_embedder(model);
 
         // Create a single PostgreSQL backend that handles both FTS (memories table)
         // and vector search (memory_vectors table) with one shared connection pool.
@@ -216,10 +237,16 @@ fn create_postgres_backends_inner(
             Ok(backend) => {
                 tracing::info!("Created PostgreSQL backend (index + vector + persistence)");
                 let backend = Arc::new(backend);
+                let vector: Arc<dyn VectorBackend + Send + Sync> = if pgvector {
+                    Self::create_pgvector_backend(connection_url, model)
+                        .unwrap_or_else(|| backend.clone())
+                } else {
+                    backend.clone()
+                };
                 BackendSet {
                     embedder,
                     index: Some(backend.clone()),
-                    vector: Some(backend.clone()),
+                    vector: Some(vector),
                     persistence: Some(backend),
                 }
             },
@@ -231,18 +258,50 @@ fn create_postgres_backends_inner(
                 BackendSet {
                     embedder,
                     index: Self::create_index_backend(fallback_index_path),
-                    vector: Self::create_vector_backend(fallback_vector_path),
+                    vector: Self::create_vector_backend_with_dimensions(
+                        fallback_vector_path,
+                        model.dimensions(),
+                    ),
                     persistence: None,
                 }
             },
         }
     }
 
+    /// Creates the standalone pgvector backend, filtering through the
+    /// `memories` index table.
+    ///
+    /// Returns `None` (logged) on failure, so the PostgreSQL backend's own
+    /// vector table is used instead.
+    #[cfg(feature = "postgres")]
+    fn create_pgvector_backend(
+        connection_url: &str,
+        model: &FastEmbedEmbedder,
+    ) -> Option<Arc<dyn VectorBackend + Send + Sync>> {
+        match PgVectorBackend::for_embedder(connection_url, model)
+            .and_then(|backend| backend.with_index_table("memories"))
+        {
+            Ok(backend) => {
+                tracing::info!(table = backend.table_name(), "Created pgvector backend");
+                Some(Arc::new(backend))
+            },
+            Err(e) => {
+                tracing::warn!(
+                    error = ?e,
+                    "Failed to create pgvector backend, using the PostgreSQL vector table"
+                );
+                None
+            },
+        }
+    }
+
     /// Fallback when `postgres` feature is not enabled.
     #[cfg(not(feature = "postgres"))]
     fn create_postgres_backends_inner(
         _connection_url: &str,
         _pool_max_size: Option<usize>,
+        _pgvector: bool,
+        model: &FastEmbedEmbedder,
         index_path: &Path,
         vector_path: &Path,
     ) -> BackendSet {
@@ -250,16 +309,20 @@ fn create_postgres_backends_inner(
             "PostgreSQL backend configured but the 'postgres' feature is not enabled. \
              Rebuild with: cargo build --features postgres. Falling back to SQLite."
         );
-        Self::create_all(index_path, vector_path)
+        Self::create_all(index_path, vector_path, model)
     }
 
-    /// Creates the embedder backend.
+    /// Creates the embedder backend for `model`.
     ///
-    /// Currently always returns `FastEmbedEmbedder`. In the future,
-    /// this could be configured to use different embedding models.
+    /// Returns the embedder, or `None` in offline mode so search falls back
+    /// to text-only (BM25) instead of downloading the embedding model.
     #[must_use]
-    pub fn create_embedder() -> Option<Arc<dyn Embedder>> {
-        Some(Arc::new(FastEmbedEmbedder::new()))
+    pub fn create_embedder(model: &FastEmbedEmbedder) -> Option<Arc<dyn Embedder>> {
+        if crate::config::is_offline() {
+            tracing::info!("Offline mode enabled, vector search disabled");
+            return None;
+        }
+        Some(Arc::new(model.clone()))
     }
 
     /// Creates the index backend (`SQLite` FTS5).
@@ -272,6 +335,12 @@ pub fn create_embedder() -> Option<Arc<dyn Embedder>> {
     ///
     /// `Some(backend)` on success, `None` if initialization fails.
     pub fn create_index_backend(path: &Path) -> Option<Arc<dyn IndexBackend + Send + Sync>> {
+        Self::create_sqlite_backend(path)
+            .map(|backend| backend as Arc<dyn IndexBackend + Send + Sync>)
+    }
+
+    /// Opens the `SQLite` index, logging and returning `None` on failure.
+    fn create_sqlite_backend(path: &Path) -> Option<Arc<SqliteBackend>> {
         match SqliteBackend::new(path) {
             Ok(backend) => {
                 tracing::debug!(path = %path.display(), "Created SQLite index backend");
@@ -297,8 +366,68 @@ pub fn create_index_backend(path: &Path) -> Option<Arc<dyn IndexBackend + Send +
     /// # Returns
     ///
     /// `Some(backend)` on success, `None` if initialization fails.
+    ///
+    /// An existing index is opened at the dimensions recorded in its
+    /// metadata, so an index rebuilt by `subcog migrate embeddings` for a
+    /// different embedding model is not discarded.
     pub fn create_vector_backend(path: &Path) -> Option<Arc<dyn VectorBackend + Send + Sync>> {
-        let dimensions = FastEmbedEmbedder::DEFAULT_DIMENSIONS;
+        Self::create_vector_backend_with_dimensions(path, FastEmbedEmbedder::DEFAULT_DIMENSIONS)
+    }
+
+    /// Creates the vector backend, loading it through its embedding cache.
+    ///
+    /// The pure-Rust backend stores its index as JSON, which is slow to parse
+    /// on every startup. The cache is reused while it matches the embedding
+    /// model and the memories in `index`, and rebuilt otherwise (see
+    /// [`EmbeddingCache`](crate::storage::vector::EmbeddingCache)).
+    #[cfg(not(feature = "usearch-hnsw"))]
+    fn create_cached_vector_backend(
+        path: &Path,
+        index: Option<&SqliteBackend>,
+        model: &FastEmbedEmbedder,
+    ) -> Option<Arc<dyn VectorBackend + Send + Sync>> {
+        let checksum = match index.map(SqliteBackend::change_stamp) {
+            Some(Ok((count, last_updated))) => EmbeddingCache::source_checksum(count, last_updated),
+            Some(Err(e)) => {
+                tracing::warn!(error = %e, "Failed to checksum index, skipping embedding cache");
+                return Self::create_vector_backend_with_dimensions(path, model.dimensions());
+            },
+            None => return Self::create_vector_backend_with_dimensions(path, model.dimensions()),
+        };
+
+        let dimensions = UsearchBackend::stored_dimensions(path)
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| model.dimensions());
+        let backend = UsearchBackend::new(path, dimensions);
+        match backend.load_cached(model.model_name(), checksum) {
+            Ok(cached) => {
+                tracing::debug!(path = %path.display(), cached, "Created usearch vector backend");
+            },
+            Err(e) => {
+                tracing::warn!(
+                    path = %path.display(),
+                    error = %e,
+                    "Failed to load vector index, starting with empty index"
+                );
+            },
+        }
+        Some(Arc::new(backend))
+    }
+
+    /// Creates a vector backend whose new index uses `dimensions`.
+    ///
+    /// Used for namespaces with their own embedding model. As with
+    /// [`create_vector_backend`](Self::create_vector_backend), an existing
+    /// index keeps the dimensions recorded in its metadata.
+    pub fn create_vector_backend_with_dimensions(
+        path: &Path,
+        dimensions: usize,
+    ) -> Option<Arc<dyn VectorBackend + Send + Sync>> {
+        let dimensions = UsearchBackend::stored_dimensions(path)
+            .ok()
+            .flatten()
+            .unwrap_or(dimensions);
 
         #[cfg(feature = "usearch-hnsw")]
         let result = UsearchBackend::new(path, dimensions);
@@ -345,7 +474,7 @@ pub fn create_with_dimensions(
         vector_path: &Path,
         dimensions: usize,
     ) -> BackendSet {
-        let embedder = Self::create_embedder();
+        let embedder = Self::create_embedder(&FastEmbedEmbedder::new());
         let index = Self::create_index_backend(index_path);
 
         #[cfg(feature = "usearch-hnsw")]
@@ -393,7 +522,7 @@ fn test_backend_set_default() {
 
     #[test]
     fn test_create_embedder() {
-        let embedder = BackendFactory::create_embedder();
+        let embedder = BackendFactory::create_embedder(&FastEmbedEmbedder::new());
         assert!(embedder.is_some());
     }
 
@@ -420,7 +549,8 @@ fn test_create_all() {
         let index_path = temp_dir.path().join("index.db");
         let vector_path = temp_dir.path().join("vectors");
 
-        let backends = BackendFactory::create_all(&index_path, &vector_path);
+        let backends =
+            BackendFactory::create_all(&index_path, &vector_path, &FastEmbedEmbedder::new());
 
         assert!(backends.has_embedder());
         assert!(backends.has_index());
@@ -430,7 +560,7 @@ fn test_create_all() {
     #[test]
     fn test_backend_set_partial() {
         let set = BackendSet {
-            embedder: BackendFactory::create_embedder(),
+            embedder: BackendFactory::create_embedder(&FastEmbedEmbedder::new()),
             ..Default::default()
         };
 
@@ -448,7 +578,9 @@ fn test_create_from_config_sqlite_default() {
         let vector_path = temp_dir.path().join("vectors");
 
         let config = StorageBackendConfig::default();
-        let backends = BackendFactory::create_from_config(&config, &index_path, &vector_path);
+        let model = FastEmbedEmbedder::new();
+        let backends =
+            BackendFactory::create_from_config(&config, &model, &index_path, &vector_path);
 
         assert!(backends.has_embedder());
         assert!(backends.has_index());
@@ -467,7 +599,9 @@ fn test_create_from_config_postgres_no_connection_string() {
         };
 
         // Should fall back to SQLite when no connection string provided
-        let backends = BackendFactory::create_from_config(&config, &index_path, &vector_path);
+        let model = FastEmbedEmbedder::new();
+        let backends =
+            BackendFactory::create_from_config(&config, &model, &index_path, &vector_path);
         assert!(backends.has_embedder());
         assert!(backends.has_index());
     }
@@ -485,9 +619,119 @@ fn test_create_from_config_filesystem() {
             ..Default::default()
         };
 
-        let backends = BackendFactory::create_from_config(&config, &index_path, &vector_path);
+        let model = FastEmbedEmbedder::new();
+        let backends =
+            BackendFactory::create_from_config(&config, &model, &index_path, &vector_path);
         assert!(backends.has_embedder());
         assert!(backends.has_index());
         assert!(backends.has_persistence());
     }
+
+    #[cfg(not(feature = "usearch-hnsw"))]
+    #[test]
+    fn test_embedding_cache_is_rebuilt_when_index_changes() {
+        use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+        use crate::storage::traits::VectorFilter;
+        use crate::storage::vector::EmbeddingCache;
+        use crate::storage::{IndexBackend, VectorBackend};
+
+        fn memory(id: &str, content: &str) -> Memory {
+            Memory {
+                id: MemoryId::new(id),
+                content: content.to_string(),
+                namespace: Namespace::Decisions,
+                domain: Domain::new(),
+                project_id: None,
+                branch: None,
+                file_path: None,
+                status: MemoryStatus::Active,
+                created_at: 0,
+                updated_at: 0,
+                tombstoned_at: None,
+                expires_at: None,
+                embedding: None,
+                tags: Vec::new(),
+                #[cfg(feature = "group-scope")]
+                group_id: None,
+                source: None,
+                is_summary: false,
+                source_memory_ids: None,
+                consolidation_timestamp: None,
+                origin: CaptureOrigin::Manual,
+                confidence: None,
+                attachments: Vec::new(),
+                content_type: None,
+                pinned: false,
+                commit: None,
+                language: None,
+            }
+        }
+        fn axis(i: usize) -> Vec<f32> {
+            let mut v = vec![0.0; FastEmbedEmbedder::DEFAULT_DIMENSIONS];
+            v[i] = 1.0;
+            v
+        }
+        fn stamp(index: &SqliteBackend) -> u64 {
+            let (count, last_updated) = index.change_stamp().unwrap();
+            EmbeddingCache::source_checksum(count, last_updated)
+        }
+        fn nearest(vector: &dyn VectorBackend, query: &[f32]) -> Vec<MemoryId> {
+            let hits = vector.search(query, &VectorFilter::new(), 1).unwrap();
+            hits.into_iter().map(|(id, _)| id).collect()
+        }
+
+        let temp_dir = TempDir::new().expect("Failed to create temp dir");
+        let index_path = temp_dir.path().join("index.db");
+        let vector_path = temp_dir.path().join("vectors.usearch");
+        let cache_path = EmbeddingCache::path_for(&vector_path);
+        let embedder = FastEmbedEmbedder::new();
+        let model = embedder.model_name();
+
+        let index = SqliteBackend::new(&index_path).unwrap();
+        index.index(&memory("mem-a", "first")).unwrap();
+        let vector = UsearchBackend::new(&vector_path, FastEmbedEmbedder::DEFAULT_DIMENSIONS);
+        vector.upsert(&MemoryId::new("mem-a"), &axis(0)).unwrap();
+        vector.save().unwrap();
+        drop(vector);
+
+        // A cold start builds the cache; the next start with an unchanged
+        // index is served from it.
+        let backends = BackendFactory::create_all(&index_path, &vector_path, &embedder);
+        assert_eq!(backends.vector.unwrap().count().unwrap(), 1);
+        let checksum = stamp(&index);
+        let cache = EmbeddingCache::load(&cache_path).unwrap().unwrap();
+        assert!(cache.is_current(model, FastEmbedEmbedder::DEFAULT_DIMENSIONS, checksum));
+        let reloaded = UsearchBackend::new(&vector_path, FastEmbedEmbedder::DEFAULT_DIMENSIONS);
+        assert!(reloaded.load_cached(model, checksum).unwrap());
+        drop(reloaded);
+
+        // Capturing a memory changes both stores; saving the vector index
+        // drops the cache and the next start sees the new memory.
+        index.index(&memory("mem-b", "second")).unwrap();
+        let vector = UsearchBackend::new(&vector_path, FastEmbedEmbedder::DEFAULT_DIMENSIONS);
+        vector.load().unwrap();
+        vector.upsert(&MemoryId::new("mem-b"), &axis(1)).unwrap();
+        vector.save().unwrap();
+        drop(vector);
+        assert!(!cache_path.exists());
+
+        let vector = BackendFactory::create_all(&index_path, &vector_path, &embedder)
+            .vector
+            .unwrap();
+        assert_eq!(vector.count().unwrap(), 2);
+        assert_eq!(nearest(vector.as_ref(), &axis(1)), [MemoryId::new("mem-b")]);
+        drop(vector);
+
+        // Editing the index alone makes the cache stale: it is ignored and
+        // rebuilt from the vector index rather than served.
+        let mut edited = memory("mem-a", "first, edited");
+        edited.updated_at = 1;
+        index.index(&edited).unwrap();
+        let checksum = stamp(&index);
+        let reloaded = UsearchBackend::new(&vector_path, FastEmbedEmbedder::DEFAULT_DIMENSIONS);
+        assert!(!reloaded.load_cached(model, checksum).unwrap());
+        assert_eq!(nearest(&reloaded, &axis(0)), [MemoryId::new("mem-a")]);
+        assert!(reloaded.load_cached(model, checksum).unwrap());
+        assert!(!reloaded.load_cached("other-model", checksum).unwrap());
+    }
 }
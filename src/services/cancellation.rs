@@ -0,0 +1,78 @@
+//! Cooperative cancellation of long-running operations.
+//!
+//! Recall with reranking, enrichment and consolidation run for seconds and
+//! spend LLM budget along the way. They check a [`Cancellation`] between
+//! phases (retrieval, reranking, each LLM call) and stop with
+//! [`Error::Cancelled`] once it is cancelled. The MCP server cancels it when
+//! the client cancels the request or disconnects, or the tool call times out.
+//!
+//! Cancellation is cooperative: a phase already in flight (e.g. an LLM
+//! request) completes, but no further phase starts.
+
+use crate::{Error, Result};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Shared flag signalling that an operation should stop.
+///
+/// Clones share the same flag, so the token can be handed to a worker thread
+/// and cancelled from elsewhere.
+#[derive(Debug, Clone, Default)]
+pub struct Cancellation {
+    /// Set once the operation is cancelled.
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Cancellation {
+    /// Creates a token that is not cancelled.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cancels the operation; every clone observes it.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns true if the operation was cancelled.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Returns an error if the operation was cancelled.
+    ///
+    /// Call between phases of a long-running operation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Cancelled`] naming `operation` if cancelled.
+    pub fn check(&self, operation: &str) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(Error::Cancelled {
+                operation: operation.to_string(),
+            });
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_is_shared_by_clones() {
+        let cancellation = Cancellation::new();
+        let worker = cancellation.clone();
+        assert!(worker.check("enrich").is_ok());
+
+        cancellation.cancel();
+        assert!(worker.is_cancelled());
+        assert!(matches!(
+            worker.check("enrich"),
+            Err(Error::Cancelled { operation }) if operation == "enrich"
+        ));
+    }
+}
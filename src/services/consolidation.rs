@@ -18,12 +18,15 @@
 use crate::current_timestamp;
 use crate::llm::LlmProvider;
 use crate::models::{
-    EdgeType, EventMeta, Memory, MemoryEvent, MemoryStatus, MemoryTier, Namespace, RetentionScore,
+    CaptureOrigin, ContentType, EdgeType, EventMeta, Memory, MemoryEvent, MemoryId, MemoryStatus,
+    MemoryTier, Namespace, RetentionScore,
 };
 use crate::observability::current_request_id;
 use crate::security::record_event;
+use crate::services::Cancellation;
 use crate::storage::traits::{IndexBackend, PersistenceBackend};
 use lru::LruCache;
+use serde::Serialize;
 use std::collections::HashMap;
 use std::num::NonZeroUsize;
 use std::sync::Arc;
@@ -63,6 +66,8 @@ pub struct ConsolidationService<P: PersistenceBackend> {
     llm: Option<Arc<dyn LlmProvider + Send + Sync>>,
     /// Optional index backend for storing memory edges.
     index: Option<Arc<dyn IndexBackend + Send + Sync>>,
+    /// Checked between consolidation phases and before each LLM call.
+    cancellation: Cancellation,
 }
 
 impl<P: PersistenceBackend> ConsolidationService<P> {
@@ -75,6 +80,7 @@ pub fn new(persistence: P) -> Self {
             last_access: LruCache::new(ACCESS_CACHE_CAPACITY),
             llm: None,
             index: None,
+            cancellation: Cancellation::new(),
         }
     }
 
@@ -153,6 +159,17 @@ pub fn with_index(mut self, index: Arc<dyn IndexBackend + Send + Sync>) -> Self
         self
     }
 
+    /// Sets the cancellation token checked between phases and before each
+    /// LLM summarization.
+    ///
+    /// Once cancelled, consolidation stops with [`crate::Error::Cancelled`];
+    /// summaries already created are kept.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.cancellation = cancellation;
+        self
+    }
+
     /// Records an access to a memory for retention scoring.
     ///
     /// This updates the internal LRU caches tracking access frequency and recency.
@@ -197,6 +214,9 @@ pub fn record_access(&mut self, memory_id: &str) {
     /// 2. Summarizes each group using LLM
     /// 3. Creates summary nodes and stores edge relationships
     ///
+    /// Steps 1-2 are [`plan_consolidation`](Self::plan_consolidation) and step 3
+    /// is [`apply_consolidation_plan`](Self::apply_consolidation_plan).
+    ///
     /// # Arguments
     ///
     /// * `recall_service` - The recall service for semantic search
@@ -212,6 +232,7 @@ pub fn record_access(&mut self, memory_id: &str) {
     /// - Finding related memories fails
     /// - LLM summarization fails (when LLM is configured)
     /// - Creating summary nodes fails
+    /// - The operation is cancelled (see [`Self::with_cancellation`])
     ///
     /// # Graceful Degradation
     ///
@@ -263,7 +284,6 @@ pub fn record_access(&mut self, memory_id: &str) {
             operation = "consolidate_memories"
         )
     )]
-    #[allow(clippy::too_many_lines)]
     pub fn consolidate_memories(
         &mut self,
         recall_service: &crate::services::RecallService,
@@ -274,158 +294,9 @@ pub fn consolidate_memories(
             tracing::Span::current().record("request_id", request_id.as_str());
         }
 
-        let result = (|| {
-            let mut stats = ConsolidationStats::default();
-
-            // Check if consolidation is enabled
-            if !config.enabled {
-                tracing::info!("Consolidation is disabled in configuration");
-                return Ok(stats);
-            }
-
-            // Find related memory groups
-            tracing::info!(
-                namespace_filter = ?config.namespace_filter,
-                time_window_days = ?config.time_window_days,
-                similarity_threshold = config.similarity_threshold,
-                "Finding related memory groups for consolidation"
-            );
-
-            let groups = self.find_related_memories(recall_service, config)?;
-
-            if groups.is_empty() {
-                tracing::info!("No related memory groups found for consolidation");
-                return Ok(stats);
-            }
-
-            tracing::info!(
-                namespace_count = groups.len(),
-                "Found related memory groups in {} namespaces",
-                groups.len()
-            );
-
-            // Process each namespace
-            for (namespace, namespace_groups) in groups {
-                tracing::debug!(
-                    namespace = ?namespace,
-                    group_count = namespace_groups.len(),
-                    "Processing {} groups in namespace {:?}",
-                    namespace_groups.len(),
-                    namespace
-                );
-
-                // Process each group within the namespace
-                for (group_idx, memory_ids) in namespace_groups.iter().enumerate() {
-                    tracing::debug!(
-                        namespace = ?namespace,
-                        group_idx = group_idx,
-                        memory_count = memory_ids.len(),
-                        "Processing group {} with {} memories",
-                        group_idx,
-                        memory_ids.len()
-                    );
-
-                    // Fetch the actual Memory objects from persistence
-                    let mut memories = Vec::new();
-                    for memory_id in memory_ids {
-                        if let Some(memory) = self.persistence.get(memory_id)? {
-                            memories.push(memory);
-                            stats.processed += 1;
-                        } else {
-                            tracing::warn!(
-                                memory_id = %memory_id.as_str(),
-                                "Memory not found in persistence, skipping"
-                            );
-                        }
-                    }
-
-                    if memories.is_empty() {
-                        tracing::warn!(
-                            namespace = ?namespace,
-                            group_idx = group_idx,
-                            "No memories found for group, skipping"
-                        );
-                        continue;
-                    }
-
-                    // Summarize the group using LLM
-                    let summary_content = match self.summarize_group(&memories) {
-                        Ok(summary) => summary,
-                        Err(e) => {
-                            // Track LLM failures
-                            metrics::counter!(
-                                "consolidation_llm_failures",
-                                "namespace" => namespace.as_str()
-                            )
-                            .increment(1);
-
-                            tracing::warn!(
-                                error = %e,
-                                namespace = ?namespace,
-                                group_idx = group_idx,
-                                memory_count = memories.len(),
-                                "Failed to summarize group, creating relationships without summary"
-                            );
-
-                            // Graceful degradation: Create RelatedTo edges without summary node
-                            if let Some(ref index) = self.index {
-                                self.create_related_edges(&memories, index)?;
-                                tracing::info!(
-                                    namespace = ?namespace,
-                                    memory_count = memories.len(),
-                                    "Created RelatedTo edges for group without LLM summary"
-                                );
-                            } else {
-                                tracing::debug!(
-                                    namespace = ?namespace,
-                                    memory_count = memories.len(),
-                                    "Index backend not available, skipping edge creation"
-                                );
-                            }
-                            continue;
-                        },
-                    };
-
-                    // Create summary node (also stores edges if index backend available)
-                    match self.create_summary_node(&summary_content, &memories) {
-                        Ok(summary_node) => {
-                            stats.summaries_created += 1;
-                            tracing::info!(
-                                summary_id = %summary_node.id.as_str(),
-                                namespace = ?namespace,
-                                source_count = memories.len(),
-                                "Created summary node"
-                            );
-                        },
-                        Err(e) => {
-                            tracing::error!(
-                                error = %e,
-                                namespace = ?namespace,
-                                group_idx = group_idx,
-                                memory_count = memories.len(),
-                                "Failed to create summary node"
-                            );
-                            return Err(e);
-                        },
-                    }
-                }
-            }
-
-            record_event(MemoryEvent::Consolidated {
-                meta: EventMeta::new("consolidation", current_request_id()),
-                processed: stats.processed,
-                archived: stats.archived,
-                merged: stats.merged,
-            });
-
-            tracing::info!(
-                processed = stats.processed,
-                summaries_created = stats.summaries_created,
-                "Consolidation completed successfully"
-            );
-
-            Ok(stats)
-        })();
+        let result = self
+            .plan_consolidation(recall_service, config)
+            .and_then(|plan| self.apply_consolidation_plan(&plan));
 
         let status = if result.is_ok() { "success" } else { "error" };
         metrics::counter!(
@@ -461,6 +332,248 @@ pub fn consolidate_memories(
         result
     }
 
+    /// Plans a consolidation run without writing anything.
+    ///
+    /// Finds the clusters of related memories and asks the LLM for each
+    /// cluster's summary, exactly as [`consolidate_memories`](Self::consolidate_memories)
+    /// would, but stores no summary nodes or edges. This backs
+    /// `subcog consolidate --dry-run`; pass the plan to
+    /// [`apply_consolidation_plan`](Self::apply_consolidation_plan) to commit it.
+    ///
+    /// A cluster whose summary fails keeps the error in
+    /// [`ConsolidationCluster::summary_error`]; applying the plan links its
+    /// members with `RelatedTo` edges instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if finding or loading the related memories fails, or if
+    /// the operation is cancelled.
+    pub fn plan_consolidation(
+        &self,
+        recall_service: &crate::services::RecallService,
+        config: &crate::config::ConsolidationConfig,
+    ) -> Result<ConsolidationPlan> {
+        let mut plan = ConsolidationPlan::default();
+
+        if !config.enabled {
+            tracing::info!("Consolidation is disabled in configuration");
+            return Ok(plan);
+        }
+
+        tracing::info!(
+            namespace_filter = ?config.namespace_filter,
+            time_window_days = ?config.time_window_days,
+            similarity_threshold = config.similarity_threshold,
+            "Finding related memory groups for consolidation"
+        );
+
+        let groups = self.find_related_memories(recall_service, config)?;
+        self.cancellation.check("consolidate_memories")?;
+
+        // Stable order so dry-run reports are reproducible
+        let mut groups: Vec<_> = groups.into_iter().collect();
+        groups.sort_by_key(|(namespace, _)| namespace.as_str());
+
+        let groups = groups
+            .into_iter()
+            .flat_map(|(namespace, namespace_groups)| {
+                namespace_groups
+                    .into_iter()
+                    .enumerate()
+                    .map(move |(group_idx, memory_ids)| (namespace, group_idx, memory_ids))
+            });
+        for (namespace, group_idx, memory_ids) in groups {
+            let memories = self.load_group(&memory_ids)?;
+            if memories.is_empty() {
+                tracing::warn!(
+                    namespace = ?namespace,
+                    group_idx = group_idx,
+                    "No memories found for group, skipping"
+                );
+                continue;
+            }
+
+            self.cancellation.check("consolidate_memories")?;
+            plan.clusters
+                .push(self.plan_cluster(namespace, group_idx, memories));
+        }
+
+        Ok(plan)
+    }
+
+    /// Loads the memories of a related group, skipping any that are missing.
+    fn load_group(&self, memory_ids: &[MemoryId]) -> Result<Vec<Memory>> {
+        let mut memories = Vec::new();
+        for memory_id in memory_ids {
+            let Some(memory) = self.persistence.get(memory_id)? else {
+                tracing::warn!(
+                    memory_id = %memory_id.as_str(),
+                    "Memory not found in persistence, skipping"
+                );
+                continue;
+            };
+            memories.push(memory);
+        }
+        Ok(memories)
+    }
+
+    /// Summarizes a group into a planned cluster, keeping a summary failure
+    /// in the cluster instead of failing the plan.
+    fn plan_cluster(
+        &self,
+        namespace: Namespace,
+        group_idx: usize,
+        memories: Vec<Memory>,
+    ) -> ConsolidationCluster {
+        let (proposed_summary, summary_error) = match self.summarize_group(&memories) {
+            Ok(summary) => (Some(summary), None),
+            Err(e) => {
+                metrics::counter!(
+                    "consolidation_llm_failures",
+                    "namespace" => namespace.as_str()
+                )
+                .increment(1);
+                tracing::warn!(
+                    error = %e,
+                    namespace = ?namespace,
+                    group_idx = group_idx,
+                    memory_count = memories.len(),
+                    "Failed to summarize group, planning relationships without summary"
+                );
+                (None, Some(e.to_string()))
+            },
+        };
+        ConsolidationCluster::new(namespace, memories, proposed_summary, summary_error)
+    }
+
+    /// Links the members of a cluster without a summary with `RelatedTo`
+    /// edges (graceful degradation when the LLM summary failed).
+    fn link_unsummarized(&self, cluster: &ConsolidationCluster) -> Result<()> {
+        let Some(ref index) = self.index else {
+            tracing::debug!(
+                namespace = ?cluster.namespace,
+                memory_count = cluster.memories.len(),
+                "Index backend not available, skipping edge creation"
+            );
+            return Ok(());
+        };
+        self.create_related_edges(&cluster.memories, index)?;
+        tracing::info!(
+            namespace = ?cluster.namespace,
+            memory_count = cluster.memories.len(),
+            "Created RelatedTo edges for group without LLM summary"
+        );
+        Ok(())
+    }
+
+    /// Applies a plan from [`plan_consolidation`](Self::plan_consolidation).
+    ///
+    /// Creates a summary node (and its edges) for every summarized cluster and
+    /// marks the cluster's members superseded, and links the members of the
+    /// other clusters with `RelatedTo` edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if creating a summary node, storing edges, or storing
+    /// the superseded members fails.
+    pub fn apply_consolidation_plan(
+        &mut self,
+        plan: &ConsolidationPlan,
+    ) -> Result<ConsolidationStats> {
+        let mut stats = ConsolidationStats::default();
+        if plan.clusters.is_empty() {
+            tracing::info!("No related memory groups found for consolidation");
+            return Ok(stats);
+        }
+
+        for cluster in &plan.clusters {
+            stats.processed += cluster.memories.len();
+            let namespace = cluster.namespace;
+
+            let Some(ref summary_content) = cluster.proposed_summary else {
+                self.link_unsummarized(cluster)?;
+                continue;
+            };
+
+            // Supersede the members before the summary links them: re-indexing
+            // a member replaces its index row, which drops its edges.
+            let superseded = self.supersede_sources(&cluster.memories)?;
+
+            // Create summary node (also stores edges if index backend available)
+            match self.create_summary_node(summary_content, &cluster.memories) {
+                Ok(summary_node) => {
+                    stats.summaries_created += 1;
+                    stats.merged += superseded;
+                    tracing::info!(
+                        summary_id = %summary_node.id.as_str(),
+                        namespace = ?namespace,
+                        source_count = cluster.memories.len(),
+                        "Created summary node"
+                    );
+                },
+                Err(e) => {
+                    tracing::error!(
+                        error = %e,
+                        namespace = ?namespace,
+                        memory_count = cluster.memories.len(),
+                        "Failed to create summary node"
+                    );
+                    self.store_sources(&cluster.memories)?;
+                    return Err(e);
+                },
+            }
+        }
+
+        record_event(MemoryEvent::Consolidated {
+            meta: EventMeta::new("consolidation", current_request_id()),
+            processed: stats.processed,
+            archived: stats.archived,
+            merged: stats.merged,
+        });
+
+        tracing::info!(
+            processed = stats.processed,
+            summaries_created = stats.summaries_created,
+            "Consolidation completed successfully"
+        );
+
+        Ok(stats)
+    }
+
+    /// Marks the members of a summarized cluster superseded.
+    ///
+    /// The members stay searchable and linked to their summary, but later
+    /// runs skip them, so consolidation does not summarize them twice.
+    /// Returns the number of memories updated.
+    fn supersede_sources(&self, memories: &[Memory]) -> Result<usize> {
+        let now = current_timestamp();
+        let superseded: Vec<Memory> = memories
+            .iter()
+            .map(|memory| {
+                let mut superseded = memory.clone();
+                superseded.status = MemoryStatus::Superseded;
+                superseded.updated_at = now;
+                superseded.consolidation_timestamp = Some(now);
+                superseded
+            })
+            .collect();
+        self.store_sources(&superseded)?;
+        Ok(superseded.len())
+    }
+
+    /// Writes cluster members to persistence and, when present, the index.
+    ///
+    /// Also restores the original members when their summary cannot be created.
+    fn store_sources(&self, memories: &[Memory]) -> Result<()> {
+        for memory in memories {
+            self.persistence.store(memory)?;
+            if let Some(ref index) = self.index {
+                index.index(memory)?;
+            }
+        }
+        Ok(())
+    }
+
     /// Runs lifecycle consolidation on all memories based on retention scoring.
     ///
     /// This method performs the following operations:
@@ -764,6 +877,7 @@ pub fn merge_memories(
         // Create merged memory
         let now = current_timestamp();
         let merged_content = format!("{}\n\n---\n\n{}", target.content, source.content);
+        let merged_content_type = ContentType::detect(&merged_content);
 
         // Combine tags
         let mut merged_tags = target.tags.clone();
@@ -773,6 +887,13 @@ pub fn merge_memories(
             }
         }
 
+        let mut merged_attachments = target.attachments.clone();
+        for attachment in &source.attachments {
+            if !merged_attachments.contains(attachment) {
+                merged_attachments.push(attachment.clone());
+            }
+        }
+
         // Clone source before using its fields to avoid partial move
         let source_created_at = source.created_at;
         let source_source = source.source.clone();
@@ -804,6 +925,13 @@ pub fn merge_memories(
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: target.origin,
+            confidence: target.confidence,
+            attachments: merged_attachments,
+            content_type: Some(merged_content_type),
+            pinned: target.pinned || source.pinned,
+            commit: target.commit.clone().or_else(|| source.commit.clone()),
+            language: target.language.or_else(|| source.language.clone()),
         };
 
         // Store merged memory
@@ -986,6 +1114,11 @@ pub fn find_related_memories(
                 continue;
             };
 
+            // Summaries and memories already folded into one are not consolidated again
+            if !is_consolidation_candidate(&memory) {
+                continue;
+            }
+
             // Skip if outside time window
             let outside_window = cutoff_timestamp.is_some_and(|cutoff| memory.created_at < cutoff);
             if outside_window {
@@ -1032,6 +1165,7 @@ pub fn find_related_memories(
             }
 
             // Find related memory groups using semantic similarity
+            self.cancellation.check("find_related_memories")?;
             let groups = self.cluster_by_similarity(&memories, config.similarity_threshold)?;
 
             if !groups.is_empty() {
@@ -1312,8 +1446,9 @@ pub fn create_summary_node(
 
         let now = current_timestamp();
 
-        // Generate unique ID for summary node
-        let summary_id = crate::models::MemoryId::new(format!("summary_{now}"));
+        // Generate unique ID for summary node (a run can create several per second)
+        let suffix = uuid::Uuid::new_v4().simple().to_string();
+        let summary_id = crate::models::MemoryId::new(format!("summary_{now}_{}", &suffix[..8]));
 
         // Collect source memory IDs
         let source_memory_ids: Vec<crate::models::MemoryId> =
@@ -1360,6 +1495,13 @@ pub fn create_summary_node(
             is_summary: true,
             source_memory_ids: Some(source_memory_ids.clone()),
             consolidation_timestamp: Some(now),
+            origin: CaptureOrigin::Consolidation,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: Some(ContentType::detect(summary_content)),
+            pinned: false,
+            commit: None,
+            language: None,
         };
 
         // Store summary node in persistence layer
@@ -1572,6 +1714,123 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
     (dot_product / (magnitude_a * magnitude_b)).clamp(0.0, 1.0)
 }
 
+/// Maximum characters of member content shown in a consolidation plan.
+const PLAN_SNIPPET_CHARS: usize = 120;
+
+/// Consolidation work planned by [`ConsolidationService::plan_consolidation`].
+///
+/// Nothing has been written yet; `subcog consolidate --dry-run` prints this
+/// report.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ConsolidationPlan {
+    /// Clusters of related memories, in namespace order.
+    pub clusters: Vec<ConsolidationCluster>,
+}
+
+impl ConsolidationPlan {
+    /// Returns the number of clusters that would get a summary node.
+    #[must_use]
+    pub fn summary_count(&self) -> usize {
+        self.clusters
+            .iter()
+            .filter(|cluster| cluster.proposed_summary.is_some())
+            .count()
+    }
+
+    /// Returns the number of memories across all clusters.
+    #[must_use]
+    pub fn memory_count(&self) -> usize {
+        self.clusters
+            .iter()
+            .map(|cluster| cluster.members.len())
+            .sum()
+    }
+}
+
+/// A cluster of related memories in a [`ConsolidationPlan`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ConsolidationCluster {
+    /// Namespace shared by the members.
+    pub namespace: Namespace,
+    /// The clustered memories.
+    pub members: Vec<ClusterMember>,
+    /// Content of the summary node that would be created.
+    ///
+    /// `None` if summarization failed; the members would then only be linked
+    /// with `RelatedTo` edges.
+    pub proposed_summary: Option<String>,
+    /// Why summarization failed, if it did.
+    pub summary_error: Option<String>,
+    /// Memories the summary would supersede.
+    ///
+    /// They are marked `superseded` and linked to the summary with
+    /// `SummarizedBy` edges. Empty when there is no summary.
+    pub superseded: Vec<MemoryId>,
+    /// Full memories, kept for applying the plan.
+    #[serde(skip)]
+    memories: Vec<Memory>,
+}
+
+impl ConsolidationCluster {
+    /// Creates a cluster from its memories and summarization outcome.
+    fn new(
+        namespace: Namespace,
+        memories: Vec<Memory>,
+        proposed_summary: Option<String>,
+        summary_error: Option<String>,
+    ) -> Self {
+        let members = memories
+            .iter()
+            .map(|memory| ClusterMember {
+                id: memory.id.clone(),
+                snippet: snippet(&memory.content),
+            })
+            .collect();
+        let superseded = if proposed_summary.is_some() {
+            memories.iter().map(|memory| memory.id.clone()).collect()
+        } else {
+            Vec::new()
+        };
+        Self {
+            namespace,
+            members,
+            proposed_summary,
+            summary_error,
+            superseded,
+            memories,
+        }
+    }
+}
+
+/// A member of a [`ConsolidationCluster`].
+#[derive(Debug, Clone, Serialize)]
+pub struct ClusterMember {
+    /// Memory ID.
+    pub id: MemoryId,
+    /// Start of the content on one line.
+    pub snippet: String,
+}
+
+/// Returns `true` if `memory` may be clustered: it is not a summary and has
+/// not been superseded, consolidated, or deleted.
+const fn is_consolidation_candidate(memory: &Memory) -> bool {
+    !memory.is_summary
+        && matches!(
+            memory.status,
+            MemoryStatus::Active | MemoryStatus::Archived | MemoryStatus::Pending
+        )
+}
+
+/// Collapses `content` onto one line, truncated to [`PLAN_SNIPPET_CHARS`].
+fn snippet(content: &str) -> String {
+    let line = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if line.chars().count() <= PLAN_SNIPPET_CHARS {
+        return line;
+    }
+    let truncated: String = line.chars().take(PLAN_SNIPPET_CHARS - 3).collect();
+    format!("{truncated}...")
+}
+
 /// Statistics from a consolidation operation.
 ///
 /// Tracks the outcome of consolidation operations including memory processing,
@@ -1706,6 +1965,13 @@ fn create_test_memory(id: &str, content: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -2727,6 +2993,166 @@ fn clear(&self) -> Result<()> {
         assert_eq!(stats.summaries_created, 1);
     }
 
+    #[test]
+    fn test_plan_consolidation_reports_without_writing() {
+        use crate::storage::index::SqliteBackend;
+
+        struct MockLlm;
+        impl crate::llm::LlmProvider for MockLlm {
+            fn name(&self) -> &'static str {
+                "mock"
+            }
+            fn complete(&self, _prompt: &str) -> Result<String> {
+                Ok("Store data in PostgreSQL with JSONB enabled.".to_string())
+            }
+            fn analyze_for_capture(&self, _content: &str) -> Result<crate::llm::CaptureAnalysis> {
+                Err(crate::Error::OperationFailed {
+                    operation: "analyze_for_capture".to_string(),
+                    cause: "Not implemented for mock".to_string(),
+                })
+            }
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        let mut service = ConsolidationService::new(FilesystemBackend::new(temp_dir.path()))
+            .with_llm(Arc::new(MockLlm))
+            .with_index(Arc::clone(&index));
+
+        for (id, content, embedding) in [
+            ("plan_a", "Use PostgreSQL for storage", vec![1.0, 0.0, 0.0]),
+            (
+                "plan_b",
+                "Enable JSONB in PostgreSQL",
+                vec![0.95, 0.05, 0.0],
+            ),
+        ] {
+            let mut memory = create_test_memory(id, content);
+            memory.embedding = Some(embedding);
+            service.persistence.store(&memory).unwrap();
+            index.index(&memory).unwrap();
+        }
+
+        let mut config = crate::config::ConsolidationConfig::new();
+        config.enabled = true;
+        config.similarity_threshold = 0.7;
+        config.min_memories_to_consolidate = 2;
+
+        let plan = service
+            .plan_consolidation(&crate::services::RecallService::new(), &config)
+            .unwrap();
+        assert_eq!(plan.clusters.len(), 1);
+        assert_eq!(plan.summary_count(), 1);
+        let cluster = &plan.clusters[0];
+        let mut member_ids: Vec<&str> = cluster.members.iter().map(|m| m.id.as_str()).collect();
+        member_ids.sort_unstable();
+        assert_eq!(member_ids, ["plan_a", "plan_b"]);
+        assert_eq!(
+            cluster.proposed_summary.as_deref(),
+            Some("Store data in PostgreSQL with JSONB enabled.")
+        );
+        assert_eq!(cluster.superseded.len(), 2);
+
+        let report = serde_json::to_value(&plan).unwrap();
+        assert_eq!(
+            report["clusters"][0]["members"].as_array().unwrap().len(),
+            2
+        );
+
+        // Planning wrote no summary node and no edges
+        assert_eq!(service.persistence.list_ids().unwrap().len(), 2);
+        let edges = index
+            .query_edges(&MemoryId::new("plan_a"), EdgeType::SummarizedBy)
+            .unwrap();
+        assert!(edges.is_empty());
+
+        let stats = service.apply_consolidation_plan(&plan).unwrap();
+        assert_eq!(stats.summaries_created, 1);
+        assert_eq!(service.persistence.list_ids().unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_consolidation_supersedes_sources_and_is_idempotent() {
+        use crate::storage::index::SqliteBackend;
+
+        struct MockLlm;
+        impl crate::llm::LlmProvider for MockLlm {
+            fn name(&self) -> &'static str {
+                "mock"
+            }
+            fn complete(&self, _prompt: &str) -> Result<String> {
+                Ok("Store data in PostgreSQL with JSONB enabled.".to_string())
+            }
+            fn analyze_for_capture(&self, _content: &str) -> Result<crate::llm::CaptureAnalysis> {
+                Err(crate::Error::OperationFailed {
+                    operation: "analyze_for_capture".to_string(),
+                    cause: "Not implemented for mock".to_string(),
+                })
+            }
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        let mut service = ConsolidationService::new(FilesystemBackend::new(temp_dir.path()))
+            .with_llm(Arc::new(MockLlm))
+            .with_index(Arc::clone(&index));
+
+        for (id, content, embedding) in [
+            ("idem_a", "Use PostgreSQL for storage", vec![1.0, 0.0, 0.0]),
+            (
+                "idem_b",
+                "Enable JSONB in PostgreSQL",
+                vec![0.95, 0.05, 0.0],
+            ),
+            (
+                "idem_c",
+                "Deploy with blue-green releases",
+                vec![0.0, 0.0, 1.0],
+            ),
+        ] {
+            let mut memory = create_test_memory(id, content);
+            memory.embedding = Some(embedding);
+            service.persistence.store(&memory).unwrap();
+            index.index(&memory).unwrap();
+        }
+
+        let mut config = crate::config::ConsolidationConfig::new();
+        config.enabled = true;
+        config.similarity_threshold = 0.7;
+        config.min_memories_to_consolidate = 2;
+        let recall = crate::services::RecallService::new();
+
+        let stats = service.consolidate_memories(&recall, &config).unwrap();
+        assert_eq!(stats.summaries_created, 1);
+        assert_eq!(stats.merged, 2);
+
+        for id in ["idem_a", "idem_b"] {
+            let id = MemoryId::new(id);
+            let stored = service.persistence.get(&id).unwrap().unwrap();
+            assert_eq!(stored.status, MemoryStatus::Superseded);
+            let indexed = index.get_memory(&id).unwrap().unwrap();
+            assert_eq!(indexed.status, MemoryStatus::Superseded);
+            let edges = index.query_edges(&id, EdgeType::SummarizedBy).unwrap();
+            assert_eq!(edges.len(), 1);
+        }
+        // The singleton is left untouched
+        let single = service
+            .persistence
+            .get(&MemoryId::new("idem_c"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(single.status, MemoryStatus::Active);
+
+        // A second run finds nothing left to summarize
+        let plan = service.plan_consolidation(&recall, &config).unwrap();
+        assert!(plan.clusters.is_empty());
+        let stats = service.consolidate_memories(&recall, &config).unwrap();
+        assert_eq!(stats.summaries_created, 0);
+        assert_eq!(service.persistence.list_ids().unwrap().len(), 4);
+    }
+
     #[test]
     fn test_consolidate_memories_respects_namespace_filter() {
         use crate::embedding::Embedder as EmbedderTrait;
@@ -3246,8 +3672,11 @@ fn test_edge_storage_idempotency() {
         // Index summary so we can query edges
         index_arc.index(&summary1).expect("Failed to index summary");
 
-        // Create same summary again (should handle duplicate edges gracefully)
-        let result2 = service.create_summary_node(summary_content, &source_memories);
+        // Summarize the same sources again (should handle duplicate edges
+        // gracefully); a live summary with identical content would be rejected
+        // by the index
+        let result2 =
+            service.create_summary_node("Second summary for idempotency test", &source_memories);
         assert!(result2.is_ok());
         let summary2 = result2.unwrap();
 
@@ -0,0 +1,294 @@
+//! Builder for service containers with caller-supplied storage backends.
+//!
+//! [`ServiceContainer::for_repo`] and [`ServiceContainer::for_user`] create
+//! backends from configuration via [`BackendFactory`](super::BackendFactory),
+//! which only knows about `SQLite`, usearch, the filesystem and PostgreSQL.
+//! Library users with their own storage (a cloud store, an in-memory set for
+//! tests) can implement the storage traits and hand the backends to
+//! [`ServiceContainerBuilder`] instead:
+//!
+//! ```text
+//! ServiceContainerBuilder
+//!   ├── with_persistence(Arc<dyn PersistenceBackend>)  → capture writes
+//!   ├── with_index(Arc<dyn IndexBackend>)              → capture + recall (required)
+//!   ├── with_vector(Arc<dyn VectorBackend>)            → capture + recall
+//!   ├── with_embedder(Arc<dyn Embedder>)               → capture + recall
+//!   └── with_namespace_embeddings(Arc<NamespaceEmbeddings>) → capture + recall
+//! ```
+//!
+//! The supplied index replaces the `SQLite` indices of the `DomainIndexManager`
+//! for [`capture`](ServiceContainer::capture), [`recall`](ServiceContainer::recall)
+//! and [`index`](ServiceContainer::index). No embedder is created implicitly,
+//! so vector search is only enabled when both an embedder and a vector
+//! backend are supplied.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::services::ServiceContainer;
+//!
+//! let container = ServiceContainer::builder()
+//!     .with_persistence(Arc::new(MyCloudStore::connect(url)?))
+//!     .with_index(Arc::new(MyCloudIndex::connect(url)?))
+//!     .build()?;
+//!
+//! container.capture().capture(request)?;
+//! let results = container.recall()?.search("database", SearchMode::Text, &filter, 10)?;
+//! ```
+
+use super::{
+    CaptureService, DEFAULT_HYBRID_ALPHA, DEFAULT_SNIPPET_MARKER, NamespaceEmbeddings, RecallCache,
+    ServiceContainer, SyncService,
+};
+use crate::config::{Config, StorageConfig};
+use crate::embedding::Embedder;
+use crate::models::FieldWeights;
+use crate::storage::index::{DomainIndexConfig, DomainIndexManager, get_user_data_dir};
+use crate::storage::traits::{IndexBackend, PersistenceBackend, VectorBackend};
+use crate::{Error, Result};
+use std::sync::{Arc, Mutex};
+
+/// Builds a [`ServiceContainer`] from caller-supplied storage backends.
+///
+/// Created with [`ServiceContainer::builder`].
+#[derive(Default)]
+pub struct ServiceContainerBuilder {
+    /// Service configuration (repository path, data directory, capture rules).
+    config: Config,
+    /// Persistence backend for captured memories.
+    persistence: Option<Arc<dyn PersistenceBackend + Send + Sync>>,
+    /// Index backend for text search and memory retrieval.
+    index: Option<Arc<dyn IndexBackend + Send + Sync>>,
+    /// Vector backend for similarity search.
+    vector: Option<Arc<dyn VectorBackend + Send + Sync>>,
+    /// Embedder shared by capture and recall.
+    embedder: Option<Arc<dyn Embedder>>,
+    /// Recall query cache shared by every recall service.
+    recall_cache: Option<Arc<RecallCache>>,
+    /// Embedding routes for namespaces with their own model.
+    namespace_embeddings: Option<Arc<NamespaceEmbeddings>>,
+}
+
+impl ServiceContainerBuilder {
+    /// Creates an empty builder.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the service configuration.
+    ///
+    /// `repo_path` selects project scope (user scope when unset) and
+    /// `data_dir` overrides the user data directory.
+    #[must_use]
+    pub fn with_config(mut self, config: Config) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Sets the persistence backend captured memories are stored in.
+    #[must_use]
+    pub fn with_persistence(
+        mut self,
+        persistence: Arc<dyn PersistenceBackend + Send + Sync>,
+    ) -> Self {
+        self.persistence = Some(persistence);
+        self
+    }
+
+    /// Sets the index backend used for capture, recall and maintenance.
+    #[must_use]
+    pub fn with_index(mut self, index: Arc<dyn IndexBackend + Send + Sync>) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Sets the vector backend used for similarity search.
+    #[must_use]
+    pub fn with_vector(mut self, vector: Arc<dyn VectorBackend + Send + Sync>) -> Self {
+        self.vector = Some(vector);
+        self
+    }
+
+    /// Sets the embedder used to generate capture and query embeddings.
+    #[must_use]
+    pub fn with_embedder(mut self, embedder: Arc<dyn Embedder>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Sets the embedders and vector backends of namespaces with their own
+    /// embedding model.
+    #[must_use]
+    pub fn with_namespace_embeddings(mut self, routes: Arc<NamespaceEmbeddings>) -> Self {
+        self.namespace_embeddings = Some(routes);
+        self
+    }
+
+    /// Sets a recall query cache shared by every recall service.
+    #[must_use]
+    pub fn with_recall_cache(mut self, cache: Arc<RecallCache>) -> Self {
+        self.recall_cache = Some(cache);
+        self
+    }
+
+    /// Builds the service container.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if no index backend was supplied, an
+    /// error if the user data directory cannot be determined, or
+    /// [`Error::OperationFailed`] if the embedder and vector backend have
+    /// different dimensions.
+    pub fn build(self) -> Result<ServiceContainer> {
+        let index = self.index.ok_or_else(|| {
+            Error::InvalidInput("An index backend is required to build services".to_string())
+        })?;
+
+        let user_data_dir = self
+            .config
+            .data_dir
+            .clone()
+            .map_or_else(get_user_data_dir, Ok)?;
+        let repo_path = self.config.repo_path.clone();
+
+        let index_manager = DomainIndexManager::new(DomainIndexConfig {
+            repo_path: repo_path.clone(),
+            org_config: None,
+            user_data_dir: Some(user_data_dir.clone()),
+        })?;
+
+        let mut capture = CaptureService::new_minimal(self.config).with_index(Arc::clone(&index));
+        if let Some(ref embedder) = self.embedder {
+            capture = capture.with_embedder(Arc::clone(embedder));
+        }
+        if let Some(ref vector) = self.vector {
+            capture = capture.with_vector(Arc::clone(vector));
+        }
+        if let Some(ref persistence) = self.persistence {
+            capture = capture.with_persistence(Arc::clone(persistence));
+        }
+        if let Some(ref routes) = self.namespace_embeddings {
+            capture = capture.with_namespace_embeddings(Arc::clone(routes));
+        }
+
+        let sync = if repo_path.is_some() {
+            SyncService::default()
+        } else {
+            SyncService::no_op()
+        };
+
+        let container = ServiceContainer {
+            capture: Arc::new(capture),
+            sync,
+            index_manager: Mutex::new(index_manager),
+            repo_path,
+            user_data_dir,
+            embedder: self.embedder,
+            vector: self.vector,
+            index: Some(index),
+            persistence: self.persistence,
+            recall_cache: self.recall_cache,
+            field_weights: FieldWeights::default(),
+            feedback_boost: true,
+            snippet_marker: DEFAULT_SNIPPET_MARKER.to_string(),
+            hybrid_alpha: DEFAULT_HYBRID_ALPHA,
+            storage: StorageConfig::default(),
+            namespace_embeddings: self.namespace_embeddings,
+        };
+        container.check_embedding_dimensions()?;
+        Ok(container)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureRequest, Namespace, SearchFilter, SearchMode};
+    use crate::storage::index::SqliteBackend;
+    use crate::storage::vector::UsearchBackend;
+
+    /// Embedder producing zero vectors of a fixed size.
+    struct FixedEmbedder(usize);
+
+    impl Embedder for FixedEmbedder {
+        fn dimensions(&self) -> usize {
+            self.0
+        }
+
+        fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![0.0; self.0])
+        }
+    }
+
+    #[cfg(not(feature = "usearch-hnsw"))]
+    fn vector_backend(dimensions: usize) -> UsearchBackend {
+        UsearchBackend::in_memory(dimensions)
+    }
+
+    #[cfg(feature = "usearch-hnsw")]
+    fn vector_backend(dimensions: usize) -> UsearchBackend {
+        UsearchBackend::in_memory(dimensions).expect("Failed to create usearch backend")
+    }
+
+    fn test_config(dir: &tempfile::TempDir) -> Config {
+        Config::new().with_data_dir(dir.path())
+    }
+
+    #[test]
+    fn test_build_requires_index() {
+        let result = ServiceContainerBuilder::new().build();
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
+    }
+
+    #[test]
+    fn test_services_use_supplied_index() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        let container = ServiceContainer::builder()
+            .with_config(test_config(&dir))
+            .with_index(Arc::clone(&index))
+            .build()
+            .unwrap();
+
+        assert!(container.is_user_scope());
+        let result = container
+            .capture()
+            .capture(
+                CaptureRequest::new("Use PostgreSQL for storage")
+                    .with_namespace(Namespace::Decisions),
+            )
+            .unwrap();
+
+        assert!(index.get_memory(&result.memory_id).unwrap().is_some());
+        let hits = container
+            .recall()
+            .unwrap()
+            .search("PostgreSQL", SearchMode::Text, &SearchFilter::new(), 10)
+            .unwrap();
+        assert_eq!(hits.memories.len(), 1);
+        assert!(!dir.path().join("index.db").exists());
+    }
+
+    #[test]
+    fn test_build_rejects_embedder_dimension_mismatch() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let builder = |embedder_dimensions| {
+            ServiceContainer::builder()
+                .with_config(test_config(&dir))
+                .with_index(Arc::new(SqliteBackend::in_memory().unwrap()))
+                .with_vector(Arc::new(vector_backend(384)))
+                .with_embedder(Arc::new(FixedEmbedder(embedder_dimensions)))
+        };
+
+        assert!(builder(384).build().is_ok());
+        let Err(Error::OperationFailed { operation, cause }) = builder(768).build() else {
+            panic!("expected a dimension mismatch error");
+        };
+        assert_eq!(operation, "check_embedding_dimensions");
+        assert!(cause.contains("768-dimensional embeddings"), "{cause}");
+        assert!(cause.contains("384-dimensional vectors"), "{cause}");
+        assert!(cause.contains("subcog migrate embeddings"), "{cause}");
+    }
+}
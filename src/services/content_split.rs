@@ -0,0 +1,205 @@
+//! Splitting of large multi-topic captures (`capture --split`).
+//!
+//! Content longer than the threshold is split at markdown headings: each
+//! heading starts a new part. A part still over the threshold (or content
+//! without headings) is split again at blank lines, packing consecutive
+//! paragraphs into parts of at most the threshold. Paragraphs and fenced code
+//! blocks are never cut, so a single oversized paragraph stays whole.
+//!
+//! A heading directly followed by another heading (e.g. a document title
+//! above its first section) stays with the section below it.
+
+use crate::models::ContentHasher;
+
+/// Content length, in characters, above which split captures are split.
+pub const DEFAULT_SPLIT_THRESHOLD_CHARS: usize = 2000;
+
+/// Prefix of the tag linking the memories split from one capture.
+pub const SPLIT_TAG_PREFIX: &str = "split:";
+
+/// Hex characters of the content hash used in the split tag.
+const SPLIT_TAG_HASH_LEN: usize = 16;
+
+/// Splits `content` into parts of roughly at most `threshold` characters.
+///
+/// Returns the content unchanged as a single part if it is not longer than
+/// `threshold`.
+#[must_use]
+pub fn split_content(content: &str, threshold: usize) -> Vec<String> {
+    if content.chars().count() <= threshold {
+        return vec![content.to_string()];
+    }
+
+    let parts: Vec<String> = heading_sections(content)
+        .into_iter()
+        .flat_map(|section| {
+            if section.chars().count() <= threshold {
+                vec![section]
+            } else {
+                paragraph_groups(&section, threshold)
+            }
+        })
+        .map(|part| part.trim().to_string())
+        .filter(|part| !part.is_empty())
+        .collect();
+
+    if parts.is_empty() {
+        vec![content.to_string()]
+    } else {
+        parts
+    }
+}
+
+/// Returns the tag linking the memories split from `content`.
+#[must_use]
+pub fn split_tag(content: &str) -> String {
+    let hash = ContentHasher::hash(content);
+    let prefix = hash.get(..SPLIT_TAG_HASH_LEN).unwrap_or(&hash);
+    format!("{SPLIT_TAG_PREFIX}{prefix}")
+}
+
+/// Splits content before each markdown heading outside code fences.
+fn heading_sections(content: &str) -> Vec<String> {
+    let mut sections = Vec::new();
+    let mut current = String::new();
+    let mut has_body = false;
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        let trimmed = line.trim_start();
+        if is_fence(trimmed) {
+            in_fence = !in_fence;
+        }
+        let heading = !in_fence && is_heading(trimmed);
+        if heading && has_body {
+            sections.push(std::mem::take(&mut current));
+            has_body = false;
+        }
+        if !heading && !trimmed.is_empty() {
+            has_body = true;
+        }
+        current.push_str(line);
+        current.push('\n');
+    }
+    if !current.trim().is_empty() {
+        sections.push(current);
+    }
+    sections
+}
+
+/// Packs the paragraphs of `section` into groups of at most `threshold`
+/// characters.
+fn paragraph_groups(section: &str, threshold: usize) -> Vec<String> {
+    let mut groups = Vec::new();
+    let mut current = String::new();
+
+    for paragraph in paragraphs(section) {
+        let len = current.chars().count() + paragraph.chars().count() + 2;
+        if !current.is_empty() && len > threshold {
+            groups.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push_str("\n\n");
+        }
+        current.push_str(&paragraph);
+    }
+    if !current.is_empty() {
+        groups.push(current);
+    }
+    groups
+}
+
+/// Splits text at blank lines outside code fences.
+fn paragraphs(text: &str) -> Vec<String> {
+    let mut paragraphs = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    let mut in_fence = false;
+
+    for line in text.lines() {
+        if is_fence(line.trim_start()) {
+            in_fence = !in_fence;
+        }
+        if line.trim().is_empty() && !in_fence {
+            if !current.is_empty() {
+                paragraphs.push(current.join("\n"));
+                current.clear();
+            }
+            continue;
+        }
+        current.push(line);
+    }
+    if !current.is_empty() {
+        paragraphs.push(current.join("\n"));
+    }
+    paragraphs
+}
+
+/// Checks for an ATX heading (`#` to `######` followed by a space).
+fn is_heading(line: &str) -> bool {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    (1..=6).contains(&level) && line[level..].starts_with(' ')
+}
+
+/// Checks for a code fence delimiter.
+fn is_fence(line: &str) -> bool {
+    line.starts_with("```") || line.starts_with("~~~")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_short_content_is_not_split() {
+        let content = "# Title\n\nShort note.";
+        assert_eq!(split_content(content, 100), vec![content.to_string()]);
+    }
+
+    #[test]
+    fn test_splits_at_headings() {
+        let content = "# Notes\n\n## Storage\n\nUse SQLite.\n\n## Cache\n\nUse an LRU cache.\n";
+
+        let parts = split_content(content, 40);
+
+        assert_eq!(
+            parts,
+            vec![
+                "# Notes\n\n## Storage\n\nUse SQLite.".to_string(),
+                "## Cache\n\nUse an LRU cache.".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_splits_long_sections_at_blank_lines() {
+        let content = "First paragraph here.\n\nSecond paragraph.\n\nThird paragraph.";
+
+        let parts = split_content(content, 40);
+
+        assert_eq!(
+            parts,
+            vec![
+                "First paragraph here.\n\nSecond paragraph.".to_string(),
+                "Third paragraph.".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_code_fences_are_kept_whole() {
+        let content = "Intro text.\n\n```\n# not a heading\n\nstill code\n```\n\nOutro text.";
+
+        let parts = split_content(content, 20);
+
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[1], "```\n# not a heading\n\nstill code\n```");
+    }
+
+    #[test]
+    fn test_split_tag() {
+        let tag = split_tag("content");
+        assert!(tag.starts_with(SPLIT_TAG_PREFIX));
+        assert_eq!(tag.len(), SPLIT_TAG_PREFIX.len() + SPLIT_TAG_HASH_LEN);
+        assert_eq!(tag, split_tag("content"));
+    }
+}
@@ -17,6 +17,15 @@
 const CONTEXT_PROJECT_LIMIT: usize = 3;
 /// Maximum memories to fetch for tech debt.
 const CONTEXT_TECH_DEBT_LIMIT: usize = 2;
+/// Maximum pinned memories to include.
+const CONTEXT_PINNED_LIMIT: usize = 10;
+/// Namespaces whose pinned memories are always included in session context.
+const CONTEXT_PINNED_NAMESPACES: [Namespace; 4] = [
+    Namespace::Decisions,
+    Namespace::Patterns,
+    Namespace::Context,
+    Namespace::TechDebt,
+];
 /// Default search result limit.
 const SEARCH_RESULT_LIMIT: usize = 10;
 /// Maximum recent memories to fetch for statistics.
@@ -79,6 +88,12 @@ pub fn build_context(&self, max_tokens: usize) -> Result<String> {
 
         let mut context_parts = Vec::new();
 
+        // Pinned memories go first so truncation drops them last
+        let pinned = self.get_pinned_memories()?;
+        if !pinned.is_empty() {
+            context_parts.push(format_section("Pinned", &pinned));
+        }
+
         // Add recent decisions (high priority)
         if let Some(decisions) =
             self.get_relevant_memories(Namespace::Decisions, CONTEXT_DECISIONS_LIMIT)?
@@ -190,6 +205,29 @@ const fn get_relevant_memories(
         Ok(Some(Vec::new()))
     }
 
+    /// Gets the pinned memories of the session context namespaces.
+    ///
+    /// Returns an empty vector if the recall service is not configured.
+    fn get_pinned_memories(&self) -> Result<Vec<Memory>> {
+        let Some(recall) = &self.recall else {
+            return Ok(Vec::new());
+        };
+        let filter = CONTEXT_PINNED_NAMESPACES
+            .into_iter()
+            .fold(SearchFilter::new().with_pinned_only(true), |filter, ns| {
+                filter.with_namespace(ns)
+            });
+        let result = recall.list_all_with_content(&filter, CONTEXT_PINNED_LIMIT)?;
+
+        // Not every backend applies the pinned filter
+        Ok(result
+            .memories
+            .into_iter()
+            .map(|hit| hit.memory)
+            .filter(|memory| memory.pinned)
+            .collect())
+    }
+
     /// Estimates the token count for a string.
     #[must_use]
     pub const fn estimate_tokens(text: &str) -> usize {
@@ -386,7 +424,7 @@ fn test_truncate_context() {
 
     #[test]
     fn test_format_section() {
-        use crate::models::{Domain, MemoryId, MemoryStatus};
+        use crate::models::{CaptureOrigin, Domain, MemoryId, MemoryStatus};
 
         let memories = vec![Memory {
             id: MemoryId::new("test_id"),
@@ -409,6 +447,13 @@ fn test_format_section() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }];
 
         let section = format_section("Test Section", &memories);
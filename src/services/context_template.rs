@@ -43,14 +43,23 @@
 
 use crate::config::SubcogConfig;
 use crate::models::{ContextTemplate, Memory, OutputFormat, VariableType};
-use crate::rendering::{RenderContext, RenderValue, TemplateRenderer};
-use crate::services::MemoryStatistics;
+use crate::rendering::{RenderContext, RenderValue, TemplateRenderer, memory_urn};
+use crate::services::{ContextBuilderService, MemoryStatistics};
 use crate::storage::context_template::{ContextTemplateStorage, ContextTemplateStorageFactory};
 use crate::storage::index::DomainScope;
 use crate::{Error, Result};
 use std::collections::HashMap;
 use std::sync::Arc;
 
+/// Default template for `recall --template-context`: a numbered list that
+/// cites the source of each memory.
+pub const RECALL_CONTEXT_TEMPLATE: &str = concat!(
+    "{{#each memories}}",
+    "{{memory.number}}. {{memory.content}}\n",
+    "   Source: {{memory.source}}\n",
+    "{{/each}}",
+);
+
 /// Filter for listing context templates.
 #[derive(Debug, Clone, Default)]
 pub struct ContextTemplateFilter {
@@ -391,6 +400,55 @@ pub fn render_direct(
         self.renderer.render(template, &context, output_format)
     }
 
+    /// Renders recall results as one context block ready to paste into a prompt.
+    ///
+    /// Uses the stored template `template_name` (latest version), or
+    /// [`RECALL_CONTEXT_TEMPLATE`] when `None`. With `max_tokens`, the
+    /// lowest-ranked memories are dropped until the estimated size of the
+    /// block fits the budget.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the template is not found or rendering fails.
+    pub fn render_recall_context(
+        &mut self,
+        template_name: Option<&str>,
+        memories: &[Memory],
+        max_tokens: Option<usize>,
+    ) -> Result<String> {
+        let template = match template_name {
+            Some(name) => self
+                .get(name, None, None)?
+                .ok_or_else(|| Error::InvalidInput(format!("Template not found: {name}")))?,
+            None => ContextTemplate::new("recall-context", RECALL_CONTEXT_TEMPLATE),
+        };
+
+        let custom_vars = HashMap::new();
+        let mut count = memories.len();
+        loop {
+            let included = &memories[..count];
+            let mut statistics = MemoryStatistics {
+                total_count: included.len(),
+                ..MemoryStatistics::default()
+            };
+            for memory in included {
+                *statistics
+                    .namespace_counts
+                    .entry(memory.namespace.as_str().to_string())
+                    .or_insert(0) += 1;
+            }
+
+            let output =
+                self.render_direct(&template, included, &statistics, &custom_vars, None)?;
+            let fits = max_tokens
+                .is_none_or(|budget| ContextBuilderService::estimate_tokens(&output) <= budget);
+            if fits || count == 0 {
+                return Ok(output);
+            }
+            count -= 1;
+        }
+    }
+
     /// Builds a render context with auto-variables populated.
     fn build_render_context(
         &self,
@@ -426,25 +484,28 @@ fn build_render_context(
         );
         context.set("statistics", RenderValue::String(stats_str));
 
-        // Add memories as iterable list
+        // Add memories as iterable list (fields are referenced as {{memory.<field>}})
         let memory_list: Vec<HashMap<String, String>> = memories
             .iter()
             .enumerate()
             .map(|(idx, m)| {
                 let mut map = HashMap::new();
-                map.insert("memory.id".to_string(), m.id.as_str().to_string());
-                map.insert("memory.content".to_string(), m.content.clone());
+                map.insert("id".to_string(), m.id.as_str().to_string());
+                map.insert("number".to_string(), (idx + 1).to_string());
+                map.insert("content".to_string(), m.content.clone());
+                map.insert("namespace".to_string(), m.namespace.as_str().to_string());
+                map.insert("tags".to_string(), m.tags.join(", "));
+                map.insert("domain".to_string(), m.domain.to_string());
+                // Cite the recorded source, or the memory itself without one
                 map.insert(
-                    "memory.namespace".to_string(),
-                    m.namespace.as_str().to_string(),
+                    "source".to_string(),
+                    m.source.clone().unwrap_or_else(|| memory_urn(m)),
                 );
-                map.insert("memory.tags".to_string(), m.tags.join(", "));
-                map.insert("memory.domain".to_string(), m.domain.to_string());
-                map.insert("memory.created_at".to_string(), m.created_at.to_string());
-                map.insert("memory.updated_at".to_string(), m.updated_at.to_string());
+                map.insert("created_at".to_string(), m.created_at.to_string());
+                map.insert("updated_at".to_string(), m.updated_at.to_string());
                 // Score is not part of Memory, use index as placeholder
                 map.insert(
-                    "memory.score".to_string(),
+                    "score".to_string(),
                     format!("{:.2}", (idx as f64).mul_add(-0.01, 1.0)),
                 );
                 map
@@ -631,7 +692,7 @@ fn test_context_template_filter_builder() {
 
     #[test]
     fn test_build_render_context() {
-        use crate::models::{Domain, MemoryId, MemoryStatus, Namespace};
+        use crate::models::{CaptureOrigin, Domain, MemoryId, MemoryStatus, Namespace};
 
         let service = ContextTemplateService::new();
         let template = ContextTemplate::new("test", "{{total_count}} memories");
@@ -657,6 +718,13 @@ fn test_build_render_context() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }];
 
         let mut namespace_counts = HashMap::new();
@@ -678,6 +746,70 @@ fn test_build_render_context() {
         assert!(context.get("memories").is_some());
     }
 
+    fn test_memory(id: &str, content: &str, source: Option<&str>) -> Memory {
+        use crate::models::{CaptureOrigin, Domain, MemoryId, MemoryStatus, Namespace};
+
+        Memory {
+            id: MemoryId::new(id),
+            content: content.to_string(),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 0,
+            updated_at: 0,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: source.map(String::from),
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_render_recall_context_cites_sources_within_budget() {
+        let mut service = ContextTemplateService::new();
+        let memories: Vec<Memory> = (1..=3)
+            .map(|n| {
+                let content = format!("Decision {n}: {}", "use SQLite for the index. ".repeat(8));
+                let source = (n != 2).then(|| format!("docs/adr/000{n}.md"));
+                test_memory(&format!("mem-{n}"), &content, source.as_deref())
+            })
+            .collect();
+
+        let block = service
+            .render_recall_context(None, &memories, None)
+            .unwrap();
+        assert!(block.starts_with("1. Decision 1:"));
+        assert!(block.contains("   Source: docs/adr/0001.md\n"));
+        // Without a recorded source the memory itself is cited
+        assert!(block.contains("   Source: subcog://"));
+        assert!(block.contains("/decisions/mem-2\n"));
+        assert!(block.contains("3. Decision 3:"));
+
+        let budget = ContextBuilderService::estimate_tokens(&block) / 2;
+        let trimmed = service
+            .render_recall_context(None, &memories, Some(budget))
+            .unwrap();
+        assert!(ContextBuilderService::estimate_tokens(&trimmed) <= budget);
+        assert!(trimmed.contains("1. Decision 1:"));
+        assert!(!trimmed.contains("Decision 3:"));
+    }
+
     #[test]
     fn test_validate_template() {
         let service = ContextTemplateService::new();
@@ -812,7 +812,7 @@ fn record_metrics(operation: &str, start: Instant, success_count: usize, failure
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, MemoryStatus, Namespace};
+    use crate::models::{CaptureOrigin, Domain, MemoryStatus, Namespace};
     use crate::storage::index::SqliteBackend;
     use crate::storage::traits::IndexBackend;
 
@@ -842,6 +842,13 @@ fn create_test_memory(id: &str, content: &str, namespace: Namespace) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -42,11 +42,59 @@
 //! | Duplicate content still captured | Raise threshold (e.g., 0.95) |
 //! | Short content triggers false positives | Increase `min_semantic_length` |
 //! | Same content captured repeatedly in session | Extend `recent_window` |
+//!
+//! # Similarity Metric
+//!
+//! Thresholds are compared against the cosine score from the vector backend by
+//! default. With [`SimilarityMetric::Dot`] the checker instead compares the raw
+//! dot product of the new and stored embeddings, which also accounts for vector
+//! magnitude when the embedder does not normalize its output.
 
 use crate::models::Namespace;
 use std::collections::HashMap;
+use std::fmt;
 use std::time::Duration;
 
+/// Similarity metric used by the semantic deduplication tier.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum SimilarityMetric {
+    /// Cosine similarity as scored by the vector backend (0.0 to 1.0).
+    #[default]
+    Cosine,
+    /// Dot product of the new and stored embeddings.
+    ///
+    /// Requires a vector backend that returns stored embeddings; candidates
+    /// whose embedding is unavailable are not flagged.
+    Dot,
+}
+
+impl SimilarityMetric {
+    /// Returns the metric name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Cosine => "cosine",
+            Self::Dot => "dot",
+        }
+    }
+
+    /// Parses a metric name (case-insensitive).
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Some(Self::Cosine),
+            "dot" | "dot_product" => Some(Self::Dot),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for SimilarityMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Configuration for the deduplication service.
 ///
 /// # Environment Variables
@@ -58,6 +106,7 @@
 /// | `SUBCOG_DEDUP_THRESHOLD_PATTERNS` | f32 | `0.90` | Threshold for patterns namespace |
 /// | `SUBCOG_DEDUP_THRESHOLD_LEARNINGS` | f32 | `0.88` | Threshold for learnings namespace |
 /// | `SUBCOG_DEDUP_THRESHOLD_DEFAULT` | f32 | `0.90` | Default threshold |
+/// | `SUBCOG_DEDUP_METRIC` | string | `cosine` | Similarity metric (`cosine` or `dot`) |
 /// | `SUBCOG_DEDUP_TIME_WINDOW_SECS` | u64 | `300` | Recent capture window |
 /// | `SUBCOG_DEDUP_CACHE_CAPACITY` | usize | `1000` | LRU cache size |
 /// | `SUBCOG_DEDUP_MIN_SEMANTIC_LENGTH` | usize | `50` | Min content length for semantic check |
@@ -84,6 +133,9 @@ pub struct DeduplicationConfig {
     /// Default threshold when namespace not configured.
     pub default_threshold: f32,
 
+    /// Similarity metric the thresholds are compared against.
+    pub metric: SimilarityMetric,
+
     /// Recent capture time window.
     pub recent_window: Duration,
 
@@ -121,6 +173,11 @@ pub fn from_env() -> Self {
             .and_then(|v| v.parse().ok())
             .unwrap_or(0.90);
 
+        let metric = std::env::var("SUBCOG_DEDUP_METRIC")
+            .ok()
+            .and_then(|v| SimilarityMetric::parse(&v))
+            .unwrap_or_default();
+
         let recent_window_secs = std::env::var("SUBCOG_DEDUP_TIME_WINDOW_SECS")
             .ok()
             .and_then(|v| v.parse().ok())
@@ -185,6 +242,7 @@ pub fn from_env() -> Self {
             enabled,
             similarity_thresholds: thresholds,
             default_threshold,
+            metric,
             recent_window: Duration::from_secs(recent_window_secs),
             cache_capacity,
             min_semantic_length,
@@ -239,6 +297,13 @@ pub const fn with_default_threshold(mut self, threshold: f32) -> Self {
         self
     }
 
+    /// Builder method to set the similarity metric.
+    #[must_use]
+    pub const fn with_metric(mut self, metric: SimilarityMetric) -> Self {
+        self.metric = metric;
+        self
+    }
+
     /// Builder method to set the recent window duration.
     #[must_use]
     pub const fn with_recent_window(mut self, duration: Duration) -> Self {
@@ -274,6 +339,7 @@ fn default() -> Self {
             enabled: true,
             similarity_thresholds: thresholds,
             default_threshold: 0.90,
+            metric: SimilarityMetric::Cosine,
             recent_window: Duration::from_secs(300), // 5 minutes
             cache_capacity: 1000,
             min_semantic_length: 50,
@@ -332,4 +398,19 @@ fn test_builder_methods() {
         assert_eq!(config.cache_capacity, 500);
         assert_eq!(config.min_semantic_length, 100);
     }
+
+    #[test]
+    fn test_similarity_metric_parse() {
+        assert_eq!(SimilarityMetric::parse("Dot"), Some(SimilarityMetric::Dot));
+        assert_eq!(
+            SimilarityMetric::parse("cosine"),
+            Some(SimilarityMetric::Cosine)
+        );
+        assert_eq!(SimilarityMetric::parse("euclid"), None);
+        assert_eq!(
+            DeduplicationConfig::default().metric,
+            SimilarityMetric::Cosine
+        );
+        assert_eq!(SimilarityMetric::Dot.to_string(), "dot");
+    }
 }
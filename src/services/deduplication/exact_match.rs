@@ -4,14 +4,12 @@
 //! Uses `hash:sha256:<prefix>` tag format for efficient lookup.
 
 use crate::Result;
-use crate::models::{MemoryId, Namespace, SearchFilter};
+use crate::models::{ContentHasher, MemoryId, Namespace, SearchFilter};
 use crate::services::recall::RecallService;
 use std::sync::Arc;
 use std::time::Instant;
 use tracing::instrument;
 
-use super::hasher::ContentHasher;
-
 /// Checker for exact content match via SHA256 hash.
 ///
 /// # How it works
@@ -159,7 +157,7 @@ pub fn content_to_tag(content: &str) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, Memory, MemoryStatus};
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryStatus};
     use crate::storage::index::SqliteBackend;
     use crate::storage::traits::IndexBackend;
 
@@ -190,6 +188,13 @@ fn create_test_memory(
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
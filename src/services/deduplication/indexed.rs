@@ -0,0 +1,334 @@
+//! Index-backed deduplicator.
+//!
+//! Looks up exact matches in the index's `content_hash` column instead of an
+//! in-process cache, so a capture made by another process (a hook, the MCP
+//! server, a second CLI) is detected as soon as it is indexed. Semantic
+//! matches come from the vector backend; the recent-capture LRU is an
+//! optional pre-filter that only saves the index lookup.
+
+use crate::Result;
+use crate::embedding::Embedder;
+use crate::models::{ContentHasher, Domain, MemoryId, Namespace};
+use crate::storage::traits::{IndexBackend, VectorBackend};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::instrument;
+
+use super::config::DeduplicationConfig;
+use super::recent::RecentCaptureChecker;
+use super::semantic::SemanticSimilarityChecker;
+use super::types::{Deduplicator, DuplicateCheckResult};
+
+/// Semantic checker over the shared embedder and vector backend.
+type SharedSemanticChecker =
+    SemanticSimilarityChecker<dyn Embedder, dyn VectorBackend + Send + Sync>;
+
+/// Deduplicator that checks the persisted index rather than process state.
+///
+/// Checks, in order, stopping at the first match:
+/// 1. Recent capture (optional) - in-memory LRU, this process only
+/// 2. Exact match - content hash lookup in the index
+/// 3. Semantic similarity (optional) - vector search
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use subcog::services::deduplication::{DeduplicationConfig, IndexDeduplicator};
+///
+/// let dedup = IndexDeduplicator::new(index, DeduplicationConfig::from_env())
+///     .with_semantic(embedder, vector)
+///     .with_recent_cache();
+///
+/// let result = dedup.check("Use PostgreSQL for primary storage", Namespace::Decisions)?;
+/// ```
+pub struct IndexDeduplicator {
+    /// Configuration.
+    config: DeduplicationConfig,
+    /// Index holding the content hashes.
+    index: Arc<dyn IndexBackend + Send + Sync>,
+    /// Semantic similarity checker (optional).
+    semantic: Option<SharedSemanticChecker>,
+    /// Recent capture pre-filter (optional).
+    recent: Option<RecentCaptureChecker>,
+    /// Domain for URN construction.
+    domain: Domain,
+}
+
+impl IndexDeduplicator {
+    /// Creates a deduplicator doing exact matching against `index`.
+    #[must_use]
+    pub fn new(index: Arc<dyn IndexBackend + Send + Sync>, config: DeduplicationConfig) -> Self {
+        Self {
+            config,
+            index,
+            semantic: None,
+            recent: None,
+            domain: Domain::new(),
+        }
+    }
+
+    /// Enables semantic matching with the given embedder and vector backend.
+    #[must_use]
+    pub fn with_semantic(
+        mut self,
+        embedder: Arc<dyn Embedder>,
+        vector: Arc<dyn VectorBackend + Send + Sync>,
+    ) -> Self {
+        self.semantic = Some(SemanticSimilarityChecker::new(
+            embedder,
+            vector,
+            self.config.clone(),
+        ));
+        self
+    }
+
+    /// Enables the in-memory recent-capture pre-filter, sized by the
+    /// configured cache capacity and time window.
+    #[must_use]
+    pub fn with_recent_cache(mut self) -> Self {
+        self.recent = Some(RecentCaptureChecker::new(
+            self.config.cache_capacity,
+            self.config.recent_window,
+        ));
+        self
+    }
+
+    /// Sets the domain for URN construction.
+    #[must_use]
+    pub fn with_domain(mut self, domain: Domain) -> Self {
+        self.domain = domain;
+        self
+    }
+
+    /// Returns true if deduplication is enabled.
+    #[must_use]
+    pub const fn is_enabled(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Checks if content duplicates a memory in `namespace`.
+    ///
+    /// A failing index or semantic lookup is logged and skipped, so a broken
+    /// backend never blocks a capture.
+    ///
+    /// # Errors
+    ///
+    /// Currently infallible; the `Result` matches [`Deduplicator`].
+    #[allow(clippy::cast_possible_truncation)] // Duration in ms won't exceed u64::MAX
+    #[instrument(
+        skip(self, content),
+        fields(
+            operation = "dedup_check",
+            namespace = %namespace.as_str(),
+            content_length = content.len()
+        )
+    )]
+    pub fn check(&self, content: &str, namespace: Namespace) -> Result<DuplicateCheckResult> {
+        let start = Instant::now();
+        let elapsed_ms = || start.elapsed().as_millis() as u64;
+        if !self.config.enabled {
+            return Ok(DuplicateCheckResult::not_duplicate(elapsed_ms()));
+        }
+        let domain = self.domain.to_string();
+
+        if let Some((memory_id, urn)) = self
+            .recent
+            .as_ref()
+            .and_then(|recent| recent.check(content, namespace))
+        {
+            record_duplicate(namespace, "recent_capture");
+            return Ok(DuplicateCheckResult::recent_capture(
+                memory_id,
+                urn,
+                elapsed_ms(),
+            ));
+        }
+
+        let hash = ContentHasher::hash(content);
+        match self.index.find_by_content_hash(namespace, &hash) {
+            Ok(Some(memory_id)) => {
+                let urn = format!("subcog://{domain}/{}/{memory_id}", namespace.as_str());
+                tracing::info!(memory_id = %memory_id, urn = %urn, "Exact match duplicate found");
+                record_duplicate(namespace, "exact_match");
+                return Ok(DuplicateCheckResult::exact_match(
+                    memory_id,
+                    urn,
+                    elapsed_ms(),
+                ));
+            },
+            Ok(None) => {},
+            Err(e) => tracing::warn!(error = %e, "Content hash lookup failed, continuing"),
+        }
+
+        if let Some(ref semantic) = self.semantic {
+            match semantic.check(content, namespace, &domain) {
+                Ok(Some((memory_id, urn, score))) => {
+                    tracing::info!(
+                        memory_id = %memory_id,
+                        urn = %urn,
+                        score = score,
+                        "Semantic similarity duplicate found"
+                    );
+                    record_duplicate(namespace, "semantic_similar");
+                    return Ok(DuplicateCheckResult::semantic_match(
+                        memory_id,
+                        urn,
+                        score,
+                        elapsed_ms(),
+                    ));
+                },
+                Ok(None) => {},
+                Err(e) => tracing::warn!(error = %e, "Semantic check failed, continuing"),
+            }
+        }
+
+        metrics::counter!(
+            "deduplication_checks_total",
+            "namespace" => namespace.as_str().to_string(),
+            "result" => "unique"
+        )
+        .increment(1);
+        Ok(DuplicateCheckResult::not_duplicate(elapsed_ms()))
+    }
+
+    /// Records a capture in the recent-capture pre-filter, if enabled.
+    ///
+    /// The index needs no recording: the content hash is stored when the
+    /// memory is indexed.
+    pub fn record_capture(&self, content: &str, memory_id: &MemoryId, namespace: Namespace) {
+        if let Some(ref recent) = self.recent {
+            recent.record(content, memory_id, namespace, &self.domain.to_string());
+        }
+    }
+}
+
+impl Deduplicator for IndexDeduplicator {
+    fn check_duplicate(&self, content: &str, namespace: Namespace) -> Result<DuplicateCheckResult> {
+        self.check(content, namespace)
+    }
+
+    fn record_capture(&self, content_hash: &str, memory_id: &MemoryId) {
+        // As in DeduplicationService, the trait interface has no namespace
+        if let Some(ref recent) = self.recent {
+            recent.record_by_hash(
+                content_hash,
+                memory_id,
+                Namespace::Decisions,
+                &self.domain.to_string(),
+            );
+        }
+    }
+}
+
+/// Counts a duplicate found by `reason`.
+fn record_duplicate(namespace: Namespace, reason: &'static str) {
+    metrics::counter!(
+        "deduplication_duplicates_total",
+        "namespace" => namespace.as_str().to_string(),
+        "reason" => reason
+    )
+    .increment(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Memory, MemoryStatus};
+    use crate::services::deduplication::DuplicateReason;
+    use crate::storage::index::SqliteBackend;
+    use tempfile::TempDir;
+
+    fn memory(id: &str, content: &str, namespace: Namespace) -> Memory {
+        Memory {
+            id: MemoryId::new(id),
+            content: content.to_string(),
+            namespace,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1_234_567_890,
+            updated_at: 1_234_567_890,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_detects_capture_from_another_process() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("index.db");
+
+        // "Process" A indexes a memory through its own connection
+        let writer = SqliteBackend::new(&path).unwrap();
+        writer
+            .index(&memory(
+                "m1",
+                "Use PostgreSQL for storage",
+                Namespace::Decisions,
+            ))
+            .unwrap();
+
+        // "Process" B has an empty LRU and its own connection
+        let reader: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::new(&path).unwrap());
+        let dedup =
+            IndexDeduplicator::new(reader, DeduplicationConfig::default()).with_recent_cache();
+
+        let result = dedup
+            .check("  use postgresql   for storage ", Namespace::Decisions)
+            .unwrap();
+        assert!(result.is_duplicate);
+        assert_eq!(result.reason, Some(DuplicateReason::ExactMatch));
+        assert_eq!(result.matched_memory_id, Some(MemoryId::new("m1")));
+
+        // Other namespaces and tombstoned memories don't match
+        let other = dedup
+            .check("Use PostgreSQL for storage", Namespace::Learnings)
+            .unwrap();
+        assert!(!other.is_duplicate);
+        let mut tombstoned = memory("m1", "Use PostgreSQL for storage", Namespace::Decisions);
+        tombstoned.status = MemoryStatus::Tombstoned;
+        writer.index(&tombstoned).unwrap();
+        let gone = dedup
+            .check("Use PostgreSQL for storage", Namespace::Decisions)
+            .unwrap();
+        assert!(!gone.is_duplicate);
+    }
+
+    #[test]
+    fn test_recent_cache_is_only_a_pre_filter() {
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        let dedup = IndexDeduplicator::new(Arc::clone(&index), DeduplicationConfig::default())
+            .with_recent_cache();
+
+        dedup.record_capture("Cache hit", &MemoryId::new("r1"), Namespace::Patterns);
+        let cached = dedup.check("Cache hit", Namespace::Patterns).unwrap();
+        assert_eq!(cached.reason, Some(DuplicateReason::RecentCapture));
+
+        // Without the cache the persisted hash is still found
+        index
+            .index(&memory("p1", "Indexed only", Namespace::Patterns))
+            .unwrap();
+        let uncached = IndexDeduplicator::new(index, DeduplicationConfig::default());
+        let result = uncached.check("Indexed only", Namespace::Patterns).unwrap();
+        assert_eq!(result.reason, Some(DuplicateReason::ExactMatch));
+    }
+}
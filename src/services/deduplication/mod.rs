@@ -7,6 +7,11 @@
 //!
 //! The service implements short-circuit evaluation, exiting early on first match.
 //!
+//! [`IndexDeduplicator`] is the default used by capture and the pre-compact
+//! hook: it looks up exact matches in the index's `content_hash` column, so
+//! captures from other processes are detected, and keeps the LRU only as an
+//! optional pre-filter.
+//!
 //! # Architecture
 //!
 //! ```text
@@ -38,14 +43,15 @@
 
 mod config;
 mod exact_match;
-mod hasher;
+mod indexed;
 mod recent;
 mod semantic;
 mod service;
 mod types;
 
 // Public API: Only expose what users need to interact with the service
-pub use config::DeduplicationConfig;
-pub use hasher::ContentHasher;
+pub use crate::models::ContentHasher;
+pub use config::{DeduplicationConfig, SimilarityMetric};
+pub use indexed::IndexDeduplicator;
 pub use service::DeduplicationService;
 pub use types::{Deduplicator, DuplicateCheckResult, DuplicateReason};
@@ -3,15 +3,13 @@
 //! Detects duplicates by tracking recently captured content hashes
 //! in an in-memory LRU cache with TTL-based expiration.
 
-use crate::models::{MemoryId, Namespace};
+use crate::models::{ContentHasher, MemoryId, Namespace};
 use lru::LruCache;
 use std::num::NonZeroUsize;
 use std::sync::RwLock;
 use std::time::{Duration, Instant};
 use tracing::instrument;
 
-use super::hasher::ContentHasher;
-
 /// Entry in the recent capture cache.
 #[derive(Debug, Clone)]
 struct CacheEntry {
@@ -1,7 +1,7 @@
 //! Semantic similarity deduplication checker.
 //!
-//! Detects duplicates by comparing embedding vectors using cosine similarity.
-//! Uses configurable per-namespace similarity thresholds.
+//! Detects duplicates by comparing embedding vectors using cosine similarity
+//! or dot product. Uses configurable per-namespace similarity thresholds.
 
 use crate::Result;
 use crate::embedding::Embedder;
@@ -11,7 +11,7 @@
 use std::time::Instant;
 use tracing::instrument;
 
-use super::config::DeduplicationConfig;
+use super::config::{DeduplicationConfig, SimilarityMetric};
 
 // ============================================================================
 // Trait Aliases (RUST-M1)
@@ -30,7 +30,7 @@
 pub trait ThreadSafeEmbedder: Embedder + Send + Sync {}
 
 /// Blanket implementation for all thread-safe embedders.
-impl<T: Embedder + Send + Sync> ThreadSafeEmbedder for T {}
+impl<T: Embedder + Send + Sync + ?Sized> ThreadSafeEmbedder for T {}
 
 /// Thread-safe vector backend.
 ///
@@ -43,7 +43,7 @@ impl<T: Embedder + Send + Sync> ThreadSafeEmbedder for T {}
 pub trait ThreadSafeVectorBackend: VectorBackend + Send + Sync {}
 
 /// Blanket implementation for all thread-safe vector backends.
-impl<T: VectorBackend + Send + Sync> ThreadSafeVectorBackend for T {}
+impl<T: VectorBackend + Send + Sync + ?Sized> ThreadSafeVectorBackend for T {}
 
 /// Checker for semantic similarity using embeddings.
 ///
@@ -51,7 +51,9 @@ impl<T: VectorBackend + Send + Sync> ThreadSafeVectorBackend for T {}
 ///
 /// 1. Generates embedding for the new content using the configured embedder
 /// 2. Searches the vector index for similar embeddings
-/// 3. Compares similarity scores against namespace-specific thresholds
+/// 3. Scores candidates with the configured metric (the backend's cosine score,
+///    or the dot product with the stored embedding) against namespace-specific
+///    thresholds
 /// 4. Returns the first match that exceeds the threshold
 ///
 /// # Thresholds
@@ -80,7 +82,10 @@ impl<T: VectorBackend + Send + Sync> ThreadSafeVectorBackend for T {}
 ///     println!("Semantic match found: {} (score: {:.2})", urn, score);
 /// }
 /// ```
-pub struct SemanticSimilarityChecker<E: ThreadSafeEmbedder, V: ThreadSafeVectorBackend> {
+pub struct SemanticSimilarityChecker<
+    E: ThreadSafeEmbedder + ?Sized,
+    V: ThreadSafeVectorBackend + ?Sized,
+> {
     /// Embedder for generating vectors.
     embedder: Arc<E>,
     /// Vector backend for similarity search.
@@ -89,7 +94,9 @@ pub struct SemanticSimilarityChecker<E: ThreadSafeEmbedder, V: ThreadSafeVectorB
     config: DeduplicationConfig,
 }
 
-impl<E: ThreadSafeEmbedder, V: ThreadSafeVectorBackend> SemanticSimilarityChecker<E, V> {
+impl<E: ThreadSafeEmbedder + ?Sized, V: ThreadSafeVectorBackend + ?Sized>
+    SemanticSimilarityChecker<E, V>
+{
     /// Creates a new semantic similarity checker.
     ///
     /// # Arguments
@@ -163,8 +170,11 @@ pub fn check(
         // Get threshold for this namespace
         let threshold = self.config.get_threshold(namespace);
 
+        let metric = self.config.metric;
+
         tracing::debug!(
             threshold = threshold,
+            metric = %metric,
             namespace = %namespace.as_str(),
             "Checking semantic similarity"
         );
@@ -184,7 +194,20 @@ pub fn check(
         let duration_ms = start.elapsed().as_millis();
 
         // Find first result above threshold
-        for (memory_id, score) in results {
+        for (memory_id, cosine) in results {
+            let score = match metric {
+                SimilarityMetric::Cosine => cosine,
+                SimilarityMetric::Dot => {
+                    let Some(stored) = self.vector.get_embedding(&memory_id)? else {
+                        tracing::debug!(
+                            memory_id = %memory_id,
+                            "Stored embedding unavailable, skipping dot product"
+                        );
+                        continue;
+                    };
+                    dot_product(&embedding, &stored)
+                },
+            };
             if score >= threshold {
                 let urn = format!("subcog://{}/{}/{}", domain, namespace.as_str(), memory_id);
 
@@ -257,6 +280,14 @@ pub fn get_threshold(&self, namespace: Namespace) -> f32 {
     }
 }
 
+/// Computes the dot product of two vectors (0.0 for different dimensions).
+fn dot_product(a: &[f32], b: &[f32]) -> f32 {
+    if a.len() != b.len() {
+        return 0.0;
+    }
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -356,6 +387,10 @@ fn search(
                 .search(query_embedding, filter, limit)
         }
 
+        fn get_embedding(&self, id: &MemoryId) -> Result<Option<Vec<f32>>> {
+            self.inner.read().unwrap().get_embedding(id)
+        }
+
         fn count(&self) -> Result<usize> {
             self.inner.read().unwrap().count()
         }
@@ -492,6 +527,78 @@ fn test_check_below_threshold() {
         }
     }
 
+    /// Embedder returning the same vector for any text.
+    struct FixedEmbedder(Vec<f32>);
+
+    impl Embedder for FixedEmbedder {
+        fn dimensions(&self) -> usize {
+            self.0.len()
+        }
+
+        fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(self.0.clone())
+        }
+    }
+
+    /// Creates a checker whose query embedding is `[0.8, 0.6]` (cosine 0.8
+    /// with the stored vector), with `stored` indexed as "existing".
+    fn fixed_checker(
+        stored: &[f32],
+        config: DeduplicationConfig,
+    ) -> SemanticSimilarityChecker<FixedEmbedder, RwLockWrapper> {
+        let vector = Arc::new(RwLockWrapper::new(create_usearch_backend(2)));
+        vector.upsert(&MemoryId::new("existing"), stored).unwrap();
+        let embedder = Arc::new(FixedEmbedder(vec![0.8, 0.6]));
+        SemanticSimilarityChecker::new(embedder, vector, config.with_min_semantic_length(0))
+    }
+
+    #[test]
+    fn test_check_uses_namespace_threshold() {
+        let config = DeduplicationConfig::default()
+            .with_threshold(Namespace::Learnings, 0.75)
+            .with_threshold(Namespace::Decisions, 0.95);
+        let checker = fixed_checker(&[1.0, 0.0], config);
+
+        let loose = checker
+            .check("note", Namespace::Learnings, "project")
+            .unwrap();
+        assert_eq!(loose.map(|(id, _, _)| id), Some(MemoryId::new("existing")));
+
+        let strict = checker
+            .check("note", Namespace::Decisions, "project")
+            .unwrap();
+        assert!(strict.is_none());
+    }
+
+    #[test]
+    fn test_check_with_dot_metric() {
+        // Dot product with the unnormalized stored vector is 1.6
+        let config = DeduplicationConfig::default()
+            .with_threshold(Namespace::Learnings, 1.5)
+            .with_threshold(Namespace::Decisions, 1.7);
+
+        let dot = fixed_checker(
+            &[2.0, 0.0],
+            config.clone().with_metric(SimilarityMetric::Dot),
+        );
+        let loose = dot.check("note", Namespace::Learnings, "project").unwrap();
+        assert!((loose.expect("duplicate").2 - 1.6).abs() < 1e-5);
+        assert!(
+            dot.check("note", Namespace::Decisions, "project")
+                .unwrap()
+                .is_none()
+        );
+
+        // Cosine scores never reach 1.5
+        let cosine = fixed_checker(&[2.0, 0.0], config);
+        assert!(
+            cosine
+                .check("note", Namespace::Learnings, "project")
+                .unwrap()
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_get_threshold() {
         let checker = create_test_checker();
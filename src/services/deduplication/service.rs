@@ -9,7 +9,7 @@
 
 use crate::Result;
 use crate::embedding::Embedder;
-use crate::models::{Domain, MemoryId, Namespace};
+use crate::models::{ContentHasher, Domain, MemoryId, Namespace};
 use crate::services::recall::RecallService;
 use crate::storage::traits::VectorBackend;
 use std::sync::Arc;
@@ -18,7 +18,6 @@
 
 use super::config::DeduplicationConfig;
 use super::exact_match::ExactMatchChecker;
-use super::hasher::ContentHasher;
 use super::recent::RecentCaptureChecker;
 use super::semantic::SemanticSimilarityChecker;
 use super::types::{Deduplicator, DuplicateCheckResult};
@@ -425,7 +424,7 @@ fn record_capture(&self, content_hash: &str, memory_id: &MemoryId) {
 mod tests {
     use super::*;
     use crate::embedding::FastEmbedEmbedder;
-    use crate::models::{Memory, MemoryStatus};
+    use crate::models::{CaptureOrigin, Memory, MemoryStatus};
     use crate::storage::index::SqliteBackend;
     use crate::storage::traits::IndexBackend;
     use crate::storage::vector::UsearchBackend;
@@ -531,6 +530,13 @@ fn create_test_memory(
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -0,0 +1,472 @@
+//! Digests of recently captured memories (`subcog digest`).
+//!
+//! [`DigestService`] lists the memories created since a point in time with
+//! recall's time filter and condenses them into a [`MemoryDigest`]: counts per
+//! namespace, the most used tags, and notable captures (pinned memories,
+//! decisions and blockers). With an LLM the digest also gets a short prose
+//! summary; summarization is best-effort and a failed call leaves it out.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::services::DigestService;
+//!
+//! let since = subcog::current_timestamp() - 86_400;
+//! let digest = DigestService::new(&recall).generate(since, &SearchFilter::new())?;
+//! println!("{}", digest.to_markdown());
+//! ```
+
+use super::RecallService;
+use crate::Result;
+use crate::llm::LlmProvider;
+use crate::models::{Memory, Namespace, SearchFilter};
+use crate::notifications::Notification;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Default maximum number of memories read for a digest.
+pub const DEFAULT_DIGEST_LIMIT: usize = 1000;
+
+/// Number of tags listed in a digest.
+const TOP_TAGS: usize = 10;
+
+/// Number of notable captures listed in a digest.
+const MAX_NOTABLE: usize = 10;
+
+/// Characters of content shown per notable capture.
+const PREVIEW_CHARS: usize = 100;
+
+/// Characters of content per memory sent to the LLM for summarization.
+const SUMMARY_SNIPPET_CHARS: usize = 300;
+
+/// Maximum number of memories sent to the LLM for summarization.
+const SUMMARY_MAX_MEMORIES: usize = 50;
+
+/// System prompt for digest summaries.
+const SUMMARY_SYSTEM_PROMPT: &str = "You summarize the memories a developer captured \
+recently. Write a short plain-text summary (at most 5 sentences) of what was decided, \
+learned and blocked. Do not invent details.";
+
+/// Number of memories in one namespace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct NamespaceCount {
+    /// Namespace.
+    pub namespace: Namespace,
+    /// Number of memories.
+    pub count: usize,
+}
+
+/// Number of memories with one tag.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct TagCount {
+    /// Tag.
+    pub tag: String,
+    /// Number of memories.
+    pub count: usize,
+}
+
+/// A notable capture listed in a digest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DigestEntry {
+    /// Memory ID.
+    pub id: String,
+    /// Namespace.
+    pub namespace: Namespace,
+    /// Creation time (Unix seconds).
+    pub created_at: u64,
+    /// Whether the memory is pinned.
+    pub pinned: bool,
+    /// Single-line content preview.
+    pub preview: String,
+}
+
+impl DigestEntry {
+    /// Returns the suffix that marks a pinned entry.
+    const fn pin_marker(&self) -> &'static str {
+        if self.pinned { " (pinned)" } else { "" }
+    }
+}
+
+/// Summary of the memories captured in a time window.
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryDigest {
+    /// Start of the window (Unix seconds).
+    pub since: u64,
+    /// When the digest was generated (Unix seconds).
+    pub until: u64,
+    /// Number of memories in the window.
+    pub total: usize,
+    /// Memories per namespace, most first.
+    pub namespaces: Vec<NamespaceCount>,
+    /// Most used tags, most first.
+    pub top_tags: Vec<TagCount>,
+    /// Pinned memories, decisions and blockers, newest first.
+    pub notable: Vec<DigestEntry>,
+    /// LLM-written summary, if requested and available.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// Memories read, kept for summarization.
+    #[serde(skip)]
+    memories: Vec<Memory>,
+}
+
+/// Builds digests of recent memories.
+pub struct DigestService<'a> {
+    /// Recall service listing the memories.
+    recall: &'a RecallService,
+    /// Maximum number of memories read.
+    limit: usize,
+    /// LLM provider for summaries.
+    llm: Option<Arc<dyn LlmProvider>>,
+}
+
+impl<'a> DigestService<'a> {
+    /// Creates a digest service reading memories through `recall`.
+    #[must_use]
+    pub const fn new(recall: &'a RecallService) -> Self {
+        Self {
+            recall,
+            limit: DEFAULT_DIGEST_LIMIT,
+            llm: None,
+        }
+    }
+
+    /// Sets the maximum number of memories read.
+    #[must_use]
+    pub const fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Summarizes digests with `llm`.
+    #[must_use]
+    pub fn with_llm(mut self, llm: Arc<dyn LlmProvider>) -> Self {
+        self.llm = Some(llm);
+        self
+    }
+
+    /// Builds the digest of memories matching `filter` created at or after
+    /// `since` (Unix seconds).
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`RecallService::list_all_with_content`].
+    #[instrument(skip(self, filter), fields(component = "digest"))]
+    pub fn generate(&self, since: u64, filter: &SearchFilter) -> Result<MemoryDigest> {
+        let filter = filter.clone().with_created_after(since);
+        let result = self.recall.list_all_with_content(&filter, self.limit)?;
+        let memories: Vec<Memory> = result.memories.into_iter().map(|hit| hit.memory).collect();
+
+        let mut digest = MemoryDigest::from_memories(since, crate::current_timestamp(), memories);
+        if let Some(llm) = &self.llm {
+            digest.summary = summarize(llm.as_ref(), &digest.memories);
+        }
+        Ok(digest)
+    }
+}
+
+impl MemoryDigest {
+    /// Groups `memories`, newest first, into a digest of the window from
+    /// `since` to `until`.
+    fn from_memories(since: u64, until: u64, memories: Vec<Memory>) -> Self {
+        let mut namespace_counts: HashMap<Namespace, usize> = HashMap::new();
+        let mut tag_counts: HashMap<&str, usize> = HashMap::new();
+        for memory in &memories {
+            *namespace_counts.entry(memory.namespace).or_default() += 1;
+            for tag in &memory.tags {
+                *tag_counts.entry(tag.as_str()).or_default() += 1;
+            }
+        }
+
+        let mut namespaces: Vec<NamespaceCount> = namespace_counts
+            .into_iter()
+            .map(|(namespace, count)| NamespaceCount { namespace, count })
+            .collect();
+        namespaces.sort_by(|a, b| {
+            b.count
+                .cmp(&a.count)
+                .then_with(|| a.namespace.as_str().cmp(b.namespace.as_str()))
+        });
+
+        let mut top_tags: Vec<TagCount> = tag_counts
+            .into_iter()
+            .map(|(tag, count)| TagCount {
+                tag: tag.to_string(),
+                count,
+            })
+            .collect();
+        top_tags.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.tag.cmp(&b.tag)));
+        top_tags.truncate(TOP_TAGS);
+
+        let notable = memories
+            .iter()
+            .filter(|memory| is_notable(memory))
+            .take(MAX_NOTABLE)
+            .map(|memory| DigestEntry {
+                id: memory.id.as_str().to_string(),
+                namespace: memory.namespace,
+                created_at: memory.created_at,
+                pinned: memory.pinned,
+                preview: preview(&memory.content, PREVIEW_CHARS),
+            })
+            .collect();
+
+        Self {
+            since,
+            until,
+            total: memories.len(),
+            namespaces,
+            top_tags,
+            notable,
+            summary: None,
+            memories,
+        }
+    }
+
+    /// Wraps the plain-text digest in a `digest` notification for the
+    /// notifier channels.
+    #[must_use]
+    pub fn to_notification(&self) -> Notification {
+        Notification {
+            event_type: "digest".to_string(),
+            title: format!("Subcog digest: {} memories", self.total),
+            body: self.to_text(),
+            timestamp: self.until,
+        }
+    }
+
+    /// Renders the digest as plain text.
+    #[must_use]
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "Digest: {} memories since {}\n",
+            self.total,
+            format_timestamp(self.since)
+        );
+        if let Some(summary) = &self.summary {
+            let _ = writeln!(out, "\n{summary}");
+        }
+        if !self.namespaces.is_empty() {
+            out.push_str("\nNamespaces:\n");
+            for entry in &self.namespaces {
+                let _ = writeln!(out, "  {:<12} {}", entry.namespace.as_str(), entry.count);
+            }
+        }
+        if !self.top_tags.is_empty() {
+            out.push_str("\nTop tags:\n");
+            for entry in &self.top_tags {
+                let _ = writeln!(out, "  {:<20} {}", entry.tag, entry.count);
+            }
+        }
+        if !self.notable.is_empty() {
+            out.push_str("\nNotable:\n");
+            for entry in &self.notable {
+                let _ = writeln!(
+                    out,
+                    "  [{}] {}{}: {}",
+                    entry.namespace.as_str(),
+                    entry.id,
+                    entry.pin_marker(),
+                    entry.preview
+                );
+            }
+        }
+        out
+    }
+
+    /// Renders the digest as markdown.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        let mut out = format!(
+            "# Memory Digest\n\n{} memories since {}.\n",
+            self.total,
+            format_timestamp(self.since)
+        );
+        if let Some(summary) = &self.summary {
+            let _ = writeln!(out, "\n## Summary\n\n{summary}");
+        }
+        if !self.namespaces.is_empty() {
+            out.push_str("\n## Namespaces\n\n| Namespace | Count |\n|-----------|-------|\n");
+            for entry in &self.namespaces {
+                let _ = writeln!(out, "| {} | {} |", entry.namespace.as_str(), entry.count);
+            }
+        }
+        if !self.top_tags.is_empty() {
+            out.push_str("\n## Top Tags\n\n");
+            for entry in &self.top_tags {
+                let _ = writeln!(out, "- `{}` ({})", entry.tag, entry.count);
+            }
+        }
+        if !self.notable.is_empty() {
+            out.push_str("\n## Notable\n\n");
+            for entry in &self.notable {
+                let _ = writeln!(
+                    out,
+                    "- **{}** `{}`{}: {}",
+                    entry.namespace.as_str(),
+                    entry.id,
+                    entry.pin_marker(),
+                    entry.preview
+                );
+            }
+        }
+        out
+    }
+}
+
+/// Checks if a memory is listed as a notable capture.
+const fn is_notable(memory: &Memory) -> bool {
+    memory.pinned || matches!(memory.namespace, Namespace::Decisions | Namespace::Blockers)
+}
+
+/// Collapses whitespace and truncates content to `max_chars`.
+fn preview(content: &str, max_chars: usize) -> String {
+    let single_line = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if single_line.chars().count() > max_chars {
+        let truncated: String = single_line.chars().take(max_chars).collect();
+        format!("{truncated}...")
+    } else {
+        single_line
+    }
+}
+
+/// Formats a Unix timestamp as RFC 3339.
+fn format_timestamp(ts: u64) -> String {
+    chrono::DateTime::from_timestamp(i64::try_from(ts).unwrap_or(0), 0)
+        .map_or_else(|| ts.to_string(), |dt| dt.to_rfc3339())
+}
+
+/// Asks the LLM to summarize `memories`; returns `None` on failure.
+fn summarize(llm: &dyn LlmProvider, memories: &[Memory]) -> Option<String> {
+    if memories.is_empty() {
+        return None;
+    }
+    let mut prompt = String::from("Recent memories:\n");
+    for memory in memories.iter().take(SUMMARY_MAX_MEMORIES) {
+        let _ = writeln!(
+            prompt,
+            "- [{}] {}",
+            memory.namespace.as_str(),
+            preview(&memory.content, SUMMARY_SNIPPET_CHARS)
+        );
+    }
+
+    match llm.complete_with_system(SUMMARY_SYSTEM_PROMPT, &prompt) {
+        Ok(summary) if !summary.trim().is_empty() => Some(summary.trim().to_string()),
+        Ok(_) => None,
+        Err(e) => {
+            tracing::warn!(error = %e, "Digest summary failed, omitting it");
+            None
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Domain, MemoryId, MemoryStatus};
+    use crate::storage::index::SqliteBackend;
+    use crate::storage::traits::IndexBackend;
+
+    fn memory(id: &str, namespace: Namespace, created_at: u64, tags: &[&str]) -> Memory {
+        Memory {
+            id: MemoryId::new(id),
+            content: format!("Memory {id}"),
+            namespace,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at,
+            updated_at: created_at,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: tags.iter().map(ToString::to_string).collect(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    fn recall() -> RecallService {
+        let index = SqliteBackend::in_memory().unwrap();
+        let mut pinned = memory("p1", Namespace::Patterns, 1_900, &["cache"]);
+        pinned.pinned = true;
+        for memory in [
+            memory("old", Namespace::Decisions, 500, &["storage"]),
+            memory("d1", Namespace::Decisions, 1_500, &["storage", "db"]),
+            memory("d2", Namespace::Decisions, 1_600, &["storage"]),
+            memory("l1", Namespace::Learnings, 1_700, &[]),
+            pinned,
+        ] {
+            index.index(&memory).unwrap();
+        }
+        RecallService::with_index(index)
+    }
+
+    #[test]
+    fn test_digest_groups_recent_captures() {
+        let recall = recall();
+
+        let digest = DigestService::new(&recall)
+            .generate(1_000, &SearchFilter::new())
+            .unwrap();
+
+        assert_eq!(digest.total, 4);
+        assert_eq!(
+            digest.namespaces,
+            vec![
+                NamespaceCount {
+                    namespace: Namespace::Decisions,
+                    count: 2
+                },
+                NamespaceCount {
+                    namespace: Namespace::Learnings,
+                    count: 1
+                },
+                NamespaceCount {
+                    namespace: Namespace::Patterns,
+                    count: 1
+                },
+            ]
+        );
+        assert_eq!(
+            digest.top_tags.first(),
+            Some(&TagCount {
+                tag: "storage".to_string(),
+                count: 2
+            })
+        );
+        let notable: Vec<&str> = digest.notable.iter().map(|e| e.id.as_str()).collect();
+        assert_eq!(notable, vec!["p1", "d2", "d1"]);
+    }
+
+    #[test]
+    fn test_digest_respects_since_and_namespace() {
+        let recall = recall();
+        let filter = SearchFilter::new().with_namespace(Namespace::Decisions);
+
+        let digest = DigestService::new(&recall)
+            .generate(1_550, &filter)
+            .unwrap();
+
+        assert_eq!(digest.total, 1);
+        assert_eq!(digest.notable[0].id, "d2");
+        assert!(digest.to_markdown().contains("| decisions | 1 |"));
+    }
+}
@@ -0,0 +1,221 @@
+//! Diversity re-ranking of recall results.
+//!
+//! Hybrid retrieval often returns several near-duplicates of the same memory,
+//! crowding out other context. [`DiversityService`] re-ranks the candidates
+//! with Maximal Marginal Relevance (MMR): each pick maximizes
+//!
+//! ```text
+//! λ · relevance − (1 − λ) · max similarity to the hits already picked
+//! ```
+//!
+//! where relevance is the hit score and similarity is the cosine similarity
+//! of the memory embeddings. `λ = 1.0` keeps the relevance order, lower values
+//! favor dissimilar hits.
+//!
+//! Embeddings come from the memories themselves or, when missing, from the
+//! configured embedder. Without embeddings the candidates keep their order.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::services::{DIVERSITY_CANDIDATE_FACTOR, DiversityService};
+//!
+//! let candidates = recall.search(query, SearchMode::Hybrid, &filter, 10 * DIVERSITY_CANDIDATE_FACTOR)?;
+//! let hits = DiversityService::new(0.7)
+//!     .with_embedder(embedder)
+//!     .diversify(candidates.memories, 10);
+//! ```
+
+use crate::embedding::{Embedder, cosine_similarity};
+use crate::models::SearchHit;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Default trade-off between relevance (1.0) and diversity (0.0).
+pub const DEFAULT_DIVERSITY_LAMBDA: f32 = 0.7;
+
+/// Candidates retrieved per requested result for diversity re-ranking.
+pub const DIVERSITY_CANDIDATE_FACTOR: usize = 3;
+
+/// Re-ranks recall results for diversity with Maximal Marginal Relevance.
+pub struct DiversityService {
+    /// Embedder for candidates stored without an embedding.
+    embedder: Option<Arc<dyn Embedder>>,
+    /// Weight of relevance against dissimilarity (0.0 to 1.0).
+    lambda: f32,
+}
+
+impl DiversityService {
+    /// Creates a diversity service; `lambda` is clamped to 0.0-1.0.
+    #[must_use]
+    pub const fn new(lambda: f32) -> Self {
+        Self {
+            embedder: None,
+            lambda: lambda.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Sets the embedder used for candidates stored without an embedding.
+    #[must_use]
+    pub fn with_embedder(mut self, embedder: Arc<dyn Embedder>) -> Self {
+        self.embedder = Some(embedder);
+        self
+    }
+
+    /// Picks the `limit` most relevant yet mutually dissimilar candidates.
+    ///
+    /// `candidates` must be in relevance order. Without embeddings the
+    /// candidates keep their order.
+    #[instrument(
+        name = "subcog.diversify",
+        skip(self, candidates),
+        fields(component = "recall", operation = "diversify", candidates = candidates.len())
+    )]
+    pub fn diversify(&self, candidates: Vec<SearchHit>, limit: usize) -> Vec<SearchHit> {
+        let mut hits = match self.embeddings(&candidates) {
+            Some(embeddings) => mmr_select(candidates, &embeddings, self.lambda, limit),
+            None => candidates,
+        };
+        hits.truncate(limit);
+        hits
+    }
+
+    /// Returns the embedding of each candidate, or `None` if unavailable.
+    fn embeddings(&self, candidates: &[SearchHit]) -> Option<Vec<Vec<f32>>> {
+        if candidates.len() < 2 {
+            return None;
+        }
+        if let Some(stored) = candidates
+            .iter()
+            .map(|hit| hit.memory.embedding.clone())
+            .collect::<Option<Vec<_>>>()
+        {
+            return Some(stored);
+        }
+
+        let embedder = self.embedder.as_ref()?;
+        let contents: Vec<&str> = candidates
+            .iter()
+            .map(|hit| hit.memory.content.as_str())
+            .collect();
+        match embedder.embed_batch(&contents) {
+            Ok(embeddings) if embeddings.len() == candidates.len() => Some(embeddings),
+            Ok(_) => None,
+            Err(e) => {
+                tracing::warn!(error = %e, "Embedding failed, keeping relevance order");
+                None
+            },
+        }
+    }
+}
+
+/// Selects up to `limit` candidates by Maximal Marginal Relevance.
+fn mmr_select(
+    candidates: Vec<SearchHit>,
+    embeddings: &[Vec<f32>],
+    lambda: f32,
+    limit: usize,
+) -> Vec<SearchHit> {
+    let mut remaining: Vec<usize> = (0..candidates.len()).collect();
+    let mut selected: Vec<usize> = Vec::with_capacity(limit.min(candidates.len()));
+
+    while selected.len() < limit && !remaining.is_empty() {
+        let mut best: Option<(usize, f32)> = None;
+        for (position, &i) in remaining.iter().enumerate() {
+            let redundancy = selected
+                .iter()
+                .map(|&j| cosine_similarity(&embeddings[i], &embeddings[j]))
+                .fold(0.0_f32, f32::max);
+            let value = lambda.mul_add(candidates[i].score, -(1.0 - lambda) * redundancy);
+            // Strictly greater: ties keep relevance order
+            if best.is_none_or(|(_, best_value)| value > best_value) {
+                best = Some((position, value));
+            }
+        }
+        let Some((position, _)) = best else {
+            break;
+        };
+        selected.push(remaining.remove(position));
+    }
+
+    let mut slots: Vec<Option<SearchHit>> = candidates.into_iter().map(Some).collect();
+    selected
+        .into_iter()
+        .filter_map(|i| slots[i].take())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+
+    fn hit(id: &str, score: f32, embedding: Option<Vec<f32>>) -> SearchHit {
+        let memory = Memory {
+            id: MemoryId::new(id),
+            content: format!("memory {id}"),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 0,
+            updated_at: 0,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        };
+        SearchHit::new(memory, score)
+    }
+
+    fn ids(hits: &[SearchHit]) -> Vec<&str> {
+        hits.iter().map(|hit| hit.memory.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_mmr_picks_from_different_clusters() {
+        // Three near-duplicates about pooling outrank two about caching
+        let candidates = vec![
+            hit("pool-1", 1.0, Some(vec![1.0, 0.0, 0.0])),
+            hit("pool-2", 0.95, Some(vec![0.99, 0.05, 0.0])),
+            hit("pool-3", 0.9, Some(vec![0.98, 0.0, 0.05])),
+            hit("cache-1", 0.6, Some(vec![0.0, 1.0, 0.0])),
+            hit("cache-2", 0.55, Some(vec![0.05, 0.99, 0.0])),
+        ];
+
+        let hits = DiversityService::new(0.5).diversify(candidates.clone(), 3);
+        assert_eq!(hits.len(), 3);
+        assert_eq!(ids(&hits)[..2], ["pool-1", "cache-1"]);
+
+        // Without the diversity term the relevance order is kept
+        let hits = DiversityService::new(1.0).diversify(candidates, 3);
+        assert_eq!(ids(&hits), ["pool-1", "pool-2", "pool-3"]);
+    }
+
+    #[test]
+    fn test_no_embeddings_keeps_order() {
+        let candidates = vec![
+            hit("a", 1.0, None),
+            hit("b", 0.9, Some(vec![1.0, 0.0])),
+            hit("c", 0.8, None),
+        ];
+
+        let hits = DiversityService::new(0.0).diversify(candidates, 2);
+        assert_eq!(ids(&hits), ["a", "b"]);
+    }
+}
@@ -3,9 +3,11 @@
 //! Enriches memories with tags, structure, and context using LLM.
 
 use crate::llm::{
-    LlmProvider, OperationMode, build_system_prompt, sanitize_llm_response_for_error,
+    LlmProvider, OperationMode, TokenPricing, TokenUsage, build_system_prompt,
+    sanitize_llm_response_for_error,
 };
 use crate::models::{Memory, MemoryId, SearchFilter};
+use crate::services::Cancellation;
 use crate::storage::traits::IndexBackend;
 use crate::{Error, Result};
 use std::sync::Arc;
@@ -18,13 +20,41 @@ pub struct EnrichmentService<P: LlmProvider> {
     llm: P,
     /// Index backend for memory access.
     index: Arc<dyn IndexBackend>,
+    /// Checked before each LLM call.
+    cancellation: Cancellation,
+    /// Token prices for reporting the cost of LLM calls.
+    pricing: Option<TokenPricing>,
 }
 
 impl<P: LlmProvider> EnrichmentService<P> {
     /// Creates a new enrichment service.
     #[must_use]
     pub fn new(llm: P, index: Arc<dyn IndexBackend>) -> Self {
-        Self { llm, index }
+        Self {
+            llm,
+            index,
+            cancellation: Cancellation::new(),
+            pricing: None,
+        }
+    }
+
+    /// Sets the token prices used to report the cost of LLM calls.
+    #[must_use]
+    pub const fn with_pricing(mut self, pricing: TokenPricing) -> Self {
+        self.pricing = Some(pricing);
+        self
+    }
+
+    /// Returns the cost of `tokens`, if pricing is set.
+    fn cost_of(&self, tokens: &TokenUsage) -> Option<f64> {
+        self.pricing.map(|pricing| pricing.cost(tokens))
+    }
+
+    /// Sets the cancellation token checked before each LLM call.
+    #[must_use]
+    pub fn with_cancellation(mut self, cancellation: Cancellation) -> Self {
+        self.cancellation = cancellation;
+        self
     }
 
     /// Enriches all memories that have empty tags.
@@ -39,6 +69,8 @@ pub fn new(llm: P, index: Arc<dyn IndexBackend>) -> Self {
     /// Returns [`Error::OperationFailed`] if:
     /// - Memory listing fails (database access error)
     /// - LLM enrichment fails for all memories
+    ///
+    /// Returns [`Error::Cancelled`] if cancelled before all memories are processed.
     #[instrument(skip(self), fields(operation = "enrich_all", dry_run = dry_run, update_all = update_all))]
     pub fn enrich_all(&self, dry_run: bool, update_all: bool) -> Result<EnrichmentStats> {
         let start = Instant::now();
@@ -53,10 +85,12 @@ pub fn enrich_all(&self, dry_run: bool, update_all: bool) -> Result<EnrichmentSt
             };
 
             for (memory_id, _score) in &all_ids {
+                self.cancellation.check("enrich_all")?;
                 if let Some(memory) = self.index.get_memory(memory_id)? {
                     self.process_memory(&memory, dry_run, update_all, &mut stats);
                 }
             }
+            stats.cost_usd = self.cost_of(&stats.tokens);
 
             Ok(stats)
         })();
@@ -99,7 +133,7 @@ fn process_memory(
 
         let namespace = memory.namespace.as_str();
 
-        let new_tags = match self.generate_tags(&memory.content, namespace) {
+        let new_tags = match self.generate_tags(&memory.content, namespace, &mut stats.tokens) {
             Ok(tags) => tags,
             Err(e) => {
                 tracing::warn!("Failed to generate tags for {}: {e}", memory.id.as_str());
@@ -147,6 +181,8 @@ fn process_memory(
     /// - The memory with the given ID is not found
     /// - LLM tag generation fails (provider error or invalid response)
     /// - Memory update fails (database error)
+    ///
+    /// Returns [`Error::Cancelled`] if cancelled before tags are generated or applied.
     #[instrument(skip(self), fields(operation = "enrich_one", dry_run = dry_run, memory_id = memory_id))]
     pub fn enrich_one(&self, memory_id: &str, dry_run: bool) -> Result<EnrichmentResult> {
         let start = Instant::now();
@@ -163,23 +199,31 @@ pub fn enrich_one(&self, memory_id: &str, dry_run: bool) -> Result<EnrichmentRes
             let namespace = memory.namespace.as_str();
 
             // Generate tags
-            let new_tags = self.generate_tags(&memory.content, namespace)?;
+            self.cancellation.check("enrich_one")?;
+            let mut tokens = TokenUsage::default();
+            let new_tags = self.generate_tags(&memory.content, namespace, &mut tokens)?;
+            let cost_usd = self.cost_of(&tokens);
 
             if dry_run {
                 return Ok(EnrichmentResult {
                     memory_id: memory_id.to_string(),
                     new_tags,
                     applied: false,
+                    tokens,
+                    cost_usd,
                 });
             }
 
             // Update the memory
+            self.cancellation.check("enrich_one")?;
             self.update_memory_tags(&memory, &new_tags)?;
 
             Ok(EnrichmentResult {
                 memory_id: memory_id.to_string(),
                 new_tags,
                 applied: true,
+                tokens,
+                cost_usd,
             })
         })();
 
@@ -203,12 +247,21 @@ pub fn enrich_one(&self, memory_id: &str, dry_run: bool) -> Result<EnrichmentRes
     }
 
     /// Generates tags for content using LLM.
-    fn generate_tags(&self, content: &str, namespace: &str) -> Result<Vec<String>> {
+    ///
+    /// The tokens the call consumed are added to `tokens`, also when the
+    /// response cannot be parsed.
+    fn generate_tags(
+        &self,
+        content: &str,
+        namespace: &str,
+        tokens: &mut TokenUsage,
+    ) -> Result<Vec<String>> {
         let system = build_system_prompt(OperationMode::Enrichment, None);
         let user_prompt = format!(
             "Generate tags for this memory.\n\nNamespace: {namespace}\nContent: {content}\n\nReturn ONLY a JSON array of strings."
         );
-        let response = self.llm.complete_with_system(&system, &user_prompt)?;
+        let (response, usage) = self.llm.complete_with_system_usage(&system, &user_prompt)?;
+        *tokens += usage;
 
         // Parse the JSON response
         let sanitized = sanitize_llm_response_for_error(&response);
@@ -248,6 +301,13 @@ fn update_memory_tags(&self, memory: &Memory, new_tags: &[String]) -> Result<()>
             is_summary: memory.is_summary,
             source_memory_ids: memory.source_memory_ids.clone(),
             consolidation_timestamp: memory.consolidation_timestamp,
+            origin: memory.origin,
+            confidence: memory.confidence,
+            attachments: memory.attachments.clone(),
+            content_type: memory.content_type.clone(),
+            pinned: memory.pinned,
+            commit: memory.commit.clone(),
+            language: memory.language.clone(),
         };
 
         // Re-index the updated memory
@@ -274,13 +334,20 @@ pub struct EnrichmentStats {
     pub would_update: usize,
     /// Memories that failed to enrich.
     pub failed: usize,
+    /// LLM tokens consumed.
+    pub tokens: TokenUsage,
+    /// Cost of the tokens in US dollars, if pricing is configured.
+    pub cost_usd: Option<f64>,
 }
 
 impl EnrichmentStats {
     /// Returns a human-readable summary.
+    ///
+    /// Token counts prefixed with `~` are estimates, for providers that do
+    /// not report usage, and so is any cost computed from them.
     #[must_use]
     pub fn summary(&self) -> String {
-        if self.would_enrich > 0 || self.would_update > 0 {
+        let summary = if self.would_enrich > 0 || self.would_update > 0 {
             format!(
                 "Dry run: {} would be enriched, {} would be updated, {} skipped, {} failed (of {} total)",
                 self.would_enrich, self.would_update, self.skipped, self.failed, self.total
@@ -290,6 +357,13 @@ pub fn summary(&self) -> String {
                 "Enriched: {}, Updated: {}, Skipped: {}, Failed: {} (of {} total)",
                 self.enriched, self.updated, self.skipped, self.failed, self.total
             )
+        };
+        if self.tokens.is_empty() {
+            return summary;
+        }
+        match self.cost_usd {
+            Some(cost) => format!("{summary}; LLM usage: {}, cost ${cost:.4}", self.tokens),
+            None => format!("{summary}; LLM usage: {}", self.tokens),
         }
     }
 }
@@ -303,11 +377,140 @@ pub struct EnrichmentResult {
     pub new_tags: Vec<String>,
     /// Whether the changes were applied.
     pub applied: bool,
+    /// LLM tokens consumed.
+    pub tokens: TokenUsage,
+    /// Cost of the tokens in US dollars, if pricing is configured.
+    pub cost_usd: Option<f64>,
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::{CaptureOrigin, Domain, MemoryStatus, Namespace};
+    use crate::storage::index::SqliteBackend;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// LLM whose request is abandoned by the client while in flight.
+    struct AbandonedLlm {
+        calls: Arc<AtomicUsize>,
+        cancellation: Cancellation,
+    }
+
+    impl LlmProvider for AbandonedLlm {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        fn complete(&self, _prompt: &str) -> Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            self.cancellation.cancel();
+            Ok(r#"["rust"]"#.to_string())
+        }
+
+        fn analyze_for_capture(&self, _content: &str) -> Result<crate::llm::CaptureAnalysis> {
+            Err(Error::OperationFailed {
+                operation: "analyze_for_capture".to_string(),
+                cause: "Not implemented for mock".to_string(),
+            })
+        }
+    }
+
+    fn untagged_memory(id: &str) -> Memory {
+        Memory {
+            id: MemoryId::new(id),
+            content: format!("Memory {id}"),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 0,
+            updated_at: 0,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: vec![],
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_cancelled_enrichment_stops_calling_llm() {
+        let index = SqliteBackend::in_memory().unwrap();
+        for id in ["a", "b", "c"] {
+            index.index(&untagged_memory(id)).unwrap();
+        }
+        let calls = Arc::new(AtomicUsize::new(0));
+        let cancellation = Cancellation::new();
+        let llm = AbandonedLlm {
+            calls: Arc::clone(&calls),
+            cancellation: cancellation.clone(),
+        };
+
+        let service = EnrichmentService::new(llm, Arc::new(index)).with_cancellation(cancellation);
+        let result = service.enrich_all(false, false);
+
+        assert!(matches!(result, Err(Error::Cancelled { .. })));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    /// LLM that tags everything `rust` and reports no usage.
+    struct TaggingLlm;
+
+    impl LlmProvider for TaggingLlm {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        fn complete(&self, _prompt: &str) -> Result<String> {
+            Ok(r#"["rust"]"#.to_string())
+        }
+
+        fn analyze_for_capture(&self, _content: &str) -> Result<crate::llm::CaptureAnalysis> {
+            Err(Error::OperationFailed {
+                operation: "analyze_for_capture".to_string(),
+                cause: "Not implemented for mock".to_string(),
+            })
+        }
+    }
+
+    #[test]
+    fn test_enrichment_aggregates_token_usage() {
+        let index = SqliteBackend::in_memory().unwrap();
+        for id in ["a", "b"] {
+            index.index(&untagged_memory(id)).unwrap();
+        }
+        let service = EnrichmentService::new(TaggingLlm, Arc::new(index));
+
+        let one = service.enrich_one("a", true).unwrap();
+        assert!(one.tokens.approximate);
+        assert_eq!(one.tokens.completion, 1);
+
+        let stats = service.enrich_all(true, false).unwrap();
+        assert_eq!(stats.would_enrich, 2);
+        assert_eq!(stats.tokens.completion, 2);
+        assert_eq!(stats.tokens.prompt, one.tokens.prompt * 2);
+        assert!(stats.summary().contains("LLM usage: ~"));
+        assert_eq!(stats.cost_usd, None);
+
+        let priced = EnrichmentService::new(TaggingLlm, service.index.clone())
+            .with_pricing(TokenPricing::new(0.0, 1_000_000.0));
+        let one = priced.enrich_one("a", true).unwrap();
+        assert_eq!(one.cost_usd, Some(1.0));
+    }
 
     #[test]
     fn test_enrichment_stats_summary() {
@@ -319,12 +522,15 @@ fn test_enrichment_stats_summary() {
             would_enrich: 0,
             would_update: 0,
             failed: 2,
+            tokens: TokenUsage::default(),
+            cost_usd: None,
         };
         let summary = stats.summary();
         assert!(summary.contains("Enriched: 5"));
         assert!(summary.contains("Updated: 2"));
         assert!(summary.contains("Skipped: 1"));
         assert!(summary.contains("Failed: 2"));
+        assert!(!summary.contains("LLM usage"));
     }
 
     #[test]
@@ -337,11 +543,16 @@ fn test_enrichment_stats_dry_run_summary() {
             would_enrich: 5,
             would_update: 2,
             failed: 2,
+            tokens: TokenUsage::new(900, 40),
+            cost_usd: Some(0.0033),
         };
         let summary = stats.summary();
         assert!(summary.contains("Dry run"));
         assert!(summary.contains("5 would be enriched"));
         assert!(summary.contains("2 would be updated"));
+        assert!(
+            summary.contains("LLM usage: 940 tokens (900 prompt, 40 completion), cost $0.0033")
+        );
     }
 
     #[test]
@@ -360,6 +571,8 @@ fn test_enrichment_result() {
             memory_id: "test-id".to_string(),
             new_tags: vec!["rust".to_string(), "memory".to_string()],
             applied: true,
+            tokens: TokenUsage::default(),
+            cost_usd: None,
         };
         assert_eq!(result.memory_id, "test-id");
         assert_eq!(result.new_tags.len(), 2);
@@ -2,13 +2,25 @@
 //!
 //! Provides functionality for migrating existing memories to use new features,
 //! primarily generating embeddings for memories that lack them.
-
+//!
+//! [`DimensionMigration`] handles switching to an embedding model with a
+//! different dimension. The vector index is rebuilt into a staging file next
+//! to the live one, validated, and then swapped in by renaming, so vector
+//! search keeps serving the old index (and text search is never touched)
+//! until the new one is complete.
+
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
-use crate::Result;
 use crate::embedding::Embedder;
 use crate::models::{Memory, MemoryId};
+use crate::storage::vector::UsearchBackend;
 use crate::storage::{IndexBackend, VectorBackend};
+use crate::{Error, Result};
+
+/// Number of memories fetched per batch while rebuilding a vector index.
+const REBUILD_BATCH_SIZE: usize = 100;
 
 /// Statistics from a migration operation.
 #[derive(Debug, Clone, Default)]
@@ -206,9 +218,231 @@ pub const fn needs_migration(memory: &Memory, force: bool) -> bool {
     }
 }
 
+/// Statistics from rebuilding the vector index at a new dimension.
+#[derive(Debug, Clone, Default)]
+pub struct DimensionMigrationStats {
+    /// Dimensions recorded for the index before migration, if one existed.
+    pub from_dimensions: Option<usize>,
+    /// Dimensions of the rebuilt index.
+    pub to_dimensions: usize,
+    /// Number of memories embedded into the new index (or that would be).
+    pub embedded: usize,
+    /// Whether this was a dry run (no changes written).
+    pub dry_run: bool,
+}
+
+/// Rebuilds the file-backed vector index for an embedder with a different
+/// dimension.
+pub struct DimensionMigration {
+    /// Index backend holding the memories to re-embed.
+    index: Arc<dyn IndexBackend + Send + Sync>,
+    /// Embedder producing vectors at the new dimension.
+    embedder: Arc<dyn Embedder>,
+    /// Path of the live vector index.
+    vector_path: PathBuf,
+}
+
+impl DimensionMigration {
+    /// Creates a migration of the vector index at `vector_path` to the
+    /// dimension of `embedder`.
+    #[must_use]
+    pub fn new(
+        index: Arc<dyn IndexBackend + Send + Sync>,
+        embedder: Arc<dyn Embedder>,
+        vector_path: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            index,
+            embedder,
+            vector_path: vector_path.into(),
+        }
+    }
+
+    /// Returns whether the saved index was built for a different dimension
+    /// than the embedder produces.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index metadata cannot be read.
+    pub fn needs_rebuild(&self) -> Result<bool> {
+        Ok(UsearchBackend::stored_dimensions(&self.vector_path)?
+            .is_some_and(|dimensions| dimensions != self.embedder.dimensions()))
+    }
+
+    /// Rebuilds the vector index at the embedder's dimension.
+    ///
+    /// Every memory (including tombstoned ones) is re-embedded into a
+    /// staging index, which is saved, reloaded and checked before its files
+    /// replace the live ones. With `options.dry_run` only the counts are
+    /// reported.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if memories cannot be read, any memory fails to
+    /// embed, or the staged index fails validation. The live index is left
+    /// untouched in all of these cases.
+    pub fn run(&self, options: &MigrationOptions) -> Result<DimensionMigrationStats> {
+        let to_dimensions = self.embedder.dimensions();
+        let filter = crate::SearchFilter::new().with_include_tombstoned(true);
+        let ids: Vec<MemoryId> = self
+            .index
+            .list_all(&filter, usize::MAX)?
+            .into_iter()
+            .map(|(id, _score)| id)
+            .collect();
+
+        let mut stats = DimensionMigrationStats {
+            from_dimensions: UsearchBackend::stored_dimensions(&self.vector_path)?,
+            to_dimensions,
+            embedded: ids.len(),
+            dry_run: options.dry_run,
+        };
+        if options.dry_run {
+            return Ok(stats);
+        }
+        if ids.is_empty() {
+            // An empty index has no files; drop the old one so it reopens at
+            // the new dimension
+            remove_index_files(&self.vector_path)?;
+            return Ok(stats);
+        }
+
+        let staging_path = staging_path(&self.vector_path);
+        remove_index_files(&staging_path)?;
+        let result = self.build_staged(&staging_path, &ids).and_then(|embedded| {
+            validate_staged(&staging_path, to_dimensions, embedded)?;
+            swap_index_files(&staging_path, &self.vector_path)?;
+            Ok(embedded)
+        });
+        if result.is_err() {
+            let _ = remove_index_files(&staging_path);
+        }
+        stats.embedded = result?;
+
+        tracing::info!(
+            from_dimensions = ?stats.from_dimensions,
+            to_dimensions,
+            embedded = stats.embedded,
+            "Rebuilt vector index at new dimension"
+        );
+        Ok(stats)
+    }
+
+    /// Embeds every memory into a new index at `staging_path` and saves it.
+    fn build_staged(&self, staging_path: &Path, ids: &[MemoryId]) -> Result<usize> {
+        let staged = open_vector_index(staging_path, self.embedder.dimensions())?;
+        let mut embedded = 0;
+        let mut errors = 0;
+        for batch in ids.chunks(REBUILD_BATCH_SIZE) {
+            let (batch_embedded, batch_errors) = self.embed_batch(&staged, batch)?;
+            embedded += batch_embedded;
+            errors += batch_errors;
+        }
+        if errors > 0 {
+            return Err(Error::OperationFailed {
+                operation: "rebuild_vector_index".to_string(),
+                cause: format!("{errors} memories could not be re-embedded"),
+            });
+        }
+        staged.save()?;
+        Ok(embedded)
+    }
+
+    /// Embeds one batch of memories into `staged`, returning how many were
+    /// embedded and how many failed.
+    fn embed_batch(&self, staged: &UsearchBackend, batch: &[MemoryId]) -> Result<(usize, usize)> {
+        let mut embedded = 0;
+        let mut errors = 0;
+        for memory in self.index.get_memories_batch(batch)?.into_iter().flatten() {
+            let stored = self
+                .embedder
+                .embed(&memory.content)
+                .and_then(|embedding| staged.upsert(&memory.id, &embedding));
+            if let Err(e) = stored {
+                tracing::warn!("Failed to re-embed memory {}: {e}", memory.id.as_str());
+                errors += 1;
+            } else {
+                embedded += 1;
+            }
+        }
+        Ok((embedded, errors))
+    }
+}
+
+/// Opens a vector index at `path` with `dimensions`.
+fn open_vector_index(path: &Path, dimensions: usize) -> Result<UsearchBackend> {
+    #[cfg(feature = "usearch-hnsw")]
+    let backend = UsearchBackend::new(path, dimensions)?;
+    #[cfg(not(feature = "usearch-hnsw"))]
+    let backend = UsearchBackend::new(path, dimensions);
+    Ok(backend)
+}
+
+/// Returns the staging path used while rebuilding the index at `vector_path`.
+fn staging_path(vector_path: &Path) -> PathBuf {
+    let stem = vector_path
+        .file_stem()
+        .map_or_else(|| "vectors".into(), |stem| stem.to_string_lossy());
+    let mut staging = vector_path.with_file_name(format!("{stem}-migrating"));
+    if let Some(extension) = vector_path.extension() {
+        staging.set_extension(extension);
+    }
+    staging
+}
+
+/// Reloads the staged index from disk and checks its dimensions and size.
+fn validate_staged(staging_path: &Path, dimensions: usize, expected: usize) -> Result<()> {
+    let stored = UsearchBackend::stored_dimensions(staging_path)?;
+    let staged = open_vector_index(staging_path, dimensions)?;
+    staged.load()?;
+    let count = staged.count()?;
+    if stored != Some(dimensions) || count != expected {
+        return Err(Error::OperationFailed {
+            operation: "validate_vector_index".to_string(),
+            cause: format!(
+                "staged index has {count} vectors at {stored:?} dimensions, expected {expected} at {dimensions}"
+            ),
+        });
+    }
+    Ok(())
+}
+
+/// Replaces the live index files with the staged ones.
+///
+/// Each file is swapped with an atomic rename; metadata goes last.
+fn swap_index_files(staging_path: &Path, vector_path: &Path) -> Result<()> {
+    let staged = UsearchBackend::index_files(staging_path);
+    let live = UsearchBackend::index_files(vector_path);
+    for (from, to) in staged.iter().zip(&live) {
+        fs::rename(from, to).map_err(|e| Error::OperationFailed {
+            operation: "swap_vector_index".to_string(),
+            cause: format!("{} -> {}: {e}", from.display(), to.display()),
+        })?;
+    }
+    Ok(())
+}
+
+/// Removes the files of the index at `path`, ignoring missing ones.
+fn remove_index_files(path: &Path) -> Result<()> {
+    for file in UsearchBackend::index_files(path) {
+        match fs::remove_file(&file) {
+            Ok(()) => {},
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {},
+            Err(e) => {
+                return Err(Error::OperationFailed {
+                    operation: "remove_vector_index".to_string(),
+                    cause: format!("{}: {e}", file.display()),
+                });
+            },
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::CaptureOrigin;
 
     #[test]
     fn test_migration_stats_new() {
@@ -301,6 +535,13 @@ fn create_test_memory(id: &str, with_embedding: bool) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -333,4 +574,88 @@ fn test_needs_migration_force() {
             crate::storage::vector::UsearchBackend,
         >::needs_migration(&memory, true));
     }
+
+    /// Embedder producing deterministic letter-count vectors of a fixed dimension.
+    struct MockEmbedder(usize);
+
+    impl Embedder for MockEmbedder {
+        fn dimensions(&self) -> usize {
+            self.0
+        }
+
+        fn embed(&self, text: &str) -> Result<Vec<f32>> {
+            let mut embedding = vec![0.0_f32; self.0];
+            for byte in text.bytes() {
+                embedding[usize::from(byte) % self.0] += 1.0;
+            }
+            Ok(embedding)
+        }
+    }
+
+    fn seeded_index() -> Arc<dyn IndexBackend + Send + Sync> {
+        let index = crate::storage::index::SqliteBackend::in_memory().unwrap();
+        for (id, content) in [
+            ("a", "Use PostgreSQL for storage"),
+            ("b", "Rust borrow checker tips"),
+            ("c", "Deploy with Docker compose"),
+        ] {
+            let mut memory = create_test_memory(id, false);
+            memory.content = content.to_string();
+            index.index(&memory).unwrap();
+        }
+        Arc::new(index)
+    }
+
+    #[test]
+    fn test_dimension_migration_rebuilds_index() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let vector_path = dir.path().join("vectors.idx");
+        let index = seeded_index();
+        {
+            let old = MockEmbedder(4);
+            let vectors = open_vector_index(&vector_path, 4).unwrap();
+            for id in ["a", "b", "c"] {
+                let embedding = old.embed(id).unwrap();
+                vectors.upsert(&MemoryId::new(id), &embedding).unwrap();
+            }
+            vectors.save().unwrap();
+        }
+
+        let embedder = Arc::new(MockEmbedder(8));
+        let migration = DimensionMigration::new(index, Arc::clone(&embedder) as _, &vector_path);
+        assert!(migration.needs_rebuild().unwrap());
+
+        let preview = migration
+            .run(&MigrationOptions::new().with_dry_run(true))
+            .unwrap();
+        assert_eq!(preview.embedded, 3);
+        assert_eq!(
+            UsearchBackend::stored_dimensions(&vector_path).unwrap(),
+            Some(4)
+        );
+
+        let stats = migration.run(&MigrationOptions::new()).unwrap();
+        assert_eq!(stats.from_dimensions, Some(4));
+        assert_eq!(stats.to_dimensions, 8);
+        assert_eq!(stats.embedded, 3);
+        assert_eq!(
+            UsearchBackend::stored_dimensions(&vector_path).unwrap(),
+            Some(8)
+        );
+        assert!(!migration.needs_rebuild().unwrap());
+        assert!(
+            UsearchBackend::index_files(&staging_path(&vector_path))
+                .iter()
+                .all(|file| !file.exists())
+        );
+
+        let vectors = open_vector_index(&vector_path, 8).unwrap();
+        vectors.load().unwrap();
+        assert_eq!(vectors.count().unwrap(), 3);
+        let query = embedder.embed("Use PostgreSQL for storage").unwrap();
+        let hits = vectors
+            .search(&query, &crate::storage::traits::VectorFilter::new(), 1)
+            .unwrap();
+        assert_eq!(hits[0].0.as_str(), "a");
+    }
 }
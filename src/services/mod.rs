@@ -44,6 +44,21 @@
 //! # Ok::<(), subcog::Error>(())
 //! ```
 //!
+//! Async applications can await the same operations, which run on tokio's
+//! blocking pool (see `capture_async` and `search_async`):
+//!
+//! ```rust,ignore
+//! use std::sync::Arc;
+//! use subcog::services::ServiceContainer;
+//! use subcog::models::{CaptureRequest, SearchFilter, SearchMode};
+//!
+//! let container = Arc::new(ServiceContainer::from_current_dir_or_user()?);
+//! container.capture_async(CaptureRequest::new("Use axum")).await?;
+//! let results = container
+//!     .search_async("web framework", SearchMode::Hybrid, SearchFilter::new(), 10)
+//!     .await?;
+//! ```
+//!
 //! # Clippy Lints
 //!
 //! The following lints are allowed at module level due to their pervasive nature
@@ -74,40 +89,67 @@
 // Drop timing not critical for correctness in service code
 #![allow(clippy::significant_drop_tightening)]
 
+mod async_api;
 pub mod auth;
 mod backend_factory;
+mod cancellation;
 mod capture;
 mod consolidation;
+mod container_builder;
+mod content_split;
 mod context;
 mod context_template;
 mod data_subject;
 pub mod deduplication;
+mod digest;
+mod diversity;
 mod enrichment;
 mod entity_extraction;
 mod graph;
 mod graph_rag;
 pub mod migration;
+mod namespace_embeddings;
 mod path_manager;
 mod prompt;
 mod prompt_enrichment;
 mod prompt_parser;
+mod query_expansion;
 mod query_parser;
 mod recall;
+mod recall_cache;
+mod rerank;
+mod status;
 mod sync;
+mod tags;
 mod tombstone;
 mod topic_index;
+mod verify;
+#[cfg(feature = "wasm")]
+mod wasm_transformer;
+mod widening;
 
 #[cfg(feature = "group-scope")]
 pub mod group;
 
 pub use auth::{AuthContext, AuthContextBuilder, Permission};
 pub use backend_factory::{BackendFactory, BackendSet};
-pub use capture::{CaptureService, EntityExtractionCallback, EntityExtractionStats};
-pub use consolidation::{ConsolidationService, ConsolidationStats};
+pub use cancellation::Cancellation;
+pub use capture::{
+    CapturePostHook, CapturePreHook, CaptureService, EntityExtractionCallback,
+    EntityExtractionStats, normalize_content,
+};
+pub use consolidation::{
+    ClusterMember, ConsolidationCluster, ConsolidationPlan, ConsolidationService,
+    ConsolidationStats,
+};
+pub use container_builder::ServiceContainerBuilder;
+pub use content_split::{
+    DEFAULT_SPLIT_THRESHOLD_CHARS, SPLIT_TAG_PREFIX, split_content, split_tag,
+};
 pub use context::{ContextBuilderService, MemoryStatistics};
 pub use context_template::{
-    ContextTemplateFilter, ContextTemplateService, RenderResult, ValidationIssue, ValidationResult,
-    ValidationSeverity,
+    ContextTemplateFilter, ContextTemplateService, RECALL_CONTEXT_TEMPLATE, RenderResult,
+    ValidationIssue, ValidationResult, ValidationSeverity,
 };
 pub use data_subject::{
     ConsentPurpose, ConsentRecord, ConsentStatus, DataSubjectService, DeletionResult,
@@ -115,7 +157,12 @@
 };
 pub use deduplication::{
     DeduplicationConfig, DeduplicationService, Deduplicator, DuplicateCheckResult, DuplicateReason,
+    IndexDeduplicator, SimilarityMetric,
 };
+pub use digest::{
+    DEFAULT_DIGEST_LIMIT, DigestEntry, DigestService, MemoryDigest, NamespaceCount, TagCount,
+};
+pub use diversity::{DEFAULT_DIVERSITY_LAMBDA, DIVERSITY_CANDIDATE_FACTOR, DiversityService};
 pub use enrichment::{EnrichmentResult, EnrichmentService, EnrichmentStats};
 pub use entity_extraction::{
     EntityExtractorService, ExtractedEntity, ExtractedRelationship, ExtractionResult,
@@ -126,8 +173,10 @@
     ExpansionConfig, GraphRAGConfig, GraphRAGService, GraphSearchHit, GraphSearchResults,
     SearchProvenance,
 };
+pub use namespace_embeddings::{EmbeddingRoute, NamespaceEmbeddings};
 pub use path_manager::{
-    GRAPH_DB_NAME, INDEX_DB_NAME, PathManager, SUBCOG_DIR_NAME, VECTOR_INDEX_NAME,
+    ATTACHMENTS_DIR_NAME, EMBEDDING_CACHE_DB_NAME, EMBEDDING_MANIFEST_NAME, GRAPH_DB_NAME,
+    INDEX_DB_NAME, PathManager, SUBCOG_DIR_NAME, VECTOR_INDEX_NAME,
 };
 pub use prompt::{PromptFilter, PromptService, SaveOptions, SaveResult};
 pub use prompt_enrichment::{
@@ -135,26 +184,40 @@
     PartialMetadata, PromptEnrichmentResult, PromptEnrichmentService,
 };
 pub use prompt_parser::{PromptFormat, PromptParser};
-pub use query_parser::parse_filter_query;
-pub use recall::RecallService;
+pub use query_expansion::{DEFAULT_EXPANSION_TIMEOUT_MS, QueryExpansionService};
+pub use query_parser::{parse_filter_query, parse_time_bound};
+pub use recall::{DEFAULT_HYBRID_ALPHA, DEFAULT_SNIPPET_MARKER, RecallService};
+pub use recall_cache::{DEFAULT_RECALL_CACHE_MAX_ENTRIES, DEFAULT_RECALL_CACHE_TTL, RecallCache};
+pub use rerank::{DEFAULT_RERANK_CANDIDATES, RerankService};
+pub use status::{ComponentHealth, ComponentStatus, StatusComponents, StatusReport, StatusService};
 pub use sync::SyncService;
+pub use tags::{TagRewrite, TagRewriteResult, TagService};
 pub use tombstone::TombstoneService;
 pub use topic_index::{TopicIndexService, TopicInfo};
+pub use verify::{VerifyReport, VerifyService};
+pub use widening::{RelaxableFilter, Relaxation, Widened, WideningService};
 
 // Group service (feature-gated)
 #[cfg(feature = "group-scope")]
 pub use group::GroupService;
 
+// WASM capture transformers (feature-gated)
+#[cfg(feature = "wasm")]
+pub use wasm_transformer::{WasmLimits, WasmTransformer};
+
 use crate::cli::build_llm_provider_for_entity_extraction;
-use crate::config::SubcogConfig;
+use crate::config::{SearchConfig, StorageConfig, SubcogConfig};
 use crate::context::GitContext;
-use crate::embedding::Embedder;
-use crate::models::{Memory, MemoryId, SearchFilter};
+use crate::embedding::{CachedEmbedder, ContentEmbeddingCache, Embedder, FastEmbedEmbedder};
+use crate::gc::GcScope;
+use crate::io::{ExportService, ImportService};
+use crate::models::{FieldWeights, Memory, MemoryId, SearchFilter};
+use crate::storage::AttachmentStore;
 use crate::storage::index::{
     DomainIndexConfig, DomainIndexManager, DomainScope, OrgIndexConfig, find_repo_root,
     get_user_data_dir,
 };
-use crate::storage::traits::{IndexBackend, VectorBackend};
+use crate::storage::traits::{IndexBackend, PersistenceBackend, VectorBackend};
 use crate::{Error, Result};
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
@@ -215,6 +278,57 @@ pub fn prompt_service_for_cwd() -> Result<PromptService> {
     Ok(prompt_service_for_repo(&cwd))
 }
 
+/// Creates the shared recall cache when `[search] cache` is enabled.
+fn build_recall_cache(config: &SearchConfig) -> Option<Arc<RecallCache>> {
+    config.cache.then(|| {
+        Arc::new(RecallCache::new(
+            std::time::Duration::from_secs(config.cache_ttl_secs),
+            config.cache_max_entries,
+        ))
+    })
+}
+
+/// Opens the embedding routes for namespaces with their own model.
+///
+/// Returns `None` when no embedder is available or every namespace uses the
+/// global model.
+fn build_namespace_embeddings(
+    config: &SubcogConfig,
+    paths: &PathManager,
+    backends: &BackendSet,
+) -> Result<Option<Arc<NamespaceEmbeddings>>> {
+    if backends.embedder.is_none() {
+        return Ok(None);
+    }
+    let routes = NamespaceEmbeddings::from_config(&config.embedding, paths)?;
+    Ok((!routes.is_empty()).then(|| Arc::new(routes)))
+}
+
+/// Wraps the global embedder with the on-disk embedding cache.
+///
+/// Only fastembed models are cached; the hash-based fallback embeddings are
+/// cheaper to compute than to look up. Entries of other models are pruned.
+/// The cache is an optimization, so failing to open it only logs a warning.
+fn attach_embedding_cache(backends: &mut BackendSet, paths: &PathManager, model: &str) {
+    if !cfg!(feature = "fastembed-embeddings") {
+        return;
+    }
+    let Some(embedder) = backends.embedder.take() else {
+        return;
+    };
+    let cache = ContentEmbeddingCache::open(&paths.embedding_cache_path()).and_then(|cache| {
+        cache.retain_model(model)?;
+        Ok(cache)
+    });
+    backends.embedder = Some(match cache {
+        Ok(cache) => Arc::new(CachedEmbedder::new(embedder, model, Arc::new(cache))),
+        Err(e) => {
+            tracing::warn!(error = %e, "Embedding cache unavailable, embedding without it");
+            embedder
+        },
+    });
+}
+
 // ============================================================================
 // Service Container
 // ============================================================================
@@ -267,8 +381,8 @@ pub fn prompt_service_for_cwd() -> Result<PromptService> {
 /// - `SQLite` initialization errors propagate as `Error::OperationFailed`
 /// - Index creation is idempotent (safe to call multiple times)
 pub struct ServiceContainer {
-    /// Capture service.
-    capture: CaptureService,
+    /// Capture service (shared with the import service).
+    capture: Arc<CaptureService>,
     /// Sync service.
     sync: SyncService,
     /// Domain index manager for multi-domain indices.
@@ -288,9 +402,34 @@ pub struct ServiceContainer {
     /// When `Some`, this is used by [`recall_for_scope`](Self::recall_for_scope)
     /// instead of the `DomainIndexManager` (which only creates `SQLite` backends).
     index: Option<Arc<dyn IndexBackend + Send + Sync>>,
+    /// Persistence backend (from factory or [`ServiceContainerBuilder`]), if any.
+    persistence: Option<Arc<dyn PersistenceBackend + Send + Sync>>,
+    /// Recall query cache shared by every [`recall`](Self::recall) service
+    /// (`None` unless `[search] cache` is enabled).
+    recall_cache: Option<Arc<RecallCache>>,
+    /// Per-field BM25 weights for recall text search (`[search] field_weights`).
+    field_weights: FieldWeights,
+    /// Whether recall ranks with relevance feedback (`[search] feedback_boost`).
+    feedback_boost: bool,
+    /// Marker around matched terms in recall snippets (`[search] snippet_marker`).
+    snippet_marker: String,
+    /// Vector weight in hybrid recall ranking (`[search] hybrid_alpha`).
+    hybrid_alpha: f32,
+    /// Per-scope recall caps (`[storage.<scope>] max_results`).
+    storage: StorageConfig,
+    /// Embedding routes for namespaces with their own model (`[embedding] per_namespace`).
+    namespace_embeddings: Option<Arc<NamespaceEmbeddings>>,
 }
 
 impl ServiceContainer {
+    /// Returns a builder for a container with caller-supplied storage backends.
+    ///
+    /// See [`ServiceContainerBuilder`].
+    #[must_use]
+    pub fn builder() -> ServiceContainerBuilder {
+        ServiceContainerBuilder::new()
+    }
+
     /// Creates a new service container for a repository.
     ///
     /// # Arguments
@@ -300,15 +439,40 @@ impl ServiceContainer {
     ///
     /// # Errors
     ///
-    /// Returns an error if the repository cannot be found or backends fail to initialize.
+    /// Returns an error if the repository cannot be found, backends fail to
+    /// initialize, or the embedder does not match the vector index (see
+    /// [`check_embedding_dimensions`](Self::check_embedding_dimensions)).
     pub fn for_repo(
         repo_path: impl Into<PathBuf>,
         org_config: Option<OrgIndexConfig>,
     ) -> Result<Self> {
-        let repo_path = repo_path.into();
+        let container = Self::open_repo(&repo_path.into(), org_config)?;
+        container.check_embedding_dimensions()?;
+        Ok(container)
+    }
+
+    /// Creates a service container without checking the embedder against
+    /// the vector index.
+    ///
+    /// For commands that repair a mismatched index (`subcog migrate
+    /// embeddings`); everything else should use the checked constructors.
+    /// Uses `repo_path` when given, and the current directory or user scope
+    /// otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the repository cannot be found or backends fail to initialize.
+    pub fn for_vector_repair(repo_path: Option<PathBuf>) -> Result<Self> {
+        match repo_path {
+            Some(repo_path) => Self::open_repo(&repo_path, None),
+            None => Self::open_current_dir_or_user(),
+        }
+    }
 
+    /// Creates a project-scoped container without the dimension check.
+    fn open_repo(repo_path: &Path, org_config: Option<OrgIndexConfig>) -> Result<Self> {
         // Find repository root
-        let repo_root = find_repo_root(&repo_path)?;
+        let repo_root = find_repo_root(repo_path)?;
 
         if org_config.is_some() {
             let config = SubcogConfig::load_default().with_repo_path(&repo_root);
@@ -338,6 +502,9 @@ pub fn for_repo(
         let mut capture_config = crate::config::Config::new().with_repo_path(&repo_root);
         capture_config.features.auto_extract_entities =
             subcog_config.features.auto_extract_entities;
+        capture_config.namespace_rules = subcog_config.namespace_rules.clone();
+        capture_config.capture = subcog_config.capture.clone();
+        capture_config.security = subcog_config.security.clone();
         let user_data_dir = subcog_config.data_dir.clone();
 
         std::fs::create_dir_all(&user_data_dir).map_err(|e| Error::OperationFailed {
@@ -354,11 +521,14 @@ pub fn for_repo(
         let paths = PathManager::for_user(&user_data_dir);
 
         // Create backends using factory, routing to PostgreSQL if configured
-        let backends = BackendFactory::create_from_config(
+        let embedding_model = FastEmbedEmbedder::with_model(subcog_config.embedding.model())?;
+        let mut backends = BackendFactory::create_from_config(
             &subcog_config.storage.project,
+            &embedding_model,
             &paths.index_path(),
             &paths.vector_path(),
         );
+        attach_embedding_cache(&mut backends, &paths, embedding_model.model_name());
 
         // Build LLM provider for entity extraction with longer timeout (120s default)
         let llm_provider = build_llm_provider_for_entity_extraction(&subcog_config);
@@ -367,11 +537,16 @@ pub fn for_repo(
         let entity_extraction =
             Self::create_entity_extraction_callback(&capture_config, &paths, llm_provider);
 
+        let namespace_embeddings = build_namespace_embeddings(&subcog_config, &paths, &backends)?;
+
         // Build CaptureService based on available backends
-        let capture = Self::build_capture_service(capture_config, &backends, entity_extraction);
+        let mut capture = Self::build_capture_service(capture_config, &backends, entity_extraction);
+        if let Some(ref routes) = namespace_embeddings {
+            capture = capture.with_namespace_embeddings(Arc::clone(routes));
+        }
 
         Ok(Self {
-            capture,
+            capture: Arc::new(capture),
             sync: SyncService::default(),
             index_manager: Mutex::new(index_manager),
             repo_path: Some(repo_root),
@@ -379,6 +554,14 @@ pub fn for_repo(
             embedder: backends.embedder,
             vector: backends.vector,
             index: backends.index,
+            persistence: backends.persistence,
+            recall_cache: build_recall_cache(&subcog_config.search),
+            field_weights: subcog_config.search.field_weights,
+            feedback_boost: subcog_config.search.feedback_boost,
+            snippet_marker: subcog_config.search.snippet_marker.clone(),
+            hybrid_alpha: subcog_config.search.hybrid_alpha,
+            storage: subcog_config.storage.clone(),
+            namespace_embeddings,
         })
     }
 
@@ -411,9 +594,17 @@ pub fn from_current_dir() -> Result<Self> {
     ///
     /// # Errors
     ///
-    /// Returns an error if the user data directory cannot be created or
-    /// storage backends fail to initialize.
+    /// Returns an error if the user data directory cannot be created,
+    /// storage backends fail to initialize, or the embedder does not match the
+    /// vector index.
     pub fn for_user() -> Result<Self> {
+        let container = Self::open_user()?;
+        container.check_embedding_dimensions()?;
+        Ok(container)
+    }
+
+    /// Creates a user-scoped container without the dimension check.
+    fn open_user() -> Result<Self> {
         // Load config to get user's configured data_dir (respects config.toml)
         let subcog_config = SubcogConfig::load_default();
         let user_data_dir = subcog_config.data_dir.clone();
@@ -446,13 +637,19 @@ pub fn for_user() -> Result<Self> {
         let mut capture_config = crate::config::Config::new();
         capture_config.features.auto_extract_entities =
             subcog_config.features.auto_extract_entities;
+        capture_config.namespace_rules = subcog_config.namespace_rules.clone();
+        capture_config.capture = subcog_config.capture.clone();
+        capture_config.security = subcog_config.security.clone();
 
         // Create backends using factory, routing to PostgreSQL if configured
-        let backends = BackendFactory::create_from_config(
+        let embedding_model = FastEmbedEmbedder::with_model(subcog_config.embedding.model())?;
+        let mut backends = BackendFactory::create_from_config(
             &subcog_config.storage.user,
+            &embedding_model,
             &paths.index_path(),
             &paths.vector_path(),
         );
+        attach_embedding_cache(&mut backends, &paths, embedding_model.model_name());
 
         // Build LLM provider for entity extraction with longer timeout (120s default)
         let llm_provider = build_llm_provider_for_entity_extraction(&subcog_config);
@@ -461,8 +658,13 @@ pub fn for_user() -> Result<Self> {
         let entity_extraction =
             Self::create_entity_extraction_callback(&capture_config, &paths, llm_provider);
 
+        let namespace_embeddings = build_namespace_embeddings(&subcog_config, &paths, &backends)?;
+
         // Build CaptureService based on available backends
-        let capture = Self::build_capture_service(capture_config, &backends, entity_extraction);
+        let mut capture = Self::build_capture_service(capture_config, &backends, entity_extraction);
+        if let Some(ref routes) = namespace_embeddings {
+            capture = capture.with_namespace_embeddings(Arc::clone(routes));
+        }
 
         tracing::info!(
             user_data_dir = %user_data_dir.display(),
@@ -470,7 +672,7 @@ pub fn for_user() -> Result<Self> {
         );
 
         Ok(Self {
-            capture,
+            capture: Arc::new(capture),
             sync: SyncService::no_op(),
             index_manager: Mutex::new(index_manager),
             repo_path: None,
@@ -478,6 +680,14 @@ pub fn for_user() -> Result<Self> {
             embedder: backends.embedder,
             vector: backends.vector,
             index: backends.index,
+            persistence: backends.persistence,
+            recall_cache: build_recall_cache(&subcog_config.search),
+            field_weights: subcog_config.search.field_weights,
+            feedback_boost: subcog_config.search.feedback_boost,
+            snippet_marker: subcog_config.search.snippet_marker.clone(),
+            hybrid_alpha: subcog_config.search.hybrid_alpha,
+            storage: subcog_config.storage.clone(),
+            namespace_embeddings,
         })
     }
 
@@ -500,10 +710,25 @@ pub fn for_user() -> Result<Self> {
     ///
     /// # Errors
     ///
-    /// Returns an error only if both project and user scope fail to initialize.
+    /// Returns an error if both project and user scope fail to initialize, or
+    /// if the embedder does not match the vector index.
     pub fn from_current_dir_or_user() -> Result<Self> {
+        let container = Self::open_current_dir_or_user()?;
+        container.check_embedding_dimensions()?;
+        Ok(container)
+    }
+
+    /// Opens project scope for the current directory, falling back to user
+    /// scope, without the dimension check.
+    fn open_current_dir_or_user() -> Result<Self> {
+        let project = std::env::current_dir()
+            .map_err(|e| Error::OperationFailed {
+                operation: "get_current_dir".to_string(),
+                cause: e.to_string(),
+            })
+            .and_then(|cwd| Self::open_repo(&cwd, None));
         // Try project scope first
-        match Self::from_current_dir() {
+        match project {
             Ok(container) => {
                 tracing::debug!("Using project-scoped service container");
                 Ok(container)
@@ -513,11 +738,40 @@ pub fn from_current_dir_or_user() -> Result<Self> {
                     error = %e,
                     "Not in git repository, falling back to user scope"
                 );
-                Self::for_user()
+                Self::open_user()
             },
         }
     }
 
+    /// Checks that the embedder produces vectors of the vector index's size.
+    ///
+    /// An index built with another embedding model keeps that model's
+    /// dimensions, and every upsert and vector search against it would fail. Catching it
+    /// here gives one actionable error instead. Passes when either the
+    /// embedder or the vector backend is missing.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OperationFailed`] if the dimensions differ.
+    pub fn check_embedding_dimensions(&self) -> Result<()> {
+        let (Some(embedder), Some(vector)) = (&self.embedder, &self.vector) else {
+            return Ok(());
+        };
+        let (expected, actual) = (vector.dimensions(), embedder.dimensions());
+        if expected == actual {
+            return Ok(());
+        }
+        Err(Error::OperationFailed {
+            operation: "check_embedding_dimensions".to_string(),
+            cause: format!(
+                "the embedder produces {actual}-dimensional embeddings but the vector index \
+                 stores {expected}-dimensional vectors (it was built with a different embedding \
+                 model). Run `subcog migrate embeddings --force` to re-embed every memory with \
+                 the current model"
+            ),
+        })
+    }
+
     /// Returns whether this container is using user scope (no git repository).
     #[must_use]
     pub const fn is_user_scope(&self) -> bool {
@@ -565,6 +819,9 @@ pub fn recall_for_scope(&self, scope: DomainScope) -> Result<RecallService> {
         if let Some(ref vector) = self.vector {
             service = service.with_vector(Arc::clone(vector));
         }
+        if let Some(ref routes) = self.namespace_embeddings {
+            service = service.with_namespace_embeddings(Arc::clone(routes));
+        }
 
         if matches!(scope, DomainScope::Project)
             && let Some(filter) = self.project_scope_filter()
@@ -572,7 +829,20 @@ pub fn recall_for_scope(&self, scope: DomainScope) -> Result<RecallService> {
             service = service.with_scope_filter(filter);
         }
 
-        Ok(service)
+        Ok(self.configure_recall(service, scope).with_scope(scope))
+    }
+
+    /// Applies the ranking settings and the scope's `max_results` cap.
+    fn configure_recall(&self, service: RecallService, scope: DomainScope) -> RecallService {
+        let service = service
+            .with_field_weights(self.field_weights)
+            .with_feedback_boost(self.feedback_boost)
+            .with_snippet_marker(self.snippet_marker.as_str())
+            .with_hybrid_alpha(self.hybrid_alpha);
+        match self.storage.max_results_for(scope) {
+            Some(max_results) => service.with_max_results(max_results),
+            None => service,
+        }
     }
 
     fn project_scope_filter(&self) -> Option<SearchFilter> {
@@ -596,12 +866,122 @@ pub fn recall(&self) -> Result<RecallService> {
         } else {
             DomainScope::Project
         };
-        self.recall_for_scope(scope)
+        let service = self.recall_for_scope(scope)?;
+        Ok(match self.recall_cache {
+            Some(ref cache) => service.with_cache(Arc::clone(cache)),
+            None => service,
+        })
+    }
+
+    /// Creates a recall service that can also search the other available scopes.
+    ///
+    /// The service searches the same scope as [`recall`](Self::recall) and
+    /// [`RecallService::search_all_scopes`] additionally searches the user
+    /// scope (for project containers: the same index without project facets)
+    /// and the org index when one is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the project or user index cannot be initialized.
+    /// An org index that fails to open is skipped with a warning.
+    pub fn recall_all_scopes(&self) -> Result<RecallService> {
+        let scope = if self.is_user_scope() {
+            DomainScope::User
+        } else {
+            DomainScope::Project
+        };
+        let mut service = self.recall()?;
+        if scope == DomainScope::Project {
+            service = service.with_additional_scope(
+                DomainScope::User,
+                self.recall_for_scope(DomainScope::User)?,
+            );
+        }
+
+        let org_index = {
+            let manager = self
+                .index_manager
+                .lock()
+                .map_err(|e| Error::OperationFailed {
+                    operation: "lock_index_manager".to_string(),
+                    cause: e.to_string(),
+                })?;
+            if manager.has_org_config() {
+                manager
+                    .create_org_index()
+                    .inspect_err(|e| tracing::warn!(error = %e, "Org index unavailable, skipping"))
+                    .ok()
+            } else {
+                None
+            }
+        }; // Lock released here
+        if let Some(index) = org_index {
+            let org = self.configure_recall(RecallService::with_dyn_index(index), DomainScope::Org);
+            service = service.with_additional_scope(DomainScope::Org, org);
+        }
+
+        Ok(service)
+    }
+
+    /// Returns the indexes that garbage collection should cover.
+    ///
+    /// The local index holds both project and user memories, so it is
+    /// collected once: as the project scope (with stale-branch GC for the
+    /// current project) inside a repository, as the user scope otherwise.
+    /// The org index is added when one is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the local index cannot be initialized. An org
+    /// index that fails to open is skipped with a warning.
+    pub fn gc_scopes(&self) -> Result<Vec<GcScope>> {
+        let project_id = self
+            .repo_path
+            .as_ref()
+            .and_then(|path| GitContext::from_path(path).project_id);
+        let mut scopes = vec![GcScope {
+            scope: if self.is_user_scope() {
+                DomainScope::User
+            } else {
+                DomainScope::Project
+            },
+            index: self.index()?,
+            project_id,
+            repo_path: self.repo_path.clone(),
+        }];
+
+        let org_index = {
+            let manager = self
+                .index_manager
+                .lock()
+                .map_err(|e| Error::OperationFailed {
+                    operation: "lock_index_manager".to_string(),
+                    cause: e.to_string(),
+                })?;
+            if manager.has_org_config() {
+                manager
+                    .create_org_index()
+                    .inspect_err(|e| tracing::warn!(error = %e, "Org index unavailable, skipping"))
+                    .ok()
+            } else {
+                None
+            }
+        }; // Lock released here
+        if let Some(index) = org_index {
+            scopes.push(GcScope {
+                scope: DomainScope::Org,
+                index,
+                project_id: None,
+                repo_path: None,
+            });
+        }
+
+        Ok(scopes)
     }
 
     /// Returns the capture service.
     #[must_use]
-    pub const fn capture(&self) -> &CaptureService {
+    pub fn capture(&self) -> &CaptureService {
         &self.capture
     }
 
@@ -617,6 +997,28 @@ pub const fn repo_path(&self) -> Option<&PathBuf> {
         self.repo_path.as_ref()
     }
 
+    /// Returns the user data directory the container's stores live in.
+    #[must_use]
+    pub fn user_data_dir(&self) -> &Path {
+        &self.user_data_dir
+    }
+
+    /// Returns the path of the file-backed vector index.
+    #[must_use]
+    pub fn vector_path(&self) -> PathBuf {
+        PathManager::for_user(&self.user_data_dir).vector_path()
+    }
+
+    /// Returns the attachment store in the user data directory.
+    ///
+    /// The size limit comes from `[attachments] max_bytes`.
+    #[must_use]
+    pub fn attachments(&self) -> AttachmentStore {
+        let max_bytes = SubcogConfig::load_default().attachments.max_bytes;
+        AttachmentStore::new(PathManager::for_user(&self.user_data_dir).attachments_dir())
+            .with_max_bytes(max_bytes)
+    }
+
     /// Returns a reference to the embedder if available.
     #[must_use]
     pub fn embedder(&self) -> Option<Arc<dyn Embedder>> {
@@ -629,6 +1031,12 @@ pub fn vector(&self) -> Option<Arc<dyn VectorBackend + Send + Sync>> {
         self.vector.clone()
     }
 
+    /// Returns a reference to the persistence backend if available.
+    #[must_use]
+    pub fn persistence(&self) -> Option<Arc<dyn PersistenceBackend + Send + Sync>> {
+        self.persistence.clone()
+    }
+
     /// Returns the index backend for the project scope.
     ///
     /// When a factory-created index is available (e.g., PostgreSQL), returns it.
@@ -841,6 +1249,25 @@ fn create_entity_extraction_callback(
         Some(callback)
     }
 
+    /// Creates the default deduplicator for captures.
+    ///
+    /// Exact matches are looked up in the project index's content hashes, so
+    /// captures from other processes count; semantic matching is enabled when
+    /// an embedder and vector backend are available, and the recent-capture
+    /// LRU pre-filters repeats within this process.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index backend cannot be initialized.
+    pub fn deduplicator(&self) -> Result<IndexDeduplicator> {
+        let config = deduplication::DeduplicationConfig::from_env();
+        let mut dedup = IndexDeduplicator::new(self.index()?, config).with_recent_cache();
+        if let (Some(embedder), Some(vector)) = (self.embedder(), self.vector()) {
+            dedup = dedup.with_semantic(embedder, vector);
+        }
+        Ok(dedup)
+    }
+
     /// Creates a deduplication service without embedding support.
     ///
     /// This variant supports:
@@ -910,6 +1337,22 @@ pub fn data_subject(&self) -> Result<DataSubjectService> {
         Ok(service)
     }
 
+    /// Creates an import service that captures through the container's
+    /// capture service.
+    #[must_use]
+    pub fn import(&self) -> ImportService {
+        ImportService::new(Arc::clone(&self.capture))
+    }
+
+    /// Creates an export service over the project-scope index.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the index backend cannot be initialized.
+    pub fn export(&self) -> Result<ExportService> {
+        Ok(ExportService::new(self.index()?))
+    }
+
     /// Gets the index path for a domain scope.
     ///
     /// # Errors
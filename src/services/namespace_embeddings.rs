@@ -0,0 +1,243 @@
+//! Per-namespace embedding routes.
+//!
+//! `[embedding.per_namespace]` can embed a namespace with a model other than
+//! the global one. Each such namespace gets an [`EmbeddingRoute`]: an embedder
+//! for its model and a vector index (`vectors.{namespace}.idx`) sized for it.
+//! Capture embeds and upserts through the route of the memory's namespace;
+//! recall searches the global index plus every route the filter allows and
+//! merges the hits.
+//!
+//! ```text
+//! capture(apis)      → jina-embeddings-v2-base-code → vectors.apis.idx
+//! capture(decisions) → all-MiniLM-L6-v2             → vectors.idx
+//! ```
+//!
+//! Vectors from different models cannot be compared, so the model of every
+//! routed namespace is recorded in `embedding_models.json`.
+//! [`NamespaceEmbeddings::from_config`] refuses to open the routes when the
+//! configured model of a namespace no longer matches the recorded one while
+//! its index still exists.
+
+use super::{BackendFactory, PathManager};
+use crate::config::EmbeddingConfig;
+use crate::embedding::{Embedder, FastEmbedEmbedder};
+use crate::models::Namespace;
+use crate::storage::traits::VectorBackend;
+use crate::storage::vector::UsearchBackend;
+use crate::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::Arc;
+
+/// Embedder and vector index for a namespace with its own model.
+#[derive(Clone)]
+pub struct EmbeddingRoute {
+    /// Name of the embedding model.
+    pub model: String,
+    /// Embedder for the model.
+    pub embedder: Arc<dyn Embedder>,
+    /// Vector index holding the namespace's embeddings.
+    pub vector: Arc<dyn VectorBackend + Send + Sync>,
+}
+
+/// Embedding routes by namespace.
+///
+/// Namespaces without a route use the global embedder and vector index.
+#[derive(Clone, Default)]
+pub struct NamespaceEmbeddings {
+    /// Routes for namespaces with their own model.
+    routes: HashMap<Namespace, EmbeddingRoute>,
+}
+
+impl NamespaceEmbeddings {
+    /// Creates an empty set of routes.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a route for every namespace with its own model in `config`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a configured model is unknown, a vector index
+    /// cannot be opened, or a namespace's configured model differs from the
+    /// model its existing index was built with.
+    pub fn from_config(config: &EmbeddingConfig, paths: &PathManager) -> Result<Self> {
+        check_models(config, paths)?;
+
+        let mut routes = Self::new();
+        for (namespace, model) in config.namespace_models() {
+            let embedder = FastEmbedEmbedder::with_model(model)?;
+            let path = paths.namespace_vector_path(namespace);
+            let vector =
+                BackendFactory::create_vector_backend_with_dimensions(&path, embedder.dimensions())
+                    .ok_or_else(|| Error::OperationFailed {
+                        operation: "open_namespace_vector_index".to_string(),
+                        cause: format!("Cannot open {}", path.display()),
+                    })?;
+            routes = routes.with_route(
+                namespace,
+                EmbeddingRoute {
+                    model: model.to_string(),
+                    embedder: Arc::new(embedder),
+                    vector,
+                },
+            );
+        }
+        Ok(routes)
+    }
+
+    /// Routes `namespace` through `route`.
+    #[must_use]
+    pub fn with_route(mut self, namespace: Namespace, route: EmbeddingRoute) -> Self {
+        self.routes.insert(namespace, route);
+        self
+    }
+
+    /// Returns the route for `namespace`, if it has its own model.
+    #[must_use]
+    pub fn get(&self, namespace: Namespace) -> Option<&EmbeddingRoute> {
+        self.routes.get(&namespace)
+    }
+
+    /// Returns true if no namespace has its own model.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// Returns every route with its namespace.
+    pub fn iter(&self) -> impl Iterator<Item = (Namespace, &EmbeddingRoute)> {
+        self.routes
+            .iter()
+            .map(|(namespace, route)| (*namespace, route))
+    }
+}
+
+/// Models recorded for the routed namespaces (`embedding_models.json`).
+#[derive(Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct EmbeddingManifest {
+    /// Model per namespace name.
+    #[serde(default)]
+    namespaces: BTreeMap<String, String>,
+}
+
+impl EmbeddingManifest {
+    /// Loads the manifest, or an empty one if it does not exist yet.
+    fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| Error::OperationFailed {
+            operation: "read_embedding_manifest".to_string(),
+            cause: e.to_string(),
+        })?;
+        serde_json::from_str(&content).map_err(|e| Error::OperationFailed {
+            operation: "parse_embedding_manifest".to_string(),
+            cause: format!("{}: {e}", path.display()),
+        })
+    }
+
+    /// Writes the manifest.
+    fn save(&self, path: &Path) -> Result<()> {
+        PathManager::ensure_parent_dir(path)?;
+        let content = serde_json::to_string_pretty(self).map_err(|e| Error::OperationFailed {
+            operation: "serialize_embedding_manifest".to_string(),
+            cause: e.to_string(),
+        })?;
+        std::fs::write(path, content).map_err(|e| Error::OperationFailed {
+            operation: "write_embedding_manifest".to_string(),
+            cause: e.to_string(),
+        })
+    }
+}
+
+/// Checks the configured models against the manifest and records them.
+///
+/// A namespace whose model changed is only rejected while its vector index
+/// still exists; removing the index lets it be re-embedded with the new model.
+fn check_models(config: &EmbeddingConfig, paths: &PathManager) -> Result<()> {
+    let manifest_path = paths.embedding_manifest_path();
+    let manifest = EmbeddingManifest::load(&manifest_path)?;
+
+    for (name, recorded) in &manifest.namespaces {
+        let Some(namespace) = Namespace::parse(name) else {
+            continue;
+        };
+        let configured = config.model_for(namespace);
+        let index_files: Vec<_> =
+            UsearchBackend::index_files(&paths.namespace_vector_path(namespace))
+                .into_iter()
+                .filter(|file| file.exists())
+                .collect();
+        if recorded != configured && !index_files.is_empty() {
+            let files: Vec<String> = index_files
+                .iter()
+                .map(|file| file.display().to_string())
+                .collect();
+            return Err(Error::OperationFailed {
+                operation: "check_embedding_models".to_string(),
+                cause: format!(
+                    "Namespace '{name}' was embedded with '{recorded}' but is configured for \
+                     '{configured}'. Remove {} to re-embed it with the new model.",
+                    files.join(" and ")
+                ),
+            });
+        }
+    }
+
+    let current = EmbeddingManifest {
+        namespaces: config
+            .namespace_models()
+            .map(|(namespace, model)| (namespace.as_str().to_string(), model.to_string()))
+            .collect(),
+    };
+    if current != manifest {
+        current.save(&manifest_path)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_change_with_existing_index_is_rejected() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let paths = PathManager::for_user(dir.path());
+        let code = EmbeddingConfig::new()
+            .with_namespace_model(Namespace::Apis, "jina-embeddings-v2-base-code");
+        check_models(&code, &paths).unwrap();
+
+        // Vectors now exist for the recorded model
+        let index_files =
+            UsearchBackend::index_files(&paths.namespace_vector_path(Namespace::Apis));
+        for file in &index_files {
+            std::fs::write(file, b"index").unwrap();
+        }
+        check_models(&code, &paths).unwrap();
+
+        let changed =
+            EmbeddingConfig::new().with_namespace_model(Namespace::Apis, "bge-small-en-v1.5");
+        let err = check_models(&changed, &paths).unwrap_err();
+        assert!(err.to_string().contains("jina-embeddings-v2-base-code"));
+        assert!(matches!(
+            check_models(&EmbeddingConfig::new(), &paths),
+            Err(Error::OperationFailed { .. })
+        ));
+
+        // Removing the stale index allows the switch
+        for file in &index_files {
+            std::fs::remove_file(file).unwrap();
+        }
+        check_models(&changed, &paths).unwrap();
+        let manifest = EmbeddingManifest::load(&paths.embedding_manifest_path()).unwrap();
+        assert_eq!(
+            manifest.namespaces.get("apis").map(String::as_str),
+            Some("bge-small-en-v1.5")
+        );
+    }
+}
@@ -22,6 +22,7 @@
 //! manager.ensure_subcog_dir()?;
 //! ```
 
+use crate::models::Namespace;
 use crate::storage::get_user_data_dir;
 use crate::{Error, Result};
 use std::path::{Path, PathBuf};
@@ -35,9 +36,18 @@
 /// Name of the vector index file.
 pub const VECTOR_INDEX_NAME: &str = "vectors.idx";
 
+/// Name of the file recording the embedding model per namespace.
+pub const EMBEDDING_MANIFEST_NAME: &str = "embedding_models.json";
+
+/// Name of the embedding cache `SQLite` database file.
+pub const EMBEDDING_CACHE_DB_NAME: &str = "embedding_cache.db";
+
 /// Name of the graph `SQLite` database file.
 pub const GRAPH_DB_NAME: &str = "graph.db";
 
+/// Name of the directory holding attachment blobs.
+pub const ATTACHMENTS_DIR_NAME: &str = "attachments";
+
 /// Manages storage paths for subcog backends.
 ///
 /// `PathManager` provides a centralized way to construct paths for:
@@ -146,6 +156,38 @@ pub fn vector_path(&self) -> PathBuf {
         self.subcog_dir.join(VECTOR_INDEX_NAME)
     }
 
+    /// Returns the path to the vector index of a namespace with its own
+    /// embedding model.
+    ///
+    /// # Returns
+    ///
+    /// `{subcog_dir}/vectors.{namespace}.idx`
+    #[must_use]
+    pub fn namespace_vector_path(&self, namespace: Namespace) -> PathBuf {
+        self.subcog_dir
+            .join(format!("vectors.{}.idx", namespace.as_str()))
+    }
+
+    /// Returns the path to the file recording the embedding model per namespace.
+    ///
+    /// # Returns
+    ///
+    /// `{subcog_dir}/embedding_models.json`
+    #[must_use]
+    pub fn embedding_manifest_path(&self) -> PathBuf {
+        self.subcog_dir.join(EMBEDDING_MANIFEST_NAME)
+    }
+
+    /// Returns the path to the embedding cache `SQLite` database.
+    ///
+    /// # Returns
+    ///
+    /// `{subcog_dir}/embedding_cache.db`
+    #[must_use]
+    pub fn embedding_cache_path(&self) -> PathBuf {
+        self.subcog_dir.join(EMBEDDING_CACHE_DB_NAME)
+    }
+
     /// Returns the path to the graph `SQLite` database.
     ///
     /// # Returns
@@ -156,6 +198,16 @@ pub fn graph_path(&self) -> PathBuf {
         self.subcog_dir.join(GRAPH_DB_NAME)
     }
 
+    /// Returns the directory holding content-addressed attachment blobs.
+    ///
+    /// # Returns
+    ///
+    /// `{subcog_dir}/attachments`
+    #[must_use]
+    pub fn attachments_dir(&self) -> PathBuf {
+        self.subcog_dir.join(ATTACHMENTS_DIR_NAME)
+    }
+
     /// Ensures the subcog directory exists.
     ///
     /// Creates the directory and any necessary parent directories.
@@ -245,6 +297,10 @@ fn test_for_user_paths() {
             manager.vector_path(),
             Path::new("/home/user/.local/share/subcog/vectors.idx")
         );
+        assert_eq!(
+            manager.namespace_vector_path(Namespace::Apis),
+            Path::new("/home/user/.local/share/subcog/vectors.apis.idx")
+        );
     }
 
     #[test]
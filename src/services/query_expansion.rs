@@ -0,0 +1,330 @@
+//! LLM query expansion for recall.
+//!
+//! Short queries such as `auth` miss memories phrased as `login`, `OAuth` or
+//! `SSO`. [`QueryExpansionService`] asks the LLM for a few synonyms and
+//! closely related terms, which [`RecallService`](crate::services::RecallService)
+//! ORs into the text search and averages into the query embedding for the
+//! vector search.
+//!
+//! Expansion is best-effort: if the LLM fails, times out, or returns nothing
+//! usable, the raw query is searched on its own. Successful expansions are
+//! cached per query for the lifetime of the process, so repeated searches
+//! cost one LLM call.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::services::QueryExpansionService;
+//!
+//! let recall = services.recall()?.with_query_expansion(Arc::new(QueryExpansionService::new(llm)));
+//! let result = recall.search("auth", SearchMode::Hybrid, &filter, 10)?;
+//! ```
+
+use crate::llm::{LlmProvider, extract_json_from_response};
+use crate::{Error, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, LazyLock, Mutex, PoisonError};
+use std::time::Duration;
+use tracing::instrument;
+
+/// Default time allowed for the expansion LLM call, in milliseconds.
+pub const DEFAULT_EXPANSION_TIMEOUT_MS: u64 = 2_000;
+
+/// Maximum number of expansion terms used per query.
+const MAX_EXPANSION_TERMS: usize = 5;
+
+/// Maximum number of cached expansions (the cache is cleared when full).
+const MAX_CACHED_EXPANSIONS: usize = 256;
+
+/// System prompt for query expansion.
+const EXPANSION_SYSTEM_PROMPT: &str = "You expand search queries over a developer's stored \
+memories (decisions, patterns, learnings). Given a query, list up to 5 synonyms or closely \
+related terms a memory answering it might use instead, most relevant first. Use single words or \
+short phrases; do not repeat the query. Respond with JSON only: {\"terms\": [\"...\", ...]}";
+
+/// Expansions by normalized query, shared by all services in the process.
+static EXPANSION_CACHE: LazyLock<Mutex<HashMap<String, Vec<String>>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Expansion terms returned by the LLM.
+#[derive(Debug, Deserialize)]
+struct ExpansionResponse {
+    /// Synonyms and related terms.
+    terms: Vec<String>,
+}
+
+/// Expands recall queries with LLM-suggested related terms.
+pub struct QueryExpansionService {
+    /// LLM provider suggesting the terms.
+    llm: Arc<dyn LlmProvider>,
+    /// Time allowed for the LLM call.
+    timeout: Duration,
+}
+
+impl QueryExpansionService {
+    /// Creates an expansion service with the default timeout.
+    #[must_use]
+    pub fn new(llm: Arc<dyn LlmProvider>) -> Self {
+        Self {
+            llm,
+            timeout: Duration::from_millis(DEFAULT_EXPANSION_TIMEOUT_MS),
+        }
+    }
+
+    /// Sets the time allowed for the LLM call.
+    #[must_use]
+    pub const fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.timeout = Duration::from_millis(timeout_ms);
+        self
+    }
+
+    /// Returns the terms to search for in addition to `query`.
+    ///
+    /// Returns an empty list if the LLM fails or times out.
+    #[instrument(
+        name = "subcog.query_expansion",
+        skip(self, query),
+        fields(component = "recall", operation = "expand_query")
+    )]
+    pub fn expand(&self, query: &str) -> Vec<String> {
+        let key = normalize(query);
+        if key.is_empty() {
+            return Vec::new();
+        }
+        if let Some(terms) = lock_cache().get(&key) {
+            metrics::counter!("query_expansion_total", "status" => "cached").increment(1);
+            return terms.clone();
+        }
+
+        match self.request_terms(query) {
+            Ok(terms) => {
+                metrics::counter!("query_expansion_total", "status" => "success").increment(1);
+                let terms = sanitize_terms(&key, terms);
+                let mut cache = lock_cache();
+                if cache.len() >= MAX_CACHED_EXPANSIONS {
+                    cache.clear();
+                }
+                cache.insert(key, terms.clone());
+                terms
+            },
+            Err(e) => {
+                metrics::counter!("query_expansion_total", "status" => "error").increment(1);
+                tracing::warn!(error = %e, "Query expansion failed, searching the raw query");
+                Vec::new()
+            },
+        }
+    }
+
+    /// Asks the LLM for related terms, giving up after the timeout.
+    ///
+    /// On timeout the LLM call keeps running on its thread and its result is
+    /// discarded; threads cannot be cancelled.
+    fn request_terms(&self, query: &str) -> Result<Vec<String>> {
+        let (tx, rx) = mpsc::channel();
+        let llm = Arc::clone(&self.llm);
+        let prompt = format!("Query: {query}");
+        std::thread::spawn(move || {
+            // The receiver is gone after a timeout; the result is not needed then
+            let _ = tx.send(llm.complete_with_system(EXPANSION_SYSTEM_PROMPT, &prompt));
+        });
+
+        let response = match rx.recv_timeout(self.timeout) {
+            Ok(response) => response?,
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(Error::OperationFailed {
+                    operation: "expand_query".to_string(),
+                    cause: format!("LLM timed out after {}ms", self.timeout.as_millis()),
+                });
+            },
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(Error::OperationFailed {
+                    operation: "expand_query".to_string(),
+                    cause: "LLM call ended without a response".to_string(),
+                });
+            },
+        };
+        let parsed: ExpansionResponse = serde_json::from_str(extract_json_from_response(&response))
+            .map_err(|e| Error::OperationFailed {
+                operation: "parse_expansion_response".to_string(),
+                cause: format!("Invalid JSON: {e}"),
+            })?;
+        Ok(parsed.terms)
+    }
+}
+
+/// Locks the expansion cache, recovering from a poisoned lock.
+fn lock_cache() -> std::sync::MutexGuard<'static, HashMap<String, Vec<String>>> {
+    EXPANSION_CACHE
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Lowercases `query` and collapses its whitespace.
+fn normalize(query: &str) -> String {
+    query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
+/// Drops blank terms, repeats, and terms equal to the query, keeping at most
+/// [`MAX_EXPANSION_TERMS`].
+fn sanitize_terms(query: &str, terms: Vec<String>) -> Vec<String> {
+    let mut kept: Vec<String> = Vec::new();
+    for term in terms {
+        let term = term.split_whitespace().collect::<Vec<_>>().join(" ");
+        let normalized = term.to_lowercase();
+        if term.is_empty()
+            || normalized == query
+            || kept.iter().any(|k| k.to_lowercase() == normalized)
+        {
+            continue;
+        }
+        kept.push(term);
+        if kept.len() == MAX_EXPANSION_TERMS {
+            break;
+        }
+    }
+    kept
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::CaptureAnalysis;
+    use crate::models::{
+        CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace, SearchFilter, SearchMode,
+    };
+    use crate::services::RecallService;
+    use crate::storage::index::SqliteBackend;
+    use crate::storage::traits::IndexBackend;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// LLM returning a fixed expansion and counting its calls.
+    struct MockExpansionLlm {
+        response: &'static str,
+        delay: Duration,
+        calls: AtomicUsize,
+    }
+
+    impl MockExpansionLlm {
+        fn new(response: &'static str) -> Self {
+            Self {
+                response,
+                delay: Duration::ZERO,
+                calls: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    impl LlmProvider for MockExpansionLlm {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        fn complete(&self, _prompt: &str) -> Result<String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            std::thread::sleep(self.delay);
+            Ok(self.response.to_string())
+        }
+
+        fn analyze_for_capture(&self, _content: &str) -> Result<CaptureAnalysis> {
+            Ok(CaptureAnalysis {
+                should_capture: false,
+                confidence: 0.0,
+                suggested_namespace: None,
+                suggested_tags: vec![],
+                reasoning: "Mock analysis".to_string(),
+            })
+        }
+    }
+
+    fn memory(id: &str, content: &str) -> Memory {
+        Memory {
+            id: MemoryId::new(id),
+            content: content.to_string(),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1,
+            updated_at: 1,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_expanded_query_surfaces_missed_memory() {
+        let index = SqliteBackend::in_memory().unwrap();
+        index
+            .index(&memory(
+                "sso",
+                "Users sign in through OAuth with the company SSO",
+            ))
+            .unwrap();
+        let recall = RecallService::with_index(index);
+        let filter = SearchFilter::new();
+
+        let plain = recall
+            .search("authn", SearchMode::Text, &filter, 10)
+            .unwrap();
+        assert!(plain.memories.is_empty());
+
+        let llm = MockExpansionLlm::new(r#"{"terms": ["login", "OAuth", "SSO"]}"#);
+        let recall =
+            recall.with_query_expansion(Arc::new(QueryExpansionService::new(Arc::new(llm))));
+        let expanded = recall
+            .search("authn", SearchMode::Text, &filter, 10)
+            .unwrap();
+        assert_eq!(expanded.memories.len(), 1);
+        assert_eq!(expanded.memories[0].memory.id.as_str(), "sso");
+    }
+
+    #[test]
+    fn test_expansions_are_cached_and_sanitized() {
+        let llm = Arc::new(MockExpansionLlm::new(concat!(
+            "```json\n",
+            r#"{"terms": ["Deploy", "release", " release ", "", "rollout"]}"#,
+            "\n```"
+        )));
+        let service = QueryExpansionService::new(Arc::clone(&llm) as Arc<dyn LlmProvider>);
+
+        assert_eq!(service.expand("deploy"), vec!["release", "rollout"]);
+        assert_eq!(service.expand("  DEPLOY "), vec!["release", "rollout"]);
+        assert_eq!(llm.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_expansion_falls_back_on_timeout_and_bad_json() {
+        let slow = MockExpansionLlm {
+            delay: Duration::from_millis(200),
+            ..MockExpansionLlm::new(r#"{"terms": ["late"]}"#)
+        };
+        let service = QueryExpansionService::new(Arc::new(slow)).with_timeout_ms(10);
+        assert!(service.expand("timeout fallback query").is_empty());
+
+        let garbled = QueryExpansionService::new(Arc::new(MockExpansionLlm::new("not json")));
+        assert!(garbled.expand("garbled fallback query").is_empty());
+    }
+}
@@ -5,16 +5,19 @@
 //! - `tag:rust` - Filter by tag (AND with other tags)
 //! - `tag:rust,python` - Filter by tags (OR logic)
 //! - `-tag:test` - Exclude memories with tag
-//! - `since:7d` - Filter by time
+//! - `since:7d` - Filter by time (a duration, or a date such as `since:2024-01-01`)
+//! - `until:2024-01-31` - Only memories created at or before a date (or duration ago)
 //! - `source:src/*` - Filter by source pattern
 //! - `status:active` - Filter by status
+//! - `origin:hook` - Filter by capture origin (manual, hook, import, consolidation)
 //! - `project:github.com/org/repo` - Filter by project identifier
 //! - `branch:main` - Filter by branch name
 //! - `path:src/main.rs` - Filter by file path
 //! - `entity:PostgreSQL` - Filter by entity name (memories mentioning this entity)
 //! - `entity:Rust,Python` - Filter by multiple entities (OR logic)
+//! - `section:decision` - Match only within a section of structured memories
 
-use crate::models::{MemoryStatus, Namespace, SearchFilter};
+use crate::models::{CaptureOrigin, MemoryStatus, Namespace, SearchFilter, normalize_section_name};
 
 /// Parses a filter query string into a `SearchFilter`.
 ///
@@ -69,10 +72,15 @@ fn parse_token(token: &str, filter: &mut SearchFilter) {
         },
         "tag" | "tags" => parse_tag_value(value, filter),
         "since" => {
-            if let Some(timestamp) = parse_duration_to_timestamp(value) {
+            if let Some(timestamp) = parse_time_bound(value) {
                 filter.created_after = Some(timestamp);
             }
         },
+        "until" => {
+            if let Some(timestamp) = parse_time_bound(value) {
+                filter.created_before = Some(timestamp);
+            }
+        },
         "source" | "src" => {
             filter.source_pattern = Some(value.to_string());
         },
@@ -90,6 +98,11 @@ fn parse_token(token: &str, filter: &mut SearchFilter) {
                 filter.statuses.push(status);
             }
         },
+        "origin" => {
+            if let Some(origin) = CaptureOrigin::parse(value) {
+                filter.origins.push(origin);
+            }
+        },
         "entity" | "ent" | "entities" => {
             // Entity filter: entity:PostgreSQL or entity:Rust,Python (OR logic)
             filter.entity_names.extend(
@@ -100,6 +113,9 @@ fn parse_token(token: &str, filter: &mut SearchFilter) {
                     .map(ToString::to_string),
             );
         },
+        "section" | "sec" if !value.is_empty() => {
+            filter.section = Some(normalize_section_name(value));
+        },
         _ => {
             // Unknown key, ignore
         },
@@ -139,6 +155,33 @@ fn parse_tag_value(value: &str, filter: &mut SearchFilter) {
     }
 }
 
+/// Parses a time bound into a Unix timestamp.
+///
+/// Accepts a duration relative to now (`12h`, `7d`, `2w`), a date
+/// (`2024-01-01`, midnight UTC), or an RFC 3339 timestamp.
+///
+/// # Examples
+///
+/// ```
+/// use subcog::services::parse_time_bound;
+///
+/// assert_eq!(parse_time_bound("2024-01-01"), Some(1_704_067_200));
+/// assert!(parse_time_bound("7d").is_some());
+/// assert!(parse_time_bound("last sprint").is_none());
+/// ```
+#[must_use]
+pub fn parse_time_bound(value: &str) -> Option<u64> {
+    let value = value.trim();
+    if let Ok(timestamp) = chrono::DateTime::parse_from_rfc3339(value) {
+        return u64::try_from(timestamp.timestamp()).ok();
+    }
+    if let Ok(date) = chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let midnight = date.and_time(chrono::NaiveTime::MIN).and_utc();
+        return u64::try_from(midnight.timestamp()).ok();
+    }
+    parse_duration_to_timestamp(value)
+}
+
 /// Parses a duration string (e.g., "7d", "30d") into a Unix timestamp.
 ///
 /// Returns the timestamp representing "now minus duration".
@@ -259,6 +302,15 @@ fn test_parse_status() {
         assert_eq!(filter.statuses[0], MemoryStatus::Active);
     }
 
+    #[test]
+    fn test_parse_origin() {
+        let filter = parse_filter_query("origin:hook origin:Manual origin:bogus");
+        assert_eq!(
+            filter.origins,
+            vec![CaptureOrigin::Hook, CaptureOrigin::Manual]
+        );
+    }
+
     #[test]
     fn test_parse_source_pattern() {
         let filter = parse_filter_query("source:src/*");
@@ -289,6 +341,16 @@ fn test_parse_since() {
         assert!(diff <= 1);
     }
 
+    #[test]
+    fn test_parse_since_until_dates() {
+        let filter = parse_filter_query("since:2024-01-01 until:2024-01-31T12:00:00Z");
+        assert_eq!(filter.created_after, Some(1_704_067_200));
+        assert_eq!(filter.created_before, Some(1_706_702_400));
+
+        let filter = parse_filter_query("until:someday");
+        assert!(filter.created_before.is_none());
+    }
+
     #[test]
     fn test_parse_complex_query() {
         let filter = parse_filter_query("ns:decisions tag:rust tag:database -tag:test since:30d");
@@ -354,4 +416,11 @@ fn test_parse_entity_filter_aliases() {
         let filter2 = parse_filter_query("entities:Kafka,RabbitMQ");
         assert_eq!(filter2.entity_names.len(), 2);
     }
+
+    #[test]
+    fn test_parse_section_filter() {
+        let filter = parse_filter_query("ns:decisions section:Consequences");
+        assert_eq!(filter.section.as_deref(), Some("consequences"));
+        assert_eq!(filter.namespaces.len(), 1);
+    }
 }
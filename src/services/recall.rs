@@ -40,15 +40,17 @@
 
 use crate::context::GitContext;
 use crate::current_timestamp;
-use crate::embedding::Embedder;
+use crate::embedding::{Embedder, cosine_similarity};
 use crate::gc::branch_exists;
 use crate::models::{
-    EventMeta, Memory, MemoryEvent, MemoryId, MemoryStatus, SearchFilter, SearchHit, SearchMode,
-    SearchResult,
+    EdgeType, EventMeta, FieldWeights, Memory, MemoryEvent, MemoryId, MemoryStatus,
+    RelevanceFeedback, ScopedSearchHit, ScopedSearchResult, SearchFacets, SearchFilter, SearchHit,
+    SearchLegs, SearchMode, SearchResult, VectorEmptyReason,
 };
 use crate::observability::current_request_id;
 use crate::security::record_event;
-use crate::storage::index::SqliteBackend;
+use crate::services::{NamespaceEmbeddings, QueryExpansionService, RecallCache};
+use crate::storage::index::{DomainScope, SqliteBackend};
 use crate::storage::traits::{GraphBackend, IndexBackend, VectorBackend};
 use crate::{Error, Result};
 use chrono::{TimeZone, Utc};
@@ -65,6 +67,55 @@
 /// Default search timeout in milliseconds (5 seconds).
 pub const DEFAULT_SEARCH_TIMEOUT_MS: u64 = 5_000;
 
+/// Candidate multiplier for feedback-boosted searches, so boosted memories
+/// just below the cut can still make it into the results.
+const FEEDBACK_SEARCH_OVERFETCH: usize = 3;
+
+/// Candidates each hybrid search leg contributes to fusion. The pool does not
+/// depend on the requested page, so every page fuses the same candidates and
+/// keeps a stable order; hybrid results end with the fused pool.
+const HYBRID_CANDIDATE_POOL: usize = 200;
+
+/// Upper bound on the matches counted for `total_count` when a search or
+/// listing has more than one page.
+const MAX_COUNTED_MATCHES: usize = 10_000;
+
+/// Seconds either side of a memory's capture time that the memories from
+/// the same source count as captured together with it (`recall --within`).
+const CO_CAPTURE_WINDOW_SECS: u64 = 60;
+
+/// Upper bound on the memories considered for one co-captured set.
+const MAX_CO_CAPTURED: usize = 1_000;
+
+/// Score boost per net useful vote on an identical query.
+const FEEDBACK_BOOST_STEP: f32 = 0.1;
+
+/// Largest score boost (or penalty) from relevance feedback.
+const FEEDBACK_BOOST_MAX: f32 = 0.5;
+
+/// Score boost for pinned memories (`subcog pin`).
+const PINNED_BOOST: f32 = 0.25;
+
+/// Weight of text matches found only through an expansion term
+/// ([`QueryExpansionService`]), relative to matches of the query itself.
+const EXPANSION_TERM_WEIGHT: f32 = 0.8;
+
+/// Default marker wrapped around matched terms in hit snippets.
+pub const DEFAULT_SNIPPET_MARKER: &str = "**";
+
+/// Default weight of vector ranks against BM25 ranks in hybrid search.
+///
+/// At 0.5 both lists count equally, which is plain RRF.
+pub const DEFAULT_HYBRID_ALPHA: f32 = 0.5;
+
+/// Approximate length, in characters, of a snippet taken from the content
+/// when the index has none (vector-only hits).
+const SNIPPET_FALLBACK_CHARS: usize = 160;
+
+/// Maximum query size in bytes; prevents abuse and keeps embedding times
+/// reasonable (MED-RES-005).
+const MAX_QUERY_SIZE: usize = 10_000;
+
 /// Service for searching and retrieving memories.
 ///
 /// Supports three search modes:
@@ -90,12 +141,34 @@ pub struct RecallService {
     embedder: Option<Arc<dyn Embedder>>,
     /// Vector backend for similarity search (optional).
     vector: Option<Arc<dyn VectorBackend + Send + Sync>>,
+    /// Embedders and vector indices for namespaces with their own model (optional).
+    namespace_embeddings: Option<Arc<NamespaceEmbeddings>>,
     /// Graph backend for entity-based filtering (optional).
     graph: Option<Arc<dyn GraphBackend>>,
     /// Scope filter applied to every search (e.g., project facets).
     scope_filter: Option<SearchFilter>,
     /// Search timeout in milliseconds (RES-M5).
     timeout_ms: u64,
+    /// Per-field BM25 weights for text search.
+    field_weights: FieldWeights,
+    /// Optional query-result cache shared across service instances.
+    cache: Option<Arc<RecallCache>>,
+    /// Domain scope searched by this service, used to label its hits in
+    /// [`search_all_scopes`](Self::search_all_scopes).
+    scope: Option<DomainScope>,
+    /// Services for the other scopes searched by
+    /// [`search_all_scopes`](Self::search_all_scopes).
+    other_scopes: Vec<(DomainScope, Self)>,
+    /// Whether relevance feedback adjusts the ranking.
+    feedback_boost: bool,
+    /// LLM query expansion adding related terms to each search (optional).
+    query_expansion: Option<Arc<QueryExpansionService>>,
+    /// Cap on the hits returned by a search of this scope (optional).
+    max_results: Option<usize>,
+    /// Marker wrapped around matched terms in hit snippets.
+    snippet_marker: String,
+    /// Weight of vector ranks against BM25 ranks in hybrid search.
+    hybrid_alpha: f32,
 }
 
 impl RecallService {
@@ -109,9 +182,19 @@ pub fn new() -> Self {
             index: None,
             embedder: None,
             vector: None,
+            namespace_embeddings: None,
             graph: None,
             scope_filter: None,
             timeout_ms: DEFAULT_SEARCH_TIMEOUT_MS,
+            field_weights: FieldWeights::default(),
+            cache: None,
+            scope: None,
+            other_scopes: Vec::new(),
+            feedback_boost: false,
+            query_expansion: None,
+            max_results: None,
+            snippet_marker: DEFAULT_SNIPPET_MARKER.to_string(),
+            hybrid_alpha: DEFAULT_HYBRID_ALPHA,
         }
     }
 
@@ -124,9 +207,19 @@ pub fn with_index(index: SqliteBackend) -> Self {
             index: Some(Arc::new(index)),
             embedder: None,
             vector: None,
+            namespace_embeddings: None,
             graph: None,
             scope_filter: None,
             timeout_ms: DEFAULT_SEARCH_TIMEOUT_MS,
+            field_weights: FieldWeights::default(),
+            cache: None,
+            scope: None,
+            other_scopes: Vec::new(),
+            feedback_boost: false,
+            query_expansion: None,
+            max_results: None,
+            snippet_marker: DEFAULT_SNIPPET_MARKER.to_string(),
+            hybrid_alpha: DEFAULT_HYBRID_ALPHA,
         }
     }
 
@@ -141,9 +234,19 @@ pub fn with_dyn_index(index: Arc<dyn IndexBackend + Send + Sync>) -> Self {
             index: Some(index),
             embedder: None,
             vector: None,
+            namespace_embeddings: None,
             graph: None,
             scope_filter: None,
             timeout_ms: DEFAULT_SEARCH_TIMEOUT_MS,
+            field_weights: FieldWeights::default(),
+            cache: None,
+            scope: None,
+            other_scopes: Vec::new(),
+            feedback_boost: false,
+            query_expansion: None,
+            max_results: None,
+            snippet_marker: DEFAULT_SNIPPET_MARKER.to_string(),
+            hybrid_alpha: DEFAULT_HYBRID_ALPHA,
         }
     }
 
@@ -164,9 +267,19 @@ pub fn with_backends(
             index: Some(Arc::new(index)),
             embedder: Some(embedder),
             vector: Some(vector),
+            namespace_embeddings: None,
             graph: None,
             scope_filter: None,
             timeout_ms: DEFAULT_SEARCH_TIMEOUT_MS,
+            field_weights: FieldWeights::default(),
+            cache: None,
+            scope: None,
+            other_scopes: Vec::new(),
+            feedback_boost: false,
+            query_expansion: None,
+            max_results: None,
+            snippet_marker: DEFAULT_SNIPPET_MARKER.to_string(),
+            hybrid_alpha: DEFAULT_HYBRID_ALPHA,
         }
     }
 
@@ -184,6 +297,14 @@ pub fn with_vector(mut self, vector: Arc<dyn VectorBackend + Send + Sync>) -> Se
         self
     }
 
+    /// Searches the vector indices of namespaces with their own embedding
+    /// model alongside the global one.
+    #[must_use]
+    pub fn with_namespace_embeddings(mut self, routes: Arc<NamespaceEmbeddings>) -> Self {
+        self.namespace_embeddings = Some(routes);
+        self
+    }
+
     /// Adds a graph backend for entity-based filtering.
     ///
     /// When a graph backend is configured and [`SearchFilter::entity_names`] is non-empty,
@@ -217,6 +338,101 @@ pub const fn with_timeout_ms(mut self, timeout_ms: u64) -> Self {
         self
     }
 
+    /// Sets the per-field BM25 weights used by text search.
+    ///
+    /// Backends without per-field ranking ignore the weights.
+    #[must_use]
+    pub const fn with_field_weights(mut self, weights: FieldWeights) -> Self {
+        self.field_weights = weights;
+        self
+    }
+
+    /// Enables read-through caching of search results.
+    ///
+    /// Repeated searches with the same query, mode, filter and limit are
+    /// served from `cache` until it expires or a memory write invalidates it.
+    #[must_use]
+    pub fn with_cache(mut self, cache: Arc<RecallCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Sets the domain scope this service searches.
+    ///
+    /// Hits from this service are labeled with `scope` by
+    /// [`search_all_scopes`](Self::search_all_scopes) (user scope if unset).
+    #[must_use]
+    pub const fn with_scope(mut self, scope: DomainScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// Adds a service for another domain scope to
+    /// [`search_all_scopes`](Self::search_all_scopes).
+    #[must_use]
+    pub fn with_additional_scope(mut self, scope: DomainScope, service: Self) -> Self {
+        self.other_scopes.push((scope, service.with_scope(scope)));
+        self
+    }
+
+    /// Caps the hits a search of this service's scope returns.
+    ///
+    /// Searches asking for a larger limit return at most `max_results` hits;
+    /// [`search_all_scopes`](Self::search_all_scopes) applies the cap of each
+    /// scope before merging.
+    #[must_use]
+    pub const fn with_max_results(mut self, max_results: usize) -> Self {
+        self.max_results = Some(max_results);
+        self
+    }
+
+    /// Ranks memories with their relevance feedback ([`record_feedback`](Self::record_feedback)).
+    ///
+    /// A memory voted useful for a query sharing terms with the current one
+    /// ranks higher, one voted not useful ranks lower.
+    #[must_use]
+    pub const fn with_feedback_boost(mut self, enabled: bool) -> Self {
+        self.feedback_boost = enabled;
+        self
+    }
+
+    /// Sets the marker wrapped around matched terms in hit snippets
+    /// (default `**`, rendering as bold Markdown).
+    #[must_use]
+    pub fn with_snippet_marker(mut self, marker: impl Into<String>) -> Self {
+        self.snippet_marker = marker.into();
+        self
+    }
+
+    /// Sets the weight of vector ranks against BM25 ranks in hybrid search,
+    /// clamped to 0.0 (pure BM25) through 1.0 (pure vector).
+    ///
+    /// A query's [`SearchFilter::hybrid_alpha`] overrides it.
+    #[must_use]
+    pub const fn with_hybrid_alpha(mut self, alpha: f32) -> Self {
+        self.hybrid_alpha = if alpha.is_nan() {
+            DEFAULT_HYBRID_ALPHA
+        } else {
+            alpha.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    /// Expands every query with LLM-suggested related terms (`recall --expand`).
+    ///
+    /// The terms are OR-ed into the text search and averaged into the query
+    /// embedding. Also applies to the services of the other scopes.
+    #[must_use]
+    pub fn with_query_expansion(mut self, expansion: Arc<QueryExpansionService>) -> Self {
+        self.other_scopes = self
+            .other_scopes
+            .into_iter()
+            .map(|(scope, service)| (scope, service.with_query_expansion(Arc::clone(&expansion))))
+            .collect();
+        self.query_expansion = Some(expansion);
+        self
+    }
+
     /// Returns the configured search timeout in milliseconds.
     #[must_use]
     pub const fn timeout_ms(&self) -> u64 {
@@ -226,7 +442,16 @@ pub const fn timeout_ms(&self) -> u64 {
     /// Returns whether vector search is available.
     #[must_use]
     pub fn has_vector_search(&self) -> bool {
-        self.embedder.is_some() && self.vector.is_some()
+        (self.embedder.is_some() && self.vector.is_some())
+            || self
+                .namespace_embeddings
+                .as_ref()
+                .is_some_and(|routes| !routes.is_empty())
+    }
+
+    /// Applies this scope's [`max_results`](Self::with_max_results) cap to `limit`.
+    fn scope_limit(&self, limit: usize) -> usize {
+        self.max_results.map_or(limit, |max| limit.min(max))
     }
 
     fn effective_filter<'a>(&'a self, filter: &'a SearchFilter) -> Cow<'a, SearchFilter> {
@@ -260,6 +485,7 @@ fn effective_filter<'a>(&'a self, filter: &'a SearchFilter) -> Cow<'a, SearchFil
     ///
     /// Returns [`Error::InvalidInput`] if:
     /// - The query is empty or contains only whitespace
+    /// - The filter's `created_after` is later than its `created_before`
     ///
     /// Returns [`Error::OperationFailed`] if:
     /// - No index backend is configured (for `Text` and `Hybrid` modes)
@@ -287,6 +513,11 @@ pub fn search(
         limit: usize,
     ) -> Result<SearchResult> {
         let start = Instant::now();
+        filter.validate_created_range()?;
+        let limit = self.scope_limit(limit);
+        let offset = filter.offset;
+        // Rank one hit past the page to tell whether more results exist
+        let window = offset.saturating_add(limit).saturating_add(1);
         let effective_filter = self.effective_filter(filter);
         let filter = effective_filter.as_ref();
         let domain_label = domain_label(filter);
@@ -295,87 +526,22 @@ pub fn search(
             tracing::Span::current().record("request_id", request_id.as_str());
         }
         tracing::info!(mode = %mode_label, query_length = query.len(), limit = limit, timeout_ms = self.timeout_ms, "Searching memories");
-        // Maximum query size (10KB) - prevents abuse and ensures reasonable embedding times (MED-RES-005)
-        const MAX_QUERY_SIZE: usize = 10_000;
-        // Deadline for timeout enforcement (RES-M5)
-        let deadline_ms = self.timeout_ms;
-        let result = (|| {
-            // Validate query length (MED-RES-005)
-            if query.trim().is_empty() {
-                return Err(Error::InvalidInput("Query cannot be empty".to_string()));
-            }
-            if query.len() > MAX_QUERY_SIZE {
-                return Err(Error::InvalidInput(format!(
-                    "Query exceeds maximum size of {} bytes (got {} bytes)",
-                    MAX_QUERY_SIZE,
-                    query.len()
-                )));
-            }
-            // Check timeout before search (RES-M5)
-            if deadline_ms > 0 && start.elapsed().as_millis() as u64 >= deadline_ms {
-                tracing::warn!(
-                    elapsed_ms = start.elapsed().as_millis(),
-                    timeout_ms = deadline_ms,
-                    "Search timeout before execution"
-                );
-                metrics::counter!("memory_search_timeouts_total", "mode" => mode_label, "phase" => "pre_search").increment(1);
-                return Err(Error::OperationFailed {
-                    operation: "search".to_string(),
-                    cause: format!("Search timeout exceeded ({deadline_ms}ms)"),
-                });
-            }
-            let mut memories = match mode {
-                SearchMode::Text => {
-                    let _span = info_span!("subcog.memory.recall.text_search").entered();
-                    self.text_search(query, filter, limit)?
-                },
-                SearchMode::Vector => {
-                    let _span = info_span!("subcog.memory.recall.vector_search").entered();
-                    self.vector_search(query, filter, limit)?
-                },
-                SearchMode::Hybrid => {
-                    let _span = info_span!("subcog.memory.recall.hybrid_search").entered();
-                    self.hybrid_search(query, filter, limit)?
-                },
-            };
-
-            // Apply entity filter if specified (graph-augmented search)
-            if !filter.entity_names.is_empty() {
-                self.apply_entity_filter(&mut memories, &filter.entity_names);
-            }
-
-            // Check timeout after search (RES-M5)
-            if deadline_ms > 0 && start.elapsed().as_millis() as u64 >= deadline_ms {
-                tracing::warn!(
-                    elapsed_ms = start.elapsed().as_millis(),
-                    timeout_ms = deadline_ms,
-                    results_found = memories.len(),
-                    "Search timeout after execution, returning partial results"
-                );
-                metrics::counter!("memory_search_timeouts_total", "mode" => mode_label, "phase" => "post_search").increment(1);
-                // Return partial results instead of error - graceful degradation
-            }
-
-            // Normalize scores to 0.0-1.0 range for Text and Vector modes
-            // (Hybrid mode already normalizes after RRF fusion)
-            if mode != SearchMode::Hybrid {
-                normalize_scores(&mut memories);
-            }
-
-            self.lazy_tombstone_stale_branches(&mut memories, filter);
-
-            // Safe cast: u128 milliseconds will practically never exceed u64::MAX
-            let execution_time_ms = start.elapsed().as_millis() as u64;
-            let total_count = memories.len();
-            record_recall_events(&memories, query);
-
-            Ok(SearchResult {
-                memories,
-                total_count,
-                mode,
-                execution_time_ms,
-            })
-        })();
+        // Expand before the cache lookup so cached results are keyed by the terms searched
+        let expansions = self.expansions_for(query);
+        let result =
+            self.read_through_cache(query, &expansions, mode, filter, limit, start, || {
+                let result =
+                    self.run_search(query, &expansions, mode, filter, window, start, true)?;
+                let mut result = paginate(result, offset, limit);
+                if result.has_more {
+                    result.total_count =
+                        self.count_matches(query, &expansions, mode, filter, start)?;
+                }
+                result.legs.mark_contributions(&result.memories);
+                self.attach_snippets(&mut result.memories, query);
+                record_recall_events(&result.memories, query);
+                Ok(result)
+            });
 
         let status = if result.is_ok() { "success" } else { "error" };
         metrics::counter!(
@@ -402,6 +568,277 @@ pub fn search(
         result
     }
 
+    /// Counts the memories a search matches per namespace, and with
+    /// `include_tags` per tag, without returning them.
+    ///
+    /// Runs the same search as [`search`](Self::search) with the same
+    /// `limit`, so the counts match what a full recall returns, but reads no
+    /// memory content and bypasses the result cache. An empty or `*` query
+    /// counts every memory matching `filter`, like [`list_all`](Self::list_all).
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`search`](Self::search), or as
+    /// [`list_all`](Self::list_all) for an empty or `*` query.
+    #[allow(clippy::cast_possible_truncation)]
+    #[instrument(
+        name = "subcog.memory.recall.facets",
+        skip(self, query, filter),
+        fields(
+            request_id = tracing::field::Empty,
+            component = "memory",
+            operation = "facets",
+            mode = %mode,
+            limit = limit
+        )
+    )]
+    pub fn facets(
+        &self,
+        query: &str,
+        mode: SearchMode,
+        filter: &SearchFilter,
+        limit: usize,
+        include_tags: bool,
+    ) -> Result<SearchFacets> {
+        let start = Instant::now();
+        let effective_filter = self.effective_filter(filter);
+        let filter = effective_filter.as_ref();
+        if let Some(request_id) = current_request_id() {
+            tracing::Span::current().record("request_id", request_id.as_str());
+        }
+
+        let hits = if query.trim().is_empty() || query.trim() == "*" {
+            let index = self.index.as_ref().ok_or_else(|| Error::OperationFailed {
+                operation: "facets".to_string(),
+                cause: "No index backend configured".to_string(),
+            })?;
+            let results = index.list_all(filter, limit)?;
+            let ids: Vec<_> = results.iter().map(|(id, _)| id.clone()).collect();
+            index
+                .get_memories_metadata_batch(&ids)?
+                .into_iter()
+                .flatten()
+                .map(|memory| self.new_hit(memory, 0.0))
+                .collect()
+        } else {
+            let expansions = self.expansions_for(query);
+            let limit = self.scope_limit(limit);
+            self.run_search(query, &expansions, mode, filter, limit, start, false)?
+                .memories
+        };
+
+        Ok(SearchFacets {
+            // Safe cast: u128 milliseconds will practically never exceed u64::MAX
+            execution_time_ms: start.elapsed().as_millis() as u64,
+            ..SearchFacets::from_hits(&hits, include_tags)
+        })
+    }
+
+    /// Counts the hits a search ranks across all pages, up to
+    /// [`MAX_COUNTED_MATCHES`].
+    ///
+    /// Reads no memory content.
+    fn count_matches(
+        &self,
+        query: &str,
+        expansions: &[String],
+        mode: SearchMode,
+        filter: &SearchFilter,
+        start: Instant,
+    ) -> Result<usize> {
+        let limit = self.scope_limit(MAX_COUNTED_MATCHES);
+        let result = self.run_search(query, expansions, mode, filter, limit, start, false)?;
+        Ok(result.memories.len())
+    }
+
+    /// Returns the expansion terms to search alongside `query`, if query
+    /// expansion is configured.
+    fn expansions_for(&self, query: &str) -> Vec<String> {
+        match &self.query_expansion {
+            Some(expansion) if !query.trim().is_empty() && query.len() <= MAX_QUERY_SIZE => {
+                expansion.expand(query)
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    /// Runs a search: validation, the search legs for `mode`, post-filters,
+    /// and score adjustments.
+    ///
+    /// Hits carry memory content only with `with_content`.
+    #[allow(clippy::cast_possible_truncation, clippy::too_many_arguments)]
+    fn run_search(
+        &self,
+        query: &str,
+        expansions: &[String],
+        mode: SearchMode,
+        filter: &SearchFilter,
+        limit: usize,
+        start: Instant,
+        with_content: bool,
+    ) -> Result<SearchResult> {
+        let mode_label = mode.as_str();
+        // Deadline for timeout enforcement (RES-M5)
+        let deadline_ms = self.timeout_ms;
+        // Validate query length (MED-RES-005)
+        if query.trim().is_empty() {
+            return Err(Error::InvalidInput("Query cannot be empty".to_string()));
+        }
+        if query.len() > MAX_QUERY_SIZE {
+            return Err(Error::InvalidInput(format!(
+                "Query exceeds maximum size of {} bytes (got {} bytes)",
+                MAX_QUERY_SIZE,
+                query.len()
+            )));
+        }
+        // Check timeout before search (RES-M5)
+        if deadline_ms > 0 && start.elapsed().as_millis() as u64 >= deadline_ms {
+            tracing::warn!(
+                elapsed_ms = start.elapsed().as_millis(),
+                timeout_ms = deadline_ms,
+                "Search timeout before execution"
+            );
+            metrics::counter!("memory_search_timeouts_total", "mode" => mode_label, "phase" => "pre_search").increment(1);
+            return Err(Error::OperationFailed {
+                operation: "search".to_string(),
+                cause: format!("Search timeout exceeded ({deadline_ms}ms)"),
+            });
+        }
+        // Feedback re-ranks hits, so over-fetch candidates
+        let search_limit = if self.feedback_boost {
+            limit.saturating_mul(FEEDBACK_SEARCH_OVERFETCH)
+        } else {
+            limit
+        };
+        let (mut memories, legs) =
+            self.search_by_mode(query, expansions, mode, filter, search_limit, with_content)?;
+
+        // Apply entity filter if specified (graph-augmented search)
+        if !filter.entity_names.is_empty() {
+            self.apply_entity_filter(&mut memories, &filter.entity_names);
+        }
+
+        // Check timeout after search (RES-M5)
+        if deadline_ms > 0 && start.elapsed().as_millis() as u64 >= deadline_ms {
+            tracing::warn!(
+                elapsed_ms = start.elapsed().as_millis(),
+                timeout_ms = deadline_ms,
+                results_found = memories.len(),
+                "Search timeout after execution, returning partial results"
+            );
+            metrics::counter!("memory_search_timeouts_total", "mode" => mode_label, "phase" => "post_search").increment(1);
+            // Return partial results instead of error - graceful degradation
+        }
+
+        // Normalize scores to 0.0-1.0 range for Text and Vector modes
+        // (Hybrid mode already normalizes after RRF fusion)
+        if mode != SearchMode::Hybrid {
+            normalize_scores(&mut memories);
+        }
+
+        if self.feedback_boost {
+            self.apply_feedback_boost(&mut memories, query);
+        }
+        apply_pinned_boost(&mut memories);
+        memories.truncate(limit);
+
+        // Hits without content must not be written back to the index
+        self.lazy_tombstone_stale_branches(&mut memories, filter, with_content);
+
+        // Safe cast: u128 milliseconds will practically never exceed u64::MAX
+        let execution_time_ms = start.elapsed().as_millis() as u64;
+        let total_count = memories.len();
+
+        Ok(SearchResult {
+            memories,
+            total_count,
+            has_more: false,
+            mode,
+            execution_time_ms,
+            legs,
+        })
+    }
+
+    /// Serves a search from the cache when one is configured, otherwise runs `search`.
+    ///
+    /// Cache hits still record retrieval events so auditing is unaffected.
+    /// Expanded queries are cached under the query plus its expansion terms.
+    #[allow(clippy::cast_possible_truncation, clippy::too_many_arguments)]
+    fn read_through_cache(
+        &self,
+        query: &str,
+        expansions: &[String],
+        mode: SearchMode,
+        filter: &SearchFilter,
+        limit: usize,
+        start: Instant,
+        search: impl FnOnce() -> Result<SearchResult>,
+    ) -> Result<SearchResult> {
+        let Some(cache) = &self.cache else {
+            return search();
+        };
+
+        let key = if expansions.is_empty() {
+            RecallCache::key(query, mode, filter, limit)
+        } else {
+            RecallCache::key(
+                &format!("{query}\n{}", expansions.join("\n")),
+                mode,
+                filter,
+                limit,
+            )
+        };
+        let (cached, generation) = cache.get(&key);
+        if let Some(mut cached) = cached {
+            // Safe cast: u128 milliseconds will practically never exceed u64::MAX
+            cached.execution_time_ms = start.elapsed().as_millis() as u64;
+            record_recall_events(&cached.memories, query);
+            return Ok(cached);
+        }
+
+        let result = search()?;
+        cache.insert(key, result.clone(), generation);
+        Ok(result)
+    }
+
+    /// Runs the search leg(s) for the given mode, counting each leg's
+    /// candidates.
+    fn search_by_mode(
+        &self,
+        query: &str,
+        expansions: &[String],
+        mode: SearchMode,
+        filter: &SearchFilter,
+        limit: usize,
+        with_content: bool,
+    ) -> Result<(Vec<SearchHit>, SearchLegs)> {
+        match mode {
+            SearchMode::Text => {
+                let _span = info_span!("subcog.memory.recall.text_search").entered();
+                let hits = self.text_search(query, expansions, filter, limit, with_content)?;
+                let legs = SearchLegs {
+                    text_candidates: Some(hits.len()),
+                    ..SearchLegs::default()
+                };
+                Ok((hits, legs))
+            },
+            SearchMode::Vector => {
+                let _span = info_span!("subcog.memory.recall.vector_search").entered();
+                let hits = self.vector_search(query, expansions, filter, limit, with_content)?;
+                let legs = SearchLegs {
+                    vector_candidates: Some(hits.len()),
+                    vector_empty: hits.is_empty().then(|| self.vector_empty_reason()),
+                    ..SearchLegs::default()
+                };
+                Ok((hits, legs))
+            },
+            SearchMode::Hybrid => {
+                let _span = info_span!("subcog.memory.recall.hybrid_search").entered();
+                self.hybrid_search(query, expansions, filter, limit, with_content)
+            },
+        }
+    }
+
     /// Processes stale branch memories in search results.
     ///
     /// Orchestrates CQS-compliant lazy tombstoning:
@@ -410,7 +847,13 @@ pub fn search(
     /// 3. **Query**: Filters out tombstoned if needed (pure transformation)
     ///
     /// This is the entry point that coordinates the separate concerns (ARCH-HIGH-001).
-    fn lazy_tombstone_stale_branches(&self, hits: &mut Vec<SearchHit>, filter: &SearchFilter) {
+    /// Without `persist`, tombstones are applied to the hits only.
+    fn lazy_tombstone_stale_branches(
+        &self,
+        hits: &mut Vec<SearchHit>,
+        filter: &SearchFilter,
+        persist: bool,
+    ) {
         let ctx = GitContext::from_cwd();
         let Some(project_id) = ctx.project_id else {
             return;
@@ -420,7 +863,7 @@ fn lazy_tombstone_stale_branches(&self, hits: &mut Vec<SearchHit>, filter: &Sear
         let tombstoned_ids = Self::mark_stale_branch_hits(hits, &project_id);
 
         // Command: Persist tombstones to index (side effect)
-        if !tombstoned_ids.is_empty() {
+        if persist && !tombstoned_ids.is_empty() {
             self.persist_tombstones_to_index(hits, &tombstoned_ids);
         }
 
@@ -501,6 +944,99 @@ fn persist_tombstones_to_index(&self, hits: &[SearchHit], indices: &[usize]) {
         }
     }
 
+    /// Records whether a memory was useful for a query (`recall --feedback`).
+    ///
+    /// Votes accumulate per normalized query and memory. With
+    /// [`with_feedback_boost`](Self::with_feedback_boost), later searches for
+    /// similar queries rank the memory accordingly.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the query is empty or the memory is
+    /// not in the index, or an error if the vote cannot be stored.
+    pub fn record_feedback(&self, query: &str, memory_id: &MemoryId, useful: bool) -> Result<()> {
+        let index = self.index.as_ref().ok_or_else(|| Error::OperationFailed {
+            operation: "record_feedback".to_string(),
+            cause: "No index backend configured".to_string(),
+        })?;
+        let query = RelevanceFeedback::normalize_query(query);
+        if query.is_empty() {
+            return Err(Error::InvalidInput("Query cannot be empty".to_string()));
+        }
+        if index.get_memory(memory_id)?.is_none() {
+            return Err(Error::InvalidInput(format!(
+                "Memory not found: {}",
+                memory_id.as_str()
+            )));
+        }
+
+        index.record_feedback(&query, memory_id, useful)?;
+        if let Some(cache) = &self.cache {
+            cache.clear();
+        }
+        metrics::counter!(
+            "memory_feedback_total",
+            "vote" => if useful { "up" } else { "down" }
+        )
+        .increment(1);
+        Ok(())
+    }
+
+    /// Adjusts hit scores with the relevance feedback recorded for them.
+    ///
+    /// Each vote counts in proportion to the share of query terms it was
+    /// given for (see [`feedback_boost`]). Hits are re-sorted and scores are
+    /// rescaled so the best hit keeps a score of at most 1.0.
+    fn apply_feedback_boost(&self, hits: &mut [SearchHit], query: &str) {
+        let Some(index) = self.index.as_ref() else {
+            return;
+        };
+        if hits.is_empty() {
+            return;
+        }
+        let ids: Vec<MemoryId> = hits.iter().map(|hit| hit.memory.id.clone()).collect();
+        let feedback = match index.feedback_for(&ids) {
+            Ok(feedback) if !feedback.is_empty() => feedback,
+            Ok(_) => return,
+            Err(e) => {
+                warn!(error = %e, "Failed to load relevance feedback, ranking without it");
+                return;
+            },
+        };
+
+        let mut by_memory: HashMap<&MemoryId, Vec<&RelevanceFeedback>> = HashMap::new();
+        for votes in &feedback {
+            by_memory.entry(&votes.memory_id).or_default().push(votes);
+        }
+        for hit in hits.iter_mut() {
+            if let Some(votes) = by_memory.get(&hit.memory.id) {
+                hit.score *= 1.0 + feedback_boost(votes.iter().copied(), query);
+            }
+        }
+        sort_and_rescale(hits);
+    }
+
+    /// Sets each hit's snippet: the index's highlighted excerpt of the match,
+    /// or else the content around the sentence sharing most query terms.
+    fn attach_snippets(&self, hits: &mut [SearchHit], query: &str) {
+        if hits.is_empty() || query.trim().is_empty() {
+            return;
+        }
+        let mut snippets: HashMap<MemoryId, String> = HashMap::new();
+        if let Some(index) = self.index.as_ref() {
+            let ids: Vec<MemoryId> = hits.iter().map(|hit| hit.memory.id.clone()).collect();
+            match index.snippets(query, &ids, &self.snippet_marker) {
+                Ok(found) => snippets.extend(found),
+                Err(e) => warn!(error = %e, "Failed to load search snippets, using excerpts"),
+            }
+        }
+        for hit in hits {
+            hit.snippet = snippets
+                .remove(&hit.memory.id)
+                .or_else(|| sentence_excerpt(&hit.memory.content, query));
+        }
+    }
+
     /// Filters search results to only include memories that mention specified entities.
     ///
     /// Uses the graph backend to look up entity mentions. If no graph backend is configured,
@@ -604,6 +1140,138 @@ fn load_branch_names() -> Option<HashSet<String>> {
         Some(names)
     }
 
+    /// Searches this service's scope and every additional scope, ranking the
+    /// hits together.
+    ///
+    /// Each scope is searched with [`search`](Self::search) using the same
+    /// query, mode, filter and limit, starting with this service's scope. A
+    /// scope with a [`max_results`](Self::with_max_results) cap contributes at
+    /// most that many hits to the merge.
+    /// Hits are annotated with the scope they came from and deduplicated by
+    /// memory ID and by identical content, keeping the copy from the earliest
+    /// scope. The remaining hits are ranked by raw score and re-normalized so
+    /// the best merged hit scores 1.0.
+    ///
+    /// Additional scopes whose search fails are skipped with a warning.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`search`](Self::search) for this
+    /// service's own scope.
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn search_all_scopes(
+        &self,
+        query: &str,
+        mode: SearchMode,
+        filter: &SearchFilter,
+        limit: usize,
+    ) -> Result<ScopedSearchResult> {
+        let start = Instant::now();
+        let own_scope = self.scope.unwrap_or(DomainScope::User);
+        // Page the merged ranking, not each scope's own ranking, ranking one
+        // hit past the page to tell whether more results exist
+        let offset = filter.offset;
+        let window = offset.saturating_add(limit).saturating_add(1);
+        let filter = &filter.clone().with_offset(0);
+
+        let own = self.search(query, mode, filter, window)?;
+        let mut matches = own.total_count;
+        let mut scopes = vec![own_scope];
+        let mut hits: Vec<ScopedSearchHit> = own
+            .memories
+            .into_iter()
+            .map(|hit| ScopedSearchHit {
+                scope: own_scope,
+                hit,
+            })
+            .collect();
+
+        for (scope, service) in &self.other_scopes {
+            match service.search(query, mode, filter, window) {
+                Ok(result) => {
+                    scopes.push(*scope);
+                    matches += result.total_count;
+                    hits.extend(
+                        result
+                            .memories
+                            .into_iter()
+                            .map(|hit| ScopedSearchHit { scope: *scope, hit }),
+                    );
+                },
+                Err(e) => {
+                    warn!(scope = scope.as_str(), error = %e, "Scope search failed, skipping");
+                },
+            }
+        }
+
+        // Memories found in several scopes count once; duplicates past the
+        // ranked windows cannot be seen and are counted per scope
+        let ranked = hits.len();
+        let mut memories = merge_scoped_hits(hits, usize::MAX);
+        let total_count = matches.saturating_sub(ranked - memories.len());
+        memories.truncate(window);
+        memories.drain(..offset.min(memories.len()));
+        let has_more = memories.len() > limit;
+        memories.truncate(limit);
+        Ok(ScopedSearchResult {
+            total_count,
+            has_more,
+            memories,
+            mode,
+            scopes,
+            execution_time_ms: start.elapsed().as_millis() as u64,
+        })
+    }
+
+    /// Returns a memory's relationship neighborhood: the memory itself plus
+    /// every memory reachable within `depth` hops over memory edges in either
+    /// direction (links, supersession, consolidation), a summary's list of
+    /// sources, or the co-captured set (same source, captured within a
+    /// minute).
+    ///
+    /// An isolated memory's neighborhood is just the memory. Pass the result to
+    /// [`SearchFilter::with_memory_ids`] to search within the neighborhood.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the memory does not exist.
+    ///
+    /// Returns [`Error::OperationFailed`] if no index backend is configured or
+    /// a lookup fails.
+    pub fn neighborhood(&self, id: &MemoryId, depth: usize) -> Result<Vec<MemoryId>> {
+        let index = self.index.as_ref().ok_or_else(|| Error::OperationFailed {
+            operation: "neighborhood".to_string(),
+            cause: "No index backend configured".to_string(),
+        })?;
+        if index.get_memory(id)?.is_none() {
+            return Err(Error::InvalidInput(format!(
+                "Memory not found: {}",
+                id.as_str()
+            )));
+        }
+
+        let mut seen = HashSet::from([id.clone()]);
+        let mut members = vec![id.clone()];
+        let mut frontier = vec![id.clone()];
+        for _ in 0..depth {
+            let mut next = Vec::new();
+            for node in &frontier {
+                let linked = linked_memories(index.as_ref(), node)?;
+                next.extend(
+                    linked
+                        .into_iter()
+                        .filter(|linked| seen.insert(linked.clone())),
+                );
+            }
+            if next.is_empty() {
+                break;
+            }
+            members.extend(next.iter().cloned());
+            frontier = next;
+        }
+        Ok(members)
+    }
+
     /// Lists all memories, optionally filtered by namespace.
     ///
     /// Unlike `search`, this doesn't require a query and returns all matching memories.
@@ -611,6 +1279,9 @@ fn load_branch_names() -> Option<HashSet<String>> {
     ///
     /// # Errors
     ///
+    /// Returns [`Error::InvalidInput`] if the filter's `created_after` is
+    /// later than its `created_before`.
+    ///
     /// Returns [`Error::OperationFailed`] if:
     /// - No index backend is configured
     /// - The index backend list operation fails
@@ -628,6 +1299,7 @@ fn load_branch_names() -> Option<HashSet<String>> {
     )]
     pub fn list_all(&self, filter: &SearchFilter, limit: usize) -> Result<SearchResult> {
         let start = Instant::now();
+        filter.validate_created_range()?;
         let effective_filter = self.effective_filter(filter);
         let filter = effective_filter.as_ref();
         let domain_label = domain_label(filter);
@@ -641,41 +1313,41 @@ pub fn list_all(&self, filter: &SearchFilter, limit: usize) -> Result<SearchResu
                 cause: "No index backend configured".to_string(),
             })?;
 
-            let results = index.list_all(filter, limit)?;
+            // Fetch one past the page to tell whether more memories exist
+            let window = filter.offset.saturating_add(limit).saturating_add(1);
+            let results = index.list_all(filter, window)?;
 
-            // PERF-C1: Use batch query instead of N+1 individual get_memory calls
+            // PERF-C1: Use batch query instead of N+1 individual get_memory calls;
+            // content is not read for the lightweight response
             let ids: Vec<_> = results.iter().map(|(id, _)| id.clone()).collect();
-            let batch_memories = index.get_memories_batch(&ids)?;
+            let batch_memories = index.get_memories_metadata_batch(&ids)?;
 
-            // Zip results with fetched memories, clearing content for lightweight response
             let memories: Vec<SearchHit> = results
                 .into_iter()
                 .zip(batch_memories)
                 .filter_map(|((_, score), memory_opt)| {
-                    memory_opt.map(|mut memory| {
-                        // Clear content for lightweight response
-                        memory.content = String::new();
-                        SearchHit {
-                            memory,
-                            score,
-                            raw_score: score,
-                            vector_score: None,
-                            bm25_score: None,
-                        }
-                    })
+                    memory_opt.map(|memory| self.new_hit(memory, score))
                 })
                 .collect();
 
-            let execution_time_ms = start.elapsed().as_millis() as u64;
-            let total_count = memories.len();
-            record_recall_events(&memories, "*");
+            let mut result = paginate(
+                SearchResult {
+                    total_count: memories.len(),
+                    memories,
+                    has_more: false,
+                    mode: SearchMode::Text,
+                    execution_time_ms: start.elapsed().as_millis() as u64,
+                    legs: SearchLegs::default(),
+                },
+                filter.offset,
+                limit,
+            );
+            if result.has_more {
+                result.total_count = index.list_all(filter, MAX_COUNTED_MATCHES)?.len();
+            }
+            record_recall_events(&result.memories, "*");
 
-            Ok(SearchResult {
-                memories,
-                total_count,
-                mode: SearchMode::Text,
-                execution_time_ms,
-            })
+            Ok(result)
         })();
 
         let status = if result.is_ok() { "success" } else { "error" };
@@ -755,13 +1427,8 @@ pub fn list_all_with_content(
                 .into_iter()
                 .zip(batch_memories)
                 .filter_map(|((_, score), memory_opt)| {
-                    memory_opt.map(|memory| SearchHit {
-                        memory, // Content preserved for topic extraction
-                        score,
-                        raw_score: score,
-                        vector_score: None,
-                        bm25_score: None,
-                    })
+                    // Content preserved for topic extraction
+                    memory_opt.map(|memory| self.new_hit(memory, score))
                 })
                 .collect();
 
@@ -772,8 +1439,10 @@ pub fn list_all_with_content(
             Ok(SearchResult {
                 memories,
                 total_count,
+                has_more: false,
                 mode: SearchMode::Text,
                 execution_time_ms,
+                legs: SearchLegs::default(),
             })
         })();
 
@@ -802,6 +1471,17 @@ pub fn list_all_with_content(
         result
     }
 
+    /// Creates a hit attributed to this service's scope.
+    ///
+    /// Services without an explicit scope attribute hits by memory domain.
+    fn new_hit(&self, memory: Memory, score: f32) -> SearchHit {
+        let mut hit = SearchHit::new(memory, score);
+        if let Some(service_scope) = self.scope {
+            hit.scope = service_scope;
+        }
+        hit
+    }
+
     /// Performs BM25 text search.
     ///
     /// Note: Scores are NOT normalized here. Normalization is applied:
@@ -810,19 +1490,35 @@ pub fn list_all_with_content(
     fn text_search(
         &self,
         query: &str,
+        expansions: &[String],
         filter: &SearchFilter,
         limit: usize,
+        with_content: bool,
     ) -> Result<Vec<SearchHit>> {
         let index = self.index.as_ref().ok_or_else(|| Error::OperationFailed {
             operation: "text_search".to_string(),
             cause: "No index backend configured".to_string(),
         })?;
 
-        let results = index.search(query, filter, limit)?;
+        let mut results = index.search_weighted(query, filter, &self.field_weights, limit)?;
+        if !expansions.is_empty() {
+            // Each term is searched on its own (OR semantics on every backend);
+            // its matches rank below equally good matches of the query itself
+            for term in expansions {
+                let term_results =
+                    index.search_weighted(term, filter, &self.field_weights, limit)?;
+                results.extend(
+                    term_results
+                        .into_iter()
+                        .map(|(id, score)| (id, score * EXPANSION_TERM_WEIGHT)),
+                );
+            }
+            results = merge_vector_results(results, limit);
+        }
 
         // PERF-C1: Use batch query instead of N+1 individual get_memory calls
         let ids: Vec<_> = results.iter().map(|(id, _)| id.clone()).collect();
-        let batch_memories = index.get_memories_batch(&ids)?;
+        let batch_memories = fetch_memories(index.as_ref(), &ids, with_content)?;
 
         // Convert to SearchHits - zip with fetched memories
         let hits: Vec<SearchHit> = results
@@ -831,11 +1527,8 @@ fn text_search(
             .map(|((id, score), memory_opt)| {
                 let memory = memory_opt.unwrap_or_else(|| create_placeholder_memory(id));
                 SearchHit {
-                    memory,
-                    score,
-                    raw_score: score,
-                    vector_score: None,
                     bm25_score: Some(score),
+                    ..self.new_hit(memory, score)
                 }
             })
             .collect();
@@ -845,6 +1538,10 @@ fn text_search(
 
     /// Performs vector similarity search.
     ///
+    /// Searches the global vector index and the index of every namespace with
+    /// its own embedding model that `filter` allows, each with a query
+    /// embedding from its own model, and keeps the best score per memory.
+    ///
     /// # Graceful Degradation
     ///
     /// Returns empty results (not an error) if:
@@ -857,40 +1554,62 @@ fn text_search(
     fn vector_search(
         &self,
         query: &str,
+        expansions: &[String],
         filter: &SearchFilter,
         limit: usize,
+        with_content: bool,
     ) -> Result<Vec<SearchHit>> {
-        // Check if we have the required components
-        let (embedder, vector) = match (&self.embedder, &self.vector) {
-            (Some(e), Some(v)) => (e, v),
+        let vector_filter = crate::storage::traits::VectorFilter::from(filter);
+        let queries: Vec<&str> = std::iter::once(query)
+            .chain(expansions.iter().map(String::as_str))
+            .collect();
+        // Vectors embed whole memories, so a section-scoped search ranks the
+        // memories whose section matches in the index by their stored vectors
+        let section_matches = self.section_matches(&queries, filter)?;
+        let mut results = Vec::new();
+        match (&self.embedder, &self.vector) {
+            (Some(embedder), Some(vector)) => {
+                results.extend(search_vector_index(
+                    embedder.as_ref(),
+                    vector.as_ref(),
+                    &queries,
+                    &vector_filter,
+                    section_matches.as_deref(),
+                    limit,
+                ));
+            },
             (None, _) => {
-                tracing::debug!("Vector search unavailable: no embedder configured");
-                return Ok(Vec::new());
+                tracing::debug!("Global vector search unavailable: no embedder configured");
             },
             (_, None) => {
-                tracing::debug!("Vector search unavailable: no vector backend configured");
-                return Ok(Vec::new());
+                tracing::debug!("Global vector search unavailable: no vector backend configured");
             },
-        };
+        }
 
-        // Generate query embedding
-        let query_embedding = match embedder.embed(query) {
-            Ok(emb) => emb,
-            Err(e) => {
-                tracing::warn!("Failed to embed query for vector search: {e}");
-                return Ok(Vec::new());
-            },
-        };
+        // Namespaces with their own model keep their vectors in their own index
+        if let Some(routes) = &self.namespace_embeddings {
+            let searched = routes
+                .iter()
+                .filter(|(namespace, _)| {
+                    filter.namespaces.is_empty() || filter.namespaces.contains(namespace)
+                })
+                .flat_map(|(_, route)| {
+                    search_vector_index(
+                        route.embedder.as_ref(),
+                        route.vector.as_ref(),
+                        &queries,
+                        &vector_filter,
+                        section_matches.as_deref(),
+                        limit,
+                    )
+                });
+            results.extend(searched);
+            results = merge_vector_results(results, limit);
+        }
 
-        // Search vector backend (convert SearchFilter to VectorFilter)
-        let vector_filter = crate::storage::traits::VectorFilter::from(filter);
-        let results = match vector.search(&query_embedding, &vector_filter, limit) {
-            Ok(r) => r,
-            Err(e) => {
-                tracing::warn!("Vector search failed: {e}");
-                return Ok(Vec::new());
-            },
-        };
+        if results.is_empty() {
+            return Ok(Vec::new());
+        }
 
         // Get index backend to retrieve full memories
         let index = match &self.index {
@@ -900,11 +1619,8 @@ fn vector_search(
                 return Ok(results
                     .into_iter()
                     .map(|(id, score)| SearchHit {
-                        memory: create_placeholder_memory(id),
-                        score,
-                        raw_score: score,
                         vector_score: Some(score),
-                        bm25_score: None,
+                        ..self.new_hit(create_placeholder_memory(id), score)
                     })
                     .collect());
             },
@@ -912,7 +1628,7 @@ fn vector_search(
 
         // PERF: Batch fetch memories for vector results
         let ids: Vec<_> = results.iter().map(|(id, _)| id.clone()).collect();
-        let batch_memories = match index.get_memories_batch(&ids) {
+        let batch_memories = match fetch_memories(index.as_ref(), &ids, with_content) {
             Ok(m) => m,
             Err(e) => {
                 tracing::warn!("Failed to fetch memories for vector results: {e}");
@@ -920,11 +1636,8 @@ fn vector_search(
                 return Ok(results
                     .into_iter()
                     .map(|(id, score)| SearchHit {
-                        memory: create_placeholder_memory(id),
-                        score,
-                        raw_score: score,
                         vector_score: Some(score),
-                        bm25_score: None,
+                        ..self.new_hit(create_placeholder_memory(id), score)
                     })
                     .collect());
             },
@@ -937,36 +1650,141 @@ fn vector_search(
             .map(|((id, score), memory_opt)| {
                 let memory = memory_opt.unwrap_or_else(|| create_placeholder_memory(id));
                 SearchHit {
-                    memory,
-                    score,
-                    raw_score: score,
                     vector_score: Some(score),
-                    bm25_score: None,
+                    ..self.new_hit(memory, score)
                 }
             })
+            // The vector backend cannot filter by origin, confidence, language, or ID, so
+            // apply them here
+            .filter(|hit| filter.origins.is_empty() || filter.origins.contains(&hit.memory.origin))
+            .filter(|hit| {
+                filter
+                    .language
+                    .as_ref()
+                    .is_none_or(|language| hit.memory.language.as_ref() == Some(language))
+            })
+            .filter(|hit| {
+                filter.memory_ids.is_empty() || filter.memory_ids.contains(&hit.memory.id)
+            })
+            .filter(|hit| {
+                filter
+                    .min_confidence
+                    .is_none_or(|min| hit.memory.confidence.is_some_and(|c| c >= min))
+            })
             .collect();
 
         Ok(hits)
     }
 
+    /// Returns the memories whose `filter.section` matches one of `queries`
+    /// in the index, up to [`MAX_COUNTED_MATCHES`] per query, or `None` when
+    /// the search is not section-scoped. Without an index nothing matches.
+    ///
+    /// Ignores `filter.min_score`, which applies to the caller's scores.
+    fn section_matches(
+        &self,
+        queries: &[&str],
+        filter: &SearchFilter,
+    ) -> Result<Option<Vec<MemoryId>>> {
+        if filter.section.is_none() {
+            return Ok(None);
+        }
+        // Sections are only searchable in the index
+        let Some(index) = &self.index else {
+            return Ok(Some(Vec::new()));
+        };
+        let filter = SearchFilter {
+            min_score: None,
+            ..filter.clone()
+        };
+        let mut seen = HashSet::new();
+        let mut matches = Vec::new();
+        for query in queries {
+            let found =
+                index.search_weighted(query, &filter, &self.field_weights, MAX_COUNTED_MATCHES)?;
+            matches.extend(
+                found
+                    .into_iter()
+                    .map(|(id, _)| id)
+                    .filter(|id| seen.insert(id.clone())),
+            );
+        }
+        Ok(Some(matches))
+    }
+
     /// Performs hybrid search with RRF fusion.
+    ///
+    /// Each leg ranks [`HYBRID_CANDIDATE_POOL`] candidates whatever `limit`
+    /// is, and the top `limit` fused hits are returned.
     fn hybrid_search(
         &self,
         query: &str,
+        expansions: &[String],
         filter: &SearchFilter,
         limit: usize,
-    ) -> Result<Vec<SearchHit>> {
+        with_content: bool,
+    ) -> Result<(Vec<SearchHit>, SearchLegs)> {
         // Get results from both search modes
-        let text_results = self.text_search(query, filter, limit * 2)?;
-        let vector_results = self.vector_search(query, filter, limit * 2)?;
+        let pool = HYBRID_CANDIDATE_POOL;
+        let text_results = self.text_search(query, expansions, filter, pool, with_content)?;
+        let vector_results = self.vector_search(query, expansions, filter, pool, with_content)?;
+
+        let mut legs = SearchLegs {
+            text_candidates: Some(text_results.len()),
+            vector_candidates: Some(vector_results.len()),
+            vector_empty: vector_results
+                .is_empty()
+                .then(|| self.vector_empty_reason()),
+            ..SearchLegs::default()
+        };
+        if vector_results.is_empty() {
+            tracing::debug!(
+                reason = legs.vector_empty.map_or("", VectorEmptyReason::describe),
+                "Hybrid search served by text search only"
+            );
+        }
 
-        // Apply Reciprocal Rank Fusion
-        let mut fused = self.rrf_fusion(&text_results, &vector_results, limit);
+        // Apply Reciprocal Rank Fusion, weighting each list by alpha, unless
+        // only the vector leg found anything and the query falls back to it
+        let alpha = filter.hybrid_alpha.unwrap_or(self.hybrid_alpha);
+        legs.vector_only =
+            filter.vector_only_fallback && text_results.is_empty() && !vector_results.is_empty();
+        let mut fused = if legs.vector_only {
+            tracing::debug!("Hybrid search served by vector search only");
+            let mut hits = vector_results;
+            hits.truncate(limit);
+            hits
+        } else {
+            self.rrf_fusion(&text_results, &vector_results, alpha, limit)
+        };
 
         // Normalize scores to 0.0-1.0 range
         normalize_scores(&mut fused);
 
-        Ok(fused)
+        Ok((fused, legs))
+    }
+
+    /// Tells why vector search found no candidates: no backend to search,
+    /// nothing embedded in any searched index, or no similar memories.
+    fn vector_empty_reason(&self) -> VectorEmptyReason {
+        let global = match (&self.embedder, &self.vector) {
+            (Some(_), Some(vector)) => Some(vector.as_ref()),
+            _ => None,
+        };
+        let routed = self
+            .namespace_embeddings
+            .iter()
+            .flat_map(|routes| routes.iter().map(|(_, route)| route.vector.as_ref()));
+        let mut searched = global.into_iter().chain(routed).peekable();
+        if searched.peek().is_none() {
+            return VectorEmptyReason::Unavailable;
+        }
+        // An index whose size is unknown may hold embeddings
+        if searched.all(|vector| vector.count().is_ok_and(|count| count == 0)) {
+            VectorEmptyReason::NothingEmbedded
+        } else {
+            VectorEmptyReason::NoMatches
+        }
     }
 
     /// Applies Reciprocal Rank Fusion (RRF) to combine search results.
@@ -984,6 +1802,10 @@ fn hybrid_search(
     /// - `k` = 60 (standard constant, prevents division by zero and dampens high ranks)
     /// - `rank_r(d)` = position of document `d` in ranking `r` (1-indexed)
     ///
+    /// Each term is weighted by `alpha`: BM25 ranks by `2 * (1 - alpha)` and
+    /// vector ranks by `2 * alpha`, so the default 0.5 is unweighted RRF. A list
+    /// with weight 0 is ignored, leaving only the other list's hits.
+    ///
     /// # Why RRF?
     ///
     /// - **Score normalization**: Raw scores from different retrievers (BM25 vs cosine)
@@ -1014,9 +1836,18 @@ fn rrf_fusion(
         &self,
         text_results: &[SearchHit],
         vector_results: &[SearchHit],
+        alpha: f32,
         limit: usize,
     ) -> Vec<SearchHit> {
         const K: f32 = 60.0; // Standard RRF constant
+        let text_weight = 2.0 * (1.0 - alpha);
+        let vector_weight = 2.0 * alpha;
+        let text_results = if text_weight > 0.0 { text_results } else { &[] };
+        let vector_results = if vector_weight > 0.0 {
+            vector_results
+        } else {
+            &[]
+        };
 
         // Use indices instead of cloning SearchHits (PERF-C2)
         // Store: (rrf_score, text_index, vector_index, vector_score)
@@ -1029,7 +1860,7 @@ fn rrf_fusion(
         // Add text results - store indices instead of cloning (PERF-C2)
         for (rank, hit) in text_results.iter().enumerate() {
             let id = hit.memory.id.to_string();
-            let rrf_score = 1.0 / (K + rank as f32 + 1.0);
+            let rrf_score = text_weight / (K + rank as f32 + 1.0);
 
             scores
                 .entry(id)
@@ -1040,7 +1871,7 @@ fn rrf_fusion(
         // Add vector results - merge with existing or insert index (PERF-C2)
         for (rank, hit) in vector_results.iter().enumerate() {
             let id = hit.memory.id.to_string();
-            let rrf_score = 1.0 / (K + rank as f32 + 1.0);
+            let rrf_score = vector_weight / (K + rank as f32 + 1.0);
 
             scores
                 .entry(id)
@@ -1074,10 +1905,12 @@ fn rrf_fusion(
             })
             .collect();
 
+        // Break ties by ID: map iteration order is random and pages must agree
         results.sort_by(|a, b| {
             b.score
                 .partial_cmp(&a.score)
                 .unwrap_or(std::cmp::Ordering::Equal)
+                .then_with(|| a.memory.id.as_str().cmp(b.memory.id.as_str()))
         });
         results.truncate(limit);
 
@@ -1164,6 +1997,20 @@ pub fn get_by_id_authorized(
     }
 }
 
+/// Fetches the memories of search results, reading content only when
+/// `with_content` is set.
+fn fetch_memories(
+    index: &dyn IndexBackend,
+    ids: &[MemoryId],
+    with_content: bool,
+) -> Result<Vec<Option<Memory>>> {
+    if with_content {
+        index.get_memories_batch(ids)
+    } else {
+        index.get_memories_metadata_batch(ids)
+    }
+}
+
 /// Returns a domain label for metrics, avoiding allocations for common cases.
 fn domain_label(filter: &SearchFilter) -> Cow<'static, str> {
     match filter.domains.len() {
@@ -1186,6 +2033,115 @@ fn record_recall_events(memories: &[SearchHit], query: &str) {
     }
 }
 
+/// Returns about [`SNIPPET_FALLBACK_CHARS`] characters of `content`, starting
+/// at the sentence containing the most query terms (the first on ties).
+///
+/// Whitespace is collapsed and `…` marks cut text. Returns `None` for an
+/// empty query or content.
+fn sentence_excerpt(content: &str, query: &str) -> Option<String> {
+    let terms: Vec<String> = query
+        .split_whitespace()
+        .map(|term| {
+            term.trim_matches(|c: char| !c.is_alphanumeric())
+                .to_lowercase()
+        })
+        .filter(|term| !term.is_empty())
+        .collect();
+    if terms.is_empty() {
+        return None;
+    }
+
+    // Sentence boundaries are byte offsets just past a terminator, so every
+    // slice below falls on a character boundary
+    let mut bounds = vec![0];
+    bounds.extend(
+        content
+            .char_indices()
+            .filter(|&(_, c)| matches!(c, '.' | '!' | '?' | '\n'))
+            .map(|(i, c)| i + c.len_utf8()),
+    );
+    bounds.push(content.len());
+    let (start, _) = bounds
+        .windows(2)
+        .map(|pair| {
+            let sentence = content[pair[0]..pair[1]].to_lowercase();
+            let matched = terms
+                .iter()
+                .filter(|term| sentence.contains(term.as_str()))
+                .count();
+            (pair[0], matched)
+        })
+        .fold((0, 0), |best, (start, matched)| {
+            if matched > best.1 {
+                (start, matched)
+            } else {
+                best
+            }
+        });
+
+    let text = content[start..]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    if text.is_empty() {
+        return None;
+    }
+    let mut excerpt = String::with_capacity(SNIPPET_FALLBACK_CHARS + 8);
+    if !content[..start].trim().is_empty() {
+        excerpt.push('…');
+    }
+    excerpt.extend(text.chars().take(SNIPPET_FALLBACK_CHARS));
+    if text.chars().nth(SNIPPET_FALLBACK_CHARS).is_some() {
+        excerpt.push('…');
+    }
+    Some(excerpt)
+}
+
+/// Computes the relative score boost from relevance feedback for `query`.
+///
+/// Every rated query contributes its net votes (useful minus not useful),
+/// weighted by the share of `query` terms it contains, and each net vote is
+/// worth [`FEEDBACK_BOOST_STEP`]. The result is clamped to
+/// ±[`FEEDBACK_BOOST_MAX`].
+fn feedback_boost<'a>(votes: impl IntoIterator<Item = &'a RelevanceFeedback>, query: &str) -> f32 {
+    #[allow(clippy::cast_precision_loss)]
+    let net: f32 = votes
+        .into_iter()
+        .map(|v| v.net() as f32 * v.overlap(query))
+        .sum();
+    (net * FEEDBACK_BOOST_STEP).clamp(-FEEDBACK_BOOST_MAX, FEEDBACK_BOOST_MAX)
+}
+
+/// Boosts the scores of pinned hits by [`PINNED_BOOST`].
+///
+/// Hits are re-sorted and rescaled like in
+/// [`apply_feedback_boost`](RecallService::apply_feedback_boost).
+fn apply_pinned_boost(hits: &mut [SearchHit]) {
+    if !hits.iter().any(|hit| hit.memory.pinned) {
+        return;
+    }
+    for hit in hits.iter_mut().filter(|hit| hit.memory.pinned) {
+        hit.score *= 1.0 + PINNED_BOOST;
+    }
+    sort_and_rescale(hits);
+}
+
+/// Sorts hits by descending score and rescales the scores so the best hit
+/// keeps a score of at most 1.0.
+fn sort_and_rescale(hits: &mut [SearchHit]) {
+    hits.sort_by(|a, b| {
+        b.score
+            .partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let max_score = hits.first().map_or(0.0, |hit| hit.score);
+    if max_score > 1.0 {
+        for hit in hits.iter_mut() {
+            hit.score /= max_score;
+        }
+    }
+}
+
 /// Normalizes search result scores to the 0.0-1.0 range.
 ///
 /// # Algorithm
@@ -1235,16 +2191,203 @@ fn normalize_scores(results: &mut [SearchHit]) {
     }
 }
 
+/// Skips the first `offset` hits and keeps one page of `limit`.
+///
+/// `result` must hold at least one hit past the page when more exist.
+/// `total_count` keeps the number of hits ranked before paging, which is the
+/// number of matches unless `has_more` is set.
+fn paginate(mut result: SearchResult, offset: usize, limit: usize) -> SearchResult {
+    result.total_count = result.memories.len();
+    result.memories.drain(..offset.min(result.memories.len()));
+    result.has_more = result.memories.len() > limit;
+    result.memories.truncate(limit);
+    result
+}
+
+/// Ranks hits from several scopes together, dropping duplicates.
+///
+/// `hits` must be in scope order: a hit whose memory ID or trimmed content
+/// was already seen in an earlier scope is dropped. The rest are ordered by
+/// raw score (stable, so earlier scopes win ties).
+fn merge_scoped_hits(mut hits: Vec<ScopedSearchHit>, limit: usize) -> Vec<ScopedSearchHit> {
+    let mut seen_ids: HashSet<MemoryId> = HashSet::new();
+    let mut seen_content: HashSet<String> = HashSet::new();
+    hits.retain(|scoped| {
+        let memory = &scoped.hit.memory;
+        seen_ids.insert(memory.id.clone()) && seen_content.insert(memory.content.trim().to_string())
+    });
+    hits.sort_by(|a, b| b.hit.raw_score.total_cmp(&a.hit.raw_score));
+    hits.truncate(limit);
+
+    let max_score = hits
+        .iter()
+        .map(|scoped| scoped.hit.raw_score)
+        .fold(0.0_f32, f32::max);
+    if max_score > f32::EPSILON {
+        for scoped in &mut hits {
+            scoped.hit.score = scoped.hit.raw_score / max_score;
+        }
+    }
+    hits
+}
+
 impl Default for RecallService {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Returns the memories directly linked to `id`: both ends of its edges of
+/// every type, its source memories if it is a summary, and its co-captured
+/// set.
+fn linked_memories(
+    index: &(dyn IndexBackend + Send + Sync),
+    id: &MemoryId,
+) -> Result<Vec<MemoryId>> {
+    let Some(memory) = index.get_memory(id)? else {
+        return Ok(Vec::new());
+    };
+    let mut linked = Vec::new();
+    for edge_type in EdgeType::ALL {
+        linked.extend(index.query_edges(id, edge_type)?);
+        linked.extend(index.query_incoming_edges(id, edge_type)?);
+    }
+    linked.extend(memory.source_memory_ids.iter().flatten().cloned());
+    linked.extend(co_captured(index, &memory)?);
+    Ok(linked)
+}
+
+/// Returns the memories captured together with `memory`: those from the same
+/// source and project captured within [`CO_CAPTURE_WINDOW_SECS`] of it.
+///
+/// Memories without a source have no co-captured set.
+fn co_captured(index: &(dyn IndexBackend + Send + Sync), memory: &Memory) -> Result<Vec<MemoryId>> {
+    let Some(source) = memory.source.as_deref() else {
+        return Ok(Vec::new());
+    };
+    let mut filter = SearchFilter::new()
+        .with_created_after(memory.created_at.saturating_sub(CO_CAPTURE_WINDOW_SECS))
+        .with_created_before(memory.created_at.saturating_add(CO_CAPTURE_WINDOW_SECS));
+    if let Some(project_id) = &memory.project_id {
+        filter = filter.with_project_id(project_id.clone());
+    }
+
+    let candidates: Vec<MemoryId> = index
+        .list_all(&filter, MAX_CO_CAPTURED)?
+        .into_iter()
+        .map(|(id, _)| id)
+        .filter(|id| *id != memory.id)
+        .collect();
+    Ok(index
+        .get_memories_batch(&candidates)?
+        .into_iter()
+        .flatten()
+        .filter(|candidate| candidate.source.as_deref() == Some(source))
+        .map(|candidate| candidate.id)
+        .collect())
+}
+
+/// Embeds `queries` with `embedder` and searches `vector` with the average
+/// of their embeddings.
+///
+/// `queries` holds the query followed by its expansion terms, if any. With
+/// `candidates`, only those memories are ranked, by their stored embeddings.
+/// Failures are logged and yield no results so the remaining indices are
+/// still searched.
+fn search_vector_index(
+    embedder: &dyn Embedder,
+    vector: &(dyn VectorBackend + Send + Sync),
+    queries: &[&str],
+    filter: &crate::storage::traits::VectorFilter,
+    candidates: Option<&[MemoryId]>,
+    limit: usize,
+) -> Vec<(MemoryId, f32)> {
+    let embeddings: Vec<Vec<f32>> = queries
+        .iter()
+        .filter_map(|query| {
+            embedder
+                .embed(query)
+                .inspect_err(|e| tracing::warn!("Failed to embed query for vector search: {e}"))
+                .ok()
+        })
+        .collect();
+    let Some(query_embedding) = average_embeddings(embeddings) else {
+        return Vec::new();
+    };
+    if let Some(candidates) = candidates {
+        return rank_stored_embeddings(vector, &query_embedding, candidates, filter, limit);
+    }
+    match vector.search(&query_embedding, filter, limit) {
+        Ok(results) => results,
+        Err(e) => {
+            tracing::warn!("Vector search failed: {e}");
+            Vec::new()
+        },
+    }
+}
+
+/// Ranks `candidates` by the cosine similarity of their embeddings in
+/// `vector` to `query_embedding` and returns the top `limit` that reach
+/// `filter.min_score`. Candidates without an embedding in `vector` are
+/// skipped.
+fn rank_stored_embeddings(
+    vector: &(dyn VectorBackend + Send + Sync),
+    query_embedding: &[f32],
+    candidates: &[MemoryId],
+    filter: &crate::storage::traits::VectorFilter,
+    limit: usize,
+) -> Vec<(MemoryId, f32)> {
+    let mut ranked: Vec<(MemoryId, f32)> = candidates
+        .iter()
+        .filter_map(|id| {
+            let embedding = vector
+                .get_embedding(id)
+                .inspect_err(|e| tracing::warn!("Failed to load embedding for {id}: {e}"))
+                .ok()
+                .flatten()?;
+            Some((id.clone(), cosine_similarity(query_embedding, &embedding)))
+        })
+        .filter(|(_, score)| filter.min_score.is_none_or(|min| *score >= min))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranked.truncate(limit);
+    ranked
+}
+
+/// Averages embeddings element-wise; `None` if there are none.
+#[allow(clippy::cast_precision_loss)]
+fn average_embeddings(mut embeddings: Vec<Vec<f32>>) -> Option<Vec<f32>> {
+    if embeddings.len() <= 1 {
+        return embeddings.pop();
+    }
+    let count = embeddings.len() as f32;
+    let mut sum = vec![0.0; embeddings[0].len()];
+    for embedding in &embeddings {
+        for (total, value) in sum.iter_mut().zip(embedding) {
+            *total += value;
+        }
+    }
+    Some(sum.into_iter().map(|total| total / count).collect())
+}
+
+/// Merges results from several vector indices (or text searches), keeping
+/// the best score per memory, and returns the top `limit` by score.
+fn merge_vector_results(results: Vec<(MemoryId, f32)>, limit: usize) -> Vec<(MemoryId, f32)> {
+    let mut best: HashMap<MemoryId, f32> = HashMap::new();
+    for (id, score) in results {
+        let entry = best.entry(id).or_insert(score);
+        *entry = entry.max(score);
+    }
+    let mut merged: Vec<(MemoryId, f32)> = best.into_iter().collect();
+    merged.sort_by(|a, b| b.1.total_cmp(&a.1));
+    merged.truncate(limit);
+    merged
+}
+
 /// Creates a placeholder memory for search results.
 #[allow(clippy::missing_const_for_fn)] // Can't be const due to cfg attributes
 fn create_placeholder_memory(id: MemoryId) -> Memory {
-    use crate::models::{Domain, Namespace};
+    use crate::models::{CaptureOrigin, Domain, Namespace};
 
     Memory {
         id,
@@ -1267,17 +2410,22 @@ fn create_placeholder_memory(id: MemoryId) -> Memory {
         is_summary: false,
         source_memory_ids: None,
         consolidation_timestamp: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
+        pinned: false,
+        commit: None,
+        language: None,
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::Namespace;
+    use crate::models::{CaptureOrigin, Domain, Namespace};
 
     fn create_test_memory(id: &str, content: &str) -> Memory {
-        use crate::models::Domain;
-
         Memory {
             id: MemoryId::new(id),
             content: content.to_string(),
@@ -1299,14 +2447,76 @@ fn create_test_memory(id: &str, content: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
-    #[test]
-    fn test_search_empty_query() {
-        let service = RecallService::default();
-        let result = service.search("", SearchMode::Text, &SearchFilter::new(), 10);
-        assert!(result.is_err());
+    #[test]
+    fn test_search_empty_query() {
+        let service = RecallService::default();
+        let result = service.search("", SearchMode::Text, &SearchFilter::new(), 10);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_search_within_neighborhood() {
+        let index = SqliteBackend::in_memory().unwrap();
+        // a -> b -> c is a linked cluster; d mentions the query but is unlinked
+        for (id, content) in [
+            ("a", "cache invalidation decision"),
+            ("b", "cache eviction refinement"),
+            ("c", "cache sizing follow-up"),
+            ("d", "unrelated cache note"),
+        ] {
+            index.index(&create_test_memory(id, content)).unwrap();
+        }
+        // e and f were captured together; g came from the same source later
+        for (id, content, created_at) in [
+            ("e", "cache warmup captured", 1_000),
+            ("f", "cache metrics captured", 1_030),
+            ("g", "cache later captured", 5_000),
+        ] {
+            let mut memory = create_test_memory(id, content);
+            memory.source = Some("PreCompactHandler".to_string());
+            memory.created_at = created_at;
+            index.index(&memory).unwrap();
+        }
+        let (a, b, c) = (MemoryId::new("a"), MemoryId::new("b"), MemoryId::new("c"));
+        index.store_edge(&a, &b, EdgeType::RelatedTo).unwrap();
+        index.store_edge(&b, &c, EdgeType::Supersedes).unwrap();
+        let service = RecallService::with_index(index);
+
+        let search_within = |within: &str, depth: usize| {
+            let members = service.neighborhood(&MemoryId::new(within), depth).unwrap();
+            let filter = SearchFilter::new().with_memory_ids(members);
+            let mut ids: Vec<String> = service
+                .search("cache", SearchMode::Text, &filter, 10)
+                .unwrap()
+                .memories
+                .into_iter()
+                .map(|hit| hit.memory.id.as_str().to_string())
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(search_within("a", 2), vec!["a", "b", "c"]);
+        assert_eq!(search_within("a", 1), vec!["a", "b"]);
+        // Incoming edges are followed too
+        assert_eq!(search_within("c", 1), vec!["b", "c"]);
+        assert_eq!(search_within("c", 2), vec!["a", "b", "c"]);
+        assert_eq!(search_within("d", 2), vec!["d"]);
+        assert_eq!(search_within("e", 1), vec!["e", "f"]);
+        assert!(matches!(
+            service.neighborhood(&MemoryId::new("missing"), 2),
+            Err(Error::InvalidInput(_))
+        ));
     }
 
     #[test]
@@ -1337,6 +2547,187 @@ fn test_search_with_backend() {
         assert!(!result.memories.is_empty());
     }
 
+    #[test]
+    fn test_facets_match_full_recall_per_namespace() {
+        let index = SqliteBackend::in_memory().unwrap();
+        let memories = [
+            (
+                "d1",
+                Namespace::Decisions,
+                "postgres storage decision",
+                "db",
+            ),
+            (
+                "d2",
+                Namespace::Decisions,
+                "postgres pooling decision",
+                "db",
+            ),
+            ("p1", Namespace::Patterns, "postgres retry pattern", "retry"),
+            ("l1", Namespace::Learnings, "postgres vacuum learning", "db"),
+            (
+                "l2",
+                Namespace::Learnings,
+                "redis eviction learning",
+                "cache",
+            ),
+        ];
+        for (id, namespace, content, tag) in memories {
+            let mut memory = create_test_memory(id, content);
+            memory.namespace = namespace;
+            memory.tags = vec![tag.to_string(), tag.to_string()];
+            index.index(&memory).unwrap();
+        }
+        let service = RecallService::with_index(index);
+
+        for (query, limit) in [("postgres", 10), ("postgres", 2), ("*", 10)] {
+            let filter = SearchFilter::new();
+            let hits = if query == "*" {
+                service.list_all(&filter, limit).unwrap().memories
+            } else {
+                service
+                    .search(query, SearchMode::Text, &filter, limit)
+                    .unwrap()
+                    .memories
+            };
+            let mut expected: HashMap<String, usize> = HashMap::new();
+            for hit in &hits {
+                *expected
+                    .entry(hit.memory.namespace.as_str().to_string())
+                    .or_default() += 1;
+            }
+
+            let facets = service
+                .facets(query, SearchMode::Text, &filter, limit, true)
+                .unwrap();
+            assert_eq!(facets.total_count, hits.len(), "{query} / {limit}");
+            assert_eq!(
+                facets.namespaces.into_iter().collect::<HashMap<_, _>>(),
+                expected,
+                "{query} / {limit}"
+            );
+            assert!(facets.tags.is_some());
+        }
+
+        let facets = service
+            .facets("postgres", SearchMode::Text, &SearchFilter::new(), 10, true)
+            .unwrap();
+        let tags = facets.tags.unwrap();
+        assert_eq!(tags.get("db"), Some(&3));
+        assert_eq!(tags.get("retry"), Some(&1));
+        assert_eq!(tags.get("cache"), None);
+
+        let filter = SearchFilter::new().with_namespace(Namespace::Learnings);
+        let facets = service
+            .facets("postgres", SearchMode::Text, &filter, 10, false)
+            .unwrap();
+        assert_eq!(facets.namespaces.get("learnings"), Some(&1));
+        assert_eq!(facets.namespaces.len(), 1);
+        assert!(facets.tags.is_none());
+    }
+
+    #[test]
+    fn test_positive_feedback_ranks_memory_higher() {
+        let index = SqliteBackend::in_memory().unwrap();
+        index
+            .index(&create_test_memory(
+                "api",
+                "Connection pool for the API server",
+            ))
+            .unwrap();
+        index
+            .index(&create_test_memory(
+                "worker",
+                "Connection pool for the worker queue",
+            ))
+            .unwrap();
+        let service = RecallService::with_index(index).with_feedback_boost(true);
+        let search = |query: &str, limit: usize| {
+            service
+                .search(query, SearchMode::Text, &SearchFilter::new(), limit)
+                .unwrap()
+                .memories
+        };
+
+        let before = search("connection pool", 10);
+        let preferred = before[1].memory.id.clone();
+        for _ in 0..5 {
+            service
+                .record_feedback("Connection pool sizing", &preferred, true)
+                .unwrap();
+        }
+
+        // A similar query now ranks the voted memory first
+        let after = search("pool connection", 10);
+        assert_eq!(after[0].memory.id, preferred);
+        assert!(after.iter().all(|hit| hit.score <= 1.0));
+
+        // The boost applies before the limit cuts the candidates
+        let top = search("pool connection", 1);
+        assert_eq!(top.len(), 1);
+        assert_eq!(top[0].memory.id, preferred);
+
+        assert!(matches!(
+            service.record_feedback("connection pool", &MemoryId::new("missing"), true),
+            Err(Error::InvalidInput(_))
+        ));
+    }
+
+    #[test]
+    fn test_pinned_memory_ranks_higher() {
+        let memories = [
+            create_test_memory("api", "Connection pool for the API server"),
+            create_test_memory("worker", "Connection pool for the worker queue"),
+        ];
+        let search = |pinned: Option<&str>| {
+            let index = SqliteBackend::in_memory().unwrap();
+            for memory in &memories {
+                let mut memory = memory.clone();
+                memory.pinned = pinned == Some(memory.id.as_str());
+                index.index(&memory).unwrap();
+            }
+            RecallService::with_index(index)
+                .search(
+                    "connection pool",
+                    SearchMode::Text,
+                    &SearchFilter::new(),
+                    10,
+                )
+                .unwrap()
+                .memories
+        };
+
+        let unpinned = search(None);
+        let runner_up = unpinned[1].memory.id.clone();
+
+        let pinned = search(Some(runner_up.as_str()));
+        assert_eq!(pinned[0].memory.id, runner_up);
+        assert!(pinned[0].memory.pinned);
+        assert!(pinned.iter().all(|hit| hit.score <= 1.0));
+    }
+
+    #[test]
+    fn test_feedback_boost_weights_votes_by_query_overlap() {
+        let votes = |query: &str, up: u32, down: u32| RelevanceFeedback {
+            memory_id: MemoryId::new("m"),
+            query: RelevanceFeedback::normalize_query(query),
+            up,
+            down,
+        };
+
+        let exact = [votes("connection pool", 2, 0)];
+        assert!((feedback_boost(&exact, "Connection pool") - 0.2).abs() < f32::EPSILON);
+        let partial = [votes("connection pool", 2, 0)];
+        assert!((feedback_boost(&partial, "pool size") - 0.1).abs() < f32::EPSILON);
+        let unrelated = [votes("redis eviction", 9, 0)];
+        assert!(feedback_boost(&unrelated, "connection pool").abs() < f32::EPSILON);
+        let negative = [votes("connection pool", 0, 20)];
+        assert!(
+            (feedback_boost(&negative, "connection pool") + FEEDBACK_BOOST_MAX).abs()
+                < f32::EPSILON
+        );
+    }
+
     #[test]
     fn test_rrf_fusion() {
         let service = RecallService::default();
@@ -1348,6 +2739,9 @@ fn test_rrf_fusion() {
                 raw_score: 0.9,
                 vector_score: None,
                 bm25_score: Some(0.9),
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id2", ""),
@@ -1355,6 +2749,9 @@ fn test_rrf_fusion() {
                 raw_score: 0.8,
                 vector_score: None,
                 bm25_score: Some(0.8),
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
         ];
 
@@ -1365,6 +2762,9 @@ fn test_rrf_fusion() {
                 raw_score: 0.95,
                 vector_score: Some(0.95),
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id3", ""),
@@ -1372,10 +2772,13 @@ fn test_rrf_fusion() {
                 raw_score: 0.85,
                 vector_score: Some(0.85),
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
         ];
 
-        let fused = service.rrf_fusion(&text_hits, &vector_hits, 10);
+        let fused = service.rrf_fusion(&text_hits, &vector_hits, DEFAULT_HYBRID_ALPHA, 10);
 
         // id2 should be ranked higher because it appears in both
         assert!(!fused.is_empty());
@@ -1391,6 +2794,20 @@ fn test_rrf_fusion() {
             .map(|h| h.score);
 
         assert!(id2_score > id1_score);
+
+        // Pure BM25 and pure vector keep only their own list, in its order
+        let ids = |fused: &[SearchHit]| -> Vec<String> {
+            fused.iter().map(|h| h.memory.id.to_string()).collect()
+        };
+        let bm25 = service.rrf_fusion(&text_hits, &vector_hits, 0.0, 10);
+        assert_eq!(ids(&bm25), ["id1", "id2"]);
+        let vector = service.rrf_fusion(&text_hits, &vector_hits, 1.0, 10);
+        assert_eq!(ids(&vector), ["id2", "id3"]);
+
+        // Leaning toward vector puts its top hit ahead of the text-only one
+        let semantic = service.rrf_fusion(&text_hits, &vector_hits, 0.9, 10);
+        let rank = |id: &str| semantic.iter().position(|h| h.memory.id.as_str() == id);
+        assert!(rank("id3") < rank("id1"));
     }
 
     #[test]
@@ -1404,7 +2821,7 @@ fn test_hybrid_search_mode() {
     #[test]
     fn test_vector_search_no_embedder() {
         let service = RecallService::default();
-        let result = service.vector_search("test query", &SearchFilter::new(), 10);
+        let result = service.vector_search("test query", &[], &SearchFilter::new(), 10, true);
 
         // Should return empty, not error (graceful degradation)
         assert!(result.is_ok());
@@ -1418,7 +2835,7 @@ fn test_vector_search_no_vector_backend() {
         let embedder: Arc<dyn Embedder> = Arc::new(FastEmbedEmbedder::new());
         let service = RecallService::new().with_embedder(embedder);
 
-        let result = service.vector_search("test query", &SearchFilter::new(), 10);
+        let result = service.vector_search("test query", &[], &SearchFilter::new(), 10, true);
 
         // Should return empty, not error (graceful degradation)
         assert!(result.is_ok());
@@ -1490,10 +2907,13 @@ fn test_rrf_with_empty_vector_results() {
             raw_score: 0.9,
             vector_score: None,
             bm25_score: Some(0.9),
+            scope: DomainScope::Project,
+            domain: Domain::new(),
+            snippet: None,
         }];
         let vector_hits: Vec<SearchHit> = vec![]; // Empty vector results
 
-        let fused = service.rrf_fusion(&text_hits, &vector_hits, 10);
+        let fused = service.rrf_fusion(&text_hits, &vector_hits, DEFAULT_HYBRID_ALPHA, 10);
 
         // Should still return text results
         assert_eq!(fused.len(), 1);
@@ -1511,9 +2931,12 @@ fn test_rrf_with_empty_text_results() {
             raw_score: 0.9,
             vector_score: Some(0.9),
             bm25_score: None,
+            scope: DomainScope::Project,
+            domain: Domain::new(),
+            snippet: None,
         }];
 
-        let fused = service.rrf_fusion(&text_hits, &vector_hits, 10);
+        let fused = service.rrf_fusion(&text_hits, &vector_hits, DEFAULT_HYBRID_ALPHA, 10);
 
         // Should still return vector results
         assert_eq!(fused.len(), 1);
@@ -1589,6 +3012,9 @@ fn test_normalize_scores_max_becomes_one() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id2", "low score"),
@@ -1596,6 +3022,9 @@ fn test_normalize_scores_max_becomes_one() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
         ];
 
@@ -1622,6 +3051,9 @@ fn test_normalize_scores_all_in_range() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id2", ""),
@@ -1629,6 +3061,9 @@ fn test_normalize_scores_all_in_range() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id3", ""),
@@ -1636,6 +3071,9 @@ fn test_normalize_scores_all_in_range() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
         ];
 
@@ -1671,6 +3109,9 @@ fn test_normalize_scores_single_result() {
             raw_score: 0.0,
             vector_score: None,
             bm25_score: None,
+            scope: DomainScope::Project,
+            domain: Domain::new(),
+            snippet: None,
         }];
 
         normalize_scores(&mut hits);
@@ -1691,6 +3132,9 @@ fn test_normalize_scores_proportions_preserved() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id2", ""),
@@ -1698,6 +3142,9 @@ fn test_normalize_scores_proportions_preserved() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
         ];
 
@@ -1723,6 +3170,9 @@ fn test_normalize_scores_ordering_preserved() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id2", ""),
@@ -1730,6 +3180,9 @@ fn test_normalize_scores_ordering_preserved() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id3", ""),
@@ -1737,6 +3190,9 @@ fn test_normalize_scores_ordering_preserved() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
         ];
 
@@ -1756,6 +3212,9 @@ fn test_normalize_scores_zero_scores() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id2", ""),
@@ -1763,6 +3222,9 @@ fn test_normalize_scores_zero_scores() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
         ];
 
@@ -1814,6 +3276,9 @@ fn test_normalize_scores_raw_score_preserved() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
             SearchHit {
                 memory: create_test_memory("id2", ""),
@@ -1821,6 +3286,9 @@ fn test_normalize_scores_raw_score_preserved() {
                 raw_score: 0.0,
                 vector_score: None,
                 bm25_score: None,
+                scope: DomainScope::Project,
+                domain: Domain::new(),
+                snippet: None,
             },
         ];
 
@@ -1836,6 +3304,601 @@ fn test_normalize_scores_raw_score_preserved() {
             "raw_score should be 0.020"
         );
     }
+
+    #[test]
+    fn test_repeated_query_hits_cache() {
+        use crate::observability::EventBus;
+
+        let index = SqliteBackend::in_memory().unwrap();
+        index
+            .index(&create_test_memory("id1", "Rust programming language"))
+            .unwrap();
+
+        // Private bus so unrelated writes in parallel tests cannot invalidate
+        let bus = EventBus::new(16);
+        let cache = Arc::new(RecallCache::with_event_bus(
+            &bus,
+            std::time::Duration::from_secs(60),
+            16,
+        ));
+        let service = RecallService::with_index(index).with_cache(Arc::clone(&cache));
+        let filter = SearchFilter::new();
+
+        let first = service
+            .search("Rust", SearchMode::Text, &filter, 10)
+            .unwrap();
+        let second = service
+            .search("Rust", SearchMode::Text, &filter, 10)
+            .unwrap();
+
+        assert_eq!(cache.misses(), 1);
+        assert_eq!(cache.hits(), 1);
+        assert_eq!(first.total_count, second.total_count);
+
+        // A different limit is a different cache entry
+        service
+            .search("Rust", SearchMode::Text, &filter, 5)
+            .unwrap();
+        assert_eq!(cache.misses(), 2);
+    }
+
+    #[test]
+    fn test_capture_invalidates_cache() {
+        use crate::config::Config;
+        use crate::models::CaptureRequest;
+        use crate::services::CaptureService;
+
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        index
+            .index(&create_test_memory("id1", "Rust programming language"))
+            .unwrap();
+
+        let cache = Arc::new(RecallCache::new(std::time::Duration::from_secs(60), 16));
+        let service =
+            RecallService::with_dyn_index(Arc::clone(&index)).with_cache(Arc::clone(&cache));
+        let filter = SearchFilter::new();
+
+        let before = service
+            .search("Rust", SearchMode::Text, &filter, 10)
+            .unwrap();
+        assert_eq!(before.total_count, 1);
+
+        CaptureService::new(Config::default())
+            .with_index(Arc::clone(&index))
+            .capture(
+                CaptureRequest::new("Rust ownership rules").with_namespace(Namespace::Decisions),
+            )
+            .unwrap();
+
+        let after = service
+            .search("Rust", SearchMode::Text, &filter, 10)
+            .unwrap();
+        assert_eq!(after.total_count, 2);
+        assert_eq!(cache.hits(), 0);
+    }
+
+    #[test]
+    fn test_search_hits_attributed_to_service_scope() {
+        let project = SqliteBackend::in_memory().unwrap();
+        project
+            .index(&create_test_memory("p1", "PostgreSQL for the project"))
+            .unwrap();
+        let user = SqliteBackend::in_memory().unwrap();
+        let mut user_memory = create_test_memory("u1", "PostgreSQL tuning notes");
+        user_memory.domain = Domain::for_user();
+        user.index(&user_memory).unwrap();
+
+        let filter = SearchFilter::new();
+        let project_hits = RecallService::with_index(project)
+            .with_scope(DomainScope::Project)
+            .search("PostgreSQL", SearchMode::Text, &filter, 10)
+            .unwrap();
+        let user_service = RecallService::with_index(user).with_scope(DomainScope::User);
+        let user_hits = user_service
+            .search("PostgreSQL", SearchMode::Text, &filter, 10)
+            .unwrap();
+        let listed = user_service.list_all(&filter, 10).unwrap();
+
+        assert_eq!(project_hits.memories.len(), 1);
+        assert_eq!(project_hits.memories[0].scope, DomainScope::Project);
+        assert_eq!(project_hits.memories[0].domain, Domain::new());
+        assert_eq!(user_hits.memories.len(), 1);
+        assert_eq!(user_hits.memories[0].scope, DomainScope::User);
+        assert_eq!(user_hits.memories[0].domain, Domain::for_user());
+        assert_eq!(listed.memories[0].scope, DomainScope::User);
+    }
+
+    #[test]
+    fn test_unscoped_search_attributes_hits_by_domain() {
+        let index = SqliteBackend::in_memory().unwrap();
+        index
+            .index(&create_test_memory("p1", "PostgreSQL for the project"))
+            .unwrap();
+        let mut user_memory = create_test_memory("u1", "PostgreSQL tuning notes");
+        user_memory.domain = Domain::for_user();
+        index.index(&user_memory).unwrap();
+
+        let result = RecallService::with_index(index)
+            .search("PostgreSQL", SearchMode::Text, &SearchFilter::new(), 10)
+            .unwrap();
+
+        let scope_of = |id: &str| {
+            result
+                .memories
+                .iter()
+                .find(|hit| hit.memory.id.as_str() == id)
+                .map(|hit| hit.scope)
+        };
+        assert_eq!(scope_of("p1"), Some(DomainScope::Project));
+        assert_eq!(scope_of("u1"), Some(DomainScope::User));
+    }
+
+    #[test]
+    fn test_search_all_scopes_merges_and_annotates() {
+        let project = SqliteBackend::in_memory().unwrap();
+        project
+            .index(&create_test_memory(
+                "p1",
+                "Use PostgreSQL for the project database",
+            ))
+            .unwrap();
+        project
+            .index(&create_test_memory(
+                "shared-p",
+                "PostgreSQL connection pooling",
+            ))
+            .unwrap();
+        let org = SqliteBackend::in_memory().unwrap();
+        org.index(&create_test_memory(
+            "o1",
+            "Org standard: PostgreSQL everywhere",
+        ))
+        .unwrap();
+        org.index(&create_test_memory(
+            "shared-o",
+            "PostgreSQL connection pooling",
+        ))
+        .unwrap();
+
+        let service = RecallService::with_index(project)
+            .with_scope(DomainScope::Project)
+            .with_additional_scope(DomainScope::Org, RecallService::with_index(org));
+
+        let result = service
+            .search_all_scopes("PostgreSQL", SearchMode::Text, &SearchFilter::new(), 10)
+            .unwrap();
+
+        assert_eq!(result.scopes, vec![DomainScope::Project, DomainScope::Org]);
+        // Identical content in both scopes is returned once
+        assert_eq!(result.total_count, 3);
+        let scope_of = |id: &str| {
+            result
+                .memories
+                .iter()
+                .find(|scoped| scoped.hit.memory.id.as_str() == id)
+                .map(|scoped| scoped.scope)
+        };
+        assert_eq!(scope_of("p1"), Some(DomainScope::Project));
+        assert_eq!(scope_of("o1"), Some(DomainScope::Org));
+        assert_eq!(
+            usize::from(scope_of("shared-p").is_some())
+                + usize::from(scope_of("shared-o").is_some()),
+            1
+        );
+        assert!(
+            result
+                .memories
+                .windows(2)
+                .all(|pair| pair[0].hit.score >= pair[1].hit.score)
+        );
+        assert!((result.memories[0].hit.score - 1.0).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_search_all_scopes_without_additional_scopes() {
+        let index = SqliteBackend::in_memory().unwrap();
+        index
+            .index(&create_test_memory("id1", "Rust error handling"))
+            .unwrap();
+
+        let result = RecallService::with_index(index)
+            .search_all_scopes("rust", SearchMode::Text, &SearchFilter::new(), 10)
+            .unwrap();
+
+        assert_eq!(result.scopes, vec![DomainScope::User]);
+        assert_eq!(result.memories.len(), 1);
+        assert_eq!(result.memories[0].scope, DomainScope::User);
+    }
+
+    /// Returns an in-memory index with `count` distinct memories about `topic`.
+    fn scope_index(prefix: &str, topic: &str, count: usize) -> SqliteBackend {
+        let index = SqliteBackend::in_memory().unwrap();
+        for i in 0..count {
+            let content = format!("{topic} note number {i} from {prefix}");
+            index
+                .index(&create_test_memory(&format!("{prefix}-{i}"), &content))
+                .unwrap();
+        }
+        index
+    }
+
+    #[test]
+    fn test_max_results_caps_scope_search() {
+        let service = RecallService::with_index(scope_index("user", "kafka", 8))
+            .with_scope(DomainScope::User)
+            .with_max_results(3);
+        let filter = SearchFilter::new();
+
+        let capped = service
+            .search("kafka", SearchMode::Text, &filter, 10)
+            .unwrap();
+        assert_eq!(capped.memories.len(), 3);
+
+        // A smaller requested limit still applies
+        let smaller = service
+            .search("kafka", SearchMode::Text, &filter, 2)
+            .unwrap();
+        assert_eq!(smaller.memories.len(), 2);
+    }
+
+    #[test]
+    fn test_search_all_scopes_applies_per_scope_max_results() {
+        let user = RecallService::with_index(scope_index("user", "kafka", 8)).with_max_results(5);
+        let org = RecallService::with_index(scope_index("org", "kafka", 8)).with_max_results(2);
+        let service = RecallService::with_index(scope_index("project", "kafka", 8))
+            .with_scope(DomainScope::Project)
+            .with_additional_scope(DomainScope::User, user)
+            .with_additional_scope(DomainScope::Org, org);
+
+        let result = service
+            .search_all_scopes("kafka", SearchMode::Text, &SearchFilter::new(), 20)
+            .unwrap();
+
+        let count = |scope: DomainScope| {
+            result
+                .memories
+                .iter()
+                .filter(|scoped| scoped.scope == scope)
+                .count()
+        };
+        // The project scope has no cap and returns every match
+        assert_eq!(count(DomainScope::Project), 8);
+        assert_eq!(count(DomainScope::User), 5);
+        assert_eq!(count(DomainScope::Org), 2);
+        assert_eq!(result.total_count, 15);
+
+        // The merged limit still applies after the per-scope caps
+        let merged = service
+            .search_all_scopes("kafka", SearchMode::Text, &SearchFilter::new(), 4)
+            .unwrap();
+        assert_eq!(merged.memories.len(), 4);
+    }
+
+    #[test]
+    fn test_offset_pages_are_disjoint_and_stable() {
+        let service = RecallService::with_index(scope_index("page", "kafka", 8));
+        let ids = |result: &SearchResult| -> Vec<String> {
+            result
+                .memories
+                .iter()
+                .map(|hit| hit.memory.id.as_str().to_string())
+                .collect()
+        };
+        let page = |offset: usize| {
+            let filter = SearchFilter::new().with_offset(offset);
+            service
+                .search("kafka", SearchMode::Text, &filter, 3)
+                .unwrap()
+        };
+
+        let all = service
+            .search("kafka", SearchMode::Text, &SearchFilter::new(), 8)
+            .unwrap();
+        assert!(!all.has_more);
+
+        let (first, second, third) = (page(0), page(3), page(6));
+        assert!(first.has_more);
+        assert!(second.has_more);
+        assert!(!third.has_more);
+        assert_eq!(third.memories.len(), 2);
+        // Every page reports all matches, not the ranked window
+        for result in [&first, &second, &third] {
+            assert_eq!(result.total_count, 8);
+        }
+        let paged: Vec<String> = [first, second, third].iter().flat_map(ids).collect();
+        assert_eq!(paged, ids(&all));
+
+        // Past the end there is nothing left
+        let beyond = page(20);
+        assert!(beyond.memories.is_empty());
+        assert!(!beyond.has_more);
+        assert_eq!(beyond.total_count, 8);
+    }
+
+    #[test]
+    fn test_field_weights_rank_tag_match_first() {
+        let index = SqliteBackend::in_memory().unwrap();
+        let mut tagged = create_test_memory("tagged", "Storage layer notes");
+        tagged.tags = vec!["postgres".to_string()];
+        index.index(&tagged).unwrap();
+        index
+            .index(&create_test_memory(
+                "mentioned",
+                "Postgres handles storage for the service",
+            ))
+            .unwrap();
+
+        let service = RecallService::with_index(index);
+        let result = service
+            .search("postgres", SearchMode::Text, &SearchFilter::new(), 10)
+            .unwrap();
+        assert_eq!(result.memories[0].memory.id.as_str(), "tagged");
+        assert_eq!(result.memories[0].matched_fields("postgres"), vec!["tags"]);
+        assert_eq!(
+            result.memories[1].matched_fields("POSTGRES"),
+            vec!["content"]
+        );
+
+        let result = service
+            .with_field_weights(FieldWeights::new(10.0, 1.0, 1.0))
+            .search("postgres", SearchMode::Text, &SearchFilter::new(), 10)
+            .unwrap();
+        assert_eq!(result.memories[0].memory.id.as_str(), "mentioned");
+    }
+
+    #[test]
+    fn test_hits_carry_highlighted_snippets() {
+        let index = SqliteBackend::in_memory().unwrap();
+        let mut tagged = create_test_memory("tagged", "Storage layer notes");
+        tagged.tags = vec!["postgres".to_string()];
+        index.index(&tagged).unwrap();
+        index
+            .index(&create_test_memory(
+                "mentioned",
+                "Postgres handles storage for the service",
+            ))
+            .unwrap();
+
+        let service = RecallService::with_index(index).with_snippet_marker("|");
+        let result = service
+            .search("postgres", SearchMode::Text, &SearchFilter::new(), 10)
+            .unwrap();
+        let snippet = |id: &str| {
+            result
+                .memories
+                .iter()
+                .find(|hit| hit.memory.id.as_str() == id)
+                .and_then(|hit| hit.snippet.clone())
+        };
+        assert_eq!(
+            snippet("mentioned").as_deref(),
+            Some("|Postgres| handles storage for the service")
+        );
+        // A tag-only match has no highlight, so the content excerpt is used
+        assert_eq!(snippet("tagged").as_deref(), Some("Storage layer notes"));
+    }
+
+    /// Embedder producing the same unit vector for any text.
+    struct FixedEmbedder;
+
+    impl Embedder for FixedEmbedder {
+        fn dimensions(&self) -> usize {
+            2
+        }
+
+        fn embed(&self, _text: &str) -> Result<Vec<f32>> {
+            Ok(vec![1.0, 0.0])
+        }
+    }
+
+    #[cfg(not(feature = "usearch-hnsw"))]
+    fn vector_backend() -> crate::storage::vector::UsearchBackend {
+        crate::storage::vector::UsearchBackend::in_memory(2)
+    }
+
+    #[cfg(feature = "usearch-hnsw")]
+    fn vector_backend() -> crate::storage::vector::UsearchBackend {
+        crate::storage::vector::UsearchBackend::in_memory(2)
+            .expect("Failed to create usearch backend")
+    }
+
+    #[test]
+    fn test_hybrid_reports_unembedded_memories() {
+        let index = SqliteBackend::in_memory().unwrap();
+        index
+            .index(&create_test_memory("id1", "Rust programming language"))
+            .unwrap();
+        let vector: Arc<dyn VectorBackend + Send + Sync> = Arc::new(vector_backend());
+        let service = RecallService::with_index(index)
+            .with_embedder(Arc::new(FixedEmbedder))
+            .with_vector(Arc::clone(&vector));
+
+        let result = service
+            .search("Rust", SearchMode::Hybrid, &SearchFilter::new(), 10)
+            .unwrap();
+        assert_eq!(result.memories.len(), 1);
+        assert_eq!(result.legs.text_candidates, Some(1));
+        assert_eq!(result.legs.vector_candidates, Some(0));
+        assert!(result.legs.text_contributed);
+        assert!(!result.legs.vector_contributed);
+        assert_eq!(
+            result.legs.vector_empty,
+            Some(VectorEmptyReason::NothingEmbedded)
+        );
+        assert_eq!(
+            result.legs.summary().as_deref(),
+            Some(
+                "text: 1 candidates (contributed), \
+                 vector: 0 candidates (no memories are embedded)"
+            )
+        );
+
+        // Once embedded, the vector leg contributes
+        vector.upsert(&MemoryId::new("id1"), &[1.0, 0.0]).unwrap();
+        let result = service
+            .search("Rust", SearchMode::Hybrid, &SearchFilter::new(), 10)
+            .unwrap();
+        assert_eq!(result.legs.vector_candidates, Some(1));
+        assert!(result.legs.vector_contributed);
+        assert_eq!(result.legs.vector_empty, None);
+
+        // Without a vector backend the leg is unavailable
+        let text_only = RecallService::with_index(SqliteBackend::in_memory().unwrap());
+        let result = text_only
+            .search("Rust", SearchMode::Hybrid, &SearchFilter::new(), 10)
+            .unwrap();
+        assert_eq!(
+            result.legs.vector_empty,
+            Some(VectorEmptyReason::Unavailable)
+        );
+    }
+
+    #[test]
+    fn test_vector_only_fallback_serves_vector_hits_without_text_matches() {
+        let index = SqliteBackend::in_memory().unwrap();
+        index
+            .index(&create_test_memory("id1", "Rust programming language"))
+            .unwrap();
+        let vector: Arc<dyn VectorBackend + Send + Sync> = Arc::new(vector_backend());
+        vector.upsert(&MemoryId::new("id1"), &[1.0, 0.0]).unwrap();
+        let service = RecallService::with_index(index)
+            .with_embedder(Arc::new(FixedEmbedder))
+            .with_vector(vector);
+
+        // Pure BM25 weighting drops the vector hits when no keyword matches
+        let filter = SearchFilter::new().with_hybrid_alpha(0.0);
+        let result = service
+            .search("borrowck", SearchMode::Hybrid, &filter, 10)
+            .unwrap();
+        assert!(result.memories.is_empty());
+        assert!(!result.legs.vector_only);
+
+        let filter = filter.with_vector_only_fallback(true);
+        let result = service
+            .search("borrowck", SearchMode::Hybrid, &filter, 10)
+            .unwrap();
+        assert_eq!(result.memories.len(), 1);
+        assert!(result.legs.vector_only);
+        assert!(result.legs.vector_contributed);
+        assert_eq!(
+            result.legs.summary().as_deref(),
+            Some(
+                "text: 0 candidates (not in results), \
+                 vector: 1 candidates (contributed); served by vector search only"
+            )
+        );
+    }
+
+    #[test]
+    fn test_hybrid_offset_pages_are_stable_across_windows() {
+        const COUNT: usize = 150;
+
+        // Text ranks shorter memories first, vector search ranks them last
+        let index = SqliteBackend::in_memory().unwrap();
+        let vector: Arc<dyn VectorBackend + Send + Sync> = Arc::new(vector_backend());
+        for i in 0..COUNT {
+            let id = format!("hybrid-{i:03}");
+            let content = format!("kafka{}", " filler".repeat(i));
+            index.index(&create_test_memory(&id, &content)).unwrap();
+            #[allow(clippy::cast_precision_loss)]
+            let angle = (COUNT - i) as f32 / COUNT as f32 * std::f32::consts::FRAC_PI_2 * 0.9;
+            vector
+                .upsert(&MemoryId::new(&id), &[angle.cos(), angle.sin()])
+                .unwrap();
+        }
+        let service = RecallService::with_index(index)
+            .with_embedder(Arc::new(FixedEmbedder))
+            .with_vector(vector);
+        let ids = |result: &SearchResult| -> Vec<String> {
+            result
+                .memories
+                .iter()
+                .map(|hit| hit.memory.id.as_str().to_string())
+                .collect()
+        };
+        let page = |offset: usize, limit: usize| {
+            let filter = SearchFilter::new().with_offset(offset);
+            service
+                .search("kafka", SearchMode::Hybrid, &filter, limit)
+                .unwrap()
+        };
+
+        let all = page(0, COUNT);
+        assert_eq!(all.memories.len(), COUNT);
+        assert!(!all.has_more);
+
+        // Pages either side of 50 and 100 results rank the same candidates
+        let paged: Vec<String> = (30..130)
+            .step_by(10)
+            .flat_map(|offset| ids(&page(offset, 10)))
+            .collect();
+        assert_eq!(paged, ids(&all)[30..130]);
+
+        let last = page(COUNT - 10, 10);
+        assert_eq!(last.memories.len(), 10);
+        assert!(!last.has_more);
+        assert!(page(120, 10).has_more);
+    }
+
+    #[test]
+    fn test_section_search_matches_section_bodies_in_every_mode() {
+        use crate::models::{MemorySection, render_sections};
+
+        let index = SqliteBackend::in_memory().unwrap();
+        let vector: Arc<dyn VectorBackend + Send + Sync> = Arc::new(vector_backend());
+        let mut index_memory = |id: &str, content: &str| {
+            index.index(&create_test_memory(id, content)).unwrap();
+            vector.upsert(&MemoryId::new(id), &[1.0, 0.0]).unwrap();
+        };
+        // Whole-memory matches that outrank the section match many times over
+        for i in 0..6 {
+            index_memory(&format!("plain-{i}"), &format!("Postgres, Postgres {i}"));
+        }
+        let adr = |decision: &str, consequences: &str| {
+            let sections = [
+                MemorySection::new("decision", decision),
+                MemorySection::new("consequences", consequences),
+            ];
+            render_sections(None, "", &sections)
+        };
+        index_memory("adr", &adr("Use SQLite", "Postgres stays optional"));
+        index_memory("other", &adr("Postgres replicas", "No new services"));
+        let service = RecallService::with_index(index)
+            .with_embedder(Arc::new(FixedEmbedder))
+            .with_vector(vector);
+
+        let filter = SearchFilter::new().with_section("consequences");
+        for mode in [SearchMode::Text, SearchMode::Vector, SearchMode::Hybrid] {
+            let result = service.search("postgres", mode, &filter, 1).unwrap();
+            let ids: Vec<&str> = result
+                .memories
+                .iter()
+                .map(|hit| hit.memory.id.as_str())
+                .collect();
+            assert_eq!(ids, vec!["adr"], "{mode}");
+        }
+    }
+
+    #[test]
+    fn test_sentence_excerpt_starts_at_best_sentence() {
+        let content = format!(
+            "Première phrase.  Le café\n{} fin. Dernière phrase.",
+            "é".repeat(200)
+        );
+        let excerpt = sentence_excerpt(&content, "café").unwrap();
+        assert!(excerpt.starts_with("…Le café éé"));
+        assert!(excerpt.ends_with('…'));
+        assert_eq!(excerpt.chars().count(), SNIPPET_FALLBACK_CHARS + 2);
+
+        // Without a matching sentence the excerpt starts at the beginning
+        let short = sentence_excerpt("One.  Two   three.", "unrelated").unwrap();
+        assert_eq!(short, "One. Two three.");
+
+        assert_eq!(sentence_excerpt(&content, ""), None);
+        assert_eq!(sentence_excerpt(&content, " !! "), None);
+        assert_eq!(sentence_excerpt("   ", "café"), None);
+    }
 }
 
 // ============================================================================
@@ -1844,7 +3907,7 @@ fn test_normalize_scores_raw_score_preserved() {
 #[cfg(test)]
 mod proptests {
     use super::*;
-    use crate::models::{Domain, Namespace};
+    use crate::models::{CaptureOrigin, Domain, Namespace};
     use proptest::prelude::*;
 
     fn create_test_memory_prop(id: &str) -> Memory {
@@ -1869,6 +3932,13 @@ fn create_test_memory_prop(id: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -1895,6 +3965,9 @@ fn prop_normalized_scores_in_range(
                     raw_score: 0.0,
                     vector_score: None,
                     bm25_score: None,
+                    scope: DomainScope::Project,
+                    domain: Domain::new(),
+                    snippet: None,
                 })
                 .collect();
 
@@ -1928,6 +4001,9 @@ fn prop_ordering_preserved(
                     raw_score: 0.0,
                     vector_score: None,
                     bm25_score: None,
+                    scope: DomainScope::Project,
+                    domain: Domain::new(),
+                    snippet: None,
                 })
                 .collect();
 
@@ -1966,6 +4042,9 @@ fn prop_max_score_is_one(
                     raw_score: 0.0,
                     vector_score: None,
                     bm25_score: None,
+                    scope: DomainScope::Project,
+                    domain: Domain::new(),
+                    snippet: None,
                 })
                 .collect();
 
@@ -1995,6 +4074,9 @@ fn prop_raw_score_preserved(
                     raw_score: 0.0,
                     vector_score: None,
                     bm25_score: None,
+                    scope: DomainScope::Project,
+                    domain: Domain::new(),
+                    snippet: None,
                 })
                 .collect();
 
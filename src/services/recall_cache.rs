@@ -0,0 +1,312 @@
+//! Read-through cache for recall query results.
+//!
+//! Hooks and editor integrations often issue the same recall query many
+//! times within a session. [`RecallCache`] memoizes [`SearchResult`]s keyed
+//! by query, mode, effective filter and limit so repeated lookups skip the
+//! FTS and vector legs entirely.
+//!
+//! Entries expire after a TTL and the cache holds at most a fixed number of
+//! entries (oldest evicted first). Any event that changes stored memories
+//! (capture, update, delete, archive, redact, sync, consolidation) clears the
+//! whole cache; invalidation is driven by the [`EventBus`] so every write path
+//! that records an event is covered.
+//!
+//! The cache is disabled by default and enabled with `[search] cache = true`.
+
+use crate::models::{MemoryEvent, SearchFilter, SearchMode, SearchResult};
+use crate::observability::{EventBus, global_event_bus};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use tokio::sync::broadcast::{self, error::TryRecvError};
+
+/// Default time-to-live for cached results (60 seconds).
+pub const DEFAULT_RECALL_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Default maximum number of cached results.
+pub const DEFAULT_RECALL_CACHE_MAX_ENTRIES: usize = 256;
+
+/// A cached search result with its insertion time.
+struct CacheEntry {
+    result: SearchResult,
+    inserted_at: Instant,
+}
+
+/// Mutable cache state guarded by a single mutex.
+struct CacheState {
+    entries: HashMap<String, CacheEntry>,
+    events: broadcast::Receiver<MemoryEvent>,
+    /// Incremented on every invalidation; guards against caching results
+    /// computed before a concurrent write.
+    generation: u64,
+}
+
+/// Shared query-result cache for [`RecallService`](crate::services::RecallService).
+///
+/// Cheap to share via `Arc`; all methods take `&self`.
+pub struct RecallCache {
+    state: Mutex<CacheState>,
+    ttl: Duration,
+    max_entries: usize,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl RecallCache {
+    /// Creates a cache invalidated by events on the global event bus.
+    #[must_use]
+    pub fn new(ttl: Duration, max_entries: usize) -> Self {
+        Self::with_event_bus(global_event_bus(), ttl, max_entries)
+    }
+
+    /// Creates a cache invalidated by events on the given event bus.
+    #[must_use]
+    pub fn with_event_bus(bus: &EventBus, ttl: Duration, max_entries: usize) -> Self {
+        Self {
+            state: Mutex::new(CacheState {
+                entries: HashMap::new(),
+                events: bus.subscribe(),
+                generation: 0,
+            }),
+            ttl,
+            max_entries: max_entries.max(1),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    /// Builds the cache key for a search.
+    ///
+    /// `filter` should be the effective filter (after scope merging) so that
+    /// differently scoped services never share entries.
+    #[must_use]
+    pub fn key(query: &str, mode: SearchMode, filter: &SearchFilter, limit: usize) -> String {
+        format!("{mode}\u{1f}{limit}\u{1f}{filter:?}\u{1f}{query}")
+    }
+
+    /// Looks up a cached result.
+    ///
+    /// Returns the result (if fresh) and the cache generation, which must be
+    /// passed back to [`insert`](Self::insert) when caching a miss.
+    #[must_use]
+    pub fn get(&self, key: &str) -> (Option<SearchResult>, u64) {
+        let mut state = self.lock_state();
+        Self::drain_events(&mut state);
+
+        let fresh = state
+            .entries
+            .get(key)
+            .filter(|entry| entry.inserted_at.elapsed() < self.ttl)
+            .map(|entry| entry.result.clone());
+
+        if fresh.is_some() {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            metrics::counter!("memory_search_cache_total", "result" => "hit").increment(1);
+        } else {
+            state.entries.remove(key);
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            metrics::counter!("memory_search_cache_total", "result" => "miss").increment(1);
+        }
+
+        (fresh, state.generation)
+    }
+
+    /// Caches a result computed after a [`get`](Self::get) miss.
+    ///
+    /// The result is dropped if the cache was invalidated since `generation`
+    /// was observed, since it may predate the write.
+    pub fn insert(&self, key: String, result: SearchResult, generation: u64) {
+        let mut state = self.lock_state();
+        Self::drain_events(&mut state);
+        if state.generation != generation {
+            return;
+        }
+
+        if state.entries.len() >= self.max_entries && !state.entries.contains_key(&key) {
+            let ttl = self.ttl;
+            state
+                .entries
+                .retain(|_, entry| entry.inserted_at.elapsed() < ttl);
+            if state.entries.len() >= self.max_entries
+                && let Some(oldest) = state
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.inserted_at)
+                    .map(|(key, _)| key.clone())
+            {
+                state.entries.remove(&oldest);
+            }
+        }
+
+        state.entries.insert(
+            key,
+            CacheEntry {
+                result,
+                inserted_at: Instant::now(),
+            },
+        );
+    }
+
+    /// Removes all cached results.
+    pub fn clear(&self) {
+        let mut state = self.lock_state();
+        Self::invalidate(&mut state);
+    }
+
+    /// Returns the number of cached results (including expired ones not yet evicted).
+    #[must_use]
+    pub fn len(&self) -> usize {
+        let mut state = self.lock_state();
+        Self::drain_events(&mut state);
+        state.entries.len()
+    }
+
+    /// Returns whether the cache holds no results.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of lookups served from the cache.
+    #[must_use]
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of lookups that missed the cache.
+    #[must_use]
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, CacheState> {
+        // Cache state is always consistent between operations, so a poisoned
+        // lock is safe to recover.
+        self.state
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Applies pending events, clearing the cache on any write.
+    fn drain_events(state: &mut CacheState) {
+        loop {
+            match state.events.try_recv() {
+                Ok(event) if invalidates_cache(&event) => Self::invalidate(state),
+                Ok(_) => {},
+                // Missed events may have included writes
+                Err(TryRecvError::Lagged(_)) => Self::invalidate(state),
+                Err(TryRecvError::Empty | TryRecvError::Closed) => break,
+            }
+        }
+    }
+
+    fn invalidate(state: &mut CacheState) {
+        if !state.entries.is_empty() {
+            metrics::counter!("memory_search_cache_invalidations_total").increment(1);
+        }
+        state.entries.clear();
+        state.generation = state.generation.wrapping_add(1);
+    }
+}
+
+/// Returns whether an event changes stored memories.
+const fn invalidates_cache(event: &MemoryEvent) -> bool {
+    matches!(
+        event,
+        MemoryEvent::Captured { .. }
+            | MemoryEvent::Updated { .. }
+            | MemoryEvent::Archived { .. }
+            | MemoryEvent::Deleted { .. }
+            | MemoryEvent::Redacted { .. }
+            | MemoryEvent::Synced { .. }
+            | MemoryEvent::Consolidated { .. }
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Domain, EventMeta, MemoryId, Namespace};
+
+    fn empty_result() -> SearchResult {
+        SearchResult {
+            memories: Vec::new(),
+            total_count: 0,
+            mode: SearchMode::Text,
+            execution_time_ms: 0,
+            has_more: false,
+            legs: crate::models::SearchLegs::default(),
+        }
+    }
+
+    fn captured_event() -> MemoryEvent {
+        MemoryEvent::Captured {
+            meta: EventMeta::with_timestamp("test", None, 1),
+            memory_id: MemoryId::new("id1"),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            content_length: 10,
+        }
+    }
+
+    #[test]
+    fn test_key_distinguishes_mode_filter_and_limit() {
+        let filter = SearchFilter::new();
+        let scoped = SearchFilter::new().with_namespace(Namespace::Decisions);
+        let base = RecallCache::key("rust", SearchMode::Text, &filter, 10);
+
+        assert_ne!(
+            base,
+            RecallCache::key("rust", SearchMode::Hybrid, &filter, 10)
+        );
+        assert_ne!(
+            base,
+            RecallCache::key("rust", SearchMode::Text, &scoped, 10)
+        );
+        assert_ne!(base, RecallCache::key("rust", SearchMode::Text, &filter, 5));
+        assert_eq!(
+            base,
+            RecallCache::key("rust", SearchMode::Text, &filter, 10)
+        );
+    }
+
+    #[test]
+    fn test_expired_entries_miss() {
+        let bus = EventBus::new(16);
+        let cache = RecallCache::with_event_bus(&bus, Duration::ZERO, 4);
+
+        let (_, generation) = cache.get("k");
+        cache.insert("k".to_string(), empty_result(), generation);
+
+        assert!(cache.get("k").0.is_none());
+        assert_eq!(cache.hits(), 0);
+    }
+
+    #[test]
+    fn test_evicts_oldest_when_full() {
+        let bus = EventBus::new(16);
+        let cache = RecallCache::with_event_bus(&bus, DEFAULT_RECALL_CACHE_TTL, 2);
+
+        for key in ["a", "b", "c"] {
+            let (_, generation) = cache.get(key);
+            cache.insert(key.to_string(), empty_result(), generation);
+        }
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("a").0.is_none());
+        assert!(cache.get("c").0.is_some());
+    }
+
+    #[test]
+    fn test_write_event_discards_stale_insert() {
+        let bus = EventBus::new(16);
+        let cache = RecallCache::with_event_bus(&bus, DEFAULT_RECALL_CACHE_TTL, 4);
+
+        let (_, generation) = cache.get("k");
+        bus.publish(captured_event());
+        cache.insert("k".to_string(), empty_result(), generation);
+
+        assert!(cache.is_empty());
+    }
+}
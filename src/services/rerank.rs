@@ -0,0 +1,293 @@
+//! LLM reranking of recall results.
+//!
+//! Hybrid retrieval ranks by BM25 and embedding similarity, which can miss
+//! what a query actually asks for. [`RerankService`] hands the query and a
+//! snippet of each top candidate to the LLM, which scores every candidate's
+//! relevance, and reorders the candidates by those scores.
+//!
+//! Reranking is best-effort: if the LLM call fails (after the retries and
+//! timeouts of the provider's resilience configuration) or its response
+//! cannot be parsed, the candidates keep their retrieval order.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::services::RerankService;
+//!
+//! let candidates = recall.search(query, SearchMode::Hybrid, &filter, 30)?;
+//! let hits = RerankService::new(llm).rerank(query, candidates.memories, 10);
+//! ```
+
+use crate::Result;
+use crate::llm::{LlmProvider, extract_json_from_response};
+use crate::models::SearchHit;
+use serde::Deserialize;
+use std::fmt::Write as _;
+use std::sync::Arc;
+use tracing::instrument;
+
+/// Default number of candidates retrieved for reranking.
+pub const DEFAULT_RERANK_CANDIDATES: usize = 30;
+
+/// Maximum characters of memory content shown to the LLM per candidate.
+const SNIPPET_CHARS: usize = 500;
+
+/// System prompt for relevance scoring.
+const RERANK_SYSTEM_PROMPT: &str = "You rank stored memories by how well they answer a search \
+query. Score every candidate from 0.0 (irrelevant) to 1.0 (directly answers the query). \
+Respond with JSON only: {\"scores\": [{\"id\": <candidate id>, \"score\": <0.0-1.0>}, ...]}";
+
+/// Relevance scores returned by the LLM.
+#[derive(Debug, Deserialize)]
+struct RerankResponse {
+    /// Score per candidate.
+    scores: Vec<CandidateScore>,
+}
+
+/// Relevance score of one candidate.
+#[derive(Debug, Deserialize)]
+struct CandidateScore {
+    /// Candidate number as shown in the prompt.
+    id: usize,
+    /// Relevance from 0.0 to 1.0.
+    score: f32,
+}
+
+/// Reorders recall results by LLM-judged relevance.
+pub struct RerankService {
+    /// LLM provider scoring the candidates.
+    llm: Arc<dyn LlmProvider>,
+}
+
+impl RerankService {
+    /// Creates a rerank service.
+    #[must_use]
+    pub fn new(llm: Arc<dyn LlmProvider>) -> Self {
+        // Arc::strong_count prevents clippy::missing_const_for_fn false positive
+        let _ = Arc::strong_count(&llm);
+        Self { llm }
+    }
+
+    /// Reorders `candidates` by relevance to `query` and returns the top `limit`.
+    ///
+    /// Candidates the LLM did not score follow the scored ones in their
+    /// original order. On LLM failure the original order is kept.
+    #[instrument(
+        name = "subcog.rerank",
+        skip(self, query, candidates),
+        fields(component = "rerank", operation = "rerank", candidates = candidates.len())
+    )]
+    pub fn rerank(&self, query: &str, candidates: Vec<SearchHit>, limit: usize) -> Vec<SearchHit> {
+        let mut hits = if candidates.len() < 2 {
+            candidates
+        } else {
+            match self.score(query, &candidates) {
+                Ok(scores) => reorder(candidates, &scores),
+                Err(e) => {
+                    tracing::warn!(error = %e, "LLM rerank failed, keeping retrieval order");
+                    candidates
+                },
+            }
+        };
+        hits.truncate(limit);
+        hits
+    }
+
+    /// Asks the LLM for the relevance of each candidate, by candidate position.
+    fn score(&self, query: &str, candidates: &[SearchHit]) -> Result<Vec<Option<f32>>> {
+        let response = self
+            .llm
+            .complete_with_system(RERANK_SYSTEM_PROMPT, &build_prompt(query, candidates))?;
+        let parsed: RerankResponse = serde_json::from_str(extract_json_from_response(&response))
+            .map_err(|e| crate::Error::OperationFailed {
+                operation: "parse_rerank_response".to_string(),
+                cause: format!("Invalid JSON: {e}"),
+            })?;
+
+        let mut scores = vec![None; candidates.len()];
+        for CandidateScore { id, score } in parsed.scores {
+            if let Some(slot) = id.checked_sub(1).and_then(|i| scores.get_mut(i))
+                && score.is_finite()
+            {
+                *slot = Some(score.clamp(0.0, 1.0));
+            }
+        }
+        if scores.iter().all(Option::is_none) {
+            return Err(crate::Error::OperationFailed {
+                operation: "parse_rerank_response".to_string(),
+                cause: "No candidate was scored".to_string(),
+            });
+        }
+        Ok(scores)
+    }
+}
+
+/// Lists the query and numbered candidate snippets for the LLM.
+fn build_prompt(query: &str, candidates: &[SearchHit]) -> String {
+    let mut prompt = format!("Query: {query}\n\nCandidates:\n");
+    for (i, hit) in candidates.iter().enumerate() {
+        let snippet: String = hit.memory.content.chars().take(SNIPPET_CHARS).collect();
+        let _ = write!(
+            prompt,
+            "\n[{}] ({}) {}\n",
+            i + 1,
+            hit.memory.namespace,
+            snippet.replace('\n', " ")
+        );
+    }
+    prompt
+}
+
+/// Sorts `candidates` by score, keeping unscored candidates last in their
+/// original order.
+fn reorder(candidates: Vec<SearchHit>, scores: &[Option<f32>]) -> Vec<SearchHit> {
+    let mut ranked: Vec<(Option<f32>, SearchHit)> =
+        scores.iter().copied().zip(candidates).collect();
+    // Stable sort: ties keep retrieval order
+    ranked.sort_by(|(a, _), (b, _)| match (a, b) {
+        (Some(a), Some(b)) => b.total_cmp(a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    });
+    ranked.into_iter().map(|(_, hit)| hit).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::CaptureAnalysis;
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+
+    /// LLM scoring each candidate by the content suffix it ends with.
+    struct MockRerankLlm {
+        scores: Vec<(&'static str, f32)>,
+        should_fail: bool,
+    }
+
+    impl LlmProvider for MockRerankLlm {
+        fn name(&self) -> &'static str {
+            "mock"
+        }
+
+        fn complete(&self, prompt: &str) -> Result<String> {
+            if self.should_fail {
+                return Err(crate::Error::OperationFailed {
+                    operation: "mock_complete".to_string(),
+                    cause: "Mock LLM failure".to_string(),
+                });
+            }
+            let scores: Vec<String> = prompt
+                .lines()
+                .filter_map(|line| {
+                    let (id, rest) = line.strip_prefix('[')?.split_once(']')?;
+                    let score = self
+                        .scores
+                        .iter()
+                        .find(|(content, _)| rest.ends_with(content))?
+                        .1;
+                    Some(format!(r#"{{"id": {id}, "score": {score}}}"#))
+                })
+                .collect();
+            Ok(format!(
+                "```json\n{{\"scores\": [{}]}}\n```",
+                scores.join(", ")
+            ))
+        }
+
+        fn analyze_for_capture(&self, _content: &str) -> Result<CaptureAnalysis> {
+            Ok(CaptureAnalysis {
+                should_capture: false,
+                confidence: 0.0,
+                suggested_namespace: None,
+                suggested_tags: vec![],
+                reasoning: "Mock analysis".to_string(),
+            })
+        }
+    }
+
+    fn hit(id: &str, content: &str, score: f32) -> SearchHit {
+        let memory = Memory {
+            id: MemoryId::new(id),
+            content: content.to_string(),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1,
+            updated_at: 1,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        };
+        SearchHit::new(memory, score)
+    }
+
+    fn candidates() -> Vec<SearchHit> {
+        vec![
+            hit("a", "Postgres connection pool size is 20", 1.0),
+            hit("b", "Chose PostgreSQL over MySQL for JSONB support", 0.8),
+            hit("c", "Database backups run nightly", 0.6),
+        ]
+    }
+
+    fn ids(hits: &[SearchHit]) -> Vec<&str> {
+        hits.iter().map(|h| h.memory.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_rerank_orders_by_llm_scores() {
+        let llm = MockRerankLlm {
+            scores: vec![
+                ("pool size is 20", 0.2),
+                ("for JSONB support", 0.9),
+                ("run nightly", 0.5),
+            ],
+            should_fail: false,
+        };
+        let service = RerankService::new(Arc::new(llm));
+
+        let hits = service.rerank("why did we pick postgres", candidates(), 2);
+        assert_eq!(ids(&hits), vec!["b", "c"]);
+    }
+
+    #[test]
+    fn test_rerank_keeps_unscored_candidates_last() {
+        let llm = MockRerankLlm {
+            scores: vec![("run nightly", 0.7)],
+            should_fail: false,
+        };
+        let service = RerankService::new(Arc::new(llm));
+
+        let hits = service.rerank("backups", candidates(), 10);
+        assert_eq!(ids(&hits), vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_rerank_falls_back_to_retrieval_order_on_llm_failure() {
+        let llm = MockRerankLlm {
+            scores: Vec::new(),
+            should_fail: true,
+        };
+        let service = RerankService::new(Arc::new(llm));
+
+        let hits = service.rerank("postgres", candidates(), 2);
+        assert_eq!(ids(&hits), vec!["a", "b"]);
+    }
+}
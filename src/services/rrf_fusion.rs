@@ -245,7 +245,8 @@ pub fn fuse_multiple(&self, result_lists: &[&[SearchHit]]) -> Vec<SearchHit> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::storage::index::DomainScope;
 
     fn create_test_memory(id: &str) -> Memory {
         Memory {
@@ -266,6 +267,13 @@ fn create_test_memory(id: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -276,6 +284,9 @@ fn create_hit(id: &str, score: f32) -> SearchHit {
             raw_score: score,
             vector_score: None,
             bm25_score: Some(score),
+            scope: DomainScope::Project,
+            domain: Domain::new(),
+            snippet: None,
         }
     }
 
@@ -286,6 +297,9 @@ fn create_vector_hit(id: &str, score: f32) -> SearchHit {
             raw_score: score,
             vector_score: Some(score),
             bm25_score: None,
+            scope: DomainScope::Project,
+            domain: Domain::new(),
+            snippet: None,
         }
     }
 
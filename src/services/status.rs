@@ -0,0 +1,629 @@
+//! Component health for `subcog status --json`.
+//!
+//! [`StatusService`] inspects each component subcog depends on and reports a
+//! [`ComponentStatus`] with details, plus counts and sizes for the indices:
+//!
+//! - **`git_repo`**: the repository project-scoped memories are attached to
+//! - **`notes_ref`**: leftover `refs/notes/subcog` from the removed git-notes storage
+//! - **`sqlite_index`**: the `SQLite` index database
+//! - **`vector_index`**: the vector similarity index
+//! - **`embedder`**: the embedding model
+//! - **`embedding_cache`**: the on-disk cache of embeddings and its hit rate
+//! - **`llm`**: the configured LLM provider
+//!
+//! The check is cheap and side-effect free: databases are opened read-only,
+//! no embedding model is loaded, and the LLM is not called, so monitors can
+//! poll it.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::services::StatusService;
+//!
+//! let report = StatusService::from_config(&config).report();
+//! println!("{}", serde_json::to_string_pretty(&report)?);
+//! ```
+
+use crate::config::{LlmConfig, LlmProvider, StorageBackendType, SubcogConfig, is_offline};
+use crate::embedding::{ContentEmbeddingCache, Embedder, FastEmbedEmbedder};
+use crate::services::PathManager;
+use crate::storage::index::{INDEX_SCHEMA_VERSION, SqliteBackend};
+use crate::storage::traits::VectorBackend;
+use crate::storage::vector::UsearchBackend;
+use crate::{Error, Result};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Reference the removed git-notes storage wrote memories to.
+const LEGACY_NOTES_REF: &str = "refs/notes/subcog";
+
+/// Health of a single component.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ComponentStatus {
+    /// Turned off by configuration or not applicable.
+    Disabled,
+    /// Working.
+    Ok,
+    /// Working with reduced functionality, or needs attention.
+    Degraded,
+    /// Missing or failing.
+    Unavailable,
+}
+
+impl ComponentStatus {
+    /// Returns the status as a string.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disabled => "disabled",
+            Self::Ok => "ok",
+            Self::Degraded => "degraded",
+            Self::Unavailable => "unavailable",
+        }
+    }
+}
+
+/// Status of one component with its details.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentHealth {
+    /// Health of the component.
+    pub status: ComponentStatus,
+    /// Human-readable explanation of the status.
+    pub details: String,
+    /// File or directory the component lives in.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<PathBuf>,
+    /// Number of entries (memories or vectors) in an index.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<usize>,
+    /// Size of an index on disk in bytes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
+}
+
+impl ComponentHealth {
+    fn new(status: ComponentStatus, details: impl Into<String>) -> Self {
+        Self {
+            status,
+            details: details.into(),
+            path: None,
+            count: None,
+            size_bytes: None,
+        }
+    }
+
+    fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+}
+
+/// Health of every component.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusComponents {
+    /// Git repository.
+    pub git_repo: ComponentHealth,
+    /// Legacy git notes reference.
+    pub notes_ref: ComponentHealth,
+    /// `SQLite` index database.
+    pub sqlite_index: ComponentHealth,
+    /// Vector similarity index.
+    pub vector_index: ComponentHealth,
+    /// Embedding model.
+    pub embedder: ComponentHealth,
+    /// Embedding cache.
+    pub embedding_cache: ComponentHealth,
+    /// LLM provider.
+    pub llm: ComponentHealth,
+}
+
+impl StatusComponents {
+    fn iter(&self) -> impl Iterator<Item = &ComponentHealth> {
+        [
+            &self.git_repo,
+            &self.notes_ref,
+            &self.sqlite_index,
+            &self.vector_index,
+            &self.embedder,
+            &self.embedding_cache,
+            &self.llm,
+        ]
+        .into_iter()
+    }
+}
+
+/// Machine-readable status report.
+#[derive(Debug, Clone, Serialize)]
+pub struct StatusReport {
+    /// Worst status of any component.
+    pub status: ComponentStatus,
+    /// Subcog version.
+    pub version: String,
+    /// Index schema version this build writes.
+    pub schema_version: u32,
+    /// Global embedding model.
+    pub embedding_model: String,
+    /// Dimensions of the global embedding model.
+    pub embedding_dimensions: usize,
+    /// Models of namespaces embedded with their own model.
+    pub namespace_embedding_models: BTreeMap<String, String>,
+    /// Health of each component.
+    pub components: StatusComponents,
+}
+
+/// Collects component health.
+pub struct StatusService {
+    /// Repository path from the configuration.
+    repo_path: PathBuf,
+    /// Storage paths.
+    paths: PathManager,
+    /// Project storage backend.
+    backend: StorageBackendType,
+    /// Whether LLM features are enabled.
+    llm_features: bool,
+    /// LLM configuration.
+    llm: LlmConfig,
+    /// Global embedding model.
+    embedding_model: String,
+    /// Embedder for the global model, `None` if the model is unknown.
+    embedder: Option<FastEmbedEmbedder>,
+    /// Namespaces embedded with their own model.
+    namespace_models: BTreeMap<String, String>,
+}
+
+impl StatusService {
+    /// Creates a status service for `config`.
+    #[must_use]
+    pub fn from_config(config: &SubcogConfig) -> Self {
+        Self {
+            repo_path: config.repo_path.clone(),
+            paths: PathManager::for_user(&config.data_dir),
+            backend: config.storage.project.backend,
+            llm_features: config.features.llm_features,
+            llm: config.llm.clone(),
+            embedding_model: config.embedding.model().to_string(),
+            embedder: FastEmbedEmbedder::with_model(config.embedding.model()).ok(),
+            namespace_models: config
+                .embedding
+                .namespace_models()
+                .map(|(namespace, model)| (namespace.as_str().to_string(), model.to_string()))
+                .collect(),
+        }
+    }
+
+    /// Checks every component.
+    #[must_use]
+    pub fn report(&self) -> StatusReport {
+        let repo = git2::Repository::discover(&self.repo_path).ok();
+        let components = StatusComponents {
+            git_repo: self.check_git_repo(repo.as_ref()),
+            notes_ref: check_notes_ref(repo.as_ref()),
+            sqlite_index: self.check_sqlite_index(),
+            vector_index: self.check_vector_index(),
+            embedder: check_embedder(&self.embedding_model, self.embedder.as_ref()),
+            embedding_cache: self.check_embedding_cache(),
+            llm: self.check_llm(),
+        };
+        let status = components
+            .iter()
+            .map(|component| component.status)
+            .max()
+            .unwrap_or(ComponentStatus::Ok)
+            .max(ComponentStatus::Ok);
+
+        StatusReport {
+            status,
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            schema_version: INDEX_SCHEMA_VERSION,
+            embedding_model: self.embedding_model.clone(),
+            embedding_dimensions: self.embedder.as_ref().map_or(0, Embedder::dimensions),
+            namespace_embedding_models: self.namespace_models.clone(),
+            components,
+        }
+    }
+
+    fn check_git_repo(&self, repo: Option<&git2::Repository>) -> ComponentHealth {
+        let Some(repo) = repo else {
+            return ComponentHealth::new(
+                ComponentStatus::Unavailable,
+                "not a git repository; memories are user-scoped",
+            )
+            .with_path(&self.repo_path);
+        };
+        let branch = repo
+            .head()
+            .ok()
+            .and_then(|head| head.shorthand().ok().map(ToString::to_string));
+        let details = branch.map_or_else(
+            || "no commits yet".to_string(),
+            |branch| format!("on branch {branch}"),
+        );
+        let path = repo.workdir().unwrap_or_else(|| repo.path());
+        ComponentHealth::new(ComponentStatus::Ok, details).with_path(path)
+    }
+
+    fn check_sqlite_index(&self) -> ComponentHealth {
+        if self.backend != StorageBackendType::Sqlite {
+            return ComponentHealth::new(
+                ComponentStatus::Disabled,
+                format!("project storage uses the {:?} backend", self.backend),
+            );
+        }
+        let path = self.paths.index_path();
+        if !path.exists() {
+            return ComponentHealth::new(
+                ComponentStatus::Unavailable,
+                "not initialized; created by the first capture",
+            )
+            .with_path(path);
+        }
+
+        let size_bytes = files_size(&[path.clone(), wal_path(&path)]);
+        let mut health = match SqliteBackend::summarize(&path) {
+            Ok(summary) if summary.schema_version < INDEX_SCHEMA_VERSION => {
+                let mut health = ComponentHealth::new(
+                    ComponentStatus::Degraded,
+                    format!(
+                        "schema version {} is older than {INDEX_SCHEMA_VERSION}; \
+                         it is migrated the next time the index is opened",
+                        summary.schema_version
+                    ),
+                );
+                health.count = Some(summary.memories);
+                health
+            },
+            Ok(summary) => {
+                let mut health = ComponentHealth::new(
+                    ComponentStatus::Ok,
+                    format!(
+                        "{} memories ({} tombstoned), schema version {}",
+                        summary.memories, summary.tombstoned, summary.schema_version
+                    ),
+                );
+                health.count = Some(summary.memories);
+                health
+            },
+            Err(e) => ComponentHealth::new(ComponentStatus::Unavailable, e.to_string()),
+        };
+        health.size_bytes = Some(size_bytes);
+        health.with_path(path)
+    }
+
+    fn check_vector_index(&self) -> ComponentHealth {
+        let path = self.paths.vector_path();
+        let files: Vec<PathBuf> = UsearchBackend::index_files(&path)
+            .into_iter()
+            .filter(|file| file.exists())
+            .collect();
+        if files.is_empty() {
+            return ComponentHealth::new(
+                ComponentStatus::Unavailable,
+                "not initialized; created by the first capture with an embedding",
+            )
+            .with_path(path);
+        }
+
+        let expected = self.embedder.as_ref().map(Embedder::dimensions);
+        let mut health = match count_vectors(&path) {
+            Ok((count, dimensions)) if expected.is_some_and(|expected| expected != dimensions) => {
+                let mut health = ComponentHealth::new(
+                    ComponentStatus::Degraded,
+                    format!(
+                        "index has {dimensions} dimensions but {} produces {}; run \
+                         `subcog migrate embeddings --force`",
+                        self.embedding_model,
+                        expected.unwrap_or_default()
+                    ),
+                );
+                health.count = Some(count);
+                health
+            },
+            Ok((count, dimensions)) => {
+                let mut health = ComponentHealth::new(
+                    ComponentStatus::Ok,
+                    format!("{count} vectors, {dimensions} dimensions"),
+                );
+                health.count = Some(count);
+                health
+            },
+            Err(e) => ComponentHealth::new(ComponentStatus::Unavailable, e.to_string()),
+        };
+        health.size_bytes = Some(files_size(&files));
+        health.with_path(path)
+    }
+
+    fn check_embedding_cache(&self) -> ComponentHealth {
+        if !cfg!(feature = "fastembed-embeddings") {
+            return ComponentHealth::new(
+                ComponentStatus::Disabled,
+                "only used with fastembed-embeddings",
+            );
+        }
+        let path = self.paths.embedding_cache_path();
+        if !path.exists() {
+            let mut health =
+                ComponentHealth::new(ComponentStatus::Ok, "empty; created by the first embedding");
+            health.count = Some(0);
+            return health.with_path(path);
+        }
+
+        let size_bytes = files_size(&[path.clone(), wal_path(&path)]);
+        let mut health = match ContentEmbeddingCache::summarize(&path) {
+            Ok(stats) => {
+                let mut health = ComponentHealth::new(ComponentStatus::Ok, stats.to_string());
+                health.count = usize::try_from(stats.entries).ok();
+                health
+            },
+            Err(e) => ComponentHealth::new(
+                ComponentStatus::Degraded,
+                format!("{e}; embeddings are computed without the cache"),
+            ),
+        };
+        health.size_bytes = Some(size_bytes);
+        health.with_path(path)
+    }
+
+    fn check_llm(&self) -> ComponentHealth {
+        if !self.llm_features {
+            return ComponentHealth::new(ComponentStatus::Disabled, "LLM features are disabled");
+        }
+        if is_offline() {
+            return ComponentHealth::new(ComponentStatus::Disabled, "offline mode");
+        }
+        let model = self.llm.model.as_deref().unwrap_or("default model");
+        let api_key_env = match self.llm.provider {
+            LlmProvider::None => {
+                return ComponentHealth::new(ComponentStatus::Disabled, "no provider configured");
+            },
+            LlmProvider::Anthropic => Some("ANTHROPIC_API_KEY"),
+            LlmProvider::OpenAi => Some("OPENAI_API_KEY"),
+            LlmProvider::Gemini => Some("GEMINI_API_KEY"),
+            LlmProvider::Ollama | LlmProvider::LmStudio => None,
+        };
+        let provider = format!("{:?}", self.llm.provider).to_lowercase();
+        let has_key = self.llm.api_key.as_ref().is_some_and(|key| !key.is_empty())
+            || api_key_env.is_some_and(|var| std::env::var_os(var).is_some());
+        match api_key_env {
+            Some(var) if !has_key => ComponentHealth::new(
+                ComponentStatus::Unavailable,
+                format!("{provider}: no API key (set llm.api_key or {var})"),
+            ),
+            _ => ComponentHealth::new(ComponentStatus::Ok, format!("{provider} ({model})")),
+        }
+    }
+}
+
+/// Reports the legacy notes ref, which is no longer read.
+fn check_notes_ref(repo: Option<&git2::Repository>) -> ComponentHealth {
+    let present = repo.is_some_and(|repo| repo.find_reference(LEGACY_NOTES_REF).is_ok());
+    if present {
+        ComponentHealth::new(
+            ComponentStatus::Degraded,
+            format!(
+                "{LEGACY_NOTES_REF} is left over from git-notes storage and is no longer read; \
+                 it can be deleted"
+            ),
+        )
+    } else {
+        ComponentHealth::new(
+            ComponentStatus::Disabled,
+            "memories are stored in the index, not in git notes",
+        )
+    }
+}
+
+/// Reports the embedder without loading the model.
+fn check_embedder(model: &str, embedder: Option<&FastEmbedEmbedder>) -> ComponentHealth {
+    let Some(embedder) = embedder else {
+        return ComponentHealth::new(
+            ComponentStatus::Unavailable,
+            format!("unknown embedding model '{model}'"),
+        );
+    };
+    if cfg!(feature = "fastembed-embeddings") {
+        ComponentHealth::new(
+            ComponentStatus::Ok,
+            format!(
+                "fastembed {} ({} dimensions)",
+                embedder.model_name(),
+                embedder.dimensions()
+            ),
+        )
+    } else {
+        ComponentHealth::new(
+            ComponentStatus::Degraded,
+            "built without fastembed-embeddings; hash-based fallback embeddings",
+        )
+    }
+}
+
+/// Loads the vector index at `path` and returns its vector count and dimensions.
+fn count_vectors(path: &Path) -> Result<(usize, usize)> {
+    let dimensions =
+        UsearchBackend::stored_dimensions(path)?.ok_or_else(|| Error::OperationFailed {
+            operation: "read_vector_index".to_string(),
+            cause: format!("{} has no recorded dimensions", path.display()),
+        })?;
+    #[cfg(feature = "usearch-hnsw")]
+    let backend = UsearchBackend::new(path, dimensions)?;
+    #[cfg(not(feature = "usearch-hnsw"))]
+    let backend = UsearchBackend::new(path, dimensions);
+    backend.load()?;
+    Ok((backend.count()?, dimensions))
+}
+
+/// Returns the write-ahead log of the database at `path`.
+fn wal_path(path: &Path) -> PathBuf {
+    let mut wal = path.as_os_str().to_owned();
+    wal.push("-wal");
+    PathBuf::from(wal)
+}
+
+/// Returns the combined size of the existing `files`.
+fn files_size(files: &[PathBuf]) -> u64 {
+    files
+        .iter()
+        .filter_map(|file| std::fs::metadata(file).ok())
+        .map(|metadata| metadata.len())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::EmbeddingConfig;
+    use crate::embedding::{DEFAULT_DIMENSIONS, DEFAULT_MODEL};
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::storage::traits::IndexBackend;
+    use tempfile::TempDir;
+
+    fn memory(id: &str) -> Memory {
+        Memory {
+            id: MemoryId::new(id),
+            content: format!("Use SQLite for the local index ({id})"),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1,
+            updated_at: 1,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    #[test]
+    fn test_json_reports_every_component_for_healthy_setup() {
+        let repo_dir = TempDir::new().unwrap();
+        git2::Repository::init(repo_dir.path()).unwrap();
+        let data_dir = TempDir::new().unwrap();
+        let paths = PathManager::for_user(data_dir.path());
+
+        let index = SqliteBackend::new(paths.index_path()).unwrap();
+        index.index(&memory("a")).unwrap();
+        index.index(&memory("b")).unwrap();
+        #[cfg(feature = "usearch-hnsw")]
+        let vectors = UsearchBackend::new(paths.vector_path(), DEFAULT_DIMENSIONS).unwrap();
+        #[cfg(not(feature = "usearch-hnsw"))]
+        let vectors = UsearchBackend::new(paths.vector_path(), DEFAULT_DIMENSIONS);
+        vectors
+            .upsert(&MemoryId::new("a"), &[0.5; DEFAULT_DIMENSIONS])
+            .unwrap();
+        vectors.save().unwrap();
+
+        let config = SubcogConfig {
+            repo_path: repo_dir.path().to_path_buf(),
+            data_dir: data_dir.path().to_path_buf(),
+            ..SubcogConfig::default()
+        };
+        let report = StatusService::from_config(&config).report();
+        let json = serde_json::to_value(&report).unwrap();
+
+        for key in [
+            "git_repo",
+            "notes_ref",
+            "sqlite_index",
+            "vector_index",
+            "embedder",
+            "embedding_cache",
+            "llm",
+        ] {
+            let status = json["components"][key]["status"].as_str().unwrap();
+            assert!(
+                ["ok", "degraded", "unavailable", "disabled"].contains(&status),
+                "{key}: {status}"
+            );
+        }
+        assert_eq!(json["components"]["git_repo"]["status"], "ok");
+        assert_eq!(json["components"]["sqlite_index"]["status"], "ok");
+        assert_eq!(json["components"]["sqlite_index"]["count"], 2);
+        assert_eq!(json["components"]["vector_index"]["status"], "ok");
+        assert_eq!(json["components"]["vector_index"]["count"], 1);
+        assert_eq!(json["schema_version"], INDEX_SCHEMA_VERSION);
+        assert_eq!(json["embedding_model"], DEFAULT_MODEL);
+    }
+
+    #[test]
+    fn test_configured_model_with_other_dimensions_degrades_vector_index() {
+        let data_dir = TempDir::new().unwrap();
+        let paths = PathManager::for_user(data_dir.path());
+        #[cfg(feature = "usearch-hnsw")]
+        let vectors = UsearchBackend::new(paths.vector_path(), DEFAULT_DIMENSIONS).unwrap();
+        #[cfg(not(feature = "usearch-hnsw"))]
+        let vectors = UsearchBackend::new(paths.vector_path(), DEFAULT_DIMENSIONS);
+        vectors
+            .upsert(&MemoryId::new("a"), &[0.5; DEFAULT_DIMENSIONS])
+            .unwrap();
+        vectors.save().unwrap();
+
+        let config = SubcogConfig {
+            data_dir: data_dir.path().to_path_buf(),
+            embedding: EmbeddingConfig::new().with_model("jina-embeddings-v2-base-code"),
+            ..SubcogConfig::default()
+        };
+        let report = StatusService::from_config(&config).report();
+
+        assert_eq!(report.embedding_model, "jina-embeddings-v2-base-code");
+        assert_eq!(report.embedding_dimensions, 768);
+        let vector_index = &report.components.vector_index;
+        assert_eq!(vector_index.status, ComponentStatus::Degraded);
+        assert!(
+            vector_index.details.contains("--force"),
+            "{}",
+            vector_index.details
+        );
+
+        let config = SubcogConfig {
+            embedding: EmbeddingConfig::new().with_model("no-such-model"),
+            ..config
+        };
+        let report = StatusService::from_config(&config).report();
+        assert_eq!(
+            report.components.embedder.status,
+            ComponentStatus::Unavailable
+        );
+    }
+
+    #[test]
+    fn test_missing_components_are_unavailable() {
+        let repo_dir = TempDir::new().unwrap();
+        let data_dir = TempDir::new().unwrap();
+        let config = SubcogConfig {
+            repo_path: repo_dir.path().to_path_buf(),
+            data_dir: data_dir.path().to_path_buf(),
+            ..SubcogConfig::default()
+        };
+
+        let report = StatusService::from_config(&config).report();
+        assert_eq!(
+            report.components.sqlite_index.status,
+            ComponentStatus::Unavailable
+        );
+        assert_eq!(
+            report.components.vector_index.status,
+            ComponentStatus::Unavailable
+        );
+        assert_eq!(report.status, ComponentStatus::Unavailable);
+        // Status is read-only: nothing is created
+        assert!(!PathManager::for_user(data_dir.path()).index_path().exists());
+    }
+}
@@ -53,9 +53,10 @@ pub const fn is_enabled(&self) -> bool {
     ///
     /// # Errors
     ///
-    /// This function does not return errors (always succeeds with empty stats).
+    /// Returns an error in offline mode; otherwise succeeds with empty stats.
     #[instrument(skip(self), fields(operation = "sync.fetch"))]
     pub fn fetch(&self) -> Result<SyncStats> {
+        crate::config::ensure_online("sync.fetch")?;
         let start = Instant::now();
 
         // Remote sync no longer supported - return empty stats
@@ -80,9 +81,10 @@ pub fn fetch(&self) -> Result<SyncStats> {
     ///
     /// # Errors
     ///
-    /// This function does not return errors (always succeeds with empty stats).
+    /// Returns an error in offline mode; otherwise succeeds with empty stats.
     #[instrument(skip(self), fields(operation = "sync.push"))]
     pub fn push(&self) -> Result<SyncStats> {
+        crate::config::ensure_online("sync.push")?;
         let start = Instant::now();
 
         // Remote sync no longer supported - return empty stats
@@ -107,9 +109,10 @@ pub fn push(&self) -> Result<SyncStats> {
     ///
     /// # Errors
     ///
-    /// This function does not return errors (always succeeds with empty stats).
+    /// Returns an error in offline mode; otherwise succeeds with empty stats.
     #[instrument(skip(self), fields(operation = "sync.full"))]
     pub fn sync(&self) -> Result<SyncStats> {
+        crate::config::ensure_online("sync.sync")?;
         let start = Instant::now();
 
         // Remote sync no longer supported - return empty stats
@@ -0,0 +1,383 @@
+//! Tag maintenance: renaming and merging tags across memories.
+//!
+//! Tags accumulate inconsistently over time (`auth`, `authn`,
+//! `authentication`). [`TagService`] rewrites them across every stored
+//! memory, including tombstoned ones so restores stay consistent.
+//!
+//! Renaming onto a tag a memory already carries merges the two: each memory
+//! keeps a single copy of the target tag, in the position of its first
+//! occurrence. All rewritten memories are written back with one
+//! [`IndexBackend::reindex`] call, which the `SQLite` backend applies in a
+//! single transaction, after being stored in the persistence backend when
+//! one is configured.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::services::TagService;
+//!
+//! let service = TagService::new(index);
+//!
+//! // Preview, then apply
+//! let preview = service.merge(&["authn", "auth"], "authentication", true)?;
+//! println!("{}", preview.summary());
+//! service.merge(&["authn", "auth"], "authentication", false)?;
+//! ```
+
+use crate::models::{EventMeta, Memory, MemoryEvent, MemoryId, SearchFilter};
+use crate::observability::current_request_id;
+use crate::security::record_event;
+use crate::storage::traits::{IndexBackend, PersistenceBackend};
+use crate::{Error, Result};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{info, instrument};
+
+/// A memory whose tags were (or would be) rewritten.
+#[derive(Debug, Clone)]
+pub struct TagRewrite {
+    /// The rewritten memory.
+    pub id: MemoryId,
+    /// Tags before the rewrite.
+    pub before: Vec<String>,
+    /// Tags after the rewrite.
+    pub after: Vec<String>,
+}
+
+/// Result of a tag rename or merge.
+#[derive(Debug, Clone, Default)]
+pub struct TagRewriteResult {
+    /// Tags that were replaced.
+    pub sources: Vec<String>,
+    /// Tag they were replaced with.
+    pub target: String,
+    /// Total number of memories checked.
+    pub memories_checked: usize,
+    /// Memories whose tags changed.
+    pub rewrites: Vec<TagRewrite>,
+    /// Whether this was a dry run (no changes written).
+    pub dry_run: bool,
+    /// Duration of the operation in milliseconds.
+    pub duration_ms: u64,
+}
+
+impl TagRewriteResult {
+    /// Returns a human-readable summary of the rewrite.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let verb = if self.dry_run {
+            "would retag"
+        } else {
+            "retagged"
+        };
+        format!(
+            "{verb} {} memories ({} -> {}) - checked {} in {}ms",
+            self.rewrites.len(),
+            self.sources.join(", "),
+            self.target,
+            self.memories_checked,
+            self.duration_ms
+        )
+    }
+}
+
+/// Renames and merges tags across all stored memories.
+pub struct TagService {
+    /// Index backend holding the memories.
+    index: Arc<dyn IndexBackend + Send + Sync>,
+    /// Optional persistence backend that rewritten memories are stored in.
+    persistence: Option<Arc<dyn PersistenceBackend + Send + Sync>>,
+}
+
+impl TagService {
+    /// Creates a new tag service.
+    #[must_use]
+    pub fn new(index: Arc<dyn IndexBackend + Send + Sync>) -> Self {
+        // Arc::strong_count prevents clippy::missing_const_for_fn false positive
+        let _ = Arc::strong_count(&index);
+        Self {
+            index,
+            persistence: None,
+        }
+    }
+
+    /// Adds a persistence backend.
+    ///
+    /// Rewritten memories are stored in it before the index is updated.
+    #[must_use]
+    pub fn with_persistence(
+        mut self,
+        persistence: Arc<dyn PersistenceBackend + Send + Sync>,
+    ) -> Self {
+        self.persistence = Some(persistence);
+        self
+    }
+
+    /// Renames `old` to `new` on every memory (unless `dry_run`).
+    ///
+    /// Memories that already carry `new` end up with a single copy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if either tag is invalid or they are
+    /// equal, or an error if index backend operations fail.
+    pub fn rename(&self, old: &str, new: &str, dry_run: bool) -> Result<TagRewriteResult> {
+        if old.trim() == new.trim() {
+            return Err(Error::InvalidInput(format!(
+                "Cannot rename tag '{old}' to itself"
+            )));
+        }
+        self.merge(&[old], new, dry_run)
+    }
+
+    /// Replaces each of `sources` with `target` on every memory (unless
+    /// `dry_run`), deduplicating the result.
+    ///
+    /// A source equal to `target` is ignored, so `merge(["a", "b"], "a")`
+    /// folds `b` into `a`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if a tag is invalid or no source
+    /// differs from `target`, or an error if index backend operations fail.
+    #[instrument(
+        name = "subcog.tags.merge",
+        skip(self, sources),
+        fields(component = "tags", operation = "merge", target = target, dry_run = dry_run)
+    )]
+    pub fn merge<S: AsRef<str>>(
+        &self,
+        sources: &[S],
+        target: &str,
+        dry_run: bool,
+    ) -> Result<TagRewriteResult> {
+        let start = Instant::now();
+        let target = validate_tag(target)?;
+        let mut source_tags: Vec<String> = Vec::new();
+        for source in sources {
+            let source = validate_tag(source.as_ref())?;
+            if source != target && !source_tags.contains(&source) {
+                source_tags.push(source);
+            }
+        }
+        if source_tags.is_empty() {
+            return Err(Error::InvalidInput(
+                "At least one source tag different from the target is required".to_string(),
+            ));
+        }
+
+        let filter = SearchFilter::new().with_include_tombstoned(true);
+        let ids: Vec<MemoryId> = self
+            .index
+            .list_all(&filter, usize::MAX)?
+            .into_iter()
+            .map(|(id, _score)| id)
+            .collect();
+
+        let mut result = TagRewriteResult {
+            sources: source_tags,
+            target,
+            memories_checked: ids.len(),
+            dry_run,
+            ..Default::default()
+        };
+
+        let now = crate::current_timestamp();
+        let mut updated: Vec<Memory> = Vec::new();
+        for mut memory in self.index.get_memories_batch(&ids)?.into_iter().flatten() {
+            let Some(after) = rewrite_tags(&memory.tags, &result.sources, &result.target) else {
+                continue;
+            };
+            result.rewrites.push(TagRewrite {
+                id: memory.id.clone(),
+                before: std::mem::replace(&mut memory.tags, after.clone()),
+                after,
+            });
+            memory.updated_at = now;
+            updated.push(memory);
+        }
+
+        if !dry_run && !updated.is_empty() {
+            if let Some(ref persistence) = self.persistence {
+                for memory in &updated {
+                    persistence.store(memory)?;
+                }
+            }
+            self.index.reindex(&updated)?;
+            for memory in &updated {
+                record_event(MemoryEvent::Updated {
+                    meta: EventMeta::with_timestamp("tags", current_request_id(), now),
+                    memory_id: memory.id.clone(),
+                    modified_fields: vec!["tags".to_string()],
+                });
+            }
+        }
+
+        result.duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+        info!(
+            memories_checked = result.memories_checked,
+            rewritten = result.rewrites.len(),
+            dry_run,
+            "Tag merge completed"
+        );
+        Ok(result)
+    }
+}
+
+/// Trims a tag and checks it can be stored.
+fn validate_tag(tag: &str) -> Result<String> {
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err(Error::InvalidInput("Tag cannot be empty".to_string()));
+    }
+    // Tags are stored comma-joined in the index
+    if tag.contains(',') {
+        return Err(Error::InvalidInput(format!(
+            "Tag cannot contain a comma: {tag}"
+        )));
+    }
+    Ok(tag.to_string())
+}
+
+/// Returns the rewritten tag list, or `None` if no source tag is present.
+fn rewrite_tags(tags: &[String], sources: &[String], target: &str) -> Option<Vec<String>> {
+    if !tags.iter().any(|tag| sources.contains(tag)) {
+        return None;
+    }
+
+    let mut rewritten: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = if sources.contains(tag) {
+            target
+        } else {
+            tag.as_str()
+        };
+        if !rewritten.iter().any(|existing| existing == tag) {
+            rewritten.push(tag.to_string());
+        }
+    }
+    Some(rewritten)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Domain, MemoryStatus, Namespace};
+    use crate::storage::index::SqliteBackend;
+
+    fn seed(index: &Arc<dyn IndexBackend + Send + Sync>, id: &str, tags: &[&str]) {
+        let memory = Memory {
+            id: MemoryId::new(id),
+            content: format!("Memory {id}"),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1,
+            updated_at: 1,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: tags.iter().map(ToString::to_string).collect(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        };
+        index.index(&memory).unwrap();
+    }
+
+    fn tags_of(index: &Arc<dyn IndexBackend + Send + Sync>, id: &str) -> Vec<String> {
+        index
+            .get_memory(&MemoryId::new(id))
+            .unwrap()
+            .map(|m| m.tags)
+            .unwrap_or_default()
+    }
+
+    fn seeded_index() -> Arc<dyn IndexBackend + Send + Sync> {
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        seed(&index, "a", &["authn", "rust"]);
+        seed(&index, "b", &["auth"]);
+        seed(&index, "c", &["authentication", "authn"]);
+        seed(&index, "d", &["rust"]);
+        index
+    }
+
+    #[test]
+    fn test_rename_and_dry_run() {
+        let index = seeded_index();
+        let service = TagService::new(Arc::clone(&index));
+
+        let preview = service.rename("authn", "authentication", true).unwrap();
+        assert_eq!(preview.rewrites.len(), 2);
+        assert_eq!(tags_of(&index, "a"), vec!["authn", "rust"]);
+
+        let result = service.rename("authn", "authentication", false).unwrap();
+        assert_eq!(result.rewrites.len(), 2);
+        assert_eq!(tags_of(&index, "a"), vec!["authentication", "rust"]);
+        assert_eq!(tags_of(&index, "b"), vec!["auth"]);
+        assert_eq!(tags_of(&index, "d"), vec!["rust"]);
+    }
+
+    #[test]
+    fn test_rename_onto_existing_tag_dedupes() {
+        let index = seeded_index();
+        let service = TagService::new(Arc::clone(&index));
+
+        service.rename("authn", "authentication", false).unwrap();
+        assert_eq!(tags_of(&index, "c"), vec!["authentication"]);
+    }
+
+    #[test]
+    fn test_merge_into_target() {
+        let index = seeded_index();
+        let service = TagService::new(Arc::clone(&index));
+
+        let result = service
+            .merge(&["authn", "auth", "authentication"], "auth", false)
+            .unwrap();
+        assert_eq!(result.sources, vec!["authn", "authentication"]);
+        assert_eq!(result.rewrites.len(), 2);
+        assert_eq!(tags_of(&index, "a"), vec!["auth", "rust"]);
+        assert_eq!(tags_of(&index, "b"), vec!["auth"]);
+        assert_eq!(tags_of(&index, "c"), vec!["auth"]);
+    }
+
+    #[test]
+    fn test_merge_stores_rewritten_memories_in_persistence() {
+        use crate::storage::persistence::FilesystemBackend;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let persistence: Arc<dyn PersistenceBackend + Send + Sync> =
+            Arc::new(FilesystemBackend::new(dir.path()));
+        let service = TagService::new(seeded_index()).with_persistence(Arc::clone(&persistence));
+
+        service.merge(&["authn"], "auth", false).unwrap();
+        let stored = persistence.get(&MemoryId::new("a")).unwrap().unwrap();
+        assert_eq!(stored.tags, vec!["auth", "rust"]);
+        // Untouched memories are not written
+        assert!(persistence.get(&MemoryId::new("d")).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_rejects_invalid_tags() {
+        let service = TagService::new(seeded_index());
+
+        assert!(service.rename("auth", "auth", true).is_err());
+        assert!(service.rename("auth", " ", true).is_err());
+        assert!(service.merge(&["a,b"], "auth", true).is_err());
+    }
+}
@@ -162,7 +162,7 @@ pub fn purge_tombstoned(&self, older_than: Duration) -> Result<usize> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, Memory, Namespace};
+    use crate::models::{CaptureOrigin, Domain, Memory, Namespace};
     use crate::storage::persistence::FilesystemBackend;
     use tempfile::TempDir;
 
@@ -188,6 +188,13 @@ fn create_test_memory(id: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -0,0 +1,325 @@
+//! Integrity check between the persistence backend and the search index.
+//!
+//! The persistence backend is the authoritative copy of every memory; the
+//! index is derived from it and can drift (a crash between the two writes, a
+//! row removed by hand). [`VerifyService`] walks both and reports:
+//!
+//! - **missing**: persisted memories with no index row
+//! - **stale**: index rows older than the persisted memory
+//! - **mismatched**: index rows whose content hash differs from the persisted memory
+//! - **orphaned**: index rows with no persisted memory behind them
+//!
+//! Verification is read-only unless `fix` is set, in which case missing,
+//! stale and mismatched memories are reindexed from persistence in one
+//! [`IndexBackend::reindex`] call and orphaned rows are removed.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::services::VerifyService;
+//!
+//! let service = VerifyService::new(persistence, index);
+//! let report = service.verify(false)?;
+//! println!("{}", report.summary());
+//! ```
+
+use crate::Result;
+use crate::models::{Memory, MemoryId, SearchFilter};
+use crate::storage::traits::{IndexBackend, PersistenceBackend};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+use tracing::{info, instrument};
+
+/// Result of an integrity check.
+#[derive(Debug, Clone, Default)]
+pub struct VerifyReport {
+    /// Memories checked in the persistence backend.
+    pub persisted: usize,
+    /// Rows checked in the index.
+    pub indexed: usize,
+    /// Persisted memories with no index row.
+    pub missing: Vec<MemoryId>,
+    /// Index rows last updated before the persisted memory.
+    pub stale: Vec<MemoryId>,
+    /// Index rows whose content differs from the persisted memory.
+    pub mismatched: Vec<MemoryId>,
+    /// Index rows with no persisted memory.
+    pub orphaned: Vec<MemoryId>,
+    /// Whether discrepancies were repaired.
+    pub fixed: bool,
+    /// Duration of the check in milliseconds.
+    pub duration_ms: u64,
+}
+
+impl VerifyReport {
+    /// Returns the total number of discrepancies found.
+    #[must_use]
+    pub const fn discrepancies(&self) -> usize {
+        self.missing.len() + self.stale.len() + self.mismatched.len() + self.orphaned.len()
+    }
+
+    /// Returns whether the index matches the persistence backend.
+    #[must_use]
+    pub const fn is_consistent(&self) -> bool {
+        self.discrepancies() == 0
+    }
+
+    /// Returns a human-readable summary of the check.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let verb = if self.fixed { "repaired" } else { "found" };
+        format!(
+            "{verb} {} discrepancies ({} missing, {} stale, {} mismatched, {} orphaned) - checked {} persisted / {} indexed in {}ms",
+            self.discrepancies(),
+            self.missing.len(),
+            self.stale.len(),
+            self.mismatched.len(),
+            self.orphaned.len(),
+            self.persisted,
+            self.indexed,
+            self.duration_ms
+        )
+    }
+}
+
+/// Compares the index against the persistence backend.
+pub struct VerifyService {
+    /// Authoritative memory store.
+    persistence: Arc<dyn PersistenceBackend + Send + Sync>,
+    /// Index derived from the persistence backend.
+    index: Arc<dyn IndexBackend + Send + Sync>,
+}
+
+impl VerifyService {
+    /// Creates a new verify service.
+    #[must_use]
+    pub fn new(
+        persistence: Arc<dyn PersistenceBackend + Send + Sync>,
+        index: Arc<dyn IndexBackend + Send + Sync>,
+    ) -> Self {
+        // Arc::strong_count prevents clippy::missing_const_for_fn false positive
+        let _ = Arc::strong_count(&index);
+        Self { persistence, index }
+    }
+
+    /// Checks the index against the persistence backend, repairing the
+    /// discrepancies when `fix` is set.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if either backend cannot be read or, with `fix`,
+    /// written.
+    #[instrument(
+        name = "subcog.verify",
+        skip(self),
+        fields(component = "verify", operation = "verify", fix = fix)
+    )]
+    pub fn verify(&self, fix: bool) -> Result<VerifyReport> {
+        let start = Instant::now();
+
+        let persisted: HashMap<MemoryId, Memory> = self
+            .persistence
+            .get_batch(&self.persistence.list_ids()?)?
+            .into_iter()
+            .map(|memory| (memory.id.clone(), memory))
+            .collect();
+
+        let filter = SearchFilter::new().with_include_tombstoned(true);
+        let index_ids: Vec<MemoryId> = self
+            .index
+            .list_all(&filter, usize::MAX)?
+            .into_iter()
+            .map(|(id, _score)| id)
+            .collect();
+        let indexed: HashMap<MemoryId, Memory> = self
+            .index
+            .get_memories_batch(&index_ids)?
+            .into_iter()
+            .flatten()
+            .map(|memory| (memory.id.clone(), memory))
+            .collect();
+
+        let mut report = VerifyReport {
+            persisted: persisted.len(),
+            indexed: indexed.len(),
+            ..Default::default()
+        };
+
+        for (id, memory) in &persisted {
+            match indexed.get(id) {
+                None => report.missing.push(id.clone()),
+                Some(row) if content_hash(&row.content) != content_hash(&memory.content) => {
+                    report.mismatched.push(id.clone());
+                },
+                Some(row) if row.updated_at < memory.updated_at => report.stale.push(id.clone()),
+                Some(_) => {},
+            }
+        }
+        let persisted_ids: HashSet<&MemoryId> = persisted.keys().collect();
+        report.orphaned = indexed
+            .keys()
+            .filter(|id| !persisted_ids.contains(id))
+            .cloned()
+            .collect();
+
+        for ids in [
+            &mut report.missing,
+            &mut report.stale,
+            &mut report.mismatched,
+            &mut report.orphaned,
+        ] {
+            ids.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        }
+
+        if fix && !report.is_consistent() {
+            self.repair(&report, &persisted)?;
+            report.fixed = true;
+        }
+
+        report.duration_ms = u64::try_from(start.elapsed().as_millis()).unwrap_or(u64::MAX);
+        info!(
+            missing = report.missing.len(),
+            stale = report.stale.len(),
+            mismatched = report.mismatched.len(),
+            orphaned = report.orphaned.len(),
+            fixed = report.fixed,
+            "Integrity check completed"
+        );
+        Ok(report)
+    }
+
+    /// Reindexes drifted memories from persistence and drops orphaned rows.
+    fn repair(&self, report: &VerifyReport, persisted: &HashMap<MemoryId, Memory>) -> Result<()> {
+        let reindex: Vec<Memory> = report
+            .missing
+            .iter()
+            .chain(&report.stale)
+            .chain(&report.mismatched)
+            .filter_map(|id| persisted.get(id).cloned())
+            .collect();
+        if !reindex.is_empty() {
+            self.index.reindex(&reindex)?;
+        }
+
+        for id in &report.orphaned {
+            self.index.remove(id)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the hex-encoded SHA-256 of the exact memory content.
+fn content_hash(content: &str) -> String {
+    hex::encode(Sha256::digest(content.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Domain, MemoryStatus, Namespace};
+    use crate::storage::index::SqliteBackend;
+    use crate::storage::persistence::FilesystemBackend;
+
+    fn memory(id: &str, content: &str) -> Memory {
+        Memory {
+            id: MemoryId::new(id),
+            content: content.to_string(),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1,
+            updated_at: 1,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    fn seeded(
+        dir: &tempfile::TempDir,
+    ) -> (
+        Arc<dyn PersistenceBackend + Send + Sync>,
+        Arc<dyn IndexBackend + Send + Sync>,
+    ) {
+        let persistence: Arc<dyn PersistenceBackend + Send + Sync> =
+            Arc::new(FilesystemBackend::new(dir.path()));
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        for (id, content) in [("a", "Use PostgreSQL"), ("b", "Prefer Rust"), ("c", "Tabs")] {
+            let memory = memory(id, content);
+            persistence.store(&memory).unwrap();
+            index.index(&memory).unwrap();
+        }
+        (persistence, index)
+    }
+
+    #[test]
+    fn test_verify_detects_and_fixes_deleted_index_row() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let (persistence, index) = seeded(&dir);
+        let service = VerifyService::new(persistence, Arc::clone(&index));
+        assert!(service.verify(false).unwrap().is_consistent());
+
+        // Remove a row behind the service's back
+        index.remove(&MemoryId::new("b")).unwrap();
+
+        let report = service.verify(false).unwrap();
+        assert_eq!(report.missing, vec![MemoryId::new("b")]);
+        assert!(!report.fixed);
+        assert!(index.get_memory(&MemoryId::new("b")).unwrap().is_none());
+
+        let fixed = service.verify(true).unwrap();
+        assert!(fixed.fixed);
+        assert_eq!(
+            index
+                .get_memory(&MemoryId::new("b"))
+                .unwrap()
+                .unwrap()
+                .content,
+            "Prefer Rust"
+        );
+        assert!(service.verify(false).unwrap().is_consistent());
+    }
+
+    #[test]
+    fn test_verify_reports_stale_mismatched_and_orphaned() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let (persistence, index) = seeded(&dir);
+
+        let mut newer = memory("a", "Use PostgreSQL");
+        newer.updated_at = 2;
+        persistence.store(&newer).unwrap();
+        index.index(&memory("b", "Prefer Go")).unwrap();
+        index.index(&memory("z", "Orphan")).unwrap();
+
+        let service = VerifyService::new(persistence, Arc::clone(&index));
+        let report = service.verify(false).unwrap();
+        assert_eq!(report.stale, vec![MemoryId::new("a")]);
+        assert_eq!(report.mismatched, vec![MemoryId::new("b")]);
+        assert_eq!(report.orphaned, vec![MemoryId::new("z")]);
+        assert!(report.missing.is_empty());
+
+        service.verify(true).unwrap();
+        assert!(index.get_memory(&MemoryId::new("z")).unwrap().is_none());
+        assert!(service.verify(false).unwrap().is_consistent());
+    }
+}
@@ -0,0 +1,373 @@
+//! WASM capture transformers (`wasm` feature).
+//!
+//! Teams can rewrite captured content with their own logic (house style,
+//! ticket-link expansion, extra redaction) without forking subcog, by
+//! compiling it to WebAssembly and listing the module under
+//! `[capture.plugins]`. Each module runs as a capture pre-hook in a fresh,
+//! import-free `wasmi` instance with a fuel budget, a memory cap and a
+//! wall-clock timeout, so a buggy or hostile module cannot wedge or exhaust
+//! the process.
+//!
+//! # ABI
+//!
+//! A transformer module exports:
+//!
+//! | Export | Signature | Purpose |
+//! |--------|-----------|---------|
+//! | `memory` | linear memory | Shared buffer for input and output |
+//! | `alloc` | `(len: i32) -> i32` | Returns a pointer to `len` writable bytes |
+//! | `transform` | `(ptr: i32, len: i32) -> i64` | Rewrites the UTF-8 input at `ptr` |
+//!
+//! `transform` returns the output location packed as `(ptr << 32) | len`.
+//! The output must be valid UTF-8. Any trap, exhausted limit or invalid output
+//! aborts the capture.
+//!
+//! # Example
+//!
+//! ```toml
+//! [capture.plugins]
+//! modules = ["~/.config/subcog/plugins/rewrite.wasm"]
+//! timeout_ms = 500
+//! ```
+
+use crate::config::CapturePluginsConfig;
+use crate::models::CaptureRequest;
+use crate::services::CapturePreHook;
+use crate::{Error, Result};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+use wasmi::{Engine, Linker, Module, Store, StoreLimits, StoreLimitsBuilder};
+
+/// Resource limits applied to each transformer call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WasmLimits {
+    /// Fuel (roughly instructions) available to one call.
+    pub fuel: u64,
+    /// Largest linear memory the module may grow to, in bytes.
+    pub max_memory_bytes: usize,
+    /// Wall-clock time allowed for one call.
+    pub timeout: Duration,
+}
+
+impl WasmLimits {
+    /// Creates limits from the `[capture.plugins]` configuration.
+    #[must_use]
+    pub const fn from_config(config: &CapturePluginsConfig) -> Self {
+        Self {
+            fuel: config.fuel,
+            max_memory_bytes: config.max_memory_bytes,
+            timeout: Duration::from_millis(config.timeout_ms),
+        }
+    }
+}
+
+impl Default for WasmLimits {
+    fn default() -> Self {
+        Self::from_config(&CapturePluginsConfig::default())
+    }
+}
+
+/// Per-call store data.
+struct HostState {
+    /// Memory and table growth limits.
+    limits: StoreLimits,
+}
+
+/// A capture content transformer loaded from a WASM module.
+pub struct WasmTransformer {
+    /// Module path, for error messages.
+    path: PathBuf,
+    /// Engine the module was compiled for (fuel metering enabled).
+    engine: Engine,
+    /// Compiled module, instantiated afresh for every call.
+    module: Arc<Module>,
+    /// Limits applied to each call.
+    limits: WasmLimits,
+}
+
+impl WasmTransformer {
+    /// Loads and validates the module at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OperationFailed`] if the file cannot be read or is not
+    /// a valid WASM module.
+    pub fn load(path: impl Into<PathBuf>, limits: WasmLimits) -> Result<Self> {
+        let path = path.into();
+        let bytes = std::fs::read(&path).map_err(|e| Error::OperationFailed {
+            operation: "load_capture_plugin".to_string(),
+            cause: format!("{}: {e}", path.display()),
+        })?;
+        Self::from_bytes(path, &bytes, limits)
+    }
+
+    /// Creates a transformer from module bytes; `path` is only used in errors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OperationFailed`] if `bytes` is not a valid WASM module.
+    pub fn from_bytes(path: impl Into<PathBuf>, bytes: &[u8], limits: WasmLimits) -> Result<Self> {
+        let path = path.into();
+        let mut config = wasmi::Config::default();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config);
+        let module = Module::new(&engine, bytes).map_err(|e| Error::OperationFailed {
+            operation: "load_capture_plugin".to_string(),
+            cause: format!("{}: {e}", path.display()),
+        })?;
+        Ok(Self {
+            path,
+            engine,
+            module: Arc::new(module),
+            limits,
+        })
+    }
+
+    /// Returns the module path.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Runs the module on `content` and returns the transformed content.
+    ///
+    /// The call runs on its own thread so the timeout holds even while the
+    /// module computes; a timed-out call is abandoned and stops once its fuel
+    /// runs out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OperationFailed`] if the module traps, exceeds a
+    /// limit, times out, or returns invalid output.
+    pub fn transform(&self, content: &str) -> Result<String> {
+        let (tx, rx) = mpsc::channel();
+        let engine = self.engine.clone();
+        let module = Arc::clone(&self.module);
+        let limits = self.limits;
+        let input = content.as_bytes().to_vec();
+        std::thread::spawn(move || {
+            // The receiver is gone after a timeout; the result is not needed then
+            let _ = tx.send(run_module(&engine, &module, limits, &input));
+        });
+
+        let output = match rx.recv_timeout(self.limits.timeout) {
+            Ok(result) => result.map_err(|e| self.failure(e.to_string()))?,
+            Err(RecvTimeoutError::Timeout) => {
+                return Err(self.failure(format!(
+                    "timed out after {}ms",
+                    self.limits.timeout.as_millis()
+                )));
+            },
+            Err(RecvTimeoutError::Disconnected) => {
+                return Err(self.failure("call ended without a result".to_string()));
+            },
+        };
+        String::from_utf8(output).map_err(|_| self.failure("output is not UTF-8".to_string()))
+    }
+
+    /// Wraps a call failure with the module path.
+    fn failure(&self, cause: String) -> Error {
+        Error::OperationFailed {
+            operation: "run_capture_plugin".to_string(),
+            cause: format!("{}: {cause}", self.path.display()),
+        }
+    }
+
+    /// Returns a capture pre-hook replacing the request content with the
+    /// transformed content.
+    #[must_use]
+    pub fn into_pre_hook(self) -> CapturePreHook {
+        Arc::new(move |request: &mut CaptureRequest| {
+            request.content = self.transform(&request.content)?;
+            Ok(())
+        })
+    }
+}
+
+/// Instantiates `module` with `limits` and calls its `transform` export.
+fn run_module(
+    engine: &Engine,
+    module: &Module,
+    limits: WasmLimits,
+    input: &[u8],
+) -> std::result::Result<Vec<u8>, wasmi::Error> {
+    let state = HostState {
+        limits: StoreLimitsBuilder::new()
+            .memory_size(limits.max_memory_bytes)
+            .build(),
+    };
+    let mut store = Store::new(engine, state);
+    store.limiter(|state| &mut state.limits);
+    store.set_fuel(limits.fuel)?;
+
+    // No imports: modules get no access to the host
+    let linker = Linker::<HostState>::new(engine);
+    let instance = linker.instantiate(&mut store, module)?.start(&mut store)?;
+    let memory = instance
+        .get_memory(&store, "memory")
+        .ok_or_else(|| wasmi::Error::new("module does not export `memory`"))?;
+    let alloc = instance.get_typed_func::<i32, i32>(&store, "alloc")?;
+    let transform = instance.get_typed_func::<(i32, i32), i64>(&store, "transform")?;
+
+    let len = i32::try_from(input.len()).map_err(|_| wasmi::Error::new("content too large"))?;
+    let ptr = alloc.call(&mut store, len)?;
+    memory.write(&mut store, to_offset(ptr.cast_unsigned())?, input)?;
+
+    let packed = transform.call(&mut store, (ptr, len))?.cast_unsigned();
+    let out_ptr = to_offset(packed >> 32)?;
+    let out_len = to_offset(packed & 0xFFFF_FFFF)?;
+    // Check the range before allocating: the length is module-controlled
+    let in_bounds = out_ptr
+        .checked_add(out_len)
+        .is_some_and(|end| end <= memory.data(&store).len());
+    if out_len > limits.max_memory_bytes || !in_bounds {
+        return Err(wasmi::Error::new(
+            "module returned output outside its memory",
+        ));
+    }
+    let mut output = vec![0; out_len];
+    memory.read(&store, out_ptr, &mut output)?;
+    Ok(output)
+}
+
+/// Converts a pointer or length returned by the module into a host offset.
+fn to_offset(value: impl TryInto<usize>) -> std::result::Result<usize, wasmi::Error> {
+    value
+        .try_into()
+        .map_err(|_| wasmi::Error::new("module returned an out-of-range offset"))
+}
+
+/// Builds the capture pre-hooks configured in `[capture.plugins]`.
+///
+/// A module that fails to load becomes a hook failing every capture, so a
+/// broken transformer is never silently skipped.
+pub(crate) fn plugin_pre_hooks(config: &CapturePluginsConfig) -> Vec<CapturePreHook> {
+    let limits = WasmLimits::from_config(config);
+    config
+        .modules
+        .iter()
+        .map(|path| match WasmTransformer::load(path, limits) {
+            Ok(transformer) => transformer.into_pre_hook(),
+            Err(e) => {
+                tracing::error!(error = %e, "Failed to load capture plugin");
+                let cause = e.to_string();
+                Arc::new(move |_: &mut CaptureRequest| {
+                    Err(Error::OperationFailed {
+                        operation: "load_capture_plugin".to_string(),
+                        cause: cause.clone(),
+                    })
+                }) as CapturePreHook
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::models::Namespace;
+    use crate::services::CaptureService;
+    use crate::storage::index::SqliteBackend;
+    use crate::storage::traits::IndexBackend;
+
+    /// Uppercases ASCII letters in place.
+    const UPPERCASE_WAT: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (global $next (mut i32) (i32.const 1024))
+          (func (export "alloc") (param $len i32) (result i32)
+            (local $ptr i32)
+            (local.set $ptr (global.get $next))
+            (global.set $next (i32.add (global.get $next) (local.get $len)))
+            (local.get $ptr))
+          (func (export "transform") (param $ptr i32) (param $len i32) (result i64)
+            (local $i i32)
+            (local $c i32)
+            (block $done
+              (loop $next
+                (br_if $done (i32.ge_u (local.get $i) (local.get $len)))
+                (local.set $c (i32.load8_u (i32.add (local.get $ptr) (local.get $i))))
+                (if (i32.and (i32.ge_u (local.get $c) (i32.const 97))
+                             (i32.le_u (local.get $c) (i32.const 122)))
+                  (then (i32.store8 (i32.add (local.get $ptr) (local.get $i))
+                                    (i32.sub (local.get $c) (i32.const 32)))))
+                (local.set $i (i32.add (local.get $i) (i32.const 1)))
+                (br $next)))
+            (i64.or (i64.shl (i64.extend_i32_u (local.get $ptr)) (i64.const 32))
+                    (i64.extend_i32_u (local.get $len)))))
+    "#;
+
+    /// Never returns from `transform`.
+    const SPIN_WAT: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (func (export "alloc") (param i32) (result i32) (i32.const 0))
+          (func (export "transform") (param i32 i32) (result i64)
+            (loop $spin (br $spin))
+            (i64.const 0)))
+    "#;
+
+    /// Claims a 4 GiB - 1 output at offset 0.
+    const OVERSIZED_WAT: &str = r#"
+        (module
+          (memory (export "memory") 1)
+          (func (export "alloc") (param i32) (result i32) (i32.const 0))
+          (func (export "transform") (param i32 i32) (result i64)
+            (i64.const 0xFFFFFFFF)))
+    "#;
+
+    #[test]
+    fn test_configured_plugin_transforms_stored_memory() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let module_path = dir.path().join("uppercase.wasm");
+        std::fs::write(&module_path, wat::parse_str(UPPERCASE_WAT).unwrap()).unwrap();
+
+        let mut config = Config::new().with_data_dir(dir.path());
+        config.capture.plugins.modules = vec![module_path];
+        let index: Arc<dyn IndexBackend + Send + Sync> =
+            Arc::new(SqliteBackend::in_memory().unwrap());
+        let service = CaptureService::new_minimal(config).with_index(Arc::clone(&index));
+
+        let result = service
+            .capture(
+                CaptureRequest::new("use postgres for storage")
+                    .with_namespace(Namespace::Decisions),
+            )
+            .unwrap();
+        let memory = index.get_memory(&result.memory_id).unwrap().unwrap();
+        assert_eq!(memory.content, "USE POSTGRES FOR STORAGE");
+    }
+
+    #[test]
+    fn test_runaway_plugin_is_stopped() {
+        let limits = WasmLimits {
+            fuel: 1_000_000,
+            ..WasmLimits::default()
+        };
+        let spin =
+            WasmTransformer::from_bytes("spin.wasm", &wat::parse_str(SPIN_WAT).unwrap(), limits)
+                .unwrap();
+        let Err(Error::OperationFailed { operation, cause }) = spin.transform("content") else {
+            panic!("expected the spinning module to be stopped");
+        };
+        assert_eq!(operation, "run_capture_plugin");
+        assert!(cause.starts_with("spin.wasm"), "{cause}");
+    }
+
+    #[test]
+    fn test_output_outside_module_memory_is_rejected() {
+        let oversized = WasmTransformer::from_bytes(
+            "oversized.wasm",
+            &wat::parse_str(OVERSIZED_WAT).unwrap(),
+            WasmLimits::default(),
+        )
+        .unwrap();
+        let Err(Error::OperationFailed { cause, .. }) = oversized.transform("content") else {
+            panic!("expected the oversized output to be rejected");
+        };
+        assert!(cause.contains("outside its memory"), "{cause}");
+    }
+}
@@ -0,0 +1,406 @@
+//! Query widening for recall (`recall --min-results`).
+//!
+//! A precise query can match nothing, which leaves an agent without any
+//! context. [`WideningService`] re-runs such a search with relaxed
+//! constraints until it returns at least the requested number of hits,
+//! following a fixed ladder:
+//!
+//! 1. Drop one filter at a time, weakest first: `min_confidence`, `pinned`,
+//!    `commit`, `branch`, `file_path`, `time_range`, `origin`, `section`,
+//!    `source`, `entities`, `tags`, `namespace`. Filters that are not set are
+//!    skipped.
+//! 2. Switch a text search to hybrid, adding semantic matches.
+//! 3. Drop the minimum score threshold.
+//!
+//! Each applied step is reported as a [`Relaxation`]. Exclusions (excluded
+//! tags, tombstoned memories) and scope filters (project, domain, group) are
+//! never relaxed.
+//!
+//! # Example
+//!
+//! ```rust,ignore
+//! use subcog::services::WideningService;
+//!
+//! let widened = WideningService::new(3).search(&recall, query, SearchMode::Text, &filter, 10)?;
+//! for relaxation in &widened.relaxations {
+//!     eprintln!("{relaxation}");
+//! }
+//! ```
+
+use super::RecallService;
+use crate::Result;
+use crate::models::{SearchFilter, SearchMode, SearchResult};
+use std::fmt;
+use tracing::instrument;
+
+/// A filter [`WideningService`] may drop, in ladder order (weakest first).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelaxableFilter {
+    /// Minimum recorded capture confidence.
+    MinConfidence,
+    /// Pinned memories only.
+    Pinned,
+    /// Commit checked out at capture.
+    Commit,
+    /// Branch name.
+    Branch,
+    /// File path.
+    FilePath,
+    /// Creation time range.
+    TimeRange,
+    /// Capture origins.
+    Origin,
+    /// Section of structured memories.
+    Section,
+    /// Source pattern.
+    Source,
+    /// Mentioned entities.
+    Entities,
+    /// Required tags (all or any).
+    Tags,
+    /// Namespaces.
+    Namespace,
+}
+
+impl RelaxableFilter {
+    /// Filters in the order they are dropped.
+    const LADDER: [Self; 12] = [
+        Self::MinConfidence,
+        Self::Pinned,
+        Self::Commit,
+        Self::Branch,
+        Self::FilePath,
+        Self::TimeRange,
+        Self::Origin,
+        Self::Section,
+        Self::Source,
+        Self::Entities,
+        Self::Tags,
+        Self::Namespace,
+    ];
+
+    /// Returns the filter name.
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::MinConfidence => "min_confidence",
+            Self::Pinned => "pinned",
+            Self::Commit => "commit",
+            Self::Branch => "branch",
+            Self::FilePath => "file_path",
+            Self::TimeRange => "time_range",
+            Self::Origin => "origin",
+            Self::Section => "section",
+            Self::Source => "source",
+            Self::Entities => "entities",
+            Self::Tags => "tags",
+            Self::Namespace => "namespace",
+        }
+    }
+
+    /// Removes this filter from `filter`; returns `false` if it was not set.
+    fn drop_from(self, filter: &mut SearchFilter) -> bool {
+        match self {
+            Self::MinConfidence => filter.min_confidence.take().is_some(),
+            Self::Pinned => std::mem::take(&mut filter.pinned_only),
+            Self::Commit => filter.commit.take().is_some(),
+            Self::Branch => filter.branch.take().is_some(),
+            Self::FilePath => filter.file_path.take().is_some(),
+            Self::TimeRange => {
+                let after = filter.created_after.take();
+                let before = filter.created_before.take();
+                let updated = filter.updated_after.take();
+                after.is_some() || before.is_some() || updated.is_some()
+            },
+            Self::Origin => !std::mem::take(&mut filter.origins).is_empty(),
+            Self::Section => filter.section.take().is_some(),
+            Self::Source => filter.source_pattern.take().is_some(),
+            Self::Entities => !std::mem::take(&mut filter.entity_names).is_empty(),
+            Self::Tags => {
+                let tags = std::mem::take(&mut filter.tags);
+                let tags_any = std::mem::take(&mut filter.tags_any);
+                !tags.is_empty() || !tags_any.is_empty()
+            },
+            Self::Namespace => !std::mem::take(&mut filter.namespaces).is_empty(),
+        }
+    }
+}
+
+impl fmt::Display for RelaxableFilter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A constraint [`WideningService`] relaxed to find more hits.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Relaxation {
+    /// A filter was dropped.
+    DroppedFilter(RelaxableFilter),
+    /// The search mode was widened.
+    SwitchedMode {
+        /// Mode before widening.
+        from: SearchMode,
+        /// Mode after widening.
+        to: SearchMode,
+    },
+    /// The minimum score threshold was dropped.
+    DroppedMinScore(f32),
+}
+
+impl fmt::Display for Relaxation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DroppedFilter(filter) => write!(f, "dropped the {filter} filter"),
+            Self::SwitchedMode { from, to } => write!(f, "switched from {from} to {to} search"),
+            Self::DroppedMinScore(min_score) => {
+                write!(f, "dropped the minimum score ({min_score:.2})")
+            },
+        }
+    }
+}
+
+/// Result of a widened search.
+#[derive(Debug, Clone)]
+pub struct Widened<T> {
+    /// Result of the last search run.
+    pub result: T,
+    /// Relaxations applied, in order (empty if the first search sufficed).
+    pub relaxations: Vec<Relaxation>,
+}
+
+/// One step of the widening ladder.
+#[derive(Debug, Clone, Copy)]
+enum Step {
+    Drop(RelaxableFilter),
+    Mode,
+    MinScore,
+}
+
+impl Step {
+    /// Applies the step; returns `None` if it relaxes nothing.
+    fn apply(self, mode: &mut SearchMode, filter: &mut SearchFilter) -> Option<Relaxation> {
+        match self {
+            Self::Drop(kind) => kind
+                .drop_from(filter)
+                .then_some(Relaxation::DroppedFilter(kind)),
+            Self::Mode => (*mode == SearchMode::Text).then(|| {
+                *mode = SearchMode::Hybrid;
+                Relaxation::SwitchedMode {
+                    from: SearchMode::Text,
+                    to: SearchMode::Hybrid,
+                }
+            }),
+            Self::MinScore => filter.min_score.take().map(Relaxation::DroppedMinScore),
+        }
+    }
+}
+
+/// Widens recall searches that return too few hits.
+pub struct WideningService {
+    /// Number of hits to reach.
+    min_results: usize,
+}
+
+impl WideningService {
+    /// Creates a widening service that aims for at least `min_results` hits.
+    #[must_use]
+    pub const fn new(min_results: usize) -> Self {
+        Self { min_results }
+    }
+
+    /// Searches with `recall`, widening until `min_results` hits are found.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`RecallService::search`].
+    pub fn search(
+        &self,
+        recall: &RecallService,
+        query: &str,
+        mode: SearchMode,
+        filter: &SearchFilter,
+        limit: usize,
+    ) -> Result<Widened<SearchResult>> {
+        self.widen(
+            mode,
+            filter,
+            |mode, filter| recall.search(query, mode, filter, limit),
+            |result| result.memories.len(),
+        )
+    }
+
+    /// Runs `search`, then re-runs it one relaxation at a time until `hits`
+    /// of the result reaches `min_results` or the ladder is exhausted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error of `search`.
+    #[instrument(skip_all, fields(min_results = self.min_results))]
+    pub fn widen<T>(
+        &self,
+        mode: SearchMode,
+        filter: &SearchFilter,
+        mut search: impl FnMut(SearchMode, &SearchFilter) -> Result<T>,
+        hits: impl Fn(&T) -> usize,
+    ) -> Result<Widened<T>> {
+        let mut mode = mode;
+        let mut filter = filter.clone();
+        let mut result = search(mode, &filter)?;
+        let mut relaxations = Vec::new();
+
+        let ladder = RelaxableFilter::LADDER
+            .into_iter()
+            .map(Step::Drop)
+            .chain([Step::Mode, Step::MinScore]);
+        for step in ladder {
+            if hits(&result) >= self.min_results {
+                break;
+            }
+            let Some(relaxation) = step.apply(&mut mode, &mut filter) else {
+                continue;
+            };
+            tracing::debug!(%relaxation, "Widening recall query");
+            relaxations.push(relaxation);
+            result = search(mode, &filter)?;
+        }
+
+        if !relaxations.is_empty() {
+            metrics::counter!("memory_search_widened_total").increment(1);
+        }
+        Ok(Widened {
+            result,
+            relaxations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::storage::index::SqliteBackend;
+    use crate::storage::traits::IndexBackend;
+
+    fn memory(id: &str, content: &str, namespace: Namespace) -> Memory {
+        Memory {
+            id: MemoryId::new(id),
+            content: content.to_string(),
+            namespace,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 0,
+            updated_at: 0,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: Vec::new(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: None,
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    fn recall() -> RecallService {
+        let index = SqliteBackend::in_memory().unwrap();
+        index
+            .index(&memory("d1", "Use Redis for caching", Namespace::Decisions))
+            .unwrap();
+        index
+            .index(&memory("p1", "Redis cache warmup", Namespace::Patterns))
+            .unwrap();
+        RecallService::with_index(index)
+    }
+
+    #[test]
+    fn test_strict_query_widens_until_min_results() {
+        let filter = SearchFilter::new()
+            .with_namespace(Namespace::Decisions)
+            .with_origin(CaptureOrigin::Hook);
+
+        let widened = WideningService::new(2)
+            .search(&recall(), "redis", SearchMode::Text, &filter, 10)
+            .unwrap();
+
+        assert_eq!(widened.result.memories.len(), 2);
+        assert_eq!(
+            widened.relaxations,
+            vec![
+                Relaxation::DroppedFilter(RelaxableFilter::Origin),
+                Relaxation::DroppedFilter(RelaxableFilter::Namespace),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_widening_stops_at_min_results() {
+        let filter = SearchFilter::new()
+            .with_namespace(Namespace::Decisions)
+            .with_origin(CaptureOrigin::Hook);
+
+        let widened = WideningService::new(1)
+            .search(&recall(), "redis", SearchMode::Text, &filter, 10)
+            .unwrap();
+
+        // Dropping the origin filter already finds the decision
+        assert_eq!(widened.result.memories.len(), 1);
+        assert_eq!(
+            widened.relaxations,
+            vec![Relaxation::DroppedFilter(RelaxableFilter::Origin)]
+        );
+    }
+
+    #[test]
+    fn test_no_widening_when_enough_hits() {
+        let widened = WideningService::new(2)
+            .search(
+                &recall(),
+                "redis",
+                SearchMode::Text,
+                &SearchFilter::new(),
+                10,
+            )
+            .unwrap();
+
+        assert_eq!(widened.result.memories.len(), 2);
+        assert!(widened.relaxations.is_empty());
+    }
+
+    #[test]
+    fn test_exhausted_ladder_reports_mode_and_score() {
+        let filter = SearchFilter::new().with_min_score(0.9);
+
+        let widened = WideningService::new(5)
+            .search(&recall(), "redis", SearchMode::Text, &filter, 10)
+            .unwrap();
+
+        assert_eq!(widened.result.memories.len(), 2);
+        assert_eq!(
+            widened.relaxations,
+            vec![
+                Relaxation::SwitchedMode {
+                    from: SearchMode::Text,
+                    to: SearchMode::Hybrid,
+                },
+                Relaxation::DroppedMinScore(0.9),
+            ]
+        );
+        assert_eq!(
+            widened.relaxations[0].to_string(),
+            "switched from text to hybrid search"
+        );
+    }
+}
@@ -0,0 +1,234 @@
+//! Content-addressed attachment storage.
+//!
+//! Attachment blobs live in the data directory, named by the SHA-256 of their
+//! content and sharded by the first two hex digits:
+//!
+//! ```text
+//! {data_dir}/attachments/
+//!   └── 3f/
+//!       └── 3fa4…c2   (screenshot.png)
+//! ```
+//!
+//! Memories only keep an [`Attachment`] reference, so the same file attached
+//! twice is stored once. Blobs are verified against their hash on read.
+
+use crate::models::{Attachment, media_type_for};
+use crate::{Error, Result};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default largest attachment (10 MB).
+pub const DEFAULT_MAX_ATTACHMENT_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Stores and retrieves attachment blobs by content hash.
+#[derive(Debug, Clone)]
+pub struct AttachmentStore {
+    /// Directory holding the blobs.
+    dir: PathBuf,
+    /// Largest blob accepted, in bytes.
+    max_bytes: u64,
+}
+
+impl AttachmentStore {
+    /// Creates a store rooted at `dir` with the default size limit.
+    #[must_use]
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            max_bytes: DEFAULT_MAX_ATTACHMENT_BYTES,
+        }
+    }
+
+    /// Sets the largest attachment accepted, in bytes.
+    #[must_use]
+    pub const fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    /// Returns the directory holding the blobs.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the largest attachment accepted, in bytes.
+    #[must_use]
+    pub const fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    /// Stores the file at `path` and returns its reference.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if the file is missing or larger than
+    /// the size limit, or an error if it cannot be read or stored.
+    pub fn attach_file(&self, path: &Path) -> Result<Attachment> {
+        let metadata = fs::metadata(path)
+            .map_err(|e| Error::InvalidInput(format!("Cannot attach {}: {e}", path.display())))?;
+        if !metadata.is_file() {
+            return Err(Error::InvalidInput(format!(
+                "Cannot attach {}: not a file",
+                path.display()
+            )));
+        }
+        let name = path.file_name().map_or_else(
+            || path.display().to_string(),
+            |n| n.to_string_lossy().into_owned(),
+        );
+        self.check_size(&name, metadata.len())?;
+
+        let content = fs::read(path).map_err(|e| Error::OperationFailed {
+            operation: "read_attachment_file".to_string(),
+            cause: format!("{}: {e}", path.display()),
+        })?;
+        self.store(&name, &content)
+    }
+
+    /// Stores `content` under its hash and returns a reference named `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if `content` is larger than the size
+    /// limit, or an error if the blob cannot be written.
+    pub fn store(&self, name: &str, content: &[u8]) -> Result<Attachment> {
+        let size = content.len() as u64;
+        self.check_size(name, size)?;
+
+        let hash = hex::encode(Sha256::digest(content));
+        let path = self.blob_path(&hash)?;
+        if !path.exists() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|e| Error::OperationFailed {
+                    operation: "create_attachment_dir".to_string(),
+                    cause: e.to_string(),
+                })?;
+            }
+            // Write then rename so a crash never leaves a truncated blob under its hash
+            let tmp = path.with_extension("tmp");
+            fs::write(&tmp, content)
+                .and_then(|()| fs::rename(&tmp, &path))
+                .map_err(|e| Error::OperationFailed {
+                    operation: "write_attachment".to_string(),
+                    cause: e.to_string(),
+                })?;
+        }
+
+        Ok(Attachment {
+            hash,
+            name: name.to_string(),
+            media_type: media_type_for(name).to_string(),
+            size,
+        })
+    }
+
+    /// Reads the content of an attachment.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the blob is missing or its content no longer
+    /// matches its hash.
+    pub fn read(&self, attachment: &Attachment) -> Result<Vec<u8>> {
+        let path = self.blob_path(&attachment.hash)?;
+        let content = fs::read(&path).map_err(|e| Error::OperationFailed {
+            operation: "read_attachment".to_string(),
+            cause: format!("{} ({}): {e}", attachment.name, path.display()),
+        })?;
+        if hex::encode(Sha256::digest(&content)) != attachment.hash {
+            return Err(Error::OperationFailed {
+                operation: "verify_attachment".to_string(),
+                cause: format!("{} does not match its hash", path.display()),
+            });
+        }
+        Ok(content)
+    }
+
+    /// Returns whether the blob of an attachment is stored.
+    #[must_use]
+    pub fn contains(&self, attachment: &Attachment) -> bool {
+        self.blob_path(&attachment.hash)
+            .is_ok_and(|path| path.is_file())
+    }
+
+    /// Returns the path of the blob with the given hash.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidInput`] if `hash` is not a hex-encoded SHA-256.
+    pub fn blob_path(&self, hash: &str) -> Result<PathBuf> {
+        if hash.len() != 64 || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(Error::InvalidInput(format!(
+                "Invalid attachment hash: {hash}"
+            )));
+        }
+        Ok(self.dir.join(&hash[..2]).join(hash))
+    }
+
+    /// Rejects attachments over the size limit.
+    fn check_size(&self, name: &str, size: u64) -> Result<()> {
+        if size > self.max_bytes {
+            return Err(Error::InvalidInput(format!(
+                "Attachment {name} is {size} bytes, over the {} byte limit ([attachments] max_bytes)",
+                self.max_bytes
+            )));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_attach_and_read() {
+        let dir = TempDir::new().unwrap();
+        let store = AttachmentStore::new(dir.path().join("attachments"));
+        let file = dir.path().join("build.log");
+        fs::write(&file, b"error[E0382]: borrow of moved value").unwrap();
+
+        let attachment = store.attach_file(&file).unwrap();
+        assert_eq!(attachment.name, "build.log");
+        assert_eq!(attachment.media_type, "text/plain");
+        assert_eq!(attachment.size, 35);
+        assert!(store.contains(&attachment));
+        assert_eq!(
+            store.read(&attachment).unwrap(),
+            b"error[E0382]: borrow of moved value"
+        );
+
+        // Identical content is stored once
+        let again = store
+            .store("copy.log", b"error[E0382]: borrow of moved value")
+            .unwrap();
+        assert_eq!(again.hash, attachment.hash);
+    }
+
+    #[test]
+    fn test_size_limit_is_enforced() {
+        let dir = TempDir::new().unwrap();
+        let store = AttachmentStore::new(dir.path()).with_max_bytes(8);
+        let file = dir.path().join("screenshot.png");
+        fs::write(&file, [0_u8; 9]).unwrap();
+
+        assert!(matches!(
+            store.attach_file(&file),
+            Err(Error::InvalidInput(_))
+        ));
+        assert!(store.store("small.png", &[0_u8; 8]).is_ok());
+    }
+
+    #[test]
+    fn test_read_detects_tampered_blob() {
+        let dir = TempDir::new().unwrap();
+        let store = AttachmentStore::new(dir.path());
+        let attachment = store.store("notes.md", b"# Notes").unwrap();
+
+        fs::write(store.blob_path(&attachment.hash).unwrap(), b"# Edited").unwrap();
+        assert!(store.read(&attachment).is_err());
+        assert!(store.blob_path("../../etc/passwd").is_err());
+    }
+}
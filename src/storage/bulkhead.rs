@@ -39,7 +39,7 @@
 //! ```
 
 use super::traits::{IndexBackend, PersistenceBackend, VectorBackend, VectorFilter};
-use crate::models::{Memory, MemoryId, SearchFilter};
+use crate::models::{FieldWeights, Memory, MemoryId, Namespace, RelevanceFeedback, SearchFilter};
 use crate::{Error, Result};
 use std::sync::Arc;
 use std::time::Duration;
@@ -459,6 +459,18 @@ fn search(
             .execute_quiet("index", |inner| inner.search(query, filter, limit))
     }
 
+    fn search_weighted(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+        weights: &FieldWeights,
+        limit: usize,
+    ) -> Result<Vec<(MemoryId, f32)>> {
+        self.bulkhead.execute_quiet("index", |inner| {
+            inner.search_weighted(query, filter, weights, limit)
+        })
+    }
+
     fn reindex(&self, memories: &[Memory]) -> Result<()> {
         self.bulkhead
             .execute_quiet("index", |inner| inner.reindex(memories))
@@ -482,6 +494,42 @@ fn get_memories_batch(&self, ids: &[MemoryId]) -> Result<Vec<Option<Memory>>> {
         self.bulkhead
             .execute_quiet("index", |inner| inner.get_memories_batch(ids))
     }
+
+    fn get_memories_metadata_batch(&self, ids: &[MemoryId]) -> Result<Vec<Option<Memory>>> {
+        self.bulkhead
+            .execute_quiet("index", |inner| inner.get_memories_metadata_batch(ids))
+    }
+
+    fn record_feedback(&self, query: &str, memory_id: &MemoryId, useful: bool) -> Result<()> {
+        self.bulkhead.execute_quiet("index", |inner| {
+            inner.record_feedback(query, memory_id, useful)
+        })
+    }
+
+    fn feedback_for(&self, memory_ids: &[MemoryId]) -> Result<Vec<RelevanceFeedback>> {
+        self.bulkhead
+            .execute_quiet("index", |inner| inner.feedback_for(memory_ids))
+    }
+
+    fn find_by_content_hash(
+        &self,
+        namespace: Namespace,
+        content_hash: &str,
+    ) -> Result<Option<MemoryId>> {
+        self.bulkhead.execute_quiet("index", |inner| {
+            inner.find_by_content_hash(namespace, content_hash)
+        })
+    }
+
+    fn snippets(
+        &self,
+        query: &str,
+        ids: &[MemoryId],
+        marker: &str,
+    ) -> Result<Vec<(MemoryId, String)>> {
+        self.bulkhead
+            .execute_quiet("index", |inner| inner.snippets(query, ids, marker))
+    }
 }
 
 // ============================================================================
@@ -550,7 +598,7 @@ fn clear(&self) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::models::{Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use crate::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
     use std::sync::atomic::{AtomicUsize, Ordering};
 
     /// Creates a test memory for bulkhead tests.
@@ -576,6 +624,13 @@ fn create_test_memory(content: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
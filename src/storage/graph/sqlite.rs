@@ -23,25 +23,15 @@
 use crate::models::temporal::{BitemporalPoint, TransactionTime, ValidTimeRange};
 use crate::models::{Domain, MemoryId};
 use crate::storage::traits::graph::{GraphBackend, GraphStats};
+use crate::storage::{acquire_lock_with_timeout, mutex_lock_timeout};
 use crate::{Error, Result};
 use rusqlite::{Connection, OptionalExtension, Row, params};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::{Mutex, MutexGuard};
+use std::time::Duration;
 use tracing::instrument;
 
-/// Helper to acquire mutex lock with poison recovery.
-fn acquire_lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
-    match mutex.lock() {
-        Ok(guard) => guard,
-        Err(poisoned) => {
-            tracing::warn!("Graph SQLite mutex was poisoned, recovering");
-            metrics::counter!("graph_sqlite_mutex_poison_recovery_total").increment(1);
-            poisoned.into_inner()
-        },
-    }
-}
-
 /// `SQLite`-based graph backend.
 ///
 /// # Concurrency Model
@@ -60,6 +50,8 @@ pub struct SqliteGraphBackend {
     conn: Mutex<Connection>,
     /// Path to the database (None for in-memory).
     db_path: Option<PathBuf>,
+    /// Maximum time to wait for the connection mutex.
+    lock_timeout: Duration,
 }
 
 impl SqliteGraphBackend {
@@ -78,6 +70,7 @@ pub fn new(db_path: impl Into<PathBuf>) -> Result<Self> {
         let backend = Self {
             conn: Mutex::new(conn),
             db_path: Some(db_path),
+            lock_timeout: mutex_lock_timeout(),
         };
 
         backend.initialize()?;
@@ -98,6 +91,7 @@ pub fn in_memory() -> Result<Self> {
         let backend = Self {
             conn: Mutex::new(conn),
             db_path: None,
+            lock_timeout: mutex_lock_timeout(),
         };
 
         backend.initialize()?;
@@ -110,9 +104,21 @@ pub fn db_path(&self) -> Option<&Path> {
         self.db_path.as_deref()
     }
 
+    /// Sets how long operations wait for the connection before failing.
+    #[must_use]
+    pub const fn with_lock_timeout(mut self, timeout: Duration) -> Self {
+        self.lock_timeout = timeout;
+        self
+    }
+
+    /// Locks the connection, failing with a lock timeout instead of blocking forever.
+    fn lock_conn(&self) -> Result<MutexGuard<'_, Connection>> {
+        acquire_lock_with_timeout(&self.conn, self.lock_timeout)
+    }
+
     /// Initializes the database schema.
     fn initialize(&self) -> Result<()> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         // Enable WAL mode for better concurrent read performance
         let _ = conn.pragma_update(None, "journal_mode", "WAL");
@@ -371,7 +377,7 @@ impl GraphBackend for SqliteGraphBackend {
 
     #[instrument(skip(self, entity), fields(entity_id = %entity.id))]
     fn store_entity(&self, entity: &Entity) -> Result<()> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let aliases_json =
             serde_json::to_string(&entity.aliases).unwrap_or_else(|_| "[]".to_string());
@@ -422,7 +428,7 @@ fn store_entity(&self, entity: &Entity) -> Result<()> {
 
     #[instrument(skip(self), fields(entity_id = %id))]
     fn get_entity(&self, id: &EntityId) -> Result<Option<Entity>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let result = conn
             .query_row(
@@ -441,7 +447,7 @@ fn get_entity(&self, id: &EntityId) -> Result<Option<Entity>> {
 
     #[instrument(skip(self, query))]
     fn query_entities(&self, query: &EntityQuery) -> Result<Vec<Entity>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let (where_clause, params) = Self::build_entity_where_clause(query);
         let limit = query.limit.unwrap_or(100);
@@ -471,7 +477,7 @@ fn query_entities(&self, query: &EntityQuery) -> Result<Vec<Entity>> {
 
     #[instrument(skip(self), fields(entity_id = %id))]
     fn delete_entity(&self, id: &EntityId) -> Result<bool> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         // Foreign key cascades handle mentions and relationships
         let rows = conn
@@ -500,7 +506,7 @@ fn merge_entities(&self, entity_ids: &[EntityId], canonical_name: &str) -> Resul
             });
         }
 
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         // Get the canonical entity (first in list)
         let canonical_id = &entity_ids[0];
@@ -596,7 +602,7 @@ fn find_entities_by_name(
         domain: Option<&Domain>,
         limit: usize,
     ) -> Result<Vec<Entity>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let mut conditions = vec!["(name LIKE ?1 OR aliases LIKE ?1)".to_string()];
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = vec![Box::new(format!("%{name}%"))];
@@ -652,7 +658,7 @@ fn find_entities_by_name(
 
     #[instrument(skip(self, relationship))]
     fn store_relationship(&self, relationship: &Relationship) -> Result<()> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let properties_json =
             serde_json::to_string(&relationship.properties).unwrap_or_else(|_| "{}".to_string());
@@ -689,7 +695,7 @@ fn store_relationship(&self, relationship: &Relationship) -> Result<()> {
 
     #[instrument(skip(self, query))]
     fn query_relationships(&self, query: &RelationshipQuery) -> Result<Vec<Relationship>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let mut conditions = Vec::new();
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
@@ -746,7 +752,7 @@ fn query_relationships(&self, query: &RelationshipQuery) -> Result<Vec<Relations
 
     #[instrument(skip(self, query))]
     fn delete_relationships(&self, query: &RelationshipQuery) -> Result<usize> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let mut conditions = Vec::new();
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
@@ -797,7 +803,7 @@ fn get_relationship_types(
         from_entity: &EntityId,
         to_entity: &EntityId,
     ) -> Result<Vec<RelationshipType>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let mut stmt = conn
             .prepare(
@@ -830,7 +836,7 @@ fn get_relationship_types(
 
     #[instrument(skip(self, mention))]
     fn store_mention(&self, mention: &EntityMention) -> Result<()> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         conn.execute(
             "INSERT INTO graph_entity_mentions (entity_id, memory_id, confidence, start_offset, end_offset, matched_text, transaction_time)
@@ -868,7 +874,7 @@ fn store_mention(&self, mention: &EntityMention) -> Result<()> {
 
     #[instrument(skip(self))]
     fn get_mentions_for_entity(&self, entity_id: &EntityId) -> Result<Vec<EntityMention>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let mut stmt = conn
             .prepare(
@@ -908,7 +914,7 @@ fn get_mentions_for_entity(&self, entity_id: &EntityId) -> Result<Vec<EntityMent
 
     #[instrument(skip(self))]
     fn get_entities_in_memory(&self, memory_id: &MemoryId) -> Result<Vec<Entity>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let mut stmt = conn
             .prepare(
@@ -936,7 +942,7 @@ fn get_entities_in_memory(&self, memory_id: &MemoryId) -> Result<Vec<Entity>> {
 
     #[instrument(skip(self))]
     fn delete_mentions_for_entity(&self, entity_id: &EntityId) -> Result<usize> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let rows = conn
             .execute(
@@ -960,7 +966,7 @@ fn delete_mentions_for_entity(&self, entity_id: &EntityId) -> Result<usize> {
 
     #[instrument(skip(self))]
     fn delete_mentions_for_memory(&self, memory_id: &MemoryId) -> Result<usize> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         // First, get affected entity IDs to decrement their mention counts
         let mut stmt = conn
@@ -1014,7 +1020,7 @@ fn traverse(
         relationship_types: Option<&[RelationshipType]>,
         min_confidence: Option<f32>,
     ) -> Result<TraversalResult> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         // Build relationship type filter
         let type_filter = relationship_types
@@ -1126,7 +1132,7 @@ fn find_path(
         to: &EntityId,
         max_depth: u32,
     ) -> Result<Option<TraversalResult>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         // Use recursive CTE to find shortest path
         let sql = "WITH RECURSIVE path_finder(entity_id, depth, path) AS (
@@ -1222,7 +1228,7 @@ fn query_entities_at(
         query: &EntityQuery,
         point: &BitemporalPoint,
     ) -> Result<Vec<Entity>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let (base_where, mut params) = Self::build_entity_where_clause(query);
 
@@ -1276,7 +1282,7 @@ fn query_relationships_at(
         query: &RelationshipQuery,
         point: &BitemporalPoint,
     ) -> Result<Vec<Relationship>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let mut conditions = Vec::new();
         let mut params: Vec<Box<dyn rusqlite::ToSql>> = Vec::new();
@@ -1340,7 +1346,7 @@ fn query_relationships_at(
 
     #[instrument(skip(self))]
     fn close_entity_valid_time(&self, id: &EntityId, end_time: i64) -> Result<()> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let rows = conn
             .execute(
@@ -1370,7 +1376,7 @@ fn close_relationship_valid_time(
         relationship_type: RelationshipType,
         end_time: i64,
     ) -> Result<()> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let rows = conn
             .execute(
@@ -1404,7 +1410,7 @@ fn close_relationship_valid_time(
 
     #[instrument(skip(self))]
     fn get_stats(&self) -> Result<GraphStats> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let entity_count: i64 = conn
             .query_row("SELECT COUNT(*) FROM graph_entities", [], |row| row.get(0))
@@ -1494,7 +1500,7 @@ fn get_stats(&self) -> Result<GraphStats> {
 
     #[instrument(skip(self))]
     fn clear(&self) -> Result<()> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         conn.execute("DELETE FROM graph_entity_mentions", [])
             .map_err(|e| Error::OperationFailed {
@@ -10,6 +10,7 @@
 use crate::models::group::{
     Group, GroupId, GroupInvite, GroupMember, GroupMembership, GroupRole, normalize_email,
 };
+use crate::storage::{acquire_lock_with_timeout, mutex_lock_timeout};
 use crate::{Error, Result};
 
 use super::traits::GroupBackend;
@@ -81,10 +82,7 @@ pub fn default_org_path(org: &str) -> Option<PathBuf> {
 
     /// Initializes the database schema.
     fn initialize_schema(&self) -> Result<()> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         conn.execute_batch(
             r"
@@ -183,10 +181,7 @@ fn create_group(
         description: &str,
         created_by: &str,
     ) -> Result<Group> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let now = Self::now();
         let group = Group {
@@ -229,10 +224,7 @@ fn create_group(
     }
 
     fn get_group(&self, group_id: &GroupId) -> Result<Option<Group>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let mut stmt = conn
             .prepare(
@@ -266,10 +258,7 @@ fn get_group(&self, group_id: &GroupId) -> Result<Option<Group>> {
     }
 
     fn get_group_by_name(&self, org_id: &str, name: &str) -> Result<Option<Group>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let mut stmt = conn
             .prepare(
@@ -303,10 +292,7 @@ fn get_group_by_name(&self, org_id: &str, name: &str) -> Result<Option<Group>> {
     }
 
     fn list_groups(&self, org_id: &str) -> Result<Vec<Group>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let mut stmt = conn
             .prepare(
@@ -344,10 +330,7 @@ fn list_groups(&self, org_id: &str) -> Result<Vec<Group>> {
     }
 
     fn delete_group(&self, group_id: &GroupId) -> Result<bool> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let rows = conn
             .execute(
@@ -369,10 +352,7 @@ fn add_member(
         role: GroupRole,
         added_by: &str,
     ) -> Result<GroupMember> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let member = GroupMember::new(group_id.clone(), email, role, added_by);
 
@@ -402,10 +382,7 @@ fn add_member(
     }
 
     fn get_member(&self, group_id: &GroupId, email: &str) -> Result<Option<GroupMember>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let normalized_email = normalize_email(email);
 
@@ -445,10 +422,7 @@ fn update_member_role(
         email: &str,
         new_role: GroupRole,
     ) -> Result<bool> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let normalized_email = normalize_email(email);
 
@@ -466,10 +440,7 @@ fn update_member_role(
     }
 
     fn remove_member(&self, group_id: &GroupId, email: &str) -> Result<bool> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let normalized_email = normalize_email(email);
 
@@ -487,10 +458,7 @@ fn remove_member(&self, group_id: &GroupId, email: &str) -> Result<bool> {
     }
 
     fn list_members(&self, group_id: &GroupId) -> Result<Vec<GroupMember>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let mut stmt = conn
             .prepare(
@@ -527,10 +495,7 @@ fn list_members(&self, group_id: &GroupId) -> Result<Vec<GroupMember>> {
     }
 
     fn get_user_groups(&self, org_id: &str, email: &str) -> Result<Vec<GroupMembership>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let normalized_email = normalize_email(email);
 
@@ -570,10 +535,7 @@ fn get_user_groups(&self, org_id: &str, email: &str) -> Result<Vec<GroupMembersh
     }
 
     fn count_admins(&self, group_id: &GroupId) -> Result<u32> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let count: u32 = conn
             .query_row(
@@ -597,10 +559,7 @@ fn create_invite(
         expires_in_secs: Option<u64>,
         max_uses: Option<u32>,
     ) -> Result<(GroupInvite, String)> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let (invite, token) = GroupInvite::new(
             group_id.clone(),
@@ -636,10 +595,7 @@ fn create_invite(
     }
 
     fn get_invite_by_token_hash(&self, token_hash: &str) -> Result<Option<GroupInvite>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let mut stmt = conn
             .prepare(
@@ -677,10 +633,7 @@ fn get_invite_by_token_hash(&self, token_hash: &str) -> Result<Option<GroupInvit
     }
 
     fn get_invite(&self, invite_id: &str) -> Result<Option<GroupInvite>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let mut stmt = conn
             .prepare(
@@ -718,10 +671,7 @@ fn get_invite(&self, invite_id: &str) -> Result<Option<GroupInvite>> {
     }
 
     fn list_invites(&self, group_id: &GroupId, include_expired: bool) -> Result<Vec<GroupInvite>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let query = if include_expired {
             "SELECT id, group_id, token_hash, role, created_by, created_at, expires_at,
@@ -775,10 +725,7 @@ fn list_invites(&self, group_id: &GroupId, include_expired: bool) -> Result<Vec<
     }
 
     fn increment_invite_uses(&self, invite_id: &str) -> Result<()> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         conn.execute(
             "UPDATE group_invites SET current_uses = current_uses + 1 WHERE id = ?1",
@@ -793,10 +740,7 @@ fn increment_invite_uses(&self, invite_id: &str) -> Result<()> {
     }
 
     fn revoke_invite(&self, invite_id: &str) -> Result<bool> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let rows = conn
             .execute(
@@ -812,10 +756,7 @@ fn revoke_invite(&self, invite_id: &str) -> Result<bool> {
     }
 
     fn cleanup_expired_invites(&self) -> Result<u64> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_connection".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())?;
 
         let rows = conn
             .execute(
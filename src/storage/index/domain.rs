@@ -5,11 +5,19 @@
 //! - **User**: `<user-data>/index.db` - user-wide memories
 //! - **Org**: Configured path or database URL - team/enterprise memories
 
-use crate::storage::index::SqliteBackend;
+use crate::models::Domain;
+use crate::storage::index::{PostgresBackend, SqliteBackend};
+use crate::storage::traits::IndexBackend;
 use crate::{Error, Result};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
+
+/// Index table of a PostgreSQL org index.
+const ORG_POSTGRES_TABLE: &str = "org_memories_index";
+
+/// Vector table of a PostgreSQL org index.
+const ORG_POSTGRES_VECTOR_TABLE: &str = "org_memory_vectors";
 
 /// Domain scope for index isolation.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -60,6 +68,21 @@ pub fn default_for_path(path: &Path) -> Self {
             Self::User
         }
     }
+
+    /// Returns the scope a memory with `domain` belongs to.
+    ///
+    /// User domains map to `User`, organization-only domains to `Org`, and
+    /// everything else (including repository domains) to `Project`.
+    #[must_use]
+    pub fn for_domain(domain: &Domain) -> Self {
+        if domain.is_user() {
+            Self::User
+        } else if domain.organization.is_some() && domain.repository.is_none() {
+            Self::Org
+        } else {
+            Self::Project
+        }
+    }
 }
 
 /// Configuration for domain-scoped indices.
@@ -81,7 +104,7 @@ pub struct DomainIndexConfig {
 pub enum OrgIndexConfig {
     /// `SQLite` file at a shared path.
     SqlitePath(PathBuf),
-    /// PostgreSQL connection URL (future).
+    /// PostgreSQL connection URL (`org_memories_index` table).
     PostgresUrl(String),
     /// Redis connection URL (future).
     RedisUrl(String),
@@ -185,11 +208,12 @@ fn get_user_index_path(&self) -> PathBuf {
     fn get_org_index_path(&self) -> Result<PathBuf> {
         match &self.config.org_config {
             Some(OrgIndexConfig::SqlitePath(path)) => Ok(path.clone()),
-            Some(OrgIndexConfig::PostgresUrl(_) | OrgIndexConfig::RedisUrl(_)) => {
-                Err(Error::NotImplemented(
-                    "PostgreSQL and Redis org indices not yet implemented".to_string(),
-                ))
-            },
+            Some(OrgIndexConfig::PostgresUrl(_)) => Err(Error::InvalidInput(
+                "PostgreSQL org index has no file path; use create_org_index".to_string(),
+            )),
+            Some(OrgIndexConfig::RedisUrl(_)) => Err(Error::NotImplemented(
+                "Redis org indices not yet implemented".to_string(),
+            )),
             None => {
                 // Default to user data dir org folder
                 Ok(self.user_data_dir.join("org").join("index.db"))
@@ -203,6 +227,12 @@ pub fn available_scopes(&self) -> Vec<DomainScope> {
         self.indices.keys().copied().collect()
     }
 
+    /// Returns whether an org index is configured.
+    #[must_use]
+    pub const fn has_org_config(&self) -> bool {
+        self.config.org_config.is_some()
+    }
+
     /// Checks if a scope has an initialized index.
     #[must_use]
     pub fn has_scope(&self, scope: DomainScope) -> bool {
@@ -242,6 +272,27 @@ pub fn create_backend(&self, scope: DomainScope) -> Result<SqliteBackend> {
         self.create_index(scope)
     }
 
+    /// Creates the org index backend.
+    ///
+    /// A PostgreSQL URL opens the `org_memories_index` table (requires the
+    /// `postgres` feature); otherwise this is the org `SQLite` backend from
+    /// [`create_backend`](Self::create_backend).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backend cannot be created, or for a Redis org
+    /// index, which is not implemented.
+    pub fn create_org_index(&self) -> Result<Arc<dyn IndexBackend + Send + Sync>> {
+        match &self.config.org_config {
+            Some(OrgIndexConfig::PostgresUrl(url)) => Ok(Arc::new(PostgresBackend::new(
+                url,
+                ORG_POSTGRES_TABLE,
+                ORG_POSTGRES_VECTOR_TABLE,
+            )?)),
+            _ => Ok(Arc::new(self.create_backend(DomainScope::Org)?)),
+        }
+    }
+
     /// Creates a new `SQLite` backend with full path resolution.
     ///
     /// Returns both the created backend and the path it was created at.
@@ -352,6 +403,51 @@ fn test_domain_scope_as_str() {
         assert_eq!(DomainScope::Org.as_str(), "org");
     }
 
+    #[test]
+    fn test_domain_scope_for_domain() {
+        assert_eq!(
+            DomainScope::for_domain(&Domain::new()),
+            DomainScope::Project
+        );
+        assert_eq!(
+            DomainScope::for_domain(&Domain::for_repository("zircote", "subcog")),
+            DomainScope::Project
+        );
+        assert_eq!(
+            DomainScope::for_domain(&Domain::for_user()),
+            DomainScope::User
+        );
+        assert_eq!(
+            DomainScope::for_domain(&Domain::for_org()),
+            DomainScope::Org
+        );
+    }
+
+    #[test]
+    fn test_org_index_from_config() {
+        let dir = TempDir::new().unwrap();
+        let manager = |org_config| {
+            DomainIndexManager::new(DomainIndexConfig {
+                repo_path: None,
+                org_config: Some(org_config),
+                user_data_dir: Some(dir.path().to_path_buf()),
+            })
+            .unwrap()
+        };
+
+        let sqlite = manager(OrgIndexConfig::SqlitePath(dir.path().join("org.db")));
+        assert!(sqlite.create_org_index().is_ok());
+
+        // Never NotImplemented: connects, or reports the missing feature
+        let postgres = manager(OrgIndexConfig::PostgresUrl(
+            "postgresql://localhost:1/subcog_org".to_string(),
+        ));
+        assert!(!matches!(
+            postgres.create_org_index(),
+            Err(Error::NotImplemented(_))
+        ));
+    }
+
     #[test]
     fn test_find_repo_root() {
         let dir = TempDir::new().unwrap();
@@ -0,0 +1,732 @@
+//! In-memory index backend for tests and ephemeral use.
+//!
+//! Provides a non-persistent implementation of [`IndexBackend`] backed by a
+//! simple inverted index, so tests and short-lived tools can run capture and
+//! recall without creating a `SQLite` database.
+//!
+//! Search mirrors the `SQLite` FTS5 backend closely enough for recall tests:
+//! - Content and tags are tokenized on non-alphanumeric characters and
+//!   lowercased (like the FTS5 `unicode61` tokenizer)
+//! - Query terms are OR-ed together and ranked with BM25, normalized to
+//!   `0.0..1.0` with the same sigmoid as the `SQLite` backend
+//! - [`SearchFilter`] fields are applied with the same semantics as the
+//!   `SQLite` filter clause, including excluding tombstoned memories by default
+//! - A section-scoped search matches and ranks the named section bodies of
+//!   structured memories instead of the whole memory
+//!
+//! Unlike FTS5, a quoted or hyphenated query term matches its parts
+//! individually rather than as a phrase.
+
+use crate::models::{
+    ContentHasher, EdgeType, Memory, MemoryId, MemoryStatus, Namespace, RelevanceFeedback,
+    SearchFilter, parse_sections,
+};
+use crate::storage::traits::IndexBackend;
+use crate::{Error, Result};
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+/// BM25 term frequency saturation.
+const BM25_K1: f64 = 1.2;
+
+/// BM25 document length normalization.
+const BM25_B: f64 = 0.75;
+
+/// An indexed memory with its term statistics.
+#[derive(Debug)]
+struct IndexedMemory {
+    memory: Memory,
+    term_freqs: HashMap<String, u32>,
+    length: usize,
+    /// Section name → term statistics of the section body.
+    sections: HashMap<String, SectionTerms>,
+}
+
+/// Term statistics of a structured memory section.
+#[derive(Debug, Default)]
+struct SectionTerms {
+    term_freqs: HashMap<String, u32>,
+    length: usize,
+}
+
+/// Index state guarded by a single lock so postings never drift from memories.
+#[derive(Debug, Default)]
+struct IndexState {
+    memories: HashMap<MemoryId, IndexedMemory>,
+    /// Term → memories whose content or tags contain it.
+    postings: HashMap<String, HashSet<MemoryId>>,
+    /// Sum of all memory lengths (in tokens), for BM25 length normalization.
+    total_length: usize,
+    edges: HashSet<(MemoryId, MemoryId, EdgeType)>,
+    /// (query, memory) → (useful, not useful) votes.
+    feedback: HashMap<(String, MemoryId), (u32, u32)>,
+}
+
+impl IndexState {
+    fn insert(&mut self, memory: &Memory) {
+        self.remove(&memory.id);
+
+        let mut term_freqs: HashMap<String, u32> = HashMap::new();
+        let mut length = 0;
+        let tag_terms = memory.tags.iter().flat_map(|tag| tokenize(tag));
+        for term in tokenize(&memory.content).chain(tag_terms) {
+            *term_freqs.entry(term).or_default() += 1;
+            length += 1;
+        }
+        for term in term_freqs.keys() {
+            self.postings
+                .entry(term.clone())
+                .or_default()
+                .insert(memory.id.clone());
+        }
+
+        // Repeated sections are indexed as one body, like the SQLite backend
+        let mut sections: HashMap<String, SectionTerms> = HashMap::new();
+        for section in parse_sections(&memory.content) {
+            let terms = sections.entry(section.name).or_default();
+            for term in tokenize(&section.content) {
+                *terms.term_freqs.entry(term).or_default() += 1;
+                terms.length += 1;
+            }
+        }
+
+        // Embeddings live in the vector backend
+        let mut memory = memory.clone();
+        memory.embedding = None;
+
+        self.total_length += length;
+        self.memories.insert(
+            memory.id.clone(),
+            IndexedMemory {
+                memory,
+                term_freqs,
+                length,
+                sections,
+            },
+        );
+    }
+
+    fn remove(&mut self, id: &MemoryId) -> bool {
+        let Some(indexed) = self.memories.remove(id) else {
+            return false;
+        };
+        for term in indexed.term_freqs.keys() {
+            let Some(ids) = self.postings.get_mut(term) else {
+                continue;
+            };
+            ids.remove(id);
+            if ids.is_empty() {
+                self.postings.remove(term);
+            }
+        }
+        self.total_length -= indexed.length;
+        true
+    }
+
+    /// Returns the memories matching any of `terms` with their BM25 scores.
+    #[allow(clippy::cast_precision_loss)]
+    fn memory_scores(&self, terms: &[String]) -> Vec<(&IndexedMemory, f64)> {
+        let candidates: HashSet<&MemoryId> = terms
+            .iter()
+            .filter_map(|term| self.postings.get(term))
+            .flatten()
+            .collect();
+        let doc_count = self.memories.len();
+        let avg_length = self.total_length as f64 / doc_count as f64;
+        let doc_freq = |term: &str| self.postings.get(term).map_or(0, HashSet::len);
+
+        candidates
+            .into_iter()
+            .filter_map(|id| self.memories.get(id))
+            .map(|indexed| {
+                let score = bm25(
+                    &indexed.term_freqs,
+                    indexed.length,
+                    terms,
+                    (doc_count, avg_length),
+                    doc_freq,
+                );
+                (indexed, score)
+            })
+            .collect()
+    }
+
+    /// Returns the memories whose `section` body matches any of `terms`, with
+    /// the BM25 score of that body among all indexed bodies of the section.
+    #[allow(clippy::cast_precision_loss)]
+    fn section_scores(&self, section: &str, terms: &[String]) -> Vec<(&IndexedMemory, f64)> {
+        let bodies: Vec<(&IndexedMemory, &SectionTerms)> = self
+            .memories
+            .values()
+            .filter_map(|indexed| Some((indexed, indexed.sections.get(section)?)))
+            .collect();
+        let doc_count = bodies.len();
+        let total_length: usize = bodies.iter().map(|(_, body)| body.length).sum();
+        let avg_length = total_length as f64 / doc_count as f64;
+        let doc_freq = |term: &str| {
+            bodies
+                .iter()
+                .filter(|(_, body)| body.term_freqs.contains_key(term))
+                .count()
+        };
+
+        bodies
+            .iter()
+            .filter(|(_, body)| terms.iter().any(|term| body.term_freqs.contains_key(term)))
+            .map(|(indexed, body)| {
+                let score = bm25(
+                    &body.term_freqs,
+                    body.length,
+                    terms,
+                    (doc_count, avg_length),
+                    doc_freq,
+                );
+                (*indexed, score)
+            })
+            .collect()
+    }
+}
+
+/// Returns the BM25 score of a text with `term_freqs` and `length` for the
+/// given query terms, in a corpus of `(document count, average length)`
+/// where `doc_freq` counts the documents containing a term.
+#[allow(clippy::cast_precision_loss)]
+fn bm25(
+    term_freqs: &HashMap<String, u32>,
+    length: usize,
+    terms: &[String],
+    (doc_count, avg_length): (usize, f64),
+    doc_freq: impl Fn(&str) -> usize,
+) -> f64 {
+    let doc_count = doc_count as f64;
+    let length_norm = BM25_B.mul_add(length as f64 / avg_length.max(1.0), 1.0 - BM25_B);
+
+    terms
+        .iter()
+        .filter_map(|term| {
+            let tf = f64::from(*term_freqs.get(term)?);
+            let matching = doc_freq(term) as f64;
+            let idf = ((doc_count - matching + 0.5) / (matching + 0.5)).ln_1p();
+            Some(idf * tf * (BM25_K1 + 1.0) / BM25_K1.mul_add(length_norm, tf))
+        })
+        .sum()
+}
+
+/// In-memory index backend for tests and ephemeral use.
+///
+/// Uses `RwLock` for thread-safe access with reader-writer semantics.
+/// Data is not persisted between runs.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use subcog::storage::index::InMemoryIndexBackend;
+/// use subcog::services::ServiceContainer;
+///
+/// let container = ServiceContainer::builder()
+///     .with_index(Arc::new(InMemoryIndexBackend::new()))
+///     .build()?;
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryIndexBackend {
+    state: RwLock<IndexState>,
+}
+
+impl InMemoryIndexBackend {
+    /// Creates a new empty in-memory index backend.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of indexed memories.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.state.read().map_or(0, |s| s.memories.len())
+    }
+
+    /// Returns whether no memories are indexed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn read(&self) -> Result<RwLockReadGuard<'_, IndexState>> {
+        self.state.read().map_err(|e| Error::OperationFailed {
+            operation: "read_index".to_string(),
+            cause: e.to_string(),
+        })
+    }
+
+    fn write(&self) -> Result<RwLockWriteGuard<'_, IndexState>> {
+        self.state.write().map_err(|e| Error::OperationFailed {
+            operation: "write_index".to_string(),
+            cause: e.to_string(),
+        })
+    }
+}
+
+impl IndexBackend for InMemoryIndexBackend {
+    fn index(&self, memory: &Memory) -> Result<()> {
+        self.write()?.insert(memory);
+        Ok(())
+    }
+
+    fn remove(&self, id: &MemoryId) -> Result<bool> {
+        Ok(self.write()?.remove(id))
+    }
+
+    fn search(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+        limit: usize,
+    ) -> Result<Vec<(MemoryId, f32)>> {
+        let state = self.read()?;
+        let mut terms: Vec<String> = tokenize(query).collect();
+        terms.sort();
+        terms.dedup();
+
+        let candidates = filter.section.as_deref().map_or_else(
+            || state.memory_scores(&terms),
+            |section| state.section_scores(section, &terms),
+        );
+
+        let mut scored: Vec<(&IndexedMemory, f32)> = candidates
+            .into_iter()
+            .filter(|(indexed, _)| matches_filter(&indexed.memory, filter))
+            .map(|(indexed, bm25)| {
+                // Same sigmoid normalization as the SQLite backend's BM25 scores
+                #[allow(clippy::cast_possible_truncation)]
+                let score = (1.0 / (1.0 + (-0.5 * bm25).exp())).clamp(0.0, 1.0) as f32;
+                (indexed, score)
+            })
+            .filter(|(_, score)| filter.min_score.is_none_or(|min| *score >= min))
+            .collect();
+
+        scored.sort_by(|(a, a_score), (b, b_score)| {
+            b_score
+                .total_cmp(a_score)
+                .then_with(|| b.memory.created_at.cmp(&a.memory.created_at))
+        });
+        scored.truncate(limit);
+        Ok(scored
+            .into_iter()
+            .map(|(indexed, score)| (indexed.memory.id.clone(), score))
+            .collect())
+    }
+
+    fn clear(&self) -> Result<()> {
+        let mut state = self.write()?;
+        state.memories.clear();
+        state.postings.clear();
+        state.total_length = 0;
+        Ok(())
+    }
+
+    fn list_all(&self, filter: &SearchFilter, limit: usize) -> Result<Vec<(MemoryId, f32)>> {
+        let state = self.read()?;
+        let mut memories: Vec<&Memory> = state
+            .memories
+            .values()
+            .map(|indexed| &indexed.memory)
+            .filter(|memory| matches_filter(memory, filter))
+            .collect();
+        memories.sort_by_key(|memory| Reverse(memory.created_at));
+        Ok(memories
+            .into_iter()
+            .take(limit)
+            .map(|memory| (memory.id.clone(), 1.0))
+            .collect())
+    }
+
+    fn get_memory(&self, id: &MemoryId) -> Result<Option<Memory>> {
+        Ok(self
+            .read()?
+            .memories
+            .get(id)
+            .map(|indexed| indexed.memory.clone()))
+    }
+
+    fn find_by_content_hash(
+        &self,
+        namespace: Namespace,
+        content_hash: &str,
+    ) -> Result<Option<MemoryId>> {
+        Ok(self
+            .read()?
+            .memories
+            .values()
+            .map(|indexed| &indexed.memory)
+            .filter(|memory| {
+                memory.namespace == namespace
+                    && memory.status != MemoryStatus::Tombstoned
+                    && ContentHasher::hash(&memory.content) == content_hash
+            })
+            .min_by(|a, b| {
+                a.created_at
+                    .cmp(&b.created_at)
+                    .then_with(|| a.id.as_str().cmp(b.id.as_str()))
+            })
+            .map(|memory| memory.id.clone()))
+    }
+
+    fn store_edge(&self, from_id: &MemoryId, to_id: &MemoryId, edge_type: EdgeType) -> Result<()> {
+        self.write()?
+            .edges
+            .insert((from_id.clone(), to_id.clone(), edge_type));
+        Ok(())
+    }
+
+    fn query_edges(&self, from_id: &MemoryId, edge_type: EdgeType) -> Result<Vec<MemoryId>> {
+        Ok(self
+            .read()?
+            .edges
+            .iter()
+            .filter(|(from, _, kind)| from == from_id && *kind == edge_type)
+            .map(|(_, to, _)| to.clone())
+            .collect())
+    }
+
+    fn query_incoming_edges(&self, to_id: &MemoryId, edge_type: EdgeType) -> Result<Vec<MemoryId>> {
+        Ok(self
+            .read()?
+            .edges
+            .iter()
+            .filter(|(_, to, kind)| to == to_id && *kind == edge_type)
+            .map(|(from, _, _)| from.clone())
+            .collect())
+    }
+
+    fn record_feedback(&self, query: &str, memory_id: &MemoryId, useful: bool) -> Result<()> {
+        let mut state = self.write()?;
+        let votes = state
+            .feedback
+            .entry((query.to_string(), memory_id.clone()))
+            .or_default();
+        if useful {
+            votes.0 += 1;
+        } else {
+            votes.1 += 1;
+        }
+        Ok(())
+    }
+
+    fn feedback_for(&self, memory_ids: &[MemoryId]) -> Result<Vec<RelevanceFeedback>> {
+        Ok(self
+            .read()?
+            .feedback
+            .iter()
+            .filter(|((_, id), _)| memory_ids.contains(id))
+            .map(|((query, id), (up, down))| RelevanceFeedback {
+                memory_id: id.clone(),
+                query: query.clone(),
+                up: *up,
+                down: *down,
+            })
+            .collect())
+    }
+}
+
+/// Splits text into lowercase alphanumeric tokens.
+fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Applies a search filter with the same semantics as the `SQLite` backend.
+fn matches_filter(memory: &Memory, filter: &SearchFilter) -> bool {
+    let has_tag = |tag: &String| memory.tags.contains(tag);
+
+    (filter.namespaces.is_empty() || filter.namespaces.contains(&memory.namespace))
+        && (filter.statuses.is_empty() || filter.statuses.contains(&memory.status))
+        && (filter.origins.is_empty() || filter.origins.contains(&memory.origin))
+        && (filter.memory_ids.is_empty() || filter.memory_ids.contains(&memory.id))
+        && filter.tags.iter().all(has_tag)
+        && (filter.tags_any.is_empty() || filter.tags_any.iter().any(has_tag))
+        && !filter.excluded_tags.iter().any(has_tag)
+        && filter.source_pattern.as_deref().is_none_or(|pattern| {
+            memory
+                .source
+                .as_deref()
+                .is_some_and(|source| glob_matches(pattern, source))
+        })
+        && matches_optional(filter.project_id.as_ref(), memory.project_id.as_ref())
+        && matches_optional(filter.branch.as_ref(), memory.branch.as_ref())
+        && matches_optional(filter.file_path.as_ref(), memory.file_path.as_ref())
+        && filter.commit.as_deref().is_none_or(|prefix| {
+            memory
+                .commit
+                .as_deref()
+                .is_some_and(|commit| commit.to_lowercase().starts_with(&prefix.to_lowercase()))
+        })
+        && matches_optional(filter.language.as_ref(), memory.language.as_ref())
+        && (memory.created_at > 0
+            || (filter.created_after.is_none() && filter.created_before.is_none()))
+        && filter
+            .created_after
+            .is_none_or(|after| memory.created_at >= after)
+        && filter
+            .created_before
+            .is_none_or(|before| memory.created_at <= before)
+        && filter
+            .updated_after
+            .is_none_or(|after| memory.updated_at >= after)
+        && filter
+            .min_confidence
+            .is_none_or(|min| memory.confidence.is_some_and(|c| c >= min))
+        && (!filter.pinned_only || memory.pinned)
+        && (filter.include_tombstoned || memory.status != MemoryStatus::Tombstoned)
+}
+
+/// Returns whether an optional equality filter matches.
+fn matches_optional(expected: Option<&String>, actual: Option<&String>) -> bool {
+    expected.is_none_or(|expected| actual == Some(expected))
+}
+
+/// Matches a glob pattern (`*` and `?`), ASCII case-insensitively like SQL `LIKE`.
+fn glob_matches(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            },
+            Some(&c) if c == '?' || c.eq_ignore_ascii_case(&text[t]) => {
+                p += 1;
+                t += 1;
+            },
+            _ => match backtrack {
+                // Let the last `*` absorb one more character
+                Some((star, start)) => {
+                    p = star + 1;
+                    t = start + 1;
+                    backtrack = Some((star, start + 1));
+                },
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{CaptureOrigin, Domain, Namespace};
+
+    fn memory(id: &str, content: &str, tags: &[&str]) -> Memory {
+        Memory {
+            id: MemoryId::new(id),
+            content: content.to_string(),
+            namespace: Namespace::Decisions,
+            domain: Domain::new(),
+            project_id: None,
+            branch: None,
+            file_path: None,
+            status: MemoryStatus::Active,
+            created_at: 1,
+            updated_at: 1,
+            tombstoned_at: None,
+            expires_at: None,
+            embedding: None,
+            tags: tags.iter().map(ToString::to_string).collect(),
+            #[cfg(feature = "group-scope")]
+            group_id: None,
+            source: Some("src/db/pool.rs".to_string()),
+            is_summary: false,
+            source_memory_ids: None,
+            consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
+        }
+    }
+
+    fn ids(results: &[(MemoryId, f32)]) -> Vec<&str> {
+        results.iter().map(|(id, _)| id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_search_ranks_by_relevance() {
+        let index = InMemoryIndexBackend::new();
+        index
+            .index(&memory("a", "Use PostgreSQL for storage", &[]))
+            .unwrap();
+        index
+            .index(&memory(
+                "b",
+                "PostgreSQL pooling: size the PostgreSQL pool",
+                &["postgresql"],
+            ))
+            .unwrap();
+        index
+            .index(&memory("c", "Rust error handling", &["rust"]))
+            .unwrap();
+
+        let results = index
+            .search("postgresql", &SearchFilter::new(), 10)
+            .unwrap();
+        assert_eq!(ids(&results), vec!["b", "a"]);
+        assert!(results.iter().all(|(_, score)| (0.0..=1.0).contains(score)));
+
+        // Tags are searchable and terms are OR-ed
+        let results = index
+            .search("RUST storage", &SearchFilter::new(), 10)
+            .unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(
+            index
+                .search("mysql", &SearchFilter::new(), 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_reindexing_replaces_terms() {
+        let index = InMemoryIndexBackend::new();
+        index.index(&memory("a", "Use PostgreSQL", &[])).unwrap();
+        index.index(&memory("a", "Use SQLite", &[])).unwrap();
+
+        assert_eq!(index.len(), 1);
+        assert!(
+            index
+                .search("postgresql", &SearchFilter::new(), 10)
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            index
+                .search("sqlite", &SearchFilter::new(), 10)
+                .unwrap()
+                .len(),
+            1
+        );
+
+        assert!(index.remove(&MemoryId::new("a")).unwrap());
+        assert!(index.is_empty());
+        assert!(
+            index
+                .search("sqlite", &SearchFilter::new(), 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_filters_match_sqlite_semantics() {
+        let index = InMemoryIndexBackend::new();
+        index
+            .index(&memory("a", "database choice", &["db", "arch"]))
+            .unwrap();
+        let mut tombstoned = memory("b", "database legacy", &["db"]);
+        tombstoned.status = MemoryStatus::Tombstoned;
+        index.index(&tombstoned).unwrap();
+
+        let all = SearchFilter::new();
+        assert_eq!(ids(&index.list_all(&all, 10).unwrap()), vec!["a"]);
+        let with_tombstoned = SearchFilter::new().with_include_tombstoned(true);
+        assert_eq!(index.list_all(&with_tombstoned, 10).unwrap().len(), 2);
+
+        let tagged = SearchFilter::new().with_tag("arch");
+        assert_eq!(
+            ids(&index.search("database", &tagged, 10).unwrap()),
+            vec!["a"]
+        );
+        let excluded = SearchFilter::new().with_excluded_tag("arch");
+        assert!(index.search("database", &excluded, 10).unwrap().is_empty());
+
+        let by_source = SearchFilter::new().with_source_pattern("SRC/*.rs");
+        assert_eq!(index.list_all(&by_source, 10).unwrap().len(), 1);
+        let by_source = SearchFilter::new().with_source_pattern("src/*.py");
+        assert!(index.list_all(&by_source, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_section_search_matches_section_bodies() {
+        use crate::models::{MemorySection, render_sections};
+
+        let adr = |id: &str, decision: &str, consequences: &str| {
+            let sections = [
+                MemorySection::new("decision", decision),
+                MemorySection::new("consequences", consequences),
+            ];
+            memory(id, &render_sections(None, "", &sections), &[])
+        };
+        let index = InMemoryIndexBackend::new();
+        index
+            .index(&adr("a", "Postgres, Postgres and Postgres", "New tooling"))
+            .unwrap();
+        index
+            .index(&adr("b", "Use SQLite", "Postgres stays optional"))
+            .unwrap();
+        index
+            .index(&memory("c", "Postgres consequences", &[]))
+            .unwrap();
+
+        let in_consequences = SearchFilter::new().with_section("consequences");
+        let results = index.search("postgres", &in_consequences, 10).unwrap();
+        assert_eq!(ids(&results), vec!["b"]);
+        assert!(results.iter().all(|(_, score)| (0.0..=1.0).contains(score)));
+        assert_eq!(
+            index
+                .search("postgres", &SearchFilter::new(), 10)
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_capture_and_text_recall_in_memory() {
+        use crate::config::Config;
+        use crate::models::{CaptureRequest, SearchMode};
+        use crate::services::ServiceContainer;
+        use std::sync::Arc;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let data_dir = dir.path().join("subcog");
+        let container = ServiceContainer::builder()
+            .with_config(Config::new().with_data_dir(&data_dir))
+            .with_index(Arc::new(InMemoryIndexBackend::new()))
+            .build()
+            .unwrap();
+
+        let captured = container
+            .capture()
+            .capture(CaptureRequest::new("Use PostgreSQL for production storage"))
+            .unwrap();
+        container
+            .capture()
+            .capture(CaptureRequest::new("Prefer thiserror for library errors"))
+            .unwrap();
+
+        let results = container
+            .recall()
+            .unwrap()
+            .search(
+                "postgresql storage",
+                SearchMode::Text,
+                &SearchFilter::new(),
+                10,
+            )
+            .unwrap();
+        assert_eq!(results.memories.len(), 1);
+        assert_eq!(results.memories[0].memory.id, captured.memory_id);
+
+        // Nothing was written to disk
+        assert!(!data_dir.exists());
+    }
+}
@@ -1,6 +1,7 @@
 //! Index backend implementations.
 
 mod domain;
+mod memory;
 mod org_router;
 mod postgresql;
 mod sqlite;
@@ -9,9 +10,10 @@
     DomainIndexConfig, DomainIndexManager, DomainScope, OrgIndexConfig, find_repo_root,
     get_user_data_dir, is_in_git_repo, is_path_in_git_repo,
 };
+pub use memory::InMemoryIndexBackend;
 pub use org_router::{OrgBackendType, OrgIndexRouter, OrgIndexStatus};
 pub use postgresql::PostgresBackend;
-pub use sqlite::SqliteBackend;
+pub use sqlite::{INDEX_SCHEMA_VERSION, IndexSummary, SqliteBackend};
 
 // Redis backend available with feature flag
 #[cfg(feature = "redis")]
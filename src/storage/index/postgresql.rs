@@ -50,7 +50,10 @@ mod implementation {
         RelationshipType, TraversalResult,
     };
     use crate::models::temporal::{BitemporalPoint, TransactionTime, ValidTimeRange};
-    use crate::models::{Domain, Memory, MemoryId, MemoryStatus, Namespace, SearchFilter};
+    use crate::models::{
+        CaptureOrigin, ContentType, Domain, Memory, MemoryId, MemoryStatus, Namespace,
+        SearchFilter, parse_sections,
+    };
     use crate::storage::migrations::{Migration, MigrationRunner};
     use crate::storage::traits::graph::{GraphBackend, GraphStats};
     use crate::storage::traits::{IndexBackend, VectorBackend, VectorFilter};
@@ -72,10 +75,11 @@ mod implementation {
     /// instead of `GENERATED ALWAYS AS` because `to_tsvector()` is STABLE, not
     /// IMMUTABLE, and PostgreSQL 18+ enforces immutability for generated columns.
     /// The trigger approach works across all PostgreSQL versions (12+).
-    const MIGRATIONS: &[Migration] = &[Migration {
-        version: 1,
-        description: "Initial memories table with FTS and indexes",
-        sql: r"
+    const MIGRATIONS: &[Migration] = &[
+        Migration {
+            version: 1,
+            description: "Initial memories table with FTS and indexes",
+            sql: r"
                 CREATE TABLE IF NOT EXISTS {table} (
                     id TEXT PRIMARY KEY,
                     content TEXT NOT NULL,
@@ -188,7 +192,92 @@ mod implementation {
                 CREATE INDEX IF NOT EXISTS idx_graph_entity_mentions_entity ON graph_entity_mentions(entity_id);
                 CREATE INDEX IF NOT EXISTS idx_graph_entity_mentions_memory ON graph_entity_mentions(memory_id)
             ",
-    }];
+        },
+        Migration {
+            version: 2,
+            description: "Add capture origin column",
+            sql: r"
+                ALTER TABLE {table} ADD COLUMN IF NOT EXISTS origin TEXT NOT NULL DEFAULT 'manual';
+                CREATE INDEX IF NOT EXISTS {table}_origin_idx ON {table} (origin);
+            ",
+        },
+        Migration {
+            version: 3,
+            description: "Add capture confidence column",
+            sql: r"
+                ALTER TABLE {table} ADD COLUMN IF NOT EXISTS confidence REAL;
+                CREATE INDEX IF NOT EXISTS {table}_confidence_idx ON {table} (confidence);
+            ",
+        },
+        Migration {
+            version: 4,
+            description: "Add attachments column",
+            sql: r"
+                ALTER TABLE {table} ADD COLUMN IF NOT EXISTS attachments JSONB;
+            ",
+        },
+        Migration {
+            version: 5,
+            description: "Add content type column",
+            sql: r"
+                ALTER TABLE {table} ADD COLUMN IF NOT EXISTS content_type TEXT;
+            ",
+        },
+        Migration {
+            version: 6,
+            description: "Add pinned column",
+            sql: r"
+                ALTER TABLE {table} ADD COLUMN IF NOT EXISTS pinned BOOLEAN NOT NULL DEFAULT FALSE;
+            ",
+        },
+        Migration {
+            version: 7,
+            description: "Add commit_sha column",
+            sql: r"
+                ALTER TABLE {table} ADD COLUMN IF NOT EXISTS commit_sha TEXT;
+                CREATE INDEX IF NOT EXISTS {table}_commit_sha_idx ON {table} (commit_sha);
+            ",
+        },
+        Migration {
+            version: 8,
+            description: "Add language column",
+            sql: r"
+                ALTER TABLE {table} ADD COLUMN IF NOT EXISTS language TEXT;
+                CREATE INDEX IF NOT EXISTS {table}_language_idx ON {table} (language);
+            ",
+        },
+        Migration {
+            version: SECTIONS_MIGRATION,
+            description: "Add structured memory sections table",
+            sql: r"
+                CREATE TABLE IF NOT EXISTS {table}_sections (
+                    memory_id TEXT NOT NULL REFERENCES {table} (id) ON DELETE CASCADE,
+                    section TEXT NOT NULL,
+                    body TEXT NOT NULL,
+                    search_vector TSVECTOR,
+                    PRIMARY KEY (memory_id, section)
+                );
+
+                CREATE OR REPLACE FUNCTION {table}_sections_search_vector_update() RETURNS trigger AS $$
+                BEGIN
+                    NEW.search_vector := to_tsvector('english', coalesce(NEW.body, ''));
+                    RETURN NEW;
+                END
+                $$ LANGUAGE plpgsql;
+
+                DROP TRIGGER IF EXISTS {table}_sections_search_vector_trigger ON {table}_sections;
+                CREATE TRIGGER {table}_sections_search_vector_trigger
+                    BEFORE INSERT OR UPDATE ON {table}_sections
+                    FOR EACH ROW EXECUTE FUNCTION {table}_sections_search_vector_update();
+
+                CREATE INDEX IF NOT EXISTS {table}_sections_search_idx ON {table}_sections USING GIN (search_vector);
+            ",
+        },
+    ];
+
+    /// Migration that adds the sections table; memories stored before it are
+    /// backfilled once it has run.
+    const SECTIONS_MIGRATION: i32 = 9;
 
     /// Allowed table names for SQL injection prevention.
     const ALLOWED_TABLE_NAMES: &[&str] = &["memories", "subcog_memories", "org_memories_index"];
@@ -571,10 +660,69 @@ fn run_migrations(&self) -> Result<()> {
             self.block_on(async {
                 let runner = MigrationRunner::new(self.pool.clone(), &self.table_name)
                     .with_replacement("{vector_table}", &self.vector_table_name);
-                runner.run(MIGRATIONS).await
+                let previous = runner.current_version().await?;
+                runner.run(MIGRATIONS).await?;
+                if (1..SECTIONS_MIGRATION).contains(&previous) {
+                    self.backfill_sections_async().await?;
+                }
+                Ok(())
             })
         }
 
+        /// Indexes the sections of structured memories stored before the
+        /// sections table existed.
+        async fn backfill_sections_async(&self) -> Result<()> {
+            let client = self.pool.get().await.map_err(pool_error)?;
+            // Structured content starts with front matter
+            let select = format!(
+                "SELECT id, content FROM {} WHERE content LIKE '---%'",
+                self.table_name
+            );
+            let rows = client
+                .query(&select, &[])
+                .await
+                .map_err(|e| query_error("postgres_backfill_sections", e))?;
+            for row in &rows {
+                let id: String = row.get(0);
+                let content: String = row.get(1);
+                self.index_sections_async(&client, &id, &content).await?;
+            }
+            Ok(())
+        }
+
+        /// Replaces the indexed sections of memory `id` with those of `content`.
+        ///
+        /// A section that appears more than once is indexed as one body.
+        async fn index_sections_async(
+            &self,
+            client: &deadpool_postgres::Object,
+            id: &str,
+            content: &str,
+        ) -> Result<()> {
+            let delete = format!(
+                "DELETE FROM {}_sections WHERE memory_id = $1",
+                self.table_name
+            );
+            client
+                .execute(&delete, &[&id])
+                .await
+                .map_err(|e| query_error("postgres_delete_sections", e))?;
+
+            let insert = format!(
+                r"INSERT INTO {0}_sections (memory_id, section, body) VALUES ($1, $2, $3)
+                ON CONFLICT (memory_id, section) DO UPDATE SET
+                    body = {0}_sections.body || E'\n\n' || EXCLUDED.body",
+                self.table_name
+            );
+            for section in parse_sections(content) {
+                client
+                    .execute(&insert, &[&id, &section.name, &section.content])
+                    .await
+                    .map_err(|e| query_error("postgres_index_sections", e))?;
+            }
+            Ok(())
+        }
+
         /// Builds WHERE clause for filters.
         fn build_where_clause(filter: &SearchFilter, start_param: i32) -> (String, Vec<String>) {
             let mut clauses = Vec::new();
@@ -586,7 +734,15 @@ fn build_where_clause(filter: &SearchFilter, start_param: i32) -> (String, Vec<S
             Self::add_project_filter(filter, &mut clauses, &mut params, &mut param_num);
             Self::add_branch_filter(filter, &mut clauses, &mut params, &mut param_num);
             Self::add_file_path_filter(filter, &mut clauses, &mut params, &mut param_num);
+            Self::add_commit_filter(filter, &mut clauses, &mut params, &mut param_num);
+            Self::add_language_filter(filter, &mut clauses, &mut params, &mut param_num);
+            Self::add_memory_id_filter(filter, &mut clauses, &mut params, &mut param_num);
             Self::add_status_filter(filter, &mut clauses, &mut params, &mut param_num);
+            Self::add_origin_filter(filter, &mut clauses, &mut params, &mut param_num);
+            Self::add_confidence_filter(filter, &mut clauses, &mut params, &mut param_num);
+            if filter.pinned_only {
+                clauses.push("pinned".to_string());
+            }
 
             let clause = if clauses.is_empty() {
                 String::new()
@@ -672,6 +828,47 @@ fn add_status_filter(
             }
         }
 
+        /// Adds origin filter to WHERE clause.
+        fn add_origin_filter(
+            filter: &SearchFilter,
+            clauses: &mut Vec<String>,
+            params: &mut Vec<String>,
+            param_num: &mut i32,
+        ) {
+            if filter.origins.is_empty() {
+                return;
+            }
+            let placeholders: Vec<String> = filter
+                .origins
+                .iter()
+                .map(|_| {
+                    let p = format!("${param_num}");
+                    *param_num += 1;
+                    p
+                })
+                .collect();
+            clauses.push(format!("origin IN ({})", placeholders.join(", ")));
+            for origin in &filter.origins {
+                params.push(origin.as_str().to_string());
+            }
+        }
+
+        /// Adds minimum confidence filter to WHERE clause.
+        fn add_confidence_filter(
+            filter: &SearchFilter,
+            clauses: &mut Vec<String>,
+            params: &mut Vec<String>,
+            param_num: &mut i32,
+        ) {
+            let Some(min_confidence) = filter.min_confidence else {
+                return;
+            };
+            // Parameters are bound as text, so cast explicitly
+            clauses.push(format!("confidence >= ${param_num}::text::real"));
+            *param_num += 1;
+            params.push(min_confidence.to_string());
+        }
+
         fn add_project_filter(
             filter: &SearchFilter,
             clauses: &mut Vec<String>,
@@ -714,14 +911,66 @@ fn add_file_path_filter(
             params.push(file_path.clone());
         }
 
+        fn add_commit_filter(
+            filter: &SearchFilter,
+            clauses: &mut Vec<String>,
+            params: &mut Vec<String>,
+            param_num: &mut i32,
+        ) {
+            let Some(commit) = filter.commit.as_ref() else {
+                return;
+            };
+            clauses.push(format!("starts_with(lower(commit_sha), ${param_num})"));
+            *param_num += 1;
+            params.push(commit.to_lowercase());
+        }
+
+        fn add_language_filter(
+            filter: &SearchFilter,
+            clauses: &mut Vec<String>,
+            params: &mut Vec<String>,
+            param_num: &mut i32,
+        ) {
+            let Some(language) = filter.language.as_ref() else {
+                return;
+            };
+            clauses.push(format!("language = ${param_num}"));
+            *param_num += 1;
+            params.push(language.clone());
+        }
+
+        fn add_memory_id_filter(
+            filter: &SearchFilter,
+            clauses: &mut Vec<String>,
+            params: &mut Vec<String>,
+            param_num: &mut i32,
+        ) {
+            if filter.memory_ids.is_empty() {
+                return;
+            }
+            let placeholders: Vec<String> = filter
+                .memory_ids
+                .iter()
+                .map(|_| {
+                    let p = format!("${param_num}");
+                    *param_num += 1;
+                    p
+                })
+                .collect();
+            clauses.push(format!("id IN ({})", placeholders.join(", ")));
+            for id in &filter.memory_ids {
+                params.push(id.as_str().to_string());
+            }
+        }
+
         /// Async implementation of index operation.
         #[allow(clippy::cast_possible_wrap)]
         async fn index_async(&self, memory: &Memory) -> Result<()> {
             let client = self.pool.get().await.map_err(pool_error)?;
 
             let upsert = format!(
-                r"INSERT INTO {} (id, content, namespace, domain, project_id, branch, file_path, status, tags, created_at, updated_at, source, tombstoned_at, expires_at, is_summary, source_memory_ids, consolidation_timestamp)
-                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)
+                r"INSERT INTO {} (id, content, namespace, domain, project_id, branch, file_path, status, tags, created_at, updated_at, source, tombstoned_at, expires_at, is_summary, source_memory_ids, consolidation_timestamp, origin, confidence, attachments, content_type, pinned, commit_sha, language)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24)
                 ON CONFLICT (id) DO UPDATE SET
                     content = EXCLUDED.content,
                     namespace = EXCLUDED.namespace,
@@ -737,7 +986,14 @@ async fn index_async(&self, memory: &Memory) -> Result<()> {
                     expires_at = EXCLUDED.expires_at,
                     is_summary = EXCLUDED.is_summary,
                     source_memory_ids = EXCLUDED.source_memory_ids,
-                    consolidation_timestamp = EXCLUDED.consolidation_timestamp",
+                    consolidation_timestamp = EXCLUDED.consolidation_timestamp,
+                    origin = EXCLUDED.origin,
+                    confidence = EXCLUDED.confidence,
+                    attachments = EXCLUDED.attachments,
+                    content_type = EXCLUDED.content_type,
+                    pinned = EXCLUDED.pinned,
+                    commit_sha = EXCLUDED.commit_sha,
+                    language = EXCLUDED.language",
                 self.table_name
             );
 
@@ -746,6 +1002,7 @@ async fn index_async(&self, memory: &Memory) -> Result<()> {
                 serde_json::to_string(&memory.domain).unwrap_or_else(|_| memory.domain.to_string());
             let namespace_str = memory.namespace.as_str();
             let status_str = memory.status.as_str();
+            let origin_str = memory.origin.as_str();
             #[allow(clippy::cast_possible_wrap)]
             let created_at = memory.created_at as i64;
             #[allow(clippy::cast_possible_wrap)]
@@ -764,6 +1021,12 @@ async fn index_async(&self, memory: &Memory) -> Result<()> {
                             .collect(),
                     )
                 });
+            let attachments_json: Option<serde_json::Value> = if memory.attachments.is_empty() {
+                None
+            } else {
+                serde_json::to_value(&memory.attachments).ok()
+            };
+            let content_type = memory.content_type.as_ref().map(ToString::to_string);
 
             client
                 .execute(
@@ -786,12 +1049,20 @@ async fn index_async(&self, memory: &Memory) -> Result<()> {
                         &memory.is_summary,
                         &source_memory_ids_json,
                         &consolidation_ts,
+                        &origin_str,
+                        &memory.confidence,
+                        &attachments_json,
+                        &content_type,
+                        &memory.pinned,
+                        &memory.commit,
+                        &memory.language,
                     ],
                 )
                 .await
                 .map_err(|e| query_error("postgres_index", e))?;
 
-            Ok(())
+            self.index_sections_async(&client, memory.id.as_str(), &memory.content)
+                .await
         }
 
         /// Async implementation of remove operation.
@@ -813,17 +1084,36 @@ async fn search_async(
             limit: usize,
         ) -> Result<Vec<(MemoryId, f32)>> {
             let client = self.pool.get().await.map_err(pool_error)?;
-            let (filter_clause, filter_params) = Self::build_where_clause(filter, 2);
-
-            let search_query = format!(
-                r"SELECT id, ts_rank(search_vector, websearch_to_tsquery('english', $1)) as score
-                FROM {}
-                WHERE search_vector @@ websearch_to_tsquery('english', $1)
-                {}
-                ORDER BY score DESC
-                LIMIT {}",
-                self.table_name, filter_clause, limit
-            );
+            let (filter_clause, mut filter_params) = Self::build_where_clause(filter, 2);
+            filter_params.extend(filter.section.clone());
+
+            let search_query = if filter.section.is_some() {
+                // Match and rank the section body instead of the whole memory
+                format!(
+                    r"SELECT m.id, ts_rank(s.search_vector, websearch_to_tsquery('english', $1)) as score
+                    FROM {0}_sections s
+                    JOIN {0} m ON m.id = s.memory_id
+                    WHERE s.search_vector @@ websearch_to_tsquery('english', $1)
+                    AND s.section = ${1}
+                    {2}
+                    ORDER BY score DESC
+                    LIMIT {3}",
+                    self.table_name,
+                    filter_params.len() + 1,
+                    filter_clause,
+                    limit
+                )
+            } else {
+                format!(
+                    r"SELECT id, ts_rank(search_vector, websearch_to_tsquery('english', $1)) as score
+                    FROM {}
+                    WHERE search_vector @@ websearch_to_tsquery('english', $1)
+                    {}
+                    ORDER BY score DESC
+                    LIMIT {}",
+                    self.table_name, filter_clause, limit
+                )
+            };
 
             let mut params: Vec<&(dyn tokio_postgres::types::ToSql + Sync)> = Vec::new();
             params.push(&query);
@@ -891,7 +1181,7 @@ async fn list_all_async(
         /// Async implementation of clear operation.
         async fn clear_async(&self) -> Result<()> {
             let client = self.pool.get().await.map_err(pool_error)?;
-            let truncate = format!("TRUNCATE TABLE {}", self.table_name);
+            let truncate = format!("TRUNCATE TABLE {0}, {0}_sections", self.table_name);
             client
                 .execute(&truncate, &[])
                 .await
@@ -906,7 +1196,8 @@ async fn get_memory_async(&self, id: &MemoryId) -> Result<Option<Memory>> {
             let query = format!(
                 r"SELECT id, content, namespace, domain, project_id, branch, file_path,
                          status, tags, created_at, updated_at, source, tombstoned_at,
-                         expires_at, is_summary, source_memory_ids, consolidation_timestamp
+                         expires_at, is_summary, source_memory_ids, consolidation_timestamp,
+                         origin, confidence, attachments, content_type, pinned, commit_sha, language
                   FROM {}
                   WHERE id = $1",
                 self.table_name
@@ -929,7 +1220,8 @@ async fn get_memories_batch_async(&self, ids: &[MemoryId]) -> Result<Vec<Option<
             let query = format!(
                 r"SELECT id, content, namespace, domain, project_id, branch, file_path,
                          status, tags, created_at, updated_at, source, tombstoned_at,
-                         expires_at, is_summary, source_memory_ids, consolidation_timestamp
+                         expires_at, is_summary, source_memory_ids, consolidation_timestamp,
+                         origin, confidence, attachments, content_type, pinned, commit_sha, language
                   FROM {}
                   WHERE id = ANY($1)",
                 self.table_name
@@ -993,6 +1285,13 @@ fn row_to_memory(row: &tokio_postgres::Row) -> Memory {
             let is_summary: bool = row.get::<_, Option<bool>>(14).unwrap_or(false);
             let source_memory_ids_json: Option<serde_json::Value> = row.get(15);
             let consolidation_ts: Option<i64> = row.get(16);
+            let origin_str: Option<String> = row.get(17);
+            let confidence: Option<f32> = row.get(18);
+            let attachments_json: Option<serde_json::Value> = row.get(19);
+            let content_type: Option<String> = row.get(20);
+            let pinned: bool = row.get(21);
+            let commit: Option<String> = row.get(22);
+            let language: Option<String> = row.get(23);
 
             let namespace = Namespace::parse(&namespace_str).unwrap_or_default();
             let domain = serde_json::from_str::<Domain>(&domain_str).unwrap_or_default();
@@ -1041,6 +1340,18 @@ fn row_to_memory(row: &tokio_postgres::Row) -> Memory {
                 is_summary,
                 source_memory_ids,
                 consolidation_timestamp: consolidation_ts.map(|t| t as u64),
+                origin: origin_str
+                    .as_deref()
+                    .and_then(CaptureOrigin::parse)
+                    .unwrap_or_default(),
+                confidence,
+                attachments: attachments_json
+                    .and_then(|v| serde_json::from_value(v).ok())
+                    .unwrap_or_default(),
+                content_type: content_type.as_deref().and_then(ContentType::parse),
+                pinned,
+                commit,
+                language,
             }
         }
     }
@@ -241,6 +241,8 @@ fn create_index(&self, conn: &mut Connection) -> Result<()> {
                 .arg("TAG")
                 .arg("status")
                 .arg("TAG")
+                .arg("origin")
+                .arg("TAG")
                 .arg("tags")
                 .arg("TAG")
                 .arg("project_id")
@@ -255,6 +257,9 @@ fn create_index(&self, conn: &mut Connection) -> Result<()> {
                 .arg("updated_at")
                 .arg("NUMERIC")
                 .arg("SORTABLE")
+                .arg("confidence")
+                .arg("NUMERIC")
+                .arg("SORTABLE")
                 .query(conn);
 
             match result {
@@ -296,6 +301,19 @@ fn build_filter_clause(filter: &SearchFilter) -> String {
                 clauses.push(format!("@status:{{{}}}", status_strs.join("|")));
             }
 
+            if !filter.origins.is_empty() {
+                let origin_strs: Vec<&str> = filter
+                    .origins
+                    .iter()
+                    .map(crate::models::CaptureOrigin::as_str)
+                    .collect();
+                clauses.push(format!("@origin:{{{}}}", origin_strs.join("|")));
+            }
+
+            if let Some(min_confidence) = filter.min_confidence {
+                clauses.push(format!("@confidence:[{min_confidence} +inf]"));
+            }
+
             if let Some(ref project_id) = filter.project_id {
                 clauses.push(format!("@project_id:{{{project_id}}}"));
             }
@@ -330,6 +348,7 @@ fn index(&self, memory: &Memory) -> Result<()> {
             let tags_str = memory.tags.join(",");
             let domain_str = memory.domain.to_string();
             let status_str = memory.status.as_str();
+            let origin_str = memory.origin.as_str();
             let namespace_str = memory.namespace.as_str();
             let project_id = memory.project_id.as_deref().unwrap_or("");
             let branch = memory.branch.as_deref().unwrap_or("");
@@ -342,6 +361,7 @@ fn index(&self, memory: &Memory) -> Result<()> {
                     ("namespace", namespace_str),
                     ("domain", &domain_str),
                     ("status", status_str),
+                    ("origin", origin_str),
                     ("tags", &tags_str),
                     ("project_id", project_id),
                     ("branch", branch),
@@ -376,6 +396,80 @@ fn index(&self, memory: &Memory) -> Result<()> {
                 });
             }
 
+            let result: redis::RedisResult<()> = match memory.confidence {
+                Some(confidence) => conn.hset(&key, "confidence", confidence),
+                None => conn.hdel(&key, "confidence"),
+            };
+            if let Err(e) = result {
+                self.return_connection(conn);
+                return Err(Error::OperationFailed {
+                    operation: "redis_index_confidence".to_string(),
+                    cause: e.to_string(),
+                });
+            }
+
+            let attachments = if memory.attachments.is_empty() {
+                None
+            } else {
+                serde_json::to_string(&memory.attachments).ok()
+            };
+            let result: redis::RedisResult<()> = match attachments {
+                Some(json) => conn.hset(&key, "attachments", json),
+                None => conn.hdel(&key, "attachments"),
+            };
+            if let Err(e) = result {
+                self.return_connection(conn);
+                return Err(Error::OperationFailed {
+                    operation: "redis_index_attachments".to_string(),
+                    cause: e.to_string(),
+                });
+            }
+
+            let result: redis::RedisResult<()> = match &memory.content_type {
+                Some(content_type) => conn.hset(&key, "content_type", content_type.to_string()),
+                None => conn.hdel(&key, "content_type"),
+            };
+            if let Err(e) = result {
+                self.return_connection(conn);
+                return Err(Error::OperationFailed {
+                    operation: "redis_index_content_type".to_string(),
+                    cause: e.to_string(),
+                });
+            }
+
+            let result: redis::RedisResult<()> = conn.hset(&key, "pinned", u8::from(memory.pinned));
+            if let Err(e) = result {
+                self.return_connection(conn);
+                return Err(Error::OperationFailed {
+                    operation: "redis_index_pinned".to_string(),
+                    cause: e.to_string(),
+                });
+            }
+
+            let result: redis::RedisResult<()> = match &memory.commit {
+                Some(commit) => conn.hset(&key, "commit", commit),
+                None => conn.hdel(&key, "commit"),
+            };
+            if let Err(e) = result {
+                self.return_connection(conn);
+                return Err(Error::OperationFailed {
+                    operation: "redis_index_commit".to_string(),
+                    cause: e.to_string(),
+                });
+            }
+
+            let result: redis::RedisResult<()> = match &memory.language {
+                Some(language) => conn.hset(&key, "language", language),
+                None => conn.hdel(&key, "language"),
+            };
+            if let Err(e) = result {
+                self.return_connection(conn);
+                return Err(Error::OperationFailed {
+                    operation: "redis_index_language".to_string(),
+                    cause: e.to_string(),
+                });
+            }
+
             self.return_connection(conn);
             Ok(())
         }
@@ -406,6 +500,13 @@ fn search(
             filter: &SearchFilter,
             limit: usize,
         ) -> Result<Vec<(MemoryId, f32)>> {
+            // The RediSearch schema has no per-section fields
+            if filter.section.is_some() {
+                return Err(Error::InvalidInput(
+                    "Section-scoped search is not supported by the Redis index backend".to_string(),
+                ));
+            }
+
             let mut conn = self.get_connection()?;
 
             // Build query with filters
@@ -472,7 +573,7 @@ fn list_all(&self, filter: &SearchFilter, limit: usize) -> Result<Vec<(MemoryId,
         }
 
         fn get_memory(&self, id: &MemoryId) -> Result<Option<Memory>> {
-            use crate::models::{Domain, Namespace};
+            use crate::models::{CaptureOrigin, ContentType, Domain, Namespace};
 
             let mut conn = self.get_connection()?;
             let key = format!("mem:{}", id.as_str());
@@ -488,6 +589,24 @@ fn get_memory(&self, id: &MemoryId) -> Result<Option<Memory>> {
                     let namespace_str = fields.get("namespace").cloned().unwrap_or_default();
                     let domain_str = fields.get("domain").cloned();
                     let status_str = fields.get("status").cloned().unwrap_or_default();
+                    let origin = fields
+                        .get("origin")
+                        .map(String::as_str)
+                        .and_then(CaptureOrigin::parse)
+                        .unwrap_or_default();
+                    let confidence: Option<f32> =
+                        fields.get("confidence").and_then(|s| s.parse().ok());
+                    let attachments = fields
+                        .get("attachments")
+                        .and_then(|json| serde_json::from_str(json).ok())
+                        .unwrap_or_default();
+                    let content_type = fields
+                        .get("content_type")
+                        .map(String::as_str)
+                        .and_then(ContentType::parse);
+                    let pinned = fields.get("pinned").is_some_and(|s| s == "1");
+                    let commit = fields.get("commit").cloned();
+                    let language = fields.get("language").cloned();
                     let tags_str = fields.get("tags").cloned();
                     let project_id = fields
                         .get("project_id")
@@ -536,6 +655,13 @@ fn get_memory(&self, id: &MemoryId) -> Result<Option<Memory>> {
                         is_summary: false,
                         source_memory_ids: None,
                         consolidation_timestamp: None,
+                        origin,
+                        confidence,
+                        attachments,
+                        content_type,
+                        pinned,
+                        commit,
+                        language,
                     }))
                 },
                 Err(e) => Err(Error::OperationFailed {
@@ -2,72 +2,39 @@
 //!
 //! Provides full-text search using `SQLite`'s FTS5 extension.
 
-use crate::models::{Memory, MemoryId, SearchFilter};
+use crate::models::{
+    CaptureOrigin, ContentHasher, ContentType, FieldWeights, Memory, MemoryId, MemoryStatus,
+    Namespace, RelevanceFeedback, SearchFilter, parse_sections,
+};
 use crate::storage::traits::IndexBackend;
+use crate::storage::{acquire_lock_with_timeout, mutex_lock_timeout};
 use crate::{Error, Result};
 use chrono::{TimeZone, Utc};
-use rusqlite::{Connection, OptionalExtension, params};
+use rusqlite::{Connection, OpenFlags, OptionalExtension, params};
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::{Mutex, MutexGuard};
 use std::time::{Duration, Instant};
 use tracing::instrument;
 
-/// Timeout for acquiring mutex lock (5 seconds).
-/// Reserved for future use when upgrading to `parking_lot::Mutex`.
-#[allow(dead_code)]
-const MUTEX_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
-
-/// Helper to acquire mutex lock with poison recovery.
+/// Version of the index schema, stored as the database `user_version`.
 ///
-/// If the mutex is poisoned (due to a panic in a previous critical section),
-/// we recover the inner value and log a warning. This prevents cascading
-/// failures when one operation panics.
-fn acquire_lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
-    // First try to acquire lock normally
-    match mutex.lock() {
-        Ok(guard) => guard,
-        Err(poisoned) => {
-            // Recover from poison - this is safe because we log the issue
-            // and the connection state should still be valid
-            tracing::warn!("SQLite mutex was poisoned, recovering");
-            metrics::counter!("sqlite_mutex_poison_recovery_total").increment(1);
-            poisoned.into_inner()
-        },
-    }
-}
-
-/// Alternative lock acquisition with spin-wait timeout.
-///
-/// Note: Rust's `std::sync::Mutex` doesn't have a native `try_lock_for`,
-/// so we implement a spin-wait with sleep. For production, consider
-/// using `parking_lot::Mutex` which has proper timed locking.
-///
-/// Reserved for future use - currently using simpler `acquire_lock` with poison recovery.
-#[allow(dead_code)]
-fn acquire_lock_with_timeout<T>(mutex: &Mutex<T>, timeout: Duration) -> Result<MutexGuard<'_, T>> {
-    let start = Instant::now();
-    let sleep_duration = Duration::from_millis(10);
-
-    loop {
-        match mutex.try_lock() {
-            Ok(guard) => return Ok(guard),
-            Err(std::sync::TryLockError::Poisoned(poisoned)) => {
-                tracing::warn!("SQLite mutex was poisoned, recovering");
-                metrics::counter!("sqlite_mutex_poison_recovery_total").increment(1);
-                return Ok(poisoned.into_inner());
-            },
-            Err(std::sync::TryLockError::WouldBlock) => {
-                if start.elapsed() > timeout {
-                    metrics::counter!("sqlite_mutex_timeout_total").increment(1);
-                    return Err(Error::OperationFailed {
-                        operation: "acquire_lock".to_string(),
-                        cause: format!("Lock acquisition timed out after {timeout:?}"),
-                    });
-                }
-                std::thread::sleep(sleep_duration);
-            },
-        }
-    }
+/// Bump when [`SqliteBackend`] initialization changes the tables; opening an
+/// older index migrates it and records the new version.
+pub const INDEX_SCHEMA_VERSION: u32 = 7;
+
+/// Maximum number of tokens in a search hit snippet (FTS5 allows up to 64).
+const SNIPPET_TOKENS: usize = 24;
+
+/// Counts and schema version of an index database.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct IndexSummary {
+    /// Indexed memories, including tombstoned ones.
+    pub memories: usize,
+    /// Tombstoned memories.
+    pub tombstoned: usize,
+    /// Schema version recorded in the database (0 before versioning).
+    pub schema_version: u32,
 }
 
 /// Escapes SQL LIKE wildcards in a string (SEC-M4).
@@ -131,6 +98,144 @@ fn glob_to_like_pattern(pattern: &str) -> String {
     result
 }
 
+/// Adds a `column IN (...)` condition with one numbered parameter per value,
+/// advancing `param_idx` past them. Adds nothing when there are no values.
+fn push_in_condition(
+    column: &str,
+    values: Vec<String>,
+    conditions: &mut Vec<String>,
+    params: &mut Vec<String>,
+    param_idx: &mut usize,
+) {
+    if values.is_empty() {
+        return;
+    }
+    let placeholders: Vec<String> = (0..values.len())
+        .map(|offset| format!("?{}", *param_idx + offset))
+        .collect();
+    *param_idx += values.len();
+    conditions.push(format!("{column} IN ({})", placeholders.join(",")));
+    params.extend(values);
+}
+
+/// Adds the tag conditions of `filter`: all of `tags`, any of `tags_any`,
+/// and none of `excluded_tags`.
+fn push_tag_conditions(
+    filter: &SearchFilter,
+    conditions: &mut Vec<String>,
+    params: &mut Vec<String>,
+    param_idx: &mut usize,
+) {
+    // Tag filtering (AND logic - must have ALL tags)
+    // Use ',tag,' pattern with wrapped column to match whole tags only
+    // Escape LIKE wildcards in tags to prevent SQL injection (SEC-M4)
+    for tag in &filter.tags {
+        conditions.push(format!(
+            "(',' || m.tags || ',') LIKE ?{} ESCAPE '\\'",
+            *param_idx
+        ));
+        *param_idx += 1;
+        params.push(format!("%,{},%", escape_like_wildcards(tag)));
+    }
+
+    // Tag filtering (OR logic - must have ANY tag)
+    if !filter.tags_any.is_empty() {
+        let or_conditions: Vec<String> = filter
+            .tags_any
+            .iter()
+            .map(|tag| {
+                let cond = format!("(',' || m.tags || ',') LIKE ?{} ESCAPE '\\'", *param_idx);
+                *param_idx += 1;
+                params.push(format!("%,{},%", escape_like_wildcards(tag)));
+                cond
+            })
+            .collect();
+        conditions.push(format!("({})", or_conditions.join(" OR ")));
+    }
+
+    // Excluded tags (NOT LIKE) - match whole tags only
+    // Escape LIKE wildcards (SEC-M4)
+    for tag in &filter.excluded_tags {
+        conditions.push(format!(
+            "(',' || m.tags || ',') NOT LIKE ?{} ESCAPE '\\'",
+            *param_idx
+        ));
+        *param_idx += 1;
+        params.push(format!("%,{},%", escape_like_wildcards(tag)));
+    }
+}
+
+/// Adds the creation and update time window conditions of `filter`.
+fn push_time_conditions(
+    filter: &SearchFilter,
+    conditions: &mut Vec<String>,
+    params: &mut Vec<String>,
+    param_idx: &mut usize,
+) {
+    // Legacy rows without a capture time (0) never match a creation window
+    if filter.created_after.is_some() || filter.created_before.is_some() {
+        conditions.push("m.created_at > 0".to_string());
+    }
+
+    if let Some(after) = filter.created_after {
+        conditions.push(format!("m.created_at >= ?{}", *param_idx));
+        *param_idx += 1;
+        params.push(after.to_string());
+    }
+
+    if let Some(before) = filter.created_before {
+        conditions.push(format!("m.created_at <= ?{}", *param_idx));
+        *param_idx += 1;
+        params.push(before.to_string());
+    }
+
+    // COALESCE has no column affinity, so the bound text is cast to compare
+    // as an integer (text sorts above every integer)
+    if let Some(after) = filter.updated_after {
+        conditions.push(format!(
+            "COALESCE(m.updated_at, m.created_at) >= CAST(?{} AS INTEGER)",
+            *param_idx
+        ));
+        *param_idx += 1;
+        params.push(after.to_string());
+    }
+}
+
+/// Builds an FTS5 `MATCH` expression that ORs the query's terms literally.
+///
+/// Each term is wrapped in quotes (with embedded quotes doubled) so FTS5
+/// special characters - `-` (NOT), `*` (prefix), `"` (phrase), `:` (column) -
+/// are matched as text.
+///
+/// # Examples
+///
+/// ```ignore
+/// assert_eq!(fts_match_query("rust db"), "\"rust\" OR \"db\"");
+/// assert_eq!(fts_match_query("say \"hi\""), "\"say\" OR \"\"\"hi\"\"\"");
+/// ```
+fn fts_match_query(query: &str) -> String {
+    // Pre-allocate: each term becomes ~term.len() + 6 chars ("term" OR )
+    let terms: Vec<_> = query.split_whitespace().collect();
+    let estimated_len = terms.iter().map(|t| t.len() + 8).sum::<usize>();
+    let mut fts_query = String::with_capacity(estimated_len);
+    for (i, term) in terms.iter().enumerate() {
+        if i > 0 {
+            fts_query.push_str(" OR ");
+        }
+        fts_query.push('"');
+        // Escape double quotes for literal matching
+        for c in term.chars() {
+            if c == '"' {
+                fts_query.push_str("\"\"");
+            } else {
+                fts_query.push(c);
+            }
+        }
+        fts_query.push('"');
+    }
+    fts_query
+}
+
 /// `SQLite`-based index backend with FTS5.
 ///
 /// # Concurrency Model
@@ -154,6 +259,8 @@ pub struct SqliteBackend {
     conn: Mutex<Connection>,
     /// Path to the `SQLite` database (None for in-memory).
     db_path: Option<PathBuf>,
+    /// Maximum time to wait for the connection mutex.
+    lock_timeout: Duration,
 }
 
 struct MemoryRow {
@@ -165,6 +272,7 @@ struct MemoryRow {
     file_path: Option<String>,
     status: String,
     created_at: i64,
+    updated_at: Option<i64>,
     tombstoned_at: Option<i64>,
     expires_at: Option<i64>,
     tags: Option<String>,
@@ -173,6 +281,13 @@ struct MemoryRow {
     is_summary: bool,
     source_memory_ids: Option<String>,
     consolidation_timestamp: Option<i64>,
+    origin: Option<String>,
+    confidence: Option<f64>,
+    attachments: Option<String>,
+    content_type: Option<String>,
+    pinned: bool,
+    commit_sha: Option<String>,
+    language: Option<String>,
     #[cfg(feature = "group-scope")]
     group_id: Option<String>,
 }
@@ -193,6 +308,7 @@ pub fn new(db_path: impl Into<PathBuf>) -> Result<Self> {
         let backend = Self {
             conn: Mutex::new(conn),
             db_path: Some(db_path),
+            lock_timeout: mutex_lock_timeout(),
         };
 
         backend.initialize()?;
@@ -213,21 +329,74 @@ pub fn in_memory() -> Result<Self> {
         let backend = Self {
             conn: Mutex::new(conn),
             db_path: None,
+            lock_timeout: mutex_lock_timeout(),
         };
 
         backend.initialize()?;
         Ok(backend)
     }
 
+    /// Reads the counts and schema version of the index at `db_path`.
+    ///
+    /// Opens the database read-only, so unlike [`new`](Self::new) it neither
+    /// creates nor migrates it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database cannot be opened or queried.
+    pub fn summarize(db_path: &Path) -> Result<IndexSummary> {
+        let conn = Connection::open_with_flags(db_path, OpenFlags::SQLITE_OPEN_READ_ONLY).map_err(
+            |e| Error::OperationFailed {
+                operation: "open_sqlite_read_only".to_string(),
+                cause: e.to_string(),
+            },
+        )?;
+        let (memories, tombstoned): (i64, i64) = conn
+            .query_row(
+                "SELECT COUNT(*), COALESCE(SUM(status = 'tombstoned'), 0) FROM memories",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map_err(|e| Error::OperationFailed {
+                operation: "count_memories".to_string(),
+                cause: e.to_string(),
+            })?;
+        let schema_version: u32 = conn
+            .pragma_query_value(None, "user_version", |row| row.get(0))
+            .map_err(|e| Error::OperationFailed {
+                operation: "read_schema_version".to_string(),
+                cause: e.to_string(),
+            })?;
+        Ok(IndexSummary {
+            memories: usize::try_from(memories).unwrap_or(0),
+            tombstoned: usize::try_from(tombstoned).unwrap_or(0),
+            schema_version,
+        })
+    }
+
     /// Returns the database path.
     #[must_use]
     pub fn db_path(&self) -> Option<&Path> {
         self.db_path.as_deref()
     }
 
+    /// Sets how long operations wait for the connection before failing.
+    ///
+    /// Defaults to [`mutex_lock_timeout`] (`SUBCOG_SQLITE_LOCK_TIMEOUT_MS`).
+    #[must_use]
+    pub const fn with_lock_timeout(mut self, timeout: Duration) -> Self {
+        self.lock_timeout = timeout;
+        self
+    }
+
+    /// Locks the connection, failing with a lock timeout instead of blocking forever.
+    fn lock_conn(&self) -> Result<MutexGuard<'_, Connection>> {
+        acquire_lock_with_timeout(&self.conn, self.lock_timeout)
+    }
+
     /// Initializes the database schema.
     fn initialize(&self) -> Result<()> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         // Enable WAL mode for better concurrent read performance
         // Note: pragma_update returns the result which we ignore - journal_mode returns
@@ -284,27 +453,50 @@ fn initialize(&self) -> Result<()> {
             [],
         );
 
+        // Add origin column for capture-source attribution (manual/hook/import/consolidation)
+        let _ = conn.execute(
+            "ALTER TABLE memories ADD COLUMN origin TEXT NOT NULL DEFAULT 'manual'",
+            [],
+        );
+
+        // Add confidence column for auto-capture confidence scores (NULL when unknown)
+        let _ = conn.execute("ALTER TABLE memories ADD COLUMN confidence REAL", []);
+
+        // Add attachments column for attachment references (JSON array, NULL when none)
+        let _ = conn.execute("ALTER TABLE memories ADD COLUMN attachments TEXT", []);
+
+        // Add content_type column (code, markdown or text; NULL when unknown)
+        let _ = conn.execute("ALTER TABLE memories ADD COLUMN content_type TEXT", []);
+
+        // Add pinned column for memories kept in session context (subcog pin)
+        let _ = conn.execute(
+            "ALTER TABLE memories ADD COLUMN pinned INTEGER NOT NULL DEFAULT 0",
+            [],
+        );
+
+        // Add commit_sha column (HEAD commit at capture; `commit` is an SQL keyword)
+        let _ = conn.execute("ALTER TABLE memories ADD COLUMN commit_sha TEXT", []);
+
+        // Add updated_at column (NULL for rows indexed before it; read as created_at)
+        let _ = conn.execute("ALTER TABLE memories ADD COLUMN updated_at INTEGER", []);
+
+        // Add language column (ISO 639-1 code of the content; NULL when unknown)
+        let _ = conn.execute("ALTER TABLE memories ADD COLUMN language TEXT", []);
+
         // Add group_id column for group-scoped memories (ADR-0057: Group Memory Graphs)
         #[cfg(feature = "group-scope")]
         let _ = conn.execute("ALTER TABLE memories ADD COLUMN group_id TEXT", []);
 
-        // Create FTS5 virtual table for full-text search (standalone, not synced with memories)
-        // Note: FTS5 virtual tables use inverted indexes for MATCH queries and don't support
-        // traditional B-tree indexes. Joins with the memories table use memories.id (PRIMARY KEY)
-        // which is already indexed. The FTS5 MATCH operation returns a small result set first,
-        // making the join efficient. See: https://sqlite.org/fts5.html
-        conn.execute(
-            "CREATE VIRTUAL TABLE IF NOT EXISTS memories_fts USING fts5(
-                id,
-                content,
-                tags
-            )",
-            [],
-        )
-        .map_err(|e| Error::OperationFailed {
-            operation: "create_fts_table".to_string(),
-            cause: e.to_string(),
-        })?;
+        // Add content_hash column for exact-match deduplication (ContentHasher::hash)
+        let content_hash_added = conn
+            .execute("ALTER TABLE memories ADD COLUMN content_hash TEXT", [])
+            .is_ok();
+
+        Self::create_fts_table(&conn)?;
+        if content_hash_added {
+            Self::backfill_content_hashes(&conn)?;
+        }
+        Self::create_sections_table(&conn)?;
 
         // Create memory_edges table for relationship tracking (consolidation service)
         conn.execute(
@@ -324,13 +516,252 @@ fn initialize(&self) -> Result<()> {
             cause: e.to_string(),
         })?;
 
+        // Relevance votes from `recall --feedback`, per normalized query
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS memory_feedback (
+                query TEXT NOT NULL,
+                memory_id TEXT NOT NULL,
+                up INTEGER NOT NULL DEFAULT 0,
+                down INTEGER NOT NULL DEFAULT 0,
+                updated_at INTEGER NOT NULL,
+                PRIMARY KEY (query, memory_id)
+            )",
+            [],
+        )
+        .map_err(|e| Error::OperationFailed {
+            operation: "create_feedback_table".to_string(),
+            cause: e.to_string(),
+        })?;
+
         // Create indexes for common query patterns (DB-H1)
         // NOTE: This must be called AFTER all tables are created (including memory_edges)
         Self::create_indexes(&conn);
 
+        let _ = conn.pragma_update(None, "user_version", INDEX_SCHEMA_VERSION);
+
         Ok(())
     }
 
+    /// Creates the FTS5 table, rebuilding tables created before the `source`
+    /// or `code_terms` columns were added.
+    ///
+    /// The FTS table is standalone (not synced with memories) and holds the
+    /// only copy of the content, so the rebuild copies rows across rather than
+    /// reindexing. FTS5 virtual tables use inverted indexes for MATCH queries
+    /// and don't support traditional B-tree indexes; joins with the memories
+    /// table use memories.id (PRIMARY KEY), which is already indexed. See:
+    /// <https://sqlite.org/fts5.html>
+    fn create_fts_table(conn: &Connection) -> Result<()> {
+        // Columns are id, content, tags, source, code_terms; bm25() weights
+        // follow this order. code_terms holds the words of code identifiers
+        // (see ContentType::search_terms) and is empty for prose.
+        const CREATE_FTS: &str = "CREATE VIRTUAL TABLE IF NOT EXISTS memories_fts USING fts5(
+                id,
+                content,
+                tags,
+                source,
+                code_terms
+            )";
+
+        conn.execute(CREATE_FTS, [])
+            .map_err(|e| Error::OperationFailed {
+                operation: "create_fts_table".to_string(),
+                cause: e.to_string(),
+            })?;
+        if conn
+            .prepare("SELECT code_terms FROM memories_fts LIMIT 0")
+            .is_ok()
+        {
+            return Ok(());
+        }
+
+        conn.execute_batch(&format!(
+            "BEGIN IMMEDIATE;
+             ALTER TABLE memories_fts RENAME TO memories_fts_old;
+             {CREATE_FTS};
+             INSERT INTO memories_fts (id, content, tags, source)
+                 SELECT f.id, f.content, f.tags, m.source
+                 FROM memories_fts_old f LEFT JOIN memories m ON m.id = f.id;
+             DROP TABLE memories_fts_old;
+             COMMIT;"
+        ))
+        .map_err(|e| {
+            let _ = conn.execute("ROLLBACK", []);
+            Error::OperationFailed {
+                operation: "migrate_fts_table".to_string(),
+                cause: e.to_string(),
+            }
+        })
+    }
+
+    /// Creates the FTS5 table of structured memory sections, indexing the
+    /// sections of memories indexed before the table existed.
+    ///
+    /// Holds one row per section (see [`parse_sections`]) so section-scoped
+    /// searches match and rank the section body rather than the whole memory.
+    fn create_sections_table(conn: &Connection) -> Result<()> {
+        let failed = |e: rusqlite::Error| Error::OperationFailed {
+            operation: "create_sections_table".to_string(),
+            cause: e.to_string(),
+        };
+        let existed = conn
+            .prepare("SELECT id FROM memory_sections_fts LIMIT 0")
+            .is_ok();
+        conn.execute(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS memory_sections_fts USING fts5(
+                id UNINDEXED,
+                section UNINDEXED,
+                body
+            )",
+            [],
+        )
+        .map_err(failed)?;
+        if existed {
+            return Ok(());
+        }
+
+        // Structured content starts with front matter
+        let rows: Vec<(String, String)> = conn
+            .prepare("SELECT id, content FROM memories_fts WHERE content LIKE '---%'")
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect()
+            })
+            .map_err(failed)?;
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        conn.execute("BEGIN IMMEDIATE", []).map_err(failed)?;
+        let result = rows
+            .iter()
+            .try_for_each(|(id, content)| index_sections(conn, id, content));
+        match result.and_then(|()| conn.execute("COMMIT", []).map(|_| ())) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                let _ = conn.execute("ROLLBACK", []);
+                Err(failed(e))
+            },
+        }
+    }
+
+    /// Hashes the content of rows indexed before the `content_hash` column.
+    fn backfill_content_hashes(conn: &Connection) -> Result<()> {
+        let failed = |e: rusqlite::Error| Error::OperationFailed {
+            operation: "backfill_content_hashes".to_string(),
+            cause: e.to_string(),
+        };
+        let rows: Vec<(String, String)> = conn
+            .prepare(
+                "SELECT m.id, f.content FROM memories m
+                 JOIN memories_fts f ON f.id = m.id
+                 WHERE m.content_hash IS NULL",
+            )
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                    .collect()
+            })
+            .map_err(failed)?;
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        conn.execute("BEGIN IMMEDIATE", []).map_err(failed)?;
+        let result = rows.iter().try_for_each(|(id, content)| {
+            conn.execute(
+                "UPDATE memories SET content_hash = ?1 WHERE id = ?2",
+                params![ContentHasher::hash(content), id],
+            )
+            .map(|_| ())
+        });
+        match result.and_then(|()| conn.execute("COMMIT", []).map(|_| ())) {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                let _ = conn.execute("ROLLBACK", []);
+                Err(failed(e))
+            },
+        }
+    }
+
+    /// Returns the oldest live memory in `namespace` whose normalized content
+    /// hashes to `content_hash`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn find_by_content_hash(
+        &self,
+        namespace: Namespace,
+        content_hash: &str,
+    ) -> Result<Option<MemoryId>> {
+        let conn = self.lock_conn()?;
+        conn.query_row(
+            "SELECT id FROM memories
+             WHERE namespace = ?1 AND content_hash = ?2 AND status != 'tombstoned'
+             ORDER BY created_at, id LIMIT 1",
+            params![namespace.as_str(), content_hash],
+            |row| row.get::<_, String>(0),
+        )
+        .optional()
+        .map(|id| id.map(MemoryId::new))
+        .map_err(|e| Error::OperationFailed {
+            operation: "find_by_content_hash".to_string(),
+            cause: e.to_string(),
+        })
+    }
+
+    /// Returns FTS5 excerpts of the given memories around their matches for
+    /// `query`, with each matched term wrapped in `marker`.
+    ///
+    /// Memories whose content doesn't match (only their tags or source did)
+    /// are left out, as are all memories for an empty query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn snippets(
+        &self,
+        query: &str,
+        ids: &[MemoryId],
+        marker: &str,
+    ) -> Result<Vec<(MemoryId, String)>> {
+        if query.trim().is_empty() || ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let map_err = |e: rusqlite::Error| Error::OperationFailed {
+            operation: "fts_snippets".to_string(),
+            cause: e.to_string(),
+        };
+
+        let placeholders: Vec<String> = (3..ids.len() + 3).map(|i| format!("?{i}")).collect();
+        let sql = format!(
+            "SELECT id, snippet(memories_fts, 1, ?1, ?1, '…', {SNIPPET_TOKENS})
+             FROM memories_fts
+             WHERE memories_fts MATCH ?2 AND id IN ({})",
+            placeholders.join(", ")
+        );
+        let conn = self.lock_conn()?;
+        let mut stmt = conn.prepare(&sql).map_err(map_err)?;
+        let params = [marker.to_string(), fts_match_query(query)]
+            .into_iter()
+            .chain(ids.iter().map(|id| id.as_str().to_string()));
+        let rows = stmt
+            .query_map(rusqlite::params_from_iter(params), |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(map_err)?;
+
+        let mut snippets = Vec::new();
+        for row in rows {
+            let (id, snippet) = row.map_err(map_err)?;
+            // A snippet without the marker comes from a tags/source match
+            if marker.is_empty() || snippet.contains(marker) {
+                snippets.push((MemoryId::new(id), snippet));
+            }
+        }
+        Ok(snippets)
+    }
+
     /// Creates indexes for optimized queries.
     fn create_indexes(conn: &Connection) {
         // Index on namespace for filtered searches
@@ -357,6 +788,27 @@ fn create_indexes(conn: &Connection) {
             [],
         );
 
+        // Index on updated_at for recently-edited queries
+        let _ = conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_memories_updated_at ON memories(updated_at DESC)",
+            [],
+        );
+
+        // One live memory per content in a namespace (exact-match deduplication).
+        // Replaces the plain index of older databases; one that already holds
+        // duplicates keeps the plain index instead
+        let _ = conn.execute("DROP INDEX IF EXISTS idx_memories_content_hash", []);
+        if let Err(e) = conn.execute(
+            "CREATE UNIQUE INDEX IF NOT EXISTS idx_memories_live_content_hash ON memories(namespace, content_hash) WHERE status != 'tombstoned'",
+            [],
+        ) {
+            tracing::warn!(error = %e, "Index holds duplicate memories, content is not kept unique");
+            let _ = conn.execute(
+                "CREATE INDEX IF NOT EXISTS idx_memories_content_hash ON memories(namespace, content_hash)",
+                [],
+            );
+        }
+
         // Partial index on tombstoned_at for cleanup queries
         let _ = conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_memories_tombstoned_at ON memories(tombstoned_at) WHERE tombstoned_at IS NOT NULL",
@@ -388,6 +840,18 @@ fn create_indexes(conn: &Connection) {
             [],
         );
 
+        // Index on origin for capture-source filtering
+        let _ = conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_memories_origin ON memories(origin)",
+            [],
+        );
+
+        // Index on confidence for pruning and confidence filtering
+        let _ = conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_memories_confidence ON memories(confidence)",
+            [],
+        );
+
         // Facet indexes (ADR-0049)
         let _ = conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_memories_project_id ON memories(project_id)",
@@ -401,6 +865,14 @@ fn create_indexes(conn: &Connection) {
             "CREATE INDEX IF NOT EXISTS idx_memories_file_path ON memories(file_path)",
             [],
         );
+        let _ = conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_memories_commit_sha ON memories(commit_sha)",
+            [],
+        );
+        let _ = conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_memories_language ON memories(language)",
+            [],
+        );
 
         // Partial index for tombstoned memories (ADR-0053)
         let _ = conn.execute(
@@ -408,7 +880,17 @@ fn create_indexes(conn: &Connection) {
             [],
         );
 
-        // Memory edges indexes for consolidation service
+        Self::create_edge_indexes(conn);
+
+        // Index for loading the feedback of search hits
+        let _ = conn.execute(
+            "CREATE INDEX IF NOT EXISTS idx_memory_feedback_memory_id ON memory_feedback(memory_id)",
+            [],
+        );
+    }
+
+    /// Creates the memory edge indexes used by the consolidation service.
+    fn create_edge_indexes(conn: &Connection) {
         // Index on from_id for finding edges from a memory
         let _ = conn.execute(
             "CREATE INDEX IF NOT EXISTS idx_memory_edges_from_id ON memory_edges(from_id)",
@@ -451,73 +933,55 @@ fn build_filter_clause_numbered(
         let mut params = Vec::new();
         let mut param_idx = start_param;
 
-        if !filter.namespaces.is_empty() {
-            let placeholders: Vec<String> = filter
+        push_in_condition(
+            "m.namespace",
+            filter
                 .namespaces
                 .iter()
-                .map(|_| {
-                    let p = format!("?{param_idx}");
-                    param_idx += 1;
-                    p
-                })
-                .collect();
-            conditions.push(format!("m.namespace IN ({})", placeholders.join(",")));
-            for ns in &filter.namespaces {
-                params.push(ns.as_str().to_string());
-            }
-        }
+                .map(|ns| ns.as_str().to_string())
+                .collect(),
+            &mut conditions,
+            &mut params,
+            &mut param_idx,
+        );
 
-        if !filter.statuses.is_empty() {
-            let placeholders: Vec<String> = filter
+        push_in_condition(
+            "m.status",
+            filter
                 .statuses
                 .iter()
-                .map(|_| {
-                    let p = format!("?{param_idx}");
-                    param_idx += 1;
-                    p
-                })
-                .collect();
-            conditions.push(format!("m.status IN ({})", placeholders.join(",")));
-            for s in &filter.statuses {
-                params.push(s.as_str().to_string());
-            }
-        }
+                .map(|s| s.as_str().to_string())
+                .collect(),
+            &mut conditions,
+            &mut params,
+            &mut param_idx,
+        );
 
-        // Tag filtering (AND logic - must have ALL tags)
-        // Use ',tag,' pattern with wrapped column to match whole tags only
-        // Escape LIKE wildcards in tags to prevent SQL injection (SEC-M4)
-        for tag in &filter.tags {
-            conditions.push(format!(
-                "(',' || m.tags || ',') LIKE ?{param_idx} ESCAPE '\\'"
-            ));
-            param_idx += 1;
-            params.push(format!("%,{},%", escape_like_wildcards(tag)));
-        }
+        push_in_condition(
+            "m.origin",
+            filter
+                .origins
+                .iter()
+                .map(|origin| origin.as_str().to_string())
+                .collect(),
+            &mut conditions,
+            &mut params,
+            &mut param_idx,
+        );
 
-        // Tag filtering (OR logic - must have ANY tag)
-        if !filter.tags_any.is_empty() {
-            let or_conditions: Vec<String> = filter
-                .tags_any
+        push_in_condition(
+            "m.id",
+            filter
+                .memory_ids
                 .iter()
-                .map(|tag| {
-                    let cond = format!("(',' || m.tags || ',') LIKE ?{param_idx} ESCAPE '\\'");
-                    param_idx += 1;
-                    params.push(format!("%,{},%", escape_like_wildcards(tag)));
-                    cond
-                })
-                .collect();
-            conditions.push(format!("({})", or_conditions.join(" OR ")));
-        }
+                .map(|id| id.as_str().to_string())
+                .collect(),
+            &mut conditions,
+            &mut params,
+            &mut param_idx,
+        );
 
-        // Excluded tags (NOT LIKE) - match whole tags only
-        // Escape LIKE wildcards (SEC-M4)
-        for tag in &filter.excluded_tags {
-            conditions.push(format!(
-                "(',' || m.tags || ',') NOT LIKE ?{param_idx} ESCAPE '\\'"
-            ));
-            param_idx += 1;
-            params.push(format!("%,{},%", escape_like_wildcards(tag)));
-        }
+        push_tag_conditions(filter, &mut conditions, &mut params, &mut param_idx);
 
         // Source pattern (glob-style converted to SQL LIKE)
         // HIGH-SEC-005: Use glob_to_like_pattern to escape SQL wildcards before conversion
@@ -527,34 +991,39 @@ fn build_filter_clause_numbered(
             params.push(glob_to_like_pattern(pattern));
         }
 
-        if let Some(ref project_id) = filter.project_id {
-            conditions.push(format!("m.project_id = ?{param_idx}"));
-            param_idx += 1;
-            params.push(project_id.clone());
+        for (column, value) in [
+            ("m.project_id", &filter.project_id),
+            ("m.branch", &filter.branch),
+            ("m.file_path", &filter.file_path),
+            ("m.language", &filter.language),
+        ] {
+            if let Some(value) = value {
+                conditions.push(format!("{column} = ?{param_idx}"));
+                param_idx += 1;
+                params.push(value.clone());
+            }
         }
 
-        if let Some(ref branch) = filter.branch {
-            conditions.push(format!("m.branch = ?{param_idx}"));
+        if let Some(ref commit) = filter.commit {
+            // Imported memories may carry upper- or mixed-case SHAs
+            conditions.push(format!("lower(m.commit_sha) LIKE ?{param_idx} ESCAPE '\\'"));
             param_idx += 1;
-            params.push(branch.clone());
+            params.push(format!(
+                "{}%",
+                escape_like_wildcards(&commit.to_lowercase())
+            ));
         }
 
-        if let Some(ref file_path) = filter.file_path {
-            conditions.push(format!("m.file_path = ?{param_idx}"));
-            param_idx += 1;
-            params.push(file_path.clone());
-        }
+        push_time_conditions(filter, &mut conditions, &mut params, &mut param_idx);
 
-        if let Some(after) = filter.created_after {
-            conditions.push(format!("m.created_at >= ?{param_idx}"));
+        if let Some(min_confidence) = filter.min_confidence {
+            conditions.push(format!("m.confidence >= ?{param_idx}"));
             param_idx += 1;
-            params.push(after.to_string());
+            params.push(min_confidence.to_string());
         }
 
-        if let Some(before) = filter.created_before {
-            conditions.push(format!("m.created_at <= ?{param_idx}"));
-            param_idx += 1;
-            params.push(before.to_string());
+        if filter.pinned_only {
+            conditions.push("m.pinned = 1".to_string());
         }
 
         // Exclude tombstoned memories by default (ADR-0053)
@@ -614,7 +1083,7 @@ fn record_operation_metrics(
     #[instrument(skip(self), fields(operation = "checkpoint", backend = "sqlite"))]
     pub fn checkpoint(&self) -> Result<(u32, u32)> {
         let start = Instant::now();
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         // PRAGMA wal_checkpoint(TRUNCATE) checkpoints and truncates the WAL file
         // Returns: (busy, log_pages, checkpointed_pages)
@@ -680,7 +1149,7 @@ pub fn checkpoint(&self) -> Result<(u32, u32)> {
     /// Returns an error if the query fails.
     #[must_use]
     pub fn wal_size(&self) -> Option<u32> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn().ok()?;
 
         // PRAGMA wal_checkpoint(PASSIVE) returns current state without blocking
         let result: std::result::Result<(i32, i32, i32), _> =
@@ -769,7 +1238,7 @@ pub fn store_edge(
         to_id: &MemoryId,
         edge_type: crate::models::EdgeType,
     ) -> Result<()> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let created_at = crate::current_timestamp();
         #[allow(clippy::cast_possible_wrap)]
@@ -841,7 +1310,7 @@ pub fn query_edges(
         from_id: &MemoryId,
         edge_type: crate::models::EdgeType,
     ) -> Result<Vec<MemoryId>> {
-        let conn = acquire_lock(&self.conn);
+        let conn = self.lock_conn()?;
 
         let mut stmt = conn
             .prepare("SELECT to_id FROM memory_edges WHERE from_id = ?1 AND edge_type = ?2")
@@ -866,18 +1335,249 @@ pub fn query_edges(
 
         Ok(results.into_iter().map(MemoryId::new).collect())
     }
+
+    /// Queries edges into a given memory ID by edge type.
+    ///
+    /// Returns the source memory IDs of every edge of `edge_type` that
+    /// points at `to_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the database query fails.
+    pub fn query_incoming_edges(
+        &self,
+        to_id: &MemoryId,
+        edge_type: crate::models::EdgeType,
+    ) -> Result<Vec<MemoryId>> {
+        let conn = self.lock_conn()?;
+
+        let mut stmt = conn
+            .prepare("SELECT from_id FROM memory_edges WHERE to_id = ?1 AND edge_type = ?2")
+            .map_err(|e| Error::OperationFailed {
+                operation: "query_incoming_edges_prepare".to_string(),
+                cause: e.to_string(),
+            })?;
+
+        let results = stmt
+            .query_map(params![to_id.as_str(), edge_type.as_str()], |row| {
+                row.get::<_, String>(0)
+            })
+            .map_err(|e| Error::OperationFailed {
+                operation: "query_incoming_edges_map".to_string(),
+                cause: e.to_string(),
+            })?
+            .collect::<std::result::Result<Vec<String>, _>>()
+            .map_err(|e| Error::OperationFailed {
+                operation: "query_incoming_edges_collect".to_string(),
+                cause: e.to_string(),
+            })?;
+
+        Ok(results.into_iter().map(MemoryId::new).collect())
+    }
+
+    /// Records a relevance vote for a memory on a (normalized) query.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vote cannot be stored.
+    pub fn record_feedback(&self, query: &str, memory_id: &MemoryId, useful: bool) -> Result<()> {
+        let conn = self.lock_conn()?;
+        #[allow(clippy::cast_possible_wrap)]
+        let now = crate::current_timestamp() as i64;
+        let (up, down) = if useful { (1, 0) } else { (0, 1) };
+
+        conn.execute(
+            "INSERT INTO memory_feedback (query, memory_id, up, down, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5)
+             ON CONFLICT(query, memory_id) DO UPDATE SET
+                up = up + ?3, down = down + ?4, updated_at = ?5",
+            params![query, memory_id.as_str(), up, down, now],
+        )
+        .map_err(|e| Error::OperationFailed {
+            operation: "record_feedback".to_string(),
+            cause: e.to_string(),
+        })?;
+        Ok(())
+    }
+
+    /// Returns the relevance votes recorded for the given memories.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn feedback_for(&self, memory_ids: &[MemoryId]) -> Result<Vec<RelevanceFeedback>> {
+        if memory_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let conn = self.lock_conn()?;
+        let placeholders: Vec<String> = (1..=memory_ids.len()).map(|i| format!("?{i}")).collect();
+        let sql = format!(
+            "SELECT memory_id, query, up, down FROM memory_feedback WHERE memory_id IN ({})",
+            placeholders.join(", ")
+        );
+
+        let mut stmt = conn.prepare(&sql).map_err(|e| Error::OperationFailed {
+            operation: "feedback_for_prepare".to_string(),
+            cause: e.to_string(),
+        })?;
+        stmt.query_map(
+            rusqlite::params_from_iter(memory_ids.iter().map(MemoryId::as_str)),
+            |row| {
+                Ok(RelevanceFeedback {
+                    memory_id: MemoryId::new(row.get::<_, String>(0)?),
+                    query: row.get(1)?,
+                    up: row.get(2)?,
+                    down: row.get(3)?,
+                })
+            },
+        )
+        .and_then(Iterator::collect)
+        .map_err(|e| Error::OperationFailed {
+            operation: "feedback_for".to_string(),
+            cause: e.to_string(),
+        })
+    }
+
+    /// Fetches memories by id in one query, in the order of `ids`.
+    ///
+    /// Without `with_content`, the FTS table is not read and content is empty.
+    fn fetch_memories_batch(
+        &self,
+        ids: &[MemoryId],
+        with_content: bool,
+    ) -> Result<Vec<Option<Memory>>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (content, join) = if with_content {
+            ("f.content", "JOIN memories_fts f ON m.id = f.id")
+        } else {
+            ("'' AS content", "")
+        };
+
+        let conn = self.lock_conn()?;
+
+        // Build placeholders for IN clause
+        let placeholders: Vec<String> = (1..=ids.len()).map(|i| format!("?{i}")).collect();
+
+        #[cfg(feature = "group-scope")]
+        let sql = format!(
+            "SELECT m.id, m.namespace, m.domain, m.project_id, m.branch, m.file_path, m.status, m.created_at,
+                    m.tombstoned_at, m.expires_at, m.tags, m.source, {content}, m.is_summary, m.source_memory_ids, m.consolidation_timestamp, m.origin, m.confidence, m.attachments, m.content_type, m.pinned, m.commit_sha, m.updated_at, m.language, m.group_id
+             FROM memories m
+             {join}
+             WHERE m.id IN ({})",
+            placeholders.join(", ")
+        );
+        #[cfg(not(feature = "group-scope"))]
+        let sql = format!(
+            "SELECT m.id, m.namespace, m.domain, m.project_id, m.branch, m.file_path, m.status, m.created_at,
+                    m.tombstoned_at, m.expires_at, m.tags, m.source, {content}, m.is_summary, m.source_memory_ids, m.consolidation_timestamp, m.origin, m.confidence, m.attachments, m.content_type, m.pinned, m.commit_sha, m.updated_at, m.language
+             FROM memories m
+             {join}
+             WHERE m.id IN ({})",
+            placeholders.join(", ")
+        );
+
+        let mut stmt = conn.prepare(&sql).map_err(|e| Error::OperationFailed {
+            operation: "prepare_get_memories_batch".to_string(),
+            cause: e.to_string(),
+        })?;
+
+        // Collect results into a HashMap for O(1) lookup
+        let id_strs: Vec<&str> = ids.iter().map(MemoryId::as_str).collect();
+        let mut memory_map: std::collections::HashMap<String, Memory> =
+            std::collections::HashMap::with_capacity(ids.len());
+
+        let rows = stmt
+            .query_map(rusqlite::params_from_iter(id_strs.iter()), |row| {
+                Ok(MemoryRow {
+                    id: row.get(0)?,
+                    namespace: row.get(1)?,
+                    domain: row.get(2)?,
+                    project_id: row.get(3)?,
+                    branch: row.get(4)?,
+                    file_path: row.get(5)?,
+                    status: row.get(6)?,
+                    created_at: row.get(7)?,
+                    tombstoned_at: row.get(8)?,
+                    expires_at: row.get(9)?,
+                    tags: row.get(10)?,
+                    source: row.get(11)?,
+                    content: row.get(12)?,
+                    is_summary: row.get::<_, Option<bool>>(13)?.unwrap_or(false),
+                    source_memory_ids: row.get(14)?,
+                    consolidation_timestamp: row.get(15)?,
+                    origin: row.get(16)?,
+                    confidence: row.get(17)?,
+                    attachments: row.get(18)?,
+                    content_type: row.get(19)?,
+                    pinned: row.get(20)?,
+                    commit_sha: row.get(21)?,
+                    updated_at: row.get(22)?,
+                    language: row.get(23)?,
+                    #[cfg(feature = "group-scope")]
+                    group_id: row.get(24)?,
+                })
+            })
+            .map_err(|e| Error::OperationFailed {
+                operation: "execute_get_memories_batch".to_string(),
+                cause: e.to_string(),
+            })?;
+
+        for row in rows {
+            let memory_row = row.map_err(|e| Error::OperationFailed {
+                operation: "read_batch_row".to_string(),
+                cause: e.to_string(),
+            })?;
+            let id = memory_row.id.clone();
+            memory_map.insert(id, build_memory_from_row(memory_row));
+        }
+
+        // Return memories in the same order as input IDs
+        Ok(ids
+            .iter()
+            .map(|id| memory_map.remove(id.as_str()))
+            .collect())
+    }
+
+    /// Returns the number of indexed memories and the latest update time.
+    ///
+    /// Used to detect whether caches derived from the indexed memories, such
+    /// as the embedding cache, are stale, without scanning their content.
+    /// Rows indexed before `updated_at` existed count their creation time.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    pub fn change_stamp(&self) -> Result<(u64, u64)> {
+        let conn = self.lock_conn()?;
+        conn.query_row(
+            "SELECT COUNT(*), COALESCE(MAX(COALESCE(updated_at, created_at)), 0) FROM memories",
+            [],
+            |row| {
+                let count: i64 = row.get(0)?;
+                let last_updated: i64 = row.get(1)?;
+                Ok((count.cast_unsigned(), last_updated.cast_unsigned()))
+            },
+        )
+        .map_err(|e| Error::OperationFailed {
+            operation: "change_stamp".to_string(),
+            cause: e.to_string(),
+        })
+    }
 }
 
 fn fetch_memory_row(conn: &Connection, id: &MemoryId) -> Result<Option<MemoryRow>> {
     #[cfg(feature = "group-scope")]
     let query = "SELECT m.id, m.namespace, m.domain, m.project_id, m.branch, m.file_path, m.status, m.created_at,
-                    m.tombstoned_at, m.expires_at, m.tags, m.source, f.content, m.is_summary, m.source_memory_ids, m.consolidation_timestamp, m.group_id
+                    m.tombstoned_at, m.expires_at, m.tags, m.source, f.content, m.is_summary, m.source_memory_ids, m.consolidation_timestamp, m.origin, m.confidence, m.attachments, m.content_type, m.pinned, m.commit_sha, m.updated_at, m.language, m.group_id
              FROM memories m
              JOIN memories_fts f ON m.id = f.id
              WHERE m.id = ?1";
     #[cfg(not(feature = "group-scope"))]
     let query = "SELECT m.id, m.namespace, m.domain, m.project_id, m.branch, m.file_path, m.status, m.created_at,
-                    m.tombstoned_at, m.expires_at, m.tags, m.source, f.content, m.is_summary, m.source_memory_ids, m.consolidation_timestamp
+                    m.tombstoned_at, m.expires_at, m.tags, m.source, f.content, m.is_summary, m.source_memory_ids, m.consolidation_timestamp, m.origin, m.confidence, m.attachments, m.content_type, m.pinned, m.commit_sha, m.updated_at, m.language
              FROM memories m
              JOIN memories_fts f ON m.id = f.id
              WHERE m.id = ?1";
@@ -906,8 +1606,16 @@ fn fetch_memory_row(conn: &Connection, id: &MemoryId) -> Result<Option<MemoryRow
                 is_summary: row.get::<_, Option<bool>>(13)?.unwrap_or(false),
                 source_memory_ids: row.get(14)?,
                 consolidation_timestamp: row.get(15)?,
+                origin: row.get(16)?,
+                confidence: row.get(17)?,
+                attachments: row.get(18)?,
+                content_type: row.get(19)?,
+                pinned: row.get(20)?,
+                commit_sha: row.get(21)?,
+                updated_at: row.get(22)?,
+                language: row.get(23)?,
                 #[cfg(feature = "group-scope")]
-                group_id: row.get(16)?,
+                group_id: row.get(24)?,
             })
         })
         .optional();
@@ -919,12 +1627,14 @@ fn fetch_memory_row(conn: &Connection, id: &MemoryId) -> Result<Option<MemoryRow
 }
 
 fn build_memory_from_row(row: MemoryRow) -> Memory {
-    use crate::models::{Domain, MemoryStatus, Namespace};
+    use crate::models::Domain;
 
     let namespace = Namespace::parse(&row.namespace).unwrap_or_default();
     let domain = row.domain.map_or_else(Domain::new, |d: String| {
         if d.is_empty() || d == "project" {
             Domain::new()
+        } else if d == "user" {
+            Domain::for_user()
         } else {
             let parts: Vec<&str> = d.split('/').collect();
             match parts.len() {
@@ -982,7 +1692,8 @@ fn build_memory_from_row(row: MemoryRow) -> Memory {
         file_path: row.file_path,
         status,
         created_at: created_at_u64,
-        updated_at: created_at_u64,
+        #[allow(clippy::cast_sign_loss)]
+        updated_at: row.updated_at.map_or(created_at_u64, |t| t as u64),
         tombstoned_at,
         expires_at,
         embedding: None,
@@ -1000,6 +1711,135 @@ fn build_memory_from_row(row: MemoryRow) -> Memory {
         }),
         #[allow(clippy::cast_sign_loss)]
         consolidation_timestamp: row.consolidation_timestamp.map(|t| t as u64),
+        origin: row
+            .origin
+            .as_deref()
+            .and_then(CaptureOrigin::parse)
+            .unwrap_or_default(),
+        #[allow(clippy::cast_possible_truncation)]
+        confidence: row.confidence.map(|c| c as f32),
+        attachments: row
+            .attachments
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default(),
+        content_type: row.content_type.as_deref().and_then(ContentType::parse),
+        pinned: row.pinned,
+        commit: row.commit_sha,
+        language: row.language,
+    }
+}
+
+/// Returns a memory's content type for storage, or `None` when unknown.
+fn content_type_str(memory: &Memory) -> Option<String> {
+    memory.content_type.as_ref().map(ToString::to_string)
+}
+
+/// Returns the identifier words indexed alongside code content.
+fn code_terms(memory: &Memory) -> String {
+    memory
+        .content_type
+        .as_ref()
+        .map(|content_type| content_type.search_terms(&memory.content))
+        .unwrap_or_default()
+}
+
+/// Replaces the indexed sections of memory `id` with those of `content`.
+///
+/// A section that appears more than once is indexed as one body, so each
+/// memory has at most one row per section.
+fn index_sections(conn: &Connection, id: &str, content: &str) -> rusqlite::Result<()> {
+    conn.execute("DELETE FROM memory_sections_fts WHERE id = ?1", params![id])?;
+    let mut bodies: Vec<(String, String)> = Vec::new();
+    for section in parse_sections(content) {
+        match bodies.iter_mut().find(|(name, _)| *name == section.name) {
+            Some((_, body)) => {
+                body.push_str("\n\n");
+                body.push_str(&section.content);
+            },
+            None => bodies.push((section.name, section.content)),
+        }
+    }
+    for (section, body) in bodies {
+        conn.execute(
+            "INSERT INTO memory_sections_fts (id, section, body) VALUES (?1, ?2, ?3)",
+            params![id, section, body],
+        )?;
+    }
+    Ok(())
+}
+
+/// Returns the ID of another live memory in `memory`'s namespace whose
+/// content hashes to `content_hash`.
+fn live_duplicate(
+    conn: &Connection,
+    memory: &Memory,
+    content_hash: &str,
+) -> Result<Option<MemoryId>> {
+    if memory.status == MemoryStatus::Tombstoned {
+        return Ok(None);
+    }
+    conn.query_row(
+        "SELECT id FROM memories
+         WHERE namespace = ?1 AND content_hash = ?2 AND status != 'tombstoned' AND id != ?3
+         LIMIT 1",
+        params![memory.namespace.as_str(), content_hash, memory.id.as_str()],
+        |row| row.get::<_, String>(0),
+    )
+    .optional()
+    .map(|id| id.map(MemoryId::new))
+    .map_err(|e| Error::OperationFailed {
+        operation: "find_live_duplicate".to_string(),
+        cause: e.to_string(),
+    })
+}
+
+/// Fails if another live memory in `memory`'s namespace has the same content.
+///
+/// `INSERT OR REPLACE` resolves a conflict on the unique content hash index
+/// by deleting the other memory's row, so conflicts are rejected up front,
+/// inside the write transaction that holds the database lock.
+fn ensure_unique_content(conn: &Connection, memory: &Memory, content_hash: &str) -> Result<()> {
+    live_duplicate(conn, memory, content_hash)?.map_or(Ok(()), |existing| {
+        Err(Error::OperationFailed {
+            operation: "index_memory".to_string(),
+            cause: format!(
+                "memory {existing} in namespace {} has the same content",
+                memory.namespace.as_str()
+            ),
+        })
+    })
+}
+
+/// Drops memories whose content duplicates a live memory in the same
+/// namespace, either already indexed or earlier in `memories`.
+fn without_content_duplicates<'a>(
+    conn: &Connection,
+    memories: &'a [Memory],
+) -> Result<Vec<&'a Memory>> {
+    let mut seen: HashSet<(Namespace, String)> = HashSet::new();
+    let mut kept = Vec::with_capacity(memories.len());
+    for memory in memories {
+        let content_hash = ContentHasher::hash(&memory.content);
+        let live = memory.status != MemoryStatus::Tombstoned;
+        let duplicate = live
+            && (!seen.insert((memory.namespace, content_hash.clone()))
+                || live_duplicate(conn, memory, &content_hash)?.is_some());
+        if duplicate {
+            tracing::warn!(memory_id = %memory.id, "Skipping memory with duplicate content");
+            continue;
+        }
+        kept.push(memory);
+    }
+    Ok(kept)
+}
+
+/// Serializes a memory's attachment references, or `None` when it has none.
+fn attachments_json(memory: &Memory) -> Option<String> {
+    if memory.attachments.is_empty() {
+        None
+    } else {
+        serde_json::to_string(&memory.attachments).ok()
     }
 }
 
@@ -1018,7 +1858,7 @@ impl IndexBackend for SqliteBackend {
     fn index(&self, memory: &Memory) -> Result<()> {
         let start = Instant::now();
         let result = (|| {
-            let conn = acquire_lock(&self.conn);
+            let conn = self.lock_conn()?;
 
             let tags_str = memory.tags.join(",");
             let domain_str = memory.domain.to_string();
@@ -1035,6 +1875,7 @@ fn index(&self, memory: &Memory) -> Result<()> {
                 // Note: Cast u64 to i64 for SQLite compatibility (rusqlite doesn't impl ToSql for u64)
                 #[allow(clippy::cast_possible_wrap)]
                 let created_at_i64 = memory.created_at as i64;
+                let updated_at_i64 = memory.updated_at.cast_signed();
                 let tombstoned_at_i64 = memory.tombstoned_at.map(|t| t.timestamp());
                 #[allow(clippy::cast_possible_wrap)]
                 let consolidation_ts_i64 = memory.consolidation_timestamp.map(|t| t as i64);
@@ -1043,12 +1884,14 @@ fn index(&self, memory: &Memory) -> Result<()> {
                     .as_ref()
                     .map(|ids| serde_json::to_string(ids).unwrap_or_default());
                 let expires_at_i64 = memory.expires_at.map(u64::cast_signed);
+                let content_hash = ContentHasher::hash(&memory.content);
+                ensure_unique_content(&conn, memory, &content_hash)?;
                 #[cfg(feature = "group-scope")]
                 let group_id = memory.group_id.as_deref();
                 #[cfg(feature = "group-scope")]
                 conn.execute(
-                    "INSERT OR REPLACE INTO memories (id, namespace, domain, project_id, branch, file_path, status, created_at, tags, source, tombstoned_at, expires_at, is_summary, source_memory_ids, consolidation_timestamp, group_id)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)",
+                    "INSERT OR REPLACE INTO memories (id, namespace, domain, project_id, branch, file_path, status, created_at, tags, source, tombstoned_at, expires_at, is_summary, source_memory_ids, consolidation_timestamp, origin, confidence, attachments, content_type, pinned, commit_sha, updated_at, group_id, content_hash, language)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24, ?25)",
                     params![
                         memory.id.as_str(),
                         memory.namespace.as_str(),
@@ -1065,7 +1908,16 @@ fn index(&self, memory: &Memory) -> Result<()> {
                         memory.is_summary,
                         source_ids_json,
                         consolidation_ts_i64,
-                        group_id
+                        memory.origin.as_str(),
+                        memory.confidence.map(f64::from),
+                        attachments_json(memory),
+                        content_type_str(memory),
+                        memory.pinned,
+                        memory.commit.as_deref(),
+                        updated_at_i64,
+                        group_id,
+                        content_hash,
+                        memory.language.as_deref()
                     ],
                 )
                 .map_err(|e| Error::OperationFailed {
@@ -1074,8 +1926,8 @@ fn index(&self, memory: &Memory) -> Result<()> {
                 })?;
                 #[cfg(not(feature = "group-scope"))]
                 conn.execute(
-                    "INSERT OR REPLACE INTO memories (id, namespace, domain, project_id, branch, file_path, status, created_at, tags, source, tombstoned_at, expires_at, is_summary, source_memory_ids, consolidation_timestamp)
-                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+                    "INSERT OR REPLACE INTO memories (id, namespace, domain, project_id, branch, file_path, status, created_at, tags, source, tombstoned_at, expires_at, is_summary, source_memory_ids, consolidation_timestamp, origin, confidence, attachments, content_type, pinned, commit_sha, updated_at, content_hash, language)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24)",
                     params![
                         memory.id.as_str(),
                         memory.namespace.as_str(),
@@ -1091,7 +1943,16 @@ fn index(&self, memory: &Memory) -> Result<()> {
                         expires_at_i64,
                         memory.is_summary,
                         source_ids_json,
-                        consolidation_ts_i64
+                        consolidation_ts_i64,
+                        memory.origin.as_str(),
+                        memory.confidence.map(f64::from),
+                        attachments_json(memory),
+                        content_type_str(memory),
+                        memory.pinned,
+                        memory.commit.as_deref(),
+                        updated_at_i64,
+                        content_hash,
+                        memory.language.as_deref()
                     ],
                 )
                 .map_err(|e| Error::OperationFailed {
@@ -1111,14 +1972,27 @@ fn index(&self, memory: &Memory) -> Result<()> {
 
                 // Insert into FTS table
                 conn.execute(
-                    "INSERT INTO memories_fts (id, content, tags) VALUES (?1, ?2, ?3)",
-                    params![memory.id.as_str(), memory.content, tags_str],
+                    "INSERT INTO memories_fts (id, content, tags, source, code_terms) VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        memory.id.as_str(),
+                        memory.content,
+                        tags_str,
+                        memory.source.as_deref(),
+                        code_terms(memory)
+                    ],
                 )
                 .map_err(|e| Error::OperationFailed {
                     operation: "insert_fts".to_string(),
                     cause: e.to_string(),
                 })?;
 
+                index_sections(&conn, memory.id.as_str(), &memory.content).map_err(|e| {
+                    Error::OperationFailed {
+                        operation: "index_sections".to_string(),
+                        cause: e.to_string(),
+                    }
+                })?;
+
                 Ok(())
             })();
 
@@ -1144,7 +2018,7 @@ fn index(&self, memory: &Memory) -> Result<()> {
     fn remove(&self, id: &MemoryId) -> Result<bool> {
         let start = Instant::now();
         let result = (|| {
-            let conn = acquire_lock(&self.conn);
+            let conn = self.lock_conn()?;
 
             // Use transaction for atomicity (DB-H2)
             conn.execute("BEGIN IMMEDIATE", [])
@@ -1163,6 +2037,14 @@ fn remove(&self, id: &MemoryId) -> Result<bool> {
                     operation: "delete_fts".to_string(),
                     cause: e.to_string(),
                 })?;
+                conn.execute(
+                    "DELETE FROM memory_sections_fts WHERE id = ?1",
+                    params![id.as_str()],
+                )
+                .map_err(|e| Error::OperationFailed {
+                    operation: "delete_sections".to_string(),
+                    cause: e.to_string(),
+                })?;
 
                 // Delete from main table
                 let deleted = conn
@@ -1193,71 +2075,76 @@ fn remove(&self, id: &MemoryId) -> Result<bool> {
         result
     }
 
+    fn search(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+        limit: usize,
+    ) -> Result<Vec<(MemoryId, f32)>> {
+        self.search_weighted(query, filter, &FieldWeights::default(), limit)
+    }
+
     #[instrument(
-        skip(self, query, filter),
+        skip(self, query, filter, weights),
         fields(operation = "search", backend = "sqlite", query_length = query.len(), limit = limit)
     )]
-    fn search(
+    fn search_weighted(
         &self,
         query: &str,
         filter: &SearchFilter,
+        weights: &FieldWeights,
         limit: usize,
     ) -> Result<Vec<(MemoryId, f32)>> {
         let start = Instant::now();
         let result = (|| {
-            let conn = acquire_lock(&self.conn);
+            let conn = self.lock_conn()?;
 
             // Build filter clause with numbered parameters starting from ?2
             // ?1 is the FTS query
             let (filter_clause, filter_params, next_param) =
                 self.build_filter_clause_numbered(filter, 2);
 
-            // Use FTS5 MATCH for search with BM25 ranking
-            // Limit parameter comes after all filter parameters
-            let sql = format!(
-                "SELECT f.id, bm25(memories_fts) as score
-                 FROM memories_fts f
-                 JOIN memories m ON f.id = m.id
-                 WHERE memories_fts MATCH ?1 {filter_clause}
-                 ORDER BY score
-                 LIMIT ?{next_param}"
-            );
+            // Use FTS5 MATCH for search with BM25 ranking, weighting each column
+            // (id is never ranked). Limit parameter comes after all filter parameters
+            let weights = weights.sanitized();
+            let (content_weight, tags_weight, source_weight) =
+                (weights.content, weights.tags, weights.source);
+            let sql = if filter.section.is_some() {
+                // Match and rank the section body instead of the whole memory
+                format!(
+                    "SELECT s.id, bm25(memory_sections_fts, 0.0, 0.0, {content_weight:?}) as score
+                     FROM memory_sections_fts s
+                     JOIN memories m ON s.id = m.id
+                     WHERE memory_sections_fts MATCH ?1 AND s.section = ?{next_param} {filter_clause}
+                     ORDER BY score
+                     LIMIT ?{}",
+                    next_param + 1
+                )
+            } else {
+                format!(
+                    "SELECT f.id, bm25(memories_fts, 0.0, {content_weight:?}, {tags_weight:?}, {source_weight:?}, {content_weight:?}) as score
+                     FROM memories_fts f
+                     JOIN memories m ON f.id = m.id
+                     WHERE memories_fts MATCH ?1 {filter_clause}
+                     ORDER BY score
+                     LIMIT ?{next_param}"
+                )
+            };
 
             let mut stmt = conn.prepare(&sql).map_err(|e| Error::OperationFailed {
                 operation: "prepare_search".to_string(),
                 cause: e.to_string(),
             })?;
 
-            // Build parameters: query, filter params, limit
+            // Build parameters: query, filter params, section, limit
             let mut results = Vec::new();
 
-            // FTS5 query - escape special characters and wrap terms in quotes
-            // FTS5 special chars: - (NOT), * (prefix), " (phrase), : (column)
-            // Pre-allocate: each term becomes ~term.len() + 6 chars ("term" OR )
-            let terms: Vec<_> = query.split_whitespace().collect();
-            let estimated_len = terms.iter().map(|t| t.len() + 8).sum::<usize>();
-            let mut fts_query = String::with_capacity(estimated_len);
-            for (i, term) in terms.iter().enumerate() {
-                if i > 0 {
-                    fts_query.push_str(" OR ");
-                }
-                fts_query.push('"');
-                // Escape double quotes for literal matching
-                for c in term.chars() {
-                    if c == '"' {
-                        fts_query.push_str("\"\"");
-                    } else {
-                        fts_query.push(c);
-                    }
-                }
-                fts_query.push('"');
-            }
-
             let rows = stmt
                 .query_map(
                     rusqlite::params_from_iter(
-                        std::iter::once(fts_query)
+                        std::iter::once(fts_match_query(query))
                             .chain(filter_params.into_iter())
+                            .chain(filter.section.clone())
                             .chain(std::iter::once(limit.to_string())),
                     ),
                     |row| {
@@ -1313,7 +2200,7 @@ fn search(
     fn clear(&self) -> Result<()> {
         let start = Instant::now();
         let result = (|| {
-            let conn = acquire_lock(&self.conn);
+            let conn = self.lock_conn()?;
 
             // Use transaction for atomicity (DB-H2)
             conn.execute("BEGIN IMMEDIATE", [])
@@ -1329,6 +2216,11 @@ fn clear(&self) -> Result<()> {
                         cause: e.to_string(),
                     }
                 })?;
+                conn.execute("DELETE FROM memory_sections_fts", [])
+                    .map_err(|e| Error::OperationFailed {
+                        operation: "clear_sections".to_string(),
+                        cause: e.to_string(),
+                    })?;
 
                 conn.execute("DELETE FROM memories", [])
                     .map_err(|e| Error::OperationFailed {
@@ -1364,7 +2256,7 @@ fn clear(&self) -> Result<()> {
     fn list_all(&self, filter: &SearchFilter, limit: usize) -> Result<Vec<(MemoryId, f32)>> {
         let start = Instant::now();
         let result = (|| {
-            let conn = acquire_lock(&self.conn);
+            let conn = self.lock_conn()?;
             let max_limit = usize::try_from(i64::MAX).unwrap_or(usize::MAX);
             let limit = limit.min(max_limit);
 
@@ -1428,7 +2320,7 @@ fn list_all(&self, filter: &SearchFilter, limit: usize) -> Result<Vec<(MemoryId,
     fn get_memory(&self, id: &MemoryId) -> Result<Option<Memory>> {
         let start = Instant::now();
         let result = (|| {
-            let conn = acquire_lock(&self.conn);
+            let conn = self.lock_conn()?;
             let row = fetch_memory_row(&conn, id)?;
             Ok(row.map(build_memory_from_row))
         })();
@@ -1444,100 +2336,28 @@ fn get_memory(&self, id: &MemoryId) -> Result<Option<Memory>> {
     #[instrument(skip(self, ids), fields(operation = "get_memories_batch", backend = "sqlite", count = ids.len()))]
     fn get_memories_batch(&self, ids: &[MemoryId]) -> Result<Vec<Option<Memory>>> {
         let start = Instant::now();
-
-        if ids.is_empty() {
-            return Ok(Vec::new());
-        }
-
-        let result = (|| {
-            let conn = acquire_lock(&self.conn);
-
-            // Build placeholders for IN clause
-            let placeholders: Vec<String> = (1..=ids.len()).map(|i| format!("?{i}")).collect();
-
-            #[cfg(feature = "group-scope")]
-            let sql = format!(
-                "SELECT m.id, m.namespace, m.domain, m.project_id, m.branch, m.file_path, m.status, m.created_at,
-                        m.tombstoned_at, m.expires_at, m.tags, m.source, f.content, m.is_summary, m.source_memory_ids, m.consolidation_timestamp, m.group_id
-                 FROM memories m
-                 JOIN memories_fts f ON m.id = f.id
-                 WHERE m.id IN ({})",
-                placeholders.join(", ")
-            );
-            #[cfg(not(feature = "group-scope"))]
-            let sql = format!(
-                "SELECT m.id, m.namespace, m.domain, m.project_id, m.branch, m.file_path, m.status, m.created_at,
-                        m.tombstoned_at, m.expires_at, m.tags, m.source, f.content, m.is_summary, m.source_memory_ids, m.consolidation_timestamp
-                 FROM memories m
-                 JOIN memories_fts f ON m.id = f.id
-                 WHERE m.id IN ({})",
-                placeholders.join(", ")
-            );
-
-            let mut stmt = conn.prepare(&sql).map_err(|e| Error::OperationFailed {
-                operation: "prepare_get_memories_batch".to_string(),
-                cause: e.to_string(),
-            })?;
-
-            // Collect results into a HashMap for O(1) lookup
-            let id_strs: Vec<&str> = ids.iter().map(MemoryId::as_str).collect();
-            let mut memory_map: std::collections::HashMap<String, Memory> =
-                std::collections::HashMap::with_capacity(ids.len());
-
-            let rows = stmt
-                .query_map(rusqlite::params_from_iter(id_strs.iter()), |row| {
-                    Ok(MemoryRow {
-                        id: row.get(0)?,
-                        namespace: row.get(1)?,
-                        domain: row.get(2)?,
-                        project_id: row.get(3)?,
-                        branch: row.get(4)?,
-                        file_path: row.get(5)?,
-                        status: row.get(6)?,
-                        created_at: row.get(7)?,
-                        tombstoned_at: row.get(8)?,
-                        expires_at: row.get(9)?,
-                        tags: row.get(10)?,
-                        source: row.get(11)?,
-                        content: row.get(12)?,
-                        is_summary: row.get::<_, Option<bool>>(13)?.unwrap_or(false),
-                        source_memory_ids: row.get(14)?,
-                        consolidation_timestamp: row.get(15)?,
-                        #[cfg(feature = "group-scope")]
-                        group_id: row.get(16)?,
-                    })
-                })
-                .map_err(|e| Error::OperationFailed {
-                    operation: "execute_get_memories_batch".to_string(),
-                    cause: e.to_string(),
-                })?;
-
-            for row in rows {
-                let memory_row = row.map_err(|e| Error::OperationFailed {
-                    operation: "read_batch_row".to_string(),
-                    cause: e.to_string(),
-                })?;
-                let id = memory_row.id.clone();
-                memory_map.insert(id, build_memory_from_row(memory_row));
-            }
-
-            // Return memories in the same order as input IDs
-            Ok(ids
-                .iter()
-                .map(|id| memory_map.remove(id.as_str()))
-                .collect())
-        })();
-
+        let result = self.fetch_memories_batch(ids, true);
         let status = if result.is_ok() { "success" } else { "error" };
         self.record_operation_metrics("get_memories_batch", start, status);
         result
     }
 
+    /// Retrieves memory metadata without reading content from the FTS table.
+    #[instrument(skip(self, ids), fields(operation = "get_memories_metadata_batch", backend = "sqlite", count = ids.len()))]
+    fn get_memories_metadata_batch(&self, ids: &[MemoryId]) -> Result<Vec<Option<Memory>>> {
+        let start = Instant::now();
+        let result = self.fetch_memories_batch(ids, false);
+        let status = if result.is_ok() { "success" } else { "error" };
+        self.record_operation_metrics("get_memories_metadata_batch", start, status);
+        result
+    }
+
     /// Re-indexes all memories in a single transaction (DB-H2).
     ///
     /// This is more efficient than the default implementation which creates
     /// a transaction per memory.
     #[instrument(skip(self, memories), fields(operation = "reindex", backend = "sqlite", count = memories.len()))]
+    #[allow(clippy::too_many_lines)]
     fn reindex(&self, memories: &[Memory]) -> Result<()> {
         let start = Instant::now();
 
@@ -1546,7 +2366,7 @@ fn reindex(&self, memories: &[Memory]) -> Result<()> {
         }
 
         let result = (|| {
-            let conn = acquire_lock(&self.conn);
+            let conn = self.lock_conn()?;
 
             // Use a single transaction for all operations
             conn.execute("BEGIN IMMEDIATE", [])
@@ -1556,7 +2376,7 @@ fn reindex(&self, memories: &[Memory]) -> Result<()> {
                 })?;
 
             let result = (|| {
-                for memory in memories {
+                for memory in without_content_duplicates(&conn, memories)? {
                     let tags_str = memory.tags.join(",");
                     let domain_str = memory.domain.to_string();
 
@@ -1564,6 +2384,7 @@ fn reindex(&self, memories: &[Memory]) -> Result<()> {
                     // Note: Cast u64 to i64 for SQLite compatibility (rusqlite doesn't impl ToSql for u64)
                     #[allow(clippy::cast_possible_wrap)]
                     let created_at_i64 = memory.created_at as i64;
+                    let updated_at_i64 = memory.updated_at.cast_signed();
                     let tombstoned_at_i64 = memory.tombstoned_at.map(|t| t.timestamp());
                     #[allow(clippy::cast_possible_wrap)]
                     let consolidation_ts_i64 = memory.consolidation_timestamp.map(|t| t as i64);
@@ -1572,9 +2393,10 @@ fn reindex(&self, memories: &[Memory]) -> Result<()> {
                         .as_ref()
                         .map(|ids| serde_json::to_string(ids).unwrap_or_default());
                     let expires_at_i64 = memory.expires_at.map(u64::cast_signed);
+                    let content_hash = ContentHasher::hash(&memory.content);
                     conn.execute(
-                        "INSERT OR REPLACE INTO memories (id, namespace, domain, project_id, branch, file_path, status, created_at, tags, source, tombstoned_at, expires_at, is_summary, source_memory_ids, consolidation_timestamp)
-                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)",
+                        "INSERT OR REPLACE INTO memories (id, namespace, domain, project_id, branch, file_path, status, created_at, tags, source, tombstoned_at, expires_at, is_summary, source_memory_ids, consolidation_timestamp, origin, confidence, attachments, content_type, pinned, commit_sha, updated_at, content_hash, language)
+                         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24)",
                         params![
                             memory.id.as_str(),
                             memory.namespace.as_str(),
@@ -1590,7 +2412,16 @@ fn reindex(&self, memories: &[Memory]) -> Result<()> {
                             expires_at_i64,
                             memory.is_summary,
                             source_ids_json,
-                            consolidation_ts_i64
+                            consolidation_ts_i64,
+                            memory.origin.as_str(),
+                            memory.confidence.map(f64::from),
+                            attachments_json(memory),
+                            content_type_str(memory),
+                            memory.pinned,
+                            memory.commit.as_deref(),
+                            updated_at_i64,
+                            content_hash,
+                            memory.language.as_deref()
                         ],
                     )
                     .map_err(|e| Error::OperationFailed {
@@ -1610,13 +2441,26 @@ fn reindex(&self, memories: &[Memory]) -> Result<()> {
 
                     // Insert into FTS table
                     conn.execute(
-                        "INSERT INTO memories_fts (id, content, tags) VALUES (?1, ?2, ?3)",
-                        params![memory.id.as_str(), memory.content, tags_str],
+                        "INSERT INTO memories_fts (id, content, tags, source, code_terms) VALUES (?1, ?2, ?3, ?4, ?5)",
+                        params![
+                            memory.id.as_str(),
+                            memory.content,
+                            tags_str,
+                            memory.source.as_deref(),
+                            code_terms(memory)
+                        ],
                     )
                     .map_err(|e| Error::OperationFailed {
                         operation: "insert_fts".to_string(),
                         cause: e.to_string(),
                     })?;
+
+                    index_sections(&conn, memory.id.as_str(), &memory.content).map_err(|e| {
+                        Error::OperationFailed {
+                            operation: "index_sections".to_string(),
+                            cause: e.to_string(),
+                        }
+                    })?;
                 }
                 Ok(())
             })();
@@ -1657,6 +2501,39 @@ fn query_edges(
         // Delegate to the inherent method
         Self::query_edges(self, from_id, edge_type)
     }
+
+    fn query_incoming_edges(
+        &self,
+        to_id: &MemoryId,
+        edge_type: crate::models::EdgeType,
+    ) -> Result<Vec<MemoryId>> {
+        Self::query_incoming_edges(self, to_id, edge_type)
+    }
+
+    fn record_feedback(&self, query: &str, memory_id: &MemoryId, useful: bool) -> Result<()> {
+        Self::record_feedback(self, query, memory_id, useful)
+    }
+
+    fn feedback_for(&self, memory_ids: &[MemoryId]) -> Result<Vec<RelevanceFeedback>> {
+        Self::feedback_for(self, memory_ids)
+    }
+
+    fn find_by_content_hash(
+        &self,
+        namespace: Namespace,
+        content_hash: &str,
+    ) -> Result<Option<MemoryId>> {
+        Self::find_by_content_hash(self, namespace, content_hash)
+    }
+
+    fn snippets(
+        &self,
+        query: &str,
+        ids: &[MemoryId],
+        marker: &str,
+    ) -> Result<Vec<(MemoryId, String)>> {
+        Self::snippets(self, query, ids, marker)
+    }
 }
 
 // Implement PersistenceBackend for SqliteBackend so it can be used with ConsolidationService
@@ -1677,10 +2554,7 @@ fn delete(&self, id: &MemoryId) -> Result<bool> {
     fn list_ids(&self) -> Result<Vec<MemoryId>> {
         let start = Instant::now();
         let result = (|| {
-            let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-                operation: "lock_connection".to_string(),
-                cause: e.to_string(),
-            })?;
+            let conn = self.lock_conn()?;
 
             let mut stmt =
                 conn.prepare("SELECT id FROM memories")
@@ -1737,6 +2611,13 @@ fn create_test_memory(id: &str, content: &str, namespace: Namespace) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -1780,6 +2661,23 @@ fn test_search_with_namespace_filter() {
         assert_eq!(results[0].0.as_str(), "id2");
     }
 
+    #[test]
+    fn test_search_with_commit_filter_ignores_case() {
+        let backend = SqliteBackend::in_memory().unwrap();
+
+        let mut memory = create_test_memory("id1", "Rust commits", Namespace::Decisions);
+        memory.commit = Some("ABCdef0123456789abcdef0123456789abcdef01".to_string());
+        backend.index(&memory).unwrap();
+
+        for prefix in ["abcdef0", "ABCDEF0"] {
+            let filter = SearchFilter::new().with_commit(prefix);
+            let results = backend.search("Rust", &filter, 10).unwrap();
+            assert_eq!(results.len(), 1, "prefix {prefix}");
+        }
+        let filter = SearchFilter::new().with_commit("0000000");
+        assert!(backend.search("Rust", &filter, 10).unwrap().is_empty());
+    }
+
     #[test]
     fn test_search_with_facet_filters() {
         let backend = SqliteBackend::in_memory().unwrap();
@@ -1872,6 +2770,44 @@ fn test_list_all_with_max_limit() {
         assert_eq!(results[0].0.as_str(), "id1");
     }
 
+    #[test]
+    fn test_list_all_created_window_excludes_legacy_rows() {
+        let backend = SqliteBackend::in_memory().unwrap();
+        for (id, created_at) in [("old", 1_000), ("new", 2_000), ("legacy", 0)] {
+            let mut memory =
+                create_test_memory(id, &format!("window memory {id}"), Namespace::Decisions);
+            memory.created_at = created_at;
+            backend.index(&memory).unwrap();
+        }
+
+        let ids = |filter: &SearchFilter| {
+            let mut ids: Vec<String> = backend
+                .list_all(filter, 10)
+                .unwrap()
+                .into_iter()
+                .map(|(id, _)| id.as_str().to_string())
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        assert_eq!(ids(&SearchFilter::new()).len(), 3);
+        assert_eq!(
+            ids(&SearchFilter::new().with_created_after(1_500)),
+            vec!["new"]
+        );
+        assert_eq!(
+            ids(&SearchFilter::new().with_created_before(1_500)),
+            vec!["old"]
+        );
+        assert_eq!(
+            ids(&SearchFilter::new()
+                .with_created_after(1_000)
+                .with_created_before(2_000)),
+            vec!["new", "old"]
+        );
+    }
+
     #[test]
     fn test_update_index() {
         let backend = SqliteBackend::in_memory().unwrap();
@@ -1897,6 +2833,115 @@ fn test_update_index() {
         assert_eq!(new_results.len(), 1);
     }
 
+    #[test]
+    fn test_updated_at_roundtrip_and_legacy_rows() {
+        let backend = SqliteBackend::in_memory().unwrap();
+
+        let mut edited = create_test_memory("edited", "edited content", Namespace::Decisions);
+        edited.updated_at = 1_300_000_000;
+        backend.index(&edited).unwrap();
+        let stored = backend.get_memory(&edited.id).unwrap().unwrap();
+        assert_eq!(stored.created_at, 1_234_567_890);
+        assert_eq!(stored.updated_at, 1_300_000_000);
+
+        // Rows indexed before the column existed fall back to created_at
+        let legacy = create_test_memory("legacy", "legacy content", Namespace::Decisions);
+        backend.index(&legacy).unwrap();
+        backend
+            .lock_conn()
+            .unwrap()
+            .execute(
+                "UPDATE memories SET updated_at = NULL WHERE id = 'legacy'",
+                [],
+            )
+            .unwrap();
+        let stored = backend.get_memory(&legacy.id).unwrap().unwrap();
+        assert_eq!(stored.updated_at, stored.created_at);
+
+        let recent = SearchFilter::new().with_updated_after(1_250_000_000);
+        let ids = backend.list_all(&recent, 10).unwrap();
+        assert_eq!(ids.len(), 1);
+        assert_eq!(ids[0].0.as_str(), "edited");
+    }
+
+    #[test]
+    fn test_find_by_content_hash_and_backfill() {
+        let backend = SqliteBackend::in_memory().unwrap();
+        let memory =
+            create_test_memory("hashed", "Use  SQLite for the index", Namespace::Decisions);
+        backend.index(&memory).unwrap();
+        let hash = ContentHasher::hash("use sqlite for the index");
+
+        let found = backend
+            .find_by_content_hash(Namespace::Decisions, &hash)
+            .unwrap();
+        assert_eq!(found, Some(memory.id.clone()));
+        assert!(
+            backend
+                .find_by_content_hash(Namespace::Patterns, &hash)
+                .unwrap()
+                .is_none()
+        );
+
+        // Rows indexed before the column existed are hashed on migration
+        let conn = backend.lock_conn().unwrap();
+        conn.execute("UPDATE memories SET content_hash = NULL", [])
+            .unwrap();
+        SqliteBackend::backfill_content_hashes(&conn).unwrap();
+        drop(conn);
+        let found = backend
+            .find_by_content_hash(Namespace::Decisions, &hash)
+            .unwrap();
+        assert_eq!(found, Some(memory.id));
+    }
+
+    #[test]
+    fn test_live_content_is_unique_per_namespace() {
+        let backend = SqliteBackend::in_memory().unwrap();
+        let first = create_test_memory("first", "Use SQLite for the index", Namespace::Decisions);
+        backend.index(&first).unwrap();
+
+        // A second live memory with the same content is rejected, not replaced
+        let second =
+            create_test_memory("second", "use  sqlite for the index", Namespace::Decisions);
+        assert!(backend.index(&second).is_err());
+        assert!(backend.get_memory(&first.id).unwrap().is_some());
+        assert!(backend.get_memory(&second.id).unwrap().is_none());
+
+        // Other namespaces and tombstoned memories don't conflict
+        let other = create_test_memory("other", "Use SQLite for the index", Namespace::Patterns);
+        backend.index(&other).unwrap();
+        let mut tombstoned = second.clone();
+        tombstoned.status = MemoryStatus::Tombstoned;
+        backend.index(&tombstoned).unwrap();
+
+        // Re-indexing keeps the first of a set of duplicates
+        backend.clear().unwrap();
+        backend.reindex(&[first.clone(), second.clone()]).unwrap();
+        assert!(backend.get_memory(&first.id).unwrap().is_some());
+        assert!(backend.get_memory(&second.id).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_snippets_highlight_content_matches() {
+        let backend = SqliteBackend::in_memory().unwrap();
+        let fox = create_test_memory("fox", "The quick brown fox jumps", Namespace::Learnings);
+        // Matches the query only through its "test" tag
+        let tagged = create_test_memory("tagged", "Unrelated content", Namespace::Learnings);
+        backend.index(&fox).unwrap();
+        backend.index(&tagged).unwrap();
+        let ids = [fox.id.clone(), tagged.id];
+
+        let snippets = backend.snippets("FOX test", &ids, "**").unwrap();
+        assert_eq!(
+            snippets,
+            vec![(fox.id.clone(), "The quick brown **fox** jumps".to_string())]
+        );
+        let snippets = backend.snippets("fox", &ids, "<>").unwrap();
+        assert_eq!(snippets[0].1, "The quick brown <>fox<> jumps");
+        assert!(backend.snippets("  ", &ids, "**").unwrap().is_empty());
+    }
+
     #[test]
     fn test_get_memories_batch() {
         let backend = SqliteBackend::in_memory().unwrap();
@@ -2042,6 +3087,61 @@ fn test_get_memory_single() {
         assert_eq!(fetched.namespace, Namespace::Decisions);
     }
 
+    #[test]
+    fn test_get_memory_with_attachments() {
+        let backend = SqliteBackend::in_memory().unwrap();
+
+        let mut memory = create_test_memory(
+            "with_attachment",
+            "Architecture diagram for the sync service",
+            Namespace::Decisions,
+        );
+        memory.attachments.push(crate::models::Attachment {
+            hash: "ab".repeat(32),
+            name: "diagram.png".to_string(),
+            media_type: "image/png".to_string(),
+            size: 1024,
+        });
+        backend.index(&memory).unwrap();
+
+        let fetched = backend
+            .get_memory(&MemoryId::new("with_attachment"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(fetched.attachments, memory.attachments);
+    }
+
+    #[test]
+    fn test_code_identifiers_are_searchable_as_words() {
+        let backend = SqliteBackend::in_memory().unwrap();
+
+        let mut memory = create_test_memory(
+            "code",
+            "```rust\nlet cfg = parseConfig(path)?;\n```",
+            Namespace::Patterns,
+        );
+        memory.content_type = Some(ContentType::Code {
+            lang: Some("rust".to_string()),
+        });
+        backend.index(&memory).unwrap();
+        backend
+            .index(&create_test_memory(
+                "prose",
+                "Configuration is loaded once at startup",
+                Namespace::Decisions,
+            ))
+            .unwrap();
+
+        let results = backend
+            .search("parse config", &SearchFilter::new(), 10)
+            .unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].0.as_str(), "code");
+
+        let fetched = backend.get_memory(&MemoryId::new("code")).unwrap().unwrap();
+        assert_eq!(fetched.content_type, memory.content_type);
+    }
+
     #[test]
     fn test_get_memory_not_found() {
         let backend = SqliteBackend::in_memory().unwrap();
@@ -2306,7 +3406,7 @@ fn test_checkpoint_if_needed_above_threshold() {
     #[test]
     fn test_memory_edges_table_exists() {
         let backend = SqliteBackend::in_memory().unwrap();
-        let conn = acquire_lock(&backend.conn);
+        let conn = backend.lock_conn().unwrap();
 
         // Verify memory_edges table exists
         let result: std::result::Result<i64, _> = conn.query_row(
@@ -2322,7 +3422,7 @@ fn test_memory_edges_table_exists() {
     #[test]
     fn test_memory_edges_schema() {
         let backend = SqliteBackend::in_memory().unwrap();
-        let conn = acquire_lock(&backend.conn);
+        let conn = backend.lock_conn().unwrap();
 
         // Verify table has correct columns
         let result: std::result::Result<Vec<String>, _> = conn
@@ -2350,7 +3450,7 @@ fn test_memory_edges_schema() {
     #[test]
     fn test_memory_edges_indexes_exist() {
         let backend = SqliteBackend::in_memory().unwrap();
-        let conn = acquire_lock(&backend.conn);
+        let conn = backend.lock_conn().unwrap();
 
         // Verify indexes were created
         let indexes: std::result::Result<Vec<String>, _> = conn
@@ -2392,7 +3492,7 @@ fn test_memory_edges_can_insert_and_query() {
         backend.index(&memory1).unwrap();
         backend.index(&memory2).unwrap();
 
-        let conn = acquire_lock(&backend.conn);
+        let conn = backend.lock_conn().unwrap();
 
         // Insert an edge
         let result = conn.execute(
@@ -2421,7 +3521,7 @@ fn test_memory_edges_can_insert_and_query() {
     #[test]
     fn test_memory_edges_foreign_key_constraint() {
         let backend = SqliteBackend::in_memory().unwrap();
-        let conn = acquire_lock(&backend.conn);
+        let conn = backend.lock_conn().unwrap();
 
         // Enable foreign key enforcement
         let _ = conn.execute("PRAGMA foreign_keys = ON", []);
@@ -2448,7 +3548,7 @@ fn test_memory_edges_cascade_delete() {
         backend.index(&memory1).unwrap();
         backend.index(&memory2).unwrap();
 
-        let conn = acquire_lock(&backend.conn);
+        let conn = backend.lock_conn().unwrap();
 
         // Enable foreign key enforcement
         let _ = conn.execute("PRAGMA foreign_keys = ON", []);
@@ -2478,7 +3578,7 @@ fn test_memory_edges_cascade_delete() {
         backend.remove(&MemoryId::new("cascade_from")).unwrap();
 
         // Re-acquire the connection to verify cascade delete worked
-        let conn = acquire_lock(&backend.conn);
+        let conn = backend.lock_conn().unwrap();
 
         // Edge should be deleted due to CASCADE
         let count_after: i64 = conn
@@ -2596,4 +3696,209 @@ fn test_query_edges_multiple() {
         assert!(edges.contains(&target2.id));
         assert!(edges.contains(&target3.id));
     }
+
+    #[test]
+    fn test_record_feedback_accumulates_votes() {
+        let backend = SqliteBackend::in_memory().unwrap();
+        let id = MemoryId::new("m1");
+
+        backend.record_feedback("postgres pool", &id, true).unwrap();
+        backend.record_feedback("postgres pool", &id, true).unwrap();
+        backend
+            .record_feedback("postgres pool", &id, false)
+            .unwrap();
+        backend.record_feedback("redis", &id, false).unwrap();
+
+        let mut feedback = backend.feedback_for(std::slice::from_ref(&id)).unwrap();
+        feedback.sort_by(|a, b| a.query.cmp(&b.query));
+        assert_eq!(feedback.len(), 2);
+        assert_eq!((feedback[0].up, feedback[0].down), (2, 1));
+        assert_eq!((feedback[1].up, feedback[1].down), (0, 1));
+        assert!(
+            backend
+                .feedback_for(&[MemoryId::new("m2")])
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_operation_fails_when_lock_held_past_timeout() {
+        use std::sync::{Arc, mpsc};
+
+        let backend = Arc::new(
+            SqliteBackend::in_memory()
+                .unwrap()
+                .with_lock_timeout(Duration::from_millis(50)),
+        );
+
+        // Another thread holds the connection until told to release it
+        let (held_tx, held_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let holder = {
+            let backend = Arc::clone(&backend);
+            std::thread::spawn(move || {
+                let _guard = backend.conn.lock().unwrap();
+                held_tx.send(()).unwrap();
+                let _ = release_rx.recv();
+            })
+        };
+        held_rx.recv().unwrap();
+
+        let start = Instant::now();
+        let err = backend.get_memory(&MemoryId::new("waiting")).unwrap_err();
+        assert!(err.to_string().contains("lock timeout"));
+        assert!(start.elapsed() < Duration::from_secs(5));
+
+        release_tx.send(()).unwrap();
+        holder.join().unwrap();
+        assert!(backend.get_memory(&MemoryId::new("waiting")).is_ok());
+    }
+
+    #[test]
+    fn test_search_weighted_ranks_tag_match_above_content_match() {
+        let backend = SqliteBackend::in_memory().unwrap();
+
+        let mut tagged = create_test_memory("tagged", "Storage layer notes", Namespace::Decisions);
+        tagged.tags = vec!["postgres".to_string()];
+        let mut mentioned = create_test_memory(
+            "mentioned",
+            "Postgres handles storage for the service",
+            Namespace::Decisions,
+        );
+        mentioned.tags = vec!["database".to_string()];
+        let mut sourced = create_test_memory("sourced", "Connection pool", Namespace::Decisions);
+        sourced.source = Some("src/postgres/pool.rs".to_string());
+        backend.index(&tagged).unwrap();
+        backend.index(&mentioned).unwrap();
+        backend.index(&sourced).unwrap();
+
+        let results = backend
+            .search("postgres", &SearchFilter::new(), 10)
+            .unwrap();
+        let ids: Vec<_> = results.iter().map(|(id, _)| id.as_str()).collect();
+        assert_eq!(ids, vec!["tagged", "sourced", "mentioned"]);
+
+        let content_first = FieldWeights::new(10.0, 1.0, 1.0);
+        let results = backend
+            .search_weighted("postgres", &SearchFilter::new(), &content_first, 10)
+            .unwrap();
+        assert_eq!(results[0].0.as_str(), "mentioned");
+    }
+
+    #[test]
+    fn test_legacy_fts_table_gains_source_column() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("index.db");
+
+        let mut memory = create_test_memory("legacy", "Old content", Namespace::Decisions);
+        memory.source = Some("docs/adr-001.md".to_string());
+        SqliteBackend::new(&path).unwrap().index(&memory).unwrap();
+
+        // Recreate the FTS table without the source column
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch(
+            "DROP TABLE memories_fts;
+             CREATE VIRTUAL TABLE memories_fts USING fts5(id, content, tags);
+             INSERT INTO memories_fts (id, content, tags) VALUES ('legacy', 'Old content', 'test');",
+        )
+        .unwrap();
+        drop(conn);
+
+        let backend = SqliteBackend::new(&path).unwrap();
+        let found = backend
+            .get_memory(&MemoryId::new("legacy"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(found.content, "Old content");
+        let results = backend.search("adr", &SearchFilter::new(), 10).unwrap();
+        assert_eq!(results.len(), 1);
+    }
+
+    fn ids(results: &[(MemoryId, f32)]) -> Vec<&str> {
+        results.iter().map(|(id, _)| id.as_str()).collect()
+    }
+
+    fn adr(id: &str, decision: &str, consequences: &str) -> Memory {
+        let sections = [
+            crate::models::MemorySection::new("decision", decision),
+            crate::models::MemorySection::new("consequences", consequences),
+        ];
+        let content = crate::models::render_sections(None, "", &sections);
+        create_test_memory(id, &content, Namespace::Decisions)
+    }
+
+    #[test]
+    fn test_section_search_matches_and_ranks_section_bodies() {
+        let backend = SqliteBackend::in_memory().unwrap();
+        // Mentions postgres often, but only outside the consequences section
+        backend
+            .index(&adr(
+                "decision-heavy",
+                "Postgres over Postgres replicas, Postgres everywhere",
+                "Operators learn a new tool",
+            ))
+            .unwrap();
+        backend
+            .index(&adr("short", "Use SQLite", "Postgres stays optional"))
+            .unwrap();
+        backend
+            .index(&adr(
+                "long",
+                "Use SQLite",
+                "Postgres stays optional for teams that already run a large shared cluster",
+            ))
+            .unwrap();
+        backend
+            .index(&create_test_memory(
+                "plain",
+                "Postgres consequences everywhere",
+                Namespace::Decisions,
+            ))
+            .unwrap();
+
+        let filter = SearchFilter::new().with_section("consequences");
+        let results = backend.search("postgres", &filter, 10).unwrap();
+        assert_eq!(ids(&results), vec!["short", "long"]);
+
+        // The limit applies to section matches, not to whole-memory matches
+        let results = backend.search("postgres", &filter, 1).unwrap();
+        assert_eq!(ids(&results), vec!["short"]);
+
+        // Other filters still apply
+        let filter = filter.with_namespace(Namespace::Patterns);
+        assert!(backend.search("postgres", &filter, 10).unwrap().is_empty());
+
+        backend.remove(&MemoryId::new("short")).unwrap();
+        let filter = SearchFilter::new().with_section("consequences");
+        let results = backend.search("postgres", &filter, 10).unwrap();
+        assert_eq!(ids(&results), vec!["long"]);
+
+        // Reindexing replaces the sections of a memory
+        backend
+            .index(&adr("long", "Use SQLite", "No new services"))
+            .unwrap();
+        assert!(backend.search("postgres", &filter, 10).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sections_of_existing_memories_are_indexed_on_open() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("index.db");
+        SqliteBackend::new(&path)
+            .unwrap()
+            .index(&adr("legacy", "Use SQLite", "Postgres stays optional"))
+            .unwrap();
+
+        // Drop the sections table, as in an index created before it existed
+        let conn = Connection::open(&path).unwrap();
+        conn.execute_batch("DROP TABLE memory_sections_fts;")
+            .unwrap();
+        drop(conn);
+
+        let backend = SqliteBackend::new(&path).unwrap();
+        let filter = SearchFilter::new().with_section("consequences");
+        let results = backend.search("postgres", &filter, 10).unwrap();
+        assert_eq!(ids(&results), vec!["legacy"]);
+    }
 }
@@ -0,0 +1,111 @@
+//! Bounded mutex acquisition for `SQLite` connections.
+//!
+//! `rusqlite::Connection` is not `Sync`, so `SQLite` backends guard it with a
+//! `std::sync::Mutex`. Waiting on that mutex without a bound lets one hung
+//! holder wedge every other request; these helpers give up after a timeout
+//! and report an error instead.
+//!
+//! The timeout defaults to [`MUTEX_LOCK_TIMEOUT`] and can be overridden with
+//! the `SUBCOG_SQLITE_LOCK_TIMEOUT_MS` environment variable.
+
+use crate::{Error, Result};
+use std::sync::{LazyLock, Mutex, MutexGuard, TryLockError};
+use std::time::{Duration, Instant};
+
+/// Default timeout for acquiring a connection mutex (5 seconds).
+pub const MUTEX_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Environment variable overriding [`MUTEX_LOCK_TIMEOUT`], in milliseconds.
+pub const MUTEX_LOCK_TIMEOUT_ENV: &str = "SUBCOG_SQLITE_LOCK_TIMEOUT_MS";
+
+/// Interval between lock attempts while waiting.
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(10);
+
+static CONFIGURED_TIMEOUT: LazyLock<Duration> = LazyLock::new(|| {
+    std::env::var(MUTEX_LOCK_TIMEOUT_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .filter(|ms| *ms > 0)
+        .map_or(MUTEX_LOCK_TIMEOUT, Duration::from_millis)
+});
+
+/// Returns the lock timeout, honoring `SUBCOG_SQLITE_LOCK_TIMEOUT_MS`.
+///
+/// The environment variable is read once per process.
+#[must_use]
+pub fn mutex_lock_timeout() -> Duration {
+    *CONFIGURED_TIMEOUT
+}
+
+/// Acquires a mutex, giving up after `timeout`.
+///
+/// Rust's `std::sync::Mutex` has no timed lock, so this polls `try_lock`
+/// with a short sleep. A poisoned mutex is recovered (with a warning)
+/// because the connection state remains valid after a panicking holder.
+///
+/// # Errors
+///
+/// Returns [`Error::OperationFailed`] with a `lock timeout` cause if the
+/// mutex is still held when the timeout elapses.
+pub fn acquire_lock_with_timeout<T>(
+    mutex: &Mutex<T>,
+    timeout: Duration,
+) -> Result<MutexGuard<'_, T>> {
+    let start = Instant::now();
+
+    loop {
+        match mutex.try_lock() {
+            Ok(guard) => return Ok(guard),
+            Err(TryLockError::Poisoned(poisoned)) => {
+                tracing::warn!("SQLite mutex was poisoned, recovering");
+                metrics::counter!("sqlite_mutex_poison_recovery_total").increment(1);
+                return Ok(poisoned.into_inner());
+            },
+            Err(TryLockError::WouldBlock) => {
+                if start.elapsed() >= timeout {
+                    metrics::counter!("sqlite_mutex_timeout_total").increment(1);
+                    return Err(Error::OperationFailed {
+                        operation: "acquire_lock".to_string(),
+                        cause: format!("lock timeout after {timeout:?}"),
+                    });
+                }
+                std::thread::sleep(LOCK_RETRY_INTERVAL.min(timeout));
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acquires_free_lock() {
+        let mutex = Mutex::new(1);
+        let guard = acquire_lock_with_timeout(&mutex, Duration::from_millis(50)).unwrap();
+        assert_eq!(*guard, 1);
+    }
+
+    #[test]
+    fn test_recovers_poisoned_lock() {
+        let mutex = std::sync::Arc::new(Mutex::new(1));
+        let poisoner = std::sync::Arc::clone(&mutex);
+        let _ = std::thread::spawn(move || {
+            let _guard = poisoner.lock().unwrap();
+            panic!("poison the mutex");
+        })
+        .join();
+
+        assert!(mutex.is_poisoned());
+        assert!(acquire_lock_with_timeout(&mutex, Duration::from_millis(50)).is_ok());
+    }
+
+    #[test]
+    fn test_times_out_while_held() {
+        let mutex = Mutex::new(1);
+        let _held = mutex.lock().unwrap();
+
+        let err = acquire_lock_with_timeout(&mutex, Duration::from_millis(30)).unwrap_err();
+        assert!(err.to_string().contains("lock timeout"));
+    }
+}
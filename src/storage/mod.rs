@@ -19,12 +19,14 @@
 // Allow unused_self for methods kept for API consistency.
 #![allow(clippy::unused_self)]
 
+pub mod attachments;
 pub mod bulkhead;
 pub mod context_template;
 pub mod graph;
 #[cfg(feature = "group-scope")]
 pub mod group;
 pub mod index;
+mod lock;
 pub mod migrations;
 pub mod persistence;
 pub mod prompt;
@@ -32,6 +34,7 @@
 pub mod traits;
 pub mod vector;
 
+pub use attachments::{AttachmentStore, DEFAULT_MAX_ATTACHMENT_BYTES};
 pub use bulkhead::{
     BulkheadIndexBackend, BulkheadPersistenceBackend, BulkheadVectorBackend, StorageBulkheadConfig,
 };
@@ -40,6 +43,8 @@
     ContextTemplateStorageFactory, SqliteContextTemplateStorage,
 };
 pub use index::get_user_data_dir;
+pub(crate) use lock::acquire_lock_with_timeout;
+pub use lock::{MUTEX_LOCK_TIMEOUT, MUTEX_LOCK_TIMEOUT_ENV, mutex_lock_timeout};
 pub use prompt::{
     FilesystemPromptStorage, PostgresPromptStorage, PromptBackendType, PromptStorage,
     PromptStorageFactory, RedisPromptStorage, SqlitePromptStorage,
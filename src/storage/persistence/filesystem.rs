@@ -23,7 +23,7 @@
 //! export SUBCOG_ENCRYPTION_KEY="your-base64-encoded-key"
 //! ```
 
-use crate::models::{Memory, MemoryId};
+use crate::models::{Attachment, CaptureOrigin, ContentType, Memory, MemoryId};
 use crate::security::encryption::is_encrypted;
 #[cfg(feature = "encryption")]
 use crate::security::encryption::{EncryptionConfig, Encryptor};
@@ -70,6 +70,27 @@ struct StoredMemory {
     /// Timestamp when this memory was consolidated.
     #[serde(default)]
     consolidation_timestamp: Option<u64>,
+    /// How the memory was captured (older files predate this and are manual).
+    #[serde(default)]
+    origin: CaptureOrigin,
+    /// Confidence of the automatic capture, if recorded.
+    #[serde(default)]
+    confidence: Option<f32>,
+    /// Attached files (older files predate attachments).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attachments: Vec<Attachment>,
+    /// Content type (older files predate content types).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    content_type: Option<ContentType>,
+    /// Whether the memory is pinned (older files predate pinning).
+    #[serde(default)]
+    pinned: bool,
+    /// Commit checked out at capture (older files predate commit recording).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    commit: Option<String>,
+    /// Content language (older files predate language detection).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    language: Option<String>,
 }
 
 impl From<&Memory> for StoredMemory {
@@ -100,6 +121,13 @@ fn from(m: &Memory) -> Self {
                 .as_ref()
                 .map(|ids| ids.iter().map(|id| id.as_str().to_string()).collect()),
             consolidation_timestamp: m.consolidation_timestamp,
+            origin: m.origin,
+            confidence: m.confidence,
+            attachments: m.attachments.clone(),
+            content_type: m.content_type.clone(),
+            pinned: m.pinned,
+            commit: m.commit.clone(),
+            language: m.language.clone(),
         }
     }
 }
@@ -163,6 +191,13 @@ fn to_memory(&self) -> Memory {
                 .as_ref()
                 .map(|ids| ids.iter().map(MemoryId::new).collect()),
             consolidation_timestamp: self.consolidation_timestamp,
+            origin: self.origin,
+            confidence: self.confidence,
+            attachments: self.attachments.clone(),
+            content_type: self.content_type.clone(),
+            pinned: self.pinned,
+            commit: self.commit.clone(),
+            language: self.language.clone(),
         }
     }
 }
@@ -511,6 +546,13 @@ fn create_test_memory(id: &str) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
         }
     }
 
@@ -4,6 +4,7 @@
 
 use super::PromptStorage;
 use crate::models::PromptTemplate;
+use crate::storage::{acquire_lock_with_timeout, mutex_lock_timeout};
 use crate::{Error, Result};
 use rusqlite::{Connection, OptionalExtension, params};
 use std::path::{Path, PathBuf};
@@ -108,10 +109,7 @@ pub fn db_path(&self) -> &Path {
 
     /// Initializes the database schema and configures pragmas.
     fn initialize(&self) -> Result<()> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_prompt_db".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = self.lock_conn()?;
 
         // Configure SQLite pragmas for performance and reliability
         // WAL mode: better concurrent read performance
@@ -155,10 +153,7 @@ fn initialize(&self) -> Result<()> {
 
     /// Locks the connection and returns a guard.
     fn lock_conn(&self) -> Result<std::sync::MutexGuard<'_, Connection>> {
-        self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_prompt_db".to_string(),
-            cause: e.to_string(),
-        })
+        acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())
     }
 
     /// Runs database maintenance (VACUUM and ANALYZE).
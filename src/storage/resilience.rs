@@ -725,7 +725,7 @@ fn count(&self) -> Result<usize> {
 // ============================================================================
 
 use super::traits::IndexBackend;
-use crate::models::SearchFilter;
+use crate::models::{FieldWeights, Namespace, RelevanceFeedback, SearchFilter};
 
 /// Index backend wrapper with circuit breaker and retry protection.
 pub struct ResilientIndexBackend<I: IndexBackend> {
@@ -913,6 +913,18 @@ fn search(
         self.execute("search", || self.inner.search(query, filter, limit))
     }
 
+    fn search_weighted(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+        weights: &FieldWeights,
+        limit: usize,
+    ) -> Result<Vec<(MemoryId, f32)>> {
+        self.execute("search", || {
+            self.inner.search_weighted(query, filter, weights, limit)
+        })
+    }
+
     fn reindex(&self, memories: &[Memory]) -> Result<()> {
         self.execute("reindex", || self.inner.reindex(memories))
     }
@@ -932,6 +944,41 @@ fn get_memory(&self, id: &MemoryId) -> Result<Option<Memory>> {
     fn get_memories_batch(&self, ids: &[MemoryId]) -> Result<Vec<Option<Memory>>> {
         self.execute("get_memories_batch", || self.inner.get_memories_batch(ids))
     }
+
+    fn get_memories_metadata_batch(&self, ids: &[MemoryId]) -> Result<Vec<Option<Memory>>> {
+        self.execute("get_memories_metadata_batch", || {
+            self.inner.get_memories_metadata_batch(ids)
+        })
+    }
+
+    fn record_feedback(&self, query: &str, memory_id: &MemoryId, useful: bool) -> Result<()> {
+        self.execute("record_feedback", || {
+            self.inner.record_feedback(query, memory_id, useful)
+        })
+    }
+
+    fn feedback_for(&self, memory_ids: &[MemoryId]) -> Result<Vec<RelevanceFeedback>> {
+        self.execute("feedback_for", || self.inner.feedback_for(memory_ids))
+    }
+
+    fn find_by_content_hash(
+        &self,
+        namespace: Namespace,
+        content_hash: &str,
+    ) -> Result<Option<MemoryId>> {
+        self.execute("find_by_content_hash", || {
+            self.inner.find_by_content_hash(namespace, content_hash)
+        })
+    }
+
+    fn snippets(
+        &self,
+        query: &str,
+        ids: &[MemoryId],
+        marker: &str,
+    ) -> Result<Vec<(MemoryId, String)>> {
+        self.execute("snippets", || self.inner.snippets(query, ids, marker))
+    }
 }
 
 // ============================================================================
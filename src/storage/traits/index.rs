@@ -42,8 +42,10 @@
 //! - **Batch efficiency**: `get_memories_batch()` avoids N+1 query pattern
 //! - **FTS tokenization**: Whitespace + punctuation split (`SQLite`), language-aware (`PostgreSQL`)
 
-use crate::Result;
-use crate::models::{Memory, MemoryId, SearchFilter};
+use crate::models::{
+    ContentHasher, FieldWeights, Memory, MemoryId, Namespace, RelevanceFeedback, SearchFilter,
+};
+use crate::{Error, Result};
 
 /// Trait for index layer backends.
 ///
@@ -89,6 +91,25 @@ fn search(
         limit: usize,
     ) -> Result<Vec<(MemoryId, f32)>>;
 
+    /// Searches with per-field BM25 weights for content, tags and source.
+    ///
+    /// Backends without per-field ranking ignore `weights`; the default
+    /// implementation delegates to [`search`](Self::search).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the search operation fails.
+    fn search_weighted(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+        weights: &FieldWeights,
+        limit: usize,
+    ) -> Result<Vec<(MemoryId, f32)>> {
+        let _ = weights;
+        self.search(query, filter, limit)
+    }
+
     /// Re-indexes all memories.
     ///
     /// Uses interior mutability for thread-safe concurrent access.
@@ -141,6 +162,24 @@ fn get_memories_batch(&self, ids: &[MemoryId]) -> Result<Vec<Option<Memory>>> {
         ids.iter().map(|id| self.get_memory(id)).collect()
     }
 
+    /// Retrieves multiple memories without their content.
+    ///
+    /// For callers that only need metadata (namespace, tags, status), such as
+    /// facet counts and listings. Content is left empty. The default
+    /// implementation fetches full memories and drops the content; backends
+    /// that store content separately should skip reading it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the operation fails.
+    fn get_memories_metadata_batch(&self, ids: &[MemoryId]) -> Result<Vec<Option<Memory>>> {
+        let mut memories = self.get_memories_batch(ids)?;
+        for memory in memories.iter_mut().flatten() {
+            memory.content = String::new();
+        }
+        Ok(memories)
+    }
+
     /// Stores a directed edge between two memories.
     ///
     /// Used by consolidation to track relationships between summary nodes
@@ -175,4 +214,93 @@ fn query_edges(
     ) -> Result<Vec<MemoryId>> {
         Ok(vec![])
     }
+
+    /// Queries edges into a given memory by edge type.
+    ///
+    /// Returns the source memory IDs for all edges matching the type.
+    /// The default implementation returns an empty list for backends
+    /// that don't support edge storage.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    fn query_incoming_edges(
+        &self,
+        _to_id: &MemoryId,
+        _edge_type: crate::models::EdgeType,
+    ) -> Result<Vec<MemoryId>> {
+        Ok(vec![])
+    }
+
+    /// Records a relevance vote for a memory on a query.
+    ///
+    /// Used by `recall --feedback`; `query` is expected to be normalized with
+    /// [`RelevanceFeedback::normalize_query`]. The default implementation
+    /// returns [`Error::NotImplemented`] for backends that don't store
+    /// feedback, so votes are never silently dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the vote cannot be stored.
+    fn record_feedback(&self, _query: &str, _memory_id: &MemoryId, _useful: bool) -> Result<()> {
+        Err(Error::NotImplemented(
+            "Relevance feedback is not supported by this index backend".to_string(),
+        ))
+    }
+
+    /// Returns the relevance votes recorded for the given memories.
+    ///
+    /// The default implementation returns an empty list for backends that
+    /// don't store feedback.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    fn feedback_for(&self, _memory_ids: &[MemoryId]) -> Result<Vec<RelevanceFeedback>> {
+        Ok(vec![])
+    }
+
+    /// Returns excerpts of the given memories around their matches for
+    /// `query`, with each matched term wrapped in `marker`.
+    ///
+    /// Memories without a content match may be left out. The default
+    /// implementation returns an empty list for backends without full-text
+    /// snippets; callers fall back to excerpts of their own.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the query fails.
+    fn snippets(
+        &self,
+        _query: &str,
+        _ids: &[MemoryId],
+        _marker: &str,
+    ) -> Result<Vec<(MemoryId, String)>> {
+        Ok(vec![])
+    }
+
+    /// Finds a live memory in `namespace` whose normalized content hashes to
+    /// `content_hash` ([`ContentHasher::hash`]).
+    ///
+    /// Used for exact-match deduplication. The default implementation looks
+    /// up the `hash:sha256:<prefix>` tag that capture adds, for backends that
+    /// don't store the hash in a column.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lookup fails.
+    fn find_by_content_hash(
+        &self,
+        namespace: Namespace,
+        content_hash: &str,
+    ) -> Result<Option<MemoryId>> {
+        let filter = SearchFilter::new()
+            .with_namespace(namespace)
+            .with_tag(ContentHasher::hash_to_tag(content_hash));
+        Ok(self
+            .list_all(&filter, 1)?
+            .into_iter()
+            .next()
+            .map(|(id, _)| id))
+    }
 }
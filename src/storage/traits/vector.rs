@@ -196,6 +196,17 @@ fn search(
         limit: usize,
     ) -> Result<Vec<(MemoryId, f32)>>;
 
+    /// Returns the stored embedding for a memory.
+    ///
+    /// Backends that cannot read embeddings back return `Ok(None)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the lookup fails.
+    fn get_embedding(&self, _id: &MemoryId) -> Result<Option<Vec<f32>>> {
+        Ok(None)
+    }
+
     /// Returns the total count of indexed embeddings.
     ///
     /// # Errors
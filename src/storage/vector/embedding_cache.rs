@@ -0,0 +1,380 @@
+//! Binary embedding cache for fast cold starts.
+//!
+//! The pure-Rust vector backend persists its index as JSON, and parsing every
+//! float of that file dominates the first recall after startup. The cache is a
+//! compact, versioned binary copy of the same embeddings written next to the
+//! index (`<index>.cache`). It is read with a single file read and each vector
+//! is decoded straight from the buffer on first access, with no parsing pass.
+//!
+//! The crate forbids `unsafe`, so the file is read rather than memory-mapped.
+//!
+//! # Staleness
+//!
+//! The cache records the embedding model, the dimensions, and a checksum of
+//! the `SQLite` index state — its memory count and latest update time (see
+//! [`EmbeddingCache::source_checksum`]) — it was built against. A cache whose metadata no longer matches, or whose
+//! own checksum fails, is ignored and rebuilt from the index. Saving the
+//! vector index also deletes the cache.
+//!
+//! # Format (version 1, little-endian)
+//!
+//! ```text
+//! magic "SCEMBED\0" | version u16 | reserved u16 | dimensions u32 | count u64
+//! source checksum u64 | model length u32 | model bytes
+//! count × (id length u32 | id bytes) | zero padding to a 4-byte boundary
+//! count × dimensions × f32 | FNV-1a checksum of all preceding bytes u64
+//! ```
+
+use crate::models::MemoryId;
+use crate::{Error, Result};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Leading bytes of every cache file.
+const MAGIC: &[u8; 8] = b"SCEMBED\0";
+
+/// Current format version.
+const VERSION: u16 = 1;
+
+/// FNV-1a 64-bit offset basis.
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// FNV-1a 64-bit prime.
+const FNV_PRIME: u64 = 0x0100_0000_01b3;
+
+/// Embeddings loaded from a cache file, decoded on access.
+pub struct EmbeddingCache {
+    /// Raw file contents.
+    bytes: Vec<u8>,
+    /// Embedding model the vectors were produced with.
+    model: String,
+    /// Vector dimensions.
+    dimensions: usize,
+    /// Checksum of the index the cache was built against.
+    source_checksum: u64,
+    /// Position of each memory's vector in the vector block.
+    positions: HashMap<String, usize>,
+    /// Offset of the first vector in `bytes`.
+    vectors_start: usize,
+}
+
+impl EmbeddingCache {
+    /// Returns the cache file used for the vector index at `index_path`.
+    #[must_use]
+    pub fn path_for(index_path: &Path) -> PathBuf {
+        let mut path = index_path.as_os_str().to_os_string();
+        path.push(".cache");
+        PathBuf::from(path)
+    }
+
+    /// Removes the cache of the vector index at `index_path`, if any.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an existing cache cannot be removed.
+    pub fn invalidate(index_path: &Path) -> Result<()> {
+        match fs::remove_file(Self::path_for(index_path)) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(Error::OperationFailed {
+                operation: "invalidate_embedding_cache".to_string(),
+                cause: e.to_string(),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checksums the state of the index from its memory count and latest
+    /// update time ([`SqliteBackend::change_stamp`]).
+    ///
+    /// Any added, removed or edited memory changes the checksum, without
+    /// reading the memories themselves.
+    ///
+    /// [`SqliteBackend::change_stamp`]: crate::storage::index::SqliteBackend::change_stamp
+    #[must_use]
+    pub fn source_checksum(count: u64, last_updated: u64) -> u64 {
+        fnv1a(
+            fnv1a(FNV_OFFSET, &count.to_le_bytes()),
+            &last_updated.to_le_bytes(),
+        )
+    }
+
+    /// Writes a cache file atomically.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a vector has the wrong dimensions or the file
+    /// cannot be written.
+    pub fn write<'a>(
+        path: &Path,
+        model: &str,
+        dimensions: usize,
+        source_checksum: u64,
+        entries: impl IntoIterator<Item = (&'a str, &'a [f32])>,
+    ) -> Result<()> {
+        let entries: Vec<(&str, &[f32])> = entries.into_iter().collect();
+        if let Some((id, vector)) = entries.iter().find(|(_, v)| v.len() != dimensions) {
+            return Err(Error::InvalidInput(format!(
+                "Cannot cache embedding for {id}: expected {dimensions} dimensions, got {}",
+                vector.len()
+            )));
+        }
+
+        let mut bytes = Vec::with_capacity(64 + entries.len() * (dimensions * 4 + 40));
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&VERSION.to_le_bytes());
+        bytes.extend_from_slice(&0u16.to_le_bytes());
+        bytes.extend_from_slice(&to_u32(dimensions)?.to_le_bytes());
+        bytes.extend_from_slice(&(entries.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&source_checksum.to_le_bytes());
+        bytes.extend_from_slice(&to_u32(model.len())?.to_le_bytes());
+        bytes.extend_from_slice(model.as_bytes());
+        for (id, _) in &entries {
+            bytes.extend_from_slice(&to_u32(id.len())?.to_le_bytes());
+            bytes.extend_from_slice(id.as_bytes());
+        }
+        bytes.resize(bytes.len().next_multiple_of(4), 0);
+        for (_, vector) in &entries {
+            for value in *vector {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        let checksum = fnv1a(FNV_OFFSET, &bytes);
+        bytes.extend_from_slice(&checksum.to_le_bytes());
+
+        let tmp = path.with_extension("cache.tmp");
+        fs::write(&tmp, &bytes)
+            .and_then(|()| fs::rename(&tmp, path))
+            .map_err(|e| Error::OperationFailed {
+                operation: "write_embedding_cache".to_string(),
+                cause: format!("{}: {e}", path.display()),
+            })
+    }
+
+    /// Loads the cache at `path`, or `None` if there is none.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be read, has another format
+    /// version, or fails its checksum.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        let bytes = match fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(Error::OperationFailed {
+                    operation: "read_embedding_cache".to_string(),
+                    cause: format!("{}: {e}", path.display()),
+                });
+            },
+        };
+        Self::parse(bytes)
+            .map(Some)
+            .map_err(|cause| Error::OperationFailed {
+                operation: "parse_embedding_cache".to_string(),
+                cause: format!("{}: {cause}", path.display()),
+            })
+    }
+
+    /// Validates and indexes the file contents.
+    fn parse(bytes: Vec<u8>) -> std::result::Result<Self, String> {
+        let Some(body_len) = bytes.len().checked_sub(8) else {
+            return Err("file is truncated".to_string());
+        };
+        let mut trailer = Reader::new(&bytes[body_len..]);
+        if trailer.u64()? != fnv1a(FNV_OFFSET, &bytes[..body_len]) {
+            return Err("checksum mismatch".to_string());
+        }
+
+        let mut reader = Reader::new(&bytes[..body_len]);
+        if reader.take(MAGIC.len())? != MAGIC {
+            return Err("not an embedding cache".to_string());
+        }
+        let version = reader.u16()?;
+        if version != VERSION {
+            return Err(format!("unsupported version {version}"));
+        }
+        reader.u16()?;
+        let dimensions = reader.len_u32()?;
+        let count = usize::try_from(reader.u64()?).map_err(|e| e.to_string())?;
+        let source_checksum = reader.u64()?;
+        let model_len = reader.len_u32()?;
+        let model = reader.string(model_len)?;
+
+        let mut positions = HashMap::with_capacity(count);
+        for position in 0..count {
+            let id_len = reader.len_u32()?;
+            positions.insert(reader.string(id_len)?, position);
+        }
+        let vectors_start = reader.pos.next_multiple_of(4);
+        let expected = count
+            .checked_mul(dimensions * 4)
+            .and_then(|size| size.checked_add(vectors_start));
+        if expected != Some(body_len) {
+            return Err("vector block has the wrong size".to_string());
+        }
+
+        Ok(Self {
+            bytes,
+            model,
+            dimensions,
+            source_checksum,
+            positions,
+            vectors_start,
+        })
+    }
+
+    /// Returns whether the cache was built for this model, dimensions and
+    /// index state.
+    #[must_use]
+    pub fn is_current(&self, model: &str, dimensions: usize, source_checksum: u64) -> bool {
+        self.model == model
+            && self.dimensions == dimensions
+            && self.source_checksum == source_checksum
+    }
+
+    /// Returns the embedding model the vectors were produced with.
+    #[must_use]
+    pub fn model(&self) -> &str {
+        &self.model
+    }
+
+    /// Returns the vector dimensions.
+    #[must_use]
+    pub const fn dimensions(&self) -> usize {
+        self.dimensions
+    }
+
+    /// Returns the number of cached embeddings.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Returns whether the cache holds no embeddings.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Returns the embedding of `id`, decoded from the file buffer.
+    #[must_use]
+    pub fn get(&self, id: &MemoryId) -> Option<Vec<f32>> {
+        self.positions
+            .get(id.as_str())
+            .map(|&position| self.vector(position))
+    }
+
+    /// Returns every cached `(id, embedding)` pair, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Vec<f32>)> {
+        self.positions
+            .iter()
+            .map(|(id, &position)| (id.as_str(), self.vector(position)))
+    }
+
+    /// Decodes the vector at `position`.
+    fn vector(&self, position: usize) -> Vec<f32> {
+        let start = self.vectors_start + position * self.dimensions * 4;
+        self.bytes[start..start + self.dimensions * 4]
+            .chunks_exact(4)
+            .map(|chunk| f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]))
+            .collect()
+    }
+}
+
+/// Sequential little-endian reader over the cache bytes.
+struct Reader<'a> {
+    /// Bytes being read.
+    bytes: &'a [u8],
+    /// Offset of the next unread byte.
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    const fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, pos: 0 }
+    }
+
+    fn take(&mut self, len: usize) -> std::result::Result<&'a [u8], String> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.bytes.len())
+            .ok_or_else(|| "file is truncated".to_string())?;
+        let slice = &self.bytes[self.pos..end];
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn array<const N: usize>(&mut self) -> std::result::Result<[u8; N], String> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take(N)?);
+        Ok(array)
+    }
+
+    fn u16(&mut self) -> std::result::Result<u16, String> {
+        self.array().map(u16::from_le_bytes)
+    }
+
+    fn u64(&mut self) -> std::result::Result<u64, String> {
+        self.array().map(u64::from_le_bytes)
+    }
+
+    fn len_u32(&mut self) -> std::result::Result<usize, String> {
+        let value = self.array().map(u32::from_le_bytes)?;
+        usize::try_from(value).map_err(|e| e.to_string())
+    }
+
+    fn string(&mut self, len: usize) -> std::result::Result<String, String> {
+        String::from_utf8(self.take(len)?.to_vec()).map_err(|e| e.to_string())
+    }
+}
+
+/// Converts a length to the `u32` stored in the file.
+fn to_u32(len: usize) -> Result<u32> {
+    u32::try_from(len).map_err(|_| Error::InvalidInput(format!("{len} is too large to cache")))
+}
+
+/// Folds `bytes` into an FNV-1a 64-bit hash.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes.iter().fold(hash, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_round_trip_and_corruption() {
+        let dir = TempDir::new().unwrap();
+        let path = EmbeddingCache::path_for(&dir.path().join("index.usearch"));
+        let (a, b) = ([1.0, -0.5, 0.25], [0.0, 2.0, f32::MIN_POSITIVE]);
+        EmbeddingCache::write(
+            &path,
+            "model",
+            3,
+            42,
+            [("mem-a", &a[..]), ("mem-b", &b[..])],
+        )
+        .unwrap();
+
+        let cache = EmbeddingCache::load(&path).unwrap().unwrap();
+        assert_eq!(cache.len(), 2);
+        assert!(cache.is_current("model", 3, 42));
+        assert!(!cache.is_current("other-model", 3, 42));
+        assert!(!cache.is_current("model", 3, 43));
+        assert_eq!(cache.get(&MemoryId::new("mem-a")).unwrap(), a);
+        assert_eq!(cache.get(&MemoryId::new("mem-b")).unwrap(), b);
+
+        let mut bytes = fs::read(&path).unwrap();
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 0xFF;
+        fs::write(&path, bytes).unwrap();
+        assert!(EmbeddingCache::load(&path).is_err());
+
+        EmbeddingCache::invalidate(&dir.path().join("index.usearch")).unwrap();
+        assert!(EmbeddingCache::load(&path).unwrap().is_none());
+    }
+}
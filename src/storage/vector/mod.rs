@@ -1,7 +1,11 @@
 //! Vector backend implementations.
 
+mod embedding_cache;
+mod pgvector;
 mod redis;
 mod usearch;
 
+pub use embedding_cache::EmbeddingCache;
+pub use pgvector::PgVectorBackend;
 pub use redis::RedisVectorBackend;
 pub use usearch::UsearchBackend;
@@ -0,0 +1,655 @@
+//! PostgreSQL pgvector backend.
+//!
+//! Stores embeddings in a standalone pgvector table, independent of the
+//! PostgreSQL index backend's `memory_vectors` table. Requires the `vector`
+//! extension to be installable on the server.
+//!
+//! # Schema
+//!
+//! | Object | Definition |
+//! |--------|------------|
+//! | `{table}` | `id TEXT PRIMARY KEY`, `embedding vector(N)`, `updated_at TIMESTAMPTZ` |
+//! | `{table}_embedding_hnsw_idx` | HNSW index with `vector_cosine_ops` (`m = 16`, `ef_construction = 64`) |
+//! | `{table}_meta` | `key TEXT PRIMARY KEY`, `value TEXT` |
+//!
+//! # Dimensions
+//!
+//! `N` comes from the configured embedder when the backend is created and is
+//! recorded in `{table}_meta` under `dimensions`. Opening an existing table
+//! with a different embedder fails with [`Error::InvalidInput`] instead of
+//! failing later on the first insert or query.
+//!
+//! # Filtering
+//!
+//! The table holds only ids and embeddings. Namespace and domain filters join
+//! the index table set with `with_index_table` (the PostgreSQL index
+//! backend's `memories`) and are applied in the `WHERE` clause, before the
+//! `LIMIT`. Filtering without an index table is an [`Error::InvalidInput`].
+//! `min_score` is applied to the ranked hits.
+//!
+//! [`Error::InvalidInput`]: crate::Error::InvalidInput
+
+#[cfg(feature = "postgres")]
+#[allow(clippy::excessive_nesting, clippy::future_not_send)]
+mod implementation {
+    use crate::embedding::Embedder;
+    use crate::models::MemoryId;
+    use crate::storage::traits::{VectorBackend, VectorFilter};
+    use crate::{Error, Result};
+    use deadpool_postgres::{Config, ManagerConfig, Pool, RecyclingMethod, Runtime};
+    use tokio::runtime::Handle;
+    use tokio_postgres::NoTls;
+
+    /// Default table name.
+    const DEFAULT_TABLE_NAME: &str = "vectors";
+
+    /// Meta table key holding the embedding dimensions.
+    const DIMENSIONS_KEY: &str = "dimensions";
+
+    /// Longest table name that leaves room for the `_meta` suffix.
+    const MAX_TABLE_NAME_LEN: usize = 58;
+
+    /// pgvector limit for indexed `vector` columns.
+    const MAX_INDEXED_DIMENSIONS: usize = 2000;
+
+    /// Vector backend using a standalone pgvector table.
+    pub struct PgVectorBackend {
+        /// Connection pool.
+        pool: Pool,
+        /// Table holding the embeddings.
+        table_name: String,
+        /// Embedding dimensions recorded in the meta table.
+        dimensions: usize,
+        /// Index table joined for namespace and domain filters.
+        index_table: Option<String>,
+    }
+
+    impl PgVectorBackend {
+        /// Creates a backend using the `vectors` table.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the connection pool cannot be created, the
+        /// schema cannot be initialized, or the table already stores
+        /// embeddings of a different dimensionality.
+        pub fn new(connection_url: &str, dimensions: usize) -> Result<Self> {
+            Self::with_table(connection_url, DEFAULT_TABLE_NAME, dimensions)
+        }
+
+        /// Creates a backend sized for `embedder`.
+        ///
+        /// # Errors
+        ///
+        /// See [`Self::new`].
+        pub fn for_embedder(connection_url: &str, embedder: &dyn Embedder) -> Result<Self> {
+            Self::new(connection_url, embedder.dimensions())
+        }
+
+        /// Creates a backend using a custom table name.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the table name is not a valid identifier, the
+        /// dimensions are out of range, or initialization fails as in
+        /// [`Self::new`].
+        pub fn with_table(
+            connection_url: &str,
+            table_name: impl Into<String>,
+            dimensions: usize,
+        ) -> Result<Self> {
+            let table_name = table_name.into();
+            validate_table_name(&table_name)?;
+            if dimensions == 0 || dimensions > MAX_INDEXED_DIMENSIONS {
+                return Err(Error::InvalidInput(format!(
+                    "dimensions: must be between 1 and {MAX_INDEXED_DIMENSIONS}, got {dimensions}"
+                )));
+            }
+
+            let mut cfg = Config::new();
+            cfg.url = Some(connection_url.to_string());
+            cfg.manager = Some(ManagerConfig {
+                recycling_method: RecyclingMethod::Fast,
+            });
+            let pool = cfg.create_pool(Some(Runtime::Tokio1), NoTls).map_err(|e| {
+                Error::OperationFailed {
+                    operation: "pgvector_create_pool".to_string(),
+                    cause: e.to_string(),
+                }
+            })?;
+
+            let backend = Self {
+                pool,
+                table_name,
+                dimensions,
+                index_table: None,
+            };
+            block_on(backend.ensure_schema())?;
+            Ok(backend)
+        }
+
+        /// Joins `index_table` (`id`, `namespace`, `domain` columns) to apply
+        /// namespace and domain filters.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the table name is not a valid identifier.
+        pub fn with_index_table(mut self, index_table: impl Into<String>) -> Result<Self> {
+            let index_table = index_table.into();
+            validate_table_name(&index_table)?;
+            self.index_table = Some(index_table);
+            Ok(self)
+        }
+
+        /// Returns the table name.
+        #[must_use]
+        pub fn table_name(&self) -> &str {
+            &self.table_name
+        }
+
+        /// Creates the extension and tables, recording the dimensions on first
+        /// use and rejecting a mismatch afterwards.
+        async fn ensure_schema(&self) -> Result<()> {
+            let client = self.pool.get().await.map_err(pool_error)?;
+            let table = &self.table_name;
+
+            client
+                .batch_execute(&format!(
+                    "CREATE EXTENSION IF NOT EXISTS vector;
+                    CREATE TABLE IF NOT EXISTS {table}_meta (
+                        key TEXT PRIMARY KEY,
+                        value TEXT NOT NULL
+                    );"
+                ))
+                .await
+                .map_err(|e| query_error("pgvector_create_meta", e))?;
+
+            client
+                .execute(
+                    &format!(
+                        "INSERT INTO {table}_meta (key, value) VALUES ($1, $2)
+                        ON CONFLICT (key) DO NOTHING"
+                    ),
+                    &[&DIMENSIONS_KEY, &self.dimensions.to_string()],
+                )
+                .await
+                .map_err(|e| query_error("pgvector_record_dimensions", e))?;
+            let row = client
+                .query_one(
+                    &format!("SELECT value FROM {table}_meta WHERE key = $1"),
+                    &[&DIMENSIONS_KEY],
+                )
+                .await
+                .map_err(|e| query_error("pgvector_read_dimensions", e))?;
+            let stored: String = row.get(0);
+            check_dimensions(table, &stored, self.dimensions)?;
+
+            let dimensions = self.dimensions;
+            client
+                .batch_execute(&format!(
+                    "CREATE TABLE IF NOT EXISTS {table} (
+                        id TEXT PRIMARY KEY,
+                        embedding vector({dimensions}) NOT NULL,
+                        updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+                    );
+                    CREATE INDEX IF NOT EXISTS {table}_embedding_hnsw_idx ON {table}
+                        USING hnsw (embedding vector_cosine_ops)
+                        WITH (m = 16, ef_construction = 64);"
+                ))
+                .await
+                .map_err(|e| query_error("pgvector_create_table", e))?;
+
+            Ok(())
+        }
+
+        /// Rejects embeddings that don't match the table's dimensions.
+        fn validate_embedding(&self, embedding: &[f32]) -> Result<()> {
+            if embedding.len() == self.dimensions {
+                Ok(())
+            } else {
+                Err(Error::InvalidInput(format!(
+                    "Embedding dimension mismatch: expected {}, got {}",
+                    self.dimensions,
+                    embedding.len()
+                )))
+            }
+        }
+
+        async fn upsert_async(&self, id: &MemoryId, embedding: &[f32]) -> Result<()> {
+            let client = self.pool.get().await.map_err(pool_error)?;
+            let upsert = format!(
+                "INSERT INTO {} (id, embedding) VALUES ($1, $2::text::vector)
+                ON CONFLICT (id) DO UPDATE SET
+                    embedding = EXCLUDED.embedding,
+                    updated_at = NOW()",
+                self.table_name
+            );
+            client
+                .execute(&upsert, &[&id.as_str(), &format_embedding(embedding)])
+                .await
+                .map_err(|e| query_error("pgvector_upsert", e))?;
+            Ok(())
+        }
+
+        async fn remove_async(&self, id: &MemoryId) -> Result<bool> {
+            let client = self.pool.get().await.map_err(pool_error)?;
+            let delete = format!("DELETE FROM {} WHERE id = $1", self.table_name);
+            let rows = client
+                .execute(&delete, &[&id.as_str()])
+                .await
+                .map_err(|e| query_error("pgvector_remove", e))?;
+            Ok(rows > 0)
+        }
+
+        /// Returns cosine similarity (1 - cosine distance), best first.
+        async fn search_async(
+            &self,
+            query_embedding: &[f32],
+            filter: &VectorFilter,
+            limit: usize,
+        ) -> Result<Vec<(MemoryId, f32)>> {
+            let (filter_clause, filter_params) =
+                filter_clause(self.index_table.as_deref(), filter)?;
+            let client = self.pool.get().await.map_err(pool_error)?;
+            let search = format!(
+                "SELECT v.id, 1 - (v.embedding <=> $1::text::vector) AS similarity
+                FROM {} v
+                {filter_clause}
+                ORDER BY v.embedding <=> $1::text::vector
+                LIMIT {limit}",
+                self.table_name
+            );
+            let embedding = format_embedding(query_embedding);
+            let mut params: Vec<&(dyn tokio_postgres::types::ToSql + Sync)> = vec![&embedding];
+            params.extend(
+                filter_params
+                    .iter()
+                    .map(|p| p as &(dyn tokio_postgres::types::ToSql + Sync)),
+            );
+            let rows = client
+                .query(&search, &params)
+                .await
+                .map_err(|e| query_error("pgvector_search", e))?;
+
+            let min_score = filter.min_score.unwrap_or(f32::MIN);
+            Ok(rows
+                .iter()
+                .map(|row| {
+                    let id: String = row.get(0);
+                    let similarity: f64 = row.get(1);
+                    #[allow(clippy::cast_possible_truncation)]
+                    (MemoryId::new(id), similarity as f32)
+                })
+                .filter(|(_, score)| *score >= min_score)
+                .collect())
+        }
+
+        async fn get_embedding_async(&self, id: &MemoryId) -> Result<Option<Vec<f32>>> {
+            let client = self.pool.get().await.map_err(pool_error)?;
+            let select = format!(
+                "SELECT embedding::text FROM {} WHERE id = $1",
+                self.table_name
+            );
+            let row = client
+                .query_opt(&select, &[&id.as_str()])
+                .await
+                .map_err(|e| query_error("pgvector_get_embedding", e))?;
+            row.map(|row| parse_embedding(&row.get::<_, String>(0)))
+                .transpose()
+        }
+
+        #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+        async fn count_async(&self) -> Result<usize> {
+            let client = self.pool.get().await.map_err(pool_error)?;
+            let query = format!("SELECT COUNT(*) FROM {}", self.table_name);
+            let row = client
+                .query_one(&query, &[])
+                .await
+                .map_err(|e| query_error("pgvector_count", e))?;
+            let count: i64 = row.get(0);
+            Ok(count as usize)
+        }
+
+        async fn clear_async(&self) -> Result<()> {
+            let client = self.pool.get().await.map_err(pool_error)?;
+            let truncate = format!("TRUNCATE TABLE {}", self.table_name);
+            client
+                .execute(&truncate, &[])
+                .await
+                .map_err(|e| query_error("pgvector_clear", e))?;
+            Ok(())
+        }
+    }
+
+    impl VectorBackend for PgVectorBackend {
+        fn dimensions(&self) -> usize {
+            self.dimensions
+        }
+
+        fn upsert(&self, id: &MemoryId, embedding: &[f32]) -> Result<()> {
+            self.validate_embedding(embedding)?;
+            block_on(self.upsert_async(id, embedding))
+        }
+
+        fn remove(&self, id: &MemoryId) -> Result<bool> {
+            block_on(self.remove_async(id))
+        }
+
+        fn search(
+            &self,
+            query_embedding: &[f32],
+            filter: &VectorFilter,
+            limit: usize,
+        ) -> Result<Vec<(MemoryId, f32)>> {
+            self.validate_embedding(query_embedding)?;
+            block_on(self.search_async(query_embedding, filter, limit))
+        }
+
+        fn get_embedding(&self, id: &MemoryId) -> Result<Option<Vec<f32>>> {
+            block_on(self.get_embedding_async(id))
+        }
+
+        fn count(&self) -> Result<usize> {
+            block_on(self.count_async())
+        }
+
+        fn clear(&self) -> Result<()> {
+            block_on(self.clear_async())
+        }
+    }
+
+    /// Runs a future to completion from synchronous code.
+    fn block_on<F, T>(f: F) -> Result<T>
+    where
+        F: std::future::Future<Output = Result<T>>,
+    {
+        if let Ok(handle) = Handle::try_current() {
+            tokio::task::block_in_place(|| handle.block_on(f))
+        } else {
+            tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .map_err(|e| Error::OperationFailed {
+                    operation: "pgvector_create_runtime".to_string(),
+                    cause: e.to_string(),
+                })?
+                .block_on(f)
+        }
+    }
+
+    /// Validates that a table name is a safe PostgreSQL identifier.
+    fn validate_table_name(name: &str) -> Result<()> {
+        let starts_ok = name
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+        let chars_ok = name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if starts_ok && chars_ok && name.len() <= MAX_TABLE_NAME_LEN {
+            Ok(())
+        } else {
+            Err(Error::InvalidInput(format!(
+                "table_name: '{name}' must be 1-{MAX_TABLE_NAME_LEN} letters, digits, or \
+                 underscores, starting with a letter or underscore"
+            )))
+        }
+    }
+
+    /// Builds the join and `WHERE` clause for the namespace and domain
+    /// filters, with their parameters (numbered from `$2`).
+    fn filter_clause(
+        index_table: Option<&str>,
+        filter: &VectorFilter,
+    ) -> Result<(String, Vec<Vec<String>>)> {
+        if filter.namespaces.is_empty() && filter.domains.is_empty() {
+            return Ok((String::new(), Vec::new()));
+        }
+        let Some(index_table) = index_table else {
+            return Err(Error::InvalidInput(
+                "pgvector: namespace and domain filters need an index table \
+                 (with_index_table)"
+                    .to_string(),
+            ));
+        };
+
+        let mut clauses = Vec::new();
+        let mut params: Vec<Vec<String>> = Vec::new();
+        if !filter.namespaces.is_empty() {
+            params.push(
+                filter
+                    .namespaces
+                    .iter()
+                    .map(|ns| ns.as_str().to_string())
+                    .collect(),
+            );
+            clauses.push(format!("m.namespace = ANY(${})", params.len() + 1));
+        }
+        if !filter.domains.is_empty() {
+            params.push(filter.domains.iter().map(ToString::to_string).collect());
+            clauses.push(format!("m.domain = ANY(${})", params.len() + 1));
+        }
+        Ok((
+            format!(
+                "JOIN {index_table} m ON m.id = v.id WHERE {}",
+                clauses.join(" AND ")
+            ),
+            params,
+        ))
+    }
+
+    /// Compares the dimensions stored in the meta table with the embedder's.
+    fn check_dimensions(table: &str, stored: &str, configured: usize) -> Result<()> {
+        let stored: usize = stored.parse().map_err(|_| Error::OperationFailed {
+            operation: "pgvector_read_dimensions".to_string(),
+            cause: format!("invalid dimensions value '{stored}' in {table}_meta"),
+        })?;
+        if stored == configured {
+            Ok(())
+        } else {
+            Err(Error::InvalidInput(format!(
+                "pgvector table '{table}' stores {stored}-dimensional embeddings but the \
+                 embedder produces {configured}; use a different table or clear it and reindex"
+            )))
+        }
+    }
+
+    /// Formats an embedding as a pgvector literal: `[1,2.5,3]`.
+    fn format_embedding(embedding: &[f32]) -> String {
+        let values: Vec<String> = embedding.iter().map(ToString::to_string).collect();
+        format!("[{}]", values.join(","))
+    }
+
+    /// Parses a pgvector text literal back into an embedding.
+    fn parse_embedding(text: &str) -> Result<Vec<f32>> {
+        let inner = text
+            .trim()
+            .strip_prefix('[')
+            .and_then(|s| s.strip_suffix(']'))
+            .ok_or_else(|| Error::OperationFailed {
+                operation: "pgvector_parse_embedding".to_string(),
+                cause: format!("not a vector literal: {text}"),
+            })?;
+        if inner.is_empty() {
+            return Ok(Vec::new());
+        }
+        inner
+            .split(',')
+            .map(|v| {
+                v.trim().parse::<f32>().map_err(|e| Error::OperationFailed {
+                    operation: "pgvector_parse_embedding".to_string(),
+                    cause: e.to_string(),
+                })
+            })
+            .collect()
+    }
+
+    /// Maps pool errors.
+    fn pool_error(e: impl std::fmt::Debug) -> Error {
+        Error::OperationFailed {
+            operation: "pgvector_get_client".to_string(),
+            cause: format!("{e:?}"),
+        }
+    }
+
+    /// Maps query errors.
+    fn query_error(op: &str, e: impl std::fmt::Debug) -> Error {
+        Error::OperationFailed {
+            operation: op.to_string(),
+            cause: format!("{e:?}"),
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_embedding_literal_round_trip() {
+            let embedding = vec![1.0, -0.25, 3.5];
+            let text = format_embedding(&embedding);
+            assert_eq!(text, "[1,-0.25,3.5]");
+            assert_eq!(parse_embedding(&text).unwrap(), embedding);
+            assert!(parse_embedding("1,2").is_err());
+        }
+
+        #[test]
+        fn test_dimension_mismatch_is_invalid_input() {
+            assert!(check_dimensions("vectors", "384", 384).is_ok());
+            let err = check_dimensions("vectors", "384", 768).unwrap_err();
+            assert!(matches!(err, Error::InvalidInput(ref msg) if msg.contains("384")));
+            assert!(validate_table_name("org_vectors").is_ok());
+            assert!(validate_table_name("vectors; DROP TABLE x").is_err());
+        }
+
+        #[test]
+        fn test_filters_join_the_index_table() {
+            use crate::models::Namespace;
+
+            let (clause, params) = filter_clause(None, &VectorFilter::new()).unwrap();
+            assert!(clause.is_empty() && params.is_empty());
+
+            let filter = VectorFilter::new().with_namespace(Namespace::Decisions);
+            assert!(matches!(
+                filter_clause(None, &filter),
+                Err(Error::InvalidInput(_))
+            ));
+            let (clause, params) = filter_clause(Some("memories"), &filter).unwrap();
+            assert_eq!(
+                clause,
+                "JOIN memories m ON m.id = v.id WHERE m.namespace = ANY($2)"
+            );
+            assert_eq!(params, vec![vec!["decisions".to_string()]]);
+        }
+    }
+}
+
+#[cfg(feature = "postgres")]
+pub use implementation::PgVectorBackend;
+
+#[cfg(not(feature = "postgres"))]
+mod stub {
+    use crate::embedding::Embedder;
+    use crate::models::MemoryId;
+    use crate::storage::traits::{VectorBackend, VectorFilter};
+    use crate::{Error, Result};
+
+    /// Stub pgvector backend when the `postgres` feature is not enabled.
+    pub struct PgVectorBackend {
+        table_name: String,
+        dimensions: usize,
+    }
+
+    impl PgVectorBackend {
+        /// Creates a backend using the `vectors` table.
+        ///
+        /// # Errors
+        ///
+        /// Always returns [`Error::FeatureNotEnabled`].
+        pub fn new(_connection_url: &str, _dimensions: usize) -> Result<Self> {
+            Err(Error::FeatureNotEnabled("postgres".to_string()))
+        }
+
+        /// Creates a backend sized for `embedder`.
+        ///
+        /// # Errors
+        ///
+        /// Always returns [`Error::FeatureNotEnabled`].
+        pub fn for_embedder(connection_url: &str, embedder: &dyn Embedder) -> Result<Self> {
+            Self::new(connection_url, embedder.dimensions())
+        }
+
+        /// Creates a backend using a custom table name.
+        ///
+        /// # Errors
+        ///
+        /// Always returns [`Error::FeatureNotEnabled`].
+        pub fn with_table(
+            connection_url: &str,
+            _table_name: impl Into<String>,
+            dimensions: usize,
+        ) -> Result<Self> {
+            Self::new(connection_url, dimensions)
+        }
+
+        /// Joins `index_table` to apply namespace and domain filters.
+        ///
+        /// # Errors
+        ///
+        /// Never fails; the stub cannot be constructed.
+        pub fn with_index_table(self, _index_table: impl Into<String>) -> Result<Self> {
+            Ok(self)
+        }
+
+        /// Returns the table name.
+        #[must_use]
+        pub fn table_name(&self) -> &str {
+            &self.table_name
+        }
+    }
+
+    impl VectorBackend for PgVectorBackend {
+        fn dimensions(&self) -> usize {
+            self.dimensions
+        }
+
+        fn upsert(&self, _id: &MemoryId, _embedding: &[f32]) -> Result<()> {
+            Err(Error::FeatureNotEnabled("postgres".to_string()))
+        }
+
+        fn remove(&self, _id: &MemoryId) -> Result<bool> {
+            Err(Error::FeatureNotEnabled("postgres".to_string()))
+        }
+
+        fn search(
+            &self,
+            _query_embedding: &[f32],
+            _filter: &VectorFilter,
+            _limit: usize,
+        ) -> Result<Vec<(MemoryId, f32)>> {
+            Err(Error::FeatureNotEnabled("postgres".to_string()))
+        }
+
+        fn count(&self) -> Result<usize> {
+            Err(Error::FeatureNotEnabled("postgres".to_string()))
+        }
+
+        fn clear(&self) -> Result<()> {
+            Err(Error::FeatureNotEnabled("postgres".to_string()))
+        }
+    }
+}
+
+#[cfg(not(feature = "postgres"))]
+pub use stub::PgVectorBackend;
+
+#[cfg(all(test, not(feature = "postgres")))]
+mod tests {
+    use super::*;
+    use crate::Error;
+
+    #[test]
+    fn test_pgvector_requires_postgres_feature() {
+        assert!(matches!(
+            PgVectorBackend::new("postgresql://localhost/subcog", 384),
+            Err(Error::FeatureNotEnabled(_))
+        ));
+    }
+}
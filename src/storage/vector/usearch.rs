@@ -11,7 +11,7 @@
 use crate::{Error, Result};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::{Mutex, MutexGuard, PoisonError};
 
 /// Helper to recover from mutex poisoning (CRIT-002).
@@ -32,6 +32,29 @@ fn recover_lock<'a, T>(
 /// Default embedding dimensions for all-MiniLM-L6-v2.
 pub const DEFAULT_USEARCH_DIMENSIONS: usize = 384;
 
+/// Dimensions recorded in a saved index, read without keeping its vectors.
+#[derive(serde::Deserialize)]
+struct StoredDimensions {
+    dimensions: usize,
+}
+
+/// Reads the dimensions recorded in `file`, or `None` if it does not exist.
+fn read_stored_dimensions(file: &Path) -> Result<Option<usize>> {
+    if !file.exists() {
+        return Ok(None);
+    }
+    let content = fs::read_to_string(file).map_err(|e| Error::OperationFailed {
+        operation: "read_index_dimensions".to_string(),
+        cause: e.to_string(),
+    })?;
+    let stored: StoredDimensions =
+        serde_json::from_str(&content).map_err(|e| Error::OperationFailed {
+            operation: "parse_index_dimensions".to_string(),
+            cause: e.to_string(),
+        })?;
+    Ok(Some(stored.dimensions))
+}
+
 /// HNSW connectivity parameter (M).
 /// Higher values improve recall but use more memory.
 #[cfg(feature = "usearch-hnsw")]
@@ -55,8 +78,8 @@ fn recover_lock<'a, T>(
 mod native {
     use super::{
         DEFAULT_USEARCH_DIMENSIONS, Error, HNSW_CONNECTIVITY, HNSW_EXPANSION_ADD,
-        HNSW_EXPANSION_SEARCH, HashMap, MemoryId, Mutex, PathBuf, Result, VectorBackend,
-        VectorFilter, fs, recover_lock,
+        HNSW_EXPANSION_SEARCH, HashMap, MemoryId, Mutex, Path, PathBuf, Result, VectorBackend,
+        VectorFilter, fs, read_stored_dimensions, recover_lock,
     };
     use usearch::{Index, IndexOptions, MetricKind, ScalarKind};
 
@@ -160,6 +183,29 @@ pub const fn index_path(&self) -> &PathBuf {
             &self.index_path
         }
 
+        /// Returns the files an index saved at `index_path` consists of.
+        ///
+        /// The metadata file comes last so that replacing the files in order
+        /// only exposes the new dimensions once the new index is in place.
+        #[must_use]
+        pub fn index_files(index_path: &Path) -> Vec<PathBuf> {
+            vec![
+                index_path.with_extension("usearch"),
+                index_path.with_extension("meta.json"),
+            ]
+        }
+
+        /// Returns the dimensions recorded for the index saved at `index_path`.
+        ///
+        /// Returns `None` if no index has been saved there.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the metadata file cannot be read or parsed.
+        pub fn stored_dimensions(index_path: &Path) -> Result<Option<usize>> {
+            read_stored_dimensions(&index_path.with_extension("meta.json"))
+        }
+
         /// Loads the index from disk (copies entire index into RAM).
         ///
         /// For large datasets (>100K vectors), consider using [`Self::load_mmap`] instead
@@ -414,6 +460,24 @@ fn remove(&self, id: &MemoryId) -> Result<bool> {
             }
         }
 
+        fn get_embedding(&self, id: &MemoryId) -> Result<Option<Vec<f32>>> {
+            let state = recover_lock(self.state.lock());
+            let Some(&key) = state.id_to_key.get(id.as_str()) else {
+                return Ok(None);
+            };
+
+            let mut embedding = vec![0.0; self.dimensions];
+            let found =
+                state
+                    .index
+                    .get(key, &mut embedding)
+                    .map_err(|e| Error::OperationFailed {
+                        operation: "usearch_get".to_string(),
+                        cause: e.to_string(),
+                    })?;
+            Ok((found > 0).then_some(embedding))
+        }
+
         fn search(
             &self,
             query_embedding: &[f32],
@@ -496,18 +560,35 @@ fn drop(&mut self) {
 #[cfg(not(feature = "usearch-hnsw"))]
 mod fallback {
     use super::{
-        DEFAULT_USEARCH_DIMENSIONS, Error, HashMap, MemoryId, Mutex, PathBuf, Result,
-        VectorBackend, VectorFilter, fs, recover_lock,
+        DEFAULT_USEARCH_DIMENSIONS, Error, HashMap, MemoryId, Mutex, Path, PathBuf, Result,
+        VectorBackend, VectorFilter, fs, read_stored_dimensions, recover_lock,
     };
+    use crate::storage::vector::EmbeddingCache;
 
     /// Inner mutable state protected by a Mutex.
     struct InnerState {
         /// In-memory vector storage: `memory_id` -> embedding.
         vectors: HashMap<String, Vec<f32>>,
+        /// Embedding cache loaded at startup whose vectors have not been
+        /// decoded into `vectors` yet.
+        cached: Option<EmbeddingCache>,
         /// Whether the index has been modified since last save.
         dirty: bool,
     }
 
+    impl InnerState {
+        /// Returns the vectors, decoding a loaded embedding cache on first use.
+        fn vectors(&mut self) -> &mut HashMap<String, Vec<f32>> {
+            if let Some(cache) = self.cached.take() {
+                self.vectors = cache
+                    .iter()
+                    .map(|(id, vector)| (id.to_string(), vector))
+                    .collect();
+            }
+            &mut self.vectors
+        }
+    }
+
     /// Pure-Rust fallback vector backend.
     ///
     /// This is a brute-force O(n) implementation used when the `usearch-hnsw`
@@ -531,6 +612,7 @@ impl UsearchBackend {
         pub fn new(index_path: impl Into<PathBuf>, dimensions: usize) -> Self {
             let state = InnerState {
                 vectors: HashMap::new(),
+                cached: None,
                 dirty: false,
             };
             Self {
@@ -554,6 +636,7 @@ pub fn in_memory(dimensions: usize) -> Self {
                 dimensions,
                 state: Mutex::new(InnerState {
                     vectors: HashMap::new(),
+                    cached: None,
                     dirty: false,
                 }),
             }
@@ -565,6 +648,25 @@ pub const fn index_path(&self) -> &PathBuf {
             &self.index_path
         }
 
+        /// Returns the files an index saved at `index_path` consists of.
+        ///
+        /// The fallback stores vectors and metadata in a single file.
+        #[must_use]
+        pub fn index_files(index_path: &Path) -> Vec<PathBuf> {
+            vec![index_path.to_path_buf()]
+        }
+
+        /// Returns the dimensions recorded for the index saved at `index_path`.
+        ///
+        /// Returns `None` if no index has been saved there.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the index file cannot be read or parsed.
+        pub fn stored_dimensions(index_path: &Path) -> Result<Option<usize>> {
+            read_stored_dimensions(index_path)
+        }
+
         /// Loads the index from disk.
         ///
         /// # Errors
@@ -601,11 +703,70 @@ pub fn load(&self) -> Result<()> {
             let mut state = recover_lock(self.state.lock());
 
             state.vectors = data.vectors;
+            state.cached = None;
             state.dirty = false;
 
             Ok(())
         }
 
+        /// Loads the index through its binary embedding cache.
+        ///
+        /// Uses the cache when it was built for `model`, these dimensions, and
+        /// the index state identified by `source_checksum`. Otherwise the
+        /// JSON index is loaded and the cache rebuilt from it. Cached vectors
+        /// are decoded on first access, so startup only reads the file.
+        ///
+        /// Returns whether the cache was used.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the JSON index cannot be read or parsed.
+        /// Cache failures are logged and fall back to the JSON index.
+        pub fn load_cached(&self, model: &str, source_checksum: u64) -> Result<bool> {
+            if self.index_path.as_os_str().is_empty() {
+                return Ok(false);
+            }
+            let cache_path = EmbeddingCache::path_for(&self.index_path);
+
+            match EmbeddingCache::load(&cache_path) {
+                Ok(Some(cache)) if cache.is_current(model, self.dimensions, source_checksum) => {
+                    // Vectors are decoded on first access, not at startup
+                    let mut state = recover_lock(self.state.lock());
+                    state.vectors.clear();
+                    state.cached = Some(cache);
+                    state.dirty = false;
+                    return Ok(true);
+                },
+                Ok(Some(_)) => {
+                    tracing::debug!(path = %cache_path.display(), "Embedding cache is stale");
+                },
+                Ok(None) => {},
+                Err(e) => {
+                    tracing::warn!(error = %e, "Ignoring unreadable embedding cache");
+                },
+            }
+
+            self.load()?;
+            if !self.index_path.exists() {
+                return Ok(false);
+            }
+            let state = recover_lock(self.state.lock());
+            let entries = state
+                .vectors
+                .iter()
+                .map(|(id, vector)| (id.as_str(), vector.as_slice()));
+            if let Err(e) = EmbeddingCache::write(
+                &cache_path,
+                model,
+                self.dimensions,
+                source_checksum,
+                entries,
+            ) {
+                tracing::warn!(error = %e, "Failed to rebuild embedding cache");
+            }
+            Ok(false)
+        }
+
         /// Loads the index using memory mapping (PERF-M2).
         ///
         /// Note: The fallback implementation does not support true memory mapping.
@@ -647,7 +808,7 @@ pub fn save(&self) -> Result<()> {
 
             let data = IndexData {
                 dimensions: self.dimensions,
-                vectors: state.vectors.clone(),
+                vectors: state.vectors().clone(),
             };
 
             let content = serde_json::to_string(&data).map_err(|e| Error::OperationFailed {
@@ -667,6 +828,7 @@ pub fn save(&self) -> Result<()> {
                 operation: "write_index".to_string(),
                 cause: e.to_string(),
             })?;
+            EmbeddingCache::invalidate(&self.index_path)?;
 
             state.dirty = false;
             Ok(())
@@ -721,7 +883,7 @@ fn upsert(&self, id: &MemoryId, embedding: &[f32]) -> Result<()> {
             let mut state = recover_lock(self.state.lock());
 
             state
-                .vectors
+                .vectors()
                 .insert(id.as_str().to_string(), embedding.to_vec());
             state.dirty = true;
 
@@ -731,13 +893,21 @@ fn upsert(&self, id: &MemoryId, embedding: &[f32]) -> Result<()> {
         fn remove(&self, id: &MemoryId) -> Result<bool> {
             let mut state = recover_lock(self.state.lock());
 
-            let removed = state.vectors.remove(id.as_str()).is_some();
+            let removed = state.vectors().remove(id.as_str()).is_some();
             if removed {
                 state.dirty = true;
             }
             Ok(removed)
         }
 
+        fn get_embedding(&self, id: &MemoryId) -> Result<Option<Vec<f32>>> {
+            let state = recover_lock(self.state.lock());
+            if let Some(cache) = &state.cached {
+                return Ok(cache.get(id));
+            }
+            Ok(state.vectors.get(id.as_str()).cloned())
+        }
+
         fn search(
             &self,
             query_embedding: &[f32],
@@ -746,11 +916,11 @@ fn search(
         ) -> Result<Vec<(MemoryId, f32)>> {
             self.validate_embedding(query_embedding)?;
 
-            let state = recover_lock(self.state.lock());
+            let mut state = recover_lock(self.state.lock());
 
             // Compute similarity for all vectors (brute-force O(n))
             let mut scores: Vec<(String, f32)> = state
-                .vectors
+                .vectors()
                 .iter()
                 .map(|(id, vec)| {
                     let score = Self::cosine_similarity(query_embedding, vec);
@@ -773,11 +943,15 @@ fn search(
 
         fn count(&self) -> Result<usize> {
             let state = recover_lock(self.state.lock());
-            Ok(state.vectors.len())
+            Ok(state
+                .cached
+                .as_ref()
+                .map_or(state.vectors.len(), EmbeddingCache::len))
         }
 
         fn clear(&self) -> Result<()> {
             let mut state = recover_lock(self.state.lock());
+            state.cached = None;
             state.vectors.clear();
             state.dirty = true;
             Ok(())
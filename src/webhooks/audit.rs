@@ -25,11 +25,12 @@
 //! );
 //! ```
 
+use crate::storage::{acquire_lock_with_timeout, mutex_lock_timeout};
 use crate::{Error, Result};
 use rusqlite::{Connection, params};
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use std::sync::Mutex;
+use std::sync::{Mutex, MutexGuard};
 
 /// Delivery status for audit logging.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -272,12 +273,14 @@ pub fn in_memory() -> Result<Self> {
         Ok(logger)
     }
 
+    /// Locks the connection and returns a guard.
+    fn lock_conn(&self) -> Result<MutexGuard<'_, Connection>> {
+        acquire_lock_with_timeout(&self.conn, mutex_lock_timeout())
+    }
+
     /// Creates the database schema.
     fn create_schema(&self) -> Result<()> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_audit_db".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = self.lock_conn()?;
 
         conn.execute_batch(
             r"
@@ -346,10 +349,7 @@ pub fn log_delivery(
 #[allow(clippy::significant_drop_tightening)]
 impl WebhookAuditBackend for WebhookAuditLogger {
     fn store(&self, record: &DeliveryRecord) -> Result<()> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_audit_db".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = self.lock_conn()?;
 
         conn.execute(
             r"
@@ -382,10 +382,7 @@ fn store(&self, record: &DeliveryRecord) -> Result<()> {
     }
 
     fn get_history(&self, webhook_name: &str, limit: usize) -> Result<Vec<DeliveryRecord>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_audit_db".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = self.lock_conn()?;
 
         let mut stmt = conn
             .prepare(
@@ -435,10 +432,7 @@ fn get_history(&self, webhook_name: &str, limit: usize) -> Result<Vec<DeliveryRe
     }
 
     fn export_domain_logs(&self, domain: &str) -> Result<Vec<DeliveryRecord>> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_audit_db".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = self.lock_conn()?;
 
         let mut stmt = conn
             .prepare(
@@ -486,10 +480,7 @@ fn export_domain_logs(&self, domain: &str) -> Result<Vec<DeliveryRecord>> {
     }
 
     fn delete_domain_logs(&self, domain: &str) -> Result<usize> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_audit_db".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = self.lock_conn()?;
 
         let count = conn
             .execute(
@@ -505,10 +496,7 @@ fn delete_domain_logs(&self, domain: &str) -> Result<usize> {
     }
 
     fn count_by_status(&self, webhook_name: &str) -> Result<WebhookStats> {
-        let conn = self.conn.lock().map_err(|e| Error::OperationFailed {
-            operation: "lock_audit_db".to_string(),
-            cause: e.to_string(),
-        })?;
+        let conn = self.lock_conn()?;
 
         let mut stmt = conn
             .prepare(
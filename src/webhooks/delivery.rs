@@ -322,6 +322,7 @@ fn deliver(
         endpoint: &WebhookEndpoint,
         payload: &WebhookPayload,
     ) -> Result<DeliveryResult> {
+        crate::config::ensure_online("deliver_webhook")?;
         Ok(self.deliver_with_retry(endpoint, payload))
     }
 }
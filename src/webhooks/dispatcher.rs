@@ -1,12 +1,13 @@
 //! Event dispatcher for routing memory events to webhooks.
 //!
-//! The dispatcher subscribes to the global event bus and routes matching
-//! events to configured webhook endpoints, handling delivery and audit logging.
+//! The dispatcher is a [`Notifier`]: a [`crate::notifications::NotificationService`]
+//! hands it events from the global event bus, and it routes matching events to
+//! configured webhook endpoints, handling delivery and audit logging.
 //!
 //! # Architecture
 //!
 //! ```text
-//! EventBus --[subscribe]--> Dispatcher --[filter]--> Webhook Endpoints
+//! EventBus --[subscribe]--> NotificationService --> Dispatcher --[filter]--> Webhook Endpoints
 //!                                |                         |
 //!                                v                         v
 //!                          Domain Filter            Delivery Backend
@@ -17,7 +18,7 @@
 //!
 //! # Event Flow
 //!
-//! 1. Dispatcher subscribes to the global event bus
+//! 1. The notification service subscribes to the global event bus
 //! 2. Events are filtered by domain scope
 //! 3. Matching webhooks are identified by event type and scope
 //! 4. Payloads are built and delivered
@@ -28,10 +29,9 @@
 use super::delivery::{DeliveryResult, WebhookDelivery};
 use super::payload::WebhookPayload;
 use crate::models::MemoryEvent;
-use crate::observability::EventBus;
+use crate::notifications::{Notification, Notifier};
 use crate::storage::index::DomainScope;
 use std::sync::Arc;
-use tokio::sync::broadcast;
 
 /// Event dispatcher that routes memory events to matching webhooks.
 pub struct WebhookDispatcher {
@@ -69,37 +69,6 @@ pub fn new(
         }
     }
 
-    /// Runs the dispatcher, listening for events from the event bus.
-    ///
-    /// This is a long-running async task that should be spawned as a
-    /// background task. It will run until the event bus is closed.
-    ///
-    /// # Arguments
-    ///
-    /// * `event_bus` - The event bus to subscribe to
-    pub async fn run(&self, event_bus: &EventBus) {
-        let mut receiver = event_bus.subscribe();
-
-        loop {
-            match receiver.recv().await {
-                Ok(event) => {
-                    self.handle_event(&event);
-                },
-                Err(broadcast::error::RecvError::Lagged(skipped)) => {
-                    metrics::counter!("webhook_events_lagged_total").increment(skipped);
-                    tracing::warn!(
-                        skipped = skipped,
-                        "Webhook dispatcher lagged behind event bus"
-                    );
-                },
-                Err(broadcast::error::RecvError::Closed) => {
-                    tracing::info!("Event bus closed, webhook dispatcher shutting down");
-                    break;
-                },
-            }
-        }
-    }
-
     /// Handles a single event by dispatching to matching webhooks.
     fn handle_event(&self, event: &MemoryEvent) {
         let event_type = event.event_type();
@@ -279,6 +248,41 @@ fn scope_to_string(&self) -> String {
     }
 }
 
+impl Notifier for WebhookDispatcher {
+    fn name(&self) -> &'static str {
+        "webhooks"
+    }
+
+    /// Delivers the event to matching webhooks; delivery failures are logged
+    /// and audited per webhook rather than returned.
+    fn notify(&self, event: &MemoryEvent) -> crate::Result<()> {
+        self.handle_event(event);
+        Ok(())
+    }
+
+    fn send(&self, notification: &Notification) -> crate::Result<bool> {
+        let domain_str = self.scope_to_string();
+        let matching: Vec<&WebhookEndpoint> = self
+            .webhooks
+            .iter()
+            .filter(|w| {
+                w.enabled
+                    && w.matches_event(&notification.event_type)
+                    && w.matches_scope(&domain_str)
+            })
+            .collect();
+        if matching.is_empty() {
+            return Ok(false);
+        }
+
+        let payload = WebhookPayload::from_notification(notification, &domain_str);
+        for webhook in matching {
+            self.dispatch_to_webhook(webhook, &payload);
+        }
+        Ok(true)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -398,6 +402,41 @@ fn test_dispatcher_filters_by_scope() {
         assert_eq!(delivery.delivery_count(), 0);
     }
 
+    #[test]
+    fn test_dispatcher_sends_notifications_to_subscribed_webhooks() {
+        let temp_dir = TempDir::new().expect("create temp dir");
+        let audit_path = temp_dir.path().join("audit.db");
+
+        let webhooks = vec![
+            test_webhook("digests", vec!["digest"], vec![]),
+            test_webhook("captures", vec!["captured"], vec![]),
+        ];
+
+        let delivery = Arc::new(MockDeliveryBackend::new());
+        let audit = Arc::new(WebhookAuditLogger::new(&audit_path).expect("create audit"));
+
+        let dispatcher = WebhookDispatcher::new(
+            webhooks,
+            Arc::clone(&delivery) as Arc<dyn WebhookDelivery>,
+            audit,
+            DomainScope::Project,
+        );
+
+        let notification = Notification {
+            event_type: "digest".to_string(),
+            title: "Subcog digest: 3 memories".to_string(),
+            body: "Digest body".to_string(),
+            timestamp: 0,
+        };
+
+        assert!(dispatcher.send(&notification).expect("send"));
+
+        let delivered = delivery.delivered.lock().expect("lock");
+        assert_eq!(delivered.len(), 1);
+        assert_eq!(delivered[0].0, "digests");
+        assert_eq!(delivered[0].1.data["body"], "Digest body");
+    }
+
     #[test]
     fn test_dispatcher_skips_disabled_webhooks() {
         let temp_dir = TempDir::new().expect("create temp dir");
@@ -71,7 +71,7 @@
 pub use dispatcher::WebhookDispatcher;
 pub use payload::WebhookPayload;
 
-use crate::observability::global_event_bus;
+use crate::notifications::NotificationService;
 use crate::storage::index::DomainScope;
 use crate::{Error, Result};
 use std::path::Path;
@@ -177,9 +177,9 @@ pub fn enabled_webhook_count(&self) -> usize {
         self.config.webhooks.iter().filter(|w| w.enabled).count()
     }
 
-    /// Creates and starts a webhook dispatcher.
+    /// Creates a webhook dispatcher.
     ///
-    /// The dispatcher subscribes to the global event bus and delivers
+    /// The dispatcher is a [`crate::notifications::Notifier`] that delivers
     /// webhooks for matching events.
     ///
     /// # Returns
@@ -197,20 +197,18 @@ pub fn create_dispatcher(&self) -> WebhookDispatcher {
 
     /// Starts the webhook dispatcher as a background task.
     ///
-    /// This spawns a tokio task that listens to the event bus and
-    /// dispatches webhooks for matching events.
+    /// This spawns a [`NotificationService`] with only the webhook
+    /// dispatcher. Use [`Self::create_dispatcher`] to combine webhooks with
+    /// other notifiers.
     ///
     /// # Returns
     ///
     /// A join handle for the background task.
     #[must_use]
     pub fn start(&self) -> tokio::task::JoinHandle<()> {
-        let dispatcher = self.create_dispatcher();
-        let event_bus = global_event_bus();
-
-        tokio::spawn(async move {
-            dispatcher.run(event_bus).await;
-        })
+        NotificationService::new()
+            .with_notifier(Arc::new(self.create_dispatcher()))
+            .start()
     }
 
     /// Returns the audit logger for querying delivery history.
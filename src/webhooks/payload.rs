@@ -26,6 +26,7 @@
 //! in the format `sha256=<hex-encoded-signature>`.
 
 use crate::models::{MemoryEvent, MemoryId};
+use crate::notifications::Notification;
 use hmac::{Hmac, Mac};
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
@@ -66,6 +67,21 @@ pub fn from_event(event: &MemoryEvent, domain: &str) -> Self {
         }
     }
 
+    /// Creates a payload for a standalone notification (e.g. a memory digest).
+    #[must_use]
+    pub fn from_notification(notification: &Notification, domain: &str) -> Self {
+        Self {
+            event_id: uuid::Uuid::new_v4().to_string(),
+            event_type: notification.event_type.clone(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            domain: domain.to_string(),
+            data: serde_json::json!({
+                "title": notification.title,
+                "body": notification.body
+            }),
+        }
+    }
+
     /// Creates a test event payload for webhook testing.
     #[must_use]
     pub fn test_event() -> Self {
@@ -202,6 +218,7 @@ fn format_message(&self) -> String {
             "deleted" => format!("Memory deleted in {} domain", self.domain),
             "updated" => format!("Memory updated in {} domain", self.domain),
             "consolidated" => format!("Memories consolidated in {} domain", self.domain),
+            "digest" => format!("Memory digest for {} domain", self.domain),
             "test" => "Subcog webhook test event".to_string(),
             _ => format!("{} event in {} domain", self.event_type, self.domain),
         }
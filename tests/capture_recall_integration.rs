@@ -11,13 +11,16 @@
 use subcog::storage::index::SqliteBackend;
 use subcog::storage::vector::UsearchBackend;
 use subcog::{
-    CaptureRequest, CaptureService, Domain, Namespace, RecallService, SearchFilter, SearchMode,
+    CaptureOrigin, CaptureRequest, CaptureService, Domain, Namespace, RecallService, SearchFilter,
+    SearchMode,
 };
 use tempfile::TempDir;
 
 /// Helper to create a fully-configured capture service with all backends.
 fn create_capture_service(temp_dir: &TempDir) -> CaptureService {
-    let config = subcog::config::Config::default();
+    let mut config = subcog::config::Config::default();
+    // Fixtures capture with skip_security_check
+    config.security.allow_skip = true;
     let embedder: Arc<dyn subcog::Embedder> = Arc::new(FastEmbedEmbedder::new());
 
     let index_path = temp_dir.path().join("test_index.db");
@@ -64,7 +67,9 @@ fn create_recall_service(temp_dir: &TempDir) -> RecallService {
 /// Create capture and recall services with SHARED backends.
 /// This ensures vector writes from capture are visible to recall.
 fn create_shared_services(temp_dir: &TempDir) -> (CaptureService, RecallService) {
-    let config = subcog::config::Config::default();
+    let mut config = subcog::config::Config::default();
+    // Fixtures capture with skip_security_check
+    config.security.allow_skip = true;
     let embedder: Arc<dyn subcog::Embedder> = Arc::new(FastEmbedEmbedder::new());
 
     let index_path = temp_dir.path().join("test_index.db");
@@ -119,6 +124,12 @@ fn test_capture_recall_text_search_roundtrip() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     let result = capture_service.capture(request);
@@ -181,6 +192,12 @@ fn test_capture_recall_vector_search_roundtrip() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     let result = capture_service.capture(request);
@@ -280,6 +297,12 @@ fn test_capture_recall_hybrid_search_roundtrip() {
             scope: None,
             #[cfg(feature = "group-scope")]
             group_id: None,
+            sections: Vec::new(),
+            template: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
         };
 
         let result = capture_service.capture(request);
@@ -338,6 +361,12 @@ fn test_capture_recall_namespace_filtering() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
     capture_service
         .capture(request1)
@@ -355,6 +384,12 @@ fn test_capture_recall_namespace_filtering() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
     capture_service
         .capture(request2)
@@ -413,6 +448,12 @@ fn test_captured_memory_has_embedding() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     let result = capture_service.capture(request);
@@ -433,7 +474,9 @@ fn test_captured_memory_has_embedding() {
 /// Uses `new_minimal()` to avoid auto-initialization of `SQLite` backend.
 #[test]
 fn test_capture_graceful_degradation() {
-    let config = subcog::config::Config::default();
+    let mut config = subcog::config::Config::default();
+    // Fixtures capture with skip_security_check
+    config.security.allow_skip = true;
 
     // Create capture service without any backends using new_minimal()
     let capture_service = CaptureService::new_minimal(config);
@@ -454,6 +497,12 @@ fn test_capture_graceful_degradation() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     // Note: This may fail without a repo context, but the point is it doesn't crash
@@ -485,6 +534,12 @@ fn test_recall_limit_parameter_honored() {
             scope: None,
             #[cfg(feature = "group-scope")]
             group_id: None,
+            sections: Vec::new(),
+            template: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
         };
 
         let result = capture_service.capture(request);
@@ -537,6 +592,12 @@ fn test_multiple_captures_searchable() {
             scope: None,
             #[cfg(feature = "group-scope")]
             group_id: None,
+            sections: Vec::new(),
+            template: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
         };
 
         let result = capture_service.capture(request);
@@ -604,6 +665,12 @@ fn test_full_workflow_capture_recall_update() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     let result = capture_service.capture(request);
@@ -629,6 +696,12 @@ fn test_full_workflow_capture_recall_update() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     let update_result = capture_service.capture(update_request);
@@ -682,6 +755,12 @@ fn test_cross_namespace_workflow() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
     capture_service
         .capture(decision)
@@ -699,6 +778,12 @@ fn test_cross_namespace_workflow() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
     capture_service
         .capture(pattern)
@@ -716,6 +801,12 @@ fn test_cross_namespace_workflow() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
     capture_service
         .capture(learning)
@@ -769,6 +860,12 @@ fn test_semantic_search_related_concepts() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
     capture_service
         .capture(request)
@@ -812,6 +909,12 @@ fn test_score_normalization_in_results() {
             scope: None,
             #[cfg(feature = "group-scope")]
             group_id: None,
+            sections: Vec::new(),
+            template: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
         };
         capture_service
             .capture(request)
@@ -910,11 +1013,17 @@ fn test_service_container_from_current_dir_or_user_always_succeeds() {
         domain: Domain::default_for_context(),
         tags: vec!["test".to_string(), "user-scope".to_string()],
         source: None,
-        skip_security_check: true,
+        skip_security_check: false,
         ttl_seconds: None,
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     // Capture should succeed regardless of scope
@@ -941,11 +1050,17 @@ fn test_user_scope_capture_recall_roundtrip() {
         domain: Domain::new(), // User scope uses default domain
         tags: vec!["user-scope".to_string(), "sqlite".to_string()],
         source: None,
-        skip_security_check: true,
+        skip_security_check: false,
         ttl_seconds: None,
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     let capture_result = capture.capture(request);
@@ -0,0 +1,369 @@
+//! Custom Storage Backend Integration Tests
+//!
+//! Implements a trivial in-memory persistence/index/vector backend set and
+//! runs capture → recall through `ServiceContainer::builder`, verifying that
+//! library users can plug their own storage into the services.
+
+// Integration tests use expect/unwrap for simplicity - panics are acceptable in tests
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use subcog::config::Config;
+use subcog::services::{EmbeddingRoute, NamespaceEmbeddings, ServiceContainer};
+use subcog::storage::traits::VectorFilter;
+use subcog::{
+    CaptureRequest, Embedder, IndexBackend, Memory, MemoryId, Namespace, PersistenceBackend,
+    Result, SearchFilter, SearchMode, VectorBackend,
+};
+use tempfile::TempDir;
+
+// ============================================================================
+// In-Memory Backends
+// ============================================================================
+
+/// Persistence backend keeping memories in a map.
+#[derive(Default)]
+struct MemoryPersistence {
+    memories: Mutex<HashMap<MemoryId, Memory>>,
+}
+
+impl PersistenceBackend for MemoryPersistence {
+    fn store(&self, memory: &Memory) -> Result<()> {
+        self.memories
+            .lock()
+            .unwrap()
+            .insert(memory.id.clone(), memory.clone());
+        Ok(())
+    }
+
+    fn get(&self, id: &MemoryId) -> Result<Option<Memory>> {
+        Ok(self.memories.lock().unwrap().get(id).cloned())
+    }
+
+    fn delete(&self, id: &MemoryId) -> Result<bool> {
+        Ok(self.memories.lock().unwrap().remove(id).is_some())
+    }
+
+    fn list_ids(&self) -> Result<Vec<MemoryId>> {
+        Ok(self.memories.lock().unwrap().keys().cloned().collect())
+    }
+}
+
+/// Index backend matching query terms as case-insensitive substrings.
+#[derive(Default)]
+struct MemoryIndex {
+    memories: Mutex<HashMap<MemoryId, Memory>>,
+}
+
+impl MemoryIndex {
+    fn matching(
+        &self,
+        filter: &SearchFilter,
+        score: impl Fn(&Memory) -> f32,
+    ) -> Vec<(MemoryId, f32)> {
+        let mut hits: Vec<(MemoryId, f32)> = self
+            .memories
+            .lock()
+            .unwrap()
+            .values()
+            .filter(|m| filter.namespaces.is_empty() || filter.namespaces.contains(&m.namespace))
+            .map(|m| (m.id.clone(), score(m)))
+            .filter(|(_, score)| *score > 0.0)
+            .collect();
+        hits.sort_by(|a, b| b.1.total_cmp(&a.1));
+        hits
+    }
+}
+
+impl IndexBackend for MemoryIndex {
+    fn index(&self, memory: &Memory) -> Result<()> {
+        self.memories
+            .lock()
+            .unwrap()
+            .insert(memory.id.clone(), memory.clone());
+        Ok(())
+    }
+
+    fn remove(&self, id: &MemoryId) -> Result<bool> {
+        Ok(self.memories.lock().unwrap().remove(id).is_some())
+    }
+
+    fn search(
+        &self,
+        query: &str,
+        filter: &SearchFilter,
+        limit: usize,
+    ) -> Result<Vec<(MemoryId, f32)>> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        let mut hits = self.matching(filter, |memory| {
+            let content = memory.content.to_lowercase();
+            let matched = terms
+                .iter()
+                .filter(|t| content.contains(t.as_str()))
+                .count();
+            f32::from(u16::try_from(matched).unwrap_or(u16::MAX))
+        });
+        hits.truncate(limit);
+        Ok(hits)
+    }
+
+    fn clear(&self) -> Result<()> {
+        self.memories.lock().unwrap().clear();
+        Ok(())
+    }
+
+    fn list_all(&self, filter: &SearchFilter, limit: usize) -> Result<Vec<(MemoryId, f32)>> {
+        let mut hits = self.matching(filter, |_| 1.0);
+        hits.truncate(limit);
+        Ok(hits)
+    }
+
+    fn get_memory(&self, id: &MemoryId) -> Result<Option<Memory>> {
+        Ok(self.memories.lock().unwrap().get(id).cloned())
+    }
+}
+
+/// Vector backend doing a brute-force cosine scan.
+#[derive(Default)]
+struct MemoryVectors {
+    embeddings: Mutex<HashMap<MemoryId, Vec<f32>>>,
+}
+
+impl VectorBackend for MemoryVectors {
+    fn dimensions(&self) -> usize {
+        LetterEmbedder::DIMENSIONS
+    }
+
+    fn upsert(&self, id: &MemoryId, embedding: &[f32]) -> Result<()> {
+        self.embeddings
+            .lock()
+            .unwrap()
+            .insert(id.clone(), embedding.to_vec());
+        Ok(())
+    }
+
+    fn remove(&self, id: &MemoryId) -> Result<bool> {
+        Ok(self.embeddings.lock().unwrap().remove(id).is_some())
+    }
+
+    fn search(
+        &self,
+        query_embedding: &[f32],
+        _filter: &VectorFilter,
+        limit: usize,
+    ) -> Result<Vec<(MemoryId, f32)>> {
+        let mut hits: Vec<(MemoryId, f32)> = self
+            .embeddings
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, embedding)| {
+                let dot: f32 = embedding
+                    .iter()
+                    .zip(query_embedding)
+                    .map(|(a, b)| a * b)
+                    .sum();
+                (id.clone(), dot.clamp(0.0, 1.0))
+            })
+            .collect();
+        hits.sort_by(|a, b| b.1.total_cmp(&a.1));
+        hits.truncate(limit);
+        Ok(hits)
+    }
+
+    fn count(&self) -> Result<usize> {
+        Ok(self.embeddings.lock().unwrap().len())
+    }
+
+    fn clear(&self) -> Result<()> {
+        self.embeddings.lock().unwrap().clear();
+        Ok(())
+    }
+}
+
+/// Embedder producing normalized letter-frequency vectors.
+struct LetterEmbedder;
+
+impl LetterEmbedder {
+    const DIMENSIONS: usize = 26;
+}
+
+impl Embedder for LetterEmbedder {
+    fn dimensions(&self) -> usize {
+        Self::DIMENSIONS
+    }
+
+    fn embed(&self, text: &str) -> Result<Vec<f32>> {
+        let mut embedding = vec![0.0_f32; Self::DIMENSIONS];
+        for byte in text
+            .to_ascii_lowercase()
+            .bytes()
+            .filter(u8::is_ascii_lowercase)
+        {
+            embedding[usize::from(byte - b'a')] += 1.0;
+        }
+        let norm = embedding
+            .iter()
+            .map(|v| v * v)
+            .sum::<f32>()
+            .sqrt()
+            .max(f32::EPSILON);
+        Ok(embedding.into_iter().map(|v| v / norm).collect())
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+struct Backends {
+    persistence: Arc<MemoryPersistence>,
+    index: Arc<MemoryIndex>,
+    vectors: Arc<MemoryVectors>,
+}
+
+fn build_container(temp_dir: &TempDir) -> (ServiceContainer, Backends) {
+    let backends = Backends {
+        persistence: Arc::new(MemoryPersistence::default()),
+        index: Arc::new(MemoryIndex::default()),
+        vectors: Arc::new(MemoryVectors::default()),
+    };
+    let container = ServiceContainer::builder()
+        .with_config(Config::new().with_data_dir(temp_dir.path()))
+        .with_persistence(Arc::clone(&backends.persistence) as _)
+        .with_index(Arc::clone(&backends.index) as _)
+        .with_vector(Arc::clone(&backends.vectors) as _)
+        .with_embedder(Arc::new(LetterEmbedder))
+        .build()
+        .expect("Failed to build service container");
+    (container, backends)
+}
+
+#[test]
+fn test_capture_writes_to_custom_backends() {
+    let temp_dir = TempDir::new().unwrap();
+    let (container, backends) = build_container(&temp_dir);
+
+    let result = container
+        .capture()
+        .capture(
+            CaptureRequest::new("Use PostgreSQL for production storage")
+                .with_namespace(Namespace::Decisions),
+        )
+        .expect("Capture should succeed");
+
+    assert!(result.warnings.is_empty());
+    assert_eq!(backends.persistence.count().unwrap(), 1);
+    assert!(
+        backends
+            .index
+            .get_memory(&result.memory_id)
+            .unwrap()
+            .is_some()
+    );
+    assert_eq!(backends.vectors.count().unwrap(), 1);
+    // Nothing falls back to the default SQLite index
+    assert!(!temp_dir.path().join("index.db").exists());
+}
+
+#[test]
+fn test_capture_recall_roundtrip_through_custom_backends() {
+    let temp_dir = TempDir::new().unwrap();
+    let (container, _backends) = build_container(&temp_dir);
+
+    let captured = container
+        .capture()
+        .capture(
+            CaptureRequest::new("Use PostgreSQL for production storage")
+                .with_namespace(Namespace::Decisions),
+        )
+        .unwrap();
+    container
+        .capture()
+        .capture(
+            CaptureRequest::new("Rust borrow checker tips").with_namespace(Namespace::Learnings),
+        )
+        .unwrap();
+
+    let recall = container.recall().unwrap();
+    let filter = SearchFilter::new();
+
+    let text = recall
+        .search("postgresql", SearchMode::Text, &filter, 10)
+        .unwrap();
+    assert_eq!(text.memories.len(), 1);
+    assert_eq!(text.memories[0].memory.id, captured.memory_id);
+
+    let vector = recall
+        .search(
+            "PostgreSQL production storage",
+            SearchMode::Vector,
+            &filter,
+            1,
+        )
+        .unwrap();
+    assert_eq!(vector.memories.len(), 1);
+    assert_eq!(vector.memories[0].memory.id, captured.memory_id);
+}
+
+#[test]
+fn test_namespace_route_embeds_into_its_own_index() {
+    let temp_dir = TempDir::new().unwrap();
+    let global = Arc::new(MemoryVectors::default());
+    let apis = Arc::new(MemoryVectors::default());
+    let routes = NamespaceEmbeddings::new().with_route(
+        Namespace::Apis,
+        EmbeddingRoute {
+            model: "letters-apis".to_string(),
+            embedder: Arc::new(LetterEmbedder),
+            vector: Arc::clone(&apis) as _,
+        },
+    );
+    let container = ServiceContainer::builder()
+        .with_config(Config::new().with_data_dir(temp_dir.path()))
+        .with_index(Arc::new(MemoryIndex::default()))
+        .with_vector(Arc::clone(&global) as _)
+        .with_embedder(Arc::new(LetterEmbedder))
+        .with_namespace_embeddings(Arc::new(routes))
+        .build()
+        .unwrap();
+
+    let endpoint = container
+        .capture()
+        .capture(
+            CaptureRequest::new("GET /users returns paginated users")
+                .with_namespace(Namespace::Apis),
+        )
+        .unwrap();
+    let decision = container
+        .capture()
+        .capture(
+            CaptureRequest::new("Use PostgreSQL for production storage")
+                .with_namespace(Namespace::Decisions),
+        )
+        .unwrap();
+
+    assert_eq!(apis.count().unwrap(), 1);
+    assert_eq!(global.count().unwrap(), 1);
+    assert!(
+        apis.embeddings
+            .lock()
+            .unwrap()
+            .contains_key(&endpoint.memory_id)
+    );
+
+    // Recall merges hits from the global and the routed index
+    let recall = container.recall().unwrap();
+    let hits = recall
+        .search(
+            "paginated users endpoint",
+            SearchMode::Vector,
+            &SearchFilter::new(),
+            10,
+        )
+        .unwrap();
+    let ids: Vec<&MemoryId> = hits.memories.iter().map(|hit| &hit.memory.id).collect();
+    assert_eq!(ids.first(), Some(&&endpoint.memory_id));
+    assert!(ids.contains(&&decision.memory_id));
+}
@@ -18,7 +18,7 @@
 use subcog::storage::graph::SqliteGraphBackend;
 use subcog::storage::index::SqliteBackend;
 use subcog::storage::vector::UsearchBackend;
-use subcog::{CaptureRequest, Embedder, IndexBackend, Namespace, VectorBackend};
+use subcog::{CaptureOrigin, CaptureRequest, Embedder, IndexBackend, Namespace, VectorBackend};
 use tempfile::TempDir;
 
 /// Helper to create a graph service for testing.
@@ -109,6 +109,7 @@ fn create_capture_service_with_extraction(
 ) -> CaptureService {
     let mut config = subcog::config::Config::default();
     config.features.auto_extract_entities = true;
+    config.security.allow_skip = true;
 
     let embedder: Arc<dyn Embedder> = Arc::new(FastEmbedEmbedder::new());
 
@@ -166,6 +167,12 @@ fn test_entity_extraction_triggered_during_capture() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     let result = capture_service.capture(request);
@@ -201,6 +208,12 @@ fn test_entities_stored_in_graph_after_capture() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     let result = capture_service.capture(request);
@@ -473,7 +486,8 @@ fn test_entity_query_with_limit() {
 fn test_capture_without_extraction() {
     let temp_dir = TempDir::new().expect("Failed to create temp dir");
 
-    let config = subcog::config::Config::default();
+    let mut config = subcog::config::Config::default();
+    config.security.allow_skip = true;
     let embedder: Arc<dyn Embedder> = Arc::new(FastEmbedEmbedder::new());
 
     let index_path = temp_dir.path().join("test_index.db");
@@ -512,6 +526,12 @@ fn test_capture_without_extraction() {
         scope: None,
         #[cfg(feature = "group-scope")]
         group_id: None,
+        sections: Vec::new(),
+        template: None,
+        origin: CaptureOrigin::Manual,
+        confidence: None,
+        attachments: Vec::new(),
+        content_type: None,
     };
 
     let result = capture_service.capture(request);
@@ -20,6 +20,7 @@
 )]
 
 use serde_json::{Value, json};
+use subcog::Error;
 
 // ============================================================================
 // Session Start Handler Edge Cases
@@ -46,8 +47,8 @@ fn test_handle_invalid_json() {
         let handler = SessionStartHandler::default();
         let result = handler.handle("not valid json {{{{");
 
-        // Should handle invalid JSON gracefully (falls back to defaults)
-        assert!(result.is_ok());
+        // Invalid JSON is rejected rather than read as defaults
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
     #[test]
@@ -55,8 +56,8 @@ fn test_handle_json_array_instead_of_object() {
         let handler = SessionStartHandler::default();
         let result = handler.handle("[1, 2, 3]");
 
-        // Should handle wrong JSON type gracefully
-        assert!(result.is_ok());
+        // Only JSON objects match the schema
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
     #[test]
@@ -74,8 +75,8 @@ fn test_handle_numeric_session_id() {
         let input = json!({"session_id": 12345, "cwd": "/path"}).to_string();
         let result = handler.handle(&input);
 
-        // Should handle wrong type gracefully (uses default)
-        assert!(result.is_ok());
+        // Mistyped fields are rejected
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
     #[test]
@@ -267,8 +268,7 @@ fn test_handle_invalid_json() {
         let handler = UserPromptHandler::default();
         let result = handler.handle("{{{{not json}}}}");
 
-        // Should handle invalid JSON gracefully
-        assert!(result.is_ok());
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
     #[test]
@@ -296,8 +296,8 @@ fn test_handle_numeric_prompt() {
         let input = json!({"prompt": 12345}).to_string();
         let result = handler.handle(&input);
 
-        // Should handle wrong type gracefully
-        assert!(result.is_ok());
+        // Mistyped fields are rejected
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
     #[test]
@@ -419,7 +419,7 @@ fn test_handle_invalid_json() {
         let handler = StopHandler::default();
         let result = handler.handle("not json");
 
-        assert!(result.is_ok());
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
     #[test]
@@ -498,7 +498,7 @@ fn test_handle_invalid_json() {
         let handler = PostToolUseHandler::default();
         let result = handler.handle("{{{{");
 
-        assert!(result.is_ok());
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
     #[test]
@@ -605,7 +605,7 @@ fn test_handle_invalid_json() {
         let handler = PreCompactHandler::default();
         let result = handler.handle("not json at all");
 
-        assert!(result.is_ok());
+        assert!(matches!(result, Err(Error::InvalidInput(_))));
     }
 
     #[test]
@@ -54,6 +54,8 @@ fn test_registry_contains_all_core_tools() {
         assert!(registry.get_tool("subcog_sync").is_some());
         assert!(registry.get_tool("subcog_reindex").is_some());
         assert!(registry.get_tool("subcog_gdpr_export").is_some());
+        assert!(registry.get_tool("subcog_export").is_some());
+        assert!(registry.get_tool("subcog_import").is_some());
 
         // Prompt management tools
         assert!(registry.get_tool("prompt_save").is_some());
@@ -55,7 +55,7 @@ macro_rules! require_postgres {
 
 mod persistence {
     use super::*;
-    use subcog::models::{Domain, Memory, MemoryId, MemoryStatus, Namespace};
+    use subcog::models::{CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace};
     use subcog::storage::index::PostgresBackend;
     use subcog::storage::traits::PersistenceBackend;
     use uuid::Uuid;
@@ -89,6 +89,13 @@ fn create_test_memory(content: &str, namespace: Namespace) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
             expires_at: None,
         }
     }
@@ -139,6 +146,13 @@ fn test_postgres_store_and_get() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
             expires_at: None,
         };
 
@@ -250,6 +264,13 @@ fn test_postgres_update() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
             expires_at: None,
         };
 
@@ -362,7 +383,9 @@ fn test_postgres_invalid_connection() {
 
 mod index {
     use super::*;
-    use subcog::models::{Domain, Memory, MemoryId, MemoryStatus, Namespace, SearchFilter};
+    use subcog::models::{
+        CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace, SearchFilter,
+    };
     use subcog::storage::index::PostgresBackend as PostgresIndexBackend;
     use subcog::storage::traits::IndexBackend;
     use uuid::Uuid;
@@ -404,6 +427,13 @@ fn test_postgres_index_text_search() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
             expires_at: None,
         };
 
@@ -59,7 +59,9 @@ macro_rules! require_redis {
 
 mod index {
     use super::*;
-    use subcog::models::{Domain, Memory, MemoryId, MemoryStatus, Namespace, SearchFilter};
+    use subcog::models::{
+        CaptureOrigin, Domain, Memory, MemoryId, MemoryStatus, Namespace, SearchFilter,
+    };
     use subcog::storage::index::RedisBackend;
     use subcog::storage::traits::IndexBackend;
     use uuid::Uuid;
@@ -89,6 +91,13 @@ fn create_test_memory(content: &str, namespace: Namespace) -> Memory {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
             expires_at: None,
         }
     }
@@ -150,6 +159,13 @@ fn test_redis_index_and_search() {
             is_summary: false,
             source_memory_ids: None,
             consolidation_timestamp: None,
+            origin: CaptureOrigin::Manual,
+            confidence: None,
+            attachments: Vec::new(),
+            content_type: None,
+            pinned: false,
+            commit: None,
+            language: None,
             expires_at: None,
         };
 
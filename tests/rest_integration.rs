@@ -0,0 +1,146 @@
+//! REST API Integration Tests
+//!
+//! Runs capture → recall → get → delete through the `/memories` routes of the
+//! `rest` feature against an in-memory `SQLite` index.
+
+#![cfg(feature = "rest")]
+// Integration tests use expect/unwrap for simplicity - panics are acceptable in tests
+#![allow(clippy::expect_used, clippy::unwrap_used)]
+
+use axum::Router;
+use axum::body::Body;
+use axum::http::{Method, Request, StatusCode, header};
+use serde_json::{Value, json};
+use std::sync::Arc;
+use subcog::config::Config;
+use subcog::mcp::rest_router;
+use subcog::services::ServiceContainer;
+use subcog::storage::index::SqliteBackend;
+use tempfile::TempDir;
+use tower::util::ServiceExt;
+
+fn build_app(temp_dir: &TempDir) -> Router {
+    let services = ServiceContainer::builder()
+        .with_config(Config::new().with_data_dir(temp_dir.path()))
+        .with_index(Arc::new(SqliteBackend::in_memory().unwrap()))
+        .build()
+        .unwrap();
+    rest_router(Arc::new(services))
+}
+
+/// Sends a request and returns the status and JSON body (`Null` if empty).
+async fn send(app: &Router, method: Method, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let request = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => request
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string())),
+        None => request.body(Body::empty()),
+    }
+    .unwrap();
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .unwrap();
+    let json = if bytes.is_empty() {
+        Value::Null
+    } else {
+        serde_json::from_slice(&bytes).unwrap()
+    };
+    (status, json)
+}
+
+#[tokio::test]
+async fn test_capture_recall_get_delete() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = build_app(&temp_dir);
+
+    // Capture
+    let (status, captured) = send(
+        &app,
+        Method::POST,
+        "/memories",
+        Some(json!({
+            "content": "Use PostgreSQL for primary storage because of JSONB support",
+            "namespace": "decisions",
+            "tags": ["database"]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let id = captured["id"].as_str().unwrap().to_string();
+    assert!(captured["urn"].as_str().unwrap().starts_with("subcog://"));
+
+    // Recall
+    let (status, recalled) =
+        send(&app, Method::GET, "/memories?q=PostgreSQL&mode=text", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(recalled["count"], 1);
+    assert_eq!(recalled["memories"][0]["id"], id.as_str());
+    assert!(recalled["memories"][0]["score"].is_number());
+
+    // Get
+    let (status, memory) = send(&app, Method::GET, &format!("/memories/{id}"), None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(memory["namespace"], "decisions");
+    assert_eq!(memory["tags"][0], "database");
+    assert_eq!(memory["status"], "active");
+
+    // Soft delete tombstones the memory and hides it from recall
+    let (status, _) = send(&app, Method::DELETE, &format!("/memories/{id}"), None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (_, memory) = send(&app, Method::GET, &format!("/memories/{id}"), None).await;
+    assert_eq!(memory["status"], "tombstoned");
+    let (_, recalled) = send(&app, Method::GET, "/memories?q=PostgreSQL&mode=text", None).await;
+    assert_eq!(recalled["count"], 0);
+
+    // Hard delete removes it
+    let uri = format!("/memories/{id}?hard=true");
+    let (status, _) = send(&app, Method::DELETE, &uri, None).await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+    let (status, error) = send(&app, Method::GET, &format!("/memories/{id}"), None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert!(
+        error["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("Memory not found")
+    );
+}
+
+#[tokio::test]
+async fn test_invalid_requests_use_error_envelope() {
+    let temp_dir = TempDir::new().unwrap();
+    let app = build_app(&temp_dir);
+
+    let (status, error) = send(
+        &app,
+        Method::POST,
+        "/memories",
+        Some(json!({ "content": "Some note", "namespace": "nonsense" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(error["error"]["code"], -32000);
+    assert!(
+        error["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("Unknown namespace")
+    );
+
+    let (status, error) = send(
+        &app,
+        Method::POST,
+        "/memories",
+        Some(json!({ "text": "x" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(error["error"]["message"].is_string());
+
+    let (status, _) = send(&app, Method::DELETE, "/memories/missing", None).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}